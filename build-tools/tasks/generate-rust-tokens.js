@@ -17,6 +17,7 @@ const path = require('path');
 const { task } = require('../utils/gulp-utils');
 
 const RUST_OUTPUT_DIR = path.join(__dirname, '../../rust-components/crates/design-tokens/src/generated');
+const RUST_METRICS_OUTPUT_DIR = path.join(__dirname, '../../rust-components/crates/design-tokens/src/metrics');
 const CSS_OUTPUT_DIR = path.join(__dirname, '../../rust-components/dist/styles');
 const STYLE_DICTIONARY_ROOT = path.join(__dirname, '../../lib/style-dictionary');
 
@@ -89,6 +90,48 @@ function resolveTokenValue(value, allTokens, mode = 'light') {
   return value;
 }
 
+/**
+ * Mode states that are used when no mode state of a token applies
+ */
+const DEFAULT_STATES = ['light', 'comfortable', 'default'];
+
+/**
+ * Non-default mode states, by the Rust `Mode` variant selecting them
+ */
+const MODE_STATES = [
+  ['Dark', 'dark'],
+  ['Compact', 'compact'],
+  ['MotionDisabled', 'disabled'],
+];
+
+/**
+ * Resolve a token to its concrete value in a mode state, following token
+ * references. Tokens that do not vary by the state resolve to their default.
+ */
+function resolveModeValue(value, allTokens, state, name) {
+  if (typeof value === 'string' && value.startsWith('{') && value.endsWith('}')) {
+    const refName = value.slice(1, -1);
+    if (!(refName in allTokens)) {
+      throw new Error(`Token ${name} references unknown token: ${value}`);
+    }
+    return resolveModeValue(allTokens[refName], allTokens, state, name);
+  }
+
+  if (typeof value === 'object' && value !== null) {
+    const next = state in value ? value[state] : DEFAULT_STATES.map(s => value[s]).find(v => v !== undefined);
+    return resolveModeValue(next, allTokens, state, name);
+  }
+
+  return String(value);
+}
+
+/**
+ * Format a value as a Rust string literal
+ */
+function toRustString(value) {
+  return JSON.stringify(value);
+}
+
 /**
  * Load and parse design tokens from compiled style-dictionary
  */
@@ -206,14 +249,21 @@ function generateCSS(theme, metadata) {
 /**
  * Generate Rust token type definitions
  */
+/**
+ * Name of the generated enum holding a token, categorized by its prefix
+ */
+function tokenEnumName(name) {
+  const match = name.match(/^(color|spacing|size|font|border|shadow|motion)/i);
+  return `${toPascalCase(match ? match[1].toLowerCase() : 'other')}Token`;
+}
+
 function generateRustTypes(theme, metadata) {
   const tokens = theme.tokens || {};
   const tokensByCategory = {};
 
   // Categorize tokens by prefix
   Object.keys(tokens).forEach(name => {
-    const match = name.match(/^(color|spacing|size|font|border|shadow|motion)/i);
-    const category = match ? match[1].toLowerCase() : 'other';
+    const category = tokenEnumName(name);
 
     if (!tokensByCategory[category]) {
       tokensByCategory[category] = [];
@@ -227,15 +277,14 @@ function generateRustTypes(theme, metadata) {
 //! Auto-generated design tokens from style-dictionary
 //! DO NOT EDIT MANUALLY
 
+use crate::Mode;
 use std::fmt;
 
 `;
 
   // Generate enum for each category
-  Object.entries(tokensByCategory).forEach(([category, tokenNames]) => {
-    const enumName = `${toPascalCase(category)}Token`;
-
-    rust += `/// ${toPascalCase(category)} design tokens\n`;
+  Object.entries(tokensByCategory).forEach(([enumName, tokenNames]) => {
+    rust += `/// ${enumName.replace(/Token$/, '')} design tokens\n`;
     rust += `#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]\n`;
     rust += `pub enum ${enumName} {\n`;
 
@@ -251,6 +300,13 @@ use std::fmt;
 
     // Implement CSS var name method
     rust += `impl ${enumName} {\n`;
+    rust += `    /// All tokens in this group\n`;
+    rust += `    pub const ALL: &'static [Self] = &[\n`;
+    tokenNames.forEach(name => {
+      rust += `        Self::${toRustVariant(name)},\n`;
+    });
+    rust += `    ];\n\n`;
+
     rust += `    /// Get the CSS custom property name for this token\n`;
     rust += `    pub fn css_var_name(&self) -> &'static str {\n`;
     rust += `        match self {\n`;
@@ -263,6 +319,39 @@ use std::fmt;
     rust += `        }\n`;
     rust += `    }\n\n`;
 
+    // Implement resolved value method
+    rust += `    /// Get the resolved value of this token in a mode\n`;
+    rust += `    ///\n`;
+    rust += `    /// Modes that do not apply to the token, such as density modes for\n`;
+    rust += `    /// colors, give its default value.\n`;
+    const resolved = tokenNames.map(name => {
+      const defaultValue = resolveModeValue(tokens[name], tokens, null, name);
+      const overrides = MODE_STATES.map(([variant, state]) => [
+        variant,
+        resolveModeValue(tokens[name], tokens, state, name),
+      ]).filter(([, value]) => value !== defaultValue);
+      return { name, defaultValue, overrides };
+    });
+    const modeParam = resolved.some(({ overrides }) => overrides.length > 0) ? 'mode' : '_mode';
+    rust += `    pub fn value(&self, ${modeParam}: Mode) -> &'static str {\n`;
+    rust += `        match self {\n`;
+
+    resolved.forEach(({ name, defaultValue, overrides }) => {
+      if (overrides.length === 0) {
+        rust += `            Self::${toRustVariant(name)} => ${toRustString(defaultValue)},\n`;
+      } else {
+        rust += `            Self::${toRustVariant(name)} => match mode {\n`;
+        overrides.forEach(([variant, value]) => {
+          rust += `                Mode::${variant} => ${toRustString(value)},\n`;
+        });
+        rust += `                _ => ${toRustString(defaultValue)},\n`;
+        rust += `            },\n`;
+      }
+    });
+
+    rust += `        }\n`;
+    rust += `    }\n\n`;
+
     // Implement Display trait
     rust += `    /// Get the CSS var() function for use in styles\n`;
     rust += `    pub fn css_var(&self) -> String {\n`;
@@ -280,6 +369,99 @@ use std::fmt;
   return rust;
 }
 
+/**
+ * Check that a token resolves to a pixel value such as "12px" in every mode
+ */
+function assertPixelToken(name, tokens) {
+  [null, ...MODE_STATES.map(([, state]) => state)].forEach(state => {
+    const value = resolveModeValue(tokens[name], tokens, state, name);
+    if (!/^-?\d+(?:\.\d+)?px$/.test(value)) {
+      throw new Error(`Token ${name} does not resolve to a pixel value: ${value}`);
+    }
+  });
+}
+
+/**
+ * Generate an enum of pixel tokens mapping each variant to the generated
+ * token holding its value
+ */
+function generateTokenGroup(enumName, description, tokenNames, tokens) {
+  tokenNames.forEach(name => assertPixelToken(name, tokens));
+  const tokenEnum = tokenEnumName(tokenNames[0]);
+
+  let rust = `/// ${description}\n`;
+  rust += `#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]\n`;
+  rust += `pub enum ${enumName} {\n`;
+  tokenNames.forEach(name => {
+    rust += `    ${toRustVariant(name)},\n`;
+  });
+  rust += `}\n\n`;
+
+  rust += `impl ${enumName} {\n`;
+  rust += `    /// All tokens in this group\n`;
+  rust += `    pub const ALL: &'static [Self] = &[\n`;
+  tokenNames.forEach(name => {
+    rust += `        Self::${toRustVariant(name)},\n`;
+  });
+  rust += `    ];\n\n`;
+
+  rust += `    /// Get the generated design token holding this token's value\n`;
+  rust += `    pub fn token(&self) -> ${tokenEnum} {\n`;
+  rust += `        match self {\n`;
+  tokenNames.forEach(name => {
+    rust += `            Self::${toRustVariant(name)} => ${tokenEnum}::${toRustVariant(name)},\n`;
+  });
+  rust += `        }\n`;
+  rust += `    }\n`;
+  rust += `}\n\n`;
+
+  return rust;
+}
+
+/**
+ * Generate the token groups for non-CSS consumers (canvas, charts)
+ *
+ * The groups only name generated tokens; their values come from the
+ * tokens' `value()` methods, so the two cannot drift.
+ */
+function generateRustMetrics(theme) {
+  const tokens = theme.tokens || {};
+  const names = Object.keys(tokens);
+
+  const spaceNames = names.filter(name => /^space(Scaled2x|Scaled|Static)[A-Z]/.test(name));
+  const iconSizeNames = names.filter(name => /^sizeIcon[A-Z]/.test(name));
+  const radiusNames = names.filter(name => /^borderRadius[A-Z]/.test(name));
+  const categoricalNames = names
+    .filter(name => /^colorChartsPaletteCategorical\d+$/.test(name))
+    .sort((a, b) => Number(a.match(/\d+$/)[0]) - Number(b.match(/\d+$/)[0]));
+  const imports = [
+    ...new Set([spaceNames, iconSizeNames, radiusNames, categoricalNames].map(group => tokenEnumName(group[0]))),
+  ].sort();
+
+  let rust = `// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Auto-generated token groups from style-dictionary
+//! DO NOT EDIT MANUALLY
+
+use crate::generated::{${imports.join(', ')}};
+
+`;
+
+  rust += generateTokenGroup('SpaceToken', 'Spacing scale tokens', spaceNames, tokens);
+  rust += generateTokenGroup('IconSizeToken', 'Icon size tokens', iconSizeNames, tokens);
+  rust += generateTokenGroup('BorderRadiusToken', 'Border radius tokens', radiusNames, tokens);
+
+  rust += `/// Categorical chart palette tokens, in palette order\n`;
+  rust += `pub(crate) const CATEGORICAL_PALETTE: [ColorToken; ${categoricalNames.length}] = [\n`;
+  categoricalNames.forEach(name => {
+    rust += `    ColorToken::${toRustVariant(name)},\n`;
+  });
+  rust += `];\n`;
+
+  return rust;
+}
+
 /**
  * Generate Rust module file that exports all token types
  */
@@ -307,6 +489,7 @@ async function generateRustTokens() {
   // Ensure output directories exist
   await fs.mkdir(RUST_OUTPUT_DIR, { recursive: true });
   await fs.mkdir(CSS_OUTPUT_DIR, { recursive: true });
+  await fs.mkdir(RUST_METRICS_OUTPUT_DIR, { recursive: true });
 
   // Load design tokens
   const { theme, metadata } = await loadDesignTokens();
@@ -328,6 +511,12 @@ async function generateRustTokens() {
   await fs.writeFile(path.join(RUST_OUTPUT_DIR, 'mod.rs'), rustMod, 'utf-8');
   console.log(`✓ Rust module written to ${path.join(RUST_OUTPUT_DIR, 'mod.rs')}`);
 
+  // Generate numeric metrics
+  console.log('Generating Rust token metrics...');
+  const rustMetrics = generateRustMetrics(theme);
+  await fs.writeFile(path.join(RUST_METRICS_OUTPUT_DIR, 'groups.rs'), rustMetrics, 'utf-8');
+  console.log(`✓ Rust metrics written to ${path.join(RUST_METRICS_OUTPUT_DIR, 'groups.rs')}`);
+
   console.log('Token generation complete!');
 }
 
@@ -3,8 +3,8 @@
 
 //! Performance benchmarks for Cloudscape Rust components
 
-use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use cloudscape_components::*;
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
 use yew::prelude::*;
 
 /// Benchmark component HTML construction
@@ -16,7 +16,7 @@ fn bench_component_html(c: &mut Criterion) {
             let html = html! {
                 <Badge color={BadgeColor::Blue}>{"Test"}</Badge>
             };
-            black_box(html);
+            let _ = black_box(html);
         });
     });
 
@@ -25,7 +25,7 @@ fn bench_component_html(c: &mut Criterion) {
             let html = html! {
                 <Button variant={ButtonVariant::Primary}>{"Click me"}</Button>
             };
-            black_box(html);
+            let _ = black_box(html);
         });
     });
 
@@ -34,7 +34,7 @@ fn bench_component_html(c: &mut Criterion) {
             let html = html! {
                 <Spinner size={SpinnerSize::Normal} />
             };
-            black_box(html);
+            let _ = black_box(html);
         });
     });
 
@@ -43,7 +43,7 @@ fn bench_component_html(c: &mut Criterion) {
             let html = html! {
                 <Alert alert_type={AlertType::Info}>{"Message"}</Alert>
             };
-            black_box(html);
+            let _ = black_box(html);
         });
     });
 
@@ -65,7 +65,7 @@ fn bench_complex_trees(c: &mut Criterion) {
                     </SpaceBetween>
                 </Container>
             };
-            black_box(html);
+            let _ = black_box(html);
         });
     });
 
@@ -89,7 +89,7 @@ fn bench_complex_trees(c: &mut Criterion) {
                     </SpaceBetween>
                 </Container>
             };
-            black_box(html);
+            let _ = black_box(html);
         });
     });
 
@@ -145,11 +145,12 @@ fn bench_string_operations(c: &mut Criterion) {
 
     group.bench_function("class_concat_join", |b| {
         b.iter(|| {
-            let classes = vec![
+            let classes = [
                 "awsui-button",
                 "awsui-button-variant-primary",
                 "awsui-button-size-normal",
-            ].join(" ");
+            ]
+            .join(" ");
             black_box(classes);
         });
     });
@@ -164,13 +165,55 @@ fn bench_string_operations(c: &mut Criterion) {
     group.finish();
 }
 
+/// Benchmark class builder against joining owned strings
+fn bench_class_builder(c: &mut Criterion) {
+    use cloudscape_components::internal::ClassBuilder;
+
+    let mut group = c.benchmark_group("class_builder");
+
+    group.bench_function("vec_string_join", |b| {
+        b.iter(|| {
+            let mut classes = vec![String::from("awsui-table-row")];
+            if black_box(true) {
+                classes.push(String::from("awsui-table-row-selected"));
+            }
+            classes.push(String::from("awsui-table-row-accent-error"));
+            black_box(classes.join(" "));
+        });
+    });
+
+    group.bench_function("static_build", |b| {
+        b.iter(|| {
+            let classes = ClassBuilder::new()
+                .add("awsui-table-row")
+                .add_if(black_box(true), "awsui-table-row-selected")
+                .add_opt(Some("awsui-table-row-accent-error"))
+                .build();
+            black_box(classes);
+        });
+    });
+
+    group.bench_function("static_as_str", |b| {
+        let builder = ClassBuilder::new()
+            .add("awsui-table-row")
+            .add("awsui-table-row-selected")
+            .add("awsui-table-row-accent-error");
+        b.iter(|| {
+            black_box(builder.as_str());
+        });
+    });
+
+    group.finish();
+}
+
 #[cfg(feature = "generated")]
 criterion_group!(
     benches,
     bench_component_html,
     bench_complex_trees,
     bench_design_tokens,
-    bench_string_operations
+    bench_string_operations,
+    bench_class_builder
 );
 
 #[cfg(not(feature = "generated"))]
@@ -178,7 +221,8 @@ criterion_group!(
     benches,
     bench_component_html,
     bench_complex_trees,
-    bench_string_operations
+    bench_string_operations,
+    bench_class_builder
 );
 
 criterion_main!(benches);
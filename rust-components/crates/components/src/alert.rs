@@ -5,9 +5,10 @@
 //!
 //! Alerts communicate important information to users with different severity levels.
 
+use crate::i18n::{I18nContext, use_i18n};
 use crate::internal::{
     AnalyticsMetadata, AriaAttributes, BaseComponentProps, ClassBuilder, ComponentMetadata,
-    CustomEvent,
+    CustomEvent, apply_root_attributes,
 };
 use yew::prelude::*;
 
@@ -58,10 +59,74 @@ impl AlertType {
 }
 
 /// Internationalization strings for Alert
+///
+/// Unset strings fall back to the closest I18nProvider (keys under
+/// `"alert."`), then to English.
 #[derive(Clone, PartialEq, Default)]
 pub struct AlertI18nStrings {
-    /// ARIA label for the dismiss button
+    /// ARIA label for the dismiss button (default: "Dismiss")
     pub dismiss_aria_label: Option<String>,
+    /// ARIA label for the info icon (default: "Info")
+    pub info_icon_aria_label: Option<String>,
+    /// ARIA label for the success icon (default: "Success")
+    pub success_icon_aria_label: Option<String>,
+    /// ARIA label for the warning icon (default: "Warning")
+    pub warning_icon_aria_label: Option<String>,
+    /// ARIA label for the error icon (default: "Error")
+    pub error_icon_aria_label: Option<String>,
+}
+
+/// Composes the accessible name of a status message so that its severity is
+/// announced first: `"<type label>: <header> <summary>"`
+pub(crate) fn status_aria_label(
+    type_label: &str,
+    header: Option<&str>,
+    summary: Option<&str>,
+) -> String {
+    let message = [header, summary]
+        .into_iter()
+        .flatten()
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    if message.is_empty() {
+        type_label.to_string()
+    } else {
+        format!("{}: {}", type_label, message)
+    }
+}
+
+/// Strings used to render an Alert after applying the fallback chain
+struct ResolvedStrings {
+    dismiss_aria_label: String,
+    type_label: String,
+}
+
+/// Resolves the strings for an alert type: `i18n_strings` prop, then the
+/// I18nProvider, then English
+fn resolve_strings(
+    strings: &AlertI18nStrings,
+    alert_type: AlertType,
+    i18n: &I18nContext,
+) -> ResolvedStrings {
+    let resolve = |value: &Option<String>, key: &str, default: &str| {
+        value
+            .clone()
+            .or_else(|| i18n.message("alert", key).map(str::to_string))
+            .unwrap_or_else(|| default.to_string())
+    };
+    let (type_label, type_key) = match alert_type {
+        AlertType::Info => (&strings.info_icon_aria_label, "info_icon_aria_label"),
+        AlertType::Success => (&strings.success_icon_aria_label, "success_icon_aria_label"),
+        AlertType::Warning => (&strings.warning_icon_aria_label, "warning_icon_aria_label"),
+        AlertType::Error => (&strings.error_icon_aria_label, "error_icon_aria_label"),
+    };
+
+    ResolvedStrings {
+        dismiss_aria_label: resolve(&strings.dismiss_aria_label, "dismiss_aria_label", "Dismiss"),
+        type_label: resolve(type_label, type_key, alert_type.default_aria_label()),
+    }
 }
 
 /// Event detail for dismiss events
@@ -116,17 +181,22 @@ pub struct AlertProps {
 /// # Example
 ///
 /// ```rust
+/// # use yew::prelude::*;
 /// use cloudscape_components::{Alert, AlertType};
 ///
+/// # let _: Html =
 /// html! {
 ///     <Alert alert_type={AlertType::Success} header="Success!">
 ///         { "Your changes have been saved." }
 ///     </Alert>
 /// }
+/// # ;
 /// ```
 #[function_component(Alert)]
 pub fn alert(props: &AlertProps) -> Html {
     let _metadata = ComponentMetadata::new("Alert");
+    let i18n = use_i18n();
+    let strings = resolve_strings(&props.i18n_strings, props.alert_type, &i18n);
 
     // Build CSS classes
     let classes = ClassBuilder::new()
@@ -158,14 +228,13 @@ pub fn alert(props: &AlertProps) -> Html {
     };
     let analytics_attr = serde_json::to_string(&analytics).ok();
 
-    // ARIA role and label
-    let aria_label = props
-        .aria
-        .label
-        .clone()
-        .unwrap_or_else(|| props.alert_type.default_aria_label().to_string());
+    // ARIA role and label, announcing the severity before the header
+    let aria_label =
+        props.aria.label.clone().unwrap_or_else(|| {
+            status_aria_label(&strings.type_label, props.header.as_deref(), None)
+        });
 
-    html! {
+    let root = html! {
         <div
             class={classes.build()}
             role="alert"
@@ -176,7 +245,8 @@ pub fn alert(props: &AlertProps) -> Html {
             <div class="awsui-alert-icon">
                 <span
                     class={format!("awsui-icon awsui-icon-{}", props.alert_type.icon_name())}
-                    aria-hidden="true"
+                    role="img"
+                    aria-label={strings.type_label.clone()}
                 />
             </div>
 
@@ -208,10 +278,7 @@ pub fn alert(props: &AlertProps) -> Html {
                     <button
                         type="button"
                         class="awsui-button awsui-button-variant-icon"
-                        aria-label={
-                            props.i18n_strings.dismiss_aria_label.clone()
-                                .unwrap_or_else(|| "Dismiss".to_string())
-                        }
+                        aria-label={strings.dismiss_aria_label}
                         onclick={on_dismiss_click}
                     >
                         <span class="awsui-icon awsui-icon-close" aria-hidden="true" />
@@ -219,7 +286,8 @@ pub fn alert(props: &AlertProps) -> Html {
                 </div>
             }
         </div>
-    }
+    };
+    apply_root_attributes(root, &props.base)
 }
 
 #[cfg(test)]
@@ -254,4 +322,54 @@ mod tests {
     fn alert_type_default() {
         assert_eq!(AlertType::default(), AlertType::Info);
     }
+
+    #[test]
+    fn alert_status_aria_label() {
+        assert_eq!(status_aria_label("Error", None, None), "Error");
+        assert_eq!(
+            status_aria_label("Error", Some("Deployment failed"), None),
+            "Error: Deployment failed"
+        );
+        assert_eq!(
+            status_aria_label("Warning", Some(" Quota "), Some("90% used")),
+            "Warning: Quota 90% used"
+        );
+        assert_eq!(status_aria_label("Info", Some(""), Some("")), "Info");
+    }
+
+    #[test]
+    fn alert_resolve_strings_fallback() {
+        let mut messages = crate::internal::I18nStrings::new();
+        messages.set("alert.error_icon_aria_label", "Fehler");
+        messages.set("alert.dismiss_aria_label", "Schließen");
+        let i18n = I18nContext {
+            locale: Some("de-DE".to_string()),
+            messages,
+        };
+
+        let resolved = resolve_strings(&AlertI18nStrings::default(), AlertType::Error, &i18n);
+        assert_eq!(resolved.type_label, "Fehler");
+        assert_eq!(resolved.dismiss_aria_label, "Schließen");
+
+        let strings = AlertI18nStrings {
+            error_icon_aria_label: Some("Erreur".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            resolve_strings(&strings, AlertType::Error, &i18n).type_label,
+            "Erreur"
+        );
+
+        let defaults = I18nContext::default();
+        for alert_type in [
+            AlertType::Info,
+            AlertType::Success,
+            AlertType::Warning,
+            AlertType::Error,
+        ] {
+            let resolved = resolve_strings(&AlertI18nStrings::default(), alert_type, &defaults);
+            assert_eq!(resolved.type_label, alert_type.default_aria_label());
+            assert_eq!(resolved.dismiss_aria_label, "Dismiss");
+        }
+    }
 }
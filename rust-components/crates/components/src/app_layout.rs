@@ -6,8 +6,27 @@
 //! The AppLayout component provides the main application shell that organizes
 //! navigation, content, tools, and other UI elements in a consistent layout.
 //! It follows the Cloudscape Design System patterns for complex applications.
-
-use crate::internal::{BaseComponentProps, ClassBuilder, ComponentMetadata, CustomEvent};
+//!
+//! The navigation and tools panels can be resized by dragging the handle on
+//! their inner edge or with the arrow keys on it. Below
+//! [`OVERLAY_BREAKPOINT`], panels overlay the content and can't be resized.
+//!
+//! Several drawers can share the tools area: a toolbar shows a trigger per
+//! drawer, and at most one drawer is open at a time. The `tools` slot then
+//! becomes a drawer of its own.
+
+use crate::icon::Icon;
+use crate::internal::resize::{HandleDrag, HandleOrientation, ResizeHandle, dragged_size};
+use crate::internal::{
+    BaseComponentProps, ClassBuilder, ComponentMetadata, CustomEvent, apply_root_attributes,
+    use_element_size,
+};
+use crate::split_panel::{SplitPanelContext, default_split_panel_size};
+use gloo::events::EventListener;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use web_sys::Element;
 use yew::prelude::*;
 
 /// Content type determines the layout and spacing behavior
@@ -29,6 +48,28 @@ pub enum ContentType {
 }
 
 impl ContentType {
+    /// Returns the default padding of the content area
+    ///
+    /// Tables and cards start closer to the header, as their own headers
+    /// provide the spacing; forms and wizards leave extra room below for
+    /// their actions.
+    pub fn content_padding(&self) -> &'static str {
+        match self {
+            Self::Default | Self::Dashboard => {
+                "padding: var(--awsui-space-scaled-l) var(--awsui-space-layout-content-horizontal) \
+                 var(--awsui-space-layout-content-bottom)"
+            }
+            Self::Table | Self::Cards => {
+                "padding: var(--awsui-space-scaled-s) var(--awsui-space-layout-content-horizontal) \
+                 var(--awsui-space-layout-content-bottom)"
+            }
+            Self::Form | Self::Wizard => {
+                "padding: var(--awsui-space-scaled-l) var(--awsui-space-layout-content-horizontal) \
+                 var(--awsui-space-scaled-xxxl)"
+            }
+        }
+    }
+
     /// Returns the CSS class suffix for this content type
     pub fn as_str(&self) -> &'static str {
         match self {
@@ -91,11 +132,235 @@ impl SplitPanelPreferences {
     }
 }
 
+/// Width of the navigation panel in rail mode, in pixels
+pub const NAVIGATION_RAIL_WIDTH: u32 = 56;
+
+/// Viewport width below which the side panels overlay the content, in pixels
+pub const OVERLAY_BREAKPOINT: u32 = 688;
+
+/// Spacing below the content header; with `content_header` the content
+/// overlaps the header background by the dark header overlap distance
+const CONTENT_HEADER_STYLE: &str = "padding-bottom: calc(var(--awsui-space-content-header-padding-bottom) \
+     + var(--awsui-space-dark-header-overlap-distance))";
+
+/// Pulls the content up over the bottom of the content header
+const CONTENT_HEADER_OVERLAP_STYLE: &str =
+    "margin-top: calc(-1 * var(--awsui-space-dark-header-overlap-distance))";
+
+/// Builds the style of the notifications slot: pinned to the top when
+/// sticky, with a shadow once content scrolls beneath it
+fn notifications_style(sticky: bool, stuck: bool) -> Option<String> {
+    sticky.then(|| {
+        let mut style = "position: sticky; top: 0; z-index: 850".to_string();
+        if stuck {
+            style.push_str("; box-shadow: var(--awsui-shadow-flash-sticky)");
+        }
+        style
+    })
+}
+
+/// Space at the top of the viewport taken by layout content that stays in
+/// view while the page scrolls
+///
+/// Provided as a Yew context to the content of an AppLayout, so sticky
+/// content such as container headers is pinned below the header slot, where
+/// TopNavigation goes, and below sticky notifications.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) struct StickyOffset {
+    /// Height of the space in pixels
+    pub top: i32,
+}
+
+impl StickyOffset {
+    /// Gets the offset below a header and, when sticky, notifications of
+    /// the given heights
+    fn new(header_height: f64, notifications_height: Option<f64>) -> Self {
+        let height = header_height + notifications_height.unwrap_or(0.0);
+        Self {
+            top: height.round() as i32,
+        }
+    }
+}
+
+/// Width of the navigation panel when `navigation_width` is unset, in pixels
+const DEFAULT_NAVIGATION_WIDTH: u32 = 280;
+
+/// Width of the tools panel when `tools_width` is unset, in pixels
+const DEFAULT_TOOLS_WIDTH: u32 = 290;
+
+/// Id of the drawer showing the `tools` slot when `drawers` are set
+pub const TOOLS_DRAWER_ID: &str = "awsui-tools";
+
+/// Width change of a panel per arrow key press on its resize handle
+const KEYBOARD_RESIZE_STEP: u32 = 10;
+
+/// Side panel that can be resized
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ResizablePanel {
+    /// The navigation panel, resized from its right edge
+    Navigation,
+    /// The tools panel, resized from its left edge
+    Tools,
+}
+
+impl ResizablePanel {
+    /// Smallest and largest width the panel can be resized to, in pixels
+    fn limits(&self) -> (u32, u32) {
+        match self {
+            Self::Navigation => (200, 600),
+            Self::Tools => (240, 600),
+        }
+    }
+
+    /// Clamps a width to the panel's limits
+    fn clamp(&self, width: f64) -> u32 {
+        let (min, max) = self.limits();
+        (width.round().max(0.0) as u32).clamp(min, max)
+    }
+
+    /// Gets the width after dragging the handle `delta` pixels to the right
+    fn dragged_width(&self, start_width: u32, delta: f64) -> u32 {
+        let (min, max) = self.limits();
+        let delta = match self {
+            Self::Navigation => delta,
+            Self::Tools => -delta,
+        };
+        dragged_size(
+            f64::from(start_width),
+            delta,
+            f64::from(min),
+            f64::from(max),
+        ) as u32
+    }
+
+    /// Gets the width after a key press on the handle, or `None` for keys
+    /// that don't resize
+    ///
+    /// The arrow pointing away from the panel's edge of the layout grows it.
+    fn keyboard_width(&self, width: u32, key: &str) -> Option<u32> {
+        let (min, max) = self.limits();
+        let step = f64::from(KEYBOARD_RESIZE_STEP);
+        match (self, key) {
+            (Self::Navigation, "ArrowRight") | (Self::Tools, "ArrowLeft") => {
+                Some(self.clamp(f64::from(width) + step))
+            }
+            (Self::Navigation, "ArrowLeft") | (Self::Tools, "ArrowRight") => {
+                Some(self.clamp(f64::from(width) - step))
+            }
+            (_, "Home") => Some(min),
+            (_, "End") => Some(max),
+            _ => None,
+        }
+    }
+}
+
+/// Whether the viewport is narrower than [`OVERLAY_BREAKPOINT`]
+fn is_overlay_viewport() -> bool {
+    web_sys::window()
+        .and_then(|window| window.inner_width().ok())
+        .and_then(|width| width.as_f64())
+        .is_some_and(|width| width < f64::from(OVERLAY_BREAKPOINT))
+}
+
+/// Renders the handle resizing a panel to widths reported to `on_resize`
+fn render_resize_handle(
+    panel: ResizablePanel,
+    width: u32,
+    label: &str,
+    drag: &Rc<RefCell<Option<HandleDrag>>>,
+    on_resize: &Callback<u32>,
+) -> Html {
+    let class = match panel {
+        ResizablePanel::Navigation => {
+            "awsui-app-layout-resize-handle awsui-app-layout-navigation-resize-handle"
+        }
+        ResizablePanel::Tools => {
+            "awsui-app-layout-resize-handle awsui-app-layout-tools-resize-handle"
+        }
+    };
+
+    ResizeHandle {
+        class,
+        label,
+        orientation: HandleOrientation::Vertical,
+        size: width,
+        limits: panel.limits(),
+        drag,
+        on_resize,
+    }
+    .render(
+        move |start_width, delta| panel.dragged_width(start_width, delta),
+        move |width, key| panel.keyboard_width(width, key),
+    )
+}
+
+/// How the navigation panel is displayed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NavigationDisplay {
+    /// Full-width panel
+    #[default]
+    Full,
+    /// Narrow rail showing only item icons
+    Rail,
+    /// Panel collapsed, only the toggle is shown
+    Hidden,
+}
+
+impl NavigationDisplay {
+    /// Returns the CSS class name for this display mode
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Full => "full",
+            Self::Rail => "rail",
+            Self::Hidden => "hidden",
+        }
+    }
+
+    /// Returns the mode the navigation toggle switches to: Full, then Rail,
+    /// then Hidden
+    pub fn next(&self) -> Self {
+        match self {
+            Self::Full => Self::Rail,
+            Self::Rail => Self::Hidden,
+            Self::Hidden => Self::Full,
+        }
+    }
+
+    /// Whether the panel shows any navigation content
+    pub fn is_open(&self) -> bool {
+        *self != Self::Hidden
+    }
+}
+
+/// Gets the display mode the navigation toggle switches to
+///
+/// Without rail support the toggle only opens and closes the panel.
+fn next_navigation_display(current: NavigationDisplay, rail_enabled: bool) -> NavigationDisplay {
+    match (current, rail_enabled) {
+        (current, true) => current.next(),
+        (NavigationDisplay::Hidden, false) => NavigationDisplay::Full,
+        (_, false) => NavigationDisplay::Hidden,
+    }
+}
+
+/// Gets the ARIA label of the navigation toggle from the mode it switches to
+fn navigation_toggle_label(next: NavigationDisplay) -> &'static str {
+    match next {
+        NavigationDisplay::Full => "Open navigation",
+        NavigationDisplay::Rail => "Collapse navigation",
+        NavigationDisplay::Hidden => "Close navigation",
+    }
+}
+
 /// Event detail for navigation change events
 #[derive(Clone, PartialEq, Debug)]
 pub struct NavigationChangeDetail {
     /// The new open state of the navigation panel
+    ///
+    /// True in both the full and the rail display modes.
     pub open: bool,
+    /// The new display mode of the navigation panel
+    pub display: NavigationDisplay,
 }
 
 /// Event detail for tools panel change events
@@ -112,6 +377,128 @@ pub struct SplitPanelToggleDetail {
     pub open: bool,
 }
 
+/// A drawer of the tools area, opened by its trigger in the toolbar
+#[derive(Clone, PartialEq)]
+pub struct AppLayoutDrawer {
+    /// Unique identifier of the drawer
+    pub id: String,
+    /// Name of the icon shown on the trigger
+    pub trigger_icon: String,
+    /// ARIA label of the trigger
+    pub trigger_aria_label: String,
+    /// ARIA label of the drawer region
+    pub aria_label: String,
+    /// ARIA label of the button closing the drawer (default: "Close drawer")
+    pub close_aria_label: Option<String>,
+    /// Drawer content
+    pub content: Html,
+    /// Width of the drawer in pixels when it first opens (default: 290)
+    pub default_size: Option<u32>,
+    /// Whether the user can resize the drawer
+    pub resizable: bool,
+}
+
+impl AppLayoutDrawer {
+    /// Creates a drawer labelled `aria_label`, which also labels its trigger
+    pub fn new(
+        id: impl Into<String>,
+        trigger_icon: impl Into<String>,
+        aria_label: impl Into<String>,
+        content: Html,
+    ) -> Self {
+        let aria_label = aria_label.into();
+        Self {
+            id: id.into(),
+            trigger_icon: trigger_icon.into(),
+            trigger_aria_label: aria_label.clone(),
+            aria_label,
+            close_aria_label: None,
+            content,
+            default_size: None,
+            resizable: false,
+        }
+    }
+
+    /// Sets the ARIA label of the trigger
+    pub fn with_trigger_aria_label(mut self, label: impl Into<String>) -> Self {
+        self.trigger_aria_label = label.into();
+        self
+    }
+
+    /// Sets the ARIA label of the close button
+    pub fn with_close_aria_label(mut self, label: impl Into<String>) -> Self {
+        self.close_aria_label = Some(label.into());
+        self
+    }
+
+    /// Sets the width of the drawer when it first opens
+    pub fn with_default_size(mut self, size: u32) -> Self {
+        self.default_size = Some(size);
+        self
+    }
+
+    /// Sets whether the user can resize the drawer
+    pub fn with_resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+}
+
+/// Event detail for drawer change events
+#[derive(Clone, PartialEq, Debug)]
+pub struct DrawerChangeDetail {
+    /// Id of the open drawer, or `None` once all drawers are closed
+    pub active_drawer_id: Option<String>,
+}
+
+/// Event detail for drawer resize events
+#[derive(Clone, PartialEq, Debug)]
+pub struct DrawerResizeDetail {
+    /// Id of the resized drawer
+    pub id: String,
+    /// The new width of the drawer in pixels
+    pub width: u32,
+}
+
+/// Gets the drawers of the tools area, the `tools` slot first
+fn layout_drawers(props: &AppLayoutProps) -> Vec<AppLayoutDrawer> {
+    let tools = props
+        .tools
+        .as_ref()
+        .filter(|_| !props.tools_hide)
+        .map(|tools| {
+            AppLayoutDrawer::new(
+                TOOLS_DRAWER_ID,
+                "status-info",
+                props.aria_label_tools.clone(),
+                tools.clone(),
+            )
+            .with_resizable(true)
+        });
+    tools
+        .into_iter()
+        .chain(props.drawers.iter().cloned())
+        .collect()
+}
+
+/// Gets the drawer open after clicking the trigger of `clicked`: clicking
+/// the trigger of the open drawer closes it
+fn toggled_drawer(active: Option<&str>, clicked: &str) -> Option<String> {
+    (active != Some(clicked)).then(|| clicked.to_string())
+}
+
+/// Gets the id of the element scrolling the content of a drawer
+fn drawer_content_id(drawer_id: &str) -> String {
+    format!("awsui-app-layout-drawer-{}", drawer_id)
+}
+
+/// Event detail for navigation and tools panel resize events
+#[derive(Clone, PartialEq, Debug)]
+pub struct PanelResizeDetail {
+    /// The new width of the panel in pixels
+    pub width: u32,
+}
+
 /// Event detail for split panel resize events
 #[derive(Clone, PartialEq, Debug)]
 pub struct SplitPanelResizeDetail {
@@ -127,17 +514,39 @@ pub struct AppLayoutProps {
     pub base: BaseComponentProps,
 
     // Navigation panel properties
-    /// Whether the navigation panel is currently open (controlled)
+    /// Whether the navigation panel is currently open
+    ///
+    /// When set, the layout is controlled and only reports toggles through
+    /// `on_navigation_change`. When unset, the layout tracks the open state
+    /// itself, starting open.
     #[prop_or_default]
-    pub navigation_open: bool,
+    pub navigation_open: Option<bool>,
 
     /// Whether to completely hide the navigation panel
     #[prop_or(false)]
     pub navigation_hide: bool,
 
-    /// Width of the navigation panel in pixels (default: 280)
-    #[prop_or(280)]
-    pub navigation_width: u32,
+    /// Width of the navigation panel in pixels
+    ///
+    /// When set, the layout is controlled and only reports resizes through
+    /// `on_navigation_resize`. When unset, the layout tracks the width
+    /// itself, starting at 280.
+    #[prop_or_default]
+    pub navigation_width: Option<u32>,
+
+    /// Callback fired while the user resizes the navigation panel
+    #[prop_or_default]
+    pub on_navigation_resize: Option<Callback<CustomEvent<PanelResizeDetail>>>,
+
+    /// How the navigation panel is displayed, enabling the icon rail
+    ///
+    /// When set, the layout is controlled by this mode instead of
+    /// `navigation_open`, and the navigation toggle cycles Full, Rail and
+    /// Hidden, reporting the new mode through `on_navigation_change`. A
+    /// [`SideNavigation`](crate::SideNavigation) in the panel renders as an
+    /// icon rail in Rail mode.
+    #[prop_or_default]
+    pub navigation_display: Option<NavigationDisplay>,
 
     /// Callback fired when navigation open state changes
     #[prop_or_default]
@@ -148,26 +557,65 @@ pub struct AppLayoutProps {
     pub navigation: Option<Html>,
 
     // Tools panel properties
-    /// Whether the tools panel is currently open (controlled)
+    /// Whether the tools panel is currently open
+    ///
+    /// When set, the layout is controlled and only reports toggles through
+    /// `on_tools_change`. When unset, the layout tracks the open state itself,
+    /// starting closed.
     #[prop_or_default]
-    pub tools_open: bool,
+    pub tools_open: Option<bool>,
 
     /// Whether to completely hide the tools panel
     #[prop_or(false)]
     pub tools_hide: bool,
 
-    /// Width of the tools panel in pixels (default: 290)
-    #[prop_or(290)]
-    pub tools_width: u32,
+    /// Width of the tools panel in pixels
+    ///
+    /// When set, the layout is controlled and only reports resizes through
+    /// `on_tools_resize`. When unset, the layout tracks the width itself,
+    /// starting at 290.
+    #[prop_or_default]
+    pub tools_width: Option<u32>,
+
+    /// Callback fired while the user resizes the tools panel
+    #[prop_or_default]
+    pub on_tools_resize: Option<Callback<CustomEvent<PanelResizeDetail>>>,
 
     /// Callback fired when tools panel open state changes
     #[prop_or_default]
     pub on_tools_change: Option<Callback<CustomEvent<ToolsChangeDetail>>>,
 
     /// Tools panel content (typically help panel)
+    ///
+    /// With `drawers`, the tools panel is the first drawer, with the id
+    /// [`TOOLS_DRAWER_ID`].
     #[prop_or_default]
     pub tools: Option<Html>,
 
+    /// Drawers of the tools area, each opened by its trigger in the toolbar
+    #[prop_or_default]
+    pub drawers: Vec<AppLayoutDrawer>,
+
+    /// Id of the open drawer
+    ///
+    /// The layout opens and closes drawers itself, reporting the switches
+    /// through `on_drawer_change`, and follows this prop whenever it
+    /// changes.
+    #[prop_or_default]
+    pub active_drawer_id: Option<String>,
+
+    /// Callback fired when a drawer is opened or closed
+    #[prop_or_default]
+    pub on_drawer_change: Option<Callback<CustomEvent<DrawerChangeDetail>>>,
+
+    /// Callback fired while the user resizes a drawer
+    #[prop_or_default]
+    pub on_drawer_resize: Option<Callback<CustomEvent<DrawerResizeDetail>>>,
+
+    /// ARIA label of the drawer toolbar
+    #[prop_or("Drawers".to_string())]
+    pub aria_label_drawers: String,
+
     // Split panel properties
     /// Whether the split panel is currently open
     #[prop_or_default]
@@ -185,7 +633,16 @@ pub struct AppLayoutProps {
     #[prop_or_default]
     pub on_split_panel_resize: Option<Callback<CustomEvent<SplitPanelResizeDetail>>>,
 
-    /// Split panel content
+    /// Callback fired when the user changes the split panel preferences
+    #[prop_or_default]
+    pub on_split_panel_preferences_change: Option<Callback<CustomEvent<SplitPanelPreferences>>>,
+
+    /// Split panel content, typically a [`SplitPanel`](crate::SplitPanel)
+    ///
+    /// The SplitPanel renders the toggle, resize handle and preferences of
+    /// the panel. At the bottom, the open panel sticks to the bottom of the
+    /// viewport and takes its own space after the content, so the content
+    /// can always be scrolled clear of it.
     #[prop_or_default]
     pub split_panel: Option<Html>,
 
@@ -215,10 +672,21 @@ pub struct AppLayoutProps {
     #[prop_or_default]
     pub breadcrumbs: Option<Html>,
 
-    /// Notifications content (typically flashbar)
+    /// Notifications content (typically flashbar), shown above the
+    /// breadcrumbs
     #[prop_or_default]
     pub notifications: Option<Html>,
 
+    /// Whether the notifications stay pinned to the top of the viewport
+    /// when the user scrolls past them
+    #[prop_or_default]
+    pub sticky_notifications: bool,
+
+    /// Header of the page content (typically a Header), shown below the
+    /// breadcrumbs
+    #[prop_or_default]
+    pub content_header: Option<Html>,
+
     /// Main content area
     #[prop_or_default]
     pub children: Children,
@@ -246,7 +714,7 @@ pub struct AppLayoutProps {
 /// # Example
 ///
 /// ```rust
-/// use cloudscape_components::{AppLayout, NavigationChangeDetail, CustomEvent};
+/// use cloudscape_components::{AppLayout, CustomEvent, NavigationChangeDetail, ToolsChangeDetail};
 /// use yew::prelude::*;
 ///
 /// #[function_component(App)]
@@ -287,7 +755,7 @@ pub struct AppLayoutProps {
 /// # With Split Panel
 ///
 /// ```rust
-/// use cloudscape_components::{AppLayout, SplitPanelPreferences, SplitPanelPosition};
+/// use cloudscape_components::{AppLayout, SplitPanel, SplitPanelPreferences, SplitPanelPosition};
 /// use yew::prelude::*;
 ///
 /// #[function_component(AppWithSplitPanel)]
@@ -302,7 +770,9 @@ pub struct AppLayoutProps {
 ///         <AppLayout
 ///             split_panel_open={*split_panel_open}
 ///             split_panel_preferences={preferences}
-///             split_panel={html! { <div>{"Split panel content"}</div> }}
+///             split_panel={html! {
+///                 <SplitPanel header="Details">{"Split panel content"}</SplitPanel>
+///             }}
 ///         >
 ///             <div>{"Main content"}</div>
 ///         </AppLayout>
@@ -322,16 +792,176 @@ pub struct AppLayoutProps {
 pub fn app_layout(props: &AppLayoutProps) -> Html {
     let _metadata = ComponentMetadata::new("AppLayout");
 
+    // Internal open state, used when the corresponding prop is not controlled
+    let navigation_open_state = use_state(|| props.navigation_open.unwrap_or(true));
+    let tools_open_state = use_state(|| props.tools_open.unwrap_or(false));
+    let navigation_display = props.navigation_display.unwrap_or_else(|| {
+        if props.navigation_open.unwrap_or(*navigation_open_state) {
+            NavigationDisplay::Full
+        } else {
+            NavigationDisplay::Hidden
+        }
+    });
+    let navigation_open = navigation_display.is_open();
+    let navigation_rail = navigation_display == NavigationDisplay::Rail;
+    let next_display =
+        next_navigation_display(navigation_display, props.navigation_display.is_some());
+    let tools_open = props.tools_open.unwrap_or(*tools_open_state);
+
+    // Open drawer, following changes of `active_drawer_id`; the tools
+    // drawer also follows `tools_open`
+    let drawers = layout_drawers(props);
+    let active_drawer_state = use_state(|| {
+        props
+            .active_drawer_id
+            .clone()
+            .or_else(|| tools_open.then(|| TOOLS_DRAWER_ID.to_string()))
+    });
+    let last_drawer_props = use_mut_ref(|| (props.active_drawer_id.clone(), props.tools_open));
+    {
+        let active_drawer_state = active_drawer_state.clone();
+        let last_drawer_props = last_drawer_props.clone();
+        use_effect_with(
+            (props.active_drawer_id.clone(), props.tools_open),
+            move |(active_drawer_id, tools_open)| {
+                let mut last = last_drawer_props.borrow_mut();
+                if last.0 != *active_drawer_id {
+                    active_drawer_state.set(active_drawer_id.clone());
+                } else if last.1 != *tools_open
+                    && let Some(tools_open) = *tools_open
+                {
+                    let tools_active = active_drawer_state.as_deref() == Some(TOOLS_DRAWER_ID);
+                    if tools_open {
+                        active_drawer_state.set(Some(TOOLS_DRAWER_ID.to_string()));
+                    } else if tools_active {
+                        active_drawer_state.set(None);
+                    }
+                }
+                *last = (active_drawer_id.clone(), *tools_open);
+            },
+        );
+    }
+    let active_drawer = (*active_drawer_state)
+        .clone()
+        .filter(|id| drawers.iter().any(|drawer| drawer.id == *id));
+    let drawer_widths = use_state(HashMap::<String, u32>::new);
+    let drawer_scroll = use_mut_ref(HashMap::<String, i32>::new);
+
+    // Put a drawer's content back where the user left it
+    {
+        let drawer_scroll = drawer_scroll.clone();
+        use_effect_with(active_drawer.clone(), move |active_drawer| {
+            if let Some(id) = active_drawer
+                && let Some(top) = drawer_scroll.borrow().get(id)
+                && let Some(content) =
+                    gloo::utils::document().get_element_by_id(&drawer_content_id(id))
+            {
+                content.set_scroll_top(*top);
+            }
+        });
+    }
+
+    // Internal widths, used when the corresponding prop is not controlled
+    let navigation_width_state =
+        use_state(|| props.navigation_width.unwrap_or(DEFAULT_NAVIGATION_WIDTH));
+    let tools_width_state = use_state(|| props.tools_width.unwrap_or(DEFAULT_TOOLS_WIDTH));
+    let navigation_width = props.navigation_width.unwrap_or(*navigation_width_state);
+    let tools_width = props.tools_width.unwrap_or(*tools_width_state);
+    let drag = use_mut_ref(|| None::<HandleDrag>);
+
+    // Track whether the panels overlay the content
+    let overlay = use_state(is_overlay_viewport);
+    {
+        let overlay = overlay.clone();
+        use_effect_with((), move |_| {
+            let listener = web_sys::window().map(|window| {
+                EventListener::new(&window, "resize", move |_| {
+                    overlay.set(is_overlay_viewport());
+                })
+            });
+            move || drop(listener)
+        });
+    }
+    let overlay = *overlay;
+
+    // Track whether sticky notifications have content scrolled beneath them
+    let notifications_ref = use_node_ref();
+    let notifications_stuck = use_state(|| false);
+    {
+        let notifications_ref = notifications_ref.clone();
+        let notifications_stuck = notifications_stuck.clone();
+        use_effect_with(props.sticky_notifications, move |sticky| {
+            let listener = sticky.then(|| {
+                let update = move || {
+                    let stuck = notifications_ref.cast::<Element>().is_some_and(|element| {
+                        element.get_bounding_client_rect().top() <= 0.0
+                            && gloo::utils::window().scroll_y().unwrap_or(0.0) > 0.0
+                    });
+                    notifications_stuck.set(stuck);
+                };
+                update();
+                EventListener::new(&gloo::utils::window(), "scroll", move |_| update())
+            });
+            move || drop(listener)
+        });
+    }
+
+    // Keep sticky content clear of the header and sticky notifications
+    let header_ref = use_node_ref();
+    let header_size = use_element_size(header_ref.clone());
+    let notifications_size = use_element_size(notifications_ref.clone());
+    let sticky_offset = StickyOffset::new(
+        header_size.map_or(0.0, |(_, height)| height),
+        notifications_size
+            .filter(|_| props.sticky_notifications)
+            .map(|(_, height)| height),
+    );
+
+    let on_navigation_resize = {
+        let on_navigation_resize = props.on_navigation_resize.clone();
+        let navigation_width_state = navigation_width_state.clone();
+        let controlled = props.navigation_width.is_some();
+
+        Callback::from(move |width: u32| {
+            if !controlled {
+                navigation_width_state.set(width);
+            }
+            if let Some(callback) = &on_navigation_resize {
+                callback.emit(CustomEvent::new_non_cancelable(PanelResizeDetail { width }));
+            }
+        })
+    };
+
+    let on_tools_resize = {
+        let on_tools_resize = props.on_tools_resize.clone();
+        let tools_width_state = tools_width_state.clone();
+        let controlled = props.tools_width.is_some();
+
+        Callback::from(move |width: u32| {
+            if !controlled {
+                tools_width_state.set(width);
+            }
+            if let Some(callback) = &on_tools_resize {
+                callback.emit(CustomEvent::new_non_cancelable(PanelResizeDetail { width }));
+            }
+        })
+    };
+
     // Handle navigation toggle
     let on_navigation_toggle = {
         let on_navigation_change = props.on_navigation_change.clone();
-        let current_state = props.navigation_open;
+        let navigation_open_state = navigation_open_state.clone();
+        let controlled = props.navigation_open.is_some() || props.navigation_display.is_some();
 
         Callback::from(move |e: MouseEvent| {
             e.prevent_default();
+            if !controlled {
+                navigation_open_state.set(next_display.is_open());
+            }
             if let Some(callback) = &on_navigation_change {
                 callback.emit(CustomEvent::new_non_cancelable(NavigationChangeDetail {
-                    open: !current_state,
+                    open: next_display.is_open(),
+                    display: next_display,
                 }));
             }
         })
@@ -340,10 +970,15 @@ pub fn app_layout(props: &AppLayoutProps) -> Html {
     // Handle tools toggle
     let on_tools_toggle = {
         let on_tools_change = props.on_tools_change.clone();
-        let current_state = props.tools_open;
+        let tools_open_state = tools_open_state.clone();
+        let controlled = props.tools_open.is_some();
+        let current_state = tools_open;
 
         Callback::from(move |e: MouseEvent| {
             e.prevent_default();
+            if !controlled {
+                tools_open_state.set(!current_state);
+            }
             if let Some(callback) = &on_tools_change {
                 callback.emit(CustomEvent::new_non_cancelable(ToolsChangeDetail {
                     open: !current_state,
@@ -352,13 +987,54 @@ pub fn app_layout(props: &AppLayoutProps) -> Html {
         })
     };
 
+    // Handle drawer switches: the content of the drawer being left keeps its
+    // scroll position for when it opens again
+    let on_drawer_change = {
+        let on_drawer_change = props.on_drawer_change.clone();
+        let on_tools_change = props.on_tools_change.clone();
+        let active_drawer_state = active_drawer_state.clone();
+        let tools_open_state = tools_open_state.clone();
+        let tools_controlled = props.tools_open.is_some();
+        let drawer_scroll = drawer_scroll.clone();
+        let active_drawer = active_drawer.clone();
+
+        Callback::from(move |next: Option<String>| {
+            if let Some(id) = &active_drawer
+                && let Some(content) =
+                    gloo::utils::document().get_element_by_id(&drawer_content_id(id))
+            {
+                drawer_scroll
+                    .borrow_mut()
+                    .insert(id.clone(), content.scroll_top());
+            }
+            active_drawer_state.set(next.clone());
+
+            let tools_was_open = active_drawer.as_deref() == Some(TOOLS_DRAWER_ID);
+            let tools_open = next.as_deref() == Some(TOOLS_DRAWER_ID);
+            if tools_was_open != tools_open {
+                if !tools_controlled {
+                    tools_open_state.set(tools_open);
+                }
+                if let Some(callback) = &on_tools_change {
+                    callback.emit(CustomEvent::new_non_cancelable(ToolsChangeDetail {
+                        open: tools_open,
+                    }));
+                }
+            }
+            if let Some(callback) = &on_drawer_change {
+                callback.emit(CustomEvent::new_non_cancelable(DrawerChangeDetail {
+                    active_drawer_id: next,
+                }));
+            }
+        })
+    };
+
     // Handle split panel toggle
     let on_split_toggle = {
         let on_split_panel_toggle = props.on_split_panel_toggle.clone();
         let current_state = props.split_panel_open;
 
-        Callback::from(move |e: MouseEvent| {
-            e.prevent_default();
+        Callback::from(move |_: ()| {
             if let Some(callback) = &on_split_panel_toggle {
                 callback.emit(CustomEvent::new_non_cancelable(SplitPanelToggleDetail {
                     open: !current_state,
@@ -367,6 +1043,36 @@ pub fn app_layout(props: &AppLayoutProps) -> Html {
         })
     };
 
+    let split_panel_size = props
+        .split_panel_preferences
+        .size
+        .unwrap_or_else(|| default_split_panel_size(props.split_panel_preferences.position));
+
+    let split_panel_context = SplitPanelContext {
+        open: props.split_panel_open,
+        position: props.split_panel_preferences.position,
+        size: split_panel_size,
+        on_toggle: on_split_toggle,
+        on_resize: {
+            let on_split_panel_resize = props.on_split_panel_resize.clone();
+            Callback::from(move |size: u32| {
+                if let Some(callback) = &on_split_panel_resize {
+                    callback.emit(CustomEvent::new_non_cancelable(SplitPanelResizeDetail {
+                        size,
+                    }));
+                }
+            })
+        },
+        on_preferences_change: {
+            let on_preferences_change = props.on_split_panel_preferences_change.clone();
+            Callback::from(move |preferences: SplitPanelPreferences| {
+                if let Some(callback) = &on_preferences_change {
+                    callback.emit(CustomEvent::new_non_cancelable(preferences));
+                }
+            })
+        },
+    };
+
     // Build CSS classes for root element
     let root_classes = ClassBuilder::new()
         .add("awsui-app-layout")
@@ -374,11 +1080,14 @@ pub fn app_layout(props: &AppLayoutProps) -> Html {
             "awsui-app-layout-content-type-{}",
             props.content_type.as_str()
         ))
-        .add_if(props.navigation_open, "awsui-app-layout-navigation-open")
-        .add_if(props.tools_open, "awsui-app-layout-tools-open")
+        .add_if(navigation_open, "awsui-app-layout-navigation-open")
+        .add_if(navigation_rail, "awsui-app-layout-navigation-rail")
+        .add_if(tools_open, "awsui-app-layout-tools-open")
+        .add_if(active_drawer.is_some(), "awsui-app-layout-drawer-open")
         .add_if(props.split_panel_open, "awsui-app-layout-split-panel-open")
         .add_if(props.navigation_hide, "awsui-app-layout-navigation-hidden")
         .add_if(props.tools_hide, "awsui-app-layout-tools-hidden")
+        .add_if(overlay, "awsui-app-layout-overlay")
         .add_if(
             props.split_panel.is_some(),
             format!(
@@ -392,14 +1101,24 @@ pub fn app_layout(props: &AppLayoutProps) -> Html {
     // Build CSS classes for navigation
     let navigation_classes = ClassBuilder::new()
         .add("awsui-app-layout-navigation")
-        .add_if(props.navigation_open, "awsui-app-layout-navigation-open")
-        .add_if(!props.navigation_open, "awsui-app-layout-navigation-closed");
+        .add_if(navigation_open, "awsui-app-layout-navigation-open")
+        .add_if(navigation_rail, "awsui-app-layout-navigation-rail")
+        .add_if(!navigation_open, "awsui-app-layout-navigation-closed");
 
     // Build CSS classes for tools panel
     let tools_classes = ClassBuilder::new()
         .add("awsui-app-layout-tools")
-        .add_if(props.tools_open, "awsui-app-layout-tools-open")
-        .add_if(!props.tools_open, "awsui-app-layout-tools-closed");
+        .add_if(tools_open, "awsui-app-layout-tools-open")
+        .add_if(!tools_open, "awsui-app-layout-tools-closed");
+
+    // Build CSS classes for notifications
+    let notifications_classes = ClassBuilder::new()
+        .add("awsui-app-layout-notifications")
+        .add_if(
+            props.sticky_notifications,
+            "awsui-app-layout-notifications-sticky",
+        )
+        .add_if(*notifications_stuck, "awsui-app-layout-notifications-stuck");
 
     // Build CSS classes for main content
     let main_classes = ClassBuilder::new().add("awsui-app-layout-main").add_if(
@@ -435,24 +1154,171 @@ pub fn app_layout(props: &AppLayoutProps) -> Html {
             .build()
     };
 
-    // Build inline styles for navigation width
-    let navigation_style = if props.navigation_open && !props.navigation_hide {
-        Some(format!("width: {}px;", props.navigation_width))
-    } else {
-        None
+    // Build inline styles for navigation width; overlaid panels cover the
+    // content instead of pushing it aside
+    let panel_style = |width: u32, side: &str| {
+        if overlay {
+            format!(
+                "position: fixed; top: 0; bottom: 0; {}: 0; z-index: 1000; width: min({}px, 100vw);",
+                side, width
+            )
+        } else {
+            format!("width: {}px;", width)
+        }
+    };
+    let navigation_style = match navigation_display {
+        _ if props.navigation_hide => None,
+        NavigationDisplay::Full => Some(panel_style(navigation_width, "left")),
+        NavigationDisplay::Rail => Some(format!("width: {}px;", NAVIGATION_RAIL_WIDTH)),
+        NavigationDisplay::Hidden => None,
     };
 
     // Build inline styles for tools width
-    let tools_style = if props.tools_open && !props.tools_hide {
-        Some(format!("width: {}px;", props.tools_width))
+    let tools_style = if tools_open && !props.tools_hide {
+        Some(panel_style(tools_width, "right"))
     } else {
         None
     };
 
+    // Overlaid panels and the rail can't be resized
+    let navigation_resizable = navigation_display == NavigationDisplay::Full && !overlay;
+    let tools_resizable = tools_open && !overlay;
+
+    // Render the drawers of the tools area; every drawer stays mounted so
+    // its content keeps its state while another drawer is open
+    let render_drawers = || {
+        let triggers = drawers.iter().map(|drawer| {
+            let selected = active_drawer.as_deref() == Some(drawer.id.as_str());
+            let classes = ClassBuilder::new()
+                .add("awsui-app-layout-drawer-trigger")
+                .add_if(selected, "awsui-app-layout-drawer-trigger-selected");
+            let onclick = {
+                let on_drawer_change = on_drawer_change.clone();
+                let next = toggled_drawer(active_drawer.as_deref(), &drawer.id);
+                Callback::from(move |e: MouseEvent| {
+                    e.prevent_default();
+                    on_drawer_change.emit(next.clone());
+                })
+            };
+
+            html! {
+                <button
+                    key={drawer.id.clone()}
+                    type="button"
+                    class={classes.build()}
+                    aria-label={drawer.trigger_aria_label.clone()}
+                    aria-pressed={selected.to_string()}
+                    onclick={onclick}
+                >
+                    <Icon name={drawer.trigger_icon.clone()} />
+                </button>
+            }
+        });
+
+        let panels = drawers.iter().map(|drawer| {
+            let selected = active_drawer.as_deref() == Some(drawer.id.as_str());
+            let is_tools = drawer.id == TOOLS_DRAWER_ID;
+            let width = if is_tools {
+                tools_width
+            } else {
+                drawer_widths
+                    .get(&drawer.id)
+                    .copied()
+                    .or(drawer.default_size)
+                    .unwrap_or(DEFAULT_TOOLS_WIDTH)
+            };
+            let on_resize = if is_tools {
+                on_tools_resize.clone()
+            } else {
+                let drawer_widths = drawer_widths.clone();
+                let on_drawer_resize = props.on_drawer_resize.clone();
+                let id = drawer.id.clone();
+                Callback::from(move |width: u32| {
+                    let mut widths = (*drawer_widths).clone();
+                    widths.insert(id.clone(), width);
+                    drawer_widths.set(widths);
+                    if let Some(callback) = &on_drawer_resize {
+                        callback.emit(CustomEvent::new_non_cancelable(DrawerResizeDetail {
+                            id: id.clone(),
+                            width,
+                        }));
+                    }
+                })
+            };
+            let on_close = {
+                let on_drawer_change = on_drawer_change.clone();
+                Callback::from(move |e: MouseEvent| {
+                    e.prevent_default();
+                    on_drawer_change.emit(None);
+                })
+            };
+            let classes = ClassBuilder::new()
+                .add("awsui-app-layout-drawer")
+                .add_if(selected, "awsui-app-layout-drawer-open");
+
+            html! {
+                <div
+                    key={drawer.id.clone()}
+                    class={classes.build()}
+                    style={selected.then(|| panel_style(width, "right"))}
+                    role="region"
+                    aria-label={drawer.aria_label.clone()}
+                    hidden={!selected}
+                >
+                    if selected && drawer.resizable && !overlay {
+                        { render_resize_handle(
+                            ResizablePanel::Tools,
+                            width,
+                            &format!("Resize {}", drawer.aria_label),
+                            &drag,
+                            &on_resize,
+                        ) }
+                    }
+                    <button
+                        type="button"
+                        class="awsui-app-layout-drawer-close"
+                        aria-label={drawer
+                            .close_aria_label
+                            .clone()
+                            .unwrap_or_else(|| "Close drawer".to_string())}
+                        onclick={on_close}
+                    >
+                        <Icon name="close" />
+                    </button>
+                    <div id={drawer_content_id(&drawer.id)} class="awsui-app-layout-drawer-content">
+                        { drawer.content.clone() }
+                    </div>
+                </div>
+            }
+        });
+
+        html! {
+            <aside class="awsui-app-layout-drawers">
+                { for panels }
+                <div
+                    class="awsui-app-layout-drawers-toolbar"
+                    role="toolbar"
+                    aria-orientation="vertical"
+                    aria-label={props.aria_label_drawers.clone()}
+                >
+                    { for triggers }
+                </div>
+            </aside>
+        }
+    };
+
     // Build inline styles for content area
     let content_style = {
         let mut styles = Vec::new();
 
+        if !props.disable_content_paddings {
+            styles.push(props.content_type.content_padding().to_string());
+        }
+
+        if props.content_header.is_some() {
+            styles.push(CONTENT_HEADER_OVERLAP_STYLE.to_string());
+        }
+
         if let Some(ref max_width) = props.max_content_width {
             styles.push(format!("max-width: {}", max_width));
         }
@@ -468,19 +1334,25 @@ pub fn app_layout(props: &AppLayoutProps) -> Html {
         }
     };
 
-    // Build inline styles for split panel
-    let split_panel_style = if props.split_panel_open {
-        props.split_panel_preferences.size.map(|size| {
-            match props.split_panel_preferences.position {
-                SplitPanelPosition::Bottom => format!("height: {}px;", size),
-                SplitPanelPosition::Side => format!("width: {}px;", size),
-            }
-        })
-    } else {
-        None
+    // Build inline styles for split panel; an open bottom panel sticks to
+    // the bottom of the viewport
+    let split_panel_style = match props.split_panel_preferences.position {
+        SplitPanelPosition::Bottom if props.split_panel_open => Some(format!(
+            "position: sticky; bottom: 0; z-index: 800; height: {}px;",
+            split_panel_size
+        )),
+        SplitPanelPosition::Side if props.split_panel_open => {
+            Some(format!("width: {}px;", split_panel_size))
+        }
+        _ => None,
+    };
+    let split_panel = html! {
+        <ContextProvider<SplitPanelContext> context={split_panel_context}>
+            { props.split_panel.clone() }
+        </ContextProvider<SplitPanelContext>>
     };
 
-    html! {
+    let root = html! {
         <div
             id={props.base.id.clone()}
             class={root_class}
@@ -492,7 +1364,7 @@ pub fn app_layout(props: &AppLayoutProps) -> Html {
 
             // Optional header banner
             if let Some(ref header) = props.header {
-                <header class="awsui-app-layout-header">
+                <header ref={header_ref} class="awsui-app-layout-header">
                     { header.clone() }
                 </header>
             }
@@ -508,23 +1380,46 @@ pub fn app_layout(props: &AppLayoutProps) -> Html {
                     >
                         <div class="awsui-app-layout-navigation-content">
                             if let Some(ref navigation) = props.navigation {
-                                { navigation.clone() }
+                                <ContextProvider<NavigationDisplay> context={navigation_display}>
+                                    { navigation.clone() }
+                                </ContextProvider<NavigationDisplay>>
                             }
                         </div>
 
                         // Navigation toggle button
                         <button
                             class="awsui-app-layout-navigation-toggle"
-                            aria-label={if props.navigation_open { "Close navigation" } else { "Open navigation" }}
+                            aria-label={navigation_toggle_label(next_display)}
                             onclick={on_navigation_toggle}
                         >
                             <span class="awsui-app-layout-toggle-icon" />
                         </button>
+
+                        if navigation_resizable {
+                            { render_resize_handle(
+                                ResizablePanel::Navigation,
+                                navigation_width,
+                                "Resize navigation panel",
+                                &drag,
+                                &on_navigation_resize,
+                            ) }
+                        }
                     </nav>
                 }
 
                 // Main content wrapper
                 <div class="awsui-app-layout-main-wrapper">
+                    // Notifications
+                    if let Some(ref notifications) = props.notifications {
+                        <div
+                            ref={notifications_ref}
+                            class={notifications_classes.build()}
+                            style={notifications_style(props.sticky_notifications, *notifications_stuck)}
+                        >
+                            { notifications.clone() }
+                        </div>
+                    }
+
                     // Breadcrumbs
                     if let Some(ref breadcrumbs) = props.breadcrumbs {
                         <div class="awsui-app-layout-breadcrumbs">
@@ -532,21 +1427,23 @@ pub fn app_layout(props: &AppLayoutProps) -> Html {
                         </div>
                     }
 
-                    // Notifications
-                    if let Some(ref notifications) = props.notifications {
-                        <div class="awsui-app-layout-notifications">
-                            { notifications.clone() }
-                        </div>
-                    }
-
                     // Main content area
                     <main
                         id="awsui-app-layout-main-content"
                         class={main_classes.build()}
                         aria-label={props.aria_label_main.clone()}
                     >
+                        // Content header
+                        if let Some(ref content_header) = props.content_header {
+                            <div class="awsui-app-layout-content-header" style={CONTENT_HEADER_STYLE}>
+                                { content_header.clone() }
+                            </div>
+                        }
+
                         <div class={content_classes.build()} style={content_style}>
-                            { props.children.clone() }
+                            <ContextProvider<StickyOffset> context={sticky_offset}>
+                                { props.children.clone() }
+                            </ContextProvider<StickyOffset>>
                         </div>
 
                         // Split panel (when positioned at bottom)
@@ -556,21 +1453,7 @@ pub fn app_layout(props: &AppLayoutProps) -> Html {
                                 class={build_split_panel_classes()}
                                 style={split_panel_style.clone()}
                             >
-                                <div class="awsui-app-layout-split-panel-header">
-                                    <button
-                                        class="awsui-app-layout-split-panel-toggle"
-                                        aria-label={if props.split_panel_open { "Close panel" } else { "Open panel" }}
-                                        onclick={on_split_toggle.clone()}
-                                    >
-                                        <span class="awsui-app-layout-toggle-icon" />
-                                    </button>
-                                </div>
-
-                                if props.split_panel_open {
-                                    <div class="awsui-app-layout-split-panel-content">
-                                        { props.split_panel.clone() }
-                                    </div>
-                                }
+                                { split_panel.clone() }
                             </div>
                         }
                     </main>
@@ -583,31 +1466,29 @@ pub fn app_layout(props: &AppLayoutProps) -> Html {
                         class={build_split_panel_classes()}
                         style={split_panel_style}
                     >
-                        <div class="awsui-app-layout-split-panel-header">
-                            <button
-                                class="awsui-app-layout-split-panel-toggle"
-                                aria-label={if props.split_panel_open { "Close panel" } else { "Open panel" }}
-                                onclick={on_split_toggle}
-                            >
-                                <span class="awsui-app-layout-toggle-icon" />
-                            </button>
-                        </div>
-
-                        if props.split_panel_open {
-                            <div class="awsui-app-layout-split-panel-content">
-                                { props.split_panel.clone() }
-                            </div>
-                        }
+                        { split_panel }
                     </div>
                 }
 
-                // Tools panel
-                if !props.tools_hide {
+                // Drawers, with the tools panel among them
+                if !props.drawers.is_empty() {
+                    { render_drawers() }
+                } else if !props.tools_hide {
                     <aside
                         class={tools_classes.build()}
                         style={tools_style}
                         aria-label={props.aria_label_tools.clone()}
                     >
+                        if tools_resizable {
+                            { render_resize_handle(
+                                ResizablePanel::Tools,
+                                tools_width,
+                                "Resize tools panel",
+                                &drag,
+                                &on_tools_resize,
+                            ) }
+                        }
+
                         <div class="awsui-app-layout-tools-content">
                             if let Some(ref tools) = props.tools {
                                 { tools.clone() }
@@ -617,7 +1498,7 @@ pub fn app_layout(props: &AppLayoutProps) -> Html {
                         // Tools toggle button
                         <button
                             class="awsui-app-layout-tools-toggle"
-                            aria-label={if props.tools_open { "Close tools" } else { "Open tools" }}
+                            aria-label={if tools_open { "Close tools" } else { "Open tools" }}
                             onclick={on_tools_toggle}
                         >
                             <span class="awsui-app-layout-toggle-icon" />
@@ -626,7 +1507,8 @@ pub fn app_layout(props: &AppLayoutProps) -> Html {
                 }
             </div>
         </div>
-    }
+    };
+    apply_root_attributes(root, &props.base)
 }
 
 #[cfg(test)]
@@ -638,6 +1520,37 @@ mod tests {
         assert_eq!(ContentType::default(), ContentType::Default);
     }
 
+    #[test]
+    fn test_content_type_padding() {
+        assert!(
+            ContentType::Table
+                .content_padding()
+                .starts_with("padding: var(--awsui-space-scaled-s)")
+        );
+        assert!(
+            ContentType::Form
+                .content_padding()
+                .ends_with("var(--awsui-space-scaled-xxxl)")
+        );
+        assert_eq!(
+            ContentType::Default.content_padding(),
+            ContentType::Dashboard.content_padding()
+        );
+    }
+
+    #[test]
+    fn test_notifications_style() {
+        assert_eq!(notifications_style(false, true), None);
+        let pinned = notifications_style(true, false).unwrap();
+        assert!(pinned.contains("position: sticky"));
+        assert!(!pinned.contains("box-shadow"));
+        assert!(
+            notifications_style(true, true)
+                .unwrap()
+                .ends_with("box-shadow: var(--awsui-shadow-flash-sticky)")
+        );
+    }
+
     #[test]
     fn test_content_type_as_str() {
         assert_eq!(ContentType::Default.as_str(), "default");
@@ -678,13 +1591,53 @@ mod tests {
 
     #[test]
     fn test_navigation_change_detail() {
-        let detail = NavigationChangeDetail { open: true };
+        let detail = NavigationChangeDetail {
+            open: true,
+            display: NavigationDisplay::Full,
+        };
         assert!(detail.open);
 
-        let detail = NavigationChangeDetail { open: false };
+        let detail = NavigationChangeDetail {
+            open: false,
+            display: NavigationDisplay::Hidden,
+        };
         assert!(!detail.open);
     }
 
+    #[test]
+    fn test_navigation_display_cycle() {
+        assert_eq!(NavigationDisplay::Full.next(), NavigationDisplay::Rail);
+        assert_eq!(NavigationDisplay::Rail.next(), NavigationDisplay::Hidden);
+        assert_eq!(NavigationDisplay::Hidden.next(), NavigationDisplay::Full);
+        assert!(NavigationDisplay::Rail.is_open());
+        assert!(!NavigationDisplay::Hidden.is_open());
+        assert_eq!(NavigationDisplay::Rail.as_str(), "rail");
+    }
+
+    #[test]
+    fn test_next_navigation_display_without_rail() {
+        assert_eq!(
+            next_navigation_display(NavigationDisplay::Full, false),
+            NavigationDisplay::Hidden
+        );
+        assert_eq!(
+            next_navigation_display(NavigationDisplay::Hidden, false),
+            NavigationDisplay::Full
+        );
+        assert_eq!(
+            next_navigation_display(NavigationDisplay::Full, true),
+            NavigationDisplay::Rail
+        );
+        assert_eq!(
+            navigation_toggle_label(NavigationDisplay::Rail),
+            "Collapse navigation"
+        );
+        assert_eq!(
+            navigation_toggle_label(NavigationDisplay::Hidden),
+            "Close navigation"
+        );
+    }
+
     #[test]
     fn test_tools_change_detail() {
         let detail = ToolsChangeDetail { open: true };
@@ -714,7 +1667,10 @@ mod tests {
 
     #[test]
     fn test_navigation_change_detail_clone() {
-        let detail = NavigationChangeDetail { open: true };
+        let detail = NavigationChangeDetail {
+            open: true,
+            display: NavigationDisplay::Full,
+        };
         let cloned = detail.clone();
         assert_eq!(detail, cloned);
     }
@@ -733,10 +1689,71 @@ mod tests {
         assert_eq!(detail, cloned);
     }
 
+    #[test]
+    fn test_panel_dragged_width() {
+        // Dragging to the right grows the navigation and shrinks the tools
+        assert_eq!(ResizablePanel::Navigation.dragged_width(280, 40.0), 320);
+        assert_eq!(ResizablePanel::Tools.dragged_width(290, 40.0), 250);
+        assert_eq!(ResizablePanel::Tools.dragged_width(290, -40.4), 330);
+
+        // Widths stay within the limits
+        assert_eq!(ResizablePanel::Navigation.dragged_width(280, -500.0), 200);
+        assert_eq!(ResizablePanel::Navigation.dragged_width(280, 900.0), 600);
+        assert_eq!(ResizablePanel::Tools.dragged_width(290, 100.0), 240);
+    }
+
+    #[test]
+    fn test_panel_keyboard_width() {
+        let navigation = ResizablePanel::Navigation;
+        assert_eq!(navigation.keyboard_width(280, "ArrowRight"), Some(290));
+        assert_eq!(navigation.keyboard_width(280, "ArrowLeft"), Some(270));
+        assert_eq!(navigation.keyboard_width(205, "ArrowLeft"), Some(200));
+        assert_eq!(navigation.keyboard_width(280, "Home"), Some(200));
+        assert_eq!(navigation.keyboard_width(280, "End"), Some(600));
+        assert_eq!(navigation.keyboard_width(280, "Enter"), None);
+
+        let tools = ResizablePanel::Tools;
+        assert_eq!(tools.keyboard_width(290, "ArrowLeft"), Some(300));
+        assert_eq!(tools.keyboard_width(290, "ArrowRight"), Some(280));
+        assert_eq!(tools.keyboard_width(600, "ArrowLeft"), Some(600));
+    }
+
     #[test]
     fn test_split_panel_resize_detail_clone() {
         let detail = SplitPanelResizeDetail { size: 300 };
         let cloned = detail.clone();
         assert_eq!(detail, cloned);
     }
+
+    #[test]
+    fn test_sticky_offset() {
+        assert_eq!(StickyOffset::new(0.0, None).top, 0);
+        assert_eq!(StickyOffset::new(56.4, None).top, 56);
+        assert_eq!(StickyOffset::new(56.0, Some(48.0)).top, 104);
+    }
+
+    #[test]
+    fn test_toggled_drawer() {
+        assert_eq!(toggled_drawer(None, "links"), Some("links".to_string()));
+        assert_eq!(
+            toggled_drawer(Some(TOOLS_DRAWER_ID), "links"),
+            Some("links".to_string())
+        );
+        // The trigger of the open drawer closes it
+        assert_eq!(toggled_drawer(Some("links"), "links"), None);
+    }
+
+    #[test]
+    fn test_drawer_builder() {
+        let drawer = AppLayoutDrawer::new("links", "external", "Links", html! {})
+            .with_default_size(360)
+            .with_resizable(true);
+        assert_eq!(drawer.trigger_aria_label, "Links");
+        assert_eq!(drawer.default_size, Some(360));
+        assert!(drawer.resizable);
+        assert_eq!(
+            drawer_content_id(&drawer.id),
+            "awsui-app-layout-drawer-links"
+        );
+    }
 }
@@ -6,10 +6,23 @@
 //! An input component that provides suggestions as the user types, with support
 //! for filtering, keyboard navigation, and custom "Use entered text" option.
 
+use crate::form_field::{use_field_association, use_validation_state};
+use crate::i18n::use_i18n;
+use crate::input::{TrailingActionContext, render_trailing_action};
+use crate::internal::busy::use_register_busy;
+use crate::internal::dropdown::{use_dropdown_position, use_outside_dismiss};
+use crate::internal::menu::next_menu_entry;
+use crate::internal::option_group::option_id;
+use crate::internal::option_list::{
+    DropdownStatus, ListState, ListStateSlots, ListStateStrings, LoadItemsDetail,
+    list_announcement, load_status_visible, loaded_list_state, option_announcement,
+    render_list_state, render_load_status, use_load_items,
+};
 use crate::internal::{
-    AriaAttributes, BaseComponentProps, ClassBuilder, ComponentMetadata, CustomEvent,
+    AriaAttributes, BaseComponentProps, ClassBuilder, ComponentMetadata, CustomEvent, LiveRegion,
+    apply_root_attributes,
 };
-use gloo_timers::callback::Timeout;
+use std::ops::Range;
 use web_sys::HtmlInputElement;
 use yew::prelude::*;
 
@@ -101,6 +114,61 @@ impl AutosuggestOption {
     }
 }
 
+/// Background of the parts of option texts matching the entered text
+const MATCH_HIGHLIGHT_STYLE: &str =
+    "background-color: var(--awsui-color-background-dropdown-item-filter-match)";
+
+/// Gets the byte ranges of `text` matching `filter`, ignoring case
+///
+/// Matches don't overlap; each search continues after the previous match.
+fn match_ranges(text: &str, filter: &str) -> Vec<Range<usize>> {
+    let needle: Vec<String> = filter.chars().map(|c| c.to_lowercase().collect()).collect();
+    if needle.is_empty() {
+        return Vec::new();
+    }
+    let chars: Vec<(usize, String)> = text
+        .char_indices()
+        .map(|(index, c)| (index, c.to_lowercase().collect()))
+        .collect();
+
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    while start + needle.len() <= chars.len() {
+        let candidate = &chars[start..start + needle.len()];
+        if candidate.iter().zip(&needle).all(|((_, c), n)| c == n) {
+            let end = chars
+                .get(start + needle.len())
+                .map_or(text.len(), |(index, _)| *index);
+            ranges.push(chars[start].0..end);
+            start += needle.len();
+        } else {
+            start += 1;
+        }
+    }
+    ranges
+}
+
+/// Renders an option text with the parts matching `filter` highlighted
+fn render_match_highlight(text: &str, filter: &str) -> Html {
+    let mut html = Vec::new();
+    let mut position = 0;
+    for range in match_ranges(text, filter) {
+        if range.start > position {
+            html.push(html! { { &text[position..range.start] } });
+        }
+        html.push(html! {
+            <span class="awsui-autosuggest-option-match" style={MATCH_HIGHLIGHT_STYLE}>
+                { &text[range.clone()] }
+            </span>
+        });
+        position = range.end;
+    }
+    if position < text.len() {
+        html.push(html! { { &text[position..] } });
+    }
+    html.into_iter().collect()
+}
+
 /// Event detail for autosuggest change events
 #[derive(Clone, PartialEq)]
 pub struct AutosuggestChangeDetail {
@@ -148,14 +216,31 @@ pub struct AutosuggestProps {
     #[prop_or_default]
     pub warning: bool,
 
-    /// Label for the "Use entered text" option (default: "Use:")
+    /// Gets the label of the first row, which selects the entered text
+    /// itself, from the entered text
+    ///
+    /// Defaults to `Use: "<text>"`.
     #[prop_or_default]
-    pub entered_text_label: Option<String>,
+    pub entered_text_label: Option<Callback<String, String>>,
 
     /// Filtering type for options
     #[prop_or_default]
     pub filtering_type: FilteringType,
 
+    /// Content shown when the entered text matches none of the options
+    ///
+    /// Defaults to "No matches for '<text>'" with a "Clear filter" action,
+    /// localized with the `autosuggest.no_match_text` and
+    /// `autosuggest.clear_filter_label` I18nProvider messages.
+    #[prop_or_default]
+    pub no_match_content: Option<Html>,
+
+    /// Content shown when there are no options at all
+    ///
+    /// Defaults to "No options" (`autosuggest.empty_text`).
+    #[prop_or_default]
+    pub empty_content: Option<Html>,
+
     /// Callback fired when the input value changes
     #[prop_or_default]
     pub on_change: Option<Callback<CustomEvent<AutosuggestChangeDetail>>>,
@@ -191,6 +276,29 @@ pub struct AutosuggestProps {
     /// Auto-focus on mount
     #[prop_or_default]
     pub auto_focus: bool,
+
+    /// Action rendered inside the field after the value, such as a
+    /// microphone button
+    ///
+    /// The content can read the field state with
+    /// [`use_trailing_action_context`](crate::input::use_trailing_action_context).
+    #[prop_or_default]
+    pub trailing_action: Option<Html>,
+
+    /// Loading state of options fetched with `on_load_items`
+    ///
+    /// Shown in a footer below the options: "Loading"
+    /// (`autosuggest.loading_text`), an error (`autosuggest.error_text`)
+    /// with a retry action (`autosuggest.recovery_text`), or the end of
+    /// results (`autosuggest.finished_text`).
+    #[prop_or_default]
+    pub loading_status: Option<DropdownStatus>,
+
+    /// Callback fired to load options: when the dropdown opens, shortly
+    /// after the value changes, and when the list is scrolled near its end
+    /// while `loading_status` is pending
+    #[prop_or_default]
+    pub on_load_items: Option<Callback<CustomEvent<LoadItemsDetail>>>,
 }
 
 /// Autosuggest component for text input with dropdown suggestions.
@@ -248,9 +356,30 @@ pub struct AutosuggestProps {
 #[function_component(Autosuggest)]
 pub fn autosuggest(props: &AutosuggestProps) -> Html {
     let _metadata = ComponentMetadata::new("Autosuggest");
+    use_register_busy(props.loading_status == Some(DropdownStatus::Loading));
+    let i18n = use_i18n();
+    let state_strings = ListStateStrings::resolve(&i18n, "autosuggest");
     let input_ref = use_node_ref();
+    let root_ref = use_node_ref();
+    let dropdown_ref = use_node_ref();
+    let list_id = use_state(|| {
+        use std::sync::atomic::{AtomicU32, Ordering};
+        static COUNTER: AtomicU32 = AtomicU32::new(0);
+        let id = COUNTER.fetch_add(1, Ordering::Relaxed);
+        format!("awsui-autosuggest-{}", id)
+    });
+    let validation = use_validation_state(props.invalid, props.warning);
+
+    // Id and ARIA references, merged with the enclosing FormField
+    let association = use_field_association(
+        props.control_id.clone(),
+        props.aria.labelledby.clone(),
+        props.aria.describedby.clone(),
+    );
+
     let is_open = use_state(|| false);
-    let highlighted_index = use_state(|| 0usize);
+    // No row is highlighted until the user moves through the list
+    let highlighted_index = use_state(|| None::<usize>);
 
     // Filter options based on current value
     let filtered_options = use_memo(
@@ -279,6 +408,42 @@ pub fn autosuggest(props: &AutosuggestProps) -> Html {
     // Calculate total dropdown items (including "Use entered text" if shown)
     let total_items = filtered_options.len() + if show_entered_text { 1 } else { 0 };
 
+    // Explain an empty list; the state row is not an item, so keyboard
+    // navigation skips it
+    let list_state = loaded_list_state(
+        ListState::of(
+            filtered_options.len(),
+            if props.filtering_type == FilteringType::Auto {
+                &props.value
+            } else {
+                ""
+            },
+        ),
+        props.loading_status,
+    );
+    let status_visible = load_status_visible(props.loading_status, !filtered_options.is_empty());
+    let dropdown_visible = *is_open && (total_items > 0 || list_state.is_some() || status_visible);
+
+    // Close on outside interaction and keep the dropdown anchored to the input
+    use_outside_dismiss(*is_open, root_ref.clone(), {
+        let is_open = is_open.clone();
+        Callback::from(move |()| is_open.set(false))
+    });
+    let position = use_dropdown_position(
+        dropdown_visible,
+        dropdown_visible,
+        input_ref.clone(),
+        dropdown_ref.clone(),
+    );
+    let load_items = use_load_items(
+        *is_open,
+        props.value.clone(),
+        props.loading_status,
+        props.options.len(),
+        dropdown_ref.clone(),
+        props.on_load_items.clone(),
+    );
+
     // Handle input change
     let on_input = {
         let on_change = props.on_change.clone();
@@ -291,7 +456,7 @@ pub fn autosuggest(props: &AutosuggestProps) -> Html {
 
                 // Open dropdown when user types
                 is_open.set(true);
-                highlighted_index.set(0);
+                highlighted_index.set(None);
 
                 if let Some(callback) = &on_change {
                     callback.emit(CustomEvent::new_non_cancelable(AutosuggestChangeDetail {
@@ -316,19 +481,12 @@ pub fn autosuggest(props: &AutosuggestProps) -> Html {
         })
     };
 
-    // Handle blur
+    // Handle blur; the dropdown stays open while focus moves within the
+    // component and closes on outside interaction
     let on_blur_event = {
         let on_blur = props.on_blur.clone();
-        let is_open = is_open.clone();
 
         Callback::from(move |_e: FocusEvent| {
-            // Delay closing to allow click events on options to fire
-            let is_open = is_open.clone();
-            Timeout::new(200, move || {
-                is_open.set(false);
-            })
-            .forget();
-
             if let Some(callback) = &on_blur {
                 callback.emit(());
             }
@@ -354,6 +512,21 @@ pub fn autosuggest(props: &AutosuggestProps) -> Html {
         )
     };
 
+    // Clear the entered text, keeping the dropdown open on all options
+    let on_clear_filter = {
+        let on_change = props.on_change.clone();
+        let is_open = is_open.clone();
+
+        Callback::from(move |()| {
+            is_open.set(true);
+            if let Some(callback) = &on_change {
+                callback.emit(CustomEvent::new_non_cancelable(AutosuggestChangeDetail {
+                    value: String::new(),
+                }));
+            }
+        })
+    };
+
     // Handle keyboard navigation
     let on_key_down = {
         let is_open = is_open.clone();
@@ -365,62 +538,61 @@ pub fn autosuggest(props: &AutosuggestProps) -> Html {
         Callback::from(move |e: KeyboardEvent| {
             let key = e.key();
             match key.as_str() {
-                "ArrowDown" => {
+                "ArrowDown" | "ArrowUp" => {
                     e.prevent_default();
                     if !*is_open {
                         is_open.set(true);
-                    } else if total_items > 0 {
-                        let new_index = (*highlighted_index + 1) % total_items;
-                        highlighted_index.set(new_index);
+                    } else if let Some(index) =
+                        next_menu_entry(*highlighted_index, &key, total_items)
+                    {
+                        highlighted_index.set(Some(index));
                     }
                 }
-                "ArrowUp" => {
+                "Enter" if *is_open && highlighted_index.is_none() => {
+                    // Without a highlighted row, Enter submits the entered text
                     e.prevent_default();
-                    if !*is_open {
-                        is_open.set(true);
-                    } else if total_items > 0 {
-                        let new_index = if *highlighted_index == 0 {
-                            total_items - 1
-                        } else {
-                            *highlighted_index - 1
-                        };
-                        highlighted_index.set(new_index);
+                    if !value.is_empty()
+                        && let Some(callback) = &on_select
+                    {
+                        callback.emit(CustomEvent::new_non_cancelable(AutosuggestSelectDetail {
+                            value: value.clone(),
+                            selected_option: None,
+                        }));
                     }
+                    is_open.set(false);
                 }
-                "Enter" => {
-                    if *is_open && total_items > 0 {
-                        e.prevent_default();
-
-                        // Determine what was selected
-                        let idx = *highlighted_index;
-                        if show_entered_text && idx == 0 {
-                            // Selected "Use entered text"
-                            if let Some(callback) = &on_select {
-                                callback.emit(CustomEvent::new_non_cancelable(
-                                    AutosuggestSelectDetail {
-                                        value: value.clone(),
-                                        selected_option: None,
-                                    },
-                                ));
-                            }
-                        } else {
-                            // Selected an option
-                            let option_idx = if show_entered_text { idx - 1 } else { idx };
-                            if let Some(option) = filtered_options.get(option_idx)
-                                && !option.disabled
-                                && let Some(callback) = &on_select
-                            {
-                                callback.emit(CustomEvent::new_non_cancelable(
-                                    AutosuggestSelectDetail {
-                                        value: option.value.clone(),
-                                        selected_option: Some(option.clone()),
-                                    },
-                                ));
-                            }
-                        }
+                "Enter" if *is_open && total_items > 0 => {
+                    e.prevent_default();
 
-                        is_open.set(false);
+                    // Determine what was selected
+                    let idx = highlighted_index.unwrap_or_default();
+                    if show_entered_text && idx == 0 {
+                        // Selected "Use entered text"
+                        if let Some(callback) = &on_select {
+                            callback.emit(CustomEvent::new_non_cancelable(
+                                AutosuggestSelectDetail {
+                                    value: value.clone(),
+                                    selected_option: None,
+                                },
+                            ));
+                        }
+                    } else {
+                        // Selected an option
+                        let option_idx = if show_entered_text { idx - 1 } else { idx };
+                        if let Some(option) = filtered_options.get(option_idx)
+                            && !option.disabled
+                            && let Some(callback) = &on_select
+                        {
+                            callback.emit(CustomEvent::new_non_cancelable(
+                                AutosuggestSelectDetail {
+                                    value: option.value.clone(),
+                                    selected_option: Some(option.clone()),
+                                },
+                            ));
+                        }
                     }
+
+                    is_open.set(false);
                 }
                 "Escape" => {
                     e.prevent_default();
@@ -439,7 +611,7 @@ pub fn autosuggest(props: &AutosuggestProps) -> Html {
     use_effect_with(filtered_options.clone(), {
         let highlighted_index = highlighted_index.clone();
         move |_| {
-            highlighted_index.set(0);
+            highlighted_index.set(None);
             || ()
         }
     });
@@ -448,32 +620,66 @@ pub fn autosuggest(props: &AutosuggestProps) -> Html {
     let input_classes = ClassBuilder::new()
         .add("awsui-autosuggest-input")
         .add_if(props.disabled, "awsui-autosuggest-input-disabled")
-        .add_if(props.invalid, "awsui-autosuggest-input-invalid")
-        .add_if(
-            !props.invalid && props.warning,
-            "awsui-autosuggest-input-warning",
-        );
+        .add_if(validation.is_invalid(), "awsui-autosuggest-input-invalid")
+        .add_if(validation.is_warning(), "awsui-autosuggest-input-warning");
 
     // Build dropdown classes
     let dropdown_classes = ClassBuilder::new()
         .add("awsui-autosuggest-dropdown")
-        .add_if(
-            *is_open && total_items > 0,
-            "awsui-autosuggest-dropdown-open",
-        );
+        .add_if(dropdown_visible, "awsui-autosuggest-dropdown-open");
 
     // Determine entered text label
-    let entered_text_label = props
-        .entered_text_label
-        .clone()
-        .unwrap_or_else(|| "Use:".to_string());
+    let entered_text_label = match &props.entered_text_label {
+        Some(label) => label.emit(props.value.clone()),
+        None => format!("Use: \"{}\"", props.value),
+    };
+
+    // Label of a row; the entered text row comes first when shown
+    let row_label = |row: usize| -> Option<String> {
+        match row.checked_sub(usize::from(show_entered_text)) {
+            None => Some(entered_text_label.clone()),
+            Some(index) => filtered_options
+                .get(index)
+                .map(|option| option.display_text().to_string()),
+        }
+    };
+
+    // Focus stays in the input, which points at the highlighted row
+    let active_descendant = highlighted_index
+        .filter(|_| dropdown_visible)
+        .map(|row| option_id(&list_id, row));
+
+    // While open, announce the list state, else the highlighted row and its
+    // position
+    let announcement = if *is_open {
+        let state = list_announcement(
+            list_state.as_ref(),
+            props.loading_status,
+            !filtered_options.is_empty(),
+            &state_strings,
+        );
+        match highlighted_index.and_then(|row| Some((row, row_label(row)?))) {
+            Some((row, label)) if state.is_empty() => {
+                option_announcement(&label, false, Some((row + 1, total_items)), &state_strings)
+            }
+            _ => state,
+        }
+    } else {
+        String::new()
+    };
 
-    html! {
+    let root = html! {
         <div
+            ref={root_ref}
             class={ClassBuilder::new()
                 .add("awsui-autosuggest")
                 .add_if(props.disabled, "awsui-autosuggest-disabled")
-                .add_if(props.invalid, "awsui-autosuggest-invalid")
+                .add_if(validation.is_invalid(), "awsui-autosuggest-invalid")
+                .add_if(validation.is_warning(), "awsui-autosuggest-warning")
+                .add_if(
+                    props.trailing_action.is_some(),
+                    "awsui-autosuggest-has-trailing-action",
+                )
                 .build()}
         >
             // Input element
@@ -481,39 +687,62 @@ pub fn autosuggest(props: &AutosuggestProps) -> Html {
                 ref={input_ref}
                 type="text"
                 class={input_classes.build()}
-                id={props.control_id.clone()}
+                style={validation.control_style()}
+                id={association.control_id}
                 name={props.name.clone()}
                 value={props.value.clone()}
                 placeholder={props.placeholder.clone()}
                 disabled={props.disabled}
                 autofocus={props.auto_focus}
                 role="combobox"
-                aria-expanded={(*is_open && total_items > 0).to_string()}
+                aria-expanded={dropdown_visible.to_string()}
                 aria-autocomplete="list"
-                aria-controls="autosuggest-dropdown"
+                aria-controls={dropdown_visible.then(|| (*list_id).clone())}
+                aria-activedescendant={active_descendant}
                 aria-label={props.aria.label.clone()}
-                aria-labelledby={props.aria.labelledby.clone()}
-                aria-describedby={props.aria.describedby.clone()}
+                aria-labelledby={association.labelledby}
+                aria-describedby={association.describedby}
                 aria-required={props.aria_required.to_string()}
-                aria-invalid={props.invalid.to_string()}
+                aria-invalid={validation.is_invalid().then_some("true")}
                 oninput={on_input}
                 onblur={on_blur_event}
                 onfocus={on_focus_event}
                 onkeydown={on_key_down}
             />
 
+            if let Some(ref action) = props.trailing_action {
+                <div class="awsui-autosuggest-actions">
+                    { render_trailing_action(action, TrailingActionContext {
+                        disabled: props.disabled,
+                        read_only: false,
+                    }) }
+                </div>
+            }
+
+            <LiveRegion message={announcement} />
+
             // Dropdown menu
-            if *is_open && total_items > 0 {
+            if dropdown_visible {
                 <div
-                    id="autosuggest-dropdown"
+                    ref={dropdown_ref}
+                    style={position.map(|position| position.style())}
                     class={dropdown_classes.build()}
-                    role="listbox"
+                    aria-busy={(props.loading_status == Some(DropdownStatus::Loading)).then_some("true")}
+                    onscroll={load_items.on_scroll.clone()}
                 >
-                    <ul class="awsui-autosuggest-options-list">
+                    // The list itself is the listbox, so the status rows stay
+                    // outside of it
+                    <ul
+                        id={(*list_id).clone()}
+                        class="awsui-autosuggest-options-list"
+                        role="listbox"
+                        aria-label={props.aria.label.clone()}
+                        onscroll={load_items.on_scroll.clone()}
+                    >
                         // "Use entered text" option
                         if show_entered_text {
                             {{
-                                let is_highlighted = *highlighted_index == 0;
+                                let is_highlighted = *highlighted_index == Some(0);
                                 let value = props.value.clone();
                                 let on_select = on_option_select.clone();
 
@@ -530,13 +759,14 @@ pub fn autosuggest(props: &AutosuggestProps) -> Html {
 
                                 html! {
                                     <li
+                                        id={option_id(&list_id, 0)}
                                         class={option_classes}
                                         role="option"
                                         aria-selected={is_highlighted.to_string()}
                                         onclick={on_click}
                                     >
                                         <span class="awsui-autosuggest-option-label">
-                                            { format!("{} \"{}\"", entered_text_label, props.value) }
+                                            { entered_text_label.clone() }
                                         </span>
                                     </li>
                                 }
@@ -547,7 +777,7 @@ pub fn autosuggest(props: &AutosuggestProps) -> Html {
                         {
                             filtered_options.iter().enumerate().map(|(idx, option)| {
                                 let actual_idx = if show_entered_text { idx + 1 } else { idx };
-                                let is_highlighted = *highlighted_index == actual_idx;
+                                let is_highlighted = *highlighted_index == Some(actual_idx);
 
                                 let option_clone = option.clone();
                                 let value = option.value.clone();
@@ -568,6 +798,7 @@ pub fn autosuggest(props: &AutosuggestProps) -> Html {
                                 html! {
                                     <li
                                         key={option.value.clone()}
+                                        id={option_id(&list_id, actual_idx)}
                                         class={option_classes.build()}
                                         role="option"
                                         aria-selected={is_highlighted.to_string()}
@@ -576,16 +807,16 @@ pub fn autosuggest(props: &AutosuggestProps) -> Html {
                                     >
                                         <div class="awsui-autosuggest-option-content">
                                             <span class="awsui-autosuggest-option-label">
-                                                { option.display_text() }
+                                                { render_match_highlight(option.display_text(), &props.value) }
                                                 if let Some(ref tag) = option.label_tag {
                                                     <span class="awsui-autosuggest-option-label-tag">
-                                                        { tag }
+                                                        { render_match_highlight(tag, &props.value) }
                                                     </span>
                                                 }
                                             </span>
                                             if let Some(ref desc) = option.description {
                                                 <span class="awsui-autosuggest-option-description">
-                                                    { desc }
+                                                    { render_match_highlight(desc, &props.value) }
                                                 </span>
                                             }
                                         </div>
@@ -594,10 +825,24 @@ pub fn autosuggest(props: &AutosuggestProps) -> Html {
                             }).collect::<Html>()
                         }
                     </ul>
+                    if let Some(ref state) = list_state {
+                        { render_list_state(state, &state_strings, ListStateSlots {
+                            no_match_content: props.no_match_content.clone(),
+                            empty_content: props.empty_content.clone(),
+                            on_clear_filter: Some(on_clear_filter.clone()),
+                        }) }
+                    }
+                    { render_load_status(
+                        props.loading_status,
+                        !filtered_options.is_empty(),
+                        &state_strings,
+                        load_items.on_retry.clone(),
+                    ) }
                 </div>
             }
         </div>
-    }
+    };
+    apply_root_attributes(root, &props.base)
 }
 
 #[cfg(test)]
@@ -673,6 +918,18 @@ mod tests {
         assert_ne!(option1, option3);
     }
 
+    #[test]
+    fn test_match_ranges() {
+        assert_eq!(match_ranges("Apple", "ap"), vec![0..2]);
+        // Matches ignore case and repeat without overlapping
+        assert_eq!(match_ranges("Banana", "AN"), vec![1..3, 3..5]);
+        assert_eq!(match_ranges("aaa", "aa"), vec![0..2]);
+        assert_eq!(match_ranges("Cherry", "x"), Vec::<Range<usize>>::new());
+        assert_eq!(match_ranges("Cherry", ""), Vec::<Range<usize>>::new());
+        // Ranges are byte ranges of the original text
+        assert_eq!(match_ranges("Zürich zÜrich", "ÜR"), vec![1..4, 9..12]);
+    }
+
     #[test]
     fn test_filtering_type_default() {
         assert_eq!(FilteringType::default(), FilteringType::Auto);
@@ -711,7 +968,7 @@ mod tests {
 
     #[test]
     fn test_filtering_options() {
-        let options = vec![
+        let options = [
             AutosuggestOption::new("apple").with_label("Apple"),
             AutosuggestOption::new("apricot").with_label("Apricot"),
             AutosuggestOption::new("banana").with_label("Banana"),
@@ -6,7 +6,10 @@
 //! A small visual indicator for labels and metadata.
 
 use crate::internal::styles::BadgeStyle;
-use crate::internal::{AnalyticsMetadata, BaseComponentProps, ComponentMetadata, ComponentStyles};
+use crate::internal::{
+    AnalyticsMetadata, BaseComponentProps, ComponentMetadata, ComponentStyles,
+    apply_root_attributes,
+};
 use yew::prelude::*;
 
 /// Badge color variants
@@ -99,7 +102,7 @@ pub fn badge(props: &BadgeProps) -> Html {
     let analytics = AnalyticsMetadata::badge("badge", props.color.as_str());
     let analytics_attr = analytics.to_data_attribute();
 
-    html! {
+    let root = html! {
         <span
             id={props.base.id.clone()}
             class={class}
@@ -108,5 +111,6 @@ pub fn badge(props: &BadgeProps) -> Html {
         >
             { props.children.clone() }
         </span>
-    }
+    };
+    apply_root_attributes(root, &props.base)
 }
@@ -5,7 +5,7 @@
 //!
 //! A layout utility component for controlling spacing, typography, and display properties.
 
-use crate::internal::{BaseComponentProps, ClassBuilder};
+use crate::internal::{BaseComponentProps, ClassBuilder, apply_root_attributes};
 use yew::prelude::*;
 
 /// Box HTML tag variants
@@ -139,12 +139,13 @@ pub fn box_component(props: &BoxProps) -> Html {
 
     // For now, always render as div. In a full implementation,
     // we'd use @html! macro or VTag to dynamically select the tag
-    html! {
+    let root = html! {
         <div
             id={props.base.id.clone()}
             class={class}
         >
             { props.children.clone() }
         </div>
-    }
+    };
+    apply_root_attributes(root, &props.base)
 }
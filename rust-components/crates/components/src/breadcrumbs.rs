@@ -7,10 +7,20 @@
 //! navigation hierarchy. The last item represents the current page and is not clickable.
 //! When there are many items, the component can collapse them into an expandable dropdown.
 
+use crate::button::ButtonVariant;
+use crate::button_dropdown::{ButtonDropdown, ButtonDropdownItem, ButtonDropdownItemClickDetail};
+use crate::i18n::{I18nContext, use_i18n};
+use crate::input::{Input, InputChangeDetail};
+use crate::internal::live_region::VISUALLY_HIDDEN_STYLE;
 use crate::internal::{
-    AnalyticsMetadata, BaseComponentProps, ClassBuilder, ComponentMetadata, CustomEvent,
+    AnalyticsMetadata, AriaAttributes, BaseComponentProps, ClassBuilder, ClickEvent,
+    ComponentMetadata, CustomEvent, FollowEvent as DropdownFollowEvent, TruncatedText,
+    apply_root_attributes, use_element_size,
 };
-use web_sys::MouseEvent;
+use crate::link::{self, provided_follow, use_link_context};
+use crate::spinner::Spinner;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement, HtmlInputElement, KeyboardEvent, MouseEvent};
 use yew::prelude::*;
 
 /// A single breadcrumb item in the navigation trail
@@ -20,6 +30,13 @@ pub struct BreadcrumbItem {
     pub text: String,
     /// URL that the breadcrumb links to
     pub href: String,
+    /// Maximum text width as a CSS length; longer text is truncated with an
+    /// ellipsis and shown in full in a tooltip
+    pub max_width: Option<String>,
+    /// Whether the text is still loading; a spinner stands in for it
+    pub loading: bool,
+    /// Whether the current page can be renamed in place (last item only)
+    pub editable: bool,
 }
 
 impl BreadcrumbItem {
@@ -36,6 +53,9 @@ impl BreadcrumbItem {
         Self {
             text: text.into(),
             href: href.into(),
+            max_width: None,
+            loading: false,
+            editable: false,
         }
     }
 
@@ -50,6 +70,24 @@ impl BreadcrumbItem {
         self.href = href.into();
         self
     }
+
+    /// Sets the maximum text width, overriding the component default
+    pub fn with_max_width(mut self, max_width: impl Into<String>) -> Self {
+        self.max_width = Some(max_width.into());
+        self
+    }
+
+    /// Sets whether the text is still loading
+    pub fn with_loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
+    /// Sets whether the current page can be renamed in place
+    pub fn with_editable(mut self, editable: bool) -> Self {
+        self.editable = editable;
+        self
+    }
 }
 
 /// Event detail for breadcrumb follow/click events
@@ -66,6 +104,141 @@ pub struct FollowDetail {
 /// Follow event type for breadcrumbs
 pub type BreadcrumbFollowEvent = CustomEvent<FollowDetail>;
 
+/// Event detail for renaming the current page from its breadcrumb
+#[derive(Clone, PartialEq, Debug)]
+pub struct RenameDetail {
+    /// The index of the renamed item in the breadcrumbs array
+    pub item_index: usize,
+    /// The text before renaming
+    pub previous_text: String,
+    /// The new text, trimmed
+    pub text: String,
+}
+
+/// Width of the rename control when the item has no maximum width
+const DEFAULT_RENAME_WIDTH: &str = "240px";
+
+/// Result of committing a rename
+#[derive(Debug, Clone, PartialEq)]
+enum RenameOutcome {
+    /// The name didn't change; the edit is canceled
+    Unchanged,
+    /// The name is rejected with an error message; the edit stays open
+    Invalid(String),
+    /// The item is renamed to the trimmed text
+    Renamed(String),
+}
+
+/// Decides the result of committing `draft` as the new name of `previous`
+///
+/// An empty name is rejected with `empty_text`, otherwise `validate` can
+/// reject the name with an error message.
+fn rename_outcome(
+    previous: &str,
+    draft: &str,
+    empty_text: &str,
+    validate: impl FnOnce(&str) -> Option<String>,
+) -> RenameOutcome {
+    let text = draft.trim();
+    if text == previous {
+        RenameOutcome::Unchanged
+    } else if text.is_empty() {
+        RenameOutcome::Invalid(empty_text.to_string())
+    } else if let Some(error) = validate(text) {
+        RenameOutcome::Invalid(error)
+    } else {
+        RenameOutcome::Renamed(text.to_string())
+    }
+}
+
+/// Style constraining the width of the rename control
+fn rename_style(max_width: Option<&str>) -> String {
+    format!(
+        "display: inline-block; width: {}; max-width: 100%",
+        max_width.unwrap_or(DEFAULT_RENAME_WIDTH)
+    )
+}
+
+/// Width reserved for the ellipsis item, separator included
+const ELLIPSIS_WIDTH: f64 = 50.0;
+
+/// Containers narrower than this (the `xxs` breakpoint) only show a link
+/// back to the parent item
+const BACK_LINK_MAX_WIDTH: f64 = 465.0;
+
+/// Style of the hidden copy of the trail: its items are laid out on one
+/// line at their natural width, overflowing a box that takes up no space
+const MEASURE_STYLE: &str = "position: absolute; visibility: hidden; width: 0; height: 0; \
+     overflow: hidden; white-space: nowrap; pointer-events: none";
+
+/// How the trail fits into the available width
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BreadcrumbLayout {
+    /// Every item is shown
+    Full,
+    /// The `hidden` items after the first are collapsed into a dropdown
+    Collapsed { hidden: usize },
+    /// Only a link back to the parent item is shown
+    BackLink,
+}
+
+/// Fits a trail with the given item widths into the available width
+///
+/// The first and last items always stay visible; the items right before
+/// the last are kept for as long as they fit, and the others collapse.
+/// Until the items and the container are measured, every item is shown.
+fn breadcrumb_layout(widths: &[f64], count: usize, available: Option<f64>) -> BreadcrumbLayout {
+    let Some(available) = available.filter(|_| widths.len() == count && count > 1) else {
+        return BreadcrumbLayout::Full;
+    };
+    if available < BACK_LINK_MAX_WIDTH {
+        return BreadcrumbLayout::BackLink;
+    }
+    if count <= 2 || widths.iter().sum::<f64>() <= available {
+        return BreadcrumbLayout::Full;
+    }
+
+    let mut used = widths[0] + widths[count - 1] + ELLIPSIS_WIDTH;
+    let mut shown = 0;
+    for width in widths[1..count - 1].iter().rev() {
+        if used + width > available {
+            break;
+        }
+        used += width;
+        shown += 1;
+    }
+    BreadcrumbLayout::Collapsed {
+        hidden: count - 2 - shown,
+    }
+}
+
+/// Strings of the loading and rename states after applying the fallback
+/// chain: I18nProvider (keys under `"breadcrumbs."`), then English
+#[derive(Clone, PartialEq)]
+struct BreadcrumbStrings {
+    loading_text: String,
+    rename_aria_label: String,
+    empty_name_text: String,
+    expand_aria_label: String,
+}
+
+impl BreadcrumbStrings {
+    fn resolve(i18n: &I18nContext) -> Self {
+        let resolve = |key: &str, default: &str| {
+            i18n.message("breadcrumbs", key)
+                .unwrap_or(default)
+                .to_string()
+        };
+
+        Self {
+            loading_text: resolve("loading_text", "Loading"),
+            rename_aria_label: resolve("rename_aria_label", "Rename"),
+            empty_name_text: resolve("empty_name_text", "Enter a name."),
+            expand_aria_label: resolve("expand_aria_label", "Show path"),
+        }
+    }
+}
+
 /// Properties for the Breadcrumbs component
 #[derive(Properties, PartialEq, Clone)]
 pub struct BreadcrumbsProps {
@@ -104,9 +277,32 @@ pub struct BreadcrumbsProps {
     /// ARIA label for the expand/ellipsis button
     ///
     /// Used when breadcrumbs are collapsed and shown in a dropdown.
-    /// Defaults to "Show path" if not provided.
+    /// Defaults to the `breadcrumbs.expand_aria_label` message of the
+    /// I18nProvider, then "Show path".
     #[prop_or_default]
     pub expand_aria_label: Option<String>,
+
+    /// Default maximum text width for items, as a CSS length
+    ///
+    /// Longer item text is truncated with an ellipsis and shown in full in a
+    /// tooltip on hover or focus. Items can override it with `max_width`.
+    #[prop_or_default]
+    pub item_max_width: Option<String>,
+
+    /// Callback fired when the user renames an `editable` last item
+    ///
+    /// Clicking the current page turns it into an input; Enter commits the
+    /// new name and Escape cancels. The component doesn't change the item
+    /// text itself.
+    #[prop_or_default]
+    pub on_item_rename: Option<Callback<CustomEvent<RenameDetail>>>,
+
+    /// Validates a new name, returning an error message to reject it
+    ///
+    /// Rejected names keep the input open with the message shown below it.
+    /// Empty names are always rejected.
+    #[prop_or_default]
+    pub validate_rename: Option<Callback<String, Option<String>>>,
 }
 
 /// Breadcrumb navigation component
@@ -120,6 +316,7 @@ pub struct BreadcrumbsProps {
 /// ## Basic breadcrumbs
 ///
 /// ```rust
+/// # use yew::prelude::*;
 /// use cloudscape_components::{Breadcrumbs, BreadcrumbItem};
 ///
 /// let items = vec![
@@ -128,9 +325,11 @@ pub struct BreadcrumbsProps {
 ///     BreadcrumbItem::new("Details", "/products/123"),
 /// ];
 ///
+/// # let _: Html =
 /// html! {
 ///     <Breadcrumbs items={items} />
 /// }
+/// # ;
 /// ```
 ///
 /// ## With navigation handling
@@ -141,8 +340,7 @@ pub struct BreadcrumbsProps {
 ///
 /// let on_follow = Callback::from(|mut event: BreadcrumbFollowEvent| {
 ///     event.prevent_default(); // Prevent default navigation
-///     // Implement your routing logic here
-///     log::info!("Navigate to: {}", event.detail.href);
+///     // Implement your routing logic here, e.g. push `event.detail.href`
 /// });
 ///
 /// let items = vec![
@@ -150,6 +348,7 @@ pub struct BreadcrumbsProps {
 ///     BreadcrumbItem::new("Products", "/products"),
 /// ];
 ///
+/// # let _: Html =
 /// html! {
 ///     <Breadcrumbs
 ///         items={items}
@@ -157,11 +356,13 @@ pub struct BreadcrumbsProps {
 ///         aria_label="Navigation breadcrumbs"
 ///     />
 /// }
+/// # ;
 /// ```
 ///
 /// ## Builder pattern for items
 ///
 /// ```rust
+/// # use yew::prelude::*;
 /// use cloudscape_components::{Breadcrumbs, BreadcrumbItem};
 ///
 /// let items = vec![
@@ -171,26 +372,93 @@ pub struct BreadcrumbsProps {
 ///     BreadcrumbItem::new("Settings", "/settings"),
 /// ];
 ///
+/// # let _: Html =
 /// html! {
 ///     <Breadcrumbs items={items} />
 /// }
+/// # ;
+/// ```
+///
+/// ## Loading and renaming the current page
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// use cloudscape_components::{Breadcrumbs, BreadcrumbItem, CustomEvent, RenameDetail};
+///
+/// let items = vec![
+///     BreadcrumbItem::new("Dashboards", "/dashboards"),
+///     BreadcrumbItem::new("Sales", "/dashboards/sales").with_editable(true),
+/// ];
+/// let on_item_rename = Callback::from(|event: CustomEvent<RenameDetail>| {
+///     // Save `event.detail.text` as the dashboard name
+/// });
+///
+/// # let _: Html =
+/// html! {
+///     <Breadcrumbs items={items} on_item_rename={on_item_rename} />
+/// }
+/// # ;
 /// ```
 #[function_component(Breadcrumbs)]
 pub fn breadcrumbs(props: &BreadcrumbsProps) -> Html {
     let _metadata = ComponentMetadata::new("Breadcrumbs");
+    let strings = BreadcrumbStrings::resolve(&use_i18n());
+
+    // Without an `on_follow`, links are routed by the closest LinkProvider
+    let link_context = use_link_context();
+    let on_follow = props.on_follow.clone().or_else(|| {
+        provided_follow(link_context, |detail: &FollowDetail| link::FollowDetail {
+            href: Some(detail.href.clone()),
+            external: false,
+            target: None,
+        })
+    });
 
     // Default ARIA labels
     let aria_label = props
         .aria_label
         .clone()
         .unwrap_or_else(|| "Breadcrumbs".to_string());
-    let _expand_aria_label = props
+    let expand_aria_label = props
         .expand_aria_label
         .clone()
-        .unwrap_or_else(|| "Show path".to_string());
+        .unwrap_or_else(|| strings.expand_aria_label.clone());
+
+    // Measure the container, and the items in a hidden copy of the full
+    // trail, to decide which items fit
+    let root_ref = use_node_ref();
+    let measure_ref = use_node_ref();
+    let available = use_element_size(root_ref.clone()).map(|(width, _)| width);
+    let item_widths = use_state_eq(Vec::<f64>::new);
+    {
+        let measure_ref = measure_ref.clone();
+        let item_widths = item_widths.clone();
+        use_effect_with((props.items.clone(), available), move |_| {
+            if let Some(list) = measure_ref.cast::<Element>() {
+                let items = list.children();
+                item_widths.set(
+                    (0..items.length())
+                        .filter_map(|index| items.item(index))
+                        .map(|item| item.get_bounding_client_rect().width())
+                        .collect(),
+                );
+            }
+            || ()
+        });
+    }
+    let layout = breadcrumb_layout(&item_widths, props.items.len(), available);
 
     // Build component styles
-    let classes = ClassBuilder::new().add("awsui-breadcrumbs");
+    let classes = ClassBuilder::new()
+        .add("awsui-breadcrumbs")
+        .add_if(
+            matches!(layout, BreadcrumbLayout::Collapsed { .. }),
+            "awsui-breadcrumbs-collapsed",
+        )
+        .add_if(
+            layout == BreadcrumbLayout::BackLink,
+            "awsui-breadcrumbs-mobile",
+        );
 
     let class = props.base.merge_classes(&classes.build());
 
@@ -206,26 +474,69 @@ pub fn breadcrumbs(props: &BreadcrumbsProps) -> Html {
     };
     let analytics_attr = analytics.to_data_attribute();
 
+    let max_width = |item: &BreadcrumbItem| {
+        item.max_width
+            .clone()
+            .or_else(|| props.item_max_width.clone())
+    };
+    let render_item = |index: usize| {
+        let item = &props.items[index];
+        let is_last = index == props.items.len() - 1;
+        render_breadcrumb_item(
+            item,
+            index,
+            is_last,
+            max_width(item).as_deref(),
+            props,
+            &on_follow,
+            &strings,
+        )
+    };
+
     // Render breadcrumb items
-    let items_html = props
+    let count = props.items.len();
+    let items_html = match layout {
+        BreadcrumbLayout::Full => (0..count).map(render_item).collect::<Html>(),
+        BreadcrumbLayout::Collapsed { hidden } => {
+            let collapsed: Vec<(usize, BreadcrumbItem)> = (1..=hidden)
+                .map(|index| (index, props.items[index].clone()))
+                .collect();
+            html! {
+                <>
+                    { render_item(0) }
+                    { render_collapsed_items(&collapsed, props, &on_follow, &expand_aria_label) }
+                    { for (hidden + 1..count).map(render_item) }
+                </>
+            }
+        }
+        BreadcrumbLayout::BackLink => render_back_link(
+            &props.items[count - 2],
+            count - 2,
+            props,
+            &on_follow,
+            &strings.loading_text,
+        ),
+    };
+
+    // Hidden copy of the full trail, measured for the layout
+    let measured_items = props
         .items
         .iter()
-        .enumerate()
-        .map(|(index, item)| {
-            let is_last = index == props.items.len() - 1;
-
-            render_breadcrumb_item(
-                item,
-                index,
-                is_last,
-                props.on_click.clone(),
-                props.on_follow.clone(),
-            )
+        .map(|item| {
+            html! {
+                <li class="awsui-breadcrumbs-item" style="display: inline-block">
+                    <span class="awsui-breadcrumbs-link">
+                        { render_item_text(item, max_width(item).as_deref(), &strings.loading_text) }
+                    </span>
+                    <span class="awsui-breadcrumbs-separator">{ " / " }</span>
+                </li>
+            }
         })
         .collect::<Html>();
 
-    html! {
+    let root = html! {
         <nav
+            ref={root_ref}
             id={props.base.id.clone()}
             class={class}
             aria-label={aria_label}
@@ -234,7 +545,35 @@ pub fn breadcrumbs(props: &BreadcrumbsProps) -> Html {
             <ol class="awsui-breadcrumbs-list">
                 { items_html }
             </ol>
+            <ol
+                ref={measure_ref}
+                class="awsui-breadcrumbs-list awsui-breadcrumbs-measure"
+                style={MEASURE_STYLE}
+                aria-hidden="true"
+            >
+                { measured_items }
+            </ol>
         </nav>
+    };
+    apply_root_attributes(root, &props.base)
+}
+
+/// Renders the text of an item, or a spinner while it is loading
+///
+/// The spinner keeps a fixed size, so the items before it don't move when
+/// the text arrives.
+fn render_item_text(item: &BreadcrumbItem, max_width: Option<&str>, loading_text: &str) -> Html {
+    if item.loading {
+        html! {
+            <span class="awsui-breadcrumbs-loading">
+                <Spinner />
+                <span style={VISUALLY_HIDDEN_STYLE}>{ loading_text.to_string() }</span>
+            </span>
+        }
+    } else {
+        html! {
+            <TruncatedText text={item.text.clone()} max_width={max_width.map(str::to_string)} />
+        }
     }
 }
 
@@ -243,8 +582,10 @@ fn render_breadcrumb_item(
     item: &BreadcrumbItem,
     index: usize,
     is_last: bool,
-    on_click: Option<Callback<BreadcrumbFollowEvent>>,
-    on_follow: Option<Callback<BreadcrumbFollowEvent>>,
+    max_width: Option<&str>,
+    props: &BreadcrumbsProps,
+    on_follow: &Option<Callback<BreadcrumbFollowEvent>>,
+    strings: &BreadcrumbStrings,
 ) -> Html {
     let item_classes = ClassBuilder::new()
         .add("awsui-breadcrumbs-item")
@@ -267,50 +608,28 @@ fn render_breadcrumb_item(
     };
 
     // Create click handler for non-last items
-    let click_handler = if !is_last {
-        let item_clone = item.clone();
-        let on_click_cb = on_click.clone();
-        let on_follow_cb = on_follow.clone();
-
-        Some(Callback::from(move |e: MouseEvent| {
-            let detail = FollowDetail {
-                text: item_clone.text.clone(),
-                href: item_clone.href.clone(),
-                item_index: index,
-            };
-
-            // Fire click event (always fires)
-            if let Some(ref cb) = on_click_cb {
-                let event = BreadcrumbFollowEvent::new_non_cancelable(detail.clone());
-                cb.emit(event);
-            }
-
-            // Fire follow event for plain left clicks without modifiers
-            if is_plain_left_click(&e)
-                && let Some(ref cb) = on_follow_cb
-            {
-                let event = BreadcrumbFollowEvent::new(detail);
-                cb.emit(event.clone());
-
-                // Prevent default navigation if prevented by callback
-                if event.default_prevented {
-                    e.prevent_default();
-                }
-            }
-        }))
-    } else {
-        None
-    };
+    let click_handler =
+        (!is_last).then(|| item_click_handler(item, index, &props.on_click, on_follow));
 
     html! {
         <li class={item_classes.build()}>
-            if is_last {
+            if is_last && item.editable && !item.loading {
+                <EditableCurrentItem
+                    text={item.text.clone()}
+                    item_index={index}
+                    max_width={max_width.map(str::to_string)}
+                    strings={strings.clone()}
+                    on_rename={props.on_item_rename.clone()}
+                    validate={props.validate_rename.clone()}
+                />
+            } else if is_last {
                 // Last item is not a link - it's the current page
                 <span
                     class="awsui-breadcrumbs-text awsui-breadcrumbs-current"
                     aria-current="page"
+                    aria-busy={item.loading.then_some("true")}
                 >
-                    { &item.text }
+                    { render_item_text(item, max_width, &strings.loading_text) }
                 </span>
             } else {
                 // Regular breadcrumb link
@@ -319,8 +638,9 @@ fn render_breadcrumb_item(
                     href={item.href.clone()}
                     onclick={click_handler}
                     data-analytics-metadata={item_analytics}
+                    aria-busy={item.loading.then_some("true")}
                 >
-                    { &item.text }
+                    { render_item_text(item, max_width, &strings.loading_text) }
                 </a>
                 // Separator icon
                 <span class="awsui-breadcrumbs-separator" aria-hidden="true">
@@ -331,13 +651,287 @@ fn render_breadcrumb_item(
     }
 }
 
-/// Checks if a click is a plain left click without modifiers
-fn is_plain_left_click(event: &MouseEvent) -> bool {
-    event.button() == 0
-        && !event.ctrl_key()
-        && !event.shift_key()
-        && !event.alt_key()
-        && !event.meta_key()
+/// Click handler of an item link
+///
+/// Fires `on_click` for every click, and the cancelable `on_follow` for
+/// plain left clicks.
+fn item_click_handler(
+    item: &BreadcrumbItem,
+    index: usize,
+    on_click: &Option<Callback<BreadcrumbFollowEvent>>,
+    on_follow: &Option<Callback<BreadcrumbFollowEvent>>,
+) -> Callback<MouseEvent> {
+    let detail = FollowDetail {
+        text: item.text.clone(),
+        href: item.href.clone(),
+        item_index: index,
+    };
+    let on_click = on_click.clone();
+    let on_follow = on_follow.clone();
+
+    Callback::from(move |e: MouseEvent| {
+        // Fire click event (always fires)
+        if let Some(ref cb) = on_click {
+            cb.emit(BreadcrumbFollowEvent::new_non_cancelable(detail.clone()));
+        }
+
+        // Fire follow event for plain left clicks without modifiers
+        if ClickEvent::from_mouse_event(&e).is_plain_left_click()
+            && let Some(ref cb) = on_follow
+        {
+            // Prevent default navigation if prevented by callback
+            if BreadcrumbFollowEvent::new(detail.clone()).emit_to(cb) {
+                e.prevent_default();
+            }
+        }
+    })
+}
+
+/// Renders the ellipsis item holding the collapsed items in a dropdown
+///
+/// Choosing an item fires the same events as clicking its link.
+fn render_collapsed_items(
+    items: &[(usize, BreadcrumbItem)],
+    props: &BreadcrumbsProps,
+    on_follow: &Option<Callback<BreadcrumbFollowEvent>>,
+    expand_aria_label: &str,
+) -> Html {
+    let dropdown_items: Vec<ButtonDropdownItem> = items
+        .iter()
+        .map(|(index, item)| {
+            ButtonDropdownItem::new(index.to_string(), item.text.clone())
+                .with_href(item.href.clone())
+        })
+        .collect();
+    let follow_detail = |(index, item): &(usize, BreadcrumbItem)| FollowDetail {
+        text: item.text.clone(),
+        href: item.href.clone(),
+        item_index: *index,
+    };
+
+    let on_item_click = props.on_click.clone().map(|on_click| {
+        let items = items.to_vec();
+        Callback::from(move |event: CustomEvent<ButtonDropdownItemClickDetail>| {
+            if let Some(entry) = items
+                .iter()
+                .find(|(index, _)| index.to_string() == event.detail.id)
+            {
+                on_click.emit(BreadcrumbFollowEvent::new_non_cancelable(follow_detail(
+                    entry,
+                )));
+            }
+        })
+    });
+    // Follow events of the dropdown only carry the href
+    let on_item_follow = on_follow.clone().map(|on_follow| {
+        let items = items.to_vec();
+        Callback::from(move |mut event: DropdownFollowEvent| {
+            if let Some(entry) = items
+                .iter()
+                .find(|(_, item)| event.detail.href.as_deref() == Some(item.href.as_str()))
+                && BreadcrumbFollowEvent::new(follow_detail(entry)).emit_to(&on_follow)
+            {
+                event.prevent_default();
+            }
+        })
+    });
+
+    html! {
+        <li class="awsui-breadcrumbs-item awsui-breadcrumbs-ellipsis">
+            <ButtonDropdown
+                variant={ButtonVariant::InlineLink}
+                items={dropdown_items}
+                on_item_click={on_item_click}
+                on_item_follow={on_item_follow}
+                aria_label={expand_aria_label.to_string()}
+            >
+                { "…" }
+            </ButtonDropdown>
+            <span class="awsui-breadcrumbs-separator" aria-hidden="true">
+                { " / " }
+            </span>
+        </li>
+    }
+}
+
+/// Renders the link back to the parent item shown on narrow containers
+fn render_back_link(
+    parent: &BreadcrumbItem,
+    index: usize,
+    props: &BreadcrumbsProps,
+    on_follow: &Option<Callback<BreadcrumbFollowEvent>>,
+    loading_text: &str,
+) -> Html {
+    html! {
+        <li class="awsui-breadcrumbs-item awsui-breadcrumbs-back">
+            <a
+                class="awsui-breadcrumbs-link awsui-breadcrumb-item"
+                href={parent.href.clone()}
+                onclick={item_click_handler(parent, index, &props.on_click, on_follow)}
+            >
+                <span class="awsui-breadcrumbs-back-icon" aria-hidden="true">{ "← " }</span>
+                { render_item_text(parent, None, loading_text) }
+            </a>
+        </li>
+    }
+}
+
+/// Properties of the current page item while it can be renamed
+#[derive(Properties, PartialEq, Clone)]
+struct EditableCurrentItemProps {
+    text: String,
+    item_index: usize,
+    max_width: Option<String>,
+    strings: BreadcrumbStrings,
+    on_rename: Option<Callback<CustomEvent<RenameDetail>>>,
+    validate: Option<Callback<String, Option<String>>>,
+}
+
+/// Current page item that turns into an input on click
+///
+/// Enter commits the new name and Escape cancels; either way focus returns
+/// to the item.
+#[function_component(EditableCurrentItem)]
+fn editable_current_item(props: &EditableCurrentItemProps) -> Html {
+    let editing = use_state(|| false);
+    let draft = use_state(String::new);
+    let error = use_state(|| None::<String>);
+    let item_ref = use_node_ref();
+    let edit_ref = use_node_ref();
+    let restore_focus = use_mut_ref(|| false);
+
+    // Move focus into the input when editing starts, and back to the item
+    // once the edit is committed or canceled
+    {
+        let item_ref = item_ref.clone();
+        let edit_ref = edit_ref.clone();
+        let restore_focus = restore_focus.clone();
+        use_effect_with(*editing, move |editing| {
+            if *editing {
+                if let Some(edit) = edit_ref.cast::<HtmlElement>()
+                    && let Ok(Some(input)) = edit.query_selector("input")
+                    && let Ok(input) = input.dyn_into::<HtmlInputElement>()
+                {
+                    let _ = input.focus();
+                    input.select();
+                }
+            } else if std::mem::take(&mut *restore_focus.borrow_mut())
+                && let Some(item) = item_ref.cast::<HtmlElement>()
+            {
+                let _ = item.focus();
+            }
+        });
+    }
+
+    let on_start = {
+        let editing = editing.clone();
+        let draft = draft.clone();
+        let error = error.clone();
+        let text = props.text.clone();
+        Callback::from(move |_: MouseEvent| {
+            draft.set(text.clone());
+            error.set(None);
+            editing.set(true);
+        })
+    };
+
+    let on_change = {
+        let draft = draft.clone();
+        let error = error.clone();
+        Callback::from(move |event: CustomEvent<InputChangeDetail>| {
+            draft.set(event.detail.value);
+            error.set(None);
+        })
+    };
+
+    let on_keydown = {
+        let editing = editing.clone();
+        let draft = draft.clone();
+        let error = error.clone();
+        let props = props.clone();
+        Callback::from(move |e: KeyboardEvent| {
+            let finish = || {
+                *restore_focus.borrow_mut() = true;
+                editing.set(false);
+            };
+            match e.key().as_str() {
+                "Enter" => {
+                    e.prevent_default();
+                    let outcome = rename_outcome(
+                        &props.text,
+                        draft.as_str(),
+                        &props.strings.empty_name_text,
+                        |text| {
+                            props
+                                .validate
+                                .as_ref()
+                                .and_then(|validate| validate.emit(text.to_string()))
+                        },
+                    );
+                    match outcome {
+                        RenameOutcome::Unchanged => finish(),
+                        RenameOutcome::Invalid(message) => error.set(Some(message)),
+                        RenameOutcome::Renamed(text) => {
+                            if let Some(on_rename) = &props.on_rename {
+                                on_rename.emit(CustomEvent::new_non_cancelable(RenameDetail {
+                                    item_index: props.item_index,
+                                    previous_text: props.text.clone(),
+                                    text,
+                                }));
+                            }
+                            finish();
+                        }
+                    }
+                }
+                "Escape" => {
+                    e.prevent_default();
+                    e.stop_propagation();
+                    finish();
+                }
+                _ => {}
+            }
+        })
+    };
+
+    if *editing {
+        let aria = AriaAttributes {
+            label: Some(props.strings.rename_aria_label.clone()),
+            ..Default::default()
+        };
+        html! {
+            <span
+                ref={edit_ref}
+                class="awsui-breadcrumbs-rename"
+                style={rename_style(props.max_width.as_deref())}
+                onkeydown={on_keydown}
+            >
+                <Input
+                    value={(*draft).clone()}
+                    invalid={error.is_some()}
+                    aria={aria}
+                    on_change={on_change}
+                />
+                if let Some(ref message) = *error {
+                    <span class="awsui-breadcrumbs-rename-error" role="alert">
+                        { message.clone() }
+                    </span>
+                }
+            </span>
+        }
+    } else {
+        html! {
+            <button
+                ref={item_ref}
+                type="button"
+                class="awsui-breadcrumbs-text awsui-breadcrumbs-current awsui-breadcrumbs-current-editable"
+                aria-current="page"
+                aria-description={props.strings.rename_aria_label.clone()}
+                onclick={on_start}
+            >
+                <TruncatedText text={props.text.clone()} max_width={props.max_width.clone()} />
+            </button>
+        }
+    }
 }
 
 #[cfg(test)]
@@ -371,6 +965,114 @@ mod tests {
         assert_eq!(item.href, "/products");
     }
 
+    #[test]
+    fn test_breadcrumb_item_max_width() {
+        let item = BreadcrumbItem::new("Bucket", "/bucket");
+        assert_eq!(item.max_width, None);
+
+        let item = item.with_max_width("160px");
+        assert_eq!(item.max_width.as_deref(), Some("160px"));
+    }
+
+    #[test]
+    fn test_breadcrumb_item_loading_and_editable() {
+        let item = BreadcrumbItem::new("Sales", "/dashboards/sales");
+        assert!(!item.loading);
+        assert!(!item.editable);
+
+        let item = item.with_loading(true).with_editable(true);
+        assert!(item.loading);
+        assert!(item.editable);
+    }
+
+    #[test]
+    fn test_rename_outcome() {
+        let no_validation = |_: &str| None;
+        assert_eq!(
+            rename_outcome("Sales", " Sales ", "Enter a name.", no_validation),
+            RenameOutcome::Unchanged
+        );
+        assert_eq!(
+            rename_outcome("Sales", "  ", "Enter a name.", no_validation),
+            RenameOutcome::Invalid("Enter a name.".to_string())
+        );
+        assert_eq!(
+            rename_outcome("Sales", " Revenue ", "Enter a name.", no_validation),
+            RenameOutcome::Renamed("Revenue".to_string())
+        );
+
+        let too_long = |text: &str| (text.len() > 5).then(|| "Too long".to_string());
+        assert_eq!(
+            rename_outcome("Sales", "Revenue", "Enter a name.", too_long),
+            RenameOutcome::Invalid("Too long".to_string())
+        );
+    }
+
+    #[test]
+    fn test_rename_style() {
+        assert_eq!(
+            rename_style(None),
+            "display: inline-block; width: 240px; max-width: 100%"
+        );
+        assert_eq!(
+            rename_style(Some("160px")),
+            "display: inline-block; width: 160px; max-width: 100%"
+        );
+    }
+
+    #[test]
+    fn test_breadcrumb_strings_fall_back_to_english() {
+        let strings = BreadcrumbStrings::resolve(&I18nContext::default());
+        assert_eq!(strings.loading_text, "Loading");
+        assert_eq!(strings.rename_aria_label, "Rename");
+        assert_eq!(strings.empty_name_text, "Enter a name.");
+        assert_eq!(strings.expand_aria_label, "Show path");
+    }
+
+    #[test]
+    fn test_breadcrumb_layout_collapses_middle_items() {
+        let widths = [200.0; 5];
+        assert_eq!(
+            breadcrumb_layout(&widths, 5, Some(1000.0)),
+            BreadcrumbLayout::Full
+        );
+        // Items before the last are kept while they fit
+        assert_eq!(
+            breadcrumb_layout(&widths, 5, Some(900.0)),
+            BreadcrumbLayout::Collapsed { hidden: 1 }
+        );
+        assert_eq!(
+            breadcrumb_layout(&widths, 5, Some(800.0)),
+            BreadcrumbLayout::Collapsed { hidden: 2 }
+        );
+        assert_eq!(
+            breadcrumb_layout(&widths, 5, Some(600.0)),
+            BreadcrumbLayout::Collapsed { hidden: 3 }
+        );
+        // Narrow containers only show the back link
+        assert_eq!(
+            breadcrumb_layout(&widths, 5, Some(400.0)),
+            BreadcrumbLayout::BackLink
+        );
+    }
+
+    #[test]
+    fn test_breadcrumb_layout_until_measured() {
+        assert_eq!(
+            breadcrumb_layout(&[], 3, Some(500.0)),
+            BreadcrumbLayout::Full
+        );
+        assert_eq!(
+            breadcrumb_layout(&[200.0; 3], 3, None),
+            BreadcrumbLayout::Full
+        );
+        // The first and last items never collapse
+        assert_eq!(
+            breadcrumb_layout(&[400.0; 2], 2, Some(500.0)),
+            BreadcrumbLayout::Full
+        );
+    }
+
     #[test]
     fn test_follow_detail_creation() {
         let detail = FollowDetail {
@@ -455,7 +1157,7 @@ mod tests {
         let event = BreadcrumbFollowEvent::new(detail.clone());
         assert_eq!(event.detail, detail);
         assert!(event.cancelable);
-        assert!(!event.default_prevented);
+        assert!(!event.default_prevented());
     }
 
     #[test]
@@ -467,10 +1169,10 @@ mod tests {
         };
 
         let mut event = BreadcrumbFollowEvent::new(detail);
-        assert!(!event.default_prevented);
+        assert!(!event.default_prevented());
 
         event.prevent_default();
-        assert!(event.default_prevented);
+        assert!(event.default_prevented());
     }
 
     #[test]
@@ -486,6 +1188,6 @@ mod tests {
 
         event.prevent_default();
         // Should not be prevented because it's not cancelable
-        assert!(!event.default_prevented);
+        assert!(!event.default_prevented());
     }
 }
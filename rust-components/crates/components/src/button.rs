@@ -5,14 +5,18 @@
 //!
 //! An interactive button element with multiple variants and states.
 
+use crate::icon::Icon;
+use crate::internal::busy::use_register_busy;
+use crate::internal::dev_warnings::{DevWarning, unknown_style_parts, use_dev_warnings};
 use crate::internal::events::FollowDetail;
 use crate::internal::styles::ButtonStyle;
 use crate::internal::{
     AnalyticsMetadata, AriaAttributes, BaseComponentProps, ClickEvent, ComponentMetadata,
-    ComponentStyles, FollowEvent, I18nStrings, NativeAttributes,
+    ComponentStyles, CustomEvent, FollowEvent, I18nStrings, LiveRegion, NativeAttributes,
+    apply_data_attributes, apply_root_attributes,
 };
 use crate::spinner::{Spinner, SpinnerSize, SpinnerVariant};
-use web_sys::MouseEvent;
+use web_sys::{Element, MouseEvent};
 use yew::prelude::*;
 
 /// Button variants
@@ -61,6 +65,32 @@ impl FormAction {
     }
 }
 
+/// Detail for the Button toggle event
+#[derive(Clone, PartialEq, Debug)]
+pub struct ButtonToggleDetail {
+    /// The requested pressed state
+    pub pressed: bool,
+}
+
+/// CSS custom properties applied to a pressed toggle button
+///
+/// The values come from the theme, so the pressed state follows light and
+/// dark mode.
+const PRESSED_STYLE_PROPERTIES: [(&str, &str); 3] = [
+    (
+        "background-color",
+        "var(--awsui-color-background-toggle-button-normal-pressed)",
+    ),
+    (
+        "border-color",
+        "var(--awsui-color-border-toggle-button-normal-pressed)",
+    ),
+    (
+        "color",
+        "var(--awsui-color-text-toggle-button-icon-pressed)",
+    ),
+];
+
 /// Icon alignment in button
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum IconAlign {
@@ -69,6 +99,57 @@ pub enum IconAlign {
     Right,
 }
 
+/// Resolves the `type` attribute, never submitting a form from a toggle button
+fn button_type(form_action: FormAction, toggle: bool) -> &'static str {
+    if toggle {
+        FormAction::None.as_str()
+    } else {
+        form_action.as_str()
+    }
+}
+
+/// Minimum width of a loading button: its width before it started loading,
+/// so that swapping in the spinner and loading text doesn't shift the layout
+fn loading_min_width(idle_width: Option<f64>) -> Option<String> {
+    idle_width
+        .filter(|width| *width > 0.0)
+        .map(|width| format!("{}px", width))
+}
+
+/// Part names accepted by `base.style`: the text and the icon
+const STYLE_PARTS: &[&str] = &["label", "icon"];
+
+/// Misuse of Button props reported in development builds
+fn dev_warnings(props: &ButtonProps) -> Vec<DevWarning> {
+    let mut warnings = Vec::new();
+
+    let icon_only = props.variant == ButtonVariant::Icon
+        || props.variant == ButtonVariant::InlineIcon
+        || (props.icon.is_some() && props.children.is_empty());
+    if icon_only && props.aria_label.is_none() {
+        warnings.push(DevWarning::new(
+            "button-icon-only-missing-label",
+            "Icon-only buttons need an `aria_label` so screen readers can announce them.",
+        ));
+    }
+
+    if props.toggle && props.href.is_none() && props.form_action == FormAction::Submit {
+        warnings.push(DevWarning::new(
+            "button-toggle-submit",
+            "`toggle` can't be combined with `FormAction::Submit`; \
+             the button is rendered with type=\"button\" instead.",
+        ));
+    }
+
+    warnings.extend(unknown_style_parts(
+        "button-unknown-style-part",
+        &props.base.style,
+        STYLE_PARTS,
+    ));
+
+    warnings
+}
+
 /// Properties for the Button component
 #[derive(Properties, PartialEq, Clone)]
 pub struct ButtonProps {
@@ -85,9 +166,16 @@ pub struct ButtonProps {
     pub disabled: bool,
 
     /// Whether the button is in a loading state
+    ///
+    /// A loading button shows a spinner and ignores clicks, but stays
+    /// focusable (`aria-disabled`) and keeps its width.
     #[prop_or_default]
     pub loading: bool,
 
+    /// Text announced to screen readers when the button starts loading
+    #[prop_or_default]
+    pub loading_text: Option<String>,
+
     /// Icon to display in the button
     #[prop_or_default]
     pub icon: Option<Html>,
@@ -144,6 +232,26 @@ pub struct ButtonProps {
     #[prop_or_default]
     pub aria_expanded: Option<bool>,
 
+    /// Whether the button is a toggle button
+    ///
+    /// Toggle buttons expose `aria-pressed` and emit `on_toggle` when clicked.
+    /// They can't be combined with `FormAction::Submit`, so set `form_action`
+    /// to `FormAction::None`.
+    #[prop_or_default]
+    pub toggle: bool,
+
+    /// Whether the toggle button is pressed (controlled)
+    #[prop_or_default]
+    pub pressed: bool,
+
+    /// Toggle event handler, called with the requested pressed state
+    #[prop_or_default]
+    pub on_toggle: Option<Callback<CustomEvent<ButtonToggleDetail>>>,
+
+    /// Name of the icon shown instead of `icon` while pressed
+    #[prop_or_default]
+    pub pressed_icon_name: Option<String>,
+
     /// Style overrides
     #[prop_or_default]
     pub style: Option<ButtonStyle>,
@@ -165,6 +273,8 @@ pub struct ButtonProps {
 ///
 /// An interactive button with various visual styles and states.
 ///
+/// Style override parts (`base.style`): `label`, `icon`.
+///
 /// # Example
 /// ```rust,ignore
 /// use cloudscape_components::{Button, ButtonVariant};
@@ -202,14 +312,49 @@ pub struct ButtonProps {
 ///         {"Learn More"}
 ///     </Button>
 /// }
+///
+/// // As an icon toggle button:
+/// html! {
+///     <Button
+///         variant={ButtonVariant::Icon}
+///         form_action={FormAction::None}
+///         toggle={true}
+///         pressed={*favorite}
+///         on_toggle={toggle_callback}
+///         icon={html! { <Icon name="star" /> }}
+///         pressed_icon_name={"star-filled"}
+///         aria_label={"Favorite"}
+///     />
+/// }
 /// ```
 #[function_component(Button)]
 pub fn button(props: &ButtonProps) -> Html {
     let _metadata = ComponentMetadata::new("Button");
+    // A BusyAnnouncer announces loading once for the app instead of the button
+    let coordinated = use_register_busy(props.loading);
+    use_dev_warnings("Button").check(|| dev_warnings(props));
+
+    // Width of the button while not loading, measured after each such render
+    let button_ref = use_node_ref();
+    let idle_width = use_mut_ref(|| None::<f64>);
+    {
+        let button_ref = button_ref.clone();
+        let idle_width = idle_width.clone();
+        use_effect_with(
+            (props.loading, props.children.clone()),
+            move |(loading, _)| {
+                if !*loading && let Some(element) = button_ref.cast::<Element>() {
+                    *idle_width.borrow_mut() = Some(element.get_bounding_client_rect().width());
+                }
+                || ()
+            },
+        );
+    }
 
     // Determine if button is interactive
     let is_disabled = props.disabled || props.loading;
     let is_link = props.href.is_some();
+    let is_pressed = props.toggle && props.pressed;
 
     // Build component styles
     let mut styles = ComponentStyles::new();
@@ -221,6 +366,21 @@ pub fn button(props: &ButtonProps) -> Html {
     }
     if props.loading {
         styles.add_class("awsui-button-loading");
+        if let Some(min_width) = loading_min_width(*idle_width.borrow()) {
+            styles.set_property("min-width", min_width);
+        }
+    }
+    if props.toggle {
+        styles.add_class("awsui-button-toggle");
+    }
+    if is_pressed {
+        styles.add_class("awsui-button-pressed");
+        // Disabled and loading styles take precedence over the pressed style
+        if !is_disabled {
+            for (name, value) in PRESSED_STYLE_PROPERTIES {
+                styles.set_property(name, value);
+            }
+        }
     }
     if props.full_width {
         styles.add_class("awsui-button-full-width");
@@ -244,7 +404,8 @@ pub fn button(props: &ButtonProps) -> Html {
     }
 
     let class = props.base.merge_classes(&styles.class_attr());
-    let style_attr = styles.style_attr();
+    let style_attr = props.base.merge_style(styles.style_attr().as_deref());
+    let icon_style = props.base.part_style("icon", None);
 
     // Create analytics metadata
     let analytics = AnalyticsMetadata::button("button", props.variant.as_str(), is_disabled);
@@ -261,6 +422,14 @@ pub fn button(props: &ButtonProps) -> Html {
     if is_disabled {
         aria.disabled = Some(true);
     }
+    if props.toggle {
+        aria.pressed = Some(props.pressed);
+    }
+
+    let icon = match (&props.pressed_icon_name, is_pressed) {
+        (Some(name), true) => Some(html! { <Icon name={name.clone()} /> }),
+        _ => props.icon.clone(),
+    };
 
     // Get external icon label from i18n
     let external_icon_label = props
@@ -281,19 +450,19 @@ pub fn button(props: &ButtonProps) -> Html {
                     />
                 </span>
             }
-            if let Some(ref icon) = props.icon {
+            if let Some(ref icon) = icon {
                 if matches!(props.icon_align, IconAlign::Left) && !props.loading {
-                    <span class="awsui-button-icon awsui-button-icon-left">
+                    <span class="awsui-button-icon awsui-button-icon-left" style={icon_style.clone()}>
                         { icon.clone() }
                     </span>
                 }
             }
-            <span class="awsui-button-content">
+            <span class="awsui-button-content" style={props.base.part_style("label", None)}>
                 { props.children.clone() }
             </span>
-            if let Some(ref icon) = props.icon {
+            if let Some(ref icon) = icon {
                 if matches!(props.icon_align, IconAlign::Right) && !props.loading {
-                    <span class="awsui-button-icon awsui-button-icon-right">
+                    <span class="awsui-button-icon awsui-button-icon-right" style={icon_style.clone()}>
                         { icon.clone() }
                     </span>
                 }
@@ -313,12 +482,31 @@ pub fn button(props: &ButtonProps) -> Html {
         let href = props.href.clone();
         let external = props.external;
         let target = props.target.clone();
+        let toggle_callback = props.toggle.then(|| props.on_toggle.clone()).flatten();
+        let pressed = props.pressed;
 
         Callback::from(move |e: MouseEvent| {
+            // Loading buttons and disabled links stay focusable, but neither
+            // submit their form nor navigate
+            if is_disabled {
+                e.prevent_default();
+                return;
+            }
+
+            // Only a consumer cancelling the event stops the native action,
+            // such as submitting the enclosing form
+            let mut prevented = false;
+
             // Fire click event
             if let Some(ref cb) = click_callback {
-                let event = ClickEvent::from_mouse_event(&e);
-                cb.emit(event);
+                prevented |= ClickEvent::from_mouse_event(&e).emit_to(cb);
+            }
+
+            // Fire toggle event for toggle buttons
+            if let Some(ref cb) = toggle_callback {
+                cb.emit(CustomEvent::new_non_cancelable(ButtonToggleDetail {
+                    pressed: !pressed,
+                }));
             }
 
             // Fire follow event for links
@@ -328,8 +516,11 @@ pub fn button(props: &ButtonProps) -> Html {
                     external,
                     target: target.clone(),
                 };
-                let event = FollowEvent::new(follow_detail);
-                cb.emit(event);
+                prevented |= FollowEvent::new(follow_detail).emit_to(cb);
+            }
+
+            if prevented {
+                e.prevent_default();
             }
         })
     };
@@ -339,6 +530,7 @@ pub fn button(props: &ButtonProps) -> Html {
         // Render as anchor
         html! {
             <a
+                ref={button_ref}
                 id={props.base.id.clone()}
                 class={class}
                 style={style_attr}
@@ -350,6 +542,7 @@ pub fn button(props: &ButtonProps) -> Html {
                 aria-label={aria.label.clone()}
                 aria-expanded={aria.expanded.map(|e| e.to_string())}
                 aria-disabled={if is_disabled { Some("true") } else { None }}
+                aria-pressed={aria.pressed.map(|p| p.to_string())}
                 data-analytics-metadata={analytics_attr}
             >
                 { button_content }
@@ -359,14 +552,17 @@ pub fn button(props: &ButtonProps) -> Html {
         // Render as button
         html! {
             <button
+                ref={button_ref}
                 id={props.base.id.clone()}
                 class={class}
                 style={style_attr}
-                type={props.form_action.as_str()}
-                disabled={is_disabled}
+                type={button_type(props.form_action, props.toggle)}
+                disabled={props.disabled}
                 onclick={on_click}
                 aria-label={aria.label.clone()}
                 aria-expanded={aria.expanded.map(|e| e.to_string())}
+                aria-disabled={(props.loading && !props.disabled).then_some("true")}
+                aria-pressed={aria.pressed.map(|p| p.to_string())}
                 data-analytics-metadata={analytics_attr}
             >
                 { button_content }
@@ -374,6 +570,27 @@ pub fn button(props: &ButtonProps) -> Html {
         }
     };
 
+    let button_element = apply_root_attributes(button_element, &props.base);
+    let button_element = match &props.native_attributes {
+        Some(native) => apply_data_attributes(button_element, native.data_attrs()),
+        None => button_element,
+    };
+
+    // Announce the loading text; the live region is rendered up front so
+    // that the change is picked up
+    let button_element = match &props.loading_text {
+        Some(loading_text) if !coordinated => html! {
+            <>
+                { button_element }
+                <LiveRegion
+                    message={if props.loading { loading_text.clone() } else { String::new() }}
+                    delay_ms={0}
+                />
+            </>
+        },
+        _ => button_element,
+    };
+
     // Wrap with tooltip if disabled reason is provided
     if let Some(ref reason) = props.disabled_reason {
         html! {
@@ -385,3 +602,87 @@ pub fn button(props: &ButtonProps) -> Html {
         button_element
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::internal::StyleOverride;
+    use crate::internal::dev_warnings::DevWarnings;
+
+    #[test]
+    fn test_button_type() {
+        assert_eq!(button_type(FormAction::Submit, false), "submit");
+        assert_eq!(button_type(FormAction::None, false), "button");
+        assert_eq!(button_type(FormAction::Submit, true), "button");
+        assert_eq!(button_type(FormAction::None, true), "button");
+    }
+
+    #[test]
+    fn test_loading_min_width() {
+        assert_eq!(loading_min_width(Some(96.5)), Some("96.5px".to_string()));
+        // Buttons are only held at their width once measured
+        assert_eq!(loading_min_width(None), None);
+        assert_eq!(loading_min_width(Some(0.0)), None);
+    }
+
+    #[test]
+    fn test_pressed_style_uses_toggle_tokens() {
+        let mut styles = ComponentStyles::new();
+        for (name, value) in PRESSED_STYLE_PROPERTIES {
+            styles.set_property(name, value);
+        }
+        let style = styles.style_attr().unwrap();
+
+        assert!(style.contains("--awsui-color-background-toggle-button-normal-pressed"));
+        assert!(style.contains("--awsui-color-border-toggle-button-normal-pressed"));
+        assert!(style.contains("--awsui-color-text-toggle-button-icon-pressed"));
+    }
+
+    #[test]
+    fn test_dev_warning_icon_only_without_label() {
+        let props = yew::props!(ButtonProps {
+            variant: ButtonVariant::Icon,
+        });
+        let warnings = DevWarnings::new("Button");
+        assert_eq!(warnings.check(|| dev_warnings(&props)), 1);
+        assert_eq!(warnings.check(|| dev_warnings(&props)), 0);
+
+        let labelled = yew::props!(ButtonProps {
+            variant: ButtonVariant::Icon,
+            aria_label: "Settings".to_string(),
+        });
+        assert!(dev_warnings(&labelled).is_empty());
+    }
+
+    #[test]
+    fn test_dev_warning_toggle_submit() {
+        let props = yew::props!(ButtonProps {
+            toggle: true,
+            form_action: FormAction::Submit,
+        });
+        let codes: Vec<_> = dev_warnings(&props).iter().map(|w| w.code).collect();
+        assert_eq!(codes, vec!["button-toggle-submit"]);
+    }
+
+    #[test]
+    fn test_dev_warning_unknown_style_part() {
+        let props = yew::props!(ButtonProps {
+            base: BaseComponentProps {
+                style: StyleOverride::new()
+                    .with_part("label", "font-weight: bold")
+                    .with_part("text", "color: red"),
+                ..Default::default()
+            },
+        });
+        let warnings = dev_warnings(&props);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, "button-unknown-style-part");
+        assert!(warnings[0].message.contains("text"));
+    }
+
+    #[test]
+    fn test_button_toggle_detail() {
+        let detail = ButtonToggleDetail { pressed: true };
+        assert!(detail.pressed);
+    }
+}
@@ -7,13 +7,14 @@
 //! Provides a list of actions or options that the user can select from.
 
 use crate::button::ButtonVariant;
+use crate::internal::dropdown::use_dropdown_transition;
+use crate::internal::events::FollowDetail;
+use crate::internal::menu::{focus_menu_entry, next_menu_entry, toggle_expanded, use_menu_dismiss};
 use crate::internal::{
-    AnalyticsMetadata, AriaAttributes, BaseComponentProps, ClassBuilder, ComponentMetadata,
-    CustomEvent,
+    AnalyticsMetadata, AriaAttributes, BaseComponentProps, ClassBuilder, ClickEvent,
+    ComponentMetadata, CustomEvent, FollowEvent, apply_root_attributes,
 };
 use crate::spinner::{Spinner, SpinnerSize, SpinnerVariant};
-use wasm_bindgen::JsCast;
-use wasm_bindgen::prelude::*;
 use web_sys::MouseEvent;
 use yew::prelude::*;
 
@@ -28,8 +29,12 @@ pub struct ButtonDropdownItem {
     pub disabled: bool,
     /// Whether this item represents an external link
     pub external: bool,
-    /// Optional URL for link-style items
+    /// Optional URL; items with an href render as links
     pub href: Option<String>,
+    /// Target attribute for link items
+    ///
+    /// External items open in a new tab when no target is set.
+    pub target: Option<String>,
     /// Optional icon to display before the text
     pub icon: Option<Html>,
 }
@@ -51,6 +56,7 @@ impl ButtonDropdownItem {
             disabled: false,
             external: false,
             href: None,
+            target: None,
             icon: None,
         }
     }
@@ -73,6 +79,19 @@ impl ButtonDropdownItem {
         self
     }
 
+    /// Sets the target attribute for this item
+    pub fn with_target(mut self, target: impl Into<String>) -> Self {
+        self.target = Some(target.into());
+        self
+    }
+
+    /// Returns the target attribute to render for link items
+    fn link_target(&self) -> Option<String> {
+        self.target
+            .clone()
+            .or_else(|| self.external.then(|| "_blank".to_string()))
+    }
+
     /// Sets an icon for this item
     pub fn with_icon(mut self, icon: Html) -> Self {
         self.icon = Some(icon);
@@ -134,6 +153,56 @@ pub struct ButtonDropdownItemClickDetail {
     pub href: Option<String>,
 }
 
+impl ButtonDropdownItemClickDetail {
+    /// Whether the clicked item was a link
+    pub fn has_href(&self) -> bool {
+        self.href.is_some()
+    }
+}
+
+/// Entry of the menu that the arrow keys move through
+#[derive(Debug, Clone, PartialEq)]
+enum MenuEntry {
+    /// Header of an expandable group, by group index
+    GroupHeader(usize),
+    /// Enabled item, by id
+    Item(String),
+}
+
+/// Lists the focusable entries of the menu in order
+///
+/// Disabled items are skipped, and so are the items of collapsed groups.
+fn menu_entries(
+    items: &[ButtonDropdownItem],
+    item_groups: &[ButtonDropdownItemGroup],
+    expandable_groups: bool,
+    expanded_groups: &[usize],
+) -> Vec<MenuEntry> {
+    let enabled = |items: &[ButtonDropdownItem]| -> Vec<MenuEntry> {
+        items
+            .iter()
+            .filter(|item| !item.disabled)
+            .map(|item| MenuEntry::Item(item.id.clone()))
+            .collect()
+    };
+
+    if item_groups.is_empty() {
+        return enabled(items);
+    }
+
+    let mut entries = Vec::new();
+    for (group_index, group) in item_groups.iter().enumerate() {
+        let expandable = expandable_groups && group.text.is_some();
+        if expandable {
+            entries.push(MenuEntry::GroupHeader(group_index));
+        }
+        if !expandable || expanded_groups.contains(&group_index) {
+            entries.extend(enabled(&group.items));
+        }
+    }
+    entries
+}
+
 /// Properties for the ButtonDropdown component
 #[derive(Properties, PartialEq, Clone)]
 pub struct ButtonDropdownProps {
@@ -179,6 +248,22 @@ pub struct ButtonDropdownProps {
     #[prop_or_default]
     pub on_item_click: Option<Callback<CustomEvent<ButtonDropdownItemClickDetail>>>,
 
+    /// Follow event handler for link items
+    ///
+    /// Fired for plain left clicks (and Enter) on items with an `href`.
+    /// Call `prevent_default()` on the event to stop the browser from
+    /// following the link, e.g. to implement client-side routing. Clicks
+    /// with modifier keys are left to the browser.
+    #[prop_or_default]
+    pub on_item_follow: Option<Callback<FollowEvent>>,
+
+    /// Whether group headers expand and collapse their group
+    ///
+    /// Groups with a header start collapsed; groups without one are always
+    /// shown.
+    #[prop_or_default]
+    pub expandable_groups: bool,
+
     /// ARIA label for the button
     #[prop_or_default]
     pub aria_label: Option<String>,
@@ -261,92 +346,224 @@ pub struct ButtonDropdownProps {
 pub fn button_dropdown(props: &ButtonDropdownProps) -> Html {
     let _metadata = ComponentMetadata::new("ButtonDropdown");
     let is_open = use_state(|| false);
+    // Index of the focused menu entry
+    let highlighted = use_state(|| None::<usize>);
+    let expanded_groups = use_state(Vec::<usize>::new);
     let dropdown_ref = use_node_ref();
+    let trigger_ref = use_node_ref();
+    let menu_ref = use_node_ref();
+
+    // Generate unique IDs for ARIA
+    let control_id = use_state(|| {
+        use std::sync::atomic::{AtomicU32, Ordering};
+        static COUNTER: AtomicU32 = AtomicU32::new(0);
+        let id = COUNTER.fetch_add(1, Ordering::Relaxed);
+        format!("awsui-button-dropdown-{}", id)
+    });
+    let trigger_id = format!("{}-trigger", *control_id);
 
     // Determine if button is interactive
     let is_disabled = props.disabled || props.loading;
 
+    let entries = menu_entries(
+        &props.items,
+        &props.item_groups,
+        props.expandable_groups,
+        &expanded_groups,
+    );
+    let entries_count = entries.len();
+
+    // Open the menu, focusing an entry when opened with the keyboard
+    let open = {
+        let is_open = is_open.clone();
+        let highlighted = highlighted.clone();
+        Callback::from(move |entry: Option<usize>| {
+            is_open.set(true);
+            highlighted.set(entry);
+        })
+    };
+
+    // Close the menu, returning focus to the trigger unless it moved elsewhere
+    let close = {
+        let is_open = is_open.clone();
+        let highlighted = highlighted.clone();
+        let trigger_ref = trigger_ref.clone();
+        Callback::from(move |return_focus: bool| {
+            is_open.set(false);
+            highlighted.set(None);
+            if return_focus && let Some(trigger) = trigger_ref.cast::<web_sys::HtmlElement>() {
+                let _ = trigger.focus();
+            }
+        })
+    };
+
+    // Focus the highlighted entry
+    {
+        let menu_ref = menu_ref.clone();
+        use_effect_with((*is_open, *highlighted), move |(open, highlighted)| {
+            if *open && let Some(index) = highlighted {
+                focus_menu_entry(&menu_ref, *index);
+            }
+        });
+    }
+
     // Toggle dropdown open/closed
     let on_toggle = {
         let is_open = is_open.clone();
+        let open = open.clone();
+        let close = close.clone();
         let disabled = is_disabled;
 
         Callback::from(move |e: MouseEvent| {
             e.prevent_default();
             e.stop_propagation();
-            if !disabled {
-                is_open.set(!*is_open);
+            if disabled {
+                return;
+            }
+            if *is_open {
+                close.emit(false);
+            } else {
+                // Clicks from Enter or Space have no click count
+                open.emit((e.detail() == 0).then_some(0));
+            }
+        })
+    };
+
+    // Arrow keys on the trigger open the menu on the first or last entry
+    let on_trigger_key_down = {
+        let is_open = is_open.clone();
+        let open = open.clone();
+        let close = close.clone();
+        let disabled = is_disabled;
+
+        Callback::from(move |e: KeyboardEvent| {
+            if disabled {
+                return;
+            }
+            match e.key().as_str() {
+                "ArrowDown" | "ArrowUp" if entries_count > 0 => {
+                    e.prevent_default();
+                    let entry = if e.key() == "ArrowDown" {
+                        0
+                    } else {
+                        entries_count - 1
+                    };
+                    open.emit(Some(entry));
+                }
+                "Escape" if *is_open => {
+                    e.prevent_default();
+                    e.stop_propagation();
+                    close.emit(true);
+                }
+                _ => {}
             }
         })
     };
 
+    // Keyboard navigation within the open menu
+    let on_menu_key_down = {
+        let highlighted = highlighted.clone();
+        let close = close.clone();
+
+        Callback::from(move |e: KeyboardEvent| match e.key().as_str() {
+            "Escape" => {
+                e.prevent_default();
+                e.stop_propagation();
+                close.emit(true);
+            }
+            "Tab" => close.emit(false),
+            key => {
+                if let Some(entry) = next_menu_entry(*highlighted, key, entries_count) {
+                    e.prevent_default();
+                    highlighted.set(Some(entry));
+                }
+            }
+        })
+    };
+
+    // Expand or collapse a group of an expandable menu
+    let on_group_toggle = {
+        let expanded_groups = expanded_groups.clone();
+        Callback::from(move |group_index: usize| {
+            expanded_groups.set(toggle_expanded(&expanded_groups, group_index));
+        })
+    };
+
     // Handle item click
     let on_item_click_handler = {
         let on_item_click = props.on_item_click.clone();
-        let is_open = is_open.clone();
+        let close = close.clone();
 
         Callback::from(move |item: ButtonDropdownItem| {
-            is_open.set(false);
+            close.emit(true);
+
+            if !item.disabled
+                && let Some(callback) = &on_item_click
+            {
+                let detail = ButtonDropdownItemClickDetail {
+                    id: item.id.clone(),
+                    external: item.external,
+                    href: item.href.clone(),
+                };
+                callback.emit(CustomEvent::new_non_cancelable(detail));
+            }
+        })
+    };
+
+    // Handle link item click; the browser performs the navigation itself
+    let on_link_click_handler = {
+        let on_item_click = props.on_item_click.clone();
+        let on_item_follow = props.on_item_follow.clone();
+        let close = close.clone();
+
+        Callback::from(move |(e, item): (MouseEvent, ButtonDropdownItem)| {
+            e.stop_propagation();
+            if item.disabled {
+                e.prevent_default();
+                return;
+            }
 
-            if !item.disabled {
-                if let Some(callback) = &on_item_click {
-                    let detail = ButtonDropdownItemClickDetail {
+            if let Some(callback) = &on_item_click {
+                callback.emit(CustomEvent::new_non_cancelable(
+                    ButtonDropdownItemClickDetail {
                         id: item.id.clone(),
                         external: item.external,
                         href: item.href.clone(),
-                    };
-                    callback.emit(CustomEvent::new_non_cancelable(detail));
-                }
+                    },
+                ));
+            }
 
-                // If item has href, navigate to it
-                if let Some(href) = &item.href
-                    && let Some(window) = web_sys::window()
-                {
-                    if item.external {
-                        let _ = window.open_with_url_and_target(href, "_blank");
-                    } else {
-                        let _ = window.location().set_href(href);
-                    }
+            // Modifier clicks (new tab, new window) keep the menu open
+            if !ClickEvent::from_mouse_event(&e).is_plain_left_click() {
+                return;
+            }
+
+            if let Some(callback) = &on_item_follow {
+                let event = FollowEvent::new(FollowDetail {
+                    href: item.href.clone(),
+                    external: item.external,
+                    target: item.link_target(),
+                });
+                if event.emit_to(callback) {
+                    e.prevent_default();
                 }
             }
+            close.emit(true);
         })
     };
 
-    // Close dropdown when clicking outside
-    {
-        let is_open = is_open.clone();
-        let dropdown_ref = dropdown_ref.clone();
-
-        use_effect_with(*is_open, move |open| {
-            if *open {
-                let is_open = is_open.clone();
-                let dropdown_ref = dropdown_ref.clone();
-
-                let closure = Closure::wrap(Box::new(move |e: web_sys::Event| {
-                    if let Some(target) = e.target()
-                        && let Some(element) = dropdown_ref.cast::<web_sys::HtmlElement>()
-                        && let Some(target_element) = target.dyn_ref::<web_sys::Node>()
-                        && !element.contains(Some(target_element))
-                    {
-                        is_open.set(false);
-                    }
-                }) as Box<dyn FnMut(_)>);
-
-                if let Some(window) = web_sys::window()
-                    && let Some(document) = window.document()
-                {
-                    let _ = document.add_event_listener_with_callback(
-                        "click",
-                        closure.as_ref().unchecked_ref(),
-                    );
-                }
-
-                // Keep closure alive
-                closure.forget();
+    // Space activates link items instead of scrolling the page
+    let on_link_key_down = Callback::from(|e: KeyboardEvent| {
+        if e.key() == " " {
+            e.prevent_default();
+            if let Some(anchor) = e.target_dyn_into::<web_sys::HtmlElement>() {
+                anchor.click();
             }
-            || ()
-        });
-    }
+        }
+    });
+
+    // Close dropdown when clicking outside
+    use_menu_dismiss(*is_open, dropdown_ref.clone(), close.clone());
 
     // Build button classes
     let button_classes = ClassBuilder::new()
@@ -360,6 +577,8 @@ pub fn button_dropdown(props: &ButtonDropdownProps) -> Html {
         .add_if(props.expanded, "awsui-button-dropdown-trigger-expanded")
         .add_if(*is_open, "awsui-button-dropdown-trigger-open");
 
+    let transition = use_dropdown_transition(*is_open, dropdown_ref.clone());
+
     // Build dropdown classes
     let dropdown_classes = ClassBuilder::new()
         .add("awsui-button-dropdown-content")
@@ -380,13 +599,67 @@ pub fn button_dropdown(props: &ButtonDropdownProps) -> Html {
         aria.disabled = Some(true);
     }
 
+    let entry_index = |entry: &MenuEntry| entries.iter().position(|e| e == entry);
+
     // Render all items (either from items or item_groups)
     let render_items = {
         let on_item_click = on_item_click_handler.clone();
+        let on_link_click = on_link_click_handler.clone();
 
         move |items: &[ButtonDropdownItem]| {
             items.iter().map(|item| {
                 let item_clone = item.clone();
+                let item_classes = ClassBuilder::new()
+                    .add("awsui-button-dropdown-item")
+                    .add_if(item.disabled, "awsui-button-dropdown-item-disabled")
+                    .add_if(item.external, "awsui-button-dropdown-item-external");
+                let index = entry_index(&MenuEntry::Item(item.id.clone()));
+
+                if let Some(ref href) = item.href {
+                    let on_click = {
+                        let item = item.clone();
+                        let on_link_click = on_link_click.clone();
+                        Callback::from(move |e: MouseEvent| {
+                            on_link_click.emit((e, item.clone()));
+                        })
+                    };
+
+                    return html! {
+                        <li
+                            key={item_clone.id.clone()}
+                            class={item_classes.build()}
+                            role="none"
+                        >
+                            <a
+                                class="awsui-button-dropdown-item-link"
+                                role="menuitem"
+                                tabindex="-1"
+                                data-menu-index={index.map(|index| index.to_string())}
+                                href={if item.disabled { None } else { Some(href.clone()) }}
+                                target={item.link_target()}
+                                rel={item.external.then_some("noopener noreferrer")}
+                                aria-disabled={item.disabled.to_string()}
+                                onclick={on_click}
+                                onkeydown={on_link_key_down.clone()}
+                            >
+                                if let Some(ref icon) = item.icon {
+                                    <span class="awsui-button-dropdown-item-icon">
+                                        { icon.clone() }
+                                    </span>
+                                }
+                                <span class="awsui-button-dropdown-item-text">
+                                    { &item.text }
+                                </span>
+                                if item.external {
+                                    <span class="awsui-button-dropdown-item-external-icon" aria-label="(opens in a new tab)">
+                                        {"↗"}
+                                    </span>
+                                }
+                            </a>
+                        </li>
+                    };
+                }
+
                 let on_click = {
                     let item = item.clone();
                     let on_item_click = on_item_click.clone();
@@ -397,22 +670,20 @@ pub fn button_dropdown(props: &ButtonDropdownProps) -> Html {
                     })
                 };
 
-                let item_classes = ClassBuilder::new()
-                    .add("awsui-button-dropdown-item")
-                    .add_if(item.disabled, "awsui-button-dropdown-item-disabled")
-                    .add_if(item.external, "awsui-button-dropdown-item-external");
-
                 html! {
                     <li
                         key={item_clone.id.clone()}
                         class={item_classes.build()}
-                        role="menuitem"
-                        aria-disabled={item.disabled.to_string()}
+                        role="none"
                     >
                         <button
                             type="button"
                             class="awsui-button-dropdown-item-button"
+                            role="menuitem"
+                            tabindex="-1"
+                            data-menu-index={index.map(|index| index.to_string())}
                             disabled={item.disabled}
+                            aria-disabled={item.disabled.to_string()}
                             onclick={on_click}
                         >
                             if let Some(ref icon) = item.icon {
@@ -423,11 +694,6 @@ pub fn button_dropdown(props: &ButtonDropdownProps) -> Html {
                             <span class="awsui-button-dropdown-item-text">
                                 { &item.text }
                             </span>
-                            if item.external && item.href.is_some() {
-                                <span class="awsui-button-dropdown-item-external-icon" aria-label="(opens in a new tab)">
-                                    {"↗"}
-                                </span>
-                            }
                         </button>
                     </li>
                 }
@@ -437,37 +703,75 @@ pub fn button_dropdown(props: &ButtonDropdownProps) -> Html {
 
     // Build dropdown content
     let dropdown_content = if !props.item_groups.is_empty() {
-        // Render grouped items
-        html! {
-            <>
-                {
-                    props.item_groups.iter().enumerate().map(|(index, group)| {
-                        html! {
-                            <div key={index} class="awsui-button-dropdown-group">
-                                if let Some(ref text) = group.text {
-                                    <div class="awsui-button-dropdown-group-header">
-                                        { text }
-                                    </div>
-                                }
-                                <ul class="awsui-button-dropdown-items" role="menu">
-                                    { render_items(&group.items) }
-                                </ul>
+        // Render grouped items, separated from each other
+        props.item_groups.iter().enumerate().flat_map(|(group_index, group)| {
+            let header_id = format!("{}-group-{}", *control_id, group_index);
+            let expandable = props.expandable_groups && group.text.is_some();
+            let expanded = !expandable || expanded_groups.contains(&group_index);
+            let on_header_click = {
+                let on_group_toggle = on_group_toggle.clone();
+                Callback::from(move |e: MouseEvent| {
+                    e.stop_propagation();
+                    on_group_toggle.emit(group_index);
+                })
+            };
+            let header_classes = ClassBuilder::new()
+                .add("awsui-button-dropdown-group-header")
+                .add_if(expandable, "awsui-button-dropdown-group-header-expandable")
+                .add_if(expandable && expanded, "awsui-button-dropdown-group-header-expanded");
+
+            let separator = (group_index > 0).then(|| html! {
+                <li
+                    key={format!("separator-{}", group_index)}
+                    class="awsui-button-dropdown-separator"
+                    role="separator"
+                />
+            });
+            let group_node = html! {
+                <li key={format!("group-{}", group_index)} class="awsui-button-dropdown-group" role="none">
+                    if let Some(ref text) = group.text {
+                        if expandable {
+                            <button
+                                type="button"
+                                id={header_id.clone()}
+                                class={header_classes.build()}
+                                role="menuitem"
+                                tabindex="-1"
+                                data-menu-index={entry_index(&MenuEntry::GroupHeader(group_index))
+                                    .map(|index| index.to_string())}
+                                aria-expanded={expanded.to_string()}
+                                onclick={on_header_click}
+                            >
+                                <span class="awsui-button-dropdown-group-header-text">{ text }</span>
+                                <span class="awsui-button-dropdown-group-header-icon" aria-hidden="true">
+                                    { if expanded { "▲" } else { "▼" } }
+                                </span>
+                            </button>
+                        } else {
+                            <div id={header_id.clone()} class={header_classes.build()}>
+                                { text }
                             </div>
                         }
-                    }).collect::<Html>()
-                }
-            </>
-        }
+                    }
+                    if expanded {
+                        <ul
+                            class="awsui-button-dropdown-group-items"
+                            role="group"
+                            aria-labelledby={group.text.is_some().then(|| header_id.clone())}
+                        >
+                            { render_items(&group.items) }
+                        </ul>
+                    }
+                </li>
+            };
+            separator.into_iter().chain(std::iter::once(group_node))
+        }).collect::<Html>()
     } else {
         // Render flat items
-        html! {
-            <ul class="awsui-button-dropdown-items" role="menu">
-                { render_items(&props.items) }
-            </ul>
-        }
+        render_items(&props.items)
     };
 
-    html! {
+    let root = html! {
         <div
             ref={dropdown_ref}
             class={props.base.merge_classes("awsui-button-dropdown")}
@@ -476,12 +780,16 @@ pub fn button_dropdown(props: &ButtonDropdownProps) -> Html {
         >
             // Trigger button
             <button
+                ref={trigger_ref}
                 type="button"
+                id={trigger_id.clone()}
                 class={button_classes.build()}
                 disabled={is_disabled}
                 onclick={on_toggle}
+                onkeydown={on_trigger_key_down}
                 aria-expanded={is_open.to_string()}
                 aria-haspopup="menu"
+                aria-controls={is_open.then(|| format!("{}-menu", *control_id))}
                 aria-label={aria.label.clone()}
             >
                 if props.loading {
@@ -508,13 +816,23 @@ pub fn button_dropdown(props: &ButtonDropdownProps) -> Html {
             </button>
 
             // Dropdown menu
-            if *is_open {
-                <div class={dropdown_classes.build()}>
-                    { dropdown_content }
+            if transition.mounted() {
+                <div class={dropdown_classes.build()} style={transition.style()}>
+                    <ul
+                        ref={menu_ref}
+                        id={format!("{}-menu", *control_id)}
+                        class="awsui-button-dropdown-items"
+                        role="menu"
+                        aria-labelledby={trigger_id}
+                        onkeydown={on_menu_key_down}
+                    >
+                        { dropdown_content }
+                    </ul>
                 </div>
             }
         </div>
-    }
+    };
+    apply_root_attributes(root, &props.base)
 }
 
 #[cfg(test)]
@@ -646,4 +964,77 @@ mod tests {
             assert!(!variant.as_str().is_empty());
         }
     }
+
+    #[test]
+    fn test_button_dropdown_item_link_target() {
+        let internal = ButtonDropdownItem::new("settings", "Settings").with_href("/settings");
+        assert_eq!(internal.link_target(), None);
+
+        let external = ButtonDropdownItem::new("docs", "Docs")
+            .with_href("https://example.com")
+            .with_external(true);
+        assert_eq!(external.link_target(), Some("_blank".to_string()));
+
+        let framed = external.with_target("docs-frame");
+        assert_eq!(framed.link_target(), Some("docs-frame".to_string()));
+    }
+
+    #[test]
+    fn test_button_dropdown_item_click_detail_has_href() {
+        let link = ButtonDropdownItemClickDetail {
+            id: "settings".to_string(),
+            external: false,
+            href: Some("/settings".to_string()),
+        };
+        let action = ButtonDropdownItemClickDetail {
+            id: "delete".to_string(),
+            external: false,
+            href: None,
+        };
+
+        assert!(link.has_href());
+        assert!(!action.has_href());
+    }
+
+    #[test]
+    fn test_menu_entries_skip_disabled_items() {
+        let items = vec![
+            ButtonDropdownItem::new("a", "A"),
+            ButtonDropdownItem::new("b", "B").with_disabled(true),
+            ButtonDropdownItem::new("c", "C"),
+        ];
+        assert_eq!(
+            menu_entries(&items, &[], false, &[]),
+            vec![
+                MenuEntry::Item("a".to_string()),
+                MenuEntry::Item("c".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_menu_entries_of_expandable_groups() {
+        let groups = vec![
+            ButtonDropdownItemGroup::new()
+                .with_text("Edit")
+                .add_item(ButtonDropdownItem::new("copy", "Copy")),
+            ButtonDropdownItemGroup::new()
+                .with_text("Danger")
+                .add_item(ButtonDropdownItem::new("delete", "Delete")),
+            ButtonDropdownItemGroup::new().add_item(ButtonDropdownItem::new("help", "Help")),
+        ];
+
+        // Headerless groups are always shown
+        assert_eq!(
+            menu_entries(&[], &groups, true, &[1]),
+            vec![
+                MenuEntry::GroupHeader(0),
+                MenuEntry::GroupHeader(1),
+                MenuEntry::Item("delete".to_string()),
+                MenuEntry::Item("help".to_string()),
+            ]
+        );
+        // Without expandable groups, headers are labels only
+        assert_eq!(menu_entries(&[], &groups, false, &[]).len(), 3);
+    }
 }
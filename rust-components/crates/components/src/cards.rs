@@ -5,11 +5,130 @@
 //!
 //! The Cards component provides a responsive grid layout for displaying multiple cards
 //! with support for selection, loading states, empty states, and customizable rendering.
-
-use crate::internal::{BaseComponentProps, ClassBuilder, ComponentMetadata, CustomEvent};
-use web_sys::MouseEvent;
+//!
+//! The `header`, `filter`, `pagination` and `empty` slots follow the Table
+//! layout, so a list page can switch between a table and cards views with
+//! the same chrome.
+
+use crate::container::MediaPosition;
+use crate::internal::events::is_interactive_target;
+use crate::internal::{
+    BaseComponentProps, ClassBuilder, ClickEvent, ComponentMetadata, CustomEvent,
+    apply_root_attributes,
+};
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement, KeyboardEvent, MouseEvent};
 use yew::prelude::*;
 
+/// Card elements that handle their own clicks, besides the common
+/// interactive elements
+///
+/// Clicks on these don't select the card or follow the card link.
+const CARD_INTERACTIVE_SELECTOR: &str = "[tabindex], .awsui-cards-card-actions";
+
+/// Selector of the link covering the card
+const CARD_LINK_SELECTOR: &str = ".awsui-cards-card-link";
+
+/// Media shown at the top or side of each card
+#[derive(Clone)]
+pub struct CardMedia<T: Clone + PartialEq + 'static> {
+    /// Function to render the media, such as an image or illustration
+    pub content: fn(&T) -> Html,
+    /// Position of the media within the card
+    pub position: MediaPosition,
+    /// Width of the media when positioned at the side (CSS value)
+    pub width: Option<String>,
+    /// Height of the media when positioned at the top (CSS value)
+    pub height: Option<String>,
+    /// Aspect ratio of the media box, such as `"16 / 9"`
+    pub aspect_ratio: Option<String>,
+    /// Whether the browser may skip rendering the media until the card
+    /// approaches the viewport
+    pub lazy: bool,
+}
+
+impl<T: Clone + PartialEq + 'static> std::fmt::Debug for CardMedia<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CardMedia")
+            .field("content", &"<fn>")
+            .field("position", &self.position)
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("aspect_ratio", &self.aspect_ratio)
+            .field("lazy", &self.lazy)
+            .finish()
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> CardMedia<T> {
+    /// Creates media rendered at the top of the card
+    pub fn new(content: fn(&T) -> Html) -> Self {
+        Self {
+            content,
+            position: MediaPosition::Top,
+            width: None,
+            height: None,
+            aspect_ratio: None,
+            lazy: false,
+        }
+    }
+
+    /// Sets the media position
+    pub fn with_position(mut self, position: MediaPosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Sets the media width (used when positioned at the side)
+    pub fn with_width(mut self, width: impl Into<String>) -> Self {
+        self.width = Some(width.into());
+        self
+    }
+
+    /// Sets the media height (used when positioned at the top)
+    pub fn with_height(mut self, height: impl Into<String>) -> Self {
+        self.height = Some(height.into());
+        self
+    }
+
+    /// Sets the aspect ratio of the media box
+    pub fn with_aspect_ratio(mut self, aspect_ratio: impl Into<String>) -> Self {
+        self.aspect_ratio = Some(aspect_ratio.into());
+        self
+    }
+
+    /// Sets whether rendering of the media may be deferred
+    pub fn with_lazy(mut self, lazy: bool) -> Self {
+        self.lazy = lazy;
+        self
+    }
+
+    /// Builds the inline style of the media box
+    fn style(&self) -> Option<String> {
+        let mut style_parts = Vec::new();
+
+        if self.position == MediaPosition::Side {
+            if let Some(ref width) = self.width {
+                style_parts.push(format!("width: {}", width));
+            }
+        } else if let Some(ref height) = self.height {
+            style_parts.push(format!("height: {}", height));
+        }
+        if let Some(ref aspect_ratio) = self.aspect_ratio {
+            style_parts.push(format!("aspect-ratio: {}; overflow: hidden", aspect_ratio));
+        }
+        if self.lazy {
+            style_parts.push("content-visibility: auto".to_string());
+        }
+
+        if style_parts.is_empty() {
+            None
+        } else {
+            Some(style_parts.join("; "))
+        }
+    }
+}
+
 /// Definition of how a card should be rendered
 ///
 /// A card definition specifies the header and sections that compose each card.
@@ -21,6 +140,17 @@ pub struct CardDefinition<T: Clone + PartialEq + 'static> {
     pub header: fn(&T) -> Html,
     /// Functions to render card sections (content areas below header)
     pub sections: Vec<fn(&T) -> Html>,
+    /// Function to render per-card actions in the card header, such as a
+    /// ButtonDropdown; clicks on actions don't select the card
+    pub actions: Option<fn(&T) -> Html>,
+    /// Media shown at the top or side of the card
+    pub media: Option<CardMedia<T>>,
+    /// Function returning the URL the whole card links to
+    ///
+    /// The header is rendered as the link, so the header function should
+    /// not render a link itself. Clicks elsewhere on the card follow the
+    /// link, except on actions and the selection control.
+    pub card_href: Option<fn(&T) -> String>,
 }
 
 impl<T: Clone + PartialEq + 'static> std::fmt::Debug for CardDefinition<T> {
@@ -28,6 +158,9 @@ impl<T: Clone + PartialEq + 'static> std::fmt::Debug for CardDefinition<T> {
         f.debug_struct("CardDefinition")
             .field("header", &"<fn>")
             .field("sections", &format!("<{} sections>", self.sections.len()))
+            .field("actions", &self.actions.map(|_| "<fn>"))
+            .field("media", &self.media)
+            .field("card_href", &self.card_href.map(|_| "<fn>"))
             .finish()
     }
 }
@@ -56,6 +189,9 @@ impl<T: Clone + PartialEq + 'static> CardDefinition<T> {
         Self {
             header,
             sections: Vec::new(),
+            actions: None,
+            media: None,
+            card_href: None,
         }
     }
 
@@ -84,12 +220,50 @@ impl<T: Clone + PartialEq + 'static> CardDefinition<T> {
         self.sections.push(section);
         self
     }
+
+    /// Sets the function rendering per-card actions in the card header
+    pub fn with_actions(mut self, actions: fn(&T) -> Html) -> Self {
+        self.actions = Some(actions);
+        self
+    }
+
+    /// Sets the media shown in each card
+    pub fn with_media(mut self, media: CardMedia<T>) -> Self {
+        self.media = Some(media);
+        self
+    }
+
+    /// Makes each card a link to the URL returned by `card_href`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cloudscape_components::CardDefinition;
+    /// use yew::prelude::*;
+    ///
+    /// #[derive(Clone, PartialEq)]
+    /// struct Item {
+    ///     id: u32,
+    ///     name: String,
+    /// }
+    ///
+    /// let card_def = CardDefinition::new(|item: &Item| html! { {&item.name} })
+    ///     .with_card_href(|item: &Item| format!("/items/{}", item.id));
+    /// ```
+    pub fn with_card_href(mut self, card_href: fn(&T) -> String) -> Self {
+        self.card_href = Some(card_href);
+        self
+    }
 }
 
 // Manual PartialEq implementation that doesn't compare function pointers
 impl<T: Clone + PartialEq + 'static> PartialEq for CardDefinition<T> {
     fn eq(&self, other: &Self) -> bool {
         self.sections.len() == other.sections.len()
+            && self.actions.is_some() == other.actions.is_some()
+            && self.card_href.is_some() == other.card_href.is_some()
+            && self.media.as_ref().map(CardMedia::style)
+                == other.media.as_ref().map(CardMedia::style)
     }
 }
 
@@ -109,6 +283,46 @@ pub struct CardsSelectionDetail<T: Clone + PartialEq> {
     pub selected_items: Vec<T>,
 }
 
+/// Event detail for following a card link
+#[derive(Debug, Clone, PartialEq)]
+pub struct CardsFollowDetail<T: Clone + PartialEq> {
+    /// The item of the followed card
+    pub item: T,
+    /// The URL being navigated to
+    pub href: String,
+}
+
+/// Gets the card to select for an arrow key in single selection, wrapping
+/// around at either end
+fn arrow_target(count: usize, current: usize, key: &str) -> Option<usize> {
+    if count < 2 {
+        return None;
+    }
+    match key {
+        "ArrowDown" | "ArrowRight" => Some((current + 1) % count),
+        "ArrowUp" | "ArrowLeft" => Some((current + count - 1) % count),
+        _ => None,
+    }
+}
+
+/// Moves focus to the selection control of the card at `index`
+fn focus_selection_control(grid_ref: &NodeRef, index: usize) {
+    if let Some(control) = grid_ref
+        .cast::<Element>()
+        .and_then(|grid| {
+            grid.query_selector(&format!(
+                "[data-card-index=\"{}\"] .awsui-cards-card-selection input",
+                index
+            ))
+            .ok()
+            .flatten()
+        })
+        .and_then(|control| control.dyn_into::<HtmlElement>().ok())
+    {
+        let _ = control.focus();
+    }
+}
+
 /// Properties for the Cards component
 #[derive(Properties, Clone)]
 pub struct CardsProps<T: Clone + PartialEq + 'static> {
@@ -147,14 +361,34 @@ pub struct CardsProps<T: Clone + PartialEq + 'static> {
     #[prop_or_default]
     pub header: Option<Html>,
 
-    /// Footer content (typically pagination)
+    /// Filter content, such as a TextFilter or PropertyFilter, between the
+    /// header and the cards
+    #[prop_or_default]
+    pub filter: Option<Html>,
+
+    /// Footer content
     #[prop_or_default]
     pub footer: Option<Html>,
 
-    /// Callback fired when selection changes
+    /// Pagination content, rendered in the footer after `footer`
+    #[prop_or_default]
+    pub pagination: Option<Html>,
+
+    /// Callback fired when selection changes, with the pointer or the
+    /// keyboard
+    ///
+    /// Space toggles the focused selection control; in single selection,
+    /// the arrow keys select the previous or next card.
     #[prop_or_default]
     pub on_selection_change: Option<Callback<CustomEvent<CardsSelectionDetail<T>>>>,
 
+    /// Callback fired when a card link is followed with a plain left click
+    ///
+    /// Call `prevent_default` on the event to handle navigation yourself,
+    /// for example with client-side routing.
+    #[prop_or_default]
+    pub on_follow: Option<Callback<CustomEvent<CardsFollowDetail<T>>>>,
+
     /// Number of cards per row at different breakpoints [xs, sm, md, lg]
     ///
     /// Defines responsive grid columns. For example, `[1, 2, 3, 4]` means:
@@ -175,6 +409,11 @@ impl<T: Clone + PartialEq + 'static> PartialEq for CardsProps<T> {
             && self.selected_items == other.selected_items
             && self.loading == other.loading
             && self.loading_text == other.loading_text
+            && self.empty == other.empty
+            && self.header == other.header
+            && self.filter == other.filter
+            && self.footer == other.footer
+            && self.pagination == other.pagination
             && self.cards_per_row == other.cards_per_row
     }
 }
@@ -257,6 +496,45 @@ pub fn cards<T: Clone + PartialEq + 'static>(props: &CardsProps<T>) -> Html {
         })
     };
 
+    // Keyboard selection: Space toggles the focused control, and in single
+    // selection the arrow keys move the selection between cards. Space is
+    // also cancelled on key up so the native activation doesn't toggle the
+    // control a second time.
+    let grid_ref = use_node_ref();
+    let on_selection_key_down = {
+        let on_card_select = on_card_select.clone();
+        let grid_ref = grid_ref.clone();
+        let items = props.items.clone();
+        let selection_type = props.selection_type;
+        Callback::from(move |(index, e): (usize, KeyboardEvent)| {
+            if e.key() == " " {
+                e.prevent_default();
+                if !e.repeat() {
+                    on_card_select.emit(items[index].clone());
+                }
+            } else if selection_type == Some(CardsSelectionType::Single)
+                && let Some(target) = arrow_target(items.len(), index, &e.key())
+            {
+                e.prevent_default();
+                focus_selection_control(&grid_ref, target);
+                on_card_select.emit(items[target].clone());
+            }
+        })
+    };
+    let on_selection_key_up = Callback::from(|e: KeyboardEvent| {
+        if e.key() == " " {
+            e.prevent_default();
+        }
+    });
+
+    // In single selection, only the selected card's control (else the
+    // first) is a tab stop
+    let tab_stop = props
+        .items
+        .iter()
+        .position(|item| props.selected_items.contains(item))
+        .unwrap_or(0);
+
     // Build root classes
     let root_classes = ClassBuilder::new()
         .add("awsui-cards")
@@ -267,7 +545,7 @@ pub fn cards<T: Clone + PartialEq + 'static>(props: &CardsProps<T>) -> Html {
     // Build grid style based on cards_per_row configuration
     let grid_style = build_grid_style(&props.cards_per_row);
 
-    html! {
+    let root = html! {
         <div class={root_class} id={props.base.id.clone()}>
             // Header section
             if let Some(ref header) = props.header {
@@ -276,6 +554,13 @@ pub fn cards<T: Clone + PartialEq + 'static>(props: &CardsProps<T>) -> Html {
                 </div>
             }
 
+            // Filter section
+            if let Some(ref filter) = props.filter {
+                <div class="awsui-cards-filter">
+                    { filter.clone() }
+                </div>
+            }
+
             // Cards grid container
             <div class="awsui-cards-container">
                 {
@@ -305,80 +590,172 @@ pub fn cards<T: Clone + PartialEq + 'static>(props: &CardsProps<T>) -> Html {
                     } else {
                         // Cards grid
                         html! {
-                            <div class="awsui-cards-grid" style={grid_style}>
+                            <div
+                                ref={grid_ref.clone()}
+                                class="awsui-cards-grid"
+                                style={grid_style}
+                            >
                                 {
-                                    props.items.iter().map(|item| {
+                                    props.items.iter().enumerate().map(|(index, item)| {
                                         let is_selected = props.selected_items.contains(item);
                                         let selectable = props.selection_type.is_some();
+                                        let href = props.card_definition.card_href.map(|card_href| card_href(item));
+                                        let media = props.card_definition.media.as_ref();
 
                                         let card_classes = ClassBuilder::new()
                                             .add("awsui-cards-card")
                                             .add_if(is_selected, "awsui-cards-card-selected")
-                                            .add_if(selectable, "awsui-cards-card-selectable");
-
-                                        let item_clone = item.clone();
-                                        let on_card_select_clone = on_card_select.clone();
-
-                                        let on_card_click = if selectable {
+                                            .add_if(selectable, "awsui-cards-card-selectable")
+                                            .add_if(href.is_some(), "awsui-cards-card-with-link")
+                                            .add_opt(media.map(|media| {
+                                                format!("awsui-cards-card-with-media-{}", media.position.as_str())
+                                            }));
+
+                                        // Clicks on the card body follow the card link, or
+                                        // select the card when it has no link
+                                        let on_card_click = if href.is_some() || selectable {
+                                            let item = item.clone();
+                                            let on_card_select = on_card_select.clone();
+                                            let has_href = href.is_some();
                                             Some(Callback::from(move |e: MouseEvent| {
-                                                e.prevent_default();
-                                                on_card_select_clone.emit(item_clone.clone());
+                                                if is_interactive_target(&e, Some(CARD_INTERACTIVE_SELECTOR)) {
+                                                    return;
+                                                }
+                                                if has_href {
+                                                    if ClickEvent::from_mouse_event(&e).is_plain_left_click()
+                                                        && let Some(link) = e
+                                                            .current_target()
+                                                            .and_then(|t| t.dyn_into::<Element>().ok())
+                                                            .and_then(|card| card.query_selector(CARD_LINK_SELECTOR).ok().flatten())
+                                                            .and_then(|link| link.dyn_into::<HtmlElement>().ok())
+                                                    {
+                                                        link.click();
+                                                    }
+                                                } else {
+                                                    on_card_select.emit(item.clone());
+                                                }
                                             }))
                                         } else {
                                             None
                                         };
 
+                                        let on_select = {
+                                            let item = item.clone();
+                                            let on_card_select = on_card_select.clone();
+                                            Callback::from(move |e: MouseEvent| {
+                                                e.prevent_default();
+                                                on_card_select.emit(item.clone());
+                                            })
+                                        };
+                                        let on_select_key_down = on_selection_key_down
+                                            .reform(move |e: KeyboardEvent| (index, e));
+
+                                        let header_content = (props.card_definition.header)(item);
+                                        let header = if let Some(ref href) = href {
+                                            let on_link_click = {
+                                                let on_follow = props.on_follow.clone();
+                                                let item = item.clone();
+                                                let href = href.clone();
+                                                Callback::from(move |e: MouseEvent| {
+                                                    if ClickEvent::from_mouse_event(&e).is_plain_left_click()
+                                                        && let Some(ref callback) = on_follow
+                                                    {
+                                                        let event = CustomEvent::new(CardsFollowDetail {
+                                                            item: item.clone(),
+                                                            href: href.clone(),
+                                                        });
+                                                        if event.emit_to(callback) {
+                                                            e.prevent_default();
+                                                        }
+                                                    }
+                                                })
+                                            };
+                                            html! {
+                                                <a class="awsui-cards-card-link" href={href.clone()} onclick={on_link_click}>
+                                                    { header_content }
+                                                </a>
+                                            }
+                                        } else {
+                                            header_content
+                                        };
+
                                         html! {
                                             <div
                                                 class={card_classes.build()}
+                                                role="group"
+                                                aria-selected={selectable.then(|| is_selected.to_string())}
+                                                data-card-index={index.to_string()}
                                                 onclick={on_card_click}
-                                                role={if selectable { Some("button") } else { None }}
-                                                tabindex={if selectable { Some("0") } else { None }}
-                                                aria-selected={if selectable { Some(is_selected.to_string()) } else { None }}
                                             >
-                                                // Selection indicator
-                                                if let Some(selection_type) = props.selection_type {
-                                                    <div class="awsui-cards-card-selection">
-                                                        {
-                                                            match selection_type {
-                                                                CardsSelectionType::Single => html! {
-                                                                    <input
-                                                                        type="radio"
-                                                                        class="awsui-cards-selection-radio"
-                                                                        checked={is_selected}
-                                                                        readonly=true
-                                                                        aria-label="Select card"
-                                                                    />
-                                                                },
-                                                                CardsSelectionType::Multi => html! {
-                                                                    <input
-                                                                        type="checkbox"
-                                                                        class="awsui-cards-selection-checkbox"
-                                                                        checked={is_selected}
-                                                                        readonly=true
-                                                                        aria-label="Select card"
-                                                                    />
-                                                                },
-                                                            }
-                                                        }
+                                                // Card media
+                                                if let Some(media) = media {
+                                                    <div
+                                                        class={format!("awsui-cards-card-media awsui-cards-card-media-{}", media.position.as_str())}
+                                                        style={media.style()}
+                                                    >
+                                                        { (media.content)(item) }
                                                     </div>
                                                 }
 
-                                                // Card header
-                                                <div class="awsui-cards-card-header">
-                                                    { (props.card_definition.header)(item) }
-                                                </div>
+                                                <div class="awsui-cards-card-inner">
+                                                    // Card header: link, actions and selection control
+                                                    // are separate tab stops, in that order
+                                                    <div class="awsui-cards-card-header">
+                                                        <div class="awsui-cards-card-header-content">
+                                                            { header }
+                                                        </div>
+
+                                                        if let Some(actions) = props.card_definition.actions {
+                                                            <div class="awsui-cards-card-actions">
+                                                                { actions(item) }
+                                                            </div>
+                                                        }
 
-                                                // Card sections
-                                                {
-                                                    props.card_definition.sections.iter().map(|section| {
-                                                        html! {
-                                                            <div class="awsui-cards-card-section">
-                                                                { section(item) }
+                                                        // Selection control
+                                                        if let Some(selection_type) = props.selection_type {
+                                                            <div class="awsui-cards-card-selection">
+                                                                {
+                                                                    match selection_type {
+                                                                        CardsSelectionType::Single => html! {
+                                                                            <input
+                                                                                type="radio"
+                                                                                class="awsui-cards-selection-radio"
+                                                                                checked={is_selected}
+                                                                                tabindex={if index == tab_stop { "0" } else { "-1" }}
+                                                                                onclick={on_select}
+                                                                                onkeydown={on_select_key_down}
+                                                                                onkeyup={on_selection_key_up.clone()}
+                                                                                aria-label="Select card"
+                                                                            />
+                                                                        },
+                                                                        CardsSelectionType::Multi => html! {
+                                                                            <input
+                                                                                type="checkbox"
+                                                                                class="awsui-cards-selection-checkbox"
+                                                                                checked={is_selected}
+                                                                                onclick={on_select}
+                                                                                onkeydown={on_select_key_down}
+                                                                                onkeyup={on_selection_key_up.clone()}
+                                                                                aria-label="Select card"
+                                                                            />
+                                                                        },
+                                                                    }
+                                                                }
                                                             </div>
                                                         }
-                                                    }).collect::<Html>()
-                                                }
+                                                    </div>
+
+                                                    // Card sections
+                                                    {
+                                                        props.card_definition.sections.iter().map(|section| {
+                                                            html! {
+                                                                <div class="awsui-cards-card-section">
+                                                                    { section(item) }
+                                                                </div>
+                                                            }
+                                                        }).collect::<Html>()
+                                                    }
+                                                </div>
                                             </div>
                                         }
                                     }).collect::<Html>()
@@ -390,13 +767,21 @@ pub fn cards<T: Clone + PartialEq + 'static>(props: &CardsProps<T>) -> Html {
             </div>
 
             // Footer section (pagination, etc.)
-            if let Some(ref footer) = props.footer {
+            if props.footer.is_some() || props.pagination.is_some() {
                 <div class="awsui-cards-footer">
-                    { footer.clone() }
+                    if let Some(ref footer) = props.footer {
+                        { footer.clone() }
+                    }
+                    if let Some(ref pagination) = props.pagination {
+                        <div class="awsui-cards-pagination">
+                            { pagination.clone() }
+                        </div>
+                    }
                 </div>
             }
         </div>
-    }
+    };
+    apply_root_attributes(root, &props.base)
 }
 
 /// Builds the grid-template-columns style based on cards_per_row configuration
@@ -458,6 +843,61 @@ mod tests {
         assert_eq!(card_def1, card_def2);
     }
 
+    #[test]
+    fn test_card_definition_anatomy_builders() {
+        let card_def = CardDefinition::new(|item: &TestItem| html! { {&item.name} })
+            .with_actions(|_: &TestItem| html! { <button>{"Actions"}</button> })
+            .with_media(CardMedia::new(|item: &TestItem| html! { {item.id} }))
+            .with_card_href(|item: &TestItem| format!("/items/{}", item.id));
+
+        assert!(card_def.actions.is_some());
+        assert!(card_def.media.is_some());
+        let item = TestItem {
+            id: 7,
+            name: "Item".into(),
+            description: String::new(),
+        };
+        assert_eq!(
+            card_def.card_href.map(|href| href(&item)).as_deref(),
+            Some("/items/7")
+        );
+        assert_ne!(
+            card_def,
+            CardDefinition::new(|item: &TestItem| html! { {&item.name} })
+        );
+    }
+
+    #[test]
+    fn test_card_media_style() {
+        let media = CardMedia::new(|_: &TestItem| html! {});
+        assert_eq!(media.style(), None);
+
+        let media = media
+            .with_height("160px")
+            .with_aspect_ratio("16 / 9")
+            .with_lazy(true);
+        let style = media.style().unwrap();
+        assert!(style.contains("height: 160px"));
+        assert!(style.contains("aspect-ratio: 16 / 9"));
+        assert!(style.contains("content-visibility: auto"));
+
+        // Width only applies to side media
+        let top = CardMedia::new(|_: &TestItem| html! {}).with_width("120px");
+        assert_eq!(top.style(), None);
+        let side = top.with_position(MediaPosition::Side);
+        assert_eq!(side.style().as_deref(), Some("width: 120px"));
+    }
+
+    #[test]
+    fn test_arrow_target_wraps() {
+        assert_eq!(arrow_target(3, 0, "ArrowDown"), Some(1));
+        assert_eq!(arrow_target(3, 2, "ArrowRight"), Some(0));
+        assert_eq!(arrow_target(3, 0, "ArrowUp"), Some(2));
+        assert_eq!(arrow_target(3, 1, "ArrowLeft"), Some(0));
+        assert_eq!(arrow_target(3, 1, "Enter"), None);
+        assert_eq!(arrow_target(1, 0, "ArrowDown"), None);
+    }
+
     #[test]
     fn test_selection_type_equality() {
         assert_eq!(CardsSelectionType::Single, CardsSelectionType::Single);
@@ -6,8 +6,13 @@
 //! A form control that allows users to make one or multiple selections from a list,
 //! supporting checked, unchecked, and indeterminate states.
 
+use crate::form_field::{
+    join_ids, use_field_association, use_native_validity, use_validation_state,
+};
+use crate::form_state::use_field_tracking;
 use crate::internal::{
     AriaAttributes, BaseComponentProps, ClassBuilder, ComponentMetadata, CustomEvent,
+    apply_root_attributes,
 };
 use web_sys::HtmlInputElement;
 use yew::prelude::*;
@@ -57,6 +62,20 @@ pub struct CheckboxProps {
     #[prop_or_default]
     pub name: Option<String>,
 
+    /// Value submitted with the form when checked
+    ///
+    /// Browsers submit "on" when not set.
+    #[prop_or_default]
+    pub value: Option<String>,
+
+    /// Whether the control must be checked before the form can be submitted
+    #[prop_or_default]
+    pub required: bool,
+
+    /// ID of the form the control belongs to, for controls rendered outside it
+    #[prop_or_default]
+    pub form: Option<String>,
+
     /// Control ID for form field integration
     ///
     /// If not provided, an auto-generated ID will be used.
@@ -176,6 +195,15 @@ pub struct CheckboxProps {
 pub fn checkbox(props: &CheckboxProps) -> Html {
     let _metadata = ComponentMetadata::new("Checkbox");
     let input_ref = use_node_ref();
+    let on_invalid = use_native_validity(!props.required || props.checked);
+    let validation = use_validation_state(false, false);
+
+    // Id and ARIA references, merged with the enclosing FormField
+    let association = use_field_association(
+        props.control_id.clone(),
+        props.aria.labelledby.clone(),
+        props.aria.describedby.clone(),
+    );
 
     // Set indeterminate property on the native input element
     // This must be done via JavaScript as it's not reflected in HTML attributes
@@ -248,11 +276,16 @@ pub fn checkbox(props: &CheckboxProps) -> Html {
         })
     };
 
+    // Report the value and blur to the closest FormStateProvider
+    let on_field_blur = use_field_tracking(props.checked.to_string());
+
     // Handle blur events
     let on_blur_event = {
         let on_blur = props.on_blur.clone();
 
         Callback::from(move |_e: FocusEvent| {
+            on_field_blur.emit(());
+
             if let Some(callback) = &on_blur {
                 callback.emit(());
             }
@@ -277,13 +310,17 @@ pub fn checkbox(props: &CheckboxProps) -> Html {
 
     // Determine ARIA properties
     let aria_label = props.aria_label.clone();
-    let aria_labelledby = props.aria.labelledby.clone();
-    let aria_describedby = props.aria.describedby.clone();
+    let control_id = association.control_id;
 
-    // Generate control ID if not provided
-    let control_id = props.control_id.clone();
+    // The checkbox is named by both the field label and its own label
+    let label_id = control_id
+        .as_ref()
+        .filter(|_| !props.children.is_empty())
+        .map(|id| format!("{}-label", id));
+    let aria_labelledby = join_ids([association.labelledby.as_deref(), label_id.as_deref()]);
+    let aria_describedby = association.describedby;
 
-    html! {
+    let root = html! {
         <div class={root_classes.build()}>
             // Hidden native checkbox input
             <input
@@ -292,12 +329,16 @@ pub fn checkbox(props: &CheckboxProps) -> Html {
                 class="awsui-checkbox-native-input"
                 id={control_id.clone()}
                 name={props.name.clone()}
+                value={props.value.clone()}
+                form={props.form.clone()}
+                required={props.required}
                 checked={props.checked}
                 disabled={props.disabled}
                 aria-checked={if props.indeterminate { "mixed" } else if props.checked { "true" } else { "false" }}
                 aria-label={aria_label}
                 aria-labelledby={aria_labelledby}
                 aria-describedby={aria_describedby}
+                aria-invalid={validation.is_invalid().then_some("true")}
                 aria-controls={props.aria_controls.clone()}
                 aria-required={props.aria_required.then_some("true")}
                 aria-disabled={props.read_only.then_some("true")}
@@ -305,6 +346,7 @@ pub fn checkbox(props: &CheckboxProps) -> Html {
                 onblur={on_blur_event}
                 // Empty onChange to suppress React-style warnings
                 onchange={Callback::from(|_| {})}
+                oninvalid={on_invalid}
             />
 
             // Visual checkbox control (clickable)
@@ -320,6 +362,7 @@ pub fn checkbox(props: &CheckboxProps) -> Html {
                         if !props.children.is_empty() {
                             <label
                                 class="awsui-checkbox-label"
+                                id={label_id}
                                 for={control_id}
                                 onclick={on_click.clone()}
                             >
@@ -337,7 +380,8 @@ pub fn checkbox(props: &CheckboxProps) -> Html {
                 }
             </div>
         </div>
-    }
+    };
+    apply_root_attributes(root, &props.base)
 }
 
 /// Renders the checkbox icon based on its state
@@ -440,7 +484,7 @@ mod tests {
 
         let next_checked = if indeterminate { true } else { !checked };
 
-        assert_eq!(next_checked, true);
+        assert!(next_checked);
     }
 
     #[test]
@@ -451,7 +495,7 @@ mod tests {
 
         let next_checked = if indeterminate { true } else { !checked };
 
-        assert_eq!(next_checked, false);
+        assert!(!next_checked);
     }
 
     #[test]
@@ -462,6 +506,6 @@ mod tests {
 
         let next_checked = if indeterminate { true } else { !checked };
 
-        assert_eq!(next_checked, true);
+        assert!(next_checked);
     }
 }
@@ -0,0 +1,678 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Collection state shared by Table, Cards, Pagination and FilterSummary.
+//!
+//! [`CollectionState`] holds the page, sorting and filtering of a collection,
+//! and [`use_collection`] keeps it in component state. With the `url-state`
+//! feature, `use_url_collection_state` also keeps it in the query string so it
+//! survives a refresh and can be shared as a link.
+
+use crate::filter_summary::{FilterSummaryChip, FilterSummaryToken};
+use crate::internal::CustomEvent;
+use crate::pagination::PaginationChangeDetail;
+use crate::table::{SortDirection, SortingState, TableSortDetail};
+use yew::{Callback, UseStateHandle};
+
+/// Property filter operators accepted when parsing tokens from a URL
+pub const FILTER_OPERATORS: &[&str] = &["=", "!=", ":", "!:", ">", "<", ">=", "<="];
+
+/// Page, sorting and filtering state of a collection
+#[derive(Debug, Clone, PartialEq)]
+pub struct CollectionState {
+    /// Index of the current page (1-indexed)
+    pub page_index: u32,
+    /// Number of items per page
+    pub page_size: u32,
+    /// Sort column and direction
+    pub sorting: SortingState,
+    /// Free-text filter
+    pub filtering_text: String,
+    /// Property filter tokens
+    pub tokens: Vec<FilterSummaryToken>,
+}
+
+impl Default for CollectionState {
+    fn default() -> Self {
+        Self {
+            page_index: 1,
+            page_size: 10,
+            sorting: SortingState::default(),
+            filtering_text: String::new(),
+            tokens: Vec::new(),
+        }
+    }
+}
+
+impl CollectionState {
+    /// Creates a collection state on the first page with the given page size
+    pub fn new(page_size: u32) -> Self {
+        Self {
+            page_size: page_size.max(1),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the sort column and direction
+    pub fn with_sorting(mut self, column_id: impl Into<String>, direction: SortDirection) -> Self {
+        self.sorting = SortingState {
+            sort_column_id: Some(column_id.into()),
+            sort_direction: direction,
+        };
+        self
+    }
+
+    /// Sets the free-text filter
+    pub fn with_filtering_text(mut self, filtering_text: impl Into<String>) -> Self {
+        self.filtering_text = filtering_text.into();
+        self
+    }
+
+    /// Sets the property filter tokens
+    pub fn with_tokens(mut self, tokens: Vec<FilterSummaryToken>) -> Self {
+        self.tokens = tokens;
+        self
+    }
+
+    /// Gets the number of pages needed for `items_count` items
+    pub fn pages_count(&self, items_count: usize) -> u32 {
+        (items_count as u32).div_ceil(self.page_size.max(1)).max(1)
+    }
+
+    /// Clamps the page index to the pages available for `items_count` items
+    ///
+    /// A page index parsed from a URL can point past the end of the data, so
+    /// call this once the items are known.
+    pub fn clamp_page(&mut self, items_count: usize) {
+        self.page_index = self.page_index.clamp(1, self.pages_count(items_count));
+    }
+
+    /// Removes the filter or sort order shown by a FilterSummary chip
+    ///
+    /// Returns to the first page, since the matching items change.
+    pub fn dismiss(&mut self, chip: FilterSummaryChip) {
+        match chip {
+            FilterSummaryChip::FilteringText => self.filtering_text.clear(),
+            FilterSummaryChip::Token(index) => {
+                if index < self.tokens.len() {
+                    self.tokens.remove(index);
+                }
+            }
+            FilterSummaryChip::Sorting => self.sorting = SortingState::default(),
+        }
+        self.page_index = 1;
+    }
+
+    /// Removes all filters and the sort order, returning to the first page
+    pub fn clear_filters(&mut self) {
+        self.filtering_text.clear();
+        self.tokens.clear();
+        self.sorting = SortingState::default();
+        self.page_index = 1;
+    }
+}
+
+/// Holds the page, sorting and filtering state of a collection
+///
+/// Pass the handle to FilterSummary's `collection` prop so that dismissing a
+/// chip clears the matching filter. `use_url_collection_state` returns the
+/// same handle kept in sync with the URL, so switching to it is a one-line
+/// change.
+#[yew::hook]
+pub fn use_collection<F>(init: F) -> UseStateHandle<CollectionState>
+where
+    F: FnOnce() -> CollectionState,
+{
+    yew::use_state(init)
+}
+
+/// Creates a Pagination `on_change` callback moving the collection to the
+/// requested page
+pub fn page_change_callback(
+    state: &UseStateHandle<CollectionState>,
+) -> Callback<CustomEvent<PaginationChangeDetail>> {
+    let state = state.clone();
+    Callback::from(move |event: CustomEvent<PaginationChangeDetail>| {
+        state.set(CollectionState {
+            page_index: event.detail.requested_page_index,
+            ..(*state).clone()
+        });
+    })
+}
+
+/// Creates a Table `on_sort_change` callback storing the new sort order and
+/// returning to the first page
+pub fn sort_change_callback(
+    state: &UseStateHandle<CollectionState>,
+) -> Callback<CustomEvent<TableSortDetail>> {
+    let state = state.clone();
+    Callback::from(move |event: CustomEvent<TableSortDetail>| {
+        let detail = &event.detail;
+        state.set(
+            CollectionState {
+                page_index: 1,
+                ..(*state).clone()
+            }
+            .with_sorting(detail.column_id.clone(), detail.direction),
+        );
+    })
+}
+
+/// Query parameter names and validation for URL-synchronized state
+#[derive(Debug, Clone, PartialEq)]
+pub struct UrlCollectionStateConfig {
+    /// Parameter holding the page index
+    pub page_param: String,
+    /// Parameter holding the page size
+    pub page_size_param: String,
+    /// Parameter holding the sort column ID
+    pub sort_param: String,
+    /// Parameter holding the sort direction (`asc` or `desc`)
+    pub sort_direction_param: String,
+    /// Parameter holding the free-text filter
+    pub filter_param: String,
+    /// Parameter holding a property filter token, repeated once per token
+    pub token_param: String,
+    /// Page sizes accepted from the URL; any size up to 1000 when empty
+    pub page_size_options: Vec<u32>,
+    /// Sortable column IDs accepted from the URL; any ID when empty
+    pub sortable_columns: Vec<String>,
+    /// Filtering properties accepted from the URL as `(key, label)` pairs
+    ///
+    /// Token labels are taken from here. When empty, any key is accepted and
+    /// used as the label.
+    pub filtering_properties: Vec<(String, String)>,
+}
+
+impl Default for UrlCollectionStateConfig {
+    fn default() -> Self {
+        Self {
+            page_param: "page".to_string(),
+            page_size_param: "pageSize".to_string(),
+            sort_param: "sort".to_string(),
+            sort_direction_param: "sortDir".to_string(),
+            filter_param: "filter".to_string(),
+            token_param: "token".to_string(),
+            page_size_options: Vec::new(),
+            sortable_columns: Vec::new(),
+            filtering_properties: Vec::new(),
+        }
+    }
+}
+
+impl UrlCollectionStateConfig {
+    /// Adds a prefix to every parameter name, for pages with several collections
+    pub fn with_prefix(mut self, prefix: &str) -> Self {
+        for param in [
+            &mut self.page_param,
+            &mut self.page_size_param,
+            &mut self.sort_param,
+            &mut self.sort_direction_param,
+            &mut self.filter_param,
+            &mut self.token_param,
+        ] {
+            *param = format!("{}{}", prefix, param);
+        }
+        self
+    }
+
+    /// Sets the page sizes accepted from the URL
+    pub fn with_page_size_options(mut self, page_size_options: Vec<u32>) -> Self {
+        self.page_size_options = page_size_options;
+        self
+    }
+
+    /// Sets the sortable column IDs accepted from the URL
+    pub fn with_sortable_columns(mut self, sortable_columns: Vec<String>) -> Self {
+        self.sortable_columns = sortable_columns;
+        self
+    }
+
+    /// Sets the filtering properties accepted from the URL
+    pub fn with_filtering_properties(
+        mut self,
+        filtering_properties: Vec<(String, String)>,
+    ) -> Self {
+        self.filtering_properties = filtering_properties;
+        self
+    }
+
+    fn params(&self) -> [&str; 6] {
+        [
+            &self.page_param,
+            &self.page_size_param,
+            &self.sort_param,
+            &self.sort_direction_param,
+            &self.filter_param,
+            &self.token_param,
+        ]
+    }
+
+    fn property_label(&self, key: &str) -> Option<String> {
+        if self.filtering_properties.is_empty() {
+            return Some(key.to_string());
+        }
+        self.filtering_properties
+            .iter()
+            .find(|(property_key, _)| property_key == key)
+            .map(|(_, label)| label.clone())
+    }
+}
+
+/// Percent-encodes a query component, keeping only unreserved characters
+pub(crate) fn encode_component(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+/// Decodes a percent-encoded query component, treating `+` as a space
+///
+/// Malformed escapes are kept literally and invalid UTF-8 is replaced.
+pub(crate) fn decode_component(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => decoded.push(b' '),
+            b'%' if i + 2 < bytes.len() => {
+                match std::str::from_utf8(&bytes[i + 1..i + 3])
+                    .ok()
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                {
+                    Some(byte) => {
+                        decoded.push(byte);
+                        i += 2;
+                    }
+                    None => decoded.push(b'%'),
+                }
+            }
+            byte => decoded.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// Splits a query string into raw, still-encoded `(name, value)` pairs
+fn query_pairs(query: &str) -> impl Iterator<Item = (&str, &str)> {
+    query
+        .strip_prefix('?')
+        .unwrap_or(query)
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| pair.split_once('=').unwrap_or((pair, "")))
+}
+
+fn encode_token(token: &FilterSummaryToken) -> String {
+    format!(
+        "{},{},{}",
+        encode_component(&token.property_key),
+        encode_component(&token.operator),
+        encode_component(&token.value)
+    )
+}
+
+fn decode_token(raw: &str, config: &UrlCollectionStateConfig) -> Option<FilterSummaryToken> {
+    let mut parts = raw.splitn(3, ',').map(decode_component);
+    let (key, operator, value) = (parts.next()?, parts.next()?, parts.next()?);
+    if key.is_empty() || !FILTER_OPERATORS.contains(&operator.as_str()) {
+        return None;
+    }
+    let label = config.property_label(&key)?;
+    Some(FilterSummaryToken::new(key, label, operator, value))
+}
+
+/// Parses collection state from a query string
+///
+/// Parameters that are missing, malformed or out of range keep their value
+/// from `defaults`; unknown parameters are ignored.
+pub fn decode_collection_state(
+    query: &str,
+    config: &UrlCollectionStateConfig,
+    defaults: &CollectionState,
+) -> CollectionState {
+    let mut state = defaults.clone();
+    let mut tokens = Vec::new();
+    let mut has_tokens = false;
+
+    for (name, raw) in query_pairs(query) {
+        let name = decode_component(name);
+        if name == config.token_param {
+            has_tokens = true;
+            tokens.extend(decode_token(raw, config));
+            continue;
+        }
+
+        let value = decode_component(raw);
+        if name == config.page_param {
+            if let Ok(page_index) = value.parse::<u32>() {
+                state.page_index = page_index.max(1);
+            }
+        } else if name == config.page_size_param {
+            let page_size = value.parse::<u32>().ok().filter(|size| {
+                if config.page_size_options.is_empty() {
+                    (1..=1000).contains(size)
+                } else {
+                    config.page_size_options.contains(size)
+                }
+            });
+            if let Some(page_size) = page_size {
+                state.page_size = page_size;
+            }
+        } else if name == config.sort_param {
+            if !value.is_empty()
+                && (config.sortable_columns.is_empty() || config.sortable_columns.contains(&value))
+            {
+                state.sorting.sort_column_id = Some(value);
+            }
+        } else if name == config.sort_direction_param {
+            match value.as_str() {
+                "asc" => state.sorting.sort_direction = SortDirection::Ascending,
+                "desc" => state.sorting.sort_direction = SortDirection::Descending,
+                _ => {}
+            }
+        } else if name == config.filter_param {
+            state.filtering_text = value;
+        }
+    }
+
+    if has_tokens {
+        state.tokens = tokens;
+    }
+    state
+}
+
+/// Writes collection state into a query string
+///
+/// Parameters of `existing` not owned by the config are kept in order, and
+/// values equal to `defaults` are left out to keep URLs short. Returns the
+/// query including its leading `?`, or an empty string.
+pub fn encode_collection_state(
+    existing: &str,
+    state: &CollectionState,
+    config: &UrlCollectionStateConfig,
+    defaults: &CollectionState,
+) -> String {
+    let owned = config.params();
+    let mut pairs: Vec<String> = query_pairs(existing)
+        .filter(|(name, _)| !owned.contains(&decode_component(name).as_str()))
+        .map(|(name, raw)| format!("{}={}", name, raw))
+        .collect();
+    let mut push = |name: &str, value: String| {
+        pairs.push(format!("{}={}", encode_component(name), value));
+    };
+
+    if state.page_index != defaults.page_index {
+        push(&config.page_param, state.page_index.to_string());
+    }
+    if state.page_size != defaults.page_size {
+        push(&config.page_size_param, state.page_size.to_string());
+    }
+    if state.sorting != defaults.sorting {
+        if let Some(ref column_id) = state.sorting.sort_column_id {
+            push(&config.sort_param, encode_component(column_id));
+        }
+        let direction = match state.sorting.sort_direction {
+            SortDirection::Ascending => "asc",
+            SortDirection::Descending => "desc",
+        };
+        push(&config.sort_direction_param, direction.to_string());
+    }
+    if state.filtering_text != defaults.filtering_text {
+        push(
+            &config.filter_param,
+            encode_component(&state.filtering_text),
+        );
+    }
+    if state.tokens != defaults.tokens {
+        for token in &state.tokens {
+            push(&config.token_param, encode_token(token));
+        }
+    }
+
+    if pairs.is_empty() {
+        String::new()
+    } else {
+        format!("?{}", pairs.join("&"))
+    }
+}
+
+/// Keeps collection state in sync with the URL query string
+///
+/// Works like [`use_collection`], but the state is parsed from the current
+/// URL on mount, falling back to `defaults` for missing or invalid
+/// parameters, and written back with `history.replaceState` whenever it
+/// changes, so paging and filtering do not add history entries. The existing
+/// `history.state` is kept, so routers storing data there are unaffected.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use yew::prelude::*;
+/// use cloudscape_components::collection::{
+///     CollectionState, UrlCollectionStateConfig, page_change_callback, sort_change_callback,
+///     use_url_collection_state,
+/// };
+/// use cloudscape_components::{FilterSummary, Pagination, Table, TableColumn};
+///
+/// #[function_component(Instances)]
+/// fn instances() -> Html {
+///     let state = use_url_collection_state(
+///         UrlCollectionStateConfig::default().with_page_size_options(vec![10, 20, 50]),
+///         CollectionState::new(10),
+///     );
+///     let items: Vec<String> = Vec::new();
+///
+///     html! {
+///         <>
+///             <FilterSummary collection={state.clone()} />
+///             <Table<String>
+///                 columns={vec![TableColumn::new("name", "Name", |name: &String| html! { name })]}
+///                 items={items.clone()}
+///                 sorting_state={state.sorting.clone()}
+///                 on_sort_change={sort_change_callback(&state)}
+///             />
+///             <Pagination
+///                 current_page_index={state.page_index}
+///                 pages_count={state.pages_count(items.len())}
+///                 on_change={page_change_callback(&state)}
+///             />
+///         </>
+///     }
+/// }
+/// ```
+#[cfg(feature = "url-state")]
+#[yew::hook]
+pub fn use_url_collection_state(
+    config: UrlCollectionStateConfig,
+    defaults: CollectionState,
+) -> UseStateHandle<CollectionState> {
+    let state = {
+        let config = config.clone();
+        let defaults = defaults.clone();
+        use_collection(move || {
+            let query = web_sys::window()
+                .and_then(|window| window.location().search().ok())
+                .unwrap_or_default();
+            decode_collection_state(&query, &config, &defaults)
+        })
+    };
+
+    yew::use_effect_with((*state).clone(), move |state| {
+        if let Some(window) = web_sys::window() {
+            let location = window.location();
+            let search = location.search().unwrap_or_default();
+            let query = encode_collection_state(&search, state, &config, &defaults);
+            if query != search {
+                let url = format!(
+                    "{}{}{}",
+                    location.pathname().unwrap_or_default(),
+                    query,
+                    location.hash().unwrap_or_default()
+                );
+                if let Ok(history) = window.history() {
+                    let current = history.state().unwrap_or(wasm_bindgen::JsValue::NULL);
+                    let _ = history.replace_state_with_url(&current, "", Some(&url));
+                }
+            }
+        }
+        || ()
+    });
+
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(state: &CollectionState, config: &UrlCollectionStateConfig) -> CollectionState {
+        let defaults = CollectionState::default();
+        let query = encode_collection_state("", state, config, &defaults);
+        decode_collection_state(&query, config, &defaults)
+    }
+
+    #[test]
+    fn test_component_encoding() {
+        let text = "a b&c=d+e%f/ü,😀";
+        let encoded = encode_component(text);
+        assert!(!encoded.contains(['&', '=', '+', ' ', ',', '#']));
+        assert_eq!(decode_component(&encoded), text);
+        assert_eq!(decode_component("a+b%20c"), "a b c");
+        assert_eq!(decode_component("100%"), "100%");
+        assert_eq!(decode_component("%zz%4"), "%zz%4");
+        assert_eq!(decode_component("%FF"), "\u{FFFD}");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let config = UrlCollectionStateConfig::default();
+        let state = CollectionState {
+            page_index: 3,
+            page_size: 50,
+            ..CollectionState::default()
+        }
+        .with_sorting("name", SortDirection::Descending)
+        .with_filtering_text("web & db = \"prod\" 100% #1")
+        .with_tokens(vec![
+            FilterSummaryToken::new("status", "status", "=", "running"),
+            FilterSummaryToken::new("tag", "tag", ":", "a,b&c=d"),
+        ]);
+
+        assert_eq!(round_trip(&state, &config), state);
+        let prefixed = config.with_prefix("t1_");
+        assert_eq!(round_trip(&state, &prefixed), state);
+    }
+
+    #[test]
+    fn test_defaults_omitted_and_other_params_kept() {
+        let config = UrlCollectionStateConfig::default();
+        let defaults = CollectionState::default();
+        assert_eq!(
+            encode_collection_state("", &defaults, &config, &defaults),
+            ""
+        );
+
+        let state = CollectionState {
+            page_index: 2,
+            ..defaults.clone()
+        };
+        assert_eq!(
+            encode_collection_state("?tab=logs&page=5&filter=x", &state, &config, &defaults),
+            "?tab=logs&page=2"
+        );
+    }
+
+    #[test]
+    fn test_decode_validation() {
+        let config = UrlCollectionStateConfig::default()
+            .with_page_size_options(vec![10, 20])
+            .with_sortable_columns(vec!["name".to_string()])
+            .with_filtering_properties(vec![("status".to_string(), "Status".to_string())]);
+        let defaults = CollectionState::default();
+
+        let state = decode_collection_state(
+            "page=0&pageSize=30&sort=secret&sortDir=sideways&token=status,%3D,ok&token=owner,%3D,me&token=status,~,x&token=bad",
+            &config,
+            &defaults,
+        );
+        assert_eq!(state.page_index, 1);
+        assert_eq!(state.page_size, 10);
+        assert_eq!(state.sorting, SortingState::default());
+        assert_eq!(
+            state.tokens,
+            vec![FilterSummaryToken::new("status", "Status", "=", "ok")]
+        );
+
+        let state =
+            decode_collection_state("&&=&page=abc&page&%zz=1&filter=%E0", &config, &defaults);
+        assert_eq!(state.page_index, 1);
+        assert_eq!(state.filtering_text, "\u{FFFD}");
+    }
+
+    #[test]
+    fn test_dismiss_chips() {
+        let mut state = CollectionState {
+            page_index: 4,
+            ..CollectionState::default()
+        }
+        .with_sorting("name", SortDirection::Ascending)
+        .with_filtering_text("prod")
+        .with_tokens(vec![
+            FilterSummaryToken::new("status", "Status", "=", "running"),
+            FilterSummaryToken::new("owner", "Owner", "=", "me"),
+        ]);
+
+        state.dismiss(FilterSummaryChip::Token(0));
+        assert_eq!(
+            state.tokens,
+            vec![FilterSummaryToken::new("owner", "Owner", "=", "me")]
+        );
+        assert_eq!(state.page_index, 1);
+
+        state.dismiss(FilterSummaryChip::Token(5));
+        assert_eq!(state.tokens.len(), 1);
+
+        state.dismiss(FilterSummaryChip::FilteringText);
+        assert_eq!(state.filtering_text, "");
+
+        state.dismiss(FilterSummaryChip::Sorting);
+        assert_eq!(state.sorting, SortingState::default());
+    }
+
+    #[test]
+    fn test_clear_filters_keeps_page_size() {
+        let mut state = CollectionState {
+            page_index: 3,
+            ..CollectionState::new(50)
+        }
+        .with_sorting("name", SortDirection::Descending)
+        .with_filtering_text("prod")
+        .with_tokens(vec![FilterSummaryToken::new("status", "Status", "=", "ok")]);
+
+        state.clear_filters();
+        assert_eq!(state, CollectionState::new(50));
+    }
+
+    #[test]
+    fn test_clamp_page() {
+        let mut state = CollectionState {
+            page_index: 9,
+            ..CollectionState::new(20)
+        };
+        assert_eq!(state.pages_count(45), 3);
+        state.clamp_page(45);
+        assert_eq!(state.page_index, 3);
+        state.clamp_page(0);
+        assert_eq!(state.page_index, 1);
+    }
+}
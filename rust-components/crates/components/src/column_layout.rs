@@ -6,8 +6,13 @@
 //! The ColumnLayout component provides a flexible layout container for organizing
 //! content into multiple columns with responsive behavior. It supports configurable
 //! column counts, variants, borders, and gutter spacing.
+//!
+//! The column count adapts to the width of the layout: a fixed count steps
+//! down below the [`ColumnBreakpoints`], and with a minimum column width the
+//! grid fits as many columns as the width allows. Content reads the count in
+//! effect with [`use_column_layout_context`].
 
-use crate::internal::{BaseComponentProps, ClassBuilder};
+use crate::internal::{BaseComponentProps, ClassBuilder, apply_root_attributes, use_element_size};
 use yew::prelude::*;
 
 /// Column layout visual variants
@@ -60,6 +65,98 @@ impl BordersType {
     }
 }
 
+/// Gutter between columns in pixels
+const COLUMN_GUTTER: f64 = 20.0;
+
+/// Divider between an item and the one before it in its row
+const VERTICAL_DIVIDER_STYLE: &str = "border-inline-start: 1px solid var(--awsui-color-border-divider-default); \
+     padding-inline-start: var(--awsui-space-scaled-l)";
+
+/// Divider between an item and the row below it
+const HORIZONTAL_DIVIDER_STYLE: &str = "border-block-end: 1px solid var(--awsui-color-border-divider-default); \
+     padding-block-end: var(--awsui-space-scaled-l)";
+
+/// Layout widths below which a fixed column count steps down
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColumnBreakpoints {
+    /// Width in pixels below which the column count is halved, so 4
+    /// columns become 2 (default: 912)
+    pub halve_below: u32,
+    /// Width in pixels below which a single column is shown (default: 688)
+    pub single_below: u32,
+}
+
+impl Default for ColumnBreakpoints {
+    fn default() -> Self {
+        Self {
+            halve_below: 912,
+            single_below: 688,
+        }
+    }
+}
+
+impl ColumnBreakpoints {
+    /// Gets the number of columns shown for `columns` in `width` pixels
+    pub fn columns_for_width(&self, columns: u32, width: f64) -> u32 {
+        if width < f64::from(self.single_below) {
+            1
+        } else if width < f64::from(self.halve_below) {
+            columns.div_ceil(2).max(1)
+        } else {
+            columns
+        }
+    }
+}
+
+/// Column count in effect in a ColumnLayout
+///
+/// Provided as a Yew context to the content of the layout, so content can
+/// adapt to the columns it is shown in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColumnLayoutContext {
+    /// Number of columns shown
+    pub columns: u32,
+}
+
+/// Reads the column count of the closest ColumnLayout
+///
+/// Returns `None` when called outside of a ColumnLayout.
+#[hook]
+pub fn use_column_layout_context() -> Option<ColumnLayoutContext> {
+    use_context::<ColumnLayoutContext>()
+}
+
+/// Number of columns fitting in `width` with each at least `min_column_width`
+/// wide, between 1 and `max_columns`
+pub(crate) fn columns_for_width(
+    width: f64,
+    min_column_width: u32,
+    max_columns: u32,
+    gutter: f64,
+) -> u32 {
+    let column = f64::from(min_column_width.max(1)) + gutter;
+    let fitting = ((width + gutter) / column).floor() as u32;
+    fitting.clamp(1, max_columns.max(1))
+}
+
+/// Gets the grid columns fitting as many columns of at least
+/// `min_column_width` as the width allows, up to `max_columns`
+fn auto_fit_columns(min_column_width: u32, max_columns: u32, gutter: f64) -> String {
+    let gutters = gutter * f64::from(max_columns.saturating_sub(1));
+    format!(
+        "grid-template-columns: repeat(auto-fit, minmax(max({}px, calc((100% - {}px) / {})), 1fr)); column-gap: {}px",
+        min_column_width, gutters, max_columns, gutter
+    )
+}
+
+/// Gets whether the item at `index` of `count` items starts its row and
+/// whether it is in the last row, for `columns` columns
+fn item_position(index: usize, count: usize, columns: u32) -> (bool, bool) {
+    let columns = columns.max(1) as usize;
+    let last_row_start = count.saturating_sub(1) / columns * columns;
+    (index.is_multiple_of(columns), index >= last_row_start)
+}
+
 /// Properties for the ColumnLayout component
 #[derive(Properties, PartialEq, Clone)]
 pub struct ColumnLayoutProps {
@@ -83,8 +180,9 @@ pub struct ColumnLayoutProps {
 
     /// Border configuration
     ///
-    /// Controls whether dividers are placed between rows and columns.
-    /// Note: Borders are not supported when used with `min_column_width`.
+    /// Controls whether dividers are placed between rows and columns. The
+    /// dividers follow the column count in effect; items of the last row
+    /// have no divider below them.
     #[prop_or_default]
     pub borders: BordersType,
 
@@ -96,6 +194,12 @@ pub struct ColumnLayoutProps {
     #[prop_or_default]
     pub min_column_width: Option<u32>,
 
+    /// Layout widths below which the column count steps down
+    ///
+    /// Ignored with `min_column_width`, which adapts the count on its own.
+    #[prop_or_default]
+    pub breakpoints: ColumnBreakpoints,
+
     /// Whether to remove default gutters between columns
     #[prop_or(false)]
     pub disable_gutters: bool,
@@ -166,18 +270,34 @@ pub struct ColumnLayoutProps {
 /// ```
 #[function_component(ColumnLayout)]
 pub fn column_layout(props: &ColumnLayoutProps) -> Html {
+    let root_ref = use_node_ref();
+    let size = use_element_size(root_ref.clone());
+
     // Clamp column count to valid range (1-4)
-    let columns = props.columns.clamp(1, 4);
+    let max_columns = props.columns.clamp(1, 4);
 
-    // Determine if borders should be applied (not with text-grid or min_column_width)
+    // Determine if borders should be applied (not with text-grid)
     let is_text_grid = props.variant == ColumnVariant::TextGrid;
-    let has_min_width = props.min_column_width.is_some();
     let should_disable_gutters = !is_text_grid && props.disable_gutters;
+    let gutter = if should_disable_gutters {
+        0.0
+    } else {
+        COLUMN_GUTTER
+    };
+
+    // With a minimum column width, the grid fits as many columns as the
+    // observed width allows; otherwise the count steps down at the
+    // breakpoints. Before the first observation, fall back to the maximum
+    let columns = match (props.min_column_width, size) {
+        (Some(min_width), Some((width, _))) => {
+            columns_for_width(width, min_width, max_columns, gutter)
+        }
+        (None, Some((width, _))) => props.breakpoints.columns_for_width(max_columns, width),
+        (_, None) => max_columns,
+    };
     let should_have_horizontal_borders = !is_text_grid
-        && !has_min_width
         && (props.borders == BordersType::Horizontal || props.borders == BordersType::All);
     let should_have_vertical_borders = !is_text_grid
-        && !has_min_width
         && (props.borders == BordersType::Vertical || props.borders == BordersType::All);
 
     // Build CSS classes for the root element
@@ -204,21 +324,56 @@ pub fn column_layout(props: &ColumnLayoutProps) -> Html {
     let style = props.min_column_width.map(|width| {
         format!(
             "--column-layout-min-width: {}px; --column-layout-max-columns: {}",
-            width, columns
+            width, max_columns
         )
     });
 
-    html! {
+    let grid_style = props
+        .min_column_width
+        .map(|width| auto_fit_columns(width, max_columns, gutter));
+
+    // Dividers go between items, so they follow the rows of the column
+    // count in effect
+    let count = props.children.len();
+    let items = props.children.iter().enumerate().map(|(index, child)| {
+        let (row_start, last_row) = item_position(index, count, columns);
+        let styles: Vec<&str> = [
+            (should_have_vertical_borders && !row_start).then_some(VERTICAL_DIVIDER_STYLE),
+            (should_have_horizontal_borders && !last_row).then_some(HORIZONTAL_DIVIDER_STYLE),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        let classes = ClassBuilder::new()
+            .add("awsui-column-layout-item")
+            .add_if(row_start, "awsui-column-layout-item-row-start")
+            .add_if(last_row, "awsui-column-layout-item-last-row");
+
+        html! {
+            <div
+                class={classes.build()}
+                style={(!styles.is_empty()).then(|| styles.join("; "))}
+            >
+                { child }
+            </div>
+        }
+    });
+
+    let root = html! {
         <div
+            ref={root_ref}
             id={props.base.id.clone()}
             class={root_class}
             style={style}
         >
-            <div class="awsui-column-layout-grid">
-                { props.children.clone() }
-            </div>
+            <ContextProvider<ColumnLayoutContext> context={ColumnLayoutContext { columns }}>
+                <div class="awsui-column-layout-grid" style={grid_style}>
+                    { for items }
+                </div>
+            </ContextProvider<ColumnLayoutContext>>
         </div>
-    }
+    };
+    apply_root_attributes(root, &props.base)
 }
 
 #[cfg(test)]
@@ -299,6 +454,59 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_columns_for_width() {
+        // Two 200px columns and a 20px gutter need 420px
+        assert_eq!(columns_for_width(419.0, 200, 4, 20.0), 1);
+        assert_eq!(columns_for_width(420.0, 200, 4, 20.0), 2);
+        assert_eq!(columns_for_width(2000.0, 200, 3, 20.0), 3);
+        assert_eq!(columns_for_width(400.0, 200, 4, 0.0), 2);
+        assert_eq!(columns_for_width(0.0, 200, 4, 20.0), 1);
+        assert_eq!(columns_for_width(500.0, 0, 2, 20.0), 2);
+    }
+
+    #[test]
+    fn test_breakpoints_step_columns_down() {
+        let breakpoints = ColumnBreakpoints::default();
+        assert_eq!(breakpoints.columns_for_width(4, 1200.0), 4);
+        assert_eq!(breakpoints.columns_for_width(4, 800.0), 2);
+        assert_eq!(breakpoints.columns_for_width(3, 800.0), 2);
+        assert_eq!(breakpoints.columns_for_width(4, 500.0), 1);
+        assert_eq!(breakpoints.columns_for_width(1, 800.0), 1);
+
+        let custom = ColumnBreakpoints {
+            halve_below: 1200,
+            single_below: 400,
+        };
+        assert_eq!(custom.columns_for_width(4, 1000.0), 2);
+        assert_eq!(custom.columns_for_width(2, 500.0), 1);
+    }
+
+    #[test]
+    fn test_item_position() {
+        // Five items in two columns: rows [0, 1], [2, 3], [4]
+        assert_eq!(item_position(0, 5, 2), (true, false));
+        assert_eq!(item_position(1, 5, 2), (false, false));
+        assert_eq!(item_position(3, 5, 2), (false, false));
+        assert_eq!(item_position(4, 5, 2), (true, true));
+
+        // The same items in one column
+        assert_eq!(item_position(3, 5, 1), (true, false));
+        assert_eq!(item_position(4, 5, 1), (true, true));
+
+        // A full last row
+        assert_eq!(item_position(2, 4, 2), (true, true));
+        assert_eq!(item_position(3, 4, 2), (false, true));
+    }
+
+    #[test]
+    fn test_auto_fit_columns() {
+        assert_eq!(
+            auto_fit_columns(200, 3, 20.0),
+            "grid-template-columns: repeat(auto-fit, minmax(max(200px, calc((100% - 40px) / 3)), 1fr)); column-gap: 20px"
+        );
+    }
+
     #[test]
     fn test_borders_not_applied_with_text_grid() {
         // Borders should not be applied when variant is TextGrid
@@ -308,7 +516,7 @@ mod tests {
         let should_have_borders =
             !is_text_grid && (borders == BordersType::Horizontal || borders == BordersType::All);
 
-        assert_eq!(should_have_borders, false);
+        assert!(!should_have_borders);
     }
 
     #[test]
@@ -322,6 +530,6 @@ mod tests {
             && !has_min_width
             && (borders == BordersType::Horizontal || borders == BordersType::All);
 
-        assert_eq!(should_have_horizontal_borders, true);
+        assert!(should_have_horizontal_borders);
     }
 }
@@ -0,0 +1,425 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! ConfirmationModal component for confirming destructive actions.
+//!
+//! Wraps [`Modal`] with a confirm and a cancel button. Destructive
+//! confirmations render as an alert dialog, focus the cancel button first, and
+//! can require the user to type a value, such as the resource name, before the
+//! confirm button enables.
+
+use crate::button::{Button, ButtonVariant, FormAction};
+use crate::form_field::FormField;
+use crate::i18n::{I18nContext, use_i18n};
+use crate::input::{Input, InputChangeDetail};
+use crate::internal::{
+    BaseComponentProps, ClassBuilder, ClickEvent, ComponentMetadata, CustomEvent,
+};
+use crate::modal::{Modal, ModalDismissDetail, ModalSize};
+use crate::space_between::{SpaceBetween, SpaceBetweenDirection, SpaceBetweenSize};
+use wasm_bindgen::JsCast;
+use web_sys::{Element, Event, HtmlElement, KeyboardEvent};
+use yew::prelude::*;
+
+/// Internationalization strings for ConfirmationModal
+///
+/// Unset strings fall back to the closest I18nProvider (keys under
+/// `"confirmation-modal."`), then to English. The `confirm_label`,
+/// `cancel_label` and `type_to_confirm_label` props take precedence.
+#[derive(Clone, PartialEq, Default)]
+pub struct ConfirmationModalI18nStrings {
+    /// Label of the confirm button (default: "Confirm")
+    pub confirm_label: Option<String>,
+    /// Label of the cancel button (default: "Cancel")
+    pub cancel_label: Option<String>,
+    /// Label of the type-to-confirm input, with `{value}` replaced by the
+    /// value to type (default: `To confirm, type "{value}".`)
+    pub type_to_confirm_label: Option<String>,
+}
+
+/// Strings used to render a ConfirmationModal after applying the fallback
+/// chain
+struct ResolvedStrings {
+    confirm_label: String,
+    cancel_label: String,
+    type_to_confirm_label: String,
+}
+
+/// Resolves the strings: the label props, then `i18n_strings`, then the
+/// I18nProvider, then English
+fn resolve_strings(props: &ConfirmationModalProps, i18n: &I18nContext) -> ResolvedStrings {
+    let resolve = |label: &Option<String>, value: &Option<String>, key: &str, default: &str| {
+        label
+            .clone()
+            .or_else(|| value.clone())
+            .or_else(|| i18n.message("confirmation-modal", key).map(str::to_string))
+            .unwrap_or_else(|| default.to_string())
+    };
+    let strings = &props.i18n_strings;
+
+    ResolvedStrings {
+        confirm_label: resolve(
+            &props.confirm_label,
+            &strings.confirm_label,
+            "confirm_label",
+            "Confirm",
+        ),
+        cancel_label: resolve(
+            &props.cancel_label,
+            &strings.cancel_label,
+            "cancel_label",
+            "Cancel",
+        ),
+        type_to_confirm_label: match &props.type_to_confirm_label {
+            Some(label) => label.clone(),
+            None => resolve(
+                &None,
+                &strings.type_to_confirm_label,
+                "type_to_confirm_label",
+                "To confirm, type \"{value}\".",
+            )
+            .replace(
+                "{value}",
+                props.type_to_confirm.as_deref().unwrap_or_default(),
+            ),
+        },
+    }
+}
+
+/// Properties for the ConfirmationModal component
+#[derive(Properties, PartialEq, Clone)]
+pub struct ConfirmationModalProps {
+    /// Base component properties
+    #[prop_or_default]
+    pub base: BaseComponentProps,
+
+    /// Whether the modal is visible
+    #[prop_or_default]
+    pub visible: bool,
+
+    /// Title of the modal
+    pub title: String,
+
+    /// Explanation of what will happen on confirm
+    #[prop_or_default]
+    pub body: Option<Html>,
+
+    /// Label of the confirm button
+    ///
+    /// Default: `i18n_strings.confirm_label`, then "Confirm"
+    #[prop_or_default]
+    pub confirm_label: Option<String>,
+
+    /// Label of the cancel button
+    ///
+    /// Default: `i18n_strings.cancel_label`, then "Cancel"
+    #[prop_or_default]
+    pub cancel_label: Option<String>,
+
+    /// Whether the action is destructive, such as deleting a resource
+    ///
+    /// Renders the modal as an alert dialog with a destructive confirm button
+    /// and focuses the cancel button when the modal opens.
+    #[prop_or_default]
+    pub destructive: bool,
+
+    /// Value the user must type exactly before the confirm button enables,
+    /// such as the name of the resource being deleted
+    #[prop_or_default]
+    pub type_to_confirm: Option<String>,
+
+    /// Label of the type-to-confirm input
+    ///
+    /// Default: `i18n_strings.type_to_confirm_label`, then
+    /// `To confirm, type "<value>".`
+    #[prop_or_default]
+    pub type_to_confirm_label: Option<String>,
+
+    /// Internationalization strings for the default labels
+    #[prop_or_default]
+    pub i18n_strings: ConfirmationModalI18nStrings,
+
+    /// Whether pasting into the type-to-confirm input is allowed
+    #[prop_or(true)]
+    pub allow_paste: bool,
+
+    /// Whether the action is in progress, showing a loading confirm button
+    #[prop_or_default]
+    pub loading: bool,
+
+    /// Callback fired when the user confirms
+    #[prop_or_default]
+    pub on_confirm: Option<Callback<CustomEvent<()>>>,
+
+    /// Callback fired when the user cancels, including dismissing the modal
+    #[prop_or_default]
+    pub on_cancel: Option<Callback<CustomEvent<()>>>,
+}
+
+/// Whether the typed value satisfies the type-to-confirm requirement
+pub(crate) fn confirmation_satisfied(type_to_confirm: Option<&str>, typed: &str) -> bool {
+    type_to_confirm.is_none_or(|expected| typed == expected)
+}
+
+/// ConfirmationModal component for confirming destructive actions.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// use cloudscape_components::ConfirmationModal;
+///
+/// # let _: Html =
+/// html! {
+///     <ConfirmationModal
+///         visible={true}
+///         title="Delete instance?"
+///         body={html! { "Deleting web-server-1 permanently removes its data." }}
+///         confirm_label="Delete"
+///         destructive={true}
+///         type_to_confirm="web-server-1"
+///     />
+/// }
+/// # ;
+/// ```
+#[function_component(ConfirmationModal)]
+pub fn confirmation_modal(props: &ConfirmationModalProps) -> Html {
+    let _metadata = ComponentMetadata::new("ConfirmationModal");
+
+    let control_id = use_state(|| {
+        use std::sync::atomic::{AtomicU32, Ordering};
+        static COUNTER: AtomicU32 = AtomicU32::new(0);
+        let id = COUNTER.fetch_add(1, Ordering::Relaxed);
+        format!("awsui-confirmation-modal-{}", id)
+    });
+    let cancel_id = format!("{}-cancel", *control_id);
+    let input_id = format!("{}-input", *control_id);
+
+    let typed = use_state(String::new);
+
+    // Start each confirmation with an empty input, and focus the cancel
+    // button first when the action is destructive
+    {
+        let typed = typed.clone();
+        let cancel_id = cancel_id.clone();
+        use_effect_with(
+            (props.visible, props.destructive),
+            move |(visible, destructive)| {
+                typed.set(String::new());
+                if *visible
+                    && *destructive
+                    && let Some(button) = gloo::utils::document()
+                        .get_element_by_id(&cancel_id)
+                        .and_then(|element| element.dyn_into::<HtmlElement>().ok())
+                {
+                    let _ = button.focus();
+                }
+                || ()
+            },
+        );
+    }
+
+    let can_confirm =
+        !props.loading && confirmation_satisfied(props.type_to_confirm.as_deref(), &typed);
+
+    let confirm = {
+        let on_confirm = props.on_confirm.clone();
+        Callback::from(move |_: ()| {
+            if can_confirm && let Some(ref callback) = on_confirm {
+                callback.emit(CustomEvent::new_non_cancelable(()));
+            }
+        })
+    };
+
+    let cancel = {
+        let on_cancel = props.on_cancel.clone();
+        Callback::from(move |_: ()| {
+            if let Some(ref callback) = on_cancel {
+                callback.emit(CustomEvent::new_non_cancelable(()));
+            }
+        })
+    };
+
+    // Enter confirms from the body and the input; buttons handle it themselves
+    let on_keydown = {
+        let confirm = confirm.clone();
+        Callback::from(move |e: KeyboardEvent| {
+            let on_button = e
+                .target()
+                .and_then(|target| target.dyn_into::<Element>().ok())
+                .is_some_and(|element| element.closest("button, a").ok().flatten().is_some());
+            if e.key() == "Enter" && !on_button {
+                e.prevent_default();
+                confirm.emit(());
+            }
+        })
+    };
+
+    let on_paste = {
+        let allow_paste = props.allow_paste;
+        Callback::from(move |e: Event| {
+            if !allow_paste {
+                e.prevent_default();
+            }
+        })
+    };
+
+    let on_input_change = {
+        let typed = typed.clone();
+        Callback::from(move |event: CustomEvent<InputChangeDetail>| {
+            typed.set(event.detail.value);
+        })
+    };
+
+    let i18n = use_i18n();
+    let ResolvedStrings {
+        confirm_label,
+        cancel_label,
+        type_to_confirm_label,
+    } = resolve_strings(props, &i18n);
+
+    let confirm_base = BaseComponentProps {
+        class: props
+            .destructive
+            .then(|| "awsui-button-destructive".to_string()),
+        ..Default::default()
+    };
+    let cancel_base = BaseComponentProps {
+        id: Some(cancel_id),
+        ..Default::default()
+    };
+
+    let footer = html! {
+        <div class="awsui-confirmation-modal-footer">
+            <SpaceBetween direction={SpaceBetweenDirection::Horizontal} size={SpaceBetweenSize::Xs}>
+                <Button
+                    base={cancel_base}
+                    variant={ButtonVariant::Link}
+                    form_action={FormAction::None}
+                    on_click={cancel.reform(|_: ClickEvent| ())}
+                >
+                    { Html::from(cancel_label) }
+                </Button>
+                <Button
+                    base={confirm_base}
+                    variant={ButtonVariant::Primary}
+                    form_action={FormAction::None}
+                    disabled={!can_confirm && !props.loading}
+                    loading={props.loading}
+                    on_click={confirm.reform(|_: ClickEvent| ())}
+                >
+                    { Html::from(confirm_label) }
+                </Button>
+            </SpaceBetween>
+        </div>
+    };
+
+    let root_classes = ClassBuilder::new()
+        .add("awsui-confirmation-modal")
+        .add_if(props.destructive, "awsui-confirmation-modal-destructive");
+
+    let base = BaseComponentProps {
+        class: Some(props.base.merge_classes(&root_classes.build())),
+        ..props.base.clone()
+    };
+
+    html! {
+        <Modal
+            base={base}
+            visible={props.visible}
+            size={ModalSize::Medium}
+            header={props.title.clone()}
+            footer={footer}
+            alert_dialog={props.destructive}
+            on_dismiss={cancel.reform(|_: CustomEvent<ModalDismissDetail>| ())}
+        >
+            <div class="awsui-confirmation-modal-content" onkeydown={on_keydown}>
+                <SpaceBetween size={SpaceBetweenSize::M}>
+                    if let Some(ref body) = props.body {
+                        <div class="awsui-confirmation-modal-body">{ body.clone() }</div>
+                    }
+                    if let Some(ref expected) = props.type_to_confirm {
+                        <FormField
+                            label={type_to_confirm_label}
+                            control_id={input_id.clone()}
+                        >
+                            <div onpaste={on_paste}>
+                                <Input
+                                    control_id={input_id}
+                                    value={(*typed).clone()}
+                                    placeholder={expected.clone()}
+                                    autocomplete={false}
+                                    spellcheck={false}
+                                    on_change={on_input_change}
+                                />
+                            </div>
+                        </FormField>
+                    }
+                </SpaceBetween>
+            </div>
+        </Modal>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_confirmation_satisfied() {
+        assert!(confirmation_satisfied(None, ""));
+        assert!(confirmation_satisfied(Some("web-server-1"), "web-server-1"));
+        assert!(!confirmation_satisfied(Some("web-server-1"), ""));
+        assert!(!confirmation_satisfied(Some("web-server-1"), "web-server-"));
+        assert!(!confirmation_satisfied(
+            Some("web-server-1"),
+            "Web-Server-1"
+        ));
+        assert!(!confirmation_satisfied(
+            Some("web-server-1"),
+            " web-server-1"
+        ));
+    }
+
+    #[test]
+    fn test_resolve_strings() {
+        let props = yew::props!(ConfirmationModalProps {
+            title: "Delete instance",
+            type_to_confirm: "web-server-1",
+        });
+        let strings = resolve_strings(&props, &I18nContext::default());
+        assert_eq!(strings.confirm_label, "Confirm");
+        assert_eq!(strings.cancel_label, "Cancel");
+        assert_eq!(
+            strings.type_to_confirm_label,
+            "To confirm, type \"web-server-1\"."
+        );
+
+        let mut messages = crate::internal::I18nStrings::new();
+        messages.set("confirmation-modal.cancel_label", "Abbrechen");
+        messages.set(
+            "confirmation-modal.type_to_confirm_label",
+            "Zur Bestätigung \"{value}\" eingeben.",
+        );
+        let i18n = I18nContext {
+            locale: Some("de-DE".to_string()),
+            messages,
+        };
+        let props = yew::props!(ConfirmationModalProps {
+            title: "Delete instance",
+            type_to_confirm: "web-server-1",
+            confirm_label: "Delete",
+            i18n_strings: ConfirmationModalI18nStrings {
+                confirm_label: Some("Bestätigen".to_string()),
+                ..Default::default()
+            },
+        });
+        let strings = resolve_strings(&props, &i18n);
+        assert_eq!(strings.confirm_label, "Delete");
+        assert_eq!(strings.cancel_label, "Abbrechen");
+        assert_eq!(
+            strings.type_to_confirm_label,
+            "Zur Bestätigung \"web-server-1\" eingeben."
+        );
+    }
+}
@@ -6,10 +6,33 @@
 //! The Container component provides a flexible layout container with support for
 //! headers, footers, media elements, and different visual variants. It's designed
 //! to organize content sections within your application.
-
-use crate::internal::{BaseComponentProps, ClassBuilder};
+//!
+//! A sticky header stays in view while the rest of the container scrolls
+//! past. Inside an AppLayout it is pinned below the layout's header and
+//! sticky notifications.
+
+use crate::app_layout::StickyOffset;
+use crate::internal::deep_link::{highlight_style, use_deep_link};
+use crate::internal::dev_warnings::{DevWarning, unknown_style_parts, use_dev_warnings};
+use crate::internal::{AnchorLink, BaseComponentProps, ClassBuilder, apply_root_attributes};
+use gloo::events::EventListener;
+use web_sys::{Element, FocusEvent, MouseEvent};
 use yew::prelude::*;
 
+/// Divider between the content and the footer
+const FOOTER_DIVIDER_STYLE: &str =
+    "border-top: 1px solid var(--awsui-color-border-divider-default)";
+
+/// Builds the style of a sticky header pinned `offset` pixels from the top
+/// of the viewport, with a shadow once the container scrolls beneath it
+fn sticky_header_style(offset: i32, stuck: bool) -> String {
+    let mut style = format!("position: sticky; top: {}px; z-index: 800", offset);
+    if stuck {
+        style.push_str("; box-shadow: var(--awsui-shadow-sticky-embedded)");
+    }
+    style
+}
+
 /// Container visual variants
 ///
 /// Determines the visual style and spacing of the container based on its context.
@@ -124,7 +147,7 @@ pub struct ContainerProps {
     /// Footer element of the container
     ///
     /// Used to display supplementary information or actions at the
-    /// bottom of the container.
+    /// bottom of the container, below a divider.
     #[prop_or_default]
     pub footer: Option<Html>,
 
@@ -146,6 +169,18 @@ pub struct ContainerProps {
     #[prop_or_default]
     pub disable_header_paddings: bool,
 
+    /// Whether the header stays in view while the container scrolls
+    #[prop_or_default]
+    pub sticky_header: bool,
+
+    /// Distance in pixels from the top of the viewport at which the sticky
+    /// header is pinned
+    ///
+    /// Defaults to the space taken by the header and sticky notifications
+    /// of an enclosing AppLayout, or 0 outside of one.
+    #[prop_or_default]
+    pub sticky_header_vertical_offset: Option<i32>,
+
     /// Whether to remove default padding from the content area
     #[prop_or_default]
     pub disable_content_paddings: bool,
@@ -164,17 +199,46 @@ pub struct ContainerProps {
     #[prop_or_default]
     pub fit_height: bool,
 
+    /// Anchor ID for deep linking to the container header
+    ///
+    /// When the URL hash matches, on mount or after navigation, the
+    /// container scrolls into view and briefly highlights its header. The
+    /// header also shows a button copying the link while hovered or focused.
+    #[prop_or_default]
+    pub anchor_id: Option<String>,
+
     /// Main content of the container
     #[prop_or_default]
     pub children: Children,
 }
 
+/// Part names accepted by `base.style`: the sections of the container
+const STYLE_PARTS: &[&str] = &["header", "content", "footer", "media"];
+
+/// Misuse of Container props reported in development builds
+fn dev_warnings(props: &ContainerProps) -> Vec<DevWarning> {
+    unknown_style_parts(
+        "container-unknown-style-part",
+        &props.base.style,
+        STYLE_PARTS,
+    )
+    .into_iter()
+    .collect()
+}
+
 /// Container component for grouping and organizing content.
 ///
 /// The Container component provides a structured layout with optional header,
 /// footer, and media elements. It supports different visual variants and
 /// extensive customization options.
 ///
+/// Style override parts (`base.style`): `header`, `content`, `footer`,
+/// `media`.
+///
+/// With `sticky_header`, the header stays pinned while the container
+/// scrolls and gains a shadow once content passes beneath it. Media on top
+/// scrolls away as usual; only the header sticks.
+///
 /// # Example
 ///
 /// ```rust
@@ -218,6 +282,65 @@ pub struct ContainerProps {
 /// ```
 #[function_component(Container)]
 pub fn container(props: &ContainerProps) -> Html {
+    use_dev_warnings("Container").check(|| dev_warnings(props));
+
+    // Deep link: highlight the header when the URL hash targets the anchor ID
+    let header_ref = use_node_ref();
+    let highlighted = use_deep_link(
+        props.anchor_id.clone(),
+        header_ref.clone(),
+        Callback::noop(),
+    );
+
+    // Pin a sticky header, tracking whether the container has scrolled
+    // beneath it
+    let root_ref = use_node_ref();
+    let layout_offset = use_context::<StickyOffset>().unwrap_or_default();
+    let sticky_offset = props
+        .sticky_header_vertical_offset
+        .unwrap_or(layout_offset.top);
+    let stuck = use_state_eq(|| false);
+    {
+        let root_ref = root_ref.clone();
+        let stuck = stuck.clone();
+        use_effect_with(
+            (props.sticky_header, sticky_offset),
+            move |(sticky, offset)| {
+                let offset = f64::from(*offset);
+                let listener = sticky.then(|| {
+                    let update =
+                        move || {
+                            stuck.set(root_ref.cast::<Element>().is_some_and(|root| {
+                                root.get_bounding_client_rect().top() < offset
+                            }));
+                        };
+                    update();
+                    EventListener::new(&gloo::utils::window(), "scroll", move |_| update())
+                });
+                move || drop(listener)
+            },
+        );
+    }
+
+    // Show the anchor link while the header is hovered or focused
+    let header_active = use_state(|| false);
+    let on_header_enter = {
+        let header_active = header_active.clone();
+        Callback::from(move |_: MouseEvent| header_active.set(true))
+    };
+    let on_header_leave = {
+        let header_active = header_active.clone();
+        Callback::from(move |_: MouseEvent| header_active.set(false))
+    };
+    let on_header_focus = {
+        let header_active = header_active.clone();
+        Callback::from(move |_: FocusEvent| header_active.set(true))
+    };
+    let on_header_blur = {
+        let header_active = header_active.clone();
+        Callback::from(move |_: FocusEvent| header_active.set(false))
+    };
+
     // Build CSS classes for the root element
     let root_classes = ClassBuilder::new()
         .add("awsui-container")
@@ -226,6 +349,7 @@ pub fn container(props: &ContainerProps) -> Html {
             props.variant.as_str()
         ))
         .add_if(props.fit_height, "awsui-container-fit-height")
+        .add_if(props.sticky_header, "awsui-container-sticky-header")
         .add_if(
             props.media.is_some(),
             format!(
@@ -251,8 +375,28 @@ pub fn container(props: &ContainerProps) -> Html {
             props.children.is_empty(),
             "awsui-container-header-no-content",
         )
+        .add_if(highlighted, "awsui-container-header-highlighted")
+        .add_if(props.sticky_header, "awsui-container-header-sticky")
+        .add_if(
+            props.sticky_header && *stuck,
+            "awsui-container-header-stuck",
+        )
         .build();
 
+    let header_style = [
+        props
+            .anchor_id
+            .as_ref()
+            .map(|_| highlight_style(highlighted)),
+        props
+            .sticky_header
+            .then(|| sticky_header_style(sticky_offset, *stuck)),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>()
+    .join("; ");
+
     // Build content classes
     let content_classes = ClassBuilder::new()
         .add("awsui-container-content")
@@ -299,6 +443,7 @@ pub fn container(props: &ContainerProps) -> Html {
         } else {
             Some(style_parts.join("; "))
         };
+        let style = props.base.part_style("media", style.as_deref());
 
         html! {
             <div class={media_classes} style={style}>
@@ -307,10 +452,12 @@ pub fn container(props: &ContainerProps) -> Html {
         }
     });
 
-    html! {
+    let root = html! {
         <div
+            ref={root_ref}
             id={props.base.id.clone()}
             class={root_class}
+            style={props.base.merge_style(None)}
         >
             // Media element (if positioned at top or side)
             { media_element }
@@ -319,30 +466,78 @@ pub fn container(props: &ContainerProps) -> Html {
             <div class="awsui-container-content-wrapper">
                 // Header (if provided)
                 if let Some(ref header) = props.header {
-                    <div class={header_classes}>
+                    <div
+                        id={props.anchor_id.clone()}
+                        class={header_classes}
+                        style={props.base.part_style(
+                            "header",
+                            (!header_style.is_empty()).then_some(header_style.as_str()),
+                        )}
+                        ref={header_ref}
+                        onmouseenter={on_header_enter}
+                        onmouseleave={on_header_leave}
+                        onfocusin={on_header_focus}
+                        onfocusout={on_header_blur}
+                    >
                         { header.clone() }
+                        if let Some(ref anchor_id) = props.anchor_id {
+                            <AnchorLink anchor_id={anchor_id.clone()} visible={*header_active} />
+                        }
                     </div>
                 }
 
                 // Main content
-                <div class={content_classes}>
+                <div class={content_classes} style={props.base.part_style("content", None)}>
                     { props.children.clone() }
                 </div>
 
                 // Footer (if provided)
                 if let Some(ref footer) = props.footer {
-                    <div class={footer_classes}>
+                    <div
+                        class={footer_classes}
+                        style={props.base.part_style("footer", Some(FOOTER_DIVIDER_STYLE))}
+                    >
                         { footer.clone() }
                     </div>
                 }
             </div>
         </div>
-    }
+    };
+    apply_root_attributes(root, &props.base)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::internal::StyleOverride;
+
+    #[test]
+    fn test_dev_warning_unknown_style_part() {
+        let props = yew::props!(ContainerProps {
+            base: BaseComponentProps {
+                style: StyleOverride::new()
+                    .with_part("header", "background: #f2f3f3")
+                    .with_part("body", "padding: 0"),
+                ..Default::default()
+            },
+        });
+        let warnings = dev_warnings(&props);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, "container-unknown-style-part");
+        assert!(warnings[0].message.contains("body"));
+    }
+
+    #[test]
+    fn test_sticky_header_style() {
+        assert_eq!(
+            sticky_header_style(56, false),
+            "position: sticky; top: 56px; z-index: 800"
+        );
+        assert!(
+            sticky_header_style(0, true)
+                .ends_with("box-shadow: var(--awsui-shadow-sticky-embedded)")
+        );
+    }
 
     #[test]
     fn test_container_variant_default() {
@@ -397,3 +592,66 @@ mod tests {
         assert_eq!(media.width, Some("400px".to_string()));
     }
 }
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod wasm_tests {
+    use super::*;
+    use crate::internal::StyleOverride;
+    use std::time::Duration;
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[function_component(StyledContainer)]
+    fn styled_container() -> Html {
+        let base = BaseComponentProps {
+            style: StyleOverride::new()
+                .with_root("width: 50%; display: none")
+                .with_part("header", "background: red")
+                .with_part("footer", "padding: 0"),
+            ..Default::default()
+        };
+        html! {
+            <Container
+                base={base}
+                header={html! { <h2>{"Header"}</h2> }}
+                footer={html! { {"Footer"} }}
+            >
+                {"Content"}
+            </Container>
+        }
+    }
+
+    fn style_of(root: &web_sys::Element, selector: &str) -> Option<String> {
+        root.query_selector(selector)
+            .unwrap()
+            .and_then(|element| element.get_attribute("style"))
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_style_override_lands_on_parts() {
+        let root = gloo::utils::document().create_element("div").unwrap();
+        gloo::utils::body().append_child(&root).unwrap();
+
+        let app = yew::Renderer::<StyledContainer>::with_root(root.clone()).render();
+        yew::platform::time::sleep(Duration::ZERO).await;
+
+        // Protected properties are dropped from the root override
+        assert_eq!(
+            style_of(&root, ".awsui-container"),
+            Some("width: 50%".to_string())
+        );
+        assert_eq!(
+            style_of(&root, ".awsui-container-header"),
+            Some("background: red".to_string())
+        );
+        assert_eq!(
+            style_of(&root, ".awsui-container-footer"),
+            Some(format!("{}; padding: 0", FOOTER_DIVIDER_STYLE))
+        );
+        assert_eq!(style_of(&root, ".awsui-container-content"), None);
+
+        app.destroy();
+        root.remove();
+    }
+}
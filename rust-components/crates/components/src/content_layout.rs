@@ -7,7 +7,7 @@
 //! content within AppLayout. It manages header, notifications, default actions,
 //! and main content areas with proper spacing and optional header overlap effects.
 
-use crate::internal::{BaseComponentProps, ClassBuilder};
+use crate::internal::{BaseComponentProps, ClassBuilder, apply_root_attributes};
 use yew::prelude::*;
 
 /// Properties for the ContentLayout component
@@ -175,7 +175,7 @@ pub fn content_layout(props: &ContentLayoutProps) -> Html {
         )
         .build();
 
-    html! {
+    let root = html! {
         <div
             id={props.base.id.clone()}
             class={root_class}
@@ -213,7 +213,8 @@ pub fn content_layout(props: &ContentLayoutProps) -> Html {
                 { props.children.clone() }
             </div>
         </div>
-    }
+    };
+    apply_root_attributes(root, &props.base)
 }
 
 #[cfg(test)]
@@ -270,7 +271,7 @@ mod tests {
         let base = BaseComponentProps {
             id: Some("test-id".to_string()),
             class: Some("custom-class".to_string()),
-            data_attributes: None,
+            ..Default::default()
         };
 
         let props = yew::props!(ContentLayoutProps { base: base.clone() });
@@ -4,11 +4,21 @@
 //! CopyToClipboard component
 //!
 //! A button that copies text to the system clipboard with feedback messages.
-
-use crate::internal::{BaseComponentProps, ClassBuilder, ComponentMetadata, CustomEvent};
+//!
+//! Copying goes through the asynchronous Clipboard API. Where it is missing
+//! or rejects the write, as in non-secure contexts, the text is copied from a
+//! temporary textarea with `document.execCommand("copy")` instead.
+
+use crate::internal::{
+    BaseComponentProps, ClassBuilder, ComponentMetadata, CustomEvent, apply_root_attributes,
+};
+use gloo_timers::callback::Timeout;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::JsFuture;
-use web_sys::{MouseEvent, Window};
+use web_sys::{
+    Blob, BlobPropertyBag, ClipboardItem, HtmlDocument, HtmlElement, HtmlTextAreaElement,
+    MouseEvent, Window,
+};
 use yew::prelude::*;
 
 /// Copy operation status
@@ -59,6 +69,17 @@ pub struct CopyToClipboardProps {
     /// The text to copy to clipboard
     pub copy_text: String,
 
+    /// Rich text copied alongside `copy_text` as `text/html`
+    ///
+    /// Written with a `ClipboardItem` where the browser supports it;
+    /// otherwise only `copy_text` is copied.
+    #[prop_or_default]
+    pub text_to_copy_html: Option<String>,
+
+    /// How long the success or error feedback stays shown, in milliseconds
+    #[prop_or(2000)]
+    pub feedback_duration_ms: u32,
+
     /// Button text (defaults to "Copy")
     #[prop_or_default]
     pub copy_button_text: Option<String>,
@@ -76,6 +97,9 @@ pub struct CopyToClipboardProps {
     pub variant: CopyToClipboardVariant,
 
     /// Callback fired when copy operation completes
+    ///
+    /// The status is `Error` when neither the Clipboard API nor the
+    /// `execCommand` fallback could copy the text.
     #[prop_or_default]
     pub on_copy: Option<Callback<CustomEvent<CopyDetail>>>,
 
@@ -96,11 +120,20 @@ enum CopyState {
     Error,
 }
 
+impl From<CopyStatus> for CopyState {
+    fn from(status: CopyStatus) -> Self {
+        match status {
+            CopyStatus::Success => Self::Success,
+            CopyStatus::Error => Self::Error,
+        }
+    }
+}
+
 /// CopyToClipboard component for copying text to the system clipboard.
 ///
 /// This component provides a button that copies text to the clipboard and shows
 /// feedback to the user about the operation status. It uses the modern Clipboard API
-/// and falls back gracefully when the API is not available.
+/// and falls back to `document.execCommand("copy")` when the API is not available.
 ///
 /// # Example
 ///
@@ -158,14 +191,16 @@ enum CopyState {
 ///
 /// # Browser Compatibility
 ///
-/// The component uses the modern Clipboard API which is widely supported in
-/// modern browsers. The copy operation requires a secure context (HTTPS or localhost).
+/// The component uses the modern Clipboard API, which requires a secure context
+/// (HTTPS or localhost). Elsewhere, or when the API rejects the write, the text
+/// is copied through `document.execCommand("copy")`; rich text is then dropped.
 #[function_component(CopyToClipboard)]
 pub fn copy_to_clipboard(props: &CopyToClipboardProps) -> Html {
     let _metadata = ComponentMetadata::new("CopyToClipboard");
 
-    // Track copy state
+    // Track copy state, and the timer resetting it to idle
     let copy_state = use_state(|| CopyState::Idle);
+    let reset_timer = use_mut_ref(|| None::<Timeout>);
 
     // Get window for clipboard API access
     let window = web_sys::window();
@@ -187,7 +222,10 @@ pub fn copy_to_clipboard(props: &CopyToClipboardProps) -> Html {
     // Handle click to copy
     let on_click = {
         let copy_text = props.copy_text.clone();
+        let copy_html = props.text_to_copy_html.clone();
+        let feedback_duration_ms = props.feedback_duration_ms;
         let copy_state = copy_state.clone();
+        let reset_timer = reset_timer.clone();
         let on_copy = props.on_copy.clone();
         let disabled = props.disabled;
         let window = window.clone();
@@ -200,46 +238,31 @@ pub fn copy_to_clipboard(props: &CopyToClipboardProps) -> Html {
             }
 
             let copy_text = copy_text.clone();
+            let copy_html = copy_html.clone();
             let copy_state = copy_state.clone();
+            let reset_timer = reset_timer.clone();
             let on_copy = on_copy.clone();
             let window = window.clone();
 
             // Spawn async task to copy to clipboard
             wasm_bindgen_futures::spawn_local(async move {
-                let result = copy_to_clipboard_async(&copy_text, window).await;
-
-                match result {
-                    Ok(_) => {
-                        copy_state.set(CopyState::Success);
-                        if let Some(callback) = &on_copy {
-                            callback.emit(CustomEvent::new_non_cancelable(CopyDetail {
-                                status: CopyStatus::Success,
-                            }));
-                        }
-
-                        // Reset state after 2 seconds
-                        let copy_state = copy_state.clone();
-                        gloo_timers::callback::Timeout::new(2000, move || {
-                            copy_state.set(CopyState::Idle);
-                        })
-                        .forget();
-                    }
-                    Err(_) => {
-                        copy_state.set(CopyState::Error);
-                        if let Some(callback) = &on_copy {
-                            callback.emit(CustomEvent::new_non_cancelable(CopyDetail {
-                                status: CopyStatus::Error,
-                            }));
-                        }
-
-                        // Reset state after 2 seconds
-                        let copy_state = copy_state.clone();
-                        gloo_timers::callback::Timeout::new(2000, move || {
-                            copy_state.set(CopyState::Idle);
-                        })
-                        .forget();
-                    }
+                let status =
+                    match copy_to_clipboard_async(&copy_text, copy_html.as_deref(), window).await {
+                        Ok(()) => CopyStatus::Success,
+                        Err(_) => CopyStatus::Error,
+                    };
+
+                copy_state.set(status.into());
+                if let Some(callback) = &on_copy {
+                    callback.emit(CustomEvent::new_non_cancelable(CopyDetail { status }));
                 }
+
+                // Reset to idle after the feedback duration; a newer copy
+                // replaces, and so cancels, a pending reset
+                let timer = Timeout::new(feedback_duration_ms, move || {
+                    copy_state.set(CopyState::Idle);
+                });
+                *reset_timer.borrow_mut() = Some(timer);
             });
         })
     };
@@ -296,7 +319,7 @@ pub fn copy_to_clipboard(props: &CopyToClipboardProps) -> Html {
         None
     };
 
-    html! {
+    let root = html! {
         <span class={class}>
             <button
                 class="awsui-copy-to-clipboard-button"
@@ -318,20 +341,101 @@ pub fn copy_to_clipboard(props: &CopyToClipboardProps) -> Html {
                 </span>
             }
         </span>
-    }
+    };
+    apply_root_attributes(root, &props.base)
 }
 
-/// Async helper function to copy text to clipboard
-async fn copy_to_clipboard_async(text: &str, window: Option<Window>) -> Result<(), JsValue> {
+/// Copies text to the clipboard, with rich text when given and supported
+///
+/// Falls back to [`copy_with_exec_command`] when the Clipboard API is
+/// missing or rejects the write.
+async fn copy_to_clipboard_async(
+    text: &str,
+    html: Option<&str>,
+    window: Option<Window>,
+) -> Result<(), JsValue> {
     let window = window.ok_or_else(|| JsValue::from_str("No window available"))?;
 
+    // `navigator.clipboard` is undefined outside of secure contexts
     let navigator = window.navigator();
-    let clipboard = navigator.clipboard();
+    let has_clipboard = js_sys::Reflect::get(&navigator, &JsValue::from_str("clipboard"))
+        .is_ok_and(|clipboard| !clipboard.is_undefined());
+    if has_clipboard {
+        let clipboard = navigator.clipboard();
+        let has_clipboard_item =
+            js_sys::Reflect::has(&window, &JsValue::from_str("ClipboardItem")).unwrap_or(false);
+        let rich_item = html
+            .filter(|_| has_clipboard_item)
+            .and_then(|html| rich_clipboard_item(text, html).ok());
+        let promise = match rich_item {
+            Some(item) => clipboard.write(&js_sys::Array::of1(&item)),
+            None => clipboard.write_text(text),
+        };
+        if JsFuture::from(promise).await.is_ok() {
+            return Ok(());
+        }
+    }
 
-    // Use modern Clipboard API
-    let promise = clipboard.write_text(text);
-    JsFuture::from(promise).await?;
-    Ok(())
+    copy_with_exec_command(&window, text)
+}
+
+/// Builds a clipboard item holding both `text/plain` and `text/html`
+fn rich_clipboard_item(text: &str, html: &str) -> Result<ClipboardItem, JsValue> {
+    let blob = |content: &str, mime_type: &str| -> Result<JsValue, JsValue> {
+        let options = BlobPropertyBag::new();
+        options.set_type(mime_type);
+        Blob::new_with_str_sequence_and_options(
+            &js_sys::Array::of1(&JsValue::from_str(content)),
+            &options,
+        )
+        .map(JsValue::from)
+    };
+
+    let record = js_sys::Object::new();
+    let plain = blob(text, "text/plain")?;
+    let rich = blob(html, "text/html")?;
+    js_sys::Reflect::set(&record, &JsValue::from_str("text/plain"), &plain)?;
+    js_sys::Reflect::set(&record, &JsValue::from_str("text/html"), &rich)?;
+    ClipboardItem::new_with_record_from_str_to_blob_promise(&record)
+}
+
+/// Copies text by selecting it in an off-screen textarea and running
+/// `document.execCommand("copy")`, restoring focus afterwards
+fn copy_with_exec_command(window: &Window, text: &str) -> Result<(), JsValue> {
+    let document = window
+        .document()
+        .ok_or_else(|| JsValue::from_str("No document available"))?;
+    let body = document
+        .body()
+        .ok_or_else(|| JsValue::from_str("No body available"))?;
+    let previous_focus = document
+        .active_element()
+        .and_then(|element| element.dyn_into::<HtmlElement>().ok());
+
+    let textarea: HtmlTextAreaElement = document.create_element("textarea")?.dyn_into()?;
+    textarea.set_value(text);
+    textarea.set_read_only(true);
+    textarea.set_attribute(
+        "style",
+        "position: fixed; top: 0; left: 0; opacity: 0; pointer-events: none",
+    )?;
+    body.append_child(&textarea)?;
+    textarea.select();
+
+    let copied = match document.dyn_into::<HtmlDocument>() {
+        Ok(document) => document.exec_command("copy"),
+        Err(_) => Err(JsValue::from_str("Not an HTML document")),
+    };
+    textarea.remove();
+    if let Some(element) = previous_focus {
+        let _ = element.focus();
+    }
+
+    match copied {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(JsValue::from_str("Copy command was rejected")),
+        Err(error) => Err(error),
+    }
 }
 
 #[cfg(test)]
@@ -392,6 +496,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_copy_state_from_status() {
+        assert_eq!(CopyState::from(CopyStatus::Success), CopyState::Success);
+        assert_eq!(CopyState::from(CopyStatus::Error), CopyState::Error);
+    }
+
+    #[test]
+    fn test_feedback_duration_default() {
+        let props = yew::props!(CopyToClipboardProps { copy_text: "text" });
+        assert_eq!(props.feedback_duration_ms, 2000);
+        assert_eq!(props.text_to_copy_html, None);
+    }
+
     #[test]
     fn test_copy_state_equality() {
         assert_eq!(CopyState::Idle, CopyState::Idle);
@@ -408,7 +525,7 @@ mod tests {
         let event = CustomEvent::new_non_cancelable(detail);
 
         assert!(!event.cancelable);
-        assert!(!event.default_prevented);
+        assert!(!event.default_prevented());
         assert_eq!(event.detail.status, CopyStatus::Success);
     }
 }
@@ -6,10 +6,19 @@
 //! Provides a controlled date input with a calendar dropdown for visual date selection.
 //! Supports keyboard navigation, manual text entry, and validation.
 
+use crate::form_field::{use_field_association, use_validation_state};
+use crate::form_state::use_field_tracking;
+use crate::internal::date_time::{
+    DateGranularity, DateValue, TimeValue, format_date_time, get_days_in_month, parse_date,
+    parse_date_display, parse_date_time,
+};
+use crate::internal::dropdown::{use_dropdown_position, use_outside_dismiss};
 use crate::internal::{
     AriaAttributes, BaseComponentProps, ClassBuilder, ComponentMetadata, CustomEvent,
+    apply_root_attributes,
 };
-use web_sys::{HtmlInputElement, KeyboardEvent};
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement, HtmlInputElement, KeyboardEvent};
 use yew::prelude::*;
 
 #[cfg(target_arch = "wasm32")]
@@ -18,7 +27,8 @@ use js_sys;
 /// Event detail for date picker change events
 #[derive(Clone, PartialEq, Debug)]
 pub struct DatePickerChangeDetail {
-    /// The new date value in YYYY-MM-DD format
+    /// The new date value in YYYY-MM-DD format, followed by the time of day
+    /// (THH:MM or THH:MM:SS) at finer granularities
     pub value: String,
 }
 
@@ -30,9 +40,24 @@ pub struct DatePickerProps {
     pub base: BaseComponentProps,
 
     /// Current date value in YYYY-MM-DD format (controlled component)
+    ///
+    /// A time of day (YYYY-MM-DDTHH:MM or YYYY-MM-DDTHH:MM:SS) is accepted
+    /// at any granularity.
     #[prop_or_default]
     pub value: String,
 
+    /// Finest unit of the value (default: `Day`)
+    ///
+    /// At `Minute` and `Second` granularity a time input is shown next to
+    /// the calendar button, and the value includes the time of day; an
+    /// empty time is midnight.
+    #[prop_or_default]
+    pub granularity: DateGranularity,
+
+    /// ARIA label for the time input (default: "Time")
+    #[prop_or_default]
+    pub time_aria_label: Option<String>,
+
     /// Placeholder text (default: "YYYY/MM/DD")
     #[prop_or_default]
     pub placeholder: Option<String>,
@@ -69,6 +94,18 @@ pub struct DatePickerProps {
     #[prop_or_default]
     pub open_calendar_aria_label: Option<String>,
 
+    /// Whether a date (in YYYY-MM-DD format) can be selected
+    ///
+    /// Disabled dates are shown in the calendar but can't be selected, and
+    /// keyboard navigation skips them. All dates are enabled by default.
+    #[prop_or_default]
+    pub is_date_enabled: Option<Callback<String, bool>>,
+
+    /// First day of the calendar week, 0 = Sunday to 6 = Saturday
+    /// (default: 0)
+    #[prop_or_default]
+    pub start_of_week: Option<u8>,
+
     /// Callback fired when the date value changes
     #[prop_or_default]
     pub on_change: Option<Callback<CustomEvent<DatePickerChangeDetail>>>,
@@ -102,87 +139,6 @@ pub struct DatePickerProps {
     pub auto_focus: bool,
 }
 
-/// Simple date structure for internal use
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
-struct DateValue {
-    year: i32,
-    month: u32,
-    day: u32,
-}
-
-impl DateValue {
-    /// Create a new date value
-    fn new(year: i32, month: u32, day: u32) -> Option<Self> {
-        if !(1..=12).contains(&month) {
-            return None;
-        }
-        let days_in_month = get_days_in_month(year, month);
-        if day < 1 || day > days_in_month {
-            return None;
-        }
-        Some(Self { year, month, day })
-    }
-
-    /// Format as YYYY-MM-DD
-    fn format(&self) -> String {
-        format!("{:04}-{:02}-{:02}", self.year, self.month, self.day)
-    }
-
-    /// Format for display (YYYY/MM/DD)
-    fn format_display(&self) -> String {
-        format!("{:04}/{:02}/{:02}", self.year, self.month, self.day)
-    }
-}
-
-/// Parse date string in YYYY-MM-DD format
-fn parse_date(s: &str) -> Option<DateValue> {
-    let parts: Vec<&str> = s.split('-').collect();
-    if parts.len() != 3 {
-        return None;
-    }
-
-    let year = parts[0].parse::<i32>().ok()?;
-    let month = parts[1].parse::<u32>().ok()?;
-    let day = parts[2].parse::<u32>().ok()?;
-
-    DateValue::new(year, month, day)
-}
-
-/// Parse date string in display format (YYYY/MM/DD)
-fn parse_date_display(s: &str) -> Option<DateValue> {
-    let parts: Vec<&str> = s.split('/').collect();
-    if parts.len() != 3 {
-        return None;
-    }
-
-    let year = parts[0].parse::<i32>().ok()?;
-    let month = parts[1].parse::<u32>().ok()?;
-    let day = parts[2].parse::<u32>().ok()?;
-
-    DateValue::new(year, month, day)
-}
-
-/// Get the number of days in a given month
-fn get_days_in_month(year: i32, month: u32) -> u32 {
-    match month {
-        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
-        4 | 6 | 9 | 11 => 30,
-        2 => {
-            if is_leap_year(year) {
-                29
-            } else {
-                28
-            }
-        }
-        _ => 0,
-    }
-}
-
-/// Check if a year is a leap year
-fn is_leap_year(year: i32) -> bool {
-    (year % 4 == 0 && year % 100 != 0) || (year % 400 == 0)
-}
-
 /// Get the day of week for the first day of the month (0 = Sunday, 6 = Saturday)
 fn get_first_day_of_month(year: i32, month: u32) -> u32 {
     // Zeller's congruence algorithm
@@ -250,6 +206,68 @@ const MONTH_NAMES: [&str; 12] = [
 /// Day names for calendar header
 const DAY_NAMES: [&str; 7] = ["Su", "Mo", "Tu", "We", "Th", "Fr", "Sa"];
 
+/// Background of today's date in the calendar
+const TODAY_STYLE: &str = "background-color: var(--awsui-color-background-calendar-current-date)";
+
+/// Background and border of the selected date in the calendar
+const SELECTED_DAY_STYLE: &str = "background-color: var(--awsui-color-background-item-selected); \
+     border-color: var(--awsui-color-border-item-selected)";
+
+/// Number of steps an arrow key looks ahead for an enabled date
+const MAX_DATE_STEPS: i64 = 366;
+
+/// Day names in grid order for a week starting on `start_of_week`
+fn weekday_names(start_of_week: u32) -> impl Iterator<Item = &'static str> {
+    (0..7).map(move |offset| DAY_NAMES[((start_of_week + offset) % 7) as usize])
+}
+
+/// Days of a month in the weeks of a grid starting on `start_of_week`;
+/// cells outside of the month are `None`
+fn calendar_weeks(year: i32, month: u32, start_of_week: u32) -> Vec<[Option<u32>; 7]> {
+    let offset = (get_first_day_of_month(year, month) + 7 - start_of_week) % 7;
+    let cells = offset + get_days_in_month(year, month);
+    (0..cells.div_ceil(7))
+        .map(|week| {
+            std::array::from_fn(|column| {
+                let cell = week * 7 + column as u32;
+                (offset..cells).contains(&cell).then(|| cell - offset + 1)
+            })
+        })
+        .collect()
+}
+
+/// Gets the date a calendar key moves the focus to, or `None` for other
+/// keys or when no enabled date is within reach
+///
+/// The arrow keys move by day and week, skipping disabled dates; PageUp and
+/// PageDown move by month, Home and End to the first and last day of the
+/// month.
+fn calendar_key_target(
+    focused: DateValue,
+    key: &str,
+    is_enabled: impl Fn(DateValue) -> bool,
+) -> Option<DateValue> {
+    let step = match key {
+        "ArrowLeft" => -1,
+        "ArrowRight" => 1,
+        "ArrowUp" => -7,
+        "ArrowDown" => 7,
+        "PageUp" => return Some(focused.add_months(-1)),
+        "PageDown" => return Some(focused.add_months(1)),
+        "Home" => return Some(DateValue { day: 1, ..focused }),
+        "End" => {
+            return Some(DateValue {
+                day: get_days_in_month(focused.year, focused.month),
+                ..focused
+            });
+        }
+        _ => return None,
+    };
+    (1..=MAX_DATE_STEPS)
+        .map(|steps| focused.add_days(step * steps))
+        .find(|date| is_enabled(*date))
+}
+
 /// DatePicker component for date selection with a calendar dropdown.
 ///
 /// # Example
@@ -282,11 +300,22 @@ const DAY_NAMES: [&str; 7] = ["Su", "Mo", "Tu", "We", "Th", "Fr", "Sa"];
 pub fn date_picker(props: &DatePickerProps) -> Html {
     let _metadata = ComponentMetadata::new("DatePicker");
     let input_ref = use_node_ref();
+    let validation = use_validation_state(props.invalid, props.warning);
+
+    // Id and ARIA references, merged with the enclosing FormField
+    let association = use_field_association(
+        props.control_id.clone(),
+        props.aria.labelledby.clone(),
+        props.aria.describedby.clone(),
+    );
+
     let is_calendar_open = use_state(|| false);
     let input_text = use_state(String::new);
+    let time_text = use_state(String::new);
+    let granularity = props.granularity;
 
     // Parse current value to determine displayed month
-    let current_value = parse_date(&props.value);
+    let current_value = parse_date_time(&props.value).map(|(date, _)| date);
     let today = get_today();
 
     // Current displayed month in calendar (defaults to selected date or today)
@@ -296,17 +325,55 @@ pub fn date_picker(props: &DatePickerProps) -> Html {
             .unwrap_or((today.year, today.month))
     });
 
-    // Focused day in calendar for keyboard navigation
-    let focused_day = use_state(|| current_value.map(|d| d.day).unwrap_or(1));
+    // Date that holds the roving focus of the calendar grid
+    let focused_date = use_state(|| current_value.unwrap_or(today));
+
+    // Whether the focused date should receive focus after rendering; only
+    // keyboard navigation and opening the calendar move the focus
+    let focus_day = use_mut_ref(|| false);
+
+    let calendar_ref = use_node_ref();
+    let calendar_button_ref = use_node_ref();
+    let root_ref = use_node_ref();
+    let anchor_ref = use_node_ref();
+    let dropdown_ref = use_node_ref();
+    let start_of_week = props.start_of_week.map_or(0, |day| u32::from(day % 7));
+
+    {
+        let calendar_ref = calendar_ref.clone();
+        let focus_day = focus_day.clone();
+        use_effect_with((*is_calendar_open, *focused_date), move |(open, date)| {
+            if *open && std::mem::take(&mut *focus_day.borrow_mut()) {
+                focus_calendar_day(&calendar_ref, *date);
+            }
+        });
+    }
+
+    // Close on outside interaction and keep the calendar anchored to the
+    // input row
+    use_outside_dismiss(*is_calendar_open, root_ref.clone(), {
+        let is_calendar_open = is_calendar_open.clone();
+        Callback::from(move |()| is_calendar_open.set(false))
+    });
+    // The calendar unmounts as soon as it closes
+    let position = use_dropdown_position(
+        *is_calendar_open,
+        *is_calendar_open,
+        anchor_ref.clone(),
+        dropdown_ref.clone(),
+    );
 
     // Update input text when value prop changes
-    use_effect_with(props.value.clone(), {
+    use_effect_with((props.value.clone(), granularity), {
         let input_text = input_text.clone();
-        move |value| {
-            if let Some(date) = parse_date(value) {
+        let time_text = time_text.clone();
+        move |(value, granularity)| {
+            if let Some((date, time)) = parse_date_time(value) {
                 input_text.set(date.format_display());
+                time_text.set(time.unwrap_or_default().format(*granularity));
             } else if value.is_empty() {
                 input_text.set(String::new());
+                time_text.set(String::new());
             }
             || ()
         }
@@ -323,17 +390,23 @@ pub fn date_picker(props: &DatePickerProps) -> Html {
         })
     };
 
+    // Report the value and blur to the closest FormStateProvider
+    let on_field_blur = use_field_tracking(props.value.clone());
+
     // Handle input blur - validate and update value
     let on_blur_input = {
         let on_blur = props.on_blur.clone();
         let on_change = props.on_change.clone();
         let input_text = input_text.clone();
+        let time_text = time_text.clone();
         let value = props.value.clone();
+        let on_field_blur = on_field_blur.clone();
 
         Callback::from(move |_e: FocusEvent| {
-            // Try to parse the input text
-            if let Some(date) = parse_date_display(&input_text) {
-                let new_value = date.format();
+            // Try to parse the input text, in display or ISO format
+            if let Some(date) = parse_date_display(&input_text).or_else(|| parse_date(&input_text))
+            {
+                let new_value = format_date_time(date, TimeValue::parse(&time_text), granularity);
                 if new_value != value
                     && let Some(callback) = &on_change
                 {
@@ -352,13 +425,71 @@ pub fn date_picker(props: &DatePickerProps) -> Html {
                 }
             } else {
                 // Invalid input - revert to current value
-                if let Some(date) = parse_date(&value) {
+                if let Some((date, _)) = parse_date_time(&value) {
                     input_text.set(date.format_display());
                 } else {
                     input_text.set(String::new());
                 }
             }
 
+            on_field_blur.emit(());
+
+            if let Some(callback) = &on_blur {
+                callback.emit(());
+            }
+        })
+    };
+
+    // Handle time input change
+    let on_time_input = {
+        let time_text = time_text.clone();
+
+        Callback::from(move |e: InputEvent| {
+            if let Some(target) = e.target_dyn_into::<HtmlInputElement>() {
+                time_text.set(target.value());
+            }
+        })
+    };
+
+    // Handle time input blur - an empty time is midnight, an invalid one
+    // reverts to the time of the current value
+    let on_blur_time = {
+        let on_blur = props.on_blur.clone();
+        let on_change = props.on_change.clone();
+        let time_text = time_text.clone();
+        let value = props.value.clone();
+
+        Callback::from(move |_e: FocusEvent| {
+            let text = time_text.trim();
+            let time = if text.is_empty() {
+                Some(TimeValue::default())
+            } else {
+                TimeValue::parse(text)
+            };
+
+            match time {
+                Some(time) => {
+                    time_text.set(time.format(granularity));
+                    if let Some((date, _)) = parse_date_time(&value) {
+                        let new_value = format_date_time(date, Some(time), granularity);
+                        if new_value != value
+                            && let Some(callback) = &on_change
+                        {
+                            callback.emit(CustomEvent::new_non_cancelable(
+                                DatePickerChangeDetail { value: new_value },
+                            ));
+                        }
+                    }
+                }
+                None => time_text.set(
+                    parse_date_time(&value)
+                        .map(|(_, time)| time.unwrap_or_default().format(granularity))
+                        .unwrap_or_default(),
+                ),
+            }
+
+            on_field_blur.emit(());
+
             if let Some(callback) = &on_blur {
                 callback.emit(());
             }
@@ -376,147 +507,126 @@ pub fn date_picker(props: &DatePickerProps) -> Html {
         })
     };
 
-    // Toggle calendar
+    // Toggle calendar; opening it focuses the selected date, or today
     let on_calendar_button_click = {
         let is_calendar_open = is_calendar_open.clone();
+        let displayed_month = displayed_month.clone();
+        let focused_date = focused_date.clone();
+        let focus_day = focus_day.clone();
         let disabled = props.disabled;
         let read_only = props.read_only;
 
         Callback::from(move |e: web_sys::MouseEvent| {
             e.prevent_default();
-            if !disabled && !read_only {
-                is_calendar_open.set(!*is_calendar_open);
+            if disabled || read_only {
+                return;
             }
+            if !*is_calendar_open {
+                let date = current_value.unwrap_or_else(get_today);
+                displayed_month.set((date.year, date.month));
+                focused_date.set(date);
+                *focus_day.borrow_mut() = true;
+            }
+            is_calendar_open.set(!*is_calendar_open);
         })
     };
 
     // Handle day selection
     let on_day_select = {
         let is_calendar_open = is_calendar_open.clone();
+        let input_text = input_text.clone();
+        let time_text = time_text.clone();
+        let input_ref = input_ref.clone();
         let on_change = props.on_change.clone();
-        let displayed_month = displayed_month.clone();
+        let is_date_enabled = props.is_date_enabled.clone();
 
-        Callback::from(move |day: u32| {
-            let (year, month) = *displayed_month;
-            if let Some(date) = DateValue::new(year, month, day)
-                && let Some(callback) = &on_change
-            {
+        // The entered time is kept
+        Callback::from(move |date: DateValue| {
+            if !date_enabled(&is_date_enabled, date) {
+                return;
+            }
+            input_text.set(date.format_display());
+            if let Some(callback) = &on_change {
                 callback.emit(CustomEvent::new_non_cancelable(DatePickerChangeDetail {
-                    value: date.format(),
+                    value: format_date_time(date, TimeValue::parse(&time_text), granularity),
                 }));
             }
             is_calendar_open.set(false);
+            if let Some(input) = input_ref.cast::<HtmlElement>() {
+                let _ = input.focus();
+            }
         })
     };
 
-    // Navigate to previous month
-    let on_previous_month = {
-        let displayed_month = displayed_month.clone();
-
-        Callback::from(move |e: web_sys::MouseEvent| {
-            e.prevent_default();
-            let (year, month) = *displayed_month;
-            if month == 1 {
-                displayed_month.set((year - 1, 12));
-            } else {
-                displayed_month.set((year, month - 1));
-            };
-        })
-    };
-
-    // Navigate to next month
-    let on_next_month = {
+    // Navigate to previous or next month, keeping the day of the focused date
+    let on_month_step = |months: i32| {
         let displayed_month = displayed_month.clone();
+        let focused_date = focused_date.clone();
 
         Callback::from(move |e: web_sys::MouseEvent| {
             e.prevent_default();
-            let (year, month) = *displayed_month;
-            if month == 12 {
-                displayed_month.set((year + 1, 1));
-            } else {
-                displayed_month.set((year, month + 1));
-            };
+            let date = focused_date.add_months(months);
+            displayed_month.set((date.year, date.month));
+            focused_date.set(date);
         })
     };
+    let on_previous_month = on_month_step(-1);
+    let on_next_month = on_month_step(1);
 
     // Go to today
     let on_today_click = {
         let displayed_month = displayed_month.clone();
-        let focused_day = focused_day.clone();
+        let focused_date = focused_date.clone();
+        let focus_day = focus_day.clone();
 
         Callback::from(move |e: web_sys::MouseEvent| {
             e.prevent_default();
             let today = get_today();
             displayed_month.set((today.year, today.month));
-            focused_day.set(today.day);
+            focused_date.set(today);
+            *focus_day.borrow_mut() = true;
         })
     };
 
-    // Handle keyboard navigation in calendar
-    let on_calendar_keydown = {
-        let is_calendar_open = is_calendar_open.clone();
-        let focused_day = focused_day.clone();
+    // Handle keyboard navigation in the calendar grid
+    let on_grid_keydown = {
+        let focused_date = focused_date.clone();
         let displayed_month = displayed_month.clone();
-        let on_change = props.on_change.clone();
+        let focus_day = focus_day.clone();
+        let on_day_select = on_day_select.clone();
+        let is_date_enabled = props.is_date_enabled.clone();
 
         Callback::from(move |e: KeyboardEvent| {
-            if !*is_calendar_open {
+            let key = e.key();
+            if key == "Enter" || key == " " {
+                e.prevent_default();
+                on_day_select.emit(*focused_date);
                 return;
             }
 
-            let (year, month) = *displayed_month;
-            let current_day = *focused_day;
-            let days_in_month = get_days_in_month(year, month);
+            if let Some(date) = calendar_key_target(*focused_date, &key, |date| {
+                date_enabled(&is_date_enabled, date)
+            }) {
+                e.prevent_default();
+                displayed_month.set((date.year, date.month));
+                focused_date.set(date);
+                *focus_day.borrow_mut() = true;
+            }
+        })
+    };
 
-            match e.key().as_str() {
-                "ArrowLeft" => {
-                    e.prevent_default();
-                    if current_day > 1 {
-                        focused_day.set(current_day - 1);
-                    }
-                }
-                "ArrowRight" => {
-                    e.prevent_default();
-                    if current_day < days_in_month {
-                        focused_day.set(current_day + 1);
-                    }
-                }
-                "ArrowUp" => {
-                    e.prevent_default();
-                    if current_day > 7 {
-                        focused_day.set(current_day - 7);
-                    }
-                }
-                "ArrowDown" => {
-                    e.prevent_default();
-                    if current_day + 7 <= days_in_month {
-                        focused_day.set(current_day + 7);
-                    }
-                }
-                "Home" => {
-                    e.prevent_default();
-                    focused_day.set(1);
-                }
-                "End" => {
-                    e.prevent_default();
-                    focused_day.set(days_in_month);
-                }
-                "Enter" | " " => {
-                    e.prevent_default();
-                    if let Some(date) = DateValue::new(year, month, current_day)
-                        && let Some(callback) = &on_change
-                    {
-                        callback.emit(CustomEvent::new_non_cancelable(DatePickerChangeDetail {
-                            value: date.format(),
-                        }));
-                    }
-                    is_calendar_open.set(false);
-                }
-                "Escape" => {
-                    e.prevent_default();
-                    is_calendar_open.set(false);
+    // Close the calendar and return focus to its button
+    let on_calendar_keydown = {
+        let is_calendar_open = is_calendar_open.clone();
+        let calendar_button_ref = calendar_button_ref.clone();
+
+        Callback::from(move |e: KeyboardEvent| {
+            if e.key() == "Escape" {
+                e.prevent_default();
+                is_calendar_open.set(false);
+                if let Some(button) = calendar_button_ref.cast::<HtmlElement>() {
+                    let _ = button.focus();
                 }
-                _ => {}
             }
         })
     };
@@ -525,26 +635,41 @@ pub fn date_picker(props: &DatePickerProps) -> Html {
     let container_classes = ClassBuilder::new()
         .add("awsui-date-picker")
         .add_if(props.disabled, "awsui-date-picker-disabled")
-        .add_if(props.invalid, "awsui-date-picker-invalid")
-        .add_if(props.warning && !props.invalid, "awsui-date-picker-warning");
+        .add_if(validation.is_invalid(), "awsui-date-picker-invalid")
+        .add_if(validation.is_warning(), "awsui-date-picker-warning");
 
     let input_classes = ClassBuilder::new()
         .add("awsui-date-picker-input")
         .add_if(props.disabled, "awsui-date-picker-input-disabled")
-        .add_if(props.invalid, "awsui-date-picker-input-invalid");
+        .add_if(validation.is_invalid(), "awsui-date-picker-input-invalid")
+        .add_if(validation.is_warning(), "awsui-date-picker-input-warning");
+
+    // An entered time that can't be parsed; it reverts on blur
+    let is_time_invalid =
+        !time_text.trim().is_empty() && TimeValue::parse(time_text.trim()).is_none();
+
+    let time_input_classes = ClassBuilder::new()
+        .add("awsui-date-picker-time-input")
+        .add_if(props.disabled, "awsui-date-picker-input-disabled")
+        .add_if(
+            validation.is_invalid() || is_time_invalid,
+            "awsui-date-picker-input-invalid",
+        )
+        .add_if(validation.is_warning(), "awsui-date-picker-input-warning");
 
     let calendar_button_classes = ClassBuilder::new()
         .add("awsui-date-picker-calendar-button")
         .add_if(*is_calendar_open, "awsui-date-picker-calendar-button-open");
 
-    html! {
-        <div class={container_classes.build()}>
-            <div class="awsui-date-picker-input-wrapper">
+    let root = html! {
+        <div ref={root_ref} class={container_classes.build()}>
+            <div ref={anchor_ref} class="awsui-date-picker-input-wrapper">
                 <input
                     ref={input_ref}
                     type="text"
                     class={input_classes.build()}
-                    id={props.control_id.clone()}
+                    style={validation.control_style()}
+                    id={association.control_id}
                     name={props.name.clone()}
                     value={(*input_text).clone()}
                     placeholder={props.placeholder.clone().unwrap_or_else(|| "YYYY/MM/DD".to_string())}
@@ -553,15 +678,16 @@ pub fn date_picker(props: &DatePickerProps) -> Html {
                     autofocus={props.auto_focus}
                     aria-label={props.aria.label.clone()}
                     aria-required={props.aria_required.to_string()}
-                    aria-invalid={props.invalid.to_string()}
-                    aria-labelledby={props.aria.labelledby.clone()}
-                    aria-describedby={props.aria.describedby.clone()}
+                    aria-invalid={validation.is_invalid().then_some("true")}
+                    aria-labelledby={association.labelledby}
+                    aria-describedby={association.describedby.clone()}
                     oninput={on_input}
                     onblur={on_blur_input}
                     onfocus={on_focus_input}
                 />
 
                 <button
+                    ref={calendar_button_ref}
                     type="button"
                     class={calendar_button_classes.build()}
                     aria-label={props.open_calendar_aria_label.clone().unwrap_or_else(|| "Open calendar".to_string())}
@@ -573,59 +699,129 @@ pub fn date_picker(props: &DatePickerProps) -> Html {
                         {"📅"}
                     </span>
                 </button>
+                if granularity.has_time() {
+                    <input
+                        type="text"
+                        class={time_input_classes.build()}
+                        style={validation.control_style()}
+                        name={props.name.clone().map(|name| format!("{}-time", name))}
+                        value={(*time_text).clone()}
+                        placeholder={granularity.time_placeholder()}
+                        inputmode="numeric"
+                        disabled={props.disabled}
+                        readonly={props.read_only}
+                        aria-label={props.time_aria_label.clone().unwrap_or_else(|| "Time".to_string())}
+                        aria-required={props.aria_required.to_string()}
+                        aria-invalid={(validation.is_invalid() || is_time_invalid).then_some("true")}
+                        aria-describedby={association.describedby}
+                        oninput={on_time_input}
+                        onblur={on_blur_time}
+                    />
+                }
+                { validation.trigger_icon() }
             </div>
 
             if *is_calendar_open {
-                {render_calendar(
-                    *displayed_month,
-                    current_value,
+                {render_calendar(CalendarConfig {
+                    displayed_month: *displayed_month,
+                    selected_date: current_value,
                     today,
-                    *focused_day,
+                    focused_date: *focused_date,
+                    start_of_week,
+                    is_date_enabled: &props.is_date_enabled,
+                    calendar_ref,
+                    dropdown_ref,
+                    dropdown_style: position.map(|position| position.style()),
                     on_previous_month,
                     on_next_month,
                     on_today_click,
                     on_day_select,
+                    on_grid_keydown,
                     on_calendar_keydown,
-                    &props.previous_month_aria_label,
-                    &props.next_month_aria_label,
-                    &props.today_aria_label,
-                    *is_calendar_open,
-                )}
+                    previous_aria_label: &props.previous_month_aria_label,
+                    next_aria_label: &props.next_month_aria_label,
+                    today_aria_label: &props.today_aria_label,
+                })}
             }
         </div>
+    };
+    apply_root_attributes(root, &props.base)
+}
+
+/// Whether a date can be selected
+fn date_enabled(is_date_enabled: &Option<Callback<String, bool>>, date: DateValue) -> bool {
+    is_date_enabled
+        .as_ref()
+        .is_none_or(|callback| callback.emit(date.format()))
+}
+
+/// Focuses the day button of a date in the calendar
+fn focus_calendar_day(calendar: &NodeRef, date: DateValue) {
+    if let Some(calendar) = calendar.cast::<Element>()
+        && let Ok(Some(day)) =
+            calendar.query_selector(&format!("[data-date=\"{}\"]", date.format()))
+        && let Ok(day) = day.dyn_into::<HtmlElement>()
+    {
+        let _ = day.focus();
     }
 }
 
-/// Render the calendar dropdown
-#[allow(clippy::too_many_arguments)]
-fn render_calendar(
+/// Everything the calendar dropdown shows
+struct CalendarConfig<'a> {
     displayed_month: (i32, u32),
     selected_date: Option<DateValue>,
     today: DateValue,
-    focused_day: u32,
+    focused_date: DateValue,
+    start_of_week: u32,
+    is_date_enabled: &'a Option<Callback<String, bool>>,
+    calendar_ref: NodeRef,
+    dropdown_ref: NodeRef,
+    /// Fixed position anchoring the dropdown to the input row
+    dropdown_style: Option<String>,
     on_previous_month: Callback<web_sys::MouseEvent>,
     on_next_month: Callback<web_sys::MouseEvent>,
     on_today_click: Callback<web_sys::MouseEvent>,
-    on_day_select: Callback<u32>,
-    on_keydown: Callback<KeyboardEvent>,
-    previous_aria_label: &Option<String>,
-    next_aria_label: &Option<String>,
-    today_aria_label: &Option<String>,
-    is_calendar_open: bool,
-) -> Html {
-    let (year, month) = displayed_month;
-    let days_in_month = get_days_in_month(year, month);
-    let first_day = get_first_day_of_month(year, month);
-
-    let dropdown_classes = ClassBuilder::new()
-        .add("awsui-date-picker-dropdown")
-        .add_if(is_calendar_open, "awsui-date-picker-dropdown-open")
-        .build();
+    on_day_select: Callback<DateValue>,
+    on_grid_keydown: Callback<KeyboardEvent>,
+    on_calendar_keydown: Callback<KeyboardEvent>,
+    previous_aria_label: &'a Option<String>,
+    next_aria_label: &'a Option<String>,
+    today_aria_label: &'a Option<String>,
+}
+
+/// Render the calendar dropdown
+fn render_calendar(config: CalendarConfig) -> Html {
+    let (year, month) = config.displayed_month;
+    let today_label = config
+        .today_aria_label
+        .clone()
+        .unwrap_or_else(|| "Today".to_string());
+
+    let weeks = calendar_weeks(year, month, config.start_of_week)
+        .into_iter()
+        .enumerate()
+        .map(|(week, days)| {
+            html! {
+                <div class="awsui-date-picker-week" role="row" key={week}>
+                    {
+                        days.iter().enumerate().map(|(column, day)| match day.and_then(|day| DateValue::new(year, month, day)) {
+                            Some(date) => render_calendar_day(&config, date),
+                            None => html! {
+                                <div class="awsui-date-picker-day awsui-date-picker-day-empty" role="gridcell" key={format!("empty-{}", column)} />
+                            },
+                        }).collect::<Html>()
+                    }
+                </div>
+            }
+        })
+        .collect::<Html>();
 
     html! {
         <div
-            class={dropdown_classes}
-            onkeydown={on_keydown}
+            ref={config.dropdown_ref.clone()}
+            class="awsui-date-picker-dropdown awsui-date-picker-dropdown-open"
+            style={config.dropdown_style.clone()}
+            onkeydown={config.on_calendar_keydown.clone()}
             tabindex="-1"
         >
             <div class="awsui-date-picker-calendar">
@@ -634,51 +830,46 @@ fn render_calendar(
                     <button
                         type="button"
                         class="awsui-date-picker-header-button awsui-date-picker-header-prev"
-                        aria-label={previous_aria_label.clone().unwrap_or_else(|| "Previous month".to_string())}
-                        onclick={on_previous_month}
+                        aria-label={config.previous_aria_label.clone().unwrap_or_else(|| "Previous month".to_string())}
+                        onclick={config.on_previous_month.clone()}
                     >
                         {"◀"}
                     </button>
 
-                    <div class="awsui-date-picker-header-title">
+                    <div class="awsui-date-picker-header-title" aria-live="polite">
                         {format!("{} {}", MONTH_NAMES[(month - 1) as usize], year)}
                     </div>
 
                     <button
                         type="button"
                         class="awsui-date-picker-header-button awsui-date-picker-header-next"
-                        aria-label={next_aria_label.clone().unwrap_or_else(|| "Next month".to_string())}
-                        onclick={on_next_month}
+                        aria-label={config.next_aria_label.clone().unwrap_or_else(|| "Next month".to_string())}
+                        onclick={config.on_next_month.clone()}
                     >
                         {"▶"}
                     </button>
                 </div>
 
-                // Day names header
-                <div class="awsui-date-picker-weekdays">
-                    {
-                        DAY_NAMES.iter().map(|day_name| {
-                            html! {
-                                <div class="awsui-date-picker-weekday" key={*day_name}>
-                                    {*day_name}
-                                </div>
-                            }
-                        }).collect::<Html>()
-                    }
-                </div>
-
-                // Calendar grid
-                <div class="awsui-date-picker-grid" role="grid">
-                    {render_calendar_days(CalendarDaysConfig {
-                        year,
-                        month,
-                        days_in_month,
-                        first_day,
-                        selected_date,
-                        today,
-                        focused_day,
-                        on_day_select,
-                    })}
+                // Calendar grid with the day names header
+                <div
+                    ref={config.calendar_ref.clone()}
+                    class="awsui-date-picker-grid"
+                    role="grid"
+                    aria-label={format!("{} {}", MONTH_NAMES[(month - 1) as usize], year)}
+                    onkeydown={config.on_grid_keydown.clone()}
+                >
+                    <div class="awsui-date-picker-weekdays" role="row">
+                        {
+                            weekday_names(config.start_of_week).map(|day_name| {
+                                html! {
+                                    <div class="awsui-date-picker-weekday" role="columnheader" key={day_name}>
+                                        {day_name}
+                                    </div>
+                                }
+                            }).collect::<Html>()
+                        }
+                    </div>
+                    { weeks }
                 </div>
 
                 // Footer with "Today" button
@@ -686,10 +877,10 @@ fn render_calendar(
                     <button
                         type="button"
                         class="awsui-date-picker-today-button"
-                        aria-label={today_aria_label.clone().unwrap_or_else(|| "Today".to_string())}
-                        onclick={on_today_click}
+                        aria-label={today_label.clone()}
+                        onclick={config.on_today_click.clone()}
                     >
-                        {today_aria_label.clone().unwrap_or_else(|| "Today".to_string())}
+                        {today_label}
                     </button>
                 </div>
             </div>
@@ -697,80 +888,51 @@ fn render_calendar(
     }
 }
 
-/// Render the calendar days grid
-struct CalendarDaysConfig {
-    year: i32,
-    month: u32,
-    days_in_month: u32,
-    first_day: u32,
-    selected_date: Option<DateValue>,
-    today: DateValue,
-    focused_day: u32,
-    on_day_select: Callback<u32>,
-}
-
-fn render_calendar_days(config: CalendarDaysConfig) -> Html {
-    let CalendarDaysConfig {
-        year,
-        month,
-        days_in_month,
-        first_day,
-        selected_date,
-        today,
-        focused_day,
-        on_day_select,
-    } = config;
-    let mut days = Vec::new();
-
-    // Add empty cells for days before the first day of the month
-    for _ in 0..first_day {
-        days.push(html! {
-            <div class="awsui-date-picker-day awsui-date-picker-day-empty" key={format!("empty-{}", days.len())} />
-        });
-    }
+/// Render a day of the calendar grid
+fn render_calendar_day(config: &CalendarConfig, date: DateValue) -> Html {
+    let is_selected = config.selected_date == Some(date);
+    let is_today = config.today == date;
+    let is_focused = config.focused_date == date;
+    let is_enabled = date_enabled(config.is_date_enabled, date);
+
+    let day_classes = ClassBuilder::new()
+        .add("awsui-date-picker-day")
+        .add_if(is_selected, "awsui-date-picker-day-selected")
+        .add_if(is_today, "awsui-date-picker-day-today")
+        .add_if(is_focused, "awsui-date-picker-day-focused")
+        .add_if(!is_enabled, "awsui-date-picker-day-disabled");
+
+    let style = if is_selected {
+        Some(SELECTED_DAY_STYLE)
+    } else if is_today {
+        Some(TODAY_STYLE)
+    } else {
+        None
+    };
 
-    // Add cells for each day of the month
-    for day in 1..=days_in_month {
-        let is_selected = selected_date
-            .map(|d| d.year == year && d.month == month && d.day == day)
-            .unwrap_or(false);
-        let is_today = today.year == year && today.month == month && today.day == day;
-        let is_focused = day == focused_day;
-
-        let day_classes = ClassBuilder::new()
-            .add("awsui-date-picker-day")
-            .add_if(is_selected, "awsui-date-picker-day-selected")
-            .add_if(is_today, "awsui-date-picker-day-today")
-            .add_if(is_focused, "awsui-date-picker-day-focused");
-
-        let day_clone = day;
-        let on_click = {
-            let on_day_select = on_day_select.clone();
-            Callback::from(move |e: web_sys::MouseEvent| {
-                e.prevent_default();
-                on_day_select.emit(day_clone);
-            })
-        };
+    let on_click = {
+        let on_day_select = config.on_day_select.clone();
+        Callback::from(move |e: web_sys::MouseEvent| {
+            e.prevent_default();
+            on_day_select.emit(date);
+        })
+    };
 
-        days.push(html! {
+    html! {
+        <div class="awsui-date-picker-cell" role="gridcell" aria-selected={is_selected.to_string()} key={date.day}>
             <button
                 type="button"
                 class={day_classes.build()}
-                aria-selected={is_selected.to_string()}
-                aria-current={if is_today { "date" } else { "false" }}
+                style={style}
+                data-date={date.format()}
+                aria-current={is_today.then_some("date")}
+                aria-disabled={(!is_enabled).then_some("true")}
                 tabindex={if is_focused { "0" } else { "-1" }}
                 onclick={on_click}
-                key={day}
             >
-                {day}
+                {date.day}
             </button>
-        });
-    }
-
-    html! {
-        <>
-            { for days }
-        </>
+        </div>
     }
 }
 
@@ -778,74 +940,6 @@ fn render_calendar_days(config: CalendarDaysConfig) -> Html {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_parse_date_valid() {
-        let date = parse_date("2026-01-20").unwrap();
-        assert_eq!(date.year, 2026);
-        assert_eq!(date.month, 1);
-        assert_eq!(date.day, 20);
-    }
-
-    #[test]
-    fn test_parse_date_invalid_format() {
-        assert!(parse_date("20-01-2026").is_none());
-        assert!(parse_date("2026/01/20").is_none());
-        assert!(parse_date("invalid").is_none());
-        assert!(parse_date("").is_none());
-    }
-
-    #[test]
-    fn test_parse_date_invalid_values() {
-        assert!(parse_date("2026-13-01").is_none()); // Invalid month
-        assert!(parse_date("2026-00-01").is_none()); // Invalid month
-        assert!(parse_date("2026-01-32").is_none()); // Invalid day
-        assert!(parse_date("2026-01-00").is_none()); // Invalid day
-        assert!(parse_date("2026-02-30").is_none()); // Invalid day for February
-    }
-
-    #[test]
-    fn test_parse_date_display_valid() {
-        let date = parse_date_display("2026/01/20").unwrap();
-        assert_eq!(date.year, 2026);
-        assert_eq!(date.month, 1);
-        assert_eq!(date.day, 20);
-    }
-
-    #[test]
-    fn test_parse_date_display_invalid() {
-        assert!(parse_date_display("2026-01-20").is_none());
-        assert!(parse_date_display("invalid").is_none());
-    }
-
-    #[test]
-    fn test_date_value_format() {
-        let date = DateValue::new(2026, 1, 20).unwrap();
-        assert_eq!(date.format(), "2026-01-20");
-    }
-
-    #[test]
-    fn test_date_value_format_display() {
-        let date = DateValue::new(2026, 1, 20).unwrap();
-        assert_eq!(date.format_display(), "2026/01/20");
-    }
-
-    #[test]
-    fn test_get_days_in_month() {
-        assert_eq!(get_days_in_month(2026, 1), 31); // January
-        assert_eq!(get_days_in_month(2026, 2), 28); // February (non-leap)
-        assert_eq!(get_days_in_month(2024, 2), 29); // February (leap)
-        assert_eq!(get_days_in_month(2026, 4), 30); // April
-        assert_eq!(get_days_in_month(2026, 12), 31); // December
-    }
-
-    #[test]
-    fn test_is_leap_year() {
-        assert!(!is_leap_year(2026)); // Not a leap year
-        assert!(is_leap_year(2024)); // Divisible by 4
-        assert!(!is_leap_year(2100)); // Divisible by 100 but not 400
-        assert!(is_leap_year(2000)); // Divisible by 400
-    }
-
     #[test]
     fn test_get_first_day_of_month() {
         // January 2026 starts on Thursday (4)
@@ -854,16 +948,6 @@ mod tests {
         assert!(first_day < 7);
     }
 
-    #[test]
-    fn test_date_value_new_validates() {
-        assert!(DateValue::new(2026, 1, 20).is_some());
-        assert!(DateValue::new(2026, 13, 1).is_none()); // Invalid month
-        assert!(DateValue::new(2026, 0, 1).is_none()); // Invalid month
-        assert!(DateValue::new(2026, 1, 32).is_none()); // Invalid day
-        assert!(DateValue::new(2026, 2, 29).is_none()); // Invalid day for non-leap year
-        assert!(DateValue::new(2024, 2, 29).is_some()); // Valid day for leap year
-    }
-
     #[test]
     fn test_date_change_detail() {
         let detail = DatePickerChangeDetail {
@@ -887,12 +971,67 @@ mod tests {
     }
 
     #[test]
-    fn test_date_value_equality() {
-        let date1 = DateValue::new(2026, 1, 20).unwrap();
-        let date2 = DateValue::new(2026, 1, 20).unwrap();
-        let date3 = DateValue::new(2026, 1, 21).unwrap();
+    fn test_weekday_names_follow_start_of_week() {
+        let names: Vec<&str> = weekday_names(1).collect();
+        assert_eq!(names, vec!["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"]);
+        assert_eq!(weekday_names(0).next(), Some("Su"));
+    }
 
-        assert_eq!(date1, date2);
-        assert_ne!(date1, date3);
+    #[test]
+    fn test_calendar_weeks() {
+        // January 2026 starts on a Thursday
+        let weeks = calendar_weeks(2026, 1, 0);
+        assert_eq!(weeks.len(), 5);
+        assert_eq!(
+            weeks[0],
+            [None, None, None, None, Some(1), Some(2), Some(3)]
+        );
+        assert_eq!(weeks[4][6], Some(31));
+
+        let weeks = calendar_weeks(2026, 1, 1);
+        assert_eq!(weeks[0][3], Some(1));
+        assert_eq!(
+            weeks[4],
+            [
+                Some(26),
+                Some(27),
+                Some(28),
+                Some(29),
+                Some(30),
+                Some(31),
+                None
+            ]
+        );
+    }
+
+    #[test]
+    fn test_calendar_key_target() {
+        let date = DateValue::new(2026, 1, 31).unwrap();
+        let all = |_: DateValue| true;
+        assert_eq!(
+            calendar_key_target(date, "ArrowRight", all),
+            Some(DateValue::new(2026, 2, 1).unwrap())
+        );
+        assert_eq!(
+            calendar_key_target(date, "ArrowUp", all),
+            Some(DateValue::new(2026, 1, 24).unwrap())
+        );
+        assert_eq!(
+            calendar_key_target(date, "PageDown", all),
+            Some(DateValue::new(2026, 2, 28).unwrap())
+        );
+        assert_eq!(
+            calendar_key_target(date, "Home", all),
+            Some(DateValue::new(2026, 1, 1).unwrap())
+        );
+        assert_eq!(calendar_key_target(date, "Tab", all), None);
+
+        // Weekends are skipped: Friday 2026-01-30 moves to Monday 2026-02-02
+        let weekdays = |date: DateValue| !matches!((date.to_days() + 4).rem_euclid(7), 0 | 6);
+        assert_eq!(
+            calendar_key_target(DateValue::new(2026, 1, 30).unwrap(), "ArrowRight", weekdays),
+            Some(DateValue::new(2026, 2, 2).unwrap())
+        );
+        assert_eq!(calendar_key_target(date, "ArrowRight", |_| false), None);
     }
 }
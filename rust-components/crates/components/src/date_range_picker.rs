@@ -5,34 +5,72 @@
 //!
 //! Provides a controlled date range picker with support for absolute (calendar-based)
 //! and relative (preset) date range selection modes. Supports validation, disabled states,
-//! and custom relative options.
-
+//! and custom relative options. All user-visible text can be localized.
+//!
+//! Relative ranges end at the time of selection: the change detail carries
+//! their start and end as ISO 8601 UTC timestamps, and `is_valid_range`
+//! receives the dates they cover.
+
+use crate::form_field::use_validation_state;
+use crate::i18n::{I18nContext, use_i18n};
+use crate::internal::date_time::{
+    civil_from_days, days_from_civil, format_timestamp, get_days_in_month,
+};
 use crate::internal::{
     AriaAttributes, BaseComponentProps, ClassBuilder, ComponentMetadata, CustomEvent,
+    apply_root_attributes,
 };
-use web_sys::HtmlInputElement;
+use web_sys::{HtmlInputElement, HtmlSelectElement};
 use yew::prelude::*;
 
 /// Time unit for relative date ranges
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TimeUnit {
+    /// Seconds
+    Seconds,
+    /// Minutes
+    Minutes,
+    /// Hours
+    Hours,
     /// Days
     Days,
     /// Weeks
     Weeks,
     /// Months
     Months,
+    /// Years
+    Years,
 }
 
 impl TimeUnit {
+    /// All time units, from the shortest to the longest
+    pub const ALL: [TimeUnit; 7] = [
+        Self::Seconds,
+        Self::Minutes,
+        Self::Hours,
+        Self::Days,
+        Self::Weeks,
+        Self::Months,
+        Self::Years,
+    ];
+
     /// Returns the string representation of the time unit
     pub fn as_str(&self) -> &'static str {
         match self {
+            Self::Seconds => "seconds",
+            Self::Minutes => "minutes",
+            Self::Hours => "hours",
             Self::Days => "days",
             Self::Weeks => "weeks",
             Self::Months => "months",
+            Self::Years => "years",
         }
     }
+
+    /// Gets the time unit with the given string representation
+    fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|unit| unit.as_str() == value)
+    }
 }
 
 /// Range selector mode
@@ -55,6 +93,193 @@ impl RangeSelectorMode {
     }
 }
 
+/// Formats the label of a relative range, such as "Last 5 days"
+pub type RelativeRangeFormatter = fn(i32, TimeUnit) -> String;
+
+/// Formats the label of a time unit in the unit dropdown of a custom range
+pub type TimeUnitFormatter = fn(TimeUnit) -> String;
+
+/// English label for a relative range, used when no formatter is provided
+fn format_relative_range_en(amount: i32, unit: TimeUnit) -> String {
+    let unit = match (unit, amount == 1) {
+        (TimeUnit::Seconds, true) => "second",
+        (TimeUnit::Minutes, true) => "minute",
+        (TimeUnit::Hours, true) => "hour",
+        (TimeUnit::Days, true) => "day",
+        (TimeUnit::Weeks, true) => "week",
+        (TimeUnit::Months, true) => "month",
+        (TimeUnit::Years, true) => "year",
+        _ => unit.as_str(),
+    };
+    format!("Last {} {}", amount, unit)
+}
+
+/// English label for a time unit, used when no formatter is provided
+fn format_time_unit_en(unit: TimeUnit) -> String {
+    unit.as_str().to_string()
+}
+
+/// Key of the custom range option of the relative range dropdown
+const CUSTOM_RANGE_KEY: &str = "awsui-custom-range";
+
+/// Color of range validation errors
+const RANGE_ERROR_STYLE: &str = "color: var(--awsui-color-text-status-error)";
+
+/// I18n strings for the DateRangePicker component
+///
+/// Each string falls back to the closest [`I18nProvider`](crate::I18nProvider)
+/// message with the same name under the `date-range-picker` prefix (for
+/// example `date-range-picker.start_date_label`), and then to English.
+#[derive(Clone, PartialEq, Default)]
+#[allow(unpredictable_function_pointer_comparisons)]
+pub struct DateRangePickerI18nStrings {
+    /// Label of the absolute mode option (default: "Absolute")
+    pub absolute_mode_label: Option<String>,
+    /// Label of the relative mode option (default: "Relative")
+    pub relative_mode_label: Option<String>,
+    /// Label of the start date input (default: "Start date")
+    pub start_date_label: Option<String>,
+    /// Label of the end date input (default: "End date")
+    pub end_date_label: Option<String>,
+    /// Placeholder of the date inputs (default: "YYYY-MM-DD")
+    pub date_placeholder: Option<String>,
+    /// Label of the relative range dropdown (default: "Select time range")
+    pub relative_range_selection_heading: Option<String>,
+    /// ARIA label of the relative range dropdown
+    /// (default: "Select relative date range")
+    pub relative_range_selection_aria_label: Option<String>,
+    /// Empty option of the relative range dropdown (default: "Choose a time range")
+    pub relative_range_placeholder: Option<String>,
+    /// Formats labels of relative options that have an empty `label`,
+    /// handling pluralization (default: "Last 5 days", "Last 1 day")
+    pub format_relative_range: Option<RelativeRangeFormatter>,
+    /// Custom range option of the relative range dropdown
+    /// (default: "Custom range")
+    pub custom_range_option_label: Option<String>,
+    /// Label of the custom range amount input (default: "Duration")
+    pub custom_range_duration_label: Option<String>,
+    /// Label of the custom range unit dropdown (default: "Unit of time")
+    pub custom_range_unit_label: Option<String>,
+    /// Label of the button applying a custom range (default: "Apply")
+    pub apply_button_label: Option<String>,
+    /// Formats the options of the custom range unit dropdown
+    /// (default: "minutes", "days")
+    pub format_time_unit: Option<TimeUnitFormatter>,
+}
+
+/// Strings used to render a DateRangePicker after applying the fallback chain
+struct ResolvedStrings {
+    absolute_mode_label: String,
+    relative_mode_label: String,
+    start_date_label: String,
+    end_date_label: String,
+    date_placeholder: String,
+    relative_range_selection_heading: String,
+    relative_range_selection_aria_label: String,
+    relative_range_placeholder: String,
+    format_relative_range: RelativeRangeFormatter,
+    custom_range_option_label: String,
+    custom_range_duration_label: String,
+    custom_range_unit_label: String,
+    apply_button_label: String,
+    format_time_unit: TimeUnitFormatter,
+}
+
+impl ResolvedStrings {
+    /// Gets the display label of a relative option
+    fn relative_option_label(&self, option: &RelativeOption) -> String {
+        if option.label.is_empty() {
+            (self.format_relative_range)(option.amount, option.unit)
+        } else {
+            option.label.clone()
+        }
+    }
+}
+
+/// Resolves every user-visible string: `i18n_strings` prop, then the
+/// dedicated label props, then the I18nProvider, then English
+fn resolve_strings(props: &DateRangePickerProps, i18n: &I18nContext) -> ResolvedStrings {
+    let strings = &props.i18n_strings;
+    let resolve = |value: &Option<String>, key: &str, default: &str| {
+        value
+            .clone()
+            .or_else(|| i18n.message("date-range-picker", key).map(str::to_string))
+            .unwrap_or_else(|| default.to_string())
+    };
+
+    ResolvedStrings {
+        absolute_mode_label: resolve(
+            &strings.absolute_mode_label,
+            "absolute_mode_label",
+            "Absolute",
+        ),
+        relative_mode_label: resolve(
+            &strings.relative_mode_label,
+            "relative_mode_label",
+            "Relative",
+        ),
+        start_date_label: resolve(
+            &strings
+                .start_date_label
+                .clone()
+                .or(props.start_date_label.clone()),
+            "start_date_label",
+            "Start date",
+        ),
+        end_date_label: resolve(
+            &strings
+                .end_date_label
+                .clone()
+                .or(props.end_date_label.clone()),
+            "end_date_label",
+            "End date",
+        ),
+        date_placeholder: resolve(
+            &props
+                .placeholder
+                .clone()
+                .or(strings.date_placeholder.clone()),
+            "date_placeholder",
+            "YYYY-MM-DD",
+        ),
+        relative_range_selection_heading: resolve(
+            &strings.relative_range_selection_heading,
+            "relative_range_selection_heading",
+            "Select time range",
+        ),
+        relative_range_selection_aria_label: resolve(
+            &strings.relative_range_selection_aria_label,
+            "relative_range_selection_aria_label",
+            "Select relative date range",
+        ),
+        relative_range_placeholder: resolve(
+            &strings.relative_range_placeholder,
+            "relative_range_placeholder",
+            "Choose a time range",
+        ),
+        format_relative_range: strings
+            .format_relative_range
+            .unwrap_or(format_relative_range_en),
+        custom_range_option_label: resolve(
+            &strings.custom_range_option_label,
+            "custom_range_option_label",
+            "Custom range",
+        ),
+        custom_range_duration_label: resolve(
+            &strings.custom_range_duration_label,
+            "custom_range_duration_label",
+            "Duration",
+        ),
+        custom_range_unit_label: resolve(
+            &strings.custom_range_unit_label,
+            "custom_range_unit_label",
+            "Unit of time",
+        ),
+        apply_button_label: resolve(&strings.apply_button_label, "apply_button_label", "Apply"),
+        format_time_unit: strings.format_time_unit.unwrap_or(format_time_unit_en),
+    }
+}
+
 /// A date range with start and end dates
 #[derive(Clone, PartialEq, Debug, Default)]
 pub struct DateRange {
@@ -128,7 +353,82 @@ impl DateRange {
     }
 }
 
-type DateRangeValidator = Callback<(Option<String>, Option<String>), bool>;
+/// Result of validating a range with `is_valid_range`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RangeValidationResult {
+    /// The range can be applied
+    Valid,
+    /// The range can't be applied; the message is shown in the picker
+    Invalid(String),
+}
+
+type DateRangeValidator = Callback<DateRange, RangeValidationResult>;
+
+/// Validates a range with `is_valid_range`
+///
+/// Without a validator, a range ending before it starts is rejected without
+/// a message.
+fn validate_range(
+    validator: &Option<DateRangeValidator>,
+    range: &DateRange,
+) -> RangeValidationResult {
+    match validator {
+        Some(validator) => validator.emit(range.clone()),
+        None if range.is_valid() => RangeValidationResult::Valid,
+        None => RangeValidationResult::Invalid(String::new()),
+    }
+}
+
+/// Current time in seconds since the Unix epoch
+fn current_timestamp() -> i64 {
+    #[cfg(target_arch = "wasm32")]
+    {
+        (js_sys::Date::now() / 1000.0) as i64
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs() as i64)
+    }
+}
+
+/// Start of the relative range of `amount` units ending at `now`, in
+/// seconds since the Unix epoch
+///
+/// Months and years are calendar months: the start keeps the day of month
+/// and time of day of `now`, on the last day of a shorter month.
+fn relative_range_start(now: i64, amount: i32, unit: TimeUnit) -> i64 {
+    let seconds = match unit {
+        TimeUnit::Seconds => 1,
+        TimeUnit::Minutes => 60,
+        TimeUnit::Hours => 3600,
+        TimeUnit::Days => 86_400,
+        TimeUnit::Weeks => 604_800,
+        TimeUnit::Months | TimeUnit::Years => {
+            let months = if unit == TimeUnit::Years {
+                amount * 12
+            } else {
+                amount
+            };
+            let (year, month, day) = civil_from_days(now.div_euclid(86_400));
+            let index = year * 12 + month as i32 - 1 - months;
+            let (year, month) = (index.div_euclid(12), index.rem_euclid(12) as u32 + 1);
+            let day = day.min(get_days_in_month(year, month));
+            return days_from_civil(year, month, day) * 86_400 + now.rem_euclid(86_400);
+        }
+    };
+    now - i64::from(amount) * seconds
+}
+
+/// Parses the amount of a custom range, a positive whole number
+fn parse_custom_amount(value: &str) -> Option<i32> {
+    value
+        .trim()
+        .parse::<i32>()
+        .ok()
+        .filter(|amount| *amount > 0)
+}
 
 /// A relative date range option
 #[derive(Clone, PartialEq, Debug)]
@@ -189,8 +489,53 @@ impl RelativeOption {
 /// Event detail for date range picker change events
 #[derive(Clone, PartialEq)]
 pub struct DateRangePickerChangeDetail {
-    /// The new date range value
+    /// The new date range value; `None` for relative selections
     pub value: Option<DateRange>,
+    /// The selected relative range, for relative selections
+    ///
+    /// A custom range has a generated key and is labelled with
+    /// `format_relative_range`.
+    pub relative_range: Option<RelativeOption>,
+    /// Start of a relative selection as an ISO 8601 UTC timestamp
+    pub start_timestamp: Option<String>,
+    /// End of a relative selection, the time it was selected, as an ISO 8601
+    /// UTC timestamp
+    pub end_timestamp: Option<String>,
+}
+
+impl DateRangePickerChangeDetail {
+    /// Detail of an absolute selection
+    pub fn absolute(value: Option<DateRange>) -> Self {
+        Self {
+            value,
+            relative_range: None,
+            start_timestamp: None,
+            end_timestamp: None,
+        }
+    }
+
+    /// Detail of a relative selection ending at `now`, in seconds since the
+    /// Unix epoch
+    fn relative(option: RelativeOption, now: i64) -> Self {
+        let start = relative_range_start(now, option.amount, option.unit);
+        Self {
+            value: None,
+            relative_range: Some(option),
+            start_timestamp: Some(format_timestamp(start)),
+            end_timestamp: Some(format_timestamp(now)),
+        }
+    }
+
+    /// Dates of the start and end timestamps
+    fn timestamp_dates(&self) -> DateRange {
+        let date = |timestamp: &Option<String>| {
+            timestamp
+                .as_deref()
+                .and_then(|timestamp| timestamp.split('T').next())
+                .map(str::to_string)
+        };
+        DateRange::new(date(&self.start_timestamp), date(&self.end_timestamp))
+    }
 }
 
 /// Properties for the DateRangePicker component
@@ -213,9 +558,16 @@ pub struct DateRangePickerProps {
     pub disabled: bool,
 
     /// Whether the picker has invalid content
+    ///
+    /// Takes precedence over `warning`. An incomplete or reversed range is
+    /// always shown as invalid.
     #[prop_or_default]
     pub invalid: bool,
 
+    /// Whether to show a warning
+    #[prop_or_default]
+    pub warning: bool,
+
     /// Range selector mode (absolute or relative)
     #[prop_or_default]
     pub range_selector_mode: RangeSelectorMode,
@@ -230,7 +582,10 @@ pub struct DateRangePickerProps {
 
     /// Custom validation callback
     ///
-    /// Receives (start, end) and returns true if the range is valid
+    /// Receives the selected range, or the dates covered by a relative
+    /// range, before it is applied. An invalid range is not applied and its
+    /// message is shown in the picker; the Apply button of a custom relative
+    /// range is disabled while the range is invalid.
     #[prop_or_default]
     pub is_valid_range: Option<DateRangeValidator>,
 
@@ -265,6 +620,12 @@ pub struct DateRangePickerProps {
     /// End date label (for accessibility)
     #[prop_or_default]
     pub end_date_label: Option<String>,
+
+    /// I18n strings for all user-visible text
+    ///
+    /// Takes precedence over `start_date_label` and `end_date_label`.
+    #[prop_or_default]
+    pub i18n_strings: DateRangePickerI18nStrings,
 }
 
 /// DateRangePicker component for selecting a date range with start and end dates.
@@ -273,10 +634,17 @@ pub struct DateRangePickerProps {
 /// absolute dates (with calendar inputs) or relative presets (like "Last 7 days").
 /// Supports validation, disabled states, and custom relative options.
 ///
+/// Text is localized through `i18n_strings`, falling back to the closest
+/// [`I18nProvider`](crate::I18nProvider) and then to English. Relative
+/// options with an empty label are labelled with `format_relative_range`.
+/// The date inputs are native, so their calendars follow the browser locale.
+///
 /// # Example
 ///
 /// ```rust
-/// use cloudscape_components::{DateRangePicker, DateRange, RelativeOption, TimeUnit};
+/// use cloudscape_components::{
+///     CustomEvent, DateRangePicker, DateRangePickerChangeDetail, RelativeOption, TimeUnit,
+/// };
 /// use yew::prelude::*;
 ///
 /// #[function_component(MyComponent)]
@@ -313,12 +681,18 @@ pub fn date_range_picker(props: &DateRangePickerProps) -> Html {
     let end_input_ref = use_node_ref();
     let current_mode = use_state(|| props.range_selector_mode);
     let selected_relative = use_state(|| None::<String>);
+    let range_error = use_state(|| None::<String>);
+    let custom_amount = use_state(String::new);
+    let custom_unit = use_state(|| TimeUnit::Days);
+    let i18n = use_i18n();
+    let strings = resolve_strings(props, &i18n);
 
     // Handle start date change
     let on_start_change = {
         let value = props.value.clone();
         let on_change = props.on_change.clone();
         let is_valid_range = props.is_valid_range.clone();
+        let range_error = range_error.clone();
 
         Callback::from(move |e: InputEvent| {
             if let Some(target) = e.target_dyn_into::<HtmlInputElement>() {
@@ -331,27 +705,19 @@ pub fn date_range_picker(props: &DateRangePickerProps) -> Html {
                 let end = value.as_ref().and_then(|v| v.end.clone());
 
                 // Validate range
-                let is_valid = if let Some(validator) = &is_valid_range {
-                    validator.emit((start.clone(), end.clone()))
-                } else {
-                    match (&start, &end) {
-                        (Some(s), Some(e)) => e >= s,
-                        _ => true,
-                    }
-                };
-
-                if is_valid {
-                    let new_range = if start.is_none() && end.is_none() {
-                        None
-                    } else {
-                        Some(DateRange { start, end })
-                    };
-
-                    if let Some(callback) = &on_change {
-                        callback.emit(CustomEvent::new_non_cancelable(
-                            DateRangePickerChangeDetail { value: new_range },
-                        ));
+                let range = DateRange { start, end };
+                match validate_range(&is_valid_range, &range) {
+                    RangeValidationResult::Valid => {
+                        range_error.set(None);
+                        let new_range = (!range.is_empty()).then_some(range);
+
+                        if let Some(callback) = &on_change {
+                            callback.emit(CustomEvent::new_non_cancelable(
+                                DateRangePickerChangeDetail::absolute(new_range),
+                            ));
+                        }
                     }
+                    RangeValidationResult::Invalid(message) => range_error.set(Some(message)),
                 }
             }
         })
@@ -362,6 +728,7 @@ pub fn date_range_picker(props: &DateRangePickerProps) -> Html {
         let value = props.value.clone();
         let on_change = props.on_change.clone();
         let is_valid_range = props.is_valid_range.clone();
+        let range_error = range_error.clone();
 
         Callback::from(move |e: InputEvent| {
             if let Some(target) = e.target_dyn_into::<HtmlInputElement>() {
@@ -374,27 +741,19 @@ pub fn date_range_picker(props: &DateRangePickerProps) -> Html {
                 let start = value.as_ref().and_then(|v| v.start.clone());
 
                 // Validate range
-                let is_valid = if let Some(validator) = &is_valid_range {
-                    validator.emit((start.clone(), end.clone()))
-                } else {
-                    match (&start, &end) {
-                        (Some(s), Some(e)) => e >= s,
-                        _ => true,
-                    }
-                };
-
-                if is_valid {
-                    let new_range = if start.is_none() && end.is_none() {
-                        None
-                    } else {
-                        Some(DateRange { start, end })
-                    };
-
-                    if let Some(callback) = &on_change {
-                        callback.emit(CustomEvent::new_non_cancelable(
-                            DateRangePickerChangeDetail { value: new_range },
-                        ));
+                let range = DateRange { start, end };
+                match validate_range(&is_valid_range, &range) {
+                    RangeValidationResult::Valid => {
+                        range_error.set(None);
+                        let new_range = (!range.is_empty()).then_some(range);
+
+                        if let Some(callback) = &on_change {
+                            callback.emit(CustomEvent::new_non_cancelable(
+                                DateRangePickerChangeDetail::absolute(new_range),
+                            ));
+                        }
                     }
+                    RangeValidationResult::Invalid(message) => range_error.set(Some(message)),
                 }
             }
         })
@@ -403,6 +762,7 @@ pub fn date_range_picker(props: &DateRangePickerProps) -> Html {
     // Handle mode change
     let on_mode_change = {
         let current_mode = current_mode.clone();
+        let range_error = range_error.clone();
 
         Callback::from(move |e: Event| {
             if let Some(target) = e.target_dyn_into::<HtmlInputElement>() {
@@ -411,41 +771,129 @@ pub fn date_range_picker(props: &DateRangePickerProps) -> Html {
                     _ => RangeSelectorMode::Absolute,
                 };
                 current_mode.set(mode);
+                range_error.set(None);
             }
         })
     };
 
-    // Handle relative option selection
+    // Handle relative option selection; the custom range is applied with
+    // its Apply button
     let on_relative_change = {
         let on_change = props.on_change.clone();
+        let is_valid_range = props.is_valid_range.clone();
+        let relative_options = props.relative_options.clone();
         let selected_relative = selected_relative.clone();
+        let range_error = range_error.clone();
 
         Callback::from(move |e: Event| {
-            if let Some(target) = e.target_dyn_into::<HtmlInputElement>() {
+            if let Some(target) = e.target_dyn_into::<HtmlSelectElement>() {
                 let key = target.value();
-                selected_relative.set(Some(key.clone()));
+                selected_relative.set((!key.is_empty()).then(|| key.clone()));
+                range_error.set(None);
+
+                let detail = if key.is_empty() {
+                    DateRangePickerChangeDetail::absolute(None)
+                } else if let Some(option) = relative_options.iter().find(|o| o.key == key) {
+                    let detail =
+                        DateRangePickerChangeDetail::relative(option.clone(), current_timestamp());
+                    if let RangeValidationResult::Invalid(message) =
+                        validate_range(&is_valid_range, &detail.timestamp_dates())
+                    {
+                        range_error.set(Some(message));
+                        return;
+                    }
+                    detail
+                } else {
+                    return;
+                };
 
-                // In a real implementation, you would calculate the actual date range
-                // based on the relative option. For now, we'll emit None to indicate
-                // that relative mode is selected but we're not calculating dates.
                 if let Some(callback) = &on_change {
-                    callback.emit(CustomEvent::new_non_cancelable(
-                        DateRangePickerChangeDetail { value: None },
-                    ));
+                    callback.emit(CustomEvent::new_non_cancelable(detail));
                 }
             }
         })
     };
 
+    // Custom relative range, validated as it is edited
+    let custom_option = parse_custom_amount(&custom_amount).map(|amount| {
+        RelativeOption::new(
+            CUSTOM_RANGE_KEY,
+            (strings.format_relative_range)(amount, *custom_unit),
+            amount,
+            *custom_unit,
+        )
+    });
+    let custom_validation = custom_option.as_ref().map(|option| {
+        let detail = DateRangePickerChangeDetail::relative(option.clone(), current_timestamp());
+        validate_range(&props.is_valid_range, &detail.timestamp_dates())
+    });
+    let is_custom = selected_relative.as_deref() == Some(CUSTOM_RANGE_KEY);
+
+    let on_custom_amount_input = {
+        let custom_amount = custom_amount.clone();
+
+        Callback::from(move |e: InputEvent| {
+            if let Some(target) = e.target_dyn_into::<HtmlInputElement>() {
+                custom_amount.set(target.value());
+            }
+        })
+    };
+
+    let on_custom_unit_change = {
+        let custom_unit = custom_unit.clone();
+
+        Callback::from(move |e: Event| {
+            if let Some(target) = e.target_dyn_into::<HtmlSelectElement>()
+                && let Some(unit) = TimeUnit::parse(&target.value())
+            {
+                custom_unit.set(unit);
+            }
+        })
+    };
+
+    let on_custom_apply = {
+        let on_change = props.on_change.clone();
+        let is_valid_range = props.is_valid_range.clone();
+        let custom_option = custom_option.clone();
+
+        Callback::from(move |_: MouseEvent| {
+            let Some(option) = custom_option.clone() else {
+                return;
+            };
+            let detail = DateRangePickerChangeDetail::relative(option, current_timestamp());
+            if validate_range(&is_valid_range, &detail.timestamp_dates())
+                == RangeValidationResult::Valid
+                && let Some(callback) = &on_change
+            {
+                callback.emit(CustomEvent::new_non_cancelable(detail));
+            }
+        })
+    };
+
+    // Message of the range that was rejected, or of the custom range
+    let error_message = if is_custom && *current_mode == RangeSelectorMode::Relative {
+        match &custom_validation {
+            Some(RangeValidationResult::Invalid(message)) => Some(message.clone()),
+            _ => None,
+        }
+    } else {
+        (*range_error).clone()
+    }
+    .filter(|message| !message.is_empty());
+
     // Check if current range is invalid
-    let is_range_invalid =
-        props.value.as_ref().map(|v| !v.is_valid()).unwrap_or(false) || props.invalid;
+    let validation = use_validation_state(
+        props.value.as_ref().map(|v| !v.is_valid()).unwrap_or(false) || props.invalid,
+        props.warning,
+    );
+    let is_range_invalid = validation.is_invalid();
 
     // Build root classes
     let root_classes = ClassBuilder::new()
         .add("awsui-date-range-picker")
         .add_if(props.disabled, "awsui-date-range-picker-disabled")
         .add_if(is_range_invalid, "awsui-date-range-picker-invalid")
+        .add_if(validation.is_warning(), "awsui-date-range-picker-warning")
         .add_if(props.read_only, "awsui-date-range-picker-readonly");
 
     // Build input classes closure - creates a fresh builder each time it's called
@@ -456,14 +904,11 @@ pub fn date_range_picker(props: &DateRangePickerProps) -> Html {
             .add_if(props.disabled, "awsui-input-disabled")
             .add_if(props.read_only, "awsui-input-readonly")
             .add_if(is_range_invalid, "awsui-input-invalid")
+            .add_if(validation.is_warning(), "awsui-input-warning")
             .build()
     };
 
-    // Get placeholder text
-    let placeholder_text = props
-        .placeholder
-        .clone()
-        .unwrap_or_else(|| "YYYY-MM-DD".to_string());
+    let placeholder_text = strings.date_placeholder.clone();
 
     // Get start and end values
     let start_value = props
@@ -477,7 +922,7 @@ pub fn date_range_picker(props: &DateRangePickerProps) -> Html {
         .and_then(|v| v.end.clone())
         .unwrap_or_default();
 
-    html! {
+    let root = html! {
         <div class={root_classes.build()}>
             // Mode toggle
             <div class="awsui-date-range-picker-mode-toggle">
@@ -491,7 +936,7 @@ pub fn date_range_picker(props: &DateRangePickerProps) -> Html {
                         onchange={on_mode_change.clone()}
                     />
                     <span class="awsui-date-range-picker-mode-label-text">
-                        { "Absolute" }
+                        { &strings.absolute_mode_label }
                     </span>
                 </label>
                 <label class="awsui-date-range-picker-mode-label">
@@ -504,7 +949,7 @@ pub fn date_range_picker(props: &DateRangePickerProps) -> Html {
                         onchange={on_mode_change}
                     />
                     <span class="awsui-date-range-picker-mode-label-text">
-                        { "Relative" }
+                        { &strings.relative_mode_label }
                     </span>
                 </label>
             </div>
@@ -518,12 +963,13 @@ pub fn date_range_picker(props: &DateRangePickerProps) -> Html {
                             class="awsui-date-range-picker-input-label"
                             for={format!("{}-start", props.control_id.clone().unwrap_or_else(|| "date-range".to_string()))}
                         >
-                            { props.start_date_label.clone().unwrap_or_else(|| "Start date".to_string()) }
+                            { &strings.start_date_label }
                         </label>
                         <input
                             ref={start_input_ref}
                             type="date"
                             class={build_input_classes()}
+                            style={validation.control_style()}
                             id={format!("{}-start", props.control_id.clone().unwrap_or_else(|| "date-range".to_string()))}
                             name={props.name.clone().map(|n| format!("{}-start", n))}
                             value={start_value}
@@ -531,9 +977,9 @@ pub fn date_range_picker(props: &DateRangePickerProps) -> Html {
                             disabled={props.disabled}
                             readonly={props.read_only}
                             autofocus={props.auto_focus}
-                            aria-label={props.aria.label.clone().or_else(|| Some("Start date".to_string()))}
+                            aria-label={props.aria.label.clone().unwrap_or_else(|| strings.start_date_label.clone())}
                             aria-required={props.aria_required.to_string()}
-                            aria-invalid={is_range_invalid.to_string()}
+                            aria-invalid={is_range_invalid.then_some("true")}
                             aria-labelledby={props.aria.labelledby.clone()}
                             aria-describedby={props.aria.describedby.clone()}
                             oninput={on_start_change}
@@ -546,21 +992,22 @@ pub fn date_range_picker(props: &DateRangePickerProps) -> Html {
                             class="awsui-date-range-picker-input-label"
                             for={format!("{}-end", props.control_id.clone().unwrap_or_else(|| "date-range".to_string()))}
                         >
-                            { props.end_date_label.clone().unwrap_or_else(|| "End date".to_string()) }
+                            { &strings.end_date_label }
                         </label>
                         <input
                             ref={end_input_ref}
                             type="date"
                             class={build_input_classes()}
+                            style={validation.control_style()}
                             id={format!("{}-end", props.control_id.clone().unwrap_or_else(|| "date-range".to_string()))}
                             name={props.name.clone().map(|n| format!("{}-end", n))}
                             value={end_value}
                             placeholder={placeholder_text}
                             disabled={props.disabled}
                             readonly={props.read_only}
-                            aria-label={props.aria.label.clone().or_else(|| Some("End date".to_string()))}
+                            aria-label={props.aria.label.clone().unwrap_or_else(|| strings.end_date_label.clone())}
                             aria-required={props.aria_required.to_string()}
-                            aria-invalid={is_range_invalid.to_string()}
+                            aria-invalid={is_range_invalid.then_some("true")}
                             aria-labelledby={props.aria.labelledby.clone()}
                             aria-describedby={props.aria.describedby.clone()}
                             oninput={on_end_change}
@@ -574,19 +1021,20 @@ pub fn date_range_picker(props: &DateRangePickerProps) -> Html {
                         class="awsui-date-range-picker-input-label"
                         for={format!("{}-relative", props.control_id.clone().unwrap_or_else(|| "date-range".to_string()))}
                     >
-                        { "Select time range" }
+                        { &strings.relative_range_selection_heading }
                     </label>
                     <select
                         class="awsui-select-trigger"
+                        style={validation.control_style()}
                         id={format!("{}-relative", props.control_id.clone().unwrap_or_else(|| "date-range".to_string()))}
                         disabled={props.disabled}
-                        aria-label={props.aria.label.clone().or_else(|| Some("Select relative date range".to_string()))}
+                        aria-label={props.aria.label.clone().unwrap_or_else(|| strings.relative_range_selection_aria_label.clone())}
                         aria-required={props.aria_required.to_string()}
-                        aria-invalid={is_range_invalid.to_string()}
+                        aria-invalid={is_range_invalid.then_some("true")}
                         onchange={on_relative_change}
                     >
                         <option value="" selected={selected_relative.is_none()}>
-                            { "Choose a time range" }
+                            { &strings.relative_range_placeholder }
                         </option>
                         {
                             props.relative_options.iter().map(|option| {
@@ -600,16 +1048,84 @@ pub fn date_range_picker(props: &DateRangePickerProps) -> Html {
                                         value={option.key.clone()}
                                         selected={is_selected}
                                     >
-                                        { &option.label }
+                                        { strings.relative_option_label(option) }
                                     </option>
                                 }
                             }).collect::<Html>()
                         }
+                        <option value={CUSTOM_RANGE_KEY} selected={is_custom}>
+                            { &strings.custom_range_option_label }
+                        </option>
                     </select>
+
+                    if is_custom {
+                        <div class="awsui-date-range-picker-custom-range">
+                            <div class="awsui-date-range-picker-custom-range-duration">
+                                <label
+                                    class="awsui-date-range-picker-input-label"
+                                    for={format!("{}-custom-amount", props.control_id.clone().unwrap_or_else(|| "date-range".to_string()))}
+                                >
+                                    { &strings.custom_range_duration_label }
+                                </label>
+                                <input
+                                    type="number"
+                                    class="awsui-input awsui-input-type-number"
+                                    id={format!("{}-custom-amount", props.control_id.clone().unwrap_or_else(|| "date-range".to_string()))}
+                                    min="1"
+                                    step="1"
+                                    value={(*custom_amount).clone()}
+                                    disabled={props.disabled}
+                                    readonly={props.read_only}
+                                    oninput={on_custom_amount_input}
+                                />
+                            </div>
+                            <div class="awsui-date-range-picker-custom-range-unit">
+                                <label
+                                    class="awsui-date-range-picker-input-label"
+                                    for={format!("{}-custom-unit", props.control_id.clone().unwrap_or_else(|| "date-range".to_string()))}
+                                >
+                                    { &strings.custom_range_unit_label }
+                                </label>
+                                <select
+                                    class="awsui-select-trigger"
+                                    id={format!("{}-custom-unit", props.control_id.clone().unwrap_or_else(|| "date-range".to_string()))}
+                                    disabled={props.disabled}
+                                    onchange={on_custom_unit_change}
+                                >
+                                    {
+                                        TimeUnit::ALL.iter().map(|unit| html! {
+                                            <option
+                                                key={unit.as_str()}
+                                                value={unit.as_str()}
+                                                selected={*unit == *custom_unit}
+                                            >
+                                                { (strings.format_time_unit)(*unit) }
+                                            </option>
+                                        }).collect::<Html>()
+                                    }
+                                </select>
+                            </div>
+                            <button
+                                type="button"
+                                class="awsui-button awsui-button-variant-primary awsui-date-range-picker-apply"
+                                disabled={props.disabled || props.read_only || custom_validation != Some(RangeValidationResult::Valid)}
+                                onclick={on_custom_apply}
+                            >
+                                { &strings.apply_button_label }
+                            </button>
+                        </div>
+                    }
+                </div>
+            }
+
+            if let Some(message) = error_message {
+                <div class="awsui-date-range-picker-error" style={RANGE_ERROR_STYLE} role="alert">
+                    { message }
                 </div>
             }
         </div>
-    }
+    };
+    apply_root_attributes(root, &props.base)
 }
 
 #[cfg(test)]
@@ -621,6 +1137,7 @@ mod tests {
         assert_eq!(TimeUnit::Days.as_str(), "days");
         assert_eq!(TimeUnit::Weeks.as_str(), "weeks");
         assert_eq!(TimeUnit::Months.as_str(), "months");
+        assert_eq!(TimeUnit::Minutes.as_str(), "minutes");
     }
 
     #[test]
@@ -717,11 +1234,91 @@ mod tests {
             Some("2024-01-01".to_string()),
             Some("2024-01-31".to_string()),
         );
-        let detail = DateRangePickerChangeDetail {
-            value: Some(range.clone()),
-        };
+        let detail = DateRangePickerChangeDetail::absolute(Some(range.clone()));
 
         assert_eq!(detail.value.unwrap().start, Some("2024-01-01".to_string()));
+        assert!(detail.relative_range.is_none());
+    }
+
+    #[test]
+    fn test_relative_detail_has_timestamps() {
+        // 2026-03-31T10:30:00Z
+        let now = days_from_civil(2026, 3, 31) * 86_400 + 10 * 3600 + 30 * 60;
+
+        let option = RelativeOption::new("last-45-minutes", "", 45, TimeUnit::Minutes);
+        let detail = DateRangePickerChangeDetail::relative(option.clone(), now);
+        assert_eq!(detail.value, None);
+        assert_eq!(detail.relative_range, Some(option));
+        assert_eq!(
+            detail.start_timestamp.as_deref(),
+            Some("2026-03-31T09:45:00Z")
+        );
+        assert_eq!(
+            detail.end_timestamp.as_deref(),
+            Some("2026-03-31T10:30:00Z")
+        );
+
+        // Calendar months end on the last day of a shorter month
+        let option = RelativeOption::new("last-month", "", 1, TimeUnit::Months);
+        let detail = DateRangePickerChangeDetail::relative(option, now);
+        assert_eq!(
+            detail.start_timestamp.as_deref(),
+            Some("2026-02-28T10:30:00Z")
+        );
+        assert_eq!(
+            detail.timestamp_dates(),
+            DateRange::with_start("2026-02-28").set_end("2026-03-31")
+        );
+
+        let option = RelativeOption::new("last-2-weeks", "", 2, TimeUnit::Weeks);
+        let detail = DateRangePickerChangeDetail::relative(option, now);
+        assert_eq!(
+            detail.start_timestamp.as_deref(),
+            Some("2026-03-17T10:30:00Z")
+        );
+    }
+
+    #[test]
+    fn test_validate_range() {
+        let reversed = DateRange::with_start("2024-01-31").set_end("2024-01-01");
+        assert_eq!(
+            validate_range(&None, &reversed),
+            RangeValidationResult::Invalid(String::new())
+        );
+
+        let validator = Callback::from(|range: DateRange| {
+            if range.start.as_deref() < Some("2024-01-15") {
+                RangeValidationResult::Invalid("Pick a later start date.".to_string())
+            } else {
+                RangeValidationResult::Valid
+            }
+        });
+        assert_eq!(
+            validate_range(&Some(validator.clone()), &reversed),
+            RangeValidationResult::Valid
+        );
+        assert_eq!(
+            validate_range(&Some(validator), &DateRange::with_start("2024-01-01")),
+            RangeValidationResult::Invalid("Pick a later start date.".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_custom_amount() {
+        assert_eq!(parse_custom_amount("45"), Some(45));
+        assert_eq!(parse_custom_amount(" 3 "), Some(3));
+        assert_eq!(parse_custom_amount("0"), None);
+        assert_eq!(parse_custom_amount("-2"), None);
+        assert_eq!(parse_custom_amount("1.5"), None);
+        assert_eq!(parse_custom_amount(""), None);
+    }
+
+    #[test]
+    fn test_time_unit_parse() {
+        for unit in TimeUnit::ALL {
+            assert_eq!(TimeUnit::parse(unit.as_str()), Some(unit));
+        }
+        assert_eq!(TimeUnit::parse("fortnights"), None);
     }
 
     #[test]
@@ -752,4 +1349,183 @@ mod tests {
         assert_eq!(option1, option2);
         assert_ne!(option1, option3);
     }
+
+    fn props() -> DateRangePickerProps {
+        DateRangePickerProps {
+            base: BaseComponentProps::default(),
+            value: None,
+            placeholder: None,
+            disabled: false,
+            invalid: false,
+            warning: false,
+            range_selector_mode: RangeSelectorMode::Absolute,
+            relative_options: Vec::new(),
+            on_change: None,
+            is_valid_range: None,
+            aria: AriaAttributes::default(),
+            aria_required: false,
+            control_id: None,
+            name: None,
+            auto_focus: false,
+            read_only: false,
+            start_date_label: None,
+            end_date_label: None,
+            i18n_strings: DateRangePickerI18nStrings::default(),
+        }
+    }
+
+    fn all_strings(strings: &ResolvedStrings, options: &[RelativeOption]) -> Vec<String> {
+        let mut all = vec![
+            strings.absolute_mode_label.clone(),
+            strings.relative_mode_label.clone(),
+            strings.start_date_label.clone(),
+            strings.end_date_label.clone(),
+            strings.date_placeholder.clone(),
+            strings.relative_range_selection_heading.clone(),
+            strings.relative_range_selection_aria_label.clone(),
+            strings.relative_range_placeholder.clone(),
+            strings.custom_range_option_label.clone(),
+            strings.custom_range_duration_label.clone(),
+            strings.custom_range_unit_label.clone(),
+            strings.apply_button_label.clone(),
+        ];
+        all.extend(options.iter().map(|o| strings.relative_option_label(o)));
+        all.extend(TimeUnit::ALL.map(strings.format_time_unit));
+        all
+    }
+
+    #[test]
+    fn test_english_defaults() {
+        let props = props();
+        let strings = resolve_strings(&props, &I18nContext::default());
+        assert_eq!(strings.absolute_mode_label, "Absolute");
+        assert_eq!(strings.start_date_label, "Start date");
+        assert_eq!(strings.date_placeholder, "YYYY-MM-DD");
+        assert_eq!(strings.relative_range_placeholder, "Choose a time range");
+
+        let option = RelativeOption::new("last-5-days", "", 5, TimeUnit::Days);
+        assert_eq!(strings.relative_option_label(&option), "Last 5 days");
+        let option = RelativeOption::new("last-week", "", 1, TimeUnit::Weeks);
+        assert_eq!(strings.relative_option_label(&option), "Last 1 week");
+        let option = RelativeOption::new("custom", "Past week", 1, TimeUnit::Weeks);
+        assert_eq!(strings.relative_option_label(&option), "Past week");
+        let option = RelativeOption::new("last-45-minutes", "", 45, TimeUnit::Minutes);
+        assert_eq!(strings.relative_option_label(&option), "Last 45 minutes");
+        assert_eq!(strings.custom_range_option_label, "Custom range");
+        assert_eq!((strings.format_time_unit)(TimeUnit::Hours), "hours");
+    }
+
+    #[test]
+    fn test_non_english_strings_leave_no_english() {
+        let mut props = props();
+        props.i18n_strings = DateRangePickerI18nStrings {
+            absolute_mode_label: Some("Absolut".to_string()),
+            relative_mode_label: Some("Relativ".to_string()),
+            start_date_label: Some("Startdatum".to_string()),
+            end_date_label: Some("Enddatum".to_string()),
+            date_placeholder: Some("JJJJ-MM-TT".to_string()),
+            relative_range_selection_heading: Some("Zeitraum auswählen".to_string()),
+            relative_range_selection_aria_label: Some("Zeitraum relativ wählen".to_string()),
+            relative_range_placeholder: Some("Zeitraum wählen".to_string()),
+            format_relative_range: Some(|amount, unit| {
+                let unit = match (unit, amount == 1) {
+                    (TimeUnit::Seconds, true) => "Sekunde",
+                    (TimeUnit::Seconds, false) => "Sekunden",
+                    (TimeUnit::Minutes, true) => "Minute",
+                    (TimeUnit::Minutes, false) => "Minuten",
+                    (TimeUnit::Hours, true) => "Stunde",
+                    (TimeUnit::Hours, false) => "Stunden",
+                    (TimeUnit::Days, true) => "Tag",
+                    (TimeUnit::Days, false) => "Tage",
+                    (TimeUnit::Weeks, true) => "Woche",
+                    (TimeUnit::Weeks, false) => "Wochen",
+                    (TimeUnit::Months, true) => "Monat",
+                    (TimeUnit::Months, false) => "Monate",
+                    (TimeUnit::Years, true) => "Jahr",
+                    (TimeUnit::Years, false) => "Jahre",
+                };
+                format!("Letzte {} {}", amount, unit)
+            }),
+            custom_range_option_label: Some("Benutzerdefiniert".to_string()),
+            custom_range_duration_label: Some("Dauer".to_string()),
+            custom_range_unit_label: Some("Zeiteinheit".to_string()),
+            apply_button_label: Some("Anwenden".to_string()),
+            format_time_unit: Some(|unit| {
+                match unit {
+                    TimeUnit::Seconds => "Sekunden",
+                    TimeUnit::Minutes => "Minuten",
+                    TimeUnit::Hours => "Stunden",
+                    TimeUnit::Days => "Tage",
+                    TimeUnit::Weeks => "Wochen",
+                    TimeUnit::Months => "Monate",
+                    TimeUnit::Years => "Jahre",
+                }
+                .to_string()
+            }),
+        };
+        let options = vec![
+            RelativeOption::new("last-day", "", 1, TimeUnit::Days),
+            RelativeOption::new("last-7-days", "", 7, TimeUnit::Days),
+            RelativeOption::new("last-3-months", "", 3, TimeUnit::Months),
+        ];
+
+        let strings = resolve_strings(&props, &I18nContext::default());
+        let english = [
+            "Absolute",
+            "Relative",
+            "Start date",
+            "End date",
+            "YYYY-MM-DD",
+            "Select time range",
+            "Select relative date range",
+            "Choose a time range",
+            "Last ",
+            "Custom range",
+            "Duration",
+            "Unit of time",
+            "Apply",
+            "minutes",
+            "days",
+        ];
+        for text in all_strings(&strings, &options) {
+            for word in english {
+                assert!(
+                    !text.contains(word),
+                    "English '{word}' leaked into '{text}'"
+                );
+            }
+        }
+        assert_eq!(strings.relative_option_label(&options[1]), "Letzte 7 Tage");
+    }
+
+    #[test]
+    fn test_provider_fallback_chain() {
+        let mut messages = crate::internal::I18nStrings::new();
+        messages.set("date-range-picker.absolute_mode_label", "Absolut");
+        messages.set("date-range-picker.start_date_label", "Startdatum");
+        let i18n = I18nContext {
+            locale: Some("de-DE".to_string()),
+            messages,
+        };
+
+        let mut props = props();
+        props.i18n_strings.start_date_label = Some("Von".to_string());
+
+        let strings = resolve_strings(&props, &i18n);
+        // Prop beats provider, provider beats English
+        assert_eq!(strings.start_date_label, "Von");
+        assert_eq!(strings.absolute_mode_label, "Absolut");
+        assert_eq!(strings.relative_mode_label, "Relative");
+    }
+
+    #[test]
+    fn test_legacy_label_props_still_apply() {
+        let mut props = props();
+        props.start_date_label = Some("From".to_string());
+        props.placeholder = Some("DD/MM/YYYY".to_string());
+
+        let strings = resolve_strings(&props, &I18nContext::default());
+        assert_eq!(strings.start_date_label, "From");
+        assert_eq!(strings.date_placeholder, "DD/MM/YYYY");
+    }
 }
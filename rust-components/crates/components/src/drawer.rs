@@ -8,7 +8,9 @@
 //! focus management and keyboard interactions. The drawer blocks interaction with the rest
 //! of the page via an overlay backdrop.
 
-use crate::internal::{AriaAttributes, BaseComponentProps, ClassBuilder, CustomEvent};
+use crate::internal::{
+    AriaAttributes, BaseComponentProps, ClassBuilder, CustomEvent, apply_root_attributes,
+};
 use crate::modal::DismissReason;
 use web_sys::{KeyboardEvent, MouseEvent};
 use yew::prelude::*;
@@ -282,7 +284,7 @@ pub fn drawer(props: &DrawerProps) -> Html {
 
     let root_class = props.base.merge_classes(&root_classes.build());
 
-    html! {
+    let root = html! {
         <div
             id={props.base.id.clone()}
             class={root_class}
@@ -355,7 +357,8 @@ pub fn drawer(props: &DrawerProps) -> Html {
                 </div>
             </div>
         </div>
-    }
+    };
+    apply_root_attributes(root, &props.base)
 }
 
 #[cfg(test)]
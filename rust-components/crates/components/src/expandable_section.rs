@@ -7,10 +7,31 @@
 //! that users can click to expand or collapse the section. It supports both controlled
 //! and uncontrolled modes, multiple visual variants, and optional header actions.
 
-use crate::internal::{AriaAttributes, BaseComponentProps, ClassBuilder, CustomEvent};
-use web_sys::{KeyboardEvent, MouseEvent};
+use crate::internal::deep_link::{highlight_style, use_deep_link};
+use crate::internal::dev_warnings::{DevWarning, use_dev_warnings};
+use crate::internal::motion::motion_disabled;
+use crate::internal::{
+    AnchorLink, AriaAttributes, BaseComponentProps, ClassBuilder, CustomEvent,
+    apply_root_attributes,
+};
+use web_sys::{Element, FocusEvent, KeyboardEvent, MouseEvent};
 use yew::prelude::*;
 
+/// Builds the style of the chevron: pointing down when expanded, to the
+/// side when collapsed, and rotating between the two unless motion is
+/// disabled
+fn chevron_style(expanded: bool, animate: bool) -> String {
+    let rotation = if expanded { 0 } else { -90 };
+    let mut style = format!("display: inline-flex; transform: rotate({}deg)", rotation);
+    if animate {
+        style.push_str(
+            "; transition: transform var(--awsui-motion-duration-rotate180) \
+             var(--awsui-motion-easing-rotate180)",
+        );
+    }
+    style
+}
+
 /// Visual variants for the expandable section
 ///
 /// Determines the visual style and spacing of the expandable section based on context.
@@ -65,7 +86,16 @@ pub struct ExpandableSectionProps {
     #[prop_or_default]
     pub header_description: Option<Html>,
 
+    /// Counter displayed next to the header text, e.g. "(3)"
+    ///
+    /// Only shown in the container variant.
+    #[prop_or_default]
+    pub header_counter: Option<String>,
+
     /// Actions slot displayed in the header
+    ///
+    /// Only shown in the container variant. Interacting with the actions
+    /// does not expand or collapse the section.
     #[prop_or_default]
     pub header_actions: Option<Html>,
 
@@ -79,8 +109,9 @@ pub struct ExpandableSectionProps {
 
     /// Controlled expanded state
     ///
-    /// If provided, the component operates in controlled mode. Use with `on_change`
-    /// to manage the state externally.
+    /// If provided, the component operates in controlled mode: it keeps no
+    /// state of its own and only emits `on_change`, which should update
+    /// this prop.
     #[prop_or_default]
     pub expanded: Option<bool>,
 
@@ -105,6 +136,28 @@ pub struct ExpandableSectionProps {
     /// ARIA attributes
     #[prop_or_default]
     pub aria: AriaAttributes,
+
+    /// Anchor ID for deep linking to the section
+    ///
+    /// When the URL hash matches, on mount or after navigation, the section
+    /// expands (emitting `on_change`), scrolls into view and briefly
+    /// highlights its header. The header also shows a button copying the
+    /// link while hovered or focused.
+    #[prop_or_default]
+    pub anchor_id: Option<String>,
+}
+
+/// Misuse of ExpandableSection props reported in development builds
+fn dev_warnings(props: &ExpandableSectionProps) -> Vec<DevWarning> {
+    if props.expanded.is_some() && props.default_expanded {
+        vec![DevWarning::new(
+            "expandable-section-controlled-and-uncontrolled",
+            "`expanded` and `default_expanded` are both set. `default_expanded` is ignored \
+             in controlled mode; set only one of them.",
+        )]
+    } else {
+        Vec::new()
+    }
 }
 
 /// ExpandableSection component for collapsible content sections.
@@ -162,6 +215,8 @@ pub struct ExpandableSectionProps {
 /// ```
 #[function_component(ExpandableSection)]
 pub fn expandable_section(props: &ExpandableSectionProps) -> Html {
+    use_dev_warnings("ExpandableSection").check(|| dev_warnings(props));
+
     // Internal state for uncontrolled mode
     let internal_expanded = use_state(|| props.default_expanded);
 
@@ -175,18 +230,6 @@ pub fn expandable_section(props: &ExpandableSectionProps) -> Html {
         *internal_expanded
     };
 
-    // Sync controlled prop changes to internal state
-    {
-        let internal_expanded = internal_expanded.clone();
-        let controlled_expanded = props.expanded;
-        use_effect_with(controlled_expanded, move |controlled| {
-            if let Some(value) = controlled {
-                internal_expanded.set(*value);
-            }
-            || ()
-        });
-    }
-
     // Generate unique IDs for ARIA
     let control_id = use_state(|| {
         use std::sync::atomic::{AtomicU32, Ordering};
@@ -220,6 +263,40 @@ pub fn expandable_section(props: &ExpandableSectionProps) -> Html {
         })
     };
 
+    // Deep link: expand when the URL hash targets the anchor ID
+    let header_ref = use_node_ref();
+    let highlighted = {
+        let on_toggle = on_toggle.clone();
+        use_deep_link(
+            props.anchor_id.clone(),
+            header_ref.clone(),
+            Callback::from(move |_| {
+                if !expanded {
+                    on_toggle.emit(true);
+                }
+            }),
+        )
+    };
+
+    // Show the anchor link while the header is hovered or focused
+    let header_active = use_state(|| false);
+    let on_header_enter = {
+        let header_active = header_active.clone();
+        Callback::from(move |_: MouseEvent| header_active.set(true))
+    };
+    let on_header_leave = {
+        let header_active = header_active.clone();
+        Callback::from(move |_: MouseEvent| header_active.set(false))
+    };
+    let on_header_focus = {
+        let header_active = header_active.clone();
+        Callback::from(move |_: FocusEvent| header_active.set(true))
+    };
+    let on_header_blur = {
+        let header_active = header_active.clone();
+        Callback::from(move |_: FocusEvent| header_active.set(false))
+    };
+
     // Click handler
     let onclick = {
         let on_toggle = on_toggle.clone();
@@ -258,6 +335,7 @@ pub fn expandable_section(props: &ExpandableSectionProps) -> Html {
             "awsui-expandable-section-header-{}",
             props.variant.as_str()
         ))
+        .add_if(highlighted, "awsui-expandable-section-header-highlighted")
         .build();
 
     let trigger_classes = ClassBuilder::new()
@@ -270,6 +348,9 @@ pub fn expandable_section(props: &ExpandableSectionProps) -> Html {
         .add_if(expanded, "awsui-expandable-section-header-icon-expanded")
         .build();
 
+    // Without an element yet, only the document-wide scope is checked
+    let animate_chevron = !motion_disabled(header_ref.cast::<Element>().as_ref());
+
     let header_text_classes = ClassBuilder::new()
         .add("awsui-expandable-section-header-text")
         .build();
@@ -292,6 +373,16 @@ pub fn expandable_section(props: &ExpandableSectionProps) -> Html {
         html! {}
     };
 
+    // Header counter and actions belong to the container variant
+    let is_container = props.variant == ExpandableSectionVariant::Container;
+    let header_counter = props.header_counter.as_ref().filter(|_| is_container);
+    let header_actions = props.header_actions.as_ref().filter(|_| is_container);
+
+    // Keep clicks and keys inside the actions from reaching the trigger or
+    // any clickable ancestor
+    let on_actions_click = Callback::from(|e: MouseEvent| e.stop_propagation());
+    let on_actions_keydown = Callback::from(|e: KeyboardEvent| e.stop_propagation());
+
     // Determine ARIA label attributes
     let aria_label = props.header_aria_label.clone();
     let aria_labelledby = if aria_label.is_none() {
@@ -300,12 +391,21 @@ pub fn expandable_section(props: &ExpandableSectionProps) -> Html {
         None
     };
 
-    html! {
+    let root = html! {
         <div
             id={props.base.id.clone()}
             class={root_class}
         >
-            <div class={header_classes}>
+            <div
+                id={props.anchor_id.clone()}
+                class={header_classes}
+                style={props.anchor_id.as_ref().map(|_| highlight_style(highlighted))}
+                ref={header_ref}
+                onmouseenter={on_header_enter}
+                onmouseleave={on_header_leave}
+                onfocusin={on_header_focus}
+                onfocusout={on_header_blur}
+            >
                 <div
                     id={trigger_id.clone()}
                     class={trigger_classes}
@@ -318,7 +418,10 @@ pub fn expandable_section(props: &ExpandableSectionProps) -> Html {
                     onkeydown={onkeydown}
                 >
                     // Icon (chevron/caret)
-                    <span class={icon_classes}>
+                    <span
+                        class={icon_classes}
+                        style={chevron_style(expanded, animate_chevron)}
+                    >
                         // Simple caret-down icon using CSS
                         <svg
                             class="awsui-expandable-section-icon-svg"
@@ -334,11 +437,27 @@ pub fn expandable_section(props: &ExpandableSectionProps) -> Html {
                     <span class={header_text_classes}>
                         { header_content }
                     </span>
+
+                    // Header counter (container variant)
+                    if let Some(counter) = header_counter {
+                        <span class="awsui-expandable-section-header-counter">
+                            { format!(" {}", counter) }
+                        </span>
+                    }
                 </div>
 
-                // Header actions (if provided)
-                if let Some(ref actions) = props.header_actions {
-                    <div class="awsui-expandable-section-header-actions">
+                // Anchor link (if deep linking is enabled)
+                if let Some(ref anchor_id) = props.anchor_id {
+                    <AnchorLink anchor_id={anchor_id.clone()} visible={*header_active} />
+                }
+
+                // Header actions (container variant)
+                if let Some(actions) = header_actions {
+                    <div
+                        class="awsui-expandable-section-header-actions"
+                        onclick={on_actions_click}
+                        onkeydown={on_actions_keydown}
+                    >
                         { actions.clone() }
                     </div>
                 }
@@ -367,12 +486,46 @@ pub fn expandable_section(props: &ExpandableSectionProps) -> Html {
                 { props.children.clone() }
             </div>
         </div>
-    }
+    };
+    apply_root_attributes(root, &props.base)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::internal::dev_warnings::DevWarnings;
+
+    #[test]
+    fn test_dev_warning_controlled_and_uncontrolled() {
+        let props = yew::props!(ExpandableSectionProps {
+            expanded: false,
+            default_expanded: true,
+        });
+        let warnings = DevWarnings::new("ExpandableSection");
+        assert_eq!(warnings.check(|| dev_warnings(&props)), 1);
+        assert_eq!(warnings.check(|| dev_warnings(&props)), 0);
+
+        let controlled = yew::props!(ExpandableSectionProps { expanded: true });
+        assert!(dev_warnings(&controlled).is_empty());
+        let uncontrolled = yew::props!(ExpandableSectionProps {
+            default_expanded: true,
+        });
+        assert!(dev_warnings(&uncontrolled).is_empty());
+    }
+
+    #[test]
+    fn test_chevron_style() {
+        assert_eq!(
+            chevron_style(true, false),
+            "display: inline-flex; transform: rotate(0deg)"
+        );
+        assert!(chevron_style(false, false).contains("rotate(-90deg)"));
+        assert!(!chevron_style(false, false).contains("transition"));
+        assert!(chevron_style(false, true).contains(
+            "transition: transform var(--awsui-motion-duration-rotate180) \
+                 var(--awsui-motion-easing-rotate180)"
+        ));
+    }
 
     #[test]
     fn test_variant_default() {
@@ -393,10 +546,10 @@ mod tests {
     #[test]
     fn test_change_detail() {
         let detail = ExpandableSectionChangeDetail { expanded: true };
-        assert_eq!(detail.expanded, true);
+        assert!(detail.expanded);
 
         let detail2 = ExpandableSectionChangeDetail { expanded: false };
-        assert_eq!(detail2.expanded, false);
+        assert!(!detail2.expanded);
     }
 
     #[test]
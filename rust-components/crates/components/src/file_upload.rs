@@ -4,12 +4,23 @@
 //! FileUpload component for file selection with drag-and-drop support.
 //!
 //! Provides an interactive file input control with drag-and-drop zone, file validation,
-//! and comprehensive file management capabilities.
+//! and comprehensive file management capabilities. Images can also be pasted from the
+//! clipboard, and on mobile devices the picker can open the camera directly.
+//!
+//! Uploading is left to the consumer, who reports the progress and outcome
+//! of each file through `file_states`; files that failed can be retried.
 
+use crate::form_field::use_validation_state;
 use crate::internal::{
     AriaAttributes, BaseComponentProps, ClassBuilder, ComponentMetadata, CustomEvent,
+    apply_root_attributes,
 };
-use web_sys::{DragEvent, File, FileList, HtmlInputElement};
+use crate::progress_bar::{ProgressBar, ProgressBarStatus};
+use crate::status_indicator::{StatusIndicator, StatusIndicatorType};
+use gloo::events::EventListener;
+use std::collections::HashMap;
+use wasm_bindgen::JsCast;
+use web_sys::{ClipboardEvent, DragEvent, File, FileList, FilePropertyBag, HtmlInputElement};
 use yew::prelude::*;
 
 /// Represents a selected file with metadata
@@ -83,6 +94,70 @@ impl FileUploadFile {
     }
 }
 
+/// Native `capture` hint for opening a camera instead of the file picker
+///
+/// Browsers without a camera, and desktop browsers, ignore it and show the
+/// regular file picker.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FileUploadCapture {
+    /// Open the device's default camera
+    Camera,
+    /// Open the user-facing camera
+    User,
+    /// Open the outward-facing camera
+    Environment,
+}
+
+impl FileUploadCapture {
+    /// Returns the value of the native `capture` attribute
+    pub fn attribute_value(&self) -> &'static str {
+        match self {
+            FileUploadCapture::Camera => "",
+            FileUploadCapture::User => "user",
+            FileUploadCapture::Environment => "environment",
+        }
+    }
+}
+
+/// Upload status of a file
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum FileStatus {
+    /// Waiting to be uploaded
+    #[default]
+    Pending,
+    /// Being uploaded
+    Uploading,
+    /// Uploaded
+    Success,
+    /// The upload failed with the given message
+    Error(String),
+}
+
+/// Upload progress and status of a file, reported by the consumer
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FileUploadFileState {
+    /// Uploaded share of the file (0.0 to 100.0), if known
+    pub progress_percent: Option<f32>,
+    /// Upload status
+    pub status: FileStatus,
+}
+
+impl FileUploadFileState {
+    /// Creates a state with the given status and unknown progress
+    pub fn new(status: FileStatus) -> Self {
+        Self {
+            progress_percent: None,
+            status,
+        }
+    }
+
+    /// Sets the uploaded share of the file (0.0 to 100.0)
+    pub fn with_progress(mut self, progress_percent: f32) -> Self {
+        self.progress_percent = Some(progress_percent);
+        self
+    }
+}
+
 /// Event detail for file upload change events
 #[derive(Debug, Clone, PartialEq)]
 pub struct FileUploadChangeDetail {
@@ -90,6 +165,15 @@ pub struct FileUploadChangeDetail {
     pub value: Vec<FileUploadFile>,
 }
 
+/// Event detail for retrying the upload of a file
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileRetryDetail {
+    /// The file to upload again
+    pub file: FileUploadFile,
+    /// Index of the file in the value
+    pub index: usize,
+}
+
 /// Internationalization strings for FileUpload
 #[derive(Clone, PartialEq)]
 pub struct FileUploadI18nStrings {
@@ -105,6 +189,22 @@ pub struct FileUploadI18nStrings {
     pub limit_show_more: String,
     /// Text for error when file validation fails
     pub error_icon_aria_label: String,
+    /// Error of a file whose type is not accepted
+    pub error_file_type: String,
+    /// Error of a file larger than `max_file_size`, followed by the limit
+    pub error_file_too_large: String,
+    /// Error of a file beyond `max_files`, followed by the limit
+    pub error_too_many_files: String,
+    /// Error of a file named like a file that was already added
+    pub error_duplicate_file: String,
+    /// Status text of a file waiting to be uploaded
+    pub status_pending_text: String,
+    /// Status text of a file being uploaded with unknown progress
+    pub status_uploading_text: String,
+    /// Status text of an uploaded file
+    pub status_success_text: String,
+    /// Text for the button retrying a failed upload
+    pub retry_button_text: String,
 }
 
 impl Default for FileUploadI18nStrings {
@@ -116,10 +216,78 @@ impl Default for FileUploadI18nStrings {
             limit_show_fewer: "Show fewer files".to_string(),
             limit_show_more: "Show more files".to_string(),
             error_icon_aria_label: "Error".to_string(),
+            error_file_type: "File type is not accepted".to_string(),
+            error_file_too_large: "File exceeds".to_string(),
+            error_too_many_files: "Maximum number of files:".to_string(),
+            error_duplicate_file: "A file with this name was already added".to_string(),
+            status_pending_text: "Pending".to_string(),
+            status_uploading_text: "Uploading".to_string(),
+            status_success_text: "Uploaded".to_string(),
+            retry_button_text: "Retry".to_string(),
         }
     }
 }
 
+/// Reason a picked, dropped or pasted file was not added
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FileError {
+    /// The type doesn't match `accept`
+    UnsupportedType,
+    /// The file is larger than the size limit in bytes
+    TooLarge(u64),
+    /// The file count limit was reached
+    TooManyFiles(usize),
+    /// A file with the same name is already in the value
+    Duplicate,
+}
+
+impl FileError {
+    /// Gets the message shown under the file
+    fn message(&self, i18n_strings: &FileUploadI18nStrings) -> String {
+        match self {
+            Self::UnsupportedType => i18n_strings.error_file_type.clone(),
+            Self::TooLarge(limit) => {
+                format!(
+                    "{} {}",
+                    i18n_strings.error_file_too_large,
+                    format_limit(*limit)
+                )
+            }
+            Self::TooManyFiles(limit) => {
+                format!("{} {}", i18n_strings.error_too_many_files, limit)
+            }
+            Self::Duplicate => i18n_strings.error_duplicate_file.clone(),
+        }
+    }
+}
+
+/// A file that was not added, with the reason
+#[derive(Debug, Clone, PartialEq)]
+struct RejectedFile {
+    file: FileUploadFile,
+    error: FileError,
+}
+
+/// Limits a file must satisfy to be added
+#[derive(Debug, Clone, PartialEq, Default)]
+struct FileConstraints {
+    accept: Option<String>,
+    max_file_size: Option<u64>,
+    max_files: Option<usize>,
+    multiple: bool,
+}
+
+/// Background, border and text colors of the drop zone
+fn dropzone_style(active: bool) -> String {
+    let state = if active { "hover" } else { "default" };
+    format!(
+        "background-color: var(--awsui-color-dropzone-background-{state}); \
+         border: var(--awsui-border-item-width) dashed var(--awsui-color-dropzone-border-{state}); \
+         border-radius: var(--awsui-border-radius-dropzone); \
+         color: var(--awsui-color-dropzone-text-{state})"
+    )
+}
+
 /// Properties for the FileUpload component
 #[derive(Properties, PartialEq, Clone)]
 pub struct FileUploadProps {
@@ -136,9 +304,24 @@ pub struct FileUploadProps {
     pub multiple: bool,
 
     /// File type filter (e.g., "image/*,.pdf")
+    ///
+    /// Dropped and pasted files of other types are not added, and are listed
+    /// with an error.
     #[prop_or_default]
     pub accept: Option<String>,
 
+    /// Largest size of a file in bytes
+    ///
+    /// Larger files are not added, and are listed with an error.
+    #[prop_or_default]
+    pub max_file_size: Option<u64>,
+
+    /// Largest number of files when `multiple` is set
+    ///
+    /// Files beyond the limit are not added, and are listed with an error.
+    #[prop_or_default]
+    pub max_files: Option<usize>,
+
     /// Whether the component is disabled
     #[prop_or_default]
     pub disabled: bool,
@@ -160,9 +343,22 @@ pub struct FileUploadProps {
     pub show_file_thumbnails: bool,
 
     /// Callback fired when the file selection changes
+    ///
+    /// Removing a file that is being uploaded fires it as well; cancel the
+    /// upload of files that are no longer in the value.
     #[prop_or_default]
     pub on_change: Option<Callback<CustomEvent<FileUploadChangeDetail>>>,
 
+    /// Upload progress and status of the files, keyed by file name
+    ///
+    /// Files without a state show no status.
+    #[prop_or_default]
+    pub file_states: HashMap<String, FileUploadFileState>,
+
+    /// Callback fired when the user retries a failed upload
+    #[prop_or_default]
+    pub on_retry: Option<Callback<CustomEvent<FileRetryDetail>>>,
+
     /// Internationalization strings
     #[prop_or_default]
     pub i18n_strings: FileUploadI18nStrings,
@@ -182,6 +378,21 @@ pub struct FileUploadProps {
     /// HTML name attribute
     #[prop_or_default]
     pub name: Option<String>,
+
+    /// Whether images pasted while the component has focus are added as files
+    #[prop_or_default]
+    pub paste_target: bool,
+
+    /// Whether images pasted anywhere on the page are added as files
+    ///
+    /// Takes precedence over `paste_target`. Pastes without image data, such
+    /// as text pasted into another field, are left alone.
+    #[prop_or_default]
+    pub capture_global_paste: bool,
+
+    /// Opens the camera instead of the file picker on supporting devices
+    #[prop_or_default]
+    pub capture: Option<FileUploadCapture>,
 }
 
 /// FileUpload component for file selection with drag-and-drop support.
@@ -217,35 +428,50 @@ pub struct FileUploadProps {
 pub fn file_upload(props: &FileUploadProps) -> Html {
     let _metadata = ComponentMetadata::new("FileUpload");
     let input_ref = use_node_ref();
+    let validation = use_validation_state(props.invalid, props.warning);
     let drag_active = use_state(|| false);
     let drag_counter = use_state(|| 0);
+    // Files of the last pick, drop or paste that were not added
+    let rejected_files = use_state(Vec::<RejectedFile>::new);
 
-    // Handle file input change
-    let on_input_change = {
+    // Adds picked, dropped or pasted files to the current value
+    let add_files = {
         let on_change = props.on_change.clone();
-        let multiple = props.multiple;
+        let constraints = FileConstraints {
+            accept: props.accept.clone(),
+            max_file_size: props.max_file_size,
+            max_files: props.max_files,
+            multiple: props.multiple,
+        };
         let current_value = props.value.clone();
+        let rejected_files = rejected_files.clone();
+
+        Callback::from(move |files: Vec<File>| {
+            let candidates = files
+                .iter()
+                .map(|file| (FileUploadFile::from_file(file), file.type_()))
+                .collect();
+            let (value, rejected) = add_checked_files(&current_value, candidates, &constraints);
+            rejected_files.set(rejected);
+
+            if let Some(value) = value
+                && let Some(callback) = &on_change
+            {
+                callback.emit(CustomEvent::new_non_cancelable(FileUploadChangeDetail {
+                    value,
+                }));
+            }
+        })
+    };
+
+    // Handle file input change
+    let on_input_change = {
+        let add_files = add_files.clone();
 
         Callback::from(move |e: Event| {
             if let Some(target) = e.target_dyn_into::<HtmlInputElement>() {
                 if let Some(file_list) = target.files() {
-                    let new_files = files_from_file_list(&file_list);
-
-                    let updated_value = if multiple {
-                        // Append to existing files
-                        let mut combined = current_value.clone();
-                        combined.extend(new_files);
-                        combined
-                    } else {
-                        // Replace with new file
-                        new_files
-                    };
-
-                    if let Some(callback) = &on_change {
-                        callback.emit(CustomEvent::new_non_cancelable(FileUploadChangeDetail {
-                            value: updated_value,
-                        }));
-                    }
+                    add_files.emit(files_from_file_list(&file_list));
                 }
 
                 // Clear the input value to allow selecting the same file again
@@ -254,6 +480,45 @@ pub fn file_upload(props: &FileUploadProps) -> Html {
         })
     };
 
+    // Handle pasted images
+    let on_paste = {
+        let add_files = add_files.clone();
+        let disabled = props.disabled;
+
+        Callback::from(move |e: Event| {
+            if disabled {
+                return;
+            }
+
+            let files = pasted_image_files(&e);
+            if files.is_empty() {
+                return;
+            }
+
+            e.prevent_default();
+            add_files.emit(files);
+        })
+    };
+
+    let on_paste_ref = use_mut_ref(|| on_paste.clone());
+    *on_paste_ref.borrow_mut() = on_paste.clone();
+
+    use_effect_with(props.capture_global_paste, move |capture_global_paste| {
+        let listener = if *capture_global_paste {
+            web_sys::window()
+                .and_then(|window| window.document())
+                .map(|document| {
+                    EventListener::new(&document, "paste", move |event| {
+                        on_paste_ref.borrow().emit(event.clone());
+                    })
+                })
+        } else {
+            None
+        };
+
+        move || drop(listener)
+    });
+
     // Handle choose files button click
     let on_button_click = {
         let input_ref = input_ref.clone();
@@ -266,24 +531,35 @@ pub fn file_upload(props: &FileUploadProps) -> Html {
         })
     };
 
-    // Handle remove file
-    let on_remove_file = {
-        let on_change = props.on_change.clone();
-        let value = props.value.clone();
-
-        Callback::from(move |index: usize| {
+    // Handle remove file; the callbacks passed to file items only change
+    // with the value, so progress updates re-render only the affected items
+    let on_remove_file = use_callback(
+        (props.value.clone(), props.on_change.clone()),
+        |index: usize, (value, on_change)| {
             let mut new_value = value.clone();
             if index < new_value.len() {
                 new_value.remove(index);
 
-                if let Some(callback) = &on_change {
+                if let Some(callback) = on_change {
                     callback.emit(CustomEvent::new_non_cancelable(FileUploadChangeDetail {
                         value: new_value,
                     }));
                 }
             }
-        })
-    };
+        },
+    );
+
+    let on_retry_file = use_callback(
+        (props.value.clone(), props.on_retry.clone()),
+        |index: usize, (value, on_retry)| {
+            if let (Some(file), Some(callback)) = (value.get(index), on_retry) {
+                callback.emit(CustomEvent::new_non_cancelable(FileRetryDetail {
+                    file: file.clone(),
+                    index,
+                }));
+            }
+        },
+    );
 
     // Drag and drop handlers
     let on_drag_enter = {
@@ -347,9 +623,7 @@ pub fn file_upload(props: &FileUploadProps) -> Html {
     let on_drop = {
         let drag_active = drag_active.clone();
         let drag_counter = drag_counter.clone();
-        let on_change = props.on_change.clone();
-        let multiple = props.multiple;
-        let current_value = props.value.clone();
+        let add_files = add_files.clone();
         let disabled = props.disabled;
 
         Callback::from(move |e: DragEvent| {
@@ -363,29 +637,8 @@ pub fn file_upload(props: &FileUploadProps) -> Html {
             drag_active.set(false);
             drag_counter.set(0);
 
-            // Note: DragEvent doesn't have data_transfer() in web_sys
-            // This is a known limitation - in production, files would be extracted
-            // using wasm-bindgen to access the dataTransfer property directly
-            // For now, we'll leave this as a stub that would need JS interop
-
-            // Placeholder: In real implementation, would use JS interop to get files
-            // Example would be similar to:
-            // let file_list = get_files_from_drag_event(&e);
-            // For now, just emit empty to indicate drop occurred
-            let new_files: Vec<FileUploadFile> = Vec::new(); // Placeholder
-
-            let updated_value: Vec<FileUploadFile> = if multiple {
-                let mut combined = current_value.clone();
-                combined.extend(new_files);
-                combined
-            } else {
-                new_files.into_iter().take(1).collect()
-            };
-
-            if let Some(callback) = &on_change {
-                callback.emit(CustomEvent::new_non_cancelable(FileUploadChangeDetail {
-                    value: updated_value,
-                }));
+            if let Some(file_list) = e.data_transfer().and_then(|data| data.files()) {
+                add_files.emit(files_from_file_list(&file_list));
             }
         })
     };
@@ -394,21 +647,25 @@ pub fn file_upload(props: &FileUploadProps) -> Html {
     let root_classes = ClassBuilder::new()
         .add("awsui-file-upload")
         .add_if(props.disabled, "awsui-file-upload-disabled")
-        .add_if(props.invalid, "awsui-file-upload-invalid")
-        .add_if(!props.invalid && props.warning, "awsui-file-upload-warning");
+        .add_if(validation.is_invalid(), "awsui-file-upload-invalid")
+        .add_if(validation.is_warning(), "awsui-file-upload-warning");
 
+    let dropzone_active = *drag_active && !props.disabled;
     let dropzone_classes = ClassBuilder::new()
         .add("awsui-file-upload-dropzone")
-        .add_if(
-            *drag_active && !props.disabled,
-            "awsui-file-upload-dropzone-active",
-        );
+        .add_if(dropzone_active, "awsui-file-upload-dropzone-active");
+    let dropzone_style = match validation.control_style() {
+        Some(control_style) => format!("{}; {}", dropzone_style(dropzone_active), control_style),
+        None => dropzone_style(dropzone_active),
+    };
 
     // Determine control ID
     let control_id = props.control_id.clone();
 
-    html! {
-        <div class={root_classes.build()}>
+    let local_paste = (props.paste_target && !props.capture_global_paste).then_some(on_paste);
+
+    let root = html! {
+        <div class={root_classes.build()} onpaste={local_paste}>
             // Hidden file input
             <input
                 ref={input_ref}
@@ -418,9 +675,10 @@ pub fn file_upload(props: &FileUploadProps) -> Html {
                 name={props.name.clone()}
                 multiple={props.multiple}
                 accept={props.accept.clone()}
+                capture={props.capture.map(|capture| capture.attribute_value())}
                 disabled={props.disabled}
                 aria-required={props.aria_required.to_string()}
-                aria-invalid={props.invalid.to_string()}
+                aria-invalid={validation.is_invalid().then_some("true")}
                 aria-label={props.aria.label.clone()}
                 aria-labelledby={props.aria.labelledby.clone()}
                 aria-describedby={props.aria.describedby.clone()}
@@ -431,6 +689,7 @@ pub fn file_upload(props: &FileUploadProps) -> Html {
             // Drop zone
             <div
                 class={dropzone_classes.build()}
+                style={dropzone_style}
                 ondragenter={on_drag_enter}
                 ondragleave={on_drag_leave}
                 ondragover={on_drag_over}
@@ -464,25 +723,82 @@ pub fn file_upload(props: &FileUploadProps) -> Html {
             // File list
             if !props.value.is_empty() {
                 <div class="awsui-file-upload-file-list">
-                    { for props.value.iter().enumerate().map(|(index, file)| {
-                        render_file_item(file, index, &props.i18n_strings, &on_remove_file, props.disabled)
+                    { for props.value.iter().enumerate().map(|(index, file)| html! {
+                        <FileItem
+                            key={format!("{}-{}", index, file.name)}
+                            file={file.clone()}
+                            index={index}
+                            state={props.file_states.get(&file.name).cloned()}
+                            i18n_strings={props.i18n_strings.clone()}
+                            on_remove={on_remove_file.clone()}
+                            on_retry={on_retry_file.clone()}
+                            disabled={props.disabled}
+                        />
                     }) }
                 </div>
             }
+
+            // Files that were not added
+            if !rejected_files.is_empty() {
+                <div class="awsui-file-upload-file-list awsui-file-upload-rejected-list">
+                    { for rejected_files.iter().map(|rejected| render_rejected_file(rejected, &props.i18n_strings)) }
+                </div>
+            }
+        </div>
+    };
+    apply_root_attributes(root, &props.base)
+}
+
+/// Renders a file that was not added, with its error
+fn render_rejected_file(rejected: &RejectedFile, i18n_strings: &FileUploadI18nStrings) -> Html {
+    html! {
+        <div class="awsui-file-upload-file-item awsui-file-upload-file-item-error">
+            <div class="awsui-file-upload-file-info">
+                <span class="awsui-file-upload-file-name">
+                    { &rejected.file.name }
+                </span>
+                <span class="awsui-file-upload-file-size">
+                    { rejected.file.format_size() }
+                </span>
+            </div>
+            <div
+                class="awsui-file-upload-file-error"
+                style="color: var(--awsui-color-text-status-error)"
+                role="alert"
+            >
+                <span
+                    class="awsui-icon awsui-icon-status-negative"
+                    role="img"
+                    aria-label={i18n_strings.error_icon_aria_label.clone()}
+                />
+                { rejected.error.message(i18n_strings) }
+            </div>
         </div>
     }
 }
 
-/// Renders a single file item in the file list
-fn render_file_item(
-    file: &FileUploadFile,
+#[derive(Properties, PartialEq, Clone)]
+struct FileItemProps {
+    file: FileUploadFile,
     index: usize,
-    i18n_strings: &FileUploadI18nStrings,
-    on_remove: &Callback<usize>,
+    state: Option<FileUploadFileState>,
+    i18n_strings: FileUploadI18nStrings,
+    on_remove: Callback<usize>,
+    on_retry: Callback<usize>,
     disabled: bool,
-) -> Html {
+}
+
+/// A single file item in the file list, with its upload status
+///
+/// Items are components so that an update of one file's progress leaves
+/// the other items of long lists alone.
+#[function_component(FileItem)]
+fn file_item(props: &FileItemProps) -> Html {
+    let index = props.index;
+    let i18n_strings = &props.i18n_strings;
+
     let on_remove_click = {
-        let on_remove = on_remove.clone();
+        let on_remove = props.on_remove.clone();
 
         Callback::from(move |e: MouseEvent| {
             e.prevent_default();
@@ -490,22 +806,82 @@ fn render_file_item(
         })
     };
 
+    let on_retry_click = {
+        let on_retry = props.on_retry.clone();
+
+        Callback::from(move |e: MouseEvent| {
+            e.prevent_default();
+            on_retry.emit(index);
+        })
+    };
+
+    let status = props.state.as_ref().map(|state| match &state.status {
+        FileStatus::Pending => html! {
+            <StatusIndicator status_type={StatusIndicatorType::Pending}>
+                { Html::from(i18n_strings.status_pending_text.clone()) }
+            </StatusIndicator>
+        },
+        FileStatus::Uploading => match state.progress_percent {
+            Some(percent) => html! {
+                <ProgressBar
+                    value={percent}
+                    status={ProgressBarStatus::InProgress}
+                    label={props.file.name.clone()}
+                />
+            },
+            None => html! {
+                <StatusIndicator status_type={StatusIndicatorType::InProgress}>
+                    { Html::from(i18n_strings.status_uploading_text.clone()) }
+                </StatusIndicator>
+            },
+        },
+        FileStatus::Success => html! {
+            <StatusIndicator status_type={StatusIndicatorType::Success}>
+                { Html::from(i18n_strings.status_success_text.clone()) }
+            </StatusIndicator>
+        },
+        FileStatus::Error(message) => html! {
+            <>
+                <StatusIndicator
+                    status_type={StatusIndicatorType::Error}
+                    icon_aria_label={i18n_strings.error_icon_aria_label.clone()}
+                >
+                    { Html::from(message.clone()) }
+                </StatusIndicator>
+                <button
+                    type="button"
+                    class="awsui-file-upload-file-retry awsui-button awsui-button-variant-inline-link"
+                    aria-label={format!("{} {}", i18n_strings.retry_button_text, props.file.name)}
+                    onclick={on_retry_click}
+                    disabled={props.disabled}
+                >
+                    { &i18n_strings.retry_button_text }
+                </button>
+            </>
+        },
+    });
+
     html! {
         <div class="awsui-file-upload-file-item">
             <div class="awsui-file-upload-file-info">
                 <span class="awsui-file-upload-file-name">
-                    { &file.name }
+                    { &props.file.name }
                 </span>
                 <span class="awsui-file-upload-file-size">
-                    { file.format_size() }
+                    { props.file.format_size() }
                 </span>
             </div>
+            if let Some(status) = status {
+                <div class="awsui-file-upload-file-status">
+                    { status }
+                </div>
+            }
             <button
                 type="button"
                 class="awsui-file-upload-file-remove awsui-button awsui-button-variant-icon"
-                aria-label={format!("{} {}", i18n_strings.remove_file_aria_label, file.name)}
+                aria-label={format!("{} {}", i18n_strings.remove_file_aria_label, props.file.name)}
                 onclick={on_remove_click}
-                disabled={disabled}
+                disabled={props.disabled}
             >
                 <span class="awsui-icon awsui-icon-close" aria-hidden="true" />
             </button>
@@ -513,17 +889,206 @@ fn render_file_item(
     }
 }
 
-/// Converts a FileList to a Vec of FileUploadFile
-fn files_from_file_list(file_list: &FileList) -> Vec<FileUploadFile> {
+/// Converts a FileList to a Vec of File
+fn files_from_file_list(file_list: &FileList) -> Vec<File> {
     let mut files = Vec::new();
     for i in 0..file_list.length() {
         if let Some(file) = file_list.get(i) {
-            files.push(FileUploadFile::from_file(&file));
+            files.push(file);
         }
     }
     files
 }
 
+/// Adds the files that satisfy the constraints to the current value
+///
+/// `candidates` are files with their MIME types, checked in order for their
+/// type, size, name and count. A file named like a file of the value, or
+/// like a file added before it, is a duplicate; without `multiple`, the
+/// value is replaced, so only names within `candidates` are compared.
+/// Returns the new value, or `None` when no file was added, and the
+/// rejected files.
+fn add_checked_files(
+    current: &[FileUploadFile],
+    candidates: Vec<(FileUploadFile, String)>,
+    constraints: &FileConstraints,
+) -> (Option<Vec<FileUploadFile>>, Vec<RejectedFile>) {
+    let kept: &[FileUploadFile] = if constraints.multiple { current } else { &[] };
+    let limit = if constraints.multiple {
+        constraints.max_files
+    } else {
+        Some(1)
+    };
+
+    let mut added: Vec<FileUploadFile> = Vec::new();
+    let mut rejected = Vec::new();
+    for (file, mime_type) in candidates {
+        let error = if constraints
+            .accept
+            .as_deref()
+            .is_some_and(|accept| !accepts_type(accept, &mime_type, &file.name))
+        {
+            Some(FileError::UnsupportedType)
+        } else if let Some(max) = constraints.max_file_size
+            && file.size.is_some_and(|size| size as u64 > max)
+        {
+            Some(FileError::TooLarge(max))
+        } else if kept
+            .iter()
+            .chain(&added)
+            .any(|other| other.name == file.name)
+        {
+            Some(FileError::Duplicate)
+        } else if let Some(limit) = limit
+            && kept.len() + added.len() >= limit
+        {
+            Some(FileError::TooManyFiles(limit))
+        } else {
+            None
+        };
+
+        match error {
+            Some(error) => rejected.push(RejectedFile { file, error }),
+            None => added.push(file),
+        }
+    }
+
+    let value = (!added.is_empty()).then(|| merge_files(current, added, constraints.multiple));
+    (value, rejected)
+}
+
+/// Formats a size limit, e.g. "5 MB" or "1.5 GB"
+fn format_limit(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if size.fract() == 0.0 {
+        format!("{} {}", size, UNITS[unit])
+    } else {
+        format!("{:.1} {}", size, UNITS[unit])
+    }
+}
+
+/// Appends new files when multiple files are allowed, otherwise replaces the value
+fn merge_files(
+    current: &[FileUploadFile],
+    new_files: Vec<FileUploadFile>,
+    multiple: bool,
+) -> Vec<FileUploadFile> {
+    if multiple {
+        let mut combined = current.to_vec();
+        combined.extend(new_files);
+        combined
+    } else {
+        new_files.into_iter().take(1).collect()
+    }
+}
+
+/// Checks a MIME type and file name against an accept filter such as "image/*,.pdf"
+fn accepts_type(accept: &str, mime_type: &str, name: &str) -> bool {
+    let mime_type = mime_type.to_lowercase();
+    let name = name.to_lowercase();
+
+    accept
+        .split(',')
+        .map(|pattern| pattern.trim().to_lowercase())
+        .filter(|pattern| !pattern.is_empty())
+        .any(|pattern| {
+            if pattern == "*/*" {
+                true
+            } else if let Some(prefix) = pattern.strip_suffix("/*") {
+                mime_type
+                    .strip_prefix(prefix)
+                    .is_some_and(|rest| rest.starts_with('/'))
+            } else if pattern.starts_with('.') {
+                name.ends_with(&pattern)
+            } else {
+                mime_type == pattern
+            }
+        })
+}
+
+/// Extracts image files from a paste event, renamed with a timestamp
+fn pasted_image_files(event: &Event) -> Vec<File> {
+    let Some(data) = event
+        .dyn_ref::<ClipboardEvent>()
+        .and_then(|event| event.clipboard_data())
+    else {
+        return Vec::new();
+    };
+
+    let items = data.items();
+    let stamp = paste_timestamp();
+    let mut files = Vec::new();
+    for i in 0..items.length() {
+        let Some(item) = items.get(i) else {
+            continue;
+        };
+        let mime_type = item.type_();
+        if item.kind() != "file" || !mime_type.starts_with("image/") {
+            continue;
+        }
+
+        if let Ok(Some(file)) = item.get_as_file() {
+            let name = pasted_file_name(&stamp, &mime_type, files.len());
+            let options = FilePropertyBag::new();
+            options.set_type(&mime_type);
+            if let Ok(renamed) = File::new_with_blob_sequence_and_options(
+                &js_sys::Array::of1(&file),
+                &name,
+                &options,
+            ) {
+                files.push(renamed);
+            }
+        }
+    }
+    files
+}
+
+/// Formats the current local time as "YYYYMMDD-HHMMSS"
+fn paste_timestamp() -> String {
+    let now = js_sys::Date::new_0();
+    format!(
+        "{:04}{:02}{:02}-{:02}{:02}{:02}",
+        now.get_full_year(),
+        now.get_month() + 1,
+        now.get_date(),
+        now.get_hours(),
+        now.get_minutes(),
+        now.get_seconds()
+    )
+}
+
+/// Names a pasted image, e.g. "pasted-image-20240131-154502.png"
+///
+/// Images after the first in the same paste get a counter suffix so names
+/// stay unique.
+fn pasted_file_name(stamp: &str, mime_type: &str, index: usize) -> String {
+    let extension = image_extension(mime_type);
+    if index == 0 {
+        format!("pasted-image-{}.{}", stamp, extension)
+    } else {
+        format!("pasted-image-{}-{}.{}", stamp, index + 1, extension)
+    }
+}
+
+/// Returns the file extension for an image MIME type
+fn image_extension(mime_type: &str) -> &str {
+    match mime_type {
+        "image/jpeg" => "jpg",
+        "image/svg+xml" => "svg",
+        _ => mime_type
+            .strip_prefix("image/")
+            .map(|subtype| subtype.split('+').next().unwrap_or(subtype))
+            .filter(|subtype| !subtype.is_empty())
+            .unwrap_or("png"),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -622,6 +1187,18 @@ mod tests {
         assert_eq!(strings.limit_show_fewer, "Show fewer files");
         assert_eq!(strings.limit_show_more, "Show more files");
         assert_eq!(strings.error_icon_aria_label, "Error");
+        assert_eq!(strings.retry_button_text, "Retry");
+    }
+
+    #[test]
+    fn file_state_builder() {
+        let state = FileUploadFileState::default();
+        assert_eq!(state.status, FileStatus::Pending);
+        assert_eq!(state.progress_percent, None);
+
+        let state = FileUploadFileState::new(FileStatus::Uploading).with_progress(42.0);
+        assert_eq!(state.status, FileStatus::Uploading);
+        assert_eq!(state.progress_percent, Some(42.0));
     }
 
     #[test]
@@ -645,6 +1222,179 @@ mod tests {
         assert_eq!(detail1, detail2);
     }
 
+    #[test]
+    fn accepts_type_matches_mime_and_extension() {
+        assert!(accepts_type("image/*", "image/png", "pasted-image.png"));
+        assert!(!accepts_type("image/*", "application/pdf", "report.pdf"));
+        assert!(!accepts_type("image/*", "imagex/png", "file"));
+        assert!(accepts_type(".pdf, image/png", "image/png", "shot.png"));
+        assert!(accepts_type(".PDF", "", "report.pdf"));
+        assert!(accepts_type("*/*", "text/plain", "notes.txt"));
+        assert!(!accepts_type("image/jpeg", "image/png", "shot.png"));
+    }
+
+    #[test]
+    fn merge_files_appends_or_replaces() {
+        let file = |name: &str| FileUploadFile {
+            name: name.to_string(),
+            size: Some(1),
+            last_modified: None,
+        };
+        let current = vec![file("a.png")];
+
+        let merged = merge_files(&current, vec![file("b.png"), file("c.png")], true);
+        assert_eq!(merged, vec![file("a.png"), file("b.png"), file("c.png")]);
+
+        let replaced = merge_files(&current, vec![file("b.png"), file("c.png")], false);
+        assert_eq!(replaced, vec![file("b.png")]);
+    }
+
+    fn candidate(name: &str, mime_type: &str, size: usize) -> (FileUploadFile, String) {
+        (
+            FileUploadFile {
+                name: name.to_string(),
+                size: Some(size),
+                last_modified: None,
+            },
+            mime_type.to_string(),
+        )
+    }
+
+    #[test]
+    fn add_checked_files_rejects_constraint_violations() {
+        let constraints = FileConstraints {
+            accept: Some("image/*".to_string()),
+            max_file_size: Some(5 * 1024 * 1024),
+            max_files: Some(3),
+            multiple: true,
+        };
+        let current = vec![candidate("a.png", "image/png", 10).0];
+
+        let (value, rejected) = add_checked_files(
+            &current,
+            vec![
+                candidate("report.pdf", "application/pdf", 10),
+                candidate("huge.png", "image/png", 6 * 1024 * 1024),
+                candidate("b.png", "image/png", 10),
+                candidate("a.png", "image/png", 20),
+                candidate("c.png", "image/png", 10),
+                candidate("d.png", "image/png", 10),
+            ],
+            &constraints,
+        );
+
+        let names: Vec<String> = value.unwrap().into_iter().map(|file| file.name).collect();
+        assert_eq!(names, vec!["a.png", "b.png", "c.png"]);
+        let errors: Vec<(&str, FileError)> = rejected
+            .iter()
+            .map(|rejected| (rejected.file.name.as_str(), rejected.error))
+            .collect();
+        assert_eq!(
+            errors,
+            vec![
+                ("report.pdf", FileError::UnsupportedType),
+                ("huge.png", FileError::TooLarge(5 * 1024 * 1024)),
+                ("a.png", FileError::Duplicate),
+                ("d.png", FileError::TooManyFiles(3)),
+            ]
+        );
+
+        let strings = FileUploadI18nStrings::default();
+        assert_eq!(rejected[1].error.message(&strings), "File exceeds 5 MB");
+        assert_eq!(
+            rejected[3].error.message(&strings),
+            "Maximum number of files: 3"
+        );
+    }
+
+    #[test]
+    fn add_checked_files_deduplicates_within_batch() {
+        let constraints = FileConstraints {
+            multiple: true,
+            ..FileConstraints::default()
+        };
+        let (value, rejected) = add_checked_files(
+            &[],
+            vec![
+                candidate("a.png", "image/png", 10),
+                candidate("a.png", "image/png", 20),
+            ],
+            &constraints,
+        );
+
+        // The first file with a name wins
+        assert_eq!(value.unwrap(), vec![candidate("a.png", "image/png", 10).0]);
+        assert_eq!(rejected.len(), 1);
+        assert_eq!(rejected[0].file.size, Some(20));
+        assert_eq!(rejected[0].error, FileError::Duplicate);
+    }
+
+    #[test]
+    fn add_checked_files_single_replaces_value() {
+        let current = vec![candidate("a.png", "image/png", 10).0];
+        let (value, rejected) = add_checked_files(
+            &current,
+            vec![
+                candidate("a.png", "image/png", 20),
+                candidate("b.png", "image/png", 10),
+            ],
+            &FileConstraints::default(),
+        );
+
+        // Replacing a file with a file of the same name is not a duplicate
+        assert_eq!(value.unwrap(), vec![candidate("a.png", "image/png", 20).0]);
+        assert_eq!(rejected[0].error, FileError::TooManyFiles(1));
+
+        let (value, rejected) = add_checked_files(
+            &current,
+            vec![candidate("big.png", "image/png", 2048)],
+            &FileConstraints {
+                max_file_size: Some(1024),
+                ..FileConstraints::default()
+            },
+        );
+        assert_eq!(value, None);
+        assert_eq!(rejected[0].error, FileError::TooLarge(1024));
+    }
+
+    #[test]
+    fn format_limit_drops_whole_fractions() {
+        assert_eq!(format_limit(500), "500 B");
+        assert_eq!(format_limit(5 * 1024 * 1024), "5 MB");
+        assert_eq!(format_limit(1536 * 1024 * 1024), "1.5 GB");
+    }
+
+    #[test]
+    fn dropzone_style_follows_drag_state() {
+        assert!(dropzone_style(false).contains("var(--awsui-color-dropzone-background-default)"));
+        assert!(dropzone_style(true).contains("var(--awsui-color-dropzone-border-hover)"));
+    }
+
+    #[test]
+    fn pasted_file_names() {
+        assert_eq!(
+            pasted_file_name("20240131-154502", "image/png", 0),
+            "pasted-image-20240131-154502.png"
+        );
+        assert_eq!(
+            pasted_file_name("20240131-154502", "image/jpeg", 1),
+            "pasted-image-20240131-154502-2.jpg"
+        );
+        assert_eq!(image_extension("image/svg+xml"), "svg");
+        assert_eq!(image_extension("image/webp"), "webp");
+        assert_eq!(image_extension("image/"), "png");
+    }
+
+    #[test]
+    fn capture_attribute_values() {
+        assert_eq!(FileUploadCapture::Camera.attribute_value(), "");
+        assert_eq!(FileUploadCapture::User.attribute_value(), "user");
+        assert_eq!(
+            FileUploadCapture::Environment.attribute_value(),
+            "environment"
+        );
+    }
+
     #[test]
     fn file_upload_file_equality() {
         let file1 = FileUploadFile {
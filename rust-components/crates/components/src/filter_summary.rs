@@ -0,0 +1,567 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! FilterSummary component for showing the filters and sorting applied to a collection.
+//!
+//! Renders the current filter text, property filter tokens and sort order as
+//! dismissible chips, with a "Clear all" action. Designed to sit in the table
+//! header area under the title so users can see at a glance why data is
+//! missing from the list.
+
+use crate::collection::CollectionState;
+use crate::i18n::{I18nContext, use_i18n};
+use crate::internal::{
+    BaseComponentProps, ClassBuilder, ComponentMetadata, CustomEvent, LiveRegion,
+    apply_root_attributes,
+};
+use crate::table::{SortDirection, SortingState};
+use web_sys::MouseEvent;
+use yew::prelude::*;
+
+/// A single property filter condition, such as "Status = running"
+#[derive(Clone, PartialEq, Debug)]
+pub struct FilterSummaryToken {
+    /// Key of the filtered property
+    pub property_key: String,
+    /// Display label of the filtered property
+    pub property_label: String,
+    /// Comparison operator, such as "=" or "!="
+    pub operator: String,
+    /// Value compared against
+    pub value: String,
+}
+
+impl FilterSummaryToken {
+    /// Creates a new filter token
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cloudscape_components::FilterSummaryToken;
+    ///
+    /// let token = FilterSummaryToken::new("status", "Status", "=", "running");
+    /// assert_eq!(token.label(), "Status = running");
+    /// ```
+    pub fn new(
+        property_key: impl Into<String>,
+        property_label: impl Into<String>,
+        operator: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self {
+        Self {
+            property_key: property_key.into(),
+            property_label: property_label.into(),
+            operator: operator.into(),
+            value: value.into(),
+        }
+    }
+
+    /// Gets the chip text for this token
+    pub fn label(&self) -> String {
+        format!("{} {} {}", self.property_label, self.operator, self.value)
+    }
+}
+
+/// Identifies a chip in the filter summary
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FilterSummaryChip {
+    /// The free-text filter chip
+    FilteringText,
+    /// The property filter token at the given index
+    Token(usize),
+    /// The sorting chip
+    Sorting,
+}
+
+/// Event detail for chip dismiss events
+#[derive(Clone, PartialEq, Debug)]
+pub struct FilterSummaryDismissDetail {
+    /// The chip that was dismissed
+    pub chip: FilterSummaryChip,
+}
+
+/// Internationalization strings for FilterSummary
+///
+/// Unset strings fall back to the closest I18nProvider (keys under
+/// `"filter-summary."`), then to English. The `filtering_text_label` and
+/// `clear_all_text` props take precedence.
+#[derive(Clone, PartialEq, Default)]
+pub struct FilterSummaryI18nStrings {
+    /// Label describing what the free-text filter matches against
+    /// (default: "Text")
+    pub filtering_text_label: Option<String>,
+    /// Chip of the free-text filter, with `{label}` and `{text}` placeholders
+    /// (default: "{label} contains '{text}'")
+    pub filtering_text_chip_text: Option<String>,
+    /// Chip of the sorting, with `{label}` replaced by the column and
+    /// `{direction}` by an arrow (default: "Sorted by {label} {direction}")
+    pub sorting_chip_text: Option<String>,
+    /// ARIA label of the list of chips (default: "Applied filters")
+    pub chips_aria_label: Option<String>,
+    /// ARIA label of a chip's dismiss button, with `{label}` replaced by the
+    /// chip (default: "Remove {label}")
+    pub dismiss_aria_label: Option<String>,
+    /// Announcement after dismissing a chip, with `{label}` replaced by the
+    /// chip (default: "Removed {label}")
+    pub dismissed_text: Option<String>,
+    /// Text of the action clearing every chip (default: "Clear all")
+    pub clear_all_text: Option<String>,
+    /// Announcement after clearing every chip (default: "All filters cleared")
+    pub all_cleared_text: Option<String>,
+}
+
+/// Strings used to render a FilterSummary after applying the fallback chain
+struct ResolvedStrings {
+    filtering_text_label: String,
+    filtering_text_chip_text: String,
+    sorting_chip_text: String,
+    chips_aria_label: String,
+    dismiss_aria_label: String,
+    dismissed_text: String,
+    clear_all_text: String,
+    all_cleared_text: String,
+}
+
+/// Resolves every user-visible string: the label props, then
+/// `i18n_strings`, then the I18nProvider, then English
+fn resolve_strings(props: &FilterSummaryProps, i18n: &I18nContext) -> ResolvedStrings {
+    let strings = &props.i18n_strings;
+    let resolve = |value: &Option<String>, key: &str, default: &str| {
+        value
+            .clone()
+            .or_else(|| i18n.message("filter-summary", key).map(str::to_string))
+            .unwrap_or_else(|| default.to_string())
+    };
+
+    ResolvedStrings {
+        filtering_text_label: props.filtering_text_label.clone().unwrap_or_else(|| {
+            resolve(
+                &strings.filtering_text_label,
+                "filtering_text_label",
+                "Text",
+            )
+        }),
+        filtering_text_chip_text: resolve(
+            &strings.filtering_text_chip_text,
+            "filtering_text_chip_text",
+            "{label} contains '{text}'",
+        ),
+        sorting_chip_text: resolve(
+            &strings.sorting_chip_text,
+            "sorting_chip_text",
+            "Sorted by {label} {direction}",
+        ),
+        chips_aria_label: resolve(
+            &strings.chips_aria_label,
+            "chips_aria_label",
+            "Applied filters",
+        ),
+        dismiss_aria_label: resolve(
+            &strings.dismiss_aria_label,
+            "dismiss_aria_label",
+            "Remove {label}",
+        ),
+        dismissed_text: resolve(&strings.dismissed_text, "dismissed_text", "Removed {label}"),
+        clear_all_text: props
+            .clear_all_text
+            .clone()
+            .unwrap_or_else(|| resolve(&strings.clear_all_text, "clear_all_text", "Clear all")),
+        all_cleared_text: resolve(
+            &strings.all_cleared_text,
+            "all_cleared_text",
+            "All filters cleared",
+        ),
+    }
+}
+
+/// Properties for the FilterSummary component
+#[derive(Properties, PartialEq, Clone)]
+pub struct FilterSummaryProps {
+    /// Base component properties
+    #[prop_or_default]
+    pub base: BaseComponentProps,
+
+    /// Collection state to summarize, as returned by `use_collection`
+    ///
+    /// When set, the filter text, tokens and sorting are read from the
+    /// collection instead of the individual props, and dismissing a chip or
+    /// clearing all updates it. `on_dismiss` and `on_clear_all` still fire.
+    #[prop_or_default]
+    pub collection: Option<UseStateHandle<CollectionState>>,
+
+    /// Current free-text filter; no chip is shown when empty
+    #[prop_or_default]
+    pub filtering_text: String,
+
+    /// Label describing what the free-text filter matches against
+    ///
+    /// Defaults to `i18n_strings.filtering_text_label`, then "Text".
+    #[prop_or_default]
+    pub filtering_text_label: Option<String>,
+
+    /// Current property filter tokens
+    #[prop_or_default]
+    pub tokens: Vec<FilterSummaryToken>,
+
+    /// Current sorting state; no chip is shown without a sort column
+    #[prop_or_default]
+    pub sorting_state: Option<SortingState>,
+
+    /// Header text of the sorted column
+    ///
+    /// Falls back to the column ID when not provided.
+    #[prop_or_default]
+    pub sorting_column_label: Option<String>,
+
+    /// Text for the "Clear all" action
+    ///
+    /// Defaults to `i18n_strings.clear_all_text`, then "Clear all".
+    #[prop_or_default]
+    pub clear_all_text: Option<String>,
+
+    /// Internationalization strings for the chips, actions and
+    /// announcements
+    #[prop_or_default]
+    pub i18n_strings: FilterSummaryI18nStrings,
+
+    /// Callback fired when a single chip is dismissed
+    #[prop_or_default]
+    pub on_dismiss: Option<Callback<CustomEvent<FilterSummaryDismissDetail>>>,
+
+    /// Callback fired when the "Clear all" action is activated
+    #[prop_or_default]
+    pub on_clear_all: Option<Callback<CustomEvent<()>>>,
+}
+
+/// Builds the chips to display, in display order
+fn summary_chips(
+    props: &FilterSummaryProps,
+    strings: &ResolvedStrings,
+) -> Vec<(FilterSummaryChip, String)> {
+    let (filtering_text, tokens, sorting_state) = match &props.collection {
+        Some(collection) => (
+            &collection.filtering_text,
+            &collection.tokens,
+            Some(&collection.sorting),
+        ),
+        None => (
+            &props.filtering_text,
+            &props.tokens,
+            props.sorting_state.as_ref(),
+        ),
+    };
+    let mut chips = Vec::new();
+
+    if !filtering_text.is_empty() {
+        chips.push((
+            FilterSummaryChip::FilteringText,
+            strings
+                .filtering_text_chip_text
+                .replace("{label}", &strings.filtering_text_label)
+                .replace("{text}", filtering_text),
+        ));
+    }
+
+    for (index, token) in tokens.iter().enumerate() {
+        chips.push((FilterSummaryChip::Token(index), token.label()));
+    }
+
+    if let Some(state) = sorting_state
+        && let Some(column_id) = &state.sort_column_id
+    {
+        let label = props.sorting_column_label.as_ref().unwrap_or(column_id);
+        let arrow = match state.sort_direction {
+            SortDirection::Ascending => "▲",
+            SortDirection::Descending => "▼",
+        };
+        chips.push((
+            FilterSummaryChip::Sorting,
+            strings
+                .sorting_chip_text
+                .replace("{label}", label)
+                .replace("{direction}", arrow),
+        ));
+    }
+
+    chips
+}
+
+/// FilterSummary component for showing applied filters and sorting as chips.
+///
+/// The component is controlled: it renders whatever state it is given and
+/// reports dismissals through `on_dismiss` and `on_clear_all`, leaving the
+/// collection state update to the owner. Given a `collection` handle, it
+/// clears the matching filter itself. Dismissals are announced through a
+/// live region, which stays mounted after the last chip is removed so that
+/// the final announcement is still read out.
+///
+/// # Example
+///
+/// ```rust
+/// use cloudscape_components::{
+///     CustomEvent, FilterSummary, FilterSummaryChip, FilterSummaryDismissDetail,
+///     SortDirection, SortingState,
+/// };
+/// use yew::prelude::*;
+///
+/// #[function_component(MyComponent)]
+/// fn my_component() -> Html {
+///     let filtering_text = use_state(|| "prod".to_string());
+///
+///     let on_dismiss = {
+///         let filtering_text = filtering_text.clone();
+///         Callback::from(move |event: CustomEvent<FilterSummaryDismissDetail>| {
+///             if event.detail.chip == FilterSummaryChip::FilteringText {
+///                 filtering_text.set(String::new());
+///             }
+///         })
+///     };
+///
+///     html! {
+///         <FilterSummary
+///             filtering_text={(*filtering_text).clone()}
+///             filtering_text_label="Name"
+///             sorting_state={SortingState {
+///                 sort_column_id: Some("created".to_string()),
+///                 sort_direction: SortDirection::Descending,
+///             }}
+///             sorting_column_label="Created"
+///             on_dismiss={on_dismiss}
+///         />
+///     }
+/// }
+/// ```
+///
+/// With collection state, dismissing a chip needs no callbacks:
+///
+/// ```rust
+/// use cloudscape_components::{CollectionState, FilterSummary, use_collection};
+/// use yew::prelude::*;
+///
+/// #[function_component(MyComponent)]
+/// fn my_component() -> Html {
+///     let collection = use_collection(|| CollectionState::new(20).with_filtering_text("prod"));
+///
+///     html! { <FilterSummary collection={collection} filtering_text_label="Name" /> }
+/// }
+/// ```
+#[function_component(FilterSummary)]
+pub fn filter_summary(props: &FilterSummaryProps) -> Html {
+    let _metadata = ComponentMetadata::new("FilterSummary");
+    let announcement = use_state(String::new);
+    let i18n = use_i18n();
+    let strings = resolve_strings(props, &i18n);
+
+    let chips = summary_chips(props, &strings);
+
+    let classes = ClassBuilder::new()
+        .add("awsui-filter-summary")
+        .add_opt(props.base.class.clone());
+
+    let live_region = html! {
+        <LiveRegion message={(*announcement).clone()} />
+    };
+
+    if chips.is_empty() {
+        let root = html! {
+            <div id={props.base.id.clone()} class={classes.build()}>
+                { live_region }
+            </div>
+        };
+        return apply_root_attributes(root, &props.base);
+    }
+
+    let on_clear_all_click = {
+        let on_clear_all = props.on_clear_all.clone();
+        let collection = props.collection.clone();
+        let announcement = announcement.clone();
+        let all_cleared_text = strings.all_cleared_text.clone();
+
+        Callback::from(move |e: MouseEvent| {
+            e.prevent_default();
+            announcement.set(all_cleared_text.clone());
+            if let Some(collection) = &collection {
+                let mut state = (**collection).clone();
+                state.clear_filters();
+                collection.set(state);
+            }
+            if let Some(callback) = &on_clear_all {
+                callback.emit(CustomEvent::new_non_cancelable(()));
+            }
+        })
+    };
+
+    let root = html! {
+        <div id={props.base.id.clone()} class={classes.build()}>
+            <ul class="awsui-filter-summary-chips" aria-label={strings.chips_aria_label.clone()}>
+                {
+                    chips.into_iter().map(|(chip, label)| {
+                        let on_click = {
+                            let on_dismiss = props.on_dismiss.clone();
+                            let collection = props.collection.clone();
+                            let announcement = announcement.clone();
+                            let dismissed_text = strings.dismissed_text.replace("{label}", &label);
+
+                            Callback::from(move |e: MouseEvent| {
+                                e.prevent_default();
+                                announcement.set(dismissed_text.clone());
+                                if let Some(collection) = &collection {
+                                    let mut state = (**collection).clone();
+                                    state.dismiss(chip);
+                                    collection.set(state);
+                                }
+                                if let Some(callback) = &on_dismiss {
+                                    callback.emit(CustomEvent::new_non_cancelable(
+                                        FilterSummaryDismissDetail { chip },
+                                    ));
+                                }
+                            })
+                        };
+
+                        html! {
+                            <li key={format!("{:?}", chip)} class="awsui-filter-summary-chip">
+                                <span class="awsui-filter-summary-chip-label">{ label.clone() }</span>
+                                <button
+                                    type="button"
+                                    class="awsui-filter-summary-chip-dismiss"
+                                    aria-label={strings.dismiss_aria_label.replace("{label}", &label)}
+                                    onclick={on_click}
+                                >
+                                    { "×" }
+                                </button>
+                            </li>
+                        }
+                    }).collect::<Html>()
+                }
+            </ul>
+            <button
+                type="button"
+                class="awsui-filter-summary-clear-all"
+                onclick={on_clear_all_click}
+            >
+                { strings.clear_all_text.clone() }
+            </button>
+            { live_region }
+        </div>
+    };
+    apply_root_attributes(root, &props.base)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn props() -> FilterSummaryProps {
+        FilterSummaryProps {
+            base: BaseComponentProps::default(),
+            collection: None,
+            filtering_text: String::new(),
+            filtering_text_label: None,
+            tokens: Vec::new(),
+            sorting_state: None,
+            sorting_column_label: None,
+            clear_all_text: None,
+            i18n_strings: FilterSummaryI18nStrings::default(),
+            on_dismiss: None,
+            on_clear_all: None,
+        }
+    }
+
+    /// Strings resolved without an I18nProvider
+    fn english(props: &FilterSummaryProps) -> ResolvedStrings {
+        resolve_strings(props, &I18nContext::default())
+    }
+
+    #[test]
+    fn test_filter_summary_token_label() {
+        let token = FilterSummaryToken::new("status", "Status", "!=", "stopped");
+        assert_eq!(token.property_key, "status");
+        assert_eq!(token.label(), "Status != stopped");
+    }
+
+    #[test]
+    fn test_no_chips_without_state() {
+        assert!(summary_chips(&props(), &english(&props())).is_empty());
+
+        let mut unsorted = props();
+        unsorted.sorting_state = Some(SortingState::default());
+        assert!(summary_chips(&unsorted, &english(&unsorted)).is_empty());
+    }
+
+    #[test]
+    fn test_chips_in_display_order() {
+        let mut props = props();
+        props.filtering_text = "prod".to_string();
+        props.filtering_text_label = Some("Name".to_string());
+        props.tokens = vec![FilterSummaryToken::new("status", "Status", "=", "running")];
+        props.sorting_state = Some(SortingState {
+            sort_column_id: Some("created".to_string()),
+            sort_direction: SortDirection::Descending,
+        });
+        props.sorting_column_label = Some("Created".to_string());
+
+        let chips = summary_chips(&props, &english(&props));
+        assert_eq!(
+            chips,
+            vec![
+                (
+                    FilterSummaryChip::FilteringText,
+                    "Name contains 'prod'".to_string()
+                ),
+                (FilterSummaryChip::Token(0), "Status = running".to_string()),
+                (
+                    FilterSummaryChip::Sorting,
+                    "Sorted by Created ▼".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sorting_chip_falls_back_to_column_id() {
+        let mut props = props();
+        props.sorting_state = Some(SortingState {
+            sort_column_id: Some("name".to_string()),
+            sort_direction: SortDirection::Ascending,
+        });
+
+        let chips = summary_chips(&props, &english(&props));
+        assert_eq!(chips[0].1, "Sorted by name ▲");
+    }
+
+    #[test]
+    fn test_i18n_strings_fallback() {
+        let mut messages = crate::internal::I18nStrings::new();
+        messages.set(
+            "filter-summary.sorting_chip_text",
+            "Sortiert nach {label} {direction}",
+        );
+        messages.set("filter-summary.clear_all_text", "Alle löschen");
+        let i18n = I18nContext {
+            locale: Some("de-DE".to_string()),
+            messages,
+        };
+        let mut props = props();
+        props.filtering_text = "prod".to_string();
+        props.sorting_state = Some(SortingState {
+            sort_column_id: Some("name".to_string()),
+            sort_direction: SortDirection::Ascending,
+        });
+        props.i18n_strings = FilterSummaryI18nStrings {
+            filtering_text_label: Some("Text".to_string()),
+            filtering_text_chip_text: Some("{label} enthält '{text}'".to_string()),
+            ..Default::default()
+        };
+
+        let strings = resolve_strings(&props, &i18n);
+        let chips = summary_chips(&props, &strings);
+        assert_eq!(chips[0].1, "Text enthält 'prod'");
+        assert_eq!(chips[1].1, "Sortiert nach name ▲");
+        assert_eq!(strings.clear_all_text, "Alle löschen");
+        assert_eq!(strings.chips_aria_label, "Applied filters");
+
+        // The label props take precedence
+        props.clear_all_text = Some("Reset".to_string());
+        assert_eq!(resolve_strings(&props, &i18n).clear_all_text, "Reset");
+    }
+}
@@ -0,0 +1,348 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! FlashbarProvider for showing notifications from anywhere on a page.
+//!
+//! The provider owns the list of flash items and renders a Flashbar above
+//! its content. Components below it add and dismiss items through the
+//! handle returned by [`use_flashbar`]; ids are generated by the provider,
+//! and dismiss buttons remove their item without further wiring.
+
+use crate::flashbar::{Flashbar, FlashbarDismissDetail, FlashbarI18nStrings, FlashbarItem};
+use crate::internal::CustomEvent;
+use std::cell::Cell;
+use std::fmt;
+use std::rc::Rc;
+use yew::prelude::*;
+
+/// Identifier of an item added through a [`FlashbarHandle`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FlashId(u64);
+
+impl fmt::Display for FlashId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "flash-{}", self.0)
+    }
+}
+
+/// What happens to items beyond `max_items`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FlashOverflow {
+    /// The oldest items are removed to make room for new ones
+    #[default]
+    DropOldest,
+    /// New items wait until shown items are dismissed
+    Queue,
+}
+
+/// Change to the items of a [`FlashbarProvider`]
+#[derive(Clone, PartialEq)]
+enum FlashAction {
+    /// Adds an item; it already carries its id
+    Add {
+        id: FlashId,
+        item: Box<FlashbarItem>,
+    },
+    /// Removes an item
+    Dismiss(FlashId),
+    /// Removes every item
+    Clear,
+    /// Updates the cap on shown items
+    Limit {
+        max_items: Option<usize>,
+        overflow: FlashOverflow,
+    },
+}
+
+/// Items of a provider, oldest first
+#[derive(Clone, PartialEq, Default)]
+struct FlashStore {
+    items: Vec<(FlashId, FlashbarItem)>,
+    max_items: Option<usize>,
+    overflow: FlashOverflow,
+}
+
+impl FlashStore {
+    fn apply(&mut self, action: FlashAction) {
+        match action {
+            FlashAction::Add { id, item } => self.items.push((id, *item)),
+            FlashAction::Dismiss(id) => self.items.retain(|(item_id, _)| *item_id != id),
+            FlashAction::Clear => self.items.clear(),
+            FlashAction::Limit {
+                max_items,
+                overflow,
+            } => {
+                self.max_items = max_items;
+                self.overflow = overflow;
+            }
+        }
+
+        if let (Some(max_items), FlashOverflow::DropOldest) = (self.max_items, self.overflow) {
+            let excess = self.items.len().saturating_sub(max_items);
+            self.items.drain(..excess);
+        }
+    }
+
+    /// Gets the items to show; queued items are left out
+    fn visible_items(&self) -> Vec<FlashbarItem> {
+        let count = self.max_items.unwrap_or(self.items.len());
+        self.items
+            .iter()
+            .take(count)
+            .map(|(_, item)| item.clone())
+            .collect()
+    }
+
+    /// Finds the item whose `id` is the given Flashbar item id
+    fn find(&self, item_id: &str) -> Option<FlashId> {
+        self.items
+            .iter()
+            .map(|(id, _)| *id)
+            .find(|id| id.to_string() == item_id)
+    }
+}
+
+impl Reducible for FlashStore {
+    type Action = FlashAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut store = (*self).clone();
+        store.apply(action);
+        if store == *self { self } else { Rc::new(store) }
+    }
+}
+
+/// Adds and dismisses the items of the closest [`FlashbarProvider`]
+///
+/// Outside of a provider, the handle does nothing.
+#[derive(Clone)]
+pub struct FlashbarHandle {
+    dispatcher: Option<UseReducerDispatcher<FlashStore>>,
+    next_id: Rc<Cell<u64>>,
+}
+
+impl PartialEq for FlashbarHandle {
+    fn eq(&self, other: &Self) -> bool {
+        self.dispatcher == other.dispatcher && Rc::ptr_eq(&self.next_id, &other.next_id)
+    }
+}
+
+impl FlashbarHandle {
+    /// Shows an item, returning the id to dismiss it with
+    ///
+    /// The item's own `id` is replaced by the generated one.
+    pub fn add(&self, item: FlashbarItem) -> FlashId {
+        let id = FlashId(self.next_id.get());
+        self.next_id.set(id.0 + 1);
+        if let Some(dispatcher) = &self.dispatcher {
+            let item = Box::new(item.with_id(id.to_string()));
+            dispatcher.dispatch(FlashAction::Add { id, item });
+        }
+        id
+    }
+
+    /// Removes an item; unknown ids are ignored
+    pub fn dismiss(&self, id: FlashId) {
+        if let Some(dispatcher) = &self.dispatcher {
+            dispatcher.dispatch(FlashAction::Dismiss(id));
+        }
+    }
+
+    /// Removes every item
+    pub fn clear(&self) {
+        if let Some(dispatcher) = &self.dispatcher {
+            dispatcher.dispatch(FlashAction::Clear);
+        }
+    }
+}
+
+/// Properties for the FlashbarProvider component
+#[derive(Properties, PartialEq, Clone)]
+pub struct FlashbarProviderProps {
+    /// Largest number of items shown at once
+    #[prop_or_default]
+    pub max_items: Option<usize>,
+
+    /// What happens to items beyond `max_items`
+    #[prop_or_default]
+    pub overflow: FlashOverflow,
+
+    /// Whether to collapse two or more items into a stack
+    #[prop_or_default]
+    pub stack_items: bool,
+
+    /// Internationalization strings of the Flashbar
+    #[prop_or_default]
+    pub i18n_strings: FlashbarI18nStrings,
+
+    /// Page content
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// FlashbarProvider component for page-wide notifications.
+///
+/// # Example
+///
+/// ```rust
+/// use cloudscape_components::{FlashbarItem, FlashbarProvider, FlashbarType, use_flashbar};
+/// use yew::prelude::*;
+///
+/// #[function_component(SaveButton)]
+/// fn save_button() -> Html {
+///     let flashbar = use_flashbar();
+///     let onclick = Callback::from(move |_| {
+///         flashbar.add(
+///             FlashbarItem::new(FlashbarType::Success, html! { "Changes saved" })
+///                 .with_dismissible(true),
+///         );
+///     });
+///     html! { <button {onclick}>{"Save"}</button> }
+/// }
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <FlashbarProvider max_items={Some(3)}>
+///             <SaveButton />
+///         </FlashbarProvider>
+///     }
+/// }
+/// ```
+#[function_component(FlashbarProvider)]
+pub fn flashbar_provider(props: &FlashbarProviderProps) -> Html {
+    let store = use_reducer_eq(FlashStore::default);
+    let next_id = use_state(|| Rc::new(Cell::new(0u64)));
+
+    {
+        let store = store.clone();
+        use_effect_with(
+            (props.max_items, props.overflow),
+            move |(max_items, overflow)| {
+                store.dispatch(FlashAction::Limit {
+                    max_items: *max_items,
+                    overflow: *overflow,
+                });
+            },
+        );
+    }
+
+    let handle = FlashbarHandle {
+        dispatcher: Some(store.dispatcher()),
+        next_id: (*next_id).clone(),
+    };
+
+    let on_item_dismiss = {
+        let store = store.clone();
+        Callback::from(move |event: CustomEvent<FlashbarDismissDetail>| {
+            if let Some(id) = event
+                .detail
+                .item_id
+                .as_deref()
+                .and_then(|item_id| store.find(item_id))
+            {
+                store.dispatch(FlashAction::Dismiss(id));
+            }
+        })
+    };
+
+    html! {
+        <ContextProvider<FlashbarHandle> context={handle}>
+            <Flashbar
+                items={store.visible_items()}
+                stack_items={props.stack_items}
+                i18n_strings={props.i18n_strings.clone()}
+                on_item_dismiss={on_item_dismiss}
+            />
+            { for props.children.iter() }
+        </ContextProvider<FlashbarHandle>>
+    }
+}
+
+/// Gets the handle of the closest FlashbarProvider
+///
+/// Outside of a provider, the handle does nothing.
+#[hook]
+pub fn use_flashbar() -> FlashbarHandle {
+    use_context::<FlashbarHandle>().unwrap_or_else(|| FlashbarHandle {
+        dispatcher: None,
+        next_id: Rc::new(Cell::new(0)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flashbar::FlashbarType;
+
+    fn add(store: &mut FlashStore, id: u64, header: &str) {
+        store.apply(FlashAction::Add {
+            id: FlashId(id),
+            item: Box::new(
+                FlashbarItem::new(FlashbarType::Info, html! { "Message" })
+                    .with_id(FlashId(id).to_string())
+                    .with_header(header),
+            ),
+        });
+    }
+
+    fn headers(store: &FlashStore) -> Vec<String> {
+        store
+            .visible_items()
+            .into_iter()
+            .filter_map(|item| item.header)
+            .collect()
+    }
+
+    #[test]
+    fn test_add_dismiss_clear() {
+        let mut store = FlashStore::default();
+        add(&mut store, 0, "Saved");
+        add(&mut store, 1, "Deleted");
+        assert_eq!(headers(&store), vec!["Saved", "Deleted"]);
+        assert_eq!(store.find("flash-1"), Some(FlashId(1)));
+        assert_eq!(store.find("flash-7"), None);
+
+        store.apply(FlashAction::Dismiss(FlashId(0)));
+        assert_eq!(headers(&store), vec!["Deleted"]);
+
+        store.apply(FlashAction::Clear);
+        assert!(store.items.is_empty());
+    }
+
+    #[test]
+    fn test_drop_oldest_beyond_limit() {
+        let mut store = FlashStore::default();
+        store.apply(FlashAction::Limit {
+            max_items: Some(2),
+            overflow: FlashOverflow::DropOldest,
+        });
+        add(&mut store, 0, "First");
+        add(&mut store, 1, "Second");
+        add(&mut store, 2, "Third");
+        assert_eq!(headers(&store), vec!["Second", "Third"]);
+        assert_eq!(store.items.len(), 2);
+    }
+
+    #[test]
+    fn test_queue_beyond_limit() {
+        let mut store = FlashStore::default();
+        store.apply(FlashAction::Limit {
+            max_items: Some(2),
+            overflow: FlashOverflow::Queue,
+        });
+        add(&mut store, 0, "First");
+        add(&mut store, 1, "Second");
+        add(&mut store, 2, "Third");
+        assert_eq!(headers(&store), vec!["First", "Second"]);
+
+        // Dismissing a shown item reveals the next queued one
+        store.apply(FlashAction::Dismiss(FlashId(0)));
+        assert_eq!(headers(&store), vec!["Second", "Third"]);
+    }
+
+    #[test]
+    fn test_flash_id_display() {
+        assert_eq!(FlashId(3).to_string(), "flash-3");
+    }
+}
@@ -5,11 +5,39 @@
 //!
 //! The Flashbar is a notification container that displays multiple flash messages,
 //! typically used for showing success, error, warning, or informational messages to users.
+//! Items can also track a long-running operation with an in-flash progress bar.
+//!
+//! Stacked flashbars collapse two or more items into the first item and a
+//! notification bar counting the items of each type; the bar expands the
+//! full list.
+//!
+//! Items can dismiss themselves after a delay, like toasts; the countdown
+//! pauses while the item is hovered or has focus.
 
-use crate::internal::{BaseComponentProps, ClassBuilder, CustomEvent};
+use crate::alert::status_aria_label;
+use crate::i18n::{I18nContext, use_i18n};
+use crate::internal::dev_warnings::{DevWarning, use_dev_warnings};
+use crate::internal::motion::motion_disabled;
+use crate::internal::{
+    BaseComponentProps, ClassBuilder, CustomEvent, LiveRegion, apply_root_attributes,
+};
+use crate::progress_bar::{ProgressBar, ProgressBarStatus, ProgressBarVariant};
 use crate::spinner::{Spinner, SpinnerSize};
+use gloo::render::request_animation_frame;
+use gloo_timers::callback::Timeout;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, Node};
 use yew::prelude::*;
 
+/// Colors of the notification bar of stacked items
+const NOTIFICATION_BAR_STYLE: &str = "background-color: var(--awsui-color-background-notification-stack-bar); \
+     border-color: var(--awsui-color-border-notification-stack-bar); \
+     color: var(--awsui-color-text-notification-stack-bar)";
+
+/// Track of the countdown bar of auto-dismissing items
+const COUNTDOWN_TRACK_STYLE: &str =
+    "height: 2px; background-color: var(--awsui-color-background-progress-bar-layout-in-flash)";
+
 /// Flash message type variants
 ///
 /// Determines the visual styling and icon for a flash item.
@@ -56,6 +84,84 @@ impl FlashbarType {
             Self::Error => "Error",
         }
     }
+
+    /// All flash types, most severe first
+    pub const ALL: [FlashbarType; 4] = [Self::Error, Self::Warning, Self::Success, Self::Info];
+}
+
+/// Progress of a long-running operation shown inside a flash item
+///
+/// Mirrors the [`ProgressBar`] properties that apply to the in-flash variant.
+#[derive(Clone, PartialEq, Default)]
+pub struct FlashbarProgress {
+    /// Progress percentage (0-100)
+    pub value: f32,
+    /// Operation status; completing the operation switches the flash type
+    pub status: ProgressBarStatus,
+    /// Label shown above the bar
+    pub label: Option<String>,
+    /// Description shown below the label
+    pub description: Option<Html>,
+    /// Additional information shown below the bar
+    pub additional_info: Option<Html>,
+    /// Text shown instead of the bar once the operation has completed
+    pub result_text: Option<String>,
+    /// Controls for the operation, such as Pause, Resume and Cancel buttons
+    pub actions: Option<Html>,
+    /// Whether the operation is paused
+    pub paused: bool,
+}
+
+impl FlashbarProgress {
+    /// Creates in-progress state at the given percentage
+    pub fn new(value: f32) -> Self {
+        Self {
+            value,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the operation status
+    pub fn with_status(mut self, status: ProgressBarStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Sets the label
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the description
+    pub fn with_description(mut self, description: Html) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Sets the additional information
+    pub fn with_additional_info(mut self, additional_info: Html) -> Self {
+        self.additional_info = Some(additional_info);
+        self
+    }
+
+    /// Sets the result text
+    pub fn with_result_text(mut self, result_text: impl Into<String>) -> Self {
+        self.result_text = Some(result_text.into());
+        self
+    }
+
+    /// Sets the controls shown next to the bar, such as Pause and Cancel
+    pub fn with_actions(mut self, actions: Html) -> Self {
+        self.actions = Some(actions);
+        self
+    }
+
+    /// Sets whether the operation is paused
+    pub fn with_paused(mut self, paused: bool) -> Self {
+        self.paused = paused;
+        self
+    }
 }
 
 /// Individual flash message item
@@ -81,6 +187,20 @@ pub struct FlashbarItem {
     pub button_text: Option<String>,
     /// Callback for action button clicks
     pub on_button_click: Option<Callback<MouseEvent>>,
+    /// Progress of a long-running operation, rendered as an in-flash progress bar
+    pub progress: Option<FlashbarProgress>,
+    /// Whether a dismissible item can be dismissed while its operation is in progress
+    pub dismissible_while_in_progress: bool,
+    /// Delay in milliseconds after which the item dismisses itself once its
+    /// operation succeeds
+    pub auto_dismiss_on_success: Option<u32>,
+    /// Plain-text summary of the content, read after the header in the
+    /// item's accessible name
+    pub content_summary: Option<String>,
+    /// Delay in milliseconds after which the item dismisses itself
+    ///
+    /// The countdown pauses while the item is hovered or has focus.
+    pub auto_dismiss: Option<u32>,
 }
 
 impl FlashbarItem {
@@ -108,6 +228,36 @@ impl FlashbarItem {
             action: None,
             button_text: None,
             on_button_click: None,
+            progress: None,
+            dismissible_while_in_progress: false,
+            auto_dismiss_on_success: None,
+            content_summary: None,
+            auto_dismiss: None,
+        }
+    }
+
+    /// Creates a flash item tracking a long-running operation
+    ///
+    /// The item starts as an info flash with a loading indicator and switches
+    /// to success or error when the progress status is updated.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cloudscape_components::{FlashbarItem, FlashbarProgress};
+    /// use yew::prelude::*;
+    ///
+    /// let item = FlashbarItem::progress(
+    ///     html! { "Creating 12 resources" },
+    ///     FlashbarProgress::new(58.0).with_additional_info(html! { "7 of 12 complete" }),
+    /// )
+    /// .with_id("create-resources")
+    /// .with_auto_dismiss_on_success(5000);
+    /// ```
+    pub fn progress(content: Html, progress: FlashbarProgress) -> Self {
+        Self {
+            progress: Some(progress),
+            ..Self::new(FlashbarType::Info, content)
         }
     }
 
@@ -152,6 +302,198 @@ impl FlashbarItem {
         self.on_button_click = Some(callback);
         self
     }
+
+    /// Sets the operation progress
+    pub fn with_progress(mut self, progress: FlashbarProgress) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+
+    /// Sets whether the item can be dismissed while its operation is in progress
+    pub fn with_dismissible_while_in_progress(mut self, dismissible: bool) -> Self {
+        self.dismissible_while_in_progress = dismissible;
+        self
+    }
+
+    /// Dismisses the item the given number of milliseconds after its
+    /// operation succeeds
+    pub fn with_auto_dismiss_on_success(mut self, delay_ms: u32) -> Self {
+        self.auto_dismiss_on_success = Some(delay_ms);
+        self
+    }
+
+    /// Dismisses the item the given number of milliseconds after it is shown,
+    /// not counting the time it is hovered or has focus
+    pub fn with_auto_dismiss(mut self, delay_ms: u32) -> Self {
+        self.auto_dismiss = Some(delay_ms);
+        self
+    }
+
+    /// Sets the plain-text content summary read in the item's accessible name
+    pub fn with_content_summary(mut self, summary: impl Into<String>) -> Self {
+        self.content_summary = Some(summary.into());
+        self
+    }
+
+    /// Whether the item tracks an operation that has not completed yet
+    pub fn is_in_progress(&self) -> bool {
+        self.progress
+            .as_ref()
+            .is_some_and(|progress| progress.status == ProgressBarStatus::InProgress)
+    }
+
+    /// Gets the flash type to display, following the operation status
+    pub fn effective_type(&self) -> FlashbarType {
+        match self.progress.as_ref().map(|progress| progress.status) {
+            Some(ProgressBarStatus::Success) => FlashbarType::Success,
+            Some(ProgressBarStatus::Error) => FlashbarType::Error,
+            _ => self.flash_type,
+        }
+    }
+
+    /// Whether the dismiss button is shown in the item's current state
+    pub fn is_dismissible(&self) -> bool {
+        self.dismissible && (!self.is_in_progress() || self.dismissible_while_in_progress)
+    }
+}
+
+/// Internationalization strings for Flashbar
+///
+/// Unset strings fall back to the closest I18nProvider (keys under
+/// `"flashbar."`), then to English.
+#[derive(Clone, PartialEq, Default)]
+pub struct FlashbarI18nStrings {
+    /// ARIA label of the notification region (default: "Notifications")
+    pub aria_label: Option<String>,
+    /// Text of the stacked notification bar (default: "Notifications")
+    pub notification_bar_text: Option<String>,
+    /// ARIA label of the button expanding stacked notifications
+    /// (default: "View all notifications")
+    pub notification_bar_aria_label: Option<String>,
+    /// ARIA label of the button collapsing expanded notifications
+    /// (default: "Collapse notifications")
+    pub collapse_aria_label: Option<String>,
+    /// ARIA label for the info icon (default: "Info")
+    pub info_icon_aria_label: Option<String>,
+    /// ARIA label for the success icon (default: "Success")
+    pub success_icon_aria_label: Option<String>,
+    /// ARIA label for the warning icon (default: "Warning")
+    pub warning_icon_aria_label: Option<String>,
+    /// ARIA label for the error icon (default: "Error")
+    pub error_icon_aria_label: Option<String>,
+    /// ARIA label for the loading indicator of in-progress items
+    /// (default: "In progress")
+    pub in_progress_icon_aria_label: Option<String>,
+    /// ARIA label for the dismiss buttons (default: "Dismiss notification")
+    pub dismiss_aria_label: Option<String>,
+}
+
+/// Strings used to render a Flashbar after applying the fallback chain
+#[derive(Clone, PartialEq)]
+struct ResolvedStrings {
+    aria_label: String,
+    notification_bar_text: String,
+    notification_bar_aria_label: String,
+    collapse_aria_label: String,
+    info_icon_aria_label: String,
+    success_icon_aria_label: String,
+    warning_icon_aria_label: String,
+    error_icon_aria_label: String,
+    in_progress_icon_aria_label: String,
+    dismiss_aria_label: String,
+}
+
+impl ResolvedStrings {
+    /// Gets the label announcing the severity of a flash type
+    fn type_label(&self, flash_type: FlashbarType) -> &str {
+        match flash_type {
+            FlashbarType::Info => &self.info_icon_aria_label,
+            FlashbarType::Success => &self.success_icon_aria_label,
+            FlashbarType::Warning => &self.warning_icon_aria_label,
+            FlashbarType::Error => &self.error_icon_aria_label,
+        }
+    }
+
+    /// Summarizes the item counts of a stack, such as
+    /// "Notifications: Error 2, Info 1"
+    fn stack_summary(&self, counts: &[(FlashbarType, usize)]) -> String {
+        let counts = counts
+            .iter()
+            .filter(|(_, count)| *count > 0)
+            .map(|(flash_type, count)| format!("{} {}", self.type_label(*flash_type), count))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("{}: {}", self.notification_bar_text, counts)
+    }
+
+    /// Composes the accessible name of an item: severity, header, summary
+    fn item_aria_label(&self, item: &FlashbarItem) -> String {
+        status_aria_label(
+            self.type_label(item.effective_type()),
+            item.header.as_deref(),
+            item.content_summary.as_deref(),
+        )
+    }
+}
+
+/// Resolves every user-visible string: `i18n_strings` prop, then the
+/// I18nProvider, then English
+fn resolve_strings(strings: &FlashbarI18nStrings, i18n: &I18nContext) -> ResolvedStrings {
+    let resolve = |value: &Option<String>, key: &str, default: &str| {
+        value
+            .clone()
+            .or_else(|| i18n.message("flashbar", key).map(str::to_string))
+            .unwrap_or_else(|| default.to_string())
+    };
+
+    ResolvedStrings {
+        aria_label: resolve(&strings.aria_label, "aria_label", "Notifications"),
+        notification_bar_text: resolve(
+            &strings.notification_bar_text,
+            "notification_bar_text",
+            "Notifications",
+        ),
+        notification_bar_aria_label: resolve(
+            &strings.notification_bar_aria_label,
+            "notification_bar_aria_label",
+            "View all notifications",
+        ),
+        collapse_aria_label: resolve(
+            &strings.collapse_aria_label,
+            "collapse_aria_label",
+            "Collapse notifications",
+        ),
+        info_icon_aria_label: resolve(
+            &strings.info_icon_aria_label,
+            "info_icon_aria_label",
+            FlashbarType::Info.default_aria_label(),
+        ),
+        success_icon_aria_label: resolve(
+            &strings.success_icon_aria_label,
+            "success_icon_aria_label",
+            FlashbarType::Success.default_aria_label(),
+        ),
+        warning_icon_aria_label: resolve(
+            &strings.warning_icon_aria_label,
+            "warning_icon_aria_label",
+            FlashbarType::Warning.default_aria_label(),
+        ),
+        error_icon_aria_label: resolve(
+            &strings.error_icon_aria_label,
+            "error_icon_aria_label",
+            FlashbarType::Error.default_aria_label(),
+        ),
+        in_progress_icon_aria_label: resolve(
+            &strings.in_progress_icon_aria_label,
+            "in_progress_icon_aria_label",
+            "In progress",
+        ),
+        dismiss_aria_label: resolve(
+            &strings.dismiss_aria_label,
+            "dismiss_aria_label",
+            "Dismiss notification",
+        ),
+    }
 }
 
 /// Event detail for item dismiss events
@@ -172,21 +514,60 @@ pub struct FlashbarProps {
     #[prop_or_default]
     pub items: Vec<FlashbarItem>,
 
-    /// Whether to stack items in a compact vertical list
+    /// Whether to collapse two or more items into a stack
+    ///
+    /// A collapsed stack shows the first item and a notification bar with
+    /// the number of items of each type; the bar expands the full list.
     #[prop_or_default]
     pub stack_items: bool,
 
+    /// Internationalization strings
+    #[prop_or_default]
+    pub i18n_strings: FlashbarI18nStrings,
+
     /// Callback fired when an item is dismissed
     #[prop_or_default]
     pub on_item_dismiss: Option<Callback<CustomEvent<FlashbarDismissDetail>>>,
 }
 
+/// Counts the items of each type, most severe first
+fn type_counts(items: &[FlashbarItem]) -> [(FlashbarType, usize); 4] {
+    FlashbarType::ALL.map(|flash_type| {
+        let count = items
+            .iter()
+            .filter(|item| item.effective_type() == flash_type)
+            .count();
+        (flash_type, count)
+    })
+}
+
+/// Misuse of Flashbar props reported in development builds
+fn dev_warnings(props: &FlashbarProps, i18n: &I18nContext) -> Vec<DevWarning> {
+    let has_dismiss_label = props.i18n_strings.dismiss_aria_label.is_some()
+        || i18n.message("flashbar", "dismiss_aria_label").is_some();
+
+    if props.items.iter().any(|item| item.dismissible) && !has_dismiss_label {
+        vec![DevWarning::new(
+            "flashbar-dismissible-without-label",
+            "Dismissible items need a dismiss button label. Set \
+             `i18n_strings.dismiss_aria_label` or provide `flashbar.dismiss_aria_label` \
+             through an I18nProvider; the English fallback is used until then.",
+        )]
+    } else {
+        Vec::new()
+    }
+}
+
 /// Flashbar component for displaying multiple flash messages.
 ///
 /// A notification container that displays multiple flash messages with different
 /// severity levels. Each message can be dismissed individually and supports
 /// loading states, action buttons, and custom content.
 ///
+/// Items created with [`FlashbarItem::progress`] render an in-flash progress
+/// bar. Give them a stable `id` so that value updates re-render the existing
+/// item instead of replaying its entry animation.
+///
 /// # Example
 ///
 /// ```rust
@@ -211,51 +592,361 @@ pub struct FlashbarProps {
 /// ```
 #[function_component(Flashbar)]
 pub fn flashbar(props: &FlashbarProps) -> Html {
+    let i18n = use_i18n();
+    use_dev_warnings("Flashbar").check(|| dev_warnings(props, &i18n));
+    let strings = resolve_strings(&props.i18n_strings, &i18n);
+    let expanded = use_state(|| false);
+
+    let stacked = props.stack_items && props.items.len() >= 2;
+
+    // A stack that falls apart starts collapsed when it forms again
+    {
+        let expanded = expanded.clone();
+        use_effect_with(stacked, move |stacked| {
+            if !*stacked {
+                expanded.set(false);
+            }
+        });
+    }
+
+    let collapsed = stacked && !*expanded;
+    let counts = type_counts(&props.items);
+
     // Build root CSS classes
     let classes = ClassBuilder::new()
         .add("awsui-flashbar")
-        .add_if(props.stack_items, "awsui-flashbar-stacked");
+        .add_if(stacked, "awsui-flashbar-stacked")
+        .add_if(collapsed, "awsui-flashbar-collapsed");
 
     let root_class = props.base.merge_classes(&classes.build());
 
-    html! {
+    let on_toggle = {
+        let expanded = expanded.clone();
+        Callback::from(move |_: MouseEvent| expanded.set(!*expanded))
+    };
+
+    // Items keep their position in `items`, so the keys of the shown items
+    // don't change when the stack expands or an item is dismissed
+    let shown_items = if collapsed {
+        &props.items[..1]
+    } else {
+        &props.items[..]
+    };
+
+    let root = html! {
         <div
             id={props.base.id.clone()}
             class={root_class}
             role="region"
             aria-live="polite"
-            aria-label="Notifications"
+            aria-label={strings.aria_label.clone()}
         >
             {
-                props.items.iter().enumerate().map(|(index, item)| {
-                    render_flash_item(item, index, &props.on_item_dismiss)
+                shown_items.iter().enumerate().map(|(index, item)| {
+                    html! {
+                        <FlashItem
+                            key={item.id.clone().unwrap_or_else(|| index.to_string())}
+                            item={item.clone()}
+                            strings={strings.clone()}
+                            on_dismiss={props.on_item_dismiss.clone()}
+                        />
+                    }
                 }).collect::<Html>()
             }
+
+            if stacked {
+                <button
+                    type="button"
+                    class="awsui-flashbar-notification-bar"
+                    style={NOTIFICATION_BAR_STYLE}
+                    aria-expanded={(*expanded).to_string()}
+                    aria-label={if *expanded {
+                        strings.collapse_aria_label.clone()
+                    } else {
+                        strings.notification_bar_aria_label.clone()
+                    }}
+                    onclick={on_toggle}
+                >
+                    <span class="awsui-flashbar-notification-bar-text">
+                        { &strings.notification_bar_text }
+                    </span>
+                    <span class="awsui-flashbar-notification-bar-counts">
+                        { for counts.iter().map(|(flash_type, count)| html! {
+                            <span class={format!(
+                                "awsui-flashbar-notification-bar-count awsui-flashbar-notification-bar-count-{}",
+                                flash_type.as_str()
+                            )}>
+                                <span
+                                    class={format!("awsui-icon awsui-icon-{}", flash_type.icon_name())}
+                                    role="img"
+                                    aria-label={strings.type_label(*flash_type).to_string()}
+                                />
+                                <span>{ *count }</span>
+                            </span>
+                        }) }
+                    </span>
+                    <span
+                        class={if *expanded {
+                            "awsui-icon awsui-icon-angle-up"
+                        } else {
+                            "awsui-icon awsui-icon-angle-down"
+                        }}
+                        aria-hidden="true"
+                    />
+                </button>
+            }
+
+            // Items hidden in a collapsed stack announce themselves through
+            // the updated counts
+            <LiveRegion message={if collapsed { strings.stack_summary(&counts) } else { String::new() }} />
         </div>
+    };
+    apply_root_attributes(root, &props.base)
+}
+
+/// Remaining time of an auto-dismissing item, in milliseconds
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Countdown {
+    duration_ms: u32,
+    remaining_ms: f64,
+    /// Time at which the countdown last resumed, while it runs
+    running_since: Option<f64>,
+}
+
+impl Countdown {
+    fn new(duration_ms: u32) -> Self {
+        Self {
+            duration_ms,
+            remaining_ms: f64::from(duration_ms),
+            running_since: None,
+        }
+    }
+
+    /// Gets the remaining time at `now`
+    fn remaining(&self, now: f64) -> f64 {
+        let elapsed = self.running_since.map_or(0.0, |since| now - since);
+        (self.remaining_ms - elapsed).max(0.0)
+    }
+
+    /// Starts counting down from `now`, returning the remaining time
+    fn resume(&mut self, now: f64) -> f64 {
+        if self.running_since.is_none() {
+            self.running_since = Some(now);
+        }
+        self.remaining(now)
+    }
+
+    /// Stops counting down at `now`
+    fn pause(&mut self, now: f64) {
+        self.remaining_ms = self.remaining(now);
+        self.running_since = None;
+    }
+
+    /// Gets the remaining share of the duration at `now`, in percent
+    fn remaining_percent(&self, now: f64) -> f64 {
+        if self.duration_ms == 0 {
+            0.0
+        } else {
+            self.remaining(now) / f64::from(self.duration_ms) * 100.0
+        }
+    }
+}
+
+/// Width of the countdown bar, shrinking to zero over `transition_ms`
+/// while the countdown runs
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct CountdownBar {
+    percent: f64,
+    transition_ms: Option<f64>,
+}
+
+impl CountdownBar {
+    fn style(&self) -> String {
+        let transition = match self.transition_ms {
+            Some(duration) => format!("transition: width {}ms linear", duration.round()),
+            None => "transition: none".to_string(),
+        };
+        format!(
+            "height: 100%; width: {:.2}%; {}; \
+             background-color: var(--awsui-color-background-progress-bar-content-in-flash)",
+            self.percent, transition
+        )
     }
 }
 
+#[derive(Properties, PartialEq, Clone)]
+struct FlashItemProps {
+    item: FlashbarItem,
+    strings: ResolvedStrings,
+    on_dismiss: Option<Callback<CustomEvent<FlashbarDismissDetail>>>,
+}
+
 /// Renders a single flash item
-fn render_flash_item(
-    item: &FlashbarItem,
-    index: usize,
-    on_dismiss_callback: &Option<Callback<CustomEvent<FlashbarDismissDetail>>>,
-) -> Html {
+///
+/// A component rather than a render function so that each item keeps its
+/// own entry state and auto-dismiss timer across updates.
+#[function_component(FlashItem)]
+fn flash_item(props: &FlashItemProps) -> Html {
+    let item = &props.item;
+    let flash_type = item.effective_type();
+    let in_progress = item.is_in_progress();
+    let dismissible = item.is_dismissible();
+
+    // The entry class is only present on the first render, so later updates
+    // (such as progress values) don't replay the animation
+    let entering = use_state(|| true);
+    {
+        let entering = entering.clone();
+        use_effect_with((), move |_| {
+            entering.set(false);
+            || ()
+        });
+    }
+
+    // Dismiss automatically once the operation succeeds
+    {
+        let succeeded = item
+            .progress
+            .as_ref()
+            .is_some_and(|progress| progress.status == ProgressBarStatus::Success);
+        let delay = item.auto_dismiss_on_success;
+        let item_id = item.id.clone();
+        let on_dismiss = props.on_dismiss.clone();
+
+        use_effect_with((succeeded, delay), move |(succeeded, delay)| {
+            let timeout = match (*succeeded, *delay) {
+                (true, Some(delay)) => Some(Timeout::new(delay, move || {
+                    if let Some(callback) = &on_dismiss {
+                        callback.emit(CustomEvent::new_non_cancelable(FlashbarDismissDetail {
+                            item_id,
+                        }));
+                    }
+                })),
+                _ => None,
+            };
+            move || drop(timeout)
+        });
+    }
+
+    // Count down to dismissal while the item is neither hovered nor focused
+    let item_ref = use_node_ref();
+    let hovered = use_state(|| false);
+    let focused = use_state(|| false);
+    let countdown = use_mut_ref(|| None::<Countdown>);
+    let countdown_bar = use_state(|| None::<CountdownBar>);
+    {
+        let paused = *hovered || *focused;
+        let delay = item.auto_dismiss;
+        let item_id = item.id.clone();
+        let on_dismiss = props.on_dismiss.clone();
+        let item_ref = item_ref.clone();
+        let countdown_bar = countdown_bar.clone();
+
+        use_effect_with((paused, delay), move |(paused, delay)| {
+            let mut timers = None;
+            match *delay {
+                Some(delay) => {
+                    let now = js_sys::Date::now();
+                    let mut stored = countdown.borrow_mut();
+                    let state = match &mut *stored {
+                        Some(state) if state.duration_ms == delay => state,
+                        state => state.insert(Countdown::new(delay)),
+                    };
+                    // With motion disabled the bar is hidden, but the item
+                    // is still dismissed on time
+                    let show_bar = !motion_disabled(item_ref.cast::<Element>().as_ref());
+
+                    if *paused {
+                        countdown_bar.set(show_bar.then(|| CountdownBar {
+                            percent: state.remaining_percent(now),
+                            transition_ms: None,
+                        }));
+                    } else {
+                        let remaining = state.resume(now);
+                        // Show the remaining width, then shrink it to zero
+                        // from the next frame on
+                        countdown_bar.set(show_bar.then(|| CountdownBar {
+                            percent: state.remaining_percent(now),
+                            transition_ms: None,
+                        }));
+                        let frame = {
+                            let countdown_bar = countdown_bar.clone();
+                            show_bar.then(|| {
+                                request_animation_frame(move |_| {
+                                    countdown_bar.set(Some(CountdownBar {
+                                        percent: 0.0,
+                                        transition_ms: Some(remaining),
+                                    }));
+                                })
+                            })
+                        };
+                        let timeout = Timeout::new(remaining as u32, move || {
+                            if let Some(callback) = &on_dismiss {
+                                callback.emit(CustomEvent::new_non_cancelable(
+                                    FlashbarDismissDetail { item_id },
+                                ));
+                            }
+                        });
+                        timers = Some((timeout, frame));
+                    }
+                }
+                None => {
+                    *countdown.borrow_mut() = None;
+                    countdown_bar.set(None);
+                }
+            }
+
+            move || {
+                if let Some(state) = countdown.borrow_mut().as_mut() {
+                    state.pause(js_sys::Date::now());
+                }
+                drop(timers);
+            }
+        });
+    }
+
+    let on_mouse_enter = {
+        let hovered = hovered.clone();
+        Callback::from(move |_: MouseEvent| hovered.set(true))
+    };
+    let on_mouse_leave = {
+        let hovered = hovered.clone();
+        Callback::from(move |_: MouseEvent| hovered.set(false))
+    };
+    let on_focus_in = {
+        let focused = focused.clone();
+        Callback::from(move |_: FocusEvent| focused.set(true))
+    };
+    let on_focus_out = {
+        let focused = focused.clone();
+        let item_ref = item_ref.clone();
+        Callback::from(move |e: FocusEvent| {
+            // Moving focus between controls of the item keeps it paused
+            let within = e
+                .related_target()
+                .and_then(|target| target.dyn_into::<Node>().ok())
+                .zip(item_ref.cast::<Element>())
+                .is_some_and(|(target, item)| item.contains(Some(&target)));
+            if !within {
+                focused.set(false);
+            }
+        })
+    };
+
     // Build item CSS classes
     let item_classes = ClassBuilder::new()
         .add("awsui-flashbar-item")
-        .add(format!(
-            "awsui-flashbar-item-type-{}",
-            item.flash_type.as_str()
-        ))
-        .add_if(item.dismissible, "awsui-flashbar-item-dismissible")
-        .add_if(item.loading, "awsui-flashbar-item-loading")
+        .add(format!("awsui-flashbar-item-type-{}", flash_type.as_str()))
+        .add_if(dismissible, "awsui-flashbar-item-dismissible")
+        .add_if(item.loading || in_progress, "awsui-flashbar-item-loading")
+        .add_if(item.progress.is_some(), "awsui-flashbar-item-progress")
+        .add_if(*entering, "awsui-flashbar-item-entering")
         .build();
 
     // Handle dismiss button click
     let on_dismiss_click = {
         let item_id = item.id.clone();
-        let on_dismiss = on_dismiss_callback.clone();
+        let on_dismiss = props.on_dismiss.clone();
 
         Callback::from(move |e: MouseEvent| {
             e.prevent_default();
@@ -268,24 +959,32 @@ fn render_flash_item(
         })
     };
 
-    // ARIA label for the item
-    let aria_label = item.flash_type.default_aria_label();
+    // The accessible name announces the severity first
+    let strings = &props.strings;
+    let aria_label = strings.item_aria_label(item);
 
     html! {
         <div
-            key={item.id.clone().unwrap_or_else(|| index.to_string())}
+            ref={item_ref}
             class={item_classes}
             role="alert"
             aria-label={aria_label}
+            onmouseenter={on_mouse_enter}
+            onmouseleave={on_mouse_leave}
+            onfocusin={on_focus_in}
+            onfocusout={on_focus_out}
         >
             // Icon or loading spinner
             <div class="awsui-flashbar-item-icon">
-                if item.loading {
-                    <Spinner size={SpinnerSize::Normal} />
+                if item.loading || in_progress {
+                    <span role="img" aria-label={strings.in_progress_icon_aria_label.clone()}>
+                        <Spinner size={SpinnerSize::Normal} />
+                    </span>
                 } else {
                     <span
-                        class={format!("awsui-icon awsui-icon-{}", item.flash_type.icon_name())}
-                        aria-hidden="true"
+                        class={format!("awsui-icon awsui-icon-{}", flash_type.icon_name())}
+                        role="img"
+                        aria-label={strings.type_label(flash_type).to_string()}
                     />
                 }
             </div>
@@ -304,6 +1003,23 @@ fn render_flash_item(
                     { item.content.clone() }
                 </div>
 
+                // Operation progress
+                if let Some(ref progress) = item.progress {
+                    <div class="awsui-flashbar-item-progress-bar">
+                        <ProgressBar
+                            variant={ProgressBarVariant::Flash}
+                            value={progress.value}
+                            status={progress.status}
+                            label={progress.label.clone()}
+                            description={progress.description.clone()}
+                            additional_info={progress.additional_info.clone()}
+                            result_text={progress.result_text.clone()}
+                            actions={progress.actions.clone()}
+                            paused={progress.paused}
+                        />
+                    </div>
+                }
+
                 // Optional action button
                 if let Some(ref action) = item.action {
                     <div class="awsui-flashbar-item-action">
@@ -323,18 +1039,29 @@ fn render_flash_item(
             </div>
 
             // Optional dismiss button
-            if item.dismissible {
+            if dismissible {
                 <div class="awsui-flashbar-item-dismiss">
                     <button
                         type="button"
                         class="awsui-flashbar-item-dismiss-button awsui-button awsui-button-variant-icon"
-                        aria-label="Dismiss notification"
+                        aria-label={strings.dismiss_aria_label.clone()}
                         onclick={on_dismiss_click}
                     >
                         <span class="awsui-icon awsui-icon-close" aria-hidden="true" />
                     </button>
                 </div>
             }
+
+            // Remaining time of an auto-dismissing item
+            if let Some(bar) = *countdown_bar {
+                <div
+                    class="awsui-flashbar-item-countdown"
+                    style={COUNTDOWN_TRACK_STYLE}
+                    aria-hidden="true"
+                >
+                    <div class="awsui-flashbar-item-countdown-bar" style={bar.style()} />
+                </div>
+            }
         </div>
     }
 }
@@ -342,6 +1069,39 @@ fn render_flash_item(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::internal::dev_warnings::DevWarnings;
+
+    #[test]
+    fn test_dev_warning_dismissible_without_label() {
+        let props = yew::props!(FlashbarProps {
+            items: vec![
+                FlashbarItem::new(FlashbarType::Info, html! { "Info" }).with_dismissible(true)
+            ],
+        });
+        let i18n = I18nContext::default();
+        let warnings = DevWarnings::new("Flashbar");
+        assert_eq!(warnings.check(|| dev_warnings(&props, &i18n)), 1);
+        assert_eq!(warnings.check(|| dev_warnings(&props, &i18n)), 0);
+
+        let labelled = yew::props!(FlashbarProps {
+            items: vec![
+                FlashbarItem::new(FlashbarType::Info, html! { "Info" }).with_dismissible(true)
+            ],
+            i18n_strings: FlashbarI18nStrings {
+                dismiss_aria_label: Some("Dismiss".to_string()),
+                ..Default::default()
+            },
+        });
+        assert!(dev_warnings(&labelled, &i18n).is_empty());
+
+        let mut messages = crate::internal::I18nStrings::new();
+        messages.set("flashbar.dismiss_aria_label", "Schließen");
+        let provided = I18nContext {
+            locale: Some("de-DE".to_string()),
+            messages,
+        };
+        assert!(dev_warnings(&props, &provided).is_empty());
+    }
 
     #[test]
     fn test_flashbar_type_strings() {
@@ -376,8 +1136,8 @@ mod tests {
     fn test_flashbar_item_builder_basic() {
         let item = FlashbarItem::new(FlashbarType::Success, html! { "Test" });
         assert_eq!(item.flash_type, FlashbarType::Success);
-        assert_eq!(item.dismissible, false);
-        assert_eq!(item.loading, false);
+        assert!(!item.dismissible);
+        assert!(!item.loading);
         assert!(item.id.is_none());
         assert!(item.header.is_none());
     }
@@ -398,13 +1158,13 @@ mod tests {
     #[test]
     fn test_flashbar_item_builder_with_dismissible() {
         let item = FlashbarItem::new(FlashbarType::Error, html! { "Test" }).with_dismissible(true);
-        assert_eq!(item.dismissible, true);
+        assert!(item.dismissible);
     }
 
     #[test]
     fn test_flashbar_item_builder_with_loading() {
         let item = FlashbarItem::new(FlashbarType::Info, html! { "Test" }).with_loading(true);
-        assert_eq!(item.loading, true);
+        assert!(item.loading);
     }
 
     #[test]
@@ -417,8 +1177,8 @@ mod tests {
 
         assert_eq!(item.id, Some("success-1".to_string()));
         assert_eq!(item.header, Some("Success!".to_string()));
-        assert_eq!(item.dismissible, true);
-        assert_eq!(item.loading, false);
+        assert!(item.dismissible);
+        assert!(!item.loading);
         assert_eq!(item.flash_type, FlashbarType::Success);
     }
 
@@ -448,4 +1208,176 @@ mod tests {
         let type2 = type1;
         assert_eq!(type1, type2);
     }
+
+    #[test]
+    fn test_flashbar_progress_item() {
+        let item = FlashbarItem::progress(
+            html! { "Creating resources" },
+            FlashbarProgress::new(40.0).with_label("Creating"),
+        );
+        assert_eq!(item.flash_type, FlashbarType::Info);
+        assert!(item.is_in_progress());
+        assert_eq!(item.effective_type(), FlashbarType::Info);
+        assert_eq!(item.progress.as_ref().unwrap().value, 40.0);
+        assert_eq!(
+            item.progress.as_ref().unwrap().label,
+            Some("Creating".to_string())
+        );
+    }
+
+    #[test]
+    fn test_flashbar_progress_status_switches_type() {
+        let progress = FlashbarProgress::new(100.0);
+        let item = FlashbarItem::progress(
+            html! { "Done" },
+            progress.clone().with_status(ProgressBarStatus::Success),
+        );
+        assert!(!item.is_in_progress());
+        assert_eq!(item.effective_type(), FlashbarType::Success);
+
+        let item = FlashbarItem::progress(
+            html! { "Failed" },
+            progress.with_status(ProgressBarStatus::Error),
+        );
+        assert_eq!(item.effective_type(), FlashbarType::Error);
+    }
+
+    #[test]
+    fn test_flashbar_progress_dismissible() {
+        let item = FlashbarItem::progress(html! { "Working" }, FlashbarProgress::new(10.0))
+            .with_dismissible(true);
+        assert!(!item.is_dismissible());
+        assert!(
+            item.clone()
+                .with_dismissible_while_in_progress(true)
+                .is_dismissible()
+        );
+
+        let done = item
+            .with_progress(FlashbarProgress::new(100.0).with_status(ProgressBarStatus::Success));
+        assert!(done.is_dismissible());
+
+        let plain = FlashbarItem::new(FlashbarType::Info, html! { "Test" }).with_dismissible(true);
+        assert!(plain.is_dismissible());
+    }
+
+    #[test]
+    fn test_flashbar_item_aria_labels() {
+        let strings = resolve_strings(&FlashbarI18nStrings::default(), &I18nContext::default());
+        let cases = [
+            (
+                FlashbarType::Info,
+                "Info: Update available Version 2.1 is ready",
+            ),
+            (
+                FlashbarType::Success,
+                "Success: Update available Version 2.1 is ready",
+            ),
+            (
+                FlashbarType::Warning,
+                "Warning: Update available Version 2.1 is ready",
+            ),
+            (
+                FlashbarType::Error,
+                "Error: Update available Version 2.1 is ready",
+            ),
+        ];
+        for (flash_type, expected) in cases {
+            let item = FlashbarItem::new(flash_type, html! { "Test" })
+                .with_header("Update available")
+                .with_content_summary("Version 2.1 is ready");
+            assert_eq!(strings.item_aria_label(&item), expected);
+        }
+
+        let item = FlashbarItem::new(FlashbarType::Error, html! { "Test" });
+        assert_eq!(strings.item_aria_label(&item), "Error");
+
+        let done = FlashbarItem::progress(
+            html! { "Done" },
+            FlashbarProgress::new(100.0).with_status(ProgressBarStatus::Success),
+        )
+        .with_header("Resources created");
+        assert_eq!(strings.item_aria_label(&done), "Success: Resources created");
+    }
+
+    #[test]
+    fn test_flashbar_auto_dismiss_countdown() {
+        let item = FlashbarItem::new(FlashbarType::Success, html! { "Saved" });
+        assert_eq!(item.auto_dismiss, None);
+        assert_eq!(item.with_auto_dismiss(5000).auto_dismiss, Some(5000));
+
+        let mut countdown = Countdown::new(5000);
+        assert_eq!(countdown.resume(1000.0), 5000.0);
+        assert_eq!(countdown.remaining(3000.0), 3000.0);
+
+        // Time spent paused doesn't count
+        countdown.pause(3000.0);
+        assert_eq!(countdown.remaining(10_000.0), 3000.0);
+        assert_eq!(countdown.remaining_percent(10_000.0), 60.0);
+        assert_eq!(countdown.resume(10_000.0), 3000.0);
+        // Resuming a running countdown keeps its start
+        assert_eq!(countdown.resume(11_000.0), 2000.0);
+        assert_eq!(countdown.remaining(20_000.0), 0.0);
+    }
+
+    #[test]
+    fn test_flashbar_stack_counts() {
+        let items = vec![
+            FlashbarItem::new(FlashbarType::Error, html! { "Failed" }),
+            FlashbarItem::new(FlashbarType::Info, html! { "Note" }),
+            FlashbarItem::new(FlashbarType::Error, html! { "Failed again" }),
+            FlashbarItem::progress(
+                html! { "Done" },
+                FlashbarProgress::new(100.0).with_status(ProgressBarStatus::Success),
+            ),
+        ];
+        let counts = type_counts(&items);
+        assert_eq!(
+            counts,
+            [
+                (FlashbarType::Error, 2),
+                (FlashbarType::Warning, 0),
+                (FlashbarType::Success, 1),
+                (FlashbarType::Info, 1),
+            ]
+        );
+
+        let strings = resolve_strings(&FlashbarI18nStrings::default(), &I18nContext::default());
+        assert_eq!(
+            strings.stack_summary(&counts),
+            "Notifications: Error 2, Success 1, Info 1"
+        );
+        assert_eq!(
+            strings.notification_bar_aria_label,
+            "View all notifications"
+        );
+        assert_eq!(strings.collapse_aria_label, "Collapse notifications");
+    }
+
+    #[test]
+    fn test_flashbar_strings_fallback() {
+        let mut messages = crate::internal::I18nStrings::new();
+        messages.set("flashbar.error_icon_aria_label", "Fehler");
+        messages.set("flashbar.aria_label", "Benachrichtigungen");
+        let i18n = I18nContext {
+            locale: Some("de-DE".to_string()),
+            messages,
+        };
+        let props_strings = FlashbarI18nStrings {
+            dismiss_aria_label: Some("Benachrichtigung schließen".to_string()),
+            aria_label: Some("Meldungen".to_string()),
+            ..Default::default()
+        };
+
+        let strings = resolve_strings(&props_strings, &i18n);
+        assert_eq!(strings.aria_label, "Meldungen");
+        assert_eq!(strings.dismiss_aria_label, "Benachrichtigung schließen");
+        assert_eq!(strings.type_label(FlashbarType::Error), "Fehler");
+        assert_eq!(strings.type_label(FlashbarType::Warning), "Warning");
+        assert_eq!(strings.in_progress_icon_aria_label, "In progress");
+
+        let item =
+            FlashbarItem::new(FlashbarType::Error, html! { "Test" }).with_header("Fehlgeschlagen");
+        assert_eq!(strings.item_aria_label(&item), "Fehler: Fehlgeschlagen");
+    }
 }
@@ -8,9 +8,259 @@
 //! and constraint text. It handles accessibility requirements including proper
 //! ARIA attributes and ID associations.
 
-use crate::internal::{BaseComponentProps, ClassBuilder, ComponentMetadata};
+use crate::form_state::{FieldState, FormStateContext};
+use crate::icon::Icon;
+use crate::internal::{
+    BaseComponentProps, ClassBuilder, ComponentMetadata, LiveRegion, apply_root_attributes,
+};
+use crate::status_indicator::{StatusIndicator, StatusIndicatorType};
+use web_sys::HtmlInputElement;
 use yew::prelude::*;
 
+/// Ids and validation state a FormField shares with the control it wraps
+///
+/// Input, Textarea, Select, Multiselect, Autosuggest, DatePicker,
+/// RadioGroup and Checkbox take their id, `aria-labelledby`,
+/// `aria-describedby` and `aria-invalid` from here unless set explicitly.
+///
+/// Controls with native `required` semantics report the browser's
+/// validation message here when a form submission is blocked, and clear it
+/// once the requirement is met.
+#[derive(Clone, PartialEq)]
+pub struct FormFieldContext {
+    /// Id of the wrapped control: the field's `control_id`, or one generated
+    /// by the FormField
+    pub control_id: String,
+    /// Id of the field's label, if it has one
+    pub label_id: Option<String>,
+    /// Space-separated ids of the shown description, error or warning, and
+    /// constraint text
+    pub described_by: Option<String>,
+    /// Validation state from the field's error and warning text
+    pub validation: ValidationState,
+    /// Browser validation message of the wrapped control, if it is invalid
+    pub native_error: Option<String>,
+    /// Reports the wrapped control's validation message, or `None` once valid
+    pub set_native_error: Callback<Option<String>>,
+}
+
+/// Visual validation state of a form control
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ValidationState {
+    /// No validation feedback
+    #[default]
+    None,
+    /// The value is accepted but needs attention
+    Warning,
+    /// The value is invalid
+    Invalid,
+}
+
+impl ValidationState {
+    /// Builds the state from `invalid` and `warning` flags; invalid wins
+    pub fn from_flags(invalid: bool, warning: bool) -> Self {
+        if invalid {
+            Self::Invalid
+        } else if warning {
+            Self::Warning
+        } else {
+            Self::None
+        }
+    }
+
+    /// Returns the CSS class name suffix for this state
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::Warning => "warning",
+            Self::Invalid => "invalid",
+        }
+    }
+
+    /// Whether the control should be announced as invalid (`aria-invalid`)
+    pub fn is_invalid(&self) -> bool {
+        *self == Self::Invalid
+    }
+
+    /// Whether the state shows a warning
+    pub fn is_warning(&self) -> bool {
+        *self == Self::Warning
+    }
+
+    /// Inline style of the control: a status-colored left border, and a
+    /// status-colored focus ring while focused
+    pub(crate) fn control_style(&self) -> Option<String> {
+        let color = match self {
+            Self::None => return None,
+            Self::Warning => "var(--awsui-color-border-status-warning)",
+            Self::Invalid => "var(--awsui-color-border-status-error)",
+        };
+        Some(format!(
+            "border-left: var(--awsui-border-invalid-width) solid {}; outline-color: {}",
+            color, color
+        ))
+    }
+
+    /// Status icon for triggers, or an empty node when there is no feedback
+    pub(crate) fn trigger_icon(&self) -> Html {
+        let (name, color) = match self {
+            Self::None => return html! {},
+            Self::Warning => ("status-warning", "var(--awsui-color-text-status-warning)"),
+            Self::Invalid => ("status-negative", "var(--awsui-color-text-status-error)"),
+        };
+        html! {
+            <span
+                class={format!("awsui-validation-icon awsui-validation-icon-{}", self.as_str())}
+                style={format!("color: {}", color)}
+            >
+                <Icon name={name} />
+            </span>
+        }
+    }
+}
+
+/// When a FormField shows its `error_text`
+///
+/// The policies other than `Always` consult the closest
+/// [`FormStateProvider`](crate::form_state::FormStateProvider); outside of
+/// one, errors always show.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ShowErrorWhen {
+    /// As soon as there is error text
+    #[default]
+    Always,
+    /// Once the control lost focus, or a submission failed
+    Touched,
+    /// Once a submission failed
+    Submitted,
+}
+
+/// Whether a field shows its error text under a policy, given the field
+/// state and whether a submission failed, when tracked by a provider
+fn is_error_shown(policy: ShowErrorWhen, tracked: Option<(FieldState, bool)>) -> bool {
+    match (policy, tracked) {
+        (ShowErrorWhen::Always, _) | (_, None) => true,
+        (ShowErrorWhen::Touched, Some((field, submitted))) => field.touched || submitted,
+        (ShowErrorWhen::Submitted, Some((_, submitted))) => submitted,
+    }
+}
+
+/// Resolves a control's validation state
+///
+/// Explicit `invalid` or `warning` props win over the state of the
+/// enclosing FormField; without either, the FormField's state applies.
+pub(crate) fn resolve_validation_state(
+    invalid: bool,
+    warning: bool,
+    form_field: ValidationState,
+) -> ValidationState {
+    if invalid || warning {
+        ValidationState::from_flags(invalid, warning)
+    } else {
+        form_field
+    }
+}
+
+/// Gets a control's validation state, merged with the enclosing FormField
+#[hook]
+pub(crate) fn use_validation_state(invalid: bool, warning: bool) -> ValidationState {
+    let form_field = use_context::<FormFieldContext>()
+        .map(|context| context.validation)
+        .unwrap_or_default();
+    resolve_validation_state(invalid, warning, form_field)
+}
+
+/// Id and ARIA references of a control inside a FormField
+#[derive(Debug, Clone, PartialEq, Default)]
+pub(crate) struct FieldAssociation {
+    /// Id to place on the focusable control
+    pub control_id: Option<String>,
+    /// `aria-labelledby` of the control
+    pub labelledby: Option<String>,
+    /// `aria-describedby` of the control
+    pub describedby: Option<String>,
+}
+
+/// Joins space-separated id lists, dropping duplicates
+pub(crate) fn join_ids<'a>(lists: impl IntoIterator<Item = Option<&'a str>>) -> Option<String> {
+    let mut ids: Vec<&str> = Vec::new();
+    for id in lists.into_iter().flatten().flat_map(str::split_whitespace) {
+        if !ids.contains(&id) {
+            ids.push(id);
+        }
+    }
+    (!ids.is_empty()).then(|| ids.join(" "))
+}
+
+/// Associates a control with its FormField
+///
+/// An explicit id or `aria-labelledby` wins over the field's; the field's
+/// descriptions are appended to an explicit `aria-describedby`.
+pub(crate) fn associate_field(
+    control_id: Option<String>,
+    labelledby: Option<String>,
+    describedby: Option<String>,
+    form_field: Option<&FormFieldContext>,
+) -> FieldAssociation {
+    let Some(form_field) = form_field else {
+        return FieldAssociation {
+            control_id,
+            labelledby,
+            describedby,
+        };
+    };
+    FieldAssociation {
+        control_id: control_id.or_else(|| Some(form_field.control_id.clone())),
+        labelledby: labelledby.or_else(|| form_field.label_id.clone()),
+        describedby: join_ids([describedby.as_deref(), form_field.described_by.as_deref()]),
+    }
+}
+
+/// Gets a control's id and ARIA references, merged with the enclosing
+/// FormField
+#[hook]
+pub(crate) fn use_field_association(
+    control_id: Option<String>,
+    labelledby: Option<String>,
+    describedby: Option<String>,
+) -> FieldAssociation {
+    let form_field = use_context::<FormFieldContext>();
+    associate_field(control_id, labelledby, describedby, form_field.as_ref())
+}
+
+/// Reports a native control's constraint validation to the enclosing FormField
+///
+/// Returns the `oninvalid` handler to attach to the native input(s). The
+/// reported error is cleared when `satisfied` becomes true. Does nothing
+/// outside of a FormField.
+#[hook]
+pub(crate) fn use_native_validity(satisfied: bool) -> Callback<Event> {
+    let form_field = use_context::<FormFieldContext>();
+
+    {
+        let form_field = form_field.clone();
+        use_effect_with(satisfied, move |satisfied| {
+            if *satisfied
+                && let Some(context) = &form_field
+                && context.native_error.is_some()
+            {
+                context.set_native_error.emit(None);
+            }
+            || ()
+        });
+    }
+
+    Callback::from(move |e: Event| {
+        if let Some(context) = &form_field
+            && let Some(input) = e.target_dyn_into::<HtmlInputElement>()
+        {
+            context
+                .set_native_error
+                .emit(Some(input.validation_message().unwrap_or_default()));
+        }
+    })
+}
+
 /// Properties for the FormField component
 #[derive(Properties, PartialEq, Clone, Default)]
 pub struct FormFieldProps {
@@ -46,10 +296,19 @@ pub struct FormFieldProps {
     #[prop_or_default]
     pub error_text: Option<Html>,
 
+    /// When to show `error_text`
+    ///
+    /// Holding errors back until the field was touched or the form was
+    /// submitted keeps them from showing while the user is still typing.
+    /// Requires a [`FormStateProvider`](crate::form_state::FormStateProvider).
+    #[prop_or_default]
+    pub show_error_when: ShowErrorWhen,
+
     /// Warning message to display
     ///
     /// When present (and error_text is not), displays a warning message below
-    /// the form control with warning styling.
+    /// the form control with a warning status indicator. Unlike an error, the
+    /// control is not marked invalid.
     #[prop_or_default]
     pub warning_text: Option<Html>,
 
@@ -94,8 +353,11 @@ pub struct FormFieldProps {
 /// # Example
 ///
 /// ```rust
+/// # use yew::prelude::*;
 /// use cloudscape_components::{FormField, Input, InputType};
+/// # let email = String::new();
 ///
+/// # let _: Html =
 /// html! {
 ///     <FormField
 ///         label="Email address"
@@ -103,28 +365,31 @@ pub struct FormFieldProps {
 ///         error_text={html! { "Please enter a valid email address." }}
 ///         control_id="email-input"
 ///     >
-///         <Input
-///             control_id="email-input"
-///             input_type={InputType::Email}
-///             value={email}
-///             invalid={true}
-///         />
+///         <Input input_type={InputType::Email} value={email} />
 ///     </FormField>
 /// }
+/// # ;
 /// ```
 ///
 /// # Accessibility
 ///
 /// The component automatically:
 /// - Associates the label with the control using the `for` attribute
-/// - Generates unique IDs for description, error, and constraint text
-/// - Sets `aria-describedby` on the control to reference all descriptive text
-/// - Provides proper ARIA roles and attributes for validation messages
+/// - Generates unique IDs for the label, description, error, and constraint text
+/// - Shares them through [`FormFieldContext`], so the wrapped control sets
+///   its id, `aria-labelledby`, `aria-describedby` and `aria-invalid`
+///   without further props
+/// - Announces the shown error or warning politely whenever it changes
 ///
 /// # Validation States
 ///
 /// Error messages take precedence over warnings. If both `error_text` and
 /// `warning_text` are provided, only the error will be displayed.
+///
+/// Controls with a `required` prop (Checkbox, Toggle, RadioGroup, Tiles)
+/// report the browser's validation message through [`FormFieldContext`] when
+/// a native form submission is blocked. It is shown in place of the error
+/// text until the requirement is met; an explicit `error_text` still wins.
 #[function_component(FormField)]
 pub fn form_field(props: &FormFieldProps) -> Html {
     let _metadata = ComponentMetadata::new("FormField");
@@ -142,19 +407,35 @@ pub fn form_field(props: &FormFieldProps) -> Html {
         })
     });
 
+    // Hold the error text back as long as the policy asks for
+    let form_state = use_context::<FormStateContext>();
+    let tracked = form_state.as_ref().map(|form_state| {
+        (
+            form_state.field_state(base_id.as_str()),
+            form_state.submitted(),
+        )
+    });
+    let error_text = props
+        .error_text
+        .as_ref()
+        .filter(|_| is_error_shown(props.show_error_when, tracked));
+
+    // Native validation message reported by the wrapped control
+    let native_error = use_state(|| None::<String>);
+    let invalid = error_text.is_some() || native_error.is_some();
+
+    let label_id = format!("{}-label", *base_id);
     let description_id = format!("{}-description", *base_id);
     let error_id = format!("{}-error", *base_id);
     let constraint_id = format!("{}-constraint", *base_id);
 
-    // Build aria-describedby list
+    // Build aria-describedby list; a warning uses the same id as an error
     let mut described_by_ids = Vec::new();
     if props.description.is_some() {
         described_by_ids.push(description_id.clone());
     }
-    if props.error_text.is_some() {
+    if invalid || props.warning_text.is_some() {
         described_by_ids.push(error_id.clone());
-    } else if props.warning_text.is_some() {
-        described_by_ids.push(error_id.clone()); // Warning uses same ID as error
     }
     if props.constraint_text.is_some() {
         described_by_ids.push(constraint_id.clone());
@@ -166,14 +447,51 @@ pub fn form_field(props: &FormFieldProps) -> Html {
         None
     };
 
+    let context = FormFieldContext {
+        control_id: (*base_id).clone(),
+        label_id: props.label.is_some().then(|| label_id.clone()),
+        described_by: aria_describedby.clone(),
+        validation: ValidationState::from_flags(invalid, props.warning_text.is_some()),
+        native_error: (*native_error).clone(),
+        set_native_error: {
+            let native_error = native_error.clone();
+            Callback::from(move |error: Option<String>| native_error.set(error))
+        },
+    };
+
+    // Announce the shown error or warning politely whenever it changes. The
+    // message is rich content, so its text is read from the rendered node.
+    let feedback_ref = use_node_ref();
+    let announcement = use_state_eq(String::new);
+    {
+        let feedback_ref = feedback_ref.clone();
+        let announcement = announcement.clone();
+        use_effect_with(
+            (
+                error_text.cloned(),
+                (*native_error).clone(),
+                props.warning_text.clone(),
+            ),
+            move |_| {
+                let text = feedback_ref
+                    .cast::<web_sys::Element>()
+                    .and_then(|node| node.text_content())
+                    .unwrap_or_default();
+                announcement.set(text.trim().to_string());
+                || ()
+            },
+        );
+    }
+
     // Build CSS classes
     let root_classes = ClassBuilder::new()
         .add("awsui-form-field")
-        .add_if(props.stretch, "awsui-form-field-stretch");
+        .add_if(props.stretch, "awsui-form-field-stretch")
+        .add_if(native_error.is_some(), "awsui-form-field-native-invalid");
 
     let control_wrapper_classes = ClassBuilder::new().add("awsui-form-field-control-wrapper");
 
-    html! {
+    let root = html! {
         <div class={props.base.merge_classes(&root_classes.build())} id={props.base.id.clone()}>
             // Label section
             if props.label.is_some() || props.info.is_some() {
@@ -181,7 +499,8 @@ pub fn form_field(props: &FormFieldProps) -> Html {
                     if let Some(label_text) = &props.label {
                         <label
                             class="awsui-form-field-label"
-                            for={props.control_id.clone()}
+                            id={label_id}
+                            for={(*base_id).clone()}
                         >
                             { label_text }
                         </label>
@@ -210,7 +529,9 @@ pub fn form_field(props: &FormFieldProps) -> Html {
                     class="awsui-form-field-control"
                     data-aria-describedby={aria_describedby}
                 >
-                    { for props.children.iter() }
+                    <ContextProvider<FormFieldContext> context={context}>
+                        { for props.children.iter() }
+                    </ContextProvider<FormFieldContext>>
                 </div>
 
                 // Secondary control
@@ -222,23 +543,26 @@ pub fn form_field(props: &FormFieldProps) -> Html {
             </div>
 
             // Error message (takes precedence over warning)
-            if let Some(error_content) = &props.error_text {
-                <div
-                    class="awsui-form-field-error"
-                    id={error_id}
-                    role="alert"
-                >
-                    { error_content.clone() }
+            if let Some(error_content) = error_text {
+                <div class="awsui-form-field-error" id={error_id} ref={feedback_ref}>
+                    <StatusIndicator status_type={StatusIndicatorType::Error}>
+                        { error_content.clone() }
+                    </StatusIndicator>
+                </div>
+            } else if let Some(message) = &*native_error {
+                <div class="awsui-form-field-error" id={error_id} ref={feedback_ref}>
+                    <StatusIndicator status_type={StatusIndicatorType::Error}>
+                        { Html::from(message.clone()) }
+                    </StatusIndicator>
                 </div>
             } else if let Some(warning_content) = &props.warning_text {
-                <div
-                    class="awsui-form-field-warning"
-                    id={error_id}
-                    role="status"
-                >
-                    { warning_content.clone() }
+                <div class="awsui-form-field-warning" id={error_id} ref={feedback_ref}>
+                    <StatusIndicator status_type={StatusIndicatorType::Warning}>
+                        { warning_content.clone() }
+                    </StatusIndicator>
                 </div>
             }
+            <LiveRegion message={(*announcement).clone()} />
 
             // Constraint text
             if let Some(constraint_content) = &props.constraint_text {
@@ -250,7 +574,8 @@ pub fn form_field(props: &FormFieldProps) -> Html {
                 </div>
             }
         </div>
-    }
+    };
+    apply_root_attributes(root, &props.base)
 }
 
 #[cfg(test)]
@@ -436,4 +761,167 @@ mod tests {
 
         assert_eq!(classes, "awsui-form-field");
     }
+
+    #[test]
+    fn test_error_shown_by_policy() {
+        let untouched = FieldState::default();
+        let touched = FieldState {
+            dirty: true,
+            touched: true,
+        };
+
+        // Without a provider, errors always show
+        assert!(is_error_shown(ShowErrorWhen::Touched, None));
+        assert!(is_error_shown(ShowErrorWhen::Submitted, None));
+
+        assert!(is_error_shown(
+            ShowErrorWhen::Always,
+            Some((untouched, false))
+        ));
+        assert!(!is_error_shown(
+            ShowErrorWhen::Touched,
+            Some((untouched, false))
+        ));
+        assert!(is_error_shown(
+            ShowErrorWhen::Touched,
+            Some((touched, false))
+        ));
+        assert!(is_error_shown(
+            ShowErrorWhen::Touched,
+            Some((untouched, true))
+        ));
+        assert!(!is_error_shown(
+            ShowErrorWhen::Submitted,
+            Some((touched, false))
+        ));
+        assert!(is_error_shown(
+            ShowErrorWhen::Submitted,
+            Some((untouched, true))
+        ));
+    }
+
+    #[test]
+    fn test_validation_state_precedence() {
+        assert_eq!(
+            ValidationState::from_flags(true, true),
+            ValidationState::Invalid
+        );
+        assert_eq!(
+            ValidationState::from_flags(false, true),
+            ValidationState::Warning
+        );
+        assert_eq!(
+            ValidationState::from_flags(false, false),
+            ValidationState::None
+        );
+        assert!(ValidationState::Invalid.is_invalid());
+        assert!(!ValidationState::Warning.is_invalid());
+    }
+
+    #[test]
+    fn test_resolve_validation_state_explicit_prop_wins() {
+        // Without props, the FormField state applies
+        assert_eq!(
+            resolve_validation_state(false, false, ValidationState::Invalid),
+            ValidationState::Invalid
+        );
+        // An explicit prop overrides the FormField state
+        assert_eq!(
+            resolve_validation_state(false, true, ValidationState::Invalid),
+            ValidationState::Warning
+        );
+        assert_eq!(
+            resolve_validation_state(true, true, ValidationState::None),
+            ValidationState::Invalid
+        );
+    }
+
+    #[test]
+    fn test_validation_control_style() {
+        assert_eq!(ValidationState::None.control_style(), None);
+        let invalid = ValidationState::Invalid.control_style().unwrap();
+        assert!(invalid.contains("var(--awsui-border-invalid-width)"));
+        assert!(invalid.contains("var(--awsui-color-border-status-error)"));
+        assert!(invalid.contains("outline-color"));
+        let warning = ValidationState::Warning.control_style().unwrap();
+        assert!(warning.contains("var(--awsui-color-border-status-warning)"));
+    }
+
+    #[test]
+    fn test_form_field_context_reports_native_error() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let reported = Rc::new(RefCell::new(Vec::new()));
+        let context = FormFieldContext {
+            control_id: "consent".to_string(),
+            label_id: None,
+            described_by: None,
+            validation: ValidationState::None,
+            native_error: None,
+            set_native_error: {
+                let reported = reported.clone();
+                Callback::from(move |error: Option<String>| reported.borrow_mut().push(error))
+            },
+        };
+
+        context
+            .set_native_error
+            .emit(Some("Please check this box".to_string()));
+        context.set_native_error.emit(None);
+
+        assert_eq!(
+            *reported.borrow(),
+            vec![Some("Please check this box".to_string()), None]
+        );
+    }
+
+    #[test]
+    fn test_associate_field_with_form_field() {
+        let context = FormFieldContext {
+            control_id: "email".to_string(),
+            label_id: Some("email-label".to_string()),
+            described_by: Some("email-description email-error".to_string()),
+            validation: ValidationState::Invalid,
+            native_error: None,
+            set_native_error: Callback::noop(),
+        };
+
+        // Without explicit attributes, the field's ids apply
+        assert_eq!(
+            associate_field(None, None, None, Some(&context)),
+            FieldAssociation {
+                control_id: Some("email".to_string()),
+                labelledby: Some("email-label".to_string()),
+                describedby: Some("email-description email-error".to_string()),
+            }
+        );
+
+        // Explicit ids win; descriptions are merged without duplicates
+        let association = associate_field(
+            Some("custom".to_string()),
+            Some("heading".to_string()),
+            Some("hint email-error".to_string()),
+            Some(&context),
+        );
+        assert_eq!(association.control_id, Some("custom".to_string()));
+        assert_eq!(association.labelledby, Some("heading".to_string()));
+        assert_eq!(
+            association.describedby,
+            Some("hint email-error email-description".to_string())
+        );
+    }
+
+    #[test]
+    fn test_associate_field_without_form_field() {
+        assert_eq!(
+            associate_field(None, None, Some("hint".to_string()), None),
+            FieldAssociation {
+                control_id: None,
+                labelledby: None,
+                describedby: Some("hint".to_string()),
+            }
+        );
+        assert_eq!(join_ids([None, Some("  ")]), None);
+    }
 }
@@ -0,0 +1,409 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! FormStateProvider for tracking which fields of a form were changed or
+//! visited.
+//!
+//! Form controls inside a FormField report their value and blur to the
+//! closest provider, keyed by the FormField's control id. A field is dirty
+//! while its value differs from the value it had when tracking started or
+//! was last reset, and touched once it lost focus. FormField uses this to
+//! hold back errors until the user is done with a field (see
+//! [`ShowErrorWhen`](crate::form_field::ShowErrorWhen)).
+
+use crate::form_field::FormFieldContext;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+use yew::prelude::*;
+
+/// Interaction state of a single field
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FieldState {
+    /// Whether the value differs from the initial value
+    pub dirty: bool,
+    /// Whether the field lost focus at least once, or the form was submitted
+    pub touched: bool,
+}
+
+/// Tracked value and focus history of a field
+#[derive(Debug, Clone, PartialEq)]
+struct FieldEntry {
+    initial: String,
+    current: String,
+    touched: bool,
+}
+
+/// Change reported to a [`FormStateProvider`]
+#[derive(Debug, Clone, PartialEq)]
+enum FormStateAction {
+    /// A field has a value; the first value of a field is its initial value
+    Change { field_id: String, value: String },
+    /// A field lost focus
+    Blur { field_id: String },
+    /// A field was removed from the form
+    Remove { field_id: String },
+    /// A submission failed; every field counts as touched
+    MarkAllTouched,
+    /// The current values become the initial values
+    Reset,
+}
+
+/// State of all fields of a provider
+#[derive(Debug, Clone, PartialEq, Default)]
+struct FormStateStore {
+    fields: BTreeMap<String, FieldEntry>,
+    submitted: bool,
+}
+
+impl FormStateStore {
+    fn apply(&mut self, action: FormStateAction) {
+        match action {
+            FormStateAction::Change { field_id, value } => {
+                self.fields
+                    .entry(field_id)
+                    .and_modify(|entry| entry.current = value.clone())
+                    .or_insert_with(|| FieldEntry {
+                        initial: value.clone(),
+                        current: value,
+                        touched: false,
+                    });
+            }
+            FormStateAction::Blur { field_id } => {
+                if let Some(entry) = self.fields.get_mut(&field_id) {
+                    entry.touched = true;
+                }
+            }
+            FormStateAction::Remove { field_id } => {
+                self.fields.remove(&field_id);
+            }
+            FormStateAction::MarkAllTouched => {
+                self.submitted = true;
+                for entry in self.fields.values_mut() {
+                    entry.touched = true;
+                }
+            }
+            FormStateAction::Reset => {
+                self.submitted = false;
+                for entry in self.fields.values_mut() {
+                    entry.initial = entry.current.clone();
+                    entry.touched = false;
+                }
+            }
+        }
+    }
+
+    fn field(&self, field_id: &str) -> FieldState {
+        self.fields
+            .get(field_id)
+            .map(|entry| FieldState {
+                dirty: entry.current != entry.initial,
+                touched: entry.touched,
+            })
+            .unwrap_or_default()
+    }
+
+    fn any_dirty(&self) -> bool {
+        self.fields
+            .values()
+            .any(|entry| entry.current != entry.initial)
+    }
+
+    fn touched_fields(&self) -> Vec<String> {
+        self.fields
+            .iter()
+            .filter(|(_, entry)| entry.touched)
+            .map(|(field_id, _)| field_id.clone())
+            .collect()
+    }
+}
+
+impl Reducible for FormStateStore {
+    type Action = FormStateAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut store = (*self).clone();
+        store.apply(action);
+        if store == *self { self } else { Rc::new(store) }
+    }
+}
+
+/// Field tracking shared by a [`FormStateProvider`]
+#[derive(Clone, PartialEq)]
+pub struct FormStateContext {
+    store: UseReducerHandle<FormStateStore>,
+}
+
+impl FormStateContext {
+    /// Gets the state of the field with the given control id
+    pub fn field_state(&self, field_id: &str) -> FieldState {
+        self.store.field(field_id)
+    }
+
+    /// Whether a submission failed since tracking started or was last reset
+    pub fn submitted(&self) -> bool {
+        self.store.submitted
+    }
+
+    /// Marks every field as touched after a failed submission, so fields
+    /// showing errors when touched or submitted show them
+    pub fn mark_all_touched(&self) {
+        self.store.dispatch(FormStateAction::MarkAllTouched);
+    }
+
+    fn report(&self, action: FormStateAction) {
+        self.store.dispatch(action);
+    }
+}
+
+/// Interaction state of a whole form
+#[derive(Clone, PartialEq)]
+pub struct FormState {
+    /// Whether any field differs from its initial value
+    pub any_dirty: bool,
+    /// Control ids of the touched fields, sorted
+    pub touched_fields: Vec<String>,
+    /// Whether a submission failed since tracking started or was last reset
+    pub submitted: bool,
+    mark_all_touched: Callback<()>,
+}
+
+impl FormState {
+    /// Marks every field as touched; call it when a submission fails
+    pub fn mark_all_touched(&self) {
+        self.mark_all_touched.emit(());
+    }
+}
+
+/// Properties for the FormStateProvider component
+#[derive(Properties, PartialEq, Clone)]
+pub struct FormStateProviderProps {
+    /// Version of the initial values
+    ///
+    /// Increment it after the values were saved or reloaded: the current
+    /// values become the initial values, and no field is dirty or touched.
+    #[prop_or_default]
+    pub initial_values_version: u64,
+
+    /// Form content
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// FormStateProvider component for tracking dirty and touched fields.
+///
+/// # Example
+///
+/// ```rust
+/// use cloudscape_components::{
+///     FormField, FormStateProvider, Input, ShowErrorWhen, use_form_state,
+/// };
+/// use yew::prelude::*;
+///
+/// #[function_component(SubmitButton)]
+/// fn submit_button() -> Html {
+///     let form_state = use_form_state();
+///     let onclick = Callback::from(move |_| {
+///         // Validation failed: reveal the errors of untouched fields
+///         form_state.mark_all_touched();
+///     });
+///     html! { <button {onclick}>{"Save"}</button> }
+/// }
+///
+/// #[function_component(MyForm)]
+/// fn my_form() -> Html {
+///     html! {
+///         <FormStateProvider>
+///             <FormField
+///                 label="Name"
+///                 control_id="name"
+///                 error_text={html! { "Enter a name." }}
+///                 show_error_when={ShowErrorWhen::Touched}
+///             >
+///                 <Input control_id="name" value="" />
+///             </FormField>
+///             <SubmitButton />
+///         </FormStateProvider>
+///     }
+/// }
+/// ```
+#[function_component(FormStateProvider)]
+pub fn form_state_provider(props: &FormStateProviderProps) -> Html {
+    let store = use_reducer_eq(FormStateStore::default);
+
+    {
+        let store = store.clone();
+        use_effect_with(props.initial_values_version, move |_| {
+            store.dispatch(FormStateAction::Reset);
+        });
+    }
+
+    let context = FormStateContext { store };
+
+    html! {
+        <ContextProvider<FormStateContext> {context}>
+            { for props.children.iter() }
+        </ContextProvider<FormStateContext>>
+    }
+}
+
+/// Gets the state of the field with the given control id, or an untouched
+/// clean state outside of a FormStateProvider
+#[hook]
+pub fn use_field_state(field_id: &str) -> FieldState {
+    use_context::<FormStateContext>()
+        .map(|context| context.field_state(field_id))
+        .unwrap_or_default()
+}
+
+/// Gets the state of the closest FormStateProvider's form
+///
+/// Outside of a provider, no field is dirty or touched.
+#[hook]
+pub fn use_form_state() -> FormState {
+    match use_context::<FormStateContext>() {
+        Some(context) => FormState {
+            any_dirty: context.store.any_dirty(),
+            touched_fields: context.store.touched_fields(),
+            submitted: context.store.submitted,
+            mark_all_touched: Callback::from(move |_| context.mark_all_touched()),
+        },
+        None => FormState {
+            any_dirty: false,
+            touched_fields: Vec::new(),
+            submitted: false,
+            mark_all_touched: Callback::noop(),
+        },
+    }
+}
+
+/// Reports a control's value to the closest FormStateProvider, keyed by the
+/// control id of the enclosing FormField
+///
+/// Returns the callback to call when the control loses focus. Does nothing
+/// outside of a FormField or a FormStateProvider.
+#[hook]
+pub(crate) fn use_field_tracking(value: String) -> Callback<()> {
+    let form_state = use_context::<FormStateContext>();
+    let field_id = use_context::<FormFieldContext>().map(|context| context.control_id);
+
+    {
+        let form_state = form_state.clone();
+        use_effect_with((value, field_id.clone()), move |(value, field_id)| {
+            if let (Some(form_state), Some(field_id)) = (&form_state, field_id) {
+                form_state.report(FormStateAction::Change {
+                    field_id: field_id.clone(),
+                    value: value.clone(),
+                });
+            }
+        });
+    }
+
+    // Forget the field when it is removed from the form
+    {
+        let form_state = form_state.clone();
+        use_effect_with(field_id.clone(), move |field_id| {
+            let field_id = field_id.clone();
+            move || {
+                if let (Some(form_state), Some(field_id)) = (form_state, field_id) {
+                    form_state.report(FormStateAction::Remove { field_id });
+                }
+            }
+        });
+    }
+
+    Callback::from(move |_| {
+        if let (Some(form_state), Some(field_id)) = (&form_state, &field_id) {
+            form_state.report(FormStateAction::Blur {
+                field_id: field_id.clone(),
+            });
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn change(field_id: &str, value: &str) -> FormStateAction {
+        FormStateAction::Change {
+            field_id: field_id.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_first_value_is_initial() {
+        let mut store = FormStateStore::default();
+        store.apply(change("name", "Ana"));
+        assert_eq!(store.field("name"), FieldState::default());
+
+        store.apply(change("name", "Anna"));
+        assert!(store.field("name").dirty);
+        assert!(store.any_dirty());
+
+        // Changing the value back makes the field clean again
+        store.apply(change("name", "Ana"));
+        assert!(!store.field("name").dirty);
+        assert!(!store.any_dirty());
+    }
+
+    #[test]
+    fn test_blur_touches_known_fields() {
+        let mut store = FormStateStore::default();
+        store.apply(change("name", ""));
+        store.apply(change("email", ""));
+        store.apply(FormStateAction::Blur {
+            field_id: "name".to_string(),
+        });
+        store.apply(FormStateAction::Blur {
+            field_id: "unknown".to_string(),
+        });
+
+        assert!(store.field("name").touched);
+        assert!(!store.field("email").touched);
+        assert_eq!(store.touched_fields(), vec!["name".to_string()]);
+    }
+
+    #[test]
+    fn test_mark_all_touched_and_reset() {
+        let mut store = FormStateStore::default();
+        store.apply(change("name", ""));
+        store.apply(change("email", ""));
+        store.apply(change("email", "ana@example.com"));
+        store.apply(FormStateAction::MarkAllTouched);
+
+        assert!(store.submitted);
+        assert_eq!(
+            store.touched_fields(),
+            vec!["email".to_string(), "name".to_string()]
+        );
+
+        // After saving, the saved values are the new initial values
+        store.apply(FormStateAction::Reset);
+        assert!(!store.submitted);
+        assert!(!store.any_dirty());
+        assert!(store.touched_fields().is_empty());
+        store.apply(change("email", ""));
+        assert!(store.field("email").dirty);
+    }
+
+    #[test]
+    fn test_remove_forgets_field() {
+        let mut store = FormStateStore::default();
+        store.apply(change("name", ""));
+        store.apply(change("name", "Ana"));
+        store.apply(FormStateAction::Remove {
+            field_id: "name".to_string(),
+        });
+
+        assert!(!store.any_dirty());
+        assert_eq!(store.field("name"), FieldState::default());
+    }
+
+    #[test]
+    fn test_reduce_keeps_unchanged_store() {
+        let store = Rc::new(FormStateStore::default()).reduce(change("name", "Ana"));
+        let same = store.clone().reduce(change("name", "Ana"));
+        assert!(Rc::ptr_eq(&store, &same));
+    }
+}
@@ -0,0 +1,348 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Grid component for 12-column layouts.
+//!
+//! Each child takes the [`GridDefinition`] at its position: how many of the
+//! 12 columns it spans, how many it is offset by, and how far it is pushed
+//! or pulled from its place. Every field can change at container
+//! breakpoints; a breakpoint without a value takes the value of the nearest
+//! smaller breakpoint that has one.
+
+use crate::internal::{
+    BaseComponentProps, Breakpoint, ClassBuilder, apply_root_attributes, use_container_breakpoint,
+};
+use yew::prelude::*;
+
+/// Number of columns of the grid
+pub const GRID_COLUMNS: u32 = 12;
+
+/// Gutter between grid columns
+const GRID_GUTTER: &str = "var(--awsui-space-grid-gutter)";
+
+/// A grid definition field, set for one or more breakpoints
+///
+/// # Example
+///
+/// ```rust
+/// use cloudscape_components::{Breakpoint, ResponsiveValue};
+///
+/// // Full width on narrow containers, half width from the `xs` breakpoint
+/// let colspan = ResponsiveValue::new(12).at(Breakpoint::Xs, 6);
+/// assert_eq!(colspan.resolve(Breakpoint::M), Some(6));
+/// ```
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct ResponsiveValue {
+    values: Vec<(Breakpoint, u32)>,
+}
+
+impl ResponsiveValue {
+    /// Creates a value for every breakpoint
+    pub fn new(value: u32) -> Self {
+        Self::default().at(Breakpoint::Default, value)
+    }
+
+    /// Sets the value from the given breakpoint up
+    pub fn at(mut self, breakpoint: Breakpoint, value: u32) -> Self {
+        self.values.retain(|(set, _)| *set != breakpoint);
+        self.values.push((breakpoint, value));
+        self
+    }
+
+    /// Gets the value at a breakpoint: the value of the breakpoint itself,
+    /// else of the nearest smaller breakpoint with a value
+    pub fn resolve(&self, breakpoint: Breakpoint) -> Option<u32> {
+        self.values
+            .iter()
+            .filter(|(set, _)| *set <= breakpoint)
+            .max_by_key(|(set, _)| *set)
+            .map(|(_, value)| *value)
+    }
+}
+
+impl From<u32> for ResponsiveValue {
+    fn from(value: u32) -> Self {
+        Self::new(value)
+    }
+}
+
+/// Placement of a grid child
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct GridDefinition {
+    /// Number of columns the child spans (default: 12)
+    pub colspan: Option<ResponsiveValue>,
+    /// Number of columns left empty before the child
+    pub offset: Option<ResponsiveValue>,
+    /// Number of columns the child is moved towards the end of its row
+    pub push: Option<ResponsiveValue>,
+    /// Number of columns the child is moved towards the start of its row
+    pub pull: Option<ResponsiveValue>,
+}
+
+impl GridDefinition {
+    /// Creates a definition spanning all columns
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the number of columns the child spans
+    pub fn with_colspan(mut self, colspan: impl Into<ResponsiveValue>) -> Self {
+        self.colspan = Some(colspan.into());
+        self
+    }
+
+    /// Sets the number of columns left empty before the child
+    pub fn with_offset(mut self, offset: impl Into<ResponsiveValue>) -> Self {
+        self.offset = Some(offset.into());
+        self
+    }
+
+    /// Sets the number of columns the child is moved towards the end
+    pub fn with_push(mut self, push: impl Into<ResponsiveValue>) -> Self {
+        self.push = Some(push.into());
+        self
+    }
+
+    /// Sets the number of columns the child is moved towards the start
+    pub fn with_pull(mut self, pull: impl Into<ResponsiveValue>) -> Self {
+        self.pull = Some(pull.into());
+        self
+    }
+
+    /// Gets the placement at a breakpoint, clamped to the grid
+    fn resolve(&self, breakpoint: Breakpoint) -> GridPlacement {
+        let resolve = |value: &Option<ResponsiveValue>| {
+            value
+                .as_ref()
+                .and_then(|value| value.resolve(breakpoint))
+                .map(|value| value.min(GRID_COLUMNS - 1))
+                .unwrap_or(0)
+        };
+
+        GridPlacement {
+            colspan: self
+                .colspan
+                .as_ref()
+                .and_then(|colspan| colspan.resolve(breakpoint))
+                .unwrap_or(GRID_COLUMNS)
+                .clamp(1, GRID_COLUMNS),
+            offset: resolve(&self.offset),
+            push: resolve(&self.push),
+            pull: resolve(&self.pull),
+        }
+    }
+}
+
+/// Placement of a grid child at the current breakpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct GridPlacement {
+    colspan: u32,
+    offset: u32,
+    push: u32,
+    pull: u32,
+}
+
+impl GridPlacement {
+    /// Gets the inline style placing the child
+    fn style(&self, disable_gutters: bool) -> String {
+        let columns = |count: u32| format!("calc(100% * {} / {})", count, GRID_COLUMNS);
+        let mut styles = vec![
+            "box-sizing: border-box".to_string(),
+            format!("flex: 0 0 {}", columns(self.colspan)),
+            format!("max-width: {}", columns(self.colspan)),
+        ];
+        if !disable_gutters {
+            styles.push(format!("padding: calc({} / 2)", GRID_GUTTER));
+        }
+        if self.offset > 0 {
+            styles.push(format!("margin-inline-start: {}", columns(self.offset)));
+        }
+        if self.push != self.pull {
+            let shift = i64::from(self.push) - i64::from(self.pull);
+            styles.push(format!(
+                "position: relative; inset-inline-start: calc(100% * {} / {})",
+                shift, GRID_COLUMNS
+            ));
+        }
+        styles.join("; ")
+    }
+}
+
+/// Properties for the Grid component
+#[derive(Properties, PartialEq, Clone)]
+pub struct GridProps {
+    /// Base component properties
+    #[prop_or_default]
+    pub base: BaseComponentProps,
+
+    /// Placement of each child, matched by position
+    ///
+    /// Children beyond the definitions span all columns.
+    #[prop_or_default]
+    pub grid_definition: Vec<GridDefinition>,
+
+    /// Whether to remove the gutters between columns
+    #[prop_or_default]
+    pub disable_gutters: bool,
+
+    /// Grid children
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// Grid component for 12-column layouts.
+///
+/// The breakpoint is taken from the width of the grid itself. Before the
+/// grid is first measured, children take their `default` placement.
+///
+/// # Example
+///
+/// ```rust
+/// use cloudscape_components::{Breakpoint, Grid, GridDefinition, ResponsiveValue};
+/// use yew::prelude::*;
+///
+/// #[function_component(MyComponent)]
+/// fn my_component() -> Html {
+///     html! {
+///         <Grid grid_definition={vec![
+///             GridDefinition::new().with_colspan(ResponsiveValue::new(12).at(Breakpoint::S, 8)),
+///             GridDefinition::new().with_colspan(ResponsiveValue::new(12).at(Breakpoint::S, 4)),
+///         ]}>
+///             <div>{"Main"}</div>
+///             <div>{"Aside"}</div>
+///         </Grid>
+///     }
+/// }
+/// ```
+#[function_component(Grid)]
+pub fn grid(props: &GridProps) -> Html {
+    let root_ref = use_node_ref();
+    let breakpoint =
+        use_container_breakpoint(root_ref.clone(), Vec::new()).unwrap_or(Breakpoint::Default);
+
+    let classes = ClassBuilder::new()
+        .add("awsui-grid")
+        .add(format!("awsui-grid-breakpoint-{}", breakpoint.as_str()))
+        .add_if(props.disable_gutters, "awsui-grid-no-gutters");
+    let root_class = props.base.merge_classes(&classes.build());
+
+    // Negative margins let the outer columns line up with the container
+    // edges despite their gutter padding
+    let style = if props.disable_gutters {
+        "display: flex; flex-wrap: wrap".to_string()
+    } else {
+        format!(
+            "display: flex; flex-wrap: wrap; margin: calc({} / -2)",
+            GRID_GUTTER
+        )
+    };
+
+    let default_definition = GridDefinition::new();
+    let columns = props.children.iter().enumerate().map(|(index, child)| {
+        let placement = props
+            .grid_definition
+            .get(index)
+            .unwrap_or(&default_definition)
+            .resolve(breakpoint);
+        let classes = ClassBuilder::new()
+            .add("awsui-grid-column")
+            .add(format!("awsui-grid-colspan-{}", placement.colspan))
+            .add_if(
+                placement.offset > 0,
+                format!("awsui-grid-offset-{}", placement.offset),
+            );
+
+        html! {
+            <div class={classes.build()} style={placement.style(props.disable_gutters)}>
+                { child }
+            </div>
+        }
+    });
+
+    let root = html! {
+        <div ref={root_ref} id={props.base.id.clone()} class={root_class}>
+            <div class="awsui-grid-columns" style={style}>
+                { for columns }
+            </div>
+        </div>
+    };
+    apply_root_attributes(root, &props.base)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nearest_smaller_breakpoint_wins() {
+        let value = ResponsiveValue::new(12)
+            .at(Breakpoint::Xs, 6)
+            .at(Breakpoint::L, 3);
+        assert_eq!(value.resolve(Breakpoint::Default), Some(12));
+        assert_eq!(value.resolve(Breakpoint::Xxs), Some(12));
+        assert_eq!(value.resolve(Breakpoint::Xs), Some(6));
+        assert_eq!(value.resolve(Breakpoint::M), Some(6));
+        assert_eq!(value.resolve(Breakpoint::L), Some(3));
+        assert_eq!(value.resolve(Breakpoint::Xl), Some(3));
+    }
+
+    #[test]
+    fn test_value_without_default() {
+        // Breakpoints below the smallest set one have no value
+        let value = ResponsiveValue::default().at(Breakpoint::S, 4);
+        assert_eq!(value.resolve(Breakpoint::Xs), None);
+        assert_eq!(value.resolve(Breakpoint::S), Some(4));
+
+        // Setting a breakpoint again replaces its value
+        let value = value.at(Breakpoint::S, 8);
+        assert_eq!(value.resolve(Breakpoint::Xl), Some(8));
+    }
+
+    #[test]
+    fn test_definition_resolve() {
+        let definition = GridDefinition::new()
+            .with_colspan(ResponsiveValue::default().at(Breakpoint::M, 8))
+            .with_offset(ResponsiveValue::default().at(Breakpoint::M, 2));
+
+        // Without a colspan, the child spans all columns
+        assert_eq!(
+            definition.resolve(Breakpoint::S),
+            GridPlacement {
+                colspan: 12,
+                offset: 0,
+                push: 0,
+                pull: 0,
+            }
+        );
+        assert_eq!(definition.resolve(Breakpoint::Xl).colspan, 8);
+        assert_eq!(definition.resolve(Breakpoint::Xl).offset, 2);
+
+        // Values are clamped to the grid
+        let oversized = GridDefinition::new().with_colspan(20).with_offset(15);
+        assert_eq!(oversized.resolve(Breakpoint::Default).colspan, 12);
+        assert_eq!(oversized.resolve(Breakpoint::Default).offset, 11);
+        assert_eq!(
+            GridDefinition::new()
+                .with_colspan(0)
+                .resolve(Breakpoint::Default)
+                .colspan,
+            1
+        );
+    }
+
+    #[test]
+    fn test_placement_style() {
+        let placement = GridDefinition::new()
+            .with_colspan(4)
+            .with_offset(2)
+            .with_pull(1)
+            .resolve(Breakpoint::Default);
+        let style = placement.style(false);
+        assert!(style.contains("flex: 0 0 calc(100% * 4 / 12)"));
+        assert!(style.contains("padding: calc(var(--awsui-space-grid-gutter) / 2)"));
+        assert!(style.contains("margin-inline-start: calc(100% * 2 / 12)"));
+        assert!(style.contains("inset-inline-start: calc(100% * -1 / 12)"));
+
+        assert!(!placement.style(true).contains("padding"));
+    }
+}
@@ -8,6 +8,7 @@
 
 use crate::internal::{
     AnalyticsMetadata, AriaAttributes, BaseComponentProps, ClassBuilder, ComponentMetadata,
+    apply_root_attributes,
 };
 use yew::prelude::*;
 
@@ -80,16 +81,20 @@ pub struct HeaderProps {
 /// # Example
 ///
 /// ```rust
+/// # use yew::prelude::*;
 /// use cloudscape_components::{Header, HeaderVariant};
 ///
 /// // Basic page header
+/// # let _: Html =
 /// html! {
 ///     <Header variant={HeaderVariant::H1}>
 ///         { "Dashboard" }
 ///     </Header>
 /// }
+/// # ;
 ///
 /// // Container header with description and actions
+/// # let _: Html =
 /// html! {
 ///     <Header
 ///         variant={HeaderVariant::H2}
@@ -99,8 +104,10 @@ pub struct HeaderProps {
 ///         { "Resources" }
 ///     </Header>
 /// }
+/// # ;
 ///
 /// // Section header with counter and info link
+/// # let _: Html =
 /// html! {
 ///     <Header
 ///         variant={HeaderVariant::H3}
@@ -110,6 +117,7 @@ pub struct HeaderProps {
 ///         { "Items" }
 ///     </Header>
 /// }
+/// # ;
 /// ```
 #[function_component(Header)]
 pub fn header(props: &HeaderProps) -> Html {
@@ -228,7 +236,7 @@ pub fn header(props: &HeaderProps) -> Html {
         },
     };
 
-    html! {
+    let root = html! {
         <div
             id={props.base.id.clone()}
             class={root_classes.build()}
@@ -261,7 +269,8 @@ pub fn header(props: &HeaderProps) -> Html {
                 </p>
             }
         </div>
-    }
+    };
+    apply_root_attributes(root, &props.base)
 }
 
 #[cfg(test)]
@@ -0,0 +1,116 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! I18nProvider for supplying localized component strings to a subtree.
+//!
+//! Components resolve each user-visible string through a fallback chain: the
+//! component's own `i18n_strings` prop first, then the closest I18nProvider,
+//! then the built-in English default.
+
+use crate::internal::I18nStrings;
+use yew::prelude::*;
+
+/// Localized strings and locale shared by an [`I18nProvider`]
+#[derive(Clone, PartialEq, Default)]
+pub struct I18nContext {
+    /// BCP 47 locale tag, such as `"de-DE"`
+    pub locale: Option<String>,
+    /// Messages keyed by `"<component>.<string>"`, such as
+    /// `"date-range-picker.apply_button_label"`
+    pub messages: I18nStrings,
+}
+
+impl I18nContext {
+    /// Looks up a message for a component
+    pub fn message(&self, component: &str, key: &str) -> Option<&str> {
+        self.messages
+            .get(&format!("{}.{}", component, key))
+            .map(String::as_str)
+    }
+}
+
+/// Properties for the I18nProvider component
+#[derive(Properties, PartialEq, Clone)]
+pub struct I18nProviderProps {
+    /// Locale of the messages
+    ///
+    /// Inherited from an enclosing provider when not set.
+    #[prop_or_default]
+    pub locale: Option<String>,
+
+    /// Messages keyed by `"<component>.<string>"`
+    ///
+    /// Merged over the messages of an enclosing provider.
+    #[prop_or_default]
+    pub messages: I18nStrings,
+
+    /// Content using the messages
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// I18nProvider component for localizing all components in a subtree.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// use cloudscape_components::{DateRangePicker, I18nProvider, internal::I18nStrings};
+///
+/// let mut messages = I18nStrings::new();
+/// messages.set("date-range-picker.absolute_mode_label", "Absolut");
+/// messages.set("date-range-picker.relative_mode_label", "Relativ");
+///
+/// # let _: Html =
+/// html! {
+///     <I18nProvider locale="de-DE" messages={messages}>
+///         <DateRangePicker />
+///     </I18nProvider>
+/// }
+/// # ;
+/// ```
+#[function_component(I18nProvider)]
+pub fn i18n_provider(props: &I18nProviderProps) -> Html {
+    let parent = use_context::<I18nContext>().unwrap_or_default();
+
+    let mut messages = parent.messages;
+    messages.merge(&props.messages);
+    let context = I18nContext {
+        locale: props.locale.clone().or(parent.locale),
+        messages,
+    };
+
+    html! {
+        <ContextProvider<I18nContext> {context}>
+            { for props.children.iter() }
+        </ContextProvider<I18nContext>>
+    }
+}
+
+/// Gets the strings of the closest I18nProvider, or an empty context
+#[hook]
+pub fn use_i18n() -> I18nContext {
+    use_context::<I18nContext>().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_i18n_context_message() {
+        let mut messages = I18nStrings::new();
+        messages.set("date-range-picker.relative_mode_label", "Relativ");
+        let context = I18nContext {
+            locale: Some("de-DE".to_string()),
+            messages,
+        };
+
+        assert_eq!(
+            context.message("date-range-picker", "relative_mode_label"),
+            Some("Relativ")
+        );
+        assert_eq!(context.message("date-range-picker", "missing"), None);
+        assert_eq!(context.message("other", "relative_mode_label"), None);
+    }
+}
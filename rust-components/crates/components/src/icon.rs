@@ -5,7 +5,10 @@
 //!
 //! A flexible SVG-based icon component with multiple variants and sizes.
 
-use crate::internal::{AnalyticsMetadata, BaseComponentProps, ComponentMetadata, ComponentStyles};
+use crate::internal::{
+    AnalyticsMetadata, BaseComponentProps, ComponentMetadata, ComponentStyles,
+    apply_root_attributes,
+};
 use yew::prelude::*;
 
 /// Icon variant types for different visual styles
@@ -187,7 +190,7 @@ pub fn icon(props: &IconProps) -> Html {
 
     // Render custom SVG if provided
     if let Some(ref svg_content) = props.svg {
-        return html! {
+        let root = html! {
             <span
                 id={props.base.id.clone()}
                 class={class}
@@ -200,6 +203,7 @@ pub fn icon(props: &IconProps) -> Html {
                 { svg_content.clone() }
             </span>
         };
+        return apply_root_attributes(root, &props.base);
     }
 
     // Render URL-based icon if provided
@@ -210,7 +214,7 @@ pub fn icon(props: &IconProps) -> Html {
             .or_else(|| props.alt.clone())
             .unwrap_or_default();
 
-        return html! {
+        let root = html! {
             <span
                 id={props.base.id.clone()}
                 class={class}
@@ -225,12 +229,13 @@ pub fn icon(props: &IconProps) -> Html {
                 />
             </span>
         };
+        return apply_root_attributes(root, &props.base);
     }
 
     // Render name-based icon with built-in SVG
     let icon_svg = get_icon_svg(&props.name, props.size);
 
-    html! {
+    let root = html! {
         <span
             id={props.base.id.clone()}
             class={class}
@@ -242,7 +247,8 @@ pub fn icon(props: &IconProps) -> Html {
         >
             { icon_svg }
         </span>
-    }
+    };
+    apply_root_attributes(root, &props.base)
 }
 
 /// Returns the SVG markup for a given icon name
@@ -4,11 +4,18 @@
 //! Input component for text entry.
 //!
 //! Provides a controlled text input with validation states, types, and accessibility.
+//!
+//! Number inputs can show step buttons; the buttons and the Up and Down
+//! arrow keys change the value by `step`, clamped between `min` and `max`.
 
+use crate::form_field::{use_field_association, use_validation_state};
+use crate::form_state::use_field_tracking;
+use crate::i18n::{I18nContext, use_i18n};
 use crate::internal::{
     AriaAttributes, BaseComponentProps, ClassBuilder, ComponentMetadata, CustomEvent,
+    NativeAttributes, apply_data_attributes, apply_root_attributes,
 };
-use web_sys::HtmlInputElement;
+use web_sys::{HtmlInputElement, KeyboardEvent};
 use yew::prelude::*;
 
 /// Input type variants
@@ -43,6 +50,157 @@ impl InputType {
     }
 }
 
+/// State of the field that hosts a trailing action
+///
+/// Provided as a Yew context to the content of the `trailing_action` slot so
+/// custom actions can mirror the field's disabled and read-only treatment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TrailingActionContext {
+    /// Whether the field is disabled
+    pub disabled: bool,
+    /// Whether the field is read-only
+    pub read_only: bool,
+}
+
+impl TrailingActionContext {
+    /// Whether the action should be shown as inactive
+    pub fn inactive(&self) -> bool {
+        self.disabled || self.read_only
+    }
+}
+
+/// Reads the state of the field hosting the current trailing action
+///
+/// Returns `None` when called outside of a `trailing_action` slot.
+#[hook]
+pub fn use_trailing_action_context() -> Option<TrailingActionContext> {
+    use_context::<TrailingActionContext>()
+}
+
+/// Renders the trailing action slot of a text field
+///
+/// The slot sits outside the input element, so clicks on it don't move focus
+/// into the field and its content keeps its own tab stop.
+pub(crate) fn render_trailing_action(action: &Html, context: TrailingActionContext) -> Html {
+    let classes = ClassBuilder::new()
+        .add("awsui-input-trailing-action")
+        .add_if(context.disabled, "awsui-input-trailing-action-disabled")
+        .add_if(context.read_only, "awsui-input-trailing-action-readonly");
+
+    html! {
+        <ContextProvider<TrailingActionContext> context={context}>
+            <span class={classes.build()}>
+                { action.clone() }
+            </span>
+        </ContextProvider<TrailingActionContext>>
+    }
+}
+
+/// Internationalization strings for Input
+///
+/// Unset strings fall back to the closest I18nProvider (keys under
+/// `"input."`), then to English.
+#[derive(Clone, PartialEq, Default)]
+pub struct InputI18nStrings {
+    /// ARIA label of the password toggle while the password is masked
+    /// (default: "Show password")
+    pub show_password_aria_label: Option<String>,
+    /// ARIA label of the password toggle while the password is revealed
+    /// (default: "Hide password")
+    pub hide_password_aria_label: Option<String>,
+    /// ARIA label of the step button increasing a number
+    /// (default: "Increase")
+    pub increment_aria_label: Option<String>,
+    /// ARIA label of the step button decreasing a number
+    /// (default: "Decrease")
+    pub decrement_aria_label: Option<String>,
+}
+
+/// Strings used to render an Input after applying the fallback chain
+struct ResolvedStrings {
+    show_password_aria_label: String,
+    hide_password_aria_label: String,
+    increment_aria_label: String,
+    decrement_aria_label: String,
+}
+
+/// Resolves the strings: `i18n_strings` prop, then the I18nProvider, then
+/// English
+fn resolve_strings(strings: &InputI18nStrings, i18n: &I18nContext) -> ResolvedStrings {
+    let resolve = |value: &Option<String>, key: &str, default: &str| {
+        value
+            .clone()
+            .or_else(|| i18n.message("input", key).map(str::to_string))
+            .unwrap_or_else(|| default.to_string())
+    };
+
+    ResolvedStrings {
+        show_password_aria_label: resolve(
+            &strings.show_password_aria_label,
+            "show_password_aria_label",
+            "Show password",
+        ),
+        hide_password_aria_label: resolve(
+            &strings.hide_password_aria_label,
+            "hide_password_aria_label",
+            "Hide password",
+        ),
+        increment_aria_label: resolve(
+            &strings.increment_aria_label,
+            "increment_aria_label",
+            "Increase",
+        ),
+        decrement_aria_label: resolve(
+            &strings.decrement_aria_label,
+            "decrement_aria_label",
+            "Decrease",
+        ),
+    }
+}
+
+/// Steps a number input value up (`direction` 1) or down (-1), clamped
+/// between `min` and `max`
+///
+/// An empty or unparsable value steps from `min`, else from 0. The result
+/// keeps as many decimals as `step`, so repeated steps don't accumulate
+/// floating point noise.
+fn step_value(
+    value: &str,
+    step: f64,
+    direction: f64,
+    min: Option<f64>,
+    max: Option<f64>,
+) -> String {
+    let next = match value.trim().parse::<f64>() {
+        Ok(current) => current + step * direction,
+        Err(_) => min.unwrap_or(0.0),
+    };
+    let next = max.map_or(next, |max| next.min(max));
+    let next = min.map_or(next, |min| next.max(min));
+
+    let step_text = step.to_string();
+    let decimals = step_text
+        .split_once('.')
+        .map_or(0, |(_, decimals)| decimals.len());
+    format!("{:.*}", decimals, next)
+}
+
+/// Gets the `type` attribute of the input element
+///
+/// A password input with a visibility toggle becomes a text input while the
+/// password is revealed.
+fn effective_input_type(
+    input_type: InputType,
+    password_toggle: bool,
+    revealed: bool,
+) -> &'static str {
+    if input_type == InputType::Password && password_toggle && revealed {
+        InputType::Text.as_str()
+    } else {
+        input_type.as_str()
+    }
+}
+
 /// Event detail for change events
 #[derive(Clone, PartialEq)]
 pub struct InputChangeDetail {
@@ -86,6 +244,8 @@ pub struct InputProps {
     pub warning: bool,
 
     /// HTML name attribute
+    ///
+    /// Takes precedence over `native_attributes.name`.
     #[prop_or_default]
     pub name: Option<String>,
 
@@ -94,9 +254,46 @@ pub struct InputProps {
     pub auto_focus: bool,
 
     /// Autocomplete attribute (true = "on", false = "off")
+    ///
+    /// Takes precedence over `native_attributes.autocomplete`, which also
+    /// accepts tokens such as "email".
     #[prop_or_default]
     pub autocomplete: Option<bool>,
 
+    /// Native attributes of the input element for form integration
+    ///
+    /// `name`, `autocomplete`, `autofocus` and `form` are forwarded, so the
+    /// input takes part in native form submission.
+    #[prop_or_default]
+    pub native_attributes: Option<NativeAttributes>,
+
+    /// Whether to show a button clearing a non-empty value
+    ///
+    /// Search inputs are always clearable.
+    #[prop_or_default]
+    pub clearable: bool,
+
+    /// Callback fired when the value is cleared with the clear button,
+    /// after `on_change`
+    #[prop_or_default]
+    pub on_clear: Option<Callback<()>>,
+
+    /// Smallest value of a number input
+    #[prop_or_default]
+    pub min: Option<f64>,
+
+    /// Largest value of a number input
+    #[prop_or_default]
+    pub max: Option<f64>,
+
+    /// Amount a number input changes by per step (default: 1)
+    #[prop_or_default]
+    pub step: Option<f64>,
+
+    /// Whether a number input shows increment and decrement buttons
+    #[prop_or_default]
+    pub show_step_buttons: bool,
+
     /// Spellcheck attribute
     #[prop_or_default]
     pub spellcheck: Option<bool>,
@@ -125,9 +322,37 @@ pub struct InputProps {
     #[prop_or_default]
     pub control_id: Option<String>,
 
-    /// Clear button ARIA label (for search type)
+    /// Clear button ARIA label (for search type and clearable inputs)
     #[prop_or_default]
     pub clear_aria_label: Option<String>,
+
+    /// Action rendered inside the field after the value, such as a
+    /// microphone or scan button
+    ///
+    /// Placed before the clear button when both are shown. The content can
+    /// read the field state with [`use_trailing_action_context`].
+    #[prop_or_default]
+    pub trailing_action: Option<Html>,
+
+    /// Renders a button that reveals and masks the value of a password input
+    ///
+    /// Only applies to [`InputType::Password`]. The value is masked again
+    /// whenever the input is mounted, and the browser's own reveal button is
+    /// hidden so only one toggle is shown.
+    #[prop_or_default]
+    pub show_password_toggle: bool,
+
+    /// Content rendered directly below the field, such as a
+    /// [`PasswordStrengthIndicator`](crate::PasswordStrengthIndicator)
+    ///
+    /// Inside a FormField, it appears in the control area above the error and
+    /// constraint text.
+    #[prop_or_default]
+    pub secondary_control: Option<Html>,
+
+    /// Internationalization strings
+    #[prop_or_default]
+    pub i18n_strings: InputI18nStrings,
 }
 
 /// Input component for text entry.
@@ -135,12 +360,15 @@ pub struct InputProps {
 /// # Example
 ///
 /// ```rust
-/// use cloudscape_components::{Input, InputType};
+/// use cloudscape_components::{CustomEvent, Input, InputChangeDetail, InputType};
+/// use yew::prelude::*;
+/// # let current_value = String::new();
 ///
 /// let on_change = Callback::from(|event: CustomEvent<InputChangeDetail>| {
-///     console::log!("New value: {}", event.detail.value);
+///     let _new_value = event.detail.value;
 /// });
 ///
+/// # let _: Html =
 /// html! {
 ///     <Input
 ///         input_type={InputType::Text}
@@ -149,11 +377,17 @@ pub struct InputProps {
 ///         on_change={on_change}
 ///     />
 /// }
+/// # ;
 /// ```
 #[function_component(Input)]
 pub fn input(props: &InputProps) -> Html {
     let _metadata = ComponentMetadata::new("Input");
     let input_ref = use_node_ref();
+    let i18n = use_i18n();
+    let strings = resolve_strings(&props.i18n_strings, &i18n);
+    // Kept in component state only, so the password is masked on every mount
+    let password_revealed = use_state(|| false);
+    let password_toggle = props.input_type == InputType::Password && props.show_password_toggle;
 
     // Handle input change
     let on_input = {
@@ -170,11 +404,16 @@ pub fn input(props: &InputProps) -> Html {
         })
     };
 
+    // Report the value and blur to the closest FormStateProvider
+    let on_field_blur = use_field_tracking(props.value.clone());
+
     // Handle blur
     let on_blur_event = {
         let on_blur = props.on_blur.clone();
 
         Callback::from(move |_e: FocusEvent| {
+            on_field_blur.emit(());
+
             if let Some(callback) = &on_blur {
                 callback.emit(());
             }
@@ -192,9 +431,20 @@ pub fn input(props: &InputProps) -> Html {
         })
     };
 
-    // Handle clear button (for search type)
+    // Validation state, merged with the enclosing FormField
+    let validation = use_validation_state(props.invalid, props.warning);
+
+    // Id and ARIA references, merged with the enclosing FormField
+    let association = use_field_association(
+        props.control_id.clone(),
+        props.aria.labelledby.clone(),
+        props.aria.describedby.clone(),
+    );
+
+    // Handle clear button (for search type and clearable inputs)
     let on_clear = {
         let on_change = props.on_change.clone();
+        let on_clear = props.on_clear.clone();
         let input_ref = input_ref.clone();
 
         Callback::from(move |e: MouseEvent| {
@@ -205,6 +455,9 @@ pub fn input(props: &InputProps) -> Html {
                     value: String::new(),
                 }));
             }
+            if let Some(callback) = &on_clear {
+                callback.emit(());
+            }
 
             // Refocus the input after clearing
             if let Some(input) = input_ref.cast::<HtmlInputElement>() {
@@ -213,24 +466,78 @@ pub fn input(props: &InputProps) -> Html {
         })
     };
 
+    // Step a number input with the step buttons or the Up and Down keys
+    let is_number = props.input_type == InputType::Number;
+    let step = props.step.filter(|step| *step > 0.0).unwrap_or(1.0);
+    let steppable = is_number && !props.disabled && !props.read_only;
+    let on_step = {
+        let on_change = props.on_change.clone();
+        let value = props.value.clone();
+        let (min, max) = (props.min, props.max);
+        Callback::from(move |direction: f64| {
+            if let Some(callback) = &on_change {
+                callback.emit(CustomEvent::new_non_cancelable(InputChangeDetail {
+                    value: step_value(&value, step, direction, min, max),
+                }));
+            }
+        })
+    };
+    let on_key_down = {
+        let on_step = on_step.clone();
+        Callback::from(move |e: KeyboardEvent| {
+            let direction = match e.key().as_str() {
+                "ArrowUp" => 1.0,
+                "ArrowDown" => -1.0,
+                _ => return,
+            };
+            if steppable {
+                e.prevent_default();
+                on_step.emit(direction);
+            }
+        })
+    };
+    let current_number = props.value.trim().parse::<f64>().ok();
+    let at_max = current_number
+        .zip(props.max)
+        .is_some_and(|(value, max)| value >= max);
+    let at_min = current_number
+        .zip(props.min)
+        .is_some_and(|(value, min)| value <= min);
+    let show_step_buttons = is_number && props.show_step_buttons;
+
+    let on_password_toggle = {
+        let password_revealed = password_revealed.clone();
+        Callback::from(move |_e: MouseEvent| password_revealed.set(!*password_revealed))
+    };
+
     // Build CSS classes
     let input_classes = ClassBuilder::new()
         .add("awsui-input")
         .add(format!("awsui-input-type-{}", props.input_type.as_str()))
         .add_if(props.disabled, "awsui-input-disabled")
         .add_if(props.read_only, "awsui-input-readonly")
-        .add_if(props.invalid, "awsui-input-invalid")
-        .add_if(!props.invalid && props.warning, "awsui-input-warning");
-
-    // Determine autocomplete attribute
-    let autocomplete_attr = props.autocomplete.map(|ac| if ac { "on" } else { "off" });
-
-    // Determine control ID (use provided or None to let browser auto-generate)
-    let input_id = props.control_id.clone();
+        .add_if(validation.is_invalid(), "awsui-input-invalid")
+        .add_if(validation.is_warning(), "awsui-input-warning")
+        .add_if(password_toggle, "awsui-input-native-reveal-hidden");
+
+    // Determine form attributes, the dedicated props taking precedence
+    let native = props.native_attributes.clone().unwrap_or_default();
+    let native_data = native.data_attrs();
+    let name = props.name.clone().or(native.name);
+    let autocomplete_attr = props
+        .autocomplete
+        .map(|ac| if ac { "on" } else { "off" }.to_string())
+        .or(native.autocomplete);
+    let autofocus = props.auto_focus || native.autofocus.unwrap_or(false);
 
     // ARIA label
     let aria_label = props.aria.label.clone();
 
+    let show_clear_button = (props.input_type == InputType::Search || props.clearable)
+        && !props.value.is_empty()
+        && !props.disabled
+        && !props.read_only;
+
     // Build wrapper classes
     let wrapper_classes = ClassBuilder::new()
         .add("awsui-input-wrapper")
@@ -238,60 +545,150 @@ pub fn input(props: &InputProps) -> Html {
             props.input_type == InputType::Search,
             "awsui-input-has-icon-left",
         )
+        .add_if(show_clear_button, "awsui-input-has-clear-button")
+        .add_if(password_toggle, "awsui-input-has-password-toggle")
+        .add_if(show_step_buttons, "awsui-input-has-step-buttons")
         .add_if(
-            props.input_type == InputType::Search && !props.value.is_empty(),
-            "awsui-input-has-clear-button",
+            props.trailing_action.is_some(),
+            "awsui-input-has-trailing-action",
         );
 
-    html! {
-        <div class={wrapper_classes.build()}>
-            // Left icon for search type
-            if props.input_type == InputType::Search {
-                <span class="awsui-input-icon-left">
-                    <span class="awsui-icon awsui-icon-search" aria-hidden="true" />
-                </span>
-            }
+    let toggle_classes = ClassBuilder::new()
+        .add("awsui-input-password-toggle")
+        .add_if(*password_revealed, "awsui-input-password-toggle-pressed");
+
+    let control = html! {
+        <input
+            ref={input_ref}
+            type={effective_input_type(props.input_type, password_toggle, *password_revealed)}
+            class={input_classes.build()}
+            style={validation.control_style()}
+            id={association.control_id}
+            name={name}
+            form={native.form}
+            value={props.value.clone()}
+            placeholder={props.placeholder.clone()}
+            disabled={props.disabled}
+            readonly={props.read_only}
+            autofocus={autofocus}
+            autocomplete={autocomplete_attr}
+            min={is_number.then_some(props.min).flatten().map(|min| min.to_string())}
+            max={is_number.then_some(props.max).flatten().map(|max| max.to_string())}
+            step={is_number.then_some(props.step).flatten().map(|step| step.to_string())}
+            spellcheck={props.spellcheck.map(|s| s.to_string())}
+            aria-label={aria_label}
+            aria-required={props.aria_required.to_string()}
+            aria-invalid={validation.is_invalid().then_some("true")}
+            aria-labelledby={association.labelledby}
+            aria-describedby={association.describedby}
+            oninput={on_input}
+            onblur={on_blur_event}
+            onfocus={on_focus_event}
+            onkeydown={on_key_down}
+        />
+    };
+    let control = apply_data_attributes(control, native_data);
+
+    let root = html! {
+        <>
+            <div class={wrapper_classes.build()}>
+                // Left icon for search type
+                if props.input_type == InputType::Search {
+                    <span class="awsui-input-icon-left">
+                        <span class="awsui-icon awsui-icon-search" aria-hidden="true" />
+                    </span>
+                }
 
-            // Input element
-            <input
-                ref={input_ref}
-                type={props.input_type.as_str()}
-                class={input_classes.build()}
-                id={input_id}
-                name={props.name.clone()}
-                value={props.value.clone()}
-                placeholder={props.placeholder.clone()}
-                disabled={props.disabled}
-                readonly={props.read_only}
-                autofocus={props.auto_focus}
-                autocomplete={autocomplete_attr}
-                spellcheck={props.spellcheck.map(|s| s.to_string())}
-                aria-label={aria_label}
-                aria-required={props.aria_required.to_string()}
-                aria-invalid={props.invalid.to_string()}
-                aria-labelledby={props.aria.labelledby.clone()}
-                aria-describedby={props.aria.describedby.clone()}
-                oninput={on_input}
-                onblur={on_blur_event}
-                onfocus={on_focus_event}
-            />
-
-            // Clear button for search type (when value exists)
-            if props.input_type == InputType::Search && !props.value.is_empty() && !props.disabled && !props.read_only {
-                <button
-                    type="button"
-                    class="awsui-input-clear-button"
-                    aria-label={
-                        props.clear_aria_label.clone()
-                            .unwrap_or_else(|| "Clear".to_string())
-                    }
-                    onclick={on_clear}
-                >
-                    <span class="awsui-icon awsui-icon-close" aria-hidden="true" />
-                </button>
+                // Input element
+                { control }
+
+                // Trailing action and clear button (for search type, when value exists)
+                if props.trailing_action.is_some()
+                    || show_clear_button
+                    || password_toggle
+                    || show_step_buttons
+                {
+                    <div class="awsui-input-actions">
+                        if let Some(ref action) = props.trailing_action {
+                            { render_trailing_action(action, TrailingActionContext {
+                                disabled: props.disabled,
+                                read_only: props.read_only,
+                            }) }
+                        }
+                        if show_clear_button {
+                            <button
+                                type="button"
+                                class="awsui-input-clear-button"
+                                aria-label={
+                                    props.clear_aria_label.clone()
+                                        .unwrap_or_else(|| "Clear".to_string())
+                                }
+                                onclick={on_clear}
+                            >
+                                <span class="awsui-icon awsui-icon-close" aria-hidden="true" />
+                            </button>
+                        }
+                        if password_toggle {
+                            <button
+                                type="button"
+                                class={toggle_classes.build()}
+                                aria-label={if *password_revealed {
+                                    strings.hide_password_aria_label.clone()
+                                } else {
+                                    strings.show_password_aria_label.clone()
+                                }}
+                                aria-pressed={password_revealed.to_string()}
+                                aria-controls={props.control_id.clone()}
+                                disabled={props.disabled}
+                                onclick={on_password_toggle}
+                            >
+                                <span
+                                    class={if *password_revealed {
+                                        "awsui-icon awsui-icon-eye-hide"
+                                    } else {
+                                        "awsui-icon awsui-icon-eye-show"
+                                    }}
+                                    aria-hidden="true"
+                                />
+                            </button>
+                        }
+                        // Step buttons stay out of the tab order; the arrow
+                        // keys step from the input itself
+                        if show_step_buttons {
+                            <span class="awsui-input-step-buttons">
+                                <button
+                                    type="button"
+                                    class="awsui-input-step-button awsui-input-step-increment"
+                                    aria-label={strings.increment_aria_label.clone()}
+                                    tabindex="-1"
+                                    disabled={!steppable || at_max}
+                                    onclick={on_step.reform(|_: MouseEvent| 1.0)}
+                                >
+                                    <span class="awsui-icon awsui-icon-angle-up" aria-hidden="true" />
+                                </button>
+                                <button
+                                    type="button"
+                                    class="awsui-input-step-button awsui-input-step-decrement"
+                                    aria-label={strings.decrement_aria_label.clone()}
+                                    tabindex="-1"
+                                    disabled={!steppable || at_min}
+                                    onclick={on_step.reform(|_: MouseEvent| -1.0)}
+                                >
+                                    <span class="awsui-icon awsui-icon-angle-down" aria-hidden="true" />
+                                </button>
+                            </span>
+                        }
+                    </div>
+                }
+            </div>
+            if let Some(ref secondary) = props.secondary_control {
+                <div class="awsui-input-secondary-control">
+                    { secondary.clone() }
+                </div>
             }
-        </div>
-    }
+        </>
+    };
+    apply_root_attributes(root, &props.base)
 }
 
 #[cfg(test)]
@@ -312,4 +709,78 @@ mod tests {
     fn input_type_default() {
         assert_eq!(InputType::default(), InputType::Text);
     }
+
+    #[test]
+    fn password_toggle_switches_input_type() {
+        assert_eq!(
+            effective_input_type(InputType::Password, true, false),
+            "password"
+        );
+        assert_eq!(
+            effective_input_type(InputType::Password, true, true),
+            "text"
+        );
+        // Without the toggle the revealed state is ignored
+        assert_eq!(
+            effective_input_type(InputType::Password, false, true),
+            "password"
+        );
+        assert_eq!(effective_input_type(InputType::Email, true, true), "email");
+    }
+
+    #[test]
+    fn input_resolve_strings_fallback() {
+        let mut messages = crate::internal::I18nStrings::new();
+        messages.set("input.show_password_aria_label", "Passwort anzeigen");
+        let i18n = I18nContext {
+            locale: Some("de-DE".to_string()),
+            messages,
+        };
+
+        let resolved = resolve_strings(&InputI18nStrings::default(), &i18n);
+        assert_eq!(resolved.show_password_aria_label, "Passwort anzeigen");
+        assert_eq!(resolved.hide_password_aria_label, "Hide password");
+
+        let strings = InputI18nStrings {
+            show_password_aria_label: Some("Afficher".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            resolve_strings(&strings, &i18n).show_password_aria_label,
+            "Afficher"
+        );
+    }
+
+    #[test]
+    fn step_value_clamps_and_rounds() {
+        assert_eq!(step_value("4", 1.0, 1.0, None, None), "5");
+        assert_eq!(step_value("4", 1.0, -1.0, Some(4.0), None), "4");
+        assert_eq!(step_value("9.5", 1.0, 1.0, None, Some(10.0)), "10");
+        assert_eq!(step_value("0.2", 0.1, 1.0, None, None), "0.3");
+        assert_eq!(step_value("1", 0.25, -1.0, None, None), "0.75");
+        // Out of range values are brought back into range
+        assert_eq!(step_value("50", 1.0, 1.0, Some(0.0), Some(10.0)), "10");
+        // Empty values start from the minimum, else 0
+        assert_eq!(step_value("", 1.0, 1.0, Some(3.0), None), "3");
+        assert_eq!(step_value("abc", 1.0, -1.0, None, None), "0");
+    }
+
+    #[test]
+    fn trailing_action_context_inactive() {
+        assert!(!TrailingActionContext::default().inactive());
+        assert!(
+            TrailingActionContext {
+                disabled: true,
+                read_only: false,
+            }
+            .inactive()
+        );
+        assert!(
+            TrailingActionContext {
+                disabled: false,
+                read_only: true,
+            }
+            .inactive()
+        );
+    }
 }
@@ -13,6 +13,9 @@ pub struct AriaAttributes {
     pub labelledby: Option<String>,
     pub describedby: Option<String>,
     pub controls: Option<String>,
+    /// ID of the highlighted option of a combobox, whose focus stays on the
+    /// trigger
+    pub activedescendant: Option<String>,
     pub expanded: Option<bool>,
     pub haspopup: Option<AriaHasPopup>,
     pub hidden: Option<bool>,
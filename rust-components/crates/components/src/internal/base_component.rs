@@ -6,6 +6,7 @@
 //! Provides common functionality shared across all Cloudscape components,
 //! similar to the React implementation's useBaseComponent hook.
 
+use super::props::{StyleOverride, data_attrs, merge_style};
 use yew::prelude::*;
 
 /// Base properties available to all Cloudscape components
@@ -19,9 +20,23 @@ pub struct BaseComponentProps {
     #[prop_or_default]
     pub class: Option<String>,
 
-    /// Data attributes for testing and analytics
+    /// Data attributes for testing and analytics, rendered on the root
+    /// element
+    ///
+    /// Names without a `data-` prefix get one, so `("row", "1")` renders as
+    /// `data-row="1"`.
     #[prop_or_default]
-    pub data_attributes: Option<Vec<(String, String)>>,
+    pub data_attributes: Vec<(String, String)>,
+
+    /// Stable selector for end-to-end tests, rendered as `data-testid`
+    #[prop_or_default]
+    pub test_id: Option<String>,
+
+    /// Inline style overrides for the root element and named parts
+    ///
+    /// Applied by components that document their part names.
+    #[prop_or_default]
+    pub style: StyleOverride,
 }
 
 impl BaseComponentProps {
@@ -33,17 +48,37 @@ impl BaseComponentProps {
         }
     }
 
-    /// Gets all data attributes as HTML attribute tuples
-    pub fn data_attrs(&self) -> Vec<(&str, &str)> {
-        self.data_attributes
-            .as_ref()
-            .map(|attrs| {
-                attrs
-                    .iter()
-                    .map(|(k, v)| (k.as_str(), v.as_str()))
-                    .collect()
-            })
-            .unwrap_or_default()
+    /// Merges the root style override onto the component's own root style
+    pub fn merge_style(&self, component_style: Option<&str>) -> Option<String> {
+        merge_style(component_style, self.style.root.as_deref())
+    }
+
+    /// Merges the style override for `part` onto the component's own style
+    /// for that element
+    pub fn part_style(&self, part: &str, component_style: Option<&str>) -> Option<String> {
+        merge_style(component_style, self.style.part(part))
+    }
+
+    /// Sets the test ID rendered as `data-testid`
+    pub fn with_test_id(mut self, test_id: impl Into<String>) -> Self {
+        self.test_id = Some(test_id.into());
+        self
+    }
+
+    /// Adds a data attribute, prefixing the name with `data-` when missing
+    pub fn with_data_attribute(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self {
+        self.data_attributes.push((name.into(), value.into()));
+        self
+    }
+
+    /// Gets all data attributes, including the test ID, as HTML attribute
+    /// tuples
+    pub fn data_attrs(&self) -> Vec<(String, String)> {
+        data_attrs(&self.data_attributes, self.test_id.as_deref())
     }
 }
 
@@ -94,6 +129,45 @@ mod tests {
         assert_eq!(base.merge_classes("component-class"), "component-class");
     }
 
+    #[test]
+    fn test_merge_style_and_parts() {
+        let base = BaseComponentProps {
+            style: StyleOverride::new()
+                .with_root("width: 320px; display: none")
+                .with_part("header", "z-index: 3"),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            base.merge_style(Some("display: flex")).as_deref(),
+            Some("display: flex; width: 320px")
+        );
+        assert_eq!(
+            base.part_style("header", None).as_deref(),
+            Some("z-index: 3")
+        );
+        assert_eq!(base.part_style("footer", None), None);
+        assert_eq!(BaseComponentProps::default().merge_style(None), None);
+    }
+
+    #[test]
+    fn test_data_attrs() {
+        let base = BaseComponentProps::default()
+            .with_data_attribute("row", "1")
+            .with_data_attribute("data-column", "name")
+            .with_test_id("users-table");
+
+        assert_eq!(
+            base.data_attrs(),
+            vec![
+                ("data-row".to_string(), "1".to_string()),
+                ("data-column".to_string(), "name".to_string()),
+                ("data-testid".to_string(), "users-table".to_string()),
+            ]
+        );
+        assert!(BaseComponentProps::default().data_attrs().is_empty());
+    }
+
     #[test]
     fn test_component_metadata() {
         let metadata = ComponentMetadata::new("Button");
@@ -0,0 +1,290 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! App-wide busy state coordination
+//!
+//! Components entering a loading state register with the closest
+//! [`BusyAnnouncer`], which counts them and announces "Loading" once when the
+//! first registers and "Loading complete" once the last finishes. While an
+//! announcer is present, registered components stay silent, so a route
+//! transition with several spinners is announced once instead of per spinner.
+
+use crate::internal::LiveRegion;
+use std::rc::Rc;
+use yew::prelude::*;
+
+/// Announcement made when the app busy state changes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BusyAnnouncement {
+    /// The first component started loading
+    Loading,
+    /// The last component finished loading
+    Complete,
+}
+
+/// Gets the announcement for a change in the number of loading components
+pub(crate) fn busy_transition(before: usize, after: usize) -> Option<BusyAnnouncement> {
+    match (before, after) {
+        (0, 1..) => Some(BusyAnnouncement::Loading),
+        (1.., 0) => Some(BusyAnnouncement::Complete),
+        _ => None,
+    }
+}
+
+/// Registration changes dispatched to the busy state
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BusyAction {
+    /// A component started loading
+    Register,
+    /// A component finished loading
+    Unregister,
+}
+
+/// Number of loading components and the latest announcement
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct BusyState {
+    /// Number of registered loading components
+    pub count: usize,
+    /// Announcement of the latest busy/idle transition
+    pub announcement: Option<BusyAnnouncement>,
+}
+
+impl Reducible for BusyState {
+    type Action = BusyAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let count = match action {
+            BusyAction::Register => self.count + 1,
+            BusyAction::Unregister => self.count.saturating_sub(1),
+        };
+        Rc::new(Self {
+            count,
+            announcement: busy_transition(self.count, count).or(self.announcement),
+        })
+    }
+}
+
+/// Busy state shared by a [`BusyAnnouncer`]
+#[derive(Clone, PartialEq)]
+pub struct BusyContext {
+    /// Whether any registered component is loading
+    pub busy: bool,
+    dispatcher: UseReducerDispatcher<BusyState>,
+}
+
+/// Properties for the BusyAnnouncer component
+#[derive(Properties, PartialEq, Clone)]
+pub struct BusyAnnouncerProps {
+    /// Announcement when loading starts
+    ///
+    /// Default: "Loading"
+    #[prop_or_default]
+    pub loading_text: Option<String>,
+
+    /// Announcement when loading completes
+    ///
+    /// Default: "Loading complete"
+    #[prop_or_default]
+    pub loaded_text: Option<String>,
+
+    /// Content whose loading components are coordinated
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// Coordinates the loading announcements of the components in its subtree
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// use cloudscape_components::{BusyAnnouncer, Spinner};
+///
+/// # let _: Html =
+/// html! {
+///     <BusyAnnouncer>
+///         <Spinner register_busy={true} />
+///         <Spinner register_busy={true} />
+///     </BusyAnnouncer>
+/// }
+/// # ;
+/// ```
+#[function_component(BusyAnnouncer)]
+pub fn busy_announcer(props: &BusyAnnouncerProps) -> Html {
+    let state = use_reducer(BusyState::default);
+    let context = BusyContext {
+        busy: state.count > 0,
+        dispatcher: state.dispatcher(),
+    };
+
+    let message = match state.announcement {
+        Some(BusyAnnouncement::Loading) => props
+            .loading_text
+            .clone()
+            .unwrap_or_else(|| "Loading".to_string()),
+        Some(BusyAnnouncement::Complete) => props
+            .loaded_text
+            .clone()
+            .unwrap_or_else(|| "Loading complete".to_string()),
+        None => String::new(),
+    };
+
+    html! {
+        <ContextProvider<BusyContext> {context}>
+            { for props.children.iter() }
+            <LiveRegion message={message} delay_ms={0} />
+        </ContextProvider<BusyContext>>
+    }
+}
+
+/// Registers the calling component as loading while `busy` is true
+///
+/// Returns whether a [`BusyAnnouncer`] coordinates the announcements, in
+/// which case the component should not announce its own loading state.
+#[hook]
+pub(crate) fn use_register_busy(busy: bool) -> bool {
+    let context = use_context::<BusyContext>();
+    let dispatcher = context.as_ref().map(|context| context.dispatcher.clone());
+
+    use_effect_with(busy, move |busy| {
+        let registered = dispatcher.filter(|_| *busy);
+        if let Some(ref dispatcher) = registered {
+            dispatcher.dispatch(BusyAction::Register);
+        }
+        move || {
+            if let Some(dispatcher) = registered {
+                dispatcher.dispatch(BusyAction::Unregister);
+            }
+        }
+    });
+
+    context.is_some()
+}
+
+/// Whether any component registered with the closest [`BusyAnnouncer`] is
+/// loading; always false without an announcer
+#[hook]
+pub fn use_is_app_busy() -> bool {
+    use_context::<BusyContext>().is_some_and(|context| context.busy)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Reduces the actions and records each announcement change, which is
+    /// what the announcer's live region picks up
+    fn apply(actions: &[BusyAction]) -> (Rc<BusyState>, Vec<BusyAnnouncement>) {
+        let mut state = Rc::new(BusyState::default());
+        let mut announcements = Vec::new();
+        for action in actions {
+            let before = state.announcement;
+            state = state.reduce(*action);
+            if state.announcement != before {
+                announcements.extend(state.announcement);
+            }
+        }
+        (state, announcements)
+    }
+
+    #[test]
+    fn test_busy_transition() {
+        assert_eq!(busy_transition(0, 1), Some(BusyAnnouncement::Loading));
+        assert_eq!(busy_transition(1, 2), None);
+        assert_eq!(busy_transition(2, 1), None);
+        assert_eq!(busy_transition(1, 0), Some(BusyAnnouncement::Complete));
+        assert_eq!(busy_transition(0, 0), None);
+    }
+
+    #[test]
+    fn test_overlapping_loading_windows_announce_twice() {
+        use BusyAction::*;
+        // Table starts, spinner starts, table ends, button starts, spinner
+        // ends, button ends
+        let (state, announcements) = apply(&[
+            Register, Register, Unregister, Register, Unregister, Unregister,
+        ]);
+        assert_eq!(
+            announcements,
+            vec![BusyAnnouncement::Loading, BusyAnnouncement::Complete]
+        );
+        assert_eq!(state.count, 0);
+        assert_eq!(state.announcement, Some(BusyAnnouncement::Complete));
+    }
+
+    #[test]
+    fn test_unregister_never_underflows() {
+        let (state, announcements) = apply(&[BusyAction::Unregister, BusyAction::Unregister]);
+        assert_eq!(state.count, 0);
+        assert!(announcements.is_empty());
+        assert_eq!(state.announcement, None);
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod wasm_tests {
+    use super::*;
+    use std::time::Duration;
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[derive(Properties, PartialEq)]
+    struct LoadingProps {
+        loading: bool,
+    }
+
+    #[function_component(Loading)]
+    fn loading(props: &LoadingProps) -> Html {
+        use_register_busy(props.loading);
+        Html::default()
+    }
+
+    #[derive(Properties, PartialEq)]
+    struct TestAppProps {
+        loading: (bool, bool),
+    }
+
+    #[function_component(TestApp)]
+    fn test_app(props: &TestAppProps) -> Html {
+        html! {
+            <BusyAnnouncer>
+                <Loading loading={props.loading.0} />
+                <Loading loading={props.loading.1} />
+            </BusyAnnouncer>
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_overlapping_components_announce_twice() {
+        let root = gloo::utils::document().create_element("div").unwrap();
+        gloo::utils::body().append_child(&root).unwrap();
+        let mut app = yew::Renderer::<TestApp>::with_root_and_props(
+            root.clone(),
+            TestAppProps {
+                loading: (false, false),
+            },
+        )
+        .render();
+
+        // Messages of the announcer's live region after each change
+        let mut messages: Vec<String> = Vec::new();
+        for loading in [(true, false), (true, true), (false, true), (false, false)] {
+            app.update(TestAppProps { loading });
+            // Let the effects register and the live region pick up the message
+            yew::platform::time::sleep(Duration::from_millis(10)).await;
+            let message = root
+                .query_selector(".awsui-live-region")
+                .unwrap()
+                .and_then(|region| region.text_content())
+                .unwrap_or_default();
+            if messages.last() != Some(&message) {
+                messages.push(message);
+            }
+        }
+
+        app.destroy();
+        root.remove();
+        assert_eq!(messages, vec!["Loading", "Loading complete"]);
+    }
+}
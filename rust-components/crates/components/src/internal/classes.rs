@@ -5,11 +5,21 @@
 //!
 //! Provides a type-safe way to build CSS class strings,
 //! similar to the clsx library used in the React implementation.
+//!
+//! Static class names are stored without allocating; only dynamic ones such
+//! as `format!` results own a `String`. Passing the builder to a `class`
+//! attribute converts it into [`Classes`] without joining the names first.
+
+use std::borrow::Cow;
+use std::cell::OnceCell;
+use yew::html::Classes;
 
 /// Builder for constructing CSS class strings
-#[derive(Default)]
+#[derive(Debug, Clone, Default)]
 pub struct ClassBuilder {
-    classes: Vec<String>,
+    classes: Vec<Cow<'static, str>>,
+    /// Joined class string, computed once by [`ClassBuilder::as_str`]
+    joined: OnceCell<String>,
 }
 
 #[allow(clippy::should_implement_trait)]
@@ -19,31 +29,75 @@ impl ClassBuilder {
         Self::default()
     }
 
+    /// Appends a class, skipping empty names
+    fn push(&mut self, class: Cow<'static, str>) {
+        if !class.is_empty() {
+            self.classes.push(class);
+            self.joined = OnceCell::new();
+        }
+    }
+
     /// Adds a class unconditionally
-    pub fn add(mut self, class: impl Into<String>) -> Self {
-        self.classes.push(class.into());
+    pub fn add(mut self, class: impl Into<Cow<'static, str>>) -> Self {
+        self.push(class.into());
         self
     }
 
     /// Adds a class conditionally
-    pub fn add_if(mut self, condition: bool, class: impl Into<String>) -> Self {
+    pub fn add_if(mut self, condition: bool, class: impl Into<Cow<'static, str>>) -> Self {
         if condition {
-            self.classes.push(class.into());
+            self.push(class.into());
+        }
+        self
+    }
+
+    /// Adds an optional class
+    pub fn add_opt(mut self, class: Option<impl Into<Cow<'static, str>>>) -> Self {
+        if let Some(class) = class {
+            self.push(class.into());
         }
         self
     }
 
     /// Adds an optional class
-    pub fn add_option(mut self, class: Option<impl Into<String>>) -> Self {
-        if let Some(c) = class {
-            self.classes.push(c.into());
+    #[deprecated(note = "use `add_opt` instead")]
+    pub fn add_option(self, class: Option<impl Into<Cow<'static, str>>>) -> Self {
+        self.add_opt(class)
+    }
+
+    /// Adds every class of an iterator
+    pub fn extend<I>(mut self, classes: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Cow<'static, str>>,
+    {
+        for class in classes {
+            self.push(class.into());
         }
         self
     }
 
+    /// Returns the class string, joining the classes on first use
+    ///
+    /// The joined string is cached until another class is added.
+    pub fn as_str(&self) -> &str {
+        match self.classes.as_slice() {
+            [] => "",
+            [class] => class,
+            classes => self.joined.get_or_init(|| classes.join(" ")),
+        }
+    }
+
     /// Builds the final class string
-    pub fn build(self) -> String {
-        self.classes.join(" ")
+    pub fn build(mut self) -> String {
+        if let Some(joined) = self.joined.take() {
+            return joined;
+        }
+        match self.classes.len() {
+            0 => String::new(),
+            1 => self.classes.swap_remove(0).into_owned(),
+            _ => self.classes.join(" "),
+        }
     }
 }
 
@@ -53,6 +107,12 @@ impl From<ClassBuilder> for String {
     }
 }
 
+impl From<ClassBuilder> for Classes {
+    fn from(builder: ClassBuilder) -> Self {
+        builder.classes.into_iter().collect()
+    }
+}
+
 /// Convenience function for building classes
 pub fn classes() -> ClassBuilder {
     ClassBuilder::new()
@@ -68,11 +128,22 @@ mod tests {
             .add("base")
             .add_if(true, "active")
             .add_if(false, "disabled")
+            .add_opt(Some("optional"))
+            .add_opt(None::<String>)
+            .build();
+
+        assert_eq!(result, "base active optional");
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_add_option_forwards_to_add_opt() {
+        let result = ClassBuilder::new()
             .add_option(Some("optional"))
             .add_option(None::<String>)
             .build();
 
-        assert_eq!(result, "base active optional");
+        assert_eq!(result, "optional");
     }
 
     #[test]
@@ -87,4 +158,45 @@ mod tests {
 
         assert_eq!(result, "one two");
     }
+
+    #[test]
+    fn test_static_classes_are_borrowed() {
+        let builder = ClassBuilder::new()
+            .add("static")
+            .add(format!("dynamic-{}", 1));
+
+        assert!(matches!(builder.classes[0], Cow::Borrowed(_)));
+        assert!(matches!(builder.classes[1], Cow::Owned(_)));
+    }
+
+    #[test]
+    fn test_extend_skips_empty_classes() {
+        let result = classes()
+            .add("")
+            .extend(["one", "", "two"])
+            .extend(vec![String::from("three")])
+            .build();
+
+        assert_eq!(result, "one two three");
+    }
+
+    #[test]
+    fn test_as_str_is_cached_until_changed() {
+        let builder = classes().add("one").add("two");
+        let first = builder.as_str().as_ptr();
+        assert_eq!(builder.as_str(), "one two");
+        assert_eq!(builder.as_str().as_ptr(), first);
+
+        let builder = builder.add("three");
+        assert_eq!(builder.as_str(), "one two three");
+        assert_eq!(builder.build(), "one two three");
+
+        assert_eq!(classes().add("only").as_str(), "only");
+    }
+
+    #[test]
+    fn test_into_classes() {
+        let classes: Classes = classes().add("one").add_if(true, "two").into();
+        assert_eq!(classes.to_string(), "one two");
+    }
 }
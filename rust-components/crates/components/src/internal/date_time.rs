@@ -0,0 +1,451 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Date and time values shared by DatePicker and DateRangePicker
+//!
+//! Dates are days of the proleptic Gregorian calendar; times are wall-clock
+//! times without a time zone. Values are exchanged as ISO 8601 strings:
+//! `YYYY-MM-DD`, optionally followed by `THH:MM` or `THH:MM:SS`.
+
+/// Finest unit a date value is picked in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DateGranularity {
+    /// Dates only (`YYYY-MM-DD`)
+    #[default]
+    Day,
+    /// Dates with hours and minutes (`YYYY-MM-DDTHH:MM`)
+    Minute,
+    /// Dates with hours, minutes and seconds (`YYYY-MM-DDTHH:MM:SS`)
+    Second,
+}
+
+impl DateGranularity {
+    /// Whether values include a time of day
+    pub fn has_time(&self) -> bool {
+        *self != Self::Day
+    }
+
+    /// Placeholder of a time input of this granularity
+    pub(crate) fn time_placeholder(&self) -> &'static str {
+        match self {
+            Self::Second => "HH:MM:SS",
+            _ => "HH:MM",
+        }
+    }
+}
+
+/// A day of the calendar
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) struct DateValue {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+}
+
+impl DateValue {
+    /// Create a new date value
+    pub(crate) fn new(year: i32, month: u32, day: u32) -> Option<Self> {
+        if !(1..=12).contains(&month) {
+            return None;
+        }
+        let days_in_month = get_days_in_month(year, month);
+        if day < 1 || day > days_in_month {
+            return None;
+        }
+        Some(Self { year, month, day })
+    }
+
+    /// Format as YYYY-MM-DD
+    pub(crate) fn format(&self) -> String {
+        format!("{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+
+    /// Format for display (YYYY/MM/DD)
+    pub(crate) fn format_display(&self) -> String {
+        format!("{:04}/{:02}/{:02}", self.year, self.month, self.day)
+    }
+
+    /// Number of days since 1970-01-01
+    pub(crate) fn to_days(self) -> i64 {
+        days_from_civil(self.year, self.month, self.day)
+    }
+
+    /// Date a number of days after 1970-01-01
+    pub(crate) fn from_days(days: i64) -> Self {
+        let (year, month, day) = civil_from_days(days);
+        Self { year, month, day }
+    }
+
+    /// Date `days` days later (earlier when negative)
+    pub(crate) fn add_days(self, days: i64) -> Self {
+        Self::from_days(self.to_days() + days)
+    }
+
+    /// Date `months` months later (earlier when negative), on the same day
+    /// or the last day of a shorter month
+    pub(crate) fn add_months(self, months: i32) -> Self {
+        let index = self.year * 12 + self.month as i32 - 1 + months;
+        let year = index.div_euclid(12);
+        let month = index.rem_euclid(12) as u32 + 1;
+        Self {
+            year,
+            month,
+            day: self.day.min(get_days_in_month(year, month)),
+        }
+    }
+}
+
+/// A time of day; the default is midnight
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub(crate) struct TimeValue {
+    pub hour: u32,
+    pub minute: u32,
+    pub second: u32,
+}
+
+impl TimeValue {
+    /// Create a new time value
+    pub(crate) fn new(hour: u32, minute: u32, second: u32) -> Option<Self> {
+        (hour < 24 && minute < 60 && second < 60).then_some(Self {
+            hour,
+            minute,
+            second,
+        })
+    }
+
+    /// Parse a time in HH:MM or HH:MM:SS format; fractions of a second are
+    /// ignored
+    pub(crate) fn parse(s: &str) -> Option<Self> {
+        let parts: Vec<&str> = s.split(':').collect();
+        if !(2..=3).contains(&parts.len()) {
+            return None;
+        }
+
+        let component = |part: &str| {
+            if part.is_empty() || part.len() > 2 || !part.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+            part.parse::<u32>().ok()
+        };
+        let hour = component(parts[0])?;
+        let minute = component(parts[1])?;
+        let second = match parts.get(2) {
+            Some(part) => component(part.split('.').next().unwrap_or_default())?,
+            None => 0,
+        };
+
+        Self::new(hour, minute, second)
+    }
+
+    /// Format as HH:MM, or HH:MM:SS at `Second` granularity
+    pub(crate) fn format(&self, granularity: DateGranularity) -> String {
+        match granularity {
+            DateGranularity::Second => {
+                format!("{:02}:{:02}:{:02}", self.hour, self.minute, self.second)
+            }
+            _ => format!("{:02}:{:02}", self.hour, self.minute),
+        }
+    }
+}
+
+/// Parse date string in YYYY-MM-DD format
+pub(crate) fn parse_date(s: &str) -> Option<DateValue> {
+    let parts: Vec<&str> = s.split('-').collect();
+    if parts.len() != 3 {
+        return None;
+    }
+
+    let year = parts[0].parse::<i32>().ok()?;
+    let month = parts[1].parse::<u32>().ok()?;
+    let day = parts[2].parse::<u32>().ok()?;
+
+    DateValue::new(year, month, day)
+}
+
+/// Parse date string in display format (YYYY/MM/DD)
+pub(crate) fn parse_date_display(s: &str) -> Option<DateValue> {
+    let parts: Vec<&str> = s.split('/').collect();
+    if parts.len() != 3 {
+        return None;
+    }
+
+    let year = parts[0].parse::<i32>().ok()?;
+    let month = parts[1].parse::<u32>().ok()?;
+    let day = parts[2].parse::<u32>().ok()?;
+
+    DateValue::new(year, month, day)
+}
+
+/// Parse an ISO 8601 date with an optional time, `YYYY-MM-DD[THH:MM[:SS]]`
+///
+/// A trailing `Z` is accepted and ignored.
+pub(crate) fn parse_date_time(s: &str) -> Option<(DateValue, Option<TimeValue>)> {
+    match s.split_once('T') {
+        Some((date, time)) => Some((
+            parse_date(date)?,
+            Some(TimeValue::parse(time.strip_suffix('Z').unwrap_or(time))?),
+        )),
+        None => Some((parse_date(s)?, None)),
+    }
+}
+
+/// Format a date and time as an ISO 8601 value of the given granularity;
+/// a missing time is midnight
+pub(crate) fn format_date_time(
+    date: DateValue,
+    time: Option<TimeValue>,
+    granularity: DateGranularity,
+) -> String {
+    if granularity.has_time() {
+        format!(
+            "{}T{}",
+            date.format(),
+            time.unwrap_or_default().format(granularity)
+        )
+    } else {
+        date.format()
+    }
+}
+
+/// Formats seconds since the Unix epoch as an ISO 8601 UTC timestamp
+pub(crate) fn format_timestamp(seconds: i64) -> String {
+    let (year, month, day) = civil_from_days(seconds.div_euclid(86_400));
+    let time = seconds.rem_euclid(86_400);
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        time / 3600,
+        time / 60 % 60,
+        time % 60
+    )
+}
+
+/// Get the number of days in a given month
+pub(crate) fn get_days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 => {
+            if is_leap_year(year) {
+                29
+            } else {
+                28
+            }
+        }
+        _ => 0,
+    }
+}
+
+/// Check if a year is a leap year
+pub(crate) fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || (year % 400 == 0)
+}
+
+/// Number of days between 1970-01-01 and a date of the proleptic Gregorian
+/// calendar
+pub(crate) fn days_from_civil(year: i32, month: u32, day: u32) -> i64 {
+    let year = i64::from(year) - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = i64::from(month);
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Year, month and day of the date a number of days after 1970-01-01
+pub(crate) fn civil_from_days(days: i64) -> (i32, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+    let month = (if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    }) as u32;
+    let year = (year_of_era + era * 400 + i64::from(month <= 2)) as i32;
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_date_valid() {
+        let date = parse_date("2026-01-20").unwrap();
+        assert_eq!(date.year, 2026);
+        assert_eq!(date.month, 1);
+        assert_eq!(date.day, 20);
+    }
+
+    #[test]
+    fn test_parse_date_invalid_format() {
+        assert!(parse_date("20-01-2026").is_none());
+        assert!(parse_date("2026/01/20").is_none());
+        assert!(parse_date("invalid").is_none());
+        assert!(parse_date("").is_none());
+    }
+
+    #[test]
+    fn test_parse_date_invalid_values() {
+        assert!(parse_date("2026-13-01").is_none()); // Invalid month
+        assert!(parse_date("2026-00-01").is_none()); // Invalid month
+        assert!(parse_date("2026-01-32").is_none()); // Invalid day
+        assert!(parse_date("2026-01-00").is_none()); // Invalid day
+        assert!(parse_date("2026-02-30").is_none()); // Invalid day for February
+    }
+
+    #[test]
+    fn test_parse_date_display_valid() {
+        let date = parse_date_display("2026/01/20").unwrap();
+        assert_eq!(date.year, 2026);
+        assert_eq!(date.month, 1);
+        assert_eq!(date.day, 20);
+    }
+
+    #[test]
+    fn test_parse_date_display_invalid() {
+        assert!(parse_date_display("2026-01-20").is_none());
+        assert!(parse_date_display("invalid").is_none());
+    }
+
+    #[test]
+    fn test_date_value_format() {
+        let date = DateValue::new(2026, 1, 20).unwrap();
+        assert_eq!(date.format(), "2026-01-20");
+    }
+
+    #[test]
+    fn test_date_value_format_display() {
+        let date = DateValue::new(2026, 1, 20).unwrap();
+        assert_eq!(date.format_display(), "2026/01/20");
+    }
+
+    #[test]
+    fn test_get_days_in_month() {
+        assert_eq!(get_days_in_month(2026, 1), 31); // January
+        assert_eq!(get_days_in_month(2026, 2), 28); // February (non-leap)
+        assert_eq!(get_days_in_month(2024, 2), 29); // February (leap)
+        assert_eq!(get_days_in_month(2026, 4), 30); // April
+        assert_eq!(get_days_in_month(2026, 12), 31); // December
+    }
+
+    #[test]
+    fn test_is_leap_year() {
+        assert!(!is_leap_year(2026)); // Not a leap year
+        assert!(is_leap_year(2024)); // Divisible by 4
+        assert!(!is_leap_year(2100)); // Divisible by 100 but not 400
+        assert!(is_leap_year(2000)); // Divisible by 400
+    }
+
+    #[test]
+    fn test_date_value_new_validates() {
+        assert!(DateValue::new(2026, 1, 20).is_some());
+        assert!(DateValue::new(2026, 13, 1).is_none()); // Invalid month
+        assert!(DateValue::new(2026, 0, 1).is_none()); // Invalid month
+        assert!(DateValue::new(2026, 1, 32).is_none()); // Invalid day
+        assert!(DateValue::new(2026, 2, 29).is_none()); // Invalid day for non-leap year
+        assert!(DateValue::new(2024, 2, 29).is_some()); // Valid day for leap year
+    }
+
+    #[test]
+    fn test_add_days_crosses_months_and_years() {
+        let date = DateValue::new(2026, 1, 20).unwrap();
+        assert_eq!(date.add_days(12), DateValue::new(2026, 2, 1).unwrap());
+        assert_eq!(date.add_days(-20), DateValue::new(2025, 12, 31).unwrap());
+        assert_eq!(
+            DateValue::new(2024, 2, 28).unwrap().add_days(1),
+            DateValue::new(2024, 2, 29).unwrap()
+        );
+        assert_eq!(DateValue::new(1970, 1, 1).unwrap().to_days(), 0);
+        assert_eq!(DateValue::from_days(date.to_days()), date);
+    }
+
+    #[test]
+    fn test_add_months_clamps_day() {
+        let date = DateValue::new(2026, 1, 31).unwrap();
+        assert_eq!(date.add_months(1), DateValue::new(2026, 2, 28).unwrap());
+        assert_eq!(date.add_months(-1), DateValue::new(2025, 12, 31).unwrap());
+        assert_eq!(date.add_months(13), DateValue::new(2027, 2, 28).unwrap());
+    }
+
+    #[test]
+    fn test_date_value_equality() {
+        let date1 = DateValue::new(2026, 1, 20).unwrap();
+        let date2 = DateValue::new(2026, 1, 20).unwrap();
+        let date3 = DateValue::new(2026, 1, 21).unwrap();
+
+        assert_eq!(date1, date2);
+        assert_ne!(date1, date3);
+    }
+
+    #[test]
+    fn test_time_value_parse() {
+        assert_eq!(TimeValue::parse("09:05"), TimeValue::new(9, 5, 0));
+        assert_eq!(TimeValue::parse("23:59:59"), TimeValue::new(23, 59, 59));
+        assert_eq!(TimeValue::parse("7:30"), TimeValue::new(7, 30, 0));
+        assert_eq!(TimeValue::parse("10:30:15.250"), TimeValue::new(10, 30, 15));
+        assert_eq!(TimeValue::parse("24:00"), None);
+        assert_eq!(TimeValue::parse("12:60"), None);
+        assert_eq!(TimeValue::parse("12"), None);
+        assert_eq!(TimeValue::parse("12:+5"), None);
+        assert_eq!(TimeValue::parse(""), None);
+    }
+
+    #[test]
+    fn test_time_value_format() {
+        let time = TimeValue::new(9, 5, 7).unwrap();
+        assert_eq!(time.format(DateGranularity::Minute), "09:05");
+        assert_eq!(time.format(DateGranularity::Second), "09:05:07");
+    }
+
+    #[test]
+    fn test_parse_date_time() {
+        let date = DateValue::new(2026, 1, 20).unwrap();
+        assert_eq!(parse_date_time("2026-01-20"), Some((date, None)));
+        assert_eq!(
+            parse_date_time("2026-01-20T10:30"),
+            Some((date, TimeValue::new(10, 30, 0)))
+        );
+        assert_eq!(
+            parse_date_time("2026-01-20T10:30:15Z"),
+            Some((date, TimeValue::new(10, 30, 15)))
+        );
+        assert_eq!(parse_date_time("2026-01-20T25:00"), None);
+        assert_eq!(parse_date_time("2026-01-20T"), None);
+    }
+
+    #[test]
+    fn test_format_date_time() {
+        let date = DateValue::new(2026, 1, 20).unwrap();
+        let time = TimeValue::new(10, 30, 15);
+        assert_eq!(
+            format_date_time(date, time, DateGranularity::Day),
+            "2026-01-20"
+        );
+        assert_eq!(
+            format_date_time(date, time, DateGranularity::Minute),
+            "2026-01-20T10:30"
+        );
+        assert_eq!(
+            format_date_time(date, None, DateGranularity::Second),
+            "2026-01-20T00:00:00"
+        );
+    }
+
+    #[test]
+    fn test_format_timestamp() {
+        let seconds = days_from_civil(2026, 3, 31) * 86_400 + 10 * 3600 + 30 * 60 + 5;
+        assert_eq!(format_timestamp(seconds), "2026-03-31T10:30:05Z");
+        assert_eq!(format_timestamp(-1), "1969-12-31T23:59:59Z");
+    }
+}
@@ -0,0 +1,198 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Deep linking to sections through the URL hash
+//!
+//! A section with an anchor ID reacts when the location hash matches it, on
+//! mount and on later `hashchange` events: it scrolls itself into view and
+//! briefly highlights its header. An anchor link copies the section URL.
+
+use crate::copy_to_clipboard::{CopyToClipboard, CopyToClipboardVariant};
+use crate::internal::motion::prefers_reduced_motion;
+use gloo::events::EventListener;
+use gloo_timers::callback::Timeout;
+use std::cell::RefCell;
+use std::rc::Rc;
+use web_sys::{Element, ScrollBehavior, ScrollIntoViewOptions};
+use yew::prelude::*;
+
+/// How long the header stays highlighted before fading out, in milliseconds
+pub(crate) const HIGHLIGHT_DURATION_MS: u32 = 2000;
+
+/// Whether a location hash, such as `"#advanced"`, targets the anchor ID
+pub(crate) fn hash_matches(hash: &str, anchor_id: &str) -> bool {
+    !anchor_id.is_empty() && hash.strip_prefix('#').unwrap_or(hash) == anchor_id
+}
+
+/// Builds the URL of an anchor from the current URL, replacing any fragment
+pub(crate) fn deep_link_url(href: &str, anchor_id: &str) -> String {
+    let base = href.split('#').next().unwrap_or_default();
+    format!("{}#{}", base, anchor_id)
+}
+
+/// Inline style of a deep-linkable header
+///
+/// The highlight uses the selected-item background and fades out through a
+/// motion-token transition once removed.
+pub(crate) fn highlight_style(highlighted: bool) -> String {
+    let transition = "transition: background-color var(--awsui-motion-duration-slow) \
+                      var(--awsui-motion-easing-ease-out-quart)";
+    if highlighted {
+        format!(
+            "{}; background-color: var(--awsui-color-background-item-selected)",
+            transition
+        )
+    } else {
+        transition.to_string()
+    }
+}
+
+/// Scrolls an element into view, smoothly unless reduced motion is preferred
+pub(crate) fn scroll_into_view(element: &Element) {
+    let options = ScrollIntoViewOptions::new();
+    options.set_behavior(if prefers_reduced_motion() {
+        ScrollBehavior::Instant
+    } else {
+        ScrollBehavior::Smooth
+    });
+    element.scroll_into_view_with_scroll_into_view_options(&options);
+}
+
+fn current_hash() -> String {
+    web_sys::window()
+        .and_then(|window| window.location().hash().ok())
+        .unwrap_or_default()
+}
+
+/// Reacts to the location hash targeting `anchor_id`
+///
+/// Calls `on_match` and scrolls `target` into view when the hash matches on
+/// mount or after a `hashchange`. Returns whether the header is highlighted.
+#[hook]
+pub(crate) fn use_deep_link(
+    anchor_id: Option<String>,
+    target: NodeRef,
+    on_match: Callback<()>,
+) -> bool {
+    let highlighted = use_state(|| false);
+    let on_match_ref = use_mut_ref(|| on_match.clone());
+    *on_match_ref.borrow_mut() = on_match;
+
+    {
+        let highlighted = highlighted.clone();
+        use_effect_with(anchor_id, move |anchor_id| {
+            let timeout: Rc<RefCell<Option<Timeout>>> = Rc::default();
+            let check = {
+                let anchor_id = anchor_id.clone();
+                let timeout = timeout.clone();
+                move || {
+                    let Some(anchor_id) = anchor_id.as_deref() else {
+                        return;
+                    };
+                    if !hash_matches(&current_hash(), anchor_id) {
+                        return;
+                    }
+
+                    on_match_ref.borrow().emit(());
+                    if let Some(element) = target.cast::<Element>() {
+                        scroll_into_view(&element);
+                    }
+
+                    highlighted.set(true);
+                    let highlighted = highlighted.clone();
+                    *timeout.borrow_mut() = Some(Timeout::new(HIGHLIGHT_DURATION_MS, move || {
+                        highlighted.set(false)
+                    }));
+                }
+            };
+
+            check();
+            let listener = anchor_id.as_ref().and_then(|_| {
+                web_sys::window()
+                    .map(|window| EventListener::new(&window, "hashchange", move |_| check()))
+            });
+
+            move || {
+                drop(listener);
+                drop(timeout.borrow_mut().take());
+            }
+        });
+    }
+
+    *highlighted
+}
+
+/// Properties for the AnchorLink primitive
+#[derive(Properties, PartialEq, Clone)]
+pub struct AnchorLinkProps {
+    /// Anchor ID the link points to
+    pub anchor_id: String,
+
+    /// Whether the link is shown, typically while its header is hovered or
+    /// focused; the link stays focusable when hidden
+    #[prop_or_default]
+    pub visible: bool,
+
+    /// ARIA label of the copy button
+    #[prop_or_default]
+    pub aria_label: Option<String>,
+}
+
+/// Button copying the deep link to a section
+#[function_component(AnchorLink)]
+pub fn anchor_link(props: &AnchorLinkProps) -> Html {
+    let href = web_sys::window()
+        .and_then(|window| window.location().href().ok())
+        .unwrap_or_default();
+    let style = if props.visible {
+        "opacity: 1"
+    } else {
+        "opacity: 0"
+    };
+
+    html! {
+        <span class="awsui-anchor-link" style={style}>
+            <CopyToClipboard
+                copy_text={deep_link_url(&href, &props.anchor_id)}
+                variant={CopyToClipboardVariant::Icon}
+                aria_label={props
+                    .aria_label
+                    .clone()
+                    .unwrap_or_else(|| "Copy link to section".to_string())}
+            />
+        </span>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash_matches() {
+        assert!(hash_matches("#advanced", "advanced"));
+        assert!(hash_matches("advanced", "advanced"));
+        assert!(!hash_matches("#advanced-settings", "advanced"));
+        assert!(!hash_matches("", ""));
+        assert!(!hash_matches("#", ""));
+    }
+
+    #[test]
+    fn test_deep_link_url() {
+        assert_eq!(
+            deep_link_url("https://example.com/settings?tab=2", "advanced"),
+            "https://example.com/settings?tab=2#advanced"
+        );
+        assert_eq!(
+            deep_link_url("https://example.com/settings#general", "advanced"),
+            "https://example.com/settings#advanced"
+        );
+    }
+
+    #[test]
+    fn test_highlight_style() {
+        assert!(highlight_style(true).contains("--awsui-color-background-item-selected"));
+        assert!(!highlight_style(false).contains("background-color: var"));
+        assert!(highlight_style(false).contains("--awsui-motion-duration-slow"));
+    }
+}
@@ -0,0 +1,205 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Development-mode prop validation warnings
+//!
+//! Components describe misuse of their props as [`DevWarning`]s with a stable
+//! code, and [`DevWarnings`] logs each code at most once per component
+//! instance to `console.warn`, formatted as
+//! `[awsui] Component (code): message` so teams can grep for them.
+//!
+//! Validation only runs in debug builds; in release builds
+//! [`DevWarnings::check`] returns before calling the validation, so it is
+//! optimized away.
+
+use super::props::StyleOverride;
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::rc::Rc;
+use yew::prelude::*;
+
+/// A misuse of a component's props
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct DevWarning {
+    /// Stable identifier of the misuse, e.g. `button-icon-only-missing-label`
+    pub code: &'static str,
+    /// Explanation of the problem and how to fix it
+    pub message: String,
+}
+
+impl DevWarning {
+    pub(crate) fn new(code: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+/// Warning codes already logged by one component instance
+#[derive(Clone, Debug)]
+pub(crate) struct DevWarnings {
+    component: &'static str,
+    logged: Rc<RefCell<HashSet<&'static str>>>,
+}
+
+impl DevWarnings {
+    pub(crate) fn new(component: &'static str) -> Self {
+        Self {
+            component,
+            logged: Rc::default(),
+        }
+    }
+
+    /// Logs a warning unless this instance already logged one with the same code
+    ///
+    /// Returns whether the warning was logged.
+    pub(crate) fn warn_once(&self, code: &'static str, message: &str) -> bool {
+        if !self.logged.borrow_mut().insert(code) {
+            return false;
+        }
+
+        log_warning(&format_warning(self.component, code, message));
+        true
+    }
+
+    /// Runs `validate` and logs each warning it returns once
+    ///
+    /// Returns the number of warnings logged by this call. Release builds
+    /// skip the validation and return 0.
+    pub(crate) fn check(&self, validate: impl FnOnce() -> Vec<DevWarning>) -> usize {
+        if !cfg!(debug_assertions) {
+            return 0;
+        }
+
+        validate()
+            .iter()
+            .filter(|warning| self.warn_once(warning.code, &warning.message))
+            .count()
+    }
+}
+
+/// Logs a warning that isn't tied to a component instance, such as one about
+/// a theme, every time it is reported
+///
+/// Release builds don't log.
+pub(crate) fn log_dev_warning(source: &str, warning: &DevWarning) {
+    if cfg!(debug_assertions) {
+        log_warning(&format_warning(source, warning.code, &warning.message));
+    }
+}
+
+/// Keeps the logged warning codes for the lifetime of a component instance
+#[hook]
+pub(crate) fn use_dev_warnings(component: &'static str) -> DevWarnings {
+    (*use_state(|| DevWarnings::new(component))).clone()
+}
+
+/// Returns the values that occur more than once, in order of first repetition
+pub(crate) fn duplicates<'a>(values: impl IntoIterator<Item = &'a str>) -> Vec<&'a str> {
+    let mut seen = HashSet::new();
+    let mut repeated = Vec::new();
+    for value in values {
+        if !seen.insert(value) && !repeated.contains(&value) {
+            repeated.push(value);
+        }
+    }
+    repeated
+}
+
+/// Reports style override parts that a component doesn't have
+pub(crate) fn unknown_style_parts(
+    code: &'static str,
+    style: &StyleOverride,
+    known: &[&str],
+) -> Option<DevWarning> {
+    let unknown = style.unknown_parts(known);
+    (!unknown.is_empty()).then(|| {
+        DevWarning::new(
+            code,
+            format!(
+                "Unknown style parts: {}. The styles are not applied; the parts are {}.",
+                unknown.join(", "),
+                known.join(", ")
+            ),
+        )
+    })
+}
+
+fn format_warning(component: &str, code: &str, message: &str) -> String {
+    format!("[awsui] {} ({}): {}", component, code, message)
+}
+
+fn log_warning(text: &str) {
+    #[cfg(target_arch = "wasm32")]
+    gloo::console::warn!(text);
+    #[cfg(not(target_arch = "wasm32"))]
+    eprintln!("{}", text);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn warn_once_deduplicates_per_code() {
+        let warnings = DevWarnings::new("Button");
+        assert!(warnings.warn_once("code-a", "first"));
+        assert!(!warnings.warn_once("code-a", "second"));
+        assert!(warnings.warn_once("code-b", "other"));
+    }
+
+    #[test]
+    fn warn_once_is_per_instance() {
+        let first = DevWarnings::new("Tabs");
+        let second = DevWarnings::new("Tabs");
+        assert!(first.warn_once("tabs-duplicate-id", "message"));
+        assert!(second.warn_once("tabs-duplicate-id", "message"));
+
+        // Clones share the instance's log
+        assert!(!first.clone().warn_once("tabs-duplicate-id", "message"));
+    }
+
+    #[test]
+    fn check_logs_each_warning_once() {
+        let warnings = DevWarnings::new("Select");
+        let validate = || {
+            vec![
+                DevWarning::new("code-a", "a"),
+                DevWarning::new("code-b", "b"),
+            ]
+        };
+        assert_eq!(warnings.check(validate), 2);
+        assert_eq!(warnings.check(validate), 0);
+    }
+
+    #[test]
+    fn format_includes_component_and_code() {
+        assert_eq!(
+            format_warning("Button", "button-icon-only-missing-label", "Add a label."),
+            "[awsui] Button (button-icon-only-missing-label): Add a label."
+        );
+    }
+
+    #[test]
+    fn unknown_style_parts_lists_known_parts() {
+        let style = StyleOverride::new()
+            .with_part("label", "color: red")
+            .with_part("text", "color: red");
+        let warning =
+            unknown_style_parts("button-unknown-style-part", &style, &["label", "icon"]).unwrap();
+        assert_eq!(
+            warning.message,
+            "Unknown style parts: text. The styles are not applied; the parts are label, icon."
+        );
+        assert!(
+            unknown_style_parts("button-unknown-style-part", &style, &["label", "text"]).is_none()
+        );
+    }
+
+    #[test]
+    fn duplicates_in_first_repetition_order() {
+        assert_eq!(duplicates(["a", "b", "a", "c", "b", "a"]), vec!["a", "b"]);
+        assert!(duplicates(["a", "b", "c"]).is_empty());
+    }
+}
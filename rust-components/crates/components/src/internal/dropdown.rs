@@ -0,0 +1,623 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Behavior shared by dropdown containers
+//!
+//! An opening dropdown mounts hidden, so it can be measured and positioned,
+//! and fades in on the next frame. A closing dropdown fades out and stays
+//! mounted until the fade has finished. Both transitions are skipped when
+//! motion is disabled. Focus is never held back by a transition: the dropdown
+//! content is in the DOM from the first frame.
+//!
+//! While open, a dropdown is anchored to its trigger with fixed positioning,
+//! re-anchored on scroll and resize, and opens upward when there is not
+//! enough space below. Clicking or moving focus outside of the component
+//! closes it.
+
+use crate::internal::motion::motion_disabled;
+use gloo::events::{EventListener, EventListenerOptions};
+use gloo::render::{AnimationFrame, request_animation_frame};
+use gloo_timers::callback::Timeout;
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, EventTarget, Node};
+use yew::prelude::*;
+
+/// How long the closing fade takes, matching `--awsui-motion-duration-show-quick`
+pub(crate) const DROPDOWN_EXIT_DURATION_MS: u32 = 135;
+
+/// Phase of a dropdown transition
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TransitionPhase {
+    /// Not mounted
+    Closed,
+    /// Mounted and hidden, waiting a frame to animate in
+    Entering,
+    /// Fully shown
+    Open,
+    /// Fading out before unmounting
+    Exiting,
+}
+
+/// Inputs driving a transition
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DropdownEvent {
+    /// The dropdown was asked to open
+    Open,
+    /// The dropdown was asked to close
+    Close,
+    /// The frame after mounting has been painted
+    Entered,
+    /// The closing fade has finished
+    Exited,
+}
+
+/// Work to schedule after a transition step
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TransitionSchedule {
+    /// Report [`DropdownEvent::Entered`] on the next animation frame
+    Frame,
+    /// Report [`DropdownEvent::Exited`] after the exit duration
+    ExitTimer,
+}
+
+/// Advances a transition
+///
+/// Any pending schedule is replaced by the returned one, so at most one
+/// frame or timer is pending at a time.
+pub(crate) fn next_phase(
+    phase: TransitionPhase,
+    event: DropdownEvent,
+    reduced_motion: bool,
+) -> (TransitionPhase, Option<TransitionSchedule>) {
+    use DropdownEvent as E;
+    use TransitionPhase as P;
+
+    match (phase, event) {
+        (P::Open, E::Open) => (P::Open, None),
+        (P::Entering, E::Open) => (P::Entering, Some(TransitionSchedule::Frame)),
+        (_, E::Open) if reduced_motion => (P::Open, None),
+        (_, E::Open) => (P::Entering, Some(TransitionSchedule::Frame)),
+        (P::Closed, E::Close) => (P::Closed, None),
+        (P::Exiting, E::Close) => (P::Exiting, Some(TransitionSchedule::ExitTimer)),
+        (_, E::Close) if reduced_motion => (P::Closed, None),
+        (_, E::Close) => (P::Exiting, Some(TransitionSchedule::ExitTimer)),
+        (P::Entering, E::Entered) => (P::Open, None),
+        (P::Exiting, E::Exited) => (P::Closed, None),
+        // Stale reports from a cancelled schedule
+        (phase, E::Entered | E::Exited) => (phase, None),
+    }
+}
+
+/// State of a dropdown transition for rendering
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct DropdownTransition {
+    /// Current phase
+    pub phase: TransitionPhase,
+}
+
+impl DropdownTransition {
+    /// Whether the dropdown content is rendered
+    pub fn mounted(&self) -> bool {
+        self.phase != TransitionPhase::Closed
+    }
+
+    /// Inline style animating the dropdown through its phases
+    pub fn style(&self) -> Option<&'static str> {
+        match self.phase {
+            TransitionPhase::Closed => None,
+            TransitionPhase::Entering => Some("opacity: 0; transform: translateY(-4px)"),
+            TransitionPhase::Open => Some(
+                "opacity: 1; transform: none; \
+                 transition: opacity var(--awsui-motion-duration-show-quick) \
+                 var(--awsui-motion-easing-show-quick), \
+                 transform var(--awsui-motion-duration-show-quick) \
+                 var(--awsui-motion-easing-show-quick)",
+            ),
+            TransitionPhase::Exiting => Some(
+                "opacity: 0; pointer-events: none; \
+                 transition: opacity var(--awsui-motion-duration-show-quick) \
+                 var(--awsui-motion-easing-show-quick)",
+            ),
+        }
+    }
+}
+
+/// Drives the open and close transition of a dropdown
+///
+/// `anchor` is an element containing the dropdown, used to detect the
+/// motion-disabled scope.
+#[hook]
+pub(crate) fn use_dropdown_transition(open: bool, anchor: NodeRef) -> DropdownTransition {
+    let phase = use_state_eq(|| {
+        if open {
+            TransitionPhase::Open
+        } else {
+            TransitionPhase::Closed
+        }
+    });
+    // Pending work is cancelled by dropping it, including on unmount
+    let frame = use_mut_ref(|| None::<AnimationFrame>);
+    let timer = use_mut_ref(|| None::<Timeout>);
+
+    {
+        let phase = phase.clone();
+        use_effect_with(open, move |open| {
+            let reduced_motion = motion_disabled(anchor.cast::<Element>().as_ref());
+            let event = if *open {
+                DropdownEvent::Open
+            } else {
+                DropdownEvent::Close
+            };
+            let (next, schedule) = next_phase(*phase, event, reduced_motion);
+            phase.set(next);
+
+            frame.borrow_mut().take();
+            timer.borrow_mut().take();
+            match schedule {
+                Some(TransitionSchedule::Frame) => {
+                    let phase = phase.clone();
+                    *frame.borrow_mut() = Some(request_animation_frame(move |_| {
+                        phase.set(next_phase(next, DropdownEvent::Entered, reduced_motion).0)
+                    }));
+                }
+                Some(TransitionSchedule::ExitTimer) => {
+                    let phase = phase.clone();
+                    *timer.borrow_mut() =
+                        Some(Timeout::new(DROPDOWN_EXIT_DURATION_MS, move || {
+                            phase.set(next_phase(next, DropdownEvent::Exited, reduced_motion).0)
+                        }));
+                }
+                None => {}
+            }
+
+            move || {
+                frame.borrow_mut().take();
+                timer.borrow_mut().take();
+            }
+        });
+    }
+
+    DropdownTransition { phase: *phase }
+}
+
+/// Space between the trigger and the dropdown, in pixels
+const DROPDOWN_OFFSET: f64 = 4.0;
+
+/// Space kept between the dropdown and the viewport edge, in pixels
+const VIEWPORT_MARGIN: f64 = 8.0;
+
+/// Smallest height a dropdown is limited to, even in a cramped viewport
+const MIN_DROPDOWN_HEIGHT: f64 = 96.0;
+
+/// Side of the trigger a dropdown opens on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DropdownDirection {
+    /// Below the trigger
+    Down,
+    /// Above the trigger, when there is not enough space below
+    Up,
+}
+
+/// Viewport rectangle of a dropdown trigger
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct TriggerRect {
+    pub top: f64,
+    pub bottom: f64,
+    pub left: f64,
+    pub width: f64,
+}
+
+/// Fixed position of an open dropdown anchored to its trigger
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct DropdownPosition {
+    /// Side the dropdown opens on
+    pub direction: DropdownDirection,
+    /// Distance of the edge next to the trigger from the viewport edge on
+    /// the same side: the top edge when opening down, the bottom edge when
+    /// opening up
+    pub inset: f64,
+    /// Left edge, aligned with the trigger
+    pub left: f64,
+    /// Minimum width, matching the trigger
+    pub min_width: f64,
+    /// Height available on the chosen side
+    pub max_height: f64,
+}
+
+impl DropdownPosition {
+    /// Inline style placing the dropdown
+    pub fn style(&self) -> String {
+        let (edge, shadow) = match self.direction {
+            DropdownDirection::Down => ("top", "--awsui-shadow-dropdown"),
+            DropdownDirection::Up => ("bottom", "--awsui-shadow-dropup"),
+        };
+        format!(
+            "position: fixed; {}: {}px; left: {}px; min-width: {}px; max-height: {}px; \
+             overflow-y: auto; box-shadow: var({})",
+            edge, self.inset, self.left, self.min_width, self.max_height, shadow
+        )
+    }
+}
+
+/// Places a dropdown of `height` next to `trigger`
+///
+/// The dropdown opens down unless it doesn't fit below the trigger and there
+/// is more space above.
+pub(crate) fn place_dropdown(
+    trigger: TriggerRect,
+    height: f64,
+    viewport_height: f64,
+) -> DropdownPosition {
+    let below = viewport_height - trigger.bottom - DROPDOWN_OFFSET - VIEWPORT_MARGIN;
+    let above = trigger.top - DROPDOWN_OFFSET - VIEWPORT_MARGIN;
+    let (direction, inset, space) = if height > below && above > below {
+        (
+            DropdownDirection::Up,
+            viewport_height - trigger.top + DROPDOWN_OFFSET,
+            above,
+        )
+    } else {
+        (
+            DropdownDirection::Down,
+            trigger.bottom + DROPDOWN_OFFSET,
+            below,
+        )
+    };
+    DropdownPosition {
+        direction,
+        inset,
+        left: trigger.left,
+        min_width: trigger.width,
+        max_height: space.max(MIN_DROPDOWN_HEIGHT),
+    }
+}
+
+/// Combines the transition and position styles of a dropdown
+pub(crate) fn dropdown_style(
+    transition: DropdownTransition,
+    position: Option<DropdownPosition>,
+) -> Option<String> {
+    let styles: Vec<String> = position
+        .map(|position| position.style())
+        .into_iter()
+        .chain(transition.style().map(str::to_string))
+        .collect();
+    (!styles.is_empty()).then(|| styles.join("; "))
+}
+
+/// Position to render a dropdown with
+///
+/// The last measured position is kept while the dropdown is still mounted
+/// after closing, so it fades out in place.
+fn rendered_position(
+    measured: Option<DropdownPosition>,
+    mounted: bool,
+) -> Option<DropdownPosition> {
+    measured.filter(|_| mounted)
+}
+
+/// Keeps an open dropdown anchored to `trigger`
+///
+/// Measures once the dropdown is laid out, and again on viewport resize and
+/// on scroll of any ancestor, at most once per animation frame. `mounted`
+/// is whether the dropdown is rendered, which outlasts `open` during an
+/// exit transition. Returns `None` once unmounted.
+#[hook]
+pub(crate) fn use_dropdown_position(
+    open: bool,
+    mounted: bool,
+    trigger: NodeRef,
+    dropdown: NodeRef,
+) -> Option<DropdownPosition> {
+    let position = use_state_eq(|| None::<DropdownPosition>);
+
+    {
+        let position = position.clone();
+        use_effect_with(mounted, move |mounted| {
+            if !*mounted {
+                position.set(None);
+            }
+        });
+    }
+
+    {
+        let position = position.clone();
+        use_effect_with(open, move |open| {
+            let frame = Rc::new(RefCell::new(None::<AnimationFrame>));
+            let listeners = if *open {
+                let measure = Callback::from(move |_: ()| {
+                    if let Some(trigger) = trigger.cast::<Element>()
+                        && let Some(dropdown) = dropdown.cast::<Element>()
+                        && let Some(viewport_height) = web_sys::window()
+                            .and_then(|window| window.inner_height().ok())
+                            .and_then(|height| height.as_f64())
+                    {
+                        let rect = trigger.get_bounding_client_rect();
+                        position.set(Some(place_dropdown(
+                            TriggerRect {
+                                top: rect.top(),
+                                bottom: rect.bottom(),
+                                left: rect.left(),
+                                width: rect.width(),
+                            },
+                            f64::from(dropdown.scroll_height()),
+                            viewport_height,
+                        )));
+                    }
+                });
+                let schedule = {
+                    let frame = frame.clone();
+                    move || {
+                        if frame.borrow().is_some() {
+                            return;
+                        }
+                        let pending = frame.clone();
+                        let measure = measure.clone();
+                        *frame.borrow_mut() = Some(request_animation_frame(move |_| {
+                            pending.borrow_mut().take();
+                            measure.emit(());
+                        }));
+                    }
+                };
+                // The first measurement waits for the dropdown to be laid out
+                schedule();
+
+                web_sys::window().map(|window| {
+                    let on_resize = {
+                        let schedule = schedule.clone();
+                        EventListener::new(&window, "resize", move |_| schedule())
+                    };
+                    let on_scroll = EventListener::new_with_options(
+                        &window,
+                        "scroll",
+                        EventListenerOptions::run_in_capture_phase(),
+                        move |_| schedule(),
+                    );
+                    (on_resize, on_scroll)
+                })
+            } else {
+                None
+            };
+
+            move || {
+                drop(listeners);
+                frame.borrow_mut().take();
+            }
+        });
+    }
+
+    rendered_position(*position, mounted)
+}
+
+/// Whether an event target lies outside of `root`
+fn is_outside(root: &Element, target: Option<EventTarget>) -> bool {
+    target
+        .and_then(|target| target.dyn_into::<Node>().ok())
+        .is_some_and(|node| !root.contains(Some(&node)))
+}
+
+/// Calls `on_dismiss` when the user clicks or moves focus outside of `root`
+/// while a dropdown is open
+///
+/// The document listeners are only registered while open, and are removed
+/// when the dropdown closes or the component unmounts.
+#[hook]
+pub(crate) fn use_outside_dismiss(open: bool, root: NodeRef, on_dismiss: Callback<()>) {
+    use_effect_with(open, move |open| {
+        let listeners = open.then(gloo::utils::document).map(|document| {
+            let listen = |event_type: &'static str| {
+                let root = root.clone();
+                let on_dismiss = on_dismiss.clone();
+                EventListener::new_with_options(
+                    &document,
+                    event_type,
+                    EventListenerOptions::run_in_capture_phase(),
+                    move |event| {
+                        if let Some(root) = root.cast::<Element>()
+                            && is_outside(&root, event.target())
+                        {
+                            on_dismiss.emit(());
+                        }
+                    },
+                )
+            };
+            [listen("mousedown"), listen("focusin")]
+        });
+
+        move || drop(listeners)
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use DropdownEvent as E;
+    use TransitionPhase as P;
+
+    /// Simulates a dropdown, delivering scheduled work only if it is still the
+    /// pending schedule, as dropping cancels the previous frame or timer
+    struct Simulation {
+        phase: TransitionPhase,
+        pending: Option<TransitionSchedule>,
+        reduced_motion: bool,
+    }
+
+    impl Simulation {
+        fn new(reduced_motion: bool) -> Self {
+            Self {
+                phase: P::Closed,
+                pending: None,
+                reduced_motion,
+            }
+        }
+
+        fn send(&mut self, event: DropdownEvent) {
+            let (phase, schedule) = next_phase(self.phase, event, self.reduced_motion);
+            self.phase = phase;
+            self.pending = schedule;
+        }
+
+        fn toggle(&mut self, open: bool) {
+            self.send(if open { E::Open } else { E::Close });
+        }
+
+        fn flush(&mut self) {
+            while let Some(schedule) = self.pending.take() {
+                self.send(match schedule {
+                    TransitionSchedule::Frame => E::Entered,
+                    TransitionSchedule::ExitTimer => E::Exited,
+                });
+            }
+        }
+    }
+
+    #[test]
+    fn test_open_and_close() {
+        let mut dropdown = Simulation::new(false);
+        dropdown.toggle(true);
+        assert_eq!(dropdown.phase, P::Entering);
+        assert_eq!(dropdown.pending, Some(TransitionSchedule::Frame));
+        dropdown.flush();
+        assert_eq!(dropdown.phase, P::Open);
+
+        dropdown.toggle(false);
+        assert_eq!(dropdown.phase, P::Exiting);
+        assert!(
+            DropdownTransition {
+                phase: dropdown.phase
+            }
+            .mounted()
+        );
+        dropdown.flush();
+        assert_eq!(dropdown.phase, P::Closed);
+    }
+
+    #[test]
+    fn test_reduced_motion_skips_transitions() {
+        let mut dropdown = Simulation::new(true);
+        dropdown.toggle(true);
+        assert_eq!((dropdown.phase, dropdown.pending), (P::Open, None));
+        dropdown.toggle(false);
+        assert_eq!((dropdown.phase, dropdown.pending), (P::Closed, None));
+    }
+
+    #[test]
+    fn test_rapid_toggling_settles() {
+        for reduced_motion in [false, true] {
+            let mut dropdown = Simulation::new(reduced_motion);
+            for i in 0..1000 {
+                dropdown.toggle(i % 2 == 0);
+                // Never more than one pending frame or timer
+                if i % 7 == 0 {
+                    dropdown.flush();
+                }
+            }
+            // The last toggle closed the dropdown
+            dropdown.flush();
+            assert_eq!(dropdown.phase, P::Closed);
+            assert_eq!(dropdown.pending, None);
+
+            dropdown.toggle(true);
+            dropdown.toggle(false);
+            dropdown.toggle(true);
+            dropdown.flush();
+            assert_eq!(dropdown.phase, P::Open);
+        }
+    }
+
+    #[test]
+    fn test_stale_reports_are_ignored() {
+        assert_eq!(next_phase(P::Open, E::Exited, false), (P::Open, None));
+        assert_eq!(next_phase(P::Closed, E::Entered, false), (P::Closed, None));
+        assert_eq!(
+            next_phase(P::Exiting, E::Entered, false),
+            (P::Exiting, None)
+        );
+    }
+
+    fn trigger_at(top: f64) -> TriggerRect {
+        TriggerRect {
+            top,
+            bottom: top + 32.0,
+            left: 20.0,
+            width: 240.0,
+        }
+    }
+
+    #[test]
+    fn test_place_dropdown_below_trigger() {
+        let position = place_dropdown(trigger_at(100.0), 200.0, 800.0);
+        assert_eq!(position.direction, DropdownDirection::Down);
+        assert_eq!(position.inset, 136.0);
+        assert_eq!((position.left, position.min_width), (20.0, 240.0));
+        assert_eq!(position.max_height, 656.0);
+        assert!(position.style().contains("top: 136px"));
+        assert!(position.style().contains("--awsui-shadow-dropdown"));
+    }
+
+    #[test]
+    fn test_place_dropdown_flips_up() {
+        // Not enough space below, more above
+        let position = place_dropdown(trigger_at(600.0), 300.0, 800.0);
+        assert_eq!(position.direction, DropdownDirection::Up);
+        assert_eq!(position.inset, 204.0);
+        assert_eq!(position.max_height, 588.0);
+        assert!(position.style().contains("bottom: 204px"));
+        assert!(position.style().contains("--awsui-shadow-dropup"));
+
+        // Stays down when neither side fits and below has more space
+        let position = place_dropdown(trigger_at(300.0), 1000.0, 800.0);
+        assert_eq!(position.direction, DropdownDirection::Down);
+        assert_eq!(position.max_height, 456.0);
+
+        // Cramped viewports keep a usable height
+        let position = place_dropdown(trigger_at(10.0), 300.0, 80.0);
+        assert_eq!(position.max_height, MIN_DROPDOWN_HEIGHT);
+    }
+
+    #[test]
+    fn test_dropdown_style() {
+        let open = DropdownTransition { phase: P::Open };
+        assert_eq!(
+            dropdown_style(DropdownTransition { phase: P::Closed }, None),
+            None
+        );
+        assert_eq!(dropdown_style(open, None).as_deref(), open.style());
+        let style =
+            dropdown_style(open, Some(place_dropdown(trigger_at(100.0), 200.0, 800.0))).unwrap();
+        assert!(style.starts_with("position: fixed"));
+        assert!(style.ends_with(open.style().unwrap()));
+    }
+
+    #[test]
+    fn test_exiting_dropdown_keeps_position() {
+        let measured = Some(place_dropdown(trigger_at(100.0), 200.0, 800.0));
+        let exiting = DropdownTransition { phase: P::Exiting };
+        let style =
+            dropdown_style(exiting, rendered_position(measured, exiting.mounted())).unwrap();
+        assert!(style.starts_with("position: fixed; top: 136px"));
+        assert!(style.ends_with(exiting.style().unwrap()));
+
+        let closed = DropdownTransition { phase: P::Closed };
+        assert_eq!(rendered_position(measured, closed.mounted()), None);
+    }
+
+    #[test]
+    fn test_transition_style() {
+        assert_eq!(DropdownTransition { phase: P::Closed }.style(), None);
+        assert!(
+            DropdownTransition { phase: P::Open }
+                .style()
+                .unwrap()
+                .contains("--awsui-motion-easing-show-quick")
+        );
+        assert!(
+            DropdownTransition { phase: P::Exiting }
+                .style()
+                .unwrap()
+                .contains("pointer-events: none")
+        );
+    }
+}
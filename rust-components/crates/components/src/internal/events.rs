@@ -6,14 +6,25 @@
 //! Provides event structures that mirror the React implementation's
 //! CancelableEventHandler and event detail types.
 
-use web_sys::{KeyboardEvent, MouseEvent};
+use std::cell::Cell;
+use std::rc::Rc;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, KeyboardEvent, MouseEvent};
+use yew::Callback;
+
+/// Elements inside a clickable row or card that handle their own clicks
+pub(crate) const INTERACTIVE_SELECTOR: &str = "a, button, input, select, textarea, label, \
+     [role='button'], [role='link'], [role='menuitem'], [contenteditable='true']";
 
 /// Custom event wrapper that can be prevented
+///
+/// Clones share their cancellation state, so an emitter can hand a clone to a
+/// callback and observe a `prevent_default()` call made by the consumer.
 #[derive(Debug, Clone)]
 pub struct CustomEvent<T> {
     pub detail: T,
     pub cancelable: bool,
-    pub default_prevented: bool,
+    prevented: Rc<Cell<bool>>,
 }
 
 impl<T> CustomEvent<T> {
@@ -22,7 +33,7 @@ impl<T> CustomEvent<T> {
         Self {
             detail,
             cancelable: true,
-            default_prevented: false,
+            prevented: Rc::new(Cell::new(false)),
         }
     }
 
@@ -31,16 +42,32 @@ impl<T> CustomEvent<T> {
         Self {
             detail,
             cancelable: false,
-            default_prevented: false,
+            prevented: Rc::new(Cell::new(false)),
         }
     }
 
     /// Prevents the default action
     pub fn prevent_default(&mut self) {
         if self.cancelable {
-            self.default_prevented = true;
+            self.prevented.set(true);
         }
     }
+
+    /// Whether `prevent_default()` was called on this event or any of its clones
+    pub fn default_prevented(&self) -> bool {
+        self.prevented.get()
+    }
+
+    /// Emits the event to `callback` and returns whether the consumer
+    /// called `prevent_default()` on it
+    ///
+    /// Emitters use the result to suppress the native action, such as
+    /// following an anchor's href, when the consumer handles it instead.
+    pub fn emit_to(self, callback: &Callback<Self>) -> bool {
+        let prevented = self.prevented.clone();
+        callback.emit(self);
+        prevented.get()
+    }
 }
 
 /// Click event detail matching React implementation
@@ -122,6 +149,31 @@ impl From<&KeyboardEvent> for KeyDetail {
     }
 }
 
+/// Whether a click landed on an interactive element inside the element
+/// handling it, which handles the click itself
+///
+/// Matches [`INTERACTIVE_SELECTOR`], and `extra` for elements specific to the
+/// container.
+pub(crate) fn is_interactive_target(event: &MouseEvent, extra: Option<&str>) -> bool {
+    let target = event.target().and_then(|t| t.dyn_into::<Element>().ok());
+    let container = event
+        .current_target()
+        .and_then(|t| t.dyn_into::<Element>().ok());
+    let (Some(target), Some(container)) = (target, container) else {
+        return false;
+    };
+
+    std::iter::once(INTERACTIVE_SELECTOR)
+        .chain(extra)
+        .any(|selector| {
+            target
+                .closest(selector)
+                .ok()
+                .flatten()
+                .is_some_and(|hit| hit != container && container.contains(Some(&hit)))
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -129,17 +181,38 @@ mod tests {
     #[test]
     fn test_custom_event_prevent_default() {
         let mut event = CustomEvent::new(ClickDetail::default());
-        assert!(!event.default_prevented);
+        assert!(!event.default_prevented());
 
         event.prevent_default();
-        assert!(event.default_prevented);
+        assert!(event.default_prevented());
     }
 
     #[test]
     fn test_non_cancelable_event() {
         let mut event = CustomEvent::new_non_cancelable(ClickDetail::default());
         event.prevent_default();
-        assert!(!event.default_prevented); // Should not be prevented
+        assert!(!event.default_prevented()); // Should not be prevented
+    }
+
+    #[test]
+    fn test_prevent_default_shared_between_clones() {
+        let event = CustomEvent::new(ClickDetail::default());
+        let mut emitted = event.clone();
+        emitted.prevent_default();
+
+        assert!(event.default_prevented());
+    }
+
+    #[test]
+    fn test_emit_to_observes_consumer_prevent_default() {
+        let consumer = Callback::from(|mut event: ClickEvent| event.prevent_default());
+        assert!(ClickEvent::new(ClickDetail::default()).emit_to(&consumer));
+
+        let observer = Callback::from(|_: ClickEvent| ());
+        assert!(!ClickEvent::new(ClickDetail::default()).emit_to(&observer));
+
+        let non_cancelable = CustomEvent::new_non_cancelable(ClickDetail::default());
+        assert!(!non_cancelable.emit_to(&consumer));
     }
 
     #[test]
@@ -0,0 +1,68 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Visually hidden live region for screen reader announcements
+//!
+//! Updates are debounced, so a burst of changes is announced once with the
+//! latest message.
+
+use gloo_timers::callback::Timeout;
+use yew::prelude::*;
+
+/// Inline style hiding content visually while keeping it in the
+/// accessibility tree
+pub(crate) const VISUALLY_HIDDEN_STYLE: &str = "position: absolute; width: 1px; height: 1px; \
+     margin: -1px; padding: 0; overflow: hidden; clip: rect(0 0 0 0); \
+     white-space: nowrap; border: 0";
+
+/// Default delay before an updated message is announced, in milliseconds
+pub const DEFAULT_LIVE_REGION_DELAY_MS: u32 = 500;
+
+/// Properties for the LiveRegion primitive
+#[derive(Properties, PartialEq, Clone)]
+pub struct LiveRegionProps {
+    /// Message to announce; an empty message announces nothing
+    pub message: String,
+
+    /// Delay before an updated message is announced, in milliseconds
+    #[prop_or(DEFAULT_LIVE_REGION_DELAY_MS)]
+    pub delay_ms: u32,
+
+    /// Whether to interrupt the screen reader (`aria-live="assertive"`)
+    /// rather than waiting for it to be idle
+    #[prop_or_default]
+    pub assertive: bool,
+}
+
+/// Visually hidden region announcing its message to screen readers
+///
+/// The initial message is rendered immediately and not announced; later
+/// changes are announced after `delay_ms` without further changes.
+#[function_component(LiveRegion)]
+pub fn live_region(props: &LiveRegionProps) -> Html {
+    let announced = use_state(|| props.message.clone());
+
+    {
+        let announced = announced.clone();
+        use_effect_with(
+            (props.message.clone(), props.delay_ms),
+            move |(message, delay_ms)| {
+                let message = message.clone();
+                let timeout = (*announced != message)
+                    .then(|| Timeout::new(*delay_ms, move || announced.set(message)));
+                move || drop(timeout)
+            },
+        );
+    }
+
+    html! {
+        <span
+            class="awsui-live-region"
+            style={VISUALLY_HIDDEN_STYLE}
+            aria-live={if props.assertive { "assertive" } else { "polite" }}
+            aria-atomic="true"
+        >
+            { (*announced).clone() }
+        </span>
+    }
+}
@@ -0,0 +1,105 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Keyboard navigation and dismissal shared by the menus of ButtonDropdown
+//! and the TopNavigation menu-dropdown utilities
+//!
+//! A menu lists its focusable entries (enabled items and the headers of
+//! expandable groups) in order and marks each with `data-menu-index`. The
+//! arrow keys move a roving focus between them, wrapping around; disabled
+//! items are left out of the list, so they are skipped.
+
+use gloo::events::EventListener;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement, Node};
+use yew::prelude::*;
+
+/// Gets the entry a navigation key moves the focus to, wrapping around, or
+/// `None` for other keys
+pub(crate) fn next_menu_entry(current: Option<usize>, key: &str, count: usize) -> Option<usize> {
+    if count == 0 {
+        return None;
+    }
+    match key {
+        "ArrowDown" => Some(current.map_or(0, |index| (index + 1) % count)),
+        "ArrowUp" => Some(current.map_or(count - 1, |index| (index + count - 1) % count)),
+        "Home" => Some(0),
+        "End" => Some(count - 1),
+        _ => None,
+    }
+}
+
+/// Expands a collapsed group, or collapses an expanded one
+pub(crate) fn toggle_expanded<K: Clone + PartialEq>(expanded: &[K], key: K) -> Vec<K> {
+    if expanded.contains(&key) {
+        expanded.iter().filter(|k| **k != key).cloned().collect()
+    } else {
+        let mut expanded = expanded.to_vec();
+        expanded.push(key);
+        expanded
+    }
+}
+
+/// Focuses the entry of a menu with the given `data-menu-index`
+pub(crate) fn focus_menu_entry(menu: &NodeRef, index: usize) {
+    if let Some(menu) = menu.cast::<Element>()
+        && let Ok(Some(entry)) = menu.query_selector(&format!("[data-menu-index=\"{}\"]", index))
+        && let Ok(entry) = entry.dyn_into::<HtmlElement>()
+    {
+        let _ = entry.focus();
+    }
+}
+
+/// Closes an open menu when the user clicks outside of `root`
+///
+/// `close` receives whether focus should return to the trigger: it does
+/// unless the click moved focus to another control.
+#[hook]
+pub(crate) fn use_menu_dismiss(open: bool, root: NodeRef, close: Callback<bool>) {
+    use_effect_with(open, move |open| {
+        let listener = open.then(|| {
+            let document = gloo::utils::document();
+            EventListener::new(&document.clone(), "click", move |e| {
+                if let Some(target) = e.target()
+                    && let Some(element) = root.cast::<Element>()
+                    && let Some(target_node) = target.dyn_ref::<Node>()
+                    && !element.contains(Some(target_node))
+                {
+                    let focus_lost = document.active_element().is_none_or(|active| {
+                        active.tag_name() == "BODY" || element.contains(Some(&active))
+                    });
+                    close.emit(focus_lost);
+                }
+            })
+        });
+
+        move || drop(listener)
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_menu_entry_wraps() {
+        assert_eq!(next_menu_entry(None, "ArrowDown", 3), Some(0));
+        assert_eq!(next_menu_entry(None, "ArrowUp", 3), Some(2));
+        assert_eq!(next_menu_entry(Some(2), "ArrowDown", 3), Some(0));
+        assert_eq!(next_menu_entry(Some(0), "ArrowUp", 3), Some(2));
+        assert_eq!(next_menu_entry(Some(1), "Home", 3), Some(0));
+        assert_eq!(next_menu_entry(Some(1), "End", 3), Some(2));
+        assert_eq!(next_menu_entry(Some(1), "Enter", 3), None);
+        assert_eq!(next_menu_entry(None, "ArrowDown", 0), None);
+    }
+
+    #[test]
+    fn test_toggle_expanded() {
+        assert_eq!(toggle_expanded(&[], 1), vec![1]);
+        assert_eq!(toggle_expanded(&[0, 1], 1), vec![0]);
+        assert_eq!(
+            toggle_expanded(&["account".to_string()], "account".to_string()),
+            Vec::<String>::new()
+        );
+    }
+}
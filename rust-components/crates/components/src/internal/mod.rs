@@ -6,15 +6,43 @@
 pub mod accessibility;
 pub mod analytics;
 pub mod base_component;
+pub mod busy;
 pub mod classes;
+pub mod date_time;
+pub mod deep_link;
+pub mod dev_warnings;
+pub mod dropdown;
 pub mod events;
+pub mod live_region;
+pub mod menu;
+pub mod motion;
+pub mod option_group;
+pub mod option_list;
+pub mod portal;
 pub mod props;
+pub mod resize;
 pub mod styles;
+pub mod tooltip;
+pub mod truncated_text;
+pub mod virtual_list;
 
 pub use accessibility::{AriaAttributes, FocusOptions};
 pub use analytics::AnalyticsMetadata;
 pub use base_component::{BaseComponentProps, ComponentMetadata};
+pub use busy::{BusyAnnouncer, BusyContext, use_is_app_busy};
 pub use classes::{ClassBuilder, classes};
+pub use date_time::DateGranularity;
+pub use deep_link::AnchorLink;
 pub use events::{ClickDetail, ClickEvent, CustomEvent, FollowEvent};
-pub use props::{I18nStrings, NativeAttributes, StyleOverride};
+pub use live_region::LiveRegion;
+pub use option_group::OptionGroup;
+pub use option_list::{DropdownStatus, LoadItemsDetail};
+pub use portal::{Portal, PortalContext, PortalLayer};
+pub use props::{
+    I18nStrings, NativeAttributes, StyleOverride, apply_data_attributes, apply_root_attributes,
+};
+pub use resize::{Breakpoint, use_container_breakpoint, use_element_size};
 pub use styles::ComponentStyles;
+pub use tooltip::Tooltip;
+pub use truncated_text::TruncatedText;
+pub use virtual_list::VirtualScrollConfig;
@@ -0,0 +1,46 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Motion preferences
+//!
+//! Animations are skipped when the user prefers reduced motion or when the
+//! element sits inside the `Mode::MotionDisabled` scope.
+
+use cloudscape_design_tokens::Mode;
+use web_sys::Element;
+
+/// Whether the user asked the system to minimize non-essential motion
+pub(crate) fn prefers_reduced_motion() -> bool {
+    web_sys::window()
+        .and_then(|window| {
+            window
+                .match_media("(prefers-reduced-motion: reduce)")
+                .ok()
+                .flatten()
+        })
+        .is_some_and(|query| query.matches())
+}
+
+/// Whether animations should be skipped for an element
+///
+/// Without an element, only the document root and body are checked for the
+/// motion-disabled scope.
+pub(crate) fn motion_disabled(element: Option<&Element>) -> bool {
+    let selector = format!(".{}", Mode::MotionDisabled.css_class());
+    let in_disabled_scope = match element {
+        Some(element) => element.closest(&selector).ok().flatten().is_some(),
+        None => {
+            let document = gloo::utils::document();
+            let has_class = |element: Option<Element>| {
+                element.is_some_and(|element| {
+                    element
+                        .class_name()
+                        .split_whitespace()
+                        .any(|class| class == Mode::MotionDisabled.css_class())
+                })
+            };
+            has_class(document.document_element()) || has_class(document.body().map(Element::from))
+        }
+    };
+    in_disabled_scope || prefers_reduced_motion()
+}
@@ -0,0 +1,345 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Option groups shared by Select and Multiselect
+//!
+//! Ungrouped options and groups are flattened into the rows of the option
+//! list: a header row per group followed by the group's options. Keyboard
+//! navigation works on the options alone, so headers are skipped; options
+//! of a disabled group are disabled. A filter keeps a group while any of its
+//! options matches, and hides it otherwise.
+
+use crate::internal::ClassBuilder;
+use std::ops::Range;
+use yew::prelude::*;
+
+/// Color of group header labels
+const GROUP_LABEL_STYLE: &str = "color: var(--awsui-color-text-dropdown-group-label)";
+
+/// Options listed under a common label
+#[derive(Clone, PartialEq, Debug)]
+pub struct OptionGroup<T> {
+    /// Label shown in the group header
+    pub label: String,
+    /// Whether all options of the group are disabled
+    pub disabled: bool,
+    /// Options of the group
+    pub options: Vec<T>,
+}
+
+impl<T> OptionGroup<T> {
+    /// Creates an empty group with the given label
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            disabled: false,
+            options: Vec::new(),
+        }
+    }
+
+    /// Sets whether all options of the group are disabled
+    pub fn with_disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Sets the options of the group
+    pub fn with_options(mut self, options: Vec<T>) -> Self {
+        self.options = options;
+        self
+    }
+}
+
+/// A row of an option list
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum OptionRow {
+    /// Header of the group at `group`, followed by its `options`
+    Header {
+        group: usize,
+        label: String,
+        disabled: bool,
+        options: Range<usize>,
+    },
+    /// The option at `index` of [`GroupedOptions::options`]
+    Option { index: usize, group: Option<usize> },
+}
+
+impl OptionRow {
+    fn group(&self) -> Option<usize> {
+        match self {
+            OptionRow::Header { group, .. } => Some(*group),
+            OptionRow::Option { group, .. } => *group,
+        }
+    }
+}
+
+/// Options and groups flattened into the rows of an option list
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct GroupedOptions<T> {
+    /// Rows in display order
+    pub rows: Vec<OptionRow>,
+    /// Options in display order; options of disabled groups are disabled
+    pub options: Vec<T>,
+    /// Row of each option
+    option_rows: Vec<usize>,
+}
+
+impl<T: Clone> GroupedOptions<T> {
+    /// Flattens ungrouped `options` followed by `groups`, keeping the
+    /// options for which `keep` holds
+    ///
+    /// `disable` disables an option of a disabled group.
+    pub(crate) fn new(
+        options: &[T],
+        groups: &[OptionGroup<T>],
+        keep: impl Fn(&T) -> bool,
+        disable: impl Fn(&mut T),
+    ) -> Self {
+        let mut grouped = Self {
+            rows: Vec::new(),
+            options: Vec::new(),
+            option_rows: Vec::new(),
+        };
+
+        for option in options.iter().filter(|option| keep(option)) {
+            grouped.push_option(option.clone(), None);
+        }
+
+        for (group_index, group) in groups.iter().enumerate() {
+            let kept: Vec<&T> = group.options.iter().filter(|option| keep(option)).collect();
+            if kept.is_empty() {
+                continue;
+            }
+
+            let start = grouped.options.len();
+            grouped.rows.push(OptionRow::Header {
+                group: group_index,
+                label: group.label.clone(),
+                disabled: group.disabled,
+                options: start..start + kept.len(),
+            });
+            for option in kept {
+                let mut option = option.clone();
+                if group.disabled {
+                    disable(&mut option);
+                }
+                grouped.push_option(option, Some(group_index));
+            }
+        }
+
+        grouped
+    }
+
+    fn push_option(&mut self, option: T, group: Option<usize>) {
+        self.option_rows.push(self.rows.len());
+        self.rows.push(OptionRow::Option {
+            index: self.options.len(),
+            group,
+        });
+        self.options.push(option);
+    }
+}
+
+impl<T> GroupedOptions<T> {
+    /// Gets the row showing the option at `index`
+    pub(crate) fn row_of(&self, index: usize) -> Option<usize> {
+        self.option_rows.get(index).copied()
+    }
+
+    /// Renders the rows in `window`
+    ///
+    /// The rows of a group are wrapped in a `role="group"` element labelled
+    /// by the group header; when the header is scrolled out of a virtual
+    /// list, the group is labelled with its text instead.
+    pub(crate) fn render_rows(
+        &self,
+        window: Range<usize>,
+        id_prefix: &str,
+        render_header: impl Fn(&OptionRow, String) -> Html,
+        render_option: impl Fn(usize, &T) -> Html,
+    ) -> Html {
+        let rows = &self.rows[window.start.min(self.rows.len())..window.end.min(self.rows.len())];
+        let mut html = Vec::new();
+        let mut start = 0;
+        while start < rows.len() {
+            let group = rows[start].group();
+            let end = rows[start..]
+                .iter()
+                .position(|row| row.group() != group)
+                .map_or(rows.len(), |offset| start + offset);
+
+            let rendered = rows[start..end].iter().map(|row| match row {
+                OptionRow::Header { group, .. } => {
+                    render_header(row, group_header_id(id_prefix, *group))
+                }
+                OptionRow::Option { index, .. } => render_option(*index, &self.options[*index]),
+            });
+
+            match group {
+                None => html.extend(rendered),
+                Some(group) => {
+                    let header_rendered = matches!(rows[start], OptionRow::Header { .. });
+                    let label = self.rows.iter().find_map(|row| match row {
+                        OptionRow::Header {
+                            group: header_group,
+                            label,
+                            ..
+                        } if *header_group == group => Some(label.clone()),
+                        _ => None,
+                    });
+                    html.push(html! {
+                        <li key={format!("group-{}", group)} class="awsui-option-group" role="presentation">
+                            <ul
+                                class="awsui-option-group-options"
+                                role="group"
+                                aria-labelledby={header_rendered.then(|| group_header_id(id_prefix, group))}
+                                aria-label={if header_rendered { None } else { label }}
+                            >
+                                { for rendered }
+                            </ul>
+                        </li>
+                    });
+                }
+            }
+            start = end;
+        }
+        html.into_iter().collect()
+    }
+}
+
+/// Gets the id of the option at `index`, referenced by the combobox's
+/// `aria-activedescendant`
+pub(crate) fn option_id(id_prefix: &str, index: usize) -> String {
+    format!("{}-option-{}", id_prefix, index)
+}
+
+/// Gets the id of a group header
+fn group_header_id(id_prefix: &str, group: usize) -> String {
+    format!("{}-group-{}", id_prefix, group)
+}
+
+/// Renders the label of a group header
+///
+/// The header is not an option, so it is presentational and only labels its
+/// group.
+pub(crate) fn render_group_header(
+    row: &OptionRow,
+    id: String,
+    class_prefix: &str,
+    onclick: Option<Callback<MouseEvent>>,
+) -> Html {
+    let OptionRow::Header {
+        label, disabled, ..
+    } = row
+    else {
+        return html! {};
+    };
+
+    let classes = ClassBuilder::new()
+        .add(format!("{}-group-label", class_prefix))
+        .add_if(*disabled, format!("{}-group-label-disabled", class_prefix));
+
+    html! {
+        <li
+            id={id}
+            class={classes.build()}
+            style={GROUP_LABEL_STYLE}
+            role="presentation"
+            onclick={onclick}
+        >
+            { label.clone() }
+        </li>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct TestOption {
+        value: &'static str,
+        disabled: bool,
+    }
+
+    fn option(value: &'static str) -> TestOption {
+        TestOption {
+            value,
+            disabled: false,
+        }
+    }
+
+    fn grouped(filter: &str) -> GroupedOptions<TestOption> {
+        let groups = vec![
+            OptionGroup::new("US East")
+                .with_options(vec![option("us-east-1"), option("us-east-2")]),
+            OptionGroup::new("US West")
+                .with_disabled(true)
+                .with_options(vec![option("us-west-1")]),
+        ];
+        GroupedOptions::new(
+            &[option("global")],
+            &groups,
+            |option| option.value.contains(filter),
+            |option| option.disabled = true,
+        )
+    }
+
+    #[test]
+    fn test_rows_follow_groups() {
+        let groups = grouped("");
+        assert_eq!(groups.rows.len(), 6);
+        assert_eq!(
+            groups.rows[1],
+            OptionRow::Header {
+                group: 0,
+                label: "US East".to_string(),
+                disabled: false,
+                options: 1..3,
+            }
+        );
+        assert_eq!(
+            groups.rows[5],
+            OptionRow::Option {
+                index: 3,
+                group: Some(1)
+            }
+        );
+        // Headers are rows but not options
+        assert_eq!(groups.options.len(), 4);
+        assert_eq!(groups.row_of(0), Some(0));
+        assert_eq!(groups.row_of(1), Some(2));
+        assert_eq!(groups.row_of(3), Some(5));
+        assert_eq!(groups.row_of(4), None);
+    }
+
+    #[test]
+    fn test_disabled_group_disables_options() {
+        let groups = grouped("");
+        assert!(!groups.options[1].disabled);
+        assert!(groups.options[3].disabled);
+    }
+
+    #[test]
+    fn test_filter_hides_groups_without_matches() {
+        let groups = grouped("east-2");
+        assert_eq!(
+            groups.rows,
+            vec![
+                OptionRow::Header {
+                    group: 0,
+                    label: "US East".to_string(),
+                    disabled: false,
+                    options: 0..1,
+                },
+                OptionRow::Option {
+                    index: 0,
+                    group: Some(0)
+                },
+            ]
+        );
+        assert_eq!(groups.options, vec![option("us-east-2")]);
+
+        assert!(grouped("eu-").rows.is_empty());
+    }
+}
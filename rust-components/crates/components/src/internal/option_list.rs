@@ -0,0 +1,651 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Non-result states shared by the option lists of Autosuggest, Select and
+//! Multiselect
+//!
+//! When a dropdown has no options to show, a state row explains why: the
+//! component has no options at all, or the filter matched none of them. State
+//! rows are not options, so keyboard navigation never lands on them, and they
+//! look the same in every dropdown.
+//!
+//! Options may also be loaded by the application page by page. The
+//! component asks for pages with `on_load_items` and shows the
+//! [`DropdownStatus`] of the loading in a footer row below the options.
+
+use crate::i18n::I18nContext;
+use crate::internal::{ClassBuilder, CustomEvent};
+use crate::spinner::Spinner;
+use gloo_timers::callback::Timeout;
+use web_sys::Element;
+use yew::prelude::*;
+
+/// Pause in typing after which a changed filter loads its first page, in
+/// milliseconds
+const LOAD_ITEMS_DEBOUNCE_MS: u32 = 200;
+
+/// Distance from the end of the list within which scrolling loads the next
+/// page, in pixels
+const LOAD_MORE_THRESHOLD_PX: f64 = 80.0;
+
+/// Loading state of options fetched by the application
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DropdownStatus {
+    /// More options can be loaded; scrolling to the end asks for them
+    Pending,
+    /// Options are being loaded
+    Loading,
+    /// All options were loaded
+    Finished,
+    /// Loading failed; the footer offers a retry
+    Error,
+}
+
+/// Detail of a request to load options
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoadItemsDetail {
+    /// The text the options are filtered by
+    pub filtering_text: String,
+    /// Whether the options should replace the current ones, because the
+    /// dropdown opened or the filtering text changed
+    pub first_page: bool,
+    /// Whether the page that failed to load is requested again
+    pub same_page: bool,
+}
+
+/// Why an option list shows no options
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ListState {
+    /// The component has no options
+    Empty,
+    /// The filter matched none of the options
+    NoMatch {
+        /// The text the options were filtered by
+        filtering_text: String,
+    },
+}
+
+impl ListState {
+    /// Gets the state of a list showing `visible_count` options for
+    /// `filtering_text`, or `None` when options show
+    ///
+    /// An empty list is a no-match while there is filtering text, whether the
+    /// component or the application filtered the options.
+    pub(crate) fn of(visible_count: usize, filtering_text: &str) -> Option<Self> {
+        match (visible_count, filtering_text) {
+            (0, "") => Some(ListState::Empty),
+            (0, _) => Some(ListState::NoMatch {
+                filtering_text: filtering_text.to_string(),
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Default texts of the state rows after applying the fallback chain
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ListStateStrings {
+    /// Text shown when no option matches, with `{filtering_text}` replaced
+    pub no_match_text: String,
+    /// Label of the action clearing the filter
+    pub clear_filter_label: String,
+    /// Text shown when there are no options
+    pub empty_text: String,
+    /// Text shown while options load
+    pub loading_text: String,
+    /// Text shown when loading options failed
+    pub error_text: String,
+    /// Label of the action retrying a failed load
+    pub recovery_text: String,
+    /// Text shown below the options once all were loaded
+    pub finished_text: String,
+    /// Announced state of a selected option
+    pub selected_text: String,
+    /// Announced position of the highlighted option, with `{position}` and
+    /// `{total}` replaced
+    pub position_text: String,
+}
+
+impl ListStateStrings {
+    /// Resolves the texts from the I18nProvider (keys under `"<component>."`),
+    /// then English
+    pub(crate) fn resolve(i18n: &I18nContext, component: &str) -> Self {
+        let resolve =
+            |key: &str, default: &str| i18n.message(component, key).unwrap_or(default).to_string();
+
+        Self {
+            no_match_text: resolve("no_match_text", "No matches for '{filtering_text}'"),
+            clear_filter_label: resolve("clear_filter_label", "Clear filter"),
+            empty_text: resolve("empty_text", "No options"),
+            loading_text: resolve("loading_text", "Loading"),
+            error_text: resolve("error_text", "We couldn't load the options"),
+            recovery_text: resolve("recovery_text", "Retry"),
+            finished_text: resolve("finished_text", "End of results"),
+            selected_text: resolve("selected_text", "Selected"),
+            position_text: resolve("position_text", "{position} of {total}"),
+        }
+    }
+
+    /// Gets the text describing a state, which is also announced
+    pub(crate) fn text(&self, state: &ListState) -> String {
+        match state {
+            ListState::Empty => self.empty_text.clone(),
+            ListState::NoMatch { filtering_text } => self
+                .no_match_text
+                .replace("{filtering_text}", filtering_text),
+        }
+    }
+}
+
+/// Gets the message announcing the state of a list, empty while options show
+pub(crate) fn list_state_announcement(
+    state: Option<&ListState>,
+    strings: &ListStateStrings,
+) -> String {
+    state.map(|state| strings.text(state)).unwrap_or_default()
+}
+
+/// Gets the message announcing an option, such as "Oregon, Selected, 2 of 5"
+///
+/// `position` is the 1-based position of a highlighted option among `total`
+/// options; it is left out when announcing the value chosen on close.
+pub(crate) fn option_announcement(
+    label: &str,
+    selected: bool,
+    position: Option<(usize, usize)>,
+    strings: &ListStateStrings,
+) -> String {
+    let mut parts = vec![label.to_string()];
+    if selected {
+        parts.push(strings.selected_text.clone());
+    }
+    if let Some((position, total)) = position {
+        parts.push(
+            strings
+                .position_text
+                .replace("{position}", &position.to_string())
+                .replace("{total}", &total.to_string()),
+        );
+    }
+    parts.join(", ")
+}
+
+/// Whether the footer shows a loading status
+///
+/// More pending options show nothing. The end of results only shows below
+/// options; an empty finished list shows its state row instead.
+pub(crate) fn load_status_visible(status: Option<DropdownStatus>, has_options: bool) -> bool {
+    match status {
+        Some(DropdownStatus::Loading | DropdownStatus::Error) => true,
+        Some(DropdownStatus::Finished) => has_options,
+        Some(DropdownStatus::Pending) | None => false,
+    }
+}
+
+/// Gets the state row of a list whose options load with `status`
+///
+/// While options load or failed to load, the footer explains the missing
+/// options instead of a state row.
+pub(crate) fn loaded_list_state(
+    state: Option<ListState>,
+    status: Option<DropdownStatus>,
+) -> Option<ListState> {
+    state.filter(|_| {
+        !matches!(
+            status,
+            Some(DropdownStatus::Loading | DropdownStatus::Error)
+        )
+    })
+}
+
+/// Gets the message announcing a list: its state row, else its loading
+/// status
+pub(crate) fn list_announcement(
+    state: Option<&ListState>,
+    status: Option<DropdownStatus>,
+    has_options: bool,
+    strings: &ListStateStrings,
+) -> String {
+    if state.is_some() || !load_status_visible(status, has_options) {
+        return list_state_announcement(state, strings);
+    }
+    match status {
+        Some(DropdownStatus::Loading) => strings.loading_text.clone(),
+        Some(DropdownStatus::Error) => strings.error_text.clone(),
+        Some(DropdownStatus::Finished) => strings.finished_text.clone(),
+        _ => String::new(),
+    }
+}
+
+/// Whether a scroll position is close enough to the end of a list to load
+/// the next page
+fn near_end(scroll_top: f64, client_height: f64, scroll_height: f64) -> bool {
+    scroll_top + client_height >= scroll_height - LOAD_MORE_THRESHOLD_PX
+}
+
+/// Handlers of a list loading its options, returned by [`use_load_items`]
+pub(crate) struct LoadItems {
+    /// Scroll handler of the scrolling list, loading the next page near its end
+    pub on_scroll: Callback<Event>,
+    /// Requests the page that failed to load again
+    pub on_retry: Callback<()>,
+}
+
+/// Asks the application for pages of options
+///
+/// The first page loads when the dropdown opens and, debounced, when the
+/// filtering text changes while it is open. The next page loads when the
+/// scrolling `list` is near its end while more options are pending, either
+/// after scrolling or after a render, so that a page too short to fill the
+/// dropdown still loads the next one. Until the status or the number of
+/// loaded options changes, it is requested only once.
+#[hook]
+pub(crate) fn use_load_items(
+    open: bool,
+    filtering_text: String,
+    status: Option<DropdownStatus>,
+    option_count: usize,
+    list: NodeRef,
+    on_load_items: Option<Callback<CustomEvent<LoadItemsDetail>>>,
+) -> LoadItems {
+    let debounce = use_mut_ref(|| None::<Timeout>);
+    let previous = use_mut_ref(|| (false, String::new()));
+    let page_requested = use_mut_ref(|| false);
+
+    let load = {
+        let on_load_items = on_load_items.clone();
+        Callback::from(move |detail: LoadItemsDetail| {
+            if let Some(callback) = &on_load_items {
+                callback.emit(CustomEvent::new_non_cancelable(detail));
+            }
+        })
+    };
+
+    // The first page stands in for the next one until the status or the
+    // loaded options change
+    {
+        let load = load.clone().reform({
+            let page_requested = page_requested.clone();
+            move |detail| {
+                *page_requested.borrow_mut() = true;
+                detail
+            }
+        });
+        let debounce = debounce.clone();
+        use_effect_with(
+            (open, filtering_text.clone()),
+            move |(open, filtering_text)| {
+                let (was_open, previous_text) = previous.replace((*open, filtering_text.clone()));
+                let first_page = LoadItemsDetail {
+                    filtering_text: filtering_text.clone(),
+                    first_page: true,
+                    same_page: false,
+                };
+                if !*open {
+                    debounce.borrow_mut().take();
+                } else if !was_open {
+                    debounce.borrow_mut().take();
+                    load.emit(first_page);
+                } else if previous_text != *filtering_text {
+                    *debounce.borrow_mut() =
+                        Some(Timeout::new(LOAD_ITEMS_DEBOUNCE_MS, move || {
+                            load.emit(first_page)
+                        }));
+                }
+            },
+        );
+    }
+
+    // Cancel a pending request on unmount
+    {
+        let debounce = debounce.clone();
+        use_effect_with((), move |_| move || drop(debounce.borrow_mut().take()));
+    }
+
+    {
+        let page_requested = page_requested.clone();
+        use_effect_with((status, option_count), move |_| {
+            *page_requested.borrow_mut() = false
+        });
+    }
+
+    // Requests the next page once the list is scrolled near its end
+    let load_next_page = {
+        let load = load.clone();
+        let filtering_text = filtering_text.clone();
+        let page_requested = page_requested.clone();
+        Callback::from(move |list: Element| {
+            if status == Some(DropdownStatus::Pending)
+                && !*page_requested.borrow()
+                && near_end(
+                    f64::from(list.scroll_top()),
+                    f64::from(list.client_height()),
+                    f64::from(list.scroll_height()),
+                )
+            {
+                *page_requested.borrow_mut() = true;
+                load.emit(LoadItemsDetail {
+                    filtering_text: filtering_text.clone(),
+                    first_page: false,
+                    same_page: false,
+                });
+            }
+        })
+    };
+
+    // A list that doesn't fill the dropdown never scrolls, so check after
+    // each render as well
+    {
+        let load_next_page = load_next_page.clone();
+        use_effect(move || {
+            if open && let Some(list) = list.cast::<Element>() {
+                load_next_page.emit(list);
+            }
+        });
+    }
+
+    let on_scroll = Callback::from(move |e: Event| {
+        if let Some(list) = e.target_dyn_into::<Element>() {
+            load_next_page.emit(list);
+        }
+    });
+
+    let on_retry = Callback::from(move |()| {
+        load.emit(LoadItemsDetail {
+            filtering_text: filtering_text.clone(),
+            first_page: false,
+            same_page: true,
+        })
+    });
+
+    LoadItems {
+        on_scroll,
+        on_retry,
+    }
+}
+
+/// Renders the footer showing the loading status of a list
+pub(crate) fn render_load_status(
+    status: Option<DropdownStatus>,
+    has_options: bool,
+    strings: &ListStateStrings,
+    on_retry: Callback<()>,
+) -> Html {
+    let Some(status) = status.filter(|status| load_status_visible(Some(*status), has_options))
+    else {
+        return html! {};
+    };
+
+    let classes = ClassBuilder::new()
+        .add("awsui-option-list-status")
+        .add_if(
+            status == DropdownStatus::Loading,
+            "awsui-option-list-status-loading",
+        )
+        .add_if(
+            status == DropdownStatus::Error,
+            "awsui-option-list-status-error",
+        )
+        .add_if(
+            status == DropdownStatus::Finished,
+            "awsui-option-list-status-finished",
+        );
+
+    let content = match status {
+        DropdownStatus::Loading => html! {
+            <>
+                <span class="awsui-option-list-status-icon" aria-hidden="true">
+                    <Spinner />
+                </span>
+                { strings.loading_text.clone() }
+            </>
+        },
+        DropdownStatus::Error => html! {
+            <>
+                <span
+                    class="awsui-option-list-status-icon awsui-icon awsui-icon-status-warning"
+                    aria-hidden="true"
+                />
+                <span class="awsui-option-list-state-text">{ strings.error_text.clone() }</span>
+                <button
+                    type="button"
+                    class="awsui-option-list-retry"
+                    tabindex="-1"
+                    onmousedown={Callback::from(|e: MouseEvent| e.prevent_default())}
+                    onclick={on_retry.reform(|_: MouseEvent| ())}
+                >
+                    { strings.recovery_text.clone() }
+                </button>
+            </>
+        },
+        DropdownStatus::Finished => html! { { strings.finished_text.clone() } },
+        DropdownStatus::Pending => html! {},
+    };
+
+    html! {
+        <div class={classes.build()} role="presentation">
+            { content }
+        </div>
+    }
+}
+
+/// Content of the state rows and the action clearing the filter
+pub(crate) struct ListStateSlots {
+    /// Replaces the default no-match row
+    pub no_match_content: Option<Html>,
+    /// Replaces the default empty row
+    pub empty_content: Option<Html>,
+    /// Clears the filter; the default no-match row offers it when set
+    pub on_clear_filter: Option<Callback<()>>,
+}
+
+/// Renders the row explaining why a list shows no options
+///
+/// The clear-filter action can't take focus from the filter input, so focus
+/// and the dropdown's keyboard handling (including Escape) stay where they
+/// are.
+pub(crate) fn render_list_state(
+    state: &ListState,
+    strings: &ListStateStrings,
+    slots: ListStateSlots,
+) -> Html {
+    let classes = ClassBuilder::new()
+        .add("awsui-option-list-state")
+        .add_if(*state == ListState::Empty, "awsui-option-list-state-empty")
+        .add_if(
+            matches!(state, ListState::NoMatch { .. }),
+            "awsui-option-list-state-no-match",
+        );
+
+    let content = match (state, slots.empty_content, slots.no_match_content) {
+        (ListState::Empty, Some(content), _) | (ListState::NoMatch { .. }, _, Some(content)) => {
+            content
+        }
+        (ListState::Empty, None, _) => html! { { strings.empty_text.clone() } },
+        (ListState::NoMatch { .. }, _, None) => html! {
+            <>
+                <span class="awsui-option-list-state-text">{ strings.text(state) }</span>
+                if let Some(on_clear_filter) = slots.on_clear_filter {
+                    <button
+                        type="button"
+                        class="awsui-option-list-clear-filter"
+                        tabindex="-1"
+                        onmousedown={Callback::from(|e: MouseEvent| e.prevent_default())}
+                        onclick={on_clear_filter.reform(|_: MouseEvent| ())}
+                    >
+                        { strings.clear_filter_label.clone() }
+                    </button>
+                }
+            </>
+        },
+    };
+
+    html! {
+        <div class={classes.build()} role="presentation">
+            { content }
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::internal::I18nStrings;
+
+    #[test]
+    fn test_list_state_of() {
+        assert_eq!(ListState::of(0, ""), Some(ListState::Empty));
+        assert_eq!(
+            ListState::of(0, "prod"),
+            Some(ListState::NoMatch {
+                filtering_text: "prod".to_string()
+            })
+        );
+        assert_eq!(ListState::of(1, "prod"), None);
+        assert_eq!(ListState::of(3, ""), None);
+    }
+
+    #[test]
+    fn test_strings_fall_back_to_english() {
+        let strings = ListStateStrings::resolve(&I18nContext::default(), "select");
+        assert_eq!(strings.clear_filter_label, "Clear filter");
+        assert_eq!(
+            list_state_announcement(
+                Some(&ListState::NoMatch {
+                    filtering_text: "prod".to_string()
+                }),
+                &strings
+            ),
+            "No matches for 'prod'"
+        );
+        assert_eq!(
+            list_state_announcement(Some(&ListState::Empty), &strings),
+            "No options"
+        );
+        assert_eq!(list_state_announcement(None, &strings), "");
+    }
+
+    #[test]
+    fn test_option_announcement() {
+        let strings = ListStateStrings::resolve(&I18nContext::default(), "select");
+        assert_eq!(
+            option_announcement("Oregon", true, Some((2, 5)), &strings),
+            "Oregon, Selected, 2 of 5"
+        );
+        assert_eq!(
+            option_announcement("Ohio", false, Some((1, 5)), &strings),
+            "Ohio, 1 of 5"
+        );
+        assert_eq!(
+            option_announcement("Oregon", true, None, &strings),
+            "Oregon, Selected"
+        );
+
+        let mut messages = I18nStrings::new();
+        messages.set("select.position_text", "{position} von {total}");
+        messages.set("select.selected_text", "Ausgewählt");
+        let i18n = I18nContext {
+            locale: None,
+            messages,
+        };
+        let strings = ListStateStrings::resolve(&i18n, "select");
+        assert_eq!(
+            option_announcement("Oregon", true, Some((2, 5)), &strings),
+            "Oregon, Ausgewählt, 2 von 5"
+        );
+    }
+
+    #[test]
+    fn test_load_status_visible() {
+        assert!(load_status_visible(Some(DropdownStatus::Loading), false));
+        assert!(load_status_visible(Some(DropdownStatus::Error), true));
+        assert!(load_status_visible(Some(DropdownStatus::Finished), true));
+        // An empty finished list shows its state row instead
+        assert!(!load_status_visible(Some(DropdownStatus::Finished), false));
+        assert!(!load_status_visible(Some(DropdownStatus::Pending), true));
+        assert!(!load_status_visible(None, true));
+    }
+
+    #[test]
+    fn test_loaded_list_state() {
+        assert_eq!(
+            loaded_list_state(Some(ListState::Empty), Some(DropdownStatus::Loading)),
+            None
+        );
+        assert_eq!(
+            loaded_list_state(Some(ListState::Empty), Some(DropdownStatus::Error)),
+            None
+        );
+        assert_eq!(
+            loaded_list_state(Some(ListState::Empty), Some(DropdownStatus::Finished)),
+            Some(ListState::Empty)
+        );
+        assert_eq!(
+            loaded_list_state(Some(ListState::Empty), None),
+            Some(ListState::Empty)
+        );
+    }
+
+    #[test]
+    fn test_list_announcement() {
+        let strings = ListStateStrings::resolve(&I18nContext::default(), "select");
+        assert_eq!(
+            list_announcement(None, Some(DropdownStatus::Loading), false, &strings),
+            "Loading"
+        );
+        assert_eq!(
+            list_announcement(None, Some(DropdownStatus::Error), true, &strings),
+            "We couldn't load the options"
+        );
+        assert_eq!(
+            list_announcement(None, Some(DropdownStatus::Finished), true, &strings),
+            "End of results"
+        );
+        assert_eq!(
+            list_announcement(None, Some(DropdownStatus::Pending), true, &strings),
+            ""
+        );
+        assert_eq!(
+            list_announcement(
+                Some(&ListState::Empty),
+                Some(DropdownStatus::Finished),
+                false,
+                &strings
+            ),
+            "No options"
+        );
+    }
+
+    #[test]
+    fn test_near_end() {
+        assert!(!near_end(0.0, 300.0, 1000.0));
+        assert!(near_end(650.0, 300.0, 1000.0));
+        assert!(near_end(700.0, 300.0, 1000.0));
+        // A list shorter than its viewport is at its end
+        assert!(near_end(0.0, 300.0, 200.0));
+    }
+
+    #[test]
+    fn test_strings_from_provider() {
+        let mut messages = I18nStrings::new();
+        messages.set(
+            "multiselect.no_match_text",
+            "Keine Treffer für {filtering_text}",
+        );
+        let i18n = I18nContext {
+            locale: None,
+            messages,
+        };
+
+        let strings = ListStateStrings::resolve(&i18n, "multiselect");
+        assert_eq!(
+            strings.text(&ListState::NoMatch {
+                filtering_text: "prod".to_string()
+            }),
+            "Keine Treffer für prod"
+        );
+        // Messages are per component
+        let strings = ListStateStrings::resolve(&i18n, "autosuggest");
+        assert_eq!(strings.no_match_text, "No matches for '{filtering_text}'");
+    }
+}
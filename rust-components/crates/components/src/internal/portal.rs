@@ -0,0 +1,258 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Portal rendering outside the component tree
+//!
+//! A [`Portal`] renders its children into a host `<div data-awsui-portal>`
+//! created on first render and appended to the mount node: the document body,
+//! or the node given by an enclosing [`PortalContext`]. Its class and layer
+//! style are applied by an effect after each render that changes them. The
+//! host is removed when the portal is destroyed, including when its state is
+//! dropped without the effect cleanup running.
+
+use web_sys::Element;
+use yew::prelude::*;
+
+/// Attribute marking portal host elements
+pub const PORTAL_HOST_ATTRIBUTE: &str = "data-awsui-portal";
+
+/// Stacking layers for portal content, from lowest to highest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PortalLayer {
+    /// Dropdowns expanded to the viewport
+    Dropdown,
+    /// Popovers
+    Popover,
+    /// Modal dialogs
+    Modal,
+    /// Tooltips, shown above every other layer
+    Tooltip,
+}
+
+impl PortalLayer {
+    /// Returns the z-index of the layer
+    pub fn z_index(&self) -> u32 {
+        match self {
+            Self::Dropdown => 2000,
+            Self::Popover => 3000,
+            Self::Modal => 5000,
+            Self::Tooltip => 6000,
+        }
+    }
+}
+
+/// Portal configuration shared with a subtree
+///
+/// Apps rendering into a shadow root or a micro-frontend container provide
+/// this to keep portal content inside that container.
+#[derive(Clone, PartialEq, Default)]
+pub struct PortalContext {
+    /// Node that portal hosts are appended to; defaults to the document body
+    pub mount_node: Option<Element>,
+    /// Theme-scope classes stamped on portal hosts, such as `awsui-dark-mode`,
+    /// so portal content keeps the theme of the subtree that opened it
+    pub theme_class: Option<String>,
+}
+
+/// Properties for the Portal component
+#[derive(Properties, PartialEq, Clone)]
+pub struct PortalProps {
+    /// Stacking layer, setting the z-index of the host
+    #[prop_or_default]
+    pub layer: Option<PortalLayer>,
+
+    /// Content rendered into the portal host
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// Builds the class attribute of a portal host
+pub(crate) fn host_class(theme_class: Option<&str>) -> String {
+    match theme_class {
+        Some(theme_class) if !theme_class.is_empty() => format!("awsui-portal {}", theme_class),
+        _ => "awsui-portal".to_string(),
+    }
+}
+
+/// Builds the style attribute of a portal host
+pub(crate) fn host_style(layer: Option<PortalLayer>) -> Option<String> {
+    layer.map(|layer| format!("position: relative; z-index: {}", layer.z_index()))
+}
+
+/// Owns a portal host element and removes it from the DOM when dropped
+struct PortalHost(Option<Element>);
+
+impl PortalHost {
+    fn create() -> Self {
+        let host = gloo::utils::document().create_element("div").ok();
+        if let Some(ref host) = host {
+            let _ = host.set_attribute(PORTAL_HOST_ATTRIBUTE, "");
+        }
+        Self(host)
+    }
+}
+
+impl Drop for PortalHost {
+    fn drop(&mut self) {
+        if let Some(ref host) = self.0 {
+            host.remove();
+        }
+    }
+}
+
+/// Portal component rendering its children into a host under the mount node
+#[function_component(Portal)]
+pub fn portal(props: &PortalProps) -> Html {
+    let context = use_context::<PortalContext>().unwrap_or_default();
+    let host = use_state(PortalHost::create);
+
+    {
+        let host = host.clone();
+        use_effect_with(context.mount_node.clone(), move |mount_node| {
+            let element = host.0.clone();
+            if let Some(ref element) = element {
+                let mount_node = mount_node
+                    .clone()
+                    .or_else(|| gloo::utils::document().body().map(Element::from));
+                if let Some(mount_node) = mount_node {
+                    let _ = mount_node.append_child(element);
+                }
+            }
+            move || {
+                if let Some(element) = element {
+                    element.remove();
+                }
+            }
+        });
+    }
+
+    {
+        let host = host.clone();
+        use_effect_with(
+            (context.theme_class.clone(), props.layer),
+            move |(theme_class, layer)| {
+                let element = host.0.clone();
+                if let Some(ref element) = element {
+                    let _ = element.set_attribute("class", &host_class(theme_class.as_deref()));
+                    if let Some(style) = host_style(*layer) {
+                        let _ = element.set_attribute("style", &style);
+                    }
+                }
+                move || {
+                    if let Some(element) = element {
+                        let _ = element.remove_attribute("class");
+                        let _ = element.remove_attribute("style");
+                    }
+                }
+            },
+        );
+    }
+
+    let Some(ref element) = host.0 else {
+        return html! {};
+    };
+    create_portal(html! { { for props.children.iter() } }, element.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_layer_order() {
+        assert!(PortalLayer::Dropdown.z_index() < PortalLayer::Popover.z_index());
+        assert!(PortalLayer::Popover.z_index() < PortalLayer::Modal.z_index());
+        assert!(PortalLayer::Modal.z_index() < PortalLayer::Tooltip.z_index());
+        assert!(PortalLayer::Dropdown < PortalLayer::Tooltip);
+    }
+
+    #[test]
+    fn test_host_class() {
+        assert_eq!(host_class(None), "awsui-portal");
+        assert_eq!(host_class(Some("")), "awsui-portal");
+        assert_eq!(
+            host_class(Some("awsui-dark-mode awsui-compact-mode")),
+            "awsui-portal awsui-dark-mode awsui-compact-mode"
+        );
+    }
+
+    #[test]
+    fn test_host_style() {
+        assert_eq!(host_style(None), None);
+        assert_eq!(
+            host_style(Some(PortalLayer::Modal)).as_deref(),
+            Some("position: relative; z-index: 5000")
+        );
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod wasm_tests {
+    use super::*;
+    use std::time::Duration;
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    fn host_count() -> u32 {
+        gloo::utils::document()
+            .query_selector_all(&format!("[{}]", PORTAL_HOST_ATTRIBUTE))
+            .map(|hosts| hosts.length())
+            .unwrap_or_default()
+    }
+
+    #[function_component(TestPortals)]
+    fn test_portals() -> Html {
+        html! {
+            <>
+                <Portal layer={PortalLayer::Modal}>{"Modal"}</Portal>
+                <Portal>{"Dropdown"}</Portal>
+            </>
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_host_attributes_set_after_render() {
+        let root = gloo::utils::document().create_element("div").unwrap();
+        gloo::utils::body().append_child(&root).unwrap();
+
+        let app = yew::Renderer::<TestPortals>::with_root(root.clone()).render();
+        yew::platform::time::sleep(Duration::ZERO).await;
+        let hosts = gloo::utils::document()
+            .query_selector_all(&format!("[{}]", PORTAL_HOST_ATTRIBUTE))
+            .unwrap();
+        let styles: Vec<Option<String>> = (0..hosts.length())
+            .filter_map(|index| hosts.item(index))
+            .filter_map(|host| host.dyn_into::<Element>().ok())
+            .map(|host| {
+                assert_eq!(host.get_attribute("class").as_deref(), Some("awsui-portal"));
+                host.get_attribute("style")
+            })
+            .collect();
+        assert_eq!(
+            styles,
+            vec![Some("position: relative; z-index: 5000".to_string()), None]
+        );
+
+        app.destroy();
+        root.remove();
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_hosts_removed_after_mount_unmount_cycles() {
+        for _ in 0..5 {
+            let root = gloo::utils::document().create_element("div").unwrap();
+            gloo::utils::body().append_child(&root).unwrap();
+
+            let app = yew::Renderer::<TestPortals>::with_root(root.clone()).render();
+            yew::platform::time::sleep(Duration::ZERO).await;
+            assert_eq!(host_count(), 2);
+
+            app.destroy();
+            yew::platform::time::sleep(Duration::ZERO).await;
+            assert_eq!(host_count(), 0);
+            root.remove();
+        }
+    }
+}
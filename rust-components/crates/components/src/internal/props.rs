@@ -6,49 +6,145 @@
 //! Provides patterns for handling optional props, style overrides,
 //! and other advanced property scenarios.
 
+use super::BaseComponentProps;
 use std::collections::HashMap;
+use std::rc::Rc;
+use yew::prelude::{AttrValue, Html};
+use yew::virtual_dom::{AttributeOrProperty, VNode, VTag};
 
 /// Style override for component customization
 ///
-/// Mirrors the React implementation's style prop pattern,
-/// allowing users to override CSS custom properties.
+/// Mirrors the React implementation's style prop pattern. `root` declarations
+/// are merged onto the style attribute of the component's root element, and
+/// each `parts` entry onto the internal element with that part name, such as
+/// a Table's `"cell"`. Components document their stable part names, and
+/// unknown names are reported with a development warning.
+///
+/// Overrides are applied after the component's own inline styles, so they
+/// replace them, except for [`PROTECTED_PROPERTIES`] which the component
+/// always controls.
+///
+/// # Example
+///
+/// ```rust
+/// use cloudscape_components::internal::StyleOverride;
+///
+/// let style = StyleOverride::new()
+///     .with_root("max-width: 480px")
+///     .with_part("cell", "padding-block: 2px");
+/// assert_eq!(style.part("cell"), Some("padding-block: 2px"));
+/// ```
 #[derive(Debug, Clone, PartialEq, Default)]
 pub struct StyleOverride {
-    properties: HashMap<String, String>,
+    /// Declarations for the root element, such as `"width: 320px"`
+    pub root: Option<String>,
+    /// Declarations keyed by part name
+    pub parts: HashMap<String, String>,
 }
 
+/// Properties whose component-set values win over style overrides, so an
+/// override can't hide an element or break its layout by accident
+pub const PROTECTED_PROPERTIES: &[&str] = &["display", "visibility"];
+
 impl StyleOverride {
     /// Creates a new empty style override
     pub fn new() -> Self {
         Self::default()
     }
 
-    /// Sets a CSS custom property value
+    /// Sets a property on the root element, such as a CSS custom property
     pub fn set(&mut self, property: impl Into<String>, value: impl Into<String>) -> &mut Self {
-        self.properties.insert(property.into(), value.into());
+        let property = property.into();
+        let mut root: Vec<String> = declarations(self.root.as_deref().unwrap_or_default())
+            .filter(|(name, _)| *name != property)
+            .map(|(name, value)| format!("{}:{}", name, value))
+            .collect();
+        root.push(format!("{}:{}", property, value.into()));
+        self.root = Some(root.join(";"));
+        self
+    }
+
+    /// Gets a property declared on the root element
+    pub fn get(&self, property: &str) -> Option<&str> {
+        declarations(self.root.as_deref()?)
+            .filter(|(name, _)| *name == property)
+            .map(|(_, value)| value)
+            .last()
+    }
+
+    /// Sets the declarations for the root element
+    pub fn with_root(mut self, style: impl Into<String>) -> Self {
+        self.root = Some(style.into());
+        self
+    }
+
+    /// Sets the declarations for a part
+    pub fn with_part(mut self, part: impl Into<String>, style: impl Into<String>) -> Self {
+        self.parts.insert(part.into(), style.into());
         self
     }
 
-    /// Gets a CSS custom property value
-    pub fn get(&self, property: &str) -> Option<&String> {
-        self.properties.get(property)
+    /// Gets the declarations for a part
+    pub fn part(&self, part: &str) -> Option<&str> {
+        self.parts.get(part).map(String::as_str)
     }
 
-    /// Converts to CSS inline style string
+    /// Converts the root declarations to a CSS inline style string
     pub fn to_inline_style(&self) -> String {
-        self.properties
-            .iter()
-            .map(|(k, v)| format!("{}:{}", k, v))
-            .collect::<Vec<_>>()
-            .join(";")
+        self.root.clone().unwrap_or_default()
     }
 
     /// Checks if any overrides are present
     pub fn is_empty(&self) -> bool {
-        self.properties.is_empty()
+        self.root
+            .as_deref()
+            .is_none_or(|root| root.trim().is_empty())
+            && self.parts.is_empty()
+    }
+
+    /// Gets the part names that are not in `known`, sorted
+    pub(crate) fn unknown_parts(&self, known: &[&str]) -> Vec<&str> {
+        let mut unknown: Vec<&str> = self
+            .parts
+            .keys()
+            .map(String::as_str)
+            .filter(|part| !known.contains(part))
+            .collect();
+        unknown.sort_unstable();
+        unknown
     }
 }
 
+/// Splits an inline style into trimmed `(property, value)` declarations
+fn declarations(style: &str) -> impl Iterator<Item = (&str, &str)> {
+    style.split(';').filter_map(|declaration| {
+        let (property, value) = declaration.split_once(':')?;
+        let property = property.trim();
+        (!property.is_empty()).then_some((property, value.trim()))
+    })
+}
+
+/// Merges override declarations after a component's own inline style
+///
+/// Declarations of [`PROTECTED_PROPERTIES`] are dropped from the override.
+/// Returns `None` when neither declares anything.
+pub(crate) fn merge_style(
+    component_style: Option<&str>,
+    override_style: Option<&str>,
+) -> Option<String> {
+    let overrides = declarations(override_style.unwrap_or_default()).filter(|(property, _)| {
+        !PROTECTED_PROPERTIES
+            .iter()
+            .any(|protected| property.eq_ignore_ascii_case(protected))
+    });
+    let merged: Vec<String> = declarations(component_style.unwrap_or_default())
+        .chain(overrides)
+        .map(|(property, value)| format!("{}: {}", property, value))
+        .collect();
+
+    (!merged.is_empty()).then(|| merged.join("; "))
+}
+
 /// Builder pattern for creating style overrides
 pub struct StyleOverrideBuilder {
     override_style: StyleOverride,
@@ -92,6 +188,18 @@ pub struct NativeAttributes {
     pub role: Option<String>,
     pub tabindex: Option<i32>,
     pub title: Option<String>,
+    /// Name under which a form control's value is submitted
+    pub name: Option<String>,
+    /// Autocomplete token of a form control, such as "email" or "off"
+    pub autocomplete: Option<String>,
+    /// Whether a form control is focused on mount
+    pub autofocus: Option<bool>,
+    /// ID of the form a control belongs to, when outside of it
+    pub form: Option<String>,
+    /// Data attributes of the control, prefixed with `data-` when missing
+    pub data_attributes: Vec<(String, String)>,
+    /// Stable selector for end-to-end tests, rendered as `data-testid`
+    pub test_id: Option<String>,
 }
 
 impl NativeAttributes {
@@ -106,6 +214,130 @@ impl NativeAttributes {
             ..Default::default()
         }
     }
+
+    /// Gets all data attributes, including the test ID, as HTML attribute
+    /// tuples
+    pub fn data_attrs(&self) -> Vec<(String, String)> {
+        data_attrs(&self.data_attributes, self.test_id.as_deref())
+    }
+}
+
+/// Prefixes data attribute names with `data-` and appends the test ID
+pub(crate) fn data_attrs(
+    attributes: &[(String, String)],
+    test_id: Option<&str>,
+) -> Vec<(String, String)> {
+    attributes
+        .iter()
+        .map(|(name, value)| {
+            let name = if name.starts_with("data-") {
+                name.clone()
+            } else {
+                format!("data-{}", name)
+            };
+            (name, value.clone())
+        })
+        .chain(test_id.map(|id| ("data-testid".to_string(), id.to_string())))
+        .collect()
+}
+
+/// Computes the attributes of [`BaseComponentProps`] missing from a root
+/// element that already renders `id` and `class`
+///
+/// The component's own ID wins, and only user classes not yet rendered are
+/// appended.
+pub(crate) fn root_attributes(
+    base: &BaseComponentProps,
+    id: Option<&str>,
+    class: Option<&str>,
+) -> Vec<(String, String)> {
+    let mut attributes = Vec::new();
+    if id.is_none()
+        && let Some(base_id) = &base.id
+    {
+        attributes.push(("id".to_string(), base_id.clone()));
+    }
+    let rendered: Vec<&str> = class.unwrap_or_default().split_whitespace().collect();
+    let missing: Vec<&str> = base
+        .class
+        .as_deref()
+        .unwrap_or_default()
+        .split_whitespace()
+        .filter(|name| !rendered.contains(name))
+        .collect();
+    if !missing.is_empty() {
+        let merged: Vec<&str> = rendered.into_iter().chain(missing).collect();
+        attributes.push(("class".to_string(), merged.join(" ")));
+    }
+    attributes.extend(base.data_attrs());
+    attributes
+}
+
+/// Applies `id`, `class` and the data attributes of [`BaseComponentProps`]
+/// to a component's root element
+///
+/// Components call this on the markup they return, so that every component
+/// forwards the base props the same way. Fragment roots apply them to their
+/// first element; other roots, such as a nested component, are returned
+/// unchanged.
+pub fn apply_root_attributes(root: Html, base: &BaseComponentProps) -> Html {
+    with_first_element(root, |tag| {
+        let rendered = |name: &str| {
+            tag.attributes
+                .iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| value.to_string())
+        };
+        let attributes = root_attributes(
+            base,
+            rendered("id").as_deref(),
+            rendered("class").as_deref(),
+        );
+        set_attributes(tag, attributes);
+    })
+}
+
+/// Adds data attributes, such as [`NativeAttributes::data_attrs`], to the
+/// first element of `node`
+pub fn apply_data_attributes(node: Html, attributes: Vec<(String, String)>) -> Html {
+    if attributes.is_empty() {
+        return node;
+    }
+    with_first_element(node, |tag| set_attributes(tag, attributes))
+}
+
+/// Calls `f` with the element at the root of `node`, or with the first
+/// element of a fragment
+fn with_first_element(node: Html, f: impl FnOnce(&mut VTag)) -> Html {
+    match node {
+        VNode::VTag(mut tag) => {
+            f(Rc::make_mut(&mut tag));
+            VNode::VTag(tag)
+        }
+        VNode::VList(mut list) => {
+            if let Some(VNode::VTag(tag)) = Rc::make_mut(&mut list)
+                .iter_mut()
+                .find(|child| matches!(child, VNode::VTag(_)))
+            {
+                f(Rc::make_mut(tag));
+            }
+            VNode::VList(list)
+        }
+        node => node,
+    }
+}
+
+fn set_attributes(tag: &mut VTag, attributes: Vec<(String, String)>) {
+    if attributes.is_empty() {
+        return;
+    }
+    let map = tag.attributes.get_mut_index_map();
+    for (name, value) in attributes {
+        map.insert(
+            AttrValue::from(name),
+            AttributeOrProperty::Attribute(AttrValue::from(value)),
+        );
+    }
 }
 
 /// I18n strings pattern
@@ -136,6 +368,13 @@ impl I18nStrings {
     pub fn get_or<'a>(&'a self, key: &str, default: &'a str) -> &'a str {
         self.strings.get(key).map(|s| s.as_str()).unwrap_or(default)
     }
+
+    /// Copies all strings from `other`, replacing existing values for the same keys
+    pub fn merge(&mut self, other: &I18nStrings) -> &mut Self {
+        self.strings
+            .extend(other.strings.iter().map(|(k, v)| (k.clone(), v.clone())));
+        self
+    }
 }
 
 impl Default for I18nStrings {
@@ -154,12 +393,66 @@ mod tests {
         style.set("--custom-color", "#ff0000");
         style.set("--custom-padding", "10px");
 
-        assert_eq!(style.get("--custom-color"), Some(&"#ff0000".to_string()));
+        assert_eq!(style.get("--custom-color"), Some("#ff0000"));
         assert!(!style.is_empty());
 
         let inline = style.to_inline_style();
         assert!(inline.contains("--custom-color:#ff0000"));
         assert!(inline.contains("--custom-padding:10px"));
+
+        // Setting a property again replaces it
+        style.set("--custom-color", "#00ff00");
+        assert_eq!(style.get("--custom-color"), Some("#00ff00"));
+        assert_eq!(style.to_inline_style().matches("--custom-color").count(), 1);
+    }
+
+    #[test]
+    fn test_style_override_parts() {
+        let style = StyleOverride::new()
+            .with_root("width: 320px")
+            .with_part("cell", "padding: 2px")
+            .with_part("tbody", "color: red")
+            .with_part("header", "z-index: 3");
+
+        assert_eq!(style.get("width"), Some("320px"));
+        assert_eq!(style.part("cell"), Some("padding: 2px"));
+        assert_eq!(style.part("row"), None);
+        assert_eq!(
+            style.unknown_parts(&["header", "cell", "row"]),
+            vec!["tbody"]
+        );
+        assert!(StyleOverride::new().is_empty());
+        assert!(
+            !StyleOverride::new()
+                .with_part("row", "color: red")
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_merge_style_appends_overrides() {
+        assert_eq!(merge_style(None, None), None);
+        assert_eq!(
+            merge_style(Some("width: 50%"), None).as_deref(),
+            Some("width: 50%")
+        );
+        assert_eq!(
+            merge_style(Some("width: 50%;"), Some("width: 320px; z-index: 10")).as_deref(),
+            Some("width: 50%; width: 320px; z-index: 10")
+        );
+    }
+
+    #[test]
+    fn test_merge_style_keeps_protected_properties() {
+        assert_eq!(
+            merge_style(
+                Some("display: flex"),
+                Some("display: none; DISPLAY: block; visibility: hidden; color: red")
+            )
+            .as_deref(),
+            Some("display: flex; color: red")
+        );
+        assert_eq!(merge_style(None, Some("display: none")), None);
     }
 
     #[test]
@@ -169,8 +462,8 @@ mod tests {
             .property("--size", "large")
             .build();
 
-        assert_eq!(style.get("--color"), Some(&"blue".to_string()));
-        assert_eq!(style.get("--size"), Some(&"large".to_string()));
+        assert_eq!(style.get("--color"), Some("blue"));
+        assert_eq!(style.get("--size"), Some("large"));
     }
 
     #[test]
@@ -179,6 +472,49 @@ mod tests {
         assert_eq!(attrs.aria_label, Some("Test Label".to_string()));
     }
 
+    #[test]
+    fn test_native_data_attrs() {
+        let attrs = NativeAttributes {
+            data_attributes: vec![("field".to_string(), "email".to_string())],
+            test_id: Some("email-input".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            attrs.data_attrs(),
+            vec![
+                ("data-field".to_string(), "email".to_string()),
+                ("data-testid".to_string(), "email-input".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_root_attributes() {
+        let base = BaseComponentProps {
+            id: Some("users".to_string()),
+            class: Some("wide awsui-table".to_string()),
+            ..Default::default()
+        }
+        .with_test_id("users-table");
+
+        // Missing ID and classes are added
+        assert_eq!(
+            root_attributes(&base, None, Some("awsui-table")),
+            vec![
+                ("id".to_string(), "users".to_string()),
+                ("class".to_string(), "awsui-table wide".to_string()),
+                ("data-testid".to_string(), "users-table".to_string()),
+            ]
+        );
+
+        // Components that already forward them are left alone
+        assert_eq!(
+            root_attributes(&base, Some("users"), Some("awsui-table wide")),
+            vec![("data-testid".to_string(), "users-table".to_string())]
+        );
+        assert!(root_attributes(&BaseComponentProps::default(), None, None).is_empty());
+    }
+
     #[test]
     fn test_i18n_strings() {
         let mut i18n = I18nStrings::new();
@@ -188,4 +524,104 @@ mod tests {
         assert_eq!(i18n.get("button.submit"), Some(&"Submit".to_string()));
         assert_eq!(i18n.get_or("button.missing", "Default"), "Default");
     }
+
+    #[test]
+    fn test_i18n_strings_merge() {
+        let mut base = I18nStrings::new();
+        base.set("button.submit", "Submit");
+        base.set("button.cancel", "Cancel");
+
+        let mut overrides = I18nStrings::new();
+        overrides.set("button.submit", "Senden");
+
+        base.merge(&overrides);
+        assert_eq!(base.get_or("button.submit", ""), "Senden");
+        assert_eq!(base.get_or("button.cancel", ""), "Cancel");
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod wasm_tests {
+    use super::*;
+    use crate::*;
+    use std::time::Duration;
+    use wasm_bindgen_test::*;
+    use yew::prelude::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    fn e2e_base() -> BaseComponentProps {
+        BaseComponentProps {
+            id: Some("e2e-root".to_string()),
+            class: Some("e2e-class".to_string()),
+            ..Default::default()
+        }
+        .with_data_attribute("row", "1")
+        .with_test_id("e2e")
+    }
+
+    async fn assert_root_attributes<C: BaseComponent<Properties = ()>>() {
+        let root = gloo::utils::document().create_element("div").unwrap();
+        gloo::utils::body().append_child(&root).unwrap();
+
+        let app = yew::Renderer::<C>::with_root(root.clone()).render();
+        yew::platform::time::sleep(Duration::ZERO).await;
+
+        let element = root.query_selector("#e2e-root").unwrap().expect("root id");
+        assert!(
+            element
+                .class_name()
+                .split_whitespace()
+                .any(|class| class == "e2e-class")
+        );
+        assert_eq!(element.get_attribute("data-row").as_deref(), Some("1"));
+        assert_eq!(element.get_attribute("data-testid").as_deref(), Some("e2e"));
+        assert_eq!(root.query_selector_all("#e2e-root").unwrap().length(), 1);
+
+        app.destroy();
+        root.remove();
+    }
+
+    /// Renders each component with [`e2e_base`] and checks its root element
+    macro_rules! root_attribute_tests {
+        ($($test:ident($base:ident) => $html:expr;)*) => {
+            $(
+                mod $test {
+                    use super::*;
+
+                    #[function_component(Host)]
+                    fn host() -> Html {
+                        let $base = e2e_base();
+                        $html
+                    }
+
+                    #[wasm_bindgen_test]
+                    async fn renders_base_attributes_on_root() {
+                        assert_root_attributes::<Host>().await;
+                    }
+                }
+            )*
+        };
+    }
+
+    root_attribute_tests! {
+        badge(base) => html! { <Badge base={base}>{"New"}</Badge> };
+        alert(base) => html! { <Alert base={base}>{"Saved"}</Alert> };
+        spinner(base) => html! { <Spinner base={base} /> };
+        container(base) => html! { <Container base={base}>{"Content"}</Container> };
+        button(base) => html! { <Button base={base}>{"Save"}</Button> };
+        input(base) => html! { <Input base={base} /> };
+        toggle(base) => html! { <Toggle base={base}>{"Enabled"}</Toggle> };
+        table(base) => html! {
+            <Table<String> base={base} columns={Vec::<TableColumn<String>>::new()} />
+        };
+        svg_icon(base) => html! { <Icon base={base} svg={html! { <svg /> }} /> };
+        url_icon(base) => html! { <Icon base={base} url="/icon.svg" /> };
+        empty_filter_summary(base) => html! { <FilterSummary base={base} /> };
+        rail_side_navigation(base) => html! {
+            <ContextProvider<NavigationDisplay> context={NavigationDisplay::Rail}>
+                <SideNavigation base={base} />
+            </ContextProvider<NavigationDisplay>>
+        };
+    }
 }
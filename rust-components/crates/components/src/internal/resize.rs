@@ -0,0 +1,425 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Element size observation, container breakpoints and resize handles
+//!
+//! All observed elements share one `ResizeObserver`, which dispatches each
+//! entry to the subscribers of its target. Hooks return `None` until the first
+//! observation, so markup rendered before layout (or outside a browser) uses
+//! the component's default layout.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen::JsCast;
+use wasm_bindgen::prelude::Closure;
+use web_sys::{Element, KeyboardEvent, PointerEvent, ResizeObserver, ResizeObserverEntry};
+use yew::prelude::*;
+
+/// Cloudscape container breakpoints, from narrowest to widest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Breakpoint {
+    /// Narrower than every other breakpoint
+    Default,
+    /// At least 465px
+    Xxs,
+    /// At least 688px
+    Xs,
+    /// At least 912px
+    S,
+    /// At least 1120px
+    M,
+    /// At least 1280px
+    L,
+    /// At least 1840px
+    Xl,
+}
+
+impl Breakpoint {
+    /// All breakpoints, from narrowest to widest
+    pub const ALL: [Breakpoint; 7] = [
+        Self::Default,
+        Self::Xxs,
+        Self::Xs,
+        Self::S,
+        Self::M,
+        Self::L,
+        Self::Xl,
+    ];
+
+    /// Returns the minimum width of the breakpoint in pixels
+    pub fn min_width(&self) -> f64 {
+        match self {
+            Self::Default => 0.0,
+            Self::Xxs => 465.0,
+            Self::Xs => 688.0,
+            Self::S => 912.0,
+            Self::M => 1120.0,
+            Self::L => 1280.0,
+            Self::Xl => 1840.0,
+        }
+    }
+
+    /// Returns the breakpoint name
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Default => "default",
+            Self::Xxs => "xxs",
+            Self::Xs => "xs",
+            Self::S => "s",
+            Self::M => "m",
+            Self::L => "l",
+            Self::Xl => "xl",
+        }
+    }
+}
+
+/// Maps a width to the widest of `breakpoints` it reaches
+///
+/// Only the listed breakpoints are considered; an empty list considers all
+/// of them. Widths below every listed breakpoint map to `Default`.
+pub fn match_breakpoint(width: f64, breakpoints: &[Breakpoint]) -> Breakpoint {
+    let breakpoints = if breakpoints.is_empty() {
+        &Breakpoint::ALL[..]
+    } else {
+        breakpoints
+    };
+    breakpoints
+        .iter()
+        .copied()
+        .filter(|breakpoint| width >= breakpoint.min_width())
+        .max()
+        .unwrap_or(Breakpoint::Default)
+}
+
+/// Gets the size of an element dragged `delta` pixels from `start_size`,
+/// rounded to whole pixels within `min` and `max`
+pub(crate) fn dragged_size(start_size: f64, delta: f64, min: f64, max: f64) -> f64 {
+    (start_size + delta).clamp(min, max).round()
+}
+
+/// Pointer coordinate and panel size at the start of a resize handle drag
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct HandleDrag {
+    pub start: f64,
+    pub start_size: u32,
+}
+
+/// Orientation of a resize handle
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum HandleOrientation {
+    /// Between side by side regions, dragged along the x axis
+    Vertical,
+    /// Between stacked regions, dragged along the y axis
+    Horizontal,
+}
+
+impl HandleOrientation {
+    /// Value of `aria-orientation`
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Vertical => "vertical",
+            Self::Horizontal => "horizontal",
+        }
+    }
+
+    /// Pointer coordinate along the drag axis
+    fn coordinate(&self, event: &PointerEvent) -> f64 {
+        match self {
+            Self::Vertical => event.client_x() as f64,
+            Self::Horizontal => event.client_y() as f64,
+        }
+    }
+}
+
+/// Separator resizing a panel by pointer drag or keyboard
+pub(crate) struct ResizeHandle<'a> {
+    pub class: &'static str,
+    pub label: &'a str,
+    pub orientation: HandleOrientation,
+    /// Current size of the panel in pixels
+    pub size: u32,
+    /// Smallest and largest size of the panel in pixels
+    pub limits: (u32, u32),
+    /// Drag in progress, kept across renders
+    pub drag: &'a Rc<RefCell<Option<HandleDrag>>>,
+    /// Receives the sizes the handle resizes the panel to
+    pub on_resize: &'a Callback<u32>,
+}
+
+impl ResizeHandle<'_> {
+    /// Renders the handle
+    ///
+    /// `dragged_size` gets the size after the pointer moved a number of pixels
+    /// along the axis from a start size, and `keyboard_size` the size after a
+    /// key press, or `None` for keys that don't resize.
+    pub(crate) fn render(
+        self,
+        dragged_size: impl Fn(u32, f64) -> u32 + 'static,
+        keyboard_size: impl Fn(u32, &str) -> Option<u32> + 'static,
+    ) -> Html {
+        let Self {
+            class,
+            label,
+            orientation,
+            size,
+            limits: (min, max),
+            drag,
+            on_resize,
+        } = self;
+
+        let on_pointer_down = {
+            let drag = drag.clone();
+            Callback::from(move |e: PointerEvent| {
+                if e.button() != 0 {
+                    return;
+                }
+                // Keep the drag from selecting text
+                e.prevent_default();
+                if let Some(handle) = e
+                    .target()
+                    .and_then(|target| target.dyn_into::<Element>().ok())
+                {
+                    let _ = handle.set_pointer_capture(e.pointer_id());
+                }
+                *drag.borrow_mut() = Some(HandleDrag {
+                    start: orientation.coordinate(&e),
+                    start_size: size,
+                });
+            })
+        };
+
+        // The handle captures the pointer, so it gets the moves and the
+        // release even when the pointer leaves it
+        let on_pointer_move = {
+            let drag = drag.clone();
+            let on_resize = on_resize.clone();
+            Callback::from(move |e: PointerEvent| {
+                let dragged = drag.borrow().map(|drag| {
+                    dragged_size(drag.start_size, orientation.coordinate(&e) - drag.start)
+                });
+                if let Some(dragged) = dragged
+                    && dragged != size
+                {
+                    on_resize.emit(dragged);
+                }
+            })
+        };
+
+        let on_pointer_end = {
+            let drag = drag.clone();
+            Callback::from(move |_: PointerEvent| {
+                drag.borrow_mut().take();
+            })
+        };
+
+        let on_key_down = {
+            let on_resize = on_resize.clone();
+            Callback::from(move |e: KeyboardEvent| {
+                if let Some(resized) = keyboard_size(size, &e.key()) {
+                    e.prevent_default();
+                    if resized != size {
+                        on_resize.emit(resized);
+                    }
+                }
+            })
+        };
+
+        html! {
+            <span
+                class={class}
+                role="separator"
+                tabindex="0"
+                aria-orientation={orientation.as_str()}
+                aria-label={label.to_string()}
+                aria-valuemin={min.to_string()}
+                aria-valuemax={max.to_string()}
+                aria-valuenow={size.to_string()}
+                onpointerdown={on_pointer_down}
+                onpointermove={on_pointer_move}
+                onpointerup={on_pointer_end.clone()}
+                onpointercancel={on_pointer_end}
+                onkeydown={on_key_down}
+            />
+        }
+    }
+}
+
+type SizeCallback = Rc<dyn Fn(f64, f64)>;
+
+struct SharedObserver {
+    observer: ResizeObserver,
+    subscribers: Vec<(u32, Element, SizeCallback)>,
+    next_id: u32,
+    _closure: Closure<dyn FnMut(js_sys::Array)>,
+}
+
+thread_local! {
+    static SHARED_OBSERVER: RefCell<Option<SharedObserver>> = const { RefCell::new(None) };
+}
+
+fn dispatch(entries: js_sys::Array) {
+    let sizes: Vec<(Element, f64, f64)> = entries
+        .iter()
+        .filter_map(|entry| entry.dyn_into::<ResizeObserverEntry>().ok())
+        .map(|entry| {
+            let rect = entry.content_rect();
+            (entry.target(), rect.width(), rect.height())
+        })
+        .collect();
+
+    // Collect callbacks first so subscribers can unsubscribe while handling
+    let callbacks: Vec<(SizeCallback, f64, f64)> = SHARED_OBSERVER.with(|shared| {
+        let shared = shared.borrow();
+        let Some(shared) = shared.as_ref() else {
+            return Vec::new();
+        };
+        sizes
+            .iter()
+            .flat_map(|(target, width, height)| {
+                shared
+                    .subscribers
+                    .iter()
+                    .filter(move |(_, element, _)| element == target)
+                    .map(move |(_, _, callback)| (callback.clone(), *width, *height))
+            })
+            .collect()
+    });
+    for (callback, width, height) in callbacks {
+        callback(width, height);
+    }
+}
+
+/// Subscription to the size of an element, ended when dropped
+pub(crate) struct ResizeSubscription(Option<u32>);
+
+impl ResizeSubscription {
+    /// Observes `element`, calling `callback` with its content width and height
+    pub(crate) fn new(element: Element, callback: impl Fn(f64, f64) + 'static) -> Self {
+        let id = SHARED_OBSERVER.with(|shared| {
+            let mut shared = shared.borrow_mut();
+            if shared.is_none() {
+                let closure = Closure::<dyn FnMut(js_sys::Array)>::new(dispatch);
+                let observer = ResizeObserver::new(closure.as_ref().unchecked_ref()).ok()?;
+                *shared = Some(SharedObserver {
+                    observer,
+                    subscribers: Vec::new(),
+                    next_id: 0,
+                    _closure: closure,
+                });
+            }
+            let shared = shared.as_mut()?;
+            let id = shared.next_id;
+            shared.next_id += 1;
+            shared.observer.observe(&element);
+            shared.subscribers.push((id, element, Rc::new(callback)));
+            Some(id)
+        });
+        Self(id)
+    }
+}
+
+impl Drop for ResizeSubscription {
+    fn drop(&mut self) {
+        let Some(id) = self.0 else {
+            return;
+        };
+        SHARED_OBSERVER.with(|shared| {
+            let mut shared = shared.borrow_mut();
+            let Some(observer) = shared.as_mut() else {
+                return;
+            };
+            let Some(index) = observer
+                .subscribers
+                .iter()
+                .position(|(subscriber, _, _)| *subscriber == id)
+            else {
+                return;
+            };
+            let (_, element, _) = observer.subscribers.remove(index);
+            if !observer
+                .subscribers
+                .iter()
+                .any(|(_, other, _)| *other == element)
+            {
+                observer.observer.unobserve(&element);
+            }
+            if observer.subscribers.is_empty() {
+                observer.observer.disconnect();
+                *shared = None;
+            }
+        });
+    }
+}
+
+/// Observes the content size of the referenced element
+///
+/// Returns `(width, height)` in pixels, or `None` until the element has been
+/// observed once.
+#[hook]
+pub fn use_element_size(node_ref: NodeRef) -> Option<(f64, f64)> {
+    let size = use_state_eq(|| None);
+
+    {
+        let size = size.clone();
+        use_effect_with(node_ref, move |node_ref| {
+            let subscription = node_ref.cast::<Element>().map(|element| {
+                ResizeSubscription::new(element, move |width, height| {
+                    size.set(Some((width, height)))
+                })
+            });
+            move || drop(subscription)
+        });
+    }
+
+    *size
+}
+
+/// Observes the breakpoint reached by the width of the referenced element
+///
+/// See [`match_breakpoint`] for how `breakpoints` restricts the result.
+/// Returns `None` until the element has been observed once.
+#[hook]
+pub fn use_container_breakpoint(
+    node_ref: NodeRef,
+    breakpoints: Vec<Breakpoint>,
+) -> Option<Breakpoint> {
+    use_element_size(node_ref).map(|(width, _)| match_breakpoint(width, &breakpoints))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_breakpoint_order() {
+        for pair in Breakpoint::ALL.windows(2) {
+            assert!(pair[0] < pair[1]);
+            assert!(pair[0].min_width() < pair[1].min_width());
+        }
+        assert_eq!(Breakpoint::Xxs.as_str(), "xxs");
+    }
+
+    #[test]
+    fn test_match_breakpoint() {
+        assert_eq!(match_breakpoint(0.0, &[]), Breakpoint::Default);
+        assert_eq!(match_breakpoint(464.9, &[]), Breakpoint::Default);
+        assert_eq!(match_breakpoint(465.0, &[]), Breakpoint::Xxs);
+        assert_eq!(match_breakpoint(1000.0, &[]), Breakpoint::S);
+        assert_eq!(match_breakpoint(5000.0, &[]), Breakpoint::Xl);
+    }
+
+    #[test]
+    fn test_match_breakpoint_subset() {
+        let breakpoints = [Breakpoint::Xs, Breakpoint::M];
+        assert_eq!(match_breakpoint(600.0, &breakpoints), Breakpoint::Default);
+        assert_eq!(match_breakpoint(1000.0, &breakpoints), Breakpoint::Xs);
+        assert_eq!(match_breakpoint(2000.0, &breakpoints), Breakpoint::M);
+    }
+
+    #[test]
+    fn test_dragged_size() {
+        assert_eq!(dragged_size(200.0, 35.4, 80.0, f64::INFINITY), 235.0);
+        assert_eq!(dragged_size(200.0, -150.0, 80.0, f64::INFINITY), 80.0);
+        assert_eq!(dragged_size(280.0, 900.0, 200.0, 600.0), 600.0);
+    }
+}
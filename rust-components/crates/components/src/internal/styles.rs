@@ -218,11 +218,11 @@ mod tests {
         let override_style = badge_style.to_style_override();
         assert_eq!(
             override_style.get(CssProperties::BADGE_BACKGROUND),
-            Some(&"#ff0000".to_string())
+            Some("#ff0000")
         );
         assert_eq!(
             override_style.get(CssProperties::BADGE_COLOR),
-            Some(&"#ffffff".to_string())
+            Some("#ffffff")
         );
     }
 
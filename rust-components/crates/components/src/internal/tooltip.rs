@@ -0,0 +1,42 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tooltip primitive
+//!
+//! A small, non-interactive overlay showing supplementary text next to the
+//! element it is rendered alongside. Visibility is owned by the caller.
+
+use yew::prelude::*;
+
+/// Properties for the Tooltip primitive
+#[derive(Properties, PartialEq, Clone)]
+pub struct TooltipProps {
+    /// Content shown in the tooltip
+    pub content: Html,
+
+    /// Element ID, for referencing the tooltip from `aria-describedby`
+    #[prop_or_default]
+    pub id: Option<String>,
+
+    /// Whether to hide the tooltip from assistive technology
+    ///
+    /// Set this when the anchor's accessible name already contains the
+    /// tooltip content, so it is not announced twice.
+    #[prop_or_default]
+    pub aria_hidden: bool,
+}
+
+/// Tooltip primitive rendered next to its anchor while visible
+#[function_component(Tooltip)]
+pub fn tooltip(props: &TooltipProps) -> Html {
+    html! {
+        <span
+            id={props.id.clone()}
+            class="awsui-tooltip"
+            role="tooltip"
+            aria-hidden={props.aria_hidden.then_some("true")}
+        >
+            { props.content.clone() }
+        </span>
+    }
+}
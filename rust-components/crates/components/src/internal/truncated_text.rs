@@ -0,0 +1,153 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Single-line text truncation with a full-text tooltip
+//!
+//! Truncation is done in CSS with an ellipsis; the DOM keeps the full text,
+//! so the accessible name of the surrounding link or button is unaffected.
+//! The tooltip is only shown when the text actually overflows.
+
+use super::tooltip::Tooltip;
+use gloo::events::EventListener;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, KeyboardEvent};
+use yew::prelude::*;
+
+/// Interactive ancestors whose hover and focus show the tooltip
+const TRIGGER_SELECTOR: &str = "a, button, [tabindex]";
+
+/// Properties for the TruncatedText primitive
+#[derive(Properties, PartialEq, Clone)]
+pub struct TruncatedTextProps {
+    /// Full text, shown in the tooltip when truncated
+    pub text: String,
+
+    /// Maximum width as a CSS length, such as `"240px"` or `"20ch"`
+    ///
+    /// Defaults to the width available in the parent.
+    #[prop_or_default]
+    pub max_width: Option<String>,
+
+    /// Rendered content, when it differs from the plain `text` (for example
+    /// with highlighted matches); defaults to `text`
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// Builds the inline style that truncates the text with an ellipsis
+pub(crate) fn truncation_style(max_width: Option<&str>) -> String {
+    format!(
+        "display: inline-block; max-width: {}; overflow: hidden; \
+         text-overflow: ellipsis; white-space: nowrap; vertical-align: bottom",
+        max_width.unwrap_or("100%")
+    )
+}
+
+/// Whether an element's content is wider than its visible box
+pub(crate) fn is_overflowing(scroll_width: i32, client_width: i32) -> bool {
+    scroll_width > client_width
+}
+
+/// Text truncated with an ellipsis, revealing the full text in a tooltip
+///
+/// The tooltip follows hover and focus of the closest link, button or
+/// focusable ancestor, and is dismissed with Escape.
+#[function_component(TruncatedText)]
+pub fn truncated_text(props: &TruncatedTextProps) -> Html {
+    let wrapper_ref = use_node_ref();
+    let content_ref = use_node_ref();
+    let show_tooltip = use_state(|| false);
+
+    {
+        let wrapper_ref = wrapper_ref.clone();
+        let content_ref = content_ref.clone();
+        let show_tooltip = show_tooltip.clone();
+
+        use_effect_with((), move |_| {
+            let listeners = wrapper_ref.cast::<Element>().map(|wrapper| {
+                let trigger = wrapper
+                    .closest(TRIGGER_SELECTOR)
+                    .ok()
+                    .flatten()
+                    .unwrap_or(wrapper);
+
+                let show = {
+                    let show_tooltip = show_tooltip.clone();
+                    move |_: &Event| {
+                        if let Some(content) = content_ref.cast::<Element>() {
+                            show_tooltip.set(is_overflowing(
+                                content.scroll_width(),
+                                content.client_width(),
+                            ));
+                        }
+                    }
+                };
+                let hide = {
+                    let show_tooltip = show_tooltip.clone();
+                    move |_: &Event| show_tooltip.set(false)
+                };
+                let on_escape = move |event: &Event| {
+                    if let Some(event) = event.dyn_ref::<KeyboardEvent>()
+                        && event.key() == "Escape"
+                    {
+                        show_tooltip.set(false);
+                    }
+                };
+
+                vec![
+                    EventListener::new(&trigger, "mouseenter", show.clone()),
+                    EventListener::new(&trigger, "focusin", show),
+                    EventListener::new(&trigger, "mouseleave", hide.clone()),
+                    EventListener::new(&trigger, "focusout", hide),
+                    EventListener::new(&trigger, "keydown", on_escape),
+                ]
+            });
+
+            move || drop(listeners)
+        });
+    }
+
+    let content = if props.children.is_empty() {
+        html! { { &props.text } }
+    } else {
+        html! { { for props.children.iter() } }
+    };
+
+    html! {
+        <span
+            class="awsui-truncated-text"
+            style="position: relative; display: inline-flex; min-width: 0; max-width: 100%"
+            ref={wrapper_ref}
+        >
+            <span
+                class="awsui-truncated-text-content"
+                style={truncation_style(props.max_width.as_deref())}
+                ref={content_ref}
+            >
+                { content }
+            </span>
+            if *show_tooltip {
+                <Tooltip content={html! { { &props.text } }} aria_hidden={true} />
+            }
+        </span>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncation_style_max_width() {
+        assert!(truncation_style(None).contains("max-width: 100%"));
+        assert!(truncation_style(Some("240px")).contains("max-width: 240px"));
+        assert!(truncation_style(None).contains("text-overflow: ellipsis"));
+    }
+
+    #[test]
+    fn test_is_overflowing() {
+        assert!(is_overflowing(320, 240));
+        assert!(!is_overflowing(240, 240));
+        assert!(!is_overflowing(100, 240));
+    }
+}
@@ -0,0 +1,453 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Windowed rendering for long option lists
+//!
+//! A virtual list renders only the rows inside its scroll viewport, plus an
+//! overscan buffer on each side; spacer rows above and below keep the full
+//! scroll height. Rows are assumed to share one fixed height, so the window
+//! follows from the scroll position alone, and keyboard navigation works on
+//! the option data without the rows being rendered.
+
+use web_sys::Element;
+use yew::prelude::*;
+
+/// Maximum height of a virtual list, in rows
+const VIEWPORT_ROWS: f64 = 10.0;
+
+/// Pause after which type-ahead starts a new query, in milliseconds
+const TYPE_AHEAD_TIMEOUT_MS: f64 = 500.0;
+
+/// Configuration of windowed rendering for an option list
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VirtualScrollConfig {
+    /// Estimated height of every row, in pixels
+    pub row_height: f64,
+    /// Rows rendered beyond each edge of the viewport
+    pub overscan: usize,
+}
+
+impl Default for VirtualScrollConfig {
+    fn default() -> Self {
+        Self {
+            row_height: 32.0,
+            overscan: 5,
+        }
+    }
+}
+
+impl VirtualScrollConfig {
+    /// Creates a configuration with the default row height and overscan
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the estimated row height in pixels
+    pub fn with_row_height(mut self, row_height: f64) -> Self {
+        self.row_height = row_height;
+        self
+    }
+
+    /// Sets the number of rows rendered beyond each edge of the viewport
+    pub fn with_overscan(mut self, overscan: usize) -> Self {
+        self.overscan = overscan;
+        self
+    }
+
+    /// Gets the window of rows to render for a scroll position
+    pub(crate) fn window(
+        &self,
+        scroll_top: f64,
+        viewport_height: f64,
+        count: usize,
+    ) -> VirtualWindow {
+        let row_height = self.row_height.max(1.0);
+        let first_visible = (scroll_top.max(0.0) / row_height).floor() as usize;
+        let visible_rows = (viewport_height.max(0.0) / row_height).ceil() as usize + 1;
+
+        let start = first_visible.saturating_sub(self.overscan).min(count);
+        let end = (first_visible + visible_rows + self.overscan).min(count);
+        VirtualWindow { start, end }
+    }
+
+    /// Gets the scroll position revealing a row, or `None` when the row is
+    /// already fully visible
+    pub(crate) fn scroll_to_reveal(
+        &self,
+        index: usize,
+        scroll_top: f64,
+        viewport_height: f64,
+    ) -> Option<f64> {
+        let row_top = index as f64 * self.row_height;
+        let row_bottom = row_top + self.row_height;
+        if row_top < scroll_top {
+            Some(row_top)
+        } else if row_bottom > scroll_top + viewport_height {
+            Some(row_bottom - viewport_height)
+        } else {
+            None
+        }
+    }
+}
+
+/// Range of rows to render, `start..end`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct VirtualWindow {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl VirtualWindow {
+    /// Window rendering every row
+    pub(crate) fn all(count: usize) -> Self {
+        Self {
+            start: 0,
+            end: count,
+        }
+    }
+
+    /// Number of rendered rows
+    #[cfg(test)]
+    pub(crate) fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// Whether the row at `index` is rendered
+    #[cfg(test)]
+    pub(crate) fn contains(&self, index: usize) -> bool {
+        (self.start..self.end).contains(&index)
+    }
+}
+
+/// State of a virtual list, returned by [`use_virtual_list`]
+pub(crate) struct VirtualList {
+    /// Reference to attach to the scrolling element
+    pub list_ref: NodeRef,
+    /// Rows to render
+    pub window: VirtualWindow,
+    /// Inline style of the scrolling element
+    pub style: Option<String>,
+    /// Scroll handler to attach to the scrolling element
+    pub on_scroll: Callback<Event>,
+    config: Option<VirtualScrollConfig>,
+    count: usize,
+}
+
+impl VirtualList {
+    /// Whether rows outside the window are left out
+    pub(crate) fn is_virtual(&self) -> bool {
+        self.config.is_some()
+    }
+
+    /// Renders a spacer row standing in for `rows` rows that are not rendered
+    fn render_spacer(&self, rows: usize) -> Html {
+        match self.config {
+            Some(config) if rows > 0 => html! {
+                <li
+                    class="awsui-virtual-list-spacer"
+                    role="presentation"
+                    aria-hidden="true"
+                    style={format!("height: {}px", rows as f64 * config.row_height)}
+                />
+            },
+            _ => html! {},
+        }
+    }
+
+    /// Renders the spacer for the rows above the window
+    pub(crate) fn top_spacer(&self) -> Html {
+        self.render_spacer(self.window.start)
+    }
+
+    /// Renders the spacer for the rows below the window
+    pub(crate) fn bottom_spacer(&self) -> Html {
+        self.render_spacer(self.count - self.window.end)
+    }
+}
+
+/// Windows a list of `count` rows when `config` is set
+///
+/// While `open`, the row at `highlighted` is scrolled into view whenever it
+/// changes, including rows outside the rendered window.
+#[hook]
+pub(crate) fn use_virtual_list(
+    config: Option<VirtualScrollConfig>,
+    count: usize,
+    highlighted: Option<usize>,
+    open: bool,
+) -> VirtualList {
+    let list_ref = use_node_ref();
+    // Scroll position and viewport height of the scrolling element
+    let viewport = use_state_eq(|| (0.0_f64, None::<f64>));
+
+    let on_scroll = {
+        let viewport = viewport.clone();
+        let list_ref = list_ref.clone();
+        Callback::from(move |_: Event| {
+            if let Some(list) = list_ref.cast::<Element>() {
+                viewport.set((
+                    f64::from(list.scroll_top()),
+                    Some(f64::from(list.client_height())),
+                ));
+            }
+        })
+    };
+
+    // Keep the highlighted row in view
+    {
+        let list_ref = list_ref.clone();
+        let viewport = viewport.clone();
+        use_effect_with(
+            (config, highlighted, open),
+            move |(config, highlighted, open)| {
+                if *open
+                    && let Some(config) = config
+                    && let Some(index) = highlighted
+                    && let Some(list) = list_ref.cast::<Element>()
+                {
+                    let viewport_height = f64::from(list.client_height());
+                    let scroll_top = f64::from(list.scroll_top());
+                    let scroll_top =
+                        match config.scroll_to_reveal(*index, scroll_top, viewport_height) {
+                            Some(scroll_top) => {
+                                list.set_scroll_top(scroll_top as i32);
+                                scroll_top
+                            }
+                            None => scroll_top,
+                        };
+                    viewport.set((scroll_top, Some(viewport_height)));
+                }
+            },
+        );
+    }
+
+    let window = match config {
+        Some(config) => {
+            let (scroll_top, viewport_height) = *viewport;
+            let viewport_height = viewport_height.unwrap_or(VIEWPORT_ROWS * config.row_height);
+            config.window(scroll_top, viewport_height, count)
+        }
+        None => VirtualWindow::all(count),
+    };
+    // The list scrolls itself, so its viewport is known before it was measured
+    let style = config.map(|config| {
+        format!(
+            "max-height: {}px; overflow-y: auto",
+            VIEWPORT_ROWS * config.row_height
+        )
+    });
+
+    VirtualList {
+        list_ref,
+        window,
+        style,
+        on_scroll,
+        config,
+        count,
+    }
+}
+
+/// Gets the enabled option a navigation key highlights, or `None` for other
+/// keys and when no option is enabled
+///
+/// ArrowDown and ArrowUp move from `current` to the next or previous enabled
+/// option, wrapping around; Home and End go to the first or last enabled
+/// option. Only the option data is consulted, so the target doesn't need to
+/// be rendered.
+pub(crate) fn next_enabled_option(
+    current: usize,
+    key: &str,
+    count: usize,
+    is_disabled: impl Fn(usize) -> bool,
+) -> Option<usize> {
+    if count == 0 {
+        return None;
+    }
+    let enabled = |index: &usize| !is_disabled(*index);
+    match key {
+        "ArrowDown" => (1..=count)
+            .map(|offset| (current + offset) % count)
+            .find(enabled),
+        "ArrowUp" => (1..=count)
+            .map(|offset| (current + count - offset % count) % count)
+            .find(enabled),
+        "Home" => (0..count).find(enabled),
+        "End" => (0..count).rev().find(enabled),
+        _ => None,
+    }
+}
+
+/// Characters typed in quick succession to jump to an option by its label
+#[derive(Debug, Clone, Default)]
+pub(crate) struct TypeAhead {
+    query: String,
+    last_key_time: f64,
+}
+
+impl TypeAhead {
+    /// Whether a key press extends the query
+    ///
+    /// Space is left to selection, and shortcuts are not typing.
+    pub(crate) fn accepts(e: &KeyboardEvent) -> bool {
+        let key = e.key();
+        key.chars().count() == 1 && key != " " && !e.ctrl_key() && !e.meta_key() && !e.alt_key()
+    }
+
+    /// Adds a typed character at time `now`, starting a new query after a
+    /// pause, and gets the query
+    pub(crate) fn push(&mut self, key: &str, now: f64) -> &str {
+        if now - self.last_key_time > TYPE_AHEAD_TIMEOUT_MS {
+            self.query.clear();
+        }
+        self.last_key_time = now;
+        self.query.push_str(&key.to_lowercase());
+        &self.query
+    }
+}
+
+/// Gets the enabled option whose label starts with `query`, ignoring case
+///
+/// A single character moves on from `current`, so typing it repeatedly
+/// cycles through the options starting with it; a longer query keeps
+/// `current` while it still matches. `label` gets the label of an option,
+/// or `None` for a disabled one.
+pub(crate) fn type_ahead_match<'a>(
+    query: &str,
+    current: usize,
+    count: usize,
+    label: impl Fn(usize) -> Option<&'a str>,
+) -> Option<usize> {
+    if count == 0 || query.is_empty() {
+        return None;
+    }
+    let start = if query.chars().count() == 1 {
+        current + 1
+    } else {
+        current
+    };
+    (0..count)
+        .map(|offset| (start + offset) % count)
+        .find(|index| label(*index).is_some_and(|label| label.to_lowercase().starts_with(query)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_window_at_top() {
+        let config = VirtualScrollConfig::new()
+            .with_row_height(20.0)
+            .with_overscan(2);
+        // 5 visible rows, one partially visible row and 2 overscan rows
+        assert_eq!(
+            config.window(0.0, 100.0, 1000),
+            VirtualWindow { start: 0, end: 8 }
+        );
+    }
+
+    #[test]
+    fn test_window_scrolled() {
+        let config = VirtualScrollConfig::new()
+            .with_row_height(20.0)
+            .with_overscan(2);
+        assert_eq!(
+            config.window(210.0, 100.0, 1000),
+            VirtualWindow { start: 8, end: 18 }
+        );
+        // Near the end the window is cut at the last row
+        assert_eq!(
+            config.window(19_950.0, 100.0, 1000),
+            VirtualWindow {
+                start: 995,
+                end: 1000
+            }
+        );
+        // A scroll position past a shrunken list renders nothing extra
+        assert_eq!(
+            config.window(5000.0, 100.0, 10),
+            VirtualWindow { start: 10, end: 10 }
+        );
+    }
+
+    #[test]
+    fn test_window_size_bounded_for_large_lists() {
+        let config = VirtualScrollConfig::default();
+        let count = 10_000;
+        let viewport_height = 320.0;
+        let bound = (viewport_height / config.row_height) as usize + 2 + 2 * config.overscan;
+
+        for scroll_top in (0..count)
+            .step_by(97)
+            .map(|row| row as f64 * config.row_height)
+        {
+            let window = config.window(scroll_top, viewport_height, count);
+            assert!(window.len() <= bound, "{:?} renders too many rows", window);
+            assert!(window.end <= count);
+        }
+    }
+
+    #[test]
+    fn test_next_enabled_option() {
+        let disabled = [false, true, false, false, true];
+        let is_disabled = |index: usize| disabled[index];
+
+        assert_eq!(next_enabled_option(0, "ArrowDown", 5, is_disabled), Some(2));
+        assert_eq!(next_enabled_option(3, "ArrowDown", 5, is_disabled), Some(0));
+        assert_eq!(next_enabled_option(0, "ArrowUp", 5, is_disabled), Some(3));
+        assert_eq!(next_enabled_option(2, "ArrowUp", 5, is_disabled), Some(0));
+        assert_eq!(next_enabled_option(3, "Home", 5, is_disabled), Some(0));
+        assert_eq!(next_enabled_option(0, "End", 5, is_disabled), Some(3));
+        assert_eq!(next_enabled_option(0, "Enter", 5, is_disabled), None);
+        assert_eq!(next_enabled_option(0, "ArrowDown", 2, |_| true), None);
+        assert_eq!(next_enabled_option(0, "End", 0, |_| false), None);
+    }
+
+    #[test]
+    fn test_navigation_reaches_unrendered_options() {
+        let config = VirtualScrollConfig::default();
+        let count = 10_000;
+        let window = config.window(0.0, 320.0, count);
+
+        let last = next_enabled_option(0, "End", count, |_| false).unwrap();
+        assert_eq!(last, count - 1);
+        assert!(!window.contains(last));
+        // Scrolling to the option brings it into the window
+        let scroll_top = config.scroll_to_reveal(last, 0.0, 320.0).unwrap();
+        assert!(config.window(scroll_top, 320.0, count).contains(last));
+    }
+
+    #[test]
+    fn test_type_ahead_query() {
+        let mut type_ahead = TypeAhead::default();
+        assert_eq!(type_ahead.push("U", 1000.0), "u");
+        assert_eq!(type_ahead.push("s", 1200.0), "us");
+        // A pause starts a new query
+        assert_eq!(type_ahead.push("e", 2000.0), "e");
+    }
+
+    #[test]
+    fn test_type_ahead_match() {
+        let labels = ["us-east-1", "us-west-2", "eu-west-1", "us-east-2"];
+        let label = |index: usize| (index != 3).then(|| labels[index]);
+
+        assert_eq!(type_ahead_match("u", 0, 4, label), Some(1));
+        // Repeating a character cycles, skipping disabled options
+        assert_eq!(type_ahead_match("u", 1, 4, label), Some(0));
+        assert_eq!(type_ahead_match("us-w", 0, 4, label), Some(1));
+        assert_eq!(type_ahead_match("us-e", 0, 4, label), Some(0));
+        assert_eq!(type_ahead_match("ap", 0, 4, label), None);
+        assert_eq!(type_ahead_match("", 0, 4, label), None);
+    }
+
+    #[test]
+    fn test_scroll_to_reveal() {
+        let config = VirtualScrollConfig::new().with_row_height(20.0);
+        // Visible rows need no scrolling
+        assert_eq!(config.scroll_to_reveal(3, 0.0, 100.0), None);
+        // Rows below scroll up to the bottom edge, rows above to the top edge
+        assert_eq!(config.scroll_to_reveal(9, 0.0, 100.0), Some(100.0));
+        assert_eq!(config.scroll_to_reveal(2, 200.0, 100.0), Some(40.0));
+        assert_eq!(config.scroll_to_reveal(9_999, 0.0, 100.0), Some(199_900.0));
+    }
+}
@@ -6,7 +6,7 @@
 //! The KeyValuePairs component provides a flexible way to display structured data as
 //! key-value pairs with support for multiple columns and semantic HTML for accessibility.
 
-use crate::internal::{BaseComponentProps, ClassBuilder};
+use crate::internal::{BaseComponentProps, ClassBuilder, apply_root_attributes};
 use yew::prelude::*;
 
 /// A single key-value pair item
@@ -200,7 +200,7 @@ pub fn key_value_pairs(props: &KeyValuePairsProps) -> Html {
         }
     });
 
-    html! {
+    let root = html! {
         <dl
             id={props.base.id.clone()}
             class={root_class}
@@ -209,7 +209,8 @@ pub fn key_value_pairs(props: &KeyValuePairsProps) -> Html {
         >
             { for pairs }
         </dl>
-    }
+    };
+    apply_root_attributes(root, &props.base)
 }
 
 #[cfg(test)]
@@ -308,7 +309,7 @@ mod tests {
 
     #[test]
     fn test_multiple_items() {
-        let items = vec![
+        let items = [
             KeyValuePair::new("Key 1", html! { "Value 1" }),
             KeyValuePair::new("Key 2", html! { "Value 2" }),
             KeyValuePair::new("Key 3", html! { "Value 3" }),
@@ -6,8 +6,6 @@
 //! This library provides Rust/WASM implementations of Cloudscape components
 //! using the Yew framework, optimized for performance and security.
 
-use wasm_bindgen::prelude::*;
-
 // Re-export design tokens
 pub use cloudscape_design_tokens as tokens;
 
@@ -22,7 +20,9 @@ pub mod button;
 pub mod button_dropdown;
 pub mod cards;
 pub mod checkbox;
+pub mod collection;
 pub mod column_layout;
+pub mod confirmation_modal;
 pub mod container;
 pub mod content_layout;
 pub mod copy_to_clipboard;
@@ -31,9 +31,14 @@ pub mod date_range_picker;
 pub mod drawer;
 pub mod expandable_section;
 pub mod file_upload;
+pub mod filter_summary;
+pub mod flash_service;
 pub mod flashbar;
 pub mod form_field;
+pub mod form_state;
+pub mod grid;
 pub mod header;
+pub mod i18n;
 pub mod icon;
 pub mod input;
 pub mod key_value_pairs;
@@ -41,18 +46,22 @@ pub mod link;
 pub mod modal;
 pub mod multiselect;
 pub mod pagination;
+pub mod password_strength_indicator;
 pub mod popover;
 pub mod progress_bar;
+pub mod property_filter;
 pub mod radio_group;
 pub mod select;
 pub mod side_navigation;
 pub mod space_between;
 pub mod spinner;
+pub mod split_panel;
 pub mod status_indicator;
 pub mod table;
 pub mod tabs;
 pub mod text_content;
 pub mod textarea;
+pub mod theme;
 pub mod tiles;
 pub mod toggle;
 pub mod token_group;
@@ -64,8 +73,10 @@ pub mod internal;
 // Re-export components for convenient access
 pub use alert::{Alert, AlertI18nStrings, AlertProps, AlertType, DismissDetail};
 pub use app_layout::{
-    AppLayout, AppLayoutProps, ContentType, NavigationChangeDetail, SplitPanelPosition,
-    SplitPanelPreferences, SplitPanelResizeDetail, SplitPanelToggleDetail, ToolsChangeDetail,
+    AppLayout, AppLayoutDrawer, AppLayoutProps, ContentType, DrawerChangeDetail,
+    DrawerResizeDetail, NAVIGATION_RAIL_WIDTH, NavigationChangeDetail, NavigationDisplay,
+    OVERLAY_BREAKPOINT, PanelResizeDetail, SplitPanelPosition, SplitPanelPreferences,
+    SplitPanelResizeDetail, SplitPanelToggleDetail, TOOLS_DRAWER_ID, ToolsChangeDetail,
 };
 pub use autosuggest::{
     Autosuggest, AutosuggestChangeDetail, AutosuggestOption, AutosuggestProps,
@@ -73,15 +84,32 @@ pub use autosuggest::{
 };
 pub use badge::{Badge, BadgeColor, BadgeProps};
 pub use box_component::{Box, BoxProps, BoxVariant, SpacingSize};
-pub use breadcrumbs::{BreadcrumbFollowEvent, BreadcrumbItem, Breadcrumbs, BreadcrumbsProps};
-pub use button::{Button, ButtonProps, ButtonVariant, FormAction, IconAlign};
+pub use breadcrumbs::{
+    BreadcrumbFollowEvent, BreadcrumbItem, Breadcrumbs, BreadcrumbsProps, RenameDetail,
+};
+pub use button::{Button, ButtonProps, ButtonToggleDetail, ButtonVariant, FormAction, IconAlign};
 pub use button_dropdown::{
     ButtonDropdown, ButtonDropdownItem, ButtonDropdownItemClickDetail, ButtonDropdownItemGroup,
     ButtonDropdownProps,
 };
-pub use cards::{CardDefinition, Cards, CardsProps, CardsSelectionDetail, CardsSelectionType};
+pub use cards::{
+    CardDefinition, CardMedia, Cards, CardsFollowDetail, CardsProps, CardsSelectionDetail,
+    CardsSelectionType,
+};
 pub use checkbox::{Checkbox, CheckboxChangeDetail, CheckboxProps};
-pub use column_layout::{BordersType, ColumnLayout, ColumnLayoutProps, ColumnVariant};
+#[cfg(feature = "url-state")]
+pub use collection::use_url_collection_state;
+pub use collection::{
+    CollectionState, UrlCollectionStateConfig, page_change_callback, sort_change_callback,
+    use_collection,
+};
+pub use column_layout::{
+    BordersType, ColumnBreakpoints, ColumnLayout, ColumnLayoutContext, ColumnLayoutProps,
+    ColumnVariant, use_column_layout_context,
+};
+pub use confirmation_modal::{
+    ConfirmationModal, ConfirmationModalI18nStrings, ConfirmationModalProps,
+};
 pub use container::{Container, ContainerProps, ContainerVariant, Media, MediaPosition};
 pub use content_layout::{ContentLayout, ContentLayoutProps};
 pub use copy_to_clipboard::{
@@ -89,8 +117,9 @@ pub use copy_to_clipboard::{
 };
 pub use date_picker::{DatePicker, DatePickerChangeDetail, DatePickerProps};
 pub use date_range_picker::{
-    DateRange, DateRangePicker, DateRangePickerChangeDetail, DateRangePickerProps,
-    RangeSelectorMode, RelativeOption, TimeUnit,
+    DateRange, DateRangePicker, DateRangePickerChangeDetail, DateRangePickerI18nStrings,
+    DateRangePickerProps, RangeSelectorMode, RangeValidationResult, RelativeOption,
+    RelativeRangeFormatter, TimeUnit, TimeUnitFormatter,
 };
 pub use drawer::{Drawer, DrawerDismissDetail, DrawerProps, DrawerSize};
 pub use expandable_section::{
@@ -98,60 +127,113 @@ pub use expandable_section::{
     ExpandableSectionVariant,
 };
 pub use file_upload::{
-    FileUpload, FileUploadChangeDetail, FileUploadFile, FileUploadI18nStrings, FileUploadProps,
+    FileRetryDetail, FileStatus, FileUpload, FileUploadCapture, FileUploadChangeDetail,
+    FileUploadFile, FileUploadFileState, FileUploadI18nStrings, FileUploadProps,
+};
+pub use filter_summary::{
+    FilterSummary, FilterSummaryChip, FilterSummaryDismissDetail, FilterSummaryI18nStrings,
+    FilterSummaryProps, FilterSummaryToken,
+};
+pub use flash_service::{
+    FlashId, FlashOverflow, FlashbarHandle, FlashbarProvider, FlashbarProviderProps, use_flashbar,
+};
+pub use flashbar::{
+    Flashbar, FlashbarDismissDetail, FlashbarI18nStrings, FlashbarItem, FlashbarProgress,
+    FlashbarProps, FlashbarType,
+};
+pub use form_field::{FormField, FormFieldContext, FormFieldProps, ShowErrorWhen, ValidationState};
+pub use form_state::{
+    FieldState, FormState, FormStateContext, FormStateProvider, FormStateProviderProps,
+    use_field_state, use_form_state,
 };
-pub use flashbar::{Flashbar, FlashbarDismissDetail, FlashbarItem, FlashbarProps, FlashbarType};
-pub use form_field::{FormField, FormFieldProps};
+pub use grid::{GRID_COLUMNS, Grid, GridDefinition, GridProps, ResponsiveValue};
 pub use header::{Header, HeaderProps, HeaderVariant};
+pub use i18n::{I18nContext, I18nProvider, I18nProviderProps, use_i18n};
 pub use icon::{Icon, IconProps, IconSize, IconVariant};
-pub use input::{Input, InputChangeDetail, InputProps, InputType};
+pub use input::{
+    Input, InputChangeDetail, InputI18nStrings, InputProps, InputType, TrailingActionContext,
+    use_trailing_action_context,
+};
 pub use key_value_pairs::{KeyValuePair, KeyValuePairs, KeyValuePairsProps};
-pub use link::{FollowDetail, FollowEvent, Link, LinkColor, LinkFontSize, LinkProps, LinkVariant};
+pub use link::{
+    FollowDetail, FollowEvent, Link, LinkColor, LinkContext, LinkFontSize, LinkProps, LinkProvider,
+    LinkProviderProps, LinkVariant, use_link_context,
+};
 pub use modal::{DismissReason, Modal, ModalDismissDetail, ModalProps, ModalSize};
 pub use multiselect::{
-    FilteringType, Multiselect, MultiselectChangeDetail, MultiselectOption, MultiselectProps,
+    FilteringType, Multiselect, MultiselectChangeDetail, MultiselectI18nStrings, MultiselectOption,
+    MultiselectProps,
 };
 pub use pagination::{
     Pagination, PaginationChangeDetail, PaginationI18nStrings, PaginationPageClickDetail,
     PaginationProps,
 };
+pub use password_strength_indicator::{
+    MAX_PASSWORD_SCORE, PasswordStrengthIndicator, PasswordStrengthIndicatorI18nStrings,
+    PasswordStrengthIndicatorProps,
+};
 pub use popover::{Popover, PopoverDismissDetail, PopoverPosition, PopoverProps, PopoverSize};
 pub use progress_bar::{ProgressBar, ProgressBarProps, ProgressBarStatus, ProgressBarVariant};
+pub use property_filter::{
+    AndOr, DEFAULT_OPERATORS, FilterToken, FilteringOption, FilteringProperty, PropertyFilter,
+    PropertyFilterI18nStrings, PropertyFilterLoadItemsDetail, PropertyFilterProps,
+    PropertyFilterQuery,
+};
 pub use radio_group::{
     RadioGroup, RadioGroupChangeDetail, RadioGroupDirection, RadioGroupItem, RadioGroupProps,
 };
-pub use select::{Select, SelectChangeDetail, SelectOption, SelectProps};
+pub use select::{
+    Select, SelectChangeDetail, SelectOption, SelectProps, TriggerRenderContext, TriggerVariant,
+};
 pub use side_navigation::{
     ChangeDetail as SideNavigationChangeDetail, FollowDetail as SideNavigationFollowDetail,
-    SideNavigation, SideNavigationHeader, SideNavigationItem, SideNavigationItemType,
-    SideNavigationProps,
+    SideNavigation, SideNavigationHeader, SideNavigationI18nStrings, SideNavigationItem,
+    SideNavigationItemType, SideNavigationProps,
 };
 pub use space_between::{
     SpaceBetween, SpaceBetweenAlignment, SpaceBetweenDirection, SpaceBetweenProps,
     SpaceBetweenSize,
 };
 pub use spinner::{Spinner, SpinnerProps, SpinnerSize, SpinnerVariant};
+pub use split_panel::{SplitPanel, SplitPanelI18nStrings, SplitPanelProps};
 pub use status_indicator::{
     StatusIndicator, StatusIndicatorColor, StatusIndicatorProps, StatusIndicatorType,
 };
 pub use table::{
-    SelectionType, SortDirection, SortingState, Table, TableColumn, TableProps,
-    TableSelectionDetail, TableSortDetail,
+    ColumnWidthsChangeDetail, ExpandToggleDetail, ExpandableRowsConfig, FilterChangeDetail,
+    RowSeverity, RowStyle, SelectAllMode, SelectionState, SelectionType, SortDirection,
+    SortingState, Table, TableCellEditDetail, TableCellEditor, TableColumn, TableEditConfig,
+    TableI18nStrings, TableLoadingVariant, TablePaginationConfig, TableProps, TableSelectionDetail,
+    TableSortDetail, TextFilterConfig,
+};
+pub use tabs::{
+    Tab, TabChangeDetail, TabDismissDetail, TabRenderStrategy, TabReorderDetail, Tabs,
+    TabsI18nStrings, TabsProps, TabsVariant,
 };
-pub use tabs::{Tab, TabChangeDetail, TabDismissDetail, Tabs, TabsProps, TabsVariant};
 pub use text_content::{TextContent, TextContentProps};
-pub use textarea::{Textarea, TextareaChangeDetail, TextareaProps};
+pub use textarea::{Textarea, TextareaChangeDetail, TextareaI18nStrings, TextareaProps};
+pub use theme::{
+    ColorScheme, UseColorSchemeHandle, apply_mode, apply_theme_overrides, remove_mode,
+    remove_theme_overrides, use_color_scheme,
+};
 pub use tiles::{TileItem, Tiles, TilesChangeDetail, TilesProps};
 pub use toggle::{Toggle, ToggleChangeDetail, ToggleProps};
-pub use token_group::{Token, TokenDismissDetail, TokenGroup, TokenGroupAlignment, TokenGroupProps};
+pub use token_group::{
+    Token, TokenDismissDetail, TokenGroup, TokenGroupAlignment, TokenGroupI18nStrings,
+    TokenGroupOverflowMode, TokenGroupProps, TokenGroupReorderDetail,
+};
 pub use top_navigation::{
-    IdentityFollowDetail, TopNavigation, TopNavigationI18nStrings, TopNavigationIdentity,
-    TopNavigationLogo, TopNavigationProps, TopNavigationUtility, UtilityButtonVariant,
-    UtilityClickDetail, UtilityFollowDetail, UtilityType,
+    IdentityFollowDetail, MenuDropdownItem, SearchToggleDetail, TopNavigation,
+    TopNavigationI18nStrings, TopNavigationIdentity, TopNavigationLogo, TopNavigationProps,
+    TopNavigationUtility, UtilityButtonVariant, UtilityClickDetail, UtilityFollowDetail,
+    UtilityType,
 };
 
 // Re-export commonly used internal types
-pub use internal::CustomEvent;
+pub use internal::{
+    Breakpoint, BusyAnnouncer, CustomEvent, DateGranularity, DropdownStatus, LoadItemsDetail,
+    OptionGroup, PortalContext, VirtualScrollConfig, use_is_app_busy,
+};
 
 /// Library version
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
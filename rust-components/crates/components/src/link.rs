@@ -7,9 +7,10 @@
 //! or to external resources. They support different visual variants and can display
 //! an external icon for links that open in new windows or tabs.
 
+use crate::internal::live_region::VISUALLY_HIDDEN_STYLE;
 use crate::internal::{
     AnalyticsMetadata, BaseComponentProps, ClassBuilder, ClickDetail, ClickEvent,
-    ComponentMetadata, CustomEvent,
+    ComponentMetadata, CustomEvent, apply_root_attributes,
 };
 use web_sys::{KeyboardEvent, MouseEvent};
 use yew::prelude::*;
@@ -105,6 +106,112 @@ pub struct FollowDetail {
 /// Follow event type
 pub type FollowEvent = CustomEvent<FollowDetail>;
 
+/// App-wide navigation shared by a [`LinkProvider`]
+#[derive(Clone, PartialEq)]
+pub struct LinkContext {
+    /// Called with the internal links followed without their own `on_follow`
+    pub on_follow: Callback<FollowDetail>,
+}
+
+/// Properties for the LinkProvider component
+#[derive(Properties, PartialEq, Clone)]
+pub struct LinkProviderProps {
+    /// Navigates to a followed link, typically by pushing its `href` to the
+    /// app's router
+    pub on_follow: Callback<FollowDetail>,
+
+    /// The app, or the part of it whose links are routed
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// Routes links through the app's router.
+///
+/// Links, Breadcrumbs and SideNavigation links inside the provider that
+/// have no `on_follow` of their own hand internal, same-tab links to
+/// `on_follow` and cancel the native navigation. External links, links
+/// opening in another tab and clicks with modifier keys keep their native
+/// behavior.
+///
+/// # Example
+///
+/// ```rust
+/// use cloudscape_components::{FollowDetail, Link, LinkProvider};
+/// use yew::prelude::*;
+///
+/// let on_follow = Callback::from(|detail: FollowDetail| {
+///     let _path = detail.href;
+///     // Push the path to the router here
+/// });
+///
+/// # let _: Html =
+/// html! {
+///     <LinkProvider on_follow={on_follow}>
+///         <Link href="/dashboard">{ "Dashboard" }</Link>
+///     </LinkProvider>
+/// }
+/// # ;
+/// ```
+#[function_component(LinkProvider)]
+pub fn link_provider(props: &LinkProviderProps) -> Html {
+    let context = LinkContext {
+        on_follow: props.on_follow.clone(),
+    };
+
+    html! {
+        <ContextProvider<LinkContext> context={context}>
+            { props.children.clone() }
+        </ContextProvider<LinkContext>>
+    }
+}
+
+/// Gets the navigation of the closest [`LinkProvider`], if any
+#[hook]
+pub fn use_link_context() -> Option<LinkContext> {
+    use_context::<LinkContext>()
+}
+
+/// Whether an href points into the app: a path rather than a URL with a
+/// scheme or host, and not a fragment of the current page
+pub(crate) fn is_internal_href(href: &str) -> bool {
+    if href.is_empty() || href.starts_with('#') || href.starts_with("//") {
+        return false;
+    }
+    // A scheme ends with the first `:`, before any path, query or fragment
+    !href
+        .find([':', '/', '?', '#'])
+        .is_some_and(|index| href[index..].starts_with(':'))
+}
+
+/// Whether a followed link is handed to the app's router
+fn is_routed(detail: &FollowDetail) -> bool {
+    !detail.external
+        && detail
+            .target
+            .as_deref()
+            .is_none_or(|target| target == "_self")
+        && detail.href.as_deref().is_some_and(is_internal_href)
+}
+
+/// Follow handler of a component whose consumer set no `on_follow`
+///
+/// Hands routed links to the closest [`LinkProvider`] and cancels their
+/// native navigation. `to_detail` describes the component's own follow
+/// event detail as a link.
+pub(crate) fn provided_follow<T: 'static>(
+    context: Option<LinkContext>,
+    to_detail: impl Fn(&T) -> FollowDetail + 'static,
+) -> Option<Callback<CustomEvent<T>>> {
+    let context = context?;
+    Some(Callback::from(move |mut event: CustomEvent<T>| {
+        let detail = to_detail(&event.detail);
+        if is_routed(&detail) {
+            event.prevent_default();
+            context.on_follow.emit(detail);
+        }
+    }))
+}
+
 /// Properties for the Link component
 #[derive(Properties, PartialEq, Clone)]
 pub struct LinkProps {
@@ -118,7 +225,9 @@ pub struct LinkProps {
     ///   Use for links where context doesn't imply interactivity.
     /// - `Secondary`: Minimal styling with underline on hover. Use when
     ///   interactivity is strongly implied by context (tables, lists).
-    /// - `Info`: For info links that open help panels.
+    /// - `Info`: For info links that open help panels, such as next to a
+    ///   FormField label. Rendered in small bold text, and reads "Info"
+    ///   without children.
     /// - `ValueLarge`: For displaying large values as links.
     #[prop_or_default]
     pub variant: LinkVariant,
@@ -148,6 +257,7 @@ pub struct LinkProps {
     /// Marks the link as external by adding an icon after the text
     ///
     /// If `href` is provided, opens the link in a new tab when clicked.
+    /// Screen readers announce `external_icon_aria_label` after the text.
     #[prop_or_default]
     pub external: bool,
 
@@ -183,7 +293,8 @@ pub struct LinkProps {
     ///
     /// If the link has no `href` provided, it will be called on all clicks.
     /// Use this event and prevent default browser navigation (by calling
-    /// `prevent_default`) to implement client-side routing.
+    /// `prevent_default`) to implement client-side routing. Without it,
+    /// the closest [`LinkProvider`] routes internal links.
     #[prop_or_default]
     pub on_follow: Option<Callback<FollowEvent>>,
 
@@ -206,20 +317,25 @@ pub struct LinkProps {
 /// ## Basic link
 ///
 /// ```rust
+/// # use yew::prelude::*;
 /// use cloudscape_components::{Link, LinkVariant};
 ///
+/// # let _: Html =
 /// html! {
 ///     <Link href="https://example.com" variant={LinkVariant::Primary}>
 ///         { "Learn more" }
 ///     </Link>
 /// }
+/// # ;
 /// ```
 ///
 /// ## External link
 ///
 /// ```rust
+/// # use yew::prelude::*;
 /// use cloudscape_components::{Link, LinkVariant};
 ///
+/// # let _: Html =
 /// html! {
 ///     <Link
 ///         href="https://docs.example.com"
@@ -229,6 +345,7 @@ pub struct LinkProps {
 ///         { "Documentation" }
 ///     </Link>
 /// }
+/// # ;
 /// ```
 ///
 /// ## Button-style link (no href)
@@ -237,16 +354,17 @@ pub struct LinkProps {
 /// use cloudscape_components::{Link, FollowEvent};
 /// use yew::prelude::*;
 ///
-/// let on_follow = Callback::from(|event: FollowEvent| {
+/// let on_follow = Callback::from(|_event: FollowEvent| {
 ///     // Handle navigation programmatically
-///     log::info!("Link clicked");
 /// });
 ///
+/// # let _: Html =
 /// html! {
 ///     <Link on_follow={on_follow}>
 ///         { "Click me" }
 ///     </Link>
 /// }
+/// # ;
 /// ```
 ///
 /// ## Client-side routing
@@ -258,20 +376,27 @@ pub struct LinkProps {
 /// let on_follow = Callback::from(|mut event: FollowEvent| {
 ///     event.prevent_default(); // Prevent default navigation
 ///     // Implement your routing logic here
-///     if let Some(href) = &event.detail.href {
+///     if let Some(_href) = &event.detail.href {
 ///         // Navigate using your router
 ///     }
 /// });
 ///
+/// # let _: Html =
 /// html! {
 ///     <Link href="/dashboard" on_follow={on_follow}>
 ///         { "Dashboard" }
 ///     </Link>
 /// }
+/// # ;
 /// ```
 #[function_component(Link)]
 pub fn link(props: &LinkProps) -> Html {
     let _metadata = ComponentMetadata::new("Link");
+    let link_context = use_link_context();
+    let on_follow = props
+        .on_follow
+        .clone()
+        .or_else(|| provided_follow(link_context, FollowDetail::clone));
 
     // Determine if this is a button or anchor
     let is_button = props.href.is_none();
@@ -298,7 +423,11 @@ pub fn link(props: &LinkProps) -> Html {
         .add_if(is_button, "awsui-link-button")
         .add(format!("awsui-link-variant-{}", props.variant.as_str()))
         .add(get_font_size_class(props.variant, props.font_size))
-        .add(get_color_class(props.variant, props.color));
+        .add(get_color_class(props.variant, props.color))
+        .add_if(
+            props.variant == LinkVariant::Info,
+            "awsui-link-font-weight-bold",
+        );
 
     let class = props.base.merge_classes(&classes.build());
 
@@ -326,20 +455,35 @@ pub fn link(props: &LinkProps) -> Html {
         .clone()
         .unwrap_or_else(|| "(opens in a new tab)".to_string());
 
+    // An explicit label replaces the content, so it names the icon too
+    let aria_label = props.aria_label.as_ref().map(|label| {
+        if props.external {
+            format!("{} {}", label, external_icon_label)
+        } else {
+            label.clone()
+        }
+    });
+
     // Create the link content
     let content = html! {
         <>
-            { props.children.clone() }
+            if props.children.is_empty() && props.variant == LinkVariant::Info {
+                { "Info" }
+            } else {
+                { props.children.clone() }
+            }
             if props.external {
-                <span class="awsui-link-icon-wrapper">
-                    { " " }
+                <span class="awsui-link-icon-wrapper" style={EXTERNAL_ICON_STYLE}>
+                    { "\u{a0}" }
                     <span
                         class="awsui-link-icon awsui-icon awsui-icon-external"
-                        aria-label={external_icon_label}
-                        role="img"
+                        aria-hidden="true"
                     >
                         { "↗" }
                     </span>
+                    <span class="awsui-link-external-label" style={VISUALLY_HIDDEN_STYLE}>
+                        { external_icon_label }
+                    </span>
                 </span>
             }
         </>
@@ -348,7 +492,7 @@ pub fn link(props: &LinkProps) -> Html {
     // Create event handlers
     let on_click_handler = create_click_handler(
         props.on_click.clone(),
-        props.on_follow.clone(),
+        on_follow.clone(),
         props.href.clone(),
         props.external,
         actual_target.clone(),
@@ -358,7 +502,7 @@ pub fn link(props: &LinkProps) -> Html {
     let on_keydown_handler = if is_button {
         Some(create_keydown_handler(
             props.on_click.clone(),
-            props.on_follow.clone(),
+            on_follow,
             props.href.clone(),
             props.external,
             actual_target.clone(),
@@ -368,14 +512,14 @@ pub fn link(props: &LinkProps) -> Html {
     };
 
     // Render as button or anchor
-    if is_button {
+    let root = if is_button {
         html! {
             <a
                 id={props.base.id.clone()}
                 class={class}
                 role="button"
                 tabindex="0"
-                aria-label={props.aria_label.clone()}
+                aria-label={aria_label}
                 onclick={on_click_handler}
                 onkeydown={on_keydown_handler.unwrap()}
                 data-analytics-metadata={analytics_attr}
@@ -391,16 +535,20 @@ pub fn link(props: &LinkProps) -> Html {
                 href={props.href.clone()}
                 target={actual_target}
                 rel={actual_rel}
-                aria-label={props.aria_label.clone()}
+                aria-label={aria_label}
                 onclick={on_click_handler}
                 data-analytics-metadata={analytics_attr}
             >
                 { content }
             </a>
         }
-    }
+    };
+    apply_root_attributes(root, &props.base)
 }
 
+/// Inline style of the external icon, kept on the line of the last word
+const EXTERNAL_ICON_STYLE: &str = "white-space: nowrap";
+
 /// Gets the font size CSS class based on variant and font size
 fn get_font_size_class(variant: LinkVariant, font_size: LinkFontSize) -> String {
     match variant {
@@ -420,15 +568,6 @@ fn get_color_class(variant: LinkVariant, color: LinkColor) -> String {
     format!("awsui-link-color-{}", actual_color)
 }
 
-/// Checks if a click is a plain left click without modifiers
-fn is_plain_left_click(event: &MouseEvent) -> bool {
-    event.button() == 0
-        && !event.ctrl_key()
-        && !event.shift_key()
-        && !event.alt_key()
-        && !event.meta_key()
-}
-
 /// Creates the click handler for the link
 fn create_click_handler(
     on_click: Option<Callback<ClickEvent>>,
@@ -452,7 +591,7 @@ fn create_click_handler(
         }
 
         // Fire follow event for plain left clicks (or all clicks if button)
-        if (is_button || is_plain_left_click(&e))
+        if (is_button || ClickEvent::from_mouse_event(&e).is_plain_left_click())
             && let Some(ref cb) = on_follow
         {
             let follow_detail = FollowDetail {
@@ -460,11 +599,8 @@ fn create_click_handler(
                 external,
                 target: target.clone(),
             };
-            let event = FollowEvent::new(follow_detail);
-            cb.emit(event.clone());
-
             // Prevent default navigation if prevented by callback
-            if event.default_prevented {
+            if FollowEvent::new(follow_detail).emit_to(cb) {
                 e.prevent_default();
             }
         }
@@ -607,4 +743,118 @@ mod tests {
 
         assert_ne!(detail1, detail2);
     }
+
+    #[test]
+    fn test_is_internal_href() {
+        assert!(is_internal_href("/dashboard"));
+        assert!(is_internal_href("settings?tab=general"));
+        assert!(is_internal_href("/search?q=a:b"));
+        assert!(!is_internal_href("https://example.com"));
+        assert!(!is_internal_href("mailto:team@example.com"));
+        assert!(!is_internal_href("//cdn.example.com/file"));
+        assert!(!is_internal_href("#section"));
+        assert!(!is_internal_href(""));
+    }
+
+    #[test]
+    fn test_provided_follow_routes_internal_links() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let routed = Rc::new(RefCell::new(Vec::new()));
+        let context = LinkContext {
+            on_follow: {
+                let routed = routed.clone();
+                Callback::from(move |detail: FollowDetail| routed.borrow_mut().push(detail.href))
+            },
+        };
+        let on_follow = provided_follow(Some(context), FollowDetail::clone).unwrap();
+        let follow = |href: &str, external: bool, target: Option<&str>| {
+            let event = FollowEvent::new(FollowDetail {
+                href: Some(href.to_string()),
+                external,
+                target: target.map(str::to_string),
+            });
+            event.emit_to(&on_follow)
+        };
+
+        assert!(follow("/dashboard", false, None));
+        assert!(!follow("/dashboard", true, None));
+        assert!(!follow("/dashboard", false, Some("_blank")));
+        assert!(!follow("https://example.com", false, None));
+        assert_eq!(*routed.borrow(), vec![Some("/dashboard".to_string())]);
+
+        // Without a provider, nothing is routed
+        assert!(provided_follow(None, FollowDetail::clone).is_none());
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod wasm_tests {
+    use super::*;
+    use gloo::events::EventListener;
+    use std::cell::Cell;
+    use std::rc::Rc;
+    use std::time::Duration;
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_test::*;
+    use web_sys::HtmlElement;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[derive(Properties, PartialEq)]
+    struct TestLinkProps {
+        prevent: bool,
+    }
+
+    #[function_component(TestLink)]
+    fn test_link(props: &TestLinkProps) -> Html {
+        let prevent = props.prevent;
+        let on_follow = Callback::from(move |mut event: FollowEvent| {
+            if prevent {
+                event.prevent_default();
+            }
+        });
+
+        html! {
+            <Link href="#link-follow-target" on_follow={on_follow}>{"Details"}</Link>
+        }
+    }
+
+    /// Clicks the rendered anchor and returns whether its native navigation
+    /// was canceled by the time the click reached the document
+    async fn click_link(prevent: bool) -> bool {
+        let root = gloo::utils::document().create_element("div").unwrap();
+        gloo::utils::body().append_child(&root).unwrap();
+        let app =
+            yew::Renderer::<TestLink>::with_root_and_props(root.clone(), TestLinkProps { prevent })
+                .render();
+        yew::platform::time::sleep(Duration::ZERO).await;
+
+        let canceled = Rc::new(Cell::new(None));
+        let listener = {
+            let canceled = canceled.clone();
+            EventListener::new(&gloo::utils::document(), "click", move |event| {
+                canceled.set(Some(event.default_prevented()));
+            })
+        };
+        let anchor = root
+            .query_selector("a")
+            .unwrap()
+            .unwrap()
+            .dyn_into::<HtmlElement>()
+            .unwrap();
+        anchor.click();
+
+        drop(listener);
+        app.destroy();
+        root.remove();
+        canceled.get().expect("click reached the document")
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_consumer_prevent_default_cancels_navigation() {
+        assert!(click_link(true).await);
+        assert!(!click_link(false).await);
+    }
 }
@@ -7,8 +7,14 @@
 //! blocking interaction with the rest of the page. It supports various sizes, custom
 //! header and footer content, and handles focus management and keyboard interactions.
 
-use crate::internal::{AriaAttributes, BaseComponentProps, ClassBuilder, CustomEvent};
-use web_sys::{KeyboardEvent, MouseEvent};
+use crate::internal::dev_warnings::{DevWarning, unknown_style_parts, use_dev_warnings};
+use crate::internal::{
+    AriaAttributes, BaseComponentProps, ClassBuilder, CustomEvent, Portal, PortalLayer,
+    apply_root_attributes,
+};
+use std::cell::RefCell;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement, KeyboardEvent, MouseEvent};
 use yew::prelude::*;
 
 /// Modal size variants
@@ -111,12 +117,27 @@ pub struct ModalProps {
     #[prop_or_default]
     pub close_aria_label: Option<String>,
 
+    /// Whether the modal asks to confirm an urgent or destructive action
+    ///
+    /// Renders the dialog with `role="alertdialog"`, so screen readers
+    /// announce it immediately.
+    #[prop_or_default]
+    pub alert_dialog: bool,
+
     /// Callback fired when the modal is dismissed
     ///
     /// The detail contains the reason for dismissal (closeButton, overlay, or keyboard)
     #[prop_or_default]
     pub on_dismiss: Option<Callback<CustomEvent<ModalDismissDetail>>>,
 
+    /// Whether to render the modal inline instead of in a portal
+    ///
+    /// By default the modal renders into a portal host under the document
+    /// body, so it isn't clipped by containers with `overflow: hidden` or
+    /// trapped in their stacking context.
+    #[prop_or_default]
+    pub disable_portal: bool,
+
     /// Modal content (children)
     #[prop_or_default]
     pub children: Children,
@@ -126,12 +147,177 @@ pub struct ModalProps {
     pub aria: AriaAttributes,
 }
 
+/// Part names accepted by `base.style`: the dialog box and its sections
+const STYLE_PARTS: &[&str] = &["dialog", "header", "content", "footer"];
+
+/// Misuse of Modal props reported in development builds
+fn dev_warnings(props: &ModalProps) -> Vec<DevWarning> {
+    unknown_style_parts("modal-unknown-style-part", &props.base.style, STYLE_PARTS)
+        .into_iter()
+        .collect()
+}
+
+/// Focusable elements of the dialog, in tab order
+const FOCUSABLE_SELECTOR: &str = "button:not([disabled]), a[href], input:not([disabled]), \
+     select:not([disabled]), textarea:not([disabled]), [tabindex]:not([tabindex='-1'])";
+
+/// Open modals from the bottom to the top
+///
+/// The body scroll stays locked while any modal is open, and only the
+/// topmost modal reacts to Escape.
+#[derive(Debug, Default)]
+struct ModalStack {
+    open: Vec<u32>,
+}
+
+impl ModalStack {
+    /// Puts a modal on top, returning whether it is the only open modal
+    fn push(&mut self, id: u32) -> bool {
+        self.open.retain(|open| *open != id);
+        self.open.push(id);
+        self.open.len() == 1
+    }
+
+    /// Removes a modal, returning whether it was the last open modal
+    fn remove(&mut self, id: u32) -> bool {
+        let len = self.open.len();
+        self.open.retain(|open| *open != id);
+        len > 0 && self.open.is_empty()
+    }
+
+    fn is_topmost(&self, id: u32) -> bool {
+        self.open.last() == Some(&id)
+    }
+}
+
+thread_local! {
+    static MODAL_STACK: RefCell<ModalStack> = RefCell::new(ModalStack::default());
+    /// The body `style` attribute from before the scroll was locked
+    static SAVED_BODY_STYLE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Gets the body style that locks scrolling, keeping the existing style
+fn locked_body_style(style: Option<&str>) -> String {
+    match style.map(|style| style.trim().trim_end_matches(';')) {
+        Some(style) if !style.is_empty() => format!("{}; overflow: hidden", style),
+        _ => "overflow: hidden".to_string(),
+    }
+}
+
+/// Gets the element Tab moves focus to when it would leave the dialog, or
+/// `None` when the browser can move focus itself
+///
+/// `current` is the position of the focused element among the `count`
+/// focusable elements, `None` when focus is elsewhere.
+fn wrapped_focus(current: Option<usize>, count: usize, backward: bool) -> Option<usize> {
+    if count == 0 {
+        return None;
+    }
+    match (current, backward) {
+        (None, false) => Some(0),
+        (None, true) | (Some(0), true) => Some(count - 1),
+        (Some(index), false) if index + 1 == count => Some(0),
+        _ => None,
+    }
+}
+
+fn focusable_elements(container: &Element) -> Vec<HtmlElement> {
+    let Ok(nodes) = container.query_selector_all(FOCUSABLE_SELECTOR) else {
+        return Vec::new();
+    };
+    (0..nodes.length())
+        .filter_map(|index| nodes.item(index))
+        .filter_map(|node| node.dyn_into::<HtmlElement>().ok())
+        .collect()
+}
+
+/// A modal's place in the stack while it is open, released when dropped
+///
+/// Opening locks the body scroll and moves focus into the dialog; closing
+/// unlocks the scroll once no modal is open and returns focus to where it
+/// was before.
+struct OpenModal {
+    id: u32,
+    previous_focus: Option<HtmlElement>,
+}
+
+impl OpenModal {
+    fn open(id: u32, container: &NodeRef) -> Self {
+        let document = gloo::utils::document();
+        let previous_focus = document
+            .active_element()
+            .and_then(|element| element.dyn_into::<HtmlElement>().ok());
+
+        if MODAL_STACK.with(|stack| stack.borrow_mut().push(id))
+            && let Some(body) = document.body()
+        {
+            let style = body.get_attribute("style");
+            let _ = body.set_attribute("style", &locked_body_style(style.as_deref()));
+            SAVED_BODY_STYLE.with(|saved| *saved.borrow_mut() = style);
+        }
+
+        // Focus the first control, skipping the dismiss button unless it is
+        // the only one
+        if let Some(container) = container.cast::<Element>() {
+            let focusable = focusable_elements(&container);
+            let initial = focusable
+                .iter()
+                .find(|element| {
+                    !element
+                        .matches(".awsui-modal-close-button")
+                        .unwrap_or(false)
+                })
+                .or(focusable.first());
+            if let Some(element) = initial {
+                let _ = element.focus();
+            }
+        }
+
+        Self { id, previous_focus }
+    }
+}
+
+impl Drop for OpenModal {
+    fn drop(&mut self) {
+        if MODAL_STACK.with(|stack| stack.borrow_mut().remove(self.id))
+            && let Some(body) = gloo::utils::document().body()
+        {
+            match SAVED_BODY_STYLE.with(|saved| saved.borrow_mut().take()) {
+                Some(style) => {
+                    let _ = body.set_attribute("style", &style);
+                }
+                None => {
+                    let _ = body.remove_attribute("style");
+                }
+            }
+        }
+
+        if let Some(ref element) = self.previous_focus
+            && element.is_connected()
+        {
+            let _ = element.focus();
+        }
+    }
+}
+
 /// Modal component for dialog/modal overlay with portal rendering.
 ///
 /// A component that displays content in a modal dialog overlay. The modal blocks
 /// interaction with the rest of the page and can be dismissed via close button,
 /// clicking the overlay, or pressing the Escape key.
 ///
+/// While open, the modal keeps focus inside the dialog, cycling Tab and
+/// Shift+Tab through its controls, and locks the page scroll. Focus returns
+/// to the previously focused element on close. With nested modals, only the
+/// topmost one is dismissed by Escape.
+///
+/// The modal renders in a portal unless `disable_portal` is set. Each modal
+/// gets its portal host when it opens, so a modal opened from another one
+/// stacks above it.
+///
+/// Style override parts (`base.style`): `dialog`, `header`, `content`,
+/// `footer`. The root keeps its own `display` and `visibility`.
+///
 /// # Example
 ///
 /// ```rust
@@ -177,46 +363,38 @@ pub struct ModalProps {
 /// ```
 #[function_component(Modal)]
 pub fn modal(props: &ModalProps) -> Html {
-    // Don't render anything if not visible
-    if !props.visible {
-        return html! {};
-    }
+    use_dev_warnings("Modal").check(|| dev_warnings(props));
 
-    // Generate unique IDs for ARIA
-    let control_id = use_state(|| {
+    // Generate unique IDs for ARIA and the modal stack
+    let modal_id = use_state(|| {
         use std::sync::atomic::{AtomicU32, Ordering};
         static COUNTER: AtomicU32 = AtomicU32::new(0);
-        let id = COUNTER.fetch_add(1, Ordering::Relaxed);
-        format!("awsui-modal-{}", id)
+        COUNTER.fetch_add(1, Ordering::Relaxed)
     });
+    let modal_id = *modal_id;
+    let control_id = format!("awsui-modal-{}", modal_id);
 
-    let header_id = format!("{}-header", *control_id);
-    let content_id = format!("{}-content", *control_id);
+    let header_id = format!("{}-header", control_id);
+    let content_id = format!("{}-content", control_id);
+    let container_ref = use_node_ref();
 
     // Track whether mousedown happened on overlay
     let mousedown_on_overlay = use_state(|| false);
 
-    // Lock body scroll when modal is visible
-    use_effect_with(props.visible, |visible| {
-        if *visible
-            && let Some(window) = web_sys::window()
-            && let Some(document) = window.document()
-            && let Some(body) = document.body()
-        {
-            // HtmlElement inherits from Element which has set_attribute
-            let _ = body.set_attribute("style", "overflow: hidden");
-        }
+    // Join the modal stack while visible, which locks the body scroll and
+    // moves focus into the dialog
+    {
+        let container_ref = container_ref.clone();
+        use_effect_with(props.visible, move |visible| {
+            let open = visible.then(|| OpenModal::open(modal_id, &container_ref));
+            move || drop(open)
+        });
+    }
 
-        // Cleanup: restore body scroll
-        move || {
-            if let Some(window) = web_sys::window()
-                && let Some(document) = window.document()
-                && let Some(body) = document.body()
-            {
-                let _ = body.remove_attribute("style");
-            }
-        }
-    });
+    // Don't render anything if not visible
+    if !props.visible {
+        return html! {};
+    }
 
     // Dismiss handler
     let dismiss = {
@@ -260,14 +438,33 @@ pub fn modal(props: &ModalProps) -> Html {
         })
     };
 
-    // Escape key handler
+    // Escape dismisses the topmost modal; Tab cycles focus within the dialog
     let on_keydown = {
         let dismiss = dismiss.clone();
-        Callback::from(move |e: KeyboardEvent| {
-            if e.key() == "Escape" {
+        let container_ref = container_ref.clone();
+        Callback::from(move |e: KeyboardEvent| match e.key().as_str() {
+            "Escape" if MODAL_STACK.with(|stack| stack.borrow().is_topmost(modal_id)) => {
                 e.prevent_default();
+                e.stop_propagation();
                 dismiss.emit(DismissReason::Keyboard);
             }
+            "Tab" => {
+                let Some(container) = container_ref.cast::<Element>() else {
+                    return;
+                };
+                let focusable = focusable_elements(&container);
+                let active = gloo::utils::document().active_element();
+                let current = focusable
+                    .iter()
+                    .position(|element| active.as_ref() == Some(&**element));
+                if focusable.is_empty() {
+                    e.prevent_default();
+                } else if let Some(index) = wrapped_focus(current, focusable.len(), e.shift_key()) {
+                    e.prevent_default();
+                    let _ = focusable[index].focus();
+                }
+            }
+            _ => {}
         })
     };
 
@@ -318,11 +515,12 @@ pub fn modal(props: &ModalProps) -> Html {
 
     let root_class = props.base.merge_classes(&root_classes.build());
 
-    html! {
+    let dialog = html! {
         <div
             id={props.base.id.clone()}
             class={root_class}
-            role="dialog"
+            style={props.base.merge_style(None)}
+            role={if props.alert_dialog { "alertdialog" } else { "dialog" }}
             aria-modal="true"
             aria-labelledby={header_id.clone()}
             aria-describedby={content_id.clone()}
@@ -334,7 +532,9 @@ pub fn modal(props: &ModalProps) -> Html {
                 onclick={on_overlay_click}
             >
                 <div
+                    ref={container_ref}
                     class={container_classes}
+                    style={props.base.part_style("dialog", None)}
                     onmousedown={{
                         let mousedown_on_overlay = mousedown_on_overlay.clone();
                         Callback::from(move |e: MouseEvent| {
@@ -349,7 +549,7 @@ pub fn modal(props: &ModalProps) -> Html {
                     })}
                 >
                     // Header
-                    <div class={header_classes}>
+                    <div class={header_classes} style={props.base.part_style("header", None)}>
                         <div class="awsui-modal-header-content">
                             <h2 id={header_id} class="awsui-modal-header-text">
                                 { header_content }
@@ -369,25 +569,92 @@ pub fn modal(props: &ModalProps) -> Html {
                     </div>
 
                     // Content
-                    <div id={content_id} class={content_classes}>
+                    <div
+                        id={content_id}
+                        class={content_classes}
+                        style={props.base.part_style("content", None)}
+                    >
                         { props.children.clone() }
                     </div>
 
                     // Footer (if provided)
                     if let Some(ref footer) = props.footer {
-                        <div class={footer_classes}>
+                        <div class={footer_classes} style={props.base.part_style("footer", None)}>
                             { footer.clone() }
                         </div>
                     }
                 </div>
             </div>
         </div>
+    };
+    let dialog = apply_root_attributes(dialog, &props.base);
+
+    if props.disable_portal {
+        dialog
+    } else {
+        html! {
+            <Portal layer={PortalLayer::Modal}>{ dialog }</Portal>
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::internal::StyleOverride;
+
+    #[test]
+    fn test_dev_warning_unknown_style_part() {
+        let props = yew::props!(ModalProps {
+            base: BaseComponentProps {
+                style: StyleOverride::new().with_part("overlay", "opacity: 0.5"),
+                ..Default::default()
+            },
+        });
+        let warnings = dev_warnings(&props);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, "modal-unknown-style-part");
+        assert!(warnings[0].message.contains("overlay"));
+    }
+
+    #[test]
+    fn test_modal_stack() {
+        let mut stack = ModalStack::default();
+        assert!(stack.push(1));
+        assert!(!stack.push(2));
+        assert!(stack.is_topmost(2));
+        assert!(!stack.is_topmost(1));
+
+        // The scroll stays locked until the last modal closes
+        assert!(!stack.remove(1));
+        assert!(stack.is_topmost(2));
+        assert!(stack.remove(2));
+        assert!(!stack.remove(2));
+    }
+
+    #[test]
+    fn test_locked_body_style() {
+        assert_eq!(locked_body_style(None), "overflow: hidden");
+        assert_eq!(locked_body_style(Some(" ")), "overflow: hidden");
+        assert_eq!(
+            locked_body_style(Some("margin: 0;")),
+            "margin: 0; overflow: hidden"
+        );
+    }
+
+    #[test]
+    fn test_wrapped_focus() {
+        // Tab past the last control wraps to the first, and back
+        assert_eq!(wrapped_focus(Some(2), 3, false), Some(0));
+        assert_eq!(wrapped_focus(Some(0), 3, true), Some(2));
+        // Within the dialog the browser moves focus
+        assert_eq!(wrapped_focus(Some(0), 3, false), None);
+        assert_eq!(wrapped_focus(Some(2), 3, true), None);
+        // Focus outside the dialog is brought back in
+        assert_eq!(wrapped_focus(None, 3, false), Some(0));
+        assert_eq!(wrapped_focus(None, 3, true), Some(2));
+        assert_eq!(wrapped_focus(None, 0, false), None);
+    }
 
     #[test]
     fn test_modal_size_default() {
@@ -495,3 +762,104 @@ mod tests {
         }
     }
 }
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod wasm_tests {
+    use super::*;
+    use std::time::Duration;
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[derive(Properties, PartialEq)]
+    struct TestModalProps {
+        visible: bool,
+    }
+
+    #[function_component(TestModal)]
+    fn test_modal(props: &TestModalProps) -> Html {
+        html! {
+            <>
+                <button id="opener">{"Open"}</button>
+                <Modal
+                    visible={props.visible}
+                    header="Title"
+                    footer={html! { <button id="confirm">{"Confirm"}</button> }}
+                >
+                    <input id="name" />
+                    <button disabled=true>{"Unavailable"}</button>
+                </Modal>
+            </>
+        }
+    }
+
+    fn active_element() -> Element {
+        gloo::utils::document().active_element().unwrap()
+    }
+
+    fn press_tab(target: &Element, shift_key: bool) {
+        let init = web_sys::KeyboardEventInit::new();
+        init.set_key("Tab");
+        init.set_shift_key(shift_key);
+        init.set_bubbles(true);
+        let event = KeyboardEvent::new_with_keyboard_event_init_dict("keydown", &init).unwrap();
+        target.dispatch_event(&event).unwrap();
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_focus_order() {
+        let root = gloo::utils::document().create_element("div").unwrap();
+        gloo::utils::body().append_child(&root).unwrap();
+        let mut app = yew::Renderer::<TestModal>::with_root_and_props(
+            root.clone(),
+            TestModalProps { visible: false },
+        )
+        .render();
+        yew::platform::time::sleep(Duration::ZERO).await;
+
+        let opener = gloo::utils::document()
+            .get_element_by_id("opener")
+            .unwrap()
+            .dyn_into::<HtmlElement>()
+            .unwrap();
+        opener.focus().unwrap();
+
+        app.update(TestModalProps { visible: true });
+        yew::platform::time::sleep(Duration::ZERO).await;
+
+        // The first control other than the dismiss button takes focus
+        assert_eq!(active_element().id(), "name");
+        assert!(
+            gloo::utils::body()
+                .get_attribute("style")
+                .unwrap_or_default()
+                .contains("overflow: hidden")
+        );
+
+        // Tab past the last control wraps to the dismiss button, and
+        // Shift+Tab wraps back; disabled controls are skipped
+        let confirm = gloo::utils::document()
+            .get_element_by_id("confirm")
+            .unwrap()
+            .dyn_into::<HtmlElement>()
+            .unwrap();
+        confirm.focus().unwrap();
+        press_tab(&confirm, false);
+        assert!(
+            active_element()
+                .matches(".awsui-modal-close-button")
+                .unwrap()
+        );
+        press_tab(&active_element(), true);
+        assert_eq!(active_element().id(), "confirm");
+
+        // Closing restores focus and the body style
+        app.update(TestModalProps { visible: false });
+        yew::platform::time::sleep(Duration::ZERO).await;
+        assert_eq!(active_element().id(), "opener");
+        assert_eq!(gloo::utils::body().get_attribute("style"), None);
+
+        app.destroy();
+        root.remove();
+    }
+}
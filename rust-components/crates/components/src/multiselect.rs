@@ -8,9 +8,36 @@
 //! and the dropdown remains open after selections. Supports disabled states,
 //! validation, descriptions, label tags, and filtering.
 
+use crate::button::{Button, ButtonVariant, FormAction};
+use crate::form_field::{use_field_association, use_validation_state};
+use crate::form_state::use_field_tracking;
+use crate::i18n::{I18nContext, use_i18n};
+use crate::internal::busy::use_register_busy;
+use crate::internal::dropdown::{
+    dropdown_style, use_dropdown_position, use_dropdown_transition, use_outside_dismiss,
+};
+use crate::internal::option_group::{
+    GroupedOptions, OptionGroup, OptionRow, option_id, render_group_header,
+};
+use crate::internal::option_list::{
+    DropdownStatus, ListState, ListStateSlots, ListStateStrings, LoadItemsDetail,
+    list_announcement, loaded_list_state, option_announcement, render_list_state,
+    render_load_status, use_load_items,
+};
+use crate::internal::virtual_list::{
+    TypeAhead, VirtualScrollConfig, next_enabled_option, type_ahead_match, use_virtual_list,
+};
 use crate::internal::{
-    AriaAttributes, BaseComponentProps, ClassBuilder, ComponentMetadata, CustomEvent,
+    AriaAttributes, BaseComponentProps, ClassBuilder, ClickEvent, ComponentMetadata, CustomEvent,
+    LiveRegion, apply_root_attributes,
+};
+use crate::popover::{Popover, PopoverDismissDetail, PopoverPosition, PopoverSize};
+use crate::select::{
+    TriggerRenderContext, TriggerVariant, clear_button_visible, inline_trigger_style, is_clear_key,
+    render_clear_button, trigger_aria_attributes,
 };
+use crate::token_group::{Token, TokenDismissDetail, TokenGroup};
+use wasm_bindgen::JsCast;
 use web_sys::MouseEvent;
 use yew::prelude::*;
 
@@ -98,8 +125,127 @@ pub struct MultiselectChangeDetail {
     pub selected_options: Vec<MultiselectOption>,
 }
 
+/// Internationalization strings for Multiselect
+///
+/// Unset strings fall back to the closest I18nProvider (keys under
+/// `"multiselect."`), then to English.
+#[derive(Clone, PartialEq, Default)]
+pub struct MultiselectI18nStrings {
+    /// Question asking to confirm clearing a large selection, with `{count}`
+    /// replaced by the number of selected options (default: "Remove {count}
+    /// selections?")
+    pub confirm_clear_text: Option<String>,
+    /// Label of the button confirming the clear (default: "Remove")
+    pub confirm_clear_button_label: Option<String>,
+    /// Label of the button keeping the selection (default: "Cancel")
+    pub cancel_clear_button_label: Option<String>,
+}
+
+/// Strings used to render a Multiselect after applying the fallback chain
+struct ResolvedStrings {
+    confirm_clear_text: String,
+    confirm_clear_button_label: String,
+    cancel_clear_button_label: String,
+}
+
+/// Resolves the strings for a selection of `count` options: `i18n_strings`
+/// prop, then the I18nProvider, then English
+fn resolve_strings(
+    strings: &MultiselectI18nStrings,
+    count: usize,
+    i18n: &I18nContext,
+) -> ResolvedStrings {
+    let resolve = |value: &Option<String>, key: &str, default: &str| {
+        value
+            .clone()
+            .or_else(|| i18n.message("multiselect", key).map(str::to_string))
+            .unwrap_or_else(|| default.to_string())
+    };
+
+    ResolvedStrings {
+        confirm_clear_text: resolve(
+            &strings.confirm_clear_text,
+            "confirm_clear_text",
+            "Remove {count} selections?",
+        )
+        .replace("{count}", &count.to_string()),
+        confirm_clear_button_label: resolve(
+            &strings.confirm_clear_button_label,
+            "confirm_clear_button_label",
+            "Remove",
+        ),
+        cancel_clear_button_label: resolve(
+            &strings.cancel_clear_button_label,
+            "cancel_clear_button_label",
+            "Cancel",
+        ),
+    }
+}
+
+/// Whether clearing `selected` options asks for confirmation first
+fn clear_needs_confirmation(selected: usize, threshold: Option<usize>) -> bool {
+    threshold.is_some_and(|threshold| selected > threshold)
+}
+
+/// Gets the tokens showing the selected options below the trigger
+///
+/// Disabled options, and all options of a disabled multiselect, can't be
+/// deselected, so their tokens are not dismissible.
+fn selection_tokens(selected: &[MultiselectOption], disabled: bool) -> Vec<Token> {
+    selected
+        .iter()
+        .map(|option| {
+            let locked = disabled || option.disabled;
+            Token::new(option.value.clone(), option.display_text())
+                .with_dismissible(!locked)
+                .with_disabled(locked)
+        })
+        .collect()
+}
+
+/// Gets the text of the trigger: the placeholder, or the number of selected
+/// options while their tokens are hidden
+fn trigger_text(selected: usize, hide_tokens: bool, placeholder: Option<&str>) -> String {
+    if selected > 0 && hide_tokens {
+        format!("{} selected", selected)
+    } else {
+        placeholder.unwrap_or("Choose options").to_string()
+    }
+}
+
+/// Toggles the enabled options of a group: selects those not yet selected,
+/// or deselects them all when all are selected
+fn toggle_group_selection(
+    selected: &[MultiselectOption],
+    group_options: &[MultiselectOption],
+) -> Vec<MultiselectOption> {
+    let enabled: Vec<&MultiselectOption> = group_options
+        .iter()
+        .filter(|option| !option.disabled)
+        .collect();
+    let is_selected = |option: &MultiselectOption| selected.iter().any(|s| s.value == option.value);
+
+    if enabled.iter().all(|option| is_selected(option)) {
+        selected
+            .iter()
+            .filter(|s| !enabled.iter().any(|option| option.value == s.value))
+            .cloned()
+            .collect()
+    } else {
+        let mut selection = selected.to_vec();
+        selection.extend(
+            enabled
+                .into_iter()
+                .filter(|option| !is_selected(option))
+                .cloned(),
+        );
+        selection
+    }
+}
+
 /// Properties for the Multiselect component
 #[derive(Properties, PartialEq, Clone)]
+#[allow(unpredictable_function_pointer_comparisons)]
 pub struct MultiselectProps {
     /// Base component properties
     #[prop_or_default]
@@ -113,6 +259,14 @@ pub struct MultiselectProps {
     #[prop_or_default]
     pub options: Vec<MultiselectOption>,
 
+    /// Groups of options listed after `options`, each under a header
+    ///
+    /// Clicking a header selects all enabled options of the group, or
+    /// deselects them when all are selected. Options of a disabled group
+    /// can't be selected.
+    #[prop_or_default]
+    pub option_groups: Vec<OptionGroup<MultiselectOption>>,
+
     /// Placeholder text shown when no options are selected
     #[prop_or_default]
     pub placeholder: Option<String>,
@@ -133,12 +287,20 @@ pub struct MultiselectProps {
     #[prop_or_default]
     pub warning: bool,
 
-    /// Maximum number of tokens to display before showing "+N more"
+    /// Maximum number of tokens to display before a toggle showing the
+    /// remaining ones
     ///
     /// Defaults to 3 if not specified.
     #[prop_or_default]
     pub token_limit: Option<usize>,
 
+    /// Whether the selected options are left out below the trigger
+    ///
+    /// The trigger then shows the number of selected options instead of the
+    /// placeholder.
+    #[prop_or_default]
+    pub hide_tokens: bool,
+
     /// Type of filtering behavior
     #[prop_or_default]
     pub filtering_type: FilteringType,
@@ -147,12 +309,34 @@ pub struct MultiselectProps {
     #[prop_or_default]
     pub filtering_placeholder: Option<String>,
 
+    /// Content shown when the filter matches none of the options
+    ///
+    /// Defaults to "No matches for '<filter>'" with a "Clear filter" action,
+    /// localized with the `multiselect.no_match_text` and
+    /// `multiselect.clear_filter_label` I18nProvider messages.
+    #[prop_or_default]
+    pub no_match_content: Option<Html>,
+
+    /// Content shown when there are no options at all
+    ///
+    /// Defaults to "No options" (`multiselect.empty_text`).
+    #[prop_or_default]
+    pub empty_content: Option<Html>,
+
     /// Whether to keep the dropdown open after selection
     ///
     /// Defaults to true. Set to false to close after each selection.
     #[prop_or_default]
     pub keep_open: Option<bool>,
 
+    /// Visual variant of the trigger
+    #[prop_or_default]
+    pub trigger_variant: TriggerVariant,
+
+    /// Renders the trigger when `trigger_variant` is `Custom`
+    #[prop_or_default]
+    pub render_trigger: Option<fn(TriggerRenderContext<MultiselectOption>) -> Html>,
+
     /// Callback fired when the selected options change
     ///
     /// The event detail contains the newly selected options.
@@ -194,13 +378,61 @@ pub struct MultiselectProps {
     /// Automatically focus the multiselect when component is mounted
     #[prop_or_default]
     pub auto_focus: bool,
+
+    /// Whether to show a button in the trigger that clears the selection
+    ///
+    /// Shown while options are selected and the multiselect is enabled.
+    /// Pressing Delete or Backspace on the closed trigger also clears the
+    /// selection. Clearing emits `on_change` with no selected options and
+    /// never opens the dropdown.
+    #[prop_or_default]
+    pub show_clear_button: bool,
+
+    /// Number of selected options above which clearing asks for
+    /// confirmation in a popover anchored to the clear button
+    ///
+    /// No confirmation is asked when not set.
+    #[prop_or_default]
+    pub confirm_clear_threshold: Option<usize>,
+
+    /// ARIA label of the clear button
+    ///
+    /// Default: "Clear selection"
+    #[prop_or_default]
+    pub clear_aria_label: Option<String>,
+
+    /// Internationalization strings
+    #[prop_or_default]
+    pub i18n_strings: MultiselectI18nStrings,
+
+    /// Renders only the options scrolled into view
+    ///
+    /// Set it for long option lists. Options are assumed to share the
+    /// configured row height; filtering applies to all options.
+    #[prop_or_default]
+    pub virtual_scroll: Option<VirtualScrollConfig>,
+
+    /// Loading state of options fetched with `on_load_items`
+    ///
+    /// Shown in a footer below the options: "Loading"
+    /// (`multiselect.loading_text`), an error (`multiselect.error_text`)
+    /// with a retry action (`multiselect.recovery_text`), or the end of
+    /// results (`multiselect.finished_text`).
+    #[prop_or_default]
+    pub loading_status: Option<DropdownStatus>,
+
+    /// Callback fired to load options: when the dropdown opens, shortly
+    /// after the filter text changes, and when the list is scrolled near its
+    /// end while `loading_status` is pending
+    #[prop_or_default]
+    pub on_load_items: Option<Callback<CustomEvent<LoadItemsDetail>>>,
 }
 
 /// Multiselect component for choosing multiple options from a dropdown list.
 ///
 /// A controlled dropdown component that allows users to select multiple options
-/// from a list of choices. Selected items are displayed as dismissible tokens above
-/// the dropdown. The component handles keyboard navigation, accessibility,
+/// from a list of choices. Selected items are displayed as dismissible tokens below
+/// the trigger. The component handles keyboard navigation, accessibility,
 /// and supports disabled options, descriptions, label tags, and filtering.
 ///
 /// # Example
@@ -245,54 +477,133 @@ pub struct MultiselectProps {
 #[function_component(Multiselect)]
 pub fn multiselect(props: &MultiselectProps) -> Html {
     let _metadata = ComponentMetadata::new("Multiselect");
+    use_register_busy(props.loading_status == Some(DropdownStatus::Loading));
     let multiselect_ref = use_node_ref();
+    let trigger_ref = use_node_ref();
+    let confirm_ref = use_node_ref();
+    let confirm_clear = use_state(|| false);
+    let i18n = use_i18n();
+    let strings = resolve_strings(&props.i18n_strings, props.selected_options.len(), &i18n);
+    let state_strings = ListStateStrings::resolve(&i18n, "multiselect");
+    let validation = use_validation_state(props.invalid, props.warning);
+
+    // Id and ARIA references, merged with the enclosing FormField
+    let association = use_field_association(
+        props.control_id.clone(),
+        props.aria.labelledby.clone(),
+        props.aria.describedby.clone(),
+    );
+    let field_aria = AriaAttributes {
+        labelledby: association.labelledby,
+        describedby: association.describedby,
+        ..props.aria.clone()
+    };
     let is_open = use_state(|| false);
+    let trigger_focused = use_state(|| false);
     let highlighted_index = use_state(|| 0usize);
     let filter_text = use_state(String::new);
 
     let keep_open = props.keep_open.unwrap_or(true);
     let token_limit = props.token_limit.unwrap_or(3);
 
-    // Filter options based on filtering type and filter text
-    let filtered_options = use_memo(
+    let list_id = use_state(|| {
+        use std::sync::atomic::{AtomicU32, Ordering};
+        static COUNTER: AtomicU32 = AtomicU32::new(0);
+        let id = COUNTER.fetch_add(1, Ordering::Relaxed);
+        format!("awsui-multiselect-{}", id)
+    });
+
+    // Filter options based on filtering type and filter text; a group shows
+    // while any of its options matches
+    let grouped = use_memo(
         (
             props.options.clone(),
+            props.option_groups.clone(),
             (*filter_text).clone(),
             props.filtering_type.clone(),
         ),
-        |(options, filter, filtering_type)| {
-            if *filtering_type == FilteringType::Auto && !filter.is_empty() {
-                let filter_lower = filter.to_lowercase();
-                options
-                    .iter()
-                    .filter(|opt| {
-                        opt.display_text().to_lowercase().contains(&filter_lower)
-                            || opt
-                                .description
-                                .as_ref()
-                                .is_some_and(|d| d.to_lowercase().contains(&filter_lower))
-                    })
-                    .cloned()
-                    .collect::<Vec<_>>()
-            } else {
-                options.clone()
-            }
+        |(options, groups, filter, filtering_type)| {
+            let filter_lower = filter.to_lowercase();
+            let filtering = *filtering_type == FilteringType::Auto && !filter.is_empty();
+            GroupedOptions::new(
+                options,
+                groups,
+                |opt| {
+                    !filtering
+                        || opt.display_text().to_lowercase().contains(&filter_lower)
+                        || opt
+                            .description
+                            .as_ref()
+                            .is_some_and(|d| d.to_lowercase().contains(&filter_lower))
+                },
+                |opt| opt.disabled = true,
+            )
         },
     );
 
+    let type_ahead = use_mut_ref(TypeAhead::default);
+    let virtual_list = use_virtual_list(
+        props.virtual_scroll,
+        grouped.rows.len(),
+        grouped.row_of(*highlighted_index),
+        *is_open,
+    );
+
+    // Explain an empty list; the state row is not an option, so keyboard
+    // navigation skips it
+    let filtering_text = if props.filtering_type == FilteringType::None {
+        ""
+    } else {
+        filter_text.as_str()
+    };
+    let list_state = loaded_list_state(
+        ListState::of(grouped.options.len(), filtering_text),
+        props.loading_status,
+    );
+    let dropdown_ref = use_node_ref();
+    let load_items = use_load_items(
+        *is_open,
+        filtering_text.to_string(),
+        props.loading_status,
+        props.options.len()
+            + props
+                .option_groups
+                .iter()
+                .map(|group| group.options.len())
+                .sum::<usize>(),
+        dropdown_ref.clone(),
+        props.on_load_items.clone(),
+    );
+    let on_list_scroll = {
+        let on_virtual_scroll = virtual_list.on_scroll.clone();
+        let on_load_scroll = load_items.on_scroll.clone();
+        Callback::from(move |e: Event| {
+            on_virtual_scroll.emit(e.clone());
+            on_load_scroll.emit(e);
+        })
+    };
+
     // Handle dropdown toggle
-    let on_trigger_click = {
+    let toggle = {
         let is_open = is_open.clone();
         let disabled = props.disabled;
 
-        Callback::from(move |e: MouseEvent| {
-            e.prevent_default();
+        Callback::from(move |()| {
             if !disabled {
                 is_open.set(!*is_open);
             }
         })
     };
 
+    let on_trigger_click = {
+        let toggle = toggle.clone();
+
+        Callback::from(move |e: MouseEvent| {
+            e.prevent_default();
+            toggle.emit(());
+        })
+    };
+
     // Handle option selection/deselection
     let on_option_click = {
         let is_open = is_open.clone();
@@ -329,6 +640,20 @@ pub fn multiselect(props: &MultiselectProps) -> Html {
         })
     };
 
+    // Toggle all enabled options of a group
+    let on_group_click = {
+        let on_change = props.on_change.clone();
+        let selected_options = props.selected_options.clone();
+
+        Callback::from(move |group_options: Vec<MultiselectOption>| {
+            if let Some(callback) = &on_change {
+                callback.emit(CustomEvent::new_non_cancelable(MultiselectChangeDetail {
+                    selected_options: toggle_group_selection(&selected_options, &group_options),
+                }));
+            }
+        })
+    };
+
     // Handle token dismiss
     let on_token_dismiss = {
         let on_change = props.on_change.clone();
@@ -352,6 +677,16 @@ pub fn multiselect(props: &MultiselectProps) -> Html {
         })
     };
 
+    // Report the value and blur to the closest FormStateProvider
+    let on_field_blur = use_field_tracking(
+        props
+            .selected_options
+            .iter()
+            .map(|option| option.value.as_str())
+            .collect::<Vec<_>>()
+            .join("\n"),
+    );
+
     // Handle blur event
     let on_blur_event = {
         let on_blur = props.on_blur.clone();
@@ -361,6 +696,8 @@ pub fn multiselect(props: &MultiselectProps) -> Html {
             // Close dropdown on blur
             is_open.set(false);
 
+            on_field_blur.emit(());
+
             if let Some(callback) = &on_blur {
                 callback.emit(());
             }
@@ -389,67 +726,134 @@ pub fn multiselect(props: &MultiselectProps) -> Html {
         })
     };
 
+    let on_clear_filter = {
+        let filter_text = filter_text.clone();
+        Callback::from(move |()| filter_text.set(String::new()))
+    };
+
+    let show_clear = clear_button_visible(
+        props.show_clear_button,
+        !props.selected_options.is_empty(),
+        props.disabled,
+        false,
+    );
+
+    // Clear the selection, keeping focus on the trigger as the clear button
+    // disappears
+    let clear = {
+        let on_change = props.on_change.clone();
+        let confirm_clear = confirm_clear.clone();
+        let trigger_ref = trigger_ref.clone();
+
+        Callback::from(move |()| {
+            confirm_clear.set(false);
+            if let Some(callback) = &on_change {
+                callback.emit(CustomEvent::new_non_cancelable(MultiselectChangeDetail {
+                    selected_options: Vec::new(),
+                }));
+            }
+            if let Some(trigger) = trigger_ref.cast::<web_sys::HtmlElement>() {
+                let _ = trigger.focus();
+            }
+        })
+    };
+
+    // Clear, or ask first when the selection is large
+    let request_clear = {
+        let clear = clear.clone();
+        let confirm_clear = confirm_clear.clone();
+        let needs_confirmation =
+            clear_needs_confirmation(props.selected_options.len(), props.confirm_clear_threshold);
+
+        Callback::from(move |()| {
+            if needs_confirmation {
+                confirm_clear.set(true);
+            } else {
+                clear.emit(());
+            }
+        })
+    };
+
+    let cancel_clear = {
+        let confirm_clear = confirm_clear.clone();
+        Callback::from(move |()| confirm_clear.set(false))
+    };
+
+    // Move focus into the confirmation, starting on the safe choice
+    use_effect_with(*confirm_clear, {
+        let confirm_ref = confirm_ref.clone();
+        move |open| {
+            if *open
+                && let Some(container) = confirm_ref.cast::<web_sys::Element>()
+                && let Ok(Some(button)) = container.query_selector("button")
+                && let Ok(button) = button.dyn_into::<web_sys::HtmlElement>()
+            {
+                let _ = button.focus();
+            }
+            || ()
+        }
+    });
+
     // Handle keyboard navigation
     let on_key_down = {
         let is_open = is_open.clone();
         let highlighted_index = highlighted_index.clone();
-        let filtered_options = (*filtered_options).clone();
+        let grouped = grouped.clone();
         let on_change = props.on_change.clone();
         let selected_options = props.selected_options.clone();
         let disabled = props.disabled;
+        let request_clear = request_clear.clone();
+        let trigger_ref = trigger_ref.clone();
+        let type_ahead = type_ahead.clone();
 
         Callback::from(move |e: KeyboardEvent| {
             if disabled {
                 return;
             }
 
+            // Keys typed into the filter input edit the filter text
+            let from_filter = e.target_dyn_into::<web_sys::HtmlInputElement>().is_some();
             let key = e.key();
             match key.as_str() {
-                "ArrowDown" => {
+                key if is_clear_key(key)
+                    && show_clear
+                    && !*is_open
+                    && e.target()
+                        .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+                        == trigger_ref.cast::<web_sys::Element>() =>
+                {
+                    e.prevent_default();
+                    request_clear.emit(());
+                }
+                "ArrowDown" | "ArrowUp" => {
                     e.prevent_default();
                     if !*is_open {
                         is_open.set(true);
-                    } else if !filtered_options.is_empty() {
-                        // Move to next non-disabled option
-                        let mut new_index = *highlighted_index;
-                        loop {
-                            new_index = (new_index + 1) % filtered_options.len();
-                            if new_index == *highlighted_index
-                                || !filtered_options[new_index].disabled
-                            {
-                                break;
-                            }
-                        }
-                        highlighted_index.set(new_index);
+                    } else if let Some(index) = next_enabled_option(
+                        *highlighted_index,
+                        &key,
+                        grouped.options.len(),
+                        |index| grouped.options[index].disabled,
+                    ) {
+                        highlighted_index.set(index);
                     }
                 }
-                "ArrowUp" => {
+                "Home" | "End" if *is_open && !from_filter => {
                     e.prevent_default();
-                    if !*is_open {
-                        is_open.set(true);
-                    } else if !filtered_options.is_empty() {
-                        // Move to previous non-disabled option
-                        let mut new_index = *highlighted_index;
-                        loop {
-                            new_index = if new_index == 0 {
-                                filtered_options.len() - 1
-                            } else {
-                                new_index - 1
-                            };
-                            if new_index == *highlighted_index
-                                || !filtered_options[new_index].disabled
-                            {
-                                break;
-                            }
-                        }
-                        highlighted_index.set(new_index);
+                    if let Some(index) = next_enabled_option(
+                        *highlighted_index,
+                        &key,
+                        grouped.options.len(),
+                        |index| grouped.options[index].disabled,
+                    ) {
+                        highlighted_index.set(index);
                     }
                 }
                 " " | "Enter" => {
                     e.prevent_default();
-                    if *is_open && !filtered_options.is_empty() {
+                    if *is_open && !grouped.options.is_empty() {
                         // Toggle highlighted option
-                        if let Some(option) = filtered_options.get(*highlighted_index)
+                        if let Some(option) = grouped.options.get(*highlighted_index)
                             && !option.disabled
                         {
                             let mut new_selection = selected_options.clone();
@@ -483,6 +887,23 @@ pub fn multiselect(props: &MultiselectProps) -> Html {
                     e.prevent_default();
                     is_open.set(false);
                 }
+                _ if *is_open && !from_filter && TypeAhead::accepts(&e) => {
+                    let query = type_ahead
+                        .borrow_mut()
+                        .push(&key, js_sys::Date::now())
+                        .to_string();
+                    if let Some(index) = type_ahead_match(
+                        &query,
+                        *highlighted_index,
+                        grouped.options.len(),
+                        |index| {
+                            let option = &grouped.options[index];
+                            (!option.disabled).then(|| option.display_text())
+                        },
+                    ) {
+                        highlighted_index.set(index);
+                    }
+                }
                 _ => {}
             }
         })
@@ -492,11 +913,15 @@ pub fn multiselect(props: &MultiselectProps) -> Html {
     let trigger_classes = ClassBuilder::new()
         .add("awsui-multiselect-trigger")
         .add_if(props.disabled, "awsui-multiselect-trigger-disabled")
-        .add_if(props.invalid, "awsui-multiselect-trigger-invalid")
-        .add_if(props.warning, "awsui-multiselect-trigger-warning")
+        .add_if(validation.is_invalid(), "awsui-multiselect-trigger-invalid")
+        .add_if(validation.is_warning(), "awsui-multiselect-trigger-warning")
         .add_if(*is_open, "awsui-multiselect-trigger-open")
         .add_if(
-            props.selected_options.is_empty(),
+            props.trigger_variant == TriggerVariant::Inline,
+            "awsui-multiselect-trigger-inline",
+        )
+        .add_if(
+            props.selected_options.is_empty() || !props.hide_tokens,
             "awsui-multiselect-trigger-placeholder",
         );
 
@@ -506,7 +931,7 @@ pub fn multiselect(props: &MultiselectProps) -> Html {
         .add_if(*is_open, "awsui-multiselect-dropdown-open");
 
     // Reset highlighted index when filtered options change
-    use_effect_with(filtered_options.clone(), {
+    use_effect_with(grouped.clone(), {
         let highlighted_index = highlighted_index.clone();
         move |_| {
             highlighted_index.set(0);
@@ -514,123 +939,287 @@ pub fn multiselect(props: &MultiselectProps) -> Html {
         }
     });
 
-    // Render tokens
-    let render_tokens = {
-        let selected_options = &props.selected_options;
-        let visible_tokens = if selected_options.len() > token_limit {
-            &selected_options[..token_limit]
-        } else {
-            selected_options
-        };
+    // Determine what to show in the trigger
+    let trigger_content = trigger_text(
+        props.selected_options.len(),
+        props.hide_tokens,
+        props.placeholder.as_deref(),
+    );
 
-        let remaining_count = if selected_options.len() > token_limit {
-            selected_options.len() - token_limit
-        } else {
-            0
-        };
+    // Close on outside interaction and keep the dropdown anchored to the
+    // trigger rather than the tokens below it
+    use_outside_dismiss(*is_open, multiselect_ref.clone(), {
+        let is_open = is_open.clone();
+        Callback::from(move |()| is_open.set(false))
+    });
+    let transition = use_dropdown_transition(*is_open, multiselect_ref.clone());
+    let position = use_dropdown_position(
+        *is_open,
+        transition.mounted(),
+        trigger_ref.clone(),
+        dropdown_ref.clone(),
+    );
 
-        html! {
-            <div class="awsui-multiselect-tokens">
-                {
-                    visible_tokens.iter().map(|option| {
-                        let option_value = option.value.clone();
-                        let on_dismiss = {
-                            let on_token_dismiss = on_token_dismiss.clone();
-                            Callback::from(move |e: MouseEvent| {
-                                e.prevent_default();
-                                e.stop_propagation();
-                                on_token_dismiss.emit(option_value.clone());
-                            })
-                        };
-
-                        html! {
-                            <div key={option.value.clone()} class="awsui-multiselect-token">
-                                <span class="awsui-multiselect-token-label">
-                                    { option.display_text() }
-                                </span>
-                                <button
-                                    type="button"
-                                    class="awsui-multiselect-token-dismiss"
-                                    aria-label={format!("Remove {}", option.display_text())}
-                                    onclick={on_dismiss}
-                                    disabled={props.disabled}
-                                >
-                                    { "×" }
-                                </button>
-                            </div>
-                        }
-                    }).collect::<Html>()
-                }
-                if remaining_count > 0 {
-                    <div class="awsui-multiselect-token awsui-multiselect-token-overflow">
-                        <span class="awsui-multiselect-token-label">
-                            { format!("+{} more", remaining_count) }
-                        </span>
-                    </div>
-                }
-            </div>
-        }
-    };
+    let highlighted_option = grouped
+        .options
+        .get(*highlighted_index)
+        .filter(|option| !option.disabled);
+    let trigger_aria = trigger_aria_attributes(
+        props.aria_label.clone(),
+        &field_aria,
+        props.aria_required,
+        validation.is_invalid(),
+        *is_open,
+        list_id.as_str(),
+        highlighted_option.map(|_| *highlighted_index),
+    );
 
-    // Determine what to show in the trigger
-    let trigger_content = if props.selected_options.is_empty() {
+    // The filter input is a combobox for the same listbox
+    let filter_aria = trigger_aria.clone();
+
+    // While open, announce the list state, else the highlighted option, its
+    // selection and its position
+    let is_selected = |option: &MultiselectOption| {
         props
-            .placeholder
-            .clone()
-            .unwrap_or_else(|| "Choose options".to_string())
+            .selected_options
+            .iter()
+            .any(|selected| selected.value == option.value)
+    };
+    let announcement = if *is_open {
+        let state = list_announcement(
+            list_state.as_ref(),
+            props.loading_status,
+            !grouped.options.is_empty(),
+            &state_strings,
+        );
+        match highlighted_option {
+            Some(option) if state.is_empty() => option_announcement(
+                option.display_text(),
+                is_selected(option),
+                Some((*highlighted_index + 1, grouped.options.len())),
+                &state_strings,
+            ),
+            _ => state,
+        }
     } else {
-        format!("{} selected", props.selected_options.len())
+        String::new()
     };
 
-    html! {
-        <div
-            ref={multiselect_ref}
-            class={ClassBuilder::new()
-                .add("awsui-multiselect")
-                .add_if(props.disabled, "awsui-multiselect-disabled")
-                .add_if(props.invalid, "awsui-multiselect-invalid")
-                .add_if(props.warning, "awsui-multiselect-warning")
-                .build()}
-            onblur={on_blur_event}
-            onfocus={on_focus_event}
-            onkeydown={on_key_down}
-        >
-            // Token display (shown when there are selections)
-            if !props.selected_options.is_empty() {
-                { render_tokens }
-            }
+    // Inline triggers draw their own focus ring
+    let on_trigger_focus = {
+        let trigger_focused = trigger_focused.clone();
+        Callback::from(move |_: FocusEvent| trigger_focused.set(true))
+    };
+    let on_trigger_blur = {
+        let trigger_focused = trigger_focused.clone();
+        Callback::from(move |_: FocusEvent| trigger_focused.set(false))
+    };
+    let trigger_style = match props.trigger_variant {
+        TriggerVariant::Inline => Some(inline_trigger_style(
+            *trigger_focused,
+            validation.is_invalid(),
+            props.disabled,
+        )),
+        _ => validation.control_style(),
+    };
 
-            // Trigger button
+    let trigger = match (props.trigger_variant, props.render_trigger) {
+        (TriggerVariant::Custom, Some(render_trigger)) => render_trigger(TriggerRenderContext {
+            selected_options: props.selected_options.clone(),
+            open: *is_open,
+            disabled: props.disabled,
+            invalid: validation.is_invalid(),
+            warning: validation.is_warning(),
+            control_id: association.control_id.clone(),
+            aria: trigger_aria,
+            toggle,
+        }),
+        _ => html! {
             <button
                 type="button"
                 class={trigger_classes.build()}
-                id={props.control_id.clone()}
+                style={trigger_style}
+                id={association.control_id.clone()}
                 disabled={props.disabled}
+                role="combobox"
                 aria-expanded={is_open.to_string()}
                 aria-haspopup="listbox"
-                aria-label={props.aria_label.clone()}
-                aria-labelledby={props.aria.labelledby.clone()}
-                aria-describedby={props.aria.describedby.clone()}
+                aria-controls={trigger_aria.controls}
+                aria-activedescendant={trigger_aria.activedescendant}
+                aria-label={trigger_aria.label}
+                aria-labelledby={trigger_aria.labelledby}
+                aria-describedby={trigger_aria.describedby}
                 aria-required={props.aria_required.to_string()}
-                aria-invalid={props.invalid.to_string()}
+                aria-invalid={trigger_aria.invalid.map(|i| i.to_string())}
                 onclick={on_trigger_click}
+                onfocus={on_trigger_focus}
+                onblur={on_trigger_blur}
                 autofocus={props.auto_focus}
+                ref={trigger_ref}
             >
                 <span class="awsui-multiselect-trigger-content">
                     { trigger_content }
                 </span>
+                { validation.trigger_icon() }
                 <span class="awsui-multiselect-trigger-icon" aria-hidden="true">
                     { if *is_open { "▲" } else { "▼" } }
                 </span>
             </button>
+        },
+    };
+
+    // Render an option row; `index` is its position among all options
+    let option_count = grouped.options.len();
+    let render_option = |index: usize, option: &MultiselectOption| -> Html {
+        let is_selected = is_selected(option);
+        let is_highlighted = index == *highlighted_index;
+
+        let option_clone = option.clone();
+        let on_click = {
+            let on_option_click = on_option_click.clone();
+            Callback::from(move |e: MouseEvent| {
+                e.prevent_default();
+                on_option_click.emit(option_clone.clone());
+            })
+        };
+
+        let option_classes = ClassBuilder::new()
+            .add("awsui-multiselect-option")
+            .add_if(option.disabled, "awsui-multiselect-option-disabled")
+            .add_if(is_selected, "awsui-multiselect-option-selected")
+            .add_if(is_highlighted, "awsui-multiselect-option-highlighted");
+
+        html! {
+            <li
+                key={option.value.clone()}
+                id={option_id(&list_id, index)}
+                class={option_classes.build()}
+                role="option"
+                aria-selected={is_selected.to_string()}
+                aria-disabled={option.disabled.to_string()}
+                aria-setsize={virtual_list.is_virtual().then(|| option_count.to_string())}
+                aria-posinset={virtual_list.is_virtual().then(|| (index + 1).to_string())}
+                onclick={on_click}
+            >
+                <div class="awsui-multiselect-option-checkbox">
+                    <input
+                        type="checkbox"
+                        checked={is_selected}
+                        disabled={option.disabled}
+                        tabindex="-1"
+                        aria-hidden="true"
+                    />
+                </div>
+                <div class="awsui-multiselect-option-content">
+                    <span class="awsui-multiselect-option-label">
+                        { option.display_text() }
+                        if let Some(ref tag) = option.label_tag {
+                            <span class="awsui-multiselect-option-label-tag">
+                                { tag }
+                            </span>
+                        }
+                    </span>
+                    if let Some(ref desc) = option.description {
+                        <span class="awsui-multiselect-option-description">
+                            { desc }
+                        </span>
+                    }
+                </div>
+            </li>
+        }
+    };
+
+    // Clicking a group header toggles its enabled options
+    let render_header = |row: &OptionRow, id: String| -> Html {
+        let onclick = match row {
+            OptionRow::Header {
+                disabled: false,
+                options,
+                ..
+            } => {
+                let group_options = grouped.options[options.clone()].to_vec();
+                let on_group_click = on_group_click.clone();
+                Some(Callback::from(move |e: MouseEvent| {
+                    e.prevent_default();
+                    on_group_click.emit(group_options.clone());
+                }))
+            }
+            _ => None,
+        };
+        render_group_header(row, id, "awsui-multiselect", onclick)
+    };
+
+    let root = html! {
+        <div
+            ref={multiselect_ref}
+            class={ClassBuilder::new()
+                .add("awsui-multiselect")
+                .add(format!(
+                    "awsui-multiselect-trigger-variant-{}",
+                    props.trigger_variant.as_str()
+                ))
+                .add_if(props.disabled, "awsui-multiselect-disabled")
+                .add_if(validation.is_invalid(), "awsui-multiselect-invalid")
+                .add_if(validation.is_warning(), "awsui-multiselect-warning")
+                .build()}
+            onblur={on_blur_event}
+            onfocus={on_focus_event}
+            onkeydown={on_key_down}
+        >
+            { trigger }
+
+            if show_clear {
+                // Keys pressed in the clear button or its confirmation stay
+                // out of the trigger's keyboard handling
+                <span
+                    class="awsui-multiselect-clear"
+                    onkeydown={Callback::from(|e: KeyboardEvent| e.stop_propagation())}
+                >
+                    <Popover
+                        visible={Some(*confirm_clear)}
+                        dismissible={true}
+                        size={PopoverSize::Small}
+                        position={PopoverPosition::Bottom}
+                        on_dismiss={cancel_clear.reform(|_: CustomEvent<PopoverDismissDetail>| ())}
+                        content={html! {
+                            <div class="awsui-multiselect-clear-confirm" ref={confirm_ref}>
+                                <p>{ strings.confirm_clear_text.clone() }</p>
+                                <Button
+                                    variant={ButtonVariant::Link}
+                                    form_action={FormAction::None}
+                                    on_click={cancel_clear.reform(|_: ClickEvent| ())}
+                                >
+                                    { Html::from(strings.cancel_clear_button_label.clone()) }
+                                </Button>
+                                <Button
+                                    variant={ButtonVariant::Primary}
+                                    form_action={FormAction::None}
+                                    on_click={clear.reform(|_: ClickEvent| ())}
+                                >
+                                    { Html::from(strings.confirm_clear_button_label.clone()) }
+                                </Button>
+                            </div>
+                        }}
+                    >
+                        { render_clear_button(
+                            "awsui-multiselect-clear-button",
+                            props.clear_aria_label.clone(),
+                            request_clear.reform(|_: MouseEvent| ()),
+                        ) }
+                    </Popover>
+                </span>
+            }
+
+            <LiveRegion message={announcement} />
 
             // Dropdown menu
-            if *is_open {
+            if transition.mounted() {
                 <div
+                    ref={dropdown_ref}
+                    style={dropdown_style(transition, position)}
                     class={dropdown_classes.build()}
-                    role="listbox"
-                    aria-label={props.aria_label.clone()}
-                    aria-multiselectable="true"
+                    aria-busy={(props.loading_status == Some(DropdownStatus::Loading)).then_some("true")}
+                    onscroll={load_items.on_scroll.clone()}
                 >
                     // Filter input (for Auto or Manual filtering)
                     if props.filtering_type != FilteringType::None {
@@ -641,89 +1230,130 @@ pub fn multiselect(props: &MultiselectProps) -> Html {
                                 placeholder={props.filtering_placeholder.clone().unwrap_or_else(|| "Filter options".to_string())}
                                 value={(*filter_text).clone()}
                                 oninput={on_filter_input}
+                                role="combobox"
                                 aria-label="Filter options"
+                                aria-expanded="true"
+                                aria-autocomplete="list"
+                                aria-controls={filter_aria.controls}
+                                aria-activedescendant={filter_aria.activedescendant}
                             />
                         </div>
                     }
 
-                    <ul class="awsui-multiselect-options-list">
-                        {
-                            if filtered_options.is_empty() {
-                                html! {
-                                    <li class="awsui-multiselect-empty">
-                                        { "No options found" }
-                                    </li>
-                                }
-                            } else {
-                                filtered_options.iter().enumerate().map(|(index, option)| {
-                                    let is_selected = props.selected_options.iter()
-                                        .any(|s| s.value == option.value);
-                                    let is_highlighted = index == *highlighted_index;
-
-                                    let option_clone = option.clone();
-                                    let on_click = {
-                                        let on_option_click = on_option_click.clone();
-                                        Callback::from(move |e: MouseEvent| {
-                                            e.prevent_default();
-                                            on_option_click.emit(option_clone.clone());
-                                        })
-                                    };
-
-                                    let option_classes = ClassBuilder::new()
-                                        .add("awsui-multiselect-option")
-                                        .add_if(option.disabled, "awsui-multiselect-option-disabled")
-                                        .add_if(is_selected, "awsui-multiselect-option-selected")
-                                        .add_if(is_highlighted, "awsui-multiselect-option-highlighted");
-
-                                    html! {
-                                        <li
-                                            key={option.value.clone()}
-                                            class={option_classes.build()}
-                                            role="option"
-                                            aria-selected={is_selected.to_string()}
-                                            aria-disabled={option.disabled.to_string()}
-                                            onclick={on_click}
-                                        >
-                                            <div class="awsui-multiselect-option-checkbox">
-                                                <input
-                                                    type="checkbox"
-                                                    checked={is_selected}
-                                                    disabled={option.disabled}
-                                                    tabindex="-1"
-                                                    aria-hidden="true"
-                                                />
-                                            </div>
-                                            <div class="awsui-multiselect-option-content">
-                                                <span class="awsui-multiselect-option-label">
-                                                    { option.display_text() }
-                                                    if let Some(ref tag) = option.label_tag {
-                                                        <span class="awsui-multiselect-option-label-tag">
-                                                            { tag }
-                                                        </span>
-                                                    }
-                                                </span>
-                                                if let Some(ref desc) = option.description {
-                                                    <span class="awsui-multiselect-option-description">
-                                                        { desc }
-                                                    </span>
-                                                }
-                                            </div>
-                                        </li>
-                                    }
-                                }).collect::<Html>()
-                            }
-                        }
+                    // The list itself is the listbox, so the filter input and
+                    // status rows stay outside of it
+                    <ul
+                        ref={virtual_list.list_ref.clone()}
+                        id={(*list_id).clone()}
+                        class="awsui-multiselect-options-list"
+                        style={virtual_list.style.clone()}
+                        role="listbox"
+                        aria-label={props.aria_label.clone()}
+                        aria-multiselectable="true"
+                        onscroll={on_list_scroll}
+                    >
+                        { virtual_list.top_spacer() }
+                        { grouped.render_rows(
+                            virtual_list.window.start..virtual_list.window.end,
+                            list_id.as_str(),
+                            render_header,
+                            render_option,
+                        ) }
+                        { virtual_list.bottom_spacer() }
                     </ul>
+                    if let Some(ref state) = list_state {
+                        { render_list_state(state, &state_strings, ListStateSlots {
+                            no_match_content: props.no_match_content.clone(),
+                            empty_content: props.empty_content.clone(),
+                            on_clear_filter: Some(on_clear_filter.clone()),
+                        }) }
+                    }
+                    { render_load_status(
+                        props.loading_status,
+                        !grouped.options.is_empty(),
+                        &state_strings,
+                        load_items.on_retry.clone(),
+                    ) }
+                </div>
+            }
+
+            // Selected options, shown below the trigger; their keys stay out
+            // of the trigger's keyboard handling
+            if !props.hide_tokens && !props.selected_options.is_empty() {
+                <div
+                    class="awsui-multiselect-tokens"
+                    onkeydown={Callback::from(|e: KeyboardEvent| e.stop_propagation())}
+                >
+                    <TokenGroup
+                        items={selection_tokens(&props.selected_options, props.disabled)}
+                        limit={token_limit}
+                        on_dismiss={on_token_dismiss.reform(
+                            |event: CustomEvent<TokenDismissDetail>| event.detail.item_id,
+                        )}
+                    />
                 </div>
             }
         </div>
-    }
+    };
+    apply_root_attributes(root, &props.base)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_selection_tokens() {
+        let selected = vec![
+            MultiselectOption::new("us-east-1").with_label("US East (N. Virginia)"),
+            MultiselectOption::new("us-gov-1").with_disabled(true),
+        ];
+
+        let tokens = selection_tokens(&selected, false);
+        assert_eq!(tokens[0].id, "us-east-1");
+        assert_eq!(tokens[0].label, "US East (N. Virginia)");
+        assert!(tokens[0].dismissible);
+        // Disabled options can't be deselected from their token
+        assert!(!tokens[1].dismissible);
+        assert!(tokens[1].disabled);
+
+        let tokens = selection_tokens(&selected, true);
+        assert!(
+            tokens
+                .iter()
+                .all(|token| !token.dismissible && token.disabled)
+        );
+    }
+
+    #[test]
+    fn test_trigger_text() {
+        assert_eq!(trigger_text(0, false, None), "Choose options");
+        assert_eq!(trigger_text(0, true, Some("Regions")), "Regions");
+        // Tokens show the selection, so the trigger keeps the placeholder
+        assert_eq!(trigger_text(3, false, Some("Regions")), "Regions");
+        assert_eq!(trigger_text(3, true, Some("Regions")), "3 selected");
+    }
+
+    #[test]
+    fn test_toggle_group_selection() {
+        let east_1 = MultiselectOption::new("us-east-1");
+        let east_2 = MultiselectOption::new("us-east-2");
+        let east_3 = MultiselectOption::new("us-east-3").with_disabled(true);
+        let west = MultiselectOption::new("us-west-2");
+        let group = vec![east_1.clone(), east_2.clone(), east_3];
+
+        // Selects the missing enabled options, never the disabled one
+        assert_eq!(
+            toggle_group_selection(&[west.clone(), east_2.clone()], &group),
+            vec![west.clone(), east_2.clone(), east_1.clone()]
+        );
+        // Deselects the group once all its enabled options are selected
+        assert_eq!(
+            toggle_group_selection(&[east_1, west.clone(), east_2], &group),
+            vec![west]
+        );
+    }
+
     #[test]
     fn test_multiselect_option_new() {
         let option = MultiselectOption::new("test-value");
@@ -822,4 +1452,63 @@ mod tests {
         assert_eq!(option1.label, option2.label);
         assert_eq!(option1.description, option2.description);
     }
+
+    #[test]
+    fn test_trigger_render_context_carries_selection() {
+        let context = TriggerRenderContext {
+            selected_options: vec![MultiselectOption::new("a"), MultiselectOption::new("b")],
+            open: false,
+            disabled: false,
+            invalid: true,
+            warning: false,
+            control_id: Some("regions".to_string()),
+            aria: trigger_aria_attributes(
+                None,
+                &AriaAttributes::default(),
+                false,
+                true,
+                false,
+                "awsui-multiselect-0",
+                None,
+            ),
+            toggle: Callback::noop(),
+        };
+
+        assert_eq!(context.selected_options.len(), 2);
+        assert_eq!(context.aria.invalid, Some(true));
+        assert_eq!(context.aria.expanded, Some(false));
+    }
+
+    #[test]
+    fn test_clear_needs_confirmation() {
+        assert!(!clear_needs_confirmation(40, None));
+        assert!(!clear_needs_confirmation(10, Some(10)));
+        assert!(clear_needs_confirmation(11, Some(10)));
+    }
+
+    #[test]
+    fn test_resolve_strings_count() {
+        let resolved = resolve_strings(
+            &MultiselectI18nStrings::default(),
+            40,
+            &I18nContext::default(),
+        );
+        assert_eq!(resolved.confirm_clear_text, "Remove 40 selections?");
+        assert_eq!(resolved.confirm_clear_button_label, "Remove");
+        assert_eq!(resolved.cancel_clear_button_label, "Cancel");
+
+        let mut messages = crate::internal::I18nStrings::new();
+        messages.set(
+            "multiselect.confirm_clear_text",
+            "{count} Auswahlen entfernen?",
+        );
+        let i18n = I18nContext {
+            locale: Some("de-DE".to_string()),
+            messages,
+        };
+        assert_eq!(
+            resolve_strings(&MultiselectI18nStrings::default(), 12, &i18n).confirm_clear_text,
+            "12 Auswahlen entfernen?"
+        );
+    }
 }
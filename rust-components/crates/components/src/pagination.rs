@@ -5,12 +5,16 @@
 //!
 //! Page navigation with next/previous and page numbers, enabling users to navigate
 //! through large sets of data.
+//!
+//! An open-ended pagination serves cursor-based data whose page count is
+//! unknown, and an optional input lets users jump straight to a page.
 
+use crate::i18n::{I18nContext, use_i18n};
 use crate::internal::{
     AnalyticsMetadata, BaseComponentProps, ClassBuilder, ComponentMetadata, ComponentStyles,
-    CustomEvent,
+    CustomEvent, apply_root_attributes,
 };
-use web_sys::MouseEvent;
+use web_sys::{HtmlInputElement, KeyboardEvent, MouseEvent};
 use yew::prelude::*;
 
 /// Event detail for pagination change events
@@ -30,30 +34,131 @@ pub struct PaginationPageClickDetail {
 }
 
 /// I18n strings for pagination component
+///
+/// Strings not set here come from the closest I18nProvider, then English.
+/// `Default` sets the English previous, next and page labels; start from
+/// [`PaginationI18nStrings::unset`] to take those from the provider too.
 #[derive(Clone, PartialEq)]
 #[allow(unpredictable_function_pointer_comparisons)]
 pub struct PaginationI18nStrings {
     /// Label for the pagination navigation
     pub pagination_label: Option<String>,
-    /// Label for the previous page button
+    /// Label for the previous page button (default: "Previous page")
     pub previous_page_label: Option<String>,
-    /// Label for the next page button
+    /// Label for the next page button (default: "Next page")
     pub next_page_label: Option<String>,
     /// Function to generate label for a specific page number
+    ///
+    /// An I18nProvider supplies it as a message with a `{page_number}`
+    /// placeholder (default: "Page {page_number}").
     pub page_label: Option<fn(u32) -> String>,
+    /// Label for the jump to page input (default: "Go to page")
+    pub jump_to_page_label: Option<String>,
+    /// Error shown when the jump to page input holds no valid page
+    /// (default: "Enter a valid page number")
+    pub jump_to_page_error_text: Option<String>,
 }
 
 impl Default for PaginationI18nStrings {
     fn default() -> Self {
         Self {
-            pagination_label: None,
             previous_page_label: Some("Previous page".to_string()),
             next_page_label: Some("Next page".to_string()),
             page_label: Some(|page_number| format!("Page {}", page_number)),
+            ..Self::unset()
+        }
+    }
+}
+
+impl PaginationI18nStrings {
+    /// Strings with every label unset, so each comes from the closest
+    /// I18nProvider, then English
+    pub fn unset() -> Self {
+        Self {
+            pagination_label: None,
+            previous_page_label: None,
+            next_page_label: None,
+            page_label: None,
+            jump_to_page_label: None,
+            jump_to_page_error_text: None,
+        }
+    }
+}
+
+/// Strings used to render Pagination after applying the fallback chain
+#[derive(Clone, PartialEq, Debug)]
+#[allow(unpredictable_function_pointer_comparisons)]
+struct ResolvedStrings {
+    pagination_label: Option<String>,
+    previous_page_label: String,
+    next_page_label: String,
+    page_label: Option<fn(u32) -> String>,
+    page_label_template: String,
+    jump_to_page_label: String,
+    jump_to_page_error_text: String,
+}
+
+impl ResolvedStrings {
+    /// Label of the button of a page
+    fn page_label(&self, page_number: u32) -> String {
+        match self.page_label {
+            Some(page_label) => page_label(page_number),
+            None => self
+                .page_label_template
+                .replace("{page_number}", &page_number.to_string()),
         }
     }
 }
 
+/// Resolves every user-visible string: `aria_labels` prop, then the
+/// I18nProvider, then English
+fn resolve_strings(strings: &PaginationI18nStrings, i18n: &I18nContext) -> ResolvedStrings {
+    let message = |key: &str| i18n.message("pagination", key).map(str::to_string);
+    let resolve = |value: &Option<String>, key: &str, default: &str| {
+        value
+            .clone()
+            .or_else(|| message(key))
+            .unwrap_or_else(|| default.to_string())
+    };
+
+    ResolvedStrings {
+        pagination_label: strings
+            .pagination_label
+            .clone()
+            .or_else(|| message("pagination_label")),
+        previous_page_label: resolve(
+            &strings.previous_page_label,
+            "previous_page_label",
+            "Previous page",
+        ),
+        next_page_label: resolve(&strings.next_page_label, "next_page_label", "Next page"),
+        page_label: strings.page_label,
+        page_label_template: resolve(&None, "page_label", "Page {page_number}"),
+        jump_to_page_label: resolve(
+            &strings.jump_to_page_label,
+            "jump_to_page_label",
+            "Go to page",
+        ),
+        jump_to_page_error_text: resolve(
+            &strings.jump_to_page_error_text,
+            "jump_to_page_error_text",
+            "Enter a valid page number",
+        ),
+    }
+}
+
+/// Gets the page typed into the jump to page input
+///
+/// Pages past `pages_count` are rejected unless the pagination is
+/// open-ended, where the page count isn't known.
+fn jump_target(value: &str, pages_count: u32, open_end: bool) -> Option<u32> {
+    value
+        .trim()
+        .parse::<u32>()
+        .ok()
+        .filter(|page| *page >= 1 && (open_end || *page <= pages_count))
+}
+
 /// Properties for the Pagination component
 #[derive(Properties, PartialEq, Clone)]
 pub struct PaginationProps {
@@ -76,9 +181,17 @@ pub struct PaginationProps {
     /// Whether the pagination is disabled
     ///
     /// Use this to prevent the user from changing pages before items are loaded.
+    /// Every control is greyed out, including the jump to page input.
     #[prop_or_default]
     pub disabled: bool,
 
+    /// Whether to show an input for jumping to a page
+    ///
+    /// Typing a page number and pressing Enter fires `on_change` with that
+    /// page. Pages past `pages_count` are rejected unless `open_end` is set.
+    #[prop_or_default]
+    pub jump_to_page: bool,
+
     /// Sets the pagination variant to open-ended
     ///
     /// When true, always displays three dots before the next page icon.
@@ -88,7 +201,10 @@ pub struct PaginationProps {
     pub open_end: bool,
 
     /// I18n strings for ARIA labels and text
-    #[prop_or_default]
+    ///
+    /// Unset by default, so every label comes from the closest I18nProvider,
+    /// then English.
+    #[prop_or_else(PaginationI18nStrings::unset)]
     pub aria_labels: PaginationI18nStrings,
 
     /// Called when a user interaction causes a pagination change
@@ -194,25 +310,13 @@ pub fn pagination(props: &PaginationProps) -> Html {
     let pagination_state =
         get_pagination_state(props.current_page_index, props.pages_count, props.open_end);
 
-    // Get i18n strings with defaults
-    let previous_page_label = props
-        .aria_labels
-        .previous_page_label
-        .as_ref()
-        .cloned()
-        .unwrap_or_else(|| "Previous page".to_string());
-
-    let next_page_label = props
-        .aria_labels
-        .next_page_label
-        .as_ref()
-        .cloned()
-        .unwrap_or_else(|| "Next page".to_string());
-
-    let page_label_fn = props
-        .aria_labels
-        .page_label
-        .unwrap_or(|page_number| format!("Page {}", page_number));
+    let i18n = use_i18n();
+    let strings = resolve_strings(&props.aria_labels, &i18n);
+
+    // Value of the jump to page input, and whether the last page entered in
+    // it was rejected
+    let jump_value = use_state(String::new);
+    let jump_invalid = use_state_eq(|| false);
 
     // Create handlers
     let handle_page_click = {
@@ -283,6 +387,42 @@ pub fn pagination(props: &PaginationProps) -> Html {
         })
     };
 
+    let on_jump_input = {
+        let jump_value = jump_value.clone();
+        let jump_invalid = jump_invalid.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            jump_value.set(input.value());
+            jump_invalid.set(false);
+        })
+    };
+
+    let on_jump_keydown = {
+        let jump_value = jump_value.clone();
+        let jump_invalid = jump_invalid.clone();
+        let handle_page_click = handle_page_click.clone();
+        let pages_count = props.pages_count;
+        let open_end = props.open_end;
+        let disabled = props.disabled;
+        Callback::from(move |e: KeyboardEvent| {
+            if e.key() != "Enter" || disabled {
+                return;
+            }
+            e.prevent_default();
+            match jump_target(&jump_value, pages_count, open_end) {
+                Some(page) => {
+                    jump_value.set(String::new());
+                    handle_page_click.emit(page);
+                }
+                None => jump_invalid.set(true),
+            }
+        })
+    };
+
+    let dots_classes = ClassBuilder::new()
+        .add("awsui-pagination-dots")
+        .add_if(props.disabled, "awsui-pagination-dots-disabled");
+
     // Determine button states
     let previous_button_disabled = props.disabled || props.current_page_index == 1;
     let next_button_disabled = props.disabled
@@ -314,18 +454,18 @@ pub fn pagination(props: &PaginationProps) -> Html {
     };
     let analytics_attr = analytics.to_data_attribute();
 
-    html! {
+    let root = html! {
         <ul
             id={props.base.id.clone()}
             class={class}
             style={style_attr}
-            aria-label={props.aria_labels.pagination_label.clone()}
+            aria-label={strings.pagination_label.clone()}
             data-analytics-metadata={analytics_attr}
         >
             // Previous button
             <PageButton
                 class_name="awsui-pagination-arrow"
-                aria_label={previous_page_label}
+                aria_label={strings.previous_page_label.clone()}
                 disabled={previous_button_disabled}
                 on_click={handle_prev_page_click}
                 position="prev"
@@ -338,14 +478,14 @@ pub fn pagination(props: &PaginationProps) -> Html {
                 page_index={1}
                 is_current={props.current_page_index == 1}
                 disabled={props.disabled}
-                aria_label={page_label_fn(1)}
+                aria_label={strings.page_label(1)}
                 on_click={handle_page_click.clone()}
             />
 
             // Left dots
             if pagination_state.left_dots {
                 <li class="awsui-pagination-page-item">
-                    <span class="awsui-pagination-dots">{"..."}</span>
+                    <span class={dots_classes.clone()}>{"..."}</span>
                 </li>
             }
 
@@ -360,7 +500,7 @@ pub fn pagination(props: &PaginationProps) -> Html {
                                 page_index={page_index}
                                 is_current={props.current_page_index == page_index}
                                 disabled={props.disabled}
-                                aria_label={page_label_fn(page_index)}
+                                aria_label={strings.page_label(page_index)}
                                 on_click={handle_page_click.clone()}
                             />
                         }
@@ -371,7 +511,7 @@ pub fn pagination(props: &PaginationProps) -> Html {
             // Right dots
             if pagination_state.right_dots {
                 <li class="awsui-pagination-page-item">
-                    <span class="awsui-pagination-dots">{"..."}</span>
+                    <span class={dots_classes}>{"..."}</span>
                 </li>
             }
 
@@ -381,7 +521,7 @@ pub fn pagination(props: &PaginationProps) -> Html {
                     page_index={props.pages_count}
                     is_current={props.current_page_index == props.pages_count}
                     disabled={props.disabled}
-                    aria_label={page_label_fn(props.pages_count)}
+                    aria_label={strings.page_label(props.pages_count)}
                     on_click={handle_page_click.clone()}
                 />
             }
@@ -389,15 +529,42 @@ pub fn pagination(props: &PaginationProps) -> Html {
             // Next button
             <PageButton
                 class_name="awsui-pagination-arrow"
-                aria_label={next_page_label}
+                aria_label={strings.next_page_label.clone()}
                 disabled={next_button_disabled}
                 on_click={handle_next_page_click}
                 position="next"
             >
                 <span class="awsui-pagination-icon-next">{"▶"}</span>
             </PageButton>
+
+            // Jump to page
+            if props.jump_to_page {
+                <li class="awsui-pagination-page-item awsui-pagination-jump-to-page">
+                    <input
+                        type="number"
+                        class={ClassBuilder::new()
+                            .add("awsui-pagination-jump-to-page-input")
+                            .add_if(*jump_invalid, "awsui-pagination-jump-to-page-input-invalid")}
+                        inputmode="numeric"
+                        min="1"
+                        max={(!props.open_end).then(|| props.pages_count.to_string())}
+                        value={(*jump_value).clone()}
+                        aria-label={strings.jump_to_page_label.clone()}
+                        aria-invalid={jump_invalid.then_some("true")}
+                        disabled={props.disabled}
+                        oninput={on_jump_input}
+                        onkeydown={on_jump_keydown}
+                    />
+                    if *jump_invalid {
+                        <span class="awsui-pagination-jump-to-page-error" role="alert">
+                            { strings.jump_to_page_error_text.clone() }
+                        </span>
+                    }
+                </li>
+            }
         </ul>
-    }
+    };
+    apply_root_attributes(root, &props.base)
 }
 
 /// Properties for page button component
@@ -430,7 +597,7 @@ fn page_button(props: &PageButtonProps) -> Html {
     };
 
     let button_classes = ClassBuilder::new()
-        .add(&props.class_name)
+        .add(props.class_name.clone())
         .add("awsui-pagination-button")
         .add_if(props.disabled, "awsui-pagination-button-disabled");
 
@@ -690,6 +857,58 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_i18n_strings_unset() {
+        let strings = resolve_strings(&PaginationI18nStrings::unset(), &I18nContext::default());
+
+        assert_eq!(strings.pagination_label, None);
+        assert_eq!(strings.previous_page_label, "Previous page");
+        assert_eq!(strings.next_page_label, "Next page");
+        assert_eq!(strings.page_label(1), "Page 1");
+        assert_eq!(strings.page_label(10), "Page 10");
+        assert_eq!(strings.jump_to_page_label, "Go to page");
+        assert_eq!(strings.jump_to_page_error_text, "Enter a valid page number");
+    }
+
+    #[test]
+    fn test_i18n_strings_fallback() {
+        let mut messages = crate::internal::I18nStrings::new();
+        messages.set("pagination.next_page_label", "Nächste Seite");
+        messages.set("pagination.page_label", "Seite {page_number}");
+        let i18n = I18nContext {
+            locale: Some("de-DE".to_string()),
+            messages,
+        };
+        let props_strings = PaginationI18nStrings {
+            previous_page_label: Some("Zurück".to_string()),
+            ..PaginationI18nStrings::unset()
+        };
+        let strings = resolve_strings(&props_strings, &i18n);
+        assert_eq!(strings.previous_page_label, "Zurück");
+        assert_eq!(strings.next_page_label, "Nächste Seite");
+        assert_eq!(strings.page_label(3), "Seite 3");
+
+        // A page label function takes precedence over the provider
+        let props_strings = PaginationI18nStrings {
+            page_label: Some(|page_number| format!("S. {}", page_number)),
+            ..PaginationI18nStrings::unset()
+        };
+        assert_eq!(resolve_strings(&props_strings, &i18n).page_label(3), "S. 3");
+    }
+
+    #[test]
+    fn test_jump_target() {
+        assert_eq!(jump_target("3", 10, false), Some(3));
+        assert_eq!(jump_target(" 10 ", 10, false), Some(10));
+        assert_eq!(jump_target("11", 10, false), None);
+        assert_eq!(jump_target("0", 10, false), None);
+        assert_eq!(jump_target("abc", 10, false), None);
+        assert_eq!(jump_target("", 10, false), None);
+
+        // The page count isn't known in open-ended paginations
+        assert_eq!(jump_target("11", 10, true), Some(11));
+    }
+
     #[test]
     fn test_pagination_state_edge_case_zero_pages() {
         let state = get_pagination_state(1, 0, false);
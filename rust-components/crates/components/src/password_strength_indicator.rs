@@ -0,0 +1,213 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! PasswordStrengthIndicator component
+//!
+//! Segmented bar and label showing the strength of a password. The score is
+//! computed by the caller, so teams can use whatever estimator their
+//! security policy requires.
+
+use crate::i18n::{I18nContext, use_i18n};
+use crate::internal::{BaseComponentProps, ClassBuilder, ComponentMetadata, apply_root_attributes};
+use yew::prelude::*;
+
+/// Highest password strength score
+pub const MAX_PASSWORD_SCORE: u8 = 4;
+
+/// Default labels for each score, from weakest to strongest
+const DEFAULT_SCORE_LABELS: [&str; 5] = ["Very weak", "Weak", "Fair", "Strong", "Very strong"];
+
+/// Gets the status of a score, used for the segment color
+fn score_status(score: u8) -> &'static str {
+    match score {
+        0 | 1 => "error",
+        2 => "warning",
+        _ => "success",
+    }
+}
+
+/// Internationalization strings for PasswordStrengthIndicator
+///
+/// Unset strings fall back to the closest I18nProvider (keys under
+/// `"password-strength-indicator."`, with score labels under `score_0` to
+/// `score_4`), then to English.
+#[derive(Clone, PartialEq, Default)]
+pub struct PasswordStrengthIndicatorI18nStrings {
+    /// Accessible name of the meter (default: "Password strength")
+    pub aria_label: Option<String>,
+    /// Labels for scores 0 to 4 (default: "Very weak", "Weak", "Fair",
+    /// "Strong", "Very strong")
+    pub score_labels: Option<[String; 5]>,
+}
+
+/// Strings used to render a PasswordStrengthIndicator after applying the
+/// fallback chain
+struct ResolvedStrings {
+    aria_label: String,
+    score_label: String,
+}
+
+/// Resolves the strings for a score: `i18n_strings` prop, then the
+/// I18nProvider, then English
+fn resolve_strings(
+    strings: &PasswordStrengthIndicatorI18nStrings,
+    score: u8,
+    i18n: &I18nContext,
+) -> ResolvedStrings {
+    let message = |key: &str| {
+        i18n.message("password-strength-indicator", key)
+            .map(str::to_string)
+    };
+    let index = usize::from(score.min(MAX_PASSWORD_SCORE));
+
+    ResolvedStrings {
+        aria_label: strings
+            .aria_label
+            .clone()
+            .or_else(|| message("aria_label"))
+            .unwrap_or_else(|| "Password strength".to_string()),
+        score_label: strings
+            .score_labels
+            .as_ref()
+            .map(|labels| labels[index].clone())
+            .or_else(|| message(&format!("score_{}", index)))
+            .unwrap_or_else(|| DEFAULT_SCORE_LABELS[index].to_string()),
+    }
+}
+
+/// Properties for the PasswordStrengthIndicator component
+#[derive(Properties, PartialEq, Clone)]
+pub struct PasswordStrengthIndicatorProps {
+    /// Base component properties
+    #[prop_or_default]
+    pub base: BaseComponentProps,
+
+    /// Strength score from 0 (weakest) to 4 (strongest)
+    ///
+    /// Higher values are treated as 4.
+    #[prop_or_default]
+    pub score: u8,
+
+    /// Label shown next to the bar, replacing the label of the score
+    #[prop_or_default]
+    pub label: Option<String>,
+
+    /// Internationalization strings
+    #[prop_or_default]
+    pub i18n_strings: PasswordStrengthIndicatorI18nStrings,
+}
+
+/// Shows the strength of a password as a segmented bar with a label
+///
+/// Meant for the `secondary_control` slot of a password
+/// [`Input`](crate::Input).
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// use cloudscape_components::{Input, InputType, PasswordStrengthIndicator};
+///
+/// # let _: Html =
+/// html! {
+///     <Input
+///         input_type={InputType::Password}
+///         show_password_toggle={true}
+///         secondary_control={html! { <PasswordStrengthIndicator score={3} /> }}
+///     />
+/// }
+/// # ;
+/// ```
+#[function_component(PasswordStrengthIndicator)]
+pub fn password_strength_indicator(props: &PasswordStrengthIndicatorProps) -> Html {
+    let _metadata = ComponentMetadata::new("PasswordStrengthIndicator");
+    let i18n = use_i18n();
+
+    let score = props.score.min(MAX_PASSWORD_SCORE);
+    let strings = resolve_strings(&props.i18n_strings, score, &i18n);
+    let label = props.label.clone().unwrap_or(strings.score_label);
+
+    let classes = ClassBuilder::new()
+        .add("awsui-password-strength-indicator")
+        .add(format!(
+            "awsui-password-strength-indicator-status-{}",
+            score_status(score)
+        ))
+        .build();
+    let class = props.base.merge_classes(&classes);
+
+    let root = html! {
+        <div
+            id={props.base.id.clone()}
+            {class}
+            role="meter"
+            aria-label={strings.aria_label}
+            aria-valuemin="0"
+            aria-valuemax={MAX_PASSWORD_SCORE.to_string()}
+            aria-valuenow={score.to_string()}
+            aria-valuetext={label.clone()}
+        >
+            <div class="awsui-password-strength-indicator-bar" aria-hidden="true">
+                { for (1..=MAX_PASSWORD_SCORE).map(|segment| {
+                    let segment_classes = ClassBuilder::new()
+                        .add("awsui-password-strength-indicator-segment")
+                        .add_if(
+                            segment <= score,
+                            "awsui-password-strength-indicator-segment-filled",
+                        );
+                    html! { <span class={segment_classes.build()} /> }
+                }) }
+            </div>
+            <span class="awsui-password-strength-indicator-label" aria-hidden="true">
+                { label }
+            </span>
+        </div>
+    };
+    apply_root_attributes(root, &props.base)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_score_status() {
+        assert_eq!(score_status(0), "error");
+        assert_eq!(score_status(1), "error");
+        assert_eq!(score_status(2), "warning");
+        assert_eq!(score_status(3), "success");
+        assert_eq!(score_status(4), "success");
+    }
+
+    #[test]
+    fn test_resolve_strings() {
+        let defaults = I18nContext::default();
+        let strings = PasswordStrengthIndicatorI18nStrings::default();
+        assert_eq!(
+            resolve_strings(&strings, 0, &defaults).score_label,
+            "Very weak"
+        );
+        assert_eq!(
+            resolve_strings(&strings, 4, &defaults).score_label,
+            "Very strong"
+        );
+        // Out-of-range scores use the strongest label
+        assert_eq!(
+            resolve_strings(&strings, 9, &defaults).score_label,
+            "Very strong"
+        );
+        assert_eq!(
+            resolve_strings(&strings, 2, &defaults).aria_label,
+            "Password strength"
+        );
+
+        let mut messages = crate::internal::I18nStrings::new();
+        messages.set("password-strength-indicator.score_2", "Mittel");
+        let i18n = I18nContext {
+            locale: Some("de-DE".to_string()),
+            messages,
+        };
+        assert_eq!(resolve_strings(&strings, 2, &i18n).score_label, "Mittel");
+        assert_eq!(resolve_strings(&strings, 3, &i18n).score_label, "Strong");
+    }
+}
@@ -5,13 +5,20 @@
 //!
 //! The Popover component displays content in a positioned overlay relative to a trigger element.
 //! It supports multiple positions, sizes, and dismissal methods including close button,
-//! clicking outside, and keyboard interactions.
+//! clicking outside, and keyboard interactions. Rich content can be split into a
+//! pinned header, a scrollable body and a pinned footer.
 
-use crate::internal::{AriaAttributes, BaseComponentProps, ClassBuilder, CustomEvent};
+use crate::internal::{
+    AriaAttributes, BaseComponentProps, ClassBuilder, CustomEvent, Portal, PortalLayer,
+    apply_root_attributes,
+};
 use crate::modal::DismissReason;
 use gloo::events::EventListener;
+use gloo::render::{AnimationFrame, request_animation_frame};
+use std::cell::RefCell;
+use std::rc::Rc;
 use wasm_bindgen::JsCast;
-use web_sys::{Element, KeyboardEvent, MouseEvent};
+use web_sys::{Element, HtmlElement, KeyboardEvent, MouseEvent};
 use yew::prelude::*;
 
 /// Popover size variants
@@ -26,6 +33,8 @@ pub enum PopoverSize {
     Medium,
     /// Large popover (400px)
     Large,
+    /// Sized to its content, up to the large popover width
+    Content,
 }
 
 impl PopoverSize {
@@ -35,6 +44,7 @@ impl PopoverSize {
             Self::Small => "small",
             Self::Medium => "medium",
             Self::Large => "large",
+            Self::Content => "content",
         }
     }
 }
@@ -67,6 +77,216 @@ impl PopoverPosition {
     }
 }
 
+/// Gap kept between the popover and the viewport edge, in pixels
+const VIEWPORT_MARGIN: f64 = 8.0;
+
+/// Smallest max height the popover is given, in pixels
+///
+/// Keeps the body usable when the trigger sits right at the viewport edge.
+const MIN_MAX_HEIGHT: f64 = 120.0;
+
+/// Width cap for [`PopoverSize::Content`], matching [`PopoverSize::Large`]
+const CONTENT_MAX_WIDTH: u32 = 400;
+
+/// Focusable descendants of the trigger, used to restore focus on dismissal
+const FOCUSABLE_SELECTOR: &str =
+    "button, a[href], input, select, textarea, [tabindex]:not([tabindex='-1'])";
+
+/// Computes the popover max height from the space available around the trigger
+///
+/// Popovers above or below the trigger may use the space between the trigger
+/// and that viewport edge; side popovers may use the full viewport height.
+/// Both are reduced by [`VIEWPORT_MARGIN`] and never drop below
+/// [`MIN_MAX_HEIGHT`].
+fn available_height(
+    position: PopoverPosition,
+    trigger_top: f64,
+    trigger_bottom: f64,
+    viewport_height: f64,
+) -> f64 {
+    let space = match position {
+        PopoverPosition::Top => trigger_top,
+        PopoverPosition::Bottom => viewport_height - trigger_bottom,
+        PopoverPosition::Left | PopoverPosition::Right => viewport_height,
+    };
+    (space - 2.0 * VIEWPORT_MARGIN).max(MIN_MAX_HEIGHT)
+}
+
+/// Gap between the trigger and the popover, leaving room for the arrow, in
+/// pixels
+const TRIGGER_GAP: f64 = 12.0;
+
+/// Smallest distance between the arrow and the popover corners, in pixels
+const ARROW_EDGE_MARGIN: f64 = 16.0;
+
+/// Viewport coordinates of the trigger, in pixels
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct TriggerRect {
+    top: f64,
+    right: f64,
+    bottom: f64,
+    left: f64,
+}
+
+/// Where the popover is shown, in viewport coordinates
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Placement {
+    /// Side of the trigger, the preferred one unless it flipped
+    position: PopoverPosition,
+    top: f64,
+    left: f64,
+    /// Arrow position along the popover edge facing the trigger
+    arrow_offset: f64,
+}
+
+impl Placement {
+    /// Builds the inline style placing the popover
+    ///
+    /// Popovers in a portal or with `fixed` use viewport coordinates; inline
+    /// popovers are placed relative to the root at `origin`.
+    fn style(&self, origin: Option<(f64, f64)>) -> String {
+        match origin {
+            Some((origin_left, origin_top)) => format!(
+                "position: absolute; top: {}px; left: {}px",
+                self.top - origin_top,
+                self.left - origin_left
+            ),
+            None => format!(
+                "position: fixed; top: {}px; left: {}px",
+                self.top, self.left
+            ),
+        }
+    }
+
+    /// Builds the inline style keeping the arrow pointed at the trigger
+    fn arrow_style(&self) -> String {
+        match self.position {
+            PopoverPosition::Top | PopoverPosition::Bottom => {
+                format!("left: {}px; transform: translateX(-50%)", self.arrow_offset)
+            }
+            PopoverPosition::Left | PopoverPosition::Right => {
+                format!("top: {}px; transform: translateY(-50%)", self.arrow_offset)
+            }
+        }
+    }
+}
+
+/// Gets the side opposite to a position
+fn opposite_position(position: PopoverPosition) -> PopoverPosition {
+    match position {
+        PopoverPosition::Top => PopoverPosition::Bottom,
+        PopoverPosition::Bottom => PopoverPosition::Top,
+        PopoverPosition::Left => PopoverPosition::Right,
+        PopoverPosition::Right => PopoverPosition::Left,
+    }
+}
+
+/// Gets the viewport space on one side of the trigger
+fn space_on_side(position: PopoverPosition, trigger: TriggerRect, viewport: (f64, f64)) -> f64 {
+    match position {
+        PopoverPosition::Top => trigger.top,
+        PopoverPosition::Bottom => viewport.1 - trigger.bottom,
+        PopoverPosition::Left => trigger.left,
+        PopoverPosition::Right => viewport.0 - trigger.right,
+    }
+}
+
+/// Places a popover of the given size next to its trigger
+///
+/// The preferred side wins while the popover fits there; otherwise the
+/// popover flips to the opposite side if that one fits or has more room.
+/// Along the trigger edge the popover is centered, then shifted to stay
+/// [`VIEWPORT_MARGIN`] inside the viewport, and the arrow follows the
+/// trigger center.
+fn place_popover(
+    preferred: PopoverPosition,
+    trigger: TriggerRect,
+    size: (f64, f64),
+    viewport: (f64, f64),
+) -> Placement {
+    let (width, height) = size;
+    let needed = |position: PopoverPosition| {
+        let extent = match position {
+            PopoverPosition::Top | PopoverPosition::Bottom => height,
+            PopoverPosition::Left | PopoverPosition::Right => width,
+        };
+        extent + TRIGGER_GAP + VIEWPORT_MARGIN
+    };
+    let space = |position| space_on_side(position, trigger, viewport);
+    let opposite = opposite_position(preferred);
+    let position = if space(preferred) < needed(preferred)
+        && (space(opposite) >= needed(opposite) || space(opposite) > space(preferred))
+    {
+        opposite
+    } else {
+        preferred
+    };
+
+    let shift = |start: f64, extent: f64, viewport_extent: f64| {
+        start
+            .min(viewport_extent - VIEWPORT_MARGIN - extent)
+            .max(VIEWPORT_MARGIN)
+    };
+    let center_x = (trigger.left + trigger.right) / 2.0;
+    let center_y = (trigger.top + trigger.bottom) / 2.0;
+    let (top, left) = match position {
+        PopoverPosition::Top => (
+            trigger.top - TRIGGER_GAP - height,
+            shift(center_x - width / 2.0, width, viewport.0),
+        ),
+        PopoverPosition::Bottom => (
+            trigger.bottom + TRIGGER_GAP,
+            shift(center_x - width / 2.0, width, viewport.0),
+        ),
+        PopoverPosition::Left => (
+            shift(center_y - height / 2.0, height, viewport.1),
+            trigger.left - TRIGGER_GAP - width,
+        ),
+        PopoverPosition::Right => (
+            shift(center_y - height / 2.0, height, viewport.1),
+            trigger.right + TRIGGER_GAP,
+        ),
+    };
+
+    let (center, start, extent) = match position {
+        PopoverPosition::Top | PopoverPosition::Bottom => (center_x, left, width),
+        PopoverPosition::Left | PopoverPosition::Right => (center_y, top, height),
+    };
+    let arrow_offset = (center - start)
+        .min(extent - ARROW_EDGE_MARGIN)
+        .max(ARROW_EDGE_MARGIN);
+
+    Placement {
+        position,
+        top,
+        left,
+        arrow_offset,
+    }
+}
+
+/// Builds the inline style of the popover container
+///
+/// The container is a column so that the header and footer stay pinned while
+/// the body takes the remaining height and scrolls.
+fn container_style(size: PopoverSize, max_height: Option<f64>) -> String {
+    let mut style = String::from("display: flex; flex-direction: column");
+    if let Some(max_height) = max_height {
+        style.push_str(&format!("; max-height: {}px", max_height.floor()));
+    }
+    if size == PopoverSize::Content {
+        style.push_str(&format!(
+            "; width: max-content; max-width: {}px",
+            CONTENT_MAX_WIDTH
+        ));
+    }
+    style
+}
+
+/// Inline style of the popover body; scrolling is contained so it does not
+/// chain to the page behind the popover
+const BODY_STYLE: &str =
+    "flex: 1 1 auto; min-height: 0; overflow-y: auto; overscroll-behavior: contain";
+
 /// Event detail for popover dismiss events
 ///
 /// Contains information about why the popover was dismissed.
@@ -97,16 +317,25 @@ pub struct PopoverProps {
     #[prop_or_default]
     pub dismissible: bool,
 
-    /// Optional header text for the popover
+    /// Optional header for the popover
+    ///
+    /// Pinned above the scrollable body, next to the dismiss button.
     #[prop_or_default]
-    pub header: Option<String>,
+    pub header: Option<Html>,
 
     /// Content to display in the popover body
     ///
-    /// This is the main content area of the popover.
+    /// This is the main content area of the popover. It scrolls when it does
+    /// not fit in the space available around the trigger.
     #[prop_or_default]
     pub content: Option<Html>,
 
+    /// Optional footer, such as a "Learn more" link
+    ///
+    /// Pinned below the scrollable body.
+    #[prop_or_default]
+    pub footer: Option<Html>,
+
     /// Type of trigger element
     ///
     /// Can be "text" for inline text triggers or "custom" for custom trigger elements.
@@ -140,6 +369,21 @@ pub struct PopoverProps {
     /// When `true`, the popover uses fixed positioning instead of absolute.
     #[prop_or_default]
     pub fixed: bool,
+
+    /// Whether the popover is shown, for controlled usage
+    ///
+    /// When set, clicking the trigger no longer toggles the popover; the
+    /// owner shows and hides it, typically closing it from `on_dismiss`.
+    #[prop_or_default]
+    pub visible: Option<bool>,
+
+    /// Whether to render the popover inline instead of in a portal
+    ///
+    /// By default the popover renders into a portal host under the document
+    /// body, so containers with `overflow: hidden` don't clip it. Inline
+    /// popovers are positioned by CSS relative to the trigger.
+    #[prop_or_default]
+    pub disable_portal: bool,
 }
 
 /// Popover component for displaying additional content in an overlay.
@@ -147,6 +391,21 @@ pub struct PopoverProps {
 /// A component that displays content in a positioned overlay relative to a trigger element.
 /// The popover can be dismissed via close button, clicking outside, or pressing the Escape key.
 ///
+/// The popover is limited to the viewport space available on its side of the
+/// trigger. Within that height the `header` and `footer` stay pinned and the
+/// body scrolls, without scrolling the page behind it. The dismiss button sits
+/// in the header row. Focus moves through header, body and footer in order,
+/// and returns to the trigger when the popover is dismissed with the close
+/// button or the Escape key.
+///
+/// The popover renders in a portal unless `disable_portal` is set; clicks in
+/// the portaled popover count as inside for dismissal.
+///
+/// `position` is the preferred side. When the popover doesn't fit there it
+/// flips to the opposite side, and it shifts along the trigger to stay in
+/// the viewport, re-placed on resize and scroll. The side it is shown on is
+/// in the container's `data-position` attribute.
+///
 /// # Example
 ///
 /// ```rust
@@ -169,8 +428,9 @@ pub struct PopoverProps {
 ///             size={PopoverSize::Medium}
 ///             position={PopoverPosition::Top}
 ///             dismissible={true}
-///             header="More information"
+///             header={html! { "What's new" }}
 ///             content={html! { <div>{"Detailed content here"}</div> }}
+///             footer={html! { <a href="/changelog">{"Learn more"}</a> }}
 ///             on_dismiss={on_dismiss}
 ///         >
 ///             <button>{"Show info"}</button>
@@ -182,10 +442,14 @@ pub struct PopoverProps {
 pub fn popover(props: &PopoverProps) -> Html {
     // Track whether popover is visible
     let visible = use_state(|| false);
+    let is_visible = props.visible.unwrap_or(*visible);
 
-    // Reference to the popover container
+    // References to the root, the trigger and the (possibly portaled)
+    // popover container
     let popover_ref = use_node_ref();
     let trigger_ref = use_node_ref();
+    let container_ref = use_node_ref();
+    let use_portal = !props.disable_portal;
 
     // Generate unique IDs for ARIA
     let control_id = use_state(|| {
@@ -198,6 +462,14 @@ pub fn popover(props: &PopoverProps) -> Html {
     let header_id = format!("{}-header", *control_id);
     let content_id = format!("{}-content", *control_id);
 
+    // Max height derived from the viewport space around the trigger, and
+    // where the popover fits
+    let max_height = use_state(|| None::<f64>);
+    let placement = use_state(|| None::<Placement>);
+    // Root position that inline, absolutely positioned popovers are placed
+    // relative to
+    let origin = use_state(|| None::<(f64, f64)>);
+
     // Toggle popover visibility
     let on_trigger_click = {
         let visible = visible.clone();
@@ -211,8 +483,17 @@ pub fn popover(props: &PopoverProps) -> Html {
     let dismiss = {
         let visible = visible.clone();
         let on_dismiss = props.on_dismiss.clone();
+        let trigger_ref = trigger_ref.clone();
         Callback::from(move |reason: DismissReason| {
             visible.set(false);
+            // Return focus to the trigger, unless the user clicked elsewhere
+            if reason != DismissReason::Overlay
+                && let Some(trigger) = trigger_ref.cast::<Element>()
+                && let Ok(Some(focusable)) = trigger.query_selector(FOCUSABLE_SELECTOR)
+                && let Ok(focusable) = focusable.dyn_into::<HtmlElement>()
+            {
+                let _ = focusable.focus();
+            }
             if let Some(ref callback) = on_dismiss {
                 callback.emit(CustomEvent::new_non_cancelable(PopoverDismissDetail {
                     reason,
@@ -231,27 +512,128 @@ pub fn popover(props: &PopoverProps) -> Html {
         })
     };
 
-    // Escape key handler
+    // Escape key handler, on the trigger and on the popover, which may be
+    // outside the root when portaled
     let on_keydown = {
         let dismiss = dismiss.clone();
         let dismissible = props.dismissible;
         Callback::from(move |e: KeyboardEvent| {
             if dismissible && e.key() == "Escape" {
                 e.prevent_default();
+                e.stop_propagation();
                 dismiss.emit(DismissReason::Keyboard);
             }
         })
     };
 
+    // Place the popover while visible, and keep it placed on viewport
+    // resize and scroll, measuring at most once per animation frame
+    {
+        let max_height = max_height.clone();
+        let placement = placement.clone();
+        let origin = origin.clone();
+        let trigger_ref = trigger_ref.clone();
+        let container_ref = container_ref.clone();
+        let popover_ref = popover_ref.clone();
+        let position = props.position;
+        let relative_to_root = !use_portal && !props.fixed;
+
+        use_effect_with((is_visible, position), move |(is_visible, position)| {
+            let frame = Rc::new(RefCell::new(None::<AnimationFrame>));
+            let listeners = if *is_visible {
+                let preferred = *position;
+                let measure = Callback::from(move |_: ()| {
+                    if let Some(window) = web_sys::window()
+                        && let Some(trigger) = trigger_ref.cast::<Element>()
+                        && let Some(container) = container_ref.cast::<Element>()
+                        && let Some(viewport_width) =
+                            window.inner_width().ok().and_then(|w| w.as_f64())
+                        && let Some(viewport_height) =
+                            window.inner_height().ok().and_then(|h| h.as_f64())
+                    {
+                        let rect = trigger.get_bounding_client_rect();
+                        let trigger_rect = TriggerRect {
+                            top: rect.top(),
+                            right: rect.right(),
+                            bottom: rect.bottom(),
+                            left: rect.left(),
+                        };
+                        let size = container.get_bounding_client_rect();
+                        let next = place_popover(
+                            preferred,
+                            trigger_rect,
+                            (size.width(), size.height()),
+                            (viewport_width, viewport_height),
+                        );
+                        max_height.set(Some(available_height(
+                            next.position,
+                            rect.top(),
+                            rect.bottom(),
+                            viewport_height,
+                        )));
+                        placement.set(Some(next));
+                        origin.set(
+                            popover_ref
+                                .cast::<Element>()
+                                .filter(|_| relative_to_root)
+                                .map(|root| {
+                                    let rect = root.get_bounding_client_rect();
+                                    (rect.left(), rect.top())
+                                }),
+                        );
+                    }
+                });
+                let schedule = {
+                    let frame = frame.clone();
+                    move || {
+                        if frame.borrow().is_some() {
+                            return;
+                        }
+                        let pending = frame.clone();
+                        let measure = measure.clone();
+                        *frame.borrow_mut() = Some(request_animation_frame(move |_| {
+                            pending.borrow_mut().take();
+                            measure.emit(());
+                        }));
+                    }
+                };
+                // The first measurement waits for the popover to be laid out
+                schedule();
+
+                web_sys::window().map(|window| {
+                    let on_resize = {
+                        let schedule = schedule.clone();
+                        EventListener::new(&window, "resize", move |_| schedule())
+                    };
+                    let on_scroll = EventListener::new_with_options(
+                        &window,
+                        "scroll",
+                        gloo::events::EventListenerOptions::run_in_capture_phase(),
+                        move |_| schedule(),
+                    );
+                    (on_resize, on_scroll)
+                })
+            } else {
+                placement.set(None);
+                None
+            };
+
+            move || {
+                drop(listeners);
+                frame.borrow_mut().take();
+            }
+        });
+    }
+
     // Click outside handler using gloo events
     {
-        let visible = visible.clone();
         let dismiss = dismiss.clone();
         let dismissible = props.dismissible;
         let popover_ref = popover_ref.clone();
+        let container_ref = container_ref.clone();
 
         use_effect_with(
-            (*visible, dismissible),
+            (is_visible, dismissible),
             move |(is_visible, is_dismissible)| {
                 let listener = if *is_visible && *is_dismissible {
                     let document = web_sys::window()
@@ -259,6 +641,7 @@ pub fn popover(props: &PopoverProps) -> Html {
                         .expect("Should have document");
 
                     let popover_ref = popover_ref.clone();
+                    let container_ref = container_ref.clone();
                     let dismiss = dismiss.clone();
 
                     Some(EventListener::new(&document, "mousedown", move |event| {
@@ -269,8 +652,11 @@ pub fn popover(props: &PopoverProps) -> Html {
                             && let Some(target) = mouse_event.target()
                             && let Ok(target_element) = target.dyn_into::<Element>()
                         {
-                            // Check if click is outside popover
-                            if !popover_element.contains(Some(&target_element)) {
+                            // Clicks in the portaled popover are inside too
+                            let in_container = container_ref
+                                .cast::<Element>()
+                                .is_some_and(|container| container.contains(Some(&target_element)));
+                            if !popover_element.contains(Some(&target_element)) && !in_container {
                                 dismiss.emit(DismissReason::Overlay);
                             }
                         }
@@ -290,11 +676,11 @@ pub fn popover(props: &PopoverProps) -> Html {
     // Build CSS classes
     let root_classes = ClassBuilder::new()
         .add("awsui-popover")
-        .add_if(*visible, "awsui-popover-visible");
+        .add_if(is_visible, "awsui-popover-visible");
 
     let trigger_classes = ClassBuilder::new()
         .add("awsui-popover-trigger")
-        .add_option(
+        .add_opt(
             props
                 .trigger_type
                 .as_ref()
@@ -302,13 +688,13 @@ pub fn popover(props: &PopoverProps) -> Html {
         )
         .build();
 
+    // The side the popover is shown on, after flipping
+    let position = placement.map_or(props.position, |placement| placement.position);
+
     let container_classes = ClassBuilder::new()
         .add("awsui-popover-container")
         .add(format!("awsui-popover-size-{}", props.size.as_str()))
-        .add(format!(
-            "awsui-popover-position-{}",
-            props.position.as_str()
-        ))
+        .add(format!("awsui-popover-position-{}", position.as_str()))
         .add_if(props.fixed, "awsui-popover-fixed")
         .build();
 
@@ -316,6 +702,8 @@ pub fn popover(props: &PopoverProps) -> Html {
 
     let content_classes = ClassBuilder::new().add("awsui-popover-content").build();
 
+    let footer_classes = ClassBuilder::new().add("awsui-popover-footer").build();
+
     let dismiss_button_classes = ClassBuilder::new()
         .add("awsui-popover-dismiss-button")
         .build();
@@ -328,7 +716,80 @@ pub fn popover(props: &PopoverProps) -> Html {
 
     let root_class = props.base.merge_classes(&root_classes.build());
 
-    html! {
+    // The popover stays hidden until it was placed
+    let mut style = container_style(props.size, *max_height);
+    style.push_str("; ");
+    match *placement {
+        Some(placement) => style.push_str(&placement.style(*origin)),
+        None => style.push_str("position: fixed; visibility: hidden"),
+    }
+
+    // Popover content, rendered inline or into a portal
+    let popover_content = html! {
+        <div
+            ref={container_ref}
+            id={(*control_id).clone()}
+            class={container_classes}
+            style={style}
+            role="dialog"
+            aria-modal="false"
+            data-position={position.as_str()}
+            aria-labelledby={if props.header.is_some() { Some(header_id.clone()) } else { None }}
+            aria-describedby={content_id.clone()}
+            onkeydown={on_keydown.clone()}
+            onclick={Callback::from(|e: MouseEvent| {
+                // Prevent clicks inside the popover from bubbling
+                e.stop_propagation();
+            })}
+        >
+            // Arrow/pointer
+            <div
+                class="awsui-popover-arrow"
+                style={placement.map(|placement| placement.arrow_style())}
+            />
+
+            // Header row, pinned; also holds the dismiss button
+            if props.header.is_some() || props.dismissible {
+                <div class={header_classes}>
+                    <div class="awsui-popover-header-content">
+                        if let Some(ref header) = props.header {
+                            <h3 id={header_id} class="awsui-popover-header-text">
+                                { header.clone() }
+                            </h3>
+                        }
+                    </div>
+                    if props.dismissible {
+                        <button
+                            type="button"
+                            class={dismiss_button_classes}
+                            aria-label={close_label}
+                            onclick={on_close_button_click}
+                        >
+                            <span class="awsui-icon awsui-icon-close" aria-hidden="true">
+                                { "×" }
+                            </span>
+                        </button>
+                    }
+                </div>
+            }
+
+            // Content, scrolls within the available height
+            <div id={content_id} class={content_classes} style={BODY_STYLE}>
+                if let Some(ref content) = props.content {
+                    { content.clone() }
+                }
+            </div>
+
+            // Footer, pinned
+            if let Some(ref footer) = props.footer {
+                <div class={footer_classes}>
+                    { footer.clone() }
+                </div>
+            }
+        </div>
+    };
+
+    let root = html! {
         <div
             id={props.base.id.clone()}
             class={root_class}
@@ -340,84 +801,127 @@ pub fn popover(props: &PopoverProps) -> Html {
                 class={trigger_classes}
                 onclick={on_trigger_click}
                 ref={trigger_ref}
-                aria-expanded={(*visible).to_string()}
+                aria-expanded={is_visible.to_string()}
                 aria-controls={(*control_id).clone()}
             >
                 { props.children.clone() }
             </div>
 
-            // Popover content (only render when visible)
-            if *visible {
-                <div
-                    id={(*control_id).clone()}
-                    class={container_classes}
-                    role="dialog"
-                    aria-modal="false"
-                    aria-labelledby={if props.header.is_some() { Some(header_id.clone()) } else { None }}
-                    aria-describedby={content_id.clone()}
-                    onclick={Callback::from(|e: MouseEvent| {
-                        // Prevent clicks inside the popover from bubbling
-                        e.stop_propagation();
-                    })}
-                >
-                    // Arrow/pointer
-                    <div class="awsui-popover-arrow" />
-
-                    // Header (if provided)
-                    if let Some(ref header) = props.header {
-                        <div class={header_classes}>
-                            <div class="awsui-popover-header-content">
-                                <h3 id={header_id} class="awsui-popover-header-text">
-                                    { header.clone() }
-                                </h3>
-                            </div>
-                            if props.dismissible {
-                                <button
-                                    type="button"
-                                    class={dismiss_button_classes.clone()}
-                                    aria-label={close_label.clone()}
-                                    onclick={on_close_button_click.clone()}
-                                >
-                                    <span class="awsui-icon awsui-icon-close" aria-hidden="true">
-                                        { "×" }
-                                    </span>
-                                </button>
-                            }
-                        </div>
-                    }
-
-                    // Content
-                    <div id={content_id} class={content_classes}>
-                        if let Some(ref content) = props.content {
-                            { content.clone() }
-                        }
-                    </div>
-
-                    // Close button at bottom (if dismissible and no header)
-                    if props.dismissible && props.header.is_none() {
-                        <div class="awsui-popover-footer">
-                            <button
-                                type="button"
-                                class={dismiss_button_classes}
-                                aria-label={close_label}
-                                onclick={on_close_button_click}
-                            >
-                                <span class="awsui-icon awsui-icon-close" aria-hidden="true">
-                                    { "×" }
-                                </span>
-                            </button>
-                        </div>
-                    }
-                </div>
+            if is_visible {
+                if use_portal {
+                    <Portal layer={PortalLayer::Popover}>{ popover_content }</Portal>
+                } else {
+                    { popover_content }
+                }
             }
         </div>
-    }
+    };
+    apply_root_attributes(root, &props.base)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    const VIEWPORT: (f64, f64) = (1000.0, 800.0);
+
+    fn trigger_at(left: f64, top: f64) -> TriggerRect {
+        TriggerRect {
+            top,
+            right: left + 40.0,
+            bottom: top + 20.0,
+            left,
+        }
+    }
+
+    #[test]
+    fn test_place_popover_preferred_side() {
+        let placement = place_popover(
+            PopoverPosition::Top,
+            trigger_at(480.0, 400.0),
+            (200.0, 100.0),
+            VIEWPORT,
+        );
+        assert_eq!(
+            placement,
+            Placement {
+                position: PopoverPosition::Top,
+                top: 288.0,
+                left: 400.0,
+                arrow_offset: 100.0,
+            }
+        );
+        assert_eq!(
+            placement.style(None),
+            "position: fixed; top: 288px; left: 400px"
+        );
+        assert_eq!(
+            placement.style(Some((380.0, 390.0))),
+            "position: absolute; top: -102px; left: 20px"
+        );
+    }
+
+    #[test]
+    fn test_place_popover_flips() {
+        // No room on the right near the right edge
+        let placement = place_popover(
+            PopoverPosition::Right,
+            trigger_at(900.0, 400.0),
+            (200.0, 100.0),
+            VIEWPORT,
+        );
+        assert_eq!(placement.position, PopoverPosition::Left);
+        assert_eq!(placement.left, 688.0);
+
+        // No room above near the top edge
+        let placement = place_popover(
+            PopoverPosition::Top,
+            trigger_at(480.0, 30.0),
+            (200.0, 100.0),
+            VIEWPORT,
+        );
+        assert_eq!(placement.position, PopoverPosition::Bottom);
+        assert_eq!(placement.top, 62.0);
+
+        // Neither side fits: the preferred side is kept unless the other one
+        // has more room
+        let placement = place_popover(
+            PopoverPosition::Bottom,
+            trigger_at(480.0, 390.0),
+            (200.0, 500.0),
+            VIEWPORT,
+        );
+        assert_eq!(placement.position, PopoverPosition::Bottom);
+    }
+
+    #[test]
+    fn test_place_popover_shifts_along_trigger() {
+        // Near the left edge the popover shifts right, and the arrow keeps
+        // pointing at the trigger
+        let placement = place_popover(
+            PopoverPosition::Bottom,
+            trigger_at(10.0, 400.0),
+            (200.0, 100.0),
+            VIEWPORT,
+        );
+        assert_eq!(placement.left, VIEWPORT_MARGIN);
+        assert_eq!(placement.arrow_offset, 22.0);
+        assert_eq!(
+            placement.arrow_style(),
+            "left: 22px; transform: translateX(-50%)"
+        );
+
+        // The arrow stays clear of the popover corners
+        let placement = place_popover(
+            PopoverPosition::Right,
+            trigger_at(100.0, 785.0),
+            (200.0, 100.0),
+            VIEWPORT,
+        );
+        assert_eq!(placement.top, 692.0);
+        assert_eq!(placement.arrow_offset, 84.0);
+    }
+
     #[test]
     fn test_popover_size_default() {
         assert_eq!(PopoverSize::default(), PopoverSize::Medium);
@@ -428,6 +932,7 @@ mod tests {
         assert_eq!(PopoverSize::Small.as_str(), "small");
         assert_eq!(PopoverSize::Medium.as_str(), "medium");
         assert_eq!(PopoverSize::Large.as_str(), "large");
+        assert_eq!(PopoverSize::Content.as_str(), "content");
     }
 
     #[test]
@@ -504,7 +1009,12 @@ mod tests {
 
     #[test]
     fn test_all_popover_sizes() {
-        let sizes = vec![PopoverSize::Small, PopoverSize::Medium, PopoverSize::Large];
+        let sizes = vec![
+            PopoverSize::Small,
+            PopoverSize::Medium,
+            PopoverSize::Large,
+            PopoverSize::Content,
+        ];
 
         for size in sizes {
             assert!(!size.as_str().is_empty());
@@ -524,4 +1034,49 @@ mod tests {
             assert!(!position.as_str().is_empty());
         }
     }
+
+    #[test]
+    fn test_available_height_by_position() {
+        // Trigger spanning 300..330 in an 800px viewport
+        assert_eq!(
+            available_height(PopoverPosition::Top, 300.0, 330.0, 800.0),
+            284.0
+        );
+        assert_eq!(
+            available_height(PopoverPosition::Bottom, 300.0, 330.0, 800.0),
+            454.0
+        );
+        assert_eq!(
+            available_height(PopoverPosition::Left, 300.0, 330.0, 800.0),
+            784.0
+        );
+        assert_eq!(
+            available_height(PopoverPosition::Right, 300.0, 330.0, 800.0),
+            784.0
+        );
+    }
+
+    #[test]
+    fn test_available_height_has_minimum() {
+        assert_eq!(
+            available_height(PopoverPosition::Top, 10.0, 40.0, 800.0),
+            MIN_MAX_HEIGHT
+        );
+    }
+
+    #[test]
+    fn test_container_style() {
+        assert_eq!(
+            container_style(PopoverSize::Medium, None),
+            "display: flex; flex-direction: column"
+        );
+        assert_eq!(
+            container_style(PopoverSize::Medium, Some(284.6)),
+            "display: flex; flex-direction: column; max-height: 284px"
+        );
+        assert!(
+            container_style(PopoverSize::Content, None)
+                .ends_with("width: max-content; max-width: 400px")
+        );
+    }
 }
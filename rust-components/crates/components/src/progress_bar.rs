@@ -4,8 +4,21 @@
 //! ProgressBar component
 //!
 //! Progress indicator with percentage display and status variants.
-
-use crate::internal::{AnalyticsMetadata, BaseComponentProps, ClassBuilder, ComponentMetadata};
+//!
+//! Long-running operations can be paused: a paused bar keeps showing the value
+//! it had when it was paused, and user controls such as Pause, Resume and
+//! Cancel go in the `actions` slot. The operation itself is application
+//! logic.
+//!
+//! Operations without a known total use the indeterminate mode, which loops
+//! an animation instead of showing a value.
+
+use crate::i18n::use_i18n;
+use crate::internal::motion::motion_disabled;
+use crate::internal::{
+    AnalyticsMetadata, BaseComponentProps, ClassBuilder, ComponentMetadata, apply_root_attributes,
+};
+use web_sys::Element;
 use yew::prelude::*;
 
 /// Status variants for the progress bar
@@ -59,9 +72,18 @@ pub struct ProgressBarProps {
     pub base: BaseComponentProps,
 
     /// Progress value (0.0 to 100.0)
+    ///
+    /// Values outside the range are clamped to it; NaN counts as 0.
     #[prop_or(0.0)]
     pub value: f32,
 
+    /// Whether the total of the operation is unknown
+    ///
+    /// An indeterminate bar loops an animation instead of showing `value`,
+    /// or shows a static striped fill when motion is disabled.
+    #[prop_or_default]
+    pub indeterminate: bool,
+
     /// Status variant
     #[prop_or_default]
     pub status: ProgressBarStatus,
@@ -82,13 +104,86 @@ pub struct ProgressBarProps {
     #[prop_or_default]
     pub additional_info: Option<Html>,
 
-    /// Result text shown when complete
+    /// Result text shown, and announced to screen readers, once `status`
+    /// is `Success` or `Error`
     #[prop_or_default]
     pub result_text: Option<String>,
 
-    /// Action button shown when complete
+    /// Action button shown with the result, once `status` is `Success` or
+    /// `Error`
     #[prop_or_default]
     pub result_button: Option<Html>,
+
+    /// Controls for the operation, such as Pause, Resume and Cancel buttons
+    ///
+    /// Rendered to the right of the bar while the operation is in progress.
+    #[prop_or_default]
+    pub actions: Option<Html>,
+
+    /// Whether the operation is paused
+    ///
+    /// A paused bar is dimmed and striped, its value is announced with a
+    /// "(paused)" suffix (`progress-bar.paused_text` I18nProvider message),
+    /// and it keeps the value it had when it was paused.
+    #[prop_or_default]
+    pub paused: bool,
+
+    /// Whether `value` updates still move a paused bar
+    ///
+    /// By default, updates arriving after pausing, such as in-flight chunk
+    /// completions, are ignored until the operation resumes.
+    #[prop_or_default]
+    pub allow_updates_while_paused: bool,
+}
+
+/// Gets the value a bar shows, given the value it showed last
+///
+/// A paused bar holds its value unless updates are allowed while paused.
+fn shown_value(last_shown: Option<f32>, value: f32, paused: bool, allow_updates: bool) -> f32 {
+    match last_shown {
+        Some(last_shown) if paused && !allow_updates => last_shown,
+        _ => value,
+    }
+}
+
+/// Clamps a value to the 0 to 100 range, treating NaN as 0
+fn clamp_value(value: f32) -> f32 {
+    if value.is_nan() {
+        0.0
+    } else {
+        value.clamp(0.0, 100.0)
+    }
+}
+
+/// Text announcing the value of the bar, such as "40% (paused)"
+fn value_text(percentage_text: &str, paused_text: Option<&str>) -> String {
+    match paused_text {
+        Some(paused_text) => format!("{} ({})", percentage_text, paused_text),
+        None => percentage_text.to_string(),
+    }
+}
+
+/// Dimmed neutral stripes over the fill of a paused bar, or of an
+/// indeterminate bar when motion is disabled
+const STRIPED_FILL_STYLE: &str = "opacity: 0.6; background-image: repeating-linear-gradient(\
+     -45deg, transparent 0 6px, var(--awsui-color-neutral500) 6px 12px)";
+
+/// Keyframes sliding the fill of an indeterminate bar across the track
+const INDETERMINATE_KEYFRAMES: &str = "@keyframes awsui-progress-bar-indeterminate { \
+     from { transform: translateX(-100%) } to { transform: translateX(250%) } }";
+
+/// Fill of an indeterminate bar, looping across the track
+const INDETERMINATE_FILL_STYLE: &str = "width: 40%; \
+     animation: awsui-progress-bar-indeterminate 1.5s var(--awsui-motion-easing-responsive) infinite";
+
+/// Gets the style of the fill
+fn fill_style(value: f32, paused: bool, indeterminate: bool, animate: bool) -> String {
+    match (indeterminate, animate) {
+        (true, true) => INDETERMINATE_FILL_STYLE.to_string(),
+        (true, false) => format!("width: 100%; {}", STRIPED_FILL_STYLE),
+        _ if paused => format!("width: {}%; {}", value, STRIPED_FILL_STYLE),
+        _ => format!("width: {}%", value),
+    }
 }
 
 /// ProgressBar component
@@ -99,8 +194,10 @@ pub struct ProgressBarProps {
 /// # Example
 ///
 /// ```rust
+/// # use yew::prelude::*;
 /// use cloudscape_components::{ProgressBar, ProgressBarStatus};
 ///
+/// # let _: Html =
 /// html! {
 ///     <ProgressBar
 ///         value={75.0}
@@ -108,13 +205,16 @@ pub struct ProgressBarProps {
 ///         label="Uploading files"
 ///     />
 /// }
+/// # ;
 /// ```
 ///
 /// # With additional info
 ///
 /// ```rust
+/// # use yew::prelude::*;
 /// use cloudscape_components::{ProgressBar, ProgressBarStatus};
 ///
+/// # let _: Html =
 /// html! {
 ///     <ProgressBar
 ///         value={50.0}
@@ -124,13 +224,16 @@ pub struct ProgressBarProps {
 ///         description={html! { <span>{ "This may take a few moments" }</span> }}
 ///     />
 /// }
+/// # ;
 /// ```
 ///
 /// # With result text and button
 ///
 /// ```rust
+/// # use yew::prelude::*;
 /// use cloudscape_components::{ProgressBar, ProgressBarStatus, Button};
 ///
+/// # let _: Html =
 /// html! {
 ///     <ProgressBar
 ///         value={100.0}
@@ -140,13 +243,58 @@ pub struct ProgressBarProps {
 ///         result_button={html! { <Button>{ "View files" }</Button> }}
 ///     />
 /// }
+/// # ;
+/// ```
+///
+/// # Pausable operation
+///
+/// Clear `paused` and emit new values once the operation resumes. Summarize a
+/// canceled operation with [`StatusIndicatorType::Stopped`](crate::StatusIndicatorType).
+/// In a [`Flashbar`](crate::Flashbar), set the same fields on
+/// [`FlashbarProgress`](crate::FlashbarProgress) so the actions show inside the
+/// flash.
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// use cloudscape_components::{Button, ButtonVariant, ProgressBar, SpaceBetween, SpaceBetweenDirection, SpaceBetweenSize};
+///
+/// # let _: Html =
+/// html! {
+///     <ProgressBar
+///         value={40.0}
+///         label="Uploading files"
+///         paused={true}
+///         actions={html! {
+///             <SpaceBetween direction={SpaceBetweenDirection::Horizontal} size={SpaceBetweenSize::Xs}>
+///                 <Button>{ "Resume" }</Button>
+///                 <Button variant={ButtonVariant::Link}>{ "Cancel" }</Button>
+///             </SpaceBetween>
+///         }}
+///     />
+/// }
+/// # ;
+/// ```
+///
+/// # Indeterminate
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// use cloudscape_components::ProgressBar;
+///
+/// # let _: Html =
+/// html! {
+///     <ProgressBar indeterminate={true} label="Preparing export" />
+/// }
+/// # ;
 /// ```
 ///
 /// # Compact flash variant
 ///
 /// ```rust
+/// # use yew::prelude::*;
 /// use cloudscape_components::{ProgressBar, ProgressBarVariant, ProgressBarStatus};
 ///
+/// # let _: Html =
 /// html! {
 ///     <ProgressBar
 ///         value={80.0}
@@ -154,14 +302,30 @@ pub struct ProgressBarProps {
 ///         status={ProgressBarStatus::InProgress}
 ///     />
 /// }
+/// # ;
 /// ```
 #[function_component(ProgressBar)]
 pub fn progress_bar(props: &ProgressBarProps) -> Html {
     let _metadata = ComponentMetadata::new("ProgressBar");
-
-    // Clamp value between 0 and 100
-    let clamped_value = props.value.clamp(0.0, 100.0);
+    let i18n = use_i18n();
+    let last_shown = use_mut_ref(|| None::<f32>);
+    let root_ref = use_node_ref();
+
+    // Hold the value while paused, then clamp it between 0 and 100
+    let value = shown_value(
+        *last_shown.borrow(),
+        props.value,
+        props.paused,
+        props.allow_updates_while_paused,
+    );
+    *last_shown.borrow_mut() = Some(value);
+    let clamped_value = clamp_value(value);
     let percentage_text = format!("{:.0}%", clamped_value);
+    let paused_text = props.paused.then(|| {
+        i18n.message("progress-bar", "paused_text")
+            .unwrap_or("paused")
+            .to_string()
+    });
 
     // Build CSS classes
     let classes = ClassBuilder::new()
@@ -174,6 +338,8 @@ pub fn progress_bar(props: &ProgressBarProps) -> Html {
             "awsui-progress-bar-variant-{}",
             props.variant.as_str()
         ))
+        .add_if(props.paused, "awsui-progress-bar-paused")
+        .add_if(props.indeterminate, "awsui-progress-bar-indeterminate")
         .build();
 
     let class = props.base.merge_classes(&classes);
@@ -183,7 +349,8 @@ pub fn progress_bar(props: &ProgressBarProps) -> Html {
         action: Some("progress-bar".to_string()),
         detail: Some(serde_json::json!({
             "status": props.status.as_str(),
-            "value": clamped_value
+            "value": clamped_value,
+            "paused": props.paused
         })),
         component: None,
     };
@@ -224,59 +391,73 @@ pub fn progress_bar(props: &ProgressBarProps) -> Html {
         html! {}
     };
 
-    // Render progress track and fill
+    // Render progress track and fill. An indeterminate bar has no value to
+    // report; before the bar is mounted, only the document-wide motion
+    // scope is checked.
+    let animate = !motion_disabled(root_ref.cast::<Element>().as_ref());
+    let indeterminate = props.indeterminate;
     let progress_section = html! {
         <div class="awsui-progress-bar-content">
-            <div class="awsui-progress-bar-track">
+            if indeterminate && animate {
+                <style>{ INDETERMINATE_KEYFRAMES }</style>
+            }
+            <div class="awsui-progress-bar-track" style="overflow: hidden">
                 <div
                     class="awsui-progress-bar-fill"
-                    style={format!("width: {}%", clamped_value)}
+                    style={fill_style(clamped_value, props.paused, indeterminate, animate)}
                     role="progressbar"
-                    aria-valuenow={clamped_value.to_string()}
+                    aria-label={props.label.clone()}
+                    aria-busy={indeterminate.then_some("true")}
+                    aria-valuenow={(!indeterminate).then(|| format!("{:.0}", clamped_value))}
+                    aria-valuetext={(!indeterminate).then(|| {
+                        value_text(&percentage_text, paused_text.as_deref())
+                    })}
                     aria-valuemin="0"
                     aria-valuemax="100"
                 />
             </div>
-            <div class="awsui-progress-bar-percentage">
-                { percentage_text }
-            </div>
+            if !indeterminate {
+                <div class="awsui-progress-bar-percentage">
+                    { percentage_text }
+                </div>
+            }
+            if let Some(ref actions) = props.actions
+                && props.status == ProgressBarStatus::InProgress
+            {
+                <div class="awsui-progress-bar-actions">
+                    { actions.clone() }
+                </div>
+            }
         </div>
     };
 
-    // Render result section if result text or button is provided
-    let result_section = if props.result_text.is_some() || props.result_button.is_some() {
-        html! {
-            <div class="awsui-progress-bar-result">
-                {
+    // Render the result once the operation finishes. The live region is
+    // always rendered so that screen readers announce the result when it
+    // appears.
+    let finished = props.status != ProgressBarStatus::InProgress;
+    let has_result = props.result_text.is_some() || props.result_button.is_some();
+    let result_section = html! {
+        <div class="awsui-progress-bar-result-region" aria-live="polite">
+            if finished && has_result {
+                <div class="awsui-progress-bar-result">
                     if let Some(ref result_text) = props.result_text {
-                        html! {
-                            <div class="awsui-progress-bar-result-text">
-                                { result_text }
-                            </div>
-                        }
-                    } else {
-                        html! {}
+                        <div class="awsui-progress-bar-result-text">
+                            { result_text }
+                        </div>
                     }
-                }
-                {
                     if let Some(ref result_button) = props.result_button {
-                        html! {
-                            <div class="awsui-progress-bar-result-button">
-                                { result_button.clone() }
-                            </div>
-                        }
-                    } else {
-                        html! {}
+                        <div class="awsui-progress-bar-result-button">
+                            { result_button.clone() }
+                        </div>
                     }
-                }
-            </div>
-        }
-    } else {
-        html! {}
+                </div>
+            }
+        </div>
     };
 
-    html! {
+    let root = html! {
         <div
+            ref={root_ref}
             id={props.base.id.clone()}
             class={class}
             data-analytics-metadata={analytics_attr}
@@ -286,7 +467,8 @@ pub fn progress_bar(props: &ProgressBarProps) -> Html {
             { progress_section }
             { result_section }
         </div>
-    }
+    };
+    apply_root_attributes(root, &props.base)
 }
 
 #[cfg(test)]
@@ -331,7 +513,7 @@ mod tests {
 
     #[test]
     fn all_status_types_have_unique_strings() {
-        let statuses = vec![
+        let statuses = [
             ProgressBarStatus::InProgress,
             ProgressBarStatus::Success,
             ProgressBarStatus::Error,
@@ -351,7 +533,7 @@ mod tests {
 
     #[test]
     fn all_variants_have_unique_strings() {
-        let variants = vec![ProgressBarVariant::Default, ProgressBarVariant::Flash];
+        let variants = [ProgressBarVariant::Default, ProgressBarVariant::Flash];
 
         let strings: Vec<_> = variants.iter().map(|v| v.as_str()).collect();
         let mut sorted = strings.clone();
@@ -378,7 +560,7 @@ mod tests {
         ];
 
         for (input, expected) in test_cases {
-            let clamped = input.clamp(0.0, 100.0);
+            let clamped = clamp_value(input);
             assert_eq!(
                 clamped, expected,
                 "Value {} should clamp to {}",
@@ -387,6 +569,68 @@ mod tests {
         }
     }
 
+    #[test]
+    fn nan_value_clamps_to_zero() {
+        assert_eq!(clamp_value(f32::NAN), 0.0);
+        assert_eq!(clamp_value(f32::INFINITY), 100.0);
+        assert_eq!(clamp_value(f32::NEG_INFINITY), 0.0);
+    }
+
+    #[test]
+    fn indeterminate_fill_style() {
+        assert_eq!(fill_style(40.0, false, false, true), "width: 40%");
+        assert!(fill_style(40.0, true, false, true).starts_with("width: 40%; opacity: 0.6"));
+        assert!(
+            fill_style(40.0, false, true, true)
+                .contains("animation: awsui-progress-bar-indeterminate")
+        );
+        assert!(!fill_style(40.0, false, true, false).contains("animation"));
+        assert!(fill_style(40.0, false, true, false).starts_with("width: 100%"));
+    }
+
+    /// CSS custom properties referenced by a style
+    #[cfg(feature = "generated")]
+    fn referenced_vars(style: &str) -> Vec<&str> {
+        style
+            .split("var(")
+            .skip(1)
+            .filter_map(|rest| rest.split([')', ',']).next())
+            .collect()
+    }
+
+    #[cfg(feature = "generated")]
+    #[test]
+    fn striped_fill_references_design_tokens() {
+        use cloudscape_design_tokens::generated::ColorToken;
+
+        let vars = referenced_vars(STRIPED_FILL_STYLE);
+        assert!(!vars.is_empty());
+        for var in vars {
+            assert!(
+                ColorToken::ALL
+                    .iter()
+                    .any(|token| token.css_var_name() == var),
+                "{} is not a design token",
+                var
+            );
+        }
+    }
+
+    #[test]
+    fn paused_bar_holds_its_value() {
+        // The first render shows the value even when paused
+        assert_eq!(shown_value(None, 40.0, true, false), 40.0);
+        assert_eq!(shown_value(Some(40.0), 45.0, true, false), 40.0);
+        assert_eq!(shown_value(Some(40.0), 45.0, true, true), 45.0);
+        assert_eq!(shown_value(Some(40.0), 45.0, false, false), 45.0);
+    }
+
+    #[test]
+    fn paused_value_text() {
+        assert_eq!(value_text("40%", None), "40%");
+        assert_eq!(value_text("40%", Some("paused")), "40% (paused)");
+    }
+
     #[test]
     fn status_debug_format() {
         let status = ProgressBarStatus::Success;
@@ -0,0 +1,1449 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! PropertyFilter component for filtering collections by property values.
+//!
+//! The user builds a query of tokens such as "Status = running" in a single
+//! input: suggestions offer property names first, then the operators of the
+//! chosen property, then its values. Text matching no property becomes a
+//! free-text token. Applied tokens are listed below the input; clicking one
+//! opens an editor popover, and the dropdown before each token after the
+//! first sets whether tokens combine with "and" or "or".
+
+use crate::autosuggest::{
+    Autosuggest, AutosuggestChangeDetail, AutosuggestOption, AutosuggestSelectDetail, FilteringType,
+};
+use crate::button::{Button, ButtonVariant, FormAction};
+use crate::form_field::FormField;
+use crate::i18n::{I18nContext, use_i18n};
+use crate::icon::Icon;
+use crate::internal::{
+    AriaAttributes, BaseComponentProps, ClassBuilder, ClickEvent, ComponentMetadata, CustomEvent,
+    DropdownStatus, LoadItemsDetail, apply_root_attributes,
+};
+use crate::popover::{Popover, PopoverDismissDetail, PopoverPosition, PopoverSize};
+use crate::select::{Select, SelectChangeDetail, SelectOption};
+use crate::space_between::{SpaceBetween, SpaceBetweenDirection, SpaceBetweenSize};
+use yew::prelude::*;
+
+/// Operators of a property created without explicit operators
+pub const DEFAULT_OPERATORS: &[&str] = &["=", "!=", ":", "!:"];
+
+/// Operators of free-text tokens
+const FREE_TEXT_OPERATORS: &[&str] = &[":", "!:"];
+
+/// A property the collection can be filtered by
+#[derive(Clone, PartialEq, Debug)]
+pub struct FilteringProperty {
+    /// Key of the property, stored in tokens
+    pub key: String,
+    /// Display label of the property, typed to pick it
+    pub property_label: String,
+    /// Operators the property supports, such as "=" or ">="
+    pub operators: Vec<String>,
+    /// Group the property is listed under in suggestions
+    pub group: Option<String>,
+}
+
+impl FilteringProperty {
+    /// Creates a property supporting the [`DEFAULT_OPERATORS`]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cloudscape_components::FilteringProperty;
+    ///
+    /// let property = FilteringProperty::new("instance_type", "Instance type")
+    ///     .with_operators(["=", "!="])
+    ///     .with_group("Compute");
+    /// ```
+    pub fn new(key: impl Into<String>, property_label: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            property_label: property_label.into(),
+            operators: DEFAULT_OPERATORS.iter().map(|op| op.to_string()).collect(),
+            group: None,
+        }
+    }
+
+    /// Sets the operators the property supports
+    pub fn with_operators(
+        mut self,
+        operators: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.operators = operators.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the group the property is listed under
+    pub fn with_group(mut self, group: impl Into<String>) -> Self {
+        self.group = Some(group.into());
+        self
+    }
+}
+
+/// A value suggested for a property
+#[derive(Clone, PartialEq, Debug)]
+pub struct FilteringOption {
+    /// Key of the property the value belongs to
+    pub property_key: String,
+    /// The value stored in tokens
+    pub value: String,
+    /// Display label of the value
+    pub label: Option<String>,
+}
+
+impl FilteringOption {
+    /// Creates a value suggestion for the property with the given key
+    pub fn new(property_key: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            property_key: property_key.into(),
+            value: value.into(),
+            label: None,
+        }
+    }
+
+    /// Sets the display label of the value
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Gets the display text for this value (label or value)
+    pub fn display_text(&self) -> &str {
+        self.label.as_ref().unwrap_or(&self.value)
+    }
+}
+
+/// A condition of a property filter query
+#[derive(Clone, PartialEq, Debug)]
+pub struct FilterToken {
+    /// Key of the filtered property, or `None` for free text matching any
+    /// property
+    pub property_key: Option<String>,
+    /// Comparison operator, such as "=" or ":"
+    pub operator: String,
+    /// Value compared against
+    pub value: String,
+}
+
+impl FilterToken {
+    /// Creates a token comparing a property with a value
+    pub fn new(
+        property_key: impl Into<String>,
+        operator: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self {
+        Self {
+            property_key: Some(property_key.into()),
+            operator: operator.into(),
+            value: value.into(),
+        }
+    }
+
+    /// Creates a free-text token matching items containing `value` in any
+    /// property
+    pub fn free_text(value: impl Into<String>) -> Self {
+        Self {
+            property_key: None,
+            operator: ":".to_string(),
+            value: value.into(),
+        }
+    }
+
+    /// Sets the comparison operator
+    pub fn with_operator(mut self, operator: impl Into<String>) -> Self {
+        self.operator = operator.into();
+        self
+    }
+
+    /// Whether the token matches any property
+    pub fn is_free_text(&self) -> bool {
+        self.property_key.is_none()
+    }
+}
+
+/// How the tokens of a query combine
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AndOr {
+    /// Items must match every token
+    #[default]
+    And,
+    /// Items must match at least one token
+    Or,
+}
+
+impl AndOr {
+    /// Gets the string representation of the operation
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AndOr::And => "and",
+            AndOr::Or => "or",
+        }
+    }
+}
+
+/// Tokens of a property filter and how they combine
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct PropertyFilterQuery {
+    /// Tokens in the order they were added
+    pub tokens: Vec<FilterToken>,
+    /// How the tokens combine
+    pub operation: AndOr,
+}
+
+impl PropertyFilterQuery {
+    /// Creates an empty query combining tokens with "and"
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the tokens
+    pub fn with_tokens(mut self, tokens: Vec<FilterToken>) -> Self {
+        self.tokens = tokens;
+        self
+    }
+
+    /// Sets how the tokens combine
+    pub fn with_operation(mut self, operation: AndOr) -> Self {
+        self.operation = operation;
+        self
+    }
+}
+
+/// Detail of a request to load the values of a property
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PropertyFilterLoadItemsDetail {
+    /// Key of the property whose values are requested, or `None` while no
+    /// property is typed yet
+    pub filtering_property: Option<String>,
+    /// Operator typed after the property
+    pub filtering_operator: Option<String>,
+    /// The typed value the values are filtered by, or all the text when no
+    /// property is typed
+    pub filtering_text: String,
+    /// Whether the values should replace the current ones
+    pub first_page: bool,
+    /// Whether the page that failed to load is requested again
+    pub same_page: bool,
+}
+
+/// Internationalization strings for PropertyFilter
+///
+/// Unset strings fall back to the closest I18nProvider (keys under
+/// `"property_filter."`), then to English.
+#[derive(Clone, PartialEq, Default)]
+pub struct PropertyFilterI18nStrings {
+    /// ARIA label of the filter input (default: "Filter")
+    pub filtering_aria_label: Option<String>,
+    /// Placeholder of the filter input (default: "Filter by property or value")
+    pub filtering_placeholder: Option<String>,
+    /// Property option of free-text tokens in the editor
+    /// (default: "All properties")
+    pub all_properties_label: Option<String>,
+    /// Label of the property field in the editor (default: "Property")
+    pub property_text: Option<String>,
+    /// Label of the operator field in the editor (default: "Operator")
+    pub operator_text: Option<String>,
+    /// Label of the value field in the editor (default: "Value")
+    pub value_text: Option<String>,
+    /// Header of the editor popover (default: "Edit filter")
+    pub edit_token_header: Option<String>,
+    /// Text of the "and" operation (default: "and")
+    pub operation_and_text: Option<String>,
+    /// Text of the "or" operation (default: "or")
+    pub operation_or_text: Option<String>,
+    /// ARIA label of the operation dropdowns (default: "Boolean operator")
+    pub operation_aria_label: Option<String>,
+    /// Text of the editor's apply button (default: "Apply")
+    pub apply_action_text: Option<String>,
+    /// Text of the editor's cancel button (default: "Cancel")
+    pub cancel_action_text: Option<String>,
+    /// Text of the button removing every token (default: "Clear filters")
+    pub clear_filters_text: Option<String>,
+    /// ARIA label of token dismiss buttons, followed by the token
+    /// (default: "Remove filter")
+    pub remove_token_aria_label: Option<String>,
+    /// Description of "=" (default: "Equals")
+    pub operator_equals_text: Option<String>,
+    /// Description of "!=" (default: "Does not equal")
+    pub operator_does_not_equal_text: Option<String>,
+    /// Description of ":" (default: "Contains")
+    pub operator_contains_text: Option<String>,
+    /// Description of "!:" (default: "Does not contain")
+    pub operator_does_not_contain_text: Option<String>,
+    /// Description of ">" (default: "Greater than")
+    pub operator_greater_text: Option<String>,
+    /// Description of ">=" (default: "Greater than or equal")
+    pub operator_greater_or_equal_text: Option<String>,
+    /// Description of "<" (default: "Less than")
+    pub operator_less_text: Option<String>,
+    /// Description of "<=" (default: "Less than or equal")
+    pub operator_less_or_equal_text: Option<String>,
+}
+
+/// Strings used to render a PropertyFilter after applying the fallback chain
+#[derive(Clone, PartialEq)]
+struct ResolvedStrings {
+    filtering_aria_label: String,
+    filtering_placeholder: String,
+    all_properties_label: String,
+    property_text: String,
+    operator_text: String,
+    value_text: String,
+    edit_token_header: String,
+    operation_and_text: String,
+    operation_or_text: String,
+    operation_aria_label: String,
+    apply_action_text: String,
+    cancel_action_text: String,
+    clear_filters_text: String,
+    remove_token_aria_label: String,
+    operator_equals_text: String,
+    operator_does_not_equal_text: String,
+    operator_contains_text: String,
+    operator_does_not_contain_text: String,
+    operator_greater_text: String,
+    operator_greater_or_equal_text: String,
+    operator_less_text: String,
+    operator_less_or_equal_text: String,
+}
+
+impl ResolvedStrings {
+    /// Gets the description of an operator, or `None` for unknown operators
+    fn operator_description(&self, operator: &str) -> Option<&str> {
+        match operator {
+            "=" => Some(&self.operator_equals_text),
+            "!=" => Some(&self.operator_does_not_equal_text),
+            ":" => Some(&self.operator_contains_text),
+            "!:" => Some(&self.operator_does_not_contain_text),
+            ">" => Some(&self.operator_greater_text),
+            ">=" => Some(&self.operator_greater_or_equal_text),
+            "<" => Some(&self.operator_less_text),
+            "<=" => Some(&self.operator_less_or_equal_text),
+            _ => None,
+        }
+    }
+
+    /// Gets the text of an operation
+    fn operation_text(&self, operation: AndOr) -> &str {
+        match operation {
+            AndOr::And => &self.operation_and_text,
+            AndOr::Or => &self.operation_or_text,
+        }
+    }
+}
+
+/// Resolves every user-visible string: `i18n_strings` prop, then the
+/// I18nProvider, then English
+fn resolve_strings(strings: &PropertyFilterI18nStrings, i18n: &I18nContext) -> ResolvedStrings {
+    let resolve = |value: &Option<String>, key: &str, default: &str| {
+        value
+            .clone()
+            .or_else(|| i18n.message("property_filter", key).map(str::to_string))
+            .unwrap_or_else(|| default.to_string())
+    };
+
+    ResolvedStrings {
+        filtering_aria_label: resolve(
+            &strings.filtering_aria_label,
+            "filtering_aria_label",
+            "Filter",
+        ),
+        filtering_placeholder: resolve(
+            &strings.filtering_placeholder,
+            "filtering_placeholder",
+            "Filter by property or value",
+        ),
+        all_properties_label: resolve(
+            &strings.all_properties_label,
+            "all_properties_label",
+            "All properties",
+        ),
+        property_text: resolve(&strings.property_text, "property_text", "Property"),
+        operator_text: resolve(&strings.operator_text, "operator_text", "Operator"),
+        value_text: resolve(&strings.value_text, "value_text", "Value"),
+        edit_token_header: resolve(
+            &strings.edit_token_header,
+            "edit_token_header",
+            "Edit filter",
+        ),
+        operation_and_text: resolve(&strings.operation_and_text, "operation_and_text", "and"),
+        operation_or_text: resolve(&strings.operation_or_text, "operation_or_text", "or"),
+        operation_aria_label: resolve(
+            &strings.operation_aria_label,
+            "operation_aria_label",
+            "Boolean operator",
+        ),
+        apply_action_text: resolve(&strings.apply_action_text, "apply_action_text", "Apply"),
+        cancel_action_text: resolve(&strings.cancel_action_text, "cancel_action_text", "Cancel"),
+        clear_filters_text: resolve(
+            &strings.clear_filters_text,
+            "clear_filters_text",
+            "Clear filters",
+        ),
+        remove_token_aria_label: resolve(
+            &strings.remove_token_aria_label,
+            "remove_token_aria_label",
+            "Remove filter",
+        ),
+        operator_equals_text: resolve(
+            &strings.operator_equals_text,
+            "operator_equals_text",
+            "Equals",
+        ),
+        operator_does_not_equal_text: resolve(
+            &strings.operator_does_not_equal_text,
+            "operator_does_not_equal_text",
+            "Does not equal",
+        ),
+        operator_contains_text: resolve(
+            &strings.operator_contains_text,
+            "operator_contains_text",
+            "Contains",
+        ),
+        operator_does_not_contain_text: resolve(
+            &strings.operator_does_not_contain_text,
+            "operator_does_not_contain_text",
+            "Does not contain",
+        ),
+        operator_greater_text: resolve(
+            &strings.operator_greater_text,
+            "operator_greater_text",
+            "Greater than",
+        ),
+        operator_greater_or_equal_text: resolve(
+            &strings.operator_greater_or_equal_text,
+            "operator_greater_or_equal_text",
+            "Greater than or equal",
+        ),
+        operator_less_text: resolve(
+            &strings.operator_less_text,
+            "operator_less_text",
+            "Less than",
+        ),
+        operator_less_or_equal_text: resolve(
+            &strings.operator_less_or_equal_text,
+            "operator_less_or_equal_text",
+            "Less than or equal",
+        ),
+    }
+}
+
+/// How far the text in the filter input has been typed
+#[derive(Debug, Clone, PartialEq)]
+enum ParsedText<'a> {
+    /// A property label followed by a complete operator and a value, which
+    /// may still be empty
+    Property {
+        property: &'a FilteringProperty,
+        operator: &'a str,
+        value: &'a str,
+    },
+    /// A property label followed by the start of one of its operators
+    Operator {
+        property: &'a FilteringProperty,
+        prefix: &'a str,
+    },
+    /// Text matching no property
+    FreeText {
+        operator: &'static str,
+        value: &'a str,
+    },
+}
+
+/// Strips `prefix` from the start of `text`, ignoring ASCII case
+fn strip_prefix_ignore_case<'a>(text: &'a str, prefix: &str) -> Option<&'a str> {
+    text.get(..prefix.len())
+        .filter(|head| head.eq_ignore_ascii_case(prefix))
+        .map(|_| &text[prefix.len()..])
+}
+
+/// Whether `text` contains `filter`, ignoring case
+fn contains_ignore_case(text: &str, filter: &str) -> bool {
+    text.to_lowercase().contains(&filter.to_lowercase())
+}
+
+/// Parses the text of the filter input
+///
+/// Longer property labels and operators are tried first, so "Instance
+/// type" wins over "Instance" and ">=" over ">". An operator that starts a
+/// longer one, such as ">", is complete once something follows it.
+fn parse_text<'a>(text: &'a str, properties: &'a [FilteringProperty]) -> ParsedText<'a> {
+    let mut candidates: Vec<&FilteringProperty> = properties.iter().collect();
+    candidates.sort_by_key(|property| std::cmp::Reverse(property.property_label.len()));
+
+    for property in candidates {
+        let Some(rest) = strip_prefix_ignore_case(text, &property.property_label) else {
+            continue;
+        };
+        let rest = rest.trim_start();
+        if let Some(operator) = property
+            .operators
+            .iter()
+            .filter(|operator| rest.starts_with(operator.as_str()))
+            .max_by_key(|operator| operator.len())
+        {
+            let value = rest[operator.len()..].trim_start();
+            let longer = property
+                .operators
+                .iter()
+                .any(|other| other.len() > operator.len() && other.starts_with(rest));
+            if !(value.is_empty() && longer) {
+                return ParsedText::Property {
+                    property,
+                    operator,
+                    value,
+                };
+            }
+        }
+        if property
+            .operators
+            .iter()
+            .any(|operator| operator.starts_with(rest))
+        {
+            return ParsedText::Operator {
+                property,
+                prefix: rest,
+            };
+        }
+    }
+
+    match text.strip_prefix("!:") {
+        Some(value) => ParsedText::FreeText {
+            operator: "!:",
+            value: value.trim_start(),
+        },
+        None => ParsedText::FreeText {
+            operator: ":",
+            value: text,
+        },
+    }
+}
+
+/// Gets the token submitted with the text of the filter input, or `None`
+/// while the text is incomplete
+///
+/// A property with an operator but no value is incomplete. A property
+/// label without an operator is taken as free text.
+fn text_token(
+    text: &str,
+    properties: &[FilteringProperty],
+    free_text: bool,
+) -> Option<FilterToken> {
+    let token = match parse_text(text, properties) {
+        ParsedText::Property {
+            property,
+            operator,
+            value,
+        } => {
+            let value = value.trim_end();
+            return (!value.is_empty())
+                .then(|| FilterToken::new(property.key.clone(), operator, value));
+        }
+        ParsedText::Operator { .. } => FilterToken::free_text(text.trim()),
+        ParsedText::FreeText { operator, value } => {
+            FilterToken::free_text(value.trim()).with_operator(operator)
+        }
+    };
+    (free_text && !token.value.is_empty()).then_some(token)
+}
+
+/// Gets the suggestions for the text of the filter input
+///
+/// Picking a property or an operator suggestion fills in the input up to a
+/// trailing space, so typing continues with the next part of the token.
+fn suggestions(
+    text: &str,
+    properties: &[FilteringProperty],
+    options: &[FilteringOption],
+    strings: &ResolvedStrings,
+) -> Vec<AutosuggestOption> {
+    match parse_text(text, properties) {
+        ParsedText::FreeText { value, .. } => {
+            let property_suggestions = properties
+                .iter()
+                .filter(|property| contains_ignore_case(&property.property_label, value))
+                .map(|property| {
+                    let option = AutosuggestOption::new(format!("{} ", property.property_label))
+                        .with_label(property.property_label.clone());
+                    match &property.group {
+                        Some(group) => option.with_label_tag(group.clone()),
+                        None => option,
+                    }
+                });
+            let value_suggestions = options
+                .iter()
+                .filter(|option| contains_ignore_case(option.display_text(), value))
+                .filter_map(|option| {
+                    let property = properties.iter().find(|property| {
+                        property.key == option.property_key
+                            && property.operators.iter().any(|operator| operator == "=")
+                    })?;
+                    Some(
+                        AutosuggestOption::new(format!(
+                            "{} = {}",
+                            property.property_label, option.value
+                        ))
+                        .with_label(format!(
+                            "{} = {}",
+                            property.property_label,
+                            option.display_text()
+                        )),
+                    )
+                });
+            property_suggestions.chain(value_suggestions).collect()
+        }
+        ParsedText::Operator { property, prefix } => property
+            .operators
+            .iter()
+            .filter(|operator| operator.starts_with(prefix))
+            .map(|operator| {
+                let option =
+                    AutosuggestOption::new(format!("{} {} ", property.property_label, operator))
+                        .with_label(format!("{} {}", property.property_label, operator));
+                match strings.operator_description(operator) {
+                    Some(description) => option.with_description(description),
+                    None => option,
+                }
+            })
+            .collect(),
+        ParsedText::Property {
+            property,
+            operator,
+            value,
+        } => options
+            .iter()
+            .filter(|option| {
+                option.property_key == property.key
+                    && contains_ignore_case(option.display_text(), value)
+            })
+            .map(|option| {
+                AutosuggestOption::new(format!(
+                    "{} {} {}",
+                    property.property_label, operator, option.value
+                ))
+                .with_label(option.display_text())
+            })
+            .collect(),
+    }
+}
+
+/// Gets the request to load values for the text of the filter input
+fn load_items_detail(
+    detail: &LoadItemsDetail,
+    properties: &[FilteringProperty],
+) -> PropertyFilterLoadItemsDetail {
+    let (filtering_property, filtering_operator, filtering_text) =
+        match parse_text(&detail.filtering_text, properties) {
+            ParsedText::Property {
+                property,
+                operator,
+                value,
+            } => (
+                Some(property.key.clone()),
+                Some(operator.to_string()),
+                value.to_string(),
+            ),
+            ParsedText::Operator { property, .. } => {
+                (Some(property.key.clone()), None, String::new())
+            }
+            ParsedText::FreeText { value, .. } => (None, None, value.to_string()),
+        };
+
+    PropertyFilterLoadItemsDetail {
+        filtering_property,
+        filtering_operator,
+        filtering_text,
+        first_page: detail.first_page,
+        same_page: detail.same_page,
+    }
+}
+
+/// Gets the text of a token, such as "Status = Running"
+///
+/// Values show their option label; "contains" free text shows the value
+/// alone.
+fn token_label(
+    token: &FilterToken,
+    properties: &[FilteringProperty],
+    options: &[FilteringOption],
+) -> String {
+    let Some(key) = &token.property_key else {
+        return match token.operator.as_str() {
+            ":" => token.value.clone(),
+            operator => format!("{} {}", operator, token.value),
+        };
+    };
+
+    let property_label = properties
+        .iter()
+        .find(|property| property.key == *key)
+        .map_or(key.as_str(), |property| property.property_label.as_str());
+    let value = options
+        .iter()
+        .find(|option| option.property_key == *key && option.value == token.value)
+        .map_or(token.value.as_str(), FilteringOption::display_text);
+    format!("{} {} {}", property_label, token.operator, value)
+}
+
+/// Gets the operators offered for a token of the property with the given
+/// key, or for free text
+fn token_operators(properties: &[FilteringProperty], key: Option<&str>) -> Vec<String> {
+    key.and_then(|key| properties.iter().find(|property| property.key == key))
+        .map(|property| property.operators.clone())
+        .unwrap_or_else(|| {
+            FREE_TEXT_OPERATORS
+                .iter()
+                .map(|op| op.to_string())
+                .collect()
+        })
+}
+
+/// Moves a token to another property, keeping its operator when the
+/// property supports it and taking the property's first operator otherwise
+fn with_token_property(
+    token: &FilterToken,
+    properties: &[FilteringProperty],
+    key: Option<String>,
+) -> FilterToken {
+    let operators = token_operators(properties, key.as_deref());
+    let operator = if operators.contains(&token.operator) {
+        token.operator.clone()
+    } else {
+        operators.first().cloned().unwrap_or_default()
+    };
+    FilterToken {
+        property_key: key,
+        operator,
+        value: token.value.clone(),
+    }
+}
+
+/// Gets the index of the edited token after removing the token at
+/// `removed`: the edit closes with its token and follows a token moving down
+fn editing_after_remove(editing: Option<usize>, removed: usize) -> Option<usize> {
+    match editing {
+        Some(index) if index == removed => None,
+        Some(index) if index > removed => Some(index - 1),
+        editing => editing,
+    }
+}
+
+/// Properties for the token editor
+#[derive(Properties, PartialEq, Clone)]
+struct TokenEditorProps {
+    token: FilterToken,
+    properties: Vec<FilteringProperty>,
+    options: Vec<FilteringOption>,
+    strings: ResolvedStrings,
+    free_text: bool,
+    on_submit: Callback<FilterToken>,
+    on_cancel: Callback<()>,
+}
+
+/// Form editing the property, operator and value of an applied token
+#[function_component(TokenEditor)]
+fn token_editor(props: &TokenEditorProps) -> Html {
+    let draft = use_state(|| props.token.clone());
+    let strings = &props.strings;
+
+    let mut property_options: Vec<SelectOption> = props
+        .properties
+        .iter()
+        .map(|property| {
+            let option =
+                SelectOption::new(property.key.clone()).with_label(property.property_label.clone());
+            match &property.group {
+                Some(group) => option.with_label_tag(group.clone()),
+                None => option,
+            }
+        })
+        .collect();
+    if props.free_text || draft.is_free_text() {
+        property_options.insert(
+            0,
+            SelectOption::new("").with_label(strings.all_properties_label.clone()),
+        );
+    }
+    let selected_property = property_options
+        .iter()
+        .find(|option| option.value == draft.property_key.clone().unwrap_or_default())
+        .cloned();
+
+    let operator_options: Vec<SelectOption> =
+        token_operators(&props.properties, draft.property_key.as_deref())
+            .into_iter()
+            .map(|operator| {
+                let option = SelectOption::new(operator.clone());
+                match strings.operator_description(&operator) {
+                    Some(description) => option.with_description(description),
+                    None => option,
+                }
+            })
+            .collect();
+    let selected_operator = operator_options
+        .iter()
+        .find(|option| option.value == draft.operator)
+        .cloned();
+
+    let value_options: Vec<AutosuggestOption> = props
+        .options
+        .iter()
+        .filter(|option| draft.property_key.as_ref() == Some(&option.property_key))
+        .map(|option| {
+            AutosuggestOption::new(option.value.clone()).with_label(option.display_text())
+        })
+        .collect();
+
+    let on_property_change = {
+        let draft = draft.clone();
+        let properties = props.properties.clone();
+        Callback::from(move |event: CustomEvent<SelectChangeDetail>| {
+            let key = event
+                .detail
+                .selected_option
+                .map(|option| option.value)
+                .filter(|key| !key.is_empty());
+            draft.set(with_token_property(&draft, &properties, key));
+        })
+    };
+
+    let on_operator_change = {
+        let draft = draft.clone();
+        Callback::from(move |event: CustomEvent<SelectChangeDetail>| {
+            if let Some(option) = event.detail.selected_option {
+                draft.set((*draft).clone().with_operator(option.value));
+            }
+        })
+    };
+
+    let set_value = {
+        let draft = draft.clone();
+        Callback::from(move |value: String| {
+            draft.set(FilterToken {
+                value,
+                ..(*draft).clone()
+            });
+        })
+    };
+
+    let on_apply = {
+        let draft = draft.clone();
+        let on_submit = props.on_submit.clone();
+        Callback::from(move |_: ClickEvent| {
+            on_submit.emit(FilterToken {
+                value: draft.value.trim().to_string(),
+                ..(*draft).clone()
+            });
+        })
+    };
+
+    html! {
+        <div class="awsui-property-filter-token-editor">
+            <SpaceBetween size={SpaceBetweenSize::M}>
+                <FormField label={strings.property_text.clone()}>
+                    <Select
+                        selected_option={selected_property}
+                        options={property_options}
+                        on_change={on_property_change}
+                    />
+                </FormField>
+                <FormField label={strings.operator_text.clone()}>
+                    <Select
+                        selected_option={selected_operator}
+                        options={operator_options}
+                        on_change={on_operator_change}
+                    />
+                </FormField>
+                <FormField label={strings.value_text.clone()}>
+                    <Autosuggest
+                        value={draft.value.clone()}
+                        options={value_options}
+                        on_change={set_value.reform(|event: CustomEvent<AutosuggestChangeDetail>| {
+                            event.detail.value
+                        })}
+                        on_select={set_value.reform(|event: CustomEvent<AutosuggestSelectDetail>| {
+                            event.detail.value
+                        })}
+                    />
+                </FormField>
+                <SpaceBetween direction={SpaceBetweenDirection::Horizontal} size={SpaceBetweenSize::Xs}>
+                    <Button
+                        variant={ButtonVariant::Link}
+                        form_action={FormAction::None}
+                        on_click={props.on_cancel.reform(|_: ClickEvent| ())}
+                    >
+                        { Html::from(strings.cancel_action_text.clone()) }
+                    </Button>
+                    <Button
+                        variant={ButtonVariant::Primary}
+                        form_action={FormAction::None}
+                        disabled={draft.value.trim().is_empty()}
+                        on_click={on_apply}
+                    >
+                        { Html::from(strings.apply_action_text.clone()) }
+                    </Button>
+                </SpaceBetween>
+            </SpaceBetween>
+        </div>
+    }
+}
+
+/// Properties for the PropertyFilter component
+#[derive(Properties, PartialEq, Clone)]
+pub struct PropertyFilterProps {
+    /// Base component properties
+    #[prop_or_default]
+    pub base: BaseComponentProps,
+
+    /// Properties the collection can be filtered by
+    #[prop_or_default]
+    pub filtering_properties: Vec<FilteringProperty>,
+
+    /// Values suggested for the properties
+    #[prop_or_default]
+    pub filtering_options: Vec<FilteringOption>,
+
+    /// The applied tokens (controlled component)
+    #[prop_or_default]
+    pub query: PropertyFilterQuery,
+
+    /// Callback fired when tokens are added, edited or removed, or their
+    /// operation changes
+    #[prop_or_default]
+    pub on_change: Option<Callback<CustomEvent<PropertyFilterQuery>>>,
+
+    /// Whether text matching no property is rejected instead of becoming a
+    /// free-text token
+    #[prop_or_default]
+    pub disable_free_text_filtering: bool,
+
+    /// Whether the filter is disabled
+    #[prop_or_default]
+    pub disabled: bool,
+
+    /// Loading state of values fetched with `on_load_items`
+    #[prop_or_default]
+    pub loading_status: Option<DropdownStatus>,
+
+    /// Callback fired to load values: when the suggestions open, shortly
+    /// after the typed text changes, and when the suggestions are scrolled
+    /// near their end while `loading_status` is pending
+    #[prop_or_default]
+    pub on_load_items: Option<Callback<CustomEvent<PropertyFilterLoadItemsDetail>>>,
+
+    /// Internationalization strings
+    #[prop_or_default]
+    pub i18n_strings: PropertyFilterI18nStrings,
+}
+
+/// PropertyFilter component for filtering collections with tokens.
+///
+/// The component is controlled: it renders the `query` it is given and
+/// reports every change through `on_change`. The text being typed stays
+/// internal until it is submitted as a token, with Enter or by picking a
+/// value suggestion.
+///
+/// # Example
+///
+/// ```rust
+/// use cloudscape_components::{
+///     CustomEvent, FilteringOption, FilteringProperty, PropertyFilter, PropertyFilterQuery,
+/// };
+/// use yew::prelude::*;
+///
+/// #[function_component(MyComponent)]
+/// fn my_component() -> Html {
+///     let query = use_state(PropertyFilterQuery::new);
+///
+///     let on_change = {
+///         let query = query.clone();
+///         Callback::from(move |event: CustomEvent<PropertyFilterQuery>| {
+///             query.set(event.detail);
+///         })
+///     };
+///
+///     html! {
+///         <PropertyFilter
+///             filtering_properties={vec![
+///                 FilteringProperty::new("status", "Status").with_operators(["=", "!="]),
+///                 FilteringProperty::new("name", "Name"),
+///             ]}
+///             filtering_options={vec![
+///                 FilteringOption::new("status", "running").with_label("Running"),
+///                 FilteringOption::new("status", "stopped").with_label("Stopped"),
+///             ]}
+///             query={(*query).clone()}
+///             on_change={on_change}
+///         />
+///     }
+/// }
+/// ```
+#[function_component(PropertyFilter)]
+pub fn property_filter(props: &PropertyFilterProps) -> Html {
+    let _metadata = ComponentMetadata::new("PropertyFilter");
+    let i18n = use_i18n();
+    let strings = resolve_strings(&props.i18n_strings, &i18n);
+    let filtering_text = use_state(String::new);
+    // Index of the token whose editor is open
+    let editing = use_state(|| None::<usize>);
+    let free_text = !props.disable_free_text_filtering;
+
+    let emit_change = {
+        let on_change = props.on_change.clone();
+        Callback::from(move |query: PropertyFilterQuery| {
+            if let Some(callback) = &on_change {
+                callback.emit(CustomEvent::new_non_cancelable(query));
+            }
+        })
+    };
+
+    let on_text_change = {
+        let filtering_text = filtering_text.clone();
+        Callback::from(move |event: CustomEvent<AutosuggestChangeDetail>| {
+            filtering_text.set(event.detail.value);
+        })
+    };
+
+    let on_text_select = {
+        let filtering_text = filtering_text.clone();
+        let emit_change = emit_change.clone();
+        let query = props.query.clone();
+        let properties = props.filtering_properties.clone();
+
+        Callback::from(move |event: CustomEvent<AutosuggestSelectDetail>| {
+            let text = event.detail.value;
+            // Picking a property or an operator continues the token
+            if event.detail.selected_option.is_some() && text.ends_with(' ') {
+                filtering_text.set(text);
+                return;
+            }
+            match text_token(&text, &properties, free_text) {
+                Some(token) => {
+                    let mut query = query.clone();
+                    query.tokens.push(token);
+                    emit_change.emit(query);
+                    filtering_text.set(String::new());
+                }
+                None => filtering_text.set(text),
+            }
+        })
+    };
+
+    let on_load_items = props.on_load_items.clone().map(|on_load_items| {
+        let properties = props.filtering_properties.clone();
+        Callback::from(move |event: CustomEvent<LoadItemsDetail>| {
+            on_load_items.emit(CustomEvent::new_non_cancelable(load_items_detail(
+                &event.detail,
+                &properties,
+            )));
+        })
+    });
+
+    let on_operation_change = {
+        let emit_change = emit_change.clone();
+        let query = props.query.clone();
+        Callback::from(move |event: CustomEvent<SelectChangeDetail>| {
+            let operation = match event.detail.selected_option {
+                Some(option) if option.value == AndOr::Or.as_str() => AndOr::Or,
+                _ => AndOr::And,
+            };
+            emit_change.emit(query.clone().with_operation(operation));
+        })
+    };
+
+    let on_clear = {
+        let emit_change = emit_change.clone();
+        let query = props.query.clone();
+        Callback::from(move |_: ClickEvent| {
+            emit_change.emit(query.clone().with_tokens(Vec::new()));
+        })
+    };
+
+    let close_editor = {
+        let editing = editing.clone();
+        Callback::from(move |_: ()| editing.set(None))
+    };
+
+    let operation_options: Vec<SelectOption> = [AndOr::And, AndOr::Or]
+        .into_iter()
+        .map(|operation| {
+            SelectOption::new(operation.as_str()).with_label(strings.operation_text(operation))
+        })
+        .collect();
+    let selected_operation = operation_options
+        .iter()
+        .find(|option| option.value == props.query.operation.as_str())
+        .cloned();
+
+    let classes = ClassBuilder::new()
+        .add("awsui-property-filter")
+        .add_if(props.disabled, "awsui-property-filter-disabled")
+        .add_opt(props.base.class.clone());
+
+    let tokens = props.query.tokens.iter().enumerate().map(|(index, token)| {
+        let label = token_label(token, &props.filtering_properties, &props.filtering_options);
+        let is_editing = *editing == Some(index);
+
+        let on_edit = {
+            let editing = editing.clone();
+            Callback::from(move |e: MouseEvent| {
+                e.prevent_default();
+                editing.set(Some(index));
+            })
+        };
+
+        let on_remove = {
+            let emit_change = emit_change.clone();
+            let editing = editing.clone();
+            let query = props.query.clone();
+            Callback::from(move |e: MouseEvent| {
+                e.prevent_default();
+                let mut query = query.clone();
+                query.tokens.remove(index);
+                editing.set(editing_after_remove(*editing, index));
+                emit_change.emit(query);
+            })
+        };
+
+        let on_submit = {
+            let emit_change = emit_change.clone();
+            let editing = editing.clone();
+            let query = props.query.clone();
+            Callback::from(move |token: FilterToken| {
+                let mut query = query.clone();
+                query.tokens[index] = token;
+                emit_change.emit(query);
+                editing.set(None);
+            })
+        };
+
+        let editor = html! {
+            <TokenEditor
+                token={token.clone()}
+                properties={props.filtering_properties.clone()}
+                options={props.filtering_options.clone()}
+                strings={strings.clone()}
+                free_text={free_text}
+                on_submit={on_submit}
+                on_cancel={close_editor.clone()}
+            />
+        };
+
+        html! {
+            <li key={index} class="awsui-property-filter-token-item">
+                if index > 0 {
+                    <div class="awsui-property-filter-token-operation">
+                        <Select
+                            selected_option={selected_operation.clone()}
+                            options={operation_options.clone()}
+                            disabled={props.disabled}
+                            aria_label={strings.operation_aria_label.clone()}
+                            on_change={on_operation_change.clone()}
+                        />
+                    </div>
+                }
+                <div class="awsui-property-filter-token">
+                    <Popover
+                        visible={Some(is_editing)}
+                        size={PopoverSize::Large}
+                        position={PopoverPosition::Bottom}
+                        dismissible={true}
+                        trigger_type="custom"
+                        header={Html::from(strings.edit_token_header.clone())}
+                        content={is_editing.then_some(editor)}
+                        on_dismiss={close_editor.reform(|_: CustomEvent<PopoverDismissDetail>| ())}
+                    >
+                        <button
+                            type="button"
+                            class="awsui-property-filter-token-label"
+                            aria-haspopup="dialog"
+                            aria-expanded={is_editing.to_string()}
+                            disabled={props.disabled}
+                            onclick={on_edit}
+                        >
+                            { label.clone() }
+                        </button>
+                    </Popover>
+                    <button
+                        type="button"
+                        class="awsui-property-filter-token-dismiss"
+                        aria-label={format!("{} {}", strings.remove_token_aria_label, label)}
+                        disabled={props.disabled}
+                        onclick={on_remove}
+                    >
+                        <Icon name="close" />
+                    </button>
+                </div>
+            </li>
+        }
+    });
+
+    let root = html! {
+        <div id={props.base.id.clone()} class={classes.build()}>
+            <div class="awsui-property-filter-input">
+                <Autosuggest
+                    value={(*filtering_text).clone()}
+                    options={suggestions(
+                        &filtering_text,
+                        &props.filtering_properties,
+                        &props.filtering_options,
+                        &strings,
+                    )}
+                    filtering_type={FilteringType::None}
+                    placeholder={strings.filtering_placeholder.clone()}
+                    disabled={props.disabled}
+                    aria={AriaAttributes {
+                        label: Some(strings.filtering_aria_label.clone()),
+                        ..Default::default()
+                    }}
+                    loading_status={props.loading_status}
+                    on_load_items={on_load_items}
+                    on_change={on_text_change}
+                    on_select={on_text_select}
+                />
+            </div>
+            if !props.query.tokens.is_empty() {
+                <div class="awsui-property-filter-tokens">
+                    <ul class="awsui-property-filter-token-list">
+                        { for tokens }
+                    </ul>
+                    <Button
+                        variant={ButtonVariant::Link}
+                        form_action={FormAction::None}
+                        disabled={props.disabled}
+                        on_click={on_clear}
+                    >
+                        { Html::from(strings.clear_filters_text.clone()) }
+                    </Button>
+                </div>
+            }
+        </div>
+    };
+    apply_root_attributes(root, &props.base)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn properties() -> Vec<FilteringProperty> {
+        vec![
+            FilteringProperty::new("instance", "Instance"),
+            FilteringProperty::new("instance_type", "Instance type")
+                .with_operators(["=", "!="])
+                .with_group("Compute"),
+            FilteringProperty::new("cpu", "CPU").with_operators([">", ">=", "<", "<="]),
+        ]
+    }
+
+    fn options() -> Vec<FilteringOption> {
+        vec![
+            FilteringOption::new("instance_type", "t3.micro").with_label("T3 micro"),
+            FilteringOption::new("instance_type", "m5.large"),
+        ]
+    }
+
+    fn strings() -> ResolvedStrings {
+        resolve_strings(
+            &PropertyFilterI18nStrings::default(),
+            &I18nContext::default(),
+        )
+    }
+
+    fn values(suggestions: Vec<AutosuggestOption>) -> Vec<String> {
+        suggestions.into_iter().map(|option| option.value).collect()
+    }
+
+    #[test]
+    fn test_parse_text() {
+        let properties = properties();
+        // The longest matching label wins, ignoring case
+        match parse_text("instance TYPE != m5", &properties) {
+            ParsedText::Property {
+                property,
+                operator,
+                value,
+            } => {
+                assert_eq!(property.key, "instance_type");
+                assert_eq!(operator, "!=");
+                assert_eq!(value, "m5");
+            }
+            parsed => panic!("unexpected {:?}", parsed),
+        }
+        // The longest matching operator wins
+        assert!(matches!(
+            parse_text("CPU>=4", &properties),
+            ParsedText::Property {
+                operator: ">=",
+                value: "4",
+                ..
+            }
+        ));
+        assert!(matches!(
+            parse_text("CPU ", &properties),
+            ParsedText::Operator { prefix: "", .. }
+        ));
+        assert!(matches!(
+            parse_text("CPUs", &properties),
+            ParsedText::FreeText {
+                operator: ":",
+                value: "CPUs"
+            }
+        ));
+        assert!(matches!(
+            parse_text("!: prod", &properties),
+            ParsedText::FreeText {
+                operator: "!:",
+                value: "prod"
+            }
+        ));
+    }
+
+    #[test]
+    fn test_text_token() {
+        let properties = properties();
+        assert_eq!(
+            text_token("Instance type = t3.micro ", &properties, true),
+            Some(FilterToken::new("instance_type", "=", "t3.micro"))
+        );
+        // A property and operator without a value are incomplete
+        assert_eq!(text_token("Instance type = ", &properties, true), None);
+        assert_eq!(
+            text_token("CPU", &properties, true),
+            Some(FilterToken::free_text("CPU"))
+        );
+        assert_eq!(
+            text_token("!: prod", &properties, true),
+            Some(FilterToken::free_text("prod").with_operator("!:"))
+        );
+        assert_eq!(text_token("prod", &properties, false), None);
+        assert_eq!(text_token("  ", &properties, true), None);
+    }
+
+    #[test]
+    fn test_suggestions_follow_typing() {
+        let properties = properties();
+        let options = options();
+        let strings = strings();
+
+        assert_eq!(
+            values(suggestions("micro", &properties, &options, &strings)),
+            vec!["Instance type = t3.micro"]
+        );
+        assert_eq!(
+            values(suggestions("inst", &properties, &options, &strings)),
+            vec!["Instance ", "Instance type "]
+        );
+
+        let operators = suggestions("CPU >", &properties, &options, &strings);
+        assert_eq!(values(operators.clone()), vec!["CPU > ", "CPU >= "]);
+        assert_eq!(
+            operators[1].description.as_deref(),
+            Some("Greater than or equal")
+        );
+
+        let values_of_type = suggestions("Instance type = ", &properties, &options, &strings);
+        assert_eq!(
+            values(values_of_type.clone()),
+            vec!["Instance type = t3.micro", "Instance type = m5.large"]
+        );
+        assert_eq!(values_of_type[0].label.as_deref(), Some("T3 micro"));
+    }
+
+    #[test]
+    fn test_load_items_detail() {
+        let properties = properties();
+        let detail = |text: &str| {
+            load_items_detail(
+                &LoadItemsDetail {
+                    filtering_text: text.to_string(),
+                    first_page: true,
+                    same_page: false,
+                },
+                &properties,
+            )
+        };
+
+        let typed_value = detail("Instance type = t3");
+        assert_eq!(
+            typed_value.filtering_property.as_deref(),
+            Some("instance_type")
+        );
+        assert_eq!(typed_value.filtering_operator.as_deref(), Some("="));
+        assert_eq!(typed_value.filtering_text, "t3");
+        assert!(typed_value.first_page);
+
+        let free_text = detail("prod");
+        assert_eq!(free_text.filtering_property, None);
+        assert_eq!(free_text.filtering_text, "prod");
+    }
+
+    #[test]
+    fn test_token_label() {
+        let properties = properties();
+        let options = options();
+        assert_eq!(
+            token_label(
+                &FilterToken::new("instance_type", "=", "t3.micro"),
+                &properties,
+                &options
+            ),
+            "Instance type = T3 micro"
+        );
+        assert_eq!(
+            token_label(&FilterToken::new("zone", "=", "a"), &properties, &options),
+            "zone = a"
+        );
+        assert_eq!(
+            token_label(&FilterToken::free_text("prod"), &properties, &options),
+            "prod"
+        );
+        assert_eq!(
+            token_label(
+                &FilterToken::free_text("prod").with_operator("!:"),
+                &properties,
+                &options
+            ),
+            "!: prod"
+        );
+    }
+
+    #[test]
+    fn test_with_token_property() {
+        let properties = properties();
+        let token = FilterToken::new("instance", "!=", "web");
+
+        // The operator is kept when the new property supports it
+        let moved = with_token_property(&token, &properties, Some("instance_type".to_string()));
+        assert_eq!(moved.operator, "!=");
+        assert_eq!(moved.value, "web");
+
+        let moved = with_token_property(&token, &properties, Some("cpu".to_string()));
+        assert_eq!(moved.operator, ">");
+
+        let moved = with_token_property(&token, &properties, None);
+        assert!(moved.is_free_text());
+        assert_eq!(moved.operator, ":");
+    }
+
+    #[test]
+    fn test_editing_after_remove() {
+        // Removing an earlier token moves the edit down with its token
+        assert_eq!(editing_after_remove(Some(2), 0), Some(1));
+        assert_eq!(editing_after_remove(Some(2), 2), None);
+        assert_eq!(editing_after_remove(Some(1), 2), Some(1));
+        assert_eq!(editing_after_remove(None, 0), None);
+    }
+
+    #[test]
+    fn test_query_builder() {
+        let query = PropertyFilterQuery::new()
+            .with_tokens(vec![FilterToken::free_text("prod")])
+            .with_operation(AndOr::Or);
+        assert_eq!(query.tokens.len(), 1);
+        assert_eq!(query.operation.as_str(), "or");
+        assert_eq!(AndOr::default(), AndOr::And);
+    }
+}
@@ -6,10 +6,15 @@
 //! Provides an accessible radio button group with support for disabled items,
 //! descriptions, and form integration.
 
+use crate::form_field::{use_field_association, use_native_validity, use_validation_state};
+use crate::form_state::use_field_tracking;
+use crate::internal::virtual_list::next_enabled_option;
 use crate::internal::{
     AriaAttributes, BaseComponentProps, ClassBuilder, ComponentMetadata, CustomEvent,
+    apply_root_attributes,
 };
-use web_sys::HtmlInputElement;
+use wasm_bindgen::JsCast;
+use web_sys::{FocusEvent, HtmlElement, HtmlInputElement, Node};
 use yew::prelude::*;
 
 /// Direction for radio button layout
@@ -68,6 +73,42 @@ impl RadioGroupItem {
     }
 }
 
+/// Color of radio controls in read-only groups
+pub(crate) const READ_ONLY_CONTROL_STYLE: &str =
+    "color: var(--awsui-color-foreground-control-read-only)";
+
+/// Index of the item in the tab order of a radio-style group: the checked
+/// item, or the first enabled item when no enabled item is checked
+pub(crate) fn radio_tab_stop(checked: Option<usize>, disabled: &[bool]) -> Option<usize> {
+    checked
+        .filter(|&index| disabled.get(index) == Some(&false))
+        .or_else(|| disabled.iter().position(|disabled| !disabled))
+}
+
+/// Item an arrow key moves to from `current` in a radio-style group
+///
+/// ArrowDown and ArrowRight move forward, ArrowUp and ArrowLeft move back,
+/// skipping disabled items and wrapping around. Other keys move nowhere.
+pub(crate) fn radio_arrow_target(current: usize, key: &str, disabled: &[bool]) -> Option<usize> {
+    let key = match key {
+        "ArrowDown" | "ArrowRight" => "ArrowDown",
+        "ArrowUp" | "ArrowLeft" => "ArrowUp",
+        _ => return None,
+    };
+    next_enabled_option(current, key, disabled.len(), |index| disabled[index])
+}
+
+/// Moves focus to the element with the given id
+pub(crate) fn focus_by_id(id: &str) {
+    if let Some(element) = web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.get_element_by_id(id))
+        .and_then(|element| element.dyn_into::<HtmlElement>().ok())
+    {
+        let _ = element.focus();
+    }
+}
+
 /// Event detail for radio group change events
 #[derive(Clone, PartialEq)]
 pub struct RadioGroupChangeDetail {
@@ -97,7 +138,18 @@ pub struct RadioGroupProps {
     #[prop_or_default]
     pub name: Option<String>,
 
+    /// Whether a selection is required before the form can be submitted
+    #[prop_or_default]
+    pub required: bool,
+
+    /// ID of the form the control belongs to, for controls rendered outside it
+    #[prop_or_default]
+    pub form: Option<String>,
+
     /// Whether the entire group is read-only
+    ///
+    /// Read-only radio buttons stay focusable and readable, but the
+    /// selection can't be changed.
     #[prop_or_default]
     pub read_only: bool,
 
@@ -120,10 +172,17 @@ pub struct RadioGroupProps {
 
 /// RadioGroup component for selecting one option from a list.
 ///
+/// # Keyboard
+///
+/// Only the checked radio button, or the first enabled one when none is
+/// checked, is in the tab order. Arrow keys select the next or previous
+/// enabled radio button, wrapping around; in a read-only group they only
+/// move focus.
+///
 /// # Example
 ///
 /// ```rust
-/// use cloudscape_components::{RadioGroup, RadioGroupItem, RadioGroupChangeDetail};
+/// use cloudscape_components::{CustomEvent, RadioGroup, RadioGroupItem, RadioGroupChangeDetail};
 /// use yew::prelude::*;
 ///
 /// let items = vec![
@@ -135,9 +194,10 @@ pub struct RadioGroupProps {
 /// ];
 ///
 /// let on_change = Callback::from(|event: CustomEvent<RadioGroupChangeDetail>| {
-///     console::log!("Selected: {}", event.detail.value);
+///     let _selected = event.detail.value;
 /// });
 ///
+/// # let _: Html =
 /// html! {
 ///     <RadioGroup
 ///         value={Some("option1".to_string())}
@@ -145,18 +205,102 @@ pub struct RadioGroupProps {
 ///         on_change={on_change}
 ///     />
 /// }
+/// # ;
 /// ```
 #[function_component(RadioGroup)]
 pub fn radio_group(props: &RadioGroupProps) -> Html {
     let _metadata = ComponentMetadata::new("RadioGroup");
 
-    // Generate a unique name if not provided
-    let group_name = props.name.clone().unwrap_or_else(|| {
+    // Generate a unique name if not provided; kept stable across renders so
+    // native grouping and form submission keep working
+    let generated_name = use_state(|| {
         use std::sync::atomic::{AtomicUsize, Ordering};
         static COUNTER: AtomicUsize = AtomicUsize::new(0);
         let id = COUNTER.fetch_add(1, Ordering::Relaxed);
         format!("awsui-radio-group-{}", id)
     });
+    let group_name = props
+        .name
+        .clone()
+        .unwrap_or_else(|| (*generated_name).clone());
+
+    let on_invalid = use_native_validity(!props.required || props.value.is_some());
+    let validation = use_validation_state(false, false);
+
+    // ARIA references, merged with the enclosing FormField; the field's
+    // label names the group, as a `for` attribute cannot point at it
+    let association = use_field_association(
+        None,
+        props.aria.labelledby.clone(),
+        props.aria.describedby.clone(),
+    );
+
+    // Report the value to the closest FormStateProvider, and blur once focus
+    // leaves the group rather than each radio button
+    let on_field_blur = use_field_tracking(props.value.clone().unwrap_or_default());
+    let on_focus_out = Callback::from(move |e: FocusEvent| {
+        let group = e
+            .current_target()
+            .and_then(|target| target.dyn_into::<Node>().ok());
+        let next = e
+            .related_target()
+            .and_then(|target| target.dyn_into::<Node>().ok());
+        if let Some(group) = group
+            && !group.contains(next.as_ref())
+        {
+            on_field_blur.emit(());
+        }
+    });
+
+    // Only one radio button is in the tab order; arrow keys move the
+    // selection between the enabled ones
+    let control_ids: Vec<String> = props
+        .items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| {
+            item.control_id
+                .clone()
+                .unwrap_or_else(|| format!("{}-{}", group_name, index))
+        })
+        .collect();
+    let disabled: Vec<bool> = props.items.iter().map(|item| item.disabled).collect();
+    let checked_index = props
+        .items
+        .iter()
+        .position(|item| props.value.as_ref() == Some(&item.value));
+    let tab_stop = radio_tab_stop(checked_index, &disabled);
+
+    let on_key_down = {
+        let control_ids = control_ids.clone();
+        let values: Vec<String> = props.items.iter().map(|item| item.value.clone()).collect();
+        let on_change = props.on_change.clone();
+        let read_only = props.read_only;
+        Callback::from(move |e: KeyboardEvent| {
+            // Space would check a focused radio button of a read-only group
+            if read_only && e.key() == " " {
+                e.prevent_default();
+                return;
+            }
+            let Some(current) = e
+                .target_dyn_into::<HtmlInputElement>()
+                .and_then(|input| control_ids.iter().position(|id| *id == input.id()))
+            else {
+                return;
+            };
+            let Some(target) = radio_arrow_target(current, &e.key(), &disabled) else {
+                return;
+            };
+            // The browser would check the next radio button itself
+            e.prevent_default();
+            focus_by_id(&control_ids[target]);
+            if !read_only && let Some(callback) = &on_change {
+                callback.emit(CustomEvent::new_non_cancelable(RadioGroupChangeDetail {
+                    value: values[target].clone(),
+                }));
+            }
+        })
+    };
 
     // Build root CSS classes
     let root_classes = ClassBuilder::new()
@@ -167,22 +311,22 @@ pub fn radio_group(props: &RadioGroupProps) -> Html {
             "awsui-radio-group-horizontal",
         );
 
-    // Determine ARIA labelledby (form field context would set this in React)
-    let aria_labelledby = props.aria.labelledby.clone();
     let aria_label = props.aria.label.clone();
-    let aria_describedby = props.aria.describedby.clone();
 
-    html! {
+    let root = html! {
         <div
             id={props.base.id.clone()}
             class={root_classes.build()}
             role="radiogroup"
-            aria-labelledby={aria_labelledby}
+            aria-labelledby={association.labelledby}
             aria-label={aria_label}
-            aria-describedby={aria_describedby}
+            aria-describedby={association.describedby}
+            aria-invalid={validation.is_invalid().then_some("true")}
             aria-required={props.aria_required.then_some("true")}
             aria-readonly={props.read_only.then_some("true")}
             aria-controls={props.aria_controls.clone()}
+            onfocusout={on_focus_out}
+            onkeydown={on_key_down}
         >
             {
                 props.items.iter().enumerate().map(|(index, item)| {
@@ -214,11 +358,6 @@ pub fn radio_group(props: &RadioGroupProps) -> Html {
                             }
                     });
 
-                    // Generate control ID
-                    let control_id = item.control_id.clone().unwrap_or_else(|| {
-                        format!("{}-{}", group_name, index)
-                    });
-
                     html! {
                         <RadioButton
                             key={item.value.clone()}
@@ -228,7 +367,11 @@ pub fn radio_group(props: &RadioGroupProps) -> Html {
                             checked={is_checked}
                             disabled={item.disabled}
                             read_only={props.read_only}
-                            control_id={control_id}
+                            required={props.required}
+                            form={props.form.clone()}
+                            on_invalid={on_invalid.clone()}
+                            control_id={control_ids[index].clone()}
+                            tab_stop={tab_stop == Some(index)}
                             label={item.label.clone()}
                             description={item.description.clone()}
                             on_select={on_select}
@@ -237,7 +380,8 @@ pub fn radio_group(props: &RadioGroupProps) -> Html {
                 }).collect::<Html>()
             }
         </div>
-    }
+    };
+    apply_root_attributes(root, &props.base)
 }
 
 /// Internal RadioButton component (mimics React's RadioButton)
@@ -248,7 +392,11 @@ struct RadioButtonProps {
     pub checked: bool,
     pub disabled: bool,
     pub read_only: bool,
+    pub required: bool,
+    pub form: Option<String>,
+    pub on_invalid: Callback<Event>,
     pub control_id: String,
+    pub tab_stop: bool,
     pub label: Html,
     pub description: Option<Html>,
     pub on_select: Callback<Event>,
@@ -321,13 +469,20 @@ fn radio_button(props: &RadioButtonProps) -> Html {
                         id={props.control_id.clone()}
                         name={props.name.clone()}
                         value={props.value.clone()}
+                        form={props.form.clone()}
+                        required={props.required}
                         checked={props.checked}
                         disabled={props.disabled}
                         aria-disabled={props.read_only.then_some("true")}
+                        tabindex={if props.tab_stop { "0" } else { "-1" }}
                         onchange={on_change}
+                        oninvalid={props.on_invalid.clone()}
                         class="awsui-radio-native-input"
                     />
-                    <span class={control_classes.build()}>
+                    <span
+                        class={control_classes.build()}
+                        style={props.read_only.then_some(READ_ONLY_CONTROL_STYLE)}
+                    >
                         // SVG styled control
                         <svg viewBox="0 0 100 100" focusable="false" aria-hidden="true" class="awsui-radio-svg">
                             // Outer circle (border)
@@ -429,4 +584,26 @@ mod tests {
         assert!(item.description.is_some());
         assert_eq!(item.control_id, Some("id".to_string()));
     }
+
+    #[test]
+    fn test_radio_tab_stop() {
+        let disabled = [true, false, false];
+        // The checked item is the tab stop
+        assert_eq!(radio_tab_stop(Some(2), &disabled), Some(2));
+        // Without a checked item, or with a disabled one, the first enabled
+        assert_eq!(radio_tab_stop(None, &disabled), Some(1));
+        assert_eq!(radio_tab_stop(Some(0), &disabled), Some(1));
+        assert_eq!(radio_tab_stop(None, &[true, true]), None);
+    }
+
+    #[test]
+    fn test_radio_arrow_target_skips_disabled_and_wraps() {
+        let disabled = [false, true, false, false];
+        assert_eq!(radio_arrow_target(0, "ArrowDown", &disabled), Some(2));
+        assert_eq!(radio_arrow_target(0, "ArrowRight", &disabled), Some(2));
+        assert_eq!(radio_arrow_target(3, "ArrowDown", &disabled), Some(0));
+        assert_eq!(radio_arrow_target(0, "ArrowUp", &disabled), Some(3));
+        assert_eq!(radio_arrow_target(2, "ArrowLeft", &disabled), Some(0));
+        assert_eq!(radio_arrow_target(0, "Home", &disabled), None);
+    }
 }
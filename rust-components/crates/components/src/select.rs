@@ -7,9 +7,29 @@
 //! from a list of choices. Supports disabled states, validation, descriptions,
 //! and label tags for options.
 
+use crate::form_field::{use_field_association, use_validation_state};
+use crate::form_state::use_field_tracking;
+use crate::i18n::use_i18n;
+use crate::internal::accessibility::AriaHasPopup;
+use crate::internal::busy::use_register_busy;
+use crate::internal::dev_warnings::{DevWarning, duplicates, use_dev_warnings};
+use crate::internal::dropdown::{
+    dropdown_style, use_dropdown_position, use_dropdown_transition, use_outside_dismiss,
+};
+use crate::internal::option_group::{GroupedOptions, OptionGroup, option_id, render_group_header};
+use crate::internal::option_list::{
+    DropdownStatus, ListState, ListStateSlots, ListStateStrings, LoadItemsDetail,
+    list_announcement, loaded_list_state, option_announcement, render_list_state,
+    render_load_status, use_load_items,
+};
+use crate::internal::virtual_list::{
+    TypeAhead, VirtualScrollConfig, next_enabled_option, type_ahead_match, use_virtual_list,
+};
 use crate::internal::{
-    AriaAttributes, BaseComponentProps, ClassBuilder, ComponentMetadata, CustomEvent,
+    AriaAttributes, BaseComponentProps, ClassBuilder, ComponentMetadata, CustomEvent, LiveRegion,
+    apply_root_attributes,
 };
+use wasm_bindgen::JsCast;
 use web_sys::MouseEvent;
 use yew::prelude::*;
 
@@ -81,12 +101,143 @@ impl SelectOption {
 /// Event detail for select change events
 #[derive(Clone, PartialEq)]
 pub struct SelectChangeDetail {
-    /// The selected option
-    pub selected_option: SelectOption,
+    /// The selected option, or `None` when the selection was cleared
+    pub selected_option: Option<SelectOption>,
+}
+
+/// Whether the clear affordance of a select or multiselect trigger is shown
+pub(crate) fn clear_button_visible(
+    show_clear_button: bool,
+    has_value: bool,
+    disabled: bool,
+    read_only: bool,
+) -> bool {
+    show_clear_button && has_value && !disabled && !read_only
+}
+
+/// Whether a key pressed on the focused, closed trigger clears the selection,
+/// following combobox conventions
+pub(crate) fn is_clear_key(key: &str) -> bool {
+    matches!(key, "Delete" | "Backspace")
+}
+
+/// Visual variant of the select and multiselect trigger
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum TriggerVariant {
+    /// Full-width bordered trigger
+    #[default]
+    Default,
+    /// Borderless trigger sized to its content, styled with the link-button tokens
+    Inline,
+    /// Trigger rendered by the `render_trigger` callback
+    ///
+    /// Falls back to the default trigger when no `render_trigger` is provided.
+    Custom,
+}
+
+impl TriggerVariant {
+    /// Returns the CSS class suffix for this variant
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TriggerVariant::Default => "default",
+            TriggerVariant::Inline => "inline",
+            TriggerVariant::Custom => "custom",
+        }
+    }
+}
+
+/// Inline style of an `Inline` trigger: borderless, sized to its content and
+/// colored with the link-button tokens
+///
+/// Inline styles cannot match `:focus`, so the focus ring is drawn while
+/// `focused` is set.
+pub(crate) fn inline_trigger_style(focused: bool, invalid: bool, disabled: bool) -> String {
+    let color = if disabled {
+        "var(--awsui-color-text-interactive-disabled)"
+    } else if invalid {
+        "var(--awsui-color-text-status-error)"
+    } else {
+        "var(--awsui-color-text-link-button-normal-default)"
+    };
+    let mut style = format!(
+        "display: inline-flex; width: auto; padding: 0; border: none; background: none; \
+         color: {}; font-weight: var(--awsui-font-link-button-weight); \
+         letter-spacing: var(--awsui-font-link-button-letter-spacing)",
+        color
+    );
+    if invalid {
+        style.push_str("; text-decoration: underline var(--awsui-color-border-status-error)");
+    }
+    if focused {
+        style.push_str(
+            "; outline: none; border-radius: var(--awsui-border-radius-control-default-focus-ring); \
+             box-shadow: 0 0 0 var(--awsui-border-link-focus-ring-shadow-spread) \
+             var(--awsui-color-border-item-focused)",
+        );
+    }
+    style
+}
+
+/// State passed to a custom trigger renderer
+///
+/// The component keeps ownership of the dropdown, keyboard handling and
+/// focus management; the renderer only draws the trigger. The `aria`
+/// attributes and `control_id` must be applied to the focusable trigger
+/// element so screen readers and form fields keep working.
+#[derive(Clone, PartialEq, Debug)]
+pub struct TriggerRenderContext<T> {
+    /// The currently selected options (at most one for `Select`)
+    pub selected_options: Vec<T>,
+    /// Whether the dropdown is open
+    pub open: bool,
+    /// Whether the component is disabled
+    pub disabled: bool,
+    /// Whether the component is marked invalid
+    pub invalid: bool,
+    /// Whether the component shows a warning (never set together with
+    /// `invalid`)
+    pub warning: bool,
+    /// ID to place on the trigger element for form field integration
+    pub control_id: Option<String>,
+    /// Computed ARIA attributes for the trigger element
+    pub aria: AriaAttributes,
+    /// Opens or closes the dropdown; ignored while disabled or read-only
+    pub toggle: Callback<()>,
+}
+
+/// Computes the ARIA attributes shared by every trigger variant
+///
+/// The trigger follows the WAI-ARIA 1.2 combobox pattern: while open, it
+/// controls the listbox with id `list_id` and points `aria-activedescendant`
+/// at the highlighted option, as focus stays on the trigger.
+pub(crate) fn trigger_aria_attributes(
+    aria_label: Option<String>,
+    aria: &AriaAttributes,
+    required: bool,
+    invalid: bool,
+    open: bool,
+    list_id: &str,
+    highlighted: Option<usize>,
+) -> AriaAttributes {
+    AriaAttributes {
+        label: aria_label,
+        labelledby: aria.labelledby.clone(),
+        describedby: aria.describedby.clone(),
+        controls: open.then(|| list_id.to_string()),
+        activedescendant: highlighted
+            .filter(|_| open)
+            .map(|index| option_id(list_id, index)),
+        expanded: Some(open),
+        haspopup: Some(AriaHasPopup::Listbox),
+        required: Some(required),
+        invalid: invalid.then_some(true),
+        ..Default::default()
+    }
 }
 
 /// Properties for the Select component
 #[derive(Properties, PartialEq, Clone)]
+#[allow(unpredictable_function_pointer_comparisons)]
 pub struct SelectProps {
     /// Base component properties
     #[prop_or_default]
@@ -102,6 +253,12 @@ pub struct SelectProps {
     #[prop_or_default]
     pub options: Vec<SelectOption>,
 
+    /// Groups of options listed after `options`, each under a header
+    ///
+    /// Options of a disabled group can't be selected.
+    #[prop_or_default]
+    pub option_groups: Vec<OptionGroup<SelectOption>>,
+
     /// Placeholder text shown when no option is selected
     #[prop_or_default]
     pub placeholder: Option<String>,
@@ -114,16 +271,35 @@ pub struct SelectProps {
 
     /// Whether the select has invalid content
     ///
-    /// Used to indicate validation errors.
+    /// Used to indicate validation errors. Takes precedence over `warning`.
     #[prop_or_default]
     pub invalid: bool,
 
+    /// Whether the select shows a warning
+    #[prop_or_default]
+    pub warning: bool,
+
     /// Whether the select is read-only
     ///
     /// A read-only select can be focused but cannot be modified.
     #[prop_or_default]
     pub read_only: bool,
 
+    /// Visual variant of the trigger
+    #[prop_or_default]
+    pub trigger_variant: TriggerVariant,
+
+    /// Renders the trigger when `trigger_variant` is `Custom`
+    #[prop_or_default]
+    pub render_trigger: Option<fn(TriggerRenderContext<SelectOption>) -> Html>,
+
+    /// Content shown in the dropdown when there are no options
+    ///
+    /// Defaults to "No options", localized with the `select.empty_text`
+    /// I18nProvider message.
+    #[prop_or_default]
+    pub empty_content: Option<Html>,
+
     /// Callback fired when the selected option changes
     ///
     /// The event detail contains the newly selected option.
@@ -165,6 +341,94 @@ pub struct SelectProps {
     /// Automatically focus the select when component is mounted
     #[prop_or_default]
     pub auto_focus: bool,
+
+    /// Whether to show a button in the trigger that clears the selection
+    ///
+    /// Shown while an option is selected and the select is neither disabled
+    /// nor read-only. Pressing Delete or Backspace on the closed trigger also
+    /// clears the selection. Clearing emits `on_change` with no selected
+    /// option and never opens the dropdown.
+    #[prop_or_default]
+    pub show_clear_button: bool,
+
+    /// ARIA label of the clear button
+    ///
+    /// Default: "Clear selection"
+    #[prop_or_default]
+    pub clear_aria_label: Option<String>,
+
+    /// Renders only the options scrolled into view
+    ///
+    /// Set it for long option lists. Options are assumed to share the
+    /// configured row height.
+    #[prop_or_default]
+    pub virtual_scroll: Option<VirtualScrollConfig>,
+
+    /// Loading state of options fetched with `on_load_items`
+    ///
+    /// Shown in a footer below the options: "Loading" (`select.loading_text`),
+    /// an error (`select.error_text`) with a retry action
+    /// (`select.recovery_text`), or the end of results
+    /// (`select.finished_text`).
+    #[prop_or_default]
+    pub loading_status: Option<DropdownStatus>,
+
+    /// Callback fired to load options: when the dropdown opens, and when the
+    /// list is scrolled near its end while `loading_status` is pending
+    #[prop_or_default]
+    pub on_load_items: Option<Callback<CustomEvent<LoadItemsDetail>>>,
+}
+
+/// Renders the clear affordance placed after a select or multiselect trigger
+///
+/// The button is a sibling of the trigger, so it is reached with Tab right
+/// after it. Its key presses don't reach the trigger's keyboard handling, so
+/// activating it never opens the dropdown.
+pub(crate) fn render_clear_button(
+    class: &'static str,
+    aria_label: Option<String>,
+    onclick: Callback<MouseEvent>,
+) -> Html {
+    let onclick = Callback::from(move |e: MouseEvent| {
+        e.prevent_default();
+        e.stop_propagation();
+        onclick.emit(e);
+    });
+
+    html! {
+        <button
+            type="button"
+            class={class}
+            aria-label={aria_label.unwrap_or_else(|| "Clear selection".to_string())}
+            onclick={onclick}
+            onkeydown={Callback::from(|e: KeyboardEvent| e.stop_propagation())}
+        >
+            <span class="awsui-icon awsui-icon-close" aria-hidden="true" />
+        </button>
+    }
+}
+
+/// Misuse of Select props reported in development builds
+fn dev_warnings(props: &SelectProps) -> Vec<DevWarning> {
+    let repeated = duplicates(
+        props
+            .options
+            .iter()
+            .chain(props.option_groups.iter().flat_map(|group| &group.options))
+            .map(|option| option.value.as_str()),
+    );
+    if repeated.is_empty() {
+        return Vec::new();
+    }
+
+    vec![DevWarning::new(
+        "select-duplicate-option-value",
+        format!(
+            "Option values must be unique; found duplicates: {}. Options with the same \
+             value are all shown as selected.",
+            repeated.join(", ")
+        ),
+    )]
 }
 
 /// Select component for choosing one option from a dropdown list.
@@ -198,7 +462,7 @@ pub struct SelectProps {
 ///     let on_change = {
 ///         let selected = selected.clone();
 ///         Callback::from(move |event: CustomEvent<SelectChangeDetail>| {
-///             selected.set(Some(event.detail.selected_option.clone()));
+///             selected.set(event.detail.selected_option.clone());
 ///         })
 ///     };
 ///
@@ -215,24 +479,99 @@ pub struct SelectProps {
 #[function_component(Select)]
 pub fn select(props: &SelectProps) -> Html {
     let _metadata = ComponentMetadata::new("Select");
+    use_register_busy(props.loading_status == Some(DropdownStatus::Loading));
+    use_dev_warnings("Select").check(|| dev_warnings(props));
     let select_ref = use_node_ref();
+    let trigger_ref = use_node_ref();
+    let i18n = use_i18n();
+    let state_strings = ListStateStrings::resolve(&i18n, "select");
+    let list_id = use_state(|| {
+        use std::sync::atomic::{AtomicU32, Ordering};
+        static COUNTER: AtomicU32 = AtomicU32::new(0);
+        let id = COUNTER.fetch_add(1, Ordering::Relaxed);
+        format!("awsui-select-{}", id)
+    });
+    let grouped = use_memo(
+        (props.options.clone(), props.option_groups.clone()),
+        |(options, groups)| {
+            GroupedOptions::new(options, groups, |_| true, |option| option.disabled = true)
+        },
+    );
+    let list_state = loaded_list_state(
+        ListState::of(grouped.options.len(), ""),
+        props.loading_status,
+    );
+    let validation = use_validation_state(props.invalid, props.warning);
+
+    // Id and ARIA references, merged with the enclosing FormField
+    let association = use_field_association(
+        props.control_id.clone(),
+        props.aria.labelledby.clone(),
+        props.aria.describedby.clone(),
+    );
+    let field_aria = AriaAttributes {
+        labelledby: association.labelledby,
+        describedby: association.describedby,
+        ..props.aria.clone()
+    };
     let is_open = use_state(|| false);
+    let trigger_focused = use_state(|| false);
+    // Whether the dropdown was opened, so the selection is only announced
+    // after the user interacted with it
+    let opened = use_mut_ref(|| false);
     let highlighted_index = use_state(|| 0usize);
+    let type_ahead = use_mut_ref(TypeAhead::default);
+    let virtual_list = use_virtual_list(
+        props.virtual_scroll,
+        grouped.rows.len(),
+        grouped.row_of(*highlighted_index),
+        *is_open,
+    );
+    let dropdown_ref = use_node_ref();
+    let load_items = use_load_items(
+        *is_open,
+        String::new(),
+        props.loading_status,
+        props.options.len()
+            + props
+                .option_groups
+                .iter()
+                .map(|group| group.options.len())
+                .sum::<usize>(),
+        dropdown_ref.clone(),
+        props.on_load_items.clone(),
+    );
+    let on_list_scroll = {
+        let on_virtual_scroll = virtual_list.on_scroll.clone();
+        let on_load_scroll = load_items.on_scroll.clone();
+        Callback::from(move |e: Event| {
+            on_virtual_scroll.emit(e.clone());
+            on_load_scroll.emit(e);
+        })
+    };
 
     // Handle dropdown toggle
-    let on_trigger_click = {
+    let toggle = {
         let is_open = is_open.clone();
         let disabled = props.disabled;
         let read_only = props.read_only;
 
-        Callback::from(move |e: MouseEvent| {
-            e.prevent_default();
+        Callback::from(move |()| {
             if !disabled && !read_only {
                 is_open.set(!*is_open);
             }
         })
     };
 
+    let on_trigger_click = {
+        let toggle = toggle.clone();
+
+        Callback::from(move |e: MouseEvent| {
+            e.prevent_default();
+            toggle.emit(());
+        })
+    };
+
     // Handle option selection
     let on_option_click = {
         let is_open = is_open.clone();
@@ -245,12 +584,21 @@ pub fn select(props: &SelectProps) -> Html {
                 && let Some(callback) = &on_change
             {
                 callback.emit(CustomEvent::new_non_cancelable(SelectChangeDetail {
-                    selected_option: option,
+                    selected_option: Some(option),
                 }));
             }
         })
     };
 
+    // Report the value and blur to the closest FormStateProvider
+    let on_field_blur = use_field_tracking(
+        props
+            .selected_option
+            .as_ref()
+            .map(|option| option.value.clone())
+            .unwrap_or_default(),
+    );
+
     // Handle blur event
     let on_blur_event = {
         let on_blur = props.on_blur.clone();
@@ -260,6 +608,8 @@ pub fn select(props: &SelectProps) -> Html {
             // Close dropdown on blur
             is_open.set(false);
 
+            on_field_blur.emit(());
+
             if let Some(callback) = &on_blur {
                 callback.emit(());
             }
@@ -277,14 +627,42 @@ pub fn select(props: &SelectProps) -> Html {
         })
     };
 
+    let show_clear = clear_button_visible(
+        props.show_clear_button,
+        props.selected_option.is_some(),
+        props.disabled,
+        props.read_only,
+    );
+
+    // Clear the selection, keeping focus on the trigger as the clear button
+    // disappears
+    let on_clear = {
+        let on_change = props.on_change.clone();
+        let trigger_ref = trigger_ref.clone();
+
+        Callback::from(move |()| {
+            if let Some(callback) = &on_change {
+                callback.emit(CustomEvent::new_non_cancelable(SelectChangeDetail {
+                    selected_option: None,
+                }));
+            }
+            if let Some(trigger) = trigger_ref.cast::<web_sys::HtmlElement>() {
+                let _ = trigger.focus();
+            }
+        })
+    };
+
     // Handle keyboard navigation
     let on_key_down = {
         let is_open = is_open.clone();
         let highlighted_index = highlighted_index.clone();
-        let options = props.options.clone();
+        let grouped = grouped.clone();
         let on_change = props.on_change.clone();
         let disabled = props.disabled;
         let read_only = props.read_only;
+        let on_clear = on_clear.clone();
+        let trigger_ref = trigger_ref.clone();
+        let type_ahead = type_ahead.clone();
 
         Callback::from(move |e: KeyboardEvent| {
             if disabled || read_only {
@@ -293,53 +671,51 @@ pub fn select(props: &SelectProps) -> Html {
 
             let key = e.key();
             match key.as_str() {
-                "ArrowDown" => {
+                key if is_clear_key(key)
+                    && show_clear
+                    && !*is_open
+                    && e.target()
+                        .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+                        == trigger_ref.cast::<web_sys::Element>() =>
+                {
+                    e.prevent_default();
+                    on_clear.emit(());
+                }
+                "ArrowDown" | "ArrowUp" => {
                     e.prevent_default();
                     if !*is_open {
                         is_open.set(true);
-                    } else {
-                        // Move to next non-disabled option
-                        let mut new_index = *highlighted_index;
-                        loop {
-                            new_index = (new_index + 1) % options.len();
-                            if new_index == *highlighted_index || !options[new_index].disabled {
-                                break;
-                            }
-                        }
-                        highlighted_index.set(new_index);
+                    } else if let Some(index) = next_enabled_option(
+                        *highlighted_index,
+                        &key,
+                        grouped.options.len(),
+                        |index| grouped.options[index].disabled,
+                    ) {
+                        highlighted_index.set(index);
                     }
                 }
-                "ArrowUp" => {
+                "Home" | "End" if *is_open => {
                     e.prevent_default();
-                    if !*is_open {
-                        is_open.set(true);
-                    } else {
-                        // Move to previous non-disabled option
-                        let mut new_index = *highlighted_index;
-                        loop {
-                            new_index = if new_index == 0 {
-                                options.len() - 1
-                            } else {
-                                new_index - 1
-                            };
-                            if new_index == *highlighted_index || !options[new_index].disabled {
-                                break;
-                            }
-                        }
-                        highlighted_index.set(new_index);
+                    if let Some(index) = next_enabled_option(
+                        *highlighted_index,
+                        &key,
+                        grouped.options.len(),
+                        |index| grouped.options[index].disabled,
+                    ) {
+                        highlighted_index.set(index);
                     }
                 }
                 "Enter" | " " => {
                     e.prevent_default();
                     if *is_open {
                         // Select highlighted option
-                        if let Some(option) = options.get(*highlighted_index)
+                        if let Some(option) = grouped.options.get(*highlighted_index)
                             && !option.disabled
                         {
                             if let Some(callback) = &on_change {
                                 callback.emit(CustomEvent::new_non_cancelable(
                                     SelectChangeDetail {
-                                        selected_option: option.clone(),
+                                        selected_option: Some(option.clone()),
                                     },
                                 ));
                             }
@@ -353,6 +729,23 @@ pub fn select(props: &SelectProps) -> Html {
                     e.prevent_default();
                     is_open.set(false);
                 }
+                _ if *is_open && TypeAhead::accepts(&e) => {
+                    let query = type_ahead
+                        .borrow_mut()
+                        .push(&key, js_sys::Date::now())
+                        .to_string();
+                    if let Some(index) = type_ahead_match(
+                        &query,
+                        *highlighted_index,
+                        grouped.options.len(),
+                        |index| {
+                            let option = &grouped.options[index];
+                            (!option.disabled).then(|| option.display_text())
+                        },
+                    ) {
+                        highlighted_index.set(index);
+                    }
+                }
                 _ => {}
             }
         })
@@ -363,8 +756,13 @@ pub fn select(props: &SelectProps) -> Html {
         .add("awsui-select-trigger")
         .add_if(props.disabled, "awsui-select-trigger-disabled")
         .add_if(props.read_only, "awsui-select-trigger-readonly")
-        .add_if(props.invalid, "awsui-select-trigger-invalid")
+        .add_if(validation.is_invalid(), "awsui-select-trigger-invalid")
+        .add_if(validation.is_warning(), "awsui-select-trigger-warning")
         .add_if(*is_open, "awsui-select-trigger-open")
+        .add_if(
+            props.trigger_variant == TriggerVariant::Inline,
+            "awsui-select-trigger-inline",
+        )
         .add_if(
             props.selected_option.is_none(),
             "awsui-select-trigger-placeholder",
@@ -386,11 +784,12 @@ pub fn select(props: &SelectProps) -> Html {
     };
 
     // Find index of selected option for initial highlight
-    use_effect_with((props.selected_option.clone(), props.options.clone()), {
+    use_effect_with((props.selected_option.clone(), grouped.clone()), {
         let highlighted_index = highlighted_index.clone();
-        move |(selected, options)| {
+        move |(selected, grouped)| {
             if let Some(selected_opt) = selected
-                && let Some(index) = options
+                && let Some(index) = grouped
+                    .options
                     .iter()
                     .position(|opt| opt.value == selected_opt.value)
             {
@@ -400,131 +799,288 @@ pub fn select(props: &SelectProps) -> Html {
         }
     });
 
-    // Close dropdown when clicking outside
-    use_effect_with(*is_open, {
-        let _is_open = is_open.clone();
-        move |open| {
-            if *open {
-                // Setup click outside handler
-                let window = web_sys::window().expect("window should exist");
-                let _document = window.document().expect("document should exist");
-
-                // Note: In production, you'd want to add a proper event listener
-                // This is a simplified version for demonstration
-            }
-            || ()
-        }
+    // Close on outside interaction and keep the dropdown anchored
+    use_outside_dismiss(*is_open, select_ref.clone(), {
+        let is_open = is_open.clone();
+        Callback::from(move |()| is_open.set(false))
     });
+    let transition = use_dropdown_transition(*is_open, select_ref.clone());
+    let position = use_dropdown_position(
+        *is_open,
+        transition.mounted(),
+        select_ref.clone(),
+        dropdown_ref.clone(),
+    );
 
-    html! {
-        <div
-            ref={select_ref}
-            class={ClassBuilder::new()
-                .add("awsui-select")
-                .add_if(props.disabled, "awsui-select-disabled")
-                .add_if(props.invalid, "awsui-select-invalid")
-                .build()}
-            onblur={on_blur_event}
-            onfocus={on_focus_event}
-            onkeydown={on_key_down}
-        >
-            // Trigger button
+    let highlighted_option = grouped
+        .options
+        .get(*highlighted_index)
+        .filter(|option| !option.disabled);
+    let trigger_aria = trigger_aria_attributes(
+        props.aria_label.clone(),
+        &field_aria,
+        props.aria_required,
+        validation.is_invalid(),
+        *is_open,
+        list_id.as_str(),
+        highlighted_option.map(|_| *highlighted_index),
+    );
+
+    // While open, announce the list state, else the highlighted option and
+    // its position; once closed after use, the selected value
+    if *is_open {
+        *opened.borrow_mut() = true;
+    }
+    let is_selected = |option: &SelectOption| {
+        props
+            .selected_option
+            .as_ref()
+            .is_some_and(|selected| selected.value == option.value)
+    };
+    let announcement = if *is_open {
+        let state = list_announcement(
+            list_state.as_ref(),
+            props.loading_status,
+            !grouped.options.is_empty(),
+            &state_strings,
+        );
+        match highlighted_option {
+            Some(option) if state.is_empty() => option_announcement(
+                option.display_text(),
+                is_selected(option),
+                Some((*highlighted_index + 1, grouped.options.len())),
+                &state_strings,
+            ),
+            _ => state,
+        }
+    } else if *opened.borrow() {
+        props
+            .selected_option
+            .as_ref()
+            .map(|option| option_announcement(option.display_text(), true, None, &state_strings))
+            .unwrap_or_default()
+    } else {
+        String::new()
+    };
+
+    // Inline triggers draw their own focus ring
+    let on_trigger_focus = {
+        let trigger_focused = trigger_focused.clone();
+        Callback::from(move |_: FocusEvent| trigger_focused.set(true))
+    };
+    let on_trigger_blur = {
+        let trigger_focused = trigger_focused.clone();
+        Callback::from(move |_: FocusEvent| trigger_focused.set(false))
+    };
+    let trigger_style = match props.trigger_variant {
+        TriggerVariant::Inline => Some(inline_trigger_style(
+            *trigger_focused,
+            validation.is_invalid(),
+            props.disabled,
+        )),
+        _ => validation.control_style(),
+    };
+
+    let trigger = match (props.trigger_variant, props.render_trigger) {
+        (TriggerVariant::Custom, Some(render_trigger)) => render_trigger(TriggerRenderContext {
+            selected_options: props.selected_option.iter().cloned().collect(),
+            open: *is_open,
+            disabled: props.disabled,
+            invalid: validation.is_invalid(),
+            warning: validation.is_warning(),
+            control_id: association.control_id.clone(),
+            aria: trigger_aria,
+            toggle,
+        }),
+        _ => html! {
             <button
                 type="button"
                 class={trigger_classes.build()}
-                id={props.control_id.clone()}
+                style={trigger_style}
+                id={association.control_id.clone()}
                 disabled={props.disabled}
+                role="combobox"
                 aria-expanded={is_open.to_string()}
                 aria-haspopup="listbox"
-                aria-label={props.aria_label.clone()}
-                aria-labelledby={props.aria.labelledby.clone()}
-                aria-describedby={props.aria.describedby.clone()}
+                aria-controls={trigger_aria.controls}
+                aria-activedescendant={trigger_aria.activedescendant}
+                aria-label={trigger_aria.label}
+                aria-labelledby={trigger_aria.labelledby}
+                aria-describedby={trigger_aria.describedby}
                 aria-required={props.aria_required.to_string()}
-                aria-invalid={props.invalid.to_string()}
+                aria-invalid={trigger_aria.invalid.map(|i| i.to_string())}
                 onclick={on_trigger_click}
+                onfocus={on_trigger_focus}
+                onblur={on_trigger_blur}
                 autofocus={props.auto_focus}
+                ref={trigger_ref}
             >
                 <span class="awsui-select-trigger-content">
                     { trigger_content }
                 </span>
+                { validation.trigger_icon() }
                 <span class="awsui-select-trigger-icon" aria-hidden="true">
                     { if *is_open { "▲" } else { "▼" } }
                 </span>
             </button>
+        },
+    };
+
+    // Render an option row; `index` is its position among all options
+    let option_count = grouped.options.len();
+    let render_option = |index: usize, option: &SelectOption| -> Html {
+        let is_selected = is_selected(option);
+        let is_highlighted = index == *highlighted_index;
+
+        let option_clone = option.clone();
+        let on_click = {
+            let on_option_click = on_option_click.clone();
+            Callback::from(move |e: MouseEvent| {
+                e.prevent_default();
+                on_option_click.emit(option_clone.clone());
+            })
+        };
+
+        let option_classes = ClassBuilder::new()
+            .add("awsui-select-option")
+            .add_if(option.disabled, "awsui-select-option-disabled")
+            .add_if(is_selected, "awsui-select-option-selected")
+            .add_if(is_highlighted, "awsui-select-option-highlighted");
+
+        html! {
+            <li
+                key={option.value.clone()}
+                id={option_id(&list_id, index)}
+                class={option_classes.build()}
+                role="option"
+                aria-selected={is_selected.to_string()}
+                aria-disabled={option.disabled.to_string()}
+                aria-setsize={virtual_list.is_virtual().then(|| option_count.to_string())}
+                aria-posinset={virtual_list.is_virtual().then(|| (index + 1).to_string())}
+                onclick={on_click}
+            >
+                <div class="awsui-select-option-content">
+                    <span class="awsui-select-option-label">
+                        { option.display_text() }
+                        if let Some(ref tag) = option.label_tag {
+                            <span class="awsui-select-option-label-tag">
+                                { tag }
+                            </span>
+                        }
+                    </span>
+                    if let Some(ref desc) = option.description {
+                        <span class="awsui-select-option-description">
+                            { desc }
+                        </span>
+                    }
+                </div>
+                if is_selected {
+                    <span class="awsui-select-option-checkmark" aria-hidden="true">
+                        { "✓" }
+                    </span>
+                }
+            </li>
+        }
+    };
+
+    let root = html! {
+        <div
+            ref={select_ref}
+            class={ClassBuilder::new()
+                .add("awsui-select")
+                .add(format!("awsui-select-trigger-variant-{}", props.trigger_variant.as_str()))
+                .add_if(props.disabled, "awsui-select-disabled")
+                .add_if(validation.is_invalid(), "awsui-select-invalid")
+                .add_if(validation.is_warning(), "awsui-select-warning")
+                .build()}
+            onblur={on_blur_event}
+            onfocus={on_focus_event}
+            onkeydown={on_key_down}
+        >
+            { trigger }
+
+            if show_clear {
+                { render_clear_button(
+                    "awsui-select-clear-button",
+                    props.clear_aria_label.clone(),
+                    on_clear.reform(|_: MouseEvent| ()),
+                ) }
+            }
+
+            <LiveRegion message={announcement} />
 
             // Dropdown menu
-            if *is_open {
+            if transition.mounted() {
                 <div
+                    ref={dropdown_ref}
+                    style={dropdown_style(transition, position)}
                     class={dropdown_classes.build()}
-                    role="listbox"
-                    aria-label={props.aria_label.clone()}
+                    aria-busy={(props.loading_status == Some(DropdownStatus::Loading)).then_some("true")}
+                    onscroll={load_items.on_scroll.clone()}
                 >
-                    <ul class="awsui-select-options-list">
-                        {
-                            props.options.iter().enumerate().map(|(index, option)| {
-                                let is_selected = props.selected_option.as_ref()
-                                    .map(|s| s.value == option.value)
-                                    .unwrap_or(false);
-                                let is_highlighted = index == *highlighted_index;
-
-                                let option_clone = option.clone();
-                                let on_click = {
-                                    let on_option_click = on_option_click.clone();
-                                    Callback::from(move |e: MouseEvent| {
-                                        e.prevent_default();
-                                        on_option_click.emit(option_clone.clone());
-                                    })
-                                };
-
-                                let option_classes = ClassBuilder::new()
-                                    .add("awsui-select-option")
-                                    .add_if(option.disabled, "awsui-select-option-disabled")
-                                    .add_if(is_selected, "awsui-select-option-selected")
-                                    .add_if(is_highlighted, "awsui-select-option-highlighted");
-
-                                html! {
-                                    <li
-                                        key={option.value.clone()}
-                                        class={option_classes.build()}
-                                        role="option"
-                                        aria-selected={is_selected.to_string()}
-                                        aria-disabled={option.disabled.to_string()}
-                                        onclick={on_click}
-                                    >
-                                        <div class="awsui-select-option-content">
-                                            <span class="awsui-select-option-label">
-                                                { option.display_text() }
-                                                if let Some(ref tag) = option.label_tag {
-                                                    <span class="awsui-select-option-label-tag">
-                                                        { tag }
-                                                    </span>
-                                                }
-                                            </span>
-                                            if let Some(ref desc) = option.description {
-                                                <span class="awsui-select-option-description">
-                                                    { desc }
-                                                </span>
-                                            }
-                                        </div>
-                                        if is_selected {
-                                            <span class="awsui-select-option-checkmark" aria-hidden="true">
-                                                { "✓" }
-                                            </span>
-                                        }
-                                    </li>
-                                }
-                            }).collect::<Html>()
-                        }
+                    // The list itself is the listbox, so the status rows stay
+                    // outside of it
+                    <ul
+                        ref={virtual_list.list_ref.clone()}
+                        id={(*list_id).clone()}
+                        class="awsui-select-options-list"
+                        style={virtual_list.style.clone()}
+                        role="listbox"
+                        aria-label={props.aria_label.clone()}
+                        onscroll={on_list_scroll}
+                    >
+                        { virtual_list.top_spacer() }
+                        { grouped.render_rows(
+                            virtual_list.window.start..virtual_list.window.end,
+                            list_id.as_str(),
+                            |row, id| render_group_header(row, id, "awsui-select", None),
+                            render_option,
+                        ) }
+                        { virtual_list.bottom_spacer() }
                     </ul>
+                    if let Some(ref state) = list_state {
+                        { render_list_state(state, &state_strings, ListStateSlots {
+                            no_match_content: None,
+                            empty_content: props.empty_content.clone(),
+                            on_clear_filter: None,
+                        }) }
+                    }
+                    { render_load_status(
+                        props.loading_status,
+                        !grouped.options.is_empty(),
+                        &state_strings,
+                        load_items.on_retry.clone(),
+                    ) }
                 </div>
             }
         </div>
-    }
+    };
+    apply_root_attributes(root, &props.base)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::internal::dev_warnings::DevWarnings;
+
+    #[test]
+    fn test_dev_warning_duplicate_option_values() {
+        let props = yew::props!(SelectProps {
+            options: vec![
+                SelectOption::new("a"),
+                SelectOption::new("b"),
+                SelectOption::new("a"),
+            ],
+        });
+        let warnings = DevWarnings::new("Select");
+        assert_eq!(warnings.check(|| dev_warnings(&props)), 1);
+        assert_eq!(warnings.check(|| dev_warnings(&props)), 0);
+
+        let unique = yew::props!(SelectProps {
+            options: vec![SelectOption::new("a"), SelectOption::new("b")],
+        });
+        assert!(dev_warnings(&unique).is_empty());
+    }
 
     #[test]
     fn test_select_option_new() {
@@ -567,10 +1123,27 @@ mod tests {
     fn test_select_change_detail() {
         let option = SelectOption::new("test");
         let detail = SelectChangeDetail {
-            selected_option: option.clone(),
+            selected_option: Some(option.clone()),
         };
 
-        assert_eq!(detail.selected_option.value, "test");
+        assert_eq!(detail.selected_option.unwrap().value, "test");
+    }
+
+    #[test]
+    fn test_clear_button_visible() {
+        assert!(clear_button_visible(true, true, false, false));
+        assert!(!clear_button_visible(false, true, false, false));
+        assert!(!clear_button_visible(true, false, false, false));
+        assert!(!clear_button_visible(true, true, true, false));
+        assert!(!clear_button_visible(true, true, false, true));
+    }
+
+    #[test]
+    fn test_is_clear_key() {
+        assert!(is_clear_key("Delete"));
+        assert!(is_clear_key("Backspace"));
+        assert!(!is_clear_key("Enter"));
+        assert!(!is_clear_key(" "));
     }
 
     #[test]
@@ -582,4 +1155,104 @@ mod tests {
         assert_eq!(option1, option2);
         assert_ne!(option1, option3);
     }
+
+    #[test]
+    fn test_virtual_scroll_bounds_rendered_options() {
+        let options: Vec<SelectOption> = (0..10_000)
+            .map(|index| SelectOption::new(format!("option-{}", index)))
+            .collect();
+        let config = VirtualScrollConfig::default();
+        let viewport_height = 10.0 * config.row_height;
+
+        for scroll_top in [0.0, 16_000.0, 10_000.0 * config.row_height] {
+            let window = config.window(scroll_top, viewport_height, options.len());
+            let rendered = options.iter().skip(window.start).take(window.len()).count();
+            assert!(
+                rendered <= 11 + 2 * config.overscan,
+                "rendered {}",
+                rendered
+            );
+        }
+
+        // Type-ahead finds options that are not rendered
+        let last = type_ahead_match("option-9999", 0, options.len(), |index| {
+            Some(options[index].display_text())
+        });
+        assert_eq!(last, Some(9_999));
+    }
+
+    #[test]
+    fn test_trigger_variant_default() {
+        assert_eq!(TriggerVariant::default(), TriggerVariant::Default);
+    }
+
+    #[test]
+    fn test_inline_trigger_style() {
+        let style = inline_trigger_style(false, false, false);
+        assert!(style.contains("border: none"));
+        assert!(style.contains("width: auto"));
+        assert!(style.contains("color: var(--awsui-color-text-link-button-normal-default)"));
+        assert!(style.contains("font-weight: var(--awsui-font-link-button-weight)"));
+        assert!(!style.contains("box-shadow"));
+
+        let focused = inline_trigger_style(true, false, false);
+        assert!(focused.contains(
+            "box-shadow: 0 0 0 var(--awsui-border-link-focus-ring-shadow-spread) \
+             var(--awsui-color-border-item-focused)"
+        ));
+
+        let invalid = inline_trigger_style(false, true, false);
+        assert!(invalid.contains("color: var(--awsui-color-text-status-error)"));
+        assert!(invalid.contains("underline var(--awsui-color-border-status-error)"));
+
+        let disabled = inline_trigger_style(false, false, true);
+        assert!(disabled.contains("color: var(--awsui-color-text-interactive-disabled)"));
+    }
+
+    #[test]
+    fn test_trigger_variant_as_str() {
+        assert_eq!(TriggerVariant::Default.as_str(), "default");
+        assert_eq!(TriggerVariant::Inline.as_str(), "inline");
+        assert_eq!(TriggerVariant::Custom.as_str(), "custom");
+    }
+
+    #[test]
+    fn test_trigger_aria_attributes() {
+        let aria = AriaAttributes {
+            labelledby: Some("label-id".to_string()),
+            describedby: Some("description-id".to_string()),
+            ..Default::default()
+        };
+        let computed = trigger_aria_attributes(
+            Some("Region".to_string()),
+            &aria,
+            true,
+            false,
+            true,
+            "awsui-select-0",
+            Some(2),
+        );
+
+        assert_eq!(computed.label, Some("Region".to_string()));
+        assert_eq!(computed.labelledby, Some("label-id".to_string()));
+        assert_eq!(computed.describedby, Some("description-id".to_string()));
+        assert_eq!(computed.expanded, Some(true));
+        assert_eq!(computed.haspopup, Some(AriaHasPopup::Listbox));
+        assert_eq!(computed.required, Some(true));
+        // aria-invalid is omitted unless the trigger is invalid
+        assert_eq!(computed.invalid, None);
+
+        assert_eq!(computed.controls.as_deref(), Some("awsui-select-0"));
+        assert_eq!(
+            computed.activedescendant.as_deref(),
+            Some("awsui-select-0-option-2")
+        );
+
+        // The listbox is only referenced while open
+        let computed =
+            trigger_aria_attributes(None, &aria, false, true, false, "awsui-select-0", Some(2));
+        assert_eq!(computed.invalid, Some(true));
+        assert_eq!(computed.controls, None);
+        assert_eq!(computed.activedescendant, None);
+    }
 }
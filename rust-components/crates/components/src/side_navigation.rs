@@ -5,10 +5,18 @@
 //!
 //! Sidebar navigation with hierarchical items for organizing application navigation.
 
+use crate::app_layout::NavigationDisplay;
+use crate::i18n::{I18nContext, use_i18n};
+use crate::icon::Icon;
+use crate::input::{Input, InputChangeDetail, InputType};
+use crate::internal::dev_warnings::{DevWarning, duplicates, use_dev_warnings};
 use crate::internal::{
-    AriaAttributes, BaseComponentProps, ClassBuilder, ComponentMetadata, CustomEvent,
+    AriaAttributes, BaseComponentProps, ClassBuilder, ComponentMetadata, CustomEvent, Tooltip,
+    TruncatedText, apply_root_attributes,
 };
-use web_sys::MouseEvent;
+use crate::link::{self, provided_follow, use_link_context};
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement, MouseEvent};
 use yew::prelude::*;
 
 /// Types of items that can appear in the side navigation
@@ -43,6 +51,9 @@ pub struct SideNavigationItem {
     /// Display text for the item
     pub text: String,
     /// Optional href for link items
+    ///
+    /// Items have no separate id; the href identifies a link item, so hrefs
+    /// should be unique within the navigation.
     pub href: Option<String>,
     /// Whether the link opens in an external context
     pub external: bool,
@@ -52,6 +63,12 @@ pub struct SideNavigationItem {
     pub info: Option<Html>,
     /// Whether the item should be expanded by default
     pub default_expanded: Option<bool>,
+    /// Maximum text width as a CSS length, overriding the component default
+    pub max_width: Option<String>,
+    /// Name of the icon shown for the item in the navigation rail
+    ///
+    /// Items without an icon show the first letter of their text instead.
+    pub icon_name: Option<String>,
 }
 
 impl SideNavigationItem {
@@ -73,6 +90,8 @@ impl SideNavigationItem {
             items: Vec::new(),
             info: None,
             default_expanded: None,
+            max_width: None,
+            icon_name: None,
         }
     }
 
@@ -94,6 +113,8 @@ impl SideNavigationItem {
             items: Vec::new(),
             info: None,
             default_expanded: None,
+            max_width: None,
+            icon_name: None,
         }
     }
 
@@ -119,6 +140,8 @@ impl SideNavigationItem {
             items: Vec::new(),
             info: None,
             default_expanded: Some(true),
+            max_width: None,
+            icon_name: None,
         }
     }
 
@@ -144,6 +167,8 @@ impl SideNavigationItem {
             items: Vec::new(),
             info: None,
             default_expanded: None,
+            max_width: None,
+            icon_name: None,
         }
     }
 
@@ -170,6 +195,18 @@ impl SideNavigationItem {
         self.default_expanded = Some(expanded);
         self
     }
+
+    /// Sets the maximum text width
+    pub fn with_max_width(mut self, max_width: impl Into<String>) -> Self {
+        self.max_width = Some(max_width.into());
+        self
+    }
+
+    /// Sets the icon shown in the navigation rail
+    pub fn with_icon_name(mut self, icon_name: impl Into<String>) -> Self {
+        self.icon_name = Some(icon_name.into());
+        self
+    }
 }
 
 /// Header configuration for the side navigation
@@ -242,9 +279,11 @@ pub struct SideNavigationProps {
     #[prop_or_default]
     pub items: Vec<SideNavigationItem>,
 
-    /// Callback fired when a link is clicked
+    /// Callback fired when a link is clicked with the left mouse button
+    /// without pressing modifier keys (CTRL, ALT, SHIFT, META).
     ///
-    /// The event detail contains information about the clicked item.
+    /// Call `prevent_default()` on the event to stop the browser from
+    /// following the link, e.g. to implement client-side routing.
     #[prop_or_default]
     pub on_follow: Option<Callback<CustomEvent<FollowDetail>>>,
 
@@ -254,6 +293,31 @@ pub struct SideNavigationProps {
     #[prop_or_default]
     pub on_change: Option<Callback<CustomEvent<ChangeDetail>>>,
 
+    /// Whether to show a filter box above the items
+    ///
+    /// Typing filters the item tree by item text (case-insensitive) and
+    /// expands the sections and groups containing matches. Clearing the
+    /// filter restores the expansion state from before filtering started.
+    #[prop_or_default]
+    pub show_filter: bool,
+
+    /// Placeholder text for the filter box
+    ///
+    /// Defaults to `i18n_strings.filter_placeholder`, then "Find pages".
+    #[prop_or_default]
+    pub filter_placeholder: Option<String>,
+
+    /// Internationalization strings for the filter box
+    #[prop_or_default]
+    pub i18n_strings: SideNavigationI18nStrings,
+
+    /// Default maximum text width for items, as a CSS length
+    ///
+    /// Longer item text is truncated with an ellipsis and shown in full in a
+    /// tooltip on hover or focus. Items can override it with `max_width`.
+    #[prop_or_default]
+    pub item_max_width: Option<String>,
+
     /// ARIA attributes
     #[prop_or_default]
     pub aria: AriaAttributes,
@@ -263,6 +327,105 @@ pub struct SideNavigationProps {
     pub aria_label: Option<String>,
 }
 
+/// Internationalization strings for the SideNavigation filter
+///
+/// Unset strings fall back to the closest I18nProvider (keys under
+/// `"side-navigation."`), then to English. The `filter_placeholder` prop
+/// takes precedence.
+#[derive(Clone, PartialEq, Default)]
+pub struct SideNavigationI18nStrings {
+    /// Placeholder of the filter box (default: "Find pages")
+    pub filter_placeholder: Option<String>,
+    /// ARIA label of the filter box (default: "Filter navigation")
+    pub filtering_aria_label: Option<String>,
+    /// ARIA label of the filter box's clear button (default: "Clear filter")
+    pub filtering_clear_aria_label: Option<String>,
+    /// Text shown when no item matches the filter (default: "No matches")
+    pub no_match_text: Option<String>,
+    /// Text of the button clearing the filter when nothing matches
+    /// (default: "Clear filter")
+    pub clear_filter_text: Option<String>,
+}
+
+/// Strings used to render a SideNavigation after applying the fallback chain
+struct ResolvedStrings {
+    filter_placeholder: String,
+    filtering_aria_label: String,
+    filtering_clear_aria_label: String,
+    no_match_text: String,
+    clear_filter_text: String,
+}
+
+/// Resolves the filter strings: the `filter_placeholder` prop, then
+/// `i18n_strings`, then the I18nProvider, then English
+fn resolve_strings(props: &SideNavigationProps, i18n: &I18nContext) -> ResolvedStrings {
+    let strings = &props.i18n_strings;
+    let resolve = |value: &Option<String>, key: &str, default: &str| {
+        value
+            .clone()
+            .or_else(|| i18n.message("side-navigation", key).map(str::to_string))
+            .unwrap_or_else(|| default.to_string())
+    };
+
+    ResolvedStrings {
+        filter_placeholder: props.filter_placeholder.clone().unwrap_or_else(|| {
+            resolve(
+                &strings.filter_placeholder,
+                "filter_placeholder",
+                "Find pages",
+            )
+        }),
+        filtering_aria_label: resolve(
+            &strings.filtering_aria_label,
+            "filtering_aria_label",
+            "Filter navigation",
+        ),
+        filtering_clear_aria_label: resolve(
+            &strings.filtering_clear_aria_label,
+            "filtering_clear_aria_label",
+            "Clear filter",
+        ),
+        no_match_text: resolve(&strings.no_match_text, "no_match_text", "No matches"),
+        clear_filter_text: resolve(
+            &strings.clear_filter_text,
+            "clear_filter_text",
+            "Clear filter",
+        ),
+    }
+}
+
+/// Misuse of SideNavigation props reported in development builds
+///
+/// Items have no id field, so the duplicate id check runs on hrefs: the href
+/// is what identifies an item for `active_href` matching, and duplicates
+/// highlight several items at once.
+fn dev_warnings(props: &SideNavigationProps) -> Vec<DevWarning> {
+    fn collect_hrefs<'a>(items: &'a [SideNavigationItem], hrefs: &mut Vec<&'a str>) {
+        for item in items {
+            if let Some(href) = &item.href {
+                hrefs.push(href);
+            }
+            collect_hrefs(&item.items, hrefs);
+        }
+    }
+
+    let mut hrefs = Vec::new();
+    collect_hrefs(&props.items, &mut hrefs);
+    let repeated = duplicates(hrefs);
+    if repeated.is_empty() {
+        return Vec::new();
+    }
+
+    vec![DevWarning::new(
+        "side-navigation-duplicate-href",
+        format!(
+            "Navigation item hrefs must be unique; found duplicates: {}. Every item with \
+             the active href is highlighted.",
+            repeated.join(", ")
+        ),
+    )]
+}
+
 /// SideNavigation component
 ///
 /// A sidebar navigation component with hierarchical items for organizing application navigation.
@@ -270,7 +433,10 @@ pub struct SideNavigationProps {
 /// # Example
 ///
 /// ```rust
-/// use cloudscape_components::{SideNavigation, SideNavigationItem, SideNavigationHeader, FollowDetail, CustomEvent};
+/// use cloudscape_components::{
+///     CustomEvent, SideNavigation, SideNavigationFollowDetail, SideNavigationHeader,
+///     SideNavigationItem,
+/// };
 /// use yew::prelude::*;
 ///
 /// #[function_component(MyComponent)]
@@ -296,7 +462,7 @@ pub struct SideNavigationProps {
 ///
 ///     let on_follow = {
 ///         let active_href = active_href.clone();
-///         Callback::from(move |event: CustomEvent<FollowDetail>| {
+///         Callback::from(move |event: CustomEvent<SideNavigationFollowDetail>| {
 ///             if !event.detail.external {
 ///                 active_href.set(event.detail.href.clone());
 ///             }
@@ -316,6 +482,19 @@ pub struct SideNavigationProps {
 #[function_component(SideNavigation)]
 pub fn side_navigation(props: &SideNavigationProps) -> Html {
     let _metadata = ComponentMetadata::new("SideNavigation");
+    use_dev_warnings("SideNavigation").check(|| dev_warnings(props));
+    let i18n = use_i18n();
+    let strings = resolve_strings(props, &i18n);
+
+    // Without an `on_follow`, links are routed by the closest LinkProvider
+    let link_context = use_link_context();
+    let on_follow = props.on_follow.clone().or_else(|| {
+        provided_follow(link_context, |detail: &FollowDetail| link::FollowDetail {
+            href: Some(detail.href.clone()),
+            external: detail.external,
+            target: None,
+        })
+    });
 
     // Track expanded state for sections and expandable groups
     let expanded_items = use_state(|| {
@@ -325,38 +504,221 @@ pub fn side_navigation(props: &SideNavigationProps) -> Html {
     });
 
     // Re-initialize expanded state when items change
+    let initialized_items = use_mut_ref(|| props.items.clone());
     use_effect_with((props.items.clone(), props.active_href.clone()), {
         let expanded_items = expanded_items.clone();
         move |(items, active_href)| {
-            let mut map = std::collections::HashMap::new();
-            init_expanded_state(items, active_href, &mut map);
-            expanded_items.set(map);
+            if *initialized_items.borrow() != *items {
+                *initialized_items.borrow_mut() = items.clone();
+                let mut map = std::collections::HashMap::new();
+                init_expanded_state(items, active_href, &mut map);
+                expanded_items.set(map);
+            } else if let Some(active) = active_href {
+                // Navigation only reveals the active item; sections the user
+                // expanded or collapsed keep their state.
+                let mut map = (*expanded_items).clone();
+                if expand_active_ancestors(items, active, "", &mut map) {
+                    expanded_items.set(map);
+                }
+            }
             || ()
         }
     });
 
+    // Filter text and the expansion state captured when filtering started
+    let filter_text = use_state(String::new);
+    let expansion_snapshot = use_mut_ref(|| None::<std::collections::HashMap<String, bool>>);
+
+    let apply_filter = {
+        let filter_text = filter_text.clone();
+        let expanded_items = expanded_items.clone();
+        let items = props.items.clone();
+
+        Callback::from(move |value: String| {
+            if value.is_empty() {
+                if let Some(saved) = expansion_snapshot.borrow_mut().take() {
+                    expanded_items.set(saved);
+                }
+            } else {
+                let mut map = expansion_snapshot
+                    .borrow_mut()
+                    .get_or_insert_with(|| (*expanded_items).clone())
+                    .clone();
+                expand_matching_ancestors(&items, &value, "", &mut map);
+                expanded_items.set(map);
+            }
+            filter_text.set(value);
+        })
+    };
+
+    let on_filter_change = {
+        let apply_filter = apply_filter.clone();
+        Callback::from(move |event: CustomEvent<InputChangeDetail>| {
+            apply_filter.emit(event.detail.value);
+        })
+    };
+
+    let on_filter_clear = Callback::from(move |e: MouseEvent| {
+        e.prevent_default();
+        apply_filter.emit(String::new());
+    });
+
+    // Rail mode, set by an enclosing AppLayout
+    let rail = use_context::<NavigationDisplay>() == Some(NavigationDisplay::Rail);
+    let rail_ref = use_node_ref();
+    let open_flyout = use_state(|| None::<String>);
+    let tooltip_key = use_state(|| None::<String>);
+
+    // Close flyouts when leaving the rail
+    {
+        let open_flyout = open_flyout.clone();
+        use_effect_with(rail, move |rail| {
+            if !*rail {
+                open_flyout.set(None);
+            }
+            || ()
+        });
+    }
+
+    // Move focus into a flyout when it opens
+    {
+        let rail_ref = rail_ref.clone();
+        use_effect_with((*open_flyout).clone(), move |open| {
+            if let Some(key) = open
+                && let Some(rail) = rail_ref.cast::<Element>()
+                && let Ok(Some(flyout)) =
+                    rail.query_selector(&format!("[data-rail-flyout=\"{}\"]", key))
+            {
+                focus_rail_target(&flyout, RAIL_FLYOUT_TARGETS, Some(0));
+            }
+            || ()
+        });
+    }
+
+    if rail {
+        let context = RailContext {
+            active_href: props.active_href.clone(),
+            on_follow: on_follow.clone(),
+            on_change: props.on_change.clone(),
+            default_max_width: props.item_max_width.clone(),
+            expanded_items: expanded_items.clone(),
+            open_flyout,
+            tooltip_key,
+            rail_ref: rail_ref.clone(),
+        };
+
+        let on_rail_key_down = {
+            let rail_ref = rail_ref.clone();
+            Callback::from(move |e: KeyboardEvent| {
+                let Some(rail) = rail_ref.cast::<Element>() else {
+                    return;
+                };
+                let is_trigger = e
+                    .target()
+                    .and_then(|target| target.dyn_into::<Element>().ok())
+                    .is_some_and(|target| {
+                        target
+                            .class_name()
+                            .split_whitespace()
+                            .any(|class| class == RAIL_TRIGGER_CLASS)
+                    });
+                if is_trigger {
+                    let current = active_rail_index(&rail, RAIL_TARGETS);
+                    if let Some(index) =
+                        rail_focus_target(current, rail_target_count(&rail, RAIL_TARGETS), &e.key())
+                    {
+                        e.prevent_default();
+                        focus_rail_target(&rail, RAIL_TARGETS, Some(index));
+                    }
+                }
+            })
+        };
+
+        let root_classes = ClassBuilder::new()
+            .add("awsui-side-navigation")
+            .add("awsui-side-navigation-rail");
+
+        let root = html! {
+            <nav
+                id={props.base.id.clone()}
+                class={root_classes}
+                aria-label={props.aria_label.clone().or_else(|| Some("Side navigation".to_string()))}
+                aria-labelledby={props.aria.labelledby.clone()}
+                ref={rail_ref}
+            >
+                <ul class="awsui-side-navigation-rail-list" role="list" onkeydown={on_rail_key_down}>
+                    {
+                        props.items.iter().enumerate().map(|(index, item)| {
+                            render_rail_item(item, &item_key("", index), &context)
+                        }).collect::<Html>()
+                    }
+                </ul>
+            </nav>
+        };
+        return apply_root_attributes(root, &props.base);
+    }
+
+    let has_matches = filter_text.is_empty()
+        || props
+            .items
+            .iter()
+            .any(|item| is_item_visible(item, &filter_text));
+
     // Build root classes
     let root_classes = ClassBuilder::new().add("awsui-side-navigation");
 
-    html! {
+    let root = html! {
         <nav
             id={props.base.id.clone()}
-            class={root_classes.build()}
+            class={root_classes}
             aria-label={props.aria_label.clone().or_else(|| Some("Side navigation".to_string()))}
             aria-labelledby={props.aria.labelledby.clone()}
         >
             // Header
             if let Some(ref header) = props.header {
-                { render_header(header, &props.active_href, &props.on_follow) }
+                { render_header(header, &props.active_href, &on_follow) }
+            }
+
+            // Filter
+            if props.show_filter {
+                <div class="awsui-side-navigation-filter">
+                    <Input
+                        input_type={InputType::Search}
+                        value={(*filter_text).clone()}
+                        placeholder={strings.filter_placeholder.clone()}
+                        aria={AriaAttributes {
+                            label: Some(strings.filtering_aria_label.clone()),
+                            ..Default::default()
+                        }}
+                        clear_aria_label={strings.filtering_clear_aria_label.clone()}
+                        on_change={on_filter_change}
+                    />
+                </div>
             }
 
             // Items list
-            if !props.items.is_empty() {
+            if !has_matches {
+                <div class="awsui-side-navigation-filter-empty" role="status">
+                    <span class="awsui-side-navigation-filter-empty-text">
+                        { strings.no_match_text.clone() }
+                    </span>
+                    <button
+                        type="button"
+                        class="awsui-side-navigation-filter-clear"
+                        onclick={on_filter_clear}
+                    >
+                        { strings.clear_filter_text.clone() }
+                    </button>
+                </div>
+            } else if !props.items.is_empty() {
                 <div class="awsui-side-navigation-list-container">
                     { render_items_list(
                         &props.items,
+                        "",
+                        &filter_text,
+                        props.item_max_width.as_deref(),
                         &props.active_href,
-                        &props.on_follow,
+                        &on_follow,
                         &props.on_change,
                         expanded_items.clone(),
                         0
@@ -364,6 +726,355 @@ pub fn side_navigation(props: &SideNavigationProps) -> Html {
                 </div>
             }
         </nav>
+    };
+    apply_root_attributes(root, &props.base)
+}
+
+/// Class of the focusable top-level items of the navigation rail
+const RAIL_TRIGGER_CLASS: &str = "awsui-side-navigation-rail-trigger";
+
+/// Selector of the focusable top-level items of the navigation rail
+const RAIL_TARGETS: &str = ".awsui-side-navigation-rail-trigger";
+
+/// Selector of the focusable items in a rail flyout
+const RAIL_FLYOUT_TARGETS: &str = "a[href], button";
+
+/// State shared by the items of the navigation rail
+struct RailContext {
+    active_href: Option<String>,
+    on_follow: Option<Callback<CustomEvent<FollowDetail>>>,
+    on_change: Option<Callback<CustomEvent<ChangeDetail>>>,
+    default_max_width: Option<String>,
+    expanded_items: UseStateHandle<std::collections::HashMap<String, bool>>,
+    /// Key of the section or group whose flyout is open
+    open_flyout: UseStateHandle<Option<String>>,
+    /// Key of the item showing its label tooltip
+    tooltip_key: UseStateHandle<Option<String>>,
+    rail_ref: NodeRef,
+}
+
+/// Gets the index to move rail focus to for a navigation key
+///
+/// ArrowDown and ArrowUp wrap around; Home and End jump to the first and
+/// last item. Returns `None` for other keys or an empty rail.
+fn rail_focus_target(current: Option<usize>, count: usize, key: &str) -> Option<usize> {
+    if count == 0 {
+        return None;
+    }
+    match key {
+        "ArrowDown" => Some(current.map_or(0, |index| (index + 1) % count)),
+        "ArrowUp" => Some(current.map_or(count - 1, |index| (index + count - 1) % count)),
+        "Home" => Some(0),
+        "End" => Some(count - 1),
+        _ => None,
+    }
+}
+
+fn rail_targets(container: &Element, selector: &str) -> Vec<HtmlElement> {
+    let Ok(nodes) = container.query_selector_all(selector) else {
+        return Vec::new();
+    };
+    (0..nodes.length())
+        .filter_map(|index| nodes.item(index))
+        .filter_map(|node| node.dyn_into::<HtmlElement>().ok())
+        .collect()
+}
+
+fn rail_target_count(container: &Element, selector: &str) -> usize {
+    rail_targets(container, selector).len()
+}
+
+/// Gets the index of the focused element among the targets in `container`
+fn active_rail_index(container: &Element, selector: &str) -> Option<usize> {
+    let active = gloo::utils::document().active_element()?;
+    rail_targets(container, selector)
+        .iter()
+        .position(|target| **target == active)
+}
+
+/// Focuses the target at `index` among the targets in `container`
+fn focus_rail_target(container: &Element, selector: &str, index: Option<usize>) {
+    if let Some(target) =
+        index.and_then(|index| rail_targets(container, selector).into_iter().nth(index))
+    {
+        let _ = target.focus();
+    }
+}
+
+/// Renders the icon of a rail item, or its first letter without an icon
+fn render_rail_icon(item: &SideNavigationItem) -> Html {
+    match item.icon_name {
+        Some(ref name) => html! { <Icon name={name.clone()} /> },
+        None => html! {
+            <span class="awsui-side-navigation-rail-initial" aria-hidden="true">
+                { item.text.chars().next().map(|ch| ch.to_uppercase().to_string()).unwrap_or_default() }
+            </span>
+        },
+    }
+}
+
+/// Renders a top-level item of the navigation rail
+fn render_rail_item(item: &SideNavigationItem, item_key: &str, context: &RailContext) -> Html {
+    if item.item_type == SideNavigationItemType::Divider {
+        return html! {
+            <li key={item_key} class="awsui-side-navigation-rail-divider" role="separator" />
+        };
+    }
+
+    let has_flyout = matches!(
+        item.item_type,
+        SideNavigationItemType::Section | SideNavigationItemType::ExpandableLinkGroup
+    );
+    let is_active = is_item_active(item.href.as_ref(), &context.active_href)
+        || context
+            .active_href
+            .as_ref()
+            .is_some_and(|active| contains_active_href(&item.items, active));
+    let flyout_open = has_flyout && context.open_flyout.as_deref() == Some(item_key);
+    let show_tooltip = context.tooltip_key.as_deref() == Some(item_key) && !flyout_open;
+
+    let item_classes = ClassBuilder::new()
+        .add("awsui-side-navigation-rail-item")
+        .add_if(is_active, "awsui-side-navigation-rail-item-active")
+        .add_if(flyout_open, "awsui-side-navigation-rail-item-expanded");
+
+    let show_label = {
+        let tooltip_key = context.tooltip_key.clone();
+        let item_key = item_key.to_string();
+        Callback::from(move |()| tooltip_key.set(Some(item_key.clone())))
+    };
+    let hide_label = {
+        let tooltip_key = context.tooltip_key.clone();
+        Callback::from(move |()| tooltip_key.set(None))
+    };
+
+    // Closes the flyout once focus leaves the item
+    let on_focus_out = {
+        let open_flyout = context.open_flyout.clone();
+        let tooltip_key = context.tooltip_key.clone();
+        let item_key = item_key.to_string();
+        Callback::from(move |e: FocusEvent| {
+            let Some(current) = e
+                .current_target()
+                .and_then(|target| target.dyn_into::<Element>().ok())
+            else {
+                return;
+            };
+            let inside = e
+                .related_target()
+                .and_then(|target| target.dyn_into::<web_sys::Node>().ok())
+                .is_some_and(|target| current.contains(Some(&target)));
+            if !inside {
+                tooltip_key.set(None);
+                if open_flyout.as_deref() == Some(item_key.as_str()) {
+                    open_flyout.set(None);
+                }
+            }
+        })
+    };
+
+    let trigger = if has_flyout {
+        let toggle_flyout = {
+            let open_flyout = context.open_flyout.clone();
+            let item_key = item_key.to_string();
+            Callback::from(move |e: MouseEvent| {
+                e.prevent_default();
+                open_flyout.set((!flyout_open).then(|| item_key.clone()));
+            })
+        };
+        let on_key_down = {
+            let open_flyout = context.open_flyout.clone();
+            let item_key = item_key.to_string();
+            Callback::from(move |e: KeyboardEvent| {
+                if e.key() == "ArrowRight" {
+                    e.prevent_default();
+                    open_flyout.set(Some(item_key.clone()));
+                }
+            })
+        };
+
+        html! {
+            <button
+                type="button"
+                class={RAIL_TRIGGER_CLASS}
+                data-rail-key={item_key.to_string()}
+                aria-label={item.text.clone()}
+                aria-expanded={flyout_open.to_string()}
+                aria-haspopup="true"
+                onclick={toggle_flyout}
+                onkeydown={on_key_down}
+                onmouseenter={show_label.reform(|_: MouseEvent| ())}
+                onmouseleave={hide_label.reform(|_: MouseEvent| ())}
+                onfocus={show_label.reform(|_: FocusEvent| ())}
+            >
+                { render_rail_icon(item) }
+            </button>
+        }
+    } else {
+        let is_link_active = is_item_active(item.href.as_ref(), &context.active_href);
+        let onclick = {
+            let on_follow = context.on_follow.clone();
+            let item = item.clone();
+            Callback::from(move |e: MouseEvent| {
+                if let Some(ref href) = item.href {
+                    emit_follow(
+                        &e,
+                        &on_follow,
+                        FollowDetail {
+                            href: href.clone(),
+                            external: item.external,
+                            text: item.text.clone(),
+                            item_type: SideNavigationItemType::Link,
+                        },
+                    );
+                }
+            })
+        };
+
+        html! {
+            <a
+                href={item.href.clone()}
+                class={RAIL_TRIGGER_CLASS}
+                data-rail-key={item_key.to_string()}
+                aria-label={item.text.clone()}
+                aria-current={is_link_active.then_some("page")}
+                target={item.external.then_some("_blank")}
+                rel={item.external.then_some("noopener noreferrer")}
+                onclick={onclick}
+                onmouseenter={show_label.reform(|_: MouseEvent| ())}
+                onmouseleave={hide_label.reform(|_: MouseEvent| ())}
+                onfocus={show_label.reform(|_: FocusEvent| ())}
+            >
+                { render_rail_icon(item) }
+            </a>
+        }
+    };
+
+    let flyout = if flyout_open {
+        let on_flyout_key_down = {
+            let open_flyout = context.open_flyout.clone();
+            let rail_ref = context.rail_ref.clone();
+            let item_key = item_key.to_string();
+            Callback::from(move |e: KeyboardEvent| {
+                let Some(flyout) = e
+                    .current_target()
+                    .and_then(|target| target.dyn_into::<Element>().ok())
+                else {
+                    return;
+                };
+                match e.key().as_str() {
+                    "Escape" | "ArrowLeft" => {
+                        e.prevent_default();
+                        e.stop_propagation();
+                        open_flyout.set(None);
+                        // Return focus to the item that opened the flyout
+                        if let Some(rail) = rail_ref.cast::<Element>()
+                            && let Ok(Some(trigger)) =
+                                rail.query_selector(&format!("[data-rail-key=\"{}\"]", item_key))
+                            && let Ok(trigger) = trigger.dyn_into::<HtmlElement>()
+                        {
+                            let _ = trigger.focus();
+                        }
+                    }
+                    key => {
+                        let current = active_rail_index(&flyout, RAIL_FLYOUT_TARGETS);
+                        let count = rail_target_count(&flyout, RAIL_FLYOUT_TARGETS);
+                        if let Some(index) = rail_focus_target(current, count, key) {
+                            e.prevent_default();
+                            e.stop_propagation();
+                            focus_rail_target(&flyout, RAIL_FLYOUT_TARGETS, Some(index));
+                        }
+                    }
+                }
+            })
+        };
+
+        // Expandable groups link to their own page first
+        let group_link = (item.item_type == SideNavigationItemType::ExpandableLinkGroup)
+            .then(|| item.clone())
+            .and_then(|mut group| {
+                group.item_type = SideNavigationItemType::Link;
+                group.items.clear();
+                group.href.is_some().then_some(group)
+            });
+
+        html! {
+            <div
+                class="awsui-side-navigation-rail-flyout"
+                role="group"
+                aria-label={item.text.clone()}
+                data-rail-flyout={item_key.to_string()}
+                onkeydown={on_flyout_key_down}
+            >
+                <div class="awsui-side-navigation-rail-flyout-header">{ &item.text }</div>
+                if let Some(ref group_link) = group_link {
+                    <ul class="awsui-side-navigation-list" role="list">
+                        { render_link_item(
+                            group_link,
+                            &format!("{}-self", item_key),
+                            "",
+                            context.default_max_width.as_deref(),
+                            &context.active_href,
+                            &context.on_follow,
+                        ) }
+                    </ul>
+                }
+                { render_items_list(
+                    &item.items,
+                    item_key,
+                    "",
+                    context.default_max_width.as_deref(),
+                    &context.active_href,
+                    &context.on_follow,
+                    &context.on_change,
+                    context.expanded_items.clone(),
+                    1
+                ) }
+            </div>
+        }
+    } else {
+        html! {}
+    };
+
+    html! {
+        <li key={item_key} class={item_classes} role="listitem" onfocusout={on_focus_out}>
+            { trigger }
+            if show_tooltip {
+                <Tooltip content={html! { { &item.text } }} aria_hidden={true} />
+            }
+            { flyout }
+        </li>
+    }
+}
+
+/// Builds the expansion-state key for an item from its position in the tree
+///
+/// Keys include the parent path so nested items at the same index don't collide.
+fn item_key(parent_key: &str, index: usize) -> String {
+    if parent_key.is_empty() {
+        index.to_string()
+    } else {
+        format!("{}-{}", parent_key, index)
+    }
+}
+
+/// Checks whether two hrefs point at the same location, ignoring a trailing slash
+fn hrefs_match(a: &str, b: &str) -> bool {
+    fn normalize(href: &str) -> &str {
+        if href.len() > 1 {
+            href.strip_suffix('/').unwrap_or(href)
+        } else {
+            href
+        }
+    }
+    normalize(a) == normalize(b)
+}
+
+/// Checks whether an item's href matches the active href
+fn is_item_active(item_href: Option<&String>, active_href: &Option<String>) -> bool {
+    match (item_href, active_href) {
+        (Some(href), Some(active)) => hrefs_match(href, active),
+        _ => false,
     }
 }
 
@@ -372,9 +1083,18 @@ fn init_expanded_state(
     items: &[SideNavigationItem],
     active_href: &Option<String>,
     map: &mut std::collections::HashMap<String, bool>,
+) {
+    init_expanded_state_at(items, active_href, "", map);
+}
+
+fn init_expanded_state_at(
+    items: &[SideNavigationItem],
+    active_href: &Option<String>,
+    parent_key: &str,
+    map: &mut std::collections::HashMap<String, bool>,
 ) {
     for (index, item) in items.iter().enumerate() {
-        let item_key = format!("{}-{}", item.item_type.as_str(), index);
+        let item_key = item_key(parent_key, index);
 
         match item.item_type {
             SideNavigationItemType::Section | SideNavigationItemType::ExpandableLinkGroup => {
@@ -390,18 +1110,43 @@ fn init_expanded_state(
                 map.insert(item_key.clone(), should_expand);
 
                 // Recursively initialize nested items
-                init_expanded_state(&item.items, active_href, map);
+                init_expanded_state_at(&item.items, active_href, &item_key, map);
             }
             _ => {}
         }
     }
 }
 
+/// Expands every section and group on the path to the active item
+///
+/// Returns `true` if any entry in the map changed.
+fn expand_active_ancestors(
+    items: &[SideNavigationItem],
+    active_href: &str,
+    parent_key: &str,
+    map: &mut std::collections::HashMap<String, bool>,
+) -> bool {
+    let mut changed = false;
+    for (index, item) in items.iter().enumerate() {
+        if item.items.is_empty() {
+            continue;
+        }
+        let item_key = item_key(parent_key, index);
+        if contains_active_href(&item.items, active_href)
+            && map.insert(item_key.clone(), true) != Some(true)
+        {
+            changed = true;
+        }
+        changed |= expand_active_ancestors(&item.items, active_href, &item_key, map);
+    }
+    changed
+}
+
 /// Checks if any item in the list has the given href
 fn contains_active_href(items: &[SideNavigationItem], href: &str) -> bool {
     items.iter().any(|item| {
         if let Some(ref item_href) = item.href
-            && item_href == href
+            && hrefs_match(item_href, href)
         {
             return true;
         }
@@ -412,16 +1157,95 @@ fn contains_active_href(items: &[SideNavigationItem], href: &str) -> bool {
     })
 }
 
+/// Finds the first case-insensitive occurrence of `query` in `text`
+///
+/// Returns the byte range of the match within `text`.
+fn find_match(text: &str, query: &str) -> Option<(usize, usize)> {
+    if query.is_empty() {
+        return None;
+    }
+    let query = query.to_lowercase();
+    for (start, _) in text.char_indices() {
+        let mut lowered = String::new();
+        for (offset, ch) in text[start..].char_indices() {
+            lowered.extend(ch.to_lowercase());
+            if lowered.len() >= query.len() {
+                if lowered == query {
+                    return Some((start, start + offset + ch.len_utf8()));
+                }
+                break;
+            }
+        }
+    }
+    None
+}
+
+/// Checks whether an item or any of its descendants matches the filter
+fn item_matches(item: &SideNavigationItem, query: &str) -> bool {
+    find_match(&item.text, query).is_some()
+        || item.items.iter().any(|child| item_matches(child, query))
+}
+
+/// Checks whether an item is shown for the current filter
+///
+/// Dividers are hidden while filtering since the groups they separate
+/// no longer line up.
+fn is_item_visible(item: &SideNavigationItem, query: &str) -> bool {
+    query.is_empty()
+        || (item.item_type != SideNavigationItemType::Divider && item_matches(item, query))
+}
+
+/// Returns the filter to apply to an item's children
+///
+/// When the item's own text matches, its whole subtree is shown.
+fn child_filter<'a>(item: &SideNavigationItem, query: &'a str) -> &'a str {
+    if find_match(&item.text, query).is_some() {
+        ""
+    } else {
+        query
+    }
+}
+
+/// Expands every section and group that contains a filter match
+fn expand_matching_ancestors(
+    items: &[SideNavigationItem],
+    query: &str,
+    parent_key: &str,
+    map: &mut std::collections::HashMap<String, bool>,
+) {
+    for (index, item) in items.iter().enumerate() {
+        if item.items.is_empty() {
+            continue;
+        }
+        let item_key = item_key(parent_key, index);
+        if item.items.iter().any(|child| item_matches(child, query)) {
+            map.insert(item_key.clone(), true);
+        }
+        expand_matching_ancestors(&item.items, query, &item_key, map);
+    }
+}
+
+/// Renders item text with the filter match highlighted
+fn render_text(text: &str, query: &str) -> Html {
+    match find_match(text, query) {
+        Some((start, end)) => html! {
+            <>
+                { &text[..start] }
+                <mark class="awsui-side-navigation-filter-match">{ &text[start..end] }</mark>
+                { &text[end..] }
+            </>
+        },
+        None => html! { { text } },
+    }
+}
+
 /// Renders the header section
 fn render_header(
     header: &SideNavigationHeader,
     active_href: &Option<String>,
     on_follow: &Option<Callback<CustomEvent<FollowDetail>>>,
 ) -> Html {
-    let is_active = active_href
-        .as_ref()
-        .map(|href| href == &header.href)
-        .unwrap_or(false);
+    let is_active = is_item_active(Some(&header.href), active_href);
 
     let header_classes = ClassBuilder::new()
         .add("awsui-side-navigation-header")
@@ -433,27 +1257,26 @@ fn render_header(
         let text = header.text.clone();
 
         Callback::from(move |e: MouseEvent| {
-            // Only fire for plain left clicks
-            if e.button() == 0 && !e.ctrl_key() && !e.shift_key() && !e.alt_key() && !e.meta_key() {
-                e.prevent_default();
-                if let Some(ref callback) = on_follow {
-                    callback.emit(CustomEvent::new(FollowDetail {
-                        href: href.clone(),
-                        external: false,
-                        text: text.clone(),
-                        item_type: SideNavigationItemType::Link,
-                    }));
-                }
-            }
+            emit_follow(
+                &e,
+                &on_follow,
+                FollowDetail {
+                    href: href.clone(),
+                    external: false,
+                    text: text.clone(),
+                    item_type: SideNavigationItemType::Link,
+                },
+            );
         })
     };
 
     html! {
-        <div class={header_classes.build()}>
+        <div class={header_classes}>
             <a
                 href={header.href.clone()}
                 class="awsui-side-navigation-header-link"
                 onclick={onclick}
+                aria-current={if is_active { Some("page") } else { None }}
             >
                 <span class="awsui-side-navigation-header-link-text">
                     { &header.text }
@@ -463,9 +1286,34 @@ fn render_header(
     }
 }
 
+/// Emits a cancelable follow event for plain left clicks
+///
+/// Native navigation is only suppressed when the consumer calls
+/// `prevent_default()` on the event.
+fn emit_follow(
+    e: &MouseEvent,
+    on_follow: &Option<Callback<CustomEvent<FollowDetail>>>,
+    detail: FollowDetail,
+) {
+    let is_plain_left_click =
+        e.button() == 0 && !e.ctrl_key() && !e.shift_key() && !e.alt_key() && !e.meta_key();
+    if !is_plain_left_click {
+        return;
+    }
+    if let Some(callback) = on_follow
+        && CustomEvent::new(detail).emit_to(callback)
+    {
+        e.prevent_default();
+    }
+}
+
 /// Renders a list of navigation items
+#[allow(clippy::too_many_arguments)]
 fn render_items_list(
     items: &[SideNavigationItem],
+    parent_key: &str,
+    filter: &str,
+    default_max_width: Option<&str>,
     active_href: &Option<String>,
     on_follow: &Option<Callback<CustomEvent<FollowDetail>>>,
     on_change: &Option<Callback<CustomEvent<ChangeDetail>>>,
@@ -477,12 +1325,14 @@ fn render_items_list(
         .add(format!("awsui-side-navigation-list-depth-{}", depth));
 
     html! {
-        <ul class={list_classes.build()} role="list">
+        <ul class={list_classes} role="list">
             {
-                items.iter().enumerate().map(|(index, item)| {
+                items.iter().enumerate().filter(|(_, item)| is_item_visible(item, filter)).map(|(index, item)| {
                     render_item(
                         item,
-                        index,
+                        &item_key(parent_key, index),
+                        filter,
+                        default_max_width,
                         active_href,
                         on_follow,
                         on_change,
@@ -496,27 +1346,37 @@ fn render_items_list(
 }
 
 /// Renders a single navigation item
+#[allow(clippy::too_many_arguments)]
 fn render_item(
     item: &SideNavigationItem,
-    index: usize,
+    item_key: &str,
+    filter: &str,
+    default_max_width: Option<&str>,
     active_href: &Option<String>,
     on_follow: &Option<Callback<CustomEvent<FollowDetail>>>,
     on_change: &Option<Callback<CustomEvent<ChangeDetail>>>,
     expanded_items: UseStateHandle<std::collections::HashMap<String, bool>>,
     depth: usize,
 ) -> Html {
-    let item_key = format!("{}-{}", item.item_type.as_str(), index);
-
     match item.item_type {
         SideNavigationItemType::Divider => {
             html! {
-                <li key={item_key.clone()} class="awsui-side-navigation-item awsui-side-navigation-divider" role="separator" />
+                <li key={item_key} class="awsui-side-navigation-item awsui-side-navigation-divider" role="separator" />
             }
         }
-        SideNavigationItemType::Link => render_link_item(item, &item_key, active_href, on_follow),
+        SideNavigationItemType::Link => render_link_item(
+            item,
+            item_key,
+            filter,
+            default_max_width,
+            active_href,
+            on_follow,
+        ),
         SideNavigationItemType::Section => render_section_item(
             item,
-            &item_key,
+            item_key,
+            filter,
+            default_max_width,
             active_href,
             on_follow,
             on_change,
@@ -525,7 +1385,9 @@ fn render_item(
         ),
         SideNavigationItemType::ExpandableLinkGroup => render_expandable_group_item(
             item,
-            &item_key,
+            item_key,
+            filter,
+            default_max_width,
             active_href,
             on_follow,
             on_change,
@@ -539,14 +1401,12 @@ fn render_item(
 fn render_link_item(
     item: &SideNavigationItem,
     item_key: &str,
+    filter: &str,
+    default_max_width: Option<&str>,
     active_href: &Option<String>,
     on_follow: &Option<Callback<CustomEvent<FollowDetail>>>,
 ) -> Html {
-    let is_active = if let (Some(active), Some(href)) = (active_href, &item.href) {
-        active == href
-    } else {
-        false
-    };
+    let is_active = is_item_active(item.href.as_ref(), active_href);
 
     let item_classes = ClassBuilder::new()
         .add("awsui-side-navigation-item")
@@ -558,27 +1418,23 @@ fn render_link_item(
         let item = item.clone();
 
         Callback::from(move |e: MouseEvent| {
-            // Only fire for plain left clicks
-            if e.button() == 0 && !e.ctrl_key() && !e.shift_key() && !e.alt_key() && !e.meta_key() {
-                if !item.external {
-                    e.prevent_default();
-                }
-                if let Some(ref callback) = on_follow
-                    && let Some(ref href) = item.href
-                {
-                    callback.emit(CustomEvent::new(FollowDetail {
+            if let Some(ref href) = item.href {
+                emit_follow(
+                    &e,
+                    &on_follow,
+                    FollowDetail {
                         href: href.clone(),
                         external: item.external,
                         text: item.text.clone(),
                         item_type: SideNavigationItemType::Link,
-                    }));
-                }
+                    },
+                );
             }
         })
     };
 
     html! {
-        <li key={item_key} class={item_classes.build()} role="listitem">
+        <li key={item_key} class={item_classes} role="listitem">
             if item.external {
                 <a
                     href={item.href.clone()}
@@ -589,7 +1445,12 @@ fn render_link_item(
                     aria-current={if is_active { Some("page") } else { None }}
                 >
                     <span class="awsui-side-navigation-link-text">
-                        { &item.text }
+                        <TruncatedText
+                            text={item.text.clone()}
+                            max_width={item.max_width.clone().or_else(|| default_max_width.map(str::to_string))}
+                        >
+                            { render_text(&item.text, filter) }
+                        </TruncatedText>
                     </span>
                     <span class="awsui-side-navigation-external-icon" aria-label="(opens in a new tab)">
                         { "↗" }
@@ -608,7 +1469,12 @@ fn render_link_item(
                     aria-current={if is_active { Some("page") } else { None }}
                 >
                     <span class="awsui-side-navigation-link-text">
-                        { &item.text }
+                        <TruncatedText
+                            text={item.text.clone()}
+                            max_width={item.max_width.clone().or_else(|| default_max_width.map(str::to_string))}
+                        >
+                            { render_text(&item.text, filter) }
+                        </TruncatedText>
                     </span>
                     if let Some(ref info) = item.info {
                         <span class="awsui-side-navigation-link-info">
@@ -622,9 +1488,12 @@ fn render_link_item(
 }
 
 /// Renders a section item
+#[allow(clippy::too_many_arguments)]
 fn render_section_item(
     item: &SideNavigationItem,
     item_key: &str,
+    filter: &str,
+    default_max_width: Option<&str>,
     active_href: &Option<String>,
     on_follow: &Option<Callback<CustomEvent<FollowDetail>>>,
     on_change: &Option<Callback<CustomEvent<ChangeDetail>>>,
@@ -664,7 +1533,7 @@ fn render_section_item(
     };
 
     html! {
-        <li key={item_key} class={section_classes.build()} role="listitem">
+        <li key={item_key} class={section_classes} role="listitem">
             <button
                 type="button"
                 class="awsui-side-navigation-section-toggle"
@@ -675,12 +1544,20 @@ fn render_section_item(
                     { if is_expanded { "▼" } else { "▶" } }
                 </span>
                 <span class="awsui-side-navigation-section-text">
-                    { &item.text }
+                    <TruncatedText
+                            text={item.text.clone()}
+                            max_width={item.max_width.clone().or_else(|| default_max_width.map(str::to_string))}
+                        >
+                            { render_text(&item.text, filter) }
+                        </TruncatedText>
                 </span>
             </button>
             if is_expanded && !item.items.is_empty() {
                 { render_items_list(
                     &item.items,
+                    item_key,
+                    child_filter(item, filter),
+                    default_max_width,
                     active_href,
                     on_follow,
                     on_change,
@@ -693,9 +1570,12 @@ fn render_section_item(
 }
 
 /// Renders an expandable link group item
+#[allow(clippy::too_many_arguments)]
 fn render_expandable_group_item(
     item: &SideNavigationItem,
     item_key: &str,
+    filter: &str,
+    default_max_width: Option<&str>,
     active_href: &Option<String>,
     on_follow: &Option<Callback<CustomEvent<FollowDetail>>>,
     on_change: &Option<Callback<CustomEvent<ChangeDetail>>>,
@@ -703,11 +1583,7 @@ fn render_expandable_group_item(
     depth: usize,
 ) -> Html {
     let is_expanded = expanded_items.get(item_key).copied().unwrap_or(false);
-    let is_active = if let (Some(active), Some(href)) = (active_href, &item.href) {
-        active == href
-    } else {
-        false
-    };
+    let is_active = is_item_active(item.href.as_ref(), active_href);
 
     let group_classes = ClassBuilder::new()
         .add("awsui-side-navigation-item")
@@ -748,25 +1624,23 @@ fn render_expandable_group_item(
         let item = item.clone();
 
         Callback::from(move |e: MouseEvent| {
-            // Only fire for plain left clicks
-            if e.button() == 0 && !e.ctrl_key() && !e.shift_key() && !e.alt_key() && !e.meta_key() {
-                e.prevent_default();
-                if let Some(ref callback) = on_follow
-                    && let Some(ref href) = item.href
-                {
-                    callback.emit(CustomEvent::new(FollowDetail {
+            if let Some(ref href) = item.href {
+                emit_follow(
+                    &e,
+                    &on_follow,
+                    FollowDetail {
                         href: href.clone(),
                         external: false,
                         text: item.text.clone(),
                         item_type: SideNavigationItemType::ExpandableLinkGroup,
-                    }));
-                }
+                    },
+                );
             }
         })
     };
 
     html! {
-        <li key={item_key} class={group_classes.build()} role="listitem">
+        <li key={item_key} class={group_classes} role="listitem">
             <div class="awsui-side-navigation-expandable-group-header">
                 <button
                     type="button"
@@ -785,7 +1659,12 @@ fn render_expandable_group_item(
                     aria-current={if is_active { Some("page") } else { None }}
                 >
                     <span class="awsui-side-navigation-expandable-group-text">
-                        { &item.text }
+                        <TruncatedText
+                            text={item.text.clone()}
+                            max_width={item.max_width.clone().or_else(|| default_max_width.map(str::to_string))}
+                        >
+                            { render_text(&item.text, filter) }
+                        </TruncatedText>
                     </span>
                     if let Some(ref info) = item.info {
                         <span class="awsui-side-navigation-expandable-group-info">
@@ -797,6 +1676,9 @@ fn render_expandable_group_item(
             if is_expanded && !item.items.is_empty() {
                 { render_items_list(
                     &item.items,
+                    item_key,
+                    child_filter(item, filter),
+                    default_max_width,
                     active_href,
                     on_follow,
                     on_change,
@@ -811,6 +1693,29 @@ fn render_expandable_group_item(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::internal::dev_warnings::DevWarnings;
+
+    #[test]
+    fn test_dev_warning_duplicate_hrefs() {
+        let props = yew::props!(SideNavigationProps {
+            items: vec![
+                SideNavigationItem::link("Home", "/home"),
+                SideNavigationItem::section("Section")
+                    .with_items(vec![SideNavigationItem::link("Home again", "/home")]),
+            ],
+        });
+        let warnings = DevWarnings::new("SideNavigation");
+        assert_eq!(warnings.check(|| dev_warnings(&props)), 1);
+        assert_eq!(warnings.check(|| dev_warnings(&props)), 0);
+
+        let unique = yew::props!(SideNavigationProps {
+            items: vec![
+                SideNavigationItem::link("Home", "/home"),
+                SideNavigationItem::link("Settings", "/settings"),
+            ],
+        });
+        assert!(dev_warnings(&unique).is_empty());
+    }
 
     #[test]
     fn test_side_navigation_item_link() {
@@ -886,6 +1791,54 @@ mod tests {
         assert!(!contains_active_href(&items, "/not-found"));
     }
 
+    #[test]
+    fn test_hrefs_match_ignores_trailing_slash() {
+        assert!(hrefs_match("/settings", "/settings/"));
+        assert!(hrefs_match("/", "/"));
+        assert!(!hrefs_match("/settings", "/settings/profile"));
+    }
+
+    #[test]
+    fn test_item_keys_are_unique_across_depths() {
+        let items = vec![
+            SideNavigationItem::section("Outer").with_items(vec![
+                SideNavigationItem::section("Inner")
+                    .with_default_expanded(false)
+                    .with_items(vec![SideNavigationItem::link("Leaf", "/leaf")]),
+            ]),
+            SideNavigationItem::section("Second"),
+        ];
+        let mut map = std::collections::HashMap::new();
+        init_expanded_state(&items, &None, &mut map);
+
+        assert_eq!(map.get("0"), Some(&true));
+        assert_eq!(map.get("0-0"), Some(&false));
+        assert_eq!(map.get("1"), Some(&true));
+    }
+
+    #[test]
+    fn test_expand_active_ancestors_keeps_other_state() {
+        let items = vec![
+            SideNavigationItem::expandable_link_group("Docs", "/docs")
+                .with_items(vec![SideNavigationItem::link("Guide", "/docs/guide")]),
+            SideNavigationItem::expandable_link_group("API", "/api")
+                .with_items(vec![SideNavigationItem::link("Reference", "/api/ref")]),
+        ];
+        let mut map = std::collections::HashMap::new();
+        init_expanded_state(&items, &None, &mut map);
+        map.insert("1".to_string(), true);
+
+        assert!(expand_active_ancestors(&items, "/docs/guide", "", &mut map));
+        assert_eq!(map.get("0"), Some(&true));
+        assert_eq!(map.get("1"), Some(&true));
+        assert!(!expand_active_ancestors(
+            &items,
+            "/docs/guide",
+            "",
+            &mut map
+        ));
+    }
+
     #[test]
     fn test_item_type_as_str() {
         assert_eq!(SideNavigationItemType::Link.as_str(), "link");
@@ -922,4 +1875,106 @@ mod tests {
         assert_eq!(detail.item.text, "Test");
         assert!(detail.expanded);
     }
+
+    #[test]
+    fn test_find_match_is_case_insensitive() {
+        assert_eq!(find_match("Security Groups", "group"), Some((9, 14)));
+        assert_eq!(find_match("Security Groups", "SEC"), Some((0, 3)));
+        assert_eq!(find_match("Security Groups", "vpc"), None);
+        assert_eq!(find_match("Security Groups", ""), None);
+    }
+
+    #[test]
+    fn test_filter_visibility() {
+        let section = SideNavigationItem::section("Network").with_items(vec![
+            SideNavigationItem::link("Subnets", "/subnets"),
+            SideNavigationItem::link("Route tables", "/routes"),
+        ]);
+
+        assert!(is_item_visible(&section, ""));
+        assert!(is_item_visible(&section, "route"));
+        assert!(!is_item_visible(&section, "bucket"));
+        assert!(!is_item_visible(&SideNavigationItem::divider(), "route"));
+        assert_eq!(child_filter(&section, "route"), "route");
+        assert_eq!(child_filter(&section, "net"), "");
+    }
+
+    #[test]
+    fn test_expand_matching_ancestors() {
+        let items = vec![
+            SideNavigationItem::section("Compute")
+                .with_default_expanded(false)
+                .with_items(vec![
+                    SideNavigationItem::expandable_link_group("Instances", "/instances")
+                        .with_items(vec![SideNavigationItem::link("Spot requests", "/spot")]),
+                ]),
+            SideNavigationItem::section("Storage")
+                .with_default_expanded(false)
+                .with_items(vec![SideNavigationItem::link("Volumes", "/volumes")]),
+        ];
+        let mut map = std::collections::HashMap::new();
+        init_expanded_state(&items, &None, &mut map);
+
+        expand_matching_ancestors(&items, "spot", "", &mut map);
+
+        assert_eq!(map.get("0"), Some(&true));
+        assert_eq!(map.get("0-0"), Some(&true));
+        assert_eq!(map.get("1"), Some(&false));
+    }
+
+    #[test]
+    fn test_item_icon_name() {
+        let item =
+            SideNavigationItem::link("Dashboard", "/dashboard").with_icon_name("status-info");
+        assert_eq!(item.icon_name.as_deref(), Some("status-info"));
+        assert_eq!(SideNavigationItem::section("Settings").icon_name, None);
+    }
+
+    #[test]
+    fn test_rail_focus_target() {
+        assert_eq!(rail_focus_target(None, 4, "ArrowDown"), Some(0));
+        assert_eq!(rail_focus_target(Some(1), 4, "ArrowDown"), Some(2));
+        assert_eq!(rail_focus_target(Some(3), 4, "ArrowDown"), Some(0));
+        assert_eq!(rail_focus_target(None, 4, "ArrowUp"), Some(3));
+        assert_eq!(rail_focus_target(Some(0), 4, "ArrowUp"), Some(3));
+        assert_eq!(rail_focus_target(Some(2), 4, "Home"), Some(0));
+        assert_eq!(rail_focus_target(Some(0), 4, "End"), Some(3));
+        assert_eq!(rail_focus_target(Some(0), 4, "Tab"), None);
+        assert_eq!(rail_focus_target(None, 0, "ArrowDown"), None);
+    }
+
+    #[test]
+    fn test_resolve_strings() {
+        let props = yew::props!(SideNavigationProps {});
+        let strings = resolve_strings(&props, &I18nContext::default());
+        assert_eq!(strings.filter_placeholder, "Find pages");
+        assert_eq!(strings.filtering_aria_label, "Filter navigation");
+        assert_eq!(strings.filtering_clear_aria_label, "Clear filter");
+        assert_eq!(strings.no_match_text, "No matches");
+        assert_eq!(strings.clear_filter_text, "Clear filter");
+
+        let mut messages = crate::internal::I18nStrings::new();
+        messages.set("side-navigation.no_match_text", "Keine Treffer");
+        messages.set("side-navigation.filter_placeholder", "Seiten suchen");
+        let i18n = I18nContext {
+            locale: Some("de-DE".to_string()),
+            messages,
+        };
+        let props = yew::props!(SideNavigationProps {
+            i18n_strings: SideNavigationI18nStrings {
+                clear_filter_text: Some("Filter löschen".to_string()),
+                ..Default::default()
+            },
+        });
+        let strings = resolve_strings(&props, &i18n);
+        assert_eq!(strings.no_match_text, "Keine Treffer");
+        assert_eq!(strings.filter_placeholder, "Seiten suchen");
+        assert_eq!(strings.clear_filter_text, "Filter löschen");
+
+        // The placeholder prop takes precedence
+        let props = yew::props!(SideNavigationProps {
+            filter_placeholder: "Search",
+        });
+        assert_eq!(resolve_strings(&props, &i18n).filter_placeholder, "Search");
+    }
 }
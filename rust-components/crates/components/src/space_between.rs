@@ -7,7 +7,7 @@
 //! This component wraps child elements and applies spacing between them
 //! according to Cloudscape Design System spacing tokens.
 
-use crate::internal::{BaseComponentProps, ClassBuilder};
+use crate::internal::{BaseComponentProps, ClassBuilder, apply_root_attributes};
 use yew::prelude::*;
 
 /// Direction of spacing between elements
@@ -182,16 +182,13 @@ pub fn space_between(props: &SpaceBetweenProps) -> Html {
             "awsui-space-between-direction-{}",
             props.direction.as_str()
         ))
-        .add(format!(
-            "awsui-space-between-size-{}",
-            props.size.as_str()
-        ))
-        .add_option(
+        .add(format!("awsui-space-between-size-{}", props.size.as_str()))
+        .add_opt(
             props
                 .alignment_horizontal
                 .map(|align| format!("awsui-space-between-align-horizontal-{}", align.as_str())),
         )
-        .add_option(
+        .add_opt(
             props
                 .alignment_vertical
                 .map(|align| format!("awsui-space-between-align-vertical-{}", align.as_str())),
@@ -200,14 +197,15 @@ pub fn space_between(props: &SpaceBetweenProps) -> Html {
 
     let class = props.base.merge_classes(&class);
 
-    html! {
+    let root = html! {
         <div
             id={props.base.id.clone()}
             class={class}
         >
             { props.children.clone() }
         </div>
-    }
+    };
+    apply_root_attributes(root, &props.base)
 }
 
 #[cfg(test)]
@@ -320,27 +318,23 @@ mod tests {
             children: Children::default(),
         };
 
-        let class = ClassBuilder::new()
-            .add("awsui-space-between")
-            .add(format!(
-                "awsui-space-between-direction-{}",
-                props.direction.as_str()
-            ))
-            .add(format!(
-                "awsui-space-between-size-{}",
-                props.size.as_str()
-            ))
-            .add_option(
-                props
-                    .alignment_horizontal
-                    .map(|align| format!("awsui-space-between-align-horizontal-{}", align.as_str())),
-            )
-            .add_option(
-                props
-                    .alignment_vertical
-                    .map(|align| format!("awsui-space-between-align-vertical-{}", align.as_str())),
-            )
-            .build();
+        let class =
+            ClassBuilder::new()
+                .add("awsui-space-between")
+                .add(format!(
+                    "awsui-space-between-direction-{}",
+                    props.direction.as_str()
+                ))
+                .add(format!("awsui-space-between-size-{}", props.size.as_str()))
+                .add_opt(props.alignment_horizontal.map(|align| {
+                    format!("awsui-space-between-align-horizontal-{}", align.as_str())
+                }))
+                .add_opt(
+                    props.alignment_vertical.map(|align| {
+                        format!("awsui-space-between-align-vertical-{}", align.as_str())
+                    }),
+                )
+                .build();
 
         assert!(class.contains("awsui-space-between-align-horizontal-center"));
         assert!(class.contains("awsui-space-between-align-vertical-end"));
@@ -374,7 +368,7 @@ mod tests {
         let base = BaseComponentProps {
             id: Some("test-space-between".to_string()),
             class: Some("custom-class".to_string()),
-            data_attributes: None,
+            ..Default::default()
         };
 
         let class = base.merge_classes("awsui-space-between awsui-space-between-direction-vertical");
@@ -5,7 +5,8 @@
 //!
 //! A loading indicator that displays an animated spinner.
 
-use crate::internal::{BaseComponentProps, ClassBuilder};
+use crate::internal::busy::use_register_busy;
+use crate::internal::{BaseComponentProps, ClassBuilder, apply_root_attributes};
 use yew::prelude::*;
 
 /// Spinner size variants
@@ -60,6 +61,13 @@ pub struct SpinnerProps {
     /// Spinner variant
     #[prop_or_default]
     pub variant: SpinnerVariant,
+
+    /// Whether the spinner registers with the closest BusyAnnouncer
+    ///
+    /// Registered spinners count towards the app busy state and leave
+    /// announcing it to the announcer.
+    #[prop_or_default]
+    pub register_busy: bool,
 }
 
 /// Spinner component
@@ -76,6 +84,9 @@ pub struct SpinnerProps {
 /// ```
 #[function_component(Spinner)]
 pub fn spinner(props: &SpinnerProps) -> Html {
+    // Spinners stay silent under a BusyAnnouncer, which announces loading once
+    let coordinated = use_register_busy(props.register_busy);
+
     let class = ClassBuilder::new()
         .add("awsui-spinner")
         .add(format!("awsui-spinner-size-{}", props.size.as_str()))
@@ -84,17 +95,18 @@ pub fn spinner(props: &SpinnerProps) -> Html {
 
     let class = props.base.merge_classes(&class);
 
-    html! {
+    let root = html! {
         <span
             id={props.base.id.clone()}
             class={class}
-            role="status"
-            aria-live="polite"
+            role={(!coordinated).then_some("status")}
+            aria-live={(!coordinated).then_some("polite")}
         >
             <span class="awsui-spinner-rotator">
                 <span class="awsui-spinner-circle awsui-spinner-circle-left" />
                 <span class="awsui-spinner-circle awsui-spinner-circle-right" />
             </span>
         </span>
-    }
+    };
+    apply_root_attributes(root, &props.base)
 }
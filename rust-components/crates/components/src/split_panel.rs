@@ -0,0 +1,553 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! SplitPanel component for secondary content next to the main content.
+//!
+//! Placed in the `split_panel` slot of an [`AppLayout`](crate::AppLayout),
+//! the panel gets its open state, position and size from the layout: its
+//! header toggles it, its edge resizes it, and its preferences modal
+//! switches it between the bottom and the side. Outside of an AppLayout it
+//! renders as an open panel without these controls.
+
+use crate::app_layout::{SplitPanelPosition, SplitPanelPreferences};
+use crate::button::{Button, ButtonVariant, FormAction};
+use crate::form_field::FormField;
+use crate::i18n::{I18nContext, use_i18n};
+use crate::internal::resize::{HandleDrag, HandleOrientation, ResizeHandle};
+use crate::internal::{
+    BaseComponentProps, ClassBuilder, ClickEvent, CustomEvent, apply_root_attributes,
+};
+use crate::modal::{Modal, ModalDismissDetail, ModalSize};
+use crate::radio_group::{RadioGroup, RadioGroupChangeDetail, RadioGroupItem};
+use crate::space_between::{SpaceBetween, SpaceBetweenDirection, SpaceBetweenSize};
+use std::cell::RefCell;
+use std::rc::Rc;
+use yew::prelude::*;
+
+/// Size change of the panel per arrow key press on its resize handle
+const KEYBOARD_RESIZE_STEP: u32 = 10;
+
+/// Gets the size of a panel without a size preference, in pixels
+pub(crate) fn default_split_panel_size(position: SplitPanelPosition) -> u32 {
+    match position {
+        SplitPanelPosition::Bottom => 280,
+        SplitPanelPosition::Side => 320,
+    }
+}
+
+/// Smallest and largest size the panel can be resized to, in pixels
+fn size_limits(position: SplitPanelPosition) -> (u32, u32) {
+    match position {
+        SplitPanelPosition::Bottom => (160, 800),
+        SplitPanelPosition::Side => (280, 800),
+    }
+}
+
+/// Clamps a size to the limits of the position
+fn clamp_size(position: SplitPanelPosition, size: f64) -> u32 {
+    let (min, max) = size_limits(position);
+    (size.round().max(0.0) as u32).clamp(min, max)
+}
+
+/// Gets the size after dragging the handle by `delta` pixels along the
+/// resize axis: down for a bottom panel, right for a side panel
+///
+/// The handle sits on the edge facing the content, so dragging it toward
+/// the content grows the panel.
+fn dragged_size(position: SplitPanelPosition, start_size: u32, delta: f64) -> u32 {
+    clamp_size(position, f64::from(start_size) - delta)
+}
+
+/// Gets the size after a key press on the handle, or `None` for keys that
+/// don't resize
+fn keyboard_size(position: SplitPanelPosition, size: u32, key: &str) -> Option<u32> {
+    let (min, max) = size_limits(position);
+    let step = f64::from(KEYBOARD_RESIZE_STEP);
+    let grow = match (position, key) {
+        (SplitPanelPosition::Bottom, "ArrowUp") | (SplitPanelPosition::Side, "ArrowLeft") => true,
+        (SplitPanelPosition::Bottom, "ArrowDown") | (SplitPanelPosition::Side, "ArrowRight") => {
+            false
+        }
+        (_, "Home") => return Some(min),
+        (_, "End") => return Some(max),
+        _ => return None,
+    };
+    let size = f64::from(size);
+    Some(clamp_size(
+        position,
+        if grow { size + step } else { size - step },
+    ))
+}
+
+/// State and callbacks an AppLayout shares with the SplitPanel in its slot
+#[derive(Clone, PartialEq)]
+pub(crate) struct SplitPanelContext {
+    pub open: bool,
+    pub position: SplitPanelPosition,
+    pub size: u32,
+    pub on_toggle: Callback<()>,
+    pub on_resize: Callback<u32>,
+    pub on_preferences_change: Callback<SplitPanelPreferences>,
+}
+
+/// Internationalization strings for SplitPanel
+///
+/// Unset strings fall back to the closest I18nProvider (keys under
+/// `"split-panel."`), then to English.
+#[derive(Clone, PartialEq, Default)]
+pub struct SplitPanelI18nStrings {
+    /// ARIA label of the button opening the panel (default: "Open panel")
+    pub open_button_aria_label: Option<String>,
+    /// ARIA label of the button closing the panel (default: "Close panel")
+    pub close_button_aria_label: Option<String>,
+    /// ARIA label of the resize handle (default: "Resize split panel")
+    pub resize_handle_aria_label: Option<String>,
+    /// Title of the preferences modal, also the preferences button label
+    /// (default: "Split panel preferences")
+    pub preferences_title: Option<String>,
+    /// Label of the position choice (default: "Split panel position")
+    pub preferences_position_label: Option<String>,
+    /// Description of the position choice
+    /// (default: "Choose the default split panel position for the service.")
+    pub preferences_position_description: Option<String>,
+    /// Label of the bottom position (default: "Bottom")
+    pub preferences_position_bottom: Option<String>,
+    /// Label of the side position (default: "Side")
+    pub preferences_position_side: Option<String>,
+    /// Text of the button applying the preferences (default: "Confirm")
+    pub preferences_confirm: Option<String>,
+    /// Text of the button closing the preferences unchanged (default: "Cancel")
+    pub preferences_cancel: Option<String>,
+}
+
+/// Strings used to render a SplitPanel after applying the fallback chain
+struct ResolvedStrings {
+    open_button_aria_label: String,
+    close_button_aria_label: String,
+    resize_handle_aria_label: String,
+    preferences_title: String,
+    preferences_position_label: String,
+    preferences_position_description: String,
+    preferences_position_bottom: String,
+    preferences_position_side: String,
+    preferences_confirm: String,
+    preferences_cancel: String,
+}
+
+/// Resolves the strings: `i18n_strings`, then the I18nProvider, then English
+fn resolve_strings(strings: &SplitPanelI18nStrings, i18n: &I18nContext) -> ResolvedStrings {
+    let resolve = |value: &Option<String>, key: &str, default: &str| {
+        value
+            .clone()
+            .or_else(|| i18n.message("split-panel", key).map(str::to_string))
+            .unwrap_or_else(|| default.to_string())
+    };
+
+    ResolvedStrings {
+        open_button_aria_label: resolve(
+            &strings.open_button_aria_label,
+            "open_button_aria_label",
+            "Open panel",
+        ),
+        close_button_aria_label: resolve(
+            &strings.close_button_aria_label,
+            "close_button_aria_label",
+            "Close panel",
+        ),
+        resize_handle_aria_label: resolve(
+            &strings.resize_handle_aria_label,
+            "resize_handle_aria_label",
+            "Resize split panel",
+        ),
+        preferences_title: resolve(
+            &strings.preferences_title,
+            "preferences_title",
+            "Split panel preferences",
+        ),
+        preferences_position_label: resolve(
+            &strings.preferences_position_label,
+            "preferences_position_label",
+            "Split panel position",
+        ),
+        preferences_position_description: resolve(
+            &strings.preferences_position_description,
+            "preferences_position_description",
+            "Choose the default split panel position for the service.",
+        ),
+        preferences_position_bottom: resolve(
+            &strings.preferences_position_bottom,
+            "preferences_position_bottom",
+            "Bottom",
+        ),
+        preferences_position_side: resolve(
+            &strings.preferences_position_side,
+            "preferences_position_side",
+            "Side",
+        ),
+        preferences_confirm: resolve(
+            &strings.preferences_confirm,
+            "preferences_confirm",
+            "Confirm",
+        ),
+        preferences_cancel: resolve(&strings.preferences_cancel, "preferences_cancel", "Cancel"),
+    }
+}
+
+/// Properties for the SplitPanel component
+#[derive(Properties, PartialEq, Clone)]
+pub struct SplitPanelProps {
+    /// Base component properties
+    #[prop_or_default]
+    pub base: BaseComponentProps,
+
+    /// Header text of the panel
+    pub header: String,
+
+    /// Whether to hide the button opening the preferences modal
+    #[prop_or_default]
+    pub hide_preferences_button: bool,
+
+    /// Internationalization strings
+    #[prop_or_default]
+    pub i18n_strings: SplitPanelI18nStrings,
+
+    /// Panel content
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// SplitPanel component for secondary content in an AppLayout.
+///
+/// # Example
+///
+/// ```rust
+/// use cloudscape_components::{
+///     AppLayout, CustomEvent, SplitPanel, SplitPanelPreferences, SplitPanelResizeDetail,
+///     SplitPanelToggleDetail,
+/// };
+/// use yew::prelude::*;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let open = use_state(|| true);
+///     let preferences = use_state(SplitPanelPreferences::new);
+///
+///     let on_toggle = {
+///         let open = open.clone();
+///         Callback::from(move |event: CustomEvent<SplitPanelToggleDetail>| {
+///             open.set(event.detail.open);
+///         })
+///     };
+///     let on_resize = {
+///         let preferences = preferences.clone();
+///         Callback::from(move |event: CustomEvent<SplitPanelResizeDetail>| {
+///             preferences.set((*preferences).clone().with_size(event.detail.size));
+///         })
+///     };
+///     let on_preferences_change = {
+///         let preferences = preferences.clone();
+///         Callback::from(move |event: CustomEvent<SplitPanelPreferences>| {
+///             preferences.set(event.detail);
+///         })
+///     };
+///
+///     html! {
+///         <AppLayout
+///             split_panel_open={*open}
+///             split_panel_preferences={(*preferences).clone()}
+///             on_split_panel_toggle={on_toggle}
+///             on_split_panel_resize={on_resize}
+///             on_split_panel_preferences_change={on_preferences_change}
+///             split_panel={html! {
+///                 <SplitPanel header="Instance details">
+///                     {"Details of the selected instance"}
+///                 </SplitPanel>
+///             }}
+///         >
+///             <h1>{"Instances"}</h1>
+///         </AppLayout>
+///     }
+/// }
+/// ```
+#[function_component(SplitPanel)]
+pub fn split_panel(props: &SplitPanelProps) -> Html {
+    let context = use_context::<SplitPanelContext>();
+    let preferences_visible = use_state(|| false);
+    let draft_position = use_state(SplitPanelPosition::default);
+    let drag = use_mut_ref(|| None::<HandleDrag>);
+
+    let i18n = use_i18n();
+    let strings = resolve_strings(&props.i18n_strings, &i18n);
+    let open = context.as_ref().is_none_or(|context| context.open);
+    let position = context
+        .as_ref()
+        .map_or(SplitPanelPosition::Bottom, |context| context.position);
+
+    let classes = ClassBuilder::new()
+        .add("awsui-split-panel")
+        .add(format!("awsui-split-panel-position-{}", position.as_str()))
+        .add_if(open, "awsui-split-panel-open")
+        .add_if(!open, "awsui-split-panel-closed");
+
+    // Toggle, resize handle and preferences only exist inside an AppLayout
+    let controls = context.map(|context| {
+        let on_toggle_click = {
+            let on_toggle = context.on_toggle.clone();
+            Callback::from(move |_: MouseEvent| on_toggle.emit(()))
+        };
+
+        let on_preferences_click = {
+            let preferences_visible = preferences_visible.clone();
+            let draft_position = draft_position.clone();
+            Callback::from(move |_: MouseEvent| {
+                draft_position.set(position);
+                preferences_visible.set(true);
+            })
+        };
+
+        let resize_handle = open.then(|| {
+            render_resize_handle(&context, &drag, &strings.resize_handle_aria_label)
+        });
+
+        let preferences_modal = (!props.hide_preferences_button).then(|| {
+            render_preferences_modal(
+                &context,
+                &strings,
+                &preferences_visible,
+                &draft_position,
+            )
+        });
+
+        html! {
+            <>
+                { for resize_handle }
+                if open && !props.hide_preferences_button {
+                    <button
+                        type="button"
+                        class="awsui-split-panel-preferences-button awsui-button awsui-button-variant-icon"
+                        aria-label={strings.preferences_title.clone()}
+                        aria-haspopup="dialog"
+                        onclick={on_preferences_click}
+                    >
+                        <span class="awsui-icon awsui-icon-settings" aria-hidden="true" />
+                    </button>
+                }
+                <button
+                    type="button"
+                    class="awsui-split-panel-toggle awsui-button awsui-button-variant-icon"
+                    aria-label={if open {
+                        strings.close_button_aria_label.clone()
+                    } else {
+                        strings.open_button_aria_label.clone()
+                    }}
+                    aria-expanded={open.to_string()}
+                    onclick={on_toggle_click}
+                >
+                    <span
+                        class={match (position, open) {
+                            (SplitPanelPosition::Bottom, true) => "awsui-icon awsui-icon-angle-down",
+                            (SplitPanelPosition::Bottom, false) => "awsui-icon awsui-icon-angle-up",
+                            (SplitPanelPosition::Side, true) => "awsui-icon awsui-icon-angle-right",
+                            (SplitPanelPosition::Side, false) => "awsui-icon awsui-icon-angle-left",
+                        }}
+                        aria-hidden="true"
+                    />
+                </button>
+                { for preferences_modal }
+            </>
+        }
+    });
+
+    let root = html! {
+        <div
+            id={props.base.id.clone()}
+            class={props.base.merge_classes(&classes.build())}
+        >
+            <div class="awsui-split-panel-header">
+                // A closed side panel only shows its toggle
+                if open || position == SplitPanelPosition::Bottom {
+                    <h2 class="awsui-split-panel-header-text">{ &props.header }</h2>
+                }
+                <div class="awsui-split-panel-header-actions">
+                    { for controls }
+                </div>
+            </div>
+
+            if open {
+                <div class="awsui-split-panel-content">
+                    { for props.children.iter() }
+                </div>
+            }
+        </div>
+    };
+    apply_root_attributes(root, &props.base)
+}
+
+/// Renders the handle on the edge of the panel facing the content
+fn render_resize_handle(
+    context: &SplitPanelContext,
+    drag: &Rc<RefCell<Option<HandleDrag>>>,
+    label: &str,
+) -> Html {
+    let position = context.position;
+    ResizeHandle {
+        class: "awsui-split-panel-resize-handle",
+        label,
+        orientation: match position {
+            SplitPanelPosition::Bottom => HandleOrientation::Horizontal,
+            SplitPanelPosition::Side => HandleOrientation::Vertical,
+        },
+        size: context.size,
+        limits: size_limits(position),
+        drag,
+        on_resize: &context.on_resize,
+    }
+    .render(
+        move |start_size, delta| dragged_size(position, start_size, delta),
+        move |size, key| keyboard_size(position, size, key),
+    )
+}
+
+/// Renders the modal choosing the position of the panel
+///
+/// Confirming a new position drops the size preference, since sizes of the
+/// bottom and the side don't carry over.
+fn render_preferences_modal(
+    context: &SplitPanelContext,
+    strings: &ResolvedStrings,
+    visible: &UseStateHandle<bool>,
+    draft_position: &UseStateHandle<SplitPanelPosition>,
+) -> Html {
+    let close = {
+        let visible = visible.clone();
+        Callback::from(move |_: ()| visible.set(false))
+    };
+
+    let confirm = {
+        let visible = visible.clone();
+        let draft_position = draft_position.clone();
+        let current = context.position;
+        let on_preferences_change = context.on_preferences_change.clone();
+        Callback::from(move |_: ()| {
+            visible.set(false);
+            if *draft_position != current {
+                on_preferences_change
+                    .emit(SplitPanelPreferences::new().with_position(*draft_position));
+            }
+        })
+    };
+
+    let on_position_change = {
+        let draft_position = draft_position.clone();
+        Callback::from(move |event: CustomEvent<RadioGroupChangeDetail>| {
+            draft_position.set(if event.detail.value == "side" {
+                SplitPanelPosition::Side
+            } else {
+                SplitPanelPosition::Bottom
+            });
+        })
+    };
+
+    let footer = html! {
+        <SpaceBetween direction={SpaceBetweenDirection::Horizontal} size={SpaceBetweenSize::Xs}>
+            <Button
+                variant={ButtonVariant::Link}
+                form_action={FormAction::None}
+                on_click={close.reform(|_: ClickEvent| ())}
+            >
+                { Html::from(strings.preferences_cancel.clone()) }
+            </Button>
+            <Button
+                variant={ButtonVariant::Primary}
+                form_action={FormAction::None}
+                on_click={confirm.reform(|_: ClickEvent| ())}
+            >
+                { Html::from(strings.preferences_confirm.clone()) }
+            </Button>
+        </SpaceBetween>
+    };
+
+    html! {
+        <Modal
+            visible={**visible}
+            size={ModalSize::Small}
+            header={strings.preferences_title.clone()}
+            footer={footer}
+            on_dismiss={close.reform(|_: CustomEvent<ModalDismissDetail>| ())}
+        >
+            <FormField
+                label={strings.preferences_position_label.clone()}
+                description={Html::from(strings.preferences_position_description.clone())}
+            >
+                <RadioGroup
+                    value={draft_position.as_str().to_string()}
+                    items={vec![
+                        RadioGroupItem::new("bottom", strings.preferences_position_bottom.clone()),
+                        RadioGroupItem::new("side", strings.preferences_position_side.clone()),
+                    ]}
+                    on_change={on_position_change}
+                />
+            </FormField>
+        </Modal>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dragged_size_grows_toward_content() {
+        // Dragging a bottom panel's handle up, or a side panel's handle
+        // left, grows the panel
+        assert_eq!(dragged_size(SplitPanelPosition::Bottom, 300, -50.0), 350);
+        assert_eq!(dragged_size(SplitPanelPosition::Side, 320, 30.0), 290);
+        assert_eq!(dragged_size(SplitPanelPosition::Bottom, 300, 500.0), 160);
+        assert_eq!(dragged_size(SplitPanelPosition::Side, 320, -900.0), 800);
+    }
+
+    #[test]
+    fn test_keyboard_size() {
+        let bottom = SplitPanelPosition::Bottom;
+        assert_eq!(keyboard_size(bottom, 300, "ArrowUp"), Some(310));
+        assert_eq!(keyboard_size(bottom, 300, "ArrowDown"), Some(290));
+        assert_eq!(keyboard_size(bottom, 300, "ArrowLeft"), None);
+        assert_eq!(keyboard_size(bottom, 300, "Home"), Some(160));
+
+        let side = SplitPanelPosition::Side;
+        assert_eq!(keyboard_size(side, 320, "ArrowLeft"), Some(330));
+        assert_eq!(keyboard_size(side, 285, "ArrowRight"), Some(280));
+        assert_eq!(keyboard_size(side, 320, "End"), Some(800));
+    }
+
+    #[test]
+    fn test_default_sizes() {
+        assert_eq!(default_split_panel_size(SplitPanelPosition::Bottom), 280);
+        assert_eq!(default_split_panel_size(SplitPanelPosition::Side), 320);
+    }
+
+    #[test]
+    fn test_resolve_strings() {
+        let strings = resolve_strings(&SplitPanelI18nStrings::default(), &I18nContext::default());
+        assert_eq!(strings.open_button_aria_label, "Open panel");
+        assert_eq!(strings.preferences_position_side, "Side");
+
+        let mut messages = crate::internal::I18nStrings::new();
+        messages.set("split-panel.preferences_position_side", "Seite");
+        messages.set("split-panel.open_button_aria_label", "Bereich öffnen");
+        let i18n = I18nContext {
+            locale: Some("de-DE".to_string()),
+            messages,
+        };
+        let strings = resolve_strings(
+            &SplitPanelI18nStrings {
+                open_button_aria_label: Some("Panel öffnen".to_string()),
+                ..Default::default()
+            },
+            &i18n,
+        );
+        assert_eq!(strings.open_button_aria_label, "Panel öffnen");
+        assert_eq!(strings.preferences_position_side, "Seite");
+        assert_eq!(strings.preferences_cancel, "Cancel");
+    }
+}
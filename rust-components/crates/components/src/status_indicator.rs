@@ -4,10 +4,14 @@
 //! StatusIndicator component
 //!
 //! Status badges with color variants and optional icons to communicate status information.
+//! An optional popover shows details about the status on hover and focus.
 
+use crate::internal::busy::use_register_busy;
 use crate::internal::{
     AnalyticsMetadata, AriaAttributes, BaseComponentProps, ClassBuilder, ComponentMetadata,
+    apply_root_attributes,
 };
+use crate::popover::{Popover, PopoverPosition, PopoverSize};
 use crate::spinner::{Spinner, SpinnerSize};
 use yew::prelude::*;
 
@@ -83,6 +87,25 @@ impl StatusIndicatorColor {
     }
 }
 
+/// Inline style of the status text when it wraps within its container
+const WRAP_STYLE: &str = "white-space: normal; overflow-wrap: break-word";
+
+/// Inline style of the status text when it stays on one line, truncated
+/// with an ellipsis
+const NO_WRAP_STYLE: &str = "display: inline-block; max-width: 100%; white-space: nowrap; \
+     overflow: hidden; text-overflow: ellipsis; vertical-align: top";
+
+/// Returns the inline style of the status text
+fn text_style(wrap_text: bool) -> &'static str {
+    if wrap_text { WRAP_STYLE } else { NO_WRAP_STYLE }
+}
+
+/// Whether the indicator is wrapped in a details popover; loading statuses
+/// never show one
+fn has_popover(status_type: StatusIndicatorType, popover_text: Option<&str>) -> bool {
+    status_type != StatusIndicatorType::Loading && popover_text.is_some_and(|text| !text.is_empty())
+}
+
 /// Properties for the StatusIndicator component
 #[derive(Properties, PartialEq, Clone)]
 pub struct StatusIndicatorProps {
@@ -102,6 +125,26 @@ pub struct StatusIndicatorProps {
     #[prop_or_default]
     pub icon_aria_label: Option<String>,
 
+    /// Custom icon shown instead of the status type's default icon
+    ///
+    /// The status type still determines the color and semantics.
+    #[prop_or_default]
+    pub icon_override: Option<Html>,
+
+    /// Details shown in a popover when the indicator is hovered or focused
+    ///
+    /// The popover closes on Escape. It is not shown for
+    /// [`StatusIndicatorType::Loading`].
+    #[prop_or_default]
+    pub popover_text: Option<String>,
+
+    /// Whether the status text wraps onto multiple lines
+    ///
+    /// When `false`, the text stays on one line and is truncated with an
+    /// ellipsis. Defaults to `true`.
+    #[prop_or(true)]
+    pub wrap_text: bool,
+
     /// ARIA attributes
     #[prop_or_default]
     pub aria: AriaAttributes,
@@ -119,20 +162,25 @@ pub struct StatusIndicatorProps {
 /// # Example
 ///
 /// ```rust
+/// # use yew::prelude::*;
 /// use cloudscape_components::{StatusIndicator, StatusIndicatorType};
 ///
+/// # let _: Html =
 /// html! {
 ///     <StatusIndicator status_type={StatusIndicatorType::Success}>
 ///         { "Operational" }
 ///     </StatusIndicator>
 /// }
+/// # ;
 /// ```
 ///
 /// # With color override
 ///
 /// ```rust
+/// # use yew::prelude::*;
 /// use cloudscape_components::{StatusIndicator, StatusIndicatorType, StatusIndicatorColor};
 ///
+/// # let _: Html =
 /// html! {
 ///     <StatusIndicator
 ///         status_type={StatusIndicatorType::Info}
@@ -141,13 +189,34 @@ pub struct StatusIndicatorProps {
 ///         { "Custom status" }
 ///     </StatusIndicator>
 /// }
+/// # ;
+/// ```
+///
+/// # With details popover
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// use cloudscape_components::{StatusIndicator, StatusIndicatorType};
+///
+/// # let _: Html =
+/// html! {
+///     <StatusIndicator
+///         status_type={StatusIndicatorType::Error}
+///         popover_text={Some("Instance limit exceeded".to_string())}
+///     >
+///         { "Failed" }
+///     </StatusIndicator>
+/// }
+/// # ;
 /// ```
 ///
 /// # With custom icon label
 ///
 /// ```rust
+/// # use yew::prelude::*;
 /// use cloudscape_components::{StatusIndicator, StatusIndicatorType};
 ///
+/// # let _: Html =
 /// html! {
 ///     <StatusIndicator
 ///         status_type={StatusIndicatorType::Error}
@@ -156,10 +225,14 @@ pub struct StatusIndicatorProps {
 ///         { "Service unavailable" }
 ///     </StatusIndicator>
 /// }
+/// # ;
 /// ```
 #[function_component(StatusIndicator)]
 pub fn status_indicator(props: &StatusIndicatorProps) -> Html {
     let _metadata = ComponentMetadata::new("StatusIndicator");
+    use_register_busy(props.status_type == StatusIndicatorType::Loading);
+    let popover_open = use_state_eq(|| false);
+    let with_popover = has_popover(props.status_type, props.popover_text.as_deref());
 
     // Build CSS classes
     let mut classes = ClassBuilder::new()
@@ -167,7 +240,8 @@ pub fn status_indicator(props: &StatusIndicatorProps) -> Html {
         .add(format!(
             "awsui-status-indicator-type-{}",
             props.status_type.as_str()
-        ));
+        ))
+        .add_if(!props.wrap_text, "awsui-status-indicator-overflow-ellipsis");
 
     // Add color override class if specified
     if let Some(color) = props.color_override {
@@ -187,7 +261,9 @@ pub fn status_indicator(props: &StatusIndicatorProps) -> Html {
     let analytics_attr = serde_json::to_string(&analytics).ok();
 
     // Render the appropriate icon based on status type
-    let icon_content = if props.status_type == StatusIndicatorType::Loading {
+    let icon_content = if let Some(ref icon) = props.icon_override {
+        icon.clone()
+    } else if props.status_type == StatusIndicatorType::Loading {
         html! {
             <Spinner size={SpinnerSize::Normal} />
         }
@@ -200,13 +276,13 @@ pub fn status_indicator(props: &StatusIndicatorProps) -> Html {
         }
     };
 
-    html! {
+    let indicator = html! {
         <span
             id={props.base.id.clone()}
             class={class}
             data-analytics-metadata={analytics_attr}
         >
-            <span class="awsui-status-indicator-container">
+            <span class="awsui-status-indicator-container" style={text_style(props.wrap_text)}>
                 <span
                     class="awsui-status-indicator-icon"
                     aria-label={props.icon_aria_label.clone()}
@@ -217,6 +293,52 @@ pub fn status_indicator(props: &StatusIndicatorProps) -> Html {
                 { props.children.clone() }
             </span>
         </span>
+    };
+    let indicator = apply_root_attributes(indicator, &props.base);
+
+    if !with_popover {
+        return indicator;
+    }
+
+    // The popover follows hover and focus of the trigger and closes on Escape
+    let set_open = {
+        let popover_open = popover_open.clone();
+        move |open: bool| {
+            let popover_open = popover_open.clone();
+            Callback::from(move |_: ()| popover_open.set(open))
+        }
+    };
+    let on_keydown = {
+        let popover_open = popover_open.clone();
+        Callback::from(move |e: KeyboardEvent| {
+            if e.key() == "Escape" && *popover_open {
+                e.stop_propagation();
+                popover_open.set(false);
+            }
+        })
+    };
+    let popover_text = props.popover_text.clone().unwrap_or_default();
+
+    html! {
+        <Popover
+            visible={Some(*popover_open)}
+            size={PopoverSize::Small}
+            position={PopoverPosition::Top}
+            trigger_type={Some("text".to_string())}
+            content={html! { { popover_text } }}
+        >
+            <span
+                class="awsui-status-indicator-popover-trigger"
+                tabindex="0"
+                onmouseenter={set_open(true).reform(|_: MouseEvent| ())}
+                onmouseleave={set_open(false).reform(|_: MouseEvent| ())}
+                onfocus={set_open(true).reform(|_: FocusEvent| ())}
+                onblur={set_open(false).reform(|_: FocusEvent| ())}
+                onkeydown={on_keydown}
+            >
+                { indicator }
+            </span>
+        </Popover>
     }
 }
 
@@ -290,7 +412,7 @@ mod tests {
 
     #[test]
     fn all_status_types_have_unique_strings() {
-        let types = vec![
+        let types = [
             StatusIndicatorType::Success,
             StatusIndicatorType::Error,
             StatusIndicatorType::Warning,
@@ -313,6 +435,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn popover_requires_text_and_non_loading_status() {
+        assert!(has_popover(
+            StatusIndicatorType::Error,
+            Some("Quota exceeded")
+        ));
+        assert!(!has_popover(StatusIndicatorType::Error, None));
+        assert!(!has_popover(StatusIndicatorType::Error, Some("")));
+        assert!(!has_popover(
+            StatusIndicatorType::Loading,
+            Some("Fetching instances")
+        ));
+    }
+
+    #[test]
+    fn text_style_wraps_by_default() {
+        assert_eq!(text_style(true), WRAP_STYLE);
+        assert!(text_style(false).contains("text-overflow: ellipsis"));
+        assert!(text_style(false).contains("white-space: nowrap"));
+    }
+
     #[test]
     fn all_status_types_have_icons() {
         let types = vec![
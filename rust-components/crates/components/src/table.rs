@@ -6,8 +6,28 @@
 //! A data table component that supports column definitions, row data, sorting,
 //! selection (single or multiple), loading states, empty states, and pagination.
 
-use crate::internal::{BaseComponentProps, ClassBuilder, ComponentMetadata, CustomEvent};
-use web_sys::MouseEvent;
+use crate::app_layout::StickyOffset;
+use crate::button::{Button, ButtonVariant, FormAction};
+use crate::checkbox::{Checkbox, CheckboxChangeDetail};
+use crate::i18n::{I18nContext, use_i18n};
+use crate::input::{Input, InputChangeDetail, InputType};
+use crate::internal::busy::use_register_busy;
+use crate::internal::dev_warnings::{DevWarning, unknown_style_parts, use_dev_warnings};
+use crate::internal::events::is_interactive_target;
+use crate::internal::live_region::VISUALLY_HIDDEN_STYLE;
+use crate::internal::resize::dragged_size;
+use crate::internal::{
+    AriaAttributes, BaseComponentProps, ClassBuilder, ComponentMetadata, CustomEvent, LiveRegion,
+    apply_root_attributes,
+};
+use crate::pagination::{Pagination, PaginationChangeDetail};
+use gloo::events::EventListener;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+use std::rc::Rc;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement, MouseEvent, PointerEvent};
 use yew::prelude::*;
 
 /// Direction for sorting
@@ -46,6 +66,15 @@ pub struct TableSortDetail {
     pub direction: SortDirection,
 }
 
+/// Event detail for column width change events
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnWidthsChangeDetail {
+    /// Widths set by the user in pixels, keyed by column ID
+    ///
+    /// Columns without an entry have their default width.
+    pub widths: HashMap<String, f64>,
+}
+
 /// Type of selection supported by the table
 #[derive(Debug, Clone, PartialEq, Copy)]
 pub enum SelectionType {
@@ -55,11 +84,683 @@ pub enum SelectionType {
     Multi,
 }
 
+/// How the header checkbox of a multi-selection table selects items
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SelectAllMode {
+    /// Selects the items on the current page
+    #[default]
+    Page,
+    /// Selects the current page, then offers selecting every item matching
+    /// the current filter, across pages
+    ///
+    /// Requires `track_by` and `total_items_count`.
+    AllMatching,
+}
+
+/// Which items a multi-selection table has selected
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum SelectionState {
+    /// The items listed in `selected_items`
+    #[default]
+    Items,
+    /// Every item matching the current filter, across pages, except the
+    /// items whose `track_by` keys are excluded
+    AllMatching {
+        /// Keys of the items the user unchecked
+        excluded: Vec<String>,
+    },
+}
+
+/// How the table shows that it is loading
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TableLoadingVariant {
+    /// A single row with a spinner and the loading text
+    #[default]
+    Spinner,
+    /// Placeholder rows matching the column layout, so the table keeps its
+    /// size when the items arrive
+    Skeleton {
+        /// Number of placeholder rows (at least one)
+        rows: usize,
+    },
+}
+
 /// Event detail for selection change events
 #[derive(Debug, Clone, PartialEq)]
 pub struct TableSelectionDetail<T: Clone + PartialEq> {
     /// List of selected items
+    ///
+    /// When all matching items are selected, lists the selected items of
+    /// the current page.
     pub selected_items: Vec<T>,
+    /// Which items are selected
+    pub selection_state: SelectionState,
+}
+
+/// Built-in client-side pagination of a Table
+///
+/// The table shows one page of `items` and renders a Pagination in its
+/// footer. The current page is controlled: the table requests page changes
+/// through `on_page_change`, including when the items shrink below the
+/// current page and, back to the first page, when the sorting changes.
+#[derive(Clone, PartialEq)]
+pub struct TablePaginationConfig {
+    /// Number of items per page
+    pub page_size: usize,
+    /// Index of the current page (1-indexed)
+    pub current_page: u32,
+    /// Callback fired when the table requests another page
+    pub on_page_change: Option<Callback<CustomEvent<PaginationChangeDetail>>>,
+}
+
+impl TablePaginationConfig {
+    /// Creates a pagination showing `page_size` items per page, starting on
+    /// `current_page`
+    pub fn new(page_size: usize, current_page: u32) -> Self {
+        Self {
+            page_size,
+            current_page,
+            on_page_change: None,
+        }
+    }
+
+    /// Sets the callback fired when the table requests another page
+    pub fn with_on_page_change(
+        mut self,
+        on_page_change: Callback<CustomEvent<PaginationChangeDetail>>,
+    ) -> Self {
+        self.on_page_change = Some(on_page_change);
+        self
+    }
+}
+
+/// Number of pages needed for `item_count` items, at least one
+fn pages_count(item_count: usize, page_size: usize) -> u32 {
+    item_count.div_ceil(page_size.max(1)).max(1) as u32
+}
+
+/// Range of the items shown on a page, given a page index within bounds
+fn page_range(item_count: usize, page_size: usize, page: u32) -> Range<usize> {
+    let page_size = page_size.max(1);
+    let start = ((page.max(1) - 1) as usize * page_size).min(item_count);
+    start..(start + page_size).min(item_count)
+}
+
+/// Page the table requests instead of `requested`: the first page after the
+/// sorting changed, or the last page when the items shrank below it
+fn page_correction(requested: u32, pages_count: u32, sorting_changed: bool) -> Option<u32> {
+    let page = if sorting_changed {
+        1
+    } else {
+        requested.clamp(1, pages_count)
+    };
+    (page != requested).then_some(page)
+}
+
+/// Event detail for expandable row toggle events
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExpandToggleDetail<T: Clone + PartialEq> {
+    /// Item whose row was expanded or collapsed
+    pub item: T,
+    /// Whether the row is requested to be expanded
+    pub expanded: bool,
+}
+
+/// Hierarchical rows of a Table
+///
+/// Items for which `is_expandable` returns true get a toggle in their first
+/// cell; expanding them shows the items returned by `get_item_children`
+/// indented beneath. Expansion is controlled: the table requests changes
+/// through `on_expandable_item_toggle`. Items are matched to
+/// `expanded_items` by the table's `track_by` key when set.
+#[derive(Clone)]
+pub struct ExpandableRowsConfig<T: Clone + PartialEq + 'static> {
+    /// Function returning the loaded children of an item
+    pub get_item_children: fn(&T) -> Vec<T>,
+    /// Function returning whether an item can be expanded
+    pub is_expandable: fn(&T) -> bool,
+    /// Currently expanded items (controlled)
+    pub expanded_items: Vec<T>,
+    /// Callback fired when the user expands or collapses a row
+    pub on_expandable_item_toggle: Option<Callback<CustomEvent<ExpandToggleDetail<T>>>>,
+}
+
+impl<T: Clone + PartialEq + 'static> ExpandableRowsConfig<T> {
+    /// Creates a configuration with every row collapsed
+    pub fn new(get_item_children: fn(&T) -> Vec<T>, is_expandable: fn(&T) -> bool) -> Self {
+        Self {
+            get_item_children,
+            is_expandable,
+            expanded_items: Vec::new(),
+            on_expandable_item_toggle: None,
+        }
+    }
+
+    /// Sets the expanded items
+    pub fn with_expanded_items(mut self, expanded_items: Vec<T>) -> Self {
+        self.expanded_items = expanded_items;
+        self
+    }
+
+    /// Sets the callback fired when the user expands or collapses a row
+    pub fn with_on_expandable_item_toggle(
+        mut self,
+        on_expandable_item_toggle: Callback<CustomEvent<ExpandToggleDetail<T>>>,
+    ) -> Self {
+        self.on_expandable_item_toggle = Some(on_expandable_item_toggle);
+        self
+    }
+}
+
+#[allow(unpredictable_function_pointer_comparisons)]
+impl<T: Clone + PartialEq + 'static> PartialEq for ExpandableRowsConfig<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.get_item_children == other.get_item_children
+            && self.is_expandable == other.is_expandable
+            && self.expanded_items == other.expanded_items
+            && self.on_expandable_item_toggle == other.on_expandable_item_toggle
+    }
+}
+
+/// Indentation of each level of expandable rows, in pixels
+const ROW_LEVEL_INDENT: usize = 20;
+
+/// Row of the table body, with its position in the hierarchy
+#[derive(Debug, Clone, PartialEq)]
+struct TableRow<T> {
+    item: T,
+    /// Level in the hierarchy, 1 for top-level items
+    level: usize,
+    /// Number of siblings, including the item
+    set_size: usize,
+    /// Position among the siblings (1-indexed)
+    pos_in_set: usize,
+    expandable: bool,
+    expanded: bool,
+}
+
+/// Lists the rows to render: each item, followed by the rows of its
+/// children while it is expanded
+///
+/// Children of collapsed items are hidden with all their descendants. An
+/// item listing one of its ancestors as a child can't be expanded again, so
+/// cyclic data stays finite.
+fn table_rows<T: Clone + PartialEq + 'static>(
+    items: &[T],
+    expandable_rows: Option<&ExpandableRowsConfig<T>>,
+    track_by: Option<fn(&T) -> String>,
+) -> Vec<TableRow<T>> {
+    fn push_rows<T: Clone + PartialEq + 'static>(
+        rows: &mut Vec<TableRow<T>>,
+        items: &[T],
+        level: usize,
+        ancestors: &mut Vec<T>,
+        config: &ExpandableRowsConfig<T>,
+        track_by: Option<fn(&T) -> String>,
+    ) {
+        for (index, item) in items.iter().enumerate() {
+            let expandable = (config.is_expandable)(item);
+            let expanded = expandable && is_item_selected(item, &config.expanded_items, track_by);
+            rows.push(TableRow {
+                item: item.clone(),
+                level,
+                set_size: items.len(),
+                pos_in_set: index + 1,
+                expandable,
+                expanded,
+            });
+            if expanded && !is_item_selected(item, ancestors, track_by) {
+                ancestors.push(item.clone());
+                let children = (config.get_item_children)(item);
+                push_rows(rows, &children, level + 1, ancestors, config, track_by);
+                ancestors.pop();
+            }
+        }
+    }
+
+    match expandable_rows {
+        Some(config) => {
+            let mut rows = Vec::new();
+            push_rows(&mut rows, items, 1, &mut Vec::new(), config, track_by);
+            rows
+        }
+        None => items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| TableRow {
+                item: item.clone(),
+                level: 1,
+                set_size: items.len(),
+                pos_in_set: index + 1,
+                expandable: false,
+                expanded: false,
+            })
+            .collect(),
+    }
+}
+
+/// Lists an item followed by all its loaded descendants, expanded or not
+fn item_with_descendants<T: Clone + PartialEq + 'static>(
+    item: &T,
+    expandable_rows: Option<&ExpandableRowsConfig<T>>,
+    track_by: Option<fn(&T) -> String>,
+) -> Vec<T> {
+    let mut family = vec![item.clone()];
+    let Some(config) = expandable_rows else {
+        return family;
+    };
+    let mut index = 0;
+    while index < family.len() {
+        let parent = family[index].clone();
+        if (config.is_expandable)(&parent) {
+            for child in (config.get_item_children)(&parent) {
+                if !is_item_selected(&child, &family, track_by) {
+                    family.push(child);
+                }
+            }
+        }
+        index += 1;
+    }
+    family
+}
+
+/// Event detail for filter change events
+#[derive(Debug, Clone, PartialEq)]
+pub struct FilterChangeDetail {
+    /// The new filtering text
+    pub filtering_text: String,
+}
+
+/// Built-in text filter of a Table
+///
+/// The table renders a search input above the rows and shows the items for
+/// which `filtering_fn` returns true. The filtering text is controlled: the
+/// table requests changes through `on_filter_change`.
+#[derive(Clone)]
+pub struct TextFilterConfig<T: Clone + PartialEq + 'static> {
+    /// Function returning whether an item matches the filtering text, which
+    /// is never empty
+    pub filtering_fn: fn(&T, &str) -> bool,
+    /// Current filtering text
+    pub filtering_text: String,
+    /// Placeholder of the filter input
+    pub placeholder: Option<String>,
+    /// Callback fired when the user changes or clears the filtering text
+    pub on_filter_change: Option<Callback<CustomEvent<FilterChangeDetail>>>,
+}
+
+impl<T: Clone + PartialEq + 'static> TextFilterConfig<T> {
+    /// Creates a filter matching items with `filtering_fn`
+    pub fn new(filtering_fn: fn(&T, &str) -> bool, filtering_text: impl Into<String>) -> Self {
+        Self {
+            filtering_fn,
+            filtering_text: filtering_text.into(),
+            placeholder: None,
+            on_filter_change: None,
+        }
+    }
+
+    /// Sets the placeholder of the filter input
+    pub fn with_placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Sets the callback fired when the user changes the filtering text
+    pub fn with_on_filter_change(
+        mut self,
+        on_filter_change: Callback<CustomEvent<FilterChangeDetail>>,
+    ) -> Self {
+        self.on_filter_change = Some(on_filter_change);
+        self
+    }
+
+    /// Whether an item matches the filtering text; every item matches an
+    /// empty text
+    pub fn matches(&self, item: &T) -> bool {
+        self.filtering_text.is_empty() || (self.filtering_fn)(item, &self.filtering_text)
+    }
+
+    /// Number of items matching the filtering text, such as for the counter
+    /// of the table header
+    pub fn matches_count(&self, items: &[T]) -> usize {
+        items.iter().filter(|item| self.matches(item)).count()
+    }
+}
+
+#[allow(unpredictable_function_pointer_comparisons)]
+impl<T: Clone + PartialEq + 'static> PartialEq for TextFilterConfig<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.filtering_fn == other.filtering_fn
+            && self.filtering_text == other.filtering_text
+            && self.placeholder == other.placeholder
+            && self.on_filter_change == other.on_filter_change
+    }
+}
+
+/// Severity or status conveyed by a row accent
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RowSeverity {
+    /// Critical severity
+    Critical,
+    /// High severity
+    High,
+    /// Medium severity
+    Medium,
+    /// Low severity
+    Low,
+    /// Informational status
+    Info,
+    /// Success status
+    Success,
+}
+
+impl RowSeverity {
+    /// Returns the CSS class name for this severity
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Critical => "critical",
+            Self::High => "high",
+            Self::Medium => "medium",
+            Self::Low => "low",
+            Self::Info => "info",
+            Self::Success => "success",
+        }
+    }
+
+    /// Class of rows with this severity, static so that rendering rows does
+    /// not allocate it
+    fn row_class(&self) -> &'static str {
+        match self {
+            Self::Critical => "awsui-table-row-accent-critical",
+            Self::High => "awsui-table-row-accent-high",
+            Self::Medium => "awsui-table-row-accent-medium",
+            Self::Low => "awsui-table-row-accent-low",
+            Self::Info => "awsui-table-row-accent-info",
+            Self::Success => "awsui-table-row-accent-success",
+        }
+    }
+
+    /// Color of the accent bar on the row's leading edge
+    pub fn accent_color(&self) -> &'static str {
+        match self {
+            Self::Critical => "var(--awsui-color-background-notification-severity-critical)",
+            Self::High => "var(--awsui-color-background-notification-severity-high)",
+            Self::Medium => "var(--awsui-color-background-notification-severity-medium)",
+            Self::Low => "var(--awsui-color-background-notification-severity-low)",
+            Self::Info => "var(--awsui-color-border-status-info)",
+            Self::Success => "var(--awsui-color-border-status-success)",
+        }
+    }
+
+    /// Tinted row background
+    ///
+    /// Uses the status backgrounds, which are checked for contrast with
+    /// status text in both color modes and are lighter than the text colors
+    /// cells use.
+    pub fn background_color(&self) -> &'static str {
+        match self {
+            Self::Critical | Self::High => "var(--awsui-color-background-status-error)",
+            Self::Medium | Self::Low => "var(--awsui-color-background-status-warning)",
+            Self::Info => "var(--awsui-color-background-status-info)",
+            Self::Success => "var(--awsui-color-background-status-success)",
+        }
+    }
+}
+
+/// Conditional styling of a table row, returned by `TableProps::row_style`
+#[derive(Debug, Clone, PartialEq)]
+pub struct RowStyle {
+    /// Severity shown by a 4px accent on the row's leading edge
+    pub severity: RowSeverity,
+    /// Whether the row background is tinted with the severity color
+    pub tinted_background: bool,
+    /// Text read by screen readers after the row's cells, conveying what the
+    /// accent shows
+    pub aria_description: Option<String>,
+}
+
+impl RowStyle {
+    /// Creates a row style with an accent and no tinted background
+    pub fn new(severity: RowSeverity) -> Self {
+        Self {
+            severity,
+            tinted_background: false,
+            aria_description: None,
+        }
+    }
+
+    /// Sets whether the row background is tinted
+    pub fn with_tinted_background(mut self, tinted_background: bool) -> Self {
+        self.tinted_background = tinted_background;
+        self
+    }
+
+    /// Sets the text read by screen readers after the row's cells
+    pub fn with_aria_description(mut self, aria_description: impl Into<String>) -> Self {
+        self.aria_description = Some(aria_description.into());
+        self
+    }
+}
+
+/// Row background layer, from highest to lowest precedence
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RowBackground {
+    /// Selection background from the selected row class
+    Selected,
+    /// Severity tint from the row style
+    Tinted(RowSeverity),
+    /// Alternate background of striped rows
+    Stripe,
+    /// Default background
+    Default,
+}
+
+/// Picks the background of a row; the severity accent is drawn regardless
+fn row_background(
+    style: Option<&RowStyle>,
+    selected: bool,
+    striped: bool,
+    index: usize,
+) -> RowBackground {
+    if selected {
+        RowBackground::Selected
+    } else if let Some(style) = style.filter(|style| style.tinted_background) {
+        RowBackground::Tinted(style.severity)
+    } else if striped && index % 2 == 1 {
+        RowBackground::Stripe
+    } else {
+        RowBackground::Default
+    }
+}
+
+/// Builds the style of a header cell pinned `offset` pixels from the top of
+/// the scrolling area, with a shadow once the body scrolls beneath it
+///
+/// The header background hides the rows scrolling under it, and the cells
+/// stay above positioned body cells.
+fn sticky_header_cell_style(offset: i32, stuck: bool, base: Option<String>) -> String {
+    let mut style = format!(
+        "position: sticky; top: {}px; z-index: 1; \
+         background-color: var(--awsui-color-background-table-header)",
+        offset
+    );
+    if stuck {
+        style.push_str("; box-shadow: var(--awsui-shadow-sticky-embedded)");
+    }
+    match base {
+        Some(base) => format!("{}; {}", base, style),
+        None => style,
+    }
+}
+
+/// Gets the nearest ancestor of an element that scrolls vertically, or
+/// `None` when the page scrolls
+fn scroll_parent(element: &Element) -> Option<Element> {
+    let window = web_sys::window()?;
+    let mut current = element.parent_element();
+    while let Some(ancestor) = current {
+        if matches!(ancestor.tag_name().as_str(), "BODY" | "HTML") {
+            return None;
+        }
+        let overflow = window
+            .get_computed_style(&ancestor)
+            .ok()
+            .flatten()
+            .and_then(|style| style.get_property_value("overflow-y").ok());
+        if matches!(overflow.as_deref(), Some("auto" | "scroll" | "overlay")) {
+            return Some(ancestor);
+        }
+        current = ancestor.parent_element();
+    }
+    None
+}
+
+/// Builds the style of the leading cell of a row, drawing the accent on the
+/// inline-start border so it follows the writing direction
+fn accent_cell_style(style: Option<&RowStyle>, base: Option<String>) -> Option<String> {
+    let accent = style.map(|style| {
+        format!(
+            "border-inline-start: 4px solid {}",
+            style.severity.accent_color()
+        )
+    });
+    match (base, accent) {
+        (Some(base), Some(accent)) => Some(format!("{}; {}", base, accent)),
+        (base, accent) => base.or(accent),
+    }
+}
+
+/// Event detail for submitting an edited cell
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableCellEditDetail<T: Clone + PartialEq> {
+    /// The item of the edited row
+    pub item: T,
+    /// ID of the edited column
+    pub column_id: String,
+    /// The value entered in the editor, which passed validation
+    pub new_value: String,
+}
+
+/// Value and callbacks an inline cell editor renders with
+#[derive(Clone, PartialEq)]
+pub struct TableCellEditor {
+    /// The value being edited
+    pub value: String,
+    /// Message of the failed validation of the value, if any
+    pub error_text: Option<String>,
+    /// Reports a new value being edited
+    pub on_change: Callback<String>,
+    /// Validates and submits the value being edited, as pressing Enter does
+    pub on_commit: Callback<()>,
+    /// Discards the edit, as pressing Escape does
+    pub on_cancel: Callback<()>,
+}
+
+/// Inline editing of the cells of a column
+///
+/// Clicking an editable cell, or pressing Enter while it has focus, swaps its
+/// content for the editor. Enter validates the value and fires the table's
+/// `submit_edit`; Escape discards the edit.
+#[derive(Clone)]
+pub struct TableEditConfig<T: Clone + PartialEq + 'static> {
+    /// Function returning the value an edit starts from
+    pub value: fn(&T) -> String,
+    /// Function rendering the editor of a cell
+    pub editor: fn(&T, TableCellEditor) -> Html,
+    /// Function returning an error message when an edited value is invalid
+    pub validation: Option<fn(&T, &str) -> Option<String>>,
+}
+
+impl<T: Clone + PartialEq + 'static> TableEditConfig<T> {
+    /// Creates an edit config starting edits from `value` and rendering
+    /// `editor`
+    pub fn new(value: fn(&T) -> String, editor: fn(&T, TableCellEditor) -> Html) -> Self {
+        Self {
+            value,
+            editor,
+            validation: None,
+        }
+    }
+
+    /// Sets the function validating edited values
+    pub fn with_validation(mut self, validation: fn(&T, &str) -> Option<String>) -> Self {
+        self.validation = Some(validation);
+        self
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> std::fmt::Debug for TableEditConfig<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TableEditConfig")
+            .field("has_validation", &self.validation.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+#[allow(unpredictable_function_pointer_comparisons)]
+impl<T: Clone + PartialEq + 'static> PartialEq for TableEditConfig<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+            && self.editor == other.editor
+            && self.validation == other.validation
+    }
+}
+
+/// Cell being edited inline
+#[derive(Debug, Clone, PartialEq)]
+struct CellEdit<T> {
+    /// The item of the edited row, matched by `track_by` key when set so the
+    /// edit follows the item when rows are sorted, filtered or refreshed
+    item: T,
+    column_id: String,
+    value: String,
+    error_text: Option<String>,
+}
+
+impl<T: PartialEq> CellEdit<T> {
+    /// Whether this is the edit of the cell of `item` in column `column_id`
+    fn is_cell(&self, item: &T, column_id: &str, track_by: Option<fn(&T) -> String>) -> bool {
+        self.column_id == column_id
+            && is_item_selected(item, std::slice::from_ref(&self.item), track_by)
+    }
+}
+
+/// What a key pressed in an editing cell does
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EditKey {
+    Commit,
+    Cancel,
+}
+
+/// Gets what a key pressed in an editing cell does, if anything
+fn edit_key(key: &str) -> Option<EditKey> {
+    match key {
+        "Enter" => Some(EditKey::Commit),
+        "Escape" => Some(EditKey::Cancel),
+        _ => None,
+    }
+}
+
+/// Validates an edited value, building the detail to submit or getting the
+/// validation error
+fn validate_edit<T: Clone + PartialEq + 'static>(
+    config: &TableEditConfig<T>,
+    item: &T,
+    column_id: &str,
+    value: &str,
+) -> Result<TableCellEditDetail<T>, String> {
+    match config
+        .validation
+        .and_then(|validation| validation(item, value))
+    {
+        Some(error_text) => Err(error_text),
+        None => Ok(TableCellEditDetail {
+            item: item.clone(),
+            column_id: column_id.to_string(),
+            new_value: value.to_string(),
+        }),
+    }
 }
 
 /// Definition of a table column
@@ -79,7 +780,14 @@ pub struct TableColumn<T: Clone + PartialEq + 'static> {
     /// CSS width for the column (e.g., "200px", "20%")
     pub width: Option<String>,
     /// Minimum CSS width for the column
+    ///
+    /// A pixel value is also the smallest width the column can be resized to.
     pub min_width: Option<String>,
+    /// Whether this column can be resized when the table has
+    /// `resizable_columns` (default true)
+    pub resizable: bool,
+    /// Inline editing of the column's cells, if they are editable
+    pub edit_config: Option<TableEditConfig<T>>,
 }
 
 impl<T: Clone + PartialEq + 'static> TableColumn<T> {
@@ -112,6 +820,8 @@ impl<T: Clone + PartialEq + 'static> TableColumn<T> {
             sortable: false,
             width: None,
             min_width: None,
+            resizable: true,
+            edit_config: None,
         }
     }
 
@@ -132,9 +842,22 @@ impl<T: Clone + PartialEq + 'static> TableColumn<T> {
         self.min_width = Some(min_width.into());
         self
     }
+
+    /// Sets whether this column can be resized
+    pub fn with_resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+
+    /// Makes the cells of this column editable inline
+    pub fn with_edit_config(mut self, edit_config: TableEditConfig<T>) -> Self {
+        self.edit_config = Some(edit_config);
+        self
+    }
 }
 
-// Manual PartialEq implementation that doesn't compare function pointers
+// Manual PartialEq implementation that doesn't compare the cell function
+// pointer; edit configs compare theirs
 impl<T: Clone + PartialEq + 'static> PartialEq for TableColumn<T> {
     fn eq(&self, other: &Self) -> bool {
         self.id == other.id
@@ -142,6 +865,8 @@ impl<T: Clone + PartialEq + 'static> PartialEq for TableColumn<T> {
             && self.sortable == other.sortable
             && self.width == other.width
             && self.min_width == other.min_width
+            && self.resizable == other.resizable
+            && self.edit_config == other.edit_config
     }
 }
 
@@ -167,6 +892,30 @@ pub struct TableProps<T: Clone + PartialEq + 'static> {
     #[prop_or_default]
     pub selected_items: Vec<T>,
 
+    /// Current selection state (controlled)
+    ///
+    /// `SelectionState::AllMatching` selects every item matching the
+    /// current filter; `selected_items` is ignored while it is set.
+    #[prop_or_default]
+    pub selection_state: SelectionState,
+
+    /// How the header checkbox selects items in multi-selection
+    #[prop_or_default]
+    pub select_all_mode: SelectAllMode,
+
+    /// Total number of items matching the current filter, across pages
+    ///
+    /// Used by `SelectAllMode::AllMatching` and the selection summary.
+    #[prop_or_default]
+    pub total_items_count: Option<usize>,
+
+    /// Text the items are currently filtered by
+    ///
+    /// Changing it, like changing the sorting, resets a selection of all
+    /// matching items, since other items match.
+    #[prop_or_default]
+    pub filtering_text: Option<String>,
+
     /// Whether the table is in a loading state
     #[prop_or_default]
     pub loading: bool,
@@ -175,6 +924,10 @@ pub struct TableProps<T: Clone + PartialEq + 'static> {
     #[prop_or_default]
     pub loading_text: Option<String>,
 
+    /// How the loading state is shown
+    #[prop_or_default]
+    pub loading_variant: TableLoadingVariant,
+
     /// Content to display when the table is empty
     #[prop_or_default]
     pub empty: Option<Html>,
@@ -183,18 +936,59 @@ pub struct TableProps<T: Clone + PartialEq + 'static> {
     #[prop_or_default]
     pub header: Option<Html>,
 
+    /// Filter content, such as a TextFilter or PropertyFilter, between the
+    /// header and the rows
+    #[prop_or_default]
+    pub filter: Option<Html>,
+
+    /// Built-in text filter
+    ///
+    /// When set, the table renders a search input after the `filter`
+    /// content and shows the matching `items` only. When no item matches,
+    /// the rows show a "No matches" state with a "Clear filter" button
+    /// instead of `empty`.
+    #[prop_or_default]
+    pub filtering: Option<TextFilterConfig<T>>,
+
     /// Footer content (typically pagination)
     #[prop_or_default]
     pub footer: Option<Html>,
 
+    /// Built-in client-side pagination
+    ///
+    /// When set, the table shows one page of `items` and renders a
+    /// Pagination in the footer, after the `footer` content. Selection still
+    /// reports items, whichever page they are on.
+    #[prop_or_default]
+    pub pagination: Option<TablePaginationConfig>,
+
+    /// Hierarchical rows
+    ///
+    /// Pagination, sorting and filtering apply to the top-level `items`. In
+    /// multi-selection, selecting an item selects its loaded descendants
+    /// too.
+    #[prop_or_default]
+    pub expandable_rows: Option<ExpandableRowsConfig<T>>,
+
     /// Current sorting state
     #[prop_or_default]
     pub sorting_state: Option<SortingState>,
 
     /// Whether to make the header sticky (remains visible on scroll)
+    ///
+    /// The header sticks to the nearest scrolling ancestor of the table, or
+    /// to the viewport when the page scrolls.
     #[prop_or_default]
     pub sticky_header: bool,
 
+    /// Distance in pixels from the top of the scrolling area at which the
+    /// sticky header is pinned
+    ///
+    /// Defaults to the space taken by the header and sticky notifications
+    /// of an enclosing AppLayout when the page scrolls, or 0.
+    #[prop_or_default]
+    pub sticky_header_vertical_offset: Option<i32>,
+
     /// Callback fired when selection changes
     #[prop_or_default]
     pub on_selection_change: Option<Callback<CustomEvent<TableSelectionDetail<T>>>>,
@@ -202,8 +996,83 @@ pub struct TableProps<T: Clone + PartialEq + 'static> {
     /// Callback fired when sort changes
     #[prop_or_default]
     pub on_sort_change: Option<Callback<CustomEvent<TableSortDetail>>>,
+
+    /// Callback fired when the user submits a valid value in an inline cell
+    /// editor
+    ///
+    /// The table doesn't change the item; update `items` with the new value.
+    #[prop_or_default]
+    pub submit_edit: Option<Callback<CustomEvent<TableCellEditDetail<T>>>>,
+
+    /// Function returning a unique key for an item
+    ///
+    /// When set, selected items are matched to `items` by key instead of by
+    /// equality, so selection survives items being re-fetched or updated.
+    #[prop_or_default]
+    pub track_by: Option<fn(&T) -> String>,
+
+    /// Whether clicking anywhere on a row selects it, as its radio button or
+    /// checkbox does
+    ///
+    /// Clicks on links, buttons and form controls inside the row are left to
+    /// them. Only applies when `selection_type` is set.
+    #[prop_or_default]
+    pub select_on_row_click: bool,
+
+    /// Whether to show a selection summary ("3/25 selected") with a "Clear
+    /// selection" link in the header area
+    ///
+    /// Only applies when `selection_type` is `Multi`.
+    #[prop_or_default]
+    pub show_selection_summary: bool,
+
+    /// Internationalization strings for the selection, filter and count
+    /// texts
+    #[prop_or_default]
+    pub i18n_strings: TableI18nStrings,
+
+    /// Whether alternate rows have a shaded background
+    #[prop_or_default]
+    pub striped_rows: bool,
+
+    /// Function returning conditional styling for an item's row
+    ///
+    /// Rows with a style get a severity accent on their leading edge and,
+    /// optionally, a tinted background. The selection background takes
+    /// precedence over the tint and the tint over striping; the accent is
+    /// always shown.
+    #[prop_or_default]
+    pub row_style: Option<fn(&T) -> Option<RowStyle>>,
+
+    /// Whether body cells form a grid navigated with the keyboard
+    ///
+    /// The grid is a single tab stop: arrow keys move focus between cells,
+    /// Home and End to the first and last cell of the row, and Page Up and
+    /// Page Down by 10 rows. Space toggles the selection of the row when the
+    /// selection cell has focus. Sortable column headers stay in the tab
+    /// order.
+    #[prop_or_default]
+    pub enable_keyboard_navigation: bool,
+
+    /// Whether the user can resize columns
+    ///
+    /// Resizable header cells get a handle that can be dragged, or focused
+    /// and moved with the left and right arrow keys. Double-clicking the
+    /// handle restores the column's default width.
+    #[prop_or_default]
+    pub resizable_columns: bool,
+
+    /// Column widths to start with in pixels, keyed by column ID, such as
+    /// widths saved from `on_column_widths_change`
+    #[prop_or_default]
+    pub column_widths: HashMap<String, f64>,
+
+    /// Callback fired when the user resized a column or restored its width
+    #[prop_or_default]
+    pub on_column_widths_change: Option<Callback<CustomEvent<ColumnWidthsChangeDetail>>>,
 }
 
+#[allow(unpredictable_function_pointer_comparisons)]
 impl<T: Clone + PartialEq + 'static> PartialEq for TableProps<T> {
     fn eq(&self, other: &Self) -> bool {
         self.base == other.base
@@ -211,11 +1080,454 @@ impl<T: Clone + PartialEq + 'static> PartialEq for TableProps<T> {
             && self.items == other.items
             && self.selection_type == other.selection_type
             && self.selected_items == other.selected_items
+            && self.selection_state == other.selection_state
+            && self.select_all_mode == other.select_all_mode
+            && self.total_items_count == other.total_items_count
+            && self.filtering_text == other.filtering_text
             && self.loading == other.loading
             && self.loading_text == other.loading_text
+            && self.loading_variant == other.loading_variant
+            && self.pagination == other.pagination
+            && self.filtering == other.filtering
+            && self.expandable_rows == other.expandable_rows
             && self.sorting_state == other.sorting_state
             && self.sticky_header == other.sticky_header
+            && self.sticky_header_vertical_offset == other.sticky_header_vertical_offset
+            && self.track_by == other.track_by
+            && self.select_on_row_click == other.select_on_row_click
+            && self.show_selection_summary == other.show_selection_summary
+            && self.i18n_strings == other.i18n_strings
+            && self.striped_rows == other.striped_rows
+            && self.row_style == other.row_style
+            && self.enable_keyboard_navigation == other.enable_keyboard_navigation
+            && self.resizable_columns == other.resizable_columns
+            && self.column_widths == other.column_widths
+    }
+}
+
+/// Whether an item is in the selection, matched by `track_by` key when set
+fn is_item_selected<T: PartialEq>(
+    item: &T,
+    selected_items: &[T],
+    track_by: Option<fn(&T) -> String>,
+) -> bool {
+    match track_by {
+        Some(track_by) => {
+            let key = track_by(item);
+            selected_items
+                .iter()
+                .any(|selected| track_by(selected) == key)
+        }
+        None => selected_items.contains(item),
+    }
+}
+
+/// Whether an item is selected under a selection state
+///
+/// When all matching items are selected, an item is selected unless its key
+/// is excluded.
+fn is_item_in_selection<T: PartialEq>(
+    item: &T,
+    selected_items: &[T],
+    selection_state: &SelectionState,
+    track_by: Option<fn(&T) -> String>,
+) -> bool {
+    match selection_state {
+        SelectionState::Items => is_item_selected(item, selected_items, track_by),
+        SelectionState::AllMatching { excluded } => {
+            track_by.is_none_or(|track_by| !excluded.contains(&track_by(item)))
+        }
+    }
+}
+
+/// Selection of one render, looking up items without scanning the selection
+///
+/// With `track_by`, the keys of the selected items, or of the excluded items
+/// when all matching items are selected, are collected once. Without it,
+/// items are matched by equality.
+struct SelectionLookup<'a, T> {
+    selected_items: &'a [T],
+    selection_state: &'a SelectionState,
+    track_by: Option<fn(&T) -> String>,
+    keys: HashSet<String>,
+}
+
+impl<'a, T: PartialEq> SelectionLookup<'a, T> {
+    fn new(
+        selected_items: &'a [T],
+        selection_state: &'a SelectionState,
+        track_by: Option<fn(&T) -> String>,
+    ) -> Self {
+        let keys = match (selection_state, track_by) {
+            (SelectionState::Items, Some(track_by)) => {
+                selected_items.iter().map(track_by).collect()
+            }
+            (SelectionState::AllMatching { excluded }, Some(_)) => {
+                excluded.iter().cloned().collect()
+            }
+            _ => HashSet::new(),
+        };
+        Self {
+            selected_items,
+            selection_state,
+            track_by,
+            keys,
+        }
+    }
+
+    /// Whether an item is selected, like [`is_item_in_selection`]
+    fn contains(&self, item: &T) -> bool {
+        match (self.selection_state, self.track_by) {
+            (SelectionState::Items, Some(track_by)) => self.keys.contains(&track_by(item)),
+            (SelectionState::Items, None) => self.selected_items.contains(item),
+            (SelectionState::AllMatching { .. }, Some(track_by)) => {
+                !self.keys.contains(&track_by(item))
+            }
+            (SelectionState::AllMatching { .. }, None) => true,
+        }
+    }
+
+    /// Counts the selected items out of `total` matching items
+    ///
+    /// Selected items are deduplicated by `track_by` key when set; without
+    /// it, equal items can't be told apart and each counts.
+    fn count(&self, total: usize) -> usize {
+        match (self.selection_state, self.track_by) {
+            (SelectionState::Items, Some(_)) => self.keys.len(),
+            (SelectionState::Items, None) => self.selected_items.len(),
+            (SelectionState::AllMatching { excluded }, _) => all_matching_count(total, excluded),
+        }
+    }
+}
+
+/// Adds an item's key to the exclusions, or removes it when already
+/// excluded
+///
+/// `keys` starts with the item's key; the keys of its descendants follow
+/// and are excluded or included with it.
+fn toggle_exclusion(excluded: &[String], keys: &[String]) -> Vec<String> {
+    let exclude = keys.first().is_some_and(|key| !excluded.contains(key));
+    let mut excluded: Vec<String> = excluded
+        .iter()
+        .filter(|key| !keys.contains(key))
+        .cloned()
+        .collect();
+    if exclude {
+        excluded.extend(keys.iter().cloned());
+    }
+    excluded
+}
+
+/// Number of items selected when all `total` matching items but the
+/// excluded ones are
+fn all_matching_count(total: usize, excluded: &[String]) -> usize {
+    total.saturating_sub(excluded.len())
+}
+
+/// Formats a count with thousands separators, such as "1,234"
+fn format_count(count: usize) -> String {
+    let digits = count.to_string();
+    let mut formatted = String::new();
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index).is_multiple_of(3) {
+            formatted.push(',');
+        }
+        formatted.push(digit);
+    }
+    formatted
+}
+
+/// Internationalization strings for Table
+///
+/// Unset strings fall back to the closest I18nProvider (keys under
+/// `"table."`), then to English. `{count}` and `{total}` are replaced by the
+/// counts the string describes.
+#[derive(Clone, PartialEq, Default)]
+pub struct TableI18nStrings {
+    /// Status of the select-all banner once the page is selected
+    /// (default: "All {count} items on this page are selected.")
+    pub page_selected_text: Option<String>,
+    /// Action of the select-all banner selecting every matching item
+    /// (default: "Select all {total} items matching the current filter")
+    pub select_all_matching_text: Option<String>,
+    /// Status of the select-all banner once every matching item is selected
+    /// (default: "All {total} items matching the current filter are selected.")
+    pub all_matching_selected_text: Option<String>,
+    /// Status of the select-all banner once some matching items are
+    /// deselected again (default: "{count} of {total} items matching the
+    /// current filter are selected.")
+    pub some_matching_selected_text: Option<String>,
+    /// Action clearing the selection (default: "Clear selection")
+    pub clear_selection_text: Option<String>,
+    /// Visible selection summary (default: "{count}/{total} selected")
+    pub selection_summary_text: Option<String>,
+    /// Announcement of the number of items (default: "{count} items", or
+    /// "1 item")
+    pub items_count_text: Option<String>,
+    /// Announcement of the number of selected items (default: "{count} items
+    /// selected", or "1 item selected")
+    pub selected_count_text: Option<String>,
+    /// ARIA label of the built-in filter input, when it has no placeholder
+    /// (default: "Filter items")
+    pub filtering_aria_label: Option<String>,
+    /// Text next to the filter input (default: "{count} matches", or
+    /// "1 match")
+    pub matches_count_text: Option<String>,
+    /// Empty state when no item matches the filter (default: "No matches")
+    pub no_match_text: Option<String>,
+    /// Action of the empty state clearing the filter (default: "Clear filter")
+    pub clear_filter_text: Option<String>,
+}
+
+/// Strings used to render a Table after applying the fallback chain
+///
+/// Count announcements stay unset without a prop or provider string, so the
+/// English default can tell one item from several.
+struct ResolvedStrings {
+    page_selected_text: String,
+    select_all_matching_text: String,
+    all_matching_selected_text: String,
+    some_matching_selected_text: String,
+    clear_selection_text: String,
+    selection_summary_text: String,
+    items_count_text: Option<String>,
+    selected_count_text: Option<String>,
+    filtering_aria_label: Option<String>,
+    matches_count_text: Option<String>,
+    no_match_text: String,
+    clear_filter_text: String,
+}
+
+/// Resolves every user-visible string: `i18n_strings` prop, then the
+/// I18nProvider, then English
+fn resolve_strings(strings: &TableI18nStrings, i18n: &I18nContext) -> ResolvedStrings {
+    let message = |value: &Option<String>, key: &str| {
+        value
+            .clone()
+            .or_else(|| i18n.message("table", key).map(str::to_string))
+    };
+    let resolve = |value: &Option<String>, key: &str, default: &str| {
+        message(value, key).unwrap_or_else(|| default.to_string())
+    };
+
+    ResolvedStrings {
+        page_selected_text: resolve(
+            &strings.page_selected_text,
+            "page_selected_text",
+            "All {count} items on this page are selected.",
+        ),
+        select_all_matching_text: resolve(
+            &strings.select_all_matching_text,
+            "select_all_matching_text",
+            "Select all {total} items matching the current filter",
+        ),
+        all_matching_selected_text: resolve(
+            &strings.all_matching_selected_text,
+            "all_matching_selected_text",
+            "All {total} items matching the current filter are selected.",
+        ),
+        some_matching_selected_text: resolve(
+            &strings.some_matching_selected_text,
+            "some_matching_selected_text",
+            "{count} of {total} items matching the current filter are selected.",
+        ),
+        clear_selection_text: resolve(
+            &strings.clear_selection_text,
+            "clear_selection_text",
+            "Clear selection",
+        ),
+        selection_summary_text: resolve(
+            &strings.selection_summary_text,
+            "selection_summary_text",
+            "{count}/{total} selected",
+        ),
+        items_count_text: message(&strings.items_count_text, "items_count_text"),
+        selected_count_text: message(&strings.selected_count_text, "selected_count_text"),
+        filtering_aria_label: message(&strings.filtering_aria_label, "filtering_aria_label"),
+        matches_count_text: message(&strings.matches_count_text, "matches_count_text"),
+        no_match_text: resolve(&strings.no_match_text, "no_match_text", "No matches"),
+        clear_filter_text: resolve(
+            &strings.clear_filter_text,
+            "clear_filter_text",
+            "Clear filter",
+        ),
+    }
+}
+
+/// Replaces the `{count}` and `{total}` placeholders of a string
+fn fill_counts(text: &str, count: &str, total: &str) -> String {
+    text.replace("{count}", count).replace("{total}", total)
+}
+
+impl ResolvedStrings {
+    /// Texts of the select-all banner: the status and the action
+    fn select_all_banner_text(
+        &self,
+        selection_state: &SelectionState,
+        page_count: usize,
+        total: usize,
+    ) -> (String, String) {
+        let total_text = format_count(total);
+        match selection_state {
+            SelectionState::Items => (
+                fill_counts(
+                    &self.page_selected_text,
+                    &format_count(page_count),
+                    &total_text,
+                ),
+                fill_counts(&self.select_all_matching_text, "", &total_text),
+            ),
+            SelectionState::AllMatching { excluded } if excluded.is_empty() => (
+                fill_counts(&self.all_matching_selected_text, "", &total_text),
+                self.clear_selection_text.clone(),
+            ),
+            SelectionState::AllMatching { excluded } => (
+                fill_counts(
+                    &self.some_matching_selected_text,
+                    &format_count(all_matching_count(total, excluded)),
+                    &total_text,
+                ),
+                self.clear_selection_text.clone(),
+            ),
+        }
+    }
+
+    /// Visible selection summary, such as "3/25 selected"
+    fn selection_summary_text(&self, selected: usize, total: usize) -> String {
+        fill_counts(
+            &self.selection_summary_text,
+            &format_count(selected),
+            &format_count(total),
+        )
+    }
+
+    /// Announcement of the number of items
+    fn items_count_text(&self, count: usize) -> String {
+        match &self.items_count_text {
+            Some(text) => fill_counts(text, &count.to_string(), ""),
+            None if count == 1 => "1 item".to_string(),
+            None => format!("{} items", count),
+        }
+    }
+
+    /// ARIA label of the built-in filter input: the string, else the
+    /// placeholder, else English
+    fn filtering_aria_label(&self, placeholder: Option<&str>) -> String {
+        self.filtering_aria_label
+            .as_deref()
+            .or(placeholder)
+            .unwrap_or("Filter items")
+            .to_string()
+    }
+
+    /// Text next to the filter input, such as "3 matches"
+    fn matches_count_text(&self, count: usize) -> String {
+        match &self.matches_count_text {
+            Some(text) => fill_counts(text, &format_count(count), ""),
+            None if count == 1 => "1 match".to_string(),
+            None => format!("{} matches", format_count(count)),
+        }
+    }
+
+    /// Announcement of the number of selected items
+    fn selected_count_text(&self, count: usize) -> String {
+        match &self.selected_count_text {
+            Some(text) => fill_counts(text, &count.to_string(), ""),
+            None if count == 1 => "1 item selected".to_string(),
+            None => format!("{} items selected", count),
+        }
+    }
+}
+
+/// Part names accepted by `base.style`: the header and footer slots, column
+/// header cells, data rows and data cells
+const STYLE_PARTS: &[&str] = &["header", "header-cell", "row", "cell", "footer"];
+
+/// Leading items compared with each other when looking for equal items, so
+/// the pairwise check stays cheap on every debug render of large tables
+const EQUAL_ITEMS_CHECK_LIMIT: usize = 200;
+
+/// Misuse of Table props reported in development builds
+fn dev_warnings<T: Clone + PartialEq + 'static>(props: &TableProps<T>) -> Vec<DevWarning> {
+    let mut warnings = Vec::new();
+
+    // Without `track_by`, selection matches items by equality, so equal
+    // items can't be selected independently
+    let has_equal_items = || {
+        let items = &props.items[..props.items.len().min(EQUAL_ITEMS_CHECK_LIMIT)];
+        items
+            .iter()
+            .enumerate()
+            .any(|(index, item)| items[..index].contains(item))
+    };
+    if props.selection_type.is_some() && props.track_by.is_none() && has_equal_items() {
+        warnings.push(DevWarning::new(
+            "table-selection-without-track-by",
+            "Selection is enabled and some items are equal to each other, so they can't be \
+             told apart. Set `track_by` to a function returning a unique key per item.",
+        ));
+    }
+
+    if props.select_all_mode == SelectAllMode::AllMatching
+        && (props.track_by.is_none() || props.total_items_count.is_none())
+    {
+        warnings.push(DevWarning::new(
+            "table-all-matching-incomplete",
+            "`SelectAllMode::AllMatching` needs `track_by` to record unchecked items and \
+             `total_items_count` to count the matching items; the header checkbox selects \
+             only the current page.",
+        ));
+    }
+
+    warnings.extend(unknown_style_parts(
+        "table-unknown-style-part",
+        &props.base.style,
+        STYLE_PARTS,
+    ));
+
+    warnings
+}
+
+/// Selects or deselects every item of the page, keeping the items selected
+/// on other pages
+///
+/// Items are matched by `track_by` key when set.
+fn toggle_page_selection<T: Clone + PartialEq>(
+    selected_items: &[T],
+    page: &[T],
+    select: bool,
+    track_by: Option<fn(&T) -> String>,
+) -> Vec<T> {
+    let mut selection: Vec<T> = selected_items
+        .iter()
+        .filter(|item| !is_item_selected(*item, page, track_by))
+        .cloned()
+        .collect();
+    if select {
+        selection.extend(page.iter().cloned());
     }
+    selection
+}
+
+/// Replaces selected items with their instances in `items`, matched by
+/// `track_by` key when set
+///
+/// Items not in `items`, such as ones on other pages, are kept as they are.
+fn current_instances<T: Clone + PartialEq>(
+    selected_items: Vec<T>,
+    items: &[T],
+    track_by: Option<fn(&T) -> String>,
+) -> Vec<T> {
+    selected_items
+        .into_iter()
+        .map(|selected| {
+            items
+                .iter()
+                .find(|item| is_item_selected(*item, std::slice::from_ref(&selected), track_by))
+                .cloned()
+                .unwrap_or(selected)
+        })
+        .collect()
 }
 
 /// Table component for displaying tabular data
@@ -223,6 +1535,9 @@ impl<T: Clone + PartialEq + 'static> PartialEq for TableProps<T> {
 /// A flexible data table with support for sorting, selection, loading states,
 /// and pagination. The component is fully generic over the item type.
 ///
+/// Style override parts (`base.style`): `header`, `header-cell`, `row`,
+/// `cell`, `footer`.
+///
 /// # Example
 ///
 /// ```rust
@@ -261,6 +1576,200 @@ impl<T: Clone + PartialEq + 'static> PartialEq for TableProps<T> {
 #[function_component(Table)]
 pub fn table<T: Clone + PartialEq + 'static>(props: &TableProps<T>) -> Html {
     let _metadata = ComponentMetadata::new("Table");
+    // A BusyAnnouncer announces loading once for the app instead of the table
+    let coordinated = use_register_busy(props.loading);
+    use_dev_warnings("Table").check(|| dev_warnings(props));
+    let i18n = use_i18n();
+    let strings = resolve_strings(&props.i18n_strings, &i18n);
+
+    // Items matching the built-in filter
+    let filtered_items: Vec<T>;
+    let items = match &props.filtering {
+        Some(filtering) if !filtering.filtering_text.is_empty() => {
+            filtered_items = props
+                .items
+                .iter()
+                .filter(|item| filtering.matches(item))
+                .cloned()
+                .collect();
+            &filtered_items[..]
+        }
+        _ => &props.items[..],
+    };
+    let filtering_text = props
+        .filtering
+        .as_ref()
+        .map(|filtering| filtering.filtering_text.clone())
+        .or_else(|| props.filtering_text.clone());
+
+    // Slice the current page when paginating; out-of-range pages show the
+    // closest page until the application catches up
+    let page = props.pagination.as_ref().map(|pagination| {
+        let pages_count = pages_count(items.len(), pagination.page_size);
+        let current_page = pagination.current_page.clamp(1, pages_count);
+        (current_page, pages_count)
+    });
+    let visible_items = match (&props.pagination, page) {
+        (Some(pagination), Some((current_page, _))) => {
+            &items[page_range(items.len(), pagination.page_size, current_page)]
+        }
+        _ => items,
+    };
+
+    // Rows of the current page, followed by the descendants of expanded items
+    let rows = table_rows(
+        visible_items,
+        props.expandable_rows.as_ref(),
+        props.track_by,
+    );
+    let row_items: Vec<T> = rows.iter().map(|row| row.item.clone()).collect();
+
+    // Request the first page after the sorting changed, and the last page
+    // once the items shrank below the current one
+    {
+        let on_page_change = props
+            .pagination
+            .as_ref()
+            .and_then(|pagination| pagination.on_page_change.clone());
+        let requested = props.pagination.as_ref().map(|pagination| {
+            (
+                pagination.current_page,
+                pages_count(items.len(), pagination.page_size),
+            )
+        });
+        let last_sorting = use_mut_ref(|| props.sorting_state.clone());
+        use_effect_with(
+            (props.sorting_state.clone(), requested),
+            move |(sorting_state, requested)| {
+                let sorting_changed = *last_sorting.borrow() != *sorting_state;
+                *last_sorting.borrow_mut() = sorting_state.clone();
+                if let (Some(callback), Some((current_page, pages_count))) =
+                    (&on_page_change, requested)
+                    && let Some(page) =
+                        page_correction(*current_page, *pages_count, sorting_changed)
+                {
+                    callback.emit(CustomEvent::new_non_cancelable(PaginationChangeDetail {
+                        requested_page_index: page,
+                    }));
+                }
+            },
+        );
+    }
+
+    let table_ref = use_node_ref();
+
+    // Cell being edited inline; editing another cell discards the edit
+    let editing = use_state(|| None::<CellEdit<T>>);
+    let set_editing = {
+        let editing = editing.clone();
+        Callback::from(move |edit| editing.set(edit))
+    };
+
+    // Move focus into the editor once a cell starts editing
+    {
+        let table_ref = table_ref.clone();
+        let editing_cell = editing
+            .as_ref()
+            .map(|edit| (edit.item.clone(), edit.column_id.clone()));
+        use_effect_with(editing_cell, move |editing_cell| {
+            if editing_cell.is_some()
+                && let Some(control) = table_ref
+                    .cast::<Element>()
+                    .and_then(|table| {
+                        table
+                            .query_selector(
+                                ".awsui-table-cell-editing :is(input, select, textarea, button)",
+                            )
+                            .ok()
+                            .flatten()
+                    })
+                    .and_then(|control| control.dyn_into::<HtmlElement>().ok())
+            {
+                let _ = control.focus();
+            }
+        });
+    }
+
+    // Pin a sticky header to the nearest scrolling ancestor, tracking whether
+    // the body has scrolled beneath it. The AppLayout offset only applies
+    // when the page scrolls.
+    let layout_offset = use_context::<StickyOffset>().unwrap_or_default();
+    let in_scroll_parent = use_state_eq(|| false);
+    let stuck = use_state_eq(|| false);
+    let sticky_offset = props
+        .sticky_header_vertical_offset
+        .unwrap_or(if *in_scroll_parent {
+            0
+        } else {
+            layout_offset.top
+        });
+    {
+        let table_ref = table_ref.clone();
+        let in_scroll_parent = in_scroll_parent.clone();
+        let stuck = stuck.clone();
+        use_effect_with(
+            (props.sticky_header, sticky_offset),
+            move |(sticky, offset)| {
+                let offset = f64::from(*offset);
+                let listener = sticky
+                    .then(|| table_ref.cast::<Element>())
+                    .flatten()
+                    .map(|table| {
+                        let parent = scroll_parent(&table);
+                        in_scroll_parent.set(parent.is_some());
+                        let update = {
+                            let parent = parent.clone();
+                            move || {
+                                let top = parent
+                                    .as_ref()
+                                    .map_or(0.0, |parent| parent.get_bounding_client_rect().top());
+                                stuck.set(table.get_bounding_client_rect().top() < top + offset);
+                            }
+                        };
+                        update();
+                        match &parent {
+                            Some(parent) => EventListener::new(parent, "scroll", move |_| update()),
+                            None => {
+                                EventListener::new(&gloo::utils::window(), "scroll", move |_| {
+                                    update()
+                                })
+                            }
+                        }
+                    });
+                move || drop(listener)
+            },
+        );
+    }
+    let header_cell_style = |base: Option<String>| {
+        if props.sticky_header {
+            Some(sticky_header_cell_style(sticky_offset, *stuck, base))
+        } else {
+            base
+        }
+    };
+
+    // Column widths set by the user; new `column_widths` replace them
+    let column_resize = {
+        let widths = use_state(|| props.column_widths.clone());
+        {
+            let widths = widths.clone();
+            use_effect_with(props.column_widths.clone(), move |column_widths| {
+                if *widths != *column_widths {
+                    widths.set(column_widths.clone());
+                }
+            });
+        }
+        ColumnResize {
+            widths,
+            drag: use_mut_ref(|| None),
+            on_change: props.on_column_widths_change.clone(),
+        }
+    };
+    let user_width = |column: &TableColumn<T>| {
+        (props.resizable_columns && column.resizable)
+            .then(|| column_resize.widths.get(&column.id).copied())
+            .flatten()
+    };
 
     // Handle sort column click
     let on_sort_click = {
@@ -298,59 +1807,262 @@ pub fn table<T: Clone + PartialEq + 'static>(props: &TableProps<T>) -> Html {
     let on_row_select = {
         let on_selection_change = props.on_selection_change.clone();
         let selected_items = props.selected_items.clone();
+        let selection_state = props.selection_state.clone();
+        let items = row_items.clone();
         let selection_type = props.selection_type;
+        let track_by = props.track_by;
+        let expandable_rows = props.expandable_rows.clone();
 
         Callback::from(move |item: T| {
             if let Some(callback) = &on_selection_change {
+                // Selecting an expandable item selects its loaded descendants
+                let family = item_with_descendants(&item, expandable_rows.as_ref(), track_by);
+
+                // Unchecking an item of an all-matching selection excludes it
+                if let (SelectionState::AllMatching { excluded }, Some(track_by)) =
+                    (&selection_state, track_by)
+                {
+                    let keys: Vec<String> = family.iter().map(track_by).collect();
+                    let selection_state = SelectionState::AllMatching {
+                        excluded: toggle_exclusion(excluded, &keys),
+                    };
+                    let selected_items = items
+                        .iter()
+                        .filter(|i| is_item_in_selection(*i, &[], &selection_state, Some(track_by)))
+                        .cloned()
+                        .collect();
+                    callback.emit(CustomEvent::new_non_cancelable(TableSelectionDetail {
+                        selected_items,
+                        selection_state,
+                    }));
+                    return;
+                }
+
                 let new_selected = match selection_type {
                     Some(SelectionType::Single) => {
                         // Single selection: replace selection
                         vec![item]
                     }
                     Some(SelectionType::Multi) => {
-                        // Multi selection: toggle item
-                        if selected_items.contains(&item) {
+                        // Multi selection: toggle item and its descendants
+                        if is_item_selected(&item, &selected_items, track_by) {
                             selected_items
                                 .iter()
-                                .filter(|i| *i != &item)
+                                .filter(|i| !is_item_selected(*i, &family, track_by))
                                 .cloned()
                                 .collect()
                         } else {
                             let mut new = selected_items.clone();
-                            new.push(item);
+                            for member in family {
+                                if !is_item_selected(&member, &new, track_by) {
+                                    new.push(member);
+                                }
+                            }
                             new
                         }
                     }
                     None => vec![],
                 };
 
+                // Report the current instances of selected items that were
+                // updated since they were selected
                 let detail = TableSelectionDetail {
-                    selected_items: new_selected,
+                    selected_items: current_instances(new_selected, &items, track_by),
+                    selection_state: SelectionState::Items,
                 };
                 callback.emit(CustomEvent::new_non_cancelable(detail));
             }
         })
     };
 
+    // Request expanding or collapsing a row
+    let on_expand_toggle = {
+        let on_toggle = props
+            .expandable_rows
+            .as_ref()
+            .and_then(|config| config.on_expandable_item_toggle.clone());
+        Callback::from(move |(item, expanded): (T, bool)| {
+            if let Some(callback) = &on_toggle {
+                callback.emit(CustomEvent::new_non_cancelable(ExpandToggleDetail {
+                    item,
+                    expanded,
+                }));
+            }
+        })
+    };
+    let tree = props.expandable_rows.is_some();
+
+    // Keyboard navigation grid of the body cells
+    let grid_column_count = column_count(props);
+    let grid_enabled = props.enable_keyboard_navigation && !props.loading && !rows.is_empty();
+    let active_cell = use_state(|| (0, 0));
+    let active_cell_ref = use_node_ref();
+    // Whether the active cell takes focus after rendering, after a key moved it
+    let focus_active_cell = use_mut_ref(|| false);
+    // Whether focus was last inside the grid, to restore it when the focused
+    // row disappears
+    let grid_has_focus = use_mut_ref(|| false);
+    let grid = GridFocus {
+        enabled: grid_enabled,
+        active: clamp_cell(*active_cell, rows.len(), grid_column_count),
+        active_cell: active_cell.clone(),
+        active_cell_ref: active_cell_ref.clone(),
+    };
+
+    {
+        let active_cell_ref = active_cell_ref.clone();
+        let focus_active_cell = focus_active_cell.clone();
+        let grid_has_focus = grid_has_focus.clone();
+        use_effect_with((grid.active, rows.len()), move |_| {
+            let focus_lost = *grid_has_focus.borrow()
+                && gloo::utils::document()
+                    .active_element()
+                    .is_none_or(|element| element.tag_name() == "BODY");
+            if (focus_active_cell.replace(false) || focus_lost)
+                && let Some(cell) = active_cell_ref.cast::<web_sys::HtmlElement>()
+            {
+                let _ = cell.focus();
+            }
+        });
+    }
+
+    let on_grid_key_down = {
+        let grid = grid.clone();
+        let focus_active_cell = focus_active_cell.clone();
+        let on_row_select = on_row_select.clone();
+        let row_items = row_items.clone();
+        let row_count = rows.len();
+        let has_selection_cell = props.selection_type.is_some();
+        Callback::from(move |e: KeyboardEvent| {
+            // Keys pressed in controls inside the cells are theirs
+            let on_active_cell = grid.active_cell_ref.get().is_some_and(|cell| {
+                e.target()
+                    .and_then(|target| target.dyn_into::<web_sys::Node>().ok())
+                    .is_some_and(|target| cell.is_same_node(Some(&target)))
+            });
+            if !grid.enabled || !on_active_cell {
+                return;
+            }
+
+            let (row, column) = grid.active;
+            if e.key() == " " && has_selection_cell && column == 0 {
+                e.prevent_default();
+                if let Some(item) = row_items.get(row) {
+                    on_row_select.emit(item.clone());
+                }
+            } else if let Some(cell) =
+                navigate_cell(&e.key(), grid.active, row_count, grid_column_count)
+            {
+                e.prevent_default();
+                if cell != grid.active {
+                    *focus_active_cell.borrow_mut() = true;
+                    grid.active_cell.set(cell);
+                }
+            }
+        })
+    };
+
+    let on_grid_focus_in = {
+        let grid_has_focus = grid_has_focus.clone();
+        Callback::from(move |_: FocusEvent| {
+            *grid_has_focus.borrow_mut() = true;
+        })
+    };
+
+    // Focus moving out of the body; a removed cell loses focus to nothing
+    let on_grid_focus_out = Callback::from(move |e: FocusEvent| {
+        let target_inside = e.related_target().is_some_and(|target| {
+            e.current_target()
+                .and_then(|body| body.dyn_into::<web_sys::Node>().ok())
+                .is_some_and(|body| body.contains(target.dyn_ref::<web_sys::Node>()))
+        });
+        if e.related_target().is_some() && !target_inside {
+            *grid_has_focus.borrow_mut() = false;
+        }
+    });
+
+    // Request a new filtering text from the built-in filter
+    let on_filter_change = props
+        .filtering
+        .as_ref()
+        .and_then(|filtering| filtering.on_filter_change.clone());
+    let on_filter_input_change = on_filter_change.clone().map(|callback| {
+        callback.reform(|e: CustomEvent<InputChangeDetail>| {
+            CustomEvent::new_non_cancelable(FilterChangeDetail {
+                filtering_text: e.detail.value,
+            })
+        })
+    });
+    let on_clear_filter = Callback::from(move |_| {
+        if let Some(callback) = &on_filter_change {
+            callback.emit(CustomEvent::new_non_cancelable(FilterChangeDetail {
+                filtering_text: String::new(),
+            }));
+        }
+    });
+
     // Handle select all (for multi-select)
     let on_select_all = {
         let on_selection_change = props.on_selection_change.clone();
         let selected_items = props.selected_items.clone();
-        let items = props.items.clone();
+        let selection_state = props.selection_state.clone();
+        let items = row_items.clone();
+        let track_by = props.track_by;
 
-        Callback::from(move |_e: MouseEvent| {
+        Callback::from(move |_e: CustomEvent<CheckboxChangeDetail>| {
             if let Some(callback) = &on_selection_change {
-                let all_selected = selected_items.len() == items.len() && !items.is_empty();
-                let new_selected = if all_selected {
-                    // Deselect all
-                    vec![]
-                } else {
-                    // Select all
-                    items.clone()
-                };
-
-                let detail = TableSelectionDetail {
-                    selected_items: new_selected,
+                let all_selected = !items.is_empty()
+                    && items.iter().all(|item| {
+                        is_item_in_selection(item, &selected_items, &selection_state, track_by)
+                    });
+                let detail = match (&selection_state, track_by) {
+                    // Deselect the page within an all-matching selection
+                    (SelectionState::AllMatching { excluded }, Some(track_by)) if all_selected => {
+                        // None of the page is excluded while it is all selected
+                        let excluded = excluded
+                            .iter()
+                            .cloned()
+                            .chain(items.iter().map(track_by))
+                            .collect();
+                        TableSelectionDetail {
+                            selected_items: vec![],
+                            selection_state: SelectionState::AllMatching { excluded },
+                        }
+                    }
+                    // Deselect the page, keeping the items selected on others
+                    _ if all_selected => TableSelectionDetail {
+                        selected_items: toggle_page_selection(
+                            &selected_items,
+                            &items,
+                            false,
+                            track_by,
+                        ),
+                        selection_state: SelectionState::Items,
+                    },
+                    // Select the page again within an all-matching selection
+                    (SelectionState::AllMatching { excluded }, Some(track_by)) => {
+                        let page_keys: Vec<String> = items.iter().map(track_by).collect();
+                        TableSelectionDetail {
+                            selected_items: items.clone(),
+                            selection_state: SelectionState::AllMatching {
+                                excluded: excluded
+                                    .iter()
+                                    .filter(|key| !page_keys.contains(key))
+                                    .cloned()
+                                    .collect(),
+                            },
+                        }
+                    }
+                    // Select the page, keeping the items selected on others
+                    _ => TableSelectionDetail {
+                        selected_items: toggle_page_selection(
+                            &selected_items,
+                            &items,
+                            true,
+                            track_by,
+                        ),
+                        selection_state: SelectionState::Items,
+                    },
                 };
                 callback.emit(CustomEvent::new_non_cancelable(detail));
             }
@@ -367,47 +2079,225 @@ pub fn table<T: Clone + PartialEq + 'static>(props: &TableProps<T>) -> Html {
         .add("awsui-table-container")
         .add_if(props.sticky_header, "awsui-table-sticky-header");
 
+    let table_classes = ClassBuilder::new()
+        .add("awsui-table-element")
+        .add_if(props.resizable_columns, "awsui-table-element-resizable");
+
+    // Handle clearing the selection from the selection summary
+    let on_clear_selection = {
+        let on_selection_change = props.on_selection_change.clone();
+        Callback::from(move |_| {
+            if let Some(callback) = &on_selection_change {
+                callback.emit(CustomEvent::new_non_cancelable(TableSelectionDetail {
+                    selected_items: vec![],
+                    selection_state: SelectionState::Items,
+                }));
+            }
+        })
+    };
+
+    // Handle selecting every matching item from the select-all banner
+    let on_select_all_matching = {
+        let on_selection_change = props.on_selection_change.clone();
+        let items = row_items.clone();
+        Callback::from(move |_| {
+            if let Some(callback) = &on_selection_change {
+                callback.emit(CustomEvent::new_non_cancelable(TableSelectionDetail {
+                    selected_items: items.clone(),
+                    selection_state: SelectionState::AllMatching { excluded: vec![] },
+                }));
+            }
+        })
+    };
+
+    // Reset an all-matching selection when other items match, after the
+    // filter or the sorting changed
+    {
+        let on_selection_change = props.on_selection_change.clone();
+        let all_matching = matches!(props.selection_state, SelectionState::AllMatching { .. });
+        let query = (filtering_text, props.sorting_state.clone());
+        let last_query = use_mut_ref(|| query.clone());
+        use_effect_with(query, move |query| {
+            let changed = *last_query.borrow() != *query;
+            *last_query.borrow_mut() = query.clone();
+            if changed
+                && all_matching
+                && let Some(callback) = &on_selection_change
+            {
+                callback.emit(CustomEvent::new_non_cancelable(TableSelectionDetail {
+                    selected_items: vec![],
+                    selection_state: SelectionState::Items,
+                }));
+            }
+        });
+    }
+
     // Check if all items are selected (for multi-select checkbox state)
+    let selection = SelectionLookup::new(
+        &props.selected_items,
+        &props.selection_state,
+        props.track_by,
+    );
+    let selected_visible_count = row_items
+        .iter()
+        .filter(|item| selection.contains(item))
+        .count();
     let all_selected = props.selection_type == Some(SelectionType::Multi)
-        && !props.items.is_empty()
-        && props.selected_items.len() == props.items.len();
+        && !row_items.is_empty()
+        && selected_visible_count == row_items.len();
 
     let some_selected = props.selection_type == Some(SelectionType::Multi)
-        && !props.selected_items.is_empty()
-        && props.selected_items.len() < props.items.len();
+        && selected_visible_count > 0
+        && selected_visible_count < row_items.len();
 
-    html! {
-        <div class={root_classes.build()} id={props.base.id.clone()}>
+    // Skeleton rows are hidden from screen readers, so the loading text is
+    // announced instead
+    let skeleton_loading = props.loading_variant != TableLoadingVariant::Spinner;
+    let skeleton_loading_message = if props.loading {
+        props
+            .loading_text
+            .clone()
+            .unwrap_or_else(|| "Loading".to_string())
+    } else {
+        String::new()
+    };
+
+    // Keep announcing the last loaded item count while loading
+    let last_items_count = use_mut_ref(|| items.len());
+    if !props.loading {
+        *last_items_count.borrow_mut() = items.len();
+    }
+    let announced_items_count = *last_items_count.borrow();
+
+    let total_count = props.total_items_count.unwrap_or(items.len());
+    let selected_count = selection.count(total_count);
+    let show_selection_summary =
+        props.show_selection_summary && props.selection_type == Some(SelectionType::Multi);
+
+    // Offer selecting every matching item once the whole page is selected,
+    // and show the all-matching selection with a way out
+    let all_matching_available = props.select_all_mode == SelectAllMode::AllMatching
+        && props.selection_type == Some(SelectionType::Multi)
+        && props.track_by.is_some()
+        && props.total_items_count.is_some();
+    let select_all_banner = match &props.selection_state {
+        SelectionState::Items => {
+            all_matching_available && all_selected && total_count > visible_items.len()
+        }
+        SelectionState::AllMatching { .. } => all_matching_available,
+    }
+    .then(|| {
+        strings.select_all_banner_text(&props.selection_state, visible_items.len(), total_count)
+    });
+    let on_select_all_banner_action = match props.selection_state {
+        SelectionState::Items => on_select_all_matching,
+        SelectionState::AllMatching { .. } => on_clear_selection.clone(),
+    };
+
+    let root = html! {
+        <div
+            class={root_classes}
+            id={props.base.id.clone()}
+            style={props.base.merge_style(None)}
+        >
             // Header section
-            if let Some(ref header) = props.header {
-                <div class="awsui-table-header">
-                    { header.clone() }
+            if props.header.is_some() || show_selection_summary {
+                <div class="awsui-table-header" style={props.base.part_style("header", None)}>
+                    if let Some(ref header) = props.header {
+                        { header.clone() }
+                    }
+                    if show_selection_summary {
+                        <div class="awsui-table-selection-summary">
+                            <span class="awsui-table-selection-summary-text">
+                                { strings.selection_summary_text(selected_count, total_count) }
+                            </span>
+                            if selected_count > 0 {
+                                <Button
+                                    variant={ButtonVariant::InlineLink}
+                                    form_action={FormAction::None}
+                                    on_click={on_clear_selection}
+                                >
+                                    { Html::from(strings.clear_selection_text.clone()) }
+                                </Button>
+                            }
+                        </div>
+                    }
+                </div>
+            }
+
+            // Screen reader announcements for item and selection counts
+            <LiveRegion message={strings.items_count_text(announced_items_count)} />
+            if skeleton_loading && !coordinated {
+                <LiveRegion message={skeleton_loading_message} />
+            }
+            if props.selection_type.is_some() {
+                <LiveRegion message={strings.selected_count_text(selected_count)} />
+            }
+
+            // Filter section
+            if props.filter.is_some() || props.filtering.is_some() {
+                <div class="awsui-table-filter">
+                    if let Some(ref filter) = props.filter {
+                        { filter.clone() }
+                    }
+                    if let Some(ref filtering) = props.filtering {
+                        <div class="awsui-table-text-filter">
+                            <Input
+                                input_type={InputType::Search}
+                                value={filtering.filtering_text.clone()}
+                                placeholder={filtering.placeholder.clone()}
+                                disabled={props.loading}
+                                on_change={on_filter_input_change}
+                                aria={AriaAttributes {
+                                    label: Some(
+                                        strings.filtering_aria_label(filtering.placeholder.as_deref()),
+                                    ),
+                                    ..Default::default()
+                                }}
+                            />
+                            if !filtering.filtering_text.is_empty() && !props.loading {
+                                <span class="awsui-table-text-filter-matches">
+                                    { strings.matches_count_text(items.len()) }
+                                </span>
+                            }
+                        </div>
+                    }
                 </div>
             }
 
             // Table container
-            <div class={container_classes.build()}>
-                <table class="awsui-table-element" role="table">
+            <div class={container_classes}>
+                <table ref={table_ref} class={table_classes} role={table_role(tree, props.enable_keyboard_navigation)}>
                     // Table head
                     <thead class="awsui-table-thead">
                         <tr class="awsui-table-row">
                             // Selection column header (for multi-select)
                             if props.selection_type == Some(SelectionType::Multi) {
-                                <th class="awsui-table-header-cell awsui-table-selection-header" scope="col">
+                                <th
+                                    class="awsui-table-header-cell awsui-table-selection-header"
+                                    scope="col"
+                                    style={header_cell_style(None)}
+                                >
                                     <div class="awsui-table-header-cell-content">
-                                        <input
-                                            type="checkbox"
-                                            class="awsui-table-selection-checkbox"
+                                        <Checkbox
+                                            base={BaseComponentProps {
+                                                class: Some("awsui-table-selection-checkbox".to_string()),
+                                                ..Default::default()
+                                            }}
                                             checked={all_selected}
-                                            indeterminate={some_selected.to_string()}
-                                            onclick={on_select_all}
-                                            aria-label="Select all items"
+                                            indeterminate={some_selected}
+                                            on_change={on_select_all}
+                                            aria_label="Select all items"
                                         />
                                     </div>
                                 </th>
                             } else if props.selection_type == Some(SelectionType::Single) {
                                 // Empty header cell for single selection
-                                <th class="awsui-table-header-cell awsui-table-selection-header" scope="col">
+                                <th
+                                    class="awsui-table-header-cell awsui-table-selection-header"
+                                    scope="col"
+                                    style={header_cell_style(None)}
+                                >
                                 </th>
                             }
 
@@ -425,9 +2315,11 @@ pub fn table<T: Clone + PartialEq + 'static>(props: &TableProps<T>) -> Html {
                                         None
                                     };
 
+                                    let resizable = props.resizable_columns && column.resizable;
                                     let header_classes = ClassBuilder::new()
                                         .add("awsui-table-header-cell")
-                                        .add_if(column.sortable, "awsui-table-header-cell-sortable");
+                                        .add_if(column.sortable, "awsui-table-header-cell-sortable")
+                                        .add_if(resizable, "awsui-table-header-cell-resizable");
 
                                     let column_id = column.id.clone();
                                     let on_sort_click_clone = on_sort_click.clone();
@@ -437,7 +2329,8 @@ pub fn table<T: Clone + PartialEq + 'static>(props: &TableProps<T>) -> Html {
                                         on_sort_click_clone.emit(column_id.clone());
                                     });
 
-                                    let cell_style = build_cell_style(&column.width, &column.min_width);
+                                    let cell_style =
+                                        header_cell_style(column_cell_style(column, user_width(column)));
 
                                     // Determine aria-sort attribute
                                     let aria_sort = if is_sorted {
@@ -455,9 +2348,9 @@ pub fn table<T: Clone + PartialEq + 'static>(props: &TableProps<T>) -> Html {
                                     html! {
                                         <th
                                             key={column.id.clone()}
-                                            class={header_classes.build()}
+                                            class={header_classes}
                                             scope="col"
-                                            style={cell_style}
+                                            style={props.base.part_style("header-cell", cell_style.as_deref())}
                                             aria-sort={aria_sort}
                                         >
                                             if column.sortable {
@@ -490,6 +2383,13 @@ pub fn table<T: Clone + PartialEq + 'static>(props: &TableProps<T>) -> Html {
                                                     </span>
                                                 </div>
                                             }
+                                            if resizable {
+                                                { column_resize.render_handle(
+                                                    &column.id,
+                                                    &column.header,
+                                                    min_column_width(column.min_width.as_deref()),
+                                                ) }
+                                            }
                                         </th>
                                     }
                                 }).collect::<Html>()
@@ -498,9 +2398,31 @@ pub fn table<T: Clone + PartialEq + 'static>(props: &TableProps<T>) -> Html {
                     </thead>
 
                     // Table body
-                    <tbody class="awsui-table-tbody">
+                    <tbody
+                        class="awsui-table-tbody"
+                        onkeydown={grid_enabled.then_some(on_grid_key_down)}
+                        onfocusin={grid_enabled.then_some(on_grid_focus_in)}
+                        onfocusout={grid_enabled.then_some(on_grid_focus_out)}
+                    >
+                        if let Some((status, action)) = select_all_banner {
+                            <tr class="awsui-table-row awsui-table-select-all-banner">
+                                <td class="awsui-table-cell" colspan={calculate_colspan(props)}>
+                                    <span class="awsui-table-select-all-banner-text">{ status }</span>
+                                    { " " }
+                                    <Button
+                                        variant={ButtonVariant::InlineLink}
+                                        form_action={FormAction::None}
+                                        on_click={on_select_all_banner_action}
+                                    >
+                                        { Html::from(action) }
+                                    </Button>
+                                </td>
+                            </tr>
+                        }
                         {
-                            if props.loading {
+                            if props.loading && skeleton_loading {
+                                render_skeleton_rows(props, user_width)
+                            } else if props.loading {
                                 // Loading state
                                 html! {
                                     <tr class="awsui-table-row">
@@ -518,7 +2440,25 @@ pub fn table<T: Clone + PartialEq + 'static>(props: &TableProps<T>) -> Html {
                                         </td>
                                     </tr>
                                 }
-                            } else if props.items.is_empty() {
+                            } else if items.is_empty() && !props.items.is_empty() {
+                                // No items match the built-in filter
+                                html! {
+                                    <tr class="awsui-table-row">
+                                        <td
+                                            class="awsui-table-cell awsui-table-empty awsui-table-no-match"
+                                            colspan={calculate_colspan(props)}
+                                        >
+                                            <div class="awsui-table-empty-default">{ strings.no_match_text.clone() }</div>
+                                            <Button
+                                                form_action={FormAction::None}
+                                                on_click={on_clear_filter}
+                                            >
+                                                { Html::from(strings.clear_filter_text.clone()) }
+                                            </Button>
+                                        </td>
+                                    </tr>
+                                }
+                            } else if items.is_empty() {
                                 // Empty state
                                 html! {
                                     <tr class="awsui-table-row">
@@ -538,21 +2478,74 @@ pub fn table<T: Clone + PartialEq + 'static>(props: &TableProps<T>) -> Html {
                                 }
                             } else {
                                 // Data rows
-                                props.items.iter().map(|item| {
-                                    let is_selected = props.selected_items.contains(item);
+                                rows.iter().enumerate().map(|(index, row)| {
+                                    let item = &row.item;
+                                    let is_selected = selection.contains(item);
+                                    let row_style = props.row_style.and_then(|row_style| row_style(item));
+                                    let background = row_background(
+                                        row_style.as_ref(),
+                                        is_selected,
+                                        props.striped_rows,
+                                        index,
+                                    );
 
+                                    let row_clickable = props.select_on_row_click && props.selection_type.is_some();
                                     let row_classes = ClassBuilder::new()
                                         .add("awsui-table-row")
-                                        .add_if(is_selected, "awsui-table-row-selected");
+                                        .add_if(is_selected, "awsui-table-row-selected")
+                                        .add_if(row_clickable, "awsui-table-row-clickable")
+                                        .add_if(background == RowBackground::Stripe, "awsui-table-row-striped")
+                                        .add_if(
+                                            matches!(background, RowBackground::Tinted(_)),
+                                            "awsui-table-row-tinted",
+                                        )
+                                        .add_opt(row_style.as_ref().map(|style| style.severity.row_class()));
+                                    let row_background_style = match background {
+                                        RowBackground::Tinted(severity) => {
+                                            Some(format!("background-color: {}", severity.background_color()))
+                                        }
+                                        _ => None,
+                                    };
+                                    let column_count = props.columns.len();
+                                    let selection_column = usize::from(props.selection_type.is_some());
 
                                     let item_clone = item.clone();
                                     let on_row_select_clone = on_row_select.clone();
 
+                                    // Clicks on the row body toggle its selection
+                                    let on_row_click = row_clickable.then(|| {
+                                        let item = item.clone();
+                                        let on_row_select = on_row_select.clone();
+                                        Callback::from(move |e: MouseEvent| {
+                                            if !is_interactive_target(&e, None) {
+                                                on_row_select.emit(item.clone());
+                                            }
+                                        })
+                                    });
+
                                     html! {
-                                        <tr class={row_classes.build()} role="row">
+                                        <tr
+                                            class={row_classes}
+                                            onclick={on_row_click}
+                                            style={props.base.part_style("row", row_background_style.as_deref())}
+                                            role="row"
+                                            aria-level={tree.then(|| row.level.to_string())}
+                                            aria-setsize={tree.then(|| row.set_size.to_string())}
+                                            aria-posinset={tree.then(|| row.pos_in_set.to_string())}
+                                            aria-expanded={row.expandable.then(|| row.expanded.to_string())}
+                                        >
                                             // Selection cell
                                             if let Some(selection_type) = props.selection_type {
-                                                <td class="awsui-table-cell awsui-table-selection-cell">
+                                                <td
+                                                    class="awsui-table-cell awsui-table-selection-cell"
+                                                    style={props.base.part_style(
+                                                        "cell",
+                                                        accent_cell_style(row_style.as_ref(), None).as_deref(),
+                                                    )}
+                                                    ref={grid.node_ref((index, 0))}
+                                                    tabindex={grid.tabindex((index, 0))}
+                                                    onfocusin={grid.on_focus((index, 0))}
+                                                >
                                                     {
                                                         match selection_type {
                                                             SelectionType::Single => {
@@ -568,6 +2561,7 @@ pub fn table<T: Clone + PartialEq + 'static>(props: &TableProps<T>) -> Html {
                                                                         checked={is_selected}
                                                                         onclick={on_select}
                                                                         aria-label="Select item"
+                                                                        tabindex={grid.control_tabindex()}
                                                                     />
                                                                 }
                                                             }
@@ -584,6 +2578,7 @@ pub fn table<T: Clone + PartialEq + 'static>(props: &TableProps<T>) -> Html {
                                                                         checked={is_selected}
                                                                         onclick={on_select}
                                                                         aria-label="Select item"
+                                                                        tabindex={grid.control_tabindex()}
                                                                     />
                                                                 }
                                                             }
@@ -594,17 +2589,85 @@ pub fn table<T: Clone + PartialEq + 'static>(props: &TableProps<T>) -> Html {
 
                                             // Data cells
                                             {
-                                                props.columns.iter().map(|column| {
-                                                    let cell_content = (column.cell)(item);
-                                                    let cell_style = build_cell_style(&column.width, &column.min_width);
+                                                props.columns.iter().enumerate().map(|(column_index, column)| {
+                                                    let cell = (index, column_index + selection_column);
+                                                    let mut cell_content = (column.cell)(item);
+                                                    if tree && column_index == 0 {
+                                                        cell_content = render_expandable_cell(
+                                                            row,
+                                                            cell_content,
+                                                            on_expand_toggle.clone(),
+                                                            grid.control_tabindex(),
+                                                        );
+                                                    }
+                                                    let mut cell_style = column_cell_style(column, user_width(column));
+                                                    if column_index == 0 && props.selection_type.is_none() {
+                                                        cell_style = accent_cell_style(row_style.as_ref(), cell_style);
+                                                    }
+                                                    let description = row_style
+                                                        .as_ref()
+                                                        .and_then(|style| style.aria_description.clone())
+                                                        .filter(|_| column_index + 1 == column_count);
+
+                                                    // Inline editing of the cell
+                                                    let edit = editing
+                                                        .as_ref()
+                                                        .filter(|edit| edit.is_cell(item, &column.id, props.track_by));
+                                                    let edit_handlers = column.edit_config.clone().map(|config| {
+                                                        cell_edit_handlers(
+                                                            config,
+                                                            item.clone(),
+                                                            column.id.clone(),
+                                                            editing.as_ref(),
+                                                            set_editing.clone(),
+                                                            props.track_by,
+                                                            props.submit_edit.clone(),
+                                                        )
+                                                    });
+                                                    let cell_classes = ClassBuilder::new()
+                                                        .add("awsui-table-cell")
+                                                        .add_if(edit_handlers.is_some(), "awsui-table-cell-editable")
+                                                        .add_if(edit.is_some(), "awsui-table-cell-editing")
+                                                        .add_if(
+                                                            edit.is_some_and(|edit| edit.error_text.is_some()),
+                                                            "awsui-table-cell-invalid",
+                                                        );
+                                                    let cell_tabindex = grid
+                                                        .tabindex(cell)
+                                                        .or(edit_handlers.as_ref().map(|_| "0"));
 
                                                     html! {
                                                         <td
                                                             key={column.id.clone()}
-                                                            class="awsui-table-cell"
-                                                            style={cell_style}
+                                                            class={cell_classes.build()}
+                                                            style={props.base.part_style("cell", cell_style.as_deref())}
+                                                            ref={grid.node_ref(cell)}
+                                                            tabindex={cell_tabindex}
+                                                            onfocusin={grid.on_focus(cell)}
+                                                            onclick={edit_handlers.as_ref().map(|handlers| handlers.on_click.clone())}
+                                                            onkeydown={edit_handlers.as_ref().map(|handlers| handlers.on_key_down.clone())}
                                                         >
-                                                            { cell_content }
+                                                            if let (Some(edit), Some(handlers)) = (edit, &edit_handlers) {
+                                                                { (handlers.config.editor)(item, TableCellEditor {
+                                                                    value: edit.value.clone(),
+                                                                    error_text: edit.error_text.clone(),
+                                                                    on_change: handlers.on_change.clone(),
+                                                                    on_commit: handlers.on_commit.clone(),
+                                                                    on_cancel: handlers.on_cancel.clone(),
+                                                                }) }
+                                                                if let Some(error_text) = &edit.error_text {
+                                                                    <span class="awsui-table-cell-error" role="alert">
+                                                                        { error_text.clone() }
+                                                                    </span>
+                                                                }
+                                                            } else {
+                                                                { cell_content }
+                                                            }
+                                                            if let Some(description) = description {
+                                                                <span style={VISUALLY_HIDDEN_STYLE}>
+                                                                    { format!(" {}", description) }
+                                                                </span>
+                                                            }
                                                         </td>
                                                     }
                                                 }).collect::<Html>()
@@ -619,43 +2682,636 @@ pub fn table<T: Clone + PartialEq + 'static>(props: &TableProps<T>) -> Html {
             </div>
 
             // Footer section (pagination, etc.)
-            if let Some(ref footer) = props.footer {
-                <div class="awsui-table-footer">
-                    { footer.clone() }
+            if props.footer.is_some() || props.pagination.is_some() {
+                <div class="awsui-table-footer" style={props.base.part_style("footer", None)}>
+                    if let Some(ref footer) = props.footer {
+                        { footer.clone() }
+                    }
+                    if let (Some(pagination), Some((current_page, pages_count))) =
+                        (&props.pagination, page)
+                    {
+                        <div class="awsui-table-pagination">
+                            <Pagination
+                                current_page_index={current_page}
+                                pages_count={pages_count}
+                                disabled={props.loading}
+                                on_change={pagination.on_page_change.clone()}
+                            />
+                        </div>
+                    }
                 </div>
             }
         </div>
+    };
+    apply_root_attributes(root, &props.base)
+}
+
+/// Handlers of a cell that can be edited inline
+struct CellEditHandlers<T: Clone + PartialEq + 'static> {
+    config: TableEditConfig<T>,
+    /// Starts editing on clicks outside of interactive content
+    on_click: Callback<MouseEvent>,
+    /// Starts editing on Enter while the cell has focus, then commits on
+    /// Enter and cancels on Escape while editing
+    on_key_down: Callback<KeyboardEvent>,
+    on_change: Callback<String>,
+    on_commit: Callback<()>,
+    on_cancel: Callback<()>,
+}
+
+/// Builds the handlers editing the cell of `item` in column `column_id`
+///
+/// `editing` is the cell being edited in the table, if any, which the
+/// handlers replace through `set_editing`.
+fn cell_edit_handlers<T: Clone + PartialEq + 'static>(
+    config: TableEditConfig<T>,
+    item: T,
+    column_id: String,
+    editing: Option<&CellEdit<T>>,
+    set_editing: Callback<Option<CellEdit<T>>>,
+    track_by: Option<fn(&T) -> String>,
+    submit_edit: Option<Callback<CustomEvent<TableCellEditDetail<T>>>>,
+) -> CellEditHandlers<T> {
+    let current = editing
+        .filter(|edit| edit.is_cell(&item, &column_id, track_by))
+        .cloned();
+
+    let on_start = {
+        let set_editing = set_editing.clone();
+        let item = item.clone();
+        let value = (config.value)(&item);
+        Callback::from(move |()| {
+            set_editing.emit(Some(CellEdit {
+                item: item.clone(),
+                column_id: column_id.clone(),
+                value: value.clone(),
+                error_text: None,
+            }))
+        })
+    };
+
+    let on_change = {
+        let set_editing = set_editing.clone();
+        let current = current.clone();
+        Callback::from(move |value: String| {
+            if let Some(edit) = &current {
+                set_editing.emit(Some(CellEdit {
+                    value,
+                    error_text: None,
+                    ..edit.clone()
+                }));
+            }
+        })
+    };
+
+    // Whether committing closes the editor, so focus returns to the cell
+    let valid = current
+        .as_ref()
+        .is_some_and(|edit| validate_edit(&config, &item, &edit.column_id, &edit.value).is_ok());
+
+    let on_commit = {
+        let set_editing = set_editing.clone();
+        let current = current.clone();
+        let config = config.clone();
+        Callback::from(move |()| {
+            let Some(edit) = &current else { return };
+            match validate_edit(&config, &item, &edit.column_id, &edit.value) {
+                Ok(detail) => {
+                    set_editing.emit(None);
+                    if let Some(callback) = &submit_edit {
+                        callback.emit(CustomEvent::new_non_cancelable(detail));
+                    }
+                }
+                Err(error_text) => set_editing.emit(Some(CellEdit {
+                    error_text: Some(error_text),
+                    ..edit.clone()
+                })),
+            }
+        })
+    };
+
+    let on_cancel = Callback::from(move |()| set_editing.emit(None));
+
+    let is_editing = current.is_some();
+    let on_click = {
+        let on_start = on_start.clone();
+        Callback::from(move |e: MouseEvent| {
+            if is_interactive_target(&e, None) {
+                return;
+            }
+            // The click edits the cell rather than selecting the row
+            e.stop_propagation();
+            if !is_editing {
+                on_start.emit(());
+            }
+        })
+    };
+
+    let on_key_down = {
+        let on_commit = on_commit.clone();
+        let on_cancel = on_cancel.clone();
+        Callback::from(move |e: KeyboardEvent| {
+            let cell = e
+                .current_target()
+                .and_then(|cell| cell.dyn_into::<HtmlElement>().ok());
+            let on_cell = cell.as_ref().is_some_and(|cell| {
+                e.target()
+                    .and_then(|target| target.dyn_into::<web_sys::Node>().ok())
+                    .is_some_and(|target| cell.is_same_node(Some(&target)))
+            });
+            match (is_editing, edit_key(&e.key())) {
+                (false, Some(EditKey::Commit)) if on_cell => {
+                    e.prevent_default();
+                    on_start.emit(());
+                }
+                (true, Some(key)) => {
+                    e.prevent_default();
+                    e.stop_propagation();
+                    match key {
+                        EditKey::Commit => on_commit.emit(()),
+                        EditKey::Cancel => on_cancel.emit(()),
+                    }
+                    // The editor goes away, so focus returns to the cell
+                    if (key == EditKey::Cancel || valid)
+                        && let Some(cell) = cell
+                    {
+                        let _ = cell.focus();
+                    }
+                }
+                _ => {}
+            }
+        })
+    };
+
+    CellEditHandlers {
+        config,
+        on_click,
+        on_key_down,
+        on_change,
+        on_commit,
+        on_cancel,
+    }
+}
+
+/// Renders the first cell content of a hierarchical row: indented by level
+/// and preceded by the toggle of expandable rows
+///
+/// `tabindex` takes the toggle out of the tab order in a keyboard
+/// navigation grid.
+fn render_expandable_cell<T: Clone + PartialEq + 'static>(
+    row: &TableRow<T>,
+    content: Html,
+    on_toggle: Callback<(T, bool)>,
+    tabindex: Option<&'static str>,
+) -> Html {
+    let toggle_classes = ClassBuilder::new()
+        .add("awsui-table-expand-toggle")
+        .add_if(row.expanded, "awsui-table-expand-toggle-expanded");
+    let on_click = {
+        let item = row.item.clone();
+        let expanded = row.expanded;
+        Callback::from(move |e: MouseEvent| {
+            e.stop_propagation();
+            on_toggle.emit((item.clone(), !expanded));
+        })
+    };
+
+    html! {
+        <div
+            class="awsui-table-expandable-cell"
+            style={format!("padding-inline-start: {}px", (row.level - 1) * ROW_LEVEL_INDENT)}
+        >
+            if row.expandable {
+                <button
+                    type="button"
+                    class={toggle_classes}
+                    aria-label="Expand row"
+                    aria-expanded={row.expanded.to_string()}
+                    tabindex={tabindex}
+                    onclick={on_click}
+                >
+                    <svg
+                        class="awsui-table-expand-toggle-icon"
+                        focusable="false"
+                        aria-hidden="true"
+                        viewBox="0 0 16 16"
+                    >
+                        <path d="M8 10.5L3.5 6h9L8 10.5z" />
+                    </svg>
+                </button>
+            } else {
+                <span class="awsui-table-expand-toggle-placeholder" aria-hidden="true" />
+            }
+            <div class="awsui-table-expandable-cell-content">{ content }</div>
+        </div>
+    }
+}
+
+/// Number of rows Page Up and Page Down move the active cell by
+const PAGE_ROWS: usize = 10;
+
+/// Gets the cell a navigation key moves the active `(row, column)` cell to,
+/// or `None` for other keys
+fn navigate_cell(
+    key: &str,
+    (row, column): (usize, usize),
+    row_count: usize,
+    column_count: usize,
+) -> Option<(usize, usize)> {
+    if row_count == 0 || column_count == 0 {
+        return None;
+    }
+    let last_row = row_count - 1;
+    let last_column = column_count - 1;
+    let cell = match key {
+        "ArrowUp" => (row.saturating_sub(1), column),
+        "ArrowDown" => ((row + 1).min(last_row), column),
+        "ArrowLeft" => (row, column.saturating_sub(1)),
+        "ArrowRight" => (row, (column + 1).min(last_column)),
+        "Home" => (row, 0),
+        "End" => (row, last_column),
+        "PageUp" => (row.saturating_sub(PAGE_ROWS), column),
+        "PageDown" => ((row + PAGE_ROWS).min(last_row), column),
+        _ => return None,
+    };
+    Some(cell)
+}
+
+/// Keeps the active cell within the grid, so the last row takes over when
+/// the active row disappears
+fn clamp_cell(
+    (row, column): (usize, usize),
+    row_count: usize,
+    column_count: usize,
+) -> (usize, usize) {
+    (
+        row.min(row_count.saturating_sub(1)),
+        column.min(column_count.saturating_sub(1)),
+    )
+}
+
+/// Roving focus of the keyboard navigation grid, shared by the body cells
+#[derive(Clone)]
+struct GridFocus {
+    enabled: bool,
+    /// Active cell, within the grid
+    active: (usize, usize),
+    active_cell: UseStateHandle<(usize, usize)>,
+    active_cell_ref: NodeRef,
+}
+
+impl GridFocus {
+    /// Tab index of a cell: only the active cell is in the tab order
+    fn tabindex(&self, cell: (usize, usize)) -> Option<&'static str> {
+        self.enabled
+            .then_some(if cell == self.active { "0" } else { "-1" })
+    }
+
+    /// Reference of a cell, set on the active cell only
+    fn node_ref(&self, cell: (usize, usize)) -> NodeRef {
+        if self.enabled && cell == self.active {
+            self.active_cell_ref.clone()
+        } else {
+            NodeRef::default()
+        }
+    }
+
+    /// Makes a cell active when it, or a control in it, gets focus
+    fn on_focus(&self, cell: (usize, usize)) -> Option<Callback<FocusEvent>> {
+        let active_cell = self.active_cell.clone();
+        let active = self.active;
+        self.enabled.then(|| {
+            Callback::from(move |_: FocusEvent| {
+                if cell != active {
+                    active_cell.set(cell);
+                }
+            })
+        })
+    }
+
+    /// Tab index of the controls in cells, which leave the tab order to
+    /// the grid
+    fn control_tabindex(&self) -> Option<&'static str> {
+        self.enabled.then_some("-1")
+    }
+}
+
+/// ARIA role of the table element
+fn table_role(tree: bool, keyboard_navigation: bool) -> &'static str {
+    match (tree, keyboard_navigation) {
+        (true, _) => "treegrid",
+        (false, true) => "grid",
+        (false, false) => "table",
+    }
+}
+
+/// Number of cells in a row: the columns, and the selection cell
+fn column_count<T: Clone + PartialEq + 'static>(props: &TableProps<T>) -> usize {
+    props.columns.len() + usize::from(props.selection_type.is_some())
+}
+
+/// Calculates the colspan for loading and empty states
+fn calculate_colspan<T: Clone + PartialEq + 'static>(props: &TableProps<T>) -> String {
+    column_count(props).to_string()
+}
+
+/// Number of placeholder rows of a skeleton loading state
+fn skeleton_rows_count(variant: TableLoadingVariant) -> usize {
+    match variant {
+        TableLoadingVariant::Spinner => 0,
+        TableLoadingVariant::Skeleton { rows } => rows.max(1),
+    }
+}
+
+/// Renders the placeholder rows of a skeleton loading state
+///
+/// The rows are hidden from screen readers; the table announces the loading
+/// text instead.
+fn render_skeleton_rows<T: Clone + PartialEq + 'static>(
+    props: &TableProps<T>,
+    user_width: impl Fn(&TableColumn<T>) -> Option<f64>,
+) -> Html {
+    (0..skeleton_rows_count(props.loading_variant))
+        .map(|index| {
+            html! {
+                <tr
+                    key={index}
+                    class="awsui-table-row awsui-table-skeleton-row"
+                    aria-hidden="true"
+                >
+                    if props.selection_type.is_some() {
+                        <td class="awsui-table-cell awsui-table-selection-cell">
+                            <span class="awsui-table-skeleton-block awsui-table-skeleton-control" />
+                        </td>
+                    }
+                    {
+                        props.columns.iter().map(|column| {
+                            let cell_style = column_cell_style(column, user_width(column));
+                            html! {
+                                <td
+                                    key={column.id.clone()}
+                                    class="awsui-table-cell"
+                                    style={props.base.part_style("cell", cell_style.as_deref())}
+                                >
+                                    <span class="awsui-table-skeleton-block" />
+                                </td>
+                            }
+                        }).collect::<Html>()
+                    }
+                </tr>
+            }
+        })
+        .collect()
+}
+
+/// Builds the style attribute for a table cell based on width constraints
+fn build_cell_style(width: &Option<String>, min_width: &Option<String>) -> Option<String> {
+    let mut styles = Vec::new();
+
+    if let Some(w) = width {
+        styles.push(format!("width: {}", w));
+    }
+
+    if let Some(min_w) = min_width {
+        styles.push(format!("min-width: {}", min_w));
+    }
+
+    if styles.is_empty() {
+        None
+    } else {
+        Some(styles.join("; "))
     }
 }
 
-/// Calculates the colspan for loading and empty states
-fn calculate_colspan<T: Clone + PartialEq + 'static>(props: &TableProps<T>) -> String {
-    let selection_col = if props.selection_type.is_some() { 1 } else { 0 };
-    (props.columns.len() + selection_col).to_string()
+/// Smallest width of a resizable column without a pixel `min_width`
+const DEFAULT_MIN_COLUMN_WIDTH: f64 = 80.0;
+
+/// Width change of a column per arrow key press on its resize handle
+const KEYBOARD_RESIZE_STEP: f64 = 10.0;
+
+/// Gets the smallest width a column can be resized to, in pixels
+///
+/// Only pixel (or unitless) minimum widths can be compared to the dragged
+/// width; other units fall back to the default.
+fn min_column_width(min_width: Option<&str>) -> f64 {
+    min_width
+        .and_then(|min_width| {
+            let min_width = min_width.trim();
+            min_width
+                .strip_suffix("px")
+                .unwrap_or(min_width)
+                .trim()
+                .parse::<f64>()
+                .ok()
+        })
+        .filter(|min_width| min_width.is_finite() && *min_width >= 0.0)
+        .unwrap_or(DEFAULT_MIN_COLUMN_WIDTH)
+}
+
+/// Gets the width of a column after an arrow key press on its resize handle,
+/// or `None` for other keys
+fn keyboard_resized_width(width: f64, key: &str, min_width: f64) -> Option<f64> {
+    match key {
+        "ArrowLeft" => Some((width - KEYBOARD_RESIZE_STEP).max(min_width).round()),
+        "ArrowRight" => Some((width + KEYBOARD_RESIZE_STEP).round()),
+        _ => None,
+    }
+}
+
+/// Builds the style attribute for a cell of a column, using the width the
+/// user resized the column to, if any
+fn column_cell_style<T: Clone + PartialEq + 'static>(
+    column: &TableColumn<T>,
+    user_width: Option<f64>,
+) -> Option<String> {
+    match user_width {
+        Some(width) => build_cell_style(&Some(format!("{}px", width)), &column.min_width),
+        None => build_cell_style(&column.width, &column.min_width),
+    }
+}
+
+/// Gets the rendered width of the header cell containing an event target
+fn header_cell_width(target: Option<web_sys::EventTarget>) -> Option<f64> {
+    target
+        .and_then(|target| target.dyn_into::<Element>().ok())
+        .and_then(|element| element.closest("th").ok().flatten())
+        .map(|cell| cell.get_bounding_client_rect().width())
+}
+
+/// Column being resized by dragging its handle
+struct ColumnDrag {
+    column_id: String,
+    start_x: f64,
+    start_width: f64,
+    min_width: f64,
+}
+
+/// Column widths set by the user, shared by the resize handles
+#[derive(Clone)]
+struct ColumnResize {
+    widths: UseStateHandle<HashMap<String, f64>>,
+    drag: Rc<RefCell<Option<ColumnDrag>>>,
+    on_change: Option<Callback<CustomEvent<ColumnWidthsChangeDetail>>>,
 }
 
-/// Builds the style attribute for a table cell based on width constraints
-fn build_cell_style(width: &Option<String>, min_width: &Option<String>) -> Option<String> {
-    let mut styles = Vec::new();
+impl ColumnResize {
+    /// Sets the width of a column, or restores its default width
+    fn set_width(&self, column_id: &str, width: Option<f64>, notify: bool) {
+        let mut widths = (*self.widths).clone();
+        match width {
+            Some(width) => widths.insert(column_id.to_string(), width),
+            None => widths.remove(column_id),
+        };
+        self.widths.set(widths.clone());
 
-    if let Some(w) = width {
-        styles.push(format!("width: {}", w));
+        if notify && let Some(callback) = &self.on_change {
+            callback.emit(CustomEvent::new_non_cancelable(ColumnWidthsChangeDetail {
+                widths,
+            }));
+        }
     }
 
-    if let Some(min_w) = min_width {
-        styles.push(format!("min-width: {}", min_w));
-    }
+    /// Renders the resize handle of a column
+    fn render_handle(&self, column_id: &str, header: &str, min_width: f64) -> Html {
+        let width = self.widths.get(column_id).copied();
 
-    if styles.is_empty() {
-        None
-    } else {
-        Some(styles.join("; "))
+        let on_pointer_down = {
+            let resize = self.clone();
+            let column_id = column_id.to_string();
+            Callback::from(move |e: PointerEvent| {
+                if e.button() != 0 {
+                    return;
+                }
+                let Some(start_width) = resize
+                    .widths
+                    .get(&column_id)
+                    .copied()
+                    .or_else(|| header_cell_width(e.target()))
+                else {
+                    return;
+                };
+                // Keep the drag from selecting text
+                e.prevent_default();
+                if let Some(handle) = e
+                    .target()
+                    .and_then(|target| target.dyn_into::<Element>().ok())
+                {
+                    let _ = handle.set_pointer_capture(e.pointer_id());
+                }
+                *resize.drag.borrow_mut() = Some(ColumnDrag {
+                    column_id: column_id.clone(),
+                    start_x: e.client_x() as f64,
+                    start_width,
+                    min_width,
+                });
+            })
+        };
+
+        // The handle captures the pointer, so it gets the moves and the
+        // release even when the pointer leaves it
+        let drag_width = |drag: &RefCell<Option<ColumnDrag>>, column_id: &str, e: &PointerEvent| {
+            drag.borrow()
+                .as_ref()
+                .filter(|drag| drag.column_id == column_id)
+                .map(|drag| {
+                    dragged_size(
+                        drag.start_width,
+                        e.client_x() as f64 - drag.start_x,
+                        drag.min_width,
+                        f64::INFINITY,
+                    )
+                })
+        };
+
+        let on_pointer_move = {
+            let resize = self.clone();
+            let column_id = column_id.to_string();
+            Callback::from(move |e: PointerEvent| {
+                if let Some(width) = drag_width(&resize.drag, &column_id, &e) {
+                    resize.set_width(&column_id, Some(width), false);
+                }
+            })
+        };
+
+        let on_pointer_up = {
+            let resize = self.clone();
+            let column_id = column_id.to_string();
+            Callback::from(move |e: PointerEvent| {
+                if let Some(width) = drag_width(&resize.drag, &column_id, &e) {
+                    resize.drag.borrow_mut().take();
+                    resize.set_width(&column_id, Some(width), true);
+                }
+            })
+        };
+
+        let on_pointer_cancel = {
+            let resize = self.clone();
+            let column_id = column_id.to_string();
+            Callback::from(move |_: PointerEvent| {
+                let dragging = resize
+                    .drag
+                    .borrow_mut()
+                    .take_if(|drag| drag.column_id == column_id)
+                    .is_some();
+                if dragging {
+                    let width = resize.widths.get(&column_id).copied();
+                    resize.set_width(&column_id, width, true);
+                }
+            })
+        };
+
+        let on_double_click = {
+            let resize = self.clone();
+            let column_id = column_id.to_string();
+            Callback::from(move |e: MouseEvent| {
+                e.stop_propagation();
+                resize.set_width(&column_id, None, true);
+            })
+        };
+
+        let on_key_down = {
+            let resize = self.clone();
+            let column_id = column_id.to_string();
+            Callback::from(move |e: KeyboardEvent| {
+                let current = resize
+                    .widths
+                    .get(&column_id)
+                    .copied()
+                    .or_else(|| header_cell_width(e.target()));
+                if let Some(width) =
+                    current.and_then(|current| keyboard_resized_width(current, &e.key(), min_width))
+                {
+                    e.prevent_default();
+                    resize.set_width(&column_id, Some(width), true);
+                }
+            })
+        };
+
+        html! {
+            <span
+                class="awsui-table-resize-handle"
+                role="separator"
+                tabindex="0"
+                aria-orientation="vertical"
+                aria-label={format!("Resize column {}", header)}
+                aria-valuemin={min_width.to_string()}
+                aria-valuenow={width.map(|width| width.to_string())}
+                onpointerdown={on_pointer_down}
+                onpointermove={on_pointer_move}
+                onpointerup={on_pointer_up}
+                onpointercancel={on_pointer_cancel}
+                ondblclick={on_double_click}
+                onkeydown={on_key_down}
+                onclick={Callback::from(|e: MouseEvent| e.stop_propagation())}
+            />
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::internal::dev_warnings::DevWarnings;
 
     #[derive(Debug, Clone, PartialEq)]
     struct TestItem {
@@ -664,6 +3320,326 @@ mod tests {
         value: i32,
     }
 
+    fn item(id: u32, value: i32) -> TestItem {
+        TestItem {
+            id,
+            name: format!("Item {}", id),
+            value,
+        }
+    }
+
+    #[test]
+    fn test_dev_warning_selection_without_track_by() {
+        let props = yew::props!(TableProps<TestItem> {
+            columns: Vec::new(),
+            items: vec![item(1, 10), item(1, 10)],
+            selection_type: SelectionType::Multi,
+        });
+        let warnings = DevWarnings::new("Table");
+        assert_eq!(warnings.check(|| dev_warnings(&props)), 1);
+        assert_eq!(warnings.check(|| dev_warnings(&props)), 0);
+
+        let distinct = yew::props!(TableProps<TestItem> {
+            columns: Vec::new(),
+            items: vec![item(1, 10), item(2, 10)],
+            selection_type: SelectionType::Multi,
+        });
+        assert!(dev_warnings(&distinct).is_empty());
+
+        let tracked = yew::props!(TableProps<TestItem> {
+            columns: Vec::new(),
+            items: vec![item(1, 10), item(1, 10)],
+            selection_type: SelectionType::Multi,
+            track_by: (|item: &TestItem| item.id.to_string()) as fn(&TestItem) -> String,
+        });
+        assert!(dev_warnings(&tracked).is_empty());
+
+        // Only the leading items are compared
+        let mut items: Vec<TestItem> = (0..EQUAL_ITEMS_CHECK_LIMIT as u32)
+            .map(|id| item(id, 10))
+            .collect();
+        items.push(item(0, 10));
+        let large = yew::props!(TableProps<TestItem> {
+            columns: Vec::new(),
+            items,
+            selection_type: SelectionType::Multi,
+        });
+        assert!(dev_warnings(&large).is_empty());
+    }
+
+    #[test]
+    fn test_is_item_selected_with_track_by() {
+        let track_by: fn(&TestItem) -> String = |item| item.id.to_string();
+        let selected = vec![item(1, 10)];
+
+        // An updated copy of a selected item is still selected when tracked by ID
+        assert!(is_item_selected(&item(1, 99), &selected, Some(track_by)));
+        assert!(!is_item_selected(&item(1, 99), &selected, None));
+        assert!(is_item_selected(&item(1, 10), &selected, None));
+        assert!(!is_item_selected(&item(2, 10), &selected, Some(track_by)));
+    }
+
+    #[test]
+    fn test_toggle_page_selection_keeps_other_pages() {
+        let track_by: fn(&TestItem) -> String = |item| item.id.to_string();
+        // Item 9 was selected on another page
+        let selected = vec![item(9, 90), item(2, 20)];
+        let page = vec![item(1, 10), item(2, 21)];
+
+        assert_eq!(
+            toggle_page_selection(&selected, &page, true, Some(track_by)),
+            vec![item(9, 90), item(1, 10), item(2, 21)]
+        );
+        assert_eq!(
+            toggle_page_selection(&selected, &page, false, Some(track_by)),
+            vec![item(9, 90)]
+        );
+
+        // Without `track_by`, the updated item 2 is a different item
+        assert_eq!(
+            toggle_page_selection(&selected, &page, false, None),
+            vec![item(9, 90), item(2, 20)]
+        );
+    }
+
+    #[test]
+    fn test_current_instances_with_track_by() {
+        let track_by: fn(&TestItem) -> String = |item| item.id.to_string();
+        let items = vec![item(1, 99), item(2, 20)];
+
+        // Updated items are reported as their current instance; items not on
+        // the page are kept
+        assert_eq!(
+            current_instances(vec![item(1, 10), item(3, 30)], &items, Some(track_by)),
+            vec![item(1, 99), item(3, 30)]
+        );
+        assert_eq!(
+            current_instances(vec![item(1, 10)], &items, None),
+            vec![item(1, 10)]
+        );
+    }
+
+    #[test]
+    fn test_count_selected_deduplicates_by_track_by() {
+        let track_by: fn(&TestItem) -> String = |item| item.id.to_string();
+        let selected = vec![item(1, 10), item(1, 11), item(2, 20)];
+        let state = SelectionState::Items;
+
+        assert_eq!(
+            SelectionLookup::new(&selected, &state, Some(track_by)).count(10),
+            2
+        );
+        assert_eq!(SelectionLookup::new(&selected, &state, None).count(10), 3);
+        assert_eq!(
+            SelectionLookup::<TestItem>::new(&[], &state, Some(track_by)).count(10),
+            0
+        );
+    }
+
+    #[test]
+    fn test_selection_lookup_matches_is_item_in_selection() {
+        let track_by: fn(&TestItem) -> String = |item| item.id.to_string();
+        let selected = vec![item(1, 10), item(3, 30)];
+        let items = [item(1, 99), item(2, 20), item(3, 30)];
+        let states = [
+            SelectionState::Items,
+            SelectionState::AllMatching {
+                excluded: vec!["2".to_string()],
+            },
+        ];
+
+        for state in &states {
+            for track_by in [Some(track_by), None] {
+                let lookup = SelectionLookup::new(&selected, state, track_by);
+                for item in &items {
+                    assert_eq!(
+                        lookup.contains(item),
+                        is_item_in_selection(item, &selected, state, track_by)
+                    );
+                }
+            }
+        }
+        let all_matching = SelectionLookup::new(&selected, &states[1], Some(track_by));
+        assert_eq!(all_matching.count(10), 9);
+    }
+
+    #[test]
+    fn test_is_item_in_selection_all_matching() {
+        let track_by: fn(&TestItem) -> String = |item| item.id.to_string();
+        let state = SelectionState::AllMatching {
+            excluded: vec!["2".to_string()],
+        };
+
+        // Selected items are ignored while all matching items are selected
+        assert!(is_item_in_selection(
+            &item(1, 10),
+            &[],
+            &state,
+            Some(track_by)
+        ));
+        assert!(!is_item_in_selection(
+            &item(2, 20),
+            &[item(2, 20)],
+            &state,
+            Some(track_by)
+        ));
+        assert!(is_item_in_selection(
+            &item(2, 20),
+            &[item(2, 20)],
+            &SelectionState::Items,
+            Some(track_by)
+        ));
+    }
+
+    #[test]
+    fn test_toggle_exclusion() {
+        let excluded = toggle_exclusion(&[], &["1".to_string()]);
+        assert_eq!(excluded, vec!["1".to_string()]);
+        let excluded = toggle_exclusion(&excluded, &["2".to_string()]);
+        assert_eq!(all_matching_count(1234, &excluded), 1232);
+        let excluded = toggle_exclusion(&excluded, &["1".to_string()]);
+        assert_eq!(excluded, vec!["2".to_string()]);
+        assert_eq!(all_matching_count(0, &excluded), 0);
+    }
+
+    #[test]
+    fn test_pages_count() {
+        assert_eq!(pages_count(200, 25), 8);
+        assert_eq!(pages_count(201, 25), 9);
+        assert_eq!(pages_count(0, 25), 1);
+        // A zero page size shows one item per page
+        assert_eq!(pages_count(3, 0), 3);
+    }
+
+    #[test]
+    fn test_page_range() {
+        assert_eq!(page_range(200, 25, 1), 0..25);
+        assert_eq!(page_range(200, 25, 8), 175..200);
+        assert_eq!(page_range(60, 25, 3), 50..60);
+        assert_eq!(page_range(0, 25, 1), 0..0);
+    }
+
+    #[test]
+    fn test_page_correction() {
+        // Within bounds, nothing to correct
+        assert_eq!(page_correction(3, 8, false), None);
+        // Items shrank below the current page
+        assert_eq!(page_correction(8, 2, false), Some(2));
+        assert_eq!(page_correction(0, 2, false), Some(1));
+        // Sorting changed
+        assert_eq!(page_correction(3, 8, true), Some(1));
+        assert_eq!(page_correction(1, 8, true), None);
+    }
+
+    #[test]
+    fn test_pagination_config_builder() {
+        let config = TablePaginationConfig::new(25, 1);
+        assert_eq!(config.page_size, 25);
+        assert_eq!(config.current_page, 1);
+        assert!(config.on_page_change.is_none());
+
+        let config = config.with_on_page_change(Callback::noop());
+        assert!(config.on_page_change.is_some());
+    }
+
+    #[test]
+    fn test_format_count() {
+        assert_eq!(format_count(0), "0");
+        assert_eq!(format_count(999), "999");
+        assert_eq!(format_count(1234), "1,234");
+        assert_eq!(format_count(1234567), "1,234,567");
+        let strings = resolve_strings(&TableI18nStrings::default(), &I18nContext::default());
+        assert_eq!(
+            strings.selection_summary_text(1232, 1234),
+            "1,232/1,234 selected"
+        );
+    }
+
+    #[test]
+    fn test_select_all_banner_text() {
+        let strings = resolve_strings(&TableI18nStrings::default(), &I18nContext::default());
+        let (status, action) = strings.select_all_banner_text(&SelectionState::Items, 25, 1234);
+        assert_eq!(status, "All 25 items on this page are selected.");
+        assert_eq!(action, "Select all 1,234 items matching the current filter");
+
+        let all = SelectionState::AllMatching { excluded: vec![] };
+        let (status, action) = strings.select_all_banner_text(&all, 25, 1234);
+        assert_eq!(
+            status,
+            "All 1,234 items matching the current filter are selected."
+        );
+        assert_eq!(action, "Clear selection");
+
+        let some = SelectionState::AllMatching {
+            excluded: vec!["7".to_string()],
+        };
+        let (status, _) = strings.select_all_banner_text(&some, 25, 1234);
+        assert_eq!(
+            status,
+            "1,233 of 1,234 items matching the current filter are selected."
+        );
+    }
+
+    #[test]
+    fn test_dev_warning_all_matching_incomplete() {
+        let props = yew::props!(TableProps<TestItem> {
+            columns: Vec::new(),
+            selection_type: SelectionType::Multi,
+            select_all_mode: SelectAllMode::AllMatching,
+            total_items_count: 1234,
+        });
+        let warnings = dev_warnings(&props);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, "table-all-matching-incomplete");
+
+        let complete = yew::props!(TableProps<TestItem> {
+            columns: Vec::new(),
+            selection_type: SelectionType::Multi,
+            select_all_mode: SelectAllMode::AllMatching,
+            total_items_count: 1234,
+            track_by: (|item: &TestItem| item.id.to_string()) as fn(&TestItem) -> String,
+        });
+        assert!(dev_warnings(&complete).is_empty());
+    }
+
+    #[test]
+    fn test_count_announcements() {
+        let strings = resolve_strings(&TableI18nStrings::default(), &I18nContext::default());
+        assert_eq!(strings.items_count_text(0), "0 items");
+        assert_eq!(strings.items_count_text(1), "1 item");
+        assert_eq!(strings.items_count_text(25), "25 items");
+        assert_eq!(strings.selected_count_text(1), "1 item selected");
+        assert_eq!(strings.selected_count_text(3), "3 items selected");
+        assert_eq!(strings.selection_summary_text(3, 25), "3/25 selected");
+    }
+
+    #[test]
+    fn test_i18n_strings_fallback() {
+        let mut messages = crate::internal::I18nStrings::new();
+        messages.set("table.clear_selection_text", "Auswahl aufheben");
+        messages.set("table.items_count_text", "{count} Elemente");
+        let i18n = I18nContext {
+            locale: Some("de-DE".to_string()),
+            messages,
+        };
+        let props_strings = TableI18nStrings {
+            selection_summary_text: Some("{count} von {total} ausgewählt".to_string()),
+            ..Default::default()
+        };
+        let strings = resolve_strings(&props_strings, &i18n);
+
+        assert_eq!(strings.clear_selection_text, "Auswahl aufheben");
+        assert_eq!(strings.items_count_text(1), "1 Elemente");
+        assert_eq!(
+            strings.selection_summary_text(3, 1234),
+            "3 von 1,234 ausgewählt"
+        );
+        // Unset strings without a provider message stay English
+        assert_eq!(strings.selected_count_text(1), "1 item selected");
+        let (_, action) = strings.select_all_banner_text(&SelectionState::Items, 25, 1234);
+        assert_eq!(action, "Select all 1,234 items matching the current filter");
+    }
+
     #[test]
     fn test_sort_direction_equality() {
         assert_eq!(SortDirection::Ascending, SortDirection::Ascending);
@@ -692,6 +3668,8 @@ mod tests {
         assert!(column.sortable);
         assert_eq!(column.width, Some("200px".to_string()));
         assert_eq!(column.min_width, Some("100px".to_string()));
+        assert!(column.resizable);
+        assert!(!column.with_resizable(false).resizable);
     }
 
     #[test]
@@ -739,6 +3717,7 @@ mod tests {
 
         let detail = TableSelectionDetail {
             selected_items: items.clone(),
+            selection_state: SelectionState::Items,
         };
 
         assert_eq!(detail.selected_items.len(), 2);
@@ -820,10 +3799,12 @@ mod tests {
 
         let detail1 = TableSelectionDetail {
             selected_items: items.clone(),
+            selection_state: SelectionState::Items,
         };
 
         let detail2 = TableSelectionDetail {
             selected_items: items,
+            selection_state: SelectionState::Items,
         };
 
         assert_eq!(detail1, detail2);
@@ -833,6 +3814,7 @@ mod tests {
     fn test_empty_selection() {
         let detail: TableSelectionDetail<TestItem> = TableSelectionDetail {
             selected_items: vec![],
+            selection_state: SelectionState::Items,
         };
 
         assert!(detail.selected_items.is_empty());
@@ -875,4 +3857,564 @@ mod tests {
 
         assert!(state.sort_column_id.is_none());
     }
+
+    #[test]
+    fn test_row_background_precedence() {
+        let tinted = RowStyle::new(RowSeverity::Critical).with_tinted_background(true);
+        let accent_only = RowStyle::new(RowSeverity::Low);
+
+        // Selection wins over the tint
+        assert_eq!(
+            row_background(Some(&tinted), true, true, 1),
+            RowBackground::Selected
+        );
+        // The tint wins over striping
+        assert_eq!(
+            row_background(Some(&tinted), false, true, 1),
+            RowBackground::Tinted(RowSeverity::Critical)
+        );
+        // Rows without a tint keep their stripe
+        assert_eq!(
+            row_background(Some(&accent_only), false, true, 1),
+            RowBackground::Stripe
+        );
+        assert_eq!(
+            row_background(Some(&accent_only), false, true, 2),
+            RowBackground::Default
+        );
+        assert_eq!(
+            row_background(None, false, false, 1),
+            RowBackground::Default
+        );
+    }
+
+    fn name_edit_config() -> TableEditConfig<TestItem> {
+        TableEditConfig::new(
+            |item: &TestItem| item.name.clone(),
+            |_, editor| html! { <input value={editor.value} /> },
+        )
+        .with_validation(|_, value| value.trim().is_empty().then(|| "Enter a name".to_string()))
+    }
+
+    #[test]
+    fn test_edit_key() {
+        assert_eq!(edit_key("Enter"), Some(EditKey::Commit));
+        assert_eq!(edit_key("Escape"), Some(EditKey::Cancel));
+        assert_eq!(edit_key("a"), None);
+    }
+
+    #[test]
+    fn test_validate_edit_commit() {
+        let config = name_edit_config();
+        assert_eq!((config.value)(&item(1, 10)), "Item 1");
+        assert_eq!(
+            validate_edit(&config, &item(1, 10), "name", "Renamed"),
+            Ok(TableCellEditDetail {
+                item: item(1, 10),
+                column_id: "name".to_string(),
+                new_value: "Renamed".to_string(),
+            })
+        );
+
+        // Without validation every value is submitted
+        let config = TableEditConfig {
+            validation: None,
+            ..name_edit_config()
+        };
+        assert!(validate_edit(&config, &item(1, 10), "name", "").is_ok());
+    }
+
+    #[test]
+    fn test_validate_edit_failure() {
+        assert_eq!(
+            validate_edit(&name_edit_config(), &item(1, 10), "name", "  "),
+            Err("Enter a name".to_string())
+        );
+    }
+
+    /// Edit state and submitted edits recorded by the callbacks of
+    /// `cell_edit_handlers`
+    type EditLog = (
+        Rc<RefCell<Option<Option<CellEdit<TestItem>>>>>,
+        Rc<RefCell<Vec<TableCellEditDetail<TestItem>>>>,
+    );
+
+    fn name_cell_handlers(
+        item: TestItem,
+        editing: &CellEdit<TestItem>,
+        track_by: Option<fn(&TestItem) -> String>,
+    ) -> (CellEditHandlers<TestItem>, EditLog) {
+        let set: Rc<RefCell<Option<Option<CellEdit<TestItem>>>>> = Rc::default();
+        let submitted: Rc<RefCell<Vec<TableCellEditDetail<TestItem>>>> = Rc::default();
+        let handlers = cell_edit_handlers(
+            name_edit_config(),
+            item,
+            "name".to_string(),
+            Some(editing),
+            {
+                let set = set.clone();
+                Callback::from(move |edit| *set.borrow_mut() = Some(edit))
+            },
+            track_by,
+            Some({
+                let submitted = submitted.clone();
+                Callback::from(move |e: CustomEvent<TableCellEditDetail<TestItem>>| {
+                    submitted.borrow_mut().push(e.detail)
+                })
+            }),
+        );
+        (handlers, (set, submitted))
+    }
+
+    fn name_edit(item: TestItem, value: &str) -> CellEdit<TestItem> {
+        CellEdit {
+            item,
+            column_id: "name".to_string(),
+            value: value.to_string(),
+            error_text: None,
+        }
+    }
+
+    #[test]
+    fn test_cell_edit_submit() {
+        let editing = name_edit(item(1, 10), "Renamed");
+        let (handlers, (set, submitted)) = name_cell_handlers(item(1, 10), &editing, None);
+
+        handlers.on_commit.emit(());
+        assert_eq!(*set.borrow(), Some(None));
+        assert_eq!(
+            *submitted.borrow(),
+            vec![TableCellEditDetail {
+                item: item(1, 10),
+                column_id: "name".to_string(),
+                new_value: "Renamed".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_cell_edit_cancel() {
+        let editing = name_edit(item(1, 10), "Renamed");
+        let (handlers, (set, submitted)) = name_cell_handlers(item(1, 10), &editing, None);
+
+        handlers.on_cancel.emit(());
+        assert_eq!(*set.borrow(), Some(None));
+        assert!(submitted.borrow().is_empty());
+    }
+
+    #[test]
+    fn test_cell_edit_validation_failure() {
+        let editing = name_edit(item(1, 10), "  ");
+        let (handlers, (set, submitted)) = name_cell_handlers(item(1, 10), &editing, None);
+
+        // The editor stays open with the error
+        handlers.on_commit.emit(());
+        assert_eq!(
+            *set.borrow(),
+            Some(Some(CellEdit {
+                error_text: Some("Enter a name".to_string()),
+                ..editing.clone()
+            }))
+        );
+        assert!(submitted.borrow().is_empty());
+
+        // Changing the value clears the error
+        handlers.on_change.emit("Renamed".to_string());
+        assert_eq!(*set.borrow(), Some(Some(name_edit(item(1, 10), "Renamed"))));
+    }
+
+    #[test]
+    fn test_cell_edit_follows_item() {
+        let track_by: fn(&TestItem) -> String = |item| item.id.to_string();
+        let editing = name_edit(item(1, 10), "Renamed");
+
+        // An updated copy of the edited item keeps the edit when tracked by ID
+        assert!(editing.is_cell(&item(1, 99), "name", Some(track_by)));
+        assert!(!editing.is_cell(&item(1, 99), "name", None));
+        assert!(!editing.is_cell(&item(2, 10), "name", Some(track_by)));
+        assert!(!editing.is_cell(&item(1, 10), "value", Some(track_by)));
+
+        // The cell of another item doesn't commit the edit
+        let (handlers, (set, submitted)) =
+            name_cell_handlers(item(2, 20), &editing, Some(track_by));
+        handlers.on_commit.emit(());
+        assert_eq!(*set.borrow(), None);
+        assert!(submitted.borrow().is_empty());
+
+        let (handlers, (_, submitted)) = name_cell_handlers(item(1, 99), &editing, Some(track_by));
+        handlers.on_commit.emit(());
+        assert_eq!(submitted.borrow()[0].item, item(1, 99));
+    }
+
+    #[test]
+    fn test_edit_config_equality() {
+        let column = TableColumn::new("name", "Name", |item: &TestItem| html! { {&item.name} });
+        let config = name_edit_config();
+        assert!(config == config.clone());
+        assert!(column.clone() != column.clone().with_edit_config(config.clone()));
+        assert!(column.clone().with_edit_config(config.clone()) == column.with_edit_config(config));
+    }
+
+    #[test]
+    fn test_sticky_header_cell_style() {
+        assert_eq!(
+            sticky_header_cell_style(0, false, None),
+            "position: sticky; top: 0px; z-index: 1; \
+             background-color: var(--awsui-color-background-table-header)"
+        );
+        assert_eq!(
+            sticky_header_cell_style(56, true, Some("width: 100px".to_string())),
+            "width: 100px; position: sticky; top: 56px; z-index: 1; \
+             background-color: var(--awsui-color-background-table-header); \
+             box-shadow: var(--awsui-shadow-sticky-embedded)"
+        );
+    }
+
+    #[test]
+    fn test_accent_cell_style() {
+        let style = RowStyle::new(RowSeverity::High).with_aria_description("Failed");
+        assert_eq!(style.aria_description.as_deref(), Some("Failed"));
+        assert_eq!(
+            accent_cell_style(Some(&style), None).as_deref(),
+            Some(
+                "border-inline-start: 4px solid \
+                 var(--awsui-color-background-notification-severity-high)"
+            )
+        );
+        assert_eq!(
+            accent_cell_style(Some(&style), Some("width: 100px".to_string()))
+                .unwrap()
+                .split("; ")
+                .count(),
+            2
+        );
+        assert_eq!(
+            accent_cell_style(None, Some("width: 100px".to_string())).as_deref(),
+            Some("width: 100px")
+        );
+        assert_eq!(accent_cell_style(None, None), None);
+    }
+
+    #[test]
+    fn test_row_severity_tokens() {
+        for severity in [
+            RowSeverity::Critical,
+            RowSeverity::High,
+            RowSeverity::Medium,
+            RowSeverity::Low,
+            RowSeverity::Info,
+            RowSeverity::Success,
+        ] {
+            assert!(severity.accent_color().starts_with("var(--awsui-color-"));
+            assert!(
+                severity
+                    .background_color()
+                    .starts_with("var(--awsui-color-background-status-")
+            );
+            assert_eq!(
+                severity.row_class(),
+                format!("awsui-table-row-accent-{}", severity.as_str())
+            );
+        }
+    }
+
+    #[test]
+    fn test_min_column_width() {
+        assert_eq!(min_column_width(Some("120px")), 120.0);
+        assert_eq!(min_column_width(Some(" 96 ")), 96.0);
+        assert_eq!(min_column_width(Some("10%")), DEFAULT_MIN_COLUMN_WIDTH);
+        assert_eq!(min_column_width(Some("-5px")), DEFAULT_MIN_COLUMN_WIDTH);
+        assert_eq!(min_column_width(None), DEFAULT_MIN_COLUMN_WIDTH);
+    }
+
+    #[test]
+    fn test_keyboard_resized_width() {
+        assert_eq!(
+            keyboard_resized_width(200.0, "ArrowRight", 80.0),
+            Some(210.0)
+        );
+        assert_eq!(
+            keyboard_resized_width(200.0, "ArrowLeft", 80.0),
+            Some(190.0)
+        );
+        assert_eq!(keyboard_resized_width(85.0, "ArrowLeft", 80.0), Some(80.0));
+        assert_eq!(keyboard_resized_width(200.0, "Enter", 80.0), None);
+    }
+
+    #[test]
+    fn test_column_cell_style_uses_user_width() {
+        let column = TableColumn::new("name", "Name", |item: &TestItem| html! { {&item.name} })
+            .with_width("20%")
+            .with_min_width("100px");
+
+        assert_eq!(
+            column_cell_style(&column, Some(240.0)).as_deref(),
+            Some("width: 240px; min-width: 100px")
+        );
+        assert_eq!(
+            column_cell_style(&column, None).as_deref(),
+            Some("width: 20%; min-width: 100px")
+        );
+    }
+
+    fn tree_children(item: &TestItem) -> Vec<TestItem> {
+        if item.id < 10 {
+            vec![item_child(item.id * 10 + 1), item_child(item.id * 10 + 2)]
+        } else {
+            vec![]
+        }
+    }
+
+    fn item_child(id: u32) -> TestItem {
+        item(id, 0)
+    }
+
+    fn tree_config(expanded: &[u32]) -> ExpandableRowsConfig<TestItem> {
+        ExpandableRowsConfig::new(tree_children, |item| item.id < 10)
+            .with_expanded_items(expanded.iter().map(|id| item(*id, 0)).collect())
+    }
+
+    fn row_ids(rows: &[TableRow<TestItem>]) -> Vec<(u32, usize)> {
+        rows.iter().map(|row| (row.item.id, row.level)).collect()
+    }
+
+    #[test]
+    fn test_table_rows_show_expanded_children() {
+        let items = vec![item(1, 0), item(2, 0)];
+
+        let rows = table_rows(&items, Some(&tree_config(&[])), None);
+        assert_eq!(row_ids(&rows), vec![(1, 1), (2, 1)]);
+        assert!(rows[0].expandable && !rows[0].expanded);
+
+        let rows = table_rows(&items, Some(&tree_config(&[1])), None);
+        assert_eq!(row_ids(&rows), vec![(1, 1), (11, 2), (12, 2), (2, 1)]);
+        assert_eq!((rows[2].set_size, rows[2].pos_in_set), (2, 2));
+        assert!(rows[0].expanded);
+        assert!(!rows[1].expandable);
+    }
+
+    #[test]
+    fn test_table_rows_without_config() {
+        let items = vec![item(1, 0), item(2, 0)];
+        let rows = table_rows(&items, None, None);
+        assert_eq!(row_ids(&rows), vec![(1, 1), (2, 1)]);
+        assert!(rows.iter().all(|row| !row.expandable && !row.expanded));
+        assert_eq!((rows[1].set_size, rows[1].pos_in_set), (2, 2));
+    }
+
+    #[test]
+    fn test_table_rows_stop_at_cycles() {
+        // Item 1 lists itself as a child
+        let config = ExpandableRowsConfig::new(|item: &TestItem| vec![item.clone()], |_| true)
+            .with_expanded_items(vec![item(1, 0)]);
+        let rows = table_rows(&[item(1, 0)], Some(&config), None);
+        assert_eq!(row_ids(&rows), vec![(1, 1), (1, 2)]);
+    }
+
+    #[test]
+    fn test_item_with_descendants() {
+        let config = ExpandableRowsConfig::new(
+            |item: &TestItem| {
+                if item.id < 100 {
+                    vec![item_child(item.id * 10 + 1)]
+                } else {
+                    vec![]
+                }
+            },
+            |item| item.id < 100,
+        );
+        let family: Vec<u32> = item_with_descendants(&item(1, 0), Some(&config), None)
+            .iter()
+            .map(|item| item.id)
+            .collect();
+        // Collapsed descendants are selected too
+        assert_eq!(family, vec![1, 11, 111]);
+
+        assert_eq!(item_with_descendants(&item(1, 0), None, None).len(), 1);
+    }
+
+    #[test]
+    fn test_toggle_exclusion_with_descendants() {
+        let keys = ["1".to_string(), "11".to_string(), "12".to_string()];
+        let excluded = toggle_exclusion(&["12".to_string()], &keys);
+        assert_eq!(excluded, keys.to_vec());
+        // Including the parent again includes its descendants
+        let excluded = toggle_exclusion(&excluded, &keys);
+        assert!(excluded.is_empty());
+    }
+
+    #[test]
+    fn test_navigate_cell() {
+        assert_eq!(navigate_cell("ArrowDown", (0, 1), 3, 4), Some((1, 1)));
+        assert_eq!(navigate_cell("ArrowDown", (2, 1), 3, 4), Some((2, 1)));
+        assert_eq!(navigate_cell("ArrowUp", (0, 1), 3, 4), Some((0, 1)));
+        assert_eq!(navigate_cell("ArrowRight", (1, 3), 3, 4), Some((1, 3)));
+        assert_eq!(navigate_cell("ArrowLeft", (1, 3), 3, 4), Some((1, 2)));
+        assert_eq!(navigate_cell("Home", (1, 2), 3, 4), Some((1, 0)));
+        assert_eq!(navigate_cell("End", (1, 0), 3, 4), Some((1, 3)));
+        assert_eq!(navigate_cell("Tab", (1, 0), 3, 4), None);
+        assert_eq!(navigate_cell("ArrowDown", (0, 0), 0, 4), None);
+    }
+
+    #[test]
+    fn test_navigate_cell_by_page() {
+        assert_eq!(navigate_cell("PageDown", (5, 2), 40, 3), Some((15, 2)));
+        assert_eq!(navigate_cell("PageDown", (35, 2), 40, 3), Some((39, 2)));
+        assert_eq!(navigate_cell("PageUp", (15, 2), 40, 3), Some((5, 2)));
+        assert_eq!(navigate_cell("PageUp", (4, 2), 40, 3), Some((0, 2)));
+    }
+
+    #[test]
+    fn test_clamp_cell_to_last_row() {
+        assert_eq!(clamp_cell((7, 2), 5, 3), (4, 2));
+        assert_eq!(clamp_cell((1, 5), 5, 3), (1, 2));
+        assert_eq!(clamp_cell((3, 1), 0, 3), (0, 1));
+    }
+
+    #[test]
+    fn test_table_role() {
+        assert_eq!(table_role(false, false), "table");
+        assert_eq!(table_role(false, true), "grid");
+        assert_eq!(table_role(true, true), "treegrid");
+    }
+
+    #[test]
+    fn test_column_count_includes_selection_cell() {
+        let columns = vec![
+            TableColumn::new("id", "ID", |item: &TestItem| html! { {item.id} }),
+            TableColumn::new("name", "Name", |item: &TestItem| html! { {&item.name} }),
+        ];
+        let props = yew::props!(TableProps<TestItem> { columns: columns.clone() });
+        assert_eq!(column_count(&props), 2);
+        assert_eq!(calculate_colspan(&props), "2");
+
+        let props = yew::props!(TableProps<TestItem> {
+            columns,
+            selection_type: SelectionType::Multi,
+        });
+        assert_eq!(column_count(&props), 3);
+        assert_eq!(calculate_colspan(&props), "3");
+    }
+
+    #[test]
+    fn test_skeleton_rows_count() {
+        assert_eq!(skeleton_rows_count(TableLoadingVariant::Spinner), 0);
+        assert_eq!(
+            skeleton_rows_count(TableLoadingVariant::Skeleton { rows: 5 }),
+            5
+        );
+        assert_eq!(
+            skeleton_rows_count(TableLoadingVariant::Skeleton { rows: 0 }),
+            1
+        );
+    }
+
+    #[test]
+    fn test_text_filter_matches() {
+        let filtering = TextFilterConfig::new(
+            |item: &TestItem, text: &str| item.name.contains(text),
+            "Item 1",
+        );
+        let items = vec![item(1, 0), item(2, 0), item(10, 0)];
+        assert!(filtering.matches(&items[0]));
+        assert!(!filtering.matches(&items[1]));
+        assert_eq!(filtering.matches_count(&items), 2);
+
+        // An empty text matches every item
+        let filtering = TextFilterConfig::new(|_: &TestItem, _: &str| false, "");
+        assert_eq!(filtering.matches_count(&items), 3);
+    }
+
+    #[test]
+    fn test_matches_count_text() {
+        let strings = resolve_strings(&TableI18nStrings::default(), &I18nContext::default());
+        assert_eq!(strings.matches_count_text(1), "1 match");
+        assert_eq!(strings.matches_count_text(0), "0 matches");
+        assert_eq!(strings.matches_count_text(1234), "1,234 matches");
+    }
+
+    #[test]
+    fn test_filter_strings_fallback() {
+        let strings = resolve_strings(&TableI18nStrings::default(), &I18nContext::default());
+        assert_eq!(strings.no_match_text, "No matches");
+        assert_eq!(strings.clear_filter_text, "Clear filter");
+        assert_eq!(strings.filtering_aria_label(None), "Filter items");
+        assert_eq!(strings.filtering_aria_label(Some("Find")), "Find");
+
+        let mut messages = crate::internal::I18nStrings::new();
+        messages.set("table.no_match_text", "Keine Treffer");
+        messages.set("table.matches_count_text", "{count} Treffer");
+        let i18n = I18nContext {
+            locale: Some("de-DE".to_string()),
+            messages,
+        };
+        let props_strings = TableI18nStrings {
+            filtering_aria_label: Some("Elemente filtern".to_string()),
+            ..Default::default()
+        };
+        let strings = resolve_strings(&props_strings, &i18n);
+        assert_eq!(strings.no_match_text, "Keine Treffer");
+        assert_eq!(strings.matches_count_text(1234), "1,234 Treffer");
+        assert_eq!(
+            strings.filtering_aria_label(Some("Find")),
+            "Elemente filtern"
+        );
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod wasm_tests {
+    use super::*;
+    use std::time::Duration;
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[derive(Clone, PartialEq)]
+    struct Row {
+        id: u32,
+    }
+
+    #[function_component(PageSelectedTable)]
+    fn page_selected_table() -> Html {
+        let items = vec![Row { id: 1 }, Row { id: 2 }];
+        html! {
+            <Table<Row>
+                columns={vec![TableColumn::new("id", "ID", |row: &Row| html! { {row.id} })]}
+                items={items.clone()}
+                selection_type={SelectionType::Multi}
+                selected_items={items}
+                select_all_mode={SelectAllMode::AllMatching}
+                track_by={(|row: &Row| row.id.to_string()) as fn(&Row) -> String}
+                total_items_count={Some(25)}
+            />
+        }
+    }
+
+    fn text_of(root: &web_sys::Element, selector: &str) -> Option<String> {
+        root.query_selector(selector)
+            .unwrap()
+            .and_then(|element| element.text_content())
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_select_all_banner_renders() {
+        let root = gloo::utils::document().create_element("div").unwrap();
+        gloo::utils::body().append_child(&root).unwrap();
+
+        let app = yew::Renderer::<PageSelectedTable>::with_root(root.clone()).render();
+        yew::platform::time::sleep(Duration::ZERO).await;
+
+        assert_eq!(
+            text_of(&root, ".awsui-table-select-all-banner-text"),
+            Some("All 2 items on this page are selected.".to_string())
+        );
+        assert_eq!(
+            text_of(&root, ".awsui-table-select-all-banner button"),
+            Some("Select all 25 items matching the current filter".to_string())
+        );
+
+        app.destroy();
+        root.remove();
+    }
 }
@@ -6,12 +6,20 @@
 //! A tabbed navigation component that allows users to switch between different
 //! views or content sections. Supports active tab state, disabled tabs,
 //! dismissible tabs, and various visual variants.
+//!
+//! Tab panels are mounted according to a [`TabRenderStrategy`]: all at once,
+//! on first activation, or only while active.
 
+use crate::i18n::{I18nContext, use_i18n};
+use crate::internal::dev_warnings::{DevWarning, duplicates, use_dev_warnings};
 use crate::internal::{
-    AriaAttributes, BaseComponentProps, ClassBuilder, ComponentMetadata, CustomEvent,
+    AriaAttributes, BaseComponentProps, ClassBuilder, ClickEvent, ComponentMetadata, CustomEvent,
+    LiveRegion, Tooltip, apply_root_attributes, use_element_size,
 };
+use crate::token_group::{drop_index, next_reorder_index, scroll_overflow};
 use std::collections::HashSet;
-use web_sys::MouseEvent;
+use wasm_bindgen::JsCast;
+use web_sys::{DragEvent, Element, HtmlElement, KeyboardEvent, MouseEvent};
 use yew::prelude::*;
 
 /// Tab item configuration
@@ -25,7 +33,8 @@ pub struct Tab {
     pub content: Option<Html>,
     /// Whether this tab is disabled
     pub disabled: bool,
-    /// Reason why the tab is disabled (shown as tooltip)
+    /// Reason why the tab is disabled, shown in a tooltip while a disabled
+    /// tab is hovered
     pub disabled_reason: Option<String>,
     /// Whether the tab can be dismissed
     pub dismissible: bool,
@@ -124,6 +133,32 @@ impl TabsVariant {
     }
 }
 
+/// When tab panels are mounted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TabRenderStrategy {
+    /// Every panel is mounted up front; inactive panels are hidden
+    #[default]
+    Eager,
+    /// A panel is mounted the first time its tab is activated and stays
+    /// mounted, hidden, afterwards
+    Lazy,
+    /// Only the active panel is mounted; a panel is unmounted, losing its
+    /// state, when another tab is activated
+    Unmount,
+}
+
+impl TabRenderStrategy {
+    /// Whether a panel is mounted, given whether its tab is active and
+    /// whether it has been active before
+    fn mounts(&self, active: bool, viewed: bool) -> bool {
+        match self {
+            Self::Eager => true,
+            Self::Lazy => active || viewed,
+            Self::Unmount => active,
+        }
+    }
+}
+
 /// Event detail for tab change events
 #[derive(Clone, PartialEq, Debug)]
 pub struct TabChangeDetail {
@@ -140,6 +175,80 @@ pub struct TabDismissDetail {
     pub tab_id: String,
 }
 
+/// Event detail for tab reorder events
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct TabReorderDetail {
+    /// Index of the moved tab
+    pub from_index: usize,
+    /// Index the tab moves to
+    pub to_index: usize,
+}
+
+impl TabReorderDetail {
+    /// Applies the move to a list, such as the tabs
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cloudscape_components::TabReorderDetail;
+    ///
+    /// let mut tabs = vec!["overview", "logs", "metrics"];
+    /// TabReorderDetail { from_index: 0, to_index: 2 }.apply(&mut tabs);
+    /// assert_eq!(tabs, vec!["logs", "metrics", "overview"]);
+    /// ```
+    pub fn apply<T>(&self, items: &mut Vec<T>) {
+        if self.from_index < items.len() && self.to_index < items.len() {
+            let item = items.remove(self.from_index);
+            items.insert(self.to_index, item);
+        }
+    }
+}
+
+/// Internationalization strings for Tabs
+///
+/// Unset strings fall back to the closest I18nProvider (keys under
+/// `"tabs."`), then to English.
+#[derive(Clone, PartialEq, Default, Debug)]
+pub struct TabsI18nStrings {
+    /// ARIA label of the button scrolling overflowing tabs towards the start
+    /// (default: "Scroll left")
+    pub scroll_left_aria_label: Option<String>,
+    /// ARIA label of the button scrolling overflowing tabs towards the end
+    /// (default: "Scroll right")
+    pub scroll_right_aria_label: Option<String>,
+}
+
+/// Strings used to render Tabs after applying the fallback chain
+#[derive(Clone, PartialEq, Debug)]
+struct ResolvedStrings {
+    scroll_left_aria_label: String,
+    scroll_right_aria_label: String,
+}
+
+/// Resolves every user-visible string: `i18n_strings` prop, then the
+/// I18nProvider, then English
+fn resolve_strings(strings: &TabsI18nStrings, i18n: &I18nContext) -> ResolvedStrings {
+    let resolve = |value: &Option<String>, key: &str, default: &str| {
+        value
+            .clone()
+            .or_else(|| i18n.message("tabs", key).map(str::to_string))
+            .unwrap_or_else(|| default.to_string())
+    };
+
+    ResolvedStrings {
+        scroll_left_aria_label: resolve(
+            &strings.scroll_left_aria_label,
+            "scroll_left_aria_label",
+            "Scroll left",
+        ),
+        scroll_right_aria_label: resolve(
+            &strings.scroll_right_aria_label,
+            "scroll_right_aria_label",
+            "Scroll right",
+        ),
+    }
+}
+
 /// Properties for the Tabs component
 #[derive(Properties, PartialEq, Clone)]
 pub struct TabsProps {
@@ -159,13 +268,20 @@ pub struct TabsProps {
 
     /// Callback fired when the active tab changes
     ///
-    /// The event detail contains the new active tab ID and href.
+    /// The event detail contains the new active tab ID and href. For tabs
+    /// with an `href` the event is cancelable: a plain left click follows the
+    /// link natively unless the callback calls `prevent_default()`, which
+    /// lets it route client-side instead.
     #[prop_or_default]
     pub on_change: Option<Callback<CustomEvent<TabChangeDetail>>>,
 
-    /// Callback fired when a dismissible tab is dismissed
+    /// Callback fired when a dismissible tab is dismissed, with its dismiss
+    /// button or the Delete key
     ///
-    /// The event detail contains the ID of the dismissed tab.
+    /// The event detail contains the ID of the dismissed tab. The tab is not
+    /// removed by the component; remove it from `tabs`. When the active tab
+    /// is dismissed, `on_change` first activates the next enabled tab, or
+    /// the previous one if there is none after it.
     #[prop_or_default]
     pub on_dismiss: Option<Callback<CustomEvent<TabDismissDetail>>>,
 
@@ -173,6 +289,21 @@ pub struct TabsProps {
     #[prop_or_default]
     pub variant: TabsVariant,
 
+    /// Whether users can reorder the tabs
+    ///
+    /// Tabs are dragged with the pointer, or lifted with Space, moved with the
+    /// arrow keys and dropped with Space or Enter (Escape cancels). Disabled
+    /// tabs stay in place.
+    #[prop_or_default]
+    pub reorderable: bool,
+
+    /// Callback fired when a tab moves while reordering
+    ///
+    /// The tabs are not reordered by the component; apply the move to the
+    /// `tabs` prop, e.g. with [`TabReorderDetail::apply`].
+    #[prop_or_default]
+    pub on_tab_reorder: Option<Callback<CustomEvent<TabReorderDetail>>>,
+
     /// Whether tabs should fit to container width
     #[prop_or_default]
     pub fit_to_container: bool,
@@ -181,6 +312,10 @@ pub struct TabsProps {
     #[prop_or_default]
     pub disable_content_paddings: bool,
 
+    /// When tab panels are mounted
+    #[prop_or_default]
+    pub content_render_strategy: TabRenderStrategy,
+
     /// ARIA label for the tab list
     ///
     /// Use this when there's no visible label for the tabs.
@@ -196,6 +331,27 @@ pub struct TabsProps {
     /// ARIA attributes
     #[prop_or_default]
     pub aria: AriaAttributes,
+
+    /// Internationalization strings
+    #[prop_or_default]
+    pub i18n_strings: TabsI18nStrings,
+}
+
+/// Misuse of Tabs props reported in development builds
+fn dev_warnings(props: &TabsProps) -> Vec<DevWarning> {
+    let repeated = duplicates(props.tabs.iter().map(|tab| tab.id.as_str()));
+    if repeated.is_empty() {
+        return Vec::new();
+    }
+
+    vec![DevWarning::new(
+        "tabs-duplicate-id",
+        format!(
+            "Tab ids must be unique; found duplicates: {}. Only the first tab with an id \
+             can be activated.",
+            repeated.join(", ")
+        ),
+    )]
 }
 
 /// Tabs component for tabbed navigation with active state.
@@ -204,6 +360,10 @@ pub struct TabsProps {
 /// between different content views. The component handles keyboard navigation,
 /// accessibility, and supports disabled and dismissible tabs.
 ///
+/// Tabs that don't fit stay on one row that scrolls horizontally. Paddle
+/// buttons at either end scroll by a page and are disabled at the matching
+/// extent; the active or focused tab is scrolled into view.
+///
 /// # Example
 ///
 /// ```rust
@@ -249,6 +409,9 @@ pub struct TabsProps {
 #[function_component(Tabs)]
 pub fn tabs(props: &TabsProps) -> Html {
     let _metadata = ComponentMetadata::new("Tabs");
+    use_dev_warnings("Tabs").check(|| dev_warnings(props));
+    let i18n = use_i18n();
+    let strings = resolve_strings(&props.i18n_strings, &i18n);
 
     // Track which tabs have been viewed for lazy loading
     let viewed_tabs = use_state(HashSet::new);
@@ -265,6 +428,7 @@ pub fn tabs(props: &TabsProps) -> Html {
             .map(|tab| tab.id.clone())
             .unwrap_or_default()
     };
+    let active_index = props.tabs.iter().position(|tab| tab.id == active_tab_id);
 
     // Mark active tab as viewed
     {
@@ -278,48 +442,355 @@ pub fn tabs(props: &TabsProps) -> Html {
         });
     }
 
-    // Find the active tab
-    let active_tab = props.tabs.iter().find(|tab| tab.id == active_tab_id);
-
     // Handle tab click
     let on_tab_click = {
         let on_change = props.on_change.clone();
         let tabs = props.tabs.clone();
 
+        // Returns whether the consumer prevented the change event
         Callback::from(move |tab_id: String| {
             if let Some(tab) = tabs.iter().find(|t| t.id == tab_id)
                 && !tab.disabled
                 && let Some(ref callback) = on_change
             {
-                callback.emit(CustomEvent::new_non_cancelable(TabChangeDetail {
-                    active_tab_id: tab_id,
-                    active_tab_href: tab.href.clone(),
-                }));
+                change_event(tab).emit_to(callback)
+            } else {
+                false
             }
         })
     };
 
-    // Handle tab dismiss
+    let list_ref = use_node_ref();
+    let disabled: Vec<bool> = props.tabs.iter().map(|tab| tab.disabled).collect();
+
+    // Handle tab dismiss, activating a neighbour first when the active tab
+    // is dismissed
     let on_tab_dismiss = {
         let on_dismiss = props.on_dismiss.clone();
+        let on_tab_click = on_tab_click.clone();
+        let tabs = props.tabs.clone();
+        let disabled = disabled.clone();
+        let list_ref = list_ref.clone();
 
         Callback::from(move |tab_id: String| {
+            let Some(index) = tabs.iter().position(|tab| tab.id == tab_id) else {
+                return;
+            };
+            if active_index == Some(index)
+                && let Some(target) = dismiss_target(&disabled, index)
+            {
+                focus_tab(&list_ref, target);
+                on_tab_click.emit(tabs[target].id.clone());
+            }
             if let Some(ref callback) = on_dismiss {
                 callback.emit(CustomEvent::new_non_cancelable(TabDismissDetail { tab_id }));
             }
         })
     };
 
+    // Disabled tab whose reason tooltip is shown
+    let reason_shown = use_state_eq(|| None::<usize>);
+
+    // Overflow: track which edges of the tab list hide tabs, recomputed when
+    // the list is resized, scrolled, or the tab set changes
+    let list_size = use_element_size(list_ref.clone());
+    let overflow = use_state_eq(TabsOverflow::default);
+    let tab_ids: Vec<String> = props.tabs.iter().map(|tab| tab.id.clone()).collect();
+
+    let update_overflow = {
+        let list_ref = list_ref.clone();
+        let overflow = overflow.clone();
+        Callback::from(move |_: ()| {
+            if let Some(element) = list_ref.cast::<Element>() {
+                overflow.set(TabsOverflow::measure(
+                    f64::from(element.scroll_left()),
+                    f64::from(element.scroll_width()),
+                    f64::from(element.client_width()),
+                ));
+            }
+        })
+    };
+
+    {
+        let update_overflow = update_overflow.clone();
+        use_effect_with(
+            (list_size.map(|(width, _)| width as i32), tab_ids),
+            move |_| {
+                update_overflow.emit(());
+                || ()
+            },
+        );
+    }
+
+    // Keep the active tab scrolled into view
+    {
+        let list_ref = list_ref.clone();
+        use_effect_with(
+            (active_index, list_size.map(|(width, _)| width as i32)),
+            move |(active_index, _)| {
+                if let Some(index) = *active_index {
+                    reveal_tab(&list_ref, index);
+                }
+                || ()
+            },
+        );
+    }
+
+    let scroll_by_page = |forward: bool| {
+        let list_ref = list_ref.clone();
+        Callback::from(move |e: MouseEvent| {
+            e.prevent_default();
+            if let Some(element) = list_ref.cast::<Element>() {
+                let distance = f64::from(element.client_width());
+                element.scroll_by_with_x_and_y(if forward { distance } else { -distance }, 0.0);
+            }
+        })
+    };
+
+    // Reordering: keyboard lift state as (starting index, current index), the
+    // index being dragged, and whether the pointer went down on a dismiss
+    // button so that it cannot start a drag
+    let lifted = use_state(|| None::<(usize, usize)>);
+    let dragging = use_state(|| None::<usize>);
+    let drop_target = use_state(|| None::<usize>);
+    let pressed_dismiss = use_mut_ref(|| false);
+    let announcement = use_state(String::new);
+    let tab_count = props.tabs.len();
+
+    let emit_reorder = {
+        let on_tab_reorder = props.on_tab_reorder.clone();
+        Callback::from(move |(from_index, to_index): (usize, usize)| {
+            if let Some(callback) = &on_tab_reorder {
+                callback.emit(CustomEvent::new_non_cancelable(TabReorderDetail {
+                    from_index,
+                    to_index,
+                }));
+            }
+        })
+    };
+
+    // Keep focus on a lifted tab as the tabs are reordered around it
+    {
+        let list_ref = list_ref.clone();
+        use_effect_with(*lifted, move |lifted| {
+            if let Some((_, current)) = *lifted {
+                focus_tab(&list_ref, current);
+            }
+            || ()
+        });
+    }
+
+    let on_key_down = {
+        let lifted = lifted.clone();
+        let announcement = announcement.clone();
+        let disabled = disabled.clone();
+        let emit_reorder = emit_reorder.clone();
+        let on_tab_click = on_tab_click.clone();
+        let on_tab_dismiss = on_tab_dismiss.clone();
+        let list_ref = list_ref.clone();
+        let tabs = props.tabs.clone();
+        let reorderable = props.reorderable;
+
+        Callback::from(move |(index, e): (usize, KeyboardEvent)| {
+            let dismissible = tabs
+                .get(index)
+                .is_some_and(|tab| tab.dismissible && !tab.dismiss_disabled);
+            let position = |index: usize| format!("position {} of {}", index + 1, tab_count);
+            let movable = reorderable && !disabled.get(index).copied().unwrap_or(true);
+
+            match (e.key().as_str(), *lifted) {
+                (" ", None) if movable => {
+                    e.prevent_default();
+                    lifted.set(Some((index, index)));
+                    announcement.set(format!("Picked up tab at {}", position(index)));
+                }
+                (" " | "Enter", Some(_)) => {
+                    e.prevent_default();
+                    lifted.set(None);
+                    announcement.set(format!("Dropped tab at {}", position(index)));
+                }
+                ("Escape", Some((start, current))) => {
+                    e.prevent_default();
+                    if start != current {
+                        emit_reorder.emit((current, start));
+                    }
+                    lifted.set(None);
+                    announcement.set(format!(
+                        "Reordering canceled, tab returned to {}",
+                        position(start)
+                    ));
+                }
+                ("ArrowLeft" | "ArrowRight", Some((start, current))) => {
+                    e.prevent_default();
+                    let forward = e.key() == "ArrowRight";
+                    if let Some(next) = next_reorder_index(&disabled, current, forward) {
+                        emit_reorder.emit((current, next));
+                        lifted.set(Some((start, next)));
+                        announcement.set(format!("Moved tab to {}", position(next)));
+                    }
+                }
+                ("Delete", None) if dismissible => {
+                    e.prevent_default();
+                    on_tab_dismiss.emit(tabs[index].id.clone());
+                }
+                (key, None) => {
+                    if let Some(target) = keyboard_target(&disabled, index, key) {
+                        e.prevent_default();
+                        focus_tab(&list_ref, target);
+                        reveal_tab(&list_ref, target);
+                        on_tab_click.emit(tabs[target].id.clone());
+                    }
+                }
+                _ => {}
+            }
+        })
+    };
+
+    let on_drag_start = {
+        let pressed_dismiss = pressed_dismiss.clone();
+        let dragging = dragging.clone();
+        let disabled = disabled.clone();
+        let reorderable = props.reorderable;
+        Callback::from(move |(index, e): (usize, DragEvent)| {
+            let draggable = reorderable && !disabled.get(index).copied().unwrap_or(true);
+            if !draggable || *pressed_dismiss.borrow() {
+                e.prevent_default();
+                return;
+            }
+            if let Some(data_transfer) = e.data_transfer() {
+                data_transfer.set_effect_allowed("move");
+                let _ = data_transfer.set_data("text/plain", &index.to_string());
+            }
+            dragging.set(Some(index));
+        })
+    };
+
+    let on_drag_over = {
+        let dragging = dragging.clone();
+        let drop_target = drop_target.clone();
+        let disabled = disabled.clone();
+        Callback::from(move |(index, e): (usize, DragEvent)| {
+            if let Some(from) = *dragging
+                && drop_index(&disabled, from, index).is_some()
+            {
+                e.prevent_default();
+                drop_target.set(Some(index));
+            }
+        })
+    };
+
+    let on_drop = {
+        let dragging = dragging.clone();
+        let drop_target = drop_target.clone();
+        let disabled = disabled.clone();
+        let emit_reorder = emit_reorder.clone();
+        Callback::from(move |(index, e): (usize, DragEvent)| {
+            e.prevent_default();
+            if let Some(from) = *dragging
+                && let Some(to) = drop_index(&disabled, from, index)
+            {
+                emit_reorder.emit((from, to));
+            }
+            dragging.set(None);
+            drop_target.set(None);
+        })
+    };
+
+    let on_drag_end = {
+        let dragging = dragging.clone();
+        let drop_target = drop_target.clone();
+        Callback::from(move |_: DragEvent| {
+            dragging.set(None);
+            drop_target.set(None);
+        })
+    };
+
+    let on_mouse_down = {
+        let pressed_dismiss = pressed_dismiss.clone();
+        Callback::from(move |e: MouseEvent| {
+            *pressed_dismiss.borrow_mut() = e
+                .target()
+                .and_then(|target| target.dyn_into::<Element>().ok())
+                .and_then(|target| target.closest(".awsui-tabs-tab-dismiss").ok().flatten())
+                .is_some();
+        })
+    };
+
+    let on_blur = {
+        let lifted = lifted.clone();
+        // Moving the tab in the DOM also blurs it, without a related target;
+        // only focusing outside the tab list drops it
+        let list_ref = list_ref.clone();
+        Callback::from(move |e: FocusEvent| {
+            let focus_left_list = e
+                .related_target()
+                .and_then(|target| target.dyn_into::<web_sys::Node>().ok())
+                .is_some_and(|target| {
+                    list_ref
+                        .cast::<Element>()
+                        .is_some_and(|list| !list.contains(Some(&target)))
+                });
+            if focus_left_list && lifted.is_some() {
+                lifted.set(None);
+            }
+        })
+    };
+
+    // Keep a tab focused from the keyboard scrolled into view
+    let on_tab_focus = {
+        let list_ref = list_ref.clone();
+        Callback::from(move |index: usize| reveal_tab(&list_ref, index))
+    };
+
+    let context = TabHeaderContext {
+        active_tab_id: active_tab_id.clone(),
+        on_tab_focus,
+        on_tab_click,
+        on_tab_dismiss,
+        on_key_down,
+        on_drag_start,
+        on_drag_over,
+        on_drop,
+        on_drag_end,
+        on_reason_toggle: {
+            let reason_shown = reason_shown.clone();
+            Callback::from(move |index: Option<usize>| reason_shown.set(index))
+        },
+        reason_shown: *reason_shown,
+        reorderable: props.reorderable,
+        lifted: (*lifted).map(|(_, current)| current),
+        dragging: *dragging,
+        drop_target: *drop_target,
+    };
+
     // Build container classes
     let container_classes = ClassBuilder::new()
         .add("awsui-tabs")
         .add(format!("awsui-tabs-variant-{}", props.variant.as_str()))
-        .add_if(props.fit_to_container, "awsui-tabs-fit-container");
+        .add_if(props.fit_to_container, "awsui-tabs-fit-container")
+        .add_if(props.reorderable, "awsui-tabs-reorderable");
 
     // Build tab list classes
     let tab_list_classes = ClassBuilder::new()
         .add("awsui-tabs-tab-list")
-        .add_if(props.fit_to_container, "awsui-tabs-tab-list-fit");
+        .add_if(props.fit_to_container, "awsui-tabs-tab-list-fit")
+        .add_if(overflow.start, "awsui-tabs-fade-start")
+        .add_if(overflow.end, "awsui-tabs-fade-end");
+
+    // Scroll in a single row and fade the edges that hide tabs
+    let tab_list_style = {
+        let start = if overflow.start {
+            "transparent"
+        } else {
+            "black"
+        };
+        let end = if overflow.end { "transparent" } else { "black" };
+        format!(
+            "display: flex; flex: 1; min-width: 0; flex-wrap: nowrap; overflow-x: auto; \
+             scrollbar-width: none; mask-image: linear-gradient(to right, {start}, \
+             black {FADE_WIDTH}px, black calc(100% - {FADE_WIDTH}px), {end})"
+        )
+    };
 
     // Build content wrapper classes
     let content_wrapper_classes = ClassBuilder::new()
@@ -329,64 +800,268 @@ pub fn tabs(props: &TabsProps) -> Html {
             "awsui-tabs-content-paddings",
         );
 
-    html! {
+    let root = html! {
         <div
             id={props.base.id.clone()}
             class={props.base.merge_classes(&container_classes.build())}
         >
-            // Tab list
-            <div
-                class={tab_list_classes.build()}
-                role="tablist"
-                aria-label={props.aria_label.clone()}
-                aria-labelledby={props.aria_labelledby.clone()}
-            >
-                {
-                    props.tabs.iter().map(|tab| {
-                        render_tab_header(
-                            tab,
-                            &active_tab_id,
-                            &on_tab_click,
-                            &on_tab_dismiss,
-                        )
-                    }).collect::<Html>()
+            <div class="awsui-tabs-header" style="display: flex; align-items: stretch">
+                if overflow.overflowing {
+                    <button
+                        type="button"
+                        class="awsui-tabs-paddle awsui-tabs-paddle-left"
+                        aria-label={strings.scroll_left_aria_label.clone()}
+                        tabindex="-1"
+                        draggable="false"
+                        disabled={!overflow.start}
+                        onclick={scroll_by_page(false)}
+                    >
+                        { "‹" }
+                    </button>
+                }
+
+                // Tab list
+                <div
+                    ref={list_ref.clone()}
+                    class={tab_list_classes.build()}
+                    style={tab_list_style}
+                    role="tablist"
+                    aria-label={props.aria_label.clone()}
+                    aria-labelledby={props.aria_labelledby.clone()}
+                    onscroll={update_overflow.reform(|_: Event| ())}
+                    onmousedown={on_mouse_down}
+                    onfocusout={on_blur}
+                >
+                    {
+                        props.tabs.iter().enumerate().map(|(index, tab)| {
+                            render_tab_header(tab, index, &context)
+                        }).collect::<Html>()
+                    }
+                </div>
+
+                if overflow.overflowing {
+                    <button
+                        type="button"
+                        class="awsui-tabs-paddle awsui-tabs-paddle-right"
+                        aria-label={strings.scroll_right_aria_label.clone()}
+                        tabindex="-1"
+                        draggable="false"
+                        disabled={!overflow.end}
+                        onclick={scroll_by_page(true)}
+                    >
+                        { "›" }
+                    </button>
                 }
             </div>
 
+            if props.reorderable {
+                <LiveRegion message={(*announcement).clone()} delay_ms={0} assertive={true} />
+            }
+
             // Tab content
             <div class={content_wrapper_classes.build()}>
                 {
-                    if let Some(active) = active_tab {
-                        render_tab_content(active, &active_tab_id)
-                    } else {
-                        html! {}
-                    }
+                    props.tabs.iter().filter(|tab| {
+                        props.content_render_strategy.mounts(
+                            tab.id == active_tab_id,
+                            viewed_tabs.contains(&tab.id),
+                        )
+                    }).map(|tab| render_tab_content(tab, &active_tab_id)).collect::<Html>()
                 }
             </div>
         </div>
+    };
+    apply_root_attributes(root, &props.base)
+}
+
+/// Width in pixels of the faded edges of an overflowing tab list
+const FADE_WIDTH: f64 = 24.0;
+
+/// Which parts of the tab list are scrolled out of view
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct TabsOverflow {
+    /// Whether the tabs are wider than the list
+    overflowing: bool,
+    /// Whether tabs are hidden before the visible area
+    start: bool,
+    /// Whether tabs are hidden after the visible area
+    end: bool,
+}
+
+impl TabsOverflow {
+    fn measure(scroll_left: f64, scroll_width: f64, client_width: f64) -> Self {
+        let (start, end) = scroll_overflow(scroll_left, scroll_width, client_width);
+        Self {
+            // Allow a pixel of rounding, as for the edges
+            overflowing: scroll_width - client_width > 1.0,
+            start,
+            end,
+        }
     }
 }
 
+/// Gets the horizontal scroll distance that brings a tab fully into view
+///
+/// Tabs under a faded edge count as hidden, so the tab is revealed `margin`
+/// pixels inside the visible area. A tab wider than the area is aligned to its
+/// start.
+fn reveal_offset(view: (f64, f64), tab: (f64, f64), margin: f64) -> f64 {
+    let (view_start, view_end) = view;
+    let (tab_start, tab_end) = tab;
+    if tab_start < view_start + margin {
+        tab_start - view_start - margin
+    } else if tab_end > view_end - margin {
+        (tab_end - view_end + margin).min(tab_start - view_start - margin)
+    } else {
+        0.0
+    }
+}
+
+/// Gets the tab to move focus to for a navigation key, skipping disabled tabs
+///
+/// ArrowLeft and ArrowRight wrap around; Home and End jump to the first and
+/// last enabled tab. Returns `None` for other keys or when no other tab is
+/// enabled.
+fn keyboard_target(disabled: &[bool], current: usize, key: &str) -> Option<usize> {
+    let count = disabled.len();
+    let enabled = |index: &usize| !disabled[*index];
+    let target = match key {
+        "ArrowRight" => (1..count)
+            .map(|offset| (current + offset) % count)
+            .find(enabled),
+        "ArrowLeft" => (1..count)
+            .map(|offset| (current + count - offset) % count)
+            .find(enabled),
+        "Home" => (0..count).find(enabled),
+        "End" => (0..count).rev().find(enabled),
+        _ => None,
+    };
+    target.filter(|&target| target != current)
+}
+
+/// Gets the tab to activate when the tab at `dismissed` is dismissed: the
+/// next enabled tab, else the previous one
+fn dismiss_target(disabled: &[bool], dismissed: usize) -> Option<usize> {
+    let enabled = |index: &usize| !disabled[*index];
+    (dismissed + 1..disabled.len())
+        .find(enabled)
+        .or_else(|| (0..dismissed).rev().find(enabled))
+}
+
+/// Finds the tab element at `index` in the tab list
+fn tab_element(list_ref: &NodeRef, index: usize) -> Option<Element> {
+    list_ref
+        .cast::<Element>()?
+        .query_selector(&format!("[data-tab-index=\"{}\"] [role=\"tab\"]", index))
+        .ok()
+        .flatten()
+}
+
+/// Moves focus to the tab at `index`
+fn focus_tab(list_ref: &NodeRef, index: usize) {
+    if let Some(tab) = tab_element(list_ref, index)
+        && let Ok(tab) = tab.dyn_into::<HtmlElement>()
+    {
+        let _ = tab.focus();
+    }
+}
+
+/// Scrolls the tab list so the tab at `index` is fully visible
+fn reveal_tab(list_ref: &NodeRef, index: usize) {
+    let (Some(list), Some(tab)) = (list_ref.cast::<Element>(), tab_element(list_ref, index)) else {
+        return;
+    };
+    let view = list.get_bounding_client_rect();
+    let rect = tab.get_bounding_client_rect();
+    let margin = if list.scroll_width() > list.client_width() {
+        FADE_WIDTH
+    } else {
+        0.0
+    };
+    let offset = reveal_offset(
+        (view.left(), view.right()),
+        (rect.left(), rect.right()),
+        margin,
+    );
+    if offset != 0.0 {
+        list.scroll_by_with_x_and_y(offset, 0.0);
+    }
+}
+
+/// Builds the change event for activating `tab`, cancelable only when the
+/// tab is a link
+fn change_event(tab: &Tab) -> CustomEvent<TabChangeDetail> {
+    let detail = TabChangeDetail {
+        active_tab_id: tab.id.clone(),
+        active_tab_href: tab.href.clone(),
+    };
+    if tab.href.is_some() {
+        CustomEvent::new(detail)
+    } else {
+        CustomEvent::new_non_cancelable(detail)
+    }
+}
+
+/// Whether a click on a tab header lets the browser follow its href
+fn follows_href(has_href: bool, disabled: bool, prevented: bool) -> bool {
+    has_href && !disabled && !prevented
+}
+
+/// State and callbacks shared by every tab header
+struct TabHeaderContext {
+    active_tab_id: String,
+    on_tab_focus: Callback<usize>,
+    on_tab_click: Callback<String, bool>,
+    on_tab_dismiss: Callback<String>,
+    on_key_down: Callback<(usize, KeyboardEvent)>,
+    on_drag_start: Callback<(usize, DragEvent)>,
+    on_drag_over: Callback<(usize, DragEvent)>,
+    on_drop: Callback<(usize, DragEvent)>,
+    on_drag_end: Callback<DragEvent>,
+    /// Shows (`Some(index)`) or hides the disabled reason tooltip
+    on_reason_toggle: Callback<Option<usize>>,
+    /// Index of the disabled tab whose reason is shown
+    reason_shown: Option<usize>,
+    reorderable: bool,
+    /// Current index of the tab lifted with the keyboard
+    lifted: Option<usize>,
+    /// Index of the tab being dragged with the pointer
+    dragging: Option<usize>,
+    /// Index of the tab the pointer drag is over
+    drop_target: Option<usize>,
+}
+
 /// Renders a single tab header
-fn render_tab_header(
-    tab: &Tab,
-    active_tab_id: &str,
-    on_tab_click: &Callback<String>,
-    on_tab_dismiss: &Callback<String>,
-) -> Html {
-    let is_active = tab.id == active_tab_id;
+fn render_tab_header(tab: &Tab, index: usize, context: &TabHeaderContext) -> Html {
+    let is_active = tab.id == context.active_tab_id;
+    let draggable = context.reorderable && !tab.disabled;
+    let is_lifted = context.lifted == Some(index) || context.dragging == Some(index);
 
     let tab_classes = ClassBuilder::new()
         .add("awsui-tabs-tab")
         .add_if(is_active, "awsui-tabs-tab-active")
         .add_if(tab.disabled, "awsui-tabs-tab-disabled")
-        .add_if(tab.dismissible, "awsui-tabs-tab-dismissible");
+        .add_if(tab.dismissible, "awsui-tabs-tab-dismissible")
+        .add_if(draggable, "awsui-tabs-tab-draggable")
+        .add_if(is_lifted, "awsui-tabs-tab-lifted")
+        .add_if(
+            context.drop_target == Some(index) && context.dragging != Some(index),
+            "awsui-tabs-tab-drop-target",
+        );
 
     let tab_id = tab.id.clone();
-    let tab_click = on_tab_click.clone();
+    let tab_click = context.on_tab_click.clone();
+    let has_href = tab.href.is_some();
+    let disabled = tab.disabled;
     let onclick = Callback::from(move |e: MouseEvent| {
-        e.prevent_default();
-        tab_click.emit(tab_id.clone());
+        // Modified clicks on a link tab open it natively, e.g. in a new tab
+        if has_href && !disabled && !ClickEvent::from_mouse_event(&e).is_plain_left_click() {
+            return;
+        }
+        let prevented = tab_click.emit(tab_id.clone());
+        if !follows_href(has_href, disabled, prevented) {
+            e.prevent_default();
+        }
     });
 
     let tab_link_classes = ClassBuilder::new()
@@ -396,7 +1071,7 @@ fn render_tab_header(
     // Build dismiss button if needed
     let dismiss_button = if tab.dismissible {
         let tab_id = tab.id.clone();
-        let dismiss_callback = on_tab_dismiss.clone();
+        let dismiss_callback = context.on_tab_dismiss.clone();
         let dismiss_label = tab
             .dismiss_label
             .clone()
@@ -415,6 +1090,7 @@ fn render_tab_header(
                 disabled={tab.dismiss_disabled}
                 onclick={on_dismiss_click}
                 type="button"
+                draggable="false"
             >
                 {"×"}
             </button>
@@ -423,11 +1099,41 @@ fn render_tab_header(
         html! {}
     };
 
-    // Wrap in tooltip if disabled with reason
-    let tab_element = html! {
+    let on_key_down = context
+        .on_key_down
+        .reform(move |e: KeyboardEvent| (index, e));
+    let on_drag_start = context.on_drag_start.reform(move |e: DragEvent| (index, e));
+    let on_drag_over = context.on_drag_over.reform(move |e: DragEvent| (index, e));
+    let on_drop = context.on_drop.reform(move |e: DragEvent| (index, e));
+
+    // Show the disabled reason while a disabled tab is hovered
+    let reason = tab.disabled_reason.as_ref().filter(|_| tab.disabled);
+    let reason_id = format!("awsui-tabs-{}-disabled-reason", tab.id);
+    let (on_mouse_enter, on_mouse_leave) = if reason.is_some() {
+        (
+            Some(
+                context
+                    .on_reason_toggle
+                    .reform(move |_: MouseEvent| Some(index)),
+            ),
+            Some(context.on_reason_toggle.reform(|_: MouseEvent| None)),
+        )
+    } else {
+        (None, None)
+    };
+
+    html! {
         <div
             class={tab_classes.build()}
             role="presentation"
+            data-tab-index={index.to_string()}
+            draggable={draggable.then_some("true")}
+            ondragstart={on_drag_start}
+            ondragover={on_drag_over}
+            ondrop={on_drop}
+            ondragend={context.on_drag_end.clone()}
+            onmouseenter={on_mouse_enter}
+            onmouseleave={on_mouse_leave}
         >
             <a
                 class={tab_link_classes.build()}
@@ -437,28 +1143,25 @@ fn render_tab_header(
                 aria-selected={is_active.to_string()}
                 aria-disabled={tab.disabled.to_string()}
                 aria-controls={format!("awsui-tabs-{}-panel", tab.id)}
+                aria-describedby={reason.map(|_| reason_id.clone())}
+                aria-roledescription={draggable.then_some("reorderable tab")}
                 tabindex={if is_active && !tab.disabled { "0" } else { "-1" }}
                 onclick={onclick}
+                onkeydown={on_key_down}
+                onfocus={context.on_tab_focus.reform(move |_: FocusEvent| index)}
             >
                 <span class="awsui-tabs-tab-label">
                     { tab.label.clone() }
                 </span>
             </a>
             { dismiss_button }
+            if let Some(reason) = reason {
+                // Always rendered for `aria-describedby`, visible on hover
+                <span hidden={context.reason_shown != Some(index)}>
+                    <Tooltip id={reason_id} content={html! { { reason } }} />
+                </span>
+            }
         </div>
-    };
-
-    if let Some(ref reason) = tab.disabled_reason {
-        html! {
-            <div
-                class="awsui-tabs-tab-tooltip-wrapper"
-                title={reason.clone()}
-            >
-                { tab_element }
-            </div>
-        }
-    } else {
-        tab_element
     }
 }
 
@@ -472,6 +1175,7 @@ fn render_tab_content(tab: &Tab, active_tab_id: &str) -> Html {
 
     html! {
         <div
+            key={tab.id.clone()}
             class={content_classes.build()}
             role="tabpanel"
             id={format!("awsui-tabs-{}-panel", tab.id)}
@@ -487,6 +1191,135 @@ fn render_tab_content(tab: &Tab, active_tab_id: &str) -> Html {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::internal::dev_warnings::DevWarnings;
+
+    #[test]
+    fn test_dev_warning_duplicate_tab_ids() {
+        let props = yew::props!(TabsProps {
+            tabs: vec![
+                Tab::new("first", html! { "First" }),
+                Tab::new("second", html! { "Second" }),
+                Tab::new("first", html! { "Again" }),
+            ],
+        });
+        let warnings = DevWarnings::new("Tabs");
+        assert_eq!(warnings.check(|| dev_warnings(&props)), 1);
+        assert_eq!(warnings.check(|| dev_warnings(&props)), 0);
+        assert!(dev_warnings(&props)[0].message.contains("first"));
+
+        let unique = yew::props!(TabsProps {
+            tabs: vec![Tab::new("first", html! { "First" })],
+        });
+        assert!(dev_warnings(&unique).is_empty());
+    }
+
+    #[test]
+    fn test_tab_reorder_detail_apply() {
+        let mut tabs = vec!["a", "b", "c", "d"];
+        TabReorderDetail {
+            from_index: 3,
+            to_index: 1,
+        }
+        .apply(&mut tabs);
+        assert_eq!(tabs, vec!["a", "d", "b", "c"]);
+
+        // Out of range moves are ignored
+        TabReorderDetail {
+            from_index: 4,
+            to_index: 0,
+        }
+        .apply(&mut tabs);
+        assert_eq!(tabs, vec!["a", "d", "b", "c"]);
+    }
+
+    #[test]
+    fn test_keyboard_target_skips_disabled_and_wraps() {
+        let disabled = [false, true, false, false];
+        assert_eq!(keyboard_target(&disabled, 0, "ArrowRight"), Some(2));
+        assert_eq!(keyboard_target(&disabled, 3, "ArrowRight"), Some(0));
+        assert_eq!(keyboard_target(&disabled, 2, "ArrowLeft"), Some(0));
+        assert_eq!(keyboard_target(&disabled, 0, "ArrowLeft"), Some(3));
+        assert_eq!(keyboard_target(&disabled, 2, "Home"), Some(0));
+        assert_eq!(keyboard_target(&disabled, 2, "End"), Some(3));
+        assert_eq!(keyboard_target(&disabled, 3, "End"), None);
+        assert_eq!(keyboard_target(&disabled, 0, "Enter"), None);
+        assert_eq!(keyboard_target(&[false, true], 0, "ArrowRight"), None);
+    }
+
+    #[test]
+    fn test_tabs_strings_fallback() {
+        let strings = resolve_strings(&TabsI18nStrings::default(), &I18nContext::default());
+        assert_eq!(strings.scroll_left_aria_label, "Scroll left");
+        assert_eq!(strings.scroll_right_aria_label, "Scroll right");
+
+        let mut messages = crate::internal::I18nStrings::new();
+        messages.set("tabs.scroll_left_aria_label", "Nach links scrollen");
+        let i18n = I18nContext {
+            locale: Some("de-DE".to_string()),
+            messages,
+        };
+        let props_strings = TabsI18nStrings {
+            scroll_right_aria_label: Some("Nach rechts".to_string()),
+            ..Default::default()
+        };
+        let strings = resolve_strings(&props_strings, &i18n);
+        assert_eq!(strings.scroll_left_aria_label, "Nach links scrollen");
+        assert_eq!(strings.scroll_right_aria_label, "Nach rechts");
+    }
+
+    #[test]
+    fn test_dismiss_target_prefers_next_enabled() {
+        let disabled = [false, false, true, false];
+        assert_eq!(dismiss_target(&disabled, 1), Some(3));
+        assert_eq!(dismiss_target(&disabled, 3), Some(1));
+        assert_eq!(dismiss_target(&disabled, 0), Some(1));
+        assert_eq!(dismiss_target(&[false], 0), None);
+        assert_eq!(dismiss_target(&[false, true], 0), None);
+    }
+
+    #[test]
+    fn test_render_strategy_mounts() {
+        // (active, viewed)
+        let cases = [(true, true), (true, false), (false, true), (false, false)];
+        let mounted = |strategy: TabRenderStrategy| {
+            cases
+                .iter()
+                .map(|&(active, viewed)| strategy.mounts(active, viewed))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(mounted(TabRenderStrategy::Eager), [true, true, true, true]);
+        assert_eq!(mounted(TabRenderStrategy::Lazy), [true, true, true, false]);
+        assert_eq!(
+            mounted(TabRenderStrategy::Unmount),
+            [true, true, false, false]
+        );
+        assert_eq!(TabRenderStrategy::default(), TabRenderStrategy::Eager);
+    }
+
+    #[test]
+    fn test_reveal_offset() {
+        // Already visible
+        assert_eq!(reveal_offset((0.0, 300.0), (50.0, 150.0), 24.0), 0.0);
+        // Clipped at the end, or under the faded end
+        assert_eq!(reveal_offset((0.0, 300.0), (250.0, 350.0), 24.0), 74.0);
+        assert_eq!(reveal_offset((0.0, 300.0), (180.0, 290.0), 24.0), 14.0);
+        // Clipped at the start
+        assert_eq!(reveal_offset((100.0, 400.0), (60.0, 160.0), 24.0), -64.0);
+        // Wider than the view: align its start
+        assert_eq!(reveal_offset((0.0, 300.0), (100.0, 500.0), 24.0), 76.0);
+    }
+
+    #[test]
+    fn test_tabs_overflow_measure() {
+        assert_eq!(
+            TabsOverflow::measure(0.0, 300.0, 300.0),
+            TabsOverflow::default()
+        );
+        let overflow = TabsOverflow::measure(0.0, 900.0, 300.0);
+        assert!(overflow.overflowing && !overflow.start && overflow.end);
+        let overflow = TabsOverflow::measure(600.0, 900.0, 300.0);
+        assert!(overflow.overflowing && overflow.start && !overflow.end);
+    }
 
     #[test]
     fn test_tab_new() {
@@ -535,6 +1368,31 @@ mod tests {
         assert_eq!(detail.active_tab_href, Some("/path".to_string()));
     }
 
+    #[test]
+    fn test_link_tab_change_prevents_navigation() {
+        let route =
+            Callback::from(|mut event: CustomEvent<TabChangeDetail>| event.prevent_default());
+        let observe = Callback::from(|_: CustomEvent<TabChangeDetail>| ());
+
+        let link = Tab::new("home", html! { "Home" }).with_href("/home");
+        assert!(!follows_href(
+            true,
+            false,
+            change_event(&link).emit_to(&route)
+        ));
+        assert!(follows_href(
+            true,
+            false,
+            change_event(&link).emit_to(&observe)
+        ));
+
+        // Tabs without an href never navigate, and their change is not cancelable
+        let plain = Tab::new("about", html! { "About" });
+        assert!(!change_event(&plain).emit_to(&route));
+        assert!(!follows_href(false, false, false));
+        assert!(!follows_href(true, true, false));
+    }
+
     #[test]
     fn test_tab_dismiss_detail() {
         let detail = TabDismissDetail {
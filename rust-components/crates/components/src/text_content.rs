@@ -7,7 +7,7 @@
 //! Automatically styles common text elements (headings, paragraphs, lists, code, etc.)
 //! without requiring custom CSS.
 
-use crate::internal::{BaseComponentProps, ClassBuilder};
+use crate::internal::{BaseComponentProps, ClassBuilder, apply_root_attributes};
 use yew::prelude::*;
 
 /// Properties for the TextContent component
@@ -131,14 +131,15 @@ pub fn text_content(props: &TextContentProps) -> Html {
 
     let class = props.base.merge_classes(&class);
 
-    html! {
+    let root = html! {
         <div
             id={props.base.id.clone()}
             class={class}
         >
             { props.children.clone() }
         </div>
-    }
+    };
+    apply_root_attributes(root, &props.base)
 }
 
 #[cfg(test)]
@@ -162,7 +163,7 @@ mod tests {
         let base = BaseComponentProps {
             id: Some("test-id".to_string()),
             class: Some("custom-class".to_string()),
-            data_attributes: None,
+            ..Default::default()
         };
 
         let props = TextContentProps {
@@ -186,7 +187,7 @@ mod tests {
         let base = BaseComponentProps {
             id: None,
             class: Some("user-class".to_string()),
-            data_attributes: None,
+            ..Default::default()
         };
 
         let component_class = ClassBuilder::new().add("awsui-text-content").build();
@@ -5,13 +5,115 @@
 //!
 //! Provides a controlled textarea input with validation states, auto-resize capabilities,
 //! and comprehensive accessibility support.
+//!
+//! With `auto_resize`, the textarea grows with its content up to `max_rows`
+//! and scrolls beyond. With `show_character_count`, a counter below the
+//! control compares the length of the value with `max_length`.
 
+use crate::form_field::{use_field_association, use_validation_state};
+use crate::form_state::use_field_tracking;
+use crate::i18n::{I18nContext, use_i18n};
 use crate::internal::{
     AriaAttributes, BaseComponentProps, ClassBuilder, ComponentMetadata, CustomEvent,
+    apply_root_attributes,
 };
 use web_sys::HtmlTextAreaElement;
 use yew::prelude::*;
 
+/// Color of the character counter once the value is too long
+const COUNTER_EXCEEDED_STYLE: &str = "color: var(--awsui-color-text-status-error)";
+
+/// Internationalization strings for Textarea
+///
+/// Unset strings fall back to the closest I18nProvider (keys under
+/// `"textarea."`), then to English.
+#[derive(Clone, PartialEq, Default)]
+pub struct TextareaI18nStrings {
+    /// Formats the character counter from the length of the value and
+    /// `max_length` (default: "123/500")
+    ///
+    /// Without it, the `textarea.character_count_text` I18nProvider message
+    /// is used, with `{count}` and `{max}` placeholders.
+    pub character_count_text: Option<Callback<(usize, usize), String>>,
+}
+
+/// Formats the character counter: `i18n_strings` callback, then the
+/// I18nProvider, then English
+fn character_count_text(
+    strings: &TextareaI18nStrings,
+    i18n: &I18nContext,
+    count: usize,
+    max: usize,
+) -> String {
+    match &strings.character_count_text {
+        Some(format) => format.emit((count, max)),
+        None => i18n
+            .message("textarea", "character_count_text")
+            .unwrap_or("{count}/{max}")
+            .replace("{count}", &count.to_string())
+            .replace("{max}", &max.to_string()),
+    }
+}
+
+/// Metrics of a textarea element used to fit its height to its content
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct TextareaMetrics {
+    /// Height of the content including padding (`scroll_height`)
+    content_height: f64,
+    /// Combined top and bottom border widths
+    border: f64,
+    /// Combined top and bottom padding
+    padding: f64,
+    /// Height of a line of text
+    line_height: f64,
+}
+
+impl TextareaMetrics {
+    /// Gets the height fitting the content, capped at `max_rows` lines, and
+    /// whether the content overflows that height and must scroll
+    fn fit(&self, max_rows: Option<u32>) -> (f64, bool) {
+        let height = self.content_height + self.border;
+        match max_rows {
+            Some(max_rows) => {
+                let cap = f64::from(max_rows) * self.line_height + self.padding + self.border;
+                // Allow a pixel of rounding
+                (height.min(cap), height > cap + 1.0)
+            }
+            None => (height, false),
+        }
+    }
+}
+
+/// Fits the height of a textarea to its content
+fn fit_height(textarea: &HtmlTextAreaElement, max_rows: Option<u32>) {
+    let style = textarea.style();
+    // Collapse first so the content height can shrink as well as grow
+    let _ = style.set_property("height", "auto");
+
+    let computed =
+        web_sys::window().and_then(|window| window.get_computed_style(textarea).ok().flatten());
+    let pixels = |name: &str| {
+        computed
+            .as_ref()
+            .and_then(|computed| computed.get_property_value(name).ok())
+            .and_then(|value| value.trim_end_matches("px").parse::<f64>().ok())
+    };
+    // "normal" line heights are about 1.2 times the font size
+    let line_height = pixels("line-height")
+        .or_else(|| pixels("font-size").map(|size| size * 1.2))
+        .unwrap_or(20.0);
+
+    let metrics = TextareaMetrics {
+        content_height: f64::from(textarea.scroll_height()),
+        border: f64::from(textarea.offset_height() - textarea.client_height()),
+        padding: pixels("padding-top").unwrap_or(0.0) + pixels("padding-bottom").unwrap_or(0.0),
+        line_height,
+    };
+    let (height, overflowing) = metrics.fit(max_rows);
+    let _ = style.set_property("height", &format!("{}px", height));
+    let _ = style.set_property("overflow-y", if overflowing { "auto" } else { "hidden" });
+}
+
 /// Event detail for change events
 #[derive(Clone, PartialEq, Debug)]
 pub struct TextareaChangeDetail {
@@ -98,6 +200,34 @@ pub struct TextareaProps {
     /// Control ID for form field integration
     #[prop_or_default]
     pub control_id: Option<String>,
+
+    /// Whether the height follows the content as it is edited
+    ///
+    /// The textarea starts at `rows` lines, and the native resize handle is
+    /// hidden while the height is automatic.
+    #[prop_or_default]
+    pub auto_resize: bool,
+
+    /// Largest number of lines an auto-resizing textarea grows to before
+    /// it scrolls
+    #[prop_or_default]
+    pub max_rows: Option<u32>,
+
+    /// Number of characters the value should not exceed
+    ///
+    /// Longer values can still be entered; the textarea is then marked
+    /// invalid (`aria-invalid`) and the character counter turns red.
+    #[prop_or_default]
+    pub max_length: Option<usize>,
+
+    /// Whether to show a character counter, such as "123/500", below the
+    /// control; requires `max_length`
+    #[prop_or_default]
+    pub show_character_count: bool,
+
+    /// Internationalization strings
+    #[prop_or_default]
+    pub i18n_strings: TextareaI18nStrings,
 }
 
 /// Textarea component for multi-line text input.
@@ -148,16 +278,20 @@ pub struct TextareaProps {
 /// ```rust
 /// use cloudscape_components::Textarea;
 /// use yew::prelude::*;
+/// # let feedback_value = String::new();
+/// # let on_feedback_change = Callback::from(|_: cloudscape_components::CustomEvent<cloudscape_components::TextareaChangeDetail>| {});
 ///
+/// # let _: Html =
 /// html! {
 ///     <Textarea
-///         value={feedback_value}
+///         value={feedback_value.clone()}
 ///         invalid={feedback_value.len() > 500}
 ///         placeholder="Maximum 500 characters"
 ///         rows={4}
 ///         on_change={on_feedback_change}
 ///     />
 /// }
+/// # ;
 /// ```
 ///
 /// # Accessibility
@@ -179,6 +313,7 @@ pub struct TextareaProps {
 pub fn textarea(props: &TextareaProps) -> Html {
     let _metadata = ComponentMetadata::new("Textarea");
     let textarea_ref = use_node_ref();
+    let i18n = use_i18n();
 
     // Handle input change
     let on_input = {
@@ -197,11 +332,16 @@ pub fn textarea(props: &TextareaProps) -> Html {
         })
     };
 
+    // Report the value and blur to the closest FormStateProvider
+    let on_field_blur = use_field_tracking(props.value.clone());
+
     // Handle blur
     let on_blur_event = {
         let on_blur = props.on_blur.clone();
 
         Callback::from(move |_e: FocusEvent| {
+            on_field_blur.emit(());
+
             if let Some(callback) = &on_blur {
                 callback.emit(());
             }
@@ -219,20 +359,71 @@ pub fn textarea(props: &TextareaProps) -> Html {
         })
     };
 
+    // Validation state, merged with the enclosing FormField
+    let validation = use_validation_state(props.invalid, props.warning);
+
+    // Id and ARIA references, merged with the enclosing FormField
+    let association = use_field_association(
+        props.control_id.clone(),
+        props.aria.labelledby.clone(),
+        props.aria.describedby.clone(),
+    );
+
+    // Compare the length of the value with the limit
+    let char_count = props.value.chars().count();
+    let exceeded = props
+        .max_length
+        .is_some_and(|max_length| char_count > max_length);
+    let invalid = validation.is_invalid() || exceeded;
+    let counter_id = association
+        .control_id
+        .as_ref()
+        .map(|id| format!("{}-character-count", id));
+
+    // Without auto resize, the height is left alone so that the native
+    // resize handle keeps working. The control style is a dependency because
+    // re-rendering it replaces the inline height.
+    let style = match (validation.control_style(), props.auto_resize) {
+        (Some(style), true) => Some(format!("{}; resize: none", style)),
+        (None, true) => Some("resize: none".to_string()),
+        (style, false) => style,
+    };
+    {
+        let textarea_ref = textarea_ref.clone();
+        use_effect_with(
+            (
+                props.value.clone(),
+                props.auto_resize,
+                props.max_rows,
+                props.rows,
+                style.clone(),
+            ),
+            move |(_, auto_resize, max_rows, _, _)| {
+                if let Some(textarea) = textarea_ref.cast::<HtmlTextAreaElement>() {
+                    if *auto_resize {
+                        fit_height(&textarea, *max_rows);
+                    } else {
+                        let style = textarea.style();
+                        let _ = style.remove_property("height");
+                        let _ = style.remove_property("overflow-y");
+                    }
+                }
+            },
+        );
+    }
+
     // Build CSS classes
     let textarea_classes = ClassBuilder::new()
         .add("awsui-textarea")
         .add_if(props.disabled, "awsui-textarea-disabled")
         .add_if(props.readonly, "awsui-textarea-readonly")
-        .add_if(props.invalid, "awsui-textarea-invalid")
-        .add_if(!props.invalid && props.warning, "awsui-textarea-warning");
+        .add_if(invalid, "awsui-textarea-invalid")
+        .add_if(validation.is_warning(), "awsui-textarea-warning")
+        .add_if(props.auto_resize, "awsui-textarea-auto-resize");
 
     // Determine autocomplete attribute
     let autocomplete_attr = props.autocomplete.map(|ac| if ac { "on" } else { "off" });
 
-    // Determine control ID (use provided or None to let browser auto-generate)
-    let textarea_id = props.control_id.clone();
-
     // ARIA label
     let aria_label = props.aria.label.clone();
 
@@ -242,12 +433,24 @@ pub fn textarea(props: &TextareaProps) -> Html {
     // Build wrapper classes
     let wrapper_classes = ClassBuilder::new().add("awsui-textarea-wrapper");
 
-    html! {
+    // Describe the textarea with the counter too
+    let show_counter = props.show_character_count && props.max_length.is_some();
+    let describedby = [
+        association.describedby.clone(),
+        counter_id.clone().filter(|_| show_counter),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>();
+    let describedby = (!describedby.is_empty()).then(|| describedby.join(" "));
+
+    let root = html! {
         <span class={wrapper_classes.build()}>
             <textarea
                 ref={textarea_ref}
                 class={textarea_classes.build()}
-                id={textarea_id}
+                style={style}
+                id={association.control_id.clone()}
                 name={props.name.clone()}
                 value={props.value.clone()}
                 placeholder={props.placeholder.clone()}
@@ -261,15 +464,28 @@ pub fn textarea(props: &TextareaProps) -> Html {
                 rows={rows}
                 aria-label={aria_label}
                 aria-required={props.aria_required.to_string()}
-                aria-invalid={props.invalid.to_string()}
-                aria-labelledby={props.aria.labelledby.clone()}
-                aria-describedby={props.aria.describedby.clone()}
+                aria-invalid={invalid.then_some("true")}
+                aria-labelledby={association.labelledby.clone()}
+                aria-describedby={describedby}
                 oninput={on_input}
                 onblur={on_blur_event}
                 onfocus={on_focus_event}
             />
+            if let Some(max_length) = props.max_length.filter(|_| show_counter) {
+                <span
+                    id={counter_id}
+                    class={ClassBuilder::new()
+                        .add("awsui-textarea-character-count")
+                        .add_if(exceeded, "awsui-textarea-character-count-exceeded")
+                        .build()}
+                    style={exceeded.then_some(COUNTER_EXCEEDED_STYLE)}
+                >
+                    { character_count_text(&props.i18n_strings, &i18n, char_count, max_length) }
+                </span>
+            }
         </span>
-    }
+    };
+    apply_root_attributes(root, &props.base)
 }
 
 #[cfg(test)]
@@ -324,6 +540,11 @@ mod tests {
             aria: AriaAttributes::default(),
             aria_required: false,
             control_id: None,
+            auto_resize: false,
+            max_rows: None,
+            max_length: None,
+            show_character_count: false,
+            i18n_strings: TextareaI18nStrings::default(),
         };
 
         assert_eq!(props.rows, None);
@@ -353,6 +574,11 @@ mod tests {
             aria: AriaAttributes::default(),
             aria_required: false,
             control_id: None,
+            auto_resize: false,
+            max_rows: None,
+            max_length: None,
+            show_character_count: false,
+            i18n_strings: TextareaI18nStrings::default(),
         };
 
         assert_eq!(props.rows, Some(5));
@@ -381,6 +607,11 @@ mod tests {
             aria: AriaAttributes::default(),
             aria_required: false,
             control_id: None,
+            auto_resize: false,
+            max_rows: None,
+            max_length: None,
+            show_character_count: false,
+            i18n_strings: TextareaI18nStrings::default(),
         };
 
         assert!(invalid_props.invalid);
@@ -397,6 +628,50 @@ mod tests {
         assert!(warning_props.warning);
     }
 
+    #[test]
+    fn auto_height_caps_at_max_rows() {
+        let metrics = TextareaMetrics {
+            content_height: 200.0,
+            border: 2.0,
+            padding: 8.0,
+            line_height: 20.0,
+        };
+        assert_eq!(metrics.fit(None), (202.0, false));
+        // 5 lines of 20px, padding and border
+        assert_eq!(metrics.fit(Some(5)), (110.0, true));
+        assert_eq!(metrics.fit(Some(10)), (202.0, false));
+    }
+
+    #[test]
+    fn character_count_text_fallback() {
+        let strings = TextareaI18nStrings::default();
+        assert_eq!(
+            character_count_text(&strings, &I18nContext::default(), 123, 500),
+            "123/500"
+        );
+
+        let mut messages = crate::internal::I18nStrings::new();
+        messages.set("textarea.character_count_text", "{count} von {max} Zeichen");
+        let i18n = I18nContext {
+            locale: Some("de-DE".to_string()),
+            messages,
+        };
+        assert_eq!(
+            character_count_text(&strings, &i18n, 3, 10),
+            "3 von 10 Zeichen"
+        );
+
+        let strings = TextareaI18nStrings {
+            character_count_text: Some(Callback::from(|(count, max): (usize, usize)| {
+                format!("{} characters left", max.saturating_sub(count))
+            })),
+        };
+        assert_eq!(
+            character_count_text(&strings, &i18n, 3, 10),
+            "7 characters left"
+        );
+    }
+
     #[test]
     fn custom_event_non_cancelable() {
         let detail = TextareaChangeDetail {
@@ -405,6 +680,6 @@ mod tests {
         let event = CustomEvent::new_non_cancelable(detail);
 
         assert!(!event.cancelable);
-        assert!(!event.default_prevented);
+        assert!(!event.default_prevented());
     }
 }
@@ -0,0 +1,512 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Theming at runtime
+//!
+//! Visual modes (dark, compact, reduced motion) are scoped by the CSS class of
+//! each [`Mode`] on an ancestor element, the document body by default.
+//! [`apply_mode`] and [`remove_mode`] toggle those classes, and
+//! [`use_color_scheme`] keeps the color mode in sync with a user choice or
+//! with the system's `prefers-color-scheme`.
+//!
+//! Design tokens can be re-branded by writing their CSS custom properties on
+//! the document root with [`apply_theme_overrides`].
+
+use crate::internal::dev_warnings::{DevWarning, log_dev_warning};
+use cloudscape_design_tokens::contrast::check_theme;
+use cloudscape_design_tokens::{DesignTokens, Mode};
+use gloo::events::EventListener;
+use std::collections::HashMap;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement, MediaQueryList};
+use yew::prelude::*;
+
+/// Media query matching a system preference for dark colors
+const PREFERS_DARK_QUERY: &str = "(prefers-color-scheme: dark)";
+
+/// Modes that exclude each other: light and dark, comfortable and compact,
+/// default and disabled motion
+fn mode_group(mode: Mode) -> [Mode; 2] {
+    match mode {
+        Mode::Light | Mode::Dark => [Mode::Light, Mode::Dark],
+        Mode::Comfortable | Mode::Compact => [Mode::Comfortable, Mode::Compact],
+        Mode::MotionDefault | Mode::MotionDisabled => [Mode::MotionDefault, Mode::MotionDisabled],
+    }
+}
+
+/// Returns `class_name` with the classes of `mode`'s group replaced by the
+/// class of `mode`; default modes have no class, so applying them clears
+/// their group
+fn with_mode_class(class_name: &str, mode: Mode) -> String {
+    let group = mode_group(mode).map(|mode| mode.css_class());
+    let mut classes: Vec<&str> = class_name
+        .split_whitespace()
+        .filter(|class| !group.contains(class))
+        .collect();
+    if !mode.css_class().is_empty() {
+        classes.push(mode.css_class());
+    }
+    classes.join(" ")
+}
+
+/// Returns `class_name` without the class of `mode`
+fn without_mode_class(class_name: &str, mode: Mode) -> String {
+    class_name
+        .split_whitespace()
+        .filter(|class| *class != mode.css_class())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// The document body, where modes apply to the whole page
+fn body() -> Option<Element> {
+    web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.body())
+        .map(Element::from)
+}
+
+/// Applies a mode to an element and everything inside it
+///
+/// The other mode of the same kind is removed, so applying [`Mode::Light`]
+/// leaves dark mode. Applying a mode twice has no further effect.
+pub fn apply_mode_to(element: &Element, mode: Mode) {
+    let class_name = with_mode_class(&element.class_name(), mode);
+    if class_name != element.class_name() {
+        element.set_class_name(&class_name);
+    }
+}
+
+/// Removes a mode from an element, returning it to the default mode of the
+/// same kind
+pub fn remove_mode_from(element: &Element, mode: Mode) {
+    let class_name = without_mode_class(&element.class_name(), mode);
+    if class_name != element.class_name() {
+        element.set_class_name(&class_name);
+    }
+}
+
+/// Applies a mode to the whole page
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use cloudscape_components::theme::apply_mode;
+/// use cloudscape_components::tokens::Mode;
+///
+/// apply_mode(Mode::Dark);
+/// apply_mode(Mode::Compact);
+/// ```
+pub fn apply_mode(mode: Mode) {
+    if let Some(body) = body() {
+        apply_mode_to(&body, mode);
+    }
+}
+
+/// Removes a mode from the whole page
+pub fn remove_mode(mode: Mode) {
+    if let Some(body) = body() {
+        remove_mode_from(&body, mode);
+    }
+}
+
+/// Color scheme chosen by the user
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorScheme {
+    /// Always light
+    Light,
+    /// Always dark
+    Dark,
+    /// Follows the system's `prefers-color-scheme` (default)
+    #[default]
+    Auto,
+}
+
+impl ColorScheme {
+    /// Returns the color mode for this scheme, given whether the system
+    /// prefers dark colors
+    pub fn mode(self, prefers_dark: bool) -> Mode {
+        match self {
+            ColorScheme::Light => Mode::Light,
+            ColorScheme::Dark => Mode::Dark,
+            ColorScheme::Auto if prefers_dark => Mode::Dark,
+            ColorScheme::Auto => Mode::Light,
+        }
+    }
+}
+
+/// Handle returned by [`use_color_scheme`]
+#[derive(Clone, PartialEq)]
+pub struct UseColorSchemeHandle {
+    /// The chosen scheme
+    pub scheme: ColorScheme,
+    /// The color mode applied to the page
+    pub mode: Mode,
+    /// Changes the chosen scheme
+    pub set_scheme: Callback<ColorScheme>,
+}
+
+/// The system's dark color preference query, if supported
+fn prefers_dark_query() -> Option<MediaQueryList> {
+    web_sys::window().and_then(|window| window.match_media(PREFERS_DARK_QUERY).ok().flatten())
+}
+
+/// Applies a color scheme to the page and tracks the resulting mode
+///
+/// With [`ColorScheme::Auto`], the mode follows the system's
+/// `prefers-color-scheme` as it changes. The mode is applied to the document
+/// body while the component using the hook is mounted.
+///
+/// # Example
+///
+/// ```rust
+/// use cloudscape_components::theme::{ColorScheme, use_color_scheme};
+/// use cloudscape_components::{Toggle, ToggleChangeDetail, CustomEvent};
+/// use cloudscape_components::tokens::Mode;
+/// use yew::prelude::*;
+///
+/// #[function_component(DarkModeToggle)]
+/// fn dark_mode_toggle() -> Html {
+///     let color_scheme = use_color_scheme(ColorScheme::Auto);
+///     let on_change = {
+///         let set_scheme = color_scheme.set_scheme.clone();
+///         Callback::from(move |event: CustomEvent<ToggleChangeDetail>| {
+///             set_scheme.emit(if event.detail.checked {
+///                 ColorScheme::Dark
+///             } else {
+///                 ColorScheme::Light
+///             });
+///         })
+///     };
+///
+///     html! {
+///         <Toggle checked={color_scheme.mode == Mode::Dark} on_change={on_change}>
+///             { "Dark mode" }
+///         </Toggle>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_color_scheme(initial: ColorScheme) -> UseColorSchemeHandle {
+    let scheme = use_state_eq(|| initial);
+    let prefers_dark = use_state_eq(|| prefers_dark_query().is_some_and(|query| query.matches()));
+
+    // Follow the system preference while it matters
+    {
+        let prefers_dark = prefers_dark.clone();
+        use_effect_with(*scheme == ColorScheme::Auto, move |auto| {
+            let listener = auto.then(prefers_dark_query).flatten().map(|query| {
+                prefers_dark.set(query.matches());
+                let target = query.clone();
+                EventListener::new(&query, "change", move |_| {
+                    prefers_dark.set(target.matches());
+                })
+            });
+            move || drop(listener)
+        });
+    }
+
+    let mode = scheme.mode(*prefers_dark);
+    use_effect_with(mode, |mode| {
+        apply_mode(*mode);
+        || remove_mode(Mode::Dark)
+    });
+
+    UseColorSchemeHandle {
+        scheme: *scheme,
+        mode,
+        set_scheme: Callback::from(move |next: ColorScheme| scheme.set(next)),
+    }
+}
+
+/// Modes whose token values [`apply_theme_overrides`] writes
+const DEFAULT_MODES: [Mode; 3] = [Mode::Light, Mode::Comfortable, Mode::MotionDefault];
+
+/// Gets the CSS custom properties written for a token set, with each token's
+/// value in the default modes
+///
+/// Tokens without a value for a default mode are skipped.
+fn override_properties(tokens: &DesignTokens) -> Vec<(&str, &str)> {
+    tokens
+        .values
+        .iter()
+        .filter_map(|(name, values)| {
+            DEFAULT_MODES
+                .iter()
+                .find_map(|mode| values.get(mode))
+                .map(|value| (name.as_str(), value.as_str()))
+        })
+        .collect()
+}
+
+/// Reports tokens with values for modes other than the default ones, which
+/// [`apply_theme_overrides`] doesn't write
+fn ignored_mode_warnings(tokens: &DesignTokens) -> Vec<DevWarning> {
+    tokens
+        .values
+        .iter()
+        .filter_map(|(name, values)| {
+            let ignored: Vec<String> = values
+                .keys()
+                .filter(|mode| !DEFAULT_MODES.contains(mode))
+                .map(|mode| format!("{:?}", mode))
+                .collect();
+            (!ignored.is_empty()).then(|| {
+                DevWarning::new(
+                    "theme-ignored-mode",
+                    format!(
+                        "{} has {} mode values, which are not applied. \
+                         Only light, comfortable and default motion values are written.",
+                        name,
+                        ignored.join(", ")
+                    ),
+                )
+            })
+        })
+        .collect()
+}
+
+/// Default light values of the color tokens, which overrides are checked
+/// against
+#[cfg(feature = "generated")]
+fn default_colors() -> HashMap<String, String> {
+    cloudscape_design_tokens::generated::ColorToken::ALL
+        .iter()
+        .map(|token| {
+            (
+                token.css_var_name().to_string(),
+                token.value(Mode::Light).to_string(),
+            )
+        })
+        .collect()
+}
+
+/// Without the generated tokens, only pairs that are both overridden are
+/// checked
+#[cfg(not(feature = "generated"))]
+fn default_colors() -> HashMap<String, String> {
+    HashMap::new()
+}
+
+/// Checks overridden properties for text and background pairs with too little
+/// contrast
+///
+/// Only pairs with at least one overridden token are reported, so the
+/// default theme's own values don't warn.
+fn contrast_warnings(properties: &[(&str, &str)]) -> Vec<DevWarning> {
+    let mut theme = default_colors();
+    theme.extend(
+        properties
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string())),
+    );
+    let overridden = |name: &str| properties.iter().any(|(property, _)| *property == name);
+
+    check_theme(&theme)
+        .into_iter()
+        .filter(|violation| {
+            overridden(violation.pair.foreground) || overridden(violation.pair.background)
+        })
+        .map(|violation| {
+            DevWarning::new(
+                "theme-low-contrast",
+                format!(
+                    "{} on {} has a contrast ratio of {:.2}, below the required {:.1}.",
+                    violation.pair.foreground,
+                    violation.pair.background,
+                    violation.ratio,
+                    violation.required_ratio
+                ),
+            )
+        })
+        .collect()
+}
+
+/// The document root, where overrides apply to the whole page
+fn root() -> Option<HtmlElement> {
+    web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.document_element())
+        .and_then(|element| element.dyn_into::<HtmlElement>().ok())
+}
+
+/// Writes token overrides onto the document root (`:root`)
+///
+/// `tokens` holds the overridden values by CSS custom property name, as
+/// returned by the `css_var_name` of the generated token enums, and mode.
+/// The values for the default modes (light, comfortable and default motion)
+/// are written. Values for the dark, compact and disabled motion modes are
+/// not applied, since the root's inline style can't be scoped to a mode;
+/// debug builds log a warning for each token that has them. Properties
+/// already set are replaced, so applying the same overrides again has no
+/// further effect.
+///
+/// Debug builds also check the overridden colors against the text and
+/// background pairs of the components, and log a warning for each pair below
+/// the WCAG AA contrast ratio.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use cloudscape_components::theme::apply_theme_overrides;
+/// use cloudscape_components::tokens::{DesignTokens, Mode};
+/// use std::collections::BTreeMap;
+///
+/// let mut tokens = DesignTokens::default();
+/// tokens.values.insert(
+///     "--awsui-color-background-button-primary-default".to_string(),
+///     BTreeMap::from([(Mode::Light, "#6b2fba".to_string())]),
+/// );
+/// apply_theme_overrides(&tokens);
+/// ```
+pub fn apply_theme_overrides(tokens: &DesignTokens) {
+    let properties = override_properties(tokens);
+    if cfg!(debug_assertions) {
+        let warnings = ignored_mode_warnings(tokens)
+            .into_iter()
+            .chain(contrast_warnings(&properties));
+        for warning in warnings {
+            log_dev_warning("Theme", &warning);
+        }
+    }
+
+    if let Some(root) = root() {
+        let style = root.style();
+        for (name, value) in properties {
+            let _ = style.set_property(name, value);
+        }
+    }
+}
+
+/// Removes token overrides from the document root, restoring the theme's
+/// values
+///
+/// Only the properties written for `tokens` are removed; their values don't
+/// need to match.
+pub fn remove_theme_overrides(tokens: &DesignTokens) {
+    if let Some(root) = root() {
+        let style = root.style();
+        for (name, _) in override_properties(tokens) {
+            let _ = style.remove_property(name);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_with_mode_class_replaces_group() {
+        assert_eq!(with_mode_class("page", Mode::Dark), "page awsui-dark-mode");
+        assert_eq!(
+            with_mode_class("page awsui-dark-mode", Mode::Dark),
+            "page awsui-dark-mode"
+        );
+        assert_eq!(with_mode_class("page awsui-dark-mode", Mode::Light), "page");
+        // Other kinds of modes are kept
+        assert_eq!(
+            with_mode_class("awsui-dark-mode", Mode::Compact),
+            "awsui-dark-mode awsui-compact-mode"
+        );
+    }
+
+    #[test]
+    fn test_without_mode_class() {
+        assert_eq!(
+            without_mode_class("page awsui-compact-mode awsui-dark-mode", Mode::Compact),
+            "page awsui-dark-mode"
+        );
+        assert_eq!(without_mode_class("page", Mode::MotionDisabled), "page");
+    }
+
+    #[test]
+    fn test_color_scheme_mode() {
+        assert_eq!(ColorScheme::default(), ColorScheme::Auto);
+        assert_eq!(ColorScheme::Auto.mode(true), Mode::Dark);
+        assert_eq!(ColorScheme::Auto.mode(false), Mode::Light);
+        assert_eq!(ColorScheme::Light.mode(true), Mode::Light);
+        assert_eq!(ColorScheme::Dark.mode(false), Mode::Dark);
+    }
+
+    #[test]
+    fn test_override_properties_use_default_modes() {
+        let mut tokens = DesignTokens::default();
+        tokens.values.insert(
+            "--awsui-color-primary600".to_string(),
+            BTreeMap::from([
+                (Mode::Light, "#6b2fba".to_string()),
+                (Mode::Dark, "#9d6ee0".to_string()),
+            ]),
+        );
+        tokens.values.insert(
+            "--awsui-space-scaled-m".to_string(),
+            BTreeMap::from([
+                (Mode::Comfortable, "16px".to_string()),
+                (Mode::Compact, "12px".to_string()),
+            ]),
+        );
+        // Without a default-mode value, nothing is written
+        tokens.values.insert(
+            "--awsui-color-primary700".to_string(),
+            BTreeMap::from([(Mode::Dark, "#b58ff0".to_string())]),
+        );
+
+        assert_eq!(
+            override_properties(&tokens),
+            vec![
+                ("--awsui-color-primary600", "#6b2fba"),
+                ("--awsui-space-scaled-m", "16px"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ignored_mode_warnings() {
+        let mut tokens = DesignTokens::default();
+        tokens.values.insert(
+            "--awsui-color-primary600".to_string(),
+            BTreeMap::from([
+                (Mode::Light, "#6b2fba".to_string()),
+                (Mode::Dark, "#9d6ee0".to_string()),
+            ]),
+        );
+        tokens.values.insert(
+            "--awsui-space-scaled-m".to_string(),
+            BTreeMap::from([(Mode::Comfortable, "16px".to_string())]),
+        );
+
+        let warnings = ignored_mode_warnings(&tokens);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, "theme-ignored-mode");
+        assert!(
+            warnings[0]
+                .message
+                .starts_with("--awsui-color-primary600 has Dark mode values")
+        );
+    }
+
+    #[test]
+    fn test_contrast_warnings_for_overridden_pairs() {
+        let warnings = contrast_warnings(&[
+            ("--awsui-color-text-button-primary-default", "#ffffff"),
+            ("--awsui-color-background-button-primary-default", "#99ccff"),
+        ]);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, "theme-low-contrast");
+        assert!(warnings[0].message.starts_with(
+            "--awsui-color-text-button-primary-default on \
+             --awsui-color-background-button-primary-default has a contrast ratio of"
+        ));
+
+        // Pairs with enough contrast don't warn
+        assert!(
+            contrast_warnings(&[
+                ("--awsui-color-text-button-primary-default", "#ffffff"),
+                ("--awsui-color-background-button-primary-default", "#0f141a"),
+            ])
+            .is_empty()
+        );
+    }
+}
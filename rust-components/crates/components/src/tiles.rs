@@ -6,8 +6,13 @@
 //! Provides a selectable tile grid for single selection with support for images,
 //! descriptions, and column layout configuration.
 
+use crate::form_field::use_native_validity;
 use crate::internal::{
     AriaAttributes, BaseComponentProps, ClassBuilder, ComponentMetadata, CustomEvent,
+    apply_root_attributes,
+};
+use crate::radio_group::{
+    READ_ONLY_CONTROL_STYLE, focus_by_id, radio_arrow_target, radio_tab_stop,
 };
 use web_sys::HtmlInputElement;
 use yew::prelude::*;
@@ -147,6 +152,21 @@ pub struct TilesProps {
     #[prop_or_default]
     pub name: Option<String>,
 
+    /// Whether a selection is required before the form can be submitted
+    #[prop_or_default]
+    pub required: bool,
+
+    /// ID of the form the control belongs to, for controls rendered outside it
+    #[prop_or_default]
+    pub form: Option<String>,
+
+    /// Whether the tiles are read-only
+    ///
+    /// Read-only tiles stay focusable and readable, but the selection
+    /// can't be changed.
+    #[prop_or_default]
+    pub read_only: bool,
+
     /// ARIA attributes
     #[prop_or_default]
     pub aria: AriaAttributes,
@@ -161,10 +181,16 @@ pub struct TilesProps {
 /// Provides a selectable tile grid for single selection with support for images,
 /// descriptions, and column layout configuration.
 ///
+/// # Keyboard
+///
+/// Only the checked tile, or the first enabled one when none is checked, is
+/// in the tab order. Arrow keys select the next or previous enabled tile,
+/// wrapping around; read-only tiles only move focus.
+///
 /// # Example
 ///
 /// ```rust
-/// use cloudscape_components::{Tiles, TileItem, TilesChangeDetail};
+/// use cloudscape_components::{CustomEvent, Tiles, TileItem, TilesChangeDetail};
 /// use yew::prelude::*;
 ///
 /// let items = vec![
@@ -177,9 +203,10 @@ pub struct TilesProps {
 /// ];
 ///
 /// let on_change = Callback::from(|event: CustomEvent<TilesChangeDetail>| {
-///     console::log!("Selected: {}", event.detail.value);
+///     let _selected = event.detail.value;
 /// });
 ///
+/// # let _: Html =
 /// html! {
 ///     <Tiles
 ///         items={items}
@@ -188,33 +215,92 @@ pub struct TilesProps {
 ///         on_change={on_change}
 ///     />
 /// }
+/// # ;
 /// ```
 #[function_component(Tiles)]
 pub fn tiles(props: &TilesProps) -> Html {
     let _metadata = ComponentMetadata::new("Tiles");
 
-    // Generate a unique name if not provided
-    let group_name = props.name.clone().unwrap_or_else(|| {
+    // Generate a unique name if not provided; kept stable across renders so
+    // native grouping and form submission keep working
+    let generated_name = use_state(|| {
         use std::sync::atomic::{AtomicUsize, Ordering};
         static COUNTER: AtomicUsize = AtomicUsize::new(0);
         let id = COUNTER.fetch_add(1, Ordering::Relaxed);
         format!("awsui-tiles-{}", id)
     });
+    let group_name = props
+        .name
+        .clone()
+        .unwrap_or_else(|| (*generated_name).clone());
+
+    let on_invalid = use_native_validity(!props.required || props.value.is_some());
 
     // Clamp columns to 1-4 range
     let columns = props.columns.clamp(1, 4);
 
+    // Only one tile is in the tab order; arrow keys move the selection
+    // between the enabled ones
+    let control_ids: Vec<String> = props
+        .items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| {
+            item.control_id
+                .clone()
+                .unwrap_or_else(|| format!("{}-{}", group_name, index))
+        })
+        .collect();
+    let disabled: Vec<bool> = props.items.iter().map(|item| item.disabled).collect();
+    let checked_index = props
+        .items
+        .iter()
+        .position(|item| props.value.as_ref() == Some(&item.value));
+    let tab_stop = radio_tab_stop(checked_index, &disabled);
+
+    let on_key_down = {
+        let control_ids = control_ids.clone();
+        let values: Vec<String> = props.items.iter().map(|item| item.value.clone()).collect();
+        let on_change = props.on_change.clone();
+        let read_only = props.read_only;
+        Callback::from(move |e: KeyboardEvent| {
+            // Space would check a focused tile of read-only tiles
+            if read_only && e.key() == " " {
+                e.prevent_default();
+                return;
+            }
+            let Some(current) = e
+                .target_dyn_into::<HtmlInputElement>()
+                .and_then(|input| control_ids.iter().position(|id| *id == input.id()))
+            else {
+                return;
+            };
+            let Some(target) = radio_arrow_target(current, &e.key(), &disabled) else {
+                return;
+            };
+            // The browser would check the next tile itself
+            e.prevent_default();
+            focus_by_id(&control_ids[target]);
+            if !read_only && let Some(callback) = &on_change {
+                callback.emit(CustomEvent::new_non_cancelable(TilesChangeDetail {
+                    value: values[target].clone(),
+                }));
+            }
+        })
+    };
+
     // Build root CSS classes
     let root_classes = ClassBuilder::new()
         .add("awsui-tiles")
-        .add(format!("awsui-tiles-columns-{}", columns));
+        .add(format!("awsui-tiles-columns-{}", columns))
+        .add_if(props.read_only, "awsui-tiles-readonly");
 
     // Determine ARIA attributes
     let aria_labelledby = props.aria.labelledby.clone();
     let aria_label = props.aria.label.clone();
     let aria_describedby = props.aria.describedby.clone();
 
-    html! {
+    let root = html! {
         <div
             id={props.base.id.clone()}
             class={root_classes.build()}
@@ -223,6 +309,8 @@ pub fn tiles(props: &TilesProps) -> Html {
             aria-label={aria_label}
             aria-describedby={aria_describedby}
             aria-required={props.aria_required.then_some("true")}
+            aria-readonly={props.read_only.then_some("true")}
+            onkeydown={on_key_down}
         >
             {
                 props.items.iter().enumerate().map(|(index, item)| {
@@ -230,16 +318,18 @@ pub fn tiles(props: &TilesProps) -> Html {
                     let item_value = item.value.clone();
                     let on_change = props.on_change.clone();
                     let item_disabled = item.disabled;
+                    let read_only = props.read_only;
 
                     // Build item CSS classes
                     let item_classes = ClassBuilder::new()
                         .add("awsui-tiles-item")
                         .add_if(is_checked, "awsui-tiles-item-selected")
-                        .add_if(item.disabled, "awsui-tiles-item-disabled");
+                        .add_if(item.disabled, "awsui-tiles-item-disabled")
+                        .add_if(props.read_only, "awsui-tiles-item-readonly");
 
                     // Handle selection
                     let on_select = Callback::from(move |_e: Event| {
-                        if !item_disabled
+                        if !read_only && !item_disabled
                             && let Some(callback) = &on_change {
                                 callback.emit(CustomEvent::new_non_cancelable(
                                     TilesChangeDetail {
@@ -249,11 +339,6 @@ pub fn tiles(props: &TilesProps) -> Html {
                             }
                     });
 
-                    // Generate control ID
-                    let control_id = item.control_id.clone().unwrap_or_else(|| {
-                        format!("{}-{}", group_name, index)
-                    });
-
                     html! {
                         <Tile
                             key={item.value.clone()}
@@ -263,7 +348,12 @@ pub fn tiles(props: &TilesProps) -> Html {
                             label={item.label.clone()}
                             checked={is_checked}
                             disabled={item.disabled}
-                            control_id={control_id}
+                            read_only={props.read_only}
+                            required={props.required}
+                            form={props.form.clone()}
+                            on_invalid={on_invalid.clone()}
+                            control_id={control_ids[index].clone()}
+                            tab_stop={tab_stop == Some(index)}
                             description={item.description.clone()}
                             image={item.image.clone()}
                             on_select={on_select}
@@ -272,7 +362,8 @@ pub fn tiles(props: &TilesProps) -> Html {
                 }).collect::<Html>()
             }
         </div>
-    }
+    };
+    apply_root_attributes(root, &props.base)
 }
 
 /// Internal Tile component
@@ -283,7 +374,12 @@ struct TileProps {
     pub label: String,
     pub checked: bool,
     pub disabled: bool,
+    pub read_only: bool,
+    pub required: bool,
+    pub form: Option<String>,
+    pub on_invalid: Callback<Event>,
     pub control_id: String,
+    pub tab_stop: bool,
     pub description: Option<Html>,
     pub image: Option<Html>,
     pub on_select: Callback<Event>,
@@ -301,11 +397,12 @@ fn tile(props: &TileProps) -> Html {
         let input_ref = input_ref.clone();
         let checked = props.checked;
         let disabled = props.disabled;
+        let read_only = props.read_only;
 
         Callback::from(move |e: MouseEvent| {
             e.prevent_default();
 
-            if !disabled && !checked {
+            if !disabled && !read_only && !checked {
                 // Focus the input
                 if let Some(input) = input_ref.cast::<HtmlInputElement>() {
                     let _ = input.focus();
@@ -333,6 +430,7 @@ fn tile(props: &TileProps) -> Html {
     let control_classes = ClassBuilder::new()
         .add("awsui-tiles-item-control")
         .add_if(props.disabled, "awsui-tiles-item-control-disabled")
+        .add_if(props.read_only, "awsui-tiles-item-control-readonly")
         .add_if(props.checked, "awsui-tiles-item-control-checked");
 
     let label_classes = ClassBuilder::new()
@@ -352,9 +450,14 @@ fn tile(props: &TileProps) -> Html {
                     id={props.control_id.clone()}
                     name={props.name.clone()}
                     value={props.value.clone()}
+                    form={props.form.clone()}
+                    required={props.required}
                     checked={props.checked}
                     disabled={props.disabled}
+                    aria-disabled={props.read_only.then_some("true")}
+                    tabindex={if props.tab_stop { "0" } else { "-1" }}
                     onchange={on_change}
+                    oninvalid={props.on_invalid.clone()}
                     class="awsui-tiles-item-native-input"
                 />
                 <div class="awsui-tiles-item-content">
@@ -364,7 +467,10 @@ fn tile(props: &TileProps) -> Html {
                         </div>
                     }
                     <div class="awsui-tiles-item-text">
-                        <span class={control_classes.build()}>
+                        <span
+                            class={control_classes.build()}
+                            style={props.read_only.then_some(READ_ONLY_CONTROL_STYLE)}
+                        >
                             // Radio control indicator
                             <svg viewBox="0 0 100 100" focusable="false" aria-hidden="true" class="awsui-tiles-item-control-svg">
                                 // Outer circle (border)
@@ -478,6 +584,9 @@ mod tests {
             on_change: None,
             columns: 5, // Should be clamped to 4
             name: None,
+            required: false,
+            form: None,
+            read_only: false,
             aria: Default::default(),
             aria_required: false,
         };
@@ -490,6 +599,9 @@ mod tests {
             on_change: None,
             columns: 0, // Should be clamped to 1
             name: None,
+            required: false,
+            form: None,
+            read_only: false,
             aria: Default::default(),
             aria_required: false,
         };
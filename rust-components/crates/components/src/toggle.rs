@@ -6,7 +6,12 @@
 //! A binary switch control for toggling between on/off states.
 //! Commonly used for boolean settings and preferences.
 
-use crate::internal::{BaseComponentProps, ClassBuilder, ComponentMetadata, CustomEvent};
+use crate::form_field::use_native_validity;
+use crate::form_state::use_field_tracking;
+use crate::internal::{
+    BaseComponentProps, ClassBuilder, ComponentMetadata, CustomEvent, apply_root_attributes,
+};
+use crate::spinner::{Spinner, SpinnerVariant};
 use web_sys::{FocusEvent, HtmlInputElement};
 use yew::prelude::*;
 
@@ -36,10 +41,31 @@ pub struct ToggleProps {
     #[prop_or_default]
     pub read_only: bool,
 
+    /// Whether a change is pending, e.g. while an async setting is saved
+    ///
+    /// Shows a spinner in the handle, blocks changes, and marks the
+    /// control busy (`aria-busy`). The toggle stays focusable.
+    #[prop_or_default]
+    pub loading: bool,
+
     /// HTML name attribute for form integration
     #[prop_or_default]
     pub name: Option<String>,
 
+    /// Value submitted with the form when checked
+    ///
+    /// Browsers submit "on" when not set.
+    #[prop_or_default]
+    pub value: Option<String>,
+
+    /// Whether the control must be checked before the form can be submitted
+    #[prop_or_default]
+    pub required: bool,
+
+    /// ID of the form the control belongs to, for controls rendered outside it
+    #[prop_or_default]
+    pub form: Option<String>,
+
     /// Control ID for form field integration
     #[prop_or_default]
     pub control_id: Option<String>,
@@ -135,10 +161,12 @@ pub struct ToggleProps {
 /// - Set the `control_id` prop to link with form field labels
 /// - Use `disabled` to prevent interaction and form submission
 /// - Use `read_only` to prevent changes while maintaining focus
+/// - Use `loading` while a change is being saved
 #[function_component(Toggle)]
 pub fn toggle(props: &ToggleProps) -> Html {
     let _metadata = ComponentMetadata::new("Toggle");
     let input_ref = use_node_ref();
+    let on_invalid = use_native_validity(!props.required || props.checked);
 
     // Use provided control_id or generate a simple unique ID
     let control_id = use_state(|| {
@@ -162,12 +190,13 @@ pub fn toggle(props: &ToggleProps) -> Html {
         let checked = props.checked;
         let disabled = props.disabled;
         let read_only = props.read_only;
+        let loading = props.loading;
         let input_ref = input_ref.clone();
 
         Callback::from(move |e: MouseEvent| {
             e.prevent_default();
 
-            if disabled || read_only {
+            if disabled || read_only || loading {
                 return;
             }
 
@@ -195,11 +224,15 @@ pub fn toggle(props: &ToggleProps) -> Html {
             }
         })
     };
+    // Report the value and blur to the closest FormStateProvider
+    let on_field_blur = use_field_tracking(props.checked.to_string());
 
     let on_blur_event = {
         let on_blur = props.on_blur.clone();
 
         Callback::from(move |_e: FocusEvent| {
+            on_field_blur.emit(());
+
             if let Some(callback) = &on_blur {
                 callback.emit(());
             }
@@ -257,7 +290,8 @@ pub fn toggle(props: &ToggleProps) -> Html {
         .add("awsui-toggle-control")
         .add_if(props.checked, "awsui-toggle-control-checked")
         .add_if(props.disabled, "awsui-toggle-control-disabled")
-        .add_if(props.read_only, "awsui-toggle-control-readonly");
+        .add_if(props.read_only, "awsui-toggle-control-readonly")
+        .add_if(props.loading, "awsui-toggle-control-loading");
 
     // Build CSS classes for the handle
     let handle_classes = ClassBuilder::new()
@@ -276,7 +310,7 @@ pub fn toggle(props: &ToggleProps) -> Html {
         .add("awsui-toggle-description")
         .add_if(props.disabled, "awsui-toggle-description-disabled");
 
-    html! {
+    let root = html! {
         <span class={wrapper_classes.build()}>
             <span
                 class={label_wrapper_classes.build()}
@@ -285,7 +319,19 @@ pub fn toggle(props: &ToggleProps) -> Html {
             >
                 <span class={control_classes.build()}>
                     // Styled toggle handle
-                    <span class={handle_classes.build()} />
+                    <span class={handle_classes.build()}>
+                        if props.loading {
+                            <span class="awsui-toggle-spinner">
+                                <Spinner
+                                    variant={if props.disabled {
+                                        SpinnerVariant::Disabled
+                                    } else {
+                                        SpinnerVariant::Normal
+                                    }}
+                                />
+                            </span>
+                        }
+                    </span>
 
                     // Native checkbox input (hidden, for form integration and accessibility)
                     <input
@@ -294,6 +340,9 @@ pub fn toggle(props: &ToggleProps) -> Html {
                         role="switch"
                         id={(*control_id).clone()}
                         name={props.name.clone()}
+                        value={props.value.clone()}
+                        form={props.form.clone()}
+                        required={props.required}
                         class="awsui-toggle-native-input"
                         checked={props.checked}
                         disabled={props.disabled}
@@ -302,12 +351,14 @@ pub fn toggle(props: &ToggleProps) -> Html {
                         aria-labelledby={aria_labelledby}
                         aria-describedby={aria_describedby}
                         aria-controls={props.aria_controls.clone()}
+                        aria-busy={props.loading.then_some("true")}
                         aria-disabled={if props.read_only && !props.disabled { Some("true") } else { None }}
                         autofocus={props.auto_focus}
                         onfocus={on_focus_event}
                         onblur={on_blur_event}
                         // Empty handler to suppress React controllability warning
                         onchange={Callback::from(|_| {})}
+                        oninvalid={on_invalid}
                     />
 
                     // Focus outline
@@ -334,7 +385,8 @@ pub fn toggle(props: &ToggleProps) -> Html {
                 </span>
             </span>
         </span>
-    }
+    };
+    apply_root_attributes(root, &props.base)
 }
 
 #[cfg(test)]
@@ -371,7 +423,7 @@ mod tests {
         let event = CustomEvent::new_non_cancelable(detail);
 
         assert!(!event.cancelable);
-        assert!(!event.default_prevented);
+        assert!(!event.default_prevented());
         assert!(event.detail.checked);
     }
 }
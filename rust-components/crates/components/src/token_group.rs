@@ -6,9 +6,17 @@
 //! A component for displaying a collection of tokens (tags) with optional dismiss
 //! functionality. Tokens can have icons, disabled states, and support both horizontal
 //! and vertical alignment. Includes a "show more" feature to limit visible tokens.
-
-use crate::internal::{BaseComponentProps, ClassBuilder, ComponentMetadata, CustomEvent};
-use web_sys::MouseEvent;
+//!
+//! Horizontal groups can scroll in a single row instead of wrapping, and
+//! groups can let users reorder tokens by dragging or with the keyboard.
+
+use crate::i18n::{I18nContext, use_i18n};
+use crate::internal::{
+    BaseComponentProps, ClassBuilder, ComponentMetadata, CustomEvent, LiveRegion,
+    apply_root_attributes, use_element_size,
+};
+use wasm_bindgen::JsCast;
+use web_sys::{DragEvent, Element, HtmlElement, KeyboardEvent, MouseEvent};
 use yew::prelude::*;
 
 /// A single token item
@@ -112,11 +120,142 @@ impl TokenGroupAlignment {
     }
 }
 
+/// How a horizontal token group handles tokens that do not fit in one row
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TokenGroupOverflowMode {
+    /// Tokens wrap onto further rows
+    #[default]
+    Wrap,
+    /// Tokens stay in a single row that scrolls horizontally, with faded
+    /// edges and scroll buttons while content overflows
+    Scroll,
+}
+
+impl TokenGroupOverflowMode {
+    fn as_str(&self) -> &'static str {
+        match self {
+            TokenGroupOverflowMode::Wrap => "wrap",
+            TokenGroupOverflowMode::Scroll => "scroll",
+        }
+    }
+}
+
+/// Event detail for token reorder events
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct TokenGroupReorderDetail {
+    /// Index of the moved token
+    pub from_index: usize,
+    /// Index the token moves to
+    pub to_index: usize,
+}
+
+impl TokenGroupReorderDetail {
+    /// Applies the move to a list, such as the token items
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cloudscape_components::TokenGroupReorderDetail;
+    ///
+    /// let mut columns = vec!["name", "status", "region"];
+    /// TokenGroupReorderDetail { from_index: 2, to_index: 0 }.apply(&mut columns);
+    /// assert_eq!(columns, vec!["region", "name", "status"]);
+    /// ```
+    pub fn apply<T>(&self, items: &mut Vec<T>) {
+        if self.from_index < items.len() && self.to_index < items.len() {
+            let item = items.remove(self.from_index);
+            items.insert(self.to_index, item);
+        }
+    }
+}
+
+/// Gets the index a lifted token moves to with the keyboard, skipping over
+/// disabled tokens, or `None` at either end
+pub(crate) fn next_reorder_index(
+    disabled: &[bool],
+    current: usize,
+    forward: bool,
+) -> Option<usize> {
+    if forward {
+        (current + 1..disabled.len()).find(|&index| !disabled[index])
+    } else {
+        (0..current.min(disabled.len()))
+            .rev()
+            .find(|&index| !disabled[index])
+    }
+}
+
+/// Gets the index a dragged token is dropped at, or `None` when the drop
+/// target is the token itself or a disabled token
+pub(crate) fn drop_index(disabled: &[bool], from: usize, to: usize) -> Option<usize> {
+    (from != to && !disabled.get(to).copied().unwrap_or(true)).then_some(to)
+}
+
+/// Whether a scrolling row has hidden content before and after its viewport
+pub(crate) fn scroll_overflow(
+    scroll_left: f64,
+    scroll_width: f64,
+    client_width: f64,
+) -> (bool, bool) {
+    // Allow a pixel of rounding from fractional scroll positions
+    let max_scroll = scroll_width - client_width;
+    (scroll_left > 1.0, max_scroll - scroll_left > 1.0)
+}
+
 /// Event detail for token dismiss events
 #[derive(Clone, PartialEq, Debug)]
 pub struct TokenDismissDetail {
     /// The ID of the dismissed token
     pub item_id: String,
+    /// The index of the dismissed token in `items`
+    pub item_index: usize,
+}
+
+/// Inline style of disabled tokens
+const DISABLED_TOKEN_STYLE: &str = "color: var(--awsui-color-text-disabled)";
+
+/// Internationalization strings for TokenGroup
+///
+/// Unset strings fall back to the closest I18nProvider (keys under
+/// `"token-group."`), then to English.
+#[derive(Clone, PartialEq, Default)]
+pub struct TokenGroupI18nStrings {
+    /// Text of the toggle showing the tokens hidden by `limit`, followed by
+    /// the hidden count (default: "Show more")
+    pub limit_show_more: Option<String>,
+    /// Text of the toggle hiding the tokens beyond `limit` again
+    /// (default: "Show fewer")
+    pub limit_show_fewer: Option<String>,
+}
+
+/// Strings used to render a TokenGroup after applying the fallback chain
+struct ResolvedStrings {
+    limit_show_more: String,
+    limit_show_fewer: String,
+}
+
+/// Resolves the strings: `i18n_strings` prop, then the I18nProvider, then
+/// English
+fn resolve_strings(strings: &TokenGroupI18nStrings, i18n: &I18nContext) -> ResolvedStrings {
+    let resolve = |value: &Option<String>, key: &str, default: &str| {
+        value
+            .clone()
+            .or_else(|| i18n.message("token-group", key).map(str::to_string))
+            .unwrap_or_else(|| default.to_string())
+    };
+
+    ResolvedStrings {
+        limit_show_more: resolve(&strings.limit_show_more, "limit_show_more", "Show more"),
+        limit_show_fewer: resolve(&strings.limit_show_fewer, "limit_show_fewer", "Show fewer"),
+    }
+}
+
+/// Number of tokens hidden behind the show more toggle
+fn hidden_count(item_count: usize, limit: Option<usize>, expanded: bool) -> usize {
+    match limit {
+        Some(limit) if !expanded => item_count.saturating_sub(limit),
+        _ => 0,
+    }
 }
 
 /// Properties for the TokenGroup component
@@ -138,15 +277,42 @@ pub struct TokenGroupProps {
 
     /// Maximum number of tokens to display before showing "show more"
     ///
-    /// If not specified, all tokens are displayed.
+    /// If not specified, all tokens are displayed. The toggle follows the
+    /// last token, at the end of the column for vertical alignment.
     #[prop_or_default]
-    pub limit: Option<u32>,
+    pub limit: Option<usize>,
+
+    /// How tokens that do not fit in one row are handled
+    ///
+    /// Only applies to horizontal alignment.
+    #[prop_or_default]
+    pub overflow_mode: TokenGroupOverflowMode,
+
+    /// Whether users can reorder tokens
+    ///
+    /// Tokens are dragged with the pointer, or focused and lifted with Space
+    /// or Enter, moved with the arrow keys and dropped with Space or Enter.
+    /// Escape returns a lifted token to where it started. Disabled tokens
+    /// cannot be moved and are skipped over.
+    #[prop_or_default]
+    pub reorderable: bool,
 
     /// Callback fired when a token is dismissed
     ///
     /// The event detail contains the ID of the dismissed token.
     #[prop_or_default]
     pub on_dismiss: Option<Callback<CustomEvent<TokenDismissDetail>>>,
+
+    /// Callback fired when a token is moved
+    ///
+    /// The group is controlled: apply the move to `items`, for example with
+    /// [`TokenGroupReorderDetail::apply`].
+    #[prop_or_default]
+    pub on_reorder: Option<Callback<CustomEvent<TokenGroupReorderDetail>>>,
+
+    /// Internationalization strings
+    #[prop_or_default]
+    pub i18n_strings: TokenGroupI18nStrings,
 }
 
 /// TokenGroup component for displaying dismissible tokens/tags.
@@ -213,30 +379,17 @@ pub struct TokenGroupProps {
 pub fn token_group(props: &TokenGroupProps) -> Html {
     let _metadata = ComponentMetadata::new("TokenGroup");
     let show_all = use_state(|| false);
+    let i18n = use_i18n();
+    let strings = resolve_strings(&props.i18n_strings, &i18n);
 
-    // Determine which tokens to show based on limit
+    // Determine which tokens to show based on limit; hidden tokens are
+    // always at the end, so indices of visible tokens match `items`
+    let remaining_count = hidden_count(props.items.len(), props.limit, *show_all);
     let visible_items = use_memo(
-        (props.items.clone(), props.limit, *show_all),
-        |(items, limit, show_all)| {
-            if *show_all || limit.is_none() {
-                items.clone()
-            } else {
-                let limit_val = limit.unwrap() as usize;
-                items.iter().take(limit_val).cloned().collect::<Vec<_>>()
-            }
-        },
+        (props.items.clone(), remaining_count),
+        |(items, remaining_count)| items[..items.len() - remaining_count].to_vec(),
     );
 
-    let remaining_count = if let Some(limit) = props.limit {
-        if !*show_all && props.items.len() > limit as usize {
-            props.items.len() - limit as usize
-        } else {
-            0
-        }
-    } else {
-        0
-    };
-
     // Toggle show more/less
     let on_toggle_show = {
         let show_all = show_all.clone();
@@ -250,53 +403,384 @@ pub fn token_group(props: &TokenGroupProps) -> Html {
     let on_token_dismiss = {
         let on_dismiss = props.on_dismiss.clone();
 
-        Callback::from(move |item_id: String| {
+        Callback::from(move |(item_id, item_index): (String, usize)| {
             if let Some(callback) = &on_dismiss {
                 callback.emit(CustomEvent::new_non_cancelable(TokenDismissDetail {
                     item_id,
+                    item_index,
+                }));
+            }
+        })
+    };
+
+    // Horizontal scrolling: track which edges hide content
+    let scrolling = props.alignment == TokenGroupAlignment::Horizontal
+        && props.overflow_mode == TokenGroupOverflowMode::Scroll;
+    let items_ref = use_node_ref();
+    let items_size = use_element_size(items_ref.clone());
+    let overflow = use_state_eq(|| (false, false));
+
+    let update_overflow = {
+        let items_ref = items_ref.clone();
+        let overflow = overflow.clone();
+        Callback::from(move |_: ()| {
+            if let Some(element) = items_ref.cast::<Element>() {
+                overflow.set(scroll_overflow(
+                    f64::from(element.scroll_left()),
+                    f64::from(element.scroll_width()),
+                    f64::from(element.client_width()),
+                ));
+            }
+        })
+    };
+
+    {
+        let update_overflow = update_overflow.clone();
+        use_effect_with(
+            (
+                scrolling,
+                items_size.map(|(width, _)| width as i32),
+                visible_items.len(),
+            ),
+            move |(scrolling, _, _)| {
+                if *scrolling {
+                    update_overflow.emit(());
+                }
+                || ()
+            },
+        );
+    }
+
+    let scroll_by = |forward: bool| {
+        let items_ref = items_ref.clone();
+        Callback::from(move |e: MouseEvent| {
+            e.prevent_default();
+            if let Some(element) = items_ref.cast::<Element>() {
+                let distance = f64::from(element.client_width()) * 0.8;
+                element.scroll_by_with_x_and_y(if forward { distance } else { -distance }, 0.0);
+            }
+        })
+    };
+
+    // Reordering: keyboard lift state as (starting index, current index), the
+    // index being dragged, and whether the pointer went down on a dismiss
+    // button so that it cannot start a drag
+    let lifted = use_state(|| None::<(usize, usize)>);
+    let dragging = use_state(|| None::<usize>);
+    let drop_target = use_state(|| None::<usize>);
+    let pressed_dismiss = use_mut_ref(|| false);
+    let announcement = use_state(String::new);
+    let disabled: Vec<bool> = props.items.iter().map(|token| token.disabled).collect();
+    let item_count = props.items.len();
+
+    let emit_reorder = {
+        let on_reorder = props.on_reorder.clone();
+        Callback::from(move |(from_index, to_index): (usize, usize)| {
+            if let Some(callback) = &on_reorder {
+                callback.emit(CustomEvent::new_non_cancelable(TokenGroupReorderDetail {
+                    from_index,
+                    to_index,
                 }));
             }
         })
     };
 
+    // Keep focus on a lifted token as the items are reordered around it
+    {
+        let items_ref = items_ref.clone();
+        use_effect_with(*lifted, move |lifted| {
+            if let Some((_, current)) = *lifted
+                && let Some(element) = items_ref.cast::<Element>()
+                && let Ok(Some(token)) =
+                    element.query_selector(&format!("[data-token-index=\"{}\"]", current))
+                && let Ok(token) = token.dyn_into::<HtmlElement>()
+            {
+                let _ = token.focus();
+            }
+            || ()
+        });
+    }
+
     // Build root classes
     let root_classes = ClassBuilder::new()
         .add("awsui-token-group")
         .add(format!(
             "awsui-token-group-alignment-{}",
             props.alignment.as_str()
-        ));
+        ))
+        .add_if(
+            props.alignment == TokenGroupAlignment::Horizontal,
+            format!(
+                "awsui-token-group-overflow-{}",
+                props.overflow_mode.as_str()
+            ),
+        )
+        .add_if(props.reorderable, "awsui-token-group-reorderable");
 
     let class = props.base.merge_classes(&root_classes.build());
 
-    html! {
+    let items_classes = ClassBuilder::new()
+        .add("awsui-token-group-items")
+        .add_if(scrolling && overflow.0, "awsui-token-group-fade-start")
+        .add_if(scrolling && overflow.1, "awsui-token-group-fade-end");
+
+    // Fade the edges that hide content
+    let items_style = scrolling.then(|| {
+        let start = if overflow.0 { "transparent" } else { "black" };
+        let end = if overflow.1 { "transparent" } else { "black" };
+        format!(
+            "display: flex; flex-wrap: nowrap; overflow-x: auto; scrollbar-width: none; \
+             mask-image: linear-gradient(to right, {start}, black 24px, black calc(100% - 24px), {end})"
+        )
+    });
+
+    // Show more/fewer toggle, shown while the limit hides tokens or after
+    // they were expanded
+    let vertical = props.alignment == TokenGroupAlignment::Vertical;
+    let has_toggle = props.limit.is_some_and(|limit| props.items.len() > limit);
+    let toggle = if has_toggle {
+        html! {
+            <button
+                type="button"
+                class="awsui-token-group-toggle awsui-link"
+                onclick={on_toggle_show}
+                aria-expanded={show_all.to_string()}
+            >
+                if *show_all {
+                    { strings.limit_show_fewer.clone() }
+                } else {
+                    { format!("{} (+{})", strings.limit_show_more, remaining_count) }
+                }
+            </button>
+        }
+    } else {
+        Html::default()
+    };
+
+    let root = html! {
         <div
             id={props.base.id.clone()}
             class={class}
         >
-            <div class="awsui-token-group-items">
+            if scrolling && overflow.0 {
+                <button
+                    type="button"
+                    class="awsui-token-group-scroll-button awsui-token-group-scroll-left"
+                    aria-label="Scroll left"
+                    tabindex="-1"
+                    onclick={scroll_by(false)}
+                >
+                    { "‹" }
+                </button>
+            }
+            <div
+                ref={items_ref.clone()}
+                class={items_classes.build()}
+                style={items_style}
+                onscroll={update_overflow.reform(|_: Event| ())}
+            >
                 {
-                    visible_items.iter().map(|token| {
+                    visible_items.iter().enumerate().map(|(index, token)| {
                         let token_id = token.id.clone();
+                        let draggable = props.reorderable && !token.disabled;
+                        let is_lifted = lifted.is_some_and(|(_, current)| current == index);
+                        let dismissible = token.dismissible && !token.disabled;
                         let token_classes = ClassBuilder::new()
                             .add("awsui-token")
-                            .add_if(token.dismissible, "awsui-token-dismissible")
-                            .add_if(token.disabled, "awsui-token-disabled");
+                            .add_if(dismissible, "awsui-token-dismissible")
+                            .add_if(token.disabled, "awsui-token-disabled")
+                            .add_if(draggable, "awsui-token-draggable")
+                            .add_if(is_lifted || *dragging == Some(index), "awsui-token-lifted")
+                            .add_if(
+                                *drop_target == Some(index) && *dragging != Some(index),
+                                "awsui-token-drop-target",
+                            );
 
                         let on_dismiss = {
                             let on_token_dismiss = on_token_dismiss.clone();
-                            let token_disabled = token.disabled;
                             Callback::from(move |e: MouseEvent| {
-                                if !token_disabled {
+                                e.prevent_default();
+                                e.stop_propagation();
+                                on_token_dismiss.emit((token_id.clone(), index));
+                            })
+                        };
+
+                        let on_mouse_down = {
+                            let pressed_dismiss = pressed_dismiss.clone();
+                            Callback::from(move |e: MouseEvent| {
+                                *pressed_dismiss.borrow_mut() = e
+                                    .target()
+                                    .and_then(|target| target.dyn_into::<Element>().ok())
+                                    .and_then(|target| {
+                                        target.closest(".awsui-token-dismiss-button").ok().flatten()
+                                    })
+                                    .is_some();
+                            })
+                        };
+
+                        let on_drag_start = {
+                            let pressed_dismiss = pressed_dismiss.clone();
+                            let dragging = dragging.clone();
+                            let token_id = token.id.clone();
+                            Callback::from(move |e: DragEvent| {
+                                if !draggable || *pressed_dismiss.borrow() {
+                                    e.prevent_default();
+                                    return;
+                                }
+                                if let Some(data_transfer) = e.data_transfer() {
+                                    data_transfer.set_effect_allowed("move");
+                                    let _ = data_transfer.set_data("text/plain", &token_id);
+                                }
+                                dragging.set(Some(index));
+                            })
+                        };
+
+                        let on_drag_over = {
+                            let dragging = dragging.clone();
+                            let drop_target = drop_target.clone();
+                            let disabled = disabled.clone();
+                            Callback::from(move |e: DragEvent| {
+                                if let Some(from) = *dragging
+                                    && drop_index(&disabled, from, index).is_some()
+                                {
                                     e.prevent_default();
-                                    e.stop_propagation();
-                                    on_token_dismiss.emit(token_id.clone());
+                                    drop_target.set(Some(index));
+                                }
+                            })
+                        };
+
+                        let on_drop = {
+                            let dragging = dragging.clone();
+                            let drop_target = drop_target.clone();
+                            let disabled = disabled.clone();
+                            let emit_reorder = emit_reorder.clone();
+                            Callback::from(move |e: DragEvent| {
+                                e.prevent_default();
+                                if let Some(from) = *dragging
+                                    && let Some(to) = drop_index(&disabled, from, index)
+                                {
+                                    emit_reorder.emit((from, to));
+                                }
+                                dragging.set(None);
+                                drop_target.set(None);
+                            })
+                        };
+
+                        let on_drag_end = {
+                            let dragging = dragging.clone();
+                            let drop_target = drop_target.clone();
+                            Callback::from(move |_: DragEvent| {
+                                dragging.set(None);
+                                drop_target.set(None);
+                            })
+                        };
+
+                        let on_key_down = {
+                            let lifted = lifted.clone();
+                            let announcement = announcement.clone();
+                            let disabled = disabled.clone();
+                            let emit_reorder = emit_reorder.clone();
+                            let label = token.label.clone();
+                            Callback::from(move |e: KeyboardEvent| {
+                                if !draggable && lifted.is_none() {
+                                    return;
+                                }
+                                let on_dismiss_button = e
+                                    .target()
+                                    .and_then(|target| target.dyn_into::<Element>().ok())
+                                    .is_some_and(|target| target.tag_name() == "BUTTON");
+                                if on_dismiss_button {
+                                    return;
+                                }
+                                let position = |index: usize| {
+                                    format!("position {} of {}", index + 1, item_count)
+                                };
+                                match (e.key().as_str(), *lifted) {
+                                    (" " | "Enter", None) => {
+                                        e.prevent_default();
+                                        lifted.set(Some((index, index)));
+                                        announcement.set(format!(
+                                            "Picked up {}, {}",
+                                            label,
+                                            position(index)
+                                        ));
+                                    }
+                                    (" " | "Enter", Some(_)) => {
+                                        e.prevent_default();
+                                        lifted.set(None);
+                                        announcement.set(format!(
+                                            "Dropped {} at {}",
+                                            label,
+                                            position(index)
+                                        ));
+                                    }
+                                    ("Escape", Some((start, current))) => {
+                                        e.prevent_default();
+                                        if start != current {
+                                            emit_reorder.emit((current, start));
+                                        }
+                                        lifted.set(None);
+                                        announcement.set(format!(
+                                            "Reordering canceled, {} returned to {}",
+                                            label,
+                                            position(start)
+                                        ));
+                                    }
+                                    (key, Some((start, current))) => {
+                                        let forward = match key {
+                                            "ArrowRight" if !vertical => true,
+                                            "ArrowDown" if vertical => true,
+                                            "ArrowLeft" if !vertical => false,
+                                            "ArrowUp" if vertical => false,
+                                            _ => return,
+                                        };
+                                        e.prevent_default();
+                                        if let Some(next) =
+                                            next_reorder_index(&disabled, current, forward)
+                                        {
+                                            emit_reorder.emit((current, next));
+                                            lifted.set(Some((start, next)));
+                                            announcement.set(format!(
+                                                "Moved {} to {}",
+                                                label,
+                                                position(next)
+                                            ));
+                                        }
+                                    }
+                                    _ => {}
                                 }
                             })
                         };
 
                         html! {
-                            <div key={token.id.clone()} class={token_classes.build()}>
+                            <div
+                                key={token.id.clone()}
+                                class={token_classes.build()}
+                                style={token.disabled.then_some(DISABLED_TOKEN_STYLE)}
+                                aria-disabled={token.disabled.then_some("true")}
+                                data-token-index={index.to_string()}
+                                draggable={draggable.then_some("true")}
+                                tabindex={draggable.then_some("0")}
+                                aria-roledescription={draggable.then_some("reorderable token")}
+                                onmousedown={on_mouse_down}
+                                ondragstart={on_drag_start}
+                                ondragover={on_drag_over}
+                                ondrop={on_drop}
+                                ondragend={on_drag_end}
+                                onkeydown={on_key_down}
+                                onblur={
+                                    let lifted = lifted.clone();
+                                    // Moving the token in the DOM also blurs it, without
+                                    // a related target; only focusing elsewhere drops it
+                                    Callback::from(move |e: FocusEvent| {
+                                        if e.related_target().is_some()
+                                            && lifted.is_some_and(|(_, current)| current == index)
+                                        {
+                                            lifted.set(None);
+                                        }
+                                    })
+                                }
+                            >
                                 if let Some(ref icon) = token.icon {
                                     <span class="awsui-token-icon">
                                         { icon.clone() }
@@ -305,13 +789,13 @@ pub fn token_group(props: &TokenGroupProps) -> Html {
                                 <span class="awsui-token-label">
                                     { &token.label }
                                 </span>
-                                if token.dismissible {
+                                if dismissible {
                                     <button
                                         type="button"
                                         class="awsui-token-dismiss-button"
                                         aria-label={format!("Remove {}", token.label)}
                                         onclick={on_dismiss}
-                                        disabled={token.disabled}
+                                        draggable="false"
                                     >
                                         { "×" }
                                     </button>
@@ -321,24 +805,31 @@ pub fn token_group(props: &TokenGroupProps) -> Html {
                     }).collect::<Html>()
                 }
 
-                // Show more/less button
-                if remaining_count > 0 || *show_all && props.limit.is_some() {
-                    <button
-                        type="button"
-                        class="awsui-token-group-toggle"
-                        onclick={on_toggle_show}
-                        aria-expanded={show_all.to_string()}
-                    >
-                        if *show_all {
-                            { "Show less" }
-                        } else {
-                            { format!("Show {} more", remaining_count) }
-                        }
-                    </button>
+                if !vertical {
+                    { toggle.clone() }
                 }
             </div>
+            // Vertical groups show the toggle below the column of tokens
+            if vertical {
+                { toggle }
+            }
+            if scrolling && overflow.1 {
+                <button
+                    type="button"
+                    class="awsui-token-group-scroll-button awsui-token-group-scroll-right"
+                    aria-label="Scroll right"
+                    tabindex="-1"
+                    onclick={scroll_by(true)}
+                >
+                    { "›" }
+                </button>
+            }
+            if props.reorderable {
+                <LiveRegion message={(*announcement).clone()} delay_ms={0} assertive={true} />
+            }
         </div>
-    }
+    };
+    apply_root_attributes(root, &props.base)
 }
 
 #[cfg(test)]
@@ -428,19 +919,98 @@ mod tests {
     fn test_token_dismiss_detail() {
         let detail = TokenDismissDetail {
             item_id: "test-id".to_string(),
+            item_index: 4,
         };
 
         assert_eq!(detail.item_id, "test-id");
+        assert_eq!(detail.item_index, 4);
     }
 
     #[test]
     fn test_token_dismiss_detail_clone() {
         let detail1 = TokenDismissDetail {
             item_id: "test-id".to_string(),
+            item_index: 0,
         };
         let detail2 = detail1.clone();
 
         assert_eq!(detail1, detail2);
         assert_eq!(detail1.item_id, detail2.item_id);
     }
+
+    #[test]
+    fn test_hidden_count() {
+        assert_eq!(hidden_count(40, Some(5), false), 35);
+        assert_eq!(hidden_count(40, Some(5), true), 0);
+        assert_eq!(hidden_count(3, Some(5), false), 0);
+        assert_eq!(hidden_count(40, None, false), 0);
+    }
+
+    #[test]
+    fn test_resolve_strings_fallback() {
+        let mut messages = crate::internal::I18nStrings::new();
+        messages.set("token-group.limit_show_more", "Mehr anzeigen");
+        let i18n = I18nContext {
+            locale: Some("de-DE".to_string()),
+            messages,
+        };
+
+        let resolved = resolve_strings(&TokenGroupI18nStrings::default(), &i18n);
+        assert_eq!(resolved.limit_show_more, "Mehr anzeigen");
+        assert_eq!(resolved.limit_show_fewer, "Show fewer");
+
+        let strings = TokenGroupI18nStrings {
+            limit_show_more: Some("Afficher plus".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            resolve_strings(&strings, &i18n).limit_show_more,
+            "Afficher plus"
+        );
+    }
+
+    #[test]
+    fn test_next_reorder_index_skips_disabled() {
+        let disabled = [false, true, false, false, true];
+        assert_eq!(next_reorder_index(&disabled, 0, true), Some(2));
+        assert_eq!(next_reorder_index(&disabled, 2, true), Some(3));
+        assert_eq!(next_reorder_index(&disabled, 3, true), None);
+        assert_eq!(next_reorder_index(&disabled, 2, false), Some(0));
+        assert_eq!(next_reorder_index(&disabled, 0, false), None);
+    }
+
+    #[test]
+    fn test_drop_index() {
+        let disabled = [false, true, false];
+        assert_eq!(drop_index(&disabled, 0, 2), Some(2));
+        assert_eq!(drop_index(&disabled, 0, 1), None);
+        assert_eq!(drop_index(&disabled, 2, 2), None);
+        assert_eq!(drop_index(&disabled, 0, 5), None);
+    }
+
+    #[test]
+    fn test_reorder_detail_apply() {
+        let mut items = vec!["a", "b", "c", "d"];
+        TokenGroupReorderDetail {
+            from_index: 0,
+            to_index: 2,
+        }
+        .apply(&mut items);
+        assert_eq!(items, vec!["b", "c", "a", "d"]);
+
+        TokenGroupReorderDetail {
+            from_index: 3,
+            to_index: 9,
+        }
+        .apply(&mut items);
+        assert_eq!(items, vec!["b", "c", "a", "d"]);
+    }
+
+    #[test]
+    fn test_scroll_overflow() {
+        assert_eq!(scroll_overflow(0.0, 300.0, 300.0), (false, false));
+        assert_eq!(scroll_overflow(0.0, 600.0, 300.0), (false, true));
+        assert_eq!(scroll_overflow(150.0, 600.0, 300.0), (true, true));
+        assert_eq!(scroll_overflow(299.5, 600.0, 300.0), (true, false));
+    }
 }
@@ -6,11 +6,14 @@
 //! A top application bar with menu items, identity section, and utility items.
 //! This component provides the main navigation header for applications.
 
+use crate::internal::dropdown::use_dropdown_transition;
+use crate::internal::menu::{focus_menu_entry, next_menu_entry, toggle_expanded, use_menu_dismiss};
 use crate::internal::{
-    AnalyticsMetadata, AriaAttributes, BaseComponentProps, ClassBuilder, ComponentMetadata,
-    CustomEvent,
+    AnalyticsMetadata, AriaAttributes, BaseComponentProps, Breakpoint, ClassBuilder, ClickEvent,
+    ComponentMetadata, CustomEvent, TruncatedText, apply_root_attributes, use_element_size,
 };
-use web_sys::MouseEvent;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, MouseEvent};
 use yew::prelude::*;
 
 /// Type of utility item in the top navigation
@@ -84,6 +87,8 @@ pub struct TopNavigationIdentity {
     pub title: Option<String>,
     /// Logo configuration
     pub logo: Option<TopNavigationLogo>,
+    /// Maximum title width as a CSS length, overriding the component default
+    pub max_width: Option<String>,
     /// Callback fired when identity is clicked without modifier keys
     pub on_follow: Option<Callback<CustomEvent<IdentityFollowDetail>>>,
 }
@@ -95,6 +100,7 @@ impl TopNavigationIdentity {
             href: href.into(),
             title: None,
             logo: None,
+            max_width: None,
             on_follow: None,
         }
     }
@@ -111,6 +117,12 @@ impl TopNavigationIdentity {
         self
     }
 
+    /// Sets the maximum title width
+    pub fn with_max_width(mut self, max_width: impl Into<String>) -> Self {
+        self.max_width = Some(max_width.into());
+        self
+    }
+
     /// Sets the on_follow callback
     pub fn with_on_follow(mut self, callback: Callback<CustomEvent<IdentityFollowDetail>>) -> Self {
         self.on_follow = Some(callback);
@@ -176,8 +188,15 @@ pub struct TopNavigationUtility {
     // Menu dropdown-specific properties
     /// Description text (for menu-dropdown type)
     pub description: Option<String>,
-    /// Menu items (for menu-dropdown type) - simplified for now
-    pub items: Vec<String>,
+    /// Menu items (for menu-dropdown type)
+    pub items: Vec<MenuDropdownItem>,
+    /// Menu item click callback (for menu-dropdown type), with the item id
+    pub on_item_click: Option<Callback<CustomEvent<UtilityClickDetail>>>,
+    /// Menu item follow callback (for menu-dropdown type items with href),
+    /// with the item id
+    pub on_item_follow: Option<Callback<CustomEvent<UtilityFollowDetail>>>,
+    /// Maximum text width as a CSS length, overriding the component default
+    pub max_width: Option<String>,
     /// Whether groups are expandable (for menu-dropdown type)
     pub expandable_groups: bool,
 }
@@ -209,7 +228,10 @@ impl TopNavigationUtility {
             on_follow: None,
             description: None,
             items: Vec::new(),
+            on_item_click: None,
+            on_item_follow: None,
             expandable_groups: false,
+            max_width: None,
         }
     }
 
@@ -239,10 +261,19 @@ impl TopNavigationUtility {
             on_follow: None,
             description: None,
             items: Vec::new(),
+            on_item_click: None,
+            on_item_follow: None,
             expandable_groups: false,
+            max_width: None,
         }
     }
 
+    /// Sets the maximum text width
+    pub fn with_max_width(mut self, max_width: impl Into<String>) -> Self {
+        self.max_width = Some(max_width.into());
+        self
+    }
+
     /// Sets the display text
     pub fn with_text(mut self, text: impl Into<String>) -> Self {
         self.text = Some(text.into());
@@ -364,16 +395,151 @@ impl TopNavigationUtility {
     }
 
     /// Sets the menu items (for menu-dropdown type)
-    pub fn with_items(mut self, items: Vec<String>) -> Self {
+    pub fn with_items(mut self, items: Vec<MenuDropdownItem>) -> Self {
         self.items = items;
         self
     }
 
+    /// Sets the menu item click callback (for menu-dropdown type)
+    pub fn with_on_item_click(
+        mut self,
+        callback: Callback<CustomEvent<UtilityClickDetail>>,
+    ) -> Self {
+        self.on_item_click = Some(callback);
+        self
+    }
+
+    /// Sets the menu item follow callback (for menu-dropdown type)
+    pub fn with_on_item_follow(
+        mut self,
+        callback: Callback<CustomEvent<UtilityFollowDetail>>,
+    ) -> Self {
+        self.on_item_follow = Some(callback);
+        self
+    }
+
     /// Sets whether groups are expandable (for menu-dropdown type)
     pub fn with_expandable_groups(mut self, expandable: bool) -> Self {
         self.expandable_groups = expandable;
         self
     }
+
+    fn has_icon(&self) -> bool {
+        self.icon_name.is_some() || self.icon_svg.is_some() || self.icon_url.is_some()
+    }
+
+    /// Whether the text can collapse to the icon on narrow viewports
+    fn text_collapsible(&self) -> bool {
+        self.text.is_some() && self.has_icon() && !self.disable_text_collapse
+    }
+}
+
+/// An item of a menu-dropdown utility
+///
+/// An item with nested items is a group: its own text labels the group, and
+/// only its nested items can be selected.
+#[derive(Clone, PartialEq, Debug)]
+pub struct MenuDropdownItem {
+    /// Unique identifier, reported in click and follow events
+    pub id: String,
+    /// Display text
+    pub text: String,
+    /// Link href, rendering the item as a link
+    pub href: Option<String>,
+    /// Whether the link opens in a new tab
+    pub external: bool,
+    /// Icon name (Cloudscape icon)
+    pub icon_name: Option<String>,
+    /// Whether the item, or the whole group, is disabled
+    pub disabled: bool,
+    /// Nested items, making the item a group
+    pub items: Vec<MenuDropdownItem>,
+}
+
+impl MenuDropdownItem {
+    /// Creates a new menu item
+    pub fn new(id: impl Into<String>, text: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            text: text.into(),
+            href: None,
+            external: false,
+            icon_name: None,
+            disabled: false,
+            items: Vec::new(),
+        }
+    }
+
+    /// Sets the link href
+    pub fn with_href(mut self, href: impl Into<String>) -> Self {
+        self.href = Some(href.into());
+        self
+    }
+
+    /// Sets whether the link is external
+    pub fn with_external(mut self, external: bool) -> Self {
+        self.external = external;
+        self
+    }
+
+    /// Sets the icon name
+    pub fn with_icon_name(mut self, icon_name: impl Into<String>) -> Self {
+        self.icon_name = Some(icon_name.into());
+        self
+    }
+
+    /// Sets whether the item is disabled
+    pub fn with_disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Sets the nested items, making the item a group
+    pub fn with_items(mut self, items: Vec<MenuDropdownItem>) -> Self {
+        self.items = items;
+        self
+    }
+
+    /// Whether the item is a group of nested items
+    pub fn is_group(&self) -> bool {
+        !self.items.is_empty()
+    }
+
+    fn link_target(&self) -> Option<String> {
+        self.external.then(|| "_blank".to_string())
+    }
+}
+
+/// Gets the ids of the focusable entries of a menu in order
+///
+/// Enabled items are focusable, and so are the headers of expandable groups;
+/// the items of collapsed or disabled groups are not shown.
+fn menu_dropdown_entries(
+    items: &[MenuDropdownItem],
+    expandable_groups: bool,
+    expanded_groups: &[String],
+) -> Vec<String> {
+    let mut entries = Vec::new();
+    for item in items {
+        if item.disabled {
+            continue;
+        }
+        if !item.is_group() {
+            entries.push(item.id.clone());
+            continue;
+        }
+        if expandable_groups {
+            entries.push(item.id.clone());
+        }
+        if !expandable_groups || expanded_groups.contains(&item.id) {
+            entries.extend(menu_dropdown_entries(
+                &item.items,
+                expandable_groups,
+                expanded_groups,
+            ));
+        }
+    }
+    entries
 }
 
 /// Event detail for utility click events
@@ -400,6 +566,22 @@ pub struct UtilityFollowDetail {
     pub target: Option<String>,
 }
 
+/// Event detail for search toggle events
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchToggleDetail {
+    /// Whether the search slot is now shown
+    pub open: bool,
+}
+
+/// Header width below which the search collapses to an icon button
+const SEARCH_COLLAPSE_BREAKPOINT: Breakpoint = Breakpoint::Xs;
+
+/// Whether the search collapses to an icon button in a header of the given
+/// width, which keeps it inline until the header was measured
+fn search_collapsed(header_width: Option<f64>) -> bool {
+    header_width.is_some_and(|width| width < SEARCH_COLLAPSE_BREAKPOINT.min_width())
+}
+
 /// I18n strings for the TopNavigation component
 #[derive(Clone, PartialEq, Default)]
 pub struct TopNavigationI18nStrings {
@@ -482,6 +664,23 @@ pub struct TopNavigationProps {
     #[prop_or_default]
     pub i18n_strings: TopNavigationI18nStrings,
 
+    /// Default maximum width for the identity title and utility text, as a
+    /// CSS length
+    ///
+    /// Longer text is truncated with an ellipsis and shown in full in a
+    /// tooltip on hover or focus. The identity and each utility can override
+    /// it with `max_width`.
+    #[prop_or_default]
+    pub text_max_width: Option<String>,
+
+    /// Called when the search slot shows or hides
+    ///
+    /// On narrow viewports the search collapses to an icon button and the
+    /// slot only renders while the user has it expanded; applications can use
+    /// this to mount expensive search content lazily.
+    #[prop_or_default]
+    pub on_search_toggle: Option<Callback<CustomEvent<SearchToggleDetail>>>,
+
     /// ARIA attributes
     #[prop_or_default]
     pub aria: AriaAttributes,
@@ -491,12 +690,16 @@ pub struct TopNavigationProps {
 ///
 /// A top application bar that provides branding, search, and utility navigation.
 ///
+/// When the utilities don't fit, their text collapses to their icons, then
+/// utilities move into an overflow menu starting from the left. Utilities
+/// opt out with `disable_text_collapse` and `disable_utility_collapse`.
+///
 /// # Example
 ///
 /// ```rust
 /// use cloudscape_components::{
 ///     TopNavigation, TopNavigationIdentity, TopNavigationUtility,
-///     TopNavigationLogo, UtilityClickDetail, CustomEvent
+///     TopNavigationLogo, UtilityClickDetail, CustomEvent, MenuDropdownItem
 /// };
 /// use yew::prelude::*;
 ///
@@ -518,7 +721,10 @@ pub struct TopNavigationProps {
 ///         TopNavigationUtility::menu_dropdown("user")
 ///             .with_text("John Doe")
 ///             .with_icon_name("user-profile")
-///             .with_items(vec!["Profile".to_string(), "Sign out".to_string()]),
+///             .with_items(vec![
+///                 MenuDropdownItem::new("profile", "Profile"),
+///                 MenuDropdownItem::new("signout", "Sign out"),
+///             ]),
 ///     ];
 ///
 ///     html! {
@@ -557,10 +763,84 @@ pub struct TopNavigationProps {
 pub fn top_navigation(props: &TopNavigationProps) -> Html {
     let _metadata = ComponentMetadata::new("TopNavigation");
 
+    // On narrow headers the search collapses to an icon button, which
+    // switches the header into search mode
+    let header_ref = use_node_ref();
+    let search_ref = use_node_ref();
+    let search_trigger_ref = use_node_ref();
+    let header_width = use_element_size(header_ref.clone()).map(|(width, _)| width);
+    let search_open = use_state(|| false);
+    let collapse_search = props.search.is_some() && search_collapsed(header_width);
+    let search_mode = collapse_search && *search_open;
+    let search_visible = props.search.is_some() && (!collapse_search || *search_open);
+
+    // Report when the search slot shows or hides, including when the header
+    // crosses the breakpoint
+    {
+        let on_search_toggle = props.on_search_toggle.clone();
+        let previous = use_mut_ref(|| search_visible);
+        use_effect_with(search_visible, move |visible| {
+            if *previous.borrow() != *visible {
+                *previous.borrow_mut() = *visible;
+                if let Some(ref callback) = on_search_toggle {
+                    callback.emit(CustomEvent::new_non_cancelable(SearchToggleDetail {
+                        open: *visible,
+                    }));
+                }
+            }
+        });
+    }
+
+    // Move focus into the search when it expands, and back to the search
+    // button when it collapses
+    {
+        let search_ref = search_ref.clone();
+        let search_trigger_ref = search_trigger_ref.clone();
+        let entered = use_mut_ref(|| false);
+        use_effect_with(search_mode, move |search_mode| {
+            if *search_mode {
+                *entered.borrow_mut() = true;
+                if let Some(search) = search_ref.cast::<Element>()
+                    && let Ok(Some(control)) =
+                        search.query_selector("input, textarea, select, [tabindex]")
+                    && let Ok(control) = control.dyn_into::<web_sys::HtmlElement>()
+                {
+                    let _ = control.focus();
+                }
+            } else if entered.replace(false)
+                && let Some(trigger) = search_trigger_ref.cast::<web_sys::HtmlElement>()
+            {
+                let _ = trigger.focus();
+            }
+        });
+    }
+
+    let on_search_open = {
+        let search_open = search_open.clone();
+        Callback::from(move |_: MouseEvent| search_open.set(true))
+    };
+    let on_search_close = {
+        let search_open = search_open.clone();
+        Callback::from(move |_: ()| search_open.set(false))
+    };
+    let on_search_key_down = {
+        let on_search_close = on_search_close.clone();
+        Callback::from(move |e: KeyboardEvent| {
+            // Controls in the slot handle Escape first, e.g. to close their
+            // dropdown
+            if search_mode && e.key() == "Escape" && !e.default_prevented() {
+                e.prevent_default();
+                on_search_close.emit(());
+            }
+        })
+    };
+
     // Build component styles
     let classes = ClassBuilder::new()
         .add("awsui-top-navigation")
-        .add_if(props.search.is_some(), "awsui-top-navigation-has-search");
+        .add_if(props.search.is_some(), "awsui-top-navigation-has-search")
+        .add_if(collapse_search, "awsui-top-navigation-search-collapsed")
+        .add_if(search_mode, "awsui-top-navigation-search-mode");
 
     let class = props.base.merge_classes(&classes.build());
 
@@ -580,25 +860,114 @@ pub fn top_navigation(props: &TopNavigationProps) -> Html {
     };
     let analytics_attr = analytics.to_data_attribute();
 
+    let text_max_width = props.text_max_width.as_deref();
+    let overflow_trigger_text = props
+        .i18n_strings
+        .overflow_menu_trigger_text
+        .clone()
+        .unwrap_or_else(|| "More".to_string());
+
+    // Measure the space the utilities have and the width they need
+    let utilities_ref = use_node_ref();
+    let measure_ref = use_node_ref();
+    let available_width = use_element_size(utilities_ref.clone()).map(|(width, _)| width);
+    let measurements = use_state_eq(|| None::<UtilityMeasurements>);
+    {
+        let measure_ref = measure_ref.clone();
+        let measurements = measurements.clone();
+        use_effect_with(
+            (
+                available_width.map(|width| width as i32),
+                props.utilities.clone(),
+                props.text_max_width.clone(),
+                overflow_trigger_text.clone(),
+            ),
+            move |(_, utilities, _, _)| {
+                if let Some(measure) = measure_ref.cast::<Element>() {
+                    measurements.set(measure_utilities(&measure, utilities));
+                }
+            },
+        );
+    }
+    let layout = match (available_width, &*measurements) {
+        (Some(available), Some(measured)) if measured.utilities.len() == props.utilities.len() => {
+            utilities_layout(available, &measured.utilities, measured.overflow_trigger)
+        }
+        _ => UtilitiesLayout::default(),
+    };
+
     // Render identity
-    let identity_html = render_identity(&props.identity);
+    let identity_html = render_identity(&props.identity, text_max_width);
+
+    // Render search, inline or collapsed to an icon button
+    let i18n = &props.i18n_strings;
+    let search_html = match props.search {
+        Some(ref search) if search_visible => html! {
+            <div
+                ref={search_ref}
+                class="awsui-top-navigation-search"
+                role="search"
+                onkeydown={on_search_key_down}
+            >
+                { search.clone() }
+            </div>
+        },
+        Some(_) => html! {
+            <div class="awsui-top-navigation-search-trigger-wrapper">
+                <button
+                    ref={search_trigger_ref}
+                    type="button"
+                    class="awsui-top-navigation-search-trigger"
+                    aria-label={
+                        i18n.search_icon_aria_label.clone().unwrap_or_else(|| "Search".to_string())
+                    }
+                    aria-expanded="false"
+                    onclick={on_search_open}
+                >
+                    <span class="awsui-icon awsui-icon-search" aria-hidden="true">{"⌕"}</span>
+                </button>
+            </div>
+        },
+        None => html! {},
+    };
+
+    // Render utilities, collapsing text and moving utilities into the
+    // overflow menu when they don't fit
+    let utilities_html = if props.utilities.is_empty() {
+        html! {}
+    } else {
+        let collapse_text = layout.collapse_text;
+        let visible = props
+            .utilities
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| !layout.overflow.contains(index))
+            .map(|(_, utility)| render_utility(utility, text_max_width, collapse_text))
+            .collect::<Html>();
+        let overflow: Vec<TopNavigationUtility> = layout
+            .overflow
+            .iter()
+            .filter_map(|index| props.utilities.get(*index).cloned())
+            .collect();
 
-    // Render search
-    let search_html = if let Some(ref search) = props.search {
         html! {
-            <div class="awsui-top-navigation-search" role="search">
-                { search.clone() }
+            <div ref={utilities_ref} class="awsui-top-navigation-utilities">
+                <ul class="awsui-top-navigation-utilities-list" role="list">
+                    { visible }
+                    if !overflow.is_empty() {
+                        <OverflowMenu utilities={overflow} i18n_strings={props.i18n_strings.clone()} />
+                    }
+                </ul>
+                <div ref={measure_ref}>
+                    { render_utilities_measure(&props.utilities, text_max_width, &overflow_trigger_text) }
+                </div>
             </div>
         }
-    } else {
-        html! {}
     };
 
-    // Render utilities
-    let utilities_html = render_utilities(&props.utilities);
-
-    html! {
+    let root = html! {
         <header
+            ref={header_ref}
             id={props.base.id.clone()}
             class={class}
             role="banner"
@@ -606,16 +975,38 @@ pub fn top_navigation(props: &TopNavigationProps) -> Html {
             data-analytics-metadata={analytics_attr}
         >
             <div class="awsui-top-navigation-inner">
-                { identity_html }
-                { search_html }
-                { utilities_html }
+                if search_mode {
+                    { search_html }
+                    <button
+                        type="button"
+                        class="awsui-top-navigation-search-dismiss"
+                        aria-label={
+                            i18n.search_dismiss_icon_aria_label
+                                .clone()
+                                .unwrap_or_else(|| "Close search".to_string())
+                        }
+                        onclick={on_search_close.reform(|_: MouseEvent| ())}
+                    >
+                        {"✕"}
+                    </button>
+                } else {
+                    { identity_html }
+                    { search_html }
+                    { utilities_html }
+                }
             </div>
         </header>
-    }
+    };
+    apply_root_attributes(root, &props.base)
 }
 
 /// Renders the identity section
-fn render_identity(identity: &TopNavigationIdentity) -> Html {
+fn render_identity(identity: &TopNavigationIdentity, default_max_width: Option<&str>) -> Html {
+    let max_width = identity
+        .max_width
+        .clone()
+        .or_else(|| default_max_width.map(str::to_string));
+
     let identity_clone = identity.clone();
 
     let on_click = Callback::from(move |e: MouseEvent| {
@@ -630,10 +1021,7 @@ fn render_identity(identity: &TopNavigationIdentity) -> Html {
             let detail = IdentityFollowDetail {
                 href: identity_clone.href.clone(),
             };
-            let event = CustomEvent::new(detail);
-            callback.emit(event.clone());
-
-            if event.default_prevented {
+            if CustomEvent::new(detail).emit_to(callback) {
                 e.prevent_default();
             }
         }
@@ -657,7 +1045,7 @@ fn render_identity(identity: &TopNavigationIdentity) -> Html {
                 }
                 if let Some(ref title) = identity.title {
                     <span class="awsui-top-navigation-title">
-                        { title }
+                        <TruncatedText text={title.clone()} max_width={max_width} />
                     </span>
                 }
             </a>
@@ -665,25 +1053,197 @@ fn render_identity(identity: &TopNavigationIdentity) -> Html {
     }
 }
 
-/// Renders the utilities section
-fn render_utilities(utilities: &[TopNavigationUtility]) -> Html {
-    if utilities.is_empty() {
-        return html! {};
+/// Measured widths of a utility, in pixels
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct UtilityMeasure {
+    /// Width with its text
+    full: f64,
+    /// Width with its text collapsed, the full width if the text can't
+    /// collapse
+    collapsed: f64,
+    /// Whether the utility can move into the overflow menu
+    can_overflow: bool,
+}
+
+/// Measured widths of the utilities and the overflow menu trigger
+#[derive(Debug, Clone, PartialEq)]
+struct UtilityMeasurements {
+    utilities: Vec<UtilityMeasure>,
+    overflow_trigger: f64,
+}
+
+/// How the utilities fit the available width
+#[derive(Debug, Clone, PartialEq, Default)]
+struct UtilitiesLayout {
+    /// Whether utility text collapses to the icon
+    collapse_text: bool,
+    /// Indices of the utilities moved into the overflow menu, in order
+    overflow: Vec<usize>,
+}
+
+/// Fits the utilities into the available width
+///
+/// Utilities keep their text while everything fits. Otherwise text collapses
+/// first, then utilities move into the overflow menu from the left, so the
+/// rightmost utilities collapse last. Utilities with
+/// `disable_utility_collapse` always stay visible.
+fn utilities_layout(
+    available: f64,
+    utilities: &[UtilityMeasure],
+    overflow_trigger: f64,
+) -> UtilitiesLayout {
+    if utilities.iter().map(|utility| utility.full).sum::<f64>() <= available {
+        return UtilitiesLayout::default();
+    }
+
+    let mut width: f64 = utilities.iter().map(|utility| utility.collapsed).sum();
+    let mut overflow = Vec::new();
+    if width > available {
+        width += overflow_trigger;
+        for (index, utility) in utilities.iter().enumerate() {
+            if width <= available {
+                break;
+            }
+            if utility.can_overflow {
+                width -= utility.collapsed;
+                overflow.push(index);
+            }
+        }
+    }
+
+    UtilitiesLayout {
+        collapse_text: true,
+        overflow,
     }
+}
 
-    let utility_items = utilities.iter().map(render_utility).collect::<Html>();
+/// Measures the utilities rendered in the hidden measurement list
+fn measure_utilities(
+    measure: &Element,
+    utilities: &[TopNavigationUtility],
+) -> Option<UtilityMeasurements> {
+    let width = |key: &str| -> Option<f64> {
+        measure
+            .query_selector(&format!("[data-utility-measure=\"{}\"]", key))
+            .ok()
+            .flatten()
+            .map(|element| element.get_bounding_client_rect().width())
+    };
+
+    let utilities = utilities
+        .iter()
+        .enumerate()
+        .map(|(index, utility)| {
+            let full = width(&format!("{}-full", index))?;
+            let collapsed = if utility.text_collapsible() {
+                width(&format!("{}-collapsed", index))?
+            } else {
+                full
+            };
+            Some(UtilityMeasure {
+                full,
+                collapsed,
+                can_overflow: !utility.disable_utility_collapse,
+            })
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(UtilityMeasurements {
+        utilities,
+        overflow_trigger: width("overflow")?,
+    })
+}
+
+/// Renders the hidden copies of the utilities that are measured to lay them
+/// out
+fn render_utilities_measure(
+    utilities: &[TopNavigationUtility],
+    default_max_width: Option<&str>,
+    overflow_trigger_text: &str,
+) -> Html {
+    let measure_content = |utility: &TopNavigationUtility, hide_text: bool| {
+        html! {
+            <>
+                { render_utility_icon(utility) }
+                if let Some(text) = utility.text.as_ref().filter(|_| !hide_text) {
+                    <span class="awsui-top-navigation-utility-text">
+                        <TruncatedText
+                            text={text.clone()}
+                            max_width={utility_max_width(utility, default_max_width)}
+                        />
+                    </span>
+                }
+                if utility.utility_type == UtilityType::MenuDropdown {
+                    <span class="awsui-top-navigation-utility-dropdown-arrow">{"▼"}</span>
+                }
+            </>
+        }
+    };
 
     html! {
-        <div class="awsui-top-navigation-utilities">
-            <ul class="awsui-top-navigation-utilities-list" role="list">
-                { utility_items }
-            </ul>
+        <div
+            class="awsui-top-navigation-utilities-measure"
+            aria-hidden="true"
+            style="position: absolute; visibility: hidden; pointer-events: none; white-space: nowrap;"
+        >
+            { for utilities.iter().enumerate().map(|(index, utility)| html! {
+                <>
+                    <span
+                        class="awsui-top-navigation-utility-measure"
+                        data-utility-measure={format!("{}-full", index)}
+                    >
+                        { measure_content(utility, false) }
+                    </span>
+                    if utility.text_collapsible() {
+                        <span
+                            class="awsui-top-navigation-utility-measure"
+                            data-utility-measure={format!("{}-collapsed", index)}
+                        >
+                            { measure_content(utility, true) }
+                        </span>
+                    }
+                </>
+            }) }
+            <span class="awsui-top-navigation-utility-measure" data-utility-measure="overflow">
+                <span class="awsui-top-navigation-utility-text">{ overflow_trigger_text }</span>
+                <span class="awsui-top-navigation-utility-dropdown-arrow">{"▼"}</span>
+            </span>
         </div>
     }
 }
 
+/// Gets the maximum text width of a utility
+fn utility_max_width(
+    utility: &TopNavigationUtility,
+    default_max_width: Option<&str>,
+) -> Option<String> {
+    utility
+        .max_width
+        .clone()
+        .or_else(|| default_max_width.map(str::to_string))
+}
+
+/// Gets the ARIA label of a utility, falling back to its text while the text
+/// is collapsed
+fn utility_aria_label(utility: &TopNavigationUtility, hide_text: bool) -> Option<String> {
+    utility
+        .aria_label
+        .clone()
+        .or_else(|| utility.text.clone().filter(|_| hide_text))
+}
+
 /// Renders a single utility item
-fn render_utility(utility: &TopNavigationUtility) -> Html {
+///
+/// With `collapse_text`, the text of utilities that have an icon and allow it
+/// collapses to the icon.
+fn render_utility(
+    utility: &TopNavigationUtility,
+    default_max_width: Option<&str>,
+    collapse_text: bool,
+) -> Html {
+    let max_width = utility_max_width(utility, default_max_width);
+    let hide_text = collapse_text && utility.text_collapsible();
+
     let utility_classes = ClassBuilder::new()
         .add("awsui-top-navigation-utility")
         .add(format!(
@@ -699,21 +1259,32 @@ fn render_utility(utility: &TopNavigationUtility) -> Html {
             utility.disable_utility_collapse,
             "awsui-top-navigation-utility-no-collapse",
         )
+        .add_if(hide_text, "awsui-top-navigation-utility-text-collapsed")
         .build();
 
     match utility.utility_type {
-        UtilityType::Button => render_button_utility(utility, &utility_classes),
-        UtilityType::MenuDropdown => render_menu_dropdown_utility(utility, &utility_classes),
+        UtilityType::Button => {
+            render_button_utility(utility, &utility_classes, max_width, hide_text)
+        }
+        UtilityType::MenuDropdown => html! {
+            <MenuDropdownUtility
+                key={utility.id.clone()}
+                utility={utility.clone()}
+                {utility_classes}
+                {max_width}
+                {hide_text}
+            />
+        },
     }
 }
 
-/// Renders a button utility
-fn render_button_utility(utility: &TopNavigationUtility, utility_classes: &str) -> Html {
+/// Creates the click handler of a button utility, firing its click and
+/// follow callbacks
+fn button_utility_click(utility: &TopNavigationUtility) -> Callback<MouseEvent> {
     let utility_clone = utility.clone();
-    let utility_classes = utility_classes.to_string();
     let is_disabled = utility.disabled;
 
-    let on_click = Callback::from(move |e: MouseEvent| {
+    Callback::from(move |e: MouseEvent| {
         if is_disabled {
             e.prevent_default();
             return;
@@ -745,14 +1316,23 @@ fn render_button_utility(utility: &TopNavigationUtility, utility_classes: &str)
                 external: utility_clone.external,
                 target: utility_clone.target.clone(),
             };
-            let event = CustomEvent::new(follow_detail);
-            callback.emit(event.clone());
-
-            if event.default_prevented {
+            if CustomEvent::new(follow_detail).emit_to(callback) {
                 e.prevent_default();
             }
         }
-    });
+    })
+}
+
+/// Renders a button utility
+fn render_button_utility(
+    utility: &TopNavigationUtility,
+    utility_classes: &str,
+    max_width: Option<String>,
+    hide_text: bool,
+) -> Html {
+    let utility_classes = utility_classes.to_string();
+    let is_disabled = utility.disabled;
+    let on_click = button_utility_click(utility);
 
     let variant = utility.variant.unwrap_or_default();
     let button_classes = ClassBuilder::new()
@@ -771,9 +1351,9 @@ fn render_button_utility(utility: &TopNavigationUtility, utility_classes: &str)
     let content = html! {
         <>
             { render_utility_icon(utility) }
-            if let Some(ref text) = utility.text {
+            if let Some(text) = utility.text.as_ref().filter(|_| !hide_text) {
                 <span class="awsui-top-navigation-utility-text">
-                    { text }
+                    <TruncatedText text={text.clone()} max_width={max_width.clone()} />
                 </span>
             }
             if utility.external && utility.href.is_some() {
@@ -811,7 +1391,7 @@ fn render_button_utility(utility: &TopNavigationUtility, utility_classes: &str)
                 target={actual_target}
                 rel={actual_rel}
                 class={button_classes}
-                aria-label={utility.aria_label.clone()}
+                aria-label={utility_aria_label(utility, hide_text)}
                 aria-disabled={if is_disabled { Some("true") } else { None }}
                 onclick={on_click}
             >
@@ -823,7 +1403,7 @@ fn render_button_utility(utility: &TopNavigationUtility, utility_classes: &str)
             <button
                 type="button"
                 class={button_classes}
-                aria-label={utility.aria_label.clone()}
+                aria-label={utility_aria_label(utility, hide_text)}
                 disabled={is_disabled}
                 onclick={on_click}
             >
@@ -833,47 +1413,833 @@ fn render_button_utility(utility: &TopNavigationUtility, utility_classes: &str)
     };
 
     html! {
-        <li class={utility_classes} role="listitem">
+        <li key={utility.id.clone()} class={utility_classes} role="listitem">
             { element }
         </li>
     }
 }
 
-/// Renders a menu dropdown utility
-fn render_menu_dropdown_utility(utility: &TopNavigationUtility, utility_classes: &str) -> Html {
-    let utility_classes = utility_classes.to_string();
+/// Properties for the menu-dropdown utility
+#[derive(Properties, PartialEq, Clone)]
+struct MenuDropdownUtilityProps {
+    utility: TopNavigationUtility,
+    utility_classes: String,
+    max_width: Option<String>,
+    hide_text: bool,
+}
+
+/// Menu state and callbacks shared by the entries of a menu-dropdown
+struct MenuRenderContext<'a> {
+    utility: &'a TopNavigationUtility,
+    entries: &'a [String],
+    expanded_groups: &'a [String],
+    control_id: &'a str,
+    on_group_toggle: Callback<String>,
+    close: Callback<bool>,
+}
+
+impl MenuRenderContext<'_> {
+    fn entry_index(&self, id: &str) -> Option<String> {
+        self.entries
+            .iter()
+            .position(|entry| entry == id)
+            .map(|index| index.to_string())
+    }
+
+    /// Renders items, with groups for items with nested items
+    fn render_items(&self, items: &[MenuDropdownItem]) -> Html {
+        items
+            .iter()
+            .map(|item| {
+                if item.is_group() {
+                    self.render_group(item)
+                } else {
+                    self.render_item(item)
+                }
+            })
+            .collect()
+    }
+
+    fn render_group(&self, group: &MenuDropdownItem) -> Html {
+        let header_id = format!("{}-group-{}", self.control_id, group.id);
+        let expandable = self.utility.expandable_groups;
+        let expanded = !expandable || self.expanded_groups.contains(&group.id);
+        let header_classes = ClassBuilder::new()
+            .add("awsui-top-navigation-menu-group-header")
+            .add_if(
+                expandable,
+                "awsui-top-navigation-menu-group-header-expandable",
+            )
+            .add_if(
+                group.disabled,
+                "awsui-top-navigation-menu-group-header-disabled",
+            );
+        let on_header_click = {
+            let on_group_toggle = self.on_group_toggle.clone();
+            let group_id = group.id.clone();
+            Callback::from(move |e: MouseEvent| {
+                e.stop_propagation();
+                on_group_toggle.emit(group_id.clone());
+            })
+        };
+
+        html! {
+            <li key={group.id.clone()} class="awsui-top-navigation-menu-group" role="none">
+                if expandable {
+                    <button
+                        type="button"
+                        id={header_id.clone()}
+                        class={header_classes.build()}
+                        role="menuitem"
+                        tabindex="-1"
+                        data-menu-index={self.entry_index(&group.id)}
+                        disabled={group.disabled}
+                        aria-expanded={expanded.to_string()}
+                        onclick={on_header_click}
+                    >
+                        { render_menu_item_content(group) }
+                        <span class="awsui-top-navigation-menu-group-icon" aria-hidden="true">
+                            { if expanded { "▲" } else { "▼" } }
+                        </span>
+                    </button>
+                } else {
+                    <div id={header_id.clone()} class={header_classes.build()}>
+                        { render_menu_item_content(group) }
+                    </div>
+                }
+                if expanded && !group.disabled {
+                    <ul
+                        class="awsui-top-navigation-menu-group-items"
+                        role="group"
+                        aria-labelledby={header_id}
+                    >
+                        { self.render_items(&group.items) }
+                    </ul>
+                }
+            </li>
+        }
+    }
+
+    fn render_item(&self, item: &MenuDropdownItem) -> Html {
+        let item_classes = ClassBuilder::new()
+            .add("awsui-top-navigation-menu-item")
+            .add_if(item.disabled, "awsui-top-navigation-menu-item-disabled");
+        let detail = UtilityClickDetail {
+            id: item.id.clone(),
+            external: item.external,
+            href: item.href.clone(),
+        };
+
+        if let Some(ref href) = item.href {
+            let on_click = {
+                let on_item_click = self.utility.on_item_click.clone();
+                let on_item_follow = self.utility.on_item_follow.clone();
+                let close = self.close.clone();
+                let item = item.clone();
+                let href = href.clone();
+                Callback::from(move |e: MouseEvent| {
+                    if item.disabled {
+                        e.prevent_default();
+                        return;
+                    }
+                    if let Some(ref callback) = on_item_click {
+                        callback.emit(CustomEvent::new(detail.clone()));
+                    }
+                    // Modifier clicks (new tab, new window) keep the menu open
+                    if !ClickEvent::from_mouse_event(&e).is_plain_left_click() {
+                        return;
+                    }
+                    if let Some(ref callback) = on_item_follow {
+                        let event = CustomEvent::new(UtilityFollowDetail {
+                            id: item.id.clone(),
+                            href: href.clone(),
+                            external: item.external,
+                            target: item.link_target(),
+                        });
+                        if event.emit_to(callback) {
+                            e.prevent_default();
+                        }
+                    }
+                    close.emit(true);
+                })
+            };
+
+            return html! {
+                <li key={item.id.clone()} class={item_classes.build()} role="none">
+                    <a
+                        class="awsui-top-navigation-menu-item-link"
+                        role="menuitem"
+                        tabindex="-1"
+                        data-menu-index={self.entry_index(&item.id)}
+                        href={(!item.disabled).then(|| href.clone())}
+                        target={item.link_target()}
+                        rel={item.external.then_some("noopener noreferrer")}
+                        aria-disabled={item.disabled.then_some("true")}
+                        onclick={on_click}
+                        onkeydown={Callback::from(activate_link_with_space)}
+                    >
+                        { render_menu_item_content(item) }
+                        if item.external {
+                            <span
+                                class="awsui-top-navigation-menu-item-external-icon"
+                                aria-label="(opens in a new tab)"
+                            >
+                                {"↗"}
+                            </span>
+                        }
+                    </a>
+                </li>
+            };
+        }
+
+        let on_click = {
+            let on_item_click = self.utility.on_item_click.clone();
+            let close = self.close.clone();
+            Callback::from(move |e: MouseEvent| {
+                e.stop_propagation();
+                close.emit(true);
+                if let Some(ref callback) = on_item_click {
+                    callback.emit(CustomEvent::new(detail.clone()));
+                }
+            })
+        };
+
+        html! {
+            <li key={item.id.clone()} class={item_classes.build()} role="none">
+                <button
+                    type="button"
+                    class="awsui-top-navigation-menu-item-button"
+                    role="menuitem"
+                    tabindex="-1"
+                    data-menu-index={self.entry_index(&item.id)}
+                    disabled={item.disabled}
+                    onclick={on_click}
+                >
+                    { render_menu_item_content(item) }
+                </button>
+            </li>
+        }
+    }
+}
+
+/// Renders the icon and text of a menu item
+fn render_menu_item_content(item: &MenuDropdownItem) -> Html {
+    html! {
+        <>
+            if let Some(ref icon_name) = item.icon_name {
+                <span
+                    class={format!("awsui-top-navigation-menu-item-icon awsui-icon awsui-icon-{}", icon_name)}
+                    aria-hidden="true"
+                />
+            }
+            <span class="awsui-top-navigation-menu-item-text">{ &item.text }</span>
+        </>
+    }
+}
+
+/// Space activates link items instead of scrolling the page
+fn activate_link_with_space(e: KeyboardEvent) {
+    if e.key() == " " {
+        e.prevent_default();
+        if let Some(anchor) = e.target_dyn_into::<web_sys::HtmlElement>() {
+            anchor.click();
+        }
+    }
+}
+
+/// Menu-dropdown utility with its open state
+#[function_component(MenuDropdownUtility)]
+fn menu_dropdown_utility(props: &MenuDropdownUtilityProps) -> Html {
+    let utility = &props.utility;
+    let is_open = use_state(|| false);
+    // Index of the focused menu entry
+    let highlighted = use_state(|| None::<usize>);
+    let expanded_groups = use_state(Vec::<String>::new);
+    let root_ref = use_node_ref();
+    let trigger_ref = use_node_ref();
+    let menu_ref = use_node_ref();
+
+    // Generate unique IDs for ARIA
+    let control_id = use_state(|| {
+        use std::sync::atomic::{AtomicU32, Ordering};
+        static COUNTER: AtomicU32 = AtomicU32::new(0);
+        let id = COUNTER.fetch_add(1, Ordering::Relaxed);
+        format!("awsui-top-navigation-menu-{}", id)
+    });
+    let trigger_id = format!("{}-trigger", *control_id);
+    let menu_id = format!("{}-menu", *control_id);
+
+    let entries =
+        menu_dropdown_entries(&utility.items, utility.expandable_groups, &expanded_groups);
+    let entries_count = entries.len();
+    let is_disabled = utility.disabled;
+
+    // Open the menu, focusing an entry when opened with the keyboard
+    let open = {
+        let is_open = is_open.clone();
+        let highlighted = highlighted.clone();
+        Callback::from(move |entry: Option<usize>| {
+            is_open.set(true);
+            highlighted.set(entry);
+        })
+    };
+
+    // Close the menu, returning focus to the trigger unless it moved elsewhere
+    let close = {
+        let is_open = is_open.clone();
+        let highlighted = highlighted.clone();
+        let trigger_ref = trigger_ref.clone();
+        Callback::from(move |return_focus: bool| {
+            is_open.set(false);
+            highlighted.set(None);
+            if return_focus && let Some(trigger) = trigger_ref.cast::<web_sys::HtmlElement>() {
+                let _ = trigger.focus();
+            }
+        })
+    };
+
+    // Focus the highlighted entry
+    {
+        let menu_ref = menu_ref.clone();
+        use_effect_with((*is_open, *highlighted), move |(open, highlighted)| {
+            if *open && let Some(index) = highlighted {
+                focus_menu_entry(&menu_ref, *index);
+            }
+        });
+    }
+
+    use_menu_dismiss(*is_open, root_ref.clone(), close.clone());
+
+    let on_toggle = {
+        let is_open = is_open.clone();
+        let open = open.clone();
+        let close = close.clone();
+        Callback::from(move |e: MouseEvent| {
+            e.stop_propagation();
+            if is_disabled {
+                return;
+            }
+            if *is_open {
+                close.emit(false);
+            } else {
+                // Clicks from Enter or Space have no click count
+                open.emit((e.detail() == 0).then_some(0));
+            }
+        })
+    };
+
+    // Arrow keys on the trigger open the menu on the first or last entry
+    let on_trigger_key_down = {
+        let is_open = is_open.clone();
+        let open = open.clone();
+        let close = close.clone();
+        Callback::from(move |e: KeyboardEvent| {
+            if is_disabled {
+                return;
+            }
+            match e.key().as_str() {
+                "ArrowDown" | "ArrowUp" if entries_count > 0 => {
+                    e.prevent_default();
+                    let entry = if e.key() == "ArrowDown" {
+                        0
+                    } else {
+                        entries_count - 1
+                    };
+                    open.emit(Some(entry));
+                }
+                "Escape" if *is_open => {
+                    e.prevent_default();
+                    e.stop_propagation();
+                    close.emit(true);
+                }
+                _ => {}
+            }
+        })
+    };
+
+    // Keyboard navigation within the open menu
+    let on_menu_key_down = {
+        let highlighted = highlighted.clone();
+        let close = close.clone();
+        Callback::from(move |e: KeyboardEvent| match e.key().as_str() {
+            "Escape" => {
+                e.prevent_default();
+                e.stop_propagation();
+                close.emit(true);
+            }
+            "Tab" => close.emit(false),
+            key => {
+                if let Some(entry) = next_menu_entry(*highlighted, key, entries_count) {
+                    e.prevent_default();
+                    highlighted.set(Some(entry));
+                }
+            }
+        })
+    };
+
+    let on_group_toggle = {
+        let expanded_groups = expanded_groups.clone();
+        Callback::from(move |group_id: String| {
+            expanded_groups.set(toggle_expanded(&expanded_groups, group_id));
+        })
+    };
+
+    let transition = use_dropdown_transition(*is_open, root_ref.clone());
+
     let dropdown_classes = ClassBuilder::new()
         .add("awsui-top-navigation-utility-dropdown")
-        .build();
+        .add_if(*is_open, "awsui-top-navigation-utility-dropdown-open");
+
+    let context = MenuRenderContext {
+        utility,
+        entries: &entries,
+        expanded_groups: &expanded_groups,
+        control_id: &control_id,
+        on_group_toggle,
+        close,
+    };
+    let has_header = utility.title.is_some() || utility.description.is_some();
 
     html! {
-        <li class={utility_classes} role="listitem">
-            <div class={dropdown_classes}>
+        <li ref={root_ref} class={props.utility_classes.clone()} role="listitem">
+            <div class={dropdown_classes.build()}>
                 <button
+                    ref={trigger_ref}
                     type="button"
+                    id={trigger_id.clone()}
                     class="awsui-top-navigation-utility-dropdown-trigger"
-                    aria-label={utility.aria_label.clone()}
-                    aria-haspopup="true"
-                    aria-expanded="false"
+                    aria-label={utility_aria_label(utility, props.hide_text)}
+                    aria-haspopup="menu"
+                    aria-expanded={is_open.to_string()}
+                    aria-controls={is_open.then(|| menu_id.clone())}
+                    disabled={is_disabled}
+                    onclick={on_toggle}
+                    onkeydown={on_trigger_key_down}
                 >
                     { render_utility_icon(utility) }
-                    if let Some(ref text) = utility.text {
+                    if let Some(text) = utility.text.as_ref().filter(|_| !props.hide_text) {
                         <span class="awsui-top-navigation-utility-text">
-                            { text }
+                            <TruncatedText text={text.clone()} max_width={props.max_width.clone()} />
                         </span>
                     }
                     if utility.badge {
                         <span class="awsui-top-navigation-utility-badge-indicator" aria-label="Badge"></span>
                     }
-                    <span class="awsui-top-navigation-utility-dropdown-arrow">{"▼"}</span>
+                    <span class="awsui-top-navigation-utility-dropdown-arrow" aria-hidden="true">
+                        { if *is_open { "▲" } else { "▼" } }
+                    </span>
                 </button>
-                // Dropdown menu would be rendered here when open
-                // This is a simplified implementation
+                if transition.mounted() {
+                    <div class="awsui-top-navigation-utility-dropdown-content" style={transition.style()}>
+                        if has_header {
+                            <div class="awsui-top-navigation-utility-dropdown-header">
+                                if let Some(ref title) = utility.title {
+                                    <div class="awsui-top-navigation-utility-dropdown-title">{ title }</div>
+                                }
+                                if let Some(ref description) = utility.description {
+                                    <div class="awsui-top-navigation-utility-dropdown-description">
+                                        { description }
+                                    </div>
+                                }
+                            </div>
+                        }
+                        <ul
+                            ref={menu_ref}
+                            id={menu_id}
+                            class="awsui-top-navigation-menu"
+                            role="menu"
+                            aria-labelledby={trigger_id}
+                            onkeydown={on_menu_key_down}
+                        >
+                            { context.render_items(&utility.items) }
+                        </ul>
+                    </div>
+                }
             </div>
         </li>
     }
 }
 
+/// Properties for the overflow menu
+#[derive(Properties, PartialEq, Clone)]
+struct OverflowMenuProps {
+    /// Utilities moved into the menu
+    utilities: Vec<TopNavigationUtility>,
+    i18n_strings: TopNavigationI18nStrings,
+}
+
+/// Overflow menu holding the utilities that don't fit
+///
+/// The panel lists the utilities; a menu-dropdown utility opens its items in
+/// a nested view that slides in, with a back button to the list.
+#[function_component(OverflowMenu)]
+fn overflow_menu(props: &OverflowMenuProps) -> Html {
+    let is_open = use_state(|| false);
+    // Index of the menu-dropdown utility whose items show
+    let submenu = use_state(|| None::<usize>);
+    // Index of the focused menu entry
+    let highlighted = use_state(|| None::<usize>);
+    let expanded_groups = use_state(Vec::<String>::new);
+    let root_ref = use_node_ref();
+    let trigger_ref = use_node_ref();
+    let menu_ref = use_node_ref();
+
+    // Generate unique IDs for ARIA
+    let control_id = use_state(|| {
+        use std::sync::atomic::{AtomicU32, Ordering};
+        static COUNTER: AtomicU32 = AtomicU32::new(0);
+        let id = COUNTER.fetch_add(1, Ordering::Relaxed);
+        format!("awsui-top-navigation-overflow-{}", id)
+    });
+    let panel_id = format!("{}-panel", *control_id);
+    let title_id = format!("{}-title", *control_id);
+
+    let i18n = &props.i18n_strings;
+    let trigger_text = i18n
+        .overflow_menu_trigger_text
+        .clone()
+        .unwrap_or_else(|| "More".to_string());
+    let title_text = i18n
+        .overflow_menu_title_text
+        .clone()
+        .unwrap_or_else(|| "All".to_string());
+    let dismiss_label = i18n
+        .overflow_menu_dismiss_icon_aria_label
+        .clone()
+        .unwrap_or_else(|| "Close menu".to_string());
+    let back_label = i18n
+        .overflow_menu_back_icon_aria_label
+        .clone()
+        .unwrap_or_else(|| "Back".to_string());
+
+    let submenu_utility = submenu.and_then(|index| props.utilities.get(index));
+    // Enabled utilities take focus in the list, in order
+    let list_entries: Vec<usize> = props
+        .utilities
+        .iter()
+        .enumerate()
+        .filter(|(_, utility)| !utility.disabled)
+        .map(|(index, _)| index)
+        .collect();
+    let submenu_entries = submenu_utility
+        .map(|utility| {
+            menu_dropdown_entries(&utility.items, utility.expandable_groups, &expanded_groups)
+        })
+        .unwrap_or_default();
+    let entries_count = if submenu_utility.is_some() {
+        submenu_entries.len()
+    } else {
+        list_entries.len()
+    };
+
+    let close = {
+        let is_open = is_open.clone();
+        let submenu = submenu.clone();
+        let highlighted = highlighted.clone();
+        let trigger_ref = trigger_ref.clone();
+        Callback::from(move |return_focus: bool| {
+            is_open.set(false);
+            submenu.set(None);
+            highlighted.set(None);
+            if return_focus && let Some(trigger) = trigger_ref.cast::<web_sys::HtmlElement>() {
+                let _ = trigger.focus();
+            }
+        })
+    };
+
+    // Show the items of a menu-dropdown utility
+    let open_submenu = {
+        let submenu = submenu.clone();
+        let highlighted = highlighted.clone();
+        Callback::from(move |index: usize| {
+            submenu.set(Some(index));
+            highlighted.set(Some(0));
+        })
+    };
+
+    // Return to the list, on the utility the items belong to
+    let back = {
+        let submenu = submenu.clone();
+        let highlighted = highlighted.clone();
+        let list_entries = list_entries.clone();
+        Callback::from(move |_: ()| {
+            let entry = submenu.and_then(|index| list_entries.iter().position(|i| *i == index));
+            submenu.set(None);
+            highlighted.set(entry);
+        })
+    };
+
+    // Focus the highlighted entry, also after switching views
+    {
+        let menu_ref = menu_ref.clone();
+        use_effect_with(
+            (*is_open, *submenu, *highlighted),
+            move |(open, _, highlighted)| {
+                if *open && let Some(index) = highlighted {
+                    focus_menu_entry(&menu_ref, *index);
+                }
+            },
+        );
+    }
+
+    use_menu_dismiss(*is_open, root_ref.clone(), close.clone());
+
+    let on_toggle = {
+        let is_open = is_open.clone();
+        let highlighted = highlighted.clone();
+        let close = close.clone();
+        Callback::from(move |e: MouseEvent| {
+            e.stop_propagation();
+            if *is_open {
+                close.emit(false);
+            } else {
+                is_open.set(true);
+                // Clicks from Enter or Space have no click count
+                highlighted.set((e.detail() == 0).then_some(0));
+            }
+        })
+    };
+
+    let on_panel_key_down = {
+        let highlighted = highlighted.clone();
+        let close = close.clone();
+        let back = back.clone();
+        let in_submenu = submenu_utility.is_some();
+        Callback::from(move |e: KeyboardEvent| match e.key().as_str() {
+            "Escape" => {
+                e.prevent_default();
+                e.stop_propagation();
+                if in_submenu {
+                    back.emit(());
+                } else {
+                    close.emit(true);
+                }
+            }
+            "ArrowLeft" if in_submenu => {
+                e.prevent_default();
+                back.emit(());
+            }
+            key => {
+                if let Some(entry) = next_menu_entry(*highlighted, key, entries_count) {
+                    e.prevent_default();
+                    highlighted.set(Some(entry));
+                }
+            }
+        })
+    };
+
+    let panel_classes = ClassBuilder::new()
+        .add("awsui-top-navigation-overflow-menu")
+        .add_if(
+            submenu_utility.is_some(),
+            "awsui-top-navigation-overflow-menu-submenu",
+        );
+
+    let menu_items = if let Some(utility) = submenu_utility {
+        let on_group_toggle = {
+            let expanded_groups = expanded_groups.clone();
+            Callback::from(move |group_id: String| {
+                expanded_groups.set(toggle_expanded(&expanded_groups, group_id));
+            })
+        };
+        let context = MenuRenderContext {
+            utility,
+            entries: &submenu_entries,
+            expanded_groups: &expanded_groups,
+            control_id: &control_id,
+            on_group_toggle,
+            close: close.clone(),
+        };
+        context.render_items(&utility.items)
+    } else {
+        props
+            .utilities
+            .iter()
+            .enumerate()
+            .map(|(index, utility)| {
+                let entry = list_entries.iter().position(|i| *i == index);
+                render_overflow_entry(utility, index, entry, &open_submenu, &close)
+            })
+            .collect::<Html>()
+    };
+
+    html! {
+        <li
+            ref={root_ref}
+            class="awsui-top-navigation-utility awsui-top-navigation-utility-overflow"
+            role="listitem"
+        >
+            <button
+                ref={trigger_ref}
+                type="button"
+                class="awsui-top-navigation-overflow-trigger"
+                aria-haspopup="true"
+                aria-expanded={is_open.to_string()}
+                aria-controls={is_open.then(|| panel_id.clone())}
+                onclick={on_toggle}
+            >
+                <span class="awsui-top-navigation-utility-text">{ trigger_text }</span>
+                <span class="awsui-top-navigation-utility-dropdown-arrow" aria-hidden="true">
+                    { if *is_open { "▲" } else { "▼" } }
+                </span>
+            </button>
+            if *is_open {
+                <div
+                    id={panel_id}
+                    class={panel_classes.build()}
+                    role="dialog"
+                    aria-labelledby={title_id.clone()}
+                    onkeydown={on_panel_key_down}
+                >
+                    <div class="awsui-top-navigation-overflow-menu-header">
+                        if submenu_utility.is_some() {
+                            <button
+                                type="button"
+                                class="awsui-top-navigation-overflow-menu-back"
+                                aria-label={back_label}
+                                onclick={back.reform(|_: MouseEvent| ())}
+                            >
+                                {"←"}
+                            </button>
+                        }
+                        <h2 id={title_id.clone()} class="awsui-top-navigation-overflow-menu-title">
+                            {
+                                submenu_utility
+                                    .and_then(|utility| utility.title.clone().or_else(|| utility.text.clone()))
+                                    .unwrap_or(title_text)
+                            }
+                        </h2>
+                        <button
+                            type="button"
+                            class="awsui-top-navigation-overflow-menu-dismiss"
+                            aria-label={dismiss_label}
+                            onclick={close.reform(|_: MouseEvent| true)}
+                        >
+                            {"✕"}
+                        </button>
+                    </div>
+                    <ul
+                        ref={menu_ref}
+                        key={submenu.map_or_else(|| "list".to_string(), |index| index.to_string())}
+                        class="awsui-top-navigation-overflow-menu-list"
+                        role="menu"
+                        aria-labelledby={title_id}
+                    >
+                        { menu_items }
+                    </ul>
+                </div>
+            }
+        </li>
+    }
+}
+
+/// Renders a utility in the overflow menu list
+///
+/// `entry` is the utility's position among the focusable entries, `None`
+/// when it is disabled.
+fn render_overflow_entry(
+    utility: &TopNavigationUtility,
+    index: usize,
+    entry: Option<usize>,
+    open_submenu: &Callback<usize>,
+    close: &Callback<bool>,
+) -> Html {
+    let text = utility
+        .text
+        .clone()
+        .or_else(|| utility.aria_label.clone())
+        .unwrap_or_else(|| utility.id.clone());
+    let item_classes = ClassBuilder::new()
+        .add("awsui-top-navigation-overflow-menu-item")
+        .add_if(
+            utility.disabled,
+            "awsui-top-navigation-overflow-menu-item-disabled",
+        );
+    let content = html! {
+        <>
+            { render_utility_icon(utility) }
+            <span class="awsui-top-navigation-utility-text">{ text }</span>
+            if utility.badge {
+                <span class="awsui-top-navigation-utility-badge-indicator" aria-label="Badge"></span>
+            }
+        </>
+    };
+
+    let element = match utility.utility_type {
+        UtilityType::MenuDropdown => {
+            let open_submenu = open_submenu.clone();
+            html! {
+                <button
+                    type="button"
+                    class="awsui-top-navigation-overflow-menu-item-button"
+                    role="menuitem"
+                    tabindex="-1"
+                    data-menu-index={entry.map(|entry| entry.to_string())}
+                    aria-haspopup="menu"
+                    disabled={utility.disabled}
+                    onclick={Callback::from(move |e: MouseEvent| {
+                        e.stop_propagation();
+                        open_submenu.emit(index);
+                    })}
+                >
+                    { content }
+                    <span class="awsui-top-navigation-overflow-menu-item-arrow" aria-hidden="true">
+                        {"›"}
+                    </span>
+                </button>
+            }
+        }
+        UtilityType::Button => {
+            let click = button_utility_click(utility);
+            let close = close.clone();
+            let on_click = Callback::from(move |e: MouseEvent| {
+                click.emit(e);
+                close.emit(true);
+            });
+            if let Some(ref href) = utility.href {
+                html! {
+                    <a
+                        class="awsui-top-navigation-overflow-menu-item-link"
+                        role="menuitem"
+                        tabindex="-1"
+                        data-menu-index={entry.map(|entry| entry.to_string())}
+                        href={(!utility.disabled).then(|| href.clone())}
+                        target={utility.target.clone().or_else(|| utility.external.then(|| "_blank".to_string()))}
+                        rel={utility.rel.clone().or_else(|| {
+                            utility.external.then(|| "noopener noreferrer".to_string())
+                        })}
+                        aria-disabled={utility.disabled.then_some("true")}
+                        onclick={on_click}
+                        onkeydown={Callback::from(activate_link_with_space)}
+                    >
+                        { content }
+                    </a>
+                }
+            } else {
+                html! {
+                    <button
+                        type="button"
+                        class="awsui-top-navigation-overflow-menu-item-button"
+                        role="menuitem"
+                        tabindex="-1"
+                        data-menu-index={entry.map(|entry| entry.to_string())}
+                        disabled={utility.disabled}
+                        onclick={on_click}
+                    >
+                        { content }
+                    </button>
+                }
+            }
+        }
+    };
+
+    html! {
+        <li key={utility.id.clone()} class={item_classes.build()} role="none">
+            { element }
+        </li>
+    }
+}
+
 /// Renders the icon for a utility
 fn render_utility_icon(utility: &TopNavigationUtility) -> Html {
     if let Some(ref icon_svg) = utility.icon_svg {
@@ -963,7 +2329,10 @@ mod tests {
         let utility = TopNavigationUtility::menu_dropdown("user")
             .with_text("John Doe")
             .with_description("User menu")
-            .with_items(vec!["Profile".to_string(), "Logout".to_string()])
+            .with_items(vec![
+                MenuDropdownItem::new("profile", "Profile"),
+                MenuDropdownItem::new("logout", "Logout"),
+            ])
             .with_expandable_groups(true);
 
         assert_eq!(utility.id, "user");
@@ -974,6 +2343,156 @@ mod tests {
         assert!(utility.expandable_groups);
     }
 
+    #[test]
+    fn test_menu_dropdown_item_builder() {
+        let item = MenuDropdownItem::new("docs", "Documentation")
+            .with_href("https://example.com/docs")
+            .with_external(true)
+            .with_icon_name("file");
+
+        assert_eq!(item.id, "docs");
+        assert_eq!(item.href, Some("https://example.com/docs".to_string()));
+        assert_eq!(item.link_target(), Some("_blank".to_string()));
+        assert_eq!(item.icon_name, Some("file".to_string()));
+        assert!(!item.is_group());
+        assert!(
+            MenuDropdownItem::new("account", "Account")
+                .with_items(vec![item])
+                .is_group()
+        );
+    }
+
+    #[test]
+    fn test_menu_dropdown_entries() {
+        let items = vec![
+            MenuDropdownItem::new("profile", "Profile"),
+            MenuDropdownItem::new("account", "Account").with_items(vec![
+                MenuDropdownItem::new("billing", "Billing"),
+                MenuDropdownItem::new("security", "Security").with_disabled(true),
+            ]),
+            MenuDropdownItem::new("beta", "Beta")
+                .with_disabled(true)
+                .with_items(vec![MenuDropdownItem::new("preview", "Preview")]),
+            MenuDropdownItem::new("signout", "Sign out"),
+        ];
+
+        // Group headers only take focus when groups are expandable
+        assert_eq!(
+            menu_dropdown_entries(&items, false, &[]),
+            vec!["profile", "billing", "signout"]
+        );
+        assert_eq!(
+            menu_dropdown_entries(&items, true, &[]),
+            vec!["profile", "account", "signout"]
+        );
+        assert_eq!(
+            menu_dropdown_entries(&items, true, &["account".to_string(), "beta".to_string()]),
+            vec!["profile", "account", "billing", "signout"]
+        );
+    }
+
+    fn measure(full: f64, collapsed: f64) -> UtilityMeasure {
+        UtilityMeasure {
+            full,
+            collapsed,
+            can_overflow: true,
+        }
+    }
+
+    #[test]
+    fn test_utilities_layout_breakpoints() {
+        let utilities = [
+            measure(100.0, 40.0),
+            measure(120.0, 40.0),
+            measure(80.0, 80.0),
+        ];
+
+        // Everything fits with text
+        assert_eq!(
+            utilities_layout(300.0, &utilities, 60.0),
+            UtilitiesLayout::default()
+        );
+        // Text collapses before anything moves into the overflow menu
+        assert_eq!(
+            utilities_layout(299.0, &utilities, 60.0),
+            UtilitiesLayout {
+                collapse_text: true,
+                overflow: vec![],
+            }
+        );
+        assert!(
+            utilities_layout(160.0, &utilities, 60.0)
+                .overflow
+                .is_empty()
+        );
+        // The overflow trigger takes space too, so moving a single
+        // utility that is narrower than the trigger doesn't help
+        assert_eq!(
+            utilities_layout(159.0, &utilities, 60.0).overflow,
+            vec![0, 1]
+        );
+        assert_eq!(
+            utilities_layout(140.0, &utilities, 60.0).overflow,
+            vec![0, 1]
+        );
+        assert_eq!(
+            utilities_layout(139.0, &utilities, 60.0).overflow,
+            vec![0, 1, 2]
+        );
+        assert_eq!(
+            utilities_layout(0.0, &utilities, 60.0).overflow,
+            vec![0, 1, 2]
+        );
+    }
+
+    #[test]
+    fn test_utilities_layout_priority() {
+        let mut utilities = [
+            measure(100.0, 40.0),
+            measure(100.0, 40.0),
+            measure(100.0, 40.0),
+        ];
+        utilities[0].can_overflow = false;
+
+        // Utilities move from the left, skipping those that can't collapse
+        assert!(
+            utilities_layout(120.0, &utilities, 20.0)
+                .overflow
+                .is_empty()
+        );
+        assert_eq!(utilities_layout(119.0, &utilities, 20.0).overflow, vec![1]);
+        assert_eq!(
+            utilities_layout(50.0, &utilities, 20.0).overflow,
+            vec![1, 2]
+        );
+    }
+
+    #[test]
+    fn test_utility_text_collapsible() {
+        let utility = TopNavigationUtility::button("settings").with_text("Settings");
+        assert!(!utility.text_collapsible());
+
+        let utility = utility.with_icon_name("settings");
+        assert!(utility.text_collapsible());
+        assert_eq!(
+            utility_aria_label(&utility, true),
+            Some("Settings".to_string())
+        );
+        assert_eq!(utility_aria_label(&utility, false), None);
+
+        let utility = utility.with_disable_text_collapse(true);
+        assert!(!utility.text_collapsible());
+    }
+
+    #[test]
+    fn test_search_collapsed() {
+        // Inline until measured
+        assert!(!search_collapsed(None));
+        assert!(search_collapsed(Some(320.0)));
+        assert!(search_collapsed(Some(687.0)));
+        assert!(!search_collapsed(Some(688.0)));
+    }
+
     #[test]
     fn test_utility_with_href() {
         let utility = TopNavigationUtility::button("link")
@@ -0,0 +1,508 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Color contrast checking
+//!
+//! Utilities for computing WCAG 2.x contrast ratios between colors, and
+//! for checking a set of token overrides against the foreground/background
+//! token pairs the components render together.
+
+use std::collections::HashMap;
+
+#[cfg(feature = "generated")]
+use crate::{Mode, generated::ColorToken};
+
+/// Minimum WCAG AA contrast ratio for normal-size text
+pub const AA_NORMAL_TEXT: f64 = 4.5;
+
+/// Minimum WCAG AA contrast ratio for large text and non-text UI elements
+pub const AA_LARGE_TEXT: f64 = 3.0;
+
+/// A foreground/background token pair that is rendered together
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ContrastPair {
+    /// CSS custom property name of the foreground token
+    pub foreground: &'static str,
+    /// CSS custom property name of the background token
+    pub background: &'static str,
+    /// Minimum contrast ratio required between the two
+    pub required_ratio: f64,
+}
+
+impl ContrastPair {
+    const fn new(foreground: &'static str, background: &'static str, required_ratio: f64) -> Self {
+        Self {
+            foreground,
+            background,
+            required_ratio,
+        }
+    }
+}
+
+/// Token pairs evaluated by [`check_theme`]
+///
+/// Add an entry here to have a new combination checked.
+pub const CONTRAST_PAIRS: &[ContrastPair] = &[
+    // Buttons
+    ContrastPair::new(
+        "--awsui-color-text-button-primary-default",
+        "--awsui-color-background-button-primary-default",
+        AA_NORMAL_TEXT,
+    ),
+    ContrastPair::new(
+        "--awsui-color-text-button-normal-default",
+        "--awsui-color-background-button-normal-default",
+        AA_NORMAL_TEXT,
+    ),
+    // Body text
+    ContrastPair::new(
+        "--awsui-color-text-body-default",
+        "--awsui-color-background-container-content",
+        AA_NORMAL_TEXT,
+    ),
+    ContrastPair::new(
+        "--awsui-color-text-body-secondary",
+        "--awsui-color-background-container-content",
+        AA_NORMAL_TEXT,
+    ),
+    ContrastPair::new(
+        "--awsui-color-text-body-default",
+        "--awsui-color-background-layout-main",
+        AA_NORMAL_TEXT,
+    ),
+    ContrastPair::new(
+        "--awsui-color-text-heading-default",
+        "--awsui-color-background-container-content",
+        AA_LARGE_TEXT,
+    ),
+    ContrastPair::new(
+        "--awsui-color-text-link-default",
+        "--awsui-color-background-container-content",
+        AA_NORMAL_TEXT,
+    ),
+    // Form controls
+    ContrastPair::new(
+        "--awsui-color-text-form-label",
+        "--awsui-color-background-container-content",
+        AA_NORMAL_TEXT,
+    ),
+    ContrastPair::new(
+        "--awsui-color-text-dropdown-item-default",
+        "--awsui-color-background-dropdown-item-default",
+        AA_NORMAL_TEXT,
+    ),
+    // Status
+    ContrastPair::new(
+        "--awsui-color-text-status-error",
+        "--awsui-color-background-status-error",
+        AA_NORMAL_TEXT,
+    ),
+    ContrastPair::new(
+        "--awsui-color-text-status-success",
+        "--awsui-color-background-status-success",
+        AA_NORMAL_TEXT,
+    ),
+    ContrastPair::new(
+        "--awsui-color-text-status-warning",
+        "--awsui-color-background-status-warning",
+        AA_NORMAL_TEXT,
+    ),
+    ContrastPair::new(
+        "--awsui-color-text-status-info",
+        "--awsui-color-background-status-info",
+        AA_NORMAL_TEXT,
+    ),
+    // Notifications
+    ContrastPair::new(
+        "--awsui-color-text-notification-default",
+        "--awsui-color-background-notification-blue",
+        AA_NORMAL_TEXT,
+    ),
+    ContrastPair::new(
+        "--awsui-color-text-notification-yellow",
+        "--awsui-color-background-notification-yellow",
+        AA_NORMAL_TEXT,
+    ),
+];
+
+/// A token pair that does not meet its required contrast ratio
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContrastViolation {
+    /// The failing token pair
+    pub pair: ContrastPair,
+    /// Contrast ratio computed from the theme values
+    pub ratio: f64,
+    /// Minimum contrast ratio required for the pair
+    pub required_ratio: f64,
+}
+
+/// An sRGB color with alpha, each channel in `0.0..=1.0`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rgba {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+    pub a: f64,
+}
+
+impl Rgba {
+    /// Parses a `#rgb`, `#rrggbb` or `#rrggbbaa` hex color
+    ///
+    /// The leading `#` is optional. Returns `None` for any other format.
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        let digits = hex.trim().trim_start_matches('#');
+        if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        let channel = |i: usize| u8::from_str_radix(&digits[i..i + 2], 16).ok();
+        let short = |i: usize| {
+            u8::from_str_radix(&digits[i..i + 1], 16)
+                .ok()
+                .map(|v| v * 17)
+        };
+
+        let (r, g, b, a) = match digits.len() {
+            3 => (short(0)?, short(1)?, short(2)?, 255),
+            6 => (channel(0)?, channel(2)?, channel(4)?, 255),
+            8 => (channel(0)?, channel(2)?, channel(4)?, channel(6)?),
+            _ => return None,
+        };
+
+        Some(Self {
+            r: f64::from(r) / 255.0,
+            g: f64::from(g) / 255.0,
+            b: f64::from(b) / 255.0,
+            a: f64::from(a) / 255.0,
+        })
+    }
+
+    /// Parses a CSS color: a hex color as accepted by [`Rgba::from_hex`],
+    /// `rgb()` or `rgba()` with comma-separated channels, or `transparent`
+    ///
+    /// Returns `None` for any other value, such as named colors or `var()`.
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim();
+        if value.eq_ignore_ascii_case("transparent") {
+            return Some(Self {
+                r: 0.0,
+                g: 0.0,
+                b: 0.0,
+                a: 0.0,
+            });
+        }
+        let Some(args) = value
+            .strip_prefix("rgba(")
+            .or_else(|| value.strip_prefix("rgb("))
+            .and_then(|rest| rest.strip_suffix(')'))
+        else {
+            return Self::from_hex(value);
+        };
+
+        let channel = |part: &str| {
+            part.trim()
+                .parse::<f64>()
+                .ok()
+                .filter(|v| (0.0..=255.0).contains(v))
+                .map(|v| v / 255.0)
+        };
+        let alpha = |part: &str| {
+            part.trim()
+                .parse::<f64>()
+                .ok()
+                .filter(|v| (0.0..=1.0).contains(v))
+        };
+
+        let parts: Vec<&str> = args.split(',').collect();
+        let (r, g, b, a) = match parts.as_slice() {
+            [r, g, b] => (channel(r)?, channel(g)?, channel(b)?, 1.0),
+            [r, g, b, a] => (channel(r)?, channel(g)?, channel(b)?, alpha(a)?),
+            _ => return None,
+        };
+        Some(Self { r, g, b, a })
+    }
+
+    /// Returns the 8-bit red, green and blue channels, and alpha in
+    /// `0.0..=1.0`
+    pub fn to_rgb8(&self) -> (u8, u8, u8, f32) {
+        let byte = |c: f64| (c * 255.0).round() as u8;
+        (byte(self.r), byte(self.g), byte(self.b), self.a as f32)
+    }
+
+    /// Composites this color over an opaque background
+    pub fn over(&self, background: &Rgba) -> Rgba {
+        let blend = |fg: f64, bg: f64| fg * self.a + bg * (1.0 - self.a);
+        Rgba {
+            r: blend(self.r, background.r),
+            g: blend(self.g, background.g),
+            b: blend(self.b, background.b),
+            a: 1.0,
+        }
+    }
+
+    /// Relative luminance as defined by WCAG 2.x, ignoring alpha
+    pub fn luminance(&self) -> f64 {
+        let linear = |c: f64| {
+            if c <= 0.03928 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        0.2126 * linear(self.r) + 0.7152 * linear(self.g) + 0.0722 * linear(self.b)
+    }
+}
+
+const WHITE: Rgba = Rgba {
+    r: 1.0,
+    g: 1.0,
+    b: 1.0,
+    a: 1.0,
+};
+
+/// Computes the WCAG relative luminance of a color
+///
+/// Alpha is ignored. Returns `None` if `hex` is not a color accepted by
+/// [`Rgba::parse`].
+///
+/// # Example
+///
+/// ```rust
+/// use cloudscape_design_tokens::contrast::relative_luminance;
+///
+/// assert_eq!(relative_luminance("#fff"), Some(1.0));
+/// assert_eq!(relative_luminance("#000000"), Some(0.0));
+/// ```
+pub fn relative_luminance(hex: &str) -> Option<f64> {
+    Rgba::parse(hex).map(|color| color.luminance())
+}
+
+/// Computes the WCAG contrast ratio between two colors
+///
+/// A translucent foreground is composited over the background, and a
+/// translucent background over white, before comparing. The result ranges
+/// from 1.0 to 21.0. Returns `None` if either color cannot be parsed by
+/// [`Rgba::parse`].
+///
+/// # Example
+///
+/// ```rust
+/// use cloudscape_design_tokens::contrast::contrast_ratio;
+///
+/// let ratio = contrast_ratio("#000", "#ffffff").unwrap();
+/// assert!((ratio - 21.0).abs() < 1e-9);
+/// ```
+pub fn contrast_ratio(foreground: &str, background: &str) -> Option<f64> {
+    let background = Rgba::parse(background)?.over(&WHITE);
+    let foreground = Rgba::parse(foreground)?.over(&background);
+
+    let (l1, l2) = (foreground.luminance(), background.luminance());
+    let (lighter, darker) = if l1 > l2 { (l1, l2) } else { (l2, l1) };
+    Some((lighter + 0.05) / (darker + 0.05))
+}
+
+#[cfg(feature = "generated")]
+impl ColorToken {
+    /// Parses the resolved value of this token in a mode into 8-bit red,
+    /// green and blue channels, and alpha in `0.0..=1.0`
+    ///
+    /// Returns `None` if the value is not a plain color.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cloudscape_design_tokens::Mode;
+    /// use cloudscape_design_tokens::generated::ColorToken;
+    ///
+    /// let (r, g, b, a) = ColorToken::ColorTextBodyDefault.parse_rgb(Mode::Light).unwrap();
+    /// assert_eq!((r, g, b, a), (15, 20, 26, 1.0));
+    /// ```
+    pub fn parse_rgb(&self, mode: Mode) -> Option<(u8, u8, u8, f32)> {
+        Rgba::parse(self.value(mode)).map(|color| color.to_rgb8())
+    }
+
+    /// Computes the WCAG contrast ratio of this token as foreground over
+    /// `background` in a mode, as [`contrast_ratio`] does
+    pub fn contrast_ratio(&self, background: ColorToken, mode: Mode) -> Option<f64> {
+        contrast_ratio(self.value(mode), background.value(mode))
+    }
+}
+
+/// Checks a theme against [`CONTRAST_PAIRS`]
+///
+/// `theme` maps CSS custom property names (for example
+/// `--awsui-color-text-body-default`) to color values. Pairs are only
+/// evaluated when both tokens are present with parseable values, so a
+/// partial set of overrides only reports on the pairs it defines.
+///
+/// # Example
+///
+/// ```rust
+/// use cloudscape_design_tokens::contrast::check_theme;
+/// use std::collections::HashMap;
+///
+/// let theme = HashMap::from([
+///     ("--awsui-color-text-button-primary-default".to_string(), "#ffffff".to_string()),
+///     ("--awsui-color-background-button-primary-default".to_string(), "#99ccff".to_string()),
+/// ]);
+///
+/// let violations = check_theme(&theme);
+/// assert_eq!(violations.len(), 1);
+/// assert!(violations[0].ratio < violations[0].required_ratio);
+/// ```
+pub fn check_theme(theme: &HashMap<String, String>) -> Vec<ContrastViolation> {
+    CONTRAST_PAIRS
+        .iter()
+        .filter_map(|pair| {
+            let foreground = theme.get(pair.foreground)?;
+            let background = theme.get(pair.background)?;
+            let ratio = contrast_ratio(foreground, background)?;
+
+            (ratio < pair.required_ratio).then_some(ContrastViolation {
+                pair: *pair,
+                ratio,
+                required_ratio: pair.required_ratio,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 0.01,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn test_parse_hex_forms() {
+        let short = Rgba::from_hex("#f80").unwrap();
+        let long = Rgba::from_hex("ff8800").unwrap();
+        assert_eq!(short, long);
+        assert_eq!(long.a, 1.0);
+
+        let translucent = Rgba::from_hex("#ff880080").unwrap();
+        assert_close(translucent.a, 0.5);
+    }
+
+    #[test]
+    fn test_parse_hex_rejects_invalid() {
+        assert_eq!(Rgba::from_hex("#ffff"), None);
+        assert_eq!(Rgba::from_hex("#gggggg"), None);
+        assert_eq!(Rgba::from_hex("rgb(0, 0, 0)"), None);
+        assert_eq!(Rgba::from_hex(""), None);
+    }
+
+    #[test]
+    fn test_parse_css_colors() {
+        assert_eq!(Rgba::parse("#ff8800"), Rgba::from_hex("#ff8800"));
+        assert_eq!(
+            Rgba::parse("rgb(255, 136, 0)").unwrap().to_rgb8(),
+            (255, 136, 0, 1.0)
+        );
+        assert_eq!(
+            Rgba::parse("rgba(15, 20, 26, 0.5)").unwrap().to_rgb8(),
+            (15, 20, 26, 0.5)
+        );
+        assert_eq!(Rgba::parse("transparent").unwrap().a, 0.0);
+        assert_eq!(Rgba::parse("rgb(256, 0, 0)"), None);
+        assert_eq!(Rgba::parse("rgba(0, 0, 0)x"), None);
+        assert_eq!(Rgba::parse("var(--brand-red)"), None);
+    }
+
+    #[test]
+    fn test_relative_luminance() {
+        assert_eq!(relative_luminance("#ffffff"), Some(1.0));
+        assert_eq!(relative_luminance("#000"), Some(0.0));
+        assert_close(relative_luminance("#808080").unwrap(), 0.2159);
+        assert_eq!(relative_luminance("blue"), None);
+    }
+
+    #[test]
+    fn test_contrast_ratio_is_symmetric() {
+        assert_close(contrast_ratio("#000000", "#ffffff").unwrap(), 21.0);
+        assert_close(contrast_ratio("#ffffff", "#000000").unwrap(), 21.0);
+        assert_close(contrast_ratio("#777777", "#ffffff").unwrap(), 4.48);
+        assert_close(contrast_ratio("#123456", "#123456").unwrap(), 1.0);
+    }
+
+    #[test]
+    fn test_contrast_ratio_composites_translucent_foreground() {
+        // Fully transparent text has no contrast with its background
+        assert_close(contrast_ratio("#00000000", "#ffffff").unwrap(), 1.0);
+        // Half-transparent black over white is mid grey
+        let ratio = contrast_ratio("#00000080", "#ffffff").unwrap();
+        assert!(ratio > 1.0 && ratio < 21.0);
+    }
+
+    #[test]
+    fn test_check_theme_reports_failing_pairs() {
+        let theme = HashMap::from([
+            (
+                "--awsui-color-text-body-default".to_string(),
+                "#aaaaaa".to_string(),
+            ),
+            (
+                "--awsui-color-background-container-content".to_string(),
+                "#ffffff".to_string(),
+            ),
+            (
+                "--awsui-color-text-body-secondary".to_string(),
+                "#414d5c".to_string(),
+            ),
+        ]);
+
+        let violations = check_theme(&theme);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(
+            violations[0].pair.foreground,
+            "--awsui-color-text-body-default"
+        );
+        assert_eq!(violations[0].required_ratio, AA_NORMAL_TEXT);
+        assert_close(violations[0].ratio, 2.32);
+    }
+
+    #[test]
+    fn test_check_theme_skips_incomplete_pairs() {
+        let theme = HashMap::from([
+            (
+                "--awsui-color-text-status-error".to_string(),
+                "#ffffff".to_string(),
+            ),
+            (
+                "--awsui-color-background-status-error".to_string(),
+                "var(--brand-red)".to_string(),
+            ),
+        ]);
+
+        assert!(check_theme(&theme).is_empty());
+    }
+
+    #[cfg(feature = "generated")]
+    #[test]
+    fn test_generated_body_text_meets_contrast() {
+        for mode in [Mode::Light, Mode::Dark] {
+            let ratio = ColorToken::ColorTextBodyDefault
+                .contrast_ratio(ColorToken::ColorBackgroundContainerContent, mode)
+                .unwrap();
+            assert!(ratio >= AA_NORMAL_TEXT, "{mode:?}: {ratio}");
+        }
+        assert_eq!(
+            ColorToken::ColorTextBodyDefault.parse_rgb(Mode::Dark),
+            Some((198, 198, 205, 1.0))
+        );
+    }
+
+    #[test]
+    fn test_contrast_pairs_use_css_var_names() {
+        for pair in CONTRAST_PAIRS {
+            assert!(pair.foreground.starts_with("--awsui-color-text-"));
+            assert!(pair.background.starts_with("--awsui-color-background-"));
+            assert!(pair.required_ratio >= AA_LARGE_TEXT);
+        }
+    }
+}
@@ -4,6 +4,7 @@
 //! Auto-generated design tokens from style-dictionary
 //! DO NOT EDIT MANUALLY
 
+use crate::Mode;
 use std::fmt;
 
 /// Color design tokens
@@ -903,6 +904,566 @@ pub enum ColorToken {
 }
 
 impl ColorToken {
+    /// All tokens in this group
+    pub const ALL: &'static [Self] = &[
+        Self::ColorPrimary50,
+        Self::ColorPrimary100,
+        Self::ColorPrimary200,
+        Self::ColorPrimary300,
+        Self::ColorPrimary400,
+        Self::ColorPrimary500,
+        Self::ColorPrimary600,
+        Self::ColorPrimary700,
+        Self::ColorPrimary800,
+        Self::ColorPrimary900,
+        Self::ColorPrimary1000,
+        Self::ColorNeutral50,
+        Self::ColorNeutral100,
+        Self::ColorNeutral150,
+        Self::ColorNeutral200,
+        Self::ColorNeutral250,
+        Self::ColorNeutral300,
+        Self::ColorNeutral350,
+        Self::ColorNeutral400,
+        Self::ColorNeutral450,
+        Self::ColorNeutral500,
+        Self::ColorNeutral550,
+        Self::ColorNeutral600,
+        Self::ColorNeutral650,
+        Self::ColorNeutral700,
+        Self::ColorNeutral750,
+        Self::ColorNeutral800,
+        Self::ColorNeutral850,
+        Self::ColorNeutral900,
+        Self::ColorNeutral950,
+        Self::ColorNeutral1000,
+        Self::ColorError50,
+        Self::ColorError400,
+        Self::ColorError600,
+        Self::ColorError900,
+        Self::ColorError1000,
+        Self::ColorSuccess50,
+        Self::ColorSuccess500,
+        Self::ColorSuccess600,
+        Self::ColorSuccess1000,
+        Self::ColorWarning50,
+        Self::ColorWarning400,
+        Self::ColorWarning500,
+        Self::ColorWarning900,
+        Self::ColorWarning1000,
+        Self::ColorInfo50,
+        Self::ColorInfo300,
+        Self::ColorInfo400,
+        Self::ColorInfo600,
+        Self::ColorInfo1000,
+        Self::ColorGrey50,
+        Self::ColorGrey100,
+        Self::ColorGrey150,
+        Self::ColorGrey200,
+        Self::ColorGrey250,
+        Self::ColorGrey300,
+        Self::ColorGrey350,
+        Self::ColorGrey400,
+        Self::ColorGrey450,
+        Self::ColorGrey500,
+        Self::ColorGrey600,
+        Self::ColorGrey650,
+        Self::ColorGrey700,
+        Self::ColorGrey750,
+        Self::ColorGrey800,
+        Self::ColorGrey850,
+        Self::ColorGrey900,
+        Self::ColorGrey950,
+        Self::ColorGrey1000,
+        Self::ColorBlue50,
+        Self::ColorBlue100,
+        Self::ColorBlue200,
+        Self::ColorBlue300,
+        Self::ColorBlue400,
+        Self::ColorBlue600,
+        Self::ColorBlue700,
+        Self::ColorBlue900,
+        Self::ColorBlue1000,
+        Self::ColorGreen50,
+        Self::ColorGreen500,
+        Self::ColorGreen600,
+        Self::ColorGreen900,
+        Self::ColorGreen1000,
+        Self::ColorRed50,
+        Self::ColorRed400,
+        Self::ColorRed600,
+        Self::ColorRed900,
+        Self::ColorRed1000,
+        Self::ColorYellow50,
+        Self::ColorYellow400,
+        Self::ColorYellow500,
+        Self::ColorYellow900,
+        Self::ColorYellow1000,
+        Self::ColorPurple400,
+        Self::ColorPurple700,
+        Self::ColorAmber400,
+        Self::ColorAmber500,
+        Self::ColorAwsSquidInk,
+        Self::ColorTransparent,
+        Self::ColorBlack,
+        Self::ColorWhite,
+        Self::ColorChartsRed300,
+        Self::ColorChartsRed400,
+        Self::ColorChartsRed500,
+        Self::ColorChartsRed600,
+        Self::ColorChartsRed700,
+        Self::ColorChartsRed800,
+        Self::ColorChartsRed900,
+        Self::ColorChartsRed1000,
+        Self::ColorChartsRed1100,
+        Self::ColorChartsRed1200,
+        Self::ColorChartsOrange300,
+        Self::ColorChartsOrange400,
+        Self::ColorChartsOrange500,
+        Self::ColorChartsOrange600,
+        Self::ColorChartsOrange700,
+        Self::ColorChartsOrange800,
+        Self::ColorChartsOrange900,
+        Self::ColorChartsOrange1000,
+        Self::ColorChartsOrange1100,
+        Self::ColorChartsOrange1200,
+        Self::ColorChartsYellow300,
+        Self::ColorChartsYellow400,
+        Self::ColorChartsYellow500,
+        Self::ColorChartsYellow600,
+        Self::ColorChartsYellow700,
+        Self::ColorChartsYellow800,
+        Self::ColorChartsYellow900,
+        Self::ColorChartsYellow1000,
+        Self::ColorChartsYellow1100,
+        Self::ColorChartsYellow1200,
+        Self::ColorChartsGreen300,
+        Self::ColorChartsGreen400,
+        Self::ColorChartsGreen500,
+        Self::ColorChartsGreen600,
+        Self::ColorChartsGreen700,
+        Self::ColorChartsGreen800,
+        Self::ColorChartsGreen900,
+        Self::ColorChartsGreen1000,
+        Self::ColorChartsGreen1100,
+        Self::ColorChartsGreen1200,
+        Self::ColorChartsTeal300,
+        Self::ColorChartsTeal400,
+        Self::ColorChartsTeal500,
+        Self::ColorChartsTeal600,
+        Self::ColorChartsTeal700,
+        Self::ColorChartsTeal800,
+        Self::ColorChartsTeal900,
+        Self::ColorChartsTeal1000,
+        Self::ColorChartsTeal1100,
+        Self::ColorChartsTeal1200,
+        Self::ColorChartsBlue1300,
+        Self::ColorChartsBlue1400,
+        Self::ColorChartsBlue1500,
+        Self::ColorChartsBlue1600,
+        Self::ColorChartsBlue1700,
+        Self::ColorChartsBlue1800,
+        Self::ColorChartsBlue1900,
+        Self::ColorChartsBlue11000,
+        Self::ColorChartsBlue11100,
+        Self::ColorChartsBlue11200,
+        Self::ColorChartsBlue2300,
+        Self::ColorChartsBlue2400,
+        Self::ColorChartsBlue2500,
+        Self::ColorChartsBlue2600,
+        Self::ColorChartsBlue2700,
+        Self::ColorChartsBlue2800,
+        Self::ColorChartsBlue2900,
+        Self::ColorChartsBlue21000,
+        Self::ColorChartsBlue21100,
+        Self::ColorChartsBlue21200,
+        Self::ColorChartsPurple300,
+        Self::ColorChartsPurple400,
+        Self::ColorChartsPurple500,
+        Self::ColorChartsPurple600,
+        Self::ColorChartsPurple700,
+        Self::ColorChartsPurple800,
+        Self::ColorChartsPurple900,
+        Self::ColorChartsPurple1000,
+        Self::ColorChartsPurple1100,
+        Self::ColorChartsPurple1200,
+        Self::ColorChartsPink300,
+        Self::ColorChartsPink400,
+        Self::ColorChartsPink500,
+        Self::ColorChartsPink600,
+        Self::ColorChartsPink700,
+        Self::ColorChartsPink800,
+        Self::ColorChartsPink900,
+        Self::ColorChartsPink1000,
+        Self::ColorChartsPink1100,
+        Self::ColorChartsPink1200,
+        Self::ColorChartsStatusCritical,
+        Self::ColorChartsStatusHigh,
+        Self::ColorChartsStatusMedium,
+        Self::ColorChartsStatusLow,
+        Self::ColorChartsStatusPositive,
+        Self::ColorChartsStatusInfo,
+        Self::ColorChartsStatusNeutral,
+        Self::ColorChartsThresholdNegative,
+        Self::ColorChartsThresholdPositive,
+        Self::ColorChartsThresholdInfo,
+        Self::ColorChartsThresholdNeutral,
+        Self::ColorChartsLineGrid,
+        Self::ColorChartsLineTick,
+        Self::ColorChartsLineAxis,
+        Self::ColorChartsPaletteCategorical1,
+        Self::ColorChartsPaletteCategorical2,
+        Self::ColorChartsPaletteCategorical3,
+        Self::ColorChartsPaletteCategorical4,
+        Self::ColorChartsPaletteCategorical5,
+        Self::ColorChartsPaletteCategorical6,
+        Self::ColorChartsPaletteCategorical7,
+        Self::ColorChartsPaletteCategorical8,
+        Self::ColorChartsPaletteCategorical9,
+        Self::ColorChartsPaletteCategorical10,
+        Self::ColorChartsPaletteCategorical11,
+        Self::ColorChartsPaletteCategorical12,
+        Self::ColorChartsPaletteCategorical13,
+        Self::ColorChartsPaletteCategorical14,
+        Self::ColorChartsPaletteCategorical15,
+        Self::ColorChartsPaletteCategorical16,
+        Self::ColorChartsPaletteCategorical17,
+        Self::ColorChartsPaletteCategorical18,
+        Self::ColorChartsPaletteCategorical19,
+        Self::ColorChartsPaletteCategorical20,
+        Self::ColorChartsPaletteCategorical21,
+        Self::ColorChartsPaletteCategorical22,
+        Self::ColorChartsPaletteCategorical23,
+        Self::ColorChartsPaletteCategorical24,
+        Self::ColorChartsPaletteCategorical25,
+        Self::ColorChartsPaletteCategorical26,
+        Self::ColorChartsPaletteCategorical27,
+        Self::ColorChartsPaletteCategorical28,
+        Self::ColorChartsPaletteCategorical29,
+        Self::ColorChartsPaletteCategorical30,
+        Self::ColorChartsPaletteCategorical31,
+        Self::ColorChartsPaletteCategorical32,
+        Self::ColorChartsPaletteCategorical33,
+        Self::ColorChartsPaletteCategorical34,
+        Self::ColorChartsPaletteCategorical35,
+        Self::ColorChartsPaletteCategorical36,
+        Self::ColorChartsPaletteCategorical37,
+        Self::ColorChartsPaletteCategorical38,
+        Self::ColorChartsPaletteCategorical39,
+        Self::ColorChartsPaletteCategorical40,
+        Self::ColorChartsPaletteCategorical41,
+        Self::ColorChartsPaletteCategorical42,
+        Self::ColorChartsPaletteCategorical43,
+        Self::ColorChartsPaletteCategorical44,
+        Self::ColorChartsPaletteCategorical45,
+        Self::ColorChartsPaletteCategorical46,
+        Self::ColorChartsPaletteCategorical47,
+        Self::ColorChartsPaletteCategorical48,
+        Self::ColorChartsPaletteCategorical49,
+        Self::ColorChartsPaletteCategorical50,
+        Self::ColorChartsErrorBarMarker,
+        Self::ColorSeverityDarkRed,
+        Self::ColorSeverityRed,
+        Self::ColorSeverityOrange,
+        Self::ColorSeverityYellow,
+        Self::ColorSeverityGrey,
+        Self::ColorBackgroundNotificationSeverityCritical,
+        Self::ColorBackgroundNotificationSeverityHigh,
+        Self::ColorBackgroundNotificationSeverityMedium,
+        Self::ColorBackgroundNotificationSeverityLow,
+        Self::ColorBackgroundNotificationSeverityNeutral,
+        Self::ColorTextNotificationSeverityCritical,
+        Self::ColorTextNotificationSeverityHigh,
+        Self::ColorTextNotificationSeverityMedium,
+        Self::ColorTextNotificationSeverityLow,
+        Self::ColorTextNotificationSeverityNeutral,
+        Self::ColorGreyOpaque10,
+        Self::ColorGreyOpaque25,
+        Self::ColorGreyOpaque40,
+        Self::ColorGreyOpaque50,
+        Self::ColorGreyOpaque70,
+        Self::ColorGreyOpaque80,
+        Self::ColorGreyOpaque90,
+        Self::ColorGreyTransparent,
+        Self::ColorGreyTransparentHeavy,
+        Self::ColorGreyTransparentLight,
+        Self::ColorBackgroundBadgeIcon,
+        Self::ColorBackgroundButtonLinkActive,
+        Self::ColorBackgroundButtonLinkHover,
+        Self::ColorBackgroundButtonNormalActive,
+        Self::ColorBackgroundButtonNormalDefault,
+        Self::ColorBackgroundButtonNormalDisabled,
+        Self::ColorBackgroundButtonNormalHover,
+        Self::ColorBackgroundToggleButtonNormalPressed,
+        Self::ColorBackgroundButtonPrimaryActive,
+        Self::ColorBackgroundButtonPrimaryDefault,
+        Self::ColorBackgroundButtonPrimaryDisabled,
+        Self::ColorBackgroundButtonPrimaryHover,
+        Self::ColorBackgroundDirectionButtonActive,
+        Self::ColorBackgroundDirectionButtonDefault,
+        Self::ColorBackgroundDirectionButtonDisabled,
+        Self::ColorBackgroundDirectionButtonHover,
+        Self::ColorTextDirectionButtonDefault,
+        Self::ColorTextDirectionButtonDisabled,
+        Self::ColorBackgroundCalendarCurrentDate,
+        Self::ColorBackgroundCellShaded,
+        Self::ColorBackgroundCodeEditorGutterActiveLineDefault,
+        Self::ColorBackgroundCodeEditorGutterActiveLineError,
+        Self::ColorBackgroundCodeEditorGutterDefault,
+        Self::ColorBackgroundCodeEditorLoading,
+        Self::ColorBackgroundCodeEditorPaneItemHover,
+        Self::ColorBackgroundCodeEditorStatusBar,
+        Self::ColorBackgroundContainerContent,
+        Self::ColorBackgroundContainerHeader,
+        Self::ColorBackgroundControlChecked,
+        Self::ColorBackgroundControlDefault,
+        Self::ColorBackgroundControlDisabled,
+        Self::ColorBackgroundDropdownItemDefault,
+        Self::ColorBackgroundDropdownItemDimmed,
+        Self::ColorBackgroundDropdownItemFilterMatch,
+        Self::ColorBackgroundDropdownItemHover,
+        Self::ColorBackgroundDropdownItemSelected,
+        Self::ColorBackgroundHomeHeader,
+        Self::ColorBackgroundInlineCode,
+        Self::ColorBackgroundInputDefault,
+        Self::ColorBackgroundInputDisabled,
+        Self::ColorBackgroundItemSelected,
+        Self::ColorBackgroundLayoutMain,
+        Self::ColorBackgroundLayoutMobilePanel,
+        Self::ColorBackgroundLayoutPanelContent,
+        Self::ColorBackgroundLayoutPanelHover,
+        Self::ColorBackgroundLayoutToggleActive,
+        Self::ColorBackgroundLayoutToggleDefault,
+        Self::ColorBackgroundLayoutToggleHover,
+        Self::ColorBackgroundLayoutToggleSelectedActive,
+        Self::ColorBackgroundLayoutToggleSelectedDefault,
+        Self::ColorBackgroundLayoutToggleSelectedHover,
+        Self::ColorBackgroundModalOverlay,
+        Self::ColorBackgroundNotificationBlue,
+        Self::ColorBackgroundNotificationGreen,
+        Self::ColorBackgroundNotificationGrey,
+        Self::ColorBackgroundNotificationRed,
+        Self::ColorBackgroundNotificationYellow,
+        Self::ColorBackgroundNotificationStackBar,
+        Self::ColorBackgroundNotificationStackBarActive,
+        Self::ColorBackgroundNotificationStackBarHover,
+        Self::ColorBackgroundPopover,
+        Self::ColorBackgroundProgressBarContentDefault,
+        Self::ColorBackgroundProgressBarContentInFlash,
+        Self::ColorBackgroundProgressBarLayoutDefault,
+        Self::ColorBackgroundProgressBarLayoutInFlash,
+        Self::ColorBackgroundSegmentActive,
+        Self::ColorBackgroundSegmentDefault,
+        Self::ColorBackgroundSegmentDisabled,
+        Self::ColorBackgroundSegmentHover,
+        Self::ColorBackgroundSegmentWrapper,
+        Self::ColorBackgroundSliderRangeDefault,
+        Self::ColorBackgroundSliderRangeActive,
+        Self::ColorBackgroundSliderHandleDefault,
+        Self::ColorBackgroundSliderHandleActive,
+        Self::ColorBackgroundSliderTrackDefault,
+        Self::ColorBackgroundSliderHandleRing,
+        Self::ColorBackgroundSliderHandleErrorDefault,
+        Self::ColorBackgroundSliderHandleErrorActive,
+        Self::ColorBackgroundSliderHandleWarningDefault,
+        Self::ColorBackgroundSliderHandleWarningActive,
+        Self::ColorBackgroundSliderRangeErrorDefault,
+        Self::ColorBackgroundSliderRangeErrorActive,
+        Self::ColorBackgroundSliderRangeWarningDefault,
+        Self::ColorBackgroundSliderRangeWarningActive,
+        Self::ColorBackgroundStatusError,
+        Self::ColorBackgroundStatusInfo,
+        Self::ColorBackgroundDialog,
+        Self::ColorBackgroundStatusSuccess,
+        Self::ColorBackgroundStatusWarning,
+        Self::ColorBackgroundTableHeader,
+        Self::ColorBackgroundTilesDisabled,
+        Self::ColorBackgroundToggleCheckedDisabled,
+        Self::ColorBackgroundToggleDefault,
+        Self::ColorBackgroundAvatarGenAi,
+        Self::ColorBackgroundAvatarDefault,
+        Self::ColorTextAvatar,
+        Self::ColorBackgroundLoadingBarGenAi,
+        Self::ColorBackgroundChatBubbleOutgoing,
+        Self::ColorBackgroundChatBubbleIncoming,
+        Self::ColorTextChatBubbleOutgoing,
+        Self::ColorTextChatBubbleIncoming,
+        Self::ColorBorderButtonNormalActive,
+        Self::ColorBorderButtonNormalDefault,
+        Self::ColorBorderToggleButtonNormalPressed,
+        Self::ColorBorderButtonNormalDisabled,
+        Self::ColorTextButtonNormalDisabled,
+        Self::ColorBorderButtonNormalHover,
+        Self::ColorTextButtonIconDisabled,
+        Self::ColorBorderButtonPrimaryDisabled,
+        Self::ColorTextButtonPrimaryDisabled,
+        Self::ColorItemSelected,
+        Self::ColorBorderCalendarGrid,
+        Self::ColorBorderCalendarGridSelectedFocusRing,
+        Self::ColorBorderCellShaded,
+        Self::ColorBorderCodeEditorAceActiveLineLightTheme,
+        Self::ColorBorderCodeEditorAceActiveLineDarkTheme,
+        Self::ColorBorderCodeEditorDefault,
+        Self::ColorBorderCodeEditorPaneItemHover,
+        Self::ColorBorderContainerDivider,
+        Self::ColorBorderContainerTop,
+        Self::ColorBorderControlChecked,
+        Self::ColorBorderControlDefault,
+        Self::ColorBorderControlDisabled,
+        Self::ColorBorderDividerActive,
+        Self::ColorBorderDividerDefault,
+        Self::ColorBorderDividerPanelBottom,
+        Self::ColorBorderDividerPanelSide,
+        Self::ColorBorderDividerSecondary,
+        Self::ColorBorderDropdownContainer,
+        Self::ColorBorderDropdownGroup,
+        Self::ColorBorderDropdownItemDefault,
+        Self::ColorBorderDropdownItemHover,
+        Self::ColorBorderDropdownItemDimmedHover,
+        Self::ColorBorderDropdownItemSelected,
+        Self::ColorBorderDropdownItemTop,
+        Self::ColorBorderEditableCellHover,
+        Self::ColorBorderInputDefault,
+        Self::ColorBorderInputDisabled,
+        Self::ColorBorderInputFocused,
+        Self::ColorBorderItemFocused,
+        Self::ColorBorderDropdownItemFocused,
+        Self::ColorBorderItemPlaceholder,
+        Self::ColorBorderItemSelected,
+        Self::ColorBorderLayout,
+        Self::ColorBorderNotificationStackBar,
+        Self::ColorBorderPanelHeader,
+        Self::ColorBorderPopover,
+        Self::ColorBorderSegmentActive,
+        Self::ColorBorderSegmentDefault,
+        Self::ColorBorderSegmentDisabled,
+        Self::ColorBorderSegmentHover,
+        Self::ColorBorderStatusError,
+        Self::ColorBorderStatusInfo,
+        Self::ColorBorderStatusSuccess,
+        Self::ColorBorderStatusWarning,
+        Self::ColorBorderDialog,
+        Self::ColorBorderDividerInteractiveDefault,
+        Self::ColorBorderTabsDivider,
+        Self::ColorBorderTabsShadow,
+        Self::ColorBorderTabsUnderline,
+        Self::ColorBorderTilesDisabled,
+        Self::ColorBorderTutorial,
+        Self::ColorForegroundControlDefault,
+        Self::ColorForegroundControlDisabled,
+        Self::ColorForegroundControlReadOnly,
+        Self::ColorShadowDefault,
+        Self::ColorShadowMedium,
+        Self::ColorShadowSide,
+        Self::ColorStrokeChartLine,
+        Self::ColorStrokeCodeEditorGutterActiveLineDefault,
+        Self::ColorStrokeCodeEditorGutterActiveLineHover,
+        Self::ColorTextAccent,
+        Self::ColorTextBodyDefault,
+        Self::ColorTextBodySecondary,
+        Self::ColorTextBreadcrumbCurrent,
+        Self::ColorTextBreadcrumbIcon,
+        Self::ColorTextButtonInlineIconDefault,
+        Self::ColorTextButtonInlineIconDisabled,
+        Self::ColorTextButtonInlineIconHover,
+        Self::ColorTextButtonNormalActive,
+        Self::ColorTextToggleButtonNormalPressed,
+        Self::ColorTextButtonNormalDefault,
+        Self::ColorTextButtonNormalHover,
+        Self::ColorTextLinkButtonNormalDefault,
+        Self::ColorTextLinkButtonNormalHover,
+        Self::ColorTextLinkButtonNormalActive,
+        Self::ColorTextButtonPrimaryActive,
+        Self::ColorTextButtonPrimaryDefault,
+        Self::ColorTextButtonPrimaryHover,
+        Self::ColorTextCalendarDateHover,
+        Self::ColorTextCalendarMonth,
+        Self::ColorTextCodeEditorGutterActiveLine,
+        Self::ColorTextCodeEditorGutterDefault,
+        Self::ColorTextCodeEditorStatusBarDisabled,
+        Self::ColorTextCodeEditorTabButtonError,
+        Self::ColorTextColumnHeader,
+        Self::ColorTextColumnSortingIcon,
+        Self::ColorTextControlDisabled,
+        Self::ColorTextCounter,
+        Self::ColorTextDisabled,
+        Self::ColorTextDisabledInlineEdit,
+        Self::ColorTextDropdownFooter,
+        Self::ColorTextDropdownGroupLabel,
+        Self::ColorTextDropdownItemDefault,
+        Self::ColorTextDropdownItemDimmed,
+        Self::ColorTextDropdownItemDisabled,
+        Self::ColorTextDropdownItemFilterMatch,
+        Self::ColorTextDropdownItemHighlighted,
+        Self::ColorTextDropdownItemSecondary,
+        Self::ColorTextDropdownItemSecondaryHover,
+        Self::ColorTextEmpty,
+        Self::ColorTextExpandableSectionDefault,
+        Self::ColorTextExpandableSectionHover,
+        Self::ColorTextExpandableSectionNavigationIconDefault,
+        Self::ColorTextFormDefault,
+        Self::ColorTextFormLabel,
+        Self::ColorTextFormSecondary,
+        Self::ColorTextGroupLabel,
+        Self::ColorTextLabelGenAi,
+        Self::ColorTextHeadingDefault,
+        Self::ColorTextHeadingSecondary,
+        Self::ColorTextHomeHeaderDefault,
+        Self::ColorTextHomeHeaderSecondary,
+        Self::ColorTextIconCaret,
+        Self::ColorTextIconSubtle,
+        Self::ColorTextInputDisabled,
+        Self::ColorTextInputPlaceholder,
+        Self::ColorTextInputPlaceholderDisabled,
+        Self::ColorTextInteractiveActive,
+        Self::ColorTextInteractiveDefault,
+        Self::ColorTextInteractiveDisabled,
+        Self::ColorTextInteractiveHover,
+        Self::ColorTextToggleButtonIconPressed,
+        Self::ColorTextInteractiveInvertedDefault,
+        Self::ColorTextInteractiveInvertedHover,
+        Self::ColorTextInverted,
+        Self::ColorTextLabel,
+        Self::ColorTextLayoutToggle,
+        Self::ColorTextLayoutToggleActive,
+        Self::ColorTextLayoutToggleHover,
+        Self::ColorTextLayoutToggleSelected,
+        Self::ColorTextLinkDefault,
+        Self::ColorTextLinkHover,
+        Self::ColorTextLinkInvertedHover,
+        Self::ColorTextLinkButtonUnderline,
+        Self::ColorTextLinkButtonUnderlineHover,
+        Self::ColorTextNotificationDefault,
+        Self::ColorTextNotificationStackBar,
+        Self::ColorTextNotificationYellow,
+        Self::ColorTextPaginationPageNumberActiveDisabled,
+        Self::ColorTextPaginationPageNumberDefault,
+        Self::ColorTextSegmentActive,
+        Self::ColorTextSegmentDefault,
+        Self::ColorTextSegmentHover,
+        Self::ColorTextSmall,
+        Self::ColorTextStatusError,
+        Self::ColorTextStatusInactive,
+        Self::ColorTextStatusInfo,
+        Self::ColorTextStatusSuccess,
+        Self::ColorTextStatusWarning,
+        Self::ColorTextTopNavigationTitle,
+        Self::ColorTextTutorialHotspotDefault,
+        Self::ColorTextTutorialHotspotHover,
+        Self::ColorBoardPlaceholderActive,
+        Self::ColorBoardPlaceholderHover,
+        Self::ColorDragPlaceholderActive,
+        Self::ColorDragPlaceholderHover,
+        Self::ColorDropzoneBackgroundDefault,
+        Self::ColorDropzoneBackgroundHover,
+        Self::ColorDropzoneTextDefault,
+        Self::ColorDropzoneTextHover,
+        Self::ColorDropzoneBorderDefault,
+        Self::ColorDropzoneBorderHover,
+        Self::ColorGapGlobalDrawer,
+        Self::ColorTreeViewConnectorLine,
+    ];
+
     /// Get the CSS custom property name for this token
     pub fn css_var_name(&self) -> &'static str {
         match self {
@@ -1167,16 +1728,36 @@ impl ColorToken {
             Self::ColorSeverityOrange => "--awsui-color-severity-orange",
             Self::ColorSeverityYellow => "--awsui-color-severity-yellow",
             Self::ColorSeverityGrey => "--awsui-color-severity-grey",
-            Self::ColorBackgroundNotificationSeverityCritical => "--awsui-color-background-notification-severity-critical",
-            Self::ColorBackgroundNotificationSeverityHigh => "--awsui-color-background-notification-severity-high",
-            Self::ColorBackgroundNotificationSeverityMedium => "--awsui-color-background-notification-severity-medium",
-            Self::ColorBackgroundNotificationSeverityLow => "--awsui-color-background-notification-severity-low",
-            Self::ColorBackgroundNotificationSeverityNeutral => "--awsui-color-background-notification-severity-neutral",
-            Self::ColorTextNotificationSeverityCritical => "--awsui-color-text-notification-severity-critical",
-            Self::ColorTextNotificationSeverityHigh => "--awsui-color-text-notification-severity-high",
-            Self::ColorTextNotificationSeverityMedium => "--awsui-color-text-notification-severity-medium",
-            Self::ColorTextNotificationSeverityLow => "--awsui-color-text-notification-severity-low",
-            Self::ColorTextNotificationSeverityNeutral => "--awsui-color-text-notification-severity-neutral",
+            Self::ColorBackgroundNotificationSeverityCritical => {
+                "--awsui-color-background-notification-severity-critical"
+            }
+            Self::ColorBackgroundNotificationSeverityHigh => {
+                "--awsui-color-background-notification-severity-high"
+            }
+            Self::ColorBackgroundNotificationSeverityMedium => {
+                "--awsui-color-background-notification-severity-medium"
+            }
+            Self::ColorBackgroundNotificationSeverityLow => {
+                "--awsui-color-background-notification-severity-low"
+            }
+            Self::ColorBackgroundNotificationSeverityNeutral => {
+                "--awsui-color-background-notification-severity-neutral"
+            }
+            Self::ColorTextNotificationSeverityCritical => {
+                "--awsui-color-text-notification-severity-critical"
+            }
+            Self::ColorTextNotificationSeverityHigh => {
+                "--awsui-color-text-notification-severity-high"
+            }
+            Self::ColorTextNotificationSeverityMedium => {
+                "--awsui-color-text-notification-severity-medium"
+            }
+            Self::ColorTextNotificationSeverityLow => {
+                "--awsui-color-text-notification-severity-low"
+            }
+            Self::ColorTextNotificationSeverityNeutral => {
+                "--awsui-color-text-notification-severity-neutral"
+            }
             Self::ColorGreyOpaque10 => "--awsui-color-grey-opaque10",
             Self::ColorGreyOpaque25 => "--awsui-color-grey-opaque25",
             Self::ColorGreyOpaque40 => "--awsui-color-grey-opaque40",
@@ -1190,87 +1771,197 @@ impl ColorToken {
             Self::ColorBackgroundBadgeIcon => "--awsui-color-background-badge-icon",
             Self::ColorBackgroundButtonLinkActive => "--awsui-color-background-button-link-active",
             Self::ColorBackgroundButtonLinkHover => "--awsui-color-background-button-link-hover",
-            Self::ColorBackgroundButtonNormalActive => "--awsui-color-background-button-normal-active",
-            Self::ColorBackgroundButtonNormalDefault => "--awsui-color-background-button-normal-default",
-            Self::ColorBackgroundButtonNormalDisabled => "--awsui-color-background-button-normal-disabled",
-            Self::ColorBackgroundButtonNormalHover => "--awsui-color-background-button-normal-hover",
-            Self::ColorBackgroundToggleButtonNormalPressed => "--awsui-color-background-toggle-button-normal-pressed",
-            Self::ColorBackgroundButtonPrimaryActive => "--awsui-color-background-button-primary-active",
-            Self::ColorBackgroundButtonPrimaryDefault => "--awsui-color-background-button-primary-default",
-            Self::ColorBackgroundButtonPrimaryDisabled => "--awsui-color-background-button-primary-disabled",
-            Self::ColorBackgroundButtonPrimaryHover => "--awsui-color-background-button-primary-hover",
-            Self::ColorBackgroundDirectionButtonActive => "--awsui-color-background-direction-button-active",
-            Self::ColorBackgroundDirectionButtonDefault => "--awsui-color-background-direction-button-default",
-            Self::ColorBackgroundDirectionButtonDisabled => "--awsui-color-background-direction-button-disabled",
-            Self::ColorBackgroundDirectionButtonHover => "--awsui-color-background-direction-button-hover",
+            Self::ColorBackgroundButtonNormalActive => {
+                "--awsui-color-background-button-normal-active"
+            }
+            Self::ColorBackgroundButtonNormalDefault => {
+                "--awsui-color-background-button-normal-default"
+            }
+            Self::ColorBackgroundButtonNormalDisabled => {
+                "--awsui-color-background-button-normal-disabled"
+            }
+            Self::ColorBackgroundButtonNormalHover => {
+                "--awsui-color-background-button-normal-hover"
+            }
+            Self::ColorBackgroundToggleButtonNormalPressed => {
+                "--awsui-color-background-toggle-button-normal-pressed"
+            }
+            Self::ColorBackgroundButtonPrimaryActive => {
+                "--awsui-color-background-button-primary-active"
+            }
+            Self::ColorBackgroundButtonPrimaryDefault => {
+                "--awsui-color-background-button-primary-default"
+            }
+            Self::ColorBackgroundButtonPrimaryDisabled => {
+                "--awsui-color-background-button-primary-disabled"
+            }
+            Self::ColorBackgroundButtonPrimaryHover => {
+                "--awsui-color-background-button-primary-hover"
+            }
+            Self::ColorBackgroundDirectionButtonActive => {
+                "--awsui-color-background-direction-button-active"
+            }
+            Self::ColorBackgroundDirectionButtonDefault => {
+                "--awsui-color-background-direction-button-default"
+            }
+            Self::ColorBackgroundDirectionButtonDisabled => {
+                "--awsui-color-background-direction-button-disabled"
+            }
+            Self::ColorBackgroundDirectionButtonHover => {
+                "--awsui-color-background-direction-button-hover"
+            }
             Self::ColorTextDirectionButtonDefault => "--awsui-color-text-direction-button-default",
-            Self::ColorTextDirectionButtonDisabled => "--awsui-color-text-direction-button-disabled",
-            Self::ColorBackgroundCalendarCurrentDate => "--awsui-color-background-calendar-current-date",
+            Self::ColorTextDirectionButtonDisabled => {
+                "--awsui-color-text-direction-button-disabled"
+            }
+            Self::ColorBackgroundCalendarCurrentDate => {
+                "--awsui-color-background-calendar-current-date"
+            }
             Self::ColorBackgroundCellShaded => "--awsui-color-background-cell-shaded",
-            Self::ColorBackgroundCodeEditorGutterActiveLineDefault => "--awsui-color-background-code-editor-gutter-active-line-default",
-            Self::ColorBackgroundCodeEditorGutterActiveLineError => "--awsui-color-background-code-editor-gutter-active-line-error",
-            Self::ColorBackgroundCodeEditorGutterDefault => "--awsui-color-background-code-editor-gutter-default",
-            Self::ColorBackgroundCodeEditorLoading => "--awsui-color-background-code-editor-loading",
-            Self::ColorBackgroundCodeEditorPaneItemHover => "--awsui-color-background-code-editor-pane-item-hover",
-            Self::ColorBackgroundCodeEditorStatusBar => "--awsui-color-background-code-editor-status-bar",
+            Self::ColorBackgroundCodeEditorGutterActiveLineDefault => {
+                "--awsui-color-background-code-editor-gutter-active-line-default"
+            }
+            Self::ColorBackgroundCodeEditorGutterActiveLineError => {
+                "--awsui-color-background-code-editor-gutter-active-line-error"
+            }
+            Self::ColorBackgroundCodeEditorGutterDefault => {
+                "--awsui-color-background-code-editor-gutter-default"
+            }
+            Self::ColorBackgroundCodeEditorLoading => {
+                "--awsui-color-background-code-editor-loading"
+            }
+            Self::ColorBackgroundCodeEditorPaneItemHover => {
+                "--awsui-color-background-code-editor-pane-item-hover"
+            }
+            Self::ColorBackgroundCodeEditorStatusBar => {
+                "--awsui-color-background-code-editor-status-bar"
+            }
             Self::ColorBackgroundContainerContent => "--awsui-color-background-container-content",
             Self::ColorBackgroundContainerHeader => "--awsui-color-background-container-header",
             Self::ColorBackgroundControlChecked => "--awsui-color-background-control-checked",
             Self::ColorBackgroundControlDefault => "--awsui-color-background-control-default",
             Self::ColorBackgroundControlDisabled => "--awsui-color-background-control-disabled",
-            Self::ColorBackgroundDropdownItemDefault => "--awsui-color-background-dropdown-item-default",
-            Self::ColorBackgroundDropdownItemDimmed => "--awsui-color-background-dropdown-item-dimmed",
-            Self::ColorBackgroundDropdownItemFilterMatch => "--awsui-color-background-dropdown-item-filter-match",
-            Self::ColorBackgroundDropdownItemHover => "--awsui-color-background-dropdown-item-hover",
-            Self::ColorBackgroundDropdownItemSelected => "--awsui-color-background-dropdown-item-selected",
+            Self::ColorBackgroundDropdownItemDefault => {
+                "--awsui-color-background-dropdown-item-default"
+            }
+            Self::ColorBackgroundDropdownItemDimmed => {
+                "--awsui-color-background-dropdown-item-dimmed"
+            }
+            Self::ColorBackgroundDropdownItemFilterMatch => {
+                "--awsui-color-background-dropdown-item-filter-match"
+            }
+            Self::ColorBackgroundDropdownItemHover => {
+                "--awsui-color-background-dropdown-item-hover"
+            }
+            Self::ColorBackgroundDropdownItemSelected => {
+                "--awsui-color-background-dropdown-item-selected"
+            }
             Self::ColorBackgroundHomeHeader => "--awsui-color-background-home-header",
             Self::ColorBackgroundInlineCode => "--awsui-color-background-inline-code",
             Self::ColorBackgroundInputDefault => "--awsui-color-background-input-default",
             Self::ColorBackgroundInputDisabled => "--awsui-color-background-input-disabled",
             Self::ColorBackgroundItemSelected => "--awsui-color-background-item-selected",
             Self::ColorBackgroundLayoutMain => "--awsui-color-background-layout-main",
-            Self::ColorBackgroundLayoutMobilePanel => "--awsui-color-background-layout-mobile-panel",
-            Self::ColorBackgroundLayoutPanelContent => "--awsui-color-background-layout-panel-content",
+            Self::ColorBackgroundLayoutMobilePanel => {
+                "--awsui-color-background-layout-mobile-panel"
+            }
+            Self::ColorBackgroundLayoutPanelContent => {
+                "--awsui-color-background-layout-panel-content"
+            }
             Self::ColorBackgroundLayoutPanelHover => "--awsui-color-background-layout-panel-hover",
-            Self::ColorBackgroundLayoutToggleActive => "--awsui-color-background-layout-toggle-active",
-            Self::ColorBackgroundLayoutToggleDefault => "--awsui-color-background-layout-toggle-default",
-            Self::ColorBackgroundLayoutToggleHover => "--awsui-color-background-layout-toggle-hover",
-            Self::ColorBackgroundLayoutToggleSelectedActive => "--awsui-color-background-layout-toggle-selected-active",
-            Self::ColorBackgroundLayoutToggleSelectedDefault => "--awsui-color-background-layout-toggle-selected-default",
-            Self::ColorBackgroundLayoutToggleSelectedHover => "--awsui-color-background-layout-toggle-selected-hover",
+            Self::ColorBackgroundLayoutToggleActive => {
+                "--awsui-color-background-layout-toggle-active"
+            }
+            Self::ColorBackgroundLayoutToggleDefault => {
+                "--awsui-color-background-layout-toggle-default"
+            }
+            Self::ColorBackgroundLayoutToggleHover => {
+                "--awsui-color-background-layout-toggle-hover"
+            }
+            Self::ColorBackgroundLayoutToggleSelectedActive => {
+                "--awsui-color-background-layout-toggle-selected-active"
+            }
+            Self::ColorBackgroundLayoutToggleSelectedDefault => {
+                "--awsui-color-background-layout-toggle-selected-default"
+            }
+            Self::ColorBackgroundLayoutToggleSelectedHover => {
+                "--awsui-color-background-layout-toggle-selected-hover"
+            }
             Self::ColorBackgroundModalOverlay => "--awsui-color-background-modal-overlay",
             Self::ColorBackgroundNotificationBlue => "--awsui-color-background-notification-blue",
             Self::ColorBackgroundNotificationGreen => "--awsui-color-background-notification-green",
             Self::ColorBackgroundNotificationGrey => "--awsui-color-background-notification-grey",
             Self::ColorBackgroundNotificationRed => "--awsui-color-background-notification-red",
-            Self::ColorBackgroundNotificationYellow => "--awsui-color-background-notification-yellow",
-            Self::ColorBackgroundNotificationStackBar => "--awsui-color-background-notification-stack-bar",
-            Self::ColorBackgroundNotificationStackBarActive => "--awsui-color-background-notification-stack-bar-active",
-            Self::ColorBackgroundNotificationStackBarHover => "--awsui-color-background-notification-stack-bar-hover",
+            Self::ColorBackgroundNotificationYellow => {
+                "--awsui-color-background-notification-yellow"
+            }
+            Self::ColorBackgroundNotificationStackBar => {
+                "--awsui-color-background-notification-stack-bar"
+            }
+            Self::ColorBackgroundNotificationStackBarActive => {
+                "--awsui-color-background-notification-stack-bar-active"
+            }
+            Self::ColorBackgroundNotificationStackBarHover => {
+                "--awsui-color-background-notification-stack-bar-hover"
+            }
             Self::ColorBackgroundPopover => "--awsui-color-background-popover",
-            Self::ColorBackgroundProgressBarContentDefault => "--awsui-color-background-progress-bar-content-default",
-            Self::ColorBackgroundProgressBarContentInFlash => "--awsui-color-background-progress-bar-content-in-flash",
-            Self::ColorBackgroundProgressBarLayoutDefault => "--awsui-color-background-progress-bar-layout-default",
-            Self::ColorBackgroundProgressBarLayoutInFlash => "--awsui-color-background-progress-bar-layout-in-flash",
+            Self::ColorBackgroundProgressBarContentDefault => {
+                "--awsui-color-background-progress-bar-content-default"
+            }
+            Self::ColorBackgroundProgressBarContentInFlash => {
+                "--awsui-color-background-progress-bar-content-in-flash"
+            }
+            Self::ColorBackgroundProgressBarLayoutDefault => {
+                "--awsui-color-background-progress-bar-layout-default"
+            }
+            Self::ColorBackgroundProgressBarLayoutInFlash => {
+                "--awsui-color-background-progress-bar-layout-in-flash"
+            }
             Self::ColorBackgroundSegmentActive => "--awsui-color-background-segment-active",
             Self::ColorBackgroundSegmentDefault => "--awsui-color-background-segment-default",
             Self::ColorBackgroundSegmentDisabled => "--awsui-color-background-segment-disabled",
             Self::ColorBackgroundSegmentHover => "--awsui-color-background-segment-hover",
             Self::ColorBackgroundSegmentWrapper => "--awsui-color-background-segment-wrapper",
-            Self::ColorBackgroundSliderRangeDefault => "--awsui-color-background-slider-range-default",
-            Self::ColorBackgroundSliderRangeActive => "--awsui-color-background-slider-range-active",
-            Self::ColorBackgroundSliderHandleDefault => "--awsui-color-background-slider-handle-default",
-            Self::ColorBackgroundSliderHandleActive => "--awsui-color-background-slider-handle-active",
-            Self::ColorBackgroundSliderTrackDefault => "--awsui-color-background-slider-track-default",
+            Self::ColorBackgroundSliderRangeDefault => {
+                "--awsui-color-background-slider-range-default"
+            }
+            Self::ColorBackgroundSliderRangeActive => {
+                "--awsui-color-background-slider-range-active"
+            }
+            Self::ColorBackgroundSliderHandleDefault => {
+                "--awsui-color-background-slider-handle-default"
+            }
+            Self::ColorBackgroundSliderHandleActive => {
+                "--awsui-color-background-slider-handle-active"
+            }
+            Self::ColorBackgroundSliderTrackDefault => {
+                "--awsui-color-background-slider-track-default"
+            }
             Self::ColorBackgroundSliderHandleRing => "--awsui-color-background-slider-handle-ring",
-            Self::ColorBackgroundSliderHandleErrorDefault => "--awsui-color-background-slider-handle-error-default",
-            Self::ColorBackgroundSliderHandleErrorActive => "--awsui-color-background-slider-handle-error-active",
-            Self::ColorBackgroundSliderHandleWarningDefault => "--awsui-color-background-slider-handle-warning-default",
-            Self::ColorBackgroundSliderHandleWarningActive => "--awsui-color-background-slider-handle-warning-active",
-            Self::ColorBackgroundSliderRangeErrorDefault => "--awsui-color-background-slider-range-error-default",
-            Self::ColorBackgroundSliderRangeErrorActive => "--awsui-color-background-slider-range-error-active",
-            Self::ColorBackgroundSliderRangeWarningDefault => "--awsui-color-background-slider-range-warning-default",
-            Self::ColorBackgroundSliderRangeWarningActive => "--awsui-color-background-slider-range-warning-active",
+            Self::ColorBackgroundSliderHandleErrorDefault => {
+                "--awsui-color-background-slider-handle-error-default"
+            }
+            Self::ColorBackgroundSliderHandleErrorActive => {
+                "--awsui-color-background-slider-handle-error-active"
+            }
+            Self::ColorBackgroundSliderHandleWarningDefault => {
+                "--awsui-color-background-slider-handle-warning-default"
+            }
+            Self::ColorBackgroundSliderHandleWarningActive => {
+                "--awsui-color-background-slider-handle-warning-active"
+            }
+            Self::ColorBackgroundSliderRangeErrorDefault => {
+                "--awsui-color-background-slider-range-error-default"
+            }
+            Self::ColorBackgroundSliderRangeErrorActive => {
+                "--awsui-color-background-slider-range-error-active"
+            }
+            Self::ColorBackgroundSliderRangeWarningDefault => {
+                "--awsui-color-background-slider-range-warning-default"
+            }
+            Self::ColorBackgroundSliderRangeWarningActive => {
+                "--awsui-color-background-slider-range-warning-active"
+            }
             Self::ColorBackgroundStatusError => "--awsui-color-background-status-error",
             Self::ColorBackgroundStatusInfo => "--awsui-color-background-status-info",
             Self::ColorBackgroundDialog => "--awsui-color-background-dialog",
@@ -1278,33 +1969,51 @@ impl ColorToken {
             Self::ColorBackgroundStatusWarning => "--awsui-color-background-status-warning",
             Self::ColorBackgroundTableHeader => "--awsui-color-background-table-header",
             Self::ColorBackgroundTilesDisabled => "--awsui-color-background-tiles-disabled",
-            Self::ColorBackgroundToggleCheckedDisabled => "--awsui-color-background-toggle-checked-disabled",
+            Self::ColorBackgroundToggleCheckedDisabled => {
+                "--awsui-color-background-toggle-checked-disabled"
+            }
             Self::ColorBackgroundToggleDefault => "--awsui-color-background-toggle-default",
             Self::ColorBackgroundAvatarGenAi => "--awsui-color-background-avatar-gen-ai",
             Self::ColorBackgroundAvatarDefault => "--awsui-color-background-avatar-default",
             Self::ColorTextAvatar => "--awsui-color-text-avatar",
             Self::ColorBackgroundLoadingBarGenAi => "--awsui-color-background-loading-bar-gen-ai",
-            Self::ColorBackgroundChatBubbleOutgoing => "--awsui-color-background-chat-bubble-outgoing",
-            Self::ColorBackgroundChatBubbleIncoming => "--awsui-color-background-chat-bubble-incoming",
+            Self::ColorBackgroundChatBubbleOutgoing => {
+                "--awsui-color-background-chat-bubble-outgoing"
+            }
+            Self::ColorBackgroundChatBubbleIncoming => {
+                "--awsui-color-background-chat-bubble-incoming"
+            }
             Self::ColorTextChatBubbleOutgoing => "--awsui-color-text-chat-bubble-outgoing",
             Self::ColorTextChatBubbleIncoming => "--awsui-color-text-chat-bubble-incoming",
             Self::ColorBorderButtonNormalActive => "--awsui-color-border-button-normal-active",
             Self::ColorBorderButtonNormalDefault => "--awsui-color-border-button-normal-default",
-            Self::ColorBorderToggleButtonNormalPressed => "--awsui-color-border-toggle-button-normal-pressed",
+            Self::ColorBorderToggleButtonNormalPressed => {
+                "--awsui-color-border-toggle-button-normal-pressed"
+            }
             Self::ColorBorderButtonNormalDisabled => "--awsui-color-border-button-normal-disabled",
             Self::ColorTextButtonNormalDisabled => "--awsui-color-text-button-normal-disabled",
             Self::ColorBorderButtonNormalHover => "--awsui-color-border-button-normal-hover",
             Self::ColorTextButtonIconDisabled => "--awsui-color-text-button-icon-disabled",
-            Self::ColorBorderButtonPrimaryDisabled => "--awsui-color-border-button-primary-disabled",
+            Self::ColorBorderButtonPrimaryDisabled => {
+                "--awsui-color-border-button-primary-disabled"
+            }
             Self::ColorTextButtonPrimaryDisabled => "--awsui-color-text-button-primary-disabled",
             Self::ColorItemSelected => "--awsui-color-item-selected",
             Self::ColorBorderCalendarGrid => "--awsui-color-border-calendar-grid",
-            Self::ColorBorderCalendarGridSelectedFocusRing => "--awsui-color-border-calendar-grid-selected-focus-ring",
+            Self::ColorBorderCalendarGridSelectedFocusRing => {
+                "--awsui-color-border-calendar-grid-selected-focus-ring"
+            }
             Self::ColorBorderCellShaded => "--awsui-color-border-cell-shaded",
-            Self::ColorBorderCodeEditorAceActiveLineLightTheme => "--awsui-color-border-code-editor-ace-active-line-light-theme",
-            Self::ColorBorderCodeEditorAceActiveLineDarkTheme => "--awsui-color-border-code-editor-ace-active-line-dark-theme",
+            Self::ColorBorderCodeEditorAceActiveLineLightTheme => {
+                "--awsui-color-border-code-editor-ace-active-line-light-theme"
+            }
+            Self::ColorBorderCodeEditorAceActiveLineDarkTheme => {
+                "--awsui-color-border-code-editor-ace-active-line-dark-theme"
+            }
             Self::ColorBorderCodeEditorDefault => "--awsui-color-border-code-editor-default",
-            Self::ColorBorderCodeEditorPaneItemHover => "--awsui-color-border-code-editor-pane-item-hover",
+            Self::ColorBorderCodeEditorPaneItemHover => {
+                "--awsui-color-border-code-editor-pane-item-hover"
+            }
             Self::ColorBorderContainerDivider => "--awsui-color-border-container-divider",
             Self::ColorBorderContainerTop => "--awsui-color-border-container-top",
             Self::ColorBorderControlChecked => "--awsui-color-border-control-checked",
@@ -1319,7 +2028,9 @@ impl ColorToken {
             Self::ColorBorderDropdownGroup => "--awsui-color-border-dropdown-group",
             Self::ColorBorderDropdownItemDefault => "--awsui-color-border-dropdown-item-default",
             Self::ColorBorderDropdownItemHover => "--awsui-color-border-dropdown-item-hover",
-            Self::ColorBorderDropdownItemDimmedHover => "--awsui-color-border-dropdown-item-dimmed-hover",
+            Self::ColorBorderDropdownItemDimmedHover => {
+                "--awsui-color-border-dropdown-item-dimmed-hover"
+            }
             Self::ColorBorderDropdownItemSelected => "--awsui-color-border-dropdown-item-selected",
             Self::ColorBorderDropdownItemTop => "--awsui-color-border-dropdown-item-top",
             Self::ColorBorderEditableCellHover => "--awsui-color-border-editable-cell-hover",
@@ -1343,7 +2054,9 @@ impl ColorToken {
             Self::ColorBorderStatusSuccess => "--awsui-color-border-status-success",
             Self::ColorBorderStatusWarning => "--awsui-color-border-status-warning",
             Self::ColorBorderDialog => "--awsui-color-border-dialog",
-            Self::ColorBorderDividerInteractiveDefault => "--awsui-color-border-divider-interactive-default",
+            Self::ColorBorderDividerInteractiveDefault => {
+                "--awsui-color-border-divider-interactive-default"
+            }
             Self::ColorBorderTabsDivider => "--awsui-color-border-tabs-divider",
             Self::ColorBorderTabsShadow => "--awsui-color-border-tabs-shadow",
             Self::ColorBorderTabsUnderline => "--awsui-color-border-tabs-underline",
@@ -1356,21 +2069,33 @@ impl ColorToken {
             Self::ColorShadowMedium => "--awsui-color-shadow-medium",
             Self::ColorShadowSide => "--awsui-color-shadow-side",
             Self::ColorStrokeChartLine => "--awsui-color-stroke-chart-line",
-            Self::ColorStrokeCodeEditorGutterActiveLineDefault => "--awsui-color-stroke-code-editor-gutter-active-line-default",
-            Self::ColorStrokeCodeEditorGutterActiveLineHover => "--awsui-color-stroke-code-editor-gutter-active-line-hover",
+            Self::ColorStrokeCodeEditorGutterActiveLineDefault => {
+                "--awsui-color-stroke-code-editor-gutter-active-line-default"
+            }
+            Self::ColorStrokeCodeEditorGutterActiveLineHover => {
+                "--awsui-color-stroke-code-editor-gutter-active-line-hover"
+            }
             Self::ColorTextAccent => "--awsui-color-text-accent",
             Self::ColorTextBodyDefault => "--awsui-color-text-body-default",
             Self::ColorTextBodySecondary => "--awsui-color-text-body-secondary",
             Self::ColorTextBreadcrumbCurrent => "--awsui-color-text-breadcrumb-current",
             Self::ColorTextBreadcrumbIcon => "--awsui-color-text-breadcrumb-icon",
-            Self::ColorTextButtonInlineIconDefault => "--awsui-color-text-button-inline-icon-default",
-            Self::ColorTextButtonInlineIconDisabled => "--awsui-color-text-button-inline-icon-disabled",
+            Self::ColorTextButtonInlineIconDefault => {
+                "--awsui-color-text-button-inline-icon-default"
+            }
+            Self::ColorTextButtonInlineIconDisabled => {
+                "--awsui-color-text-button-inline-icon-disabled"
+            }
             Self::ColorTextButtonInlineIconHover => "--awsui-color-text-button-inline-icon-hover",
             Self::ColorTextButtonNormalActive => "--awsui-color-text-button-normal-active",
-            Self::ColorTextToggleButtonNormalPressed => "--awsui-color-text-toggle-button-normal-pressed",
+            Self::ColorTextToggleButtonNormalPressed => {
+                "--awsui-color-text-toggle-button-normal-pressed"
+            }
             Self::ColorTextButtonNormalDefault => "--awsui-color-text-button-normal-default",
             Self::ColorTextButtonNormalHover => "--awsui-color-text-button-normal-hover",
-            Self::ColorTextLinkButtonNormalDefault => "--awsui-color-text-link-button-normal-default",
+            Self::ColorTextLinkButtonNormalDefault => {
+                "--awsui-color-text-link-button-normal-default"
+            }
             Self::ColorTextLinkButtonNormalHover => "--awsui-color-text-link-button-normal-hover",
             Self::ColorTextLinkButtonNormalActive => "--awsui-color-text-link-button-normal-active",
             Self::ColorTextButtonPrimaryActive => "--awsui-color-text-button-primary-active",
@@ -1378,10 +2103,18 @@ impl ColorToken {
             Self::ColorTextButtonPrimaryHover => "--awsui-color-text-button-primary-hover",
             Self::ColorTextCalendarDateHover => "--awsui-color-text-calendar-date-hover",
             Self::ColorTextCalendarMonth => "--awsui-color-text-calendar-month",
-            Self::ColorTextCodeEditorGutterActiveLine => "--awsui-color-text-code-editor-gutter-active-line",
-            Self::ColorTextCodeEditorGutterDefault => "--awsui-color-text-code-editor-gutter-default",
-            Self::ColorTextCodeEditorStatusBarDisabled => "--awsui-color-text-code-editor-status-bar-disabled",
-            Self::ColorTextCodeEditorTabButtonError => "--awsui-color-text-code-editor-tab-button-error",
+            Self::ColorTextCodeEditorGutterActiveLine => {
+                "--awsui-color-text-code-editor-gutter-active-line"
+            }
+            Self::ColorTextCodeEditorGutterDefault => {
+                "--awsui-color-text-code-editor-gutter-default"
+            }
+            Self::ColorTextCodeEditorStatusBarDisabled => {
+                "--awsui-color-text-code-editor-status-bar-disabled"
+            }
+            Self::ColorTextCodeEditorTabButtonError => {
+                "--awsui-color-text-code-editor-tab-button-error"
+            }
             Self::ColorTextColumnHeader => "--awsui-color-text-column-header",
             Self::ColorTextColumnSortingIcon => "--awsui-color-text-column-sorting-icon",
             Self::ColorTextControlDisabled => "--awsui-color-text-control-disabled",
@@ -1393,14 +2126,24 @@ impl ColorToken {
             Self::ColorTextDropdownItemDefault => "--awsui-color-text-dropdown-item-default",
             Self::ColorTextDropdownItemDimmed => "--awsui-color-text-dropdown-item-dimmed",
             Self::ColorTextDropdownItemDisabled => "--awsui-color-text-dropdown-item-disabled",
-            Self::ColorTextDropdownItemFilterMatch => "--awsui-color-text-dropdown-item-filter-match",
-            Self::ColorTextDropdownItemHighlighted => "--awsui-color-text-dropdown-item-highlighted",
+            Self::ColorTextDropdownItemFilterMatch => {
+                "--awsui-color-text-dropdown-item-filter-match"
+            }
+            Self::ColorTextDropdownItemHighlighted => {
+                "--awsui-color-text-dropdown-item-highlighted"
+            }
             Self::ColorTextDropdownItemSecondary => "--awsui-color-text-dropdown-item-secondary",
-            Self::ColorTextDropdownItemSecondaryHover => "--awsui-color-text-dropdown-item-secondary-hover",
+            Self::ColorTextDropdownItemSecondaryHover => {
+                "--awsui-color-text-dropdown-item-secondary-hover"
+            }
             Self::ColorTextEmpty => "--awsui-color-text-empty",
-            Self::ColorTextExpandableSectionDefault => "--awsui-color-text-expandable-section-default",
+            Self::ColorTextExpandableSectionDefault => {
+                "--awsui-color-text-expandable-section-default"
+            }
             Self::ColorTextExpandableSectionHover => "--awsui-color-text-expandable-section-hover",
-            Self::ColorTextExpandableSectionNavigationIconDefault => "--awsui-color-text-expandable-section-navigation-icon-default",
+            Self::ColorTextExpandableSectionNavigationIconDefault => {
+                "--awsui-color-text-expandable-section-navigation-icon-default"
+            }
             Self::ColorTextFormDefault => "--awsui-color-text-form-default",
             Self::ColorTextFormLabel => "--awsui-color-text-form-label",
             Self::ColorTextFormSecondary => "--awsui-color-text-form-secondary",
@@ -1414,14 +2157,22 @@ impl ColorToken {
             Self::ColorTextIconSubtle => "--awsui-color-text-icon-subtle",
             Self::ColorTextInputDisabled => "--awsui-color-text-input-disabled",
             Self::ColorTextInputPlaceholder => "--awsui-color-text-input-placeholder",
-            Self::ColorTextInputPlaceholderDisabled => "--awsui-color-text-input-placeholder-disabled",
+            Self::ColorTextInputPlaceholderDisabled => {
+                "--awsui-color-text-input-placeholder-disabled"
+            }
             Self::ColorTextInteractiveActive => "--awsui-color-text-interactive-active",
             Self::ColorTextInteractiveDefault => "--awsui-color-text-interactive-default",
             Self::ColorTextInteractiveDisabled => "--awsui-color-text-interactive-disabled",
             Self::ColorTextInteractiveHover => "--awsui-color-text-interactive-hover",
-            Self::ColorTextToggleButtonIconPressed => "--awsui-color-text-toggle-button-icon-pressed",
-            Self::ColorTextInteractiveInvertedDefault => "--awsui-color-text-interactive-inverted-default",
-            Self::ColorTextInteractiveInvertedHover => "--awsui-color-text-interactive-inverted-hover",
+            Self::ColorTextToggleButtonIconPressed => {
+                "--awsui-color-text-toggle-button-icon-pressed"
+            }
+            Self::ColorTextInteractiveInvertedDefault => {
+                "--awsui-color-text-interactive-inverted-default"
+            }
+            Self::ColorTextInteractiveInvertedHover => {
+                "--awsui-color-text-interactive-inverted-hover"
+            }
             Self::ColorTextInverted => "--awsui-color-text-inverted",
             Self::ColorTextLabel => "--awsui-color-text-label",
             Self::ColorTextLayoutToggle => "--awsui-color-text-layout-toggle",
@@ -1432,12 +2183,18 @@ impl ColorToken {
             Self::ColorTextLinkHover => "--awsui-color-text-link-hover",
             Self::ColorTextLinkInvertedHover => "--awsui-color-text-link-inverted-hover",
             Self::ColorTextLinkButtonUnderline => "--awsui-color-text-link-button-underline",
-            Self::ColorTextLinkButtonUnderlineHover => "--awsui-color-text-link-button-underline-hover",
+            Self::ColorTextLinkButtonUnderlineHover => {
+                "--awsui-color-text-link-button-underline-hover"
+            }
             Self::ColorTextNotificationDefault => "--awsui-color-text-notification-default",
             Self::ColorTextNotificationStackBar => "--awsui-color-text-notification-stack-bar",
             Self::ColorTextNotificationYellow => "--awsui-color-text-notification-yellow",
-            Self::ColorTextPaginationPageNumberActiveDisabled => "--awsui-color-text-pagination-page-number-active-disabled",
-            Self::ColorTextPaginationPageNumberDefault => "--awsui-color-text-pagination-page-number-default",
+            Self::ColorTextPaginationPageNumberActiveDisabled => {
+                "--awsui-color-text-pagination-page-number-active-disabled"
+            }
+            Self::ColorTextPaginationPageNumberDefault => {
+                "--awsui-color-text-pagination-page-number-default"
+            }
             Self::ColorTextSegmentActive => "--awsui-color-text-segment-active",
             Self::ColorTextSegmentDefault => "--awsui-color-text-segment-default",
             Self::ColorTextSegmentHover => "--awsui-color-text-segment-hover",
@@ -1465,6 +2222,1748 @@ impl ColorToken {
         }
     }
 
+    /// Get the resolved value of this token in a mode
+    ///
+    /// Modes that do not apply to the token, such as density modes for
+    /// colors, give its default value.
+    pub fn value(&self, mode: Mode) -> &'static str {
+        match self {
+            Self::ColorPrimary50 => "#f0fbff",
+            Self::ColorPrimary100 => "#d1f1ff",
+            Self::ColorPrimary200 => "#b8e7ff",
+            Self::ColorPrimary300 => "#75cfff",
+            Self::ColorPrimary400 => "#42b4ff",
+            Self::ColorPrimary500 => "#0099ff",
+            Self::ColorPrimary600 => "#006ce0",
+            Self::ColorPrimary700 => "#004a9e",
+            Self::ColorPrimary800 => "#003b8f",
+            Self::ColorPrimary900 => "#002b66",
+            Self::ColorPrimary1000 => "#001129",
+            Self::ColorNeutral50 => "#fcfcfd",
+            Self::ColorNeutral100 => "#f9f9fa",
+            Self::ColorNeutral150 => "#f6f6f9",
+            Self::ColorNeutral200 => "#f3f3f7",
+            Self::ColorNeutral250 => "#ebebf0",
+            Self::ColorNeutral300 => "#dedee3",
+            Self::ColorNeutral350 => "#c6c6cd",
+            Self::ColorNeutral400 => "#b4b4bb",
+            Self::ColorNeutral450 => "#a4a4ad",
+            Self::ColorNeutral500 => "#8c8c94",
+            Self::ColorNeutral550 => "#72747e",
+            Self::ColorNeutral600 => "#656871",
+            Self::ColorNeutral650 => "#424650",
+            Self::ColorNeutral700 => "#333843",
+            Self::ColorNeutral750 => "#232b37",
+            Self::ColorNeutral800 => "#1b232d",
+            Self::ColorNeutral850 => "#161d26",
+            Self::ColorNeutral900 => "#131920",
+            Self::ColorNeutral950 => "#0f141a",
+            Self::ColorNeutral1000 => "#06080a",
+            Self::ColorError50 => "#fff5f5",
+            Self::ColorError400 => "#ff7a7a",
+            Self::ColorError600 => "#db0000",
+            Self::ColorError900 => "#700000",
+            Self::ColorError1000 => "#1f0000",
+            Self::ColorSuccess50 => "#effff1",
+            Self::ColorSuccess500 => "#2bb534",
+            Self::ColorSuccess600 => "#00802f",
+            Self::ColorSuccess1000 => "#001401",
+            Self::ColorWarning50 => "#fffef0",
+            Self::ColorWarning400 => "#ffe347",
+            Self::ColorWarning500 => "#fbd332",
+            Self::ColorWarning900 => "#855900",
+            Self::ColorWarning1000 => "#191100",
+            Self::ColorInfo50 => "#f0fbff",
+            Self::ColorInfo300 => "#75cfff",
+            Self::ColorInfo400 => "#42b4ff",
+            Self::ColorInfo600 => "#006ce0",
+            Self::ColorInfo1000 => "#001129",
+            Self::ColorGrey50 => "#fcfcfd",
+            Self::ColorGrey100 => "#f9f9fa",
+            Self::ColorGrey150 => "#f6f6f9",
+            Self::ColorGrey200 => "#f3f3f7",
+            Self::ColorGrey250 => "#ebebf0",
+            Self::ColorGrey300 => "#dedee3",
+            Self::ColorGrey350 => "#c6c6cd",
+            Self::ColorGrey400 => "#b4b4bb",
+            Self::ColorGrey450 => "#a4a4ad",
+            Self::ColorGrey500 => "#8c8c94",
+            Self::ColorGrey600 => "#656871",
+            Self::ColorGrey650 => "#424650",
+            Self::ColorGrey700 => "#333843",
+            Self::ColorGrey750 => "#232b37",
+            Self::ColorGrey800 => "#1b232d",
+            Self::ColorGrey850 => "#161d26",
+            Self::ColorGrey900 => "#131920",
+            Self::ColorGrey950 => "#0f141a",
+            Self::ColorGrey1000 => "#06080a",
+            Self::ColorBlue50 => "#f0fbff",
+            Self::ColorBlue100 => "#d1f1ff",
+            Self::ColorBlue200 => "#b8e7ff",
+            Self::ColorBlue300 => "#75cfff",
+            Self::ColorBlue400 => "#42b4ff",
+            Self::ColorBlue600 => "#006ce0",
+            Self::ColorBlue700 => "#004a9e",
+            Self::ColorBlue900 => "#002b66",
+            Self::ColorBlue1000 => "#001129",
+            Self::ColorGreen50 => "#effff1",
+            Self::ColorGreen500 => "#2bb534",
+            Self::ColorGreen600 => "#00802f",
+            Self::ColorGreen900 => "#00471e",
+            Self::ColorGreen1000 => "#001401",
+            Self::ColorRed50 => "#fff5f5",
+            Self::ColorRed400 => "#ff7a7a",
+            Self::ColorRed600 => "#db0000",
+            Self::ColorRed900 => "#700000",
+            Self::ColorRed1000 => "#1f0000",
+            Self::ColorYellow50 => "#fffef0",
+            Self::ColorYellow400 => "#ffe347",
+            Self::ColorYellow500 => "#fbd332",
+            Self::ColorYellow900 => "#855900",
+            Self::ColorYellow1000 => "#191100",
+            Self::ColorPurple400 => "#bf80ff",
+            Self::ColorPurple700 => "#7300e5",
+            Self::ColorAmber400 => "#ff9900",
+            Self::ColorAmber500 => "#fa6f00",
+            Self::ColorAwsSquidInk => "#232f3e",
+            Self::ColorTransparent => "transparent",
+            Self::ColorBlack => "#000000",
+            Self::ColorWhite => "#ffffff",
+            Self::ColorChartsRed300 => match mode {
+                Mode::Dark => "#d63f38",
+                _ => "#ea7158",
+            },
+            Self::ColorChartsRed400 => match mode {
+                Mode::Dark => "#ed5958",
+                _ => "#dc5032",
+            },
+            Self::ColorChartsRed500 => match mode {
+                Mode::Dark => "#fe6e73",
+                _ => "#d13313",
+            },
+            Self::ColorChartsRed600 => match mode {
+                Mode::Dark => "#ff8a8a",
+                _ => "#ba2e0f",
+            },
+            Self::ColorChartsRed700 => match mode {
+                Mode::Dark => "#ffa09e",
+                _ => "#a82a0c",
+            },
+            Self::ColorChartsRed800 => match mode {
+                Mode::Dark => "#ffb3b0",
+                _ => "#972709",
+            },
+            Self::ColorChartsRed900 => match mode {
+                Mode::Dark => "#ffc4c0",
+                _ => "#892407",
+            },
+            Self::ColorChartsRed1000 => match mode {
+                Mode::Dark => "#ffd2cf",
+                _ => "#7d2105",
+            },
+            Self::ColorChartsRed1100 => match mode {
+                Mode::Dark => "#ffe0dd",
+                _ => "#721e03",
+            },
+            Self::ColorChartsRed1200 => match mode {
+                Mode::Dark => "#ffecea",
+                _ => "#671c00",
+            },
+            Self::ColorChartsOrange300 => match mode {
+                Mode::Dark => "#c55305",
+                _ => "#e07941",
+            },
+            Self::ColorChartsOrange400 => match mode {
+                Mode::Dark => "#de6923",
+                _ => "#cc5f21",
+            },
+            Self::ColorChartsOrange500 => match mode {
+                Mode::Dark => "#f27c36",
+                _ => "#bc4d01",
+            },
+            Self::ColorChartsOrange600 => match mode {
+                Mode::Dark => "#f89256",
+                _ => "#a84401",
+            },
+            Self::ColorChartsOrange700 => match mode {
+                Mode::Dark => "#fca572",
+                _ => "#983c02",
+            },
+            Self::ColorChartsOrange800 => match mode {
+                Mode::Dark => "#ffb68b",
+                _ => "#8a3603",
+            },
+            Self::ColorChartsOrange900 => match mode {
+                Mode::Dark => "#ffc6a4",
+                _ => "#7e3103",
+            },
+            Self::ColorChartsOrange1000 => match mode {
+                Mode::Dark => "#ffd4bb",
+                _ => "#732c02",
+            },
+            Self::ColorChartsOrange1100 => match mode {
+                Mode::Dark => "#ffe1cf",
+                _ => "#692801",
+            },
+            Self::ColorChartsOrange1200 => match mode {
+                Mode::Dark => "#ffede2",
+                _ => "#602400",
+            },
+            Self::ColorChartsYellow300 => match mode {
+                Mode::Dark => "#977001",
+                _ => "#b2911c",
+            },
+            Self::ColorChartsYellow400 => match mode {
+                Mode::Dark => "#b08400",
+                _ => "#9c7b0b",
+            },
+            Self::ColorChartsYellow500 => match mode {
+                Mode::Dark => "#c59600",
+                _ => "#8a6b05",
+            },
+            Self::ColorChartsYellow600 => match mode {
+                Mode::Dark => "#d3a61c",
+                _ => "#7b5f04",
+            },
+            Self::ColorChartsYellow700 => match mode {
+                Mode::Dark => "#dfb52c",
+                _ => "#6f5504",
+            },
+            Self::ColorChartsYellow800 => match mode {
+                Mode::Dark => "#eac33a",
+                _ => "#654d03",
+            },
+            Self::ColorChartsYellow900 => match mode {
+                Mode::Dark => "#f1cf65",
+                _ => "#5d4503",
+            },
+            Self::ColorChartsYellow1000 => match mode {
+                Mode::Dark => "#f7db8a",
+                _ => "#553f03",
+            },
+            Self::ColorChartsYellow1100 => match mode {
+                Mode::Dark => "#fce5a8",
+                _ => "#4d3901",
+            },
+            Self::ColorChartsYellow1200 => match mode {
+                Mode::Dark => "#ffefc9",
+                _ => "#483300",
+            },
+            Self::ColorChartsGreen300 => match mode {
+                Mode::Dark => "#48851a",
+                _ => "#67a353",
+            },
+            Self::ColorChartsGreen400 => match mode {
+                Mode::Dark => "#5a9b29",
+                _ => "#41902c",
+            },
+            Self::ColorChartsGreen500 => match mode {
+                Mode::Dark => "#69ae34",
+                _ => "#1f8104",
+            },
+            Self::ColorChartsGreen600 => match mode {
+                Mode::Dark => "#7dbd4c",
+                _ => "#1a7302",
+            },
+            Self::ColorChartsGreen700 => match mode {
+                Mode::Dark => "#8fca61",
+                _ => "#176702",
+            },
+            Self::ColorChartsGreen800 => match mode {
+                Mode::Dark => "#9fd673",
+                _ => "#145d02",
+            },
+            Self::ColorChartsGreen900 => match mode {
+                Mode::Dark => "#b2df8d",
+                _ => "#125502",
+            },
+            Self::ColorChartsGreen1000 => match mode {
+                Mode::Dark => "#c5e7a8",
+                _ => "#104d01",
+            },
+            Self::ColorChartsGreen1100 => match mode {
+                Mode::Dark => "#d5efbe",
+                _ => "#0f4601",
+            },
+            Self::ColorChartsGreen1200 => match mode {
+                Mode::Dark => "#e4f7d5",
+                _ => "#0d4000",
+            },
+            Self::ColorChartsTeal300 => match mode {
+                Mode::Dark => "#018977",
+                _ => "#2ea597",
+            },
+            Self::ColorChartsTeal400 => match mode {
+                Mode::Dark => "#009d89",
+                _ => "#1c8e81",
+            },
+            Self::ColorChartsTeal500 => match mode {
+                Mode::Dark => "#00b09b",
+                _ => "#0d7d70",
+            },
+            Self::ColorChartsTeal600 => match mode {
+                Mode::Dark => "#40bfa9",
+                _ => "#096f64",
+            },
+            Self::ColorChartsTeal700 => match mode {
+                Mode::Dark => "#5fccb7",
+                _ => "#06645a",
+            },
+            Self::ColorChartsTeal800 => match mode {
+                Mode::Dark => "#77d7c3",
+                _ => "#045b52",
+            },
+            Self::ColorChartsTeal900 => match mode {
+                Mode::Dark => "#94e0d0",
+                _ => "#03524a",
+            },
+            Self::ColorChartsTeal1000 => match mode {
+                Mode::Dark => "#ace9db",
+                _ => "#014b44",
+            },
+            Self::ColorChartsTeal1100 => match mode {
+                Mode::Dark => "#c2f0e6",
+                _ => "#01443e",
+            },
+            Self::ColorChartsTeal1200 => match mode {
+                Mode::Dark => "#d7f7f0",
+                _ => "#003e38",
+            },
+            Self::ColorChartsBlue1300 => match mode {
+                Mode::Dark => "#00819c",
+                _ => "#529ccb",
+            },
+            Self::ColorChartsBlue1400 => match mode {
+                Mode::Dark => "#0497ba",
+                _ => "#3184c2",
+            },
+            Self::ColorChartsBlue1500 => match mode {
+                Mode::Dark => "#08aad2",
+                _ => "#0273bb",
+            },
+            Self::ColorChartsBlue1600 => match mode {
+                Mode::Dark => "#44b9dd",
+                _ => "#0166ab",
+            },
+            Self::ColorChartsBlue1700 => match mode {
+                Mode::Dark => "#63c6e7",
+                _ => "#015b9d",
+            },
+            Self::ColorChartsBlue1800 => match mode {
+                Mode::Dark => "#79d2f0",
+                _ => "#015292",
+            },
+            Self::ColorChartsBlue1900 => match mode {
+                Mode::Dark => "#98dcf5",
+                _ => "#014a87",
+            },
+            Self::ColorChartsBlue11000 => match mode {
+                Mode::Dark => "#b3e4f8",
+                _ => "#01437d",
+            },
+            Self::ColorChartsBlue11100 => match mode {
+                Mode::Dark => "#caedfc",
+                _ => "#003c75",
+            },
+            Self::ColorChartsBlue11200 => match mode {
+                Mode::Dark => "#ddf4ff",
+                _ => "#00366d",
+            },
+            Self::ColorChartsBlue2300 => match mode {
+                Mode::Dark => "#486de8",
+                _ => "#688ae8",
+            },
+            Self::ColorChartsBlue2400 => match mode {
+                Mode::Dark => "#6384f5",
+                _ => "#5978e3",
+            },
+            Self::ColorChartsBlue2500 => match mode {
+                Mode::Dark => "#7698fe",
+                _ => "#4066df",
+            },
+            Self::ColorChartsBlue2600 => match mode {
+                Mode::Dark => "#8ea9ff",
+                _ => "#3759ce",
+            },
+            Self::ColorChartsBlue2700 => match mode {
+                Mode::Dark => "#a2b8ff",
+                _ => "#314fbf",
+            },
+            Self::ColorChartsBlue2800 => match mode {
+                Mode::Dark => "#b1c5ff",
+                _ => "#2c46b1",
+            },
+            Self::ColorChartsBlue2900 => match mode {
+                Mode::Dark => "#c3d1ff",
+                _ => "#273ea5",
+            },
+            Self::ColorChartsBlue21000 => match mode {
+                Mode::Dark => "#d2dcff",
+                _ => "#23379b",
+            },
+            Self::ColorChartsBlue21100 => match mode {
+                Mode::Dark => "#dfe6ff",
+                _ => "#1f3191",
+            },
+            Self::ColorChartsBlue21200 => match mode {
+                Mode::Dark => "#ecf0ff",
+                _ => "#1b2b88",
+            },
+            Self::ColorChartsPurple300 => match mode {
+                Mode::Dark => "#8d59de",
+                _ => "#a783e1",
+            },
+            Self::ColorChartsPurple400 => match mode {
+                Mode::Dark => "#a173ea",
+                _ => "#9469d6",
+            },
+            Self::ColorChartsPurple500 => match mode {
+                Mode::Dark => "#b088f5",
+                _ => "#8456ce",
+            },
+            Self::ColorChartsPurple600 => match mode {
+                Mode::Dark => "#bf9bf9",
+                _ => "#7749bf",
+            },
+            Self::ColorChartsPurple700 => match mode {
+                Mode::Dark => "#cbabfc",
+                _ => "#6b40b2",
+            },
+            Self::ColorChartsPurple800 => match mode {
+                Mode::Dark => "#d6baff",
+                _ => "#6237a7",
+            },
+            Self::ColorChartsPurple900 => match mode {
+                Mode::Dark => "#dfc8ff",
+                _ => "#59309d",
+            },
+            Self::ColorChartsPurple1000 => match mode {
+                Mode::Dark => "#e8d5ff",
+                _ => "#512994",
+            },
+            Self::ColorChartsPurple1100 => match mode {
+                Mode::Dark => "#efe2ff",
+                _ => "#4a238b",
+            },
+            Self::ColorChartsPurple1200 => match mode {
+                Mode::Dark => "#f5edff",
+                _ => "#431d84",
+            },
+            Self::ColorChartsPink300 => match mode {
+                Mode::Dark => "#c64a70",
+                _ => "#da7596",
+            },
+            Self::ColorChartsPink400 => match mode {
+                Mode::Dark => "#d56889",
+                _ => "#ce567c",
+            },
+            Self::ColorChartsPink500 => match mode {
+                Mode::Dark => "#e07f9d",
+                _ => "#c33d69",
+            },
+            Self::ColorChartsPink600 => match mode {
+                Mode::Dark => "#eb92ad",
+                _ => "#b1325c",
+            },
+            Self::ColorChartsPink700 => match mode {
+                Mode::Dark => "#f5a2bb",
+                _ => "#a32952",
+            },
+            Self::ColorChartsPink800 => match mode {
+                Mode::Dark => "#ffb0c8",
+                _ => "#962249",
+            },
+            Self::ColorChartsPink900 => match mode {
+                Mode::Dark => "#ffc1d4",
+                _ => "#8b1b42",
+            },
+            Self::ColorChartsPink1000 => match mode {
+                Mode::Dark => "#ffd1de",
+                _ => "#81143b",
+            },
+            Self::ColorChartsPink1100 => match mode {
+                Mode::Dark => "#ffdfe8",
+                _ => "#780d35",
+            },
+            Self::ColorChartsPink1200 => match mode {
+                Mode::Dark => "#ffecf1",
+                _ => "#6f062f",
+            },
+            Self::ColorChartsStatusCritical => match mode {
+                Mode::Dark => "#d63f38",
+                _ => "#7d2105",
+            },
+            Self::ColorChartsStatusHigh => match mode {
+                Mode::Dark => "#fe6e73",
+                _ => "#ba2e0f",
+            },
+            Self::ColorChartsStatusMedium => match mode {
+                Mode::Dark => "#f89256",
+                _ => "#cc5f21",
+            },
+            Self::ColorChartsStatusLow => match mode {
+                Mode::Dark => "#dfb52c",
+                _ => "#b2911c",
+            },
+            Self::ColorChartsStatusPositive => match mode {
+                Mode::Dark => "#69ae34",
+                _ => "#67a353",
+            },
+            Self::ColorChartsStatusInfo => match mode {
+                Mode::Dark => "#08aad2",
+                _ => "#3184c2",
+            },
+            Self::ColorChartsStatusNeutral => "#8c8c94",
+            Self::ColorChartsThresholdNegative => match mode {
+                Mode::Dark => "#ff7a7a",
+                _ => "#db0000",
+            },
+            Self::ColorChartsThresholdPositive => match mode {
+                Mode::Dark => "#2bb534",
+                _ => "#00802f",
+            },
+            Self::ColorChartsThresholdInfo => match mode {
+                Mode::Dark => "#75cfff",
+                _ => "#006ce0",
+            },
+            Self::ColorChartsThresholdNeutral => match mode {
+                Mode::Dark => "#a4a4ad",
+                _ => "#656871",
+            },
+            Self::ColorChartsLineGrid => match mode {
+                Mode::Dark => "#424650",
+                _ => "#dedee3",
+            },
+            Self::ColorChartsLineTick => match mode {
+                Mode::Dark => "#424650",
+                _ => "#dedee3",
+            },
+            Self::ColorChartsLineAxis => match mode {
+                Mode::Dark => "#424650",
+                _ => "#dedee3",
+            },
+            Self::ColorChartsPaletteCategorical1 => match mode {
+                Mode::Dark => "#486de8",
+                _ => "#688ae8",
+            },
+            Self::ColorChartsPaletteCategorical2 => match mode {
+                Mode::Dark => "#e07f9d",
+                _ => "#c33d69",
+            },
+            Self::ColorChartsPaletteCategorical3 => match mode {
+                Mode::Dark => "#018977",
+                _ => "#2ea597",
+            },
+            Self::ColorChartsPaletteCategorical4 => match mode {
+                Mode::Dark => "#b088f5",
+                _ => "#8456ce",
+            },
+            Self::ColorChartsPaletteCategorical5 => match mode {
+                Mode::Dark => "#c55305",
+                _ => "#e07941",
+            },
+            Self::ColorChartsPaletteCategorical6 => match mode {
+                Mode::Dark => "#8ea9ff",
+                _ => "#3759ce",
+            },
+            Self::ColorChartsPaletteCategorical7 => match mode {
+                Mode::Dark => "#ffb0c8",
+                _ => "#962249",
+            },
+            Self::ColorChartsPaletteCategorical8 => match mode {
+                Mode::Dark => "#40bfa9",
+                _ => "#096f64",
+            },
+            Self::ColorChartsPaletteCategorical9 => match mode {
+                Mode::Dark => "#d6baff",
+                _ => "#6237a7",
+            },
+            Self::ColorChartsPaletteCategorical10 => match mode {
+                Mode::Dark => "#f89256",
+                _ => "#a84401",
+            },
+            Self::ColorChartsPaletteCategorical11 => match mode {
+                Mode::Dark => "#c3d1ff",
+                _ => "#273ea5",
+            },
+            Self::ColorChartsPaletteCategorical12 => match mode {
+                Mode::Dark => "#ffdfe8",
+                _ => "#780d35",
+            },
+            Self::ColorChartsPaletteCategorical13 => match mode {
+                Mode::Dark => "#94e0d0",
+                _ => "#03524a",
+            },
+            Self::ColorChartsPaletteCategorical14 => match mode {
+                Mode::Dark => "#efe2ff",
+                _ => "#4a238b",
+            },
+            Self::ColorChartsPaletteCategorical15 => match mode {
+                Mode::Dark => "#ffc6a4",
+                _ => "#7e3103",
+            },
+            Self::ColorChartsPaletteCategorical16 => match mode {
+                Mode::Dark => "#ecf0ff",
+                _ => "#1b2b88",
+            },
+            Self::ColorChartsPaletteCategorical17 => match mode {
+                Mode::Dark => "#d56889",
+                _ => "#ce567c",
+            },
+            Self::ColorChartsPaletteCategorical18 => match mode {
+                Mode::Dark => "#d7f7f0",
+                _ => "#003e38",
+            },
+            Self::ColorChartsPaletteCategorical19 => match mode {
+                Mode::Dark => "#a173ea",
+                _ => "#9469d6",
+            },
+            Self::ColorChartsPaletteCategorical20 => match mode {
+                Mode::Dark => "#ffede2",
+                _ => "#602400",
+            },
+            Self::ColorChartsPaletteCategorical21 => match mode {
+                Mode::Dark => "#7698fe",
+                _ => "#4066df",
+            },
+            Self::ColorChartsPaletteCategorical22 => match mode {
+                Mode::Dark => "#f5a2bb",
+                _ => "#a32952",
+            },
+            Self::ColorChartsPaletteCategorical23 => match mode {
+                Mode::Dark => "#00b09b",
+                _ => "#0d7d70",
+            },
+            Self::ColorChartsPaletteCategorical24 => match mode {
+                Mode::Dark => "#cbabfc",
+                _ => "#6b40b2",
+            },
+            Self::ColorChartsPaletteCategorical25 => match mode {
+                Mode::Dark => "#f27c36",
+                _ => "#bc4d01",
+            },
+            Self::ColorChartsPaletteCategorical26 => match mode {
+                Mode::Dark => "#b1c5ff",
+                _ => "#2c46b1",
+            },
+            Self::ColorChartsPaletteCategorical27 => match mode {
+                Mode::Dark => "#ffd1de",
+                _ => "#81143b",
+            },
+            Self::ColorChartsPaletteCategorical28 => match mode {
+                Mode::Dark => "#77d7c3",
+                _ => "#045b52",
+            },
+            Self::ColorChartsPaletteCategorical29 => match mode {
+                Mode::Dark => "#e8d5ff",
+                _ => "#512994",
+            },
+            Self::ColorChartsPaletteCategorical30 => match mode {
+                Mode::Dark => "#ffb68b",
+                _ => "#8a3603",
+            },
+            Self::ColorChartsPaletteCategorical31 => match mode {
+                Mode::Dark => "#dfe6ff",
+                _ => "#1f3191",
+            },
+            Self::ColorChartsPaletteCategorical32 => match mode {
+                Mode::Dark => "#c64a70",
+                _ => "#da7596",
+            },
+            Self::ColorChartsPaletteCategorical33 => match mode {
+                Mode::Dark => "#c2f0e6",
+                _ => "#01443e",
+            },
+            Self::ColorChartsPaletteCategorical34 => match mode {
+                Mode::Dark => "#8d59de",
+                _ => "#a783e1",
+            },
+            Self::ColorChartsPaletteCategorical35 => match mode {
+                Mode::Dark => "#ffe1cf",
+                _ => "#692801",
+            },
+            Self::ColorChartsPaletteCategorical36 => match mode {
+                Mode::Dark => "#6384f5",
+                _ => "#5978e3",
+            },
+            Self::ColorChartsPaletteCategorical37 => match mode {
+                Mode::Dark => "#eb92ad",
+                _ => "#b1325c",
+            },
+            Self::ColorChartsPaletteCategorical38 => match mode {
+                Mode::Dark => "#009d89",
+                _ => "#1c8e81",
+            },
+            Self::ColorChartsPaletteCategorical39 => match mode {
+                Mode::Dark => "#bf9bf9",
+                _ => "#7749bf",
+            },
+            Self::ColorChartsPaletteCategorical40 => match mode {
+                Mode::Dark => "#de6923",
+                _ => "#cc5f21",
+            },
+            Self::ColorChartsPaletteCategorical41 => match mode {
+                Mode::Dark => "#a2b8ff",
+                _ => "#314fbf",
+            },
+            Self::ColorChartsPaletteCategorical42 => match mode {
+                Mode::Dark => "#ffc1d4",
+                _ => "#8b1b42",
+            },
+            Self::ColorChartsPaletteCategorical43 => match mode {
+                Mode::Dark => "#5fccb7",
+                _ => "#06645a",
+            },
+            Self::ColorChartsPaletteCategorical44 => match mode {
+                Mode::Dark => "#dfc8ff",
+                _ => "#59309d",
+            },
+            Self::ColorChartsPaletteCategorical45 => match mode {
+                Mode::Dark => "#fca572",
+                _ => "#983c02",
+            },
+            Self::ColorChartsPaletteCategorical46 => match mode {
+                Mode::Dark => "#d2dcff",
+                _ => "#23379b",
+            },
+            Self::ColorChartsPaletteCategorical47 => match mode {
+                Mode::Dark => "#ffecf1",
+                _ => "#6f062f",
+            },
+            Self::ColorChartsPaletteCategorical48 => match mode {
+                Mode::Dark => "#ace9db",
+                _ => "#014b44",
+            },
+            Self::ColorChartsPaletteCategorical49 => match mode {
+                Mode::Dark => "#f5edff",
+                _ => "#431d84",
+            },
+            Self::ColorChartsPaletteCategorical50 => match mode {
+                Mode::Dark => "#ffd4bb",
+                _ => "#732c02",
+            },
+            Self::ColorChartsErrorBarMarker => match mode {
+                Mode::Dark => "#ffffff",
+                _ => "#131920",
+            },
+            Self::ColorSeverityDarkRed => match mode {
+                Mode::Dark => "#d63f38",
+                _ => "#870303",
+            },
+            Self::ColorSeverityRed => match mode {
+                Mode::Dark => "#fe6e73",
+                _ => "#ce3311",
+            },
+            Self::ColorSeverityOrange => "#f89256",
+            Self::ColorSeverityYellow => "#f2cd54",
+            Self::ColorSeverityGrey => "#656871",
+            Self::ColorBackgroundNotificationSeverityCritical => match mode {
+                Mode::Dark => "#d63f38",
+                _ => "#870303",
+            },
+            Self::ColorBackgroundNotificationSeverityHigh => match mode {
+                Mode::Dark => "#fe6e73",
+                _ => "#ce3311",
+            },
+            Self::ColorBackgroundNotificationSeverityMedium => "#f89256",
+            Self::ColorBackgroundNotificationSeverityLow => "#f2cd54",
+            Self::ColorBackgroundNotificationSeverityNeutral => "#656871",
+            Self::ColorTextNotificationSeverityCritical => match mode {
+                Mode::Dark => "#000000",
+                _ => "#f9f9fa",
+            },
+            Self::ColorTextNotificationSeverityHigh => match mode {
+                Mode::Dark => "#0f141a",
+                _ => "#f9f9fa",
+            },
+            Self::ColorTextNotificationSeverityMedium => "#0f141a",
+            Self::ColorTextNotificationSeverityLow => "#0f141a",
+            Self::ColorTextNotificationSeverityNeutral => "#f9f9fa",
+            Self::ColorGreyOpaque10 => "rgba(0, 0, 0, 0.1)",
+            Self::ColorGreyOpaque25 => "rgba(255, 255, 255, 0.25)",
+            Self::ColorGreyOpaque40 => "rgba(0, 0, 0, 0.4)",
+            Self::ColorGreyOpaque50 => "rgba(0, 0, 0, 0.5)",
+            Self::ColorGreyOpaque70 => match mode {
+                Mode::Dark => "rgba(15, 20, 26, 0.7)",
+                _ => "rgba(35, 43, 55, 0.7)",
+            },
+            Self::ColorGreyOpaque80 => "rgba(22, 25, 31, 0.8)",
+            Self::ColorGreyOpaque90 => "rgba(242, 243, 243, 0.9)",
+            Self::ColorGreyTransparent => match mode {
+                Mode::Dark => "rgba(15, 20, 26, 1)",
+                _ => "rgba(15, 20, 26, 0.12)",
+            },
+            Self::ColorGreyTransparentHeavy => match mode {
+                Mode::Dark => "rgba(15, 20, 26, 1)",
+                _ => "rgba(15, 20, 26, 0.12)",
+            },
+            Self::ColorGreyTransparentLight => match mode {
+                Mode::Dark => "rgba(15, 20, 26, 1)",
+                _ => "rgba(15, 20, 26, 0.12)",
+            },
+            Self::ColorBackgroundBadgeIcon => match mode {
+                Mode::Dark => "#ff7a7a",
+                _ => "#db0000",
+            },
+            Self::ColorBackgroundButtonLinkActive => match mode {
+                Mode::Dark => "#333843",
+                _ => "#d1f1ff",
+            },
+            Self::ColorBackgroundButtonLinkHover => match mode {
+                Mode::Dark => "#1b232d",
+                _ => "#f0fbff",
+            },
+            Self::ColorBackgroundButtonNormalActive => match mode {
+                Mode::Dark => "#333843",
+                _ => "#d1f1ff",
+            },
+            Self::ColorBackgroundButtonNormalDefault => match mode {
+                Mode::Dark => "#161d26",
+                _ => "#ffffff",
+            },
+            Self::ColorBackgroundButtonNormalDisabled => match mode {
+                Mode::Dark => "#161d26",
+                _ => "#ffffff",
+            },
+            Self::ColorBackgroundButtonNormalHover => match mode {
+                Mode::Dark => "#1b232d",
+                _ => "#f0fbff",
+            },
+            Self::ColorBackgroundToggleButtonNormalPressed => match mode {
+                Mode::Dark => "#333843",
+                _ => "#d1f1ff",
+            },
+            Self::ColorBackgroundButtonPrimaryActive => match mode {
+                Mode::Dark => "#42b4ff",
+                _ => "#002b66",
+            },
+            Self::ColorBackgroundButtonPrimaryDefault => match mode {
+                Mode::Dark => "#42b4ff",
+                _ => "#006ce0",
+            },
+            Self::ColorBackgroundButtonPrimaryDisabled => match mode {
+                Mode::Dark => "#232b37",
+                _ => "#ebebf0",
+            },
+            Self::ColorBackgroundButtonPrimaryHover => match mode {
+                Mode::Dark => "#75cfff",
+                _ => "#002b66",
+            },
+            Self::ColorBackgroundDirectionButtonActive => "#232b37",
+            Self::ColorBackgroundDirectionButtonDefault => "#424650",
+            Self::ColorBackgroundDirectionButtonDisabled => match mode {
+                Mode::Dark => "#232b37",
+                _ => "#ebebf0",
+            },
+            Self::ColorBackgroundDirectionButtonHover => "#333843",
+            Self::ColorTextDirectionButtonDefault => "#ffffff",
+            Self::ColorTextDirectionButtonDisabled => match mode {
+                Mode::Dark => "#656871",
+                _ => "#b4b4bb",
+            },
+            Self::ColorBackgroundCalendarCurrentDate => match mode {
+                Mode::Dark => "#333843",
+                _ => "#f3f3f7",
+            },
+            Self::ColorBackgroundCellShaded => match mode {
+                Mode::Dark => "#1b232d",
+                _ => "#f6f6f9",
+            },
+            Self::ColorBackgroundCodeEditorGutterActiveLineDefault => match mode {
+                Mode::Dark => "#8c8c94",
+                _ => "#656871",
+            },
+            Self::ColorBackgroundCodeEditorGutterActiveLineError => match mode {
+                Mode::Dark => "#ff7a7a",
+                _ => "#db0000",
+            },
+            Self::ColorBackgroundCodeEditorGutterDefault => match mode {
+                Mode::Dark => "#1b232d",
+                _ => "#f3f3f7",
+            },
+            Self::ColorBackgroundCodeEditorLoading => match mode {
+                Mode::Dark => "#1b232d",
+                _ => "#f9f9fa",
+            },
+            Self::ColorBackgroundCodeEditorPaneItemHover => match mode {
+                Mode::Dark => "#333843",
+                _ => "#ebebf0",
+            },
+            Self::ColorBackgroundCodeEditorStatusBar => match mode {
+                Mode::Dark => "#1b232d",
+                _ => "#f3f3f7",
+            },
+            Self::ColorBackgroundContainerContent => match mode {
+                Mode::Dark => "#161d26",
+                _ => "#ffffff",
+            },
+            Self::ColorBackgroundContainerHeader => match mode {
+                Mode::Dark => "#161d26",
+                _ => "#ffffff",
+            },
+            Self::ColorBackgroundControlChecked => match mode {
+                Mode::Dark => "#42b4ff",
+                _ => "#006ce0",
+            },
+            Self::ColorBackgroundControlDefault => match mode {
+                Mode::Dark => "#161d26",
+                _ => "#ffffff",
+            },
+            Self::ColorBackgroundControlDisabled => match mode {
+                Mode::Dark => "#333843",
+                _ => "#dedee3",
+            },
+            Self::ColorBackgroundDropdownItemDefault => match mode {
+                Mode::Dark => "#1b232d",
+                _ => "#ffffff",
+            },
+            Self::ColorBackgroundDropdownItemDimmed => "transparent",
+            Self::ColorBackgroundDropdownItemFilterMatch => match mode {
+                Mode::Dark => "#333843",
+                _ => "#f0fbff",
+            },
+            Self::ColorBackgroundDropdownItemHover => match mode {
+                Mode::Dark => "#131920",
+                _ => "#f3f3f7",
+            },
+            Self::ColorBackgroundDropdownItemSelected => match mode {
+                Mode::Dark => "#001129",
+                _ => "#f0fbff",
+            },
+            Self::ColorBackgroundHomeHeader => "#0f141a",
+            Self::ColorBackgroundInlineCode => match mode {
+                Mode::Dark => "rgba(255, 255, 255, 0.1)",
+                _ => "rgba(0, 0, 0, 0.1)",
+            },
+            Self::ColorBackgroundInputDefault => match mode {
+                Mode::Dark => "#161d26",
+                _ => "#ffffff",
+            },
+            Self::ColorBackgroundInputDisabled => match mode {
+                Mode::Dark => "#1b232d",
+                _ => "#ebebf0",
+            },
+            Self::ColorBackgroundItemSelected => match mode {
+                Mode::Dark => "#001129",
+                _ => "#f0fbff",
+            },
+            Self::ColorBackgroundLayoutMain => match mode {
+                Mode::Dark => "#161d26",
+                _ => "#ffffff",
+            },
+            Self::ColorBackgroundLayoutMobilePanel => "#0f141a",
+            Self::ColorBackgroundLayoutPanelContent => match mode {
+                Mode::Dark => "#161d26",
+                _ => "#ffffff",
+            },
+            Self::ColorBackgroundLayoutPanelHover => match mode {
+                Mode::Dark => "#333843",
+                _ => "#ebebf0",
+            },
+            Self::ColorBackgroundLayoutToggleActive => "#424650",
+            Self::ColorBackgroundLayoutToggleDefault => "#424650",
+            Self::ColorBackgroundLayoutToggleHover => "#656871",
+            Self::ColorBackgroundLayoutToggleSelectedActive => match mode {
+                Mode::Dark => "#42b4ff",
+                _ => "#006ce0",
+            },
+            Self::ColorBackgroundLayoutToggleSelectedDefault => match mode {
+                Mode::Dark => "#42b4ff",
+                _ => "#006ce0",
+            },
+            Self::ColorBackgroundLayoutToggleSelectedHover => match mode {
+                Mode::Dark => "#75cfff",
+                _ => "#004a9e",
+            },
+            Self::ColorBackgroundModalOverlay => match mode {
+                Mode::Dark => "rgba(15, 20, 26, 0.7)",
+                _ => "rgba(35, 43, 55, 0.7)",
+            },
+            Self::ColorBackgroundNotificationBlue => "#006ce0",
+            Self::ColorBackgroundNotificationGreen => "#00802f",
+            Self::ColorBackgroundNotificationGrey => match mode {
+                Mode::Dark => "#656871",
+                _ => "#424650",
+            },
+            Self::ColorBackgroundNotificationRed => "#db0000",
+            Self::ColorBackgroundNotificationYellow => "#ffe347",
+            Self::ColorBackgroundNotificationStackBar => "#232b37",
+            Self::ColorBackgroundNotificationStackBarActive => "#232b37",
+            Self::ColorBackgroundNotificationStackBarHover => "#424650",
+            Self::ColorBackgroundPopover => match mode {
+                Mode::Dark => "#1b232d",
+                _ => "#ffffff",
+            },
+            Self::ColorBackgroundProgressBarContentDefault => match mode {
+                Mode::Dark => "#42b4ff",
+                _ => "#006ce0",
+            },
+            Self::ColorBackgroundProgressBarContentInFlash => "#ffffff",
+            Self::ColorBackgroundProgressBarLayoutDefault => match mode {
+                Mode::Dark => "#333843",
+                _ => "#ebebf0",
+            },
+            Self::ColorBackgroundProgressBarLayoutInFlash => "rgba(255, 255, 255, 0.25)",
+            Self::ColorBackgroundSegmentActive => match mode {
+                Mode::Dark => "#42b4ff",
+                _ => "#006ce0",
+            },
+            Self::ColorBackgroundSegmentDefault => match mode {
+                Mode::Dark => "#161d26",
+                _ => "#ffffff",
+            },
+            Self::ColorBackgroundSegmentDisabled => match mode {
+                Mode::Dark => "#161d26",
+                _ => "#ffffff",
+            },
+            Self::ColorBackgroundSegmentHover => match mode {
+                Mode::Dark => "#1b232d",
+                _ => "#f0fbff",
+            },
+            Self::ColorBackgroundSegmentWrapper => match mode {
+                Mode::Dark => "#161d26",
+                _ => "#ffffff",
+            },
+            Self::ColorBackgroundSliderRangeDefault => match mode {
+                Mode::Dark => "#42b4ff",
+                _ => "#006ce0",
+            },
+            Self::ColorBackgroundSliderRangeActive => match mode {
+                Mode::Dark => "#75cfff",
+                _ => "#004a9e",
+            },
+            Self::ColorBackgroundSliderHandleDefault => match mode {
+                Mode::Dark => "#42b4ff",
+                _ => "#006ce0",
+            },
+            Self::ColorBackgroundSliderHandleActive => match mode {
+                Mode::Dark => "#75cfff",
+                _ => "#004a9e",
+            },
+            Self::ColorBackgroundSliderTrackDefault => match mode {
+                Mode::Dark => "#656871",
+                _ => "#8c8c94",
+            },
+            Self::ColorBackgroundSliderHandleRing => match mode {
+                Mode::Dark => "#161d26",
+                _ => "#ffffff",
+            },
+            Self::ColorBackgroundSliderHandleErrorDefault => match mode {
+                Mode::Dark => "#ff7a7a",
+                _ => "#db0000",
+            },
+            Self::ColorBackgroundSliderHandleErrorActive => match mode {
+                Mode::Dark => "#ff7a7a",
+                _ => "#db0000",
+            },
+            Self::ColorBackgroundSliderHandleWarningDefault => match mode {
+                Mode::Dark => "#fbd332",
+                _ => "#855900",
+            },
+            Self::ColorBackgroundSliderHandleWarningActive => match mode {
+                Mode::Dark => "#fbd332",
+                _ => "#855900",
+            },
+            Self::ColorBackgroundSliderRangeErrorDefault => match mode {
+                Mode::Dark => "#ff7a7a",
+                _ => "#db0000",
+            },
+            Self::ColorBackgroundSliderRangeErrorActive => match mode {
+                Mode::Dark => "#ff7a7a",
+                _ => "#db0000",
+            },
+            Self::ColorBackgroundSliderRangeWarningDefault => match mode {
+                Mode::Dark => "#fbd332",
+                _ => "#855900",
+            },
+            Self::ColorBackgroundSliderRangeWarningActive => match mode {
+                Mode::Dark => "#fbd332",
+                _ => "#855900",
+            },
+            Self::ColorBackgroundStatusError => match mode {
+                Mode::Dark => "#1f0000",
+                _ => "#fff5f5",
+            },
+            Self::ColorBackgroundStatusInfo => match mode {
+                Mode::Dark => "#001129",
+                _ => "#f0fbff",
+            },
+            Self::ColorBackgroundDialog => match mode {
+                Mode::Dark => "#001129",
+                _ => "#f0fbff",
+            },
+            Self::ColorBackgroundStatusSuccess => match mode {
+                Mode::Dark => "#001401",
+                _ => "#effff1",
+            },
+            Self::ColorBackgroundStatusWarning => match mode {
+                Mode::Dark => "#191100",
+                _ => "#fffef0",
+            },
+            Self::ColorBackgroundTableHeader => match mode {
+                Mode::Dark => "#161d26",
+                _ => "#ffffff",
+            },
+            Self::ColorBackgroundTilesDisabled => match mode {
+                Mode::Dark => "#1b232d",
+                _ => "#ebebf0",
+            },
+            Self::ColorBackgroundToggleCheckedDisabled => match mode {
+                Mode::Dark => "#002b66",
+                _ => "#b8e7ff",
+            },
+            Self::ColorBackgroundToggleDefault => match mode {
+                Mode::Dark => "#8c8c94",
+                _ => "#424650",
+            },
+            Self::ColorBackgroundAvatarGenAi => {
+                "radial-gradient(circle farthest-corner at top right, #b8e7ff 0%, #0099ff 25%, #5c7fff 40% , #8575ff 60%, #962eff 80%)"
+            }
+            Self::ColorBackgroundAvatarDefault => "#424650",
+            Self::ColorTextAvatar => "#ffffff",
+            Self::ColorBackgroundLoadingBarGenAi => {
+                "linear-gradient(90deg, #b8e7ff 0%, #0099ff 10%, #5c7fff 24%, #8575ff 50%, #962eff 76%, #0099ff 90%, #b8e7ff 100%)"
+            }
+            Self::ColorBackgroundChatBubbleOutgoing => "transparent",
+            Self::ColorBackgroundChatBubbleIncoming => match mode {
+                Mode::Dark => "#0f141a",
+                _ => "#f6f6f9",
+            },
+            Self::ColorTextChatBubbleOutgoing => match mode {
+                Mode::Dark => "#c6c6cd",
+                _ => "#0f141a",
+            },
+            Self::ColorTextChatBubbleIncoming => match mode {
+                Mode::Dark => "#c6c6cd",
+                _ => "#0f141a",
+            },
+            Self::ColorBorderButtonNormalActive => match mode {
+                Mode::Dark => "#75cfff",
+                _ => "#002b66",
+            },
+            Self::ColorBorderButtonNormalDefault => match mode {
+                Mode::Dark => "#42b4ff",
+                _ => "#006ce0",
+            },
+            Self::ColorBorderToggleButtonNormalPressed => match mode {
+                Mode::Dark => "#42b4ff",
+                _ => "#006ce0",
+            },
+            Self::ColorBorderButtonNormalDisabled => match mode {
+                Mode::Dark => "#656871",
+                _ => "#b4b4bb",
+            },
+            Self::ColorTextButtonNormalDisabled => "#8c8c94",
+            Self::ColorBorderButtonNormalHover => match mode {
+                Mode::Dark => "#75cfff",
+                _ => "#002b66",
+            },
+            Self::ColorTextButtonIconDisabled => "#8c8c94",
+            Self::ColorBorderButtonPrimaryDisabled => match mode {
+                Mode::Dark => "#232b37",
+                _ => "#ebebf0",
+            },
+            Self::ColorTextButtonPrimaryDisabled => "#8c8c94",
+            Self::ColorItemSelected => match mode {
+                Mode::Dark => "#42b4ff",
+                _ => "#006ce0",
+            },
+            Self::ColorBorderCalendarGrid => "transparent",
+            Self::ColorBorderCalendarGridSelectedFocusRing => match mode {
+                Mode::Dark => "#161d26",
+                _ => "#f9f9fa",
+            },
+            Self::ColorBorderCellShaded => match mode {
+                Mode::Dark => "#333843",
+                _ => "#dedee3",
+            },
+            Self::ColorBorderCodeEditorAceActiveLineLightTheme => "#dedee3",
+            Self::ColorBorderCodeEditorAceActiveLineDarkTheme => "#656871",
+            Self::ColorBorderCodeEditorDefault => match mode {
+                Mode::Dark => "#656871",
+                _ => "#dedee3",
+            },
+            Self::ColorBorderCodeEditorPaneItemHover => match mode {
+                Mode::Dark => "#656871",
+                _ => "#8c8c94",
+            },
+            Self::ColorBorderContainerDivider => "transparent",
+            Self::ColorBorderContainerTop => "transparent",
+            Self::ColorBorderControlChecked => match mode {
+                Mode::Dark => "#42b4ff",
+                _ => "#006ce0",
+            },
+            Self::ColorBorderControlDefault => "#8c8c94",
+            Self::ColorBorderControlDisabled => match mode {
+                Mode::Dark => "#333843",
+                _ => "#dedee3",
+            },
+            Self::ColorBorderDividerActive => match mode {
+                Mode::Dark => "#f9f9fa",
+                _ => "#0f141a",
+            },
+            Self::ColorBorderDividerDefault => match mode {
+                Mode::Dark => "#424650",
+                _ => "#c6c6cd",
+            },
+            Self::ColorBorderDividerPanelBottom => match mode {
+                Mode::Dark => "#424650",
+                _ => "#c6c6cd",
+            },
+            Self::ColorBorderDividerPanelSide => match mode {
+                Mode::Dark => "#424650",
+                _ => "#c6c6cd",
+            },
+            Self::ColorBorderDividerSecondary => match mode {
+                Mode::Dark => "#232b37",
+                _ => "#ebebf0",
+            },
+            Self::ColorBorderDropdownContainer => match mode {
+                Mode::Dark => "#656871",
+                _ => "#b4b4bb",
+            },
+            Self::ColorBorderDropdownGroup => match mode {
+                Mode::Dark => "#424650",
+                _ => "#c6c6cd",
+            },
+            Self::ColorBorderDropdownItemDefault => match mode {
+                Mode::Dark => "#424650",
+                _ => "#c6c6cd",
+            },
+            Self::ColorBorderDropdownItemHover => match mode {
+                Mode::Dark => "#656871",
+                _ => "#8c8c94",
+            },
+            Self::ColorBorderDropdownItemDimmedHover => "#8c8c94",
+            Self::ColorBorderDropdownItemSelected => match mode {
+                Mode::Dark => "#42b4ff",
+                _ => "#006ce0",
+            },
+            Self::ColorBorderDropdownItemTop => "transparent",
+            Self::ColorBorderEditableCellHover => match mode {
+                Mode::Dark => "#656871",
+                _ => "#8c8c94",
+            },
+            Self::ColorBorderInputDefault => match mode {
+                Mode::Dark => "#656871",
+                _ => "#8c8c94",
+            },
+            Self::ColorBorderInputDisabled => match mode {
+                Mode::Dark => "#1b232d",
+                _ => "#ebebf0",
+            },
+            Self::ColorBorderInputFocused => match mode {
+                Mode::Dark => "#42b4ff",
+                _ => "#006ce0",
+            },
+            Self::ColorBorderItemFocused => match mode {
+                Mode::Dark => "#42b4ff",
+                _ => "#006ce0",
+            },
+            Self::ColorBorderDropdownItemFocused => match mode {
+                Mode::Dark => "#dedee3",
+                _ => "#424650",
+            },
+            Self::ColorBorderItemPlaceholder => match mode {
+                Mode::Dark => "#42b4ff",
+                _ => "#006ce0",
+            },
+            Self::ColorBorderItemSelected => match mode {
+                Mode::Dark => "#42b4ff",
+                _ => "#006ce0",
+            },
+            Self::ColorBorderLayout => match mode {
+                Mode::Dark => "#424650",
+                _ => "#c6c6cd",
+            },
+            Self::ColorBorderNotificationStackBar => "#232b37",
+            Self::ColorBorderPanelHeader => match mode {
+                Mode::Dark => "#424650",
+                _ => "#c6c6cd",
+            },
+            Self::ColorBorderPopover => match mode {
+                Mode::Dark => "#656871",
+                _ => "#b4b4bb",
+            },
+            Self::ColorBorderSegmentActive => match mode {
+                Mode::Dark => "#dedee3",
+                _ => "#424650",
+            },
+            Self::ColorBorderSegmentDefault => match mode {
+                Mode::Dark => "#dedee3",
+                _ => "#424650",
+            },
+            Self::ColorBorderSegmentDisabled => match mode {
+                Mode::Dark => "#dedee3",
+                _ => "#424650",
+            },
+            Self::ColorBorderSegmentHover => match mode {
+                Mode::Dark => "#dedee3",
+                _ => "#424650",
+            },
+            Self::ColorBorderStatusError => match mode {
+                Mode::Dark => "#ff7a7a",
+                _ => "#db0000",
+            },
+            Self::ColorBorderStatusInfo => match mode {
+                Mode::Dark => "#42b4ff",
+                _ => "#006ce0",
+            },
+            Self::ColorBorderStatusSuccess => match mode {
+                Mode::Dark => "#2bb534",
+                _ => "#00802f",
+            },
+            Self::ColorBorderStatusWarning => match mode {
+                Mode::Dark => "#fbd332",
+                _ => "#855900",
+            },
+            Self::ColorBorderDialog => match mode {
+                Mode::Dark => "#42b4ff",
+                _ => "#006ce0",
+            },
+            Self::ColorBorderDividerInteractiveDefault => match mode {
+                Mode::Dark => "#dedee3",
+                _ => "#8c8c94",
+            },
+            Self::ColorBorderTabsDivider => match mode {
+                Mode::Dark => "#424650",
+                _ => "#c6c6cd",
+            },
+            Self::ColorBorderTabsShadow => match mode {
+                Mode::Dark => "rgba(15, 20, 26, 1)",
+                _ => "rgba(15, 20, 26, 0.12)",
+            },
+            Self::ColorBorderTabsUnderline => match mode {
+                Mode::Dark => "#42b4ff",
+                _ => "#006ce0",
+            },
+            Self::ColorBorderTilesDisabled => match mode {
+                Mode::Dark => "#1b232d",
+                _ => "#ebebf0",
+            },
+            Self::ColorBorderTutorial => match mode {
+                Mode::Dark => "#424650",
+                _ => "#dedee3",
+            },
+            Self::ColorForegroundControlDefault => match mode {
+                Mode::Dark => "#0f141a",
+                _ => "#ffffff",
+            },
+            Self::ColorForegroundControlDisabled => match mode {
+                Mode::Dark => "#161d26",
+                _ => "#ffffff",
+            },
+            Self::ColorForegroundControlReadOnly => match mode {
+                Mode::Dark => "#a4a4ad",
+                _ => "#656871",
+            },
+            Self::ColorShadowDefault => match mode {
+                Mode::Dark => "rgba(15, 20, 26, 1)",
+                _ => "rgba(15, 20, 26, 0.12)",
+            },
+            Self::ColorShadowMedium => match mode {
+                Mode::Dark => "rgba(15, 20, 26, 1)",
+                _ => "rgba(15, 20, 26, 0.12)",
+            },
+            Self::ColorShadowSide => match mode {
+                Mode::Dark => "rgba(15, 20, 26, 1)",
+                _ => "rgba(15, 20, 26, 0.12)",
+            },
+            Self::ColorStrokeChartLine => "#8c8c94",
+            Self::ColorStrokeCodeEditorGutterActiveLineDefault => match mode {
+                Mode::Dark => "#1b232d",
+                _ => "#dedee3",
+            },
+            Self::ColorStrokeCodeEditorGutterActiveLineHover => match mode {
+                Mode::Dark => "#0f141a",
+                _ => "#f9f9fa",
+            },
+            Self::ColorTextAccent => match mode {
+                Mode::Dark => "#42b4ff",
+                _ => "#006ce0",
+            },
+            Self::ColorTextBodyDefault => match mode {
+                Mode::Dark => "#c6c6cd",
+                _ => "#0f141a",
+            },
+            Self::ColorTextBodySecondary => match mode {
+                Mode::Dark => "#c6c6cd",
+                _ => "#424650",
+            },
+            Self::ColorTextBreadcrumbCurrent => match mode {
+                Mode::Dark => "#8c8c94",
+                _ => "#656871",
+            },
+            Self::ColorTextBreadcrumbIcon => match mode {
+                Mode::Dark => "#656871",
+                _ => "#8c8c94",
+            },
+            Self::ColorTextButtonInlineIconDefault => match mode {
+                Mode::Dark => "#42b4ff",
+                _ => "#006ce0",
+            },
+            Self::ColorTextButtonInlineIconDisabled => match mode {
+                Mode::Dark => "#656871",
+                _ => "#b4b4bb",
+            },
+            Self::ColorTextButtonInlineIconHover => match mode {
+                Mode::Dark => "#75cfff",
+                _ => "#002b66",
+            },
+            Self::ColorTextButtonNormalActive => match mode {
+                Mode::Dark => "#75cfff",
+                _ => "#002b66",
+            },
+            Self::ColorTextToggleButtonNormalPressed => match mode {
+                Mode::Dark => "#75cfff",
+                _ => "#002b66",
+            },
+            Self::ColorTextButtonNormalDefault => match mode {
+                Mode::Dark => "#42b4ff",
+                _ => "#006ce0",
+            },
+            Self::ColorTextButtonNormalHover => match mode {
+                Mode::Dark => "#75cfff",
+                _ => "#002b66",
+            },
+            Self::ColorTextLinkButtonNormalDefault => match mode {
+                Mode::Dark => "#42b4ff",
+                _ => "#006ce0",
+            },
+            Self::ColorTextLinkButtonNormalHover => match mode {
+                Mode::Dark => "#75cfff",
+                _ => "#002b66",
+            },
+            Self::ColorTextLinkButtonNormalActive => match mode {
+                Mode::Dark => "#75cfff",
+                _ => "#002b66",
+            },
+            Self::ColorTextButtonPrimaryActive => match mode {
+                Mode::Dark => "#0f141a",
+                _ => "#ffffff",
+            },
+            Self::ColorTextButtonPrimaryDefault => match mode {
+                Mode::Dark => "#0f141a",
+                _ => "#ffffff",
+            },
+            Self::ColorTextButtonPrimaryHover => match mode {
+                Mode::Dark => "#0f141a",
+                _ => "#ffffff",
+            },
+            Self::ColorTextCalendarDateHover => match mode {
+                Mode::Dark => "#dedee3",
+                _ => "#0f141a",
+            },
+            Self::ColorTextCalendarMonth => match mode {
+                Mode::Dark => "#a4a4ad",
+                _ => "#656871",
+            },
+            Self::ColorTextCodeEditorGutterActiveLine => match mode {
+                Mode::Dark => "#0f141a",
+                _ => "#ffffff",
+            },
+            Self::ColorTextCodeEditorGutterDefault => match mode {
+                Mode::Dark => "#dedee3",
+                _ => "#0f141a",
+            },
+            Self::ColorTextCodeEditorStatusBarDisabled => match mode {
+                Mode::Dark => "#656871",
+                _ => "#8c8c94",
+            },
+            Self::ColorTextCodeEditorTabButtonError => match mode {
+                Mode::Dark => "#0f141a",
+                _ => "#ffffff",
+            },
+            Self::ColorTextColumnHeader => match mode {
+                Mode::Dark => "#b4b4bb",
+                _ => "#424650",
+            },
+            Self::ColorTextColumnSortingIcon => match mode {
+                Mode::Dark => "#b4b4bb",
+                _ => "#424650",
+            },
+            Self::ColorTextControlDisabled => match mode {
+                Mode::Dark => "#656871",
+                _ => "#b4b4bb",
+            },
+            Self::ColorTextCounter => match mode {
+                Mode::Dark => "#a4a4ad",
+                _ => "#656871",
+            },
+            Self::ColorTextDisabled => match mode {
+                Mode::Dark => "#656871",
+                _ => "#b4b4bb",
+            },
+            Self::ColorTextDisabledInlineEdit => match mode {
+                Mode::Dark => "#b4b4bb",
+                _ => "#424650",
+            },
+            Self::ColorTextDropdownFooter => match mode {
+                Mode::Dark => "#a4a4ad",
+                _ => "#656871",
+            },
+            Self::ColorTextDropdownGroupLabel => match mode {
+                Mode::Dark => "#c6c6cd",
+                _ => "#424650",
+            },
+            Self::ColorTextDropdownItemDefault => match mode {
+                Mode::Dark => "#dedee3",
+                _ => "#0f141a",
+            },
+            Self::ColorTextDropdownItemDimmed => match mode {
+                Mode::Dark => "#656871",
+                _ => "#b4b4bb",
+            },
+            Self::ColorTextDropdownItemDisabled => match mode {
+                Mode::Dark => "#656871",
+                _ => "#b4b4bb",
+            },
+            Self::ColorTextDropdownItemFilterMatch => match mode {
+                Mode::Dark => "#75cfff",
+                _ => "#006ce0",
+            },
+            Self::ColorTextDropdownItemHighlighted => match mode {
+                Mode::Dark => "#ebebf0",
+                _ => "#0f141a",
+            },
+            Self::ColorTextDropdownItemSecondary => match mode {
+                Mode::Dark => "#a4a4ad",
+                _ => "#656871",
+            },
+            Self::ColorTextDropdownItemSecondaryHover => match mode {
+                Mode::Dark => "#dedee3",
+                _ => "#656871",
+            },
+            Self::ColorTextEmpty => match mode {
+                Mode::Dark => "#dedee3",
+                _ => "#656871",
+            },
+            Self::ColorTextExpandableSectionDefault => match mode {
+                Mode::Dark => "#dedee3",
+                _ => "#0f141a",
+            },
+            Self::ColorTextExpandableSectionHover => match mode {
+                Mode::Dark => "#42b4ff",
+                _ => "#006ce0",
+            },
+            Self::ColorTextExpandableSectionNavigationIconDefault => match mode {
+                Mode::Dark => "#dedee3",
+                _ => "#424650",
+            },
+            Self::ColorTextFormDefault => match mode {
+                Mode::Dark => "#dedee3",
+                _ => "#0f141a",
+            },
+            Self::ColorTextFormLabel => match mode {
+                Mode::Dark => "#dedee3",
+                _ => "#0f141a",
+            },
+            Self::ColorTextFormSecondary => match mode {
+                Mode::Dark => "#a4a4ad",
+                _ => "#656871",
+            },
+            Self::ColorTextGroupLabel => match mode {
+                Mode::Dark => "#c6c6cd",
+                _ => "#424650",
+            },
+            Self::ColorTextLabelGenAi => match mode {
+                Mode::Dark => "#bf80ff",
+                _ => "#7300e5",
+            },
+            Self::ColorTextHeadingDefault => match mode {
+                Mode::Dark => "#ebebf0",
+                _ => "#0f141a",
+            },
+            Self::ColorTextHeadingSecondary => match mode {
+                Mode::Dark => "#a4a4ad",
+                _ => "#424650",
+            },
+            Self::ColorTextHomeHeaderDefault => "#ebebf0",
+            Self::ColorTextHomeHeaderSecondary => "#c6c6cd",
+            Self::ColorTextIconCaret => match mode {
+                Mode::Dark => "#a4a4ad",
+                _ => "#8c8c94",
+            },
+            Self::ColorTextIconSubtle => match mode {
+                Mode::Dark => "#b4b4bb",
+                _ => "#656871",
+            },
+            Self::ColorTextInputDisabled => match mode {
+                Mode::Dark => "#656871",
+                _ => "#b4b4bb",
+            },
+            Self::ColorTextInputPlaceholder => match mode {
+                Mode::Dark => "#a4a4ad",
+                _ => "#656871",
+            },
+            Self::ColorTextInputPlaceholderDisabled => match mode {
+                Mode::Dark => "#656871",
+                _ => "#b4b4bb",
+            },
+            Self::ColorTextInteractiveActive => match mode {
+                Mode::Dark => "#f9f9fa",
+                _ => "#0f141a",
+            },
+            Self::ColorTextInteractiveDefault => match mode {
+                Mode::Dark => "#dedee3",
+                _ => "#424650",
+            },
+            Self::ColorTextInteractiveDisabled => match mode {
+                Mode::Dark => "#656871",
+                _ => "#b4b4bb",
+            },
+            Self::ColorTextInteractiveHover => match mode {
+                Mode::Dark => "#f9f9fa",
+                _ => "#0f141a",
+            },
+            Self::ColorTextToggleButtonIconPressed => match mode {
+                Mode::Dark => "#f9f9fa",
+                _ => "#0f141a",
+            },
+            Self::ColorTextInteractiveInvertedDefault => "#dedee3",
+            Self::ColorTextInteractiveInvertedHover => "#f9f9fa",
+            Self::ColorTextInverted => match mode {
+                Mode::Dark => "#0f141a",
+                _ => "#ffffff",
+            },
+            Self::ColorTextLabel => match mode {
+                Mode::Dark => "#dedee3",
+                _ => "#0f141a",
+            },
+            Self::ColorTextLayoutToggle => "#ffffff",
+            Self::ColorTextLayoutToggleActive => match mode {
+                Mode::Dark => "#161d26",
+                _ => "#ffffff",
+            },
+            Self::ColorTextLayoutToggleHover => match mode {
+                Mode::Dark => "#42b4ff",
+                _ => "#006ce0",
+            },
+            Self::ColorTextLayoutToggleSelected => match mode {
+                Mode::Dark => "#0f141a",
+                _ => "#ffffff",
+            },
+            Self::ColorTextLinkDefault => match mode {
+                Mode::Dark => "#42b4ff",
+                _ => "#006ce0",
+            },
+            Self::ColorTextLinkHover => match mode {
+                Mode::Dark => "#75cfff",
+                _ => "#002b66",
+            },
+            Self::ColorTextLinkInvertedHover => "#ffffff",
+            Self::ColorTextLinkButtonUnderline => "transparent",
+            Self::ColorTextLinkButtonUnderlineHover => "transparent",
+            Self::ColorTextNotificationDefault => "#f9f9fa",
+            Self::ColorTextNotificationStackBar => "#ffffff",
+            Self::ColorTextNotificationYellow => "#0f141a",
+            Self::ColorTextPaginationPageNumberActiveDisabled => match mode {
+                Mode::Dark => "#656871",
+                _ => "#b4b4bb",
+            },
+            Self::ColorTextPaginationPageNumberDefault => match mode {
+                Mode::Dark => "#b4b4bb",
+                _ => "#424650",
+            },
+            Self::ColorTextSegmentActive => match mode {
+                Mode::Dark => "#0f141a",
+                _ => "#ffffff",
+            },
+            Self::ColorTextSegmentDefault => match mode {
+                Mode::Dark => "#dedee3",
+                _ => "#424650",
+            },
+            Self::ColorTextSegmentHover => match mode {
+                Mode::Dark => "#75cfff",
+                _ => "#002b66",
+            },
+            Self::ColorTextSmall => match mode {
+                Mode::Dark => "#a4a4ad",
+                _ => "#656871",
+            },
+            Self::ColorTextStatusError => match mode {
+                Mode::Dark => "#ff7a7a",
+                _ => "#db0000",
+            },
+            Self::ColorTextStatusInactive => match mode {
+                Mode::Dark => "#a4a4ad",
+                _ => "#656871",
+            },
+            Self::ColorTextStatusInfo => match mode {
+                Mode::Dark => "#42b4ff",
+                _ => "#006ce0",
+            },
+            Self::ColorTextStatusSuccess => match mode {
+                Mode::Dark => "#2bb534",
+                _ => "#00802f",
+            },
+            Self::ColorTextStatusWarning => match mode {
+                Mode::Dark => "#fbd332",
+                _ => "#855900",
+            },
+            Self::ColorTextTopNavigationTitle => match mode {
+                Mode::Dark => "#f9f9fa",
+                _ => "#0f141a",
+            },
+            Self::ColorTextTutorialHotspotDefault => match mode {
+                Mode::Dark => "#42b4ff",
+                _ => "#006ce0",
+            },
+            Self::ColorTextTutorialHotspotHover => match mode {
+                Mode::Dark => "#75cfff",
+                _ => "#002b66",
+            },
+            Self::ColorBoardPlaceholderActive => match mode {
+                Mode::Dark => "#656871",
+                _ => "#ebebf0",
+            },
+            Self::ColorBoardPlaceholderHover => match mode {
+                Mode::Dark => "#006ce0",
+                _ => "#d1f1ff",
+            },
+            Self::ColorDragPlaceholderActive => match mode {
+                Mode::Dark => "#656871",
+                _ => "#ebebf0",
+            },
+            Self::ColorDragPlaceholderHover => match mode {
+                Mode::Dark => "#006ce0",
+                _ => "#d1f1ff",
+            },
+            Self::ColorDropzoneBackgroundDefault => match mode {
+                Mode::Dark => "#161d26",
+                _ => "#ffffff",
+            },
+            Self::ColorDropzoneBackgroundHover => match mode {
+                Mode::Dark => "#001129",
+                _ => "#f0fbff",
+            },
+            Self::ColorDropzoneTextDefault => match mode {
+                Mode::Dark => "#c6c6cd",
+                _ => "#424650",
+            },
+            Self::ColorDropzoneTextHover => match mode {
+                Mode::Dark => "#c6c6cd",
+                _ => "#424650",
+            },
+            Self::ColorDropzoneBorderDefault => match mode {
+                Mode::Dark => "#656871",
+                _ => "#8c8c94",
+            },
+            Self::ColorDropzoneBorderHover => match mode {
+                Mode::Dark => "#75cfff",
+                _ => "#002b66",
+            },
+            Self::ColorGapGlobalDrawer => match mode {
+                Mode::Dark => "#0f141a",
+                _ => "#ebebf0",
+            },
+            Self::ColorTreeViewConnectorLine => match mode {
+                Mode::Dark => "#dedee3",
+                _ => "#8c8c94",
+            },
+        }
+    }
+
     /// Get the CSS var() function for use in styles
     pub fn css_var(&self) -> String {
         format!("var({})", self.css_var_name())
@@ -1536,6 +4035,44 @@ pub enum FontToken {
 }
 
 impl FontToken {
+    /// All tokens in this group
+    pub const ALL: &'static [Self] = &[
+        Self::FontBoxValueLargeWeight,
+        Self::FontButtonLetterSpacing,
+        Self::FontChartDetailSize,
+        Self::FontDisplayLabelWeight,
+        Self::FontExpandableHeadingSize,
+        Self::FontFamilyBase,
+        Self::FontFamilyMonospace,
+        Self::FontHeaderH2DescriptionLineHeight,
+        Self::FontHeaderH2DescriptionSize,
+        Self::FontLinkButtonLetterSpacing,
+        Self::FontLinkButtonWeight,
+        Self::FontPanelHeaderLineHeight,
+        Self::FontPanelHeaderSize,
+        Self::FontSizeBodyM,
+        Self::FontSizeBodyS,
+        Self::FontSizeDisplayL,
+        Self::FontSizeHeadingXl,
+        Self::FontSizeHeadingL,
+        Self::FontSizeHeadingM,
+        Self::FontSizeHeadingS,
+        Self::FontSizeHeadingXs,
+        Self::FontSmoothingMozOsx,
+        Self::FontSmoothingWebkit,
+        Self::FontTabsDisabledWeight,
+        Self::FontTabsLineHeight,
+        Self::FontTabsSize,
+        Self::FontWayfindingLinkActiveWeight,
+        Self::FontWeightButton,
+        Self::FontWeightHeadingXl,
+        Self::FontWeightHeadingL,
+        Self::FontWeightHeadingM,
+        Self::FontWeightHeadingS,
+        Self::FontWeightHeadingXs,
+        Self::FontWeightHeavy,
+    ];
+
     /// Get the CSS custom property name for this token
     pub fn css_var_name(&self) -> &'static str {
         match self {
@@ -1546,7 +4083,9 @@ impl FontToken {
             Self::FontExpandableHeadingSize => "--awsui-font-expandable-heading-size",
             Self::FontFamilyBase => "--awsui-font-family-base",
             Self::FontFamilyMonospace => "--awsui-font-family-monospace",
-            Self::FontHeaderH2DescriptionLineHeight => "--awsui-font-header-h2-description-line-height",
+            Self::FontHeaderH2DescriptionLineHeight => {
+                "--awsui-font-header-h2-description-line-height"
+            }
             Self::FontHeaderH2DescriptionSize => "--awsui-font-header-h2-description-size",
             Self::FontLinkButtonLetterSpacing => "--awsui-font-link-button-letter-spacing",
             Self::FontLinkButtonWeight => "--awsui-font-link-button-weight",
@@ -1576,6 +4115,51 @@ impl FontToken {
         }
     }
 
+    /// Get the resolved value of this token in a mode
+    ///
+    /// Modes that do not apply to the token, such as density modes for
+    /// colors, give its default value.
+    pub fn value(&self, _mode: Mode) -> &'static str {
+        match self {
+            Self::FontBoxValueLargeWeight => "700",
+            Self::FontButtonLetterSpacing => "0.005em",
+            Self::FontChartDetailSize => "12px",
+            Self::FontDisplayLabelWeight => "700",
+            Self::FontExpandableHeadingSize => "16px",
+            Self::FontFamilyBase => "'Open Sans', 'Helvetica Neue', Roboto, Arial, sans-serif",
+            Self::FontFamilyMonospace => {
+                "Monaco, Menlo, Consolas, 'Courier Prime', Courier, 'Courier New', monospace"
+            }
+            Self::FontHeaderH2DescriptionLineHeight => "20px",
+            Self::FontHeaderH2DescriptionSize => "14px",
+            Self::FontLinkButtonLetterSpacing => "0.005em",
+            Self::FontLinkButtonWeight => "700",
+            Self::FontPanelHeaderLineHeight => "22px",
+            Self::FontPanelHeaderSize => "18px",
+            Self::FontSizeBodyM => "14px",
+            Self::FontSizeBodyS => "12px",
+            Self::FontSizeDisplayL => "42px",
+            Self::FontSizeHeadingXl => "24px",
+            Self::FontSizeHeadingL => "20px",
+            Self::FontSizeHeadingM => "18px",
+            Self::FontSizeHeadingS => "16px",
+            Self::FontSizeHeadingXs => "14px",
+            Self::FontSmoothingMozOsx => "grayscale",
+            Self::FontSmoothingWebkit => "antialiased",
+            Self::FontTabsDisabledWeight => "700",
+            Self::FontTabsLineHeight => "20px",
+            Self::FontTabsSize => "16px",
+            Self::FontWayfindingLinkActiveWeight => "700",
+            Self::FontWeightButton => "700",
+            Self::FontWeightHeadingXl => "700",
+            Self::FontWeightHeadingL => "700",
+            Self::FontWeightHeadingM => "700",
+            Self::FontWeightHeadingS => "700",
+            Self::FontWeightHeadingXs => "700",
+            Self::FontWeightHeavy => "700",
+        }
+    }
+
     /// Get the CSS var() function for use in styles
     pub fn css_var(&self) -> String {
         format!("var({})", self.css_var_name())
@@ -1743,6 +4327,124 @@ pub enum OtherToken {
 }
 
 impl OtherToken {
+    /// All tokens in this group
+    pub const ALL: &'static [Self] = &[
+        Self::LetterSpacingBodyS,
+        Self::LetterSpacingDisplayL,
+        Self::LetterSpacingHeadingXl,
+        Self::LetterSpacingHeadingL,
+        Self::LetterSpacingHeadingM,
+        Self::LetterSpacingHeadingS,
+        Self::LineHeightBodyM,
+        Self::LineHeightBodyS,
+        Self::LineHeightDisplayL,
+        Self::LineHeightHeadingXl,
+        Self::LineHeightHeadingL,
+        Self::LineHeightHeadingM,
+        Self::LineHeightHeadingS,
+        Self::LineHeightHeadingXs,
+        Self::SpaceAlertActionLeft,
+        Self::SpaceAlertHorizontal,
+        Self::SpaceAlertMessageRight,
+        Self::SpaceAlertVertical,
+        Self::SpaceButtonFocusOutlineGutter,
+        Self::SpaceButtonHorizontal,
+        Self::SpaceButtonIconFocusOutlineGutterVertical,
+        Self::SpaceButtonIconOnlyHorizontal,
+        Self::SpaceButtonInlineIconFocusOutlineGutter,
+        Self::SpaceButtonModalDismissVertical,
+        Self::SpaceCalendarGridFocusOutlineGutter,
+        Self::SpaceCalendarGridSelectedFocusOutlineGutter,
+        Self::SpaceCalendarGridGutter,
+        Self::SpaceCardHorizontal,
+        Self::SpaceCardVertical,
+        Self::SpaceCodeEditorStatusFocusOutlineGutter,
+        Self::SpaceContainerContentTop,
+        Self::SpaceContainerHeaderTop,
+        Self::SpaceContainerHeaderBottom,
+        Self::SpaceContainerHorizontal,
+        Self::SpaceContentHeaderPaddingBottom,
+        Self::SpaceDarkHeaderOverlapDistance,
+        Self::SpaceExpandableSectionIconOffsetTop,
+        Self::SpaceFieldHorizontal,
+        Self::SpaceFieldIconOffset,
+        Self::SpaceFilteringTokenDismissButtonFocusOutlineGutter,
+        Self::SpaceFilteringTokenOperationSelectFocusOutlineGutter,
+        Self::SpaceFlashbarActionLeft,
+        Self::SpaceFlashbarDismissRight,
+        Self::SpaceFlashbarHorizontal,
+        Self::SpaceFlashbarVertical,
+        Self::SpaceGridGutter,
+        Self::SpaceKeyValueGap,
+        Self::SpaceLayoutContentBottom,
+        Self::SpaceLayoutContentHorizontal,
+        Self::SpaceLayoutToggleDiameter,
+        Self::SpaceLayoutTogglePadding,
+        Self::SpaceModalContentBottom,
+        Self::SpaceModalHorizontal,
+        Self::SpacePanelContentBottom,
+        Self::SpacePanelContentTop,
+        Self::SpacePanelDividerMarginHorizontal,
+        Self::SpacePanelHeaderVertical,
+        Self::SpacePanelNavLeft,
+        Self::SpacePanelSideLeft,
+        Self::SpacePanelSideRight,
+        Self::SpacePanelSplitTop,
+        Self::SpacePanelSplitBottom,
+        Self::SpaceSegmentedControlFocusOutlineGutter,
+        Self::SpaceTabsContentTop,
+        Self::SpaceTabsFocusOutlineGutter,
+        Self::SpaceTableContentBottom,
+        Self::SpaceTableEmbeddedHeaderTop,
+        Self::SpaceTableFooterHorizontal,
+        Self::SpaceTableHeaderFocusOutlineGutter,
+        Self::SpaceTableHeaderHorizontal,
+        Self::SpaceTableHeaderToolsBottom,
+        Self::SpaceTableHeaderToolsFullPageBottom,
+        Self::SpaceTableHorizontal,
+        Self::SpaceTreeViewIndentation,
+        Self::SpaceTileGutter,
+        Self::SpaceScaled2xNone,
+        Self::SpaceScaled2xXxxs,
+        Self::SpaceScaled2xXxs,
+        Self::SpaceScaled2xXs,
+        Self::SpaceScaled2xS,
+        Self::SpaceScaled2xM,
+        Self::SpaceScaled2xL,
+        Self::SpaceScaled2xXl,
+        Self::SpaceScaled2xXxl,
+        Self::SpaceScaled2xXxxl,
+        Self::SpaceScaledNone,
+        Self::SpaceScaledXxxs,
+        Self::SpaceScaledXxs,
+        Self::SpaceScaledXs,
+        Self::SpaceScaledS,
+        Self::SpaceScaledM,
+        Self::SpaceScaledL,
+        Self::SpaceScaledXl,
+        Self::SpaceScaledXxl,
+        Self::SpaceScaledXxxl,
+        Self::SpaceStaticXxxs,
+        Self::SpaceStaticXxs,
+        Self::SpaceStaticXs,
+        Self::SpaceStaticS,
+        Self::SpaceStaticM,
+        Self::SpaceStaticL,
+        Self::SpaceStaticXl,
+        Self::SpaceStaticXxl,
+        Self::SpaceStaticXxxl,
+        Self::SpaceNone,
+        Self::SpaceXxxs,
+        Self::SpaceXxs,
+        Self::SpaceXs,
+        Self::SpaceS,
+        Self::SpaceM,
+        Self::SpaceL,
+        Self::SpaceXl,
+        Self::SpaceXxl,
+        Self::SpaceXxxl,
+    ];
+
     /// Get the CSS custom property name for this token
     pub fn css_var_name(&self) -> &'static str {
         match self {
@@ -1766,27 +4468,43 @@ impl OtherToken {
             Self::SpaceAlertVertical => "--awsui-space-alert-vertical",
             Self::SpaceButtonFocusOutlineGutter => "--awsui-space-button-focus-outline-gutter",
             Self::SpaceButtonHorizontal => "--awsui-space-button-horizontal",
-            Self::SpaceButtonIconFocusOutlineGutterVertical => "--awsui-space-button-icon-focus-outline-gutter-vertical",
+            Self::SpaceButtonIconFocusOutlineGutterVertical => {
+                "--awsui-space-button-icon-focus-outline-gutter-vertical"
+            }
             Self::SpaceButtonIconOnlyHorizontal => "--awsui-space-button-icon-only-horizontal",
-            Self::SpaceButtonInlineIconFocusOutlineGutter => "--awsui-space-button-inline-icon-focus-outline-gutter",
+            Self::SpaceButtonInlineIconFocusOutlineGutter => {
+                "--awsui-space-button-inline-icon-focus-outline-gutter"
+            }
             Self::SpaceButtonModalDismissVertical => "--awsui-space-button-modal-dismiss-vertical",
-            Self::SpaceCalendarGridFocusOutlineGutter => "--awsui-space-calendar-grid-focus-outline-gutter",
-            Self::SpaceCalendarGridSelectedFocusOutlineGutter => "--awsui-space-calendar-grid-selected-focus-outline-gutter",
+            Self::SpaceCalendarGridFocusOutlineGutter => {
+                "--awsui-space-calendar-grid-focus-outline-gutter"
+            }
+            Self::SpaceCalendarGridSelectedFocusOutlineGutter => {
+                "--awsui-space-calendar-grid-selected-focus-outline-gutter"
+            }
             Self::SpaceCalendarGridGutter => "--awsui-space-calendar-grid-gutter",
             Self::SpaceCardHorizontal => "--awsui-space-card-horizontal",
             Self::SpaceCardVertical => "--awsui-space-card-vertical",
-            Self::SpaceCodeEditorStatusFocusOutlineGutter => "--awsui-space-code-editor-status-focus-outline-gutter",
+            Self::SpaceCodeEditorStatusFocusOutlineGutter => {
+                "--awsui-space-code-editor-status-focus-outline-gutter"
+            }
             Self::SpaceContainerContentTop => "--awsui-space-container-content-top",
             Self::SpaceContainerHeaderTop => "--awsui-space-container-header-top",
             Self::SpaceContainerHeaderBottom => "--awsui-space-container-header-bottom",
             Self::SpaceContainerHorizontal => "--awsui-space-container-horizontal",
             Self::SpaceContentHeaderPaddingBottom => "--awsui-space-content-header-padding-bottom",
             Self::SpaceDarkHeaderOverlapDistance => "--awsui-space-dark-header-overlap-distance",
-            Self::SpaceExpandableSectionIconOffsetTop => "--awsui-space-expandable-section-icon-offset-top",
+            Self::SpaceExpandableSectionIconOffsetTop => {
+                "--awsui-space-expandable-section-icon-offset-top"
+            }
             Self::SpaceFieldHorizontal => "--awsui-space-field-horizontal",
             Self::SpaceFieldIconOffset => "--awsui-space-field-icon-offset",
-            Self::SpaceFilteringTokenDismissButtonFocusOutlineGutter => "--awsui-space-filtering-token-dismiss-button-focus-outline-gutter",
-            Self::SpaceFilteringTokenOperationSelectFocusOutlineGutter => "--awsui-space-filtering-token-operation-select-focus-outline-gutter",
+            Self::SpaceFilteringTokenDismissButtonFocusOutlineGutter => {
+                "--awsui-space-filtering-token-dismiss-button-focus-outline-gutter"
+            }
+            Self::SpaceFilteringTokenOperationSelectFocusOutlineGutter => {
+                "--awsui-space-filtering-token-operation-select-focus-outline-gutter"
+            }
             Self::SpaceFlashbarActionLeft => "--awsui-space-flashbar-action-left",
             Self::SpaceFlashbarDismissRight => "--awsui-space-flashbar-dismiss-right",
             Self::SpaceFlashbarHorizontal => "--awsui-space-flashbar-horizontal",
@@ -1801,23 +4519,31 @@ impl OtherToken {
             Self::SpaceModalHorizontal => "--awsui-space-modal-horizontal",
             Self::SpacePanelContentBottom => "--awsui-space-panel-content-bottom",
             Self::SpacePanelContentTop => "--awsui-space-panel-content-top",
-            Self::SpacePanelDividerMarginHorizontal => "--awsui-space-panel-divider-margin-horizontal",
+            Self::SpacePanelDividerMarginHorizontal => {
+                "--awsui-space-panel-divider-margin-horizontal"
+            }
             Self::SpacePanelHeaderVertical => "--awsui-space-panel-header-vertical",
             Self::SpacePanelNavLeft => "--awsui-space-panel-nav-left",
             Self::SpacePanelSideLeft => "--awsui-space-panel-side-left",
             Self::SpacePanelSideRight => "--awsui-space-panel-side-right",
             Self::SpacePanelSplitTop => "--awsui-space-panel-split-top",
             Self::SpacePanelSplitBottom => "--awsui-space-panel-split-bottom",
-            Self::SpaceSegmentedControlFocusOutlineGutter => "--awsui-space-segmented-control-focus-outline-gutter",
+            Self::SpaceSegmentedControlFocusOutlineGutter => {
+                "--awsui-space-segmented-control-focus-outline-gutter"
+            }
             Self::SpaceTabsContentTop => "--awsui-space-tabs-content-top",
             Self::SpaceTabsFocusOutlineGutter => "--awsui-space-tabs-focus-outline-gutter",
             Self::SpaceTableContentBottom => "--awsui-space-table-content-bottom",
             Self::SpaceTableEmbeddedHeaderTop => "--awsui-space-table-embedded-header-top",
             Self::SpaceTableFooterHorizontal => "--awsui-space-table-footer-horizontal",
-            Self::SpaceTableHeaderFocusOutlineGutter => "--awsui-space-table-header-focus-outline-gutter",
+            Self::SpaceTableHeaderFocusOutlineGutter => {
+                "--awsui-space-table-header-focus-outline-gutter"
+            }
             Self::SpaceTableHeaderHorizontal => "--awsui-space-table-header-horizontal",
             Self::SpaceTableHeaderToolsBottom => "--awsui-space-table-header-tools-bottom",
-            Self::SpaceTableHeaderToolsFullPageBottom => "--awsui-space-table-header-tools-full-page-bottom",
+            Self::SpaceTableHeaderToolsFullPageBottom => {
+                "--awsui-space-table-header-tools-full-page-bottom"
+            }
             Self::SpaceTableHorizontal => "--awsui-space-table-horizontal",
             Self::SpaceTreeViewIndentation => "--awsui-space-tree-view-indentation",
             Self::SpaceTileGutter => "--awsui-space-tile-gutter",
@@ -1863,6 +4589,252 @@ impl OtherToken {
         }
     }
 
+    /// Get the resolved value of this token in a mode
+    ///
+    /// Modes that do not apply to the token, such as density modes for
+    /// colors, give its default value.
+    pub fn value(&self, mode: Mode) -> &'static str {
+        match self {
+            Self::LetterSpacingBodyS => "0.005em",
+            Self::LetterSpacingDisplayL => "-0.03em",
+            Self::LetterSpacingHeadingXl => "-0.02em",
+            Self::LetterSpacingHeadingL => "-0.015em",
+            Self::LetterSpacingHeadingM => "-0.010em",
+            Self::LetterSpacingHeadingS => "-0.005em",
+            Self::LineHeightBodyM => "20px",
+            Self::LineHeightBodyS => "16px",
+            Self::LineHeightDisplayL => "48px",
+            Self::LineHeightHeadingXl => "30px",
+            Self::LineHeightHeadingL => "24px",
+            Self::LineHeightHeadingM => "22px",
+            Self::LineHeightHeadingS => "20px",
+            Self::LineHeightHeadingXs => "18px",
+            Self::SpaceAlertActionLeft => "12px",
+            Self::SpaceAlertHorizontal => "16px",
+            Self::SpaceAlertMessageRight => "4px",
+            Self::SpaceAlertVertical => match mode {
+                Mode::Compact => "4px",
+                _ => "8px",
+            },
+            Self::SpaceButtonFocusOutlineGutter => "4px",
+            Self::SpaceButtonHorizontal => match mode {
+                Mode::Compact => "16px",
+                _ => "20px",
+            },
+            Self::SpaceButtonIconFocusOutlineGutterVertical => "0px",
+            Self::SpaceButtonIconOnlyHorizontal => match mode {
+                Mode::Compact => "4px",
+                _ => "6px",
+            },
+            Self::SpaceButtonInlineIconFocusOutlineGutter => "0px",
+            Self::SpaceButtonModalDismissVertical => match mode {
+                Mode::Compact => "0px",
+                _ => "2px",
+            },
+            Self::SpaceCalendarGridFocusOutlineGutter => "-5px",
+            Self::SpaceCalendarGridSelectedFocusOutlineGutter => "-5px",
+            Self::SpaceCalendarGridGutter => "6px",
+            Self::SpaceCardHorizontal => "20px",
+            Self::SpaceCardVertical => match mode {
+                Mode::Compact => "12px",
+                _ => "16px",
+            },
+            Self::SpaceCodeEditorStatusFocusOutlineGutter => "-7px",
+            Self::SpaceContainerContentTop => "4px",
+            Self::SpaceContainerHeaderTop => "12px",
+            Self::SpaceContainerHeaderBottom => match mode {
+                Mode::Compact => "4px",
+                _ => "8px",
+            },
+            Self::SpaceContainerHorizontal => "20px",
+            Self::SpaceContentHeaderPaddingBottom => match mode {
+                Mode::Compact => "12px",
+                _ => "16px",
+            },
+            Self::SpaceDarkHeaderOverlapDistance => match mode {
+                Mode::Compact => "32px",
+                _ => "36px",
+            },
+            Self::SpaceExpandableSectionIconOffsetTop => match mode {
+                Mode::Compact => "0px",
+                _ => "4px",
+            },
+            Self::SpaceFieldHorizontal => "12px",
+            Self::SpaceFieldIconOffset => "36px",
+            Self::SpaceFilteringTokenDismissButtonFocusOutlineGutter => "-5px",
+            Self::SpaceFilteringTokenOperationSelectFocusOutlineGutter => "-5px",
+            Self::SpaceFlashbarActionLeft => "12px",
+            Self::SpaceFlashbarDismissRight => "0px",
+            Self::SpaceFlashbarHorizontal => "16px",
+            Self::SpaceFlashbarVertical => match mode {
+                Mode::Compact => "4px",
+                _ => "8px",
+            },
+            Self::SpaceGridGutter => match mode {
+                Mode::Compact => "16px",
+                _ => "20px",
+            },
+            Self::SpaceKeyValueGap => "0px",
+            Self::SpaceLayoutContentBottom => match mode {
+                Mode::Compact => "24px",
+                _ => "40px",
+            },
+            Self::SpaceLayoutContentHorizontal => match mode {
+                Mode::Compact => "16px",
+                _ => "24px",
+            },
+            Self::SpaceLayoutToggleDiameter => "36px",
+            Self::SpaceLayoutTogglePadding => "12px",
+            Self::SpaceModalContentBottom => match mode {
+                Mode::Compact => "8px",
+                _ => "16px",
+            },
+            Self::SpaceModalHorizontal => "20px",
+            Self::SpacePanelContentBottom => match mode {
+                Mode::Compact => "32px",
+                _ => "40px",
+            },
+            Self::SpacePanelContentTop => match mode {
+                Mode::Compact => "16px",
+                _ => "20px",
+            },
+            Self::SpacePanelDividerMarginHorizontal => "8px",
+            Self::SpacePanelHeaderVertical => match mode {
+                Mode::Compact => "16px",
+                _ => "20px",
+            },
+            Self::SpacePanelNavLeft => "28px",
+            Self::SpacePanelSideLeft => "28px",
+            Self::SpacePanelSideRight => match mode {
+                Mode::Compact => "20px",
+                _ => "24px",
+            },
+            Self::SpacePanelSplitTop => match mode {
+                Mode::Compact => "16px",
+                _ => "20px",
+            },
+            Self::SpacePanelSplitBottom => match mode {
+                Mode::Compact => "16px",
+                _ => "20px",
+            },
+            Self::SpaceSegmentedControlFocusOutlineGutter => "6px",
+            Self::SpaceTabsContentTop => match mode {
+                Mode::Compact => "8px",
+                _ => "12px",
+            },
+            Self::SpaceTabsFocusOutlineGutter => "-8px",
+            Self::SpaceTableContentBottom => "4px",
+            Self::SpaceTableEmbeddedHeaderTop => "0px",
+            Self::SpaceTableFooterHorizontal => "0px",
+            Self::SpaceTableHeaderFocusOutlineGutter => match mode {
+                Mode::Compact => "-1px",
+                _ => "0px",
+            },
+            Self::SpaceTableHeaderHorizontal => "0px",
+            Self::SpaceTableHeaderToolsBottom => "0px",
+            Self::SpaceTableHeaderToolsFullPageBottom => "4px",
+            Self::SpaceTableHorizontal => "20px",
+            Self::SpaceTreeViewIndentation => "24px",
+            Self::SpaceTileGutter => match mode {
+                Mode::Compact => "16px",
+                _ => "24px",
+            },
+            Self::SpaceScaled2xNone => "0px",
+            Self::SpaceScaled2xXxxs => match mode {
+                Mode::Compact => "0px",
+                _ => "2px",
+            },
+            Self::SpaceScaled2xXxs => match mode {
+                Mode::Compact => "0px",
+                _ => "4px",
+            },
+            Self::SpaceScaled2xXs => match mode {
+                Mode::Compact => "0px",
+                _ => "8px",
+            },
+            Self::SpaceScaled2xS => match mode {
+                Mode::Compact => "4px",
+                _ => "12px",
+            },
+            Self::SpaceScaled2xM => match mode {
+                Mode::Compact => "8px",
+                _ => "16px",
+            },
+            Self::SpaceScaled2xL => match mode {
+                Mode::Compact => "12px",
+                _ => "20px",
+            },
+            Self::SpaceScaled2xXl => match mode {
+                Mode::Compact => "16px",
+                _ => "24px",
+            },
+            Self::SpaceScaled2xXxl => match mode {
+                Mode::Compact => "20px",
+                _ => "32px",
+            },
+            Self::SpaceScaled2xXxxl => match mode {
+                Mode::Compact => "24px",
+                _ => "40px",
+            },
+            Self::SpaceScaledNone => "0px",
+            Self::SpaceScaledXxxs => match mode {
+                Mode::Compact => "0px",
+                _ => "2px",
+            },
+            Self::SpaceScaledXxs => match mode {
+                Mode::Compact => "2px",
+                _ => "4px",
+            },
+            Self::SpaceScaledXs => match mode {
+                Mode::Compact => "4px",
+                _ => "8px",
+            },
+            Self::SpaceScaledS => match mode {
+                Mode::Compact => "8px",
+                _ => "12px",
+            },
+            Self::SpaceScaledM => match mode {
+                Mode::Compact => "12px",
+                _ => "16px",
+            },
+            Self::SpaceScaledL => match mode {
+                Mode::Compact => "16px",
+                _ => "20px",
+            },
+            Self::SpaceScaledXl => match mode {
+                Mode::Compact => "20px",
+                _ => "24px",
+            },
+            Self::SpaceScaledXxl => match mode {
+                Mode::Compact => "24px",
+                _ => "32px",
+            },
+            Self::SpaceScaledXxxl => match mode {
+                Mode::Compact => "32px",
+                _ => "40px",
+            },
+            Self::SpaceStaticXxxs => "2px",
+            Self::SpaceStaticXxs => "4px",
+            Self::SpaceStaticXs => "8px",
+            Self::SpaceStaticS => "12px",
+            Self::SpaceStaticM => "16px",
+            Self::SpaceStaticL => "20px",
+            Self::SpaceStaticXl => "24px",
+            Self::SpaceStaticXxl => "32px",
+            Self::SpaceStaticXxxl => "40px",
+            Self::SpaceNone => "0px",
+            Self::SpaceXxxs => "2px",
+            Self::SpaceXxs => "4px",
+            Self::SpaceXs => "8px",
+            Self::SpaceS => "12px",
+            Self::SpaceM => "16px",
+            Self::SpaceL => "20px",
+            Self::SpaceXl => "24px",
+            Self::SpaceXxl => "32px",
+            Self::SpaceXxxl => "40px",
+        }
+    }
+
     /// Get the CSS var() function for use in styles
     pub fn css_var(&self) -> String {
         format!("var({})", self.css_var_name())
@@ -1966,18 +4938,79 @@ pub enum BorderToken {
 }
 
 impl BorderToken {
+    /// All tokens in this group
+    pub const ALL: &'static [Self] = &[
+        Self::BorderActiveWidth,
+        Self::BorderCodeEditorStatusDividerWidth,
+        Self::BorderContainerStickyWidth,
+        Self::BorderContainerTopWidth,
+        Self::BorderControlFocusRingShadowSpread,
+        Self::BorderControlInvalidFocusRingShadowSpread,
+        Self::BorderDividerListWidth,
+        Self::BorderDividerSectionWidth,
+        Self::BorderDropdownVirtualOffsetWidth,
+        Self::BorderInvalidWidth,
+        Self::BorderItemWidth,
+        Self::BorderLineChartDashArray,
+        Self::BorderLineChartLineJoin,
+        Self::BorderLineChartWidth,
+        Self::BorderPanelHeaderWidth,
+        Self::BorderPanelTopWidth,
+        Self::BorderRadiusAlert,
+        Self::BorderRadiusBadge,
+        Self::BorderRadiusButton,
+        Self::BorderRadiusCalendarDayFocusRing,
+        Self::BorderRadiusCodeEditor,
+        Self::BorderRadiusContainer,
+        Self::BorderRadiusControlCircularFocusRing,
+        Self::BorderRadiusControlDefaultFocusRing,
+        Self::BorderRadiusDropdown,
+        Self::BorderRadiusDropzone,
+        Self::BorderRadiusFlashbar,
+        Self::BorderRadiusItem,
+        Self::BorderRadiusInput,
+        Self::BorderRadiusPopover,
+        Self::BorderRadiusTabsFocusRing,
+        Self::BorderRadiusTiles,
+        Self::BorderRadiusToken,
+        Self::BorderRadiusChatBubble,
+        Self::BorderRadiusTutorialPanelItem,
+        Self::BorderTableStickyWidth,
+        Self::BorderLinkFocusRingOutline,
+        Self::BorderLinkFocusRingShadowSpread,
+        Self::BorderWidthAlert,
+        Self::BorderWidthButton,
+        Self::BorderWidthDropdown,
+        Self::BorderWidthField,
+        Self::BorderWidthPopover,
+        Self::BorderWidthToken,
+        Self::BorderWidthIconSmall,
+        Self::BorderWidthIconNormal,
+        Self::BorderWidthIconMedium,
+        Self::BorderWidthIconBig,
+        Self::BorderWidthIconLarge,
+    ];
+
     /// Get the CSS custom property name for this token
     pub fn css_var_name(&self) -> &'static str {
         match self {
             Self::BorderActiveWidth => "--awsui-border-active-width",
-            Self::BorderCodeEditorStatusDividerWidth => "--awsui-border-code-editor-status-divider-width",
+            Self::BorderCodeEditorStatusDividerWidth => {
+                "--awsui-border-code-editor-status-divider-width"
+            }
             Self::BorderContainerStickyWidth => "--awsui-border-container-sticky-width",
             Self::BorderContainerTopWidth => "--awsui-border-container-top-width",
-            Self::BorderControlFocusRingShadowSpread => "--awsui-border-control-focus-ring-shadow-spread",
-            Self::BorderControlInvalidFocusRingShadowSpread => "--awsui-border-control-invalid-focus-ring-shadow-spread",
+            Self::BorderControlFocusRingShadowSpread => {
+                "--awsui-border-control-focus-ring-shadow-spread"
+            }
+            Self::BorderControlInvalidFocusRingShadowSpread => {
+                "--awsui-border-control-invalid-focus-ring-shadow-spread"
+            }
             Self::BorderDividerListWidth => "--awsui-border-divider-list-width",
             Self::BorderDividerSectionWidth => "--awsui-border-divider-section-width",
-            Self::BorderDropdownVirtualOffsetWidth => "--awsui-border-dropdown-virtual-offset-width",
+            Self::BorderDropdownVirtualOffsetWidth => {
+                "--awsui-border-dropdown-virtual-offset-width"
+            }
             Self::BorderInvalidWidth => "--awsui-border-invalid-width",
             Self::BorderItemWidth => "--awsui-border-item-width",
             Self::BorderLineChartDashArray => "--awsui-border-line-chart-dash-array",
@@ -1988,11 +5021,17 @@ impl BorderToken {
             Self::BorderRadiusAlert => "--awsui-border-radius-alert",
             Self::BorderRadiusBadge => "--awsui-border-radius-badge",
             Self::BorderRadiusButton => "--awsui-border-radius-button",
-            Self::BorderRadiusCalendarDayFocusRing => "--awsui-border-radius-calendar-day-focus-ring",
+            Self::BorderRadiusCalendarDayFocusRing => {
+                "--awsui-border-radius-calendar-day-focus-ring"
+            }
             Self::BorderRadiusCodeEditor => "--awsui-border-radius-code-editor",
             Self::BorderRadiusContainer => "--awsui-border-radius-container",
-            Self::BorderRadiusControlCircularFocusRing => "--awsui-border-radius-control-circular-focus-ring",
-            Self::BorderRadiusControlDefaultFocusRing => "--awsui-border-radius-control-default-focus-ring",
+            Self::BorderRadiusControlCircularFocusRing => {
+                "--awsui-border-radius-control-circular-focus-ring"
+            }
+            Self::BorderRadiusControlDefaultFocusRing => {
+                "--awsui-border-radius-control-default-focus-ring"
+            }
             Self::BorderRadiusDropdown => "--awsui-border-radius-dropdown",
             Self::BorderRadiusDropzone => "--awsui-border-radius-dropzone",
             Self::BorderRadiusFlashbar => "--awsui-border-radius-flashbar",
@@ -2021,6 +5060,64 @@ impl BorderToken {
         }
     }
 
+    /// Get the resolved value of this token in a mode
+    ///
+    /// Modes that do not apply to the token, such as density modes for
+    /// colors, give its default value.
+    pub fn value(&self, _mode: Mode) -> &'static str {
+        match self {
+            Self::BorderActiveWidth => "4px",
+            Self::BorderCodeEditorStatusDividerWidth => "1px",
+            Self::BorderContainerStickyWidth => "0px",
+            Self::BorderContainerTopWidth => "0px",
+            Self::BorderControlFocusRingShadowSpread => "1px",
+            Self::BorderControlInvalidFocusRingShadowSpread => "2px",
+            Self::BorderDividerListWidth => "1px",
+            Self::BorderDividerSectionWidth => "1px",
+            Self::BorderDropdownVirtualOffsetWidth => "2px",
+            Self::BorderInvalidWidth => "8px",
+            Self::BorderItemWidth => "2px",
+            Self::BorderLineChartDashArray => "3 5",
+            Self::BorderLineChartLineJoin => "round",
+            Self::BorderLineChartWidth => "2px",
+            Self::BorderPanelHeaderWidth => "1px",
+            Self::BorderPanelTopWidth => "1px",
+            Self::BorderRadiusAlert => "12px",
+            Self::BorderRadiusBadge => "4px",
+            Self::BorderRadiusButton => "20px",
+            Self::BorderRadiusCalendarDayFocusRing => "3px",
+            Self::BorderRadiusCodeEditor => "8px",
+            Self::BorderRadiusContainer => "16px",
+            Self::BorderRadiusControlCircularFocusRing => "4px",
+            Self::BorderRadiusControlDefaultFocusRing => "4px",
+            Self::BorderRadiusDropdown => "8px",
+            Self::BorderRadiusDropzone => "12px",
+            Self::BorderRadiusFlashbar => "12px",
+            Self::BorderRadiusItem => "8px",
+            Self::BorderRadiusInput => "8px",
+            Self::BorderRadiusPopover => "8px",
+            Self::BorderRadiusTabsFocusRing => "20px",
+            Self::BorderRadiusTiles => "8px",
+            Self::BorderRadiusToken => "8px",
+            Self::BorderRadiusChatBubble => "8px",
+            Self::BorderRadiusTutorialPanelItem => "8px",
+            Self::BorderTableStickyWidth => "1px",
+            Self::BorderLinkFocusRingOutline => "0",
+            Self::BorderLinkFocusRingShadowSpread => "2px",
+            Self::BorderWidthAlert => "2px",
+            Self::BorderWidthButton => "2px",
+            Self::BorderWidthDropdown => "2px",
+            Self::BorderWidthField => "1px",
+            Self::BorderWidthPopover => "2px",
+            Self::BorderWidthToken => "2px",
+            Self::BorderWidthIconSmall => "2px",
+            Self::BorderWidthIconNormal => "2px",
+            Self::BorderWidthIconMedium => "2px",
+            Self::BorderWidthIconBig => "3px",
+            Self::BorderWidthIconLarge => "4px",
+        }
+    }
+
     /// Get the CSS var() function for use in styles
     pub fn css_var(&self) -> String {
         format!("var({})", self.css_var_name())
@@ -2093,6 +5190,51 @@ pub enum MotionToken {
 }
 
 impl MotionToken {
+    /// All tokens in this group
+    pub const ALL: &'static [Self] = &[
+        Self::MotionDurationExtraFast,
+        Self::MotionDurationExtraSlow,
+        Self::MotionDurationFast,
+        Self::MotionDurationModerate,
+        Self::MotionDurationRefreshOnlyAmbient,
+        Self::MotionDurationRefreshOnlyFast,
+        Self::MotionDurationRefreshOnlyMedium,
+        Self::MotionDurationRefreshOnlySlow,
+        Self::MotionDurationAvatarGenAiGradient,
+        Self::MotionDurationAvatarLoadingDots,
+        Self::MotionDurationRotate180,
+        Self::MotionDurationRotate90,
+        Self::MotionDurationShowPaced,
+        Self::MotionDurationShowQuick,
+        Self::MotionDurationSlow,
+        Self::MotionDurationTransitionQuick,
+        Self::MotionDurationTransitionShowPaced,
+        Self::MotionDurationTransitionShowQuick,
+        Self::MotionEasingEaseOutQuart,
+        Self::MotionEasingRefreshOnlyA,
+        Self::MotionEasingRefreshOnlyB,
+        Self::MotionEasingRefreshOnlyC,
+        Self::MotionEasingRefreshOnlyD,
+        Self::MotionEasingAvatarGenAiGradient,
+        Self::MotionEasingRotate180,
+        Self::MotionEasingRotate90,
+        Self::MotionEasingShowPaced,
+        Self::MotionEasingShowQuick,
+        Self::MotionEasingTransitionQuick,
+        Self::MotionEasingTransitionShowPaced,
+        Self::MotionEasingTransitionShowQuick,
+        Self::MotionEasingResponsive,
+        Self::MotionEasingSticky,
+        Self::MotionEasingExpressive,
+        Self::MotionDurationResponsive,
+        Self::MotionDurationExpressive,
+        Self::MotionDurationComplex,
+        Self::MotionKeyframesFadeIn,
+        Self::MotionKeyframesFadeOut,
+        Self::MotionKeyframesStatusIconError,
+        Self::MotionKeyframesScalePopup,
+    ];
+
     /// Get the CSS custom property name for this token
     pub fn css_var_name(&self) -> &'static str {
         match self {
@@ -2100,11 +5242,15 @@ impl MotionToken {
             Self::MotionDurationExtraSlow => "--awsui-motion-duration-extra-slow",
             Self::MotionDurationFast => "--awsui-motion-duration-fast",
             Self::MotionDurationModerate => "--awsui-motion-duration-moderate",
-            Self::MotionDurationRefreshOnlyAmbient => "--awsui-motion-duration-refresh-only-ambient",
+            Self::MotionDurationRefreshOnlyAmbient => {
+                "--awsui-motion-duration-refresh-only-ambient"
+            }
             Self::MotionDurationRefreshOnlyFast => "--awsui-motion-duration-refresh-only-fast",
             Self::MotionDurationRefreshOnlyMedium => "--awsui-motion-duration-refresh-only-medium",
             Self::MotionDurationRefreshOnlySlow => "--awsui-motion-duration-refresh-only-slow",
-            Self::MotionDurationAvatarGenAiGradient => "--awsui-motion-duration-avatar-gen-ai-gradient",
+            Self::MotionDurationAvatarGenAiGradient => {
+                "--awsui-motion-duration-avatar-gen-ai-gradient"
+            }
             Self::MotionDurationAvatarLoadingDots => "--awsui-motion-duration-avatar-loading-dots",
             Self::MotionDurationRotate180 => "--awsui-motion-duration-rotate180",
             Self::MotionDurationRotate90 => "--awsui-motion-duration-rotate90",
@@ -2112,8 +5258,12 @@ impl MotionToken {
             Self::MotionDurationShowQuick => "--awsui-motion-duration-show-quick",
             Self::MotionDurationSlow => "--awsui-motion-duration-slow",
             Self::MotionDurationTransitionQuick => "--awsui-motion-duration-transition-quick",
-            Self::MotionDurationTransitionShowPaced => "--awsui-motion-duration-transition-show-paced",
-            Self::MotionDurationTransitionShowQuick => "--awsui-motion-duration-transition-show-quick",
+            Self::MotionDurationTransitionShowPaced => {
+                "--awsui-motion-duration-transition-show-paced"
+            }
+            Self::MotionDurationTransitionShowQuick => {
+                "--awsui-motion-duration-transition-show-quick"
+            }
             Self::MotionEasingEaseOutQuart => "--awsui-motion-easing-ease-out-quart",
             Self::MotionEasingRefreshOnlyA => "--awsui-motion-easing-refresh-only-a",
             Self::MotionEasingRefreshOnlyB => "--awsui-motion-easing-refresh-only-b",
@@ -2140,6 +5290,119 @@ impl MotionToken {
         }
     }
 
+    /// Get the resolved value of this token in a mode
+    ///
+    /// Modes that do not apply to the token, such as density modes for
+    /// colors, give its default value.
+    pub fn value(&self, mode: Mode) -> &'static str {
+        match self {
+            Self::MotionDurationExtraFast => match mode {
+                Mode::MotionDisabled => "0ms",
+                _ => "45ms",
+            },
+            Self::MotionDurationExtraSlow => match mode {
+                Mode::MotionDisabled => "0ms",
+                _ => "270ms",
+            },
+            Self::MotionDurationFast => match mode {
+                Mode::MotionDisabled => "0ms",
+                _ => "90ms",
+            },
+            Self::MotionDurationModerate => match mode {
+                Mode::MotionDisabled => "0ms",
+                _ => "135ms",
+            },
+            Self::MotionDurationRefreshOnlyAmbient => match mode {
+                Mode::MotionDisabled => "0ms",
+                _ => "2000ms",
+            },
+            Self::MotionDurationRefreshOnlyFast => match mode {
+                Mode::MotionDisabled => "0ms",
+                _ => "115ms",
+            },
+            Self::MotionDurationRefreshOnlyMedium => match mode {
+                Mode::MotionDisabled => "0ms",
+                _ => "165ms",
+            },
+            Self::MotionDurationRefreshOnlySlow => match mode {
+                Mode::MotionDisabled => "0ms",
+                _ => "250ms",
+            },
+            Self::MotionDurationAvatarGenAiGradient => match mode {
+                Mode::MotionDisabled => "0ms",
+                _ => "3600ms",
+            },
+            Self::MotionDurationAvatarLoadingDots => match mode {
+                Mode::MotionDisabled => "0ms",
+                _ => "1200ms",
+            },
+            Self::MotionDurationRotate180 => match mode {
+                Mode::MotionDisabled => "0ms",
+                _ => "135ms",
+            },
+            Self::MotionDurationRotate90 => match mode {
+                Mode::MotionDisabled => "0ms",
+                _ => "135ms",
+            },
+            Self::MotionDurationShowPaced => match mode {
+                Mode::MotionDisabled => "0ms",
+                _ => "180ms",
+            },
+            Self::MotionDurationShowQuick => match mode {
+                Mode::MotionDisabled => "0ms",
+                _ => "135ms",
+            },
+            Self::MotionDurationSlow => match mode {
+                Mode::MotionDisabled => "0ms",
+                _ => "180ms",
+            },
+            Self::MotionDurationTransitionQuick => match mode {
+                Mode::MotionDisabled => "0ms",
+                _ => "90ms",
+            },
+            Self::MotionDurationTransitionShowPaced => match mode {
+                Mode::MotionDisabled => "0ms",
+                _ => "180ms",
+            },
+            Self::MotionDurationTransitionShowQuick => match mode {
+                Mode::MotionDisabled => "0ms",
+                _ => "90ms",
+            },
+            Self::MotionEasingEaseOutQuart => "cubic-bezier(0.165, 0.84, 0.44, 1)",
+            Self::MotionEasingRefreshOnlyA => "cubic-bezier(0, 0, 0, 1)",
+            Self::MotionEasingRefreshOnlyB => "cubic-bezier(1, 0, 0.83, 1)",
+            Self::MotionEasingRefreshOnlyC => "cubic-bezier(0.84, 0, 0.16, 1)",
+            Self::MotionEasingRefreshOnlyD => "cubic-bezier(0.33, 0, 0.67, 1)",
+            Self::MotionEasingAvatarGenAiGradient => "cubic-bezier(0.7, 0, 0.3, 1)",
+            Self::MotionEasingRotate180 => "cubic-bezier(0.165, 0.84, 0.44, 1)",
+            Self::MotionEasingRotate90 => "cubic-bezier(0.165, 0.84, 0.44, 1)",
+            Self::MotionEasingShowPaced => "ease-out",
+            Self::MotionEasingShowQuick => "ease-out",
+            Self::MotionEasingTransitionQuick => "linear",
+            Self::MotionEasingTransitionShowPaced => "ease-out",
+            Self::MotionEasingTransitionShowQuick => "linear",
+            Self::MotionEasingResponsive => "cubic-bezier(0, 0, 0, 1)",
+            Self::MotionEasingSticky => "cubic-bezier(1, 0, 0.83, 1)",
+            Self::MotionEasingExpressive => "cubic-bezier(0.84, 0, 0.16, 1)",
+            Self::MotionDurationResponsive => match mode {
+                Mode::MotionDisabled => "0ms",
+                _ => "115ms",
+            },
+            Self::MotionDurationExpressive => match mode {
+                Mode::MotionDisabled => "0ms",
+                _ => "165ms",
+            },
+            Self::MotionDurationComplex => match mode {
+                Mode::MotionDisabled => "0ms",
+                _ => "250ms",
+            },
+            Self::MotionKeyframesFadeIn => "awsui-fade-in-35003c",
+            Self::MotionKeyframesFadeOut => "awsui-fade-out-35003c",
+            Self::MotionKeyframesStatusIconError => "awsui-status-icon-error-35003c",
+            Self::MotionKeyframesScalePopup => "awsui-scale-popup-35003c",
+        }
+    }
+
     /// Get the CSS var() function for use in styles
     pub fn css_var(&self) -> String {
         format!("var({})", self.css_var_name())
@@ -2167,6 +5430,19 @@ pub enum SizeToken {
 }
 
 impl SizeToken {
+    /// All tokens in this group
+    pub const ALL: &'static [Self] = &[
+        Self::SizeCalendarGridWidth,
+        Self::SizeControl,
+        Self::SizeIconBig,
+        Self::SizeIconLarge,
+        Self::SizeIconMedium,
+        Self::SizeIconNormal,
+        Self::SizeTableSelectionHorizontal,
+        Self::SizeVerticalInput,
+        Self::SizeVerticalPanelIconOffset,
+    ];
+
     /// Get the CSS custom property name for this token
     pub fn css_var_name(&self) -> &'static str {
         match self {
@@ -2182,6 +5458,30 @@ impl SizeToken {
         }
     }
 
+    /// Get the resolved value of this token in a mode
+    ///
+    /// Modes that do not apply to the token, such as density modes for
+    /// colors, give its default value.
+    pub fn value(&self, mode: Mode) -> &'static str {
+        match self {
+            Self::SizeCalendarGridWidth => "238px",
+            Self::SizeControl => "16px",
+            Self::SizeIconBig => "32px",
+            Self::SizeIconLarge => "48px",
+            Self::SizeIconMedium => "20px",
+            Self::SizeIconNormal => "16px",
+            Self::SizeTableSelectionHorizontal => "40px",
+            Self::SizeVerticalInput => match mode {
+                Mode::Compact => "28px",
+                _ => "32px",
+            },
+            Self::SizeVerticalPanelIconOffset => match mode {
+                Mode::Compact => "13px",
+                _ => "15px",
+            },
+        }
+    }
+
     /// Get the CSS var() function for use in styles
     pub fn css_var(&self) -> String {
         format!("var({})", self.css_var_name())
@@ -2224,6 +5524,26 @@ pub enum ShadowToken {
 }
 
 impl ShadowToken {
+    /// All tokens in this group
+    pub const ALL: &'static [Self] = &[
+        Self::ShadowContainer,
+        Self::ShadowContainerActive,
+        Self::ShadowDropdown,
+        Self::ShadowDropup,
+        Self::ShadowFlashCollapsed,
+        Self::ShadowFlashSticky,
+        Self::ShadowModal,
+        Self::ShadowPanel,
+        Self::ShadowPanelToggle,
+        Self::ShadowPopover,
+        Self::ShadowSplitBottom,
+        Self::ShadowSplitSide,
+        Self::ShadowSticky,
+        Self::ShadowStickyEmbedded,
+        Self::ShadowStickyColumnFirst,
+        Self::ShadowStickyColumnLast,
+    ];
+
     /// Get the CSS custom property name for this token
     pub fn css_var_name(&self) -> &'static str {
         match self {
@@ -2246,6 +5566,76 @@ impl ShadowToken {
         }
     }
 
+    /// Get the resolved value of this token in a mode
+    ///
+    /// Modes that do not apply to the token, such as density modes for
+    /// colors, give its default value.
+    pub fn value(&self, mode: Mode) -> &'static str {
+        match self {
+            Self::ShadowContainer => match mode {
+                Mode::Dark => "0px 1px 8px 2px rgba(0, 7, 22, 0.6)",
+                _ => "0px 0px 1px 1px #e9ebed, 0px 1px 8px 2px rgba(0, 7, 22, 0.12)",
+            },
+            Self::ShadowContainerActive => match mode {
+                Mode::Dark => "0px 1px 1px 1px #192534, 0px 6px 36px #00040c",
+                _ => "0px 1px 1px 1px #e9ebed, 0px 6px 36px #0007161a",
+            },
+            Self::ShadowDropdown => match mode {
+                Mode::Dark => "0px 4px 20px 1px rgba(0, 4, 12, 1)",
+                _ => "0px 4px 20px 1px rgba(0, 7, 22, 0.10)",
+            },
+            Self::ShadowDropup => match mode {
+                Mode::Dark => "0px 4px 20px 1px rgba(0, 4, 12, 1)",
+                _ => "0px 4px 20px 1px rgba(0, 7, 22, 0.10)",
+            },
+            Self::ShadowFlashCollapsed => "0px 4px 4px rgba(0, 0, 0, 0.25)",
+            Self::ShadowFlashSticky => match mode {
+                Mode::Dark => "0px 4px 8px rgba(0, 7, 22, 0.5)",
+                _ => "0px 4px 8px rgba(0, 7, 22, 0.10)",
+            },
+            Self::ShadowModal => match mode {
+                Mode::Dark => "0px 4px 20px 1px rgba(0, 4, 12, 1)",
+                _ => "0px 4px 20px 1px rgba(0, 7, 22, 0.10)",
+            },
+            Self::ShadowPanel => match mode {
+                Mode::Dark => "0px 0px 0px 1px #414d5c",
+                _ => "0px 0px 0px 1px #b6bec9",
+            },
+            Self::ShadowPanelToggle => match mode {
+                Mode::Dark => "0px 6px 12px 1px rgba(0, 7, 22, 1)",
+                _ => "0px 6px 12px 1px rgba(0, 7, 22, 0.12)",
+            },
+            Self::ShadowPopover => match mode {
+                Mode::Dark => "0px 4px 20px 1px rgba(0, 4, 12, 1)",
+                _ => "0px 4px 20px 1px rgba(0, 7, 22, 0.10)",
+            },
+            Self::ShadowSplitBottom => match mode {
+                Mode::Dark => "0px -36px 36px -36px rgba(0, 7, 22, 1)",
+                _ => "0px -36px 36px -36px rgba(0, 7, 22, 0.10)",
+            },
+            Self::ShadowSplitSide => match mode {
+                Mode::Dark => "-1px 0px 1px 0px #192534, -36px 6px 36px -36px rgba(0, 7, 22, 1)",
+                _ => "-1px 0px 1px 0px #e9ebed, -36px 6px 36px -36px rgba(0, 7, 22, 0.10)",
+            },
+            Self::ShadowSticky => match mode {
+                Mode::Dark => "0px 4px 8px 1px rgba(0, 7, 22, 0.5)",
+                _ => "0px 4px 8px 1px rgba(0, 7, 22, 0.10)",
+            },
+            Self::ShadowStickyEmbedded => match mode {
+                Mode::Dark => "0px 2px 0px 0px #414d5c, 0px 16px 16px -12px rgba(0, 7, 22, 1)",
+                _ => "0px 2px 0px 0px #e9ebed, 0px 16px 16px -12px rgba(0, 7, 22, 0.10)",
+            },
+            Self::ShadowStickyColumnFirst => match mode {
+                Mode::Dark => "0px 4px 8px 1px rgba(0, 7, 22, 0.5)",
+                _ => "4px 0px 8px 1px rgba(0, 7, 22, 0.1)",
+            },
+            Self::ShadowStickyColumnLast => match mode {
+                Mode::Dark => "0px 4px 8px 1px rgba(0, 7, 22, 0.5)",
+                _ => "-4px 0 8px 1px rgba(0, 28, 36, 0.1)",
+            },
+        }
+    }
+
     /// Get the CSS var() function for use in styles
     pub fn css_var(&self) -> String {
         format!("var({})", self.css_var_name())
@@ -2257,4 +5647,3 @@ impl fmt::Display for ShadowToken {
         write!(f, "{}", self.css_var())
     }
 }
-
@@ -10,9 +10,12 @@
 //! and are generated from the style-dictionary source.
 
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 pub mod borders;
 pub mod color;
+pub mod contrast;
+pub mod metrics;
 pub mod motion;
 pub mod shadows;
 pub mod spacing;
@@ -24,6 +27,8 @@ pub mod generated;
 
 pub use borders::BorderTokens;
 pub use color::ColorTokens;
+#[cfg(feature = "generated")]
+pub use metrics::ChartPalette;
 pub use motion::MotionTokens;
 pub use shadows::ShadowTokens;
 pub use spacing::SpacingTokens;
@@ -38,6 +43,11 @@ pub struct DesignTokens {
     pub borders: BorderTokens,
     pub shadows: ShadowTokens,
     pub motion: MotionTokens,
+    /// Resolved token values, by CSS custom property name and mode
+    ///
+    /// Empty unless the tokens were built with [`DesignTokens::resolved`].
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub values: BTreeMap<String, BTreeMap<Mode, String>>,
 }
 
 impl DesignTokens {
@@ -50,10 +60,83 @@ impl DesignTokens {
     pub fn to_json(&self) -> Result<String, serde_json::Error> {
         serde_json::to_string(self)
     }
+
+    /// Design tokens with the resolved value of every generated token in
+    /// every mode
+    #[cfg(feature = "generated")]
+    pub fn resolved() -> Self {
+        use generated::{
+            BorderToken, ColorToken, FontToken, MotionToken, OtherToken, ShadowToken, SizeToken,
+        };
+
+        fn add<T>(
+            values: &mut BTreeMap<String, BTreeMap<Mode, String>>,
+            tokens: &[T],
+            name: fn(&T) -> &'static str,
+            value: fn(&T, Mode) -> &'static str,
+        ) {
+            for token in tokens {
+                let by_mode = Mode::ALL
+                    .iter()
+                    .map(|&mode| (mode, value(token, mode).to_string()))
+                    .collect();
+                values.insert(name(token).to_string(), by_mode);
+            }
+        }
+
+        let mut values = BTreeMap::new();
+        add(
+            &mut values,
+            ColorToken::ALL,
+            ColorToken::css_var_name,
+            ColorToken::value,
+        );
+        add(
+            &mut values,
+            FontToken::ALL,
+            FontToken::css_var_name,
+            FontToken::value,
+        );
+        add(
+            &mut values,
+            OtherToken::ALL,
+            OtherToken::css_var_name,
+            OtherToken::value,
+        );
+        add(
+            &mut values,
+            BorderToken::ALL,
+            BorderToken::css_var_name,
+            BorderToken::value,
+        );
+        add(
+            &mut values,
+            MotionToken::ALL,
+            MotionToken::css_var_name,
+            MotionToken::value,
+        );
+        add(
+            &mut values,
+            SizeToken::ALL,
+            SizeToken::css_var_name,
+            SizeToken::value,
+        );
+        add(
+            &mut values,
+            ShadowToken::ALL,
+            ShadowToken::css_var_name,
+            ShadowToken::value,
+        );
+
+        Self {
+            values,
+            ..Self::default()
+        }
+    }
 }
 
 /// Mode identifier for multi-mode tokens (color, density, motion)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum Mode {
     /// Light color mode (default)
     Light,
@@ -70,6 +153,16 @@ pub enum Mode {
 }
 
 impl Mode {
+    /// All modes
+    pub const ALL: [Mode; 6] = [
+        Mode::Light,
+        Mode::Dark,
+        Mode::Comfortable,
+        Mode::Compact,
+        Mode::MotionDefault,
+        Mode::MotionDisabled,
+    ];
+
     /// Returns the CSS class name for this mode
     pub fn css_class(&self) -> &'static str {
         match self {
@@ -118,4 +211,17 @@ mod tests {
         // Basic sanity check that round-trip works
         assert!(deserialized.to_json().is_ok());
     }
+
+    #[cfg(feature = "generated")]
+    #[test]
+    fn test_resolved_tokens_serialize_values() {
+        let tokens = DesignTokens::resolved();
+        let body = &tokens.values["--awsui-color-text-body-default"];
+        assert_eq!(body[&Mode::Light], "#0f141a");
+        assert_eq!(body[&Mode::Dark], "#c6c6cd");
+
+        let json = tokens.to_json().expect("Failed to serialize");
+        let deserialized = DesignTokens::from_json(&json).expect("Failed to deserialize");
+        assert_eq!(deserialized.values, tokens.values);
+    }
 }
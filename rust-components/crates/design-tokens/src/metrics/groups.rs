@@ -0,0 +1,272 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Auto-generated token groups from style-dictionary
+//! DO NOT EDIT MANUALLY
+
+use crate::generated::{BorderToken, ColorToken, OtherToken, SizeToken};
+
+/// Spacing scale tokens
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpaceToken {
+    SpaceScaled2xNone,
+    SpaceScaled2xXxxs,
+    SpaceScaled2xXxs,
+    SpaceScaled2xXs,
+    SpaceScaled2xS,
+    SpaceScaled2xM,
+    SpaceScaled2xL,
+    SpaceScaled2xXl,
+    SpaceScaled2xXxl,
+    SpaceScaled2xXxxl,
+    SpaceScaledNone,
+    SpaceScaledXxxs,
+    SpaceScaledXxs,
+    SpaceScaledXs,
+    SpaceScaledS,
+    SpaceScaledM,
+    SpaceScaledL,
+    SpaceScaledXl,
+    SpaceScaledXxl,
+    SpaceScaledXxxl,
+    SpaceStaticXxxs,
+    SpaceStaticXxs,
+    SpaceStaticXs,
+    SpaceStaticS,
+    SpaceStaticM,
+    SpaceStaticL,
+    SpaceStaticXl,
+    SpaceStaticXxl,
+    SpaceStaticXxxl,
+}
+
+impl SpaceToken {
+    /// All tokens in this group
+    pub const ALL: &'static [Self] = &[
+        Self::SpaceScaled2xNone,
+        Self::SpaceScaled2xXxxs,
+        Self::SpaceScaled2xXxs,
+        Self::SpaceScaled2xXs,
+        Self::SpaceScaled2xS,
+        Self::SpaceScaled2xM,
+        Self::SpaceScaled2xL,
+        Self::SpaceScaled2xXl,
+        Self::SpaceScaled2xXxl,
+        Self::SpaceScaled2xXxxl,
+        Self::SpaceScaledNone,
+        Self::SpaceScaledXxxs,
+        Self::SpaceScaledXxs,
+        Self::SpaceScaledXs,
+        Self::SpaceScaledS,
+        Self::SpaceScaledM,
+        Self::SpaceScaledL,
+        Self::SpaceScaledXl,
+        Self::SpaceScaledXxl,
+        Self::SpaceScaledXxxl,
+        Self::SpaceStaticXxxs,
+        Self::SpaceStaticXxs,
+        Self::SpaceStaticXs,
+        Self::SpaceStaticS,
+        Self::SpaceStaticM,
+        Self::SpaceStaticL,
+        Self::SpaceStaticXl,
+        Self::SpaceStaticXxl,
+        Self::SpaceStaticXxxl,
+    ];
+
+    /// Get the generated design token holding this token's value
+    pub fn token(&self) -> OtherToken {
+        match self {
+            Self::SpaceScaled2xNone => OtherToken::SpaceScaled2xNone,
+            Self::SpaceScaled2xXxxs => OtherToken::SpaceScaled2xXxxs,
+            Self::SpaceScaled2xXxs => OtherToken::SpaceScaled2xXxs,
+            Self::SpaceScaled2xXs => OtherToken::SpaceScaled2xXs,
+            Self::SpaceScaled2xS => OtherToken::SpaceScaled2xS,
+            Self::SpaceScaled2xM => OtherToken::SpaceScaled2xM,
+            Self::SpaceScaled2xL => OtherToken::SpaceScaled2xL,
+            Self::SpaceScaled2xXl => OtherToken::SpaceScaled2xXl,
+            Self::SpaceScaled2xXxl => OtherToken::SpaceScaled2xXxl,
+            Self::SpaceScaled2xXxxl => OtherToken::SpaceScaled2xXxxl,
+            Self::SpaceScaledNone => OtherToken::SpaceScaledNone,
+            Self::SpaceScaledXxxs => OtherToken::SpaceScaledXxxs,
+            Self::SpaceScaledXxs => OtherToken::SpaceScaledXxs,
+            Self::SpaceScaledXs => OtherToken::SpaceScaledXs,
+            Self::SpaceScaledS => OtherToken::SpaceScaledS,
+            Self::SpaceScaledM => OtherToken::SpaceScaledM,
+            Self::SpaceScaledL => OtherToken::SpaceScaledL,
+            Self::SpaceScaledXl => OtherToken::SpaceScaledXl,
+            Self::SpaceScaledXxl => OtherToken::SpaceScaledXxl,
+            Self::SpaceScaledXxxl => OtherToken::SpaceScaledXxxl,
+            Self::SpaceStaticXxxs => OtherToken::SpaceStaticXxxs,
+            Self::SpaceStaticXxs => OtherToken::SpaceStaticXxs,
+            Self::SpaceStaticXs => OtherToken::SpaceStaticXs,
+            Self::SpaceStaticS => OtherToken::SpaceStaticS,
+            Self::SpaceStaticM => OtherToken::SpaceStaticM,
+            Self::SpaceStaticL => OtherToken::SpaceStaticL,
+            Self::SpaceStaticXl => OtherToken::SpaceStaticXl,
+            Self::SpaceStaticXxl => OtherToken::SpaceStaticXxl,
+            Self::SpaceStaticXxxl => OtherToken::SpaceStaticXxxl,
+        }
+    }
+}
+
+/// Icon size tokens
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IconSizeToken {
+    SizeIconBig,
+    SizeIconLarge,
+    SizeIconMedium,
+    SizeIconNormal,
+}
+
+impl IconSizeToken {
+    /// All tokens in this group
+    pub const ALL: &'static [Self] = &[
+        Self::SizeIconBig,
+        Self::SizeIconLarge,
+        Self::SizeIconMedium,
+        Self::SizeIconNormal,
+    ];
+
+    /// Get the generated design token holding this token's value
+    pub fn token(&self) -> SizeToken {
+        match self {
+            Self::SizeIconBig => SizeToken::SizeIconBig,
+            Self::SizeIconLarge => SizeToken::SizeIconLarge,
+            Self::SizeIconMedium => SizeToken::SizeIconMedium,
+            Self::SizeIconNormal => SizeToken::SizeIconNormal,
+        }
+    }
+}
+
+/// Border radius tokens
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BorderRadiusToken {
+    BorderRadiusAlert,
+    BorderRadiusBadge,
+    BorderRadiusButton,
+    BorderRadiusCalendarDayFocusRing,
+    BorderRadiusCodeEditor,
+    BorderRadiusContainer,
+    BorderRadiusControlCircularFocusRing,
+    BorderRadiusControlDefaultFocusRing,
+    BorderRadiusDropdown,
+    BorderRadiusDropzone,
+    BorderRadiusFlashbar,
+    BorderRadiusItem,
+    BorderRadiusInput,
+    BorderRadiusPopover,
+    BorderRadiusTabsFocusRing,
+    BorderRadiusTiles,
+    BorderRadiusToken,
+    BorderRadiusChatBubble,
+    BorderRadiusTutorialPanelItem,
+}
+
+impl BorderRadiusToken {
+    /// All tokens in this group
+    pub const ALL: &'static [Self] = &[
+        Self::BorderRadiusAlert,
+        Self::BorderRadiusBadge,
+        Self::BorderRadiusButton,
+        Self::BorderRadiusCalendarDayFocusRing,
+        Self::BorderRadiusCodeEditor,
+        Self::BorderRadiusContainer,
+        Self::BorderRadiusControlCircularFocusRing,
+        Self::BorderRadiusControlDefaultFocusRing,
+        Self::BorderRadiusDropdown,
+        Self::BorderRadiusDropzone,
+        Self::BorderRadiusFlashbar,
+        Self::BorderRadiusItem,
+        Self::BorderRadiusInput,
+        Self::BorderRadiusPopover,
+        Self::BorderRadiusTabsFocusRing,
+        Self::BorderRadiusTiles,
+        Self::BorderRadiusToken,
+        Self::BorderRadiusChatBubble,
+        Self::BorderRadiusTutorialPanelItem,
+    ];
+
+    /// Get the generated design token holding this token's value
+    pub fn token(&self) -> BorderToken {
+        match self {
+            Self::BorderRadiusAlert => BorderToken::BorderRadiusAlert,
+            Self::BorderRadiusBadge => BorderToken::BorderRadiusBadge,
+            Self::BorderRadiusButton => BorderToken::BorderRadiusButton,
+            Self::BorderRadiusCalendarDayFocusRing => BorderToken::BorderRadiusCalendarDayFocusRing,
+            Self::BorderRadiusCodeEditor => BorderToken::BorderRadiusCodeEditor,
+            Self::BorderRadiusContainer => BorderToken::BorderRadiusContainer,
+            Self::BorderRadiusControlCircularFocusRing => {
+                BorderToken::BorderRadiusControlCircularFocusRing
+            }
+            Self::BorderRadiusControlDefaultFocusRing => {
+                BorderToken::BorderRadiusControlDefaultFocusRing
+            }
+            Self::BorderRadiusDropdown => BorderToken::BorderRadiusDropdown,
+            Self::BorderRadiusDropzone => BorderToken::BorderRadiusDropzone,
+            Self::BorderRadiusFlashbar => BorderToken::BorderRadiusFlashbar,
+            Self::BorderRadiusItem => BorderToken::BorderRadiusItem,
+            Self::BorderRadiusInput => BorderToken::BorderRadiusInput,
+            Self::BorderRadiusPopover => BorderToken::BorderRadiusPopover,
+            Self::BorderRadiusTabsFocusRing => BorderToken::BorderRadiusTabsFocusRing,
+            Self::BorderRadiusTiles => BorderToken::BorderRadiusTiles,
+            Self::BorderRadiusToken => BorderToken::BorderRadiusToken,
+            Self::BorderRadiusChatBubble => BorderToken::BorderRadiusChatBubble,
+            Self::BorderRadiusTutorialPanelItem => BorderToken::BorderRadiusTutorialPanelItem,
+        }
+    }
+}
+
+/// Categorical chart palette tokens, in palette order
+pub(crate) const CATEGORICAL_PALETTE: [ColorToken; 50] = [
+    ColorToken::ColorChartsPaletteCategorical1,
+    ColorToken::ColorChartsPaletteCategorical2,
+    ColorToken::ColorChartsPaletteCategorical3,
+    ColorToken::ColorChartsPaletteCategorical4,
+    ColorToken::ColorChartsPaletteCategorical5,
+    ColorToken::ColorChartsPaletteCategorical6,
+    ColorToken::ColorChartsPaletteCategorical7,
+    ColorToken::ColorChartsPaletteCategorical8,
+    ColorToken::ColorChartsPaletteCategorical9,
+    ColorToken::ColorChartsPaletteCategorical10,
+    ColorToken::ColorChartsPaletteCategorical11,
+    ColorToken::ColorChartsPaletteCategorical12,
+    ColorToken::ColorChartsPaletteCategorical13,
+    ColorToken::ColorChartsPaletteCategorical14,
+    ColorToken::ColorChartsPaletteCategorical15,
+    ColorToken::ColorChartsPaletteCategorical16,
+    ColorToken::ColorChartsPaletteCategorical17,
+    ColorToken::ColorChartsPaletteCategorical18,
+    ColorToken::ColorChartsPaletteCategorical19,
+    ColorToken::ColorChartsPaletteCategorical20,
+    ColorToken::ColorChartsPaletteCategorical21,
+    ColorToken::ColorChartsPaletteCategorical22,
+    ColorToken::ColorChartsPaletteCategorical23,
+    ColorToken::ColorChartsPaletteCategorical24,
+    ColorToken::ColorChartsPaletteCategorical25,
+    ColorToken::ColorChartsPaletteCategorical26,
+    ColorToken::ColorChartsPaletteCategorical27,
+    ColorToken::ColorChartsPaletteCategorical28,
+    ColorToken::ColorChartsPaletteCategorical29,
+    ColorToken::ColorChartsPaletteCategorical30,
+    ColorToken::ColorChartsPaletteCategorical31,
+    ColorToken::ColorChartsPaletteCategorical32,
+    ColorToken::ColorChartsPaletteCategorical33,
+    ColorToken::ColorChartsPaletteCategorical34,
+    ColorToken::ColorChartsPaletteCategorical35,
+    ColorToken::ColorChartsPaletteCategorical36,
+    ColorToken::ColorChartsPaletteCategorical37,
+    ColorToken::ColorChartsPaletteCategorical38,
+    ColorToken::ColorChartsPaletteCategorical39,
+    ColorToken::ColorChartsPaletteCategorical40,
+    ColorToken::ColorChartsPaletteCategorical41,
+    ColorToken::ColorChartsPaletteCategorical42,
+    ColorToken::ColorChartsPaletteCategorical43,
+    ColorToken::ColorChartsPaletteCategorical44,
+    ColorToken::ColorChartsPaletteCategorical45,
+    ColorToken::ColorChartsPaletteCategorical46,
+    ColorToken::ColorChartsPaletteCategorical47,
+    ColorToken::ColorChartsPaletteCategorical48,
+    ColorToken::ColorChartsPaletteCategorical49,
+    ColorToken::ColorChartsPaletteCategorical50,
+];
@@ -0,0 +1,218 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Numeric token values
+//!
+//! Pixel and color values for consumers that cannot use CSS custom properties,
+//! such as custom `<canvas>` visualizations. The token groups in `groups.rs`
+//! are generated by `generate-rust-tokens.js` and only name generated tokens;
+//! their values are read from the tokens' `value()` methods, the same values
+//! as the CSS custom properties.
+
+#[cfg(feature = "generated")]
+mod groups;
+
+#[cfg(feature = "generated")]
+pub use groups::{BorderRadiusToken, IconSizeToken, SpaceToken};
+
+#[cfg(feature = "generated")]
+use crate::{BorderTokens, Mode, SpacingTokens};
+
+/// Browser default root font size in pixels
+pub const DEFAULT_BASE_FONT_SIZE: f64 = 16.0;
+
+/// Converts a rem length to pixels for the given root font size
+pub fn rem_to_px(rem: f64, base_font_size: f64) -> f64 {
+    rem * base_font_size
+}
+
+/// Converts a pixel length to rem for the given root font size
+pub fn px_to_rem(px: f64, base_font_size: f64) -> f64 {
+    px / base_font_size
+}
+
+/// Parses a resolved pixel value such as `"12px"`
+///
+/// The generator only groups tokens that resolve to pixels in every mode.
+#[cfg(feature = "generated")]
+fn parse_px(value: &str) -> f64 {
+    value
+        .strip_suffix("px")
+        .and_then(|number| number.parse().ok())
+        .unwrap_or_else(|| panic!("not a pixel value: {value}"))
+}
+
+#[cfg(feature = "generated")]
+impl SpaceToken {
+    /// Get the CSS custom property name for this token
+    pub fn css_var_name(&self) -> &'static str {
+        self.token().css_var_name()
+    }
+
+    /// Returns the pixel value of this token in the given density mode
+    pub fn px(&self, mode: Mode) -> f64 {
+        parse_px(self.token().value(mode))
+    }
+}
+
+#[cfg(feature = "generated")]
+impl IconSizeToken {
+    /// Get the CSS custom property name for this token
+    pub fn css_var_name(&self) -> &'static str {
+        self.token().css_var_name()
+    }
+
+    /// Returns the pixel value of this token in the given density mode
+    pub fn px(&self, mode: Mode) -> f64 {
+        parse_px(self.token().value(mode))
+    }
+}
+
+#[cfg(feature = "generated")]
+impl BorderRadiusToken {
+    /// Get the CSS custom property name for this token
+    pub fn css_var_name(&self) -> &'static str {
+        self.token().css_var_name()
+    }
+
+    /// Returns the pixel value of this token in the given density mode
+    pub fn px(&self, mode: Mode) -> f64 {
+        parse_px(self.token().value(mode))
+    }
+}
+
+#[cfg(feature = "generated")]
+impl SpacingTokens {
+    /// Returns the pixel value of a spacing token in the given density mode
+    pub fn px(token: SpaceToken, mode: Mode) -> f64 {
+        token.px(mode)
+    }
+}
+
+#[cfg(feature = "generated")]
+impl BorderTokens {
+    /// Returns the pixel value of a border radius token in the given density mode
+    pub fn radius_px(token: BorderRadiusToken, mode: Mode) -> f64 {
+        token.px(mode)
+    }
+}
+
+/// Chart color palettes
+#[cfg(feature = "generated")]
+pub struct ChartPalette;
+
+#[cfg(feature = "generated")]
+impl ChartPalette {
+    /// Number of colors in the categorical palette
+    pub const CATEGORICAL_LEN: usize = groups::CATEGORICAL_PALETTE.len();
+
+    /// Returns the hex color for a categorical series index
+    ///
+    /// Indices wrap around after the last color, so any number of series can
+    /// be colored. [`Mode::Dark`] selects the dark palette; every other mode
+    /// uses the light one.
+    pub fn categorical(index: usize, mode: Mode) -> &'static str {
+        groups::CATEGORICAL_PALETTE[index % Self::CATEGORICAL_LEN].value(mode)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "generated")]
+    fn test_spacing_px_per_density() {
+        assert_eq!(
+            SpacingTokens::px(SpaceToken::SpaceScaledM, Mode::Comfortable),
+            16.0
+        );
+        assert_eq!(
+            SpacingTokens::px(SpaceToken::SpaceScaledM, Mode::Compact),
+            12.0
+        );
+        assert_eq!(
+            SpacingTokens::px(SpaceToken::SpaceScaled2xXxxl, Mode::Compact),
+            24.0
+        );
+        assert_eq!(
+            SpacingTokens::px(SpaceToken::SpaceStaticL, Mode::Compact),
+            20.0
+        );
+        // Non-density modes fall back to comfortable
+        assert_eq!(
+            SpacingTokens::px(SpaceToken::SpaceScaledM, Mode::Dark),
+            16.0
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "generated")]
+    fn test_icon_sizes_and_radii() {
+        assert_eq!(IconSizeToken::SizeIconNormal.px(Mode::Comfortable), 16.0);
+        assert_eq!(IconSizeToken::SizeIconLarge.px(Mode::Compact), 48.0);
+        assert_eq!(
+            BorderTokens::radius_px(BorderRadiusToken::BorderRadiusButton, Mode::Comfortable),
+            20.0
+        );
+        assert_eq!(
+            BorderTokens::radius_px(BorderRadiusToken::BorderRadiusPopover, Mode::Compact),
+            8.0
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "generated")]
+    fn test_css_var_names_match_values() {
+        assert_eq!(
+            SpaceToken::SpaceScaledM.css_var_name(),
+            "--awsui-space-scaled-m"
+        );
+        assert_eq!(
+            IconSizeToken::SizeIconBig.css_var_name(),
+            "--awsui-size-icon-big"
+        );
+        assert_eq!(
+            BorderRadiusToken::BorderRadiusContainer.css_var_name(),
+            "--awsui-border-radius-container"
+        );
+    }
+
+    #[test]
+    fn test_rem_conversions() {
+        assert_eq!(rem_to_px(1.5, DEFAULT_BASE_FONT_SIZE), 24.0);
+        assert_eq!(rem_to_px(2.0, 14.0), 28.0);
+        assert_eq!(px_to_rem(24.0, DEFAULT_BASE_FONT_SIZE), 1.5);
+    }
+
+    #[test]
+    #[cfg(feature = "generated")]
+    fn test_categorical_palette() {
+        assert_eq!(ChartPalette::CATEGORICAL_LEN, 50);
+        assert_eq!(ChartPalette::categorical(0, Mode::Light), "#688ae8");
+        assert_eq!(ChartPalette::categorical(0, Mode::Dark), "#486de8");
+        assert_eq!(ChartPalette::categorical(49, Mode::Light), "#732c02");
+
+        for index in 0..50 {
+            for mode in [Mode::Light, Mode::Dark] {
+                let color = ChartPalette::categorical(index, mode);
+                assert_eq!(color.len(), 7, "index {index} in {mode:?}: {color}");
+                assert!(color.starts_with('#'));
+                assert!(color[1..].chars().all(|c| c.is_ascii_hexdigit()));
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "generated")]
+    fn test_categorical_palette_wraps() {
+        assert_eq!(
+            ChartPalette::categorical(50, Mode::Light),
+            ChartPalette::categorical(0, Mode::Light)
+        );
+        assert_eq!(
+            ChartPalette::categorical(103, Mode::Dark),
+            ChartPalette::categorical(3, Mode::Dark)
+        );
+    }
+}
@@ -1,91 +1,87 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use yew::prelude::*;
-use web_sys::wasm_bindgen::JsCast;
-use cloudscape_components::*;
 use crate::components::sidebar::Sidebar;
 use crate::pages::{
-    home::Home,
-    basic::BasicComponents,
-    forms::FormComponents,
-    layout::LayoutComponents,
-    navigation::NavigationComponents,
-    data_display::DataDisplayComponents,
-    overlay::OverlayComponents,
-    notification::NotificationComponents,
+    basic::BasicComponents, data_display::DataDisplayComponents, forms::FormComponents, home::Home,
+    layout::LayoutComponents, navigation::NavigationComponents,
+    notification::NotificationComponents, overlay::OverlayComponents, playground::Playground,
 };
+use crate::routes::{Route, breadcrumb_items};
+use cloudscape_components::*;
+use web_sys::wasm_bindgen::JsCast;
+use yew::prelude::*;
+use yew_router::prelude::*;
 
-#[derive(Clone, PartialEq)]
-pub enum Route {
-    Home,
-    Basic,
-    Forms,
-    Layout,
-    Navigation,
-    DataDisplay,
-    Overlay,
-    Notification,
+const THEME_STORAGE_KEY: &str = "cloudscape-theme";
+const NAVIGATION_OPEN_STORAGE_KEY: &str = "cloudscape-demo-navigation-open";
+const TOOLS_OPEN_STORAGE_KEY: &str = "cloudscape-demo-tools-open";
+
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok()?
 }
 
-impl Route {
-    pub fn as_str(&self) -> &'static str {
-        match self {
-            Route::Home => "home",
-            Route::Basic => "basic",
-            Route::Forms => "forms",
-            Route::Layout => "layout",
-            Route::Navigation => "navigation",
-            Route::DataDisplay => "data-display",
-            Route::Overlay => "overlay",
-            Route::Notification => "notification",
-        }
+fn load_setting(key: &str) -> Option<String> {
+    local_storage()?.get_item(key).ok()?
+}
+
+fn save_setting(key: &str, value: &str) {
+    if let Some(storage) = local_storage() {
+        let _ = storage.set_item(key, value);
     }
+}
 
-    pub fn from_str(s: &str) -> Self {
-        match s {
-            "basic" => Route::Basic,
-            "forms" => Route::Forms,
-            "layout" => Route::Layout,
-            "navigation" => Route::Navigation,
-            "data-display" => Route::DataDisplay,
-            "overlay" => Route::Overlay,
-            "notification" => Route::Notification,
-            _ => Route::Home,
-        }
+fn load_flag(key: &str, default: bool) -> bool {
+    load_setting(key)
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(default)
+}
+
+fn switch(route: Route) -> Html {
+    match route {
+        Route::Home => html! { <Home /> },
+        Route::Basic => html! { <BasicComponents /> },
+        Route::Forms => html! { <FormComponents /> },
+        Route::Layout => html! { <LayoutComponents /> },
+        Route::Navigation => html! { <NavigationComponents /> },
+        Route::DataDisplay => html! { <DataDisplayComponents /> },
+        Route::Overlay => html! { <OverlayComponents /> },
+        Route::Notification => html! { <NotificationComponents /> },
+        Route::Playground => html! { <Playground /> },
+        Route::NotFound => html! {
+            <Alert alert_type={AlertType::Warning} header="Page not found">
+                {"The page you are looking for does not exist. Use the navigation to pick a component category."}
+            </Alert>
+        },
     }
 }
 
 #[function_component(App)]
 pub fn app() -> Html {
-    let current_route = use_state(|| Route::Home);
+    html! {
+        <BrowserRouter>
+            <Shell />
+        </BrowserRouter>
+    }
+}
 
-    // Theme state: "light" or "dark"
-    let theme = use_state(|| {
-        // Check localStorage for saved preference
-        if let Some(window) = web_sys::window() {
-            if let Ok(Some(storage)) = window.local_storage() {
-                if let Ok(Some(saved_theme)) = storage.get_item("cloudscape-theme") {
-                    return saved_theme;
-                }
-            }
-        }
-        "light".to_string()
-    });
+#[function_component(Shell)]
+fn shell() -> Html {
+    let current_route = use_route::<Route>().unwrap_or(Route::NotFound);
+    let navigator = use_navigator();
 
-    let on_navigate = {
-        let current_route = current_route.clone();
-        Callback::from(move |route: Route| {
-            current_route.set(route);
-        })
-    };
+    // Theme state: "light" or "dark"
+    let theme =
+        use_state(|| load_setting(THEME_STORAGE_KEY).unwrap_or_else(|| "light".to_string()));
 
-    let navigation_open = use_state(|| true);
-    let tools_open = use_state(|| false);
+    // Panel state survives reloads
+    let navigation_open = use_state(|| load_flag(NAVIGATION_OPEN_STORAGE_KEY, true));
+    let tools_open = use_state(|| load_flag(TOOLS_OPEN_STORAGE_KEY, false));
 
     let on_navigation_change = {
         let navigation_open = navigation_open.clone();
         Callback::from(move |event: CustomEvent<NavigationChangeDetail>| {
+            save_setting(NAVIGATION_OPEN_STORAGE_KEY, &event.detail.open.to_string());
             navigation_open.set(event.detail.open);
         })
     };
@@ -93,28 +89,31 @@ pub fn app() -> Html {
     let on_tools_change = {
         let tools_open = tools_open.clone();
         Callback::from(move |event: CustomEvent<ToolsChangeDetail>| {
+            save_setting(TOOLS_OPEN_STORAGE_KEY, &event.detail.open.to_string());
             tools_open.set(event.detail.open);
         })
     };
 
+    let on_breadcrumb_follow = Callback::from(move |mut event: BreadcrumbFollowEvent| {
+        if let (Some(navigator), Some(route)) =
+            (navigator.as_ref(), Route::recognize(&event.detail.href))
+        {
+            event.prevent_default();
+            navigator.push(&route);
+        }
+    });
+
     // Apply theme to document element whenever it changes
     {
         let theme = theme.clone();
         use_effect_with((*theme).clone(), move |current_theme| {
-            if let Some(window) = web_sys::window() {
-                if let Some(document) = window.document() {
-                    if let Some(element) = document.document_element() {
-                        let html_element = element.dyn_into::<web_sys::HtmlElement>().ok();
-                        if let Some(html) = html_element {
-                            let _ = html.set_attribute("data-awsui-theme", current_theme);
-
-                            // Save to localStorage
-                            if let Ok(Some(storage)) = window.local_storage() {
-                                let _ = storage.set_item("cloudscape-theme", current_theme);
-                            }
-                        }
-                    }
-                }
+            if let Some(html) = web_sys::window()
+                .and_then(|window| window.document())
+                .and_then(|document| document.document_element())
+                .and_then(|element| element.dyn_into::<web_sys::HtmlElement>().ok())
+            {
+                let _ = html.set_attribute("data-awsui-theme", current_theme);
+                save_setting(THEME_STORAGE_KEY, current_theme);
             }
             || ()
         });
@@ -129,19 +128,15 @@ pub fn app() -> Html {
         })
     };
 
-    let content = match (*current_route).clone() {
-        Route::Home => html! { <Home /> },
-        Route::Basic => html! { <BasicComponents /> },
-        Route::Forms => html! { <FormComponents /> },
-        Route::Layout => html! { <LayoutComponents /> },
-        Route::Navigation => html! { <NavigationComponents /> },
-        Route::DataDisplay => html! { <DataDisplayComponents /> },
-        Route::Overlay => html! { <OverlayComponents /> },
-        Route::Notification => html! { <NotificationComponents /> },
+    let navigation = html! {
+        <Sidebar current_route={current_route} />
     };
 
-    let navigation = html! {
-        <Sidebar current_route={(*current_route).clone()} {on_navigate} />
+    let breadcrumbs = html! {
+        <Breadcrumbs
+            items={breadcrumb_items(&current_route)}
+            on_follow={on_breadcrumb_follow}
+        />
     };
 
     // Tools panel with theme switcher
@@ -194,9 +189,10 @@ pub fn app() -> Html {
             tools_open={*tools_open}
             tools={Some(tools)}
             on_tools_change={Some(on_tools_change)}
+            breadcrumbs={Some(breadcrumbs)}
             content_type={ContentType::Default}
         >
-            { content }
+            <Switch<Route> render={switch} />
         </AppLayout>
     }
 }
@@ -18,7 +18,7 @@ pub fn code_snippet(props: &CodeSnippetProps) -> Html {
     let on_copy = {
         let copied = copied.clone();
         let code = props.code.clone();
-        Callback::from(move |event: CustomEvent<CopyDetail>| {
+        Callback::from(move |_event: CustomEvent<CopyDetail>| {
             // In a real app, you'd use the clipboard API
             web_sys::console::log_1(&format!("Copied: {}", code).into());
             copied.set(true);
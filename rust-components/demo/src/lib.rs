@@ -4,6 +4,7 @@
 mod app;
 mod components;
 mod pages;
+mod routes;
 
 use app::App;
 use wasm_bindgen::prelude::*;
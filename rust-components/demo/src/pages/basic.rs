@@ -1,9 +1,115 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use yew::prelude::*;
-use cloudscape_components::*;
 use crate::components::code_snippet::CodeSnippet;
+use cloudscape_components::internal::ClickEvent;
+use cloudscape_components::*;
+use gloo::timers::callback::Interval;
+use std::rc::Rc;
+use yew::prelude::*;
+
+/// Number of files in the simulated upload
+const UPLOAD_FILES: u32 = 12;
+
+/// State of the simulated multi-file upload
+#[derive(Clone, Copy, PartialEq, Default)]
+struct UploadState {
+    completed: u32,
+    paused: bool,
+    canceled: bool,
+}
+
+enum UploadAction {
+    /// One more file finished uploading
+    Tick,
+    TogglePause,
+    Cancel,
+    Restart,
+}
+
+impl Reducible for UploadState {
+    type Action = UploadAction;
+
+    fn reduce(self: Rc<Self>, action: UploadAction) -> Rc<Self> {
+        let state = *self;
+        Rc::new(match action {
+            UploadAction::Tick
+                if !state.paused && !state.canceled && state.completed < UPLOAD_FILES =>
+            {
+                UploadState {
+                    completed: state.completed + 1,
+                    ..state
+                }
+            }
+            UploadAction::Tick => return self,
+            UploadAction::TogglePause => UploadState {
+                paused: !state.paused,
+                ..state
+            },
+            UploadAction::Cancel => UploadState {
+                canceled: true,
+                paused: false,
+                ..state
+            },
+            UploadAction::Restart => UploadState::default(),
+        })
+    }
+}
+
+/// Upload with Pause/Resume and Cancel controls next to the bar
+#[function_component(PausableUpload)]
+fn pausable_upload() -> Html {
+    let upload = use_reducer(UploadState::default);
+
+    {
+        let upload = upload.clone();
+        use_effect_with((), move |_| {
+            let interval = Interval::new(700, move || upload.dispatch(UploadAction::Tick));
+            move || drop(interval)
+        });
+    }
+
+    let dispatch = |action: fn() -> UploadAction| {
+        let upload = upload.clone();
+        Callback::from(move |_: ClickEvent| upload.dispatch(action()))
+    };
+
+    if upload.canceled {
+        return html! {
+            <SpaceBetween direction={SpaceBetweenDirection::Horizontal} size={SpaceBetweenSize::S}>
+                <StatusIndicator status_type={StatusIndicatorType::Stopped}>
+                    { Html::from(format!("Upload canceled after {} of {} files", upload.completed, UPLOAD_FILES)) }
+                </StatusIndicator>
+                <Button variant={ButtonVariant::Link} on_click={dispatch(|| UploadAction::Restart)}>
+                    {"Restart"}
+                </Button>
+            </SpaceBetween>
+        };
+    }
+
+    let done = upload.completed == UPLOAD_FILES;
+
+    html! {
+        <ProgressBar
+            value={upload.completed as f32 * 100.0 / UPLOAD_FILES as f32}
+            status={if done { ProgressBarStatus::Success } else { ProgressBarStatus::InProgress }}
+            label="Uploading files"
+            additional_info={html! { { format!("{} of {} files", upload.completed, UPLOAD_FILES) } }}
+            result_text={done.then(|| "All files uploaded".to_string())}
+            paused={upload.paused}
+            actions={html! {
+                <SpaceBetween direction={SpaceBetweenDirection::Horizontal} size={SpaceBetweenSize::Xs}>
+                    <Button on_click={dispatch(|| UploadAction::TogglePause)}>
+                        { Html::from(if upload.paused { "Resume" } else { "Pause" }) }
+                    </Button>
+                    <Button variant={ButtonVariant::Link} on_click={dispatch(|| UploadAction::Cancel)}>
+                        {"Cancel"}
+                    </Button>
+                </SpaceBetween>
+            }}
+        />
+    }
+}
 
 #[function_component(BasicComponents)]
 pub fn basic_components() -> Html {
@@ -339,6 +445,25 @@ pub fn basic_components() -> Html {
     value={100.0}
     status={ProgressBarStatus::Success}
     label="Complete"
+/>"#} />
+                        </div>
+
+                        <div class="demo-example">
+                            <div class="demo-example-title">{"Pausable Upload"}</div>
+                            <div class="demo-example-description">{"Pause, resume and cancel controls next to the bar; a paused bar ignores late updates"}</div>
+                            <div class="demo-preview">
+                                <PausableUpload />
+                            </div>
+                            <CodeSnippet code={r#"<ProgressBar
+    value={progress}
+    label="Uploading files"
+    paused={paused}
+    actions={html! {
+        <SpaceBetween direction={SpaceBetweenDirection::Horizontal} size={SpaceBetweenSize::Xs}>
+            <Button on_click={toggle_pause}>{ if paused { "Resume" } else { "Pause" } }</Button>
+            <Button variant={ButtonVariant::Link} on_click={cancel}>{"Cancel"}</Button>
+        </SpaceBetween>
+    }}
 />"#} />
                         </div>
                     </SpaceBetween>
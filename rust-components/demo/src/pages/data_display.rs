@@ -73,10 +73,91 @@ fn render_card_section(item: &serde_json::Value) -> Html {
     }
 }
 
+const PAGINATED_ITEMS: usize = 200;
+const PAGE_SIZE: usize = 25;
+
+fn paginated_items() -> Vec<DemoItem> {
+    let statuses = ["Active", "Pending", "Inactive"];
+    (1..=PAGINATED_ITEMS)
+        .map(|index| DemoItem {
+            id: index.to_string(),
+            name: format!("Item {}", index),
+            status: statuses[index % statuses.len()].to_string(),
+            value: ((index * 37) % 100) as i32,
+        })
+        .collect()
+}
+
+fn sort_items(items: &mut [DemoItem], sorting: &SortingState) {
+    match sorting.sort_column_id.as_deref() {
+        Some("id") => items.sort_by_key(|item| item.id.parse::<usize>().unwrap_or_default()),
+        Some("name") => items.sort_by(|a, b| a.name.cmp(&b.name)),
+        Some("status") => items.sort_by(|a, b| a.status.cmp(&b.status)),
+        Some("value") => items.sort_by_key(|item| item.value),
+        _ => return,
+    }
+    if sorting.sort_direction == SortDirection::Descending {
+        items.reverse();
+    }
+}
+
+#[function_component(PaginatedTable)]
+fn paginated_table() -> Html {
+    let current_page = use_state(|| 1u32);
+    let sorting = use_state(SortingState::default);
+    let selected = use_state(Vec::<DemoItem>::new);
+
+    let mut items = paginated_items();
+    sort_items(&mut items, &sorting);
+
+    let on_page_change = {
+        let current_page = current_page.clone();
+        Callback::from(move |event: CustomEvent<PaginationChangeDetail>| {
+            current_page.set(event.detail.requested_page_index);
+        })
+    };
+    let on_sort_change = {
+        let sorting = sorting.clone();
+        Callback::from(move |event: CustomEvent<TableSortDetail>| {
+            sorting.set(SortingState {
+                sort_column_id: Some(event.detail.column_id),
+                sort_direction: event.detail.direction,
+            });
+        })
+    };
+    let on_selection_change = {
+        let selected = selected.clone();
+        Callback::from(move |event: CustomEvent<TableSelectionDetail<DemoItem>>| {
+            selected.set(event.detail.selected_items);
+        })
+    };
+
+    html! {
+        <Table<DemoItem>
+            columns={vec![
+                TableColumn::new("id", "ID", render_id_cell).with_sortable(true),
+                TableColumn::new("name", "Name", render_name_cell).with_sortable(true),
+                TableColumn::new("status", "Status", render_status_cell).with_sortable(true),
+                TableColumn::new("value", "Value", render_value_cell).with_sortable(true),
+            ]}
+            items={items}
+            selection_type={SelectionType::Multi}
+            selected_items={(*selected).clone()}
+            on_selection_change={on_selection_change}
+            track_by={(|item: &DemoItem| item.id.clone()) as fn(&DemoItem) -> String}
+            show_selection_summary={true}
+            sorting_state={(*sorting).clone()}
+            on_sort_change={on_sort_change}
+            pagination={TablePaginationConfig::new(PAGE_SIZE, *current_page)
+                .with_on_page_change(on_page_change)}
+        />
+    }
+}
+
 #[function_component(DataDisplayComponents)]
 pub fn data_display_components() -> Html {
     let expanded = use_state(|| false);
-    let selected_items: UseStateHandle<Vec<DemoItem>> = use_state(|| vec![]);
+    let _selected_items: UseStateHandle<Vec<DemoItem>> = use_state(std::vec::Vec::new);
 
     let on_toggle_expand = {
         let expanded = expanded.clone();
@@ -361,6 +442,22 @@ pub fn data_display_components() -> Html {
     selection_type={SelectionType::Multi}
     selected_items={selected}
     on_selection_change={on_change}
+/>"#} />
+                        </div>
+
+                        <div class="demo-example">
+                            <div class="demo-example-title">{"Paginated Table"}</div>
+                            <div class="demo-example-description">{"200 items, 25 per page; sorting returns to the first page and selection spans pages"}</div>
+                            <div class="demo-preview">
+                                <PaginatedTable />
+                            </div>
+                            <CodeSnippet code={r#"<Table<Item>
+    columns={columns}
+    items={items}
+    sorting_state={sorting}
+    on_sort_change={on_sort_change}
+    pagination={TablePaginationConfig::new(25, current_page)
+        .with_on_page_change(on_page_change)}
 />"#} />
                         </div>
                     </SpaceBetween>
@@ -380,6 +477,9 @@ pub fn data_display_components() -> Html {
                                     card_definition={CardDefinition {
                                         header: render_card_header,
                                         sections: vec![render_card_section],
+                                        actions: None,
+                                        media: None,
+                                        card_href: None,
                                     }}
                                     items={items.iter().map(|item| serde_json::to_value(item).unwrap()).collect::<Vec<_>>()}
                                     cards_per_row={vec![3]}
@@ -13,7 +13,7 @@ pub fn form_components() -> Html {
     let radio_value = use_state(|| String::from("option1"));
     let select_value = use_state(|| String::from(""));
     let textarea_value = use_state(|| String::from(""));
-    let multiselect_values: UseStateHandle<Vec<String>> = use_state(|| vec![]);
+    let _multiselect_values: UseStateHandle<Vec<String>> = use_state(std::vec::Vec::new);
     let autosuggest_value = use_state(|| String::from(""));
     let tile_value = use_state(|| String::from("tile1"));
 
@@ -48,7 +48,9 @@ pub fn form_components() -> Html {
     let on_select_change = {
         let select_value = select_value.clone();
         Callback::from(move |event: CustomEvent<SelectChangeDetail>| {
-            select_value.set(event.detail.selected_option.value);
+            if let Some(option) = event.detail.selected_option {
+                select_value.set(option.value);
+            }
         })
     };
 
@@ -9,3 +9,4 @@ pub mod navigation;
 pub mod data_display;
 pub mod overlay;
 pub mod notification;
+pub mod playground;
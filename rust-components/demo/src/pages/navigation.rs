@@ -178,18 +178,9 @@ pub fn navigation_components() -> Html {
                                         <Box variant={BoxVariant::P}>{"Simple breadcrumbs"}</Box>
                                         <Breadcrumbs
                                             items={vec![
-                                                BreadcrumbItem {
-                                                    text: "Home".to_string(),
-                                                    href: "#".to_string(),
-                                                },
-                                                BreadcrumbItem {
-                                                    text: "Components".to_string(),
-                                                    href: "#".to_string(),
-                                                },
-                                                BreadcrumbItem {
-                                                    text: "Navigation".to_string(),
-                                                    href: "#".to_string(),
-                                                },
+                                                BreadcrumbItem::new("Home", "#"),
+                                                BreadcrumbItem::new("Components", "#"),
+                                                BreadcrumbItem::new("Navigation", "#"),
                                             ]}
                                         />
                                     </div>
@@ -198,26 +189,12 @@ pub fn navigation_components() -> Html {
                                         <Box variant={BoxVariant::P}>{"Deep hierarchy"}</Box>
                                         <Breadcrumbs
                                             items={vec![
-                                                BreadcrumbItem {
-                                                    text: "Services".to_string(),
-                                                    href: "#".to_string(),
-                                                },
-                                                BreadcrumbItem {
-                                                    text: "EC2".to_string(),
-                                                    href: "#".to_string(),
-                                                },
-                                                BreadcrumbItem {
-                                                    text: "Instances".to_string(),
-                                                    href: "#".to_string(),
-                                                },
-                                                BreadcrumbItem {
-                                                    text: "i-1234567890abcdef0".to_string(),
-                                                    href: "#".to_string(),
-                                                },
-                                                BreadcrumbItem {
-                                                    text: "Details".to_string(),
-                                                    href: "#".to_string(),
-                                                },
+                                                BreadcrumbItem::new("Services", "#"),
+                                                BreadcrumbItem::new("EC2", "#"),
+                                                BreadcrumbItem::new("Instances", "#"),
+                                                BreadcrumbItem::new("arn:aws:s3:::my-extremely-long-bucket-name-2024-production", "#")
+                                                    .with_max_width("160px"),
+                                                BreadcrumbItem::new("Details", "#"),
                                             ]}
                                         />
                                     </div>
@@ -225,18 +202,9 @@ pub fn navigation_components() -> Html {
                             </div>
                             <CodeSnippet code={r##"<Breadcrumbs
     items={vec![
-        BreadcrumbItem {
-            text: "Home".to_string(),
-            href: "/".to_string(),
-        },
-        BreadcrumbItem {
-            text: "Section".to_string(),
-            href: "/section".to_string(),
-        },
-        BreadcrumbItem {
-            text: "Current Page".to_string(),
-            href: "#".to_string(),
-        },
+        BreadcrumbItem::new("Home", "/"),
+        BreadcrumbItem::new("Section", "/section"),
+        BreadcrumbItem::new("Current Page", "#"),
     ]}
     on_follow={on_follow}
 />"##} />
@@ -358,6 +326,7 @@ pub fn navigation_components() -> Html {
                                             title: Some("Demo Application".to_string()),
                                             href: "#".to_string(),
                                             logo: None,
+                                            max_width: None,
                                             on_follow: None,
                                         }}
                                         utilities={vec![
@@ -18,6 +18,11 @@ pub fn notification_components() -> Html {
             action: None,
             button_text: None,
             on_button_click: None,
+            progress: None,
+            dismissible_while_in_progress: false,
+            auto_dismiss_on_success: None,
+            auto_dismiss: None,
+            content_summary: None,
         },
         FlashbarItem {
             id: Some("2".to_string()),
@@ -31,6 +36,11 @@ pub fn notification_components() -> Html {
             }),
             button_text: None,
             on_button_click: None,
+            progress: None,
+            dismissible_while_in_progress: false,
+            auto_dismiss_on_success: None,
+            auto_dismiss: None,
+            content_summary: None,
         },
     ]);
 
@@ -48,6 +58,11 @@ pub fn notification_components() -> Html {
                 action: None,
                 button_text: None,
                 on_button_click: None,
+                progress: None,
+                dismissible_while_in_progress: false,
+                auto_dismiss_on_success: None,
+                auto_dismiss: None,
+                content_summary: None,
             });
             flash_items.set(items);
         })
@@ -69,6 +84,11 @@ pub fn notification_components() -> Html {
                 }),
                 button_text: None,
                 on_button_click: None,
+                progress: None,
+                dismissible_while_in_progress: false,
+                auto_dismiss_on_success: None,
+                auto_dismiss: None,
+                content_summary: None,
             });
             flash_items.set(items);
         })
@@ -88,6 +108,11 @@ pub fn notification_components() -> Html {
                 action: None,
                 button_text: None,
                 on_button_click: None,
+                progress: None,
+                dismissible_while_in_progress: false,
+                auto_dismiss_on_success: None,
+                auto_dismiss: None,
+                content_summary: None,
             });
             flash_items.set(items);
         })
@@ -107,6 +132,11 @@ pub fn notification_components() -> Html {
                 action: None,
                 button_text: None,
                 on_button_click: None,
+                progress: None,
+                dismissible_while_in_progress: false,
+                auto_dismiss_on_success: None,
+                auto_dismiss: None,
+                content_summary: None,
             });
             flash_items.set(items);
         })
@@ -242,6 +272,11 @@ pub fn notification_components() -> Html {
             action: None,
             button_text: None,
             on_button_click: None,
+            progress: None,
+            dismissible_while_in_progress: false,
+            auto_dismiss_on_success: None,
+            auto_dismiss: None,
+            content_summary: None,
         },
     ]}
     on_item_dismiss={on_dismiss}
@@ -264,6 +299,11 @@ pub fn notification_components() -> Html {
                                             action: None,
                                             button_text: None,
                                             on_button_click: None,
+                                            progress: None,
+                                            dismissible_while_in_progress: false,
+                                            auto_dismiss_on_success: None,
+                                            auto_dismiss: None,
+                                            content_summary: None,
                                         },
                                         FlashbarItem {
                                             id: Some("type-info".to_string()),
@@ -275,6 +315,11 @@ pub fn notification_components() -> Html {
                                             action: None,
                                             button_text: None,
                                             on_button_click: None,
+                                            progress: None,
+                                            dismissible_while_in_progress: false,
+                                            auto_dismiss_on_success: None,
+                                            auto_dismiss: None,
+                                            content_summary: None,
                                         },
                                         FlashbarItem {
                                             id: Some("type-warning".to_string()),
@@ -286,6 +331,11 @@ pub fn notification_components() -> Html {
                                             action: None,
                                             button_text: None,
                                             on_button_click: None,
+                                            progress: None,
+                                            dismissible_while_in_progress: false,
+                                            auto_dismiss_on_success: None,
+                                            auto_dismiss: None,
+                                            content_summary: None,
                                         },
                                         FlashbarItem {
                                             id: Some("type-error".to_string()),
@@ -297,6 +347,11 @@ pub fn notification_components() -> Html {
                                             action: None,
                                             button_text: None,
                                             on_button_click: None,
+                                            progress: None,
+                                            dismissible_while_in_progress: false,
+                                            auto_dismiss_on_success: None,
+                                            auto_dismiss: None,
+                                            content_summary: None,
                                         },
                                     ]}
                                     on_item_dismiss={Callback::noop()}
@@ -355,6 +410,11 @@ FlashbarItem {
                                             }),
                                             button_text: None,
                                             on_button_click: None,
+                                            progress: None,
+                                            dismissible_while_in_progress: false,
+                                            auto_dismiss_on_success: None,
+                                            auto_dismiss: None,
+                                            content_summary: None,
                                         },
                                         FlashbarItem {
                                             id: Some("action-2".to_string()),
@@ -375,6 +435,11 @@ FlashbarItem {
                                             }),
                                             button_text: None,
                                             on_button_click: None,
+                                            progress: None,
+                                            dismissible_while_in_progress: false,
+                                            auto_dismiss_on_success: None,
+                                            auto_dismiss: None,
+                                            content_summary: None,
                                         },
                                     ]}
                                     on_item_dismiss={Callback::noop()}
@@ -410,6 +475,11 @@ FlashbarItem {
                                             action: None,
                                             button_text: None,
                                             on_button_click: None,
+                                            progress: None,
+                                            dismissible_while_in_progress: false,
+                                            auto_dismiss_on_success: None,
+                                            auto_dismiss: None,
+                                            content_summary: None,
                                         },
                                     ]}
                                     on_item_dismiss={Callback::noop()}
@@ -502,6 +572,11 @@ fn my_page() -> Html {
                 action: None,
                 button_text: None,
                 on_button_click: None,
+                progress: None,
+                dismissible_while_in_progress: false,
+                auto_dismiss_on_success: None,
+                auto_dismiss: None,
+                content_summary: None,
             });
             flash_items.set(items);
         })
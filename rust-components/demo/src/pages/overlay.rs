@@ -10,7 +10,7 @@ use crate::components::code_snippet::CodeSnippet;
 pub fn overlay_components() -> Html {
     let modal_visible = use_state(|| false);
     let drawer_visible = use_state(|| false);
-    let popover_visible = use_state(|| false);
+    let _popover_visible = use_state(|| false);
 
     let show_modal = {
         let modal_visible = modal_visible.clone();
@@ -280,7 +280,7 @@ pub fn overlay_components() -> Html {
                                     <div style="display: flex; gap: 16px; align-items: center;">
                                         <span>{"Click for more information: "}</span>
                                         <Popover
-                                            header="Popover Title"
+                                            header={html! { "Popover Title" }}
                                             size={PopoverSize::Medium}
                                             position={PopoverPosition::Top}
                                             dismissible={true}
@@ -317,7 +317,7 @@ pub fn overlay_components() -> Html {
                                     <div>
                                         <Box variant={BoxVariant::P}>{"With form content"}</Box>
                                         <Popover
-                                            header="Quick Edit"
+                                            header={html! { "Quick Edit" }}
                                             size={PopoverSize::Large}
                                             dismissible={true}
                                         >
@@ -342,7 +342,7 @@ pub fn overlay_components() -> Html {
                                 </SpaceBetween>
                             </div>
                             <CodeSnippet code={r#"<Popover
-    header="Popover Title"
+    header={html! { "Popover Title" }}
     size={PopoverSize::Medium}
     position={PopoverPosition::Top}
     dismissible={true}
@@ -0,0 +1,133 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::components::code_snippet::CodeSnippet;
+use cloudscape_components::*;
+use yew::prelude::*;
+
+const VARIANTS: &[(ButtonVariant, &str)] = &[
+    (ButtonVariant::Normal, "Normal"),
+    (ButtonVariant::Primary, "Primary"),
+    (ButtonVariant::Link, "Link"),
+    (ButtonVariant::InlineLink, "InlineLink"),
+];
+
+#[function_component(Playground)]
+pub fn playground() -> Html {
+    let label = use_state(|| "Launch instance".to_string());
+    let variant = use_state(|| ButtonVariant::Primary);
+    let disabled = use_state(|| false);
+    let loading = use_state(|| false);
+    let clicks = use_state(|| 0u32);
+
+    let variant_options: Vec<SelectOption> = VARIANTS
+        .iter()
+        .map(|(_, name)| SelectOption::new(*name))
+        .collect();
+    let selected_variant = VARIANTS
+        .iter()
+        .find(|(v, _)| v == &*variant)
+        .map(|(_, name)| SelectOption::new(*name));
+
+    let on_label_change = {
+        let label = label.clone();
+        Callback::from(move |event: CustomEvent<InputChangeDetail>| {
+            label.set(event.detail.value);
+        })
+    };
+
+    let on_variant_change = {
+        let variant = variant.clone();
+        Callback::from(move |event: CustomEvent<SelectChangeDetail>| {
+            if let Some(option) = event.detail.selected_option
+                && let Some((v, _)) = VARIANTS.iter().find(|(_, name)| *name == option.value)
+            {
+                variant.set(*v);
+            }
+        })
+    };
+
+    let on_disabled_change = {
+        let disabled = disabled.clone();
+        Callback::from(move |event: CustomEvent<ToggleChangeDetail>| {
+            disabled.set(event.detail.checked);
+        })
+    };
+
+    let on_loading_change = {
+        let loading = loading.clone();
+        Callback::from(move |event: CustomEvent<ToggleChangeDetail>| {
+            loading.set(event.detail.checked);
+        })
+    };
+
+    let on_click = {
+        let clicks = clicks.clone();
+        Callback::from(move |_| {
+            clicks.set(*clicks + 1);
+        })
+    };
+
+    let variant_name = selected_variant
+        .as_ref()
+        .map(|option| option.value.clone())
+        .unwrap_or_default();
+    let code = format!(
+        "<Button\n    variant={{ButtonVariant::{}}}\n    disabled={{{}}}\n    loading={{{}}}\n>\n    {{\"{}\"}}\n</Button>",
+        variant_name, *disabled, *loading, *label
+    );
+
+    html! {
+        <ContentLayout>
+            <div slot="header">
+                <Header variant={HeaderVariant::H1}>
+                    {"Playground"}
+                    <div slot="description">
+                        {"Adjust component properties and see the result live"}
+                    </div>
+                </Header>
+            </div>
+
+            <SpaceBetween size={SpaceBetweenSize::L}>
+                <Container header={Some(html! {
+                    <Header variant={HeaderVariant::H2}>{"Button"}</Header>
+                })}>
+                    <ColumnLayout columns={2}>
+                        <SpaceBetween size={SpaceBetweenSize::M}>
+                            <FormField label="Label">
+                                <Input value={(*label).clone()} on_change={on_label_change} />
+                            </FormField>
+                            <FormField label="Variant">
+                                <Select
+                                    options={variant_options}
+                                    selected_option={selected_variant}
+                                    on_change={on_variant_change}
+                                />
+                            </FormField>
+                            <Toggle checked={*disabled} on_change={on_disabled_change}>
+                                {"Disabled"}
+                            </Toggle>
+                            <Toggle checked={*loading} on_change={on_loading_change}>
+                                {"Loading"}
+                            </Toggle>
+                        </SpaceBetween>
+                        <SpaceBetween size={SpaceBetweenSize::M}>
+                            <div class="demo-preview">
+                                <Button
+                                    variant={*variant}
+                                    disabled={*disabled}
+                                    loading={*loading}
+                                    on_click={on_click}
+                                >
+                                    {html! { (*label).clone() }}
+                                </Button>
+                            </div>
+                            <Box>{html! { format!("Clicked {} times", *clicks) }}</Box>
+                            <CodeSnippet code={code} />
+                        </SpaceBetween>
+                    </ColumnLayout>
+                </Container>
+            </SpaceBetween>
+        </ContentLayout>
+    }
+}
@@ -0,0 +1,190 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Route table for the demo application
+//!
+//! The side navigation items, the active link, and the breadcrumb trail are
+//! all derived from `ROUTES`, so adding a page only requires a new `Route`
+//! variant and a matching entry here.
+
+use cloudscape_components::{BreadcrumbItem, SideNavigationItem};
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Routable)]
+pub enum Route {
+    #[at("/")]
+    Home,
+    #[at("/basic")]
+    Basic,
+    #[at("/forms")]
+    Forms,
+    #[at("/layout")]
+    Layout,
+    #[at("/navigation")]
+    Navigation,
+    #[at("/data-display")]
+    DataDisplay,
+    #[at("/overlay")]
+    Overlay,
+    #[at("/notification")]
+    Notification,
+    #[at("/playground")]
+    Playground,
+    #[not_found]
+    #[at("/404")]
+    NotFound,
+}
+
+/// Side navigation group a route is listed under
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NavGroup {
+    /// Top-level link above the component categories
+    Top,
+    /// One of the component category pages
+    Components,
+    /// Top-level link below the component categories
+    Tools,
+    /// Not listed in the side navigation
+    Hidden,
+}
+
+/// Metadata for a single route
+pub struct RouteEntry {
+    pub route: Route,
+    pub title: &'static str,
+    /// Short info text shown next to the navigation link
+    pub info: Option<&'static str>,
+    /// Parent route used to build the breadcrumb trail
+    pub parent: Option<Route>,
+    pub group: NavGroup,
+}
+
+pub const ROUTES: &[RouteEntry] = &[
+    RouteEntry {
+        route: Route::Home,
+        title: "Home",
+        info: None,
+        parent: None,
+        group: NavGroup::Top,
+    },
+    RouteEntry {
+        route: Route::Basic,
+        title: "Basic Components",
+        info: Some("11 components"),
+        parent: Some(Route::Home),
+        group: NavGroup::Components,
+    },
+    RouteEntry {
+        route: Route::Forms,
+        title: "Form Components",
+        info: Some("13 components"),
+        parent: Some(Route::Home),
+        group: NavGroup::Components,
+    },
+    RouteEntry {
+        route: Route::Layout,
+        title: "Layout Components",
+        info: Some("6 components"),
+        parent: Some(Route::Home),
+        group: NavGroup::Components,
+    },
+    RouteEntry {
+        route: Route::Navigation,
+        title: "Navigation Components",
+        info: Some("5 components"),
+        parent: Some(Route::Home),
+        group: NavGroup::Components,
+    },
+    RouteEntry {
+        route: Route::DataDisplay,
+        title: "Data Display",
+        info: Some("6 components"),
+        parent: Some(Route::Home),
+        group: NavGroup::Components,
+    },
+    RouteEntry {
+        route: Route::Overlay,
+        title: "Overlay Components",
+        info: Some("3 components"),
+        parent: Some(Route::Home),
+        group: NavGroup::Components,
+    },
+    RouteEntry {
+        route: Route::Notification,
+        title: "Notification",
+        info: Some("1 component"),
+        parent: Some(Route::Home),
+        group: NavGroup::Components,
+    },
+    RouteEntry {
+        route: Route::Playground,
+        title: "Playground",
+        info: None,
+        parent: Some(Route::Home),
+        group: NavGroup::Tools,
+    },
+    RouteEntry {
+        route: Route::NotFound,
+        title: "Page not found",
+        info: None,
+        parent: Some(Route::Home),
+        group: NavGroup::Hidden,
+    },
+];
+
+impl Route {
+    /// Looks up the route table entry for this route
+    pub fn entry(&self) -> &'static RouteEntry {
+        ROUTES
+            .iter()
+            .find(|entry| entry.route == *self)
+            .expect("every route has a table entry")
+    }
+
+    /// Returns the entries from the root down to (and including) this route
+    pub fn ancestry(&self) -> Vec<&'static RouteEntry> {
+        let mut trail = vec![self.entry()];
+        while let Some(parent) = trail.last().and_then(|entry| entry.parent) {
+            trail.push(parent.entry());
+        }
+        trail.reverse();
+        trail
+    }
+}
+
+/// Builds the breadcrumb trail for a route
+pub fn breadcrumb_items(route: &Route) -> Vec<BreadcrumbItem> {
+    route
+        .ancestry()
+        .into_iter()
+        .map(|entry| BreadcrumbItem::new(entry.title, entry.route.to_path()))
+        .collect()
+}
+
+/// Builds the side navigation items from the route table
+pub fn navigation_items() -> Vec<SideNavigationItem> {
+    let link = |entry: &RouteEntry| {
+        let item = SideNavigationItem::link(entry.title, entry.route.to_path());
+        match entry.info {
+            Some(info) => item.with_info(html! { { info } }),
+            None => item,
+        }
+    };
+    let in_group = |group: NavGroup| {
+        ROUTES
+            .iter()
+            .filter(move |entry| entry.group == group)
+            .map(link)
+    };
+
+    let mut items: Vec<SideNavigationItem> = in_group(NavGroup::Top).collect();
+    items.push(SideNavigationItem::divider());
+    items.push(
+        SideNavigationItem::section("Components")
+            .with_items(in_group(NavGroup::Components).collect()),
+    );
+    items.push(SideNavigationItem::divider());
+    items.extend(in_group(NavGroup::Tools));
+    items
+}